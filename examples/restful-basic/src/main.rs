@@ -32,9 +32,9 @@ fn main() {
     println!("------------------------");
     println!("✓ operations::get_system_info(&client) -> Result<GetSystemInfoOutput, RpcError>");
     println!(
-        "✓ operations::restart_device(&client, input) -> Result<RestartDeviceOutput, RpcError>"
+        "✓ operations::restart_device(&client, &input) -> Result<RestartDeviceOutput, RpcError>"
     );
-    println!("✓ operations::configure_interface(&client, input) -> Result<ConfigureInterfaceOutput, RpcError>");
+    println!("✓ operations::configure_interface(&client, &input) -> Result<ConfigureInterfaceOutput, RpcError>");
 
     println!("\nGenerated Input/Output Types:");
     println!("-----------------------------");
@@ -60,7 +60,7 @@ fn main() {
     println!();
     println!("4. With input parameters:");
     println!("   let input = operations::RestartDeviceInput {{ delay_seconds: Some(10) }};");
-    println!("   let output = operations::restart_device(&client, input).await?;");
+    println!("   let output = operations::restart_device(&client, &input).await?;");
 
     println!("\nTransport Adapters:");
     println!("-------------------");
@@ -95,6 +95,26 @@ fn user_friendly_message(error: &RpcError) -> String {
             format!("Configuration error: {}", msg)
         }
         RpcError::NotImplemented => "This operation is not implemented".to_string(),
+        RpcError::DeadlineExceeded { budget, .. } => {
+            format!("The operation did not finish within {:?}", budget)
+        }
+        RpcError::StreamStalled { idle, .. } => {
+            format!("The notification stream has been silent for {:?}", idle)
+        }
+        RpcError::RollbackFailed { .. } => {
+            format!(
+                "Changes failed and were only partially rolled back: {}",
+                error
+            )
+        }
+        RpcError::StepFailed { step, error } => {
+            format!("Step '{}' failed: {}", step, user_friendly_message(error))
+        }
+        RpcError::BatchFailed { written, error } => format!(
+            "Stopped after writing {} entries: {}",
+            written,
+            user_friendly_message(error)
+        ),
     }
 }
 
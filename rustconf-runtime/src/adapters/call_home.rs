@@ -0,0 +1,256 @@
+//! RESTCONF call-home (RFC 8071) over the hyper adapter.
+//!
+//! With call-home the device opens the TCP connection to the client, after
+//! which the client starts TLS and sends requests as usual. A
+//! [`CallHomeListener`] accepts these connections and completes the TLS
+//! handshake; each accepted [`CallHomeTransport`] sends requests over its one
+//! connection, so a `RestconfClient` built on it runs the same generated
+//! operations as a client that dialled the device itself.
+//!
+//! ```no_run
+//! use rustconf_runtime::call_home::CallHomeListener;
+//! use rustconf_runtime::{RestconfClient, TlsConfig};
+//!
+//! # async fn example() -> Result<(), rustconf_runtime::RpcError> {
+//! let tls = TlsConfig::new()
+//!     .with_root_certificates_pem(&std::fs::read("fleet-ca.pem").unwrap())?
+//!     .without_built_in_roots();
+//! let listener = CallHomeListener::bind("0.0.0.0:4336", &tls).await?;
+//! loop {
+//!     let transport = listener.accept().await?;
+//!     let base_url = transport.base_url();
+//!     let client = RestconfClient::new(base_url, transport)?;
+//!     // Use the client with generated operations...
+//! }
+//! # }
+//! ```
+
+use std::fmt;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::Poll;
+
+use async_trait::async_trait;
+use hyper::client::conn::{self, SendRequest};
+use hyper::Body;
+use tokio::net::{TcpListener, ToSocketAddrs};
+
+use super::hyper_adapter::{native_tls_connector, read_response, to_hyper_request};
+use crate::tls::TlsConfig;
+use crate::{HttpRequest, HttpResponse, HttpTransport, RpcError};
+
+/// The IANA-assigned port devices call home to for RESTCONF (RFC 8071).
+pub const RESTCONF_CALL_HOME_PORT: u16 = 4336;
+
+/// Accepts call-home connections from devices.
+///
+/// The listener is the TLS client: it verifies the device's certificate
+/// against the configured roots and pins and presents the configured client
+/// certificate. Because the device's host name is not known before it
+/// connects, the certificate is matched against the name given to
+/// [`with_server_name`](Self::with_server_name), or, without one, only its
+/// chain and pins are checked.
+pub struct CallHomeListener {
+    listener: TcpListener,
+    connector: tokio_native_tls::TlsConnector,
+    tls: Arc<TlsConfig>,
+    server_name: Option<String>,
+}
+
+impl CallHomeListener {
+    /// Listen for call-home connections on `address`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RpcError::ConfigurationError` if a certificate or key cannot
+    /// be loaded, and `RpcError::TransportError` if the address cannot be
+    /// bound.
+    pub async fn bind(address: impl ToSocketAddrs, tls: &TlsConfig) -> Result<Self, RpcError> {
+        let connector = native_tls_connector(tls, true)?;
+        let listener = TcpListener::bind(address)
+            .await
+            .map_err(|e| RpcError::TransportError(format!("call-home bind failed: {}", e)))?;
+        Ok(Self {
+            listener,
+            connector: connector.into(),
+            tls: Arc::new(tls.clone()),
+            server_name: None,
+        })
+    }
+
+    /// Require device certificates to be issued for `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RpcError::ConfigurationError` if the TLS settings can no
+    /// longer be loaded.
+    pub fn with_server_name(mut self, name: impl Into<String>) -> Result<Self, RpcError> {
+        self.connector = native_tls_connector(&self.tls, false)?.into();
+        self.server_name = Some(name.into());
+        Ok(self)
+    }
+
+    /// The address the listener is bound to.
+    pub fn local_addr(&self) -> Result<SocketAddr, RpcError> {
+        self.listener
+            .local_addr()
+            .map_err(|e| RpcError::TransportError(e.to_string()))
+    }
+
+    /// Wait for the next device to call home and complete the TLS handshake.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RpcError::TransportError` if accepting the connection, the
+    /// TLS handshake or public key pinning fails. The listener keeps
+    /// listening, so callers typically log the error and accept again.
+    pub async fn accept(&self) -> Result<CallHomeTransport, RpcError> {
+        let transport_error = |e: &dyn fmt::Display| RpcError::TransportError(e.to_string());
+
+        let (stream, peer) = self
+            .listener
+            .accept()
+            .await
+            .map_err(|e| transport_error(&e))?;
+        let domain = self
+            .server_name
+            .clone()
+            .unwrap_or_else(|| peer.ip().to_string());
+        let stream = self
+            .connector
+            .connect(&domain, stream)
+            .await
+            .map_err(|e| transport_error(&e))?;
+
+        let certificate = stream
+            .get_ref()
+            .peer_certificate()
+            .map_err(|e| transport_error(&e))?;
+        if !self.tls.spki_pins().is_empty() {
+            let certificate = certificate.ok_or_else(|| {
+                RpcError::TransportError("device presented no certificate".to_string())
+            })?;
+            let der = certificate.to_der().map_err(|e| transport_error(&e))?;
+            self.tls.verify_spki_pin(&der)?;
+        }
+
+        let (sender, connection) = conn::handshake(stream)
+            .await
+            .map_err(|e| transport_error(&e))?;
+        // The connection ends when the device hangs up or every transport
+        // clone is dropped
+        let closed = Arc::new(AtomicBool::new(false));
+        let connection_closed = closed.clone();
+        tokio::spawn(async move {
+            let _ = connection.await;
+            connection_closed.store(true, Ordering::Release);
+        });
+
+        Ok(CallHomeTransport {
+            peer,
+            authority: domain_authority(self.server_name.as_deref(), peer),
+            sender: Arc::new(Mutex::new(sender)),
+            closed,
+        })
+    }
+}
+
+impl fmt::Debug for CallHomeListener {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CallHomeListener")
+            .field("local_addr", &self.listener.local_addr().ok())
+            .field("server_name", &self.server_name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The `Host` used for requests to a device.
+fn domain_authority(server_name: Option<&str>, peer: SocketAddr) -> String {
+    match (server_name, peer) {
+        (Some(name), _) => name.to_string(),
+        (None, SocketAddr::V4(peer)) => peer.ip().to_string(),
+        (None, SocketAddr::V6(peer)) => format!("[{}]", peer.ip()),
+    }
+}
+
+/// Sends requests over a connection a device opened to a [`CallHomeListener`].
+///
+/// Clones share the connection. Requests fail with
+/// `RpcError::TransportError` once the device closes it; the device is
+/// expected to call home again.
+#[derive(Clone)]
+pub struct CallHomeTransport {
+    peer: SocketAddr,
+    authority: String,
+    sender: Arc<Mutex<SendRequest<Body>>>,
+    closed: Arc<AtomicBool>,
+}
+
+impl CallHomeTransport {
+    /// The address the device connected from.
+    pub fn peer_addr(&self) -> SocketAddr {
+        self.peer
+    }
+
+    /// A base URL for a `RestconfClient` using this transport.
+    ///
+    /// Only the path and query of request URLs are sent over the
+    /// connection; the authority becomes the `Host` header.
+    pub fn base_url(&self) -> String {
+        format!("https://{}", self.authority)
+    }
+
+    /// Whether the device has closed the connection.
+    pub fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Acquire)
+    }
+}
+
+impl fmt::Debug for CallHomeTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CallHomeTransport")
+            .field("peer", &self.peer)
+            .field("closed", &self.is_closed())
+            .finish()
+    }
+}
+
+#[async_trait]
+impl HttpTransport for CallHomeTransport {
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, RpcError> {
+        let hyper_request = to_hyper_request(&request, true)?;
+
+        let exchange =
+            async {
+                // Wait for the connection to accept another request, then queue
+                // this one without holding the lock across an await
+                let mut hyper_request = Some(hyper_request);
+                let response =
+                    std::future::poll_fn(|cx| {
+                        let mut sender = self.sender.lock().map_err(|_| {
+                            RpcError::TransportError("connection poisoned".to_string())
+                        })?;
+                        match sender.poll_ready(cx) {
+                            Poll::Ready(Ok(())) => Poll::Ready(Ok(sender
+                                .send_request(hyper_request.take().expect("polled after ready")))),
+                            Poll::Ready(Err(e)) => Poll::Ready(Err(RpcError::TransportError(
+                                format!("device connection closed: {}", e),
+                            ))),
+                            Poll::Pending => Poll::Pending,
+                        }
+                    })
+                    .await?
+                    .await
+                    .map_err(|e| RpcError::TransportError(e.to_string()))?;
+                read_response(response).await
+            };
+
+        match request.context.deadline() {
+            Some(deadline) => tokio::time::timeout(deadline.check()?, exchange)
+                .await
+                .map_err(|_| deadline.exceeded())?,
+            None => exchange.await,
+        }
+    }
+}
@@ -1,10 +1,23 @@
 //! Hyper-based HTTP transport adapter.
 
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use crate::connect::{ConnectConfig, Resolver};
+use crate::proxy::ProxyConfig;
+use crate::tls::{ClientIdentity, TlsConfig};
+use crate::upload::StreamingBody;
 use crate::{HttpMethod, HttpRequest, HttpResponse, HttpTransport, RpcError};
 use async_trait::async_trait;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine as _;
 use hyper::client::{Client, HttpConnector};
+use hyper::service::Service;
 use hyper::{Body, Request, Uri};
-use hyper_tls::HttpsConnector;
+use hyper_tls::{HttpsConnector, MaybeHttpsStream};
+use tokio_native_tls::native_tls;
 
 /// HTTP transport implementation using hyper.
 ///
@@ -12,7 +25,19 @@ use hyper_tls::HttpsConnector;
 /// which is a low-level, fast HTTP implementation.
 #[derive(Clone)]
 pub struct HyperTransport {
-    client: Client<HttpsConnector<HttpConnector>>,
+    client: HyperClient,
+}
+
+/// The hyper client, with or without public key pinning, through a proxy, or
+/// connecting to a unix domain socket.
+#[derive(Clone)]
+enum HyperClient {
+    Plain(Client<HttpsConnector<HttpConnector>>),
+    Resolved(Client<HttpsConnector<HttpConnector<Resolver>>>),
+    Pinned(Client<PinnedConnector>),
+    Proxied(Client<proxy::ProxyConnector>, Arc<ProxyConfig>),
+    #[cfg(all(feature = "uds", unix))]
+    Unix(Client<uds::UnixConnector>),
 }
 
 impl HyperTransport {
@@ -20,12 +45,519 @@ impl HyperTransport {
     pub fn new() -> Self {
         let https = HttpsConnector::new();
         let client = Client::builder().build::<_, Body>(https);
-        Self { client }
+        Self {
+            client: HyperClient::Plain(client),
+        }
     }
 
     /// Create a new hyper transport with a custom client.
     pub fn with_client(client: Client<HttpsConnector<HttpConnector>>) -> Self {
-        Self { client }
+        Self {
+            client: HyperClient::Plain(client),
+        }
+    }
+
+    /// Create a hyper transport with client certificates, trusted roots and
+    /// public key pins from a [`TlsConfig`].
+    ///
+    /// Pins are checked right after the TLS handshake, so no request is sent
+    /// to a server whose public key is not pinned.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RpcError::ConfigurationError` if a certificate or key cannot
+    /// be loaded.
+    pub fn with_tls(config: &TlsConfig) -> Result<Self, RpcError> {
+        Self::with_tls_and_connect(config, &ConnectConfig::new())
+    }
+
+    /// Create a hyper transport resolving hosts and connecting with a
+    /// [`ConnectConfig`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{Read, Write};
+    /// use std::net::{IpAddr, Ipv4Addr, TcpListener};
+    /// use std::time::Duration;
+    ///
+    /// use rustconf_runtime::hyper_adapter::HyperTransport;
+    /// use rustconf_runtime::{ConnectConfig, HttpMethod, RestconfClient};
+    ///
+    /// // A device known only by its inventory name
+    /// let listener = TcpListener::bind("127.0.0.1:0")?;
+    /// let port = listener.local_addr()?.port();
+    /// std::thread::spawn(move || {
+    ///     let (mut stream, _) = listener.accept().unwrap();
+    ///     let _ = stream.read(&mut [0; 1024]);
+    ///     stream.write_all(b"HTTP/1.1 204 No Content\r\ncontent-length: 0\r\n\r\n").unwrap();
+    /// });
+    ///
+    /// let connect = ConnectConfig::new()
+    ///     .with_host("core-1.lab", [IpAddr::V4(Ipv4Addr::LOCALHOST)])
+    ///     .with_prefer_ipv4()
+    ///     .with_connect_timeout(Duration::from_secs(2));
+    /// let transport = HyperTransport::with_connect(&connect)?;
+    /// let client = RestconfClient::new(&format!("http://core-1.lab:{}", port), transport)?;
+    ///
+    /// let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build()?;
+    /// let response = runtime.block_on(client.raw_request(HttpMethod::DELETE, "/restconf/data/x", None))?;
+    /// assert_eq!(response.status_code, 204);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `RpcError::ConfigurationError` if the platform's TLS
+    /// configuration cannot be loaded.
+    pub fn with_connect(config: &ConnectConfig) -> Result<Self, RpcError> {
+        Self::with_tls_and_connect(&TlsConfig::new(), config)
+    }
+
+    /// Create a hyper transport with both TLS settings and connection
+    /// settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RpcError::ConfigurationError` if a certificate or key cannot
+    /// be loaded.
+    pub fn with_tls_and_connect(
+        tls: &TlsConfig,
+        connect: &ConnectConfig,
+    ) -> Result<Self, RpcError> {
+        let https = HttpsConnector::from((
+            http_connector(connect),
+            native_tls_connector(tls, false)?.into(),
+        ));
+
+        let client = if tls.spki_pins().is_empty() {
+            HyperClient::Resolved(Client::builder().build::<_, Body>(https))
+        } else {
+            let connector = PinnedConnector {
+                https,
+                tls: Arc::new(tls.clone()),
+            };
+            HyperClient::Pinned(Client::builder().build::<_, Body>(connector))
+        };
+        Ok(Self { client })
+    }
+
+    /// Create a hyper transport sending requests through a proxy, except to
+    /// hosts matching its no-proxy rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RpcError::ConfigurationError` if the platform's TLS
+    /// configuration cannot be loaded.
+    pub fn with_proxy(config: &ProxyConfig) -> Result<Self, RpcError> {
+        Self::with_tls_and_proxy(&TlsConfig::new(), config)
+    }
+
+    /// Create a hyper transport with both TLS settings and a proxy.
+    ///
+    /// `https` requests are tunneled with `CONNECT` and the TLS handshake,
+    /// including client certificates and public key pins, is made with the
+    /// device itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RpcError::ConfigurationError` if a certificate or key cannot
+    /// be loaded.
+    pub fn with_tls_and_proxy(tls: &TlsConfig, config: &ProxyConfig) -> Result<Self, RpcError> {
+        let proxy = Arc::new(config.clone());
+        let connector = proxy::ProxyConnector {
+            http: http_connector(&ConnectConfig::new()),
+            tls: native_tls_connector(tls, false)?.into(),
+            proxy: proxy.clone(),
+            pins: (!tls.spki_pins().is_empty()).then(|| Arc::new(tls.clone())),
+        };
+        Ok(Self {
+            client: HyperClient::Proxied(Client::builder().build::<_, Body>(connector), proxy),
+        })
+    }
+
+    /// Create a hyper transport sending every request over the unix domain
+    /// socket at `path`, for agents running on the device itself.
+    ///
+    /// The socket is connected regardless of the request URL, whose
+    /// authority is only sent as the `Host` header, so clients are usually
+    /// created with a base URL such as `http://localhost`. Requires the
+    /// `uds` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustconf_runtime::hyper_adapter::HyperTransport;
+    /// use rustconf_runtime::RestconfClient;
+    ///
+    /// let transport = HyperTransport::with_unix_socket("/var/run/restconf.sock");
+    /// let client = RestconfClient::new("http://localhost", transport)?;
+    /// # Ok::<(), rustconf_runtime::RpcError>(())
+    /// ```
+    #[cfg(all(feature = "uds", unix))]
+    pub fn with_unix_socket(path: impl Into<std::path::PathBuf>) -> Self {
+        let connector = uds::UnixConnector {
+            path: Arc::new(path.into()),
+        };
+        Self {
+            client: HyperClient::Unix(Client::builder().build::<_, Body>(connector)),
+        }
+    }
+}
+
+/// A TCP connector resolving hosts and connecting with a [`ConnectConfig`],
+/// for wrapping in TLS.
+fn http_connector(config: &ConnectConfig) -> HttpConnector<Resolver> {
+    let mut http = HttpConnector::new_with_resolver(Resolver {
+        config: Arc::new(config.clone()),
+    });
+    http.enforce_http(false);
+    http.set_connect_timeout(config.connect_timeout());
+    http
+}
+
+impl Service<hyper::client::connect::dns::Name> for Resolver {
+    type Response = std::vec::IntoIter<std::net::SocketAddr>;
+    type Error = std::io::Error;
+    type Future = Pin<Box<dyn Future<Output = std::io::Result<Self::Response>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, name: hyper::client::connect::dns::Name) -> Self::Future {
+        let resolver = self.clone();
+        Box::pin(async move { Ok(resolver.resolve(name.as_str()).await?.into_iter()) })
+    }
+}
+
+/// Translate a [`TlsConfig`] into a native-tls connector.
+///
+/// `any_host_name` skips matching the certificate against the host name,
+/// leaving the chain and pins to identify the server.
+pub(crate) fn native_tls_connector(
+    config: &TlsConfig,
+    any_host_name: bool,
+) -> Result<native_tls::TlsConnector, RpcError> {
+    let invalid = |e: native_tls::Error| RpcError::ConfigurationError(format!("TLS: {}", e));
+
+    let mut builder = native_tls::TlsConnector::builder();
+    if let Some(identity) = config.identity() {
+        let identity = match identity {
+            ClientIdentity::Pem {
+                certificate_chain,
+                private_key,
+            } => native_tls::Identity::from_pkcs8(certificate_chain, private_key.expose_secret()),
+            ClientIdentity::Pkcs12 { der, password } => {
+                native_tls::Identity::from_pkcs12(der, password.expose_secret())
+            }
+        }
+        .map_err(invalid)?;
+        builder.identity(identity);
+    }
+    for certificate in config.root_certificates() {
+        builder
+            .add_root_certificate(native_tls::Certificate::from_pem(certificate).map_err(invalid)?);
+    }
+    builder.disable_built_in_roots(!config.uses_built_in_roots());
+    builder.danger_accept_invalid_hostnames(any_host_name);
+    builder.build().map_err(invalid)
+}
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// HTTPS connector that rejects servers whose public key is not pinned.
+#[derive(Clone)]
+struct PinnedConnector {
+    https: HttpsConnector<HttpConnector<Resolver>>,
+    tls: Arc<TlsConfig>,
+}
+
+impl Service<Uri> for PinnedConnector {
+    type Response = MaybeHttpsStream<<HttpConnector<Resolver> as Service<Uri>>::Response>;
+    type Error = BoxError;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, BoxError>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.https.poll_ready(cx)
+    }
+
+    fn call(&mut self, uri: Uri) -> Self::Future {
+        let connecting = self.https.call(uri);
+        let tls = self.tls.clone();
+        Box::pin(async move {
+            let stream = connecting.await?;
+            verify_spki_pin(&stream, &tls)?;
+            Ok(stream)
+        })
+    }
+}
+
+/// Reject a connection whose server public key is not pinned.
+fn verify_spki_pin<T>(stream: &MaybeHttpsStream<T>, tls: &TlsConfig) -> Result<(), BoxError>
+where
+    T: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+{
+    let MaybeHttpsStream::Https(tls_stream) = stream else {
+        return Err("public key pinning requires an https connection".into());
+    };
+    let certificate = tls_stream
+        .get_ref()
+        .peer_certificate()?
+        .ok_or("server presented no certificate")?;
+    tls.verify_spki_pin(&certificate.to_der()?)?;
+    Ok(())
+}
+
+/// Connections through an HTTP proxy.
+mod proxy {
+    use std::future::Future;
+    use std::io;
+    use std::pin::Pin;
+    use std::sync::Arc;
+    use std::task::{Context, Poll};
+
+    use hyper::client::connect::{Connected, Connection};
+    use hyper::client::HttpConnector;
+    use hyper::service::Service;
+    use hyper::Uri;
+    use hyper_tls::MaybeHttpsStream;
+    use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, ReadBuf};
+    use tokio::net::TcpStream;
+
+    use super::{verify_spki_pin, BoxError};
+    use crate::connect::Resolver;
+    use crate::proxy::ProxyConfig;
+    use crate::tls::TlsConfig;
+
+    /// Largest response to `CONNECT` read before giving up.
+    const MAX_CONNECT_RESPONSE: usize = 16 * 1024;
+
+    /// Connector tunneling `https` through the proxy, connecting to the
+    /// proxy for `http`, and connecting directly to bypassed hosts.
+    #[derive(Clone)]
+    pub(super) struct ProxyConnector {
+        pub(super) http: HttpConnector<Resolver>,
+        pub(super) tls: tokio_native_tls::TlsConnector,
+        pub(super) proxy: Arc<ProxyConfig>,
+        pub(super) pins: Option<Arc<TlsConfig>>,
+    }
+
+    impl Service<Uri> for ProxyConnector {
+        type Response = ProxyConnection;
+        type Error = BoxError;
+        type Future = Pin<Box<dyn Future<Output = Result<ProxyConnection, BoxError>> + Send>>;
+
+        fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), BoxError>> {
+            self.http.poll_ready(cx).map_err(Into::into)
+        }
+
+        fn call(&mut self, uri: Uri) -> Self::Future {
+            let mut http = self.http.clone();
+            let tls = self.tls.clone();
+            let proxy = self.proxy.clone();
+            let pins = self.pins.clone();
+            Box::pin(async move {
+                let https = uri.scheme_str() == Some("https");
+                let host = uri.host().ok_or("request URL has no host")?.to_string();
+                let bypass = proxy.bypasses(&uri.to_string());
+
+                let (stream, proxied) = match (bypass, https) {
+                    (true, _) => (http.call(uri).await?, false),
+                    (false, false) => (http.call(proxy.url().as_str().parse()?).await?, true),
+                    (false, true) => {
+                        let mut stream = http.call(proxy.url().as_str().parse()?).await?;
+                        let port = uri.port_u16().unwrap_or(443);
+                        tunnel(&mut stream, &format!("{}:{}", host, port), &proxy).await?;
+                        (stream, false)
+                    }
+                };
+
+                let stream = if https {
+                    // Certificates name IPv6 addresses without brackets
+                    let domain = host.trim_start_matches('[').trim_end_matches(']');
+                    MaybeHttpsStream::Https(tls.connect(domain, stream).await?)
+                } else {
+                    MaybeHttpsStream::Http(stream)
+                };
+                if let Some(pins) = &pins {
+                    verify_spki_pin(&stream, pins)?;
+                }
+                Ok(ProxyConnection { stream, proxied })
+            })
+        }
+    }
+
+    /// Ask the proxy for a tunnel to `authority` and wait for it to be open.
+    async fn tunnel(
+        stream: &mut TcpStream,
+        authority: &str,
+        proxy: &ProxyConfig,
+    ) -> Result<(), BoxError> {
+        let mut request = format!("CONNECT {0} HTTP/1.1\r\nHost: {0}\r\n", authority);
+        if let Some(authorization) = proxy.proxy_authorization() {
+            request.push_str("Proxy-Authorization: ");
+            request.push_str(authorization.expose_secret());
+            request.push_str("\r\n");
+        }
+        request.push_str("\r\n");
+        stream.write_all(request.as_bytes()).await?;
+
+        // The device only speaks once the TLS handshake starts, so nothing
+        // follows the proxy's response head
+        let mut head = Vec::new();
+        let mut buffer = [0u8; 1024];
+        while !head.windows(4).any(|window| window == b"\r\n\r\n") {
+            if head.len() > MAX_CONNECT_RESPONSE {
+                return Err("proxy response to CONNECT is too large".into());
+            }
+            let read = stream.read(&mut buffer).await?;
+            if read == 0 {
+                return Err("proxy closed the connection during CONNECT".into());
+            }
+            head.extend_from_slice(&buffer[..read]);
+        }
+
+        let head = String::from_utf8_lossy(&head);
+        let status_line = head.lines().next().unwrap_or_default();
+        match status_line.split_whitespace().nth(1) {
+            Some(status) if status.starts_with('2') => Ok(()),
+            Some("407") => Err(format!(
+                "proxy {} requires authentication: {}",
+                proxy.url(),
+                status_line
+            )
+            .into()),
+            _ => Err(format!(
+                "proxy {} refused to connect to {}: {}",
+                proxy.url(),
+                authority,
+                status_line
+            )
+            .into()),
+        }
+    }
+
+    /// A connection through the proxy, or directly to a bypassed host.
+    pub(super) struct ProxyConnection {
+        stream: MaybeHttpsStream<TcpStream>,
+        /// Whether requests are forwarded by the proxy, and must therefore
+        /// carry the absolute URL.
+        proxied: bool,
+    }
+
+    impl Connection for ProxyConnection {
+        fn connected(&self) -> Connected {
+            self.stream.connected().proxy(self.proxied)
+        }
+    }
+
+    impl AsyncRead for ProxyConnection {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<io::Result<()>> {
+            Pin::new(&mut self.stream).poll_read(cx, buf)
+        }
+    }
+
+    impl AsyncWrite for ProxyConnection {
+        fn poll_write(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            Pin::new(&mut self.stream).poll_write(cx, buf)
+        }
+
+        fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Pin::new(&mut self.stream).poll_flush(cx)
+        }
+
+        fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Pin::new(&mut self.stream).poll_shutdown(cx)
+        }
+    }
+}
+
+/// Connections to a unix domain socket.
+#[cfg(all(feature = "uds", unix))]
+mod uds {
+    use std::future::Future;
+    use std::io;
+    use std::path::PathBuf;
+    use std::pin::Pin;
+    use std::sync::Arc;
+    use std::task::{Context, Poll};
+
+    use hyper::client::connect::{Connected, Connection};
+    use hyper::service::Service;
+    use hyper::Uri;
+    use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+    use tokio::net::UnixStream;
+
+    /// Connector ignoring the request URI and connecting to a socket path.
+    #[derive(Clone)]
+    pub(super) struct UnixConnector {
+        pub(super) path: Arc<PathBuf>,
+    }
+
+    impl Service<Uri> for UnixConnector {
+        type Response = UnixConnection;
+        type Error = io::Error;
+        type Future = Pin<Box<dyn Future<Output = io::Result<UnixConnection>> + Send>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _uri: Uri) -> Self::Future {
+            let path = self.path.clone();
+            Box::pin(async move {
+                UnixStream::connect(path.as_path())
+                    .await
+                    .map(UnixConnection)
+                    .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))
+            })
+        }
+    }
+
+    /// A unix domain socket stream hyper can use as a connection.
+    pub(super) struct UnixConnection(UnixStream);
+
+    impl Connection for UnixConnection {
+        fn connected(&self) -> Connected {
+            Connected::new()
+        }
+    }
+
+    impl AsyncRead for UnixConnection {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<io::Result<()>> {
+            Pin::new(&mut self.0).poll_read(cx, buf)
+        }
+    }
+
+    impl AsyncWrite for UnixConnection {
+        fn poll_write(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            Pin::new(&mut self.0).poll_write(cx, buf)
+        }
+
+        fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Pin::new(&mut self.0).poll_flush(cx)
+        }
+
+        fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Pin::new(&mut self.0).poll_shutdown(cx)
+        }
     }
 }
 
@@ -38,69 +570,224 @@ impl Default for HyperTransport {
 #[async_trait]
 impl HttpTransport for HyperTransport {
     async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, RpcError> {
-        // Parse URI
-        let uri: Uri = request
-            .url
-            .parse()
-            .map_err(|e| RpcError::TransportError(format!("Invalid URL: {}", e)))?;
+        let hyper_request = to_hyper_request(&request, false)?;
 
-        // Convert HttpMethod to hyper::Method
-        let method = match request.method {
-            HttpMethod::GET => hyper::Method::GET,
-            HttpMethod::POST => hyper::Method::POST,
-            HttpMethod::PUT => hyper::Method::PUT,
-            HttpMethod::PATCH => hyper::Method::PATCH,
-            HttpMethod::DELETE => hyper::Method::DELETE,
-            HttpMethod::OPTIONS => hyper::Method::OPTIONS,
-            HttpMethod::HEAD => hyper::Method::HEAD,
-        };
+        let exchange = self.exchange(hyper_request);
 
-        // Build hyper request
-        let mut req_builder = Request::builder().method(method).uri(uri);
+        // Give the request, including connecting and reading the body, only
+        // the time left
+        match request.context.deadline() {
+            Some(deadline) => tokio::time::timeout(deadline.check()?, exchange)
+                .await
+                .map_err(|_| deadline.exceeded())?,
+            None => exchange.await,
+        }
+    }
 
-        // Add headers
-        for (name, value) in &request.headers {
-            req_builder = req_builder.header(name, value);
+    async fn execute_streaming(
+        &self,
+        request: HttpRequest,
+        mut body: StreamingBody,
+    ) -> Result<HttpResponse, RpcError> {
+        let mut hyper_request = to_hyper_request(&request, false)?;
+        // Without a length the body is sent with chunked transfer encoding
+        if let Some(length) = body.content_length() {
+            hyper_request
+                .headers_mut()
+                .insert(hyper::header::CONTENT_LENGTH, length.into());
         }
+        let (mut sender, hyper_body) = Body::channel();
+        *hyper_request.body_mut() = hyper_body;
 
-        // Add body
-        let body = if let Some(body_bytes) = request.body {
-            Body::from(body_bytes)
-        } else {
-            Body::empty()
+        // Write chunks as they are read; a failing source aborts the request
+        let feed = async move {
+            loop {
+                let chunk = match body.next_chunk().await {
+                    Ok(Some(chunk)) => chunk,
+                    Ok(None) => return Ok(()),
+                    Err(error) => {
+                        sender.abort();
+                        return Err(error);
+                    }
+                };
+                if sender.send_data(chunk).await.is_err() {
+                    // The connection is gone; the exchange reports why
+                    return Ok(());
+                }
+            }
         };
+        let exchange = exchange_while_feeding(self.exchange(hyper_request), feed);
 
-        let hyper_request = req_builder
-            .body(body)
-            .map_err(|e| RpcError::TransportError(format!("Failed to build request: {}", e)))?;
+        match request.context.deadline() {
+            Some(deadline) => tokio::time::timeout(deadline.check()?, exchange)
+                .await
+                .map_err(|_| deadline.exceeded())?,
+            None => exchange.await,
+        }
+    }
+}
 
+/// Drive a request and the writing of its body together, returning the
+/// response, or the error that stopped the body from being written.
+///
+/// The server may answer before the whole body is written, e.g. rejecting an
+/// upload that is too large, so the response is returned as soon as it
+/// arrives.
+async fn exchange_while_feeding(
+    exchange: impl Future<Output = Result<HttpResponse, RpcError>>,
+    feed: impl Future<Output = Result<(), RpcError>>,
+) -> Result<HttpResponse, RpcError> {
+    let mut exchange = std::pin::pin!(exchange);
+    let mut feed = std::pin::pin!(feed);
+    let mut fed = None;
+    std::future::poll_fn(|cx| {
+        if fed.is_none() {
+            if let Poll::Ready(result) = feed.as_mut().poll(cx) {
+                fed = Some(result);
+            }
+        }
+        match (exchange.as_mut().poll(cx), fed.take()) {
+            (Poll::Ready(_), Some(Err(error))) => Poll::Ready(Err(error)),
+            (Poll::Ready(result), _) => Poll::Ready(result),
+            (Poll::Pending, fed_now) => {
+                fed = fed_now;
+                Poll::Pending
+            }
+        }
+    })
+    .await
+}
+
+impl HyperTransport {
+    /// Send a request and read the whole response.
+    async fn exchange(&self, mut hyper_request: Request<Body>) -> Result<HttpResponse, RpcError> {
         // Execute request
-        let response = self
-            .client
-            .request(hyper_request)
-            .await
-            .map_err(|e| RpcError::TransportError(e.to_string()))?;
-
-        // Extract status code
-        let status_code = response.status().as_u16();
-
-        // Extract headers
-        let headers: Vec<(String, String)> = response
-            .headers()
+        let response = match &self.client {
+            HyperClient::Plain(client) => client.request(hyper_request),
+            HyperClient::Resolved(client) => client.request(hyper_request),
+            HyperClient::Pinned(client) => client.request(hyper_request),
+            HyperClient::Proxied(client, proxy) => {
+                // Requests the proxy forwards carry its credentials; tunneled
+                // requests sent them with CONNECT
+                let forwarded = hyper_request.uri().scheme_str() == Some("http")
+                    && !proxy.bypasses(&hyper_request.uri().to_string());
+                if let (true, Some(authorization)) = (forwarded, proxy.proxy_authorization()) {
+                    let value = authorization.expose_secret().parse().map_err(|_| {
+                        RpcError::ConfigurationError("proxy: invalid credentials".to_string())
+                    })?;
+                    hyper_request
+                        .headers_mut()
+                        .insert("Proxy-Authorization", value);
+                }
+                client.request(hyper_request)
+            }
+            #[cfg(all(feature = "uds", unix))]
+            HyperClient::Unix(client) => client.request(hyper_request),
+        }
+        .await
+        .map_err(|e| RpcError::TransportError(e.to_string()))?;
+
+        read_response(response).await
+    }
+}
+
+/// Convert a transport request into a hyper request.
+///
+/// With `origin_form`, the request target is only the path and query and the
+/// authority is sent in the `Host` header, as required when writing directly
+/// to an established connection.
+pub(crate) fn to_hyper_request(
+    request: &HttpRequest,
+    origin_form: bool,
+) -> Result<Request<Body>, RpcError> {
+    // Hyper ignores user information in the URI, so send it as Basic
+    // authentication like reqwest does
+    let (url, userinfo) = crate::base_url::take_userinfo(&request.url);
+
+    // Parse URI
+    let mut uri: Uri = url
+        .parse()
+        .map_err(|e| RpcError::TransportError(format!("Invalid URL: {}", e)))?;
+
+    // Convert HttpMethod to hyper::Method
+    let method = match request.method {
+        HttpMethod::GET => hyper::Method::GET,
+        HttpMethod::POST => hyper::Method::POST,
+        HttpMethod::PUT => hyper::Method::PUT,
+        HttpMethod::PATCH => hyper::Method::PATCH,
+        HttpMethod::DELETE => hyper::Method::DELETE,
+        HttpMethod::OPTIONS => hyper::Method::OPTIONS,
+        HttpMethod::HEAD => hyper::Method::HEAD,
+    };
+
+    let host = uri.authority().map(|authority| authority.to_string());
+    if origin_form {
+        uri = uri
+            .path_and_query()
+            .map(|path| path.as_str())
+            .unwrap_or("/")
+            .parse()
+            .map_err(|e| RpcError::TransportError(format!("Invalid URL: {}", e)))?;
+    }
+
+    // Build hyper request
+    let mut req_builder = Request::builder().method(method).uri(uri);
+
+    // Add headers
+    for (name, value) in &request.headers {
+        req_builder = req_builder.header(name, value);
+    }
+    let has_header = |wanted: &str| {
+        request
+            .headers
             .iter()
-            .map(|(name, value)| (name.to_string(), value.to_str().unwrap_or("").to_string()))
-            .collect();
-
-        // Extract body
-        let body_bytes = hyper::body::to_bytes(response.into_body())
-            .await
-            .map_err(|e| RpcError::TransportError(e.to_string()))?
-            .to_vec();
-
-        Ok(HttpResponse {
-            status_code,
-            headers,
-            body: body_bytes,
-        })
+            .any(|(name, _)| name.eq_ignore_ascii_case(wanted))
+    };
+    if let (true, Some(host), false) = (origin_form, host, has_header("host")) {
+        req_builder = req_builder.header("Host", host);
+    }
+    if let (Some(userinfo), false) = (userinfo, has_header("authorization")) {
+        req_builder = req_builder.header(
+            "Authorization",
+            format!("Basic {}", BASE64.encode(userinfo)),
+        );
     }
+
+    // Add body
+    let body = if let Some(body_bytes) = &request.body {
+        Body::from(body_bytes.clone())
+    } else {
+        Body::empty()
+    };
+
+    req_builder
+        .body(body)
+        .map_err(|e| RpcError::TransportError(format!("Failed to build request: {}", e)))
+}
+
+/// Read the status, headers and whole body of a hyper response.
+pub(crate) async fn read_response(
+    response: hyper::Response<Body>,
+) -> Result<HttpResponse, RpcError> {
+    // Extract status code
+    let status_code = response.status().as_u16();
+
+    // Extract headers
+    let headers: Vec<(String, String)> = response
+        .headers()
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_str().unwrap_or("").to_string()))
+        .collect();
+
+    // Extract body
+    let body_bytes = hyper::body::to_bytes(response.into_body())
+        .await
+        .map_err(|e| RpcError::TransportError(e.to_string()))?
+        .to_vec();
+
+    Ok(HttpResponse {
+        status_code,
+        headers,
+        body: body_bytes,
+    })
 }
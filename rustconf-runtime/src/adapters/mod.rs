@@ -10,3 +10,6 @@ pub mod reqwest_adapter;
 
 #[cfg(feature = "hyper")]
 pub mod hyper_adapter;
+
+#[cfg(feature = "hyper")]
+pub mod call_home;
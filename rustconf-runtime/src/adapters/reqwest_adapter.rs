@@ -1,5 +1,10 @@
 //! Reqwest-based HTTP transport adapter.
 
+use std::sync::Arc;
+
+use crate::connect::{ConnectConfig, Resolver};
+use crate::proxy::ProxyConfig;
+use crate::tls::{ClientIdentity, TlsConfig};
 use crate::{HttpMethod, HttpRequest, HttpResponse, HttpTransport, RpcError};
 use async_trait::async_trait;
 
@@ -10,6 +15,8 @@ use async_trait::async_trait;
 #[derive(Debug, Clone)]
 pub struct ReqwestTransport {
     client: reqwest::Client,
+    /// Set when responses must come from a pinned public key.
+    pinning: Option<TlsConfig>,
 }
 
 impl ReqwestTransport {
@@ -17,15 +24,207 @@ impl ReqwestTransport {
     pub fn new() -> Self {
         Self {
             client: reqwest::Client::new(),
+            pinning: None,
         }
     }
 
     /// Create a new reqwest transport with a custom client.
     pub fn with_client(client: reqwest::Client) -> Self {
-        Self { client }
+        Self {
+            client,
+            pinning: None,
+        }
+    }
+
+    /// Create a reqwest transport with client certificates, trusted roots
+    /// and public key pins from a [`TlsConfig`].
+    ///
+    /// reqwest exposes the server certificate only with the response, so
+    /// pins are checked when the response arrives: a request to a server with
+    /// an unpinned key is sent, but its response is discarded and the call
+    /// fails. Use the hyper adapter if requests must not reach such servers.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RpcError::ConfigurationError` if a certificate or key cannot
+    /// be loaded.
+    pub fn with_tls(config: &TlsConfig) -> Result<Self, RpcError> {
+        Self::with_tls_builder(reqwest::Client::builder(), config)
+    }
+
+    /// Like [`ReqwestTransport::with_tls`], starting from a client builder
+    /// carrying other settings such as timeouts.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RpcError::ConfigurationError` if a certificate or key cannot
+    /// be loaded or the client cannot be built.
+    pub fn with_tls_builder(
+        mut builder: reqwest::ClientBuilder,
+        config: &TlsConfig,
+    ) -> Result<Self, RpcError> {
+        let invalid = |e: reqwest::Error| RpcError::ConfigurationError(format!("TLS: {}", e));
+
+        if let Some(identity) = config.identity() {
+            let identity = match identity {
+                ClientIdentity::Pem {
+                    certificate_chain,
+                    private_key,
+                } => reqwest::Identity::from_pkcs8_pem(
+                    certificate_chain,
+                    private_key.expose_secret(),
+                ),
+                ClientIdentity::Pkcs12 { der, password } => {
+                    reqwest::Identity::from_pkcs12_der(der, password.expose_secret())
+                }
+            }
+            .map_err(invalid)?;
+            builder = builder.identity(identity);
+        }
+        for certificate in config.root_certificates() {
+            builder = builder.add_root_certificate(
+                reqwest::Certificate::from_pem(certificate).map_err(invalid)?,
+            );
+        }
+        builder = builder.tls_built_in_root_certs(config.uses_built_in_roots());
+
+        let pinning = if config.spki_pins().is_empty() {
+            None
+        } else {
+            builder = builder.tls_info(true);
+            Some(config.clone())
+        };
+
+        Ok(Self {
+            client: builder.build().map_err(invalid)?,
+            pinning,
+        })
+    }
+
+    /// Create a reqwest transport sending requests through a proxy, except
+    /// to hosts matching its no-proxy rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RpcError::ConfigurationError` if the client cannot be built.
+    pub fn with_proxy(config: &ProxyConfig) -> Result<Self, RpcError> {
+        let client = reqwest::Client::builder()
+            .proxy(proxy(config)?)
+            .build()
+            .map_err(|e| RpcError::ConfigurationError(format!("proxy: {}", e)))?;
+        Ok(Self::with_client(client))
+    }
+
+    /// Create a reqwest transport with both TLS settings and a proxy.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RpcError::ConfigurationError` if a certificate or key cannot
+    /// be loaded or the client cannot be built.
+    pub fn with_tls_and_proxy(tls: &TlsConfig, config: &ProxyConfig) -> Result<Self, RpcError> {
+        Self::with_tls_builder(reqwest::Client::builder().proxy(proxy(config)?), tls)
+    }
+
+    /// Create a reqwest transport resolving hosts and connecting with a
+    /// [`ConnectConfig`].
+    ///
+    /// Combine it with TLS settings through [`connect`]:
+    /// `ReqwestTransport::with_tls_builder(connect(reqwest::Client::builder(), &config), &tls)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{Read, Write};
+    /// use std::net::{IpAddr, Ipv4Addr, TcpListener};
+    /// use std::time::Duration;
+    ///
+    /// use rustconf_runtime::reqwest_adapter::ReqwestTransport;
+    /// use rustconf_runtime::{ConnectConfig, HttpMethod, RestconfClient};
+    ///
+    /// // A device known only by its inventory name
+    /// let listener = TcpListener::bind("127.0.0.1:0")?;
+    /// let port = listener.local_addr()?.port();
+    /// std::thread::spawn(move || {
+    ///     let (mut stream, _) = listener.accept().unwrap();
+    ///     let _ = stream.read(&mut [0; 1024]);
+    ///     stream.write_all(b"HTTP/1.1 204 No Content\r\ncontent-length: 0\r\n\r\n").unwrap();
+    /// });
+    ///
+    /// let connect = ConnectConfig::new()
+    ///     .with_host("core-1.lab", [IpAddr::V4(Ipv4Addr::LOCALHOST)])
+    ///     .with_prefer_ipv4()
+    ///     .with_connect_timeout(Duration::from_secs(2));
+    /// let transport = ReqwestTransport::with_connect(&connect)?;
+    /// let client = RestconfClient::new(&format!("http://core-1.lab:{}", port), transport)?;
+    ///
+    /// let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build()?;
+    /// let response = runtime.block_on(client.raw_request(HttpMethod::DELETE, "/restconf/data/x", None))?;
+    /// assert_eq!(response.status_code, 204);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `RpcError::ConfigurationError` if the client cannot be built.
+    pub fn with_connect(config: &ConnectConfig) -> Result<Self, RpcError> {
+        let client = connect(reqwest::Client::builder(), config)
+            .build()
+            .map_err(|e| RpcError::ConfigurationError(format!("connect: {}", e)))?;
+        Ok(Self::with_client(client))
     }
 }
 
+/// Apply a [`ConnectConfig`] to a reqwest client builder carrying other
+/// settings.
+///
+/// The connect timeout is separate from any request timeout set on the
+/// builder.
+pub fn connect(
+    mut builder: reqwest::ClientBuilder,
+    config: &ConnectConfig,
+) -> reqwest::ClientBuilder {
+    if let Some(timeout) = config.connect_timeout() {
+        builder = builder.connect_timeout(timeout);
+    }
+    if config.changes_resolution() {
+        builder = builder.dns_resolver(Arc::new(Resolver {
+            config: Arc::new(config.clone()),
+        }));
+    }
+    builder
+}
+
+impl reqwest::dns::Resolve for Resolver {
+    fn resolve(&self, name: hyper::client::connect::dns::Name) -> reqwest::dns::Resolving {
+        let resolver = self.clone();
+        Box::pin(async move {
+            let addresses = resolver.resolve(name.as_str()).await?;
+            Ok(Box::new(addresses.into_iter()) as reqwest::dns::Addrs)
+        })
+    }
+}
+
+/// Translate a [`ProxyConfig`] into a reqwest proxy, for client builders
+/// carrying other settings.
+///
+/// A client given this proxy ignores the proxy environment variables.
+///
+/// # Errors
+///
+/// Returns `RpcError::ConfigurationError` if reqwest rejects the proxy URL.
+pub fn proxy(config: &ProxyConfig) -> Result<reqwest::Proxy, RpcError> {
+    let url = reqwest::Url::parse(config.url().as_str())
+        .map_err(|e| RpcError::ConfigurationError(format!("proxy: {}", e)))?;
+    let rules = config.clone();
+    let mut proxy = reqwest::Proxy::custom(move |target| {
+        (!rules.bypasses(target.as_str())).then(|| url.clone())
+    });
+    if let (Some(username), Some(password)) = (config.username(), config.password()) {
+        proxy = proxy.basic_auth(username, password.expose_secret());
+    }
+    Ok(proxy)
+}
+
 impl Default for ReqwestTransport {
     fn default() -> Self {
         Self::new()
@@ -46,8 +245,30 @@ impl HttpTransport for ReqwestTransport {
             HttpMethod::HEAD => reqwest::Method::HEAD,
         };
 
+        // reqwest sends user information in the URL as Basic authentication;
+        // drop it when an interceptor has already set credentials
+        let has_authorization = request
+            .headers
+            .iter()
+            .any(|(name, _)| name.eq_ignore_ascii_case("authorization"));
+        let url = if has_authorization {
+            crate::base_url::take_userinfo(&request.url).0
+        } else {
+            request.url.clone()
+        };
+
         // Build reqwest request
-        let mut req_builder = self.client.request(method, &request.url);
+        let mut req_builder = self.client.request(method, &url);
+
+        // Give the request, including redirects, only the time left
+        let deadline = request.context.deadline().copied();
+        if let Some(deadline) = &deadline {
+            req_builder = req_builder.timeout(deadline.check()?);
+        }
+        let transport_error = |e: reqwest::Error| match &deadline {
+            Some(deadline) if e.is_timeout() && deadline.is_expired() => deadline.exceeded(),
+            _ => RpcError::TransportError(e.to_string()),
+        };
 
         // Add headers
         for (name, value) in &request.headers {
@@ -60,10 +281,20 @@ impl HttpTransport for ReqwestTransport {
         }
 
         // Execute request
-        let response = req_builder
-            .send()
-            .await
-            .map_err(|e| RpcError::TransportError(e.to_string()))?;
+        let response = req_builder.send().await.map_err(transport_error)?;
+
+        if let Some(tls) = &self.pinning {
+            let certificate = response
+                .extensions()
+                .get::<reqwest::tls::TlsInfo>()
+                .and_then(|info| info.peer_certificate())
+                .ok_or_else(|| {
+                    RpcError::TransportError(
+                        "public key pinning requires an https connection".to_string(),
+                    )
+                })?;
+            tls.verify_spki_pin(certificate)?;
+        }
 
         // Extract status code
         let status_code = response.status().as_u16();
@@ -76,11 +307,7 @@ impl HttpTransport for ReqwestTransport {
             .collect();
 
         // Extract body
-        let body = response
-            .bytes()
-            .await
-            .map_err(|e| RpcError::TransportError(e.to_string()))?
-            .to_vec();
+        let body = response.bytes().await.map_err(transport_error)?.to_vec();
 
         Ok(HttpResponse {
             status_code,
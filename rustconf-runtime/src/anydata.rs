@@ -0,0 +1,238 @@
+//! Content of `anydata` and `anyxml` nodes.
+//!
+//! The schema of an `anydata` node's content is not known to the module that
+//! declares it; a notification may, for example, carry a subtree of another
+//! module. RFC 7951 encodes the content like any other JSON data, so its
+//! top-level members are named `module:node`. The generated types keep it as
+//! an [`AnyData`] value, and an [`AnyDataRegistry`] filled by the generated
+//! `register_anydata` functions decodes the members of known modules into
+//! their generated types. Members of other modules stay JSON values.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The content of an `anydata` or `anyxml` node, in its JSON form.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::AnyData;
+/// use serde_json::json;
+///
+/// let data: AnyData = serde_json::from_value(json!({
+///     "example-interfaces:interfaces": {"interface": [{"name": "eth0"}]}
+/// }))
+/// .unwrap();
+///
+/// assert_eq!(data.member_names().collect::<Vec<_>>(), ["example-interfaces:interfaces"]);
+/// let interfaces: serde_json::Value = data.get("example-interfaces:interfaces").unwrap().unwrap();
+/// assert_eq!(interfaces["interface"][0]["name"], "eth0");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AnyData(Value);
+
+impl AnyData {
+    /// Wrap a JSON value.
+    pub fn new(value: Value) -> Self {
+        Self(value)
+    }
+
+    /// The JSON value.
+    pub fn value(&self) -> &Value {
+        &self.0
+    }
+
+    /// Take the JSON value.
+    pub fn into_value(self) -> Value {
+        self.0
+    }
+
+    /// The names of the top-level members, such as `module:node`.
+    pub fn member_names(&self) -> impl Iterator<Item = &str> {
+        self.0
+            .as_object()
+            .into_iter()
+            .flat_map(|members| members.keys().map(String::as_str))
+    }
+
+    /// Deserialize the member `name` into `T`, or `None` if there is no such
+    /// member.
+    pub fn get<T: DeserializeOwned>(&self, name: &str) -> Option<Result<T, serde_json::Error>> {
+        self.0.get(name).map(|value| T::deserialize(value))
+    }
+
+    /// Decode each top-level member with `registry`, in document order.
+    ///
+    /// A value that is not a JSON object has no members.
+    pub fn decode(&self, registry: &AnyDataRegistry) -> Vec<AnyDataMember> {
+        self.0
+            .as_object()
+            .into_iter()
+            .flatten()
+            .map(|(name, value)| registry.decode(name, value))
+            .collect()
+    }
+}
+
+impl From<Value> for AnyData {
+    fn from(value: Value) -> Self {
+        Self(value)
+    }
+}
+
+type Decoder = fn(&Value) -> Result<Box<dyn Any + Send + Sync>, serde_json::Error>;
+
+/// The generated types of `module:node` members, for decoding [`AnyData`].
+///
+/// Each generated module has a `types::register_anydata` function (or
+/// `register_anydata` in single-file output) that registers the structs of
+/// its top-level containers and the `Vec`s of its top-level lists.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::{AnyData, AnyDataRegistry};
+/// use serde::Deserialize;
+/// use serde_json::json;
+///
+/// // As generated for a top-level `container system`
+/// #[derive(Debug, Deserialize)]
+/// struct System {
+///     hostname: Option<String>,
+/// }
+///
+/// let mut registry = AnyDataRegistry::new();
+/// registry.register::<System>("example-system:system");
+///
+/// let data = AnyData::new(json!({
+///     "example-system:system": {"hostname": "edge-1"},
+///     "vendor-debug:trace": {"level": 3}
+/// }));
+/// let members = data.decode(&registry);
+///
+/// let system = members[0].downcast_ref::<System>().unwrap();
+/// assert_eq!(system.hostname.as_deref(), Some("edge-1"));
+/// // Members of modules without generated types stay JSON
+/// assert_eq!(members[1].name(), "vendor-debug:trace");
+/// assert_eq!(members[1].as_value(), Some(&json!({"level": 3})));
+/// ```
+#[derive(Clone, Default)]
+pub struct AnyDataRegistry {
+    decoders: HashMap<String, Decoder>,
+}
+
+impl AnyDataRegistry {
+    /// An empty registry, which leaves every member a JSON value.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decode members named `name` (`module:node`) into `T`, replacing any
+    /// type registered for the name before.
+    pub fn register<T>(&mut self, name: &str) -> &mut Self
+    where
+        T: DeserializeOwned + Any + Send + Sync,
+    {
+        self.decoders.insert(name.to_string(), |value| {
+            Ok(Box::new(T::deserialize(value)?) as Box<dyn Any + Send + Sync>)
+        });
+        self
+    }
+
+    /// Whether a type is registered for the member `name`.
+    pub fn contains(&self, name: &str) -> bool {
+        self.decoders.contains_key(name)
+    }
+
+    /// Decode one member, keeping it a JSON value when no type is
+    /// registered for it or it does not fit the registered type.
+    pub fn decode(&self, name: &str, value: &Value) -> AnyDataMember {
+        if let Some(decoder) = self.decoders.get(name) {
+            if let Ok(decoded) = decoder(value) {
+                return AnyDataMember::Typed {
+                    name: name.to_string(),
+                    value: decoded,
+                };
+            }
+        }
+        AnyDataMember::Untyped {
+            name: name.to_string(),
+            value: value.clone(),
+        }
+    }
+}
+
+impl fmt::Debug for AnyDataRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut names: Vec<&String> = self.decoders.keys().collect();
+        names.sort();
+        f.debug_struct("AnyDataRegistry")
+            .field("names", &names)
+            .finish()
+    }
+}
+
+/// A top-level member of [`AnyData`], decoded by an [`AnyDataRegistry`].
+pub enum AnyDataMember {
+    /// A member decoded into its registered type.
+    Typed {
+        /// The member name, `module:node`.
+        name: String,
+        /// The decoded value, to be downcast to the registered type.
+        value: Box<dyn Any + Send + Sync>,
+    },
+    /// A member without a registered type, or one that did not fit it.
+    Untyped {
+        /// The member name.
+        name: String,
+        /// The JSON value.
+        value: Value,
+    },
+}
+
+impl AnyDataMember {
+    /// The member name.
+    pub fn name(&self) -> &str {
+        match self {
+            AnyDataMember::Typed { name, .. } | AnyDataMember::Untyped { name, .. } => name,
+        }
+    }
+
+    /// The decoded value, if the member was decoded into a `T`.
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        match self {
+            AnyDataMember::Typed { value, .. } => value.downcast_ref(),
+            AnyDataMember::Untyped { .. } => None,
+        }
+    }
+
+    /// The JSON value, if the member was not decoded.
+    pub fn as_value(&self) -> Option<&Value> {
+        match self {
+            AnyDataMember::Typed { .. } => None,
+            AnyDataMember::Untyped { value, .. } => Some(value),
+        }
+    }
+}
+
+impl fmt::Debug for AnyDataMember {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnyDataMember::Typed { name, .. } => f
+                .debug_struct("Typed")
+                .field("name", name)
+                .finish_non_exhaustive(),
+            AnyDataMember::Untyped { name, value } => f
+                .debug_struct("Untyped")
+                .field("name", name)
+                .field("value", value)
+                .finish(),
+        }
+    }
+}
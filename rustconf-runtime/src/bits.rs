@@ -0,0 +1,153 @@
+//! Values of YANG `bits` types.
+//!
+//! A `bits` type is a set of named bits, each at a fixed position. RFC 7951
+//! section 6.5 encodes a value as the names of its set bits separated by
+//! spaces, such as `"up running"`. Generated code declares a `Copy` type for
+//! each `bits` type, with a constant for each bit, and implements [`Bits`]
+//! for it. Its `Serialize`, `Deserialize`, `Display` and `FromStr`
+//! implementations call the functions of this module.
+//!
+//! # Examples
+//!
+//! ```
+//! use rustconf_runtime::Bits;
+//!
+//! // As generated for `type bits { bit up; bit running; bit loopback; }`
+//! #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+//! struct Flags(u64);
+//!
+//! impl Flags {
+//!     const UP: Self = Self(1 << 0);
+//!     const RUNNING: Self = Self(1 << 1);
+//! }
+//!
+//! impl Bits for Flags {
+//!     const BITS: &'static [(&'static str, u32)] = &[("up", 0), ("running", 1), ("loopback", 2)];
+//!
+//!     fn from_raw(raw: u64) -> Self {
+//!         Self(raw)
+//!     }
+//!
+//!     fn raw(self) -> u64 {
+//!         self.0
+//!     }
+//! }
+//!
+//! let mut flags = Flags::UP;
+//! flags.insert(Flags::RUNNING);
+//! assert!(flags.contains(Flags::UP));
+//! assert_eq!(flags.names(), ["up", "running"]);
+//!
+//! let parsed: Flags = rustconf_runtime::bits::parse("running  up").unwrap();
+//! assert_eq!(parsed, flags);
+//! assert!(rustconf_runtime::bits::parse::<Flags>("down").is_err());
+//! ```
+
+use std::error::Error;
+use std::fmt;
+
+use serde::de::{self, Deserializer, Visitor};
+use serde::ser::Serializer;
+
+/// A value of a generated `bits` type: the set of its bits that are set.
+///
+/// Bit `n` of the raw value is the bit at position `n`, so only positions
+/// below 64 are represented; rustconf keeps values of `bits` types with
+/// higher positions as strings.
+pub trait Bits: Copy {
+    /// The names of the bits with their positions, in declaration order.
+    const BITS: &'static [(&'static str, u32)];
+
+    /// The value with the bits of `raw` set.
+    fn from_raw(raw: u64) -> Self;
+
+    /// The set bits.
+    fn raw(self) -> u64;
+
+    /// Whether no bit is set.
+    fn is_empty(self) -> bool {
+        self.raw() == 0
+    }
+
+    /// Whether every bit set in `other` is set.
+    fn contains(self, other: Self) -> bool {
+        self.raw() & other.raw() == other.raw()
+    }
+
+    /// Set the bits set in `other`.
+    fn insert(&mut self, other: Self) {
+        *self = Self::from_raw(self.raw() | other.raw());
+    }
+
+    /// Clear the bits set in `other`.
+    fn remove(&mut self, other: Self) {
+        *self = Self::from_raw(self.raw() & !other.raw());
+    }
+
+    /// The names of the set bits, in position order.
+    fn names(self) -> Vec<&'static str> {
+        let mut bits: Vec<_> = Self::BITS
+            .iter()
+            .filter(|(_, position)| *position < 64 && self.raw() & (1 << position) != 0)
+            .collect();
+        bits.sort_by_key(|(_, position)| *position);
+        bits.into_iter().map(|(name, _)| *name).collect()
+    }
+}
+
+/// A name in a `bits` value that is not a bit of its type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownBit(pub String);
+
+impl fmt::Display for UnknownBit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown bit '{}'", self.0)
+    }
+}
+
+impl Error for UnknownBit {}
+
+/// Parse the space-separated names of the set bits, in any order.
+pub fn parse<B: Bits>(text: &str) -> Result<B, UnknownBit> {
+    let mut raw = 0;
+    for name in text.split_whitespace() {
+        let position = B::BITS
+            .iter()
+            .find(|(bit, _)| *bit == name)
+            .map(|(_, position)| *position)
+            .filter(|position| *position < 64)
+            .ok_or_else(|| UnknownBit(name.to_string()))?;
+        raw |= 1 << position;
+    }
+    Ok(B::from_raw(raw))
+}
+
+/// Write the names of the set bits, in position order and separated by
+/// spaces.
+pub fn format<B: Bits>(value: B, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(&value.names().join(" "))
+}
+
+/// Serialize a value as the string of its set bits.
+pub fn serialize<B: Bits, S: Serializer>(value: &B, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&value.names().join(" "))
+}
+
+/// Deserialize a value from the string of its set bits.
+pub fn deserialize<'de, B: Bits, D: Deserializer<'de>>(deserializer: D) -> Result<B, D::Error> {
+    struct BitsVisitor<B>(std::marker::PhantomData<B>);
+
+    impl<B: Bits> Visitor<'_> for BitsVisitor<B> {
+        type Value = B;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a string of space-separated bit names")
+        }
+
+        fn visit_str<E: de::Error>(self, text: &str) -> Result<B, E> {
+            parse(text).map_err(E::custom)
+        }
+    }
+
+    deserializer.deserialize_str(BitsVisitor(std::marker::PhantomData))
+}
@@ -0,0 +1,139 @@
+//! Per-resource capability discovery via HTTP OPTIONS.
+
+use crate::error::RpcError;
+use crate::transport::{HttpMethod, HttpResponse};
+
+/// Media type for plain merge PATCH requests (RFC 8040).
+pub const MERGE_PATCH_MEDIA_TYPE: &str = "application/yang-data+json";
+
+/// Media type for YANG Patch requests (RFC 8072).
+pub const YANG_PATCH_MEDIA_TYPE: &str = "application/yang-patch+json";
+
+/// Capabilities a server advertises for a resource in response to OPTIONS.
+///
+/// Built from the `Allow` header (permitted methods) and the `Accept-Patch`
+/// header (media types accepted by PATCH), so callers can check whether an
+/// operation is permitted before attempting it.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::{HttpMethod, HttpResponse, ResourceCapabilities};
+///
+/// let mut response = HttpResponse::new(200);
+/// response.headers.push(("Allow".to_string(), "GET, PUT, PATCH, OPTIONS".to_string()));
+/// response.headers.push((
+///     "Accept-Patch".to_string(),
+///     "application/yang-data+json, application/yang-patch+json".to_string(),
+/// ));
+///
+/// let capabilities = ResourceCapabilities::from_response(&response);
+/// assert!(capabilities.can_replace());
+/// assert!(capabilities.can_patch());
+/// assert!(!capabilities.can_delete());
+/// assert!(capabilities.supports_yang_patch());
+/// assert!(capabilities.allows(HttpMethod::OPTIONS));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResourceCapabilities {
+    /// Methods listed in the `Allow` header
+    pub allow: Vec<HttpMethod>,
+    /// Media types listed in the `Accept-Patch` header
+    pub accept_patch: Vec<String>,
+}
+
+impl ResourceCapabilities {
+    /// Parse capabilities from the headers of an OPTIONS response.
+    ///
+    /// Unknown methods in `Allow` are ignored. Missing headers produce empty
+    /// lists rather than an error.
+    pub fn from_response(response: &HttpResponse) -> Self {
+        let allow = header_values(response, "Allow")
+            .filter_map(HttpMethod::from_name)
+            .collect();
+        let accept_patch = header_values(response, "Accept-Patch")
+            .map(|media_type| {
+                // Drop media type parameters such as "; charset=utf-8"
+                media_type
+                    .split(';')
+                    .next()
+                    .unwrap_or_default()
+                    .trim()
+                    .to_ascii_lowercase()
+            })
+            .collect();
+        Self {
+            allow,
+            accept_patch,
+        }
+    }
+
+    /// Check whether a method is permitted on the resource.
+    pub fn allows(&self, method: HttpMethod) -> bool {
+        self.allow.contains(&method)
+    }
+
+    /// Check whether the resource can be read (GET).
+    pub fn can_read(&self) -> bool {
+        self.allows(HttpMethod::GET)
+    }
+
+    /// Check whether child resources can be created (POST).
+    pub fn can_create(&self) -> bool {
+        self.allows(HttpMethod::POST)
+    }
+
+    /// Check whether the resource can be replaced (PUT).
+    pub fn can_replace(&self) -> bool {
+        self.allows(HttpMethod::PUT)
+    }
+
+    /// Check whether the resource can be partially updated (PATCH).
+    pub fn can_patch(&self) -> bool {
+        self.allows(HttpMethod::PATCH)
+    }
+
+    /// Check whether the resource can be deleted (DELETE).
+    pub fn can_delete(&self) -> bool {
+        self.allows(HttpMethod::DELETE)
+    }
+
+    /// Check whether PATCH accepts the given media type.
+    pub fn accepts_patch_media_type(&self, media_type: &str) -> bool {
+        self.accept_patch
+            .iter()
+            .any(|accepted| accepted.eq_ignore_ascii_case(media_type))
+    }
+
+    /// Check whether PATCH accepts YANG Patch documents (RFC 8072).
+    pub fn supports_yang_patch(&self) -> bool {
+        self.can_patch() && self.accepts_patch_media_type(YANG_PATCH_MEDIA_TYPE)
+    }
+}
+
+/// Convert an OPTIONS response into capabilities, mapping non-2xx statuses to errors.
+impl TryFrom<&HttpResponse> for ResourceCapabilities {
+    type Error = RpcError;
+
+    fn try_from(response: &HttpResponse) -> Result<Self, Self::Error> {
+        if response.is_success() {
+            Ok(Self::from_response(response))
+        } else {
+            Err(RpcError::HttpError {
+                status_code: response.status_code,
+                message: String::from_utf8_lossy(&response.body).to_string(),
+            })
+        }
+    }
+}
+
+/// Split every occurrence of a comma-separated header into trimmed values.
+fn header_values<'a>(response: &'a HttpResponse, name: &'a str) -> impl Iterator<Item = &'a str> {
+    response
+        .headers
+        .iter()
+        .filter(move |(k, _)| k.eq_ignore_ascii_case(name))
+        .flat_map(|(_, v)| v.split(','))
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
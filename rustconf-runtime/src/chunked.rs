@@ -0,0 +1,567 @@
+//! Replacement of large lists in batches.
+//!
+//! Replacing a list of tens of thousands of entries with a single PUT can run
+//! past the request timeouts of a device. [`ChunkedWrite`] replaces the list
+//! a batch of entries at a time instead, reports its progress after every
+//! batch and can resume a write that failed part way.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use bytes::Bytes;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::base_url::percent_encode;
+use crate::capabilities::YANG_PATCH_MEDIA_TYPE;
+use crate::dyn_client::RestconfClientApi;
+use crate::error::RpcError;
+use crate::streaming::ListEntries;
+use crate::transport::{HttpMethod, HttpRequest};
+
+/// Entries per batch unless set with [`ChunkedWrite::batch_size`].
+pub const DEFAULT_BATCH_SIZE: usize = 100;
+
+/// How a [`ChunkedWrite`] sends its batches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChunkStrategy {
+    /// One YANG Patch (RFC 8072) request per batch, which the server applies
+    /// as a whole. Check
+    /// [`ResourceCapabilities::supports_yang_patch`](crate::ResourceCapabilities::supports_yang_patch)
+    /// before relying on it.
+    #[default]
+    YangPatch,
+    /// One PUT per written entry and one DELETE per removed entry, for
+    /// servers without YANG Patch. Progress is still reported per batch.
+    Sequential,
+}
+
+/// Progress of a [`ChunkedWrite`], reported after every batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WriteProgress {
+    /// Entries on the server that are not in the new list.
+    pub stale: usize,
+    /// Stale entries removed so far.
+    pub removed: usize,
+    /// Entries of the new list written so far, including those skipped with
+    /// [`ChunkedWrite::resume_from`].
+    pub written: usize,
+    /// Entries of the new list.
+    pub total: usize,
+}
+
+impl WriteProgress {
+    /// Whether every stale entry is removed and every entry written.
+    pub fn is_complete(&self) -> bool {
+        self.removed == self.stale && self.written == self.total
+    }
+}
+
+type ProgressFn<'a> = Box<dyn FnMut(WriteProgress) + Send + Sync + 'a>;
+
+/// Replaces all entries of a list, a batch at a time.
+///
+/// Generated list handles create one with `replace_chunked`. The write first
+/// reads the keys of the entries on the server and removes those that are not
+/// in the new list, then creates or replaces the new entries in order. Stale
+/// entries go first so the list never holds more entries than the larger of
+/// the old and new lists, which keeps a list with `max-elements` within its
+/// bounds throughout.
+///
+/// Batches are sent one after the other. When one fails, the write stops with
+/// [`RpcError::BatchFailed`], whose `written` count is the number of entries
+/// already written: a new write of the same entries with
+/// [`resume_from(written)`](Self::resume_from) picks up from there. Removing
+/// stale entries is repeated on resume and finds nothing left to do if it had
+/// completed.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use rustconf_runtime::{HttpTransport, HttpRequest, HttpResponse, RpcError};
+/// # use async_trait::async_trait;
+/// # struct MyTransport;
+/// # #[async_trait]
+/// # impl HttpTransport for MyTransport {
+/// #     async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, RpcError> {
+/// #         todo!()
+/// #     }
+/// # }
+/// use rustconf_runtime::{ChunkedWrite, RestconfClient};
+///
+/// #[derive(serde::Serialize)]
+/// struct Route {
+///     prefix: String,
+///     #[serde(rename = "next-hop")]
+///     next_hop: String,
+/// }
+///
+/// # async fn example(routes: Vec<Route>) -> Result<(), RpcError> {
+/// let client = RestconfClient::new("https://device.example.com", MyTransport)?;
+/// let write = |written| {
+///     ChunkedWrite::new(
+///         &client,
+///         "/restconf/data/rib:routes/route",
+///         "rib:route",
+///         &["prefix", "next-hop"],
+///         &routes,
+///     )
+///     .batch_size(500)
+///     .resume_from(written)
+///     .on_progress(|progress| println!("{}/{} written", progress.written, progress.total))
+///     .run()
+/// };
+///
+/// match write(0).await {
+///     // Try the entries that were not written once more
+///     Err(RpcError::BatchFailed { written, .. }) => {
+///         write(written).await?;
+///     }
+///     result => {
+///         result?;
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Seven entries in batches of three take three YANG Patches, the last one
+/// partial. A failed batch stops the write, and resuming from its `written`
+/// count sends the remaining entries only:
+///
+/// ```
+/// # use std::sync::Mutex;
+/// # use async_trait::async_trait;
+/// # use serde_json::{json, Value};
+/// # use rustconf_runtime::{
+/// #     ChunkedWrite, HttpMethod, HttpRequest, HttpResponse, HttpTransport, RestconfClient,
+/// #     RpcError,
+/// # };
+/// /// A device with an empty list, recording the targets of every YANG Patch
+/// /// and failing the patch numbered `fail_patch`.
+/// struct Device {
+///     patches: Mutex<Vec<Vec<String>>>,
+///     fail_patch: Option<usize>,
+/// }
+///
+/// #[async_trait]
+/// impl HttpTransport for Device {
+///     async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, RpcError> {
+///         if request.method != HttpMethod::PATCH {
+///             return Ok(HttpResponse::new(200));
+///         }
+///         let body: Value = serde_json::from_slice(request.body.as_deref().unwrap()).unwrap();
+///         let edits = body["ietf-yang-patch:yang-patch"]["edit"].as_array().unwrap();
+///         let targets = edits.iter().map(|edit| edit["target"].as_str().unwrap().to_string());
+///         let mut patches = self.patches.lock().unwrap();
+///         patches.push(targets.collect());
+///         let status = if Some(patches.len()) == self.fail_patch { 500 } else { 204 };
+///         Ok(HttpResponse::new(status))
+///     }
+/// }
+///
+/// let routes: Vec<Value> = (0..7)
+///     .map(|i| json!({ "prefix": format!("10.0.{}.0", i) }))
+///     .collect();
+/// let run = |device: &Device, written: usize| {
+///     let client = RestconfClient::new("https://device.example.com", device).unwrap();
+///     let mut reported = Vec::new();
+///     let result = tokio::runtime::Builder::new_current_thread()
+///         .build()
+///         .unwrap()
+///         .block_on(
+///             ChunkedWrite::new(
+///                 &client,
+///                 "/restconf/data/rib:routes/route",
+///                 "rib:route",
+///                 &["prefix"],
+///                 &routes,
+///             )
+///             .batch_size(3)
+///             .resume_from(written)
+///             .on_progress(|progress| reported.push(progress.written))
+///             .run(),
+///         );
+///     (result, reported)
+/// };
+/// let sizes = |device: &Device| -> Vec<usize> {
+///     device.patches.lock().unwrap().iter().map(Vec::len).collect()
+/// };
+///
+/// let device = Device { patches: Mutex::new(Vec::new()), fail_patch: None };
+/// let (result, reported) = run(&device, 0);
+/// assert!(result.unwrap().is_complete());
+/// assert_eq!(sizes(&device), [3, 3, 1]);
+/// assert_eq!(reported, [0, 3, 6, 7]);
+///
+/// // The second batch fails and the third is never sent
+/// let device = Device { patches: Mutex::new(Vec::new()), fail_patch: Some(2) };
+/// let (result, reported) = run(&device, 0);
+/// let written = match result {
+///     Err(RpcError::BatchFailed { written, .. }) => written,
+///     other => panic!("expected a failed batch, got {:?}", other),
+/// };
+/// assert_eq!(written, 3);
+/// assert_eq!(sizes(&device), [3, 3]);
+/// assert_eq!(reported, [0, 3]);
+///
+/// // Resuming starts at the first entry of the failed batch
+/// let device = Device { patches: Mutex::new(Vec::new()), fail_patch: None };
+/// let (result, reported) = run(&device, written);
+/// assert_eq!(result.unwrap().written, 7);
+/// assert_eq!(sizes(&device), [3, 1]);
+/// assert_eq!(device.patches.lock().unwrap()[0][0], "/route=10.0.3.0");
+/// assert_eq!(reported, [3, 6, 7]);
+/// ```
+#[must_use = "a ChunkedWrite does nothing until `run` is called"]
+pub struct ChunkedWrite<'a, E> {
+    client: &'a dyn RestconfClientApi,
+    path: String,
+    member: String,
+    keys: Vec<String>,
+    entries: &'a [E],
+    batch_size: usize,
+    max_elements: Option<usize>,
+    strategy: ChunkStrategy,
+    resume_from: usize,
+    on_progress: Option<ProgressFn<'a>>,
+}
+
+impl<'a, E: Serialize + Sync> ChunkedWrite<'a, E> {
+    /// Replace the entries of the list at `path`, such as
+    /// `/restconf/data/example:routes/route`, with `entries`.
+    ///
+    /// `member` is the module-qualified name of the list, as used in request
+    /// bodies, and `keys` are the names of its key leaves in order.
+    pub fn new(
+        client: &'a dyn RestconfClientApi,
+        path: impl Into<String>,
+        member: impl Into<String>,
+        keys: &[&str],
+        entries: &'a [E],
+    ) -> Self {
+        Self {
+            client,
+            path: path.into(),
+            member: member.into(),
+            keys: keys.iter().map(|key| key.to_string()).collect(),
+            entries,
+            batch_size: DEFAULT_BATCH_SIZE,
+            max_elements: None,
+            strategy: ChunkStrategy::default(),
+            resume_from: 0,
+            on_progress: None,
+        }
+    }
+
+    /// Send at most `size` entries per batch. A size of zero is read as one.
+    pub fn batch_size(mut self, size: usize) -> Self {
+        self.batch_size = size.max(1);
+        self
+    }
+
+    /// Fail before sending anything if there are more entries than the
+    /// list's `max-elements`.
+    pub fn max_elements(mut self, max: usize) -> Self {
+        self.max_elements = Some(max);
+        self
+    }
+
+    /// Send the batches with `strategy`.
+    pub fn strategy(mut self, strategy: ChunkStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Skip the first `written` entries, written by an earlier attempt that
+    /// failed with [`RpcError::BatchFailed`].
+    pub fn resume_from(mut self, written: usize) -> Self {
+        self.resume_from = written;
+        self
+    }
+
+    /// Call `on_progress` once the stale entries are known and after every
+    /// batch.
+    pub fn on_progress(
+        mut self,
+        on_progress: impl FnMut(WriteProgress) + Send + Sync + 'a,
+    ) -> Self {
+        self.on_progress = Some(Box::new(on_progress));
+        self
+    }
+
+    /// Run the write, returning the final progress.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RpcError::ValidationError` if there are more entries than
+    /// `max-elements`, `RpcError::ConfigurationError` if `resume_from` is
+    /// past the last entry, `RpcError::SerializationError` if an entry has no
+    /// value for a key, and the error of reading the current keys as is.
+    /// The failure of a batch is returned as `RpcError::BatchFailed`.
+    pub async fn run(mut self) -> Result<WriteProgress, RpcError> {
+        if let Some(max) = self.max_elements {
+            if self.entries.len() > max {
+                return Err(RpcError::ValidationError(format!(
+                    "{} entries exceed max-elements {} of {}",
+                    self.entries.len(),
+                    max,
+                    self.member
+                )));
+            }
+        }
+        if self.resume_from > self.entries.len() {
+            return Err(RpcError::ConfigurationError(format!(
+                "Cannot resume from entry {} of {}",
+                self.resume_from,
+                self.entries.len()
+            )));
+        }
+
+        let mut new_keys = Vec::with_capacity(self.entries.len());
+        for entry in self.entries {
+            new_keys.push(self.entry_keys(&to_value(entry)?)?);
+        }
+        let kept: HashSet<&Vec<String>> = new_keys.iter().collect();
+        let stale: Vec<Vec<String>> = self
+            .existing_keys()
+            .await?
+            .into_iter()
+            .filter(|keys| !kept.contains(keys))
+            .collect();
+
+        let mut progress = WriteProgress {
+            stale: stale.len(),
+            removed: 0,
+            written: self.resume_from,
+            total: self.entries.len(),
+        };
+        self.report(progress);
+
+        for batch in stale.chunks(self.batch_size) {
+            self.remove_batch(batch)
+                .await
+                .map_err(|error| batch_failed(progress, error))?;
+            progress.removed += batch.len();
+            self.report(progress);
+        }
+
+        let entries = self.entries;
+        let remaining = entries[self.resume_from..].chunks(self.batch_size);
+        let remaining_keys = new_keys[self.resume_from..].chunks(self.batch_size);
+        for (batch, keys) in remaining.zip(remaining_keys) {
+            self.write_batch(batch, keys)
+                .await
+                .map_err(|error| batch_failed(progress, error))?;
+            progress.written += batch.len();
+            self.report(progress);
+        }
+
+        Ok(progress)
+    }
+
+    fn report(&mut self, progress: WriteProgress) {
+        if let Some(on_progress) = &mut self.on_progress {
+            on_progress(progress);
+        }
+    }
+
+    /// The key values of an entry, in the order of the keys.
+    fn entry_keys(&self, entry: &Value) -> Result<Vec<String>, RpcError> {
+        self.keys
+            .iter()
+            .map(|key| {
+                let value = entry.as_object().and_then(|members| {
+                    members.iter().find_map(|(name, value)| {
+                        (unqualified(name) == key.as_str()).then_some(value)
+                    })
+                });
+                match value {
+                    Some(Value::String(value)) => Ok(value.clone()),
+                    Some(value) if !value.is_null() => Ok(value.to_string()),
+                    _ => Err(RpcError::SerializationError(format!(
+                        "Entry of {} has no value for key '{}'",
+                        self.member, key
+                    ))),
+                }
+            })
+            .collect()
+    }
+
+    /// The keys of the entries on the server, in the server's order.
+    async fn existing_keys(&self) -> Result<Vec<Vec<String>>, RpcError> {
+        let response = match self
+            .client
+            .raw_request(HttpMethod::GET, &self.path, None)
+            .await
+        {
+            Ok(response) => response,
+            Err(RpcError::HttpError {
+                status_code: 404, ..
+            }) => return Ok(Vec::new()),
+            Err(error) => return Err(error),
+        };
+        if response.body.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut keys = Vec::new();
+        let mut failure = None;
+        let seed = ListEntries::new(unqualified(&self.member), |entry: Value| {
+            match self.entry_keys(&entry) {
+                Ok(entry_keys) => keys.push(entry_keys),
+                Err(error) => failure = failure.take().or(Some(error)),
+            }
+        });
+        let mut deserializer = serde_json::Deserializer::from_slice(&response.body);
+        serde::de::DeserializeSeed::deserialize(seed, &mut deserializer).map_err(|e| {
+            RpcError::DeserializationError(format!("Failed to read the list entries: {}", e))
+        })?;
+        match failure {
+            Some(error) => Err(error),
+            None => Ok(keys),
+        }
+    }
+
+    async fn remove_batch(&self, batch: &[Vec<String>]) -> Result<(), RpcError> {
+        match self.strategy {
+            ChunkStrategy::YangPatch => {
+                let edits = batch
+                    .iter()
+                    .enumerate()
+                    .map(|(index, keys)| {
+                        json!({
+                            "edit-id": format!("remove-{}", index + 1),
+                            "operation": "remove",
+                            "target": self.edit_target(keys),
+                        })
+                    })
+                    .collect();
+                self.send_patch(edits).await
+            }
+            ChunkStrategy::Sequential => {
+                for keys in batch {
+                    let path = self.entry_path(keys);
+                    match self
+                        .client
+                        .raw_request(HttpMethod::DELETE, &path, None)
+                        .await
+                    {
+                        // Removed by an earlier attempt
+                        Ok(_)
+                        | Err(RpcError::HttpError {
+                            status_code: 404, ..
+                        }) => {}
+                        Err(error) => return Err(error),
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+
+    async fn write_batch(&self, batch: &[E], keys: &[Vec<String>]) -> Result<(), RpcError> {
+        match self.strategy {
+            ChunkStrategy::YangPatch => {
+                let mut edits = Vec::with_capacity(batch.len());
+                for (index, (entry, keys)) in batch.iter().zip(keys).enumerate() {
+                    edits.push(json!({
+                        "edit-id": format!("write-{}", index + 1),
+                        "operation": "replace",
+                        "target": self.edit_target(keys),
+                        "value": { self.member.as_str(): [to_value(entry)?] },
+                    }));
+                }
+                self.send_patch(edits).await
+            }
+            ChunkStrategy::Sequential => {
+                for (entry, keys) in batch.iter().zip(keys) {
+                    let body = json!({ self.member.as_str(): [to_value(entry)?] });
+                    self.client
+                        .raw_request(
+                            HttpMethod::PUT,
+                            &self.entry_path(keys),
+                            Some(Bytes::from(body.to_string())),
+                        )
+                        .await?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Send a YANG Patch to the parent of the list.
+    async fn send_patch(&self, edits: Vec<Value>) -> Result<(), RpcError> {
+        let body = json!({
+            "ietf-yang-patch:yang-patch": {
+                "patch-id": format!("rustconf-chunked-write-{}", unqualified(&self.member)),
+                "edit": edits,
+            }
+        });
+        let (parent, _) = self.split_path();
+        let request = HttpRequest::new(HttpMethod::PATCH, self.client.build_url(parent))
+            .with_header("Accept", "application/yang-data+json")
+            .with_header("Content-Type", YANG_PATCH_MEDIA_TYPE)
+            .with_body(Bytes::from(body.to_string()));
+        let response = self.client.execute(request).await?;
+        if !response.is_success() {
+            return Err(RpcError::HttpError {
+                status_code: response.status_code,
+                message: String::from_utf8_lossy(&response.body).to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// The path of the list's parent and the list's own segment.
+    fn split_path(&self) -> (&str, &str) {
+        let path = self.path.trim_end_matches('/');
+        path.rsplit_once('/').unwrap_or(("", path))
+    }
+
+    /// Target of an edit, relative to the parent of the list.
+    fn edit_target(&self, keys: &[String]) -> String {
+        let (_, list) = self.split_path();
+        format!("/{}={}", list, encode_keys(keys))
+    }
+
+    fn entry_path(&self, keys: &[String]) -> String {
+        format!("{}={}", self.path, encode_keys(keys))
+    }
+}
+
+impl<E> fmt::Debug for ChunkedWrite<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChunkedWrite")
+            .field("path", &self.path)
+            .field("member", &self.member)
+            .field("keys", &self.keys)
+            .field("entries", &self.entries.len())
+            .field("batch_size", &self.batch_size)
+            .field("max_elements", &self.max_elements)
+            .field("strategy", &self.strategy)
+            .field("resume_from", &self.resume_from)
+            .finish()
+    }
+}
+
+fn batch_failed(progress: WriteProgress, error: RpcError) -> RpcError {
+    RpcError::BatchFailed {
+        written: progress.written,
+        error: Box::new(error),
+    }
+}
+
+fn to_value<E: Serialize>(entry: &E) -> Result<Value, RpcError> {
+    serde_json::to_value(entry).map_err(|e| RpcError::SerializationError(e.to_string()))
+}
+
+fn unqualified(name: &str) -> &str {
+    name.split_once(':').map_or(name, |(_, name)| name)
+}
+
+/// Key values of a list instance, percent-encoded as in RFC 8040 3.5.3.
+fn encode_keys(keys: &[String]) -> String {
+    let encoded: Vec<String> = keys.iter().map(|key| percent_encode(key)).collect();
+    encoded.join(",")
+}
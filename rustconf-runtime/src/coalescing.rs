@@ -0,0 +1,288 @@
+//! Deduplication of identical concurrent GET requests.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use async_trait::async_trait;
+
+use crate::error::RpcError;
+use crate::transport::{HttpMethod, HttpRequest, HttpResponse, HttpTransport};
+use crate::upload::StreamingBody;
+
+/// Requests are only coalesced when both URL and headers match, so callers
+/// with different credentials never share a response.
+type RequestKey = (String, Vec<(String, String)>);
+
+/// Transport decorator that shares one in-flight GET among identical callers.
+///
+/// When a GET is issued while an identical GET (same URL and headers) is
+/// still in flight, the second caller waits for the first response instead
+/// of sending its own request. Nothing is cached: once the response arrives
+/// it is handed to every waiter and the next GET goes to the server again.
+/// Other methods, and GETs with a body, are passed through unchanged.
+///
+/// If the request that is being waited on is cancelled, one of the waiters
+/// sends the request itself.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use rustconf_runtime::{HttpTransport, HttpRequest, HttpResponse, RpcError};
+/// # use async_trait::async_trait;
+/// # struct MyTransport;
+/// # #[async_trait]
+/// # impl HttpTransport for MyTransport {
+/// #     async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, RpcError> {
+/// #         todo!()
+/// #     }
+/// # }
+/// use rustconf_runtime::{CoalescingTransport, RestconfClient};
+///
+/// # fn example() -> Result<(), RpcError> {
+/// let transport = CoalescingTransport::new(MyTransport);
+/// let client = RestconfClient::new("https://device.example.com", transport)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Identical GETs in flight at the same time reach the server once, while
+/// other methods are always sent:
+///
+/// ```
+/// # use std::sync::atomic::{AtomicUsize, Ordering};
+/// # use async_trait::async_trait;
+/// # use rustconf_runtime::{CoalescingTransport, HttpMethod, HttpRequest, HttpResponse, HttpTransport, RpcError};
+/// /// Counts the requests reaching the server; the first one never completes
+/// /// when `hang_first` is set.
+/// struct Counting {
+///     calls: AtomicUsize,
+///     hang_first: bool,
+/// }
+///
+/// #[async_trait]
+/// impl HttpTransport for Counting {
+///     async fn execute(&self, _request: HttpRequest) -> Result<HttpResponse, RpcError> {
+///         let call = self.calls.fetch_add(1, Ordering::SeqCst);
+///         if self.hang_first && call == 0 {
+///             std::future::pending::<()>().await;
+///         }
+///         tokio::task::yield_now().await;
+///         Ok(HttpResponse::new(200))
+///     }
+/// }
+/// # let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+/// let transport = CoalescingTransport::new(Counting { calls: AtomicUsize::new(0), hang_first: false });
+/// let get = || HttpRequest::new(HttpMethod::GET, "https://device.example.com/data/system");
+/// let post = || HttpRequest::new(HttpMethod::POST, "https://device.example.com/operations/reboot");
+///
+/// # runtime.block_on(async {
+/// let (first, second) = tokio::join!(transport.execute(get()), transport.execute(get()));
+/// assert_eq!((first.unwrap().status_code, second.unwrap().status_code), (200, 200));
+/// assert_eq!(transport.inner().calls.load(Ordering::SeqCst), 1);
+/// assert_eq!(transport.in_flight(), 0);
+///
+/// let (first, second) = tokio::join!(transport.execute(post()), transport.execute(post()));
+/// assert!(first.is_ok() && second.is_ok());
+/// assert_eq!(transport.inner().calls.load(Ordering::SeqCst), 3);
+/// # });
+/// ```
+///
+/// Dropping the caller whose request the others wait on wakes them, and one
+/// of them sends the request again:
+///
+/// ```
+/// # use std::future::Future;
+/// # use std::task::Poll;
+/// # use std::sync::atomic::{AtomicUsize, Ordering};
+/// # use async_trait::async_trait;
+/// # use rustconf_runtime::{CoalescingTransport, HttpMethod, HttpRequest, HttpResponse, HttpTransport, RpcError};
+/// /// Counts the requests reaching the server; the first one never completes
+/// /// when `hang_first` is set.
+/// struct Counting {
+///     calls: AtomicUsize,
+///     hang_first: bool,
+/// }
+///
+/// #[async_trait]
+/// impl HttpTransport for Counting {
+///     async fn execute(&self, _request: HttpRequest) -> Result<HttpResponse, RpcError> {
+///         let call = self.calls.fetch_add(1, Ordering::SeqCst);
+///         if self.hang_first && call == 0 {
+///             std::future::pending::<()>().await;
+///         }
+///         tokio::task::yield_now().await;
+///         Ok(HttpResponse::new(200))
+///     }
+/// }
+/// # let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+/// let transport = CoalescingTransport::new(Counting { calls: AtomicUsize::new(0), hang_first: true });
+/// let get = || HttpRequest::new(HttpMethod::GET, "https://device.example.com/data/system");
+///
+/// # runtime.block_on(async {
+/// let mut leader = transport.execute(get());
+/// let mut waiter = transport.execute(get());
+/// std::future::poll_fn(|cx| {
+///     assert!(leader.as_mut().poll(cx).is_pending());
+///     assert!(waiter.as_mut().poll(cx).is_pending());
+///     Poll::Ready(())
+/// })
+/// .await;
+/// assert_eq!(transport.inner().calls.load(Ordering::SeqCst), 1);
+///
+/// drop(leader);
+/// assert_eq!(waiter.await.unwrap().status_code, 200);
+/// assert_eq!(transport.inner().calls.load(Ordering::SeqCst), 2);
+/// # });
+/// ```
+pub struct CoalescingTransport<T> {
+    inner: T,
+    in_flight: Mutex<HashMap<RequestKey, Arc<Flight>>>,
+}
+
+/// Shared state of one in-flight request.
+#[derive(Default)]
+struct Flight {
+    state: Mutex<FlightState>,
+}
+
+#[derive(Default)]
+struct FlightState {
+    result: Option<Result<HttpResponse, RpcError>>,
+    abandoned: bool,
+    waiters: Vec<Waker>,
+}
+
+impl<T: HttpTransport> CoalescingTransport<T> {
+    /// Wrap a transport.
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            in_flight: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The wrapped transport.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Number of distinct GET requests currently in flight.
+    pub fn in_flight(&self) -> usize {
+        self.lock_in_flight().len()
+    }
+
+    fn lock_in_flight(&self) -> std::sync::MutexGuard<'_, HashMap<RequestKey, Arc<Flight>>> {
+        self.in_flight.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[async_trait]
+impl<T: HttpTransport> HttpTransport for CoalescingTransport<T> {
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, RpcError> {
+        if request.method != HttpMethod::GET || request.body.is_some() {
+            return self.inner.execute(request).await;
+        }
+
+        let key = (request.url.clone(), request.headers.clone());
+        loop {
+            let (flight, leader) = {
+                let mut in_flight = self.lock_in_flight();
+                match in_flight.get(&key) {
+                    Some(flight) => (Arc::clone(flight), false),
+                    None => {
+                        let flight = Arc::new(Flight::default());
+                        in_flight.insert(key.clone(), Arc::clone(&flight));
+                        (flight, true)
+                    }
+                }
+            };
+
+            if leader {
+                let mut guard = LeaderGuard {
+                    transport: self,
+                    key: &key,
+                    flight: &flight,
+                    result: None,
+                };
+                let result = self.inner.execute(request.clone()).await;
+                guard.result = Some(result.clone());
+                return result;
+            }
+
+            if let Some(result) = (WaitForFlight { flight: &flight }).await {
+                return result;
+            }
+            // The leader was cancelled; try again, possibly as the new leader
+        }
+    }
+
+    async fn execute_streaming(
+        &self,
+        request: HttpRequest,
+        body: StreamingBody,
+    ) -> Result<HttpResponse, RpcError> {
+        self.inner.execute_streaming(request, body).await
+    }
+}
+
+/// Publishes the leader's outcome to waiters, including when the leader is
+/// dropped before its request completes.
+struct LeaderGuard<'a, T> {
+    transport: &'a CoalescingTransport<T>,
+    key: &'a RequestKey,
+    flight: &'a Arc<Flight>,
+    result: Option<Result<HttpResponse, RpcError>>,
+}
+
+impl<T> Drop for LeaderGuard<'_, T> {
+    fn drop(&mut self) {
+        {
+            let mut in_flight = self
+                .transport
+                .in_flight
+                .lock()
+                .unwrap_or_else(|e| e.into_inner());
+            if in_flight
+                .get(self.key)
+                .is_some_and(|current| Arc::ptr_eq(current, self.flight))
+            {
+                in_flight.remove(self.key);
+            }
+        }
+
+        let mut state = self.flight.state.lock().unwrap_or_else(|e| e.into_inner());
+        match self.result.take() {
+            Some(result) => state.result = Some(result),
+            None => state.abandoned = true,
+        }
+        for waker in state.waiters.drain(..) {
+            waker.wake();
+        }
+    }
+}
+
+/// Future resolving to the leader's result, or `None` if the leader was cancelled.
+struct WaitForFlight<'a> {
+    flight: &'a Flight,
+}
+
+impl Future for WaitForFlight<'_> {
+    type Output = Option<Result<HttpResponse, RpcError>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.flight.state.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(result) = &state.result {
+            return Poll::Ready(Some(result.clone()));
+        }
+        if state.abandoned {
+            return Poll::Ready(None);
+        }
+        if !state.waiters.iter().any(|w| w.will_wake(cx.waker())) {
+            state.waiters.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+}
@@ -0,0 +1,144 @@
+//! Connection settings shared by the transport adapters.
+//!
+//! Dual-stack devices whose IPv6 address is published but unreachable stall
+//! every request until the connection attempt times out, and lab devices are
+//! often known by names that only exist in an inventory. [`ConnectConfig`]
+//! maps host names to addresses, prefers IPv4 and bounds the time spent
+//! connecting; the reqwest and hyper adapters resolve and connect with it.
+
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+
+/// Name resolution and connection settings for the reqwest and hyper
+/// transport adapters.
+///
+/// Hosts with static addresses are never looked up. Other hosts are resolved
+/// by the system resolver, and their addresses are tried in order, with
+/// IPv4 addresses first when IPv4 is preferred. Both adapters race the
+/// second address family shortly after the first ("happy eyeballs"), so a
+/// broken address family costs a fraction of a second rather than a connect
+/// timeout.
+///
+/// The connect timeout bounds establishing the TCP connection only; the
+/// request as a whole is bounded by the request's
+/// [`Deadline`](crate::Deadline).
+///
+/// # Examples
+///
+/// ```
+/// use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+/// use std::time::Duration;
+///
+/// use rustconf_runtime::ConnectConfig;
+///
+/// let connect = ConnectConfig::new()
+///     .with_host("core-1.lab", [IpAddr::V4(Ipv4Addr::new(192, 0, 2, 10))])
+///     .with_prefer_ipv4()
+///     .with_connect_timeout(Duration::from_secs(3));
+///
+/// assert_eq!(
+///     connect.host("CORE-1.lab"),
+///     Some(&[IpAddr::V4(Ipv4Addr::new(192, 0, 2, 10))][..])
+/// );
+/// assert_eq!(connect.connect_timeout(), Some(Duration::from_secs(3)));
+///
+/// let mut addresses: Vec<SocketAddr> =
+///     vec!["[2001:db8::1]:443".parse()?, "192.0.2.1:443".parse()?];
+/// connect.order(&mut addresses);
+/// assert_eq!(addresses[0], "192.0.2.1:443".parse()?);
+/// # Ok::<(), std::net::AddrParseError>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ConnectConfig {
+    /// Static addresses, by lowercase host name.
+    hosts: HashMap<String, Vec<IpAddr>>,
+    prefer_ipv4: bool,
+    connect_timeout: Option<Duration>,
+}
+
+impl ConnectConfig {
+    /// Resolve every host with the system resolver, in the order it returns
+    /// addresses, without a connect timeout.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Connect to `host` at `addresses` instead of looking it up.
+    ///
+    /// The port still comes from the request URL. Host names are matched
+    /// without regard to case.
+    pub fn with_host(mut self, host: &str, addresses: impl IntoIterator<Item = IpAddr>) -> Self {
+        self.hosts
+            .insert(host.to_ascii_lowercase(), addresses.into_iter().collect());
+        self
+    }
+
+    /// Try IPv4 addresses before IPv6 addresses.
+    pub fn with_prefer_ipv4(mut self) -> Self {
+        self.prefer_ipv4 = true;
+        self
+    }
+
+    /// Give up establishing a connection after `timeout`.
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// The static addresses of `host`, if it has any.
+    pub fn host(&self, host: &str) -> Option<&[IpAddr]> {
+        self.hosts
+            .get(&host.to_ascii_lowercase())
+            .map(Vec::as_slice)
+    }
+
+    /// Whether IPv4 addresses are tried first.
+    pub fn prefers_ipv4(&self) -> bool {
+        self.prefer_ipv4
+    }
+
+    /// The time allowed for establishing a connection, if limited.
+    pub fn connect_timeout(&self) -> Option<Duration> {
+        self.connect_timeout
+    }
+
+    /// Put resolved addresses in the order they are tried, keeping the
+    /// resolver's order within each address family.
+    pub fn order(&self, addresses: &mut [SocketAddr]) {
+        if self.prefer_ipv4 {
+            addresses.sort_by_key(SocketAddr::is_ipv6);
+        }
+    }
+
+    /// Whether resolution differs from the system resolver's.
+    #[cfg(feature = "reqwest")]
+    pub(crate) fn changes_resolution(&self) -> bool {
+        self.prefer_ipv4 || !self.hosts.is_empty()
+    }
+}
+
+/// Resolver applying a [`ConnectConfig`], for the adapters' connectors.
+///
+/// Addresses carry port 0; the connector sets the port of the request URL.
+#[cfg(any(feature = "reqwest", feature = "hyper"))]
+#[derive(Debug, Clone)]
+pub(crate) struct Resolver {
+    pub(crate) config: std::sync::Arc<ConnectConfig>,
+}
+
+#[cfg(any(feature = "reqwest", feature = "hyper"))]
+impl Resolver {
+    /// The addresses of `host`, in the order they are tried.
+    pub(crate) async fn resolve(&self, host: &str) -> std::io::Result<Vec<SocketAddr>> {
+        let mut addresses: Vec<SocketAddr> = match self.config.host(host) {
+            Some(addresses) => addresses
+                .iter()
+                .map(|address| SocketAddr::new(*address, 0))
+                .collect(),
+            None => tokio::net::lookup_host((host, 0)).await?.collect(),
+        };
+        self.config.order(&mut addresses);
+        Ok(addresses)
+    }
+}
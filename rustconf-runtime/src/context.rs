@@ -0,0 +1,123 @@
+//! Per-request metadata for interceptors and policy layers.
+//!
+//! A [`RequestContext`] carries typed values alongside a request, such as the
+//! role a request is made on behalf of or a dry-run flag. Values are keyed by
+//! their type, so each layer defines its own types and reads them back without
+//! parsing URLs or headers. Interceptors see the context of every request in
+//! [`HttpRequest::context`](crate::HttpRequest::context); a
+//! [`RestconfClient`](crate::RestconfClient) attaches its context to the
+//! requests it executes, including those made by generated functions.
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::deadline::Deadline;
+
+/// A value stored in a context, with its type name for `Debug` output.
+#[derive(Clone)]
+struct Entry {
+    type_name: &'static str,
+    value: Arc<dyn Any + Send + Sync>,
+}
+
+/// Typed metadata attached to requests.
+///
+/// Holds at most one value per type. Cloning a context is cheap, as values
+/// are shared.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::RequestContext;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct AuthRole(&'static str);
+///
+/// #[derive(Debug, PartialEq)]
+/// struct DryRun;
+///
+/// let context = RequestContext::new().with(AuthRole("operator"));
+/// assert_eq!(context.get::<AuthRole>(), Some(&AuthRole("operator")));
+/// assert!(!context.contains::<DryRun>());
+/// ```
+#[derive(Clone, Default)]
+pub struct RequestContext {
+    values: HashMap<TypeId, Entry>,
+}
+
+impl RequestContext {
+    /// Create an empty context.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a value, replacing any previous value of the same type.
+    pub fn with<V: Send + Sync + 'static>(mut self, value: V) -> Self {
+        self.insert(value);
+        self
+    }
+
+    /// Insert a value, replacing any previous value of the same type.
+    pub fn insert<V: Send + Sync + 'static>(&mut self, value: V) {
+        self.values.insert(
+            TypeId::of::<V>(),
+            Entry {
+                type_name: std::any::type_name::<V>(),
+                value: Arc::new(value),
+            },
+        );
+    }
+
+    /// The value of the given type, if any.
+    pub fn get<V: Send + Sync + 'static>(&self) -> Option<&V> {
+        self.values
+            .get(&TypeId::of::<V>())
+            .and_then(|entry| entry.value.downcast_ref())
+    }
+
+    /// Whether a value of the given type is present.
+    pub fn contains<V: Send + Sync + 'static>(&self) -> bool {
+        self.values.contains_key(&TypeId::of::<V>())
+    }
+
+    /// Remove the value of the given type, returning whether one was present.
+    pub fn remove<V: Send + Sync + 'static>(&mut self) -> bool {
+        self.values.remove(&TypeId::of::<V>()).is_some()
+    }
+
+    /// Whether the context holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The [`Deadline`] bounding the request, if any.
+    pub fn deadline(&self) -> Option<&Deadline> {
+        self.get::<Deadline>()
+    }
+
+    /// Add all values of `other`, replacing values of the same type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustconf_runtime::RequestContext;
+    ///
+    /// let mut context = RequestContext::new().with(1u8).with("client");
+    /// context.extend(RequestContext::new().with(2u8));
+    /// assert_eq!(context.get::<u8>(), Some(&2));
+    /// assert_eq!(context.get::<&str>(), Some(&"client"));
+    /// ```
+    pub fn extend(&mut self, other: RequestContext) {
+        self.values.extend(other.values);
+    }
+}
+
+impl fmt::Debug for RequestContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut type_names: Vec<&str> = self.values.values().map(|entry| entry.type_name).collect();
+        type_names.sort_unstable();
+        f.debug_set().entries(type_names).finish()
+    }
+}
@@ -0,0 +1,253 @@
+//! Schema-checked paths into JSON data.
+//!
+//! Generated structs of the data tree have `get_by_path` and `set_by_path`
+//! methods, for tooling such as templating or policy engines that address
+//! data without knowing the generated types. They convert the struct to JSON
+//! and evaluate the path with [`get`] and [`set`].
+//!
+//! A path is a sequence of data node names separated by `/`, relative to the
+//! struct: `config/mtu`. Names may carry a module qualifier
+//! (`oc-if:config`), which is ignored. An entry of a list is selected by its
+//! key values as in a RESTCONF URL, percent-encoded and separated by commas:
+//! `subinterface=0/config`. Every step is checked against the schema, so a
+//! misspelled name is reported rather than read as an unset value.
+//!
+//! # Examples
+//!
+//! ```
+//! use rustconf_runtime::data_path;
+//! use serde_json::json;
+//!
+//! // The generated schema knows which nodes exist and the keys of lists
+//! let schema = |path: &str| match path {
+//!     "/interface/config" | "/interface/config/mtu" => Some(&[][..]),
+//!     "/interface/subinterface" => Some(&["index"][..]),
+//!     "/interface/subinterface/index" => Some(&[][..]),
+//!     _ => None,
+//! };
+//! let mut data = json!({
+//!     "config": { "mtu": 1500 },
+//!     "subinterface": [{ "index": 0 }, { "index": 1 }],
+//! });
+//!
+//! assert_eq!(data_path::get(&data, "/interface", "config/mtu", schema), Some(json!(1500)));
+//! assert_eq!(
+//!     data_path::get(&data, "/interface", "subinterface=1", schema),
+//!     Some(json!({ "index": 1 }))
+//! );
+//! assert_eq!(data_path::get(&data, "/interface", "config/mut", schema), None);
+//!
+//! data_path::set(&mut data, "/interface", "config/mtu", json!(9000), schema)?;
+//! assert_eq!(data["config"]["mtu"], 9000);
+//! # Ok::<(), data_path::DataPathError>(())
+//! ```
+
+use std::fmt;
+
+use serde_json::{Map, Value};
+
+use crate::base_url::percent_decode;
+use crate::list_key::key_value;
+
+/// Error evaluating a data path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataPathError {
+    message: String,
+}
+
+impl DataPathError {
+    /// Create an error with a message.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for DataPathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid data path: {}", self.message)
+    }
+}
+
+impl std::error::Error for DataPathError {}
+
+impl From<serde_json::Error> for DataPathError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::new(error.to_string())
+    }
+}
+
+/// One step of a path: a node name and, for list entries, key values.
+struct Step {
+    name: String,
+    keys: Option<Vec<String>>,
+}
+
+/// Split `path` into steps, checking each against the schema below `base`.
+///
+/// `schema` returns the key names of the node at a schema path, empty for
+/// nodes other than lists, or `None` if there is no such node.
+fn steps<'s>(
+    base: &str,
+    path: &str,
+    schema: impl Fn(&str) -> Option<&'s [&'s str]>,
+) -> Result<Vec<(Step, &'s [&'s str])>, DataPathError> {
+    let mut schema_path = base.trim_end_matches('/').to_string();
+    let mut steps = Vec::new();
+    for segment in path.split('/').filter(|segment| !segment.is_empty()) {
+        let (name, keys) = match segment.split_once('=') {
+            Some((name, values)) => (
+                name,
+                Some(values.split(',').map(percent_decode).collect::<Vec<_>>()),
+            ),
+            None => (segment, None),
+        };
+        let name = local_name(name);
+        schema_path.push('/');
+        schema_path.push_str(name);
+        let key_names = schema(&schema_path)
+            .ok_or_else(|| DataPathError::new(format!("no data node '{}'", schema_path)))?;
+        if let Some(keys) = &keys {
+            if key_names.len() != keys.len() {
+                return Err(DataPathError::new(format!(
+                    "'{}' needs {} key values, found {}",
+                    segment,
+                    key_names.len(),
+                    keys.len()
+                )));
+            }
+        }
+        steps.push((
+            Step {
+                name: name.to_string(),
+                keys,
+            },
+            key_names,
+        ));
+    }
+    Ok(steps)
+}
+
+/// The name without its module qualifier.
+fn local_name(name: &str) -> &str {
+    name.rsplit(':').next().unwrap_or(name)
+}
+
+/// The member of `object` named `name`, with or without a module qualifier.
+fn member_name(object: &Map<String, Value>, name: &str) -> Option<String> {
+    object
+        .keys()
+        .find(|member| local_name(member) == name)
+        .cloned()
+}
+
+/// Whether the list entry `entry` has the key values `values`.
+fn has_keys(entry: &Value, key_names: &[&str], values: &[String]) -> bool {
+    key_names.iter().zip(values).all(|(key, value)| {
+        entry
+            .as_object()
+            .and_then(|object| object.get(&member_name(object, key)?))
+            .is_some_and(|member| key_value(member) == *value)
+    })
+}
+
+/// The value at `path` below the node at the schema path `base` whose data
+/// is `data`, if the path is in the schema and a value is set there.
+///
+/// `schema` returns the key names of the node at a schema path, empty for
+/// nodes other than lists, or `None` if there is no such node.
+pub fn get<'s>(
+    data: &Value,
+    base: &str,
+    path: &str,
+    schema: impl Fn(&str) -> Option<&'s [&'s str]>,
+) -> Option<Value> {
+    let mut current = data;
+    for (step, key_names) in steps(base, path, schema).ok()? {
+        let object = current.as_object()?;
+        current = object.get(&member_name(object, &step.name)?)?;
+        if let Some(keys) = &step.keys {
+            current = current
+                .as_array()?
+                .iter()
+                .find(|entry| has_keys(entry, key_names, keys))?;
+        }
+    }
+    Some(current.clone())
+}
+
+/// Set the value at `path` below the node at the schema path `base` whose
+/// data is `data`, creating missing containers on the way. A `null` value
+/// removes the node.
+///
+/// `schema` is as for [`get`].
+///
+/// # Errors
+///
+/// Returns an error if the path is empty or not in the schema, if it steps
+/// into a list without selecting an entry, or if the selected entry does not
+/// exist.
+pub fn set<'s>(
+    data: &mut Value,
+    base: &str,
+    path: &str,
+    value: Value,
+    schema: impl Fn(&str) -> Option<&'s [&'s str]>,
+) -> Result<(), DataPathError> {
+    let mut steps = steps(base, path, schema)?;
+    let (last, _) = steps
+        .pop()
+        .ok_or_else(|| DataPathError::new("the path is empty"))?;
+    if last.keys.is_some() {
+        return Err(DataPathError::new(format!(
+            "'{}' selects a list entry; set its list or its leaves instead",
+            path
+        )));
+    }
+
+    let mut current = data;
+    for (step, key_names) in steps {
+        let object = current
+            .as_object_mut()
+            .ok_or_else(|| DataPathError::new(format!("'{}' is not a container", step.name)))?;
+        let member = member_name(object, &step.name).unwrap_or(step.name.clone());
+        let child = object.entry(member).or_insert_with(|| match step.keys {
+            Some(_) => Value::Array(Vec::new()),
+            None => Value::Object(Map::new()),
+        });
+        current = match (&step.keys, child) {
+            (Some(keys), Value::Array(entries)) => entries
+                .iter_mut()
+                .find(|entry| has_keys(entry, key_names, keys))
+                .ok_or_else(|| {
+                    DataPathError::new(format!(
+                        "no entry {} in list '{}'",
+                        keys.join(","),
+                        step.name
+                    ))
+                })?,
+            (None, Value::Array(_)) => {
+                return Err(DataPathError::new(format!(
+                    "'{}' is a list; select an entry by key",
+                    step.name
+                )))
+            }
+            (Some(_), _) => {
+                return Err(DataPathError::new(format!("'{}' is not a list", step.name)))
+            }
+            (None, child) => child,
+        };
+    }
+
+    let object = current
+        .as_object_mut()
+        .ok_or_else(|| DataPathError::new(format!("'{}' has no parent container", path)))?;
+    let member = member_name(object, &last.name).unwrap_or(last.name);
+    if value.is_null() {
+        object.remove(&member);
+    } else {
+        object.insert(member, value);
+    }
+    Ok(())
+}
@@ -0,0 +1,180 @@
+//! `date-and-time` values of `ietf-yang-types` as `chrono` date-times.
+//!
+//! Generated code uses this module with `#[serde(with = ...)]` on fields of
+//! type `DateTime<FixedOffset>`, and `Option` or `Vec` of it. Values are
+//! written in the RFC 3339 form devices expect: seconds always present,
+//! fractional seconds only when non-zero, and `Z` for a zero offset. Values
+//! without fractional seconds are read as well as values with them.
+//!
+//! ```
+//! use chrono::{DateTime, FixedOffset};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Clock {
+//!     #[serde(with = "rustconf_runtime::date_and_time")]
+//!     current: DateTime<FixedOffset>,
+//! }
+//!
+//! let clock: Clock = serde_json::from_str(r#"{"current":"2024-03-01T12:30:00+02:00"}"#).unwrap();
+//! assert_eq!(
+//!     serde_json::to_string(&clock).unwrap(),
+//!     r#"{"current":"2024-03-01T12:30:00+02:00"}"#
+//! );
+//! ```
+
+use std::fmt;
+
+use chrono::{DateTime, FixedOffset, SecondsFormat};
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+use serde::ser::{SerializeSeq, Serializer};
+
+/// Fields that can be encoded with this module: `DateTime<FixedOffset>`,
+/// and `Option` or `Vec` of it.
+pub trait DateAndTimeField: Sized {
+    #[doc(hidden)]
+    fn serialize_field<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+
+    #[doc(hidden)]
+    fn deserialize_field<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+}
+
+/// Serialize the value as an RFC 3339 string.
+pub fn serialize<T: DateAndTimeField, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    value.serialize_field(serializer)
+}
+
+/// Deserialize the value from an RFC 3339 string.
+pub fn deserialize<'de, T: DateAndTimeField, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    T::deserialize_field(deserializer)
+}
+
+/// Format `value` as a YANG `date-and-time`.
+///
+/// ```
+/// use chrono::DateTime;
+///
+/// let value = DateTime::parse_from_rfc3339("2024-03-01T12:30:00.250+00:00").unwrap();
+/// assert_eq!(rustconf_runtime::date_and_time::format(&value), "2024-03-01T12:30:00.250Z");
+/// ```
+pub fn format(value: &DateTime<FixedOffset>) -> String {
+    value.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+}
+
+/// Parse a YANG `date-and-time`, with or without fractional seconds.
+///
+/// # Errors
+///
+/// Returns an error if `text` is not an RFC 3339 date-time with an offset.
+pub fn parse(text: &str) -> Result<DateTime<FixedOffset>, chrono::ParseError> {
+    DateTime::parse_from_rfc3339(text)
+}
+
+struct DateAndTimeVisitor;
+
+impl Visitor<'_> for DateAndTimeVisitor {
+    type Value = DateTime<FixedOffset>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an RFC 3339 date-and-time string")
+    }
+
+    fn visit_str<E: de::Error>(self, text: &str) -> Result<Self::Value, E> {
+        parse(text).map_err(|_| E::invalid_value(de::Unexpected::Str(text), &self))
+    }
+}
+
+impl DateAndTimeField for DateTime<FixedOffset> {
+    fn serialize_field<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format(self))
+    }
+
+    fn deserialize_field<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(DateAndTimeVisitor)
+    }
+}
+
+impl DateAndTimeField for Option<DateTime<FixedOffset>> {
+    fn serialize_field<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Some(value) => serializer.serialize_some(&format(value)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    fn deserialize_field<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct OptionVisitor;
+
+        impl<'de> Visitor<'de> for OptionVisitor {
+            type Value = Option<DateTime<FixedOffset>>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an optional date-and-time")
+            }
+
+            fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+                Ok(None)
+            }
+
+            fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+                Ok(None)
+            }
+
+            fn visit_some<D: Deserializer<'de>>(
+                self,
+                deserializer: D,
+            ) -> Result<Self::Value, D::Error> {
+                deserializer.deserialize_str(DateAndTimeVisitor).map(Some)
+            }
+        }
+
+        deserializer.deserialize_option(OptionVisitor)
+    }
+}
+
+impl DateAndTimeField for Vec<DateTime<FixedOffset>> {
+    fn serialize_field<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for value in self {
+            seq.serialize_element(&format(value))?;
+        }
+        seq.end()
+    }
+
+    fn deserialize_field<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Element(DateTime<FixedOffset>);
+
+        impl<'de> de::Deserialize<'de> for Element {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer
+                    .deserialize_str(DateAndTimeVisitor)
+                    .map(Element)
+            }
+        }
+
+        struct VecVisitor;
+
+        impl<'de> Visitor<'de> for VecVisitor {
+            type Value = Vec<DateTime<FixedOffset>>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a list of date-and-time values")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut values = Vec::new();
+                while let Some(Element(value)) = seq.next_element()? {
+                    values.push(value);
+                }
+                Ok(values)
+            }
+        }
+
+        deserializer.deserialize_seq(VecVisitor)
+    }
+}
@@ -0,0 +1,146 @@
+//! Total time budgets for operations.
+//!
+//! A per-request timeout multiplies with every extra request an operation
+//! makes: a fallback from HEAD to GET, a redirect, a reconnect or a step of a
+//! [`ChangePlan`](crate::ChangePlan) each get the full timeout again. A
+//! [`Deadline`] placed in the [`RequestContext`](crate::RequestContext) of a
+//! client or request instead bounds the whole operation. Every request
+//! checks it before being sent and fails fast with
+//! [`RpcError::DeadlineExceeded`] once the budget is spent, and the reqwest
+//! and hyper adapters only give each request the time that remains.
+
+use std::time::{Duration, Instant};
+
+use crate::error::RpcError;
+
+/// The point in time by which an operation must complete.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use rustconf_runtime::{Deadline, RequestContext, RpcError};
+///
+/// let context = RequestContext::new().with(Deadline::after(Duration::from_secs(5)));
+/// let deadline = context.deadline().unwrap();
+/// assert!(deadline.remaining() <= Duration::from_secs(5));
+/// assert!(deadline.check().is_ok());
+///
+/// let spent = Deadline::after(Duration::ZERO);
+/// assert!(spent.is_expired());
+/// assert!(matches!(spent.check(), Err(RpcError::DeadlineExceeded { .. })));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Deadline {
+    start: Instant,
+    expires: Instant,
+}
+
+impl Deadline {
+    /// A deadline `budget` from now.
+    pub fn after(budget: Duration) -> Self {
+        let start = Instant::now();
+        Self {
+            start,
+            expires: start + budget,
+        }
+    }
+
+    /// A deadline at `instant`. An instant in the past gives an expired
+    /// deadline.
+    pub fn at(instant: Instant) -> Self {
+        Self {
+            start: Instant::now().min(instant),
+            expires: instant,
+        }
+    }
+
+    /// The instant the deadline expires.
+    pub fn expires_at(&self) -> Instant {
+        self.expires
+    }
+
+    /// The total budget, from when the deadline was created.
+    pub fn budget(&self) -> Duration {
+        self.expires - self.start
+    }
+
+    /// The time spent since the deadline was created.
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    /// The time left, or zero once the deadline has passed.
+    pub fn remaining(&self) -> Duration {
+        self.expires.saturating_duration_since(Instant::now())
+    }
+
+    /// Whether the deadline has passed.
+    pub fn is_expired(&self) -> bool {
+        Instant::now() >= self.expires
+    }
+
+    /// The earlier of two deadlines, for bounding a nested operation by both
+    /// its own budget and that of its caller.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use rustconf_runtime::Deadline;
+    ///
+    /// let outer = Deadline::after(Duration::from_secs(10));
+    /// let step = Deadline::after(Duration::from_secs(30));
+    /// assert_eq!(step.earliest(outer), outer);
+    /// ```
+    pub fn earliest(self, other: Deadline) -> Deadline {
+        if other.expires < self.expires {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// The time left.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RpcError::DeadlineExceeded` if the deadline has passed.
+    pub fn check(&self) -> Result<Duration, RpcError> {
+        let remaining = self.remaining();
+        if remaining.is_zero() {
+            Err(self.exceeded())
+        } else {
+            Ok(remaining)
+        }
+    }
+
+    /// The error reported once the deadline has passed.
+    pub fn exceeded(&self) -> RpcError {
+        RpcError::DeadlineExceeded {
+            budget: self.budget(),
+            elapsed: self.elapsed(),
+        }
+    }
+}
+
+/// The time budget of an operation that the caller has not bounded.
+///
+/// Generated functions of operations known to be slow, such as RPCs
+/// annotated with the `rustconf-extensions` `timeout` extension, put it in
+/// the context of their requests. `RestconfClient::execute` turns it into a
+/// [`Deadline`] when the request has none, so the reqwest and hyper adapters
+/// give the request that long instead of their own timeout, while a deadline
+/// set by the caller still wins.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use rustconf_runtime::{OperationTimeout, RequestContext};
+///
+/// let context = RequestContext::new().with(OperationTimeout(Duration::from_secs(600)));
+/// assert_eq!(context.get::<OperationTimeout>().unwrap().0, Duration::from_secs(600));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OperationTimeout(pub Duration);
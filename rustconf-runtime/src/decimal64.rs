@@ -0,0 +1,378 @@
+//! Values of YANG `decimal64` types.
+//!
+//! A `decimal64` is a fixed-point number: an `i64` scaled by ten to the power
+//! of the type's `fraction-digits`, between 1 and 18 (RFC 7950 section 9.3).
+//! RFC 7951 section 6.1 encodes a value as a JSON string, such as `"2.5"`.
+//! Generated code declares a `Copy` newtype over the scaled value for each
+//! number of fraction digits and implements [`Decimal64`] for it. Its
+//! `Serialize`, `Deserialize`, `Display` and `FromStr` implementations call
+//! the functions of this module.
+//!
+//! Values are written in the canonical form of RFC 7950 section 9.3.2, with
+//! one digit before and at least one after the decimal point and no other
+//! leading or trailing zeros. Deserializing also accepts JSON numbers, which
+//! some servers send.
+//!
+//! Code generated for `rust_decimal::Decimal` uses the
+//! [`rust_decimal`](self::rust_decimal) module instead (feature
+//! `rust_decimal`).
+//!
+//! # Examples
+//!
+//! ```
+//! use rustconf_runtime::Decimal64;
+//!
+//! // As generated for `type decimal64 { fraction-digits 2; }`
+//! #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+//! struct Decimal64Fd2(i64);
+//!
+//! impl Decimal64 for Decimal64Fd2 {
+//!     const FRACTION_DIGITS: u8 = 2;
+//!
+//!     fn from_scaled(scaled: i64) -> Self {
+//!         Self(scaled)
+//!     }
+//!
+//!     fn scaled(self) -> i64 {
+//!         self.0
+//!     }
+//! }
+//!
+//! let value: Decimal64Fd2 = rustconf_runtime::decimal64::parse("-12.5").unwrap();
+//! assert_eq!(value.scaled(), -1250);
+//! assert_eq!(value.to_f64(), -12.5);
+//! assert_eq!(rustconf_runtime::decimal64::format_scaled(value.scaled(), 2), "-12.5");
+//!
+//! // More fraction digits than the type has are rejected, not rounded
+//! assert!(rustconf_runtime::decimal64::parse::<Decimal64Fd2>("0.125").is_err());
+//! ```
+
+use std::error::Error;
+use std::fmt;
+
+use serde::de::{self, Deserializer, Visitor};
+use serde::ser::Serializer;
+
+/// A value of a generated `decimal64` type.
+pub trait Decimal64: Copy {
+    /// The number of digits after the decimal point, between 1 and 18.
+    const FRACTION_DIGITS: u8;
+
+    /// The value `scaled` / 10^`FRACTION_DIGITS`.
+    fn from_scaled(scaled: i64) -> Self;
+
+    /// The value multiplied by 10^`FRACTION_DIGITS`.
+    fn scaled(self) -> i64;
+
+    /// The nearest `f64` to the value.
+    fn to_f64(self) -> f64 {
+        self.scaled() as f64 / 10f64.powi(i32::from(Self::FRACTION_DIGITS))
+    }
+}
+
+/// Text that is not a `decimal64` value with the expected fraction digits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidDecimal64 {
+    /// The rejected text.
+    pub text: String,
+    /// The fraction digits of the type.
+    pub fraction_digits: u8,
+}
+
+impl fmt::Display for InvalidDecimal64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "'{}' is not a decimal64 value with at most {} fraction digits",
+            self.text, self.fraction_digits
+        )
+    }
+}
+
+impl Error for InvalidDecimal64 {}
+
+/// Format `scaled` / 10^`fraction_digits` in canonical form.
+pub fn format_scaled(scaled: i64, fraction_digits: u8) -> String {
+    let divisor = 10u64.pow(u32::from(fraction_digits));
+    let magnitude = scaled.unsigned_abs();
+    let fraction = format!(
+        "{:0width$}",
+        magnitude % divisor,
+        width = usize::from(fraction_digits)
+    );
+    let fraction = fraction.trim_end_matches('0');
+    format!(
+        "{}{}.{}",
+        if scaled < 0 { "-" } else { "" },
+        magnitude / divisor,
+        if fraction.is_empty() { "0" } else { fraction }
+    )
+}
+
+/// Parse a decimal number with at most `fraction_digits` digits after the
+/// decimal point, returning it multiplied by 10^`fraction_digits`.
+pub fn parse_scaled(text: &str, fraction_digits: u8) -> Result<i64, InvalidDecimal64> {
+    let invalid = || InvalidDecimal64 {
+        text: text.to_string(),
+        fraction_digits,
+    };
+    let (negative, unsigned) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+    let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    if integer.is_empty()
+        || !digits(integer)
+        || !digits(fraction)
+        || (unsigned.contains('.') && fraction.is_empty())
+        || fraction.len() > usize::from(fraction_digits)
+    {
+        return Err(invalid());
+    }
+
+    let padding = usize::from(fraction_digits) - fraction.len();
+    let magnitude: i128 = format!("{}{}{}", integer, fraction, "0".repeat(padding))
+        .parse()
+        .map_err(|_| invalid())?;
+    i64::try_from(if negative { -magnitude } else { magnitude }).map_err(|_| invalid())
+}
+
+/// Parse a value of a generated `decimal64` type.
+pub fn parse<D: Decimal64>(text: &str) -> Result<D, InvalidDecimal64> {
+    parse_scaled(text, D::FRACTION_DIGITS).map(D::from_scaled)
+}
+
+/// Write a value in canonical form.
+pub fn format<D: Decimal64>(value: D, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(&format_scaled(value.scaled(), D::FRACTION_DIGITS))
+}
+
+/// Serialize a value as a string in canonical form.
+pub fn serialize<D: Decimal64, S: Serializer>(value: &D, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&format_scaled(value.scaled(), D::FRACTION_DIGITS))
+}
+
+/// Deserialize a value from a string, or from a JSON number.
+pub fn deserialize<'de, D: Decimal64, De: Deserializer<'de>>(
+    deserializer: De,
+) -> Result<D, De::Error> {
+    struct Decimal64Visitor<D>(std::marker::PhantomData<D>);
+
+    impl<D: Decimal64> Visitor<'_> for Decimal64Visitor<D> {
+        type Value = D;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(
+                f,
+                "a decimal64 with at most {} fraction digits",
+                D::FRACTION_DIGITS
+            )
+        }
+
+        fn visit_str<E: de::Error>(self, text: &str) -> Result<D, E> {
+            parse(text).map_err(E::custom)
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<D, E> {
+            self.visit_str(&value.to_string())
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<D, E> {
+            self.visit_str(&value.to_string())
+        }
+
+        fn visit_f64<E: de::Error>(self, value: f64) -> Result<D, E> {
+            self.visit_str(&value.to_string())
+        }
+    }
+
+    deserializer.deserialize_any(Decimal64Visitor(std::marker::PhantomData))
+}
+
+/// RFC 7951 encoding of `rust_decimal::Decimal` fields.
+///
+/// Generated code uses this module with `#[serde(with = "...")]` on
+/// `Decimal`, `Option<Decimal>` and `Vec<Decimal>` fields when `decimal64`
+/// values are represented as `rust_decimal::Decimal`. Values are written as
+/// strings in canonical form; strings and JSON numbers are accepted.
+///
+/// # Examples
+///
+/// ```
+/// use rust_decimal::Decimal;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Position {
+///     #[serde(with = "rustconf_runtime::decimal64::rust_decimal")]
+///     latitude: Decimal,
+///     #[serde(default, with = "rustconf_runtime::decimal64::rust_decimal")]
+///     altitude: Option<Decimal>,
+/// }
+///
+/// let position: Position =
+///     serde_json::from_str(r#"{"latitude": "52.520", "altitude": 34}"#).unwrap();
+/// assert_eq!(position.latitude, Decimal::new(5252, 2));
+///
+/// let json = serde_json::to_string(&position).unwrap();
+/// assert_eq!(json, r#"{"latitude":"52.52","altitude":"34.0"}"#);
+/// ```
+#[cfg(feature = "rust_decimal")]
+pub mod rust_decimal {
+    use std::fmt;
+
+    use ::rust_decimal::Decimal;
+    use serde::de::{self, Deserializer, SeqAccess, Visitor};
+    use serde::ser::{SerializeSeq, Serializer};
+
+    /// Fields that can be encoded with this module: `Decimal`, and `Option`
+    /// or `Vec` of it.
+    pub trait DecimalField: Sized {
+        #[doc(hidden)]
+        fn serialize_field<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+
+        #[doc(hidden)]
+        fn deserialize_field<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+    }
+
+    /// Serialize the value as a string in canonical form.
+    pub fn serialize<T: DecimalField, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.serialize_field(serializer)
+    }
+
+    /// Deserialize the value from a string or a JSON number.
+    pub fn deserialize<'de, T: DecimalField, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        T::deserialize_field(deserializer)
+    }
+
+    /// The canonical form of `value`, e.g. `2.5` or `3.0`.
+    fn canonical(value: &Decimal) -> String {
+        let value = value.normalize();
+        if value.scale() == 0 {
+            format!("{}.0", value)
+        } else {
+            value.to_string()
+        }
+    }
+
+    struct DecimalVisitor;
+
+    impl Visitor<'_> for DecimalVisitor {
+        type Value = Decimal;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a decimal64 string or number")
+        }
+
+        fn visit_str<E: de::Error>(self, text: &str) -> Result<Decimal, E> {
+            Decimal::from_str_exact(text.strip_prefix('+').unwrap_or(text))
+                .map_err(|_| E::invalid_value(de::Unexpected::Str(text), &self))
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<Decimal, E> {
+            Ok(Decimal::from(value))
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Decimal, E> {
+            Ok(Decimal::from(value))
+        }
+
+        fn visit_f64<E: de::Error>(self, value: f64) -> Result<Decimal, E> {
+            self.visit_str(&value.to_string())
+        }
+    }
+
+    impl DecimalField for Decimal {
+        fn serialize_field<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&canonical(self))
+        }
+
+        fn deserialize_field<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_any(DecimalVisitor)
+        }
+    }
+
+    impl DecimalField for Option<Decimal> {
+        fn serialize_field<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match self {
+                Some(value) => serializer.serialize_some(&canonical(value)),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        fn deserialize_field<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct OptionVisitor;
+
+            impl<'de> Visitor<'de> for OptionVisitor {
+                type Value = Option<Decimal>;
+
+                fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str("an optional decimal64")
+                }
+
+                fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+                    Ok(None)
+                }
+
+                fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+                    Ok(None)
+                }
+
+                fn visit_some<D: Deserializer<'de>>(
+                    self,
+                    deserializer: D,
+                ) -> Result<Self::Value, D::Error> {
+                    deserializer.deserialize_any(DecimalVisitor).map(Some)
+                }
+            }
+
+            deserializer.deserialize_option(OptionVisitor)
+        }
+    }
+
+    impl DecimalField for Vec<Decimal> {
+        fn serialize_field<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut seq = serializer.serialize_seq(Some(self.len()))?;
+            for value in self {
+                seq.serialize_element(&canonical(value))?;
+            }
+            seq.end()
+        }
+
+        fn deserialize_field<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct Element(Decimal);
+
+            impl<'de> de::Deserialize<'de> for Element {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserializer.deserialize_any(DecimalVisitor).map(Element)
+                }
+            }
+
+            struct VecVisitor;
+
+            impl<'de> Visitor<'de> for VecVisitor {
+                type Value = Vec<Decimal>;
+
+                fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str("a list of decimal64 values")
+                }
+
+                fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                    let mut values = Vec::new();
+                    while let Some(Element(value)) = seq.next_element()? {
+                        values.push(value);
+                    }
+                    Ok(values)
+                }
+            }
+
+            deserializer.deserialize_seq(VecVisitor)
+        }
+    }
+}
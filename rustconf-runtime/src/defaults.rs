@@ -0,0 +1,158 @@
+//! Organization-wide defaults for every client an application constructs.
+//!
+//! Applications that create many clients, one per device for instance, often
+//! want the same authentication, tracing and time budgets on all of them.
+//! Rather than repeating the setup at every construction site, build a
+//! [`ClientDefaults`] once at startup and [`install`](ClientDefaults::install)
+//! it: [`RestconfClient::new`](crate::RestconfClient::new) copies its
+//! interceptors and context into each client created afterwards. Nothing is
+//! installed until the application does so, and
+//! [`RestconfClient::without_defaults`](crate::RestconfClient::without_defaults)
+//! creates a client that ignores the installed defaults.
+
+use std::fmt;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use crate::context::RequestContext;
+use crate::deadline::OperationTimeout;
+use crate::transport::RequestInterceptor;
+
+static INSTALLED: OnceLock<ClientDefaults> = OnceLock::new();
+
+/// Interceptors and context that new clients start with.
+///
+/// Interceptors are shared, not copied, between the clients: an interceptor
+/// keeping state, such as a [`CookieJar`](crate::CookieJar), keeps one state
+/// for all of them. The interceptors of the defaults run before those added
+/// to a client, and values of the client's own context replace those of the
+/// defaults.
+///
+/// Policies the runtime has no type for, such as a retry policy read by a
+/// transport wrapper, are set with [`with_value`](Self::with_value) and read
+/// back from each request's context.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use rustconf_runtime::{
+///     ClientDefaults, HttpRequest, OperationTimeout, RequestInterceptor, RestconfClient, RpcError,
+/// };
+/// # use rustconf_runtime::{HttpResponse, HttpTransport};
+/// # struct MyTransport;
+/// # #[async_trait::async_trait]
+/// # impl HttpTransport for MyTransport {
+/// #     async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, RpcError> {
+/// #         todo!()
+/// #     }
+/// # }
+///
+/// struct TraceHeader;
+///
+/// impl RequestInterceptor for TraceHeader {
+///     fn intercept(&self, request: &mut HttpRequest) -> Result<(), RpcError> {
+///         request.headers.push(("X-Trace".to_string(), "on".to_string()));
+///         Ok(())
+///     }
+/// }
+///
+/// /// Read by the application's retrying transport.
+/// struct MaxAttempts(u32);
+///
+/// let defaults = ClientDefaults::new()
+///     .with_interceptor(TraceHeader)
+///     .with_timeout(Duration::from_secs(30))
+///     .with_value(MaxAttempts(3));
+/// assert_eq!(defaults.interceptors().len(), 1);
+/// assert_eq!(defaults.context().get::<MaxAttempts>().map(|m| m.0), Some(3));
+///
+/// defaults.install().expect("installed once at startup");
+///
+/// let client = RestconfClient::new("https://device.example.com", MyTransport)?;
+/// assert_eq!(
+///     client.context().get::<OperationTimeout>(),
+///     Some(&OperationTimeout(Duration::from_secs(30)))
+/// );
+/// let bare = RestconfClient::without_defaults("https://device.example.com", MyTransport)?;
+/// assert!(bare.context().is_empty());
+/// # Ok::<(), RpcError>(())
+/// ```
+#[derive(Clone, Default)]
+pub struct ClientDefaults {
+    interceptors: Vec<Arc<dyn RequestInterceptor>>,
+    context: RequestContext,
+}
+
+impl ClientDefaults {
+    /// Create defaults without interceptors or context.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an interceptor, run after those added before it.
+    pub fn with_interceptor(mut self, interceptor: impl RequestInterceptor + 'static) -> Self {
+        self.interceptors.push(Arc::new(interceptor));
+        self
+    }
+
+    /// Add an interceptor already shared with other code.
+    pub fn with_shared_interceptor(mut self, interceptor: Arc<dyn RequestInterceptor>) -> Self {
+        self.interceptors.push(interceptor);
+        self
+    }
+
+    /// Give each request `timeout` unless it has a deadline or an
+    /// [`OperationTimeout`] of its own.
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        self.with_value(OperationTimeout(timeout))
+    }
+
+    /// Add a value to the context of every client.
+    pub fn with_value<V: Send + Sync + 'static>(mut self, value: V) -> Self {
+        self.context.insert(value);
+        self
+    }
+
+    /// Add all values of `context` to the context of every client.
+    pub fn with_context(mut self, context: RequestContext) -> Self {
+        self.context.extend(context);
+        self
+    }
+
+    /// The interceptors, in the order they run.
+    pub fn interceptors(&self) -> &[Arc<dyn RequestInterceptor>] {
+        &self.interceptors
+    }
+
+    /// The context given to every client.
+    pub fn context(&self) -> &RequestContext {
+        &self.context
+    }
+
+    /// Make these the defaults of every client created from now on.
+    ///
+    /// The defaults can be installed once per process, which is safe from
+    /// any thread. Clients created before are not changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `self` if defaults are already installed.
+    pub fn install(self) -> Result<(), Self> {
+        INSTALLED.set(self)
+    }
+
+    /// The installed defaults, if any.
+    pub fn installed() -> Option<&'static ClientDefaults> {
+        INSTALLED.get()
+    }
+}
+
+impl fmt::Debug for ClientDefaults {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientDefaults")
+            .field("interceptors", &self.interceptors.len())
+            .field("context", &self.context)
+            .finish()
+    }
+}
@@ -0,0 +1,153 @@
+//! Type-erased RESTCONF clients.
+//!
+//! `RestconfClient<T>` is generic over its transport, and the parameter
+//! spreads to every type that stores a client. [`DynRestconfClient`] boxes the
+//! transport instead, so it can be kept in a struct field or a static without
+//! naming the transport, and [`RestconfClientApi`] is a dyn-compatible view of
+//! any client that generated code can take as `&dyn RestconfClientApi`.
+
+use async_trait::async_trait;
+use bytes::Bytes;
+
+use crate::error::RpcError;
+use crate::transport::{HttpMethod, HttpRequest, HttpResponse, HttpTransport, RestconfClient};
+use crate::upload::StreamingBody;
+
+/// A RESTCONF client whose transport type is erased.
+///
+/// The client is `Send + Sync + 'static`. Create one with
+/// [`RestconfClient::into_dyn`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use rustconf_runtime::{HttpTransport, HttpRequest, HttpResponse, RpcError};
+/// # use async_trait::async_trait;
+/// # struct MyTransport;
+/// # #[async_trait]
+/// # impl HttpTransport for MyTransport {
+/// #     async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, RpcError> {
+/// #         todo!()
+/// #     }
+/// # }
+/// use rustconf_runtime::{DynRestconfClient, RestconfClient};
+///
+/// /// Application state, without a transport type parameter.
+/// struct Inventory {
+///     device: DynRestconfClient,
+/// }
+///
+/// # fn example() -> Result<(), RpcError> {
+/// let inventory = Inventory {
+///     device: RestconfClient::new("https://device.example.com", MyTransport)?.into_dyn(),
+/// };
+/// # Ok(())
+/// # }
+///
+/// // Clients can be shared between tasks
+/// fn assert_shareable<T: Send + Sync + 'static>() {}
+/// assert_shareable::<DynRestconfClient>();
+/// ```
+pub type DynRestconfClient = RestconfClient<Box<dyn HttpTransport>>;
+
+/// The requests a RESTCONF client can make, as a dyn-compatible trait.
+///
+/// Implemented by every `RestconfClient`, including [`DynRestconfClient`].
+/// Bindings generated with `enable_dyn_client` take `&dyn RestconfClientApi`
+/// instead of a generic `&RestconfClient<T>`, so a `&RestconfClient<T>` of any
+/// transport can be passed to them.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use rustconf_runtime::{HttpTransport, HttpRequest, HttpResponse, RpcError};
+/// # use async_trait::async_trait;
+/// # struct MyTransport;
+/// # #[async_trait]
+/// # impl HttpTransport for MyTransport {
+/// #     async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, RpcError> {
+/// #         todo!()
+/// #     }
+/// # }
+/// use rustconf_runtime::{HttpMethod, RestconfClient, RestconfClientApi};
+///
+/// async fn hostname(client: &dyn RestconfClientApi) -> Result<String, RpcError> {
+///     let response = client
+///         .raw_request(HttpMethod::GET, "/restconf/data/system/hostname", None)
+///         .await?;
+///     Ok(String::from_utf8_lossy(&response.body).into_owned())
+/// }
+///
+/// # async fn example() -> Result<(), RpcError> {
+/// let client = RestconfClient::new("https://device.example.com", MyTransport)?;
+/// hostname(&client).await?;
+/// hostname(&client.into_dyn()).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[async_trait]
+pub trait RestconfClientApi: Send + Sync {
+    /// The base URL of the client; see [`RestconfClient::base_url`].
+    fn base_url(&self) -> &str;
+
+    /// Build the URL of a RESTCONF path; see [`RestconfClient::build_url`].
+    fn build_url(&self, path: &str) -> String;
+
+    /// Execute a request; see [`RestconfClient::execute`].
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, RpcError>;
+
+    /// Execute a request with a streaming body; see
+    /// [`RestconfClient::execute_streaming`].
+    async fn execute_streaming(
+        &self,
+        request: HttpRequest,
+        body: StreamingBody,
+    ) -> Result<HttpResponse, RpcError>;
+
+    /// Check whether a resource exists; see [`RestconfClient::exists`].
+    async fn exists(&self, path: &str) -> Result<bool, RpcError>;
+
+    /// Send a request to a RESTCONF path; see [`RestconfClient::raw_request`].
+    async fn raw_request(
+        &self,
+        method: HttpMethod,
+        path: &str,
+        body: Option<Bytes>,
+    ) -> Result<HttpResponse, RpcError>;
+}
+
+#[async_trait]
+impl<T: HttpTransport> RestconfClientApi for RestconfClient<T> {
+    fn base_url(&self) -> &str {
+        RestconfClient::base_url(self)
+    }
+
+    fn build_url(&self, path: &str) -> String {
+        RestconfClient::build_url(self, path)
+    }
+
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, RpcError> {
+        RestconfClient::execute(self, request).await
+    }
+
+    async fn execute_streaming(
+        &self,
+        request: HttpRequest,
+        body: StreamingBody,
+    ) -> Result<HttpResponse, RpcError> {
+        RestconfClient::execute_streaming(self, request, body).await
+    }
+
+    async fn exists(&self, path: &str) -> Result<bool, RpcError> {
+        RestconfClient::exists(self, path).await
+    }
+
+    async fn raw_request(
+        &self,
+        method: HttpMethod,
+        path: &str,
+        body: Option<Bytes>,
+    ) -> Result<HttpResponse, RpcError> {
+        RestconfClient::raw_request(self, method, path, body).await
+    }
+}
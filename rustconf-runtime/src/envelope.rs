@@ -0,0 +1,66 @@
+//! RPC input and output envelopes.
+//!
+//! RFC 8040 (section 3.6) carries RPC and action bodies wrapped in a single
+//! member named after the defining module: `{"example:input": {...}}` for
+//! requests and `{"example:output": {...}}` for responses.
+
+use serde::de::{DeserializeOwned, Error as _};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// Serialize `value` wrapped in the envelope member `member`.
+///
+/// # Errors
+///
+/// Returns an error if `value` cannot be serialized.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::wrap_envelope;
+///
+/// let body = wrap_envelope("example:input", &serde_json::json!({"delay": 5})).unwrap();
+/// assert_eq!(body, br#"{"example:input":{"delay":5}}"#);
+/// ```
+pub fn wrap_envelope<T: Serialize>(member: &str, value: &T) -> Result<Vec<u8>, serde_json::Error> {
+    let mut envelope = Map::new();
+    envelope.insert(member.to_string(), serde_json::to_value(value)?);
+    serde_json::to_vec(&envelope)
+}
+
+/// Deserialize the contents of the envelope member `member` from `body`.
+///
+/// The member is also accepted without its module qualifier (e.g. `output`
+/// for `example:output`), which some servers send.
+///
+/// # Errors
+///
+/// Returns an error if `body` is not a JSON object, does not contain the
+/// member, or the member's contents do not match `T`.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::unwrap_envelope;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Output {
+///     uptime: u64,
+/// }
+///
+/// let output: Output = unwrap_envelope("example:output", br#"{"example:output": {"uptime": 42}}"#).unwrap();
+/// assert_eq!(output.uptime, 42);
+/// ```
+pub fn unwrap_envelope<T: DeserializeOwned>(
+    member: &str,
+    body: &[u8],
+) -> Result<T, serde_json::Error> {
+    let mut envelope: Map<String, Value> = serde_json::from_slice(body)?;
+    let unqualified = member.split_once(':').map(|(_, name)| name);
+    let value = envelope
+        .remove(member)
+        .or_else(|| unqualified.and_then(|name| envelope.remove(name)))
+        .ok_or_else(|| serde_json::Error::custom(format!("missing member `{}`", member)))?;
+    T::deserialize(value)
+}
@@ -1,7 +1,11 @@
 //! Error types for RESTCONF operations.
 
+use crate::payload::Violation;
 use crate::transport::HttpResponse;
+use serde::Deserialize;
 use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
 
 /// Error type for RESTCONF RPC operations.
 ///
@@ -80,6 +84,64 @@ pub enum RpcError {
     /// This indicates that a requested operation is not supported by
     /// the current implementation or configuration.
     NotImplemented,
+
+    /// The [`Deadline`](crate::Deadline) of the operation passed before it
+    /// completed.
+    ///
+    /// Raised before sending a request once the budget is spent, and by the
+    /// transport adapters when a request runs past it.
+    DeadlineExceeded {
+        /// The total time budget of the operation
+        budget: Duration,
+        /// The time spent when the deadline was detected
+        elapsed: Duration,
+    },
+
+    /// A notification stream sent nothing, not even a keep-alive, for longer
+    /// than the idle timeout of its [`StreamMonitor`](crate::StreamMonitor).
+    ///
+    /// The connection is likely dead without having been closed; the
+    /// subscription should be re-established.
+    StreamStalled {
+        /// The time since the stream last sent anything
+        idle: Duration,
+        /// The idle timeout of the monitor
+        timeout: Duration,
+    },
+
+    /// Changes made with `with_rollback` or a `ChangePlan` failed and could not
+    /// all be undone.
+    ///
+    /// The device may be left partially modified; `failed_restores` lists the
+    /// resources (or plan steps) that could not be restored and why.
+    RollbackFailed {
+        /// The error that triggered the rollback
+        error: Box<RpcError>,
+        /// Resource paths or plan step names that could not be restored, with the restore error
+        failed_restores: Vec<(String, RpcError)>,
+    },
+
+    /// A step of a `ChangePlan` failed.
+    ///
+    /// Completed steps have been rolled back; see `RollbackFailed` for when
+    /// that did not succeed.
+    StepFailed {
+        /// Name of the step that failed
+        step: String,
+        /// The error the step returned
+        error: Box<RpcError>,
+    },
+
+    /// A batch of a `ChunkedWrite` failed.
+    ///
+    /// The batches before it were applied; resume the write with
+    /// `resume_from(written)` to skip the entries already written.
+    BatchFailed {
+        /// Entries of the new list written before the batch failed
+        written: usize,
+        /// The error of the batch
+        error: Box<RpcError>,
+    },
 }
 
 impl fmt::Display for RpcError {
@@ -97,12 +159,192 @@ impl fmt::Display for RpcError {
             }
             RpcError::ConfigurationError(msg) => write!(f, "Configuration error: {}", msg),
             RpcError::NotImplemented => write!(f, "Operation not implemented"),
+            RpcError::DeadlineExceeded { budget, elapsed } => write!(
+                f,
+                "Deadline exceeded after {} ms (budget {} ms)",
+                elapsed.as_millis(),
+                budget.as_millis()
+            ),
+            RpcError::StreamStalled { idle, timeout } => write!(
+                f,
+                "Notification stream silent for {} ms (idle timeout {} ms)",
+                idle.as_millis(),
+                timeout.as_millis()
+            ),
+            RpcError::RollbackFailed {
+                error,
+                failed_restores,
+            } => {
+                let paths: Vec<&str> = failed_restores
+                    .iter()
+                    .map(|(path, _)| path.as_str())
+                    .collect();
+                write!(f, "{} (rollback failed for: {})", error, paths.join(", "))
+            }
+            RpcError::StepFailed { step, error } => write!(f, "Step '{}' failed: {}", step, error),
+            RpcError::BatchFailed { written, error } => write!(
+                f,
+                "Batch failed after {} entries were written: {}",
+                written, error
+            ),
         }
     }
 }
 
 impl std::error::Error for RpcError {}
 
+impl RpcError {
+    /// Structured errors reported by the server in an `ietf-restconf:errors` body.
+    ///
+    /// Returns an empty list for errors other than `HttpError` (or a rollback,
+    /// failed plan step or failed batch caused by one), and when the message is
+    /// not a RESTCONF errors document.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustconf_runtime::RpcError;
+    ///
+    /// let error = RpcError::HttpError {
+    ///     status_code: 400,
+    ///     message: r#"{"ietf-restconf:errors": {"error": [{
+    ///         "error-type": "application",
+    ///         "error-tag": "invalid-value",
+    ///         "error-path": "/example:interfaces/interface[name='eth0']/mtu",
+    ///         "error-message": "MTU too large"
+    ///     }]}}"#
+    ///         .to_string(),
+    /// };
+    ///
+    /// let errors = error.restconf_errors();
+    /// assert_eq!(errors[0].error_tag, "invalid-value");
+    /// assert_eq!(
+    ///     errors[0].error_path.as_deref(),
+    ///     Some("/example:interfaces/interface[name='eth0']/mtu")
+    /// );
+    /// ```
+    pub fn restconf_errors(&self) -> Vec<RestconfError> {
+        match self {
+            RpcError::HttpError { message, .. } => RestconfError::parse_all(message.as_bytes()),
+            RpcError::RollbackFailed { error, .. }
+            | RpcError::StepFailed { error, .. }
+            | RpcError::BatchFailed { error, .. } => error.restconf_errors(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// The message of this error for users, looked up in `catalog`.
+    ///
+    /// The errors the server reported are looked up by their `error-tag`,
+    /// falling back to their `error-message`, and an `HttpError` without
+    /// them by its status. Errors of a rollback, plan step or batch are
+    /// those of the error that caused them. Other errors, and those the
+    /// catalog has no message for, keep their English message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustconf_runtime::error::MessageKey;
+    /// use rustconf_runtime::RpcError;
+    ///
+    /// let catalog = |key: &MessageKey<'_>| match key {
+    ///     MessageKey::Server(error) if error.error_tag == "invalid-value" => {
+    ///         Some("Valeur invalide".to_string())
+    ///     }
+    ///     MessageKey::Status(503) => Some("Appareil indisponible".to_string()),
+    ///     _ => None,
+    /// };
+    ///
+    /// let error = RpcError::HttpError {
+    ///     status_code: 400,
+    ///     message: r#"{"ietf-restconf:errors": {"error": [
+    ///         {"error-type": "application", "error-tag": "invalid-value"},
+    ///         {"error-type": "application", "error-tag": "too-big", "error-message": "too big"}
+    ///     ]}}"#
+    ///         .to_string(),
+    /// };
+    /// assert_eq!(error.user_message(&catalog), "Valeur invalide; too big");
+    ///
+    /// let error = RpcError::HttpError { status_code: 503, message: String::new() };
+    /// assert_eq!(error.user_message(&catalog), "Appareil indisponible");
+    /// ```
+    pub fn user_message(&self, catalog: &dyn MessageCatalog) -> String {
+        match self {
+            RpcError::HttpError { status_code, .. } => {
+                let errors = self.restconf_errors();
+                if errors.is_empty() {
+                    return catalog
+                        .message(&MessageKey::Status(*status_code))
+                        .unwrap_or_else(|| self.to_string());
+                }
+                let messages: Vec<String> = errors
+                    .iter()
+                    .map(|error| {
+                        catalog
+                            .message(&MessageKey::Server(error))
+                            .or_else(|| error.error_message.clone())
+                            .unwrap_or_else(|| error.error_tag.clone())
+                    })
+                    .collect();
+                messages.join("; ")
+            }
+            RpcError::RollbackFailed { error, .. }
+            | RpcError::StepFailed { error, .. }
+            | RpcError::BatchFailed { error, .. } => error.user_message(catalog),
+            _ => self.to_string(),
+        }
+    }
+}
+
+/// A single error from an `ietf-restconf:errors` response body (RFC 8040 section 7.1).
+///
+/// The `error_path` can be mapped back to the generated `schema::SchemaNode`
+/// with `SchemaNode::from_error_path`, to find the field that caused the error.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct RestconfError {
+    /// Layer the error occurred in: `transport`, `rpc`, `protocol` or `application`.
+    pub error_type: String,
+    /// Error condition, e.g. `invalid-value` or `data-missing`.
+    pub error_tag: String,
+    /// Application-specific error condition.
+    #[serde(default)]
+    pub error_app_tag: Option<String>,
+    /// Instance identifier of the data node the error refers to,
+    /// e.g. `/example:interfaces/interface[name='eth0']/mtu`.
+    #[serde(default)]
+    pub error_path: Option<String>,
+    /// Human-readable description of the error.
+    #[serde(default)]
+    pub error_message: Option<String>,
+    /// Additional server-specific information.
+    #[serde(default)]
+    pub error_info: Option<serde_json::Value>,
+}
+
+impl RestconfError {
+    /// Parse the errors of an `ietf-restconf:errors` JSON document.
+    ///
+    /// Returns an empty list if `body` is not such a document.
+    pub fn parse_all(body: &[u8]) -> Vec<RestconfError> {
+        #[derive(Deserialize)]
+        struct Document {
+            #[serde(rename = "ietf-restconf:errors", alias = "errors")]
+            errors: Errors,
+        }
+
+        #[derive(Deserialize)]
+        struct Errors {
+            #[serde(default)]
+            error: Vec<RestconfError>,
+        }
+
+        serde_json::from_slice::<Document>(body)
+            .map(|document| document.errors.error)
+            .unwrap_or_default()
+    }
+}
+
 /// Error type for server-side RESTCONF operations.
 ///
 /// This enum covers all error conditions that can occur during
@@ -284,6 +526,68 @@ impl fmt::Display for ServerError {
 
 impl std::error::Error for ServerError {}
 
+/// Localized, user-facing messages for errors.
+///
+/// The runtime formats its errors in English for logs. Applications showing
+/// errors to users can look the message up in a catalog instead, by the
+/// `error-tag` the server reported, the HTTP status, or the kind of a payload
+/// violation. Returning `None` keeps the English message.
+///
+/// Catalogs are used by [`DefaultErrorMapper::with_catalog`],
+/// [`ValidatingTransport::with_catalog`](crate::ValidatingTransport::with_catalog)
+/// and [`RpcError::user_message`]. Closures taking a [`MessageKey`] are
+/// catalogs.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use rustconf_runtime::error::{MessageCatalog, MessageKey};
+///
+/// struct Catalog {
+///     tags: HashMap<&'static str, &'static str>,
+/// }
+///
+/// impl MessageCatalog for Catalog {
+///     fn message(&self, key: &MessageKey<'_>) -> Option<String> {
+///         match key {
+///             MessageKey::Server(error) => {
+///                 self.tags.get(error.error_tag.as_str()).map(|m| m.to_string())
+///             }
+///             MessageKey::Status(404) => Some("Introuvable".to_string()),
+///             _ => None,
+///         }
+///     }
+/// }
+/// ```
+pub trait MessageCatalog: Send + Sync {
+    /// The message for `key`, or `None` to keep the English message.
+    fn message(&self, key: &MessageKey<'_>) -> Option<String>;
+}
+
+impl<F> MessageCatalog for F
+where
+    F: Fn(&MessageKey<'_>) -> Option<String> + Send + Sync,
+{
+    fn message(&self, key: &MessageKey<'_>) -> Option<String> {
+        self(key)
+    }
+}
+
+/// What a message is looked up for in a [`MessageCatalog`].
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum MessageKey<'a> {
+    /// An error from an `ietf-restconf:errors` body, identified by its
+    /// `error-tag` and `error-app-tag`.
+    Server(&'a RestconfError),
+    /// An HTTP error status without a RESTCONF errors body.
+    Status(u16),
+    /// A problem found checking a payload before sending it.
+    Violation(&'a Violation),
+}
+
 /// Trait for mapping HTTP responses to RpcError.
 ///
 /// This allows customization of error handling for different RESTCONF servers
@@ -297,10 +601,83 @@ pub trait ErrorMapper: Send + Sync {
 ///
 /// This mapper attempts to parse JSON error responses following the
 /// RESTCONF error format (RFC 8040), and falls back to generic HTTP errors.
-pub struct DefaultErrorMapper;
+/// With a [`MessageCatalog`], the message of the error is looked up in the
+/// catalog by its `error-tag` or HTTP status instead.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::error::MessageKey;
+/// use rustconf_runtime::{DefaultErrorMapper, ErrorMapper, HttpResponse};
+///
+/// let mapper = DefaultErrorMapper::new().with_catalog(|key: &MessageKey<'_>| match key {
+///     MessageKey::Server(error) if error.error_tag == "access-denied" => {
+///         Some("Zugriff verweigert".to_string())
+///     }
+///     _ => None,
+/// });
+///
+/// let response = HttpResponse {
+///     status_code: 403,
+///     headers: vec![],
+///     body: br#"{"ietf-restconf:errors": {"error": [{
+///         "error-type": "protocol",
+///         "error-tag": "access-denied",
+///         "error-message": "access denied"
+///     }]}}"#
+///         .to_vec(),
+/// };
+/// assert_eq!(mapper.map_error(&response).to_string(), "HTTP error 403: Zugriff verweigert");
+/// ```
+#[derive(Clone, Default)]
+pub struct DefaultErrorMapper {
+    catalog: Option<Arc<dyn MessageCatalog>>,
+}
+
+impl DefaultErrorMapper {
+    /// A mapper using the messages of the server.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Look up messages in `catalog`, falling back to the messages of the
+    /// server for the errors the catalog has no message for.
+    pub fn with_catalog(mut self, catalog: impl MessageCatalog + 'static) -> Self {
+        self.catalog = Some(Arc::new(catalog));
+        self
+    }
+
+    /// Look up messages in a catalog shared with other mappers or transports.
+    pub fn with_shared_catalog(mut self, catalog: Arc<dyn MessageCatalog>) -> Self {
+        self.catalog = Some(catalog);
+        self
+    }
+}
+
+impl fmt::Debug for DefaultErrorMapper {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DefaultErrorMapper")
+            .field("catalog", &self.catalog.is_some())
+            .finish()
+    }
+}
 
 impl ErrorMapper for DefaultErrorMapper {
     fn map_error(&self, response: &HttpResponse) -> RpcError {
+        if let Some(catalog) = &self.catalog {
+            let errors = RestconfError::parse_all(&response.body);
+            let message = match errors.first() {
+                Some(error) => catalog.message(&MessageKey::Server(error)),
+                None => catalog.message(&MessageKey::Status(response.status_code)),
+            };
+            if let Some(message) = message {
+                return RpcError::HttpError {
+                    status_code: response.status_code,
+                    message,
+                };
+            }
+        }
+
         // Try to parse as JSON error response
         if let Ok(body_str) = std::str::from_utf8(&response.body) {
             if let Ok(json) = serde_json::from_str::<serde_json::Value>(body_str) {
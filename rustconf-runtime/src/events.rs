@@ -0,0 +1,382 @@
+//! Decoding of RESTCONF notification streams.
+//!
+//! RFC 8040 delivers notifications as server-sent events whose `data` is an
+//! `ietf-restconf:notification` JSON document. Collectors stay subscribed for
+//! months to devices that are only semi-trusted, so a malicious or corrupted
+//! stream must not be able to exhaust their memory. [`EventStreamDecoder`]
+//! splits the bytes of a stream into [`Event`]s and decodes their data under
+//! [`DecodeLimits`]: events larger than the limit are rejected while they are
+//! still being buffered, and the nesting depth and string lengths of a payload
+//! are checked before anything is deserialized.
+//!
+//! [`EventStreamDecoder::feed_items`] also reports the keep-alive comments
+//! servers send on idle streams, for [`StreamMonitor`](crate::StreamMonitor)
+//! to tell a quiet stream from a dead one.
+
+use serde::de::DeserializeOwned;
+
+use crate::error::RpcError;
+
+/// Bounds on the notification payloads a client accepts.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::DecodeLimits;
+///
+/// let limits = DecodeLimits::new().with_max_depth(3).with_max_string_length(8);
+/// assert!(limits.check(br#"{"a": {"b": ["short"]}}"#).is_ok());
+/// assert!(limits.check(br#"{"a": {"b": [["deep"]]}}"#).is_err());
+/// assert!(limits.check(br#"{"a": "longer than eight"}"#).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodeLimits {
+    max_event_bytes: usize,
+    max_depth: usize,
+    max_string_length: usize,
+}
+
+impl DecodeLimits {
+    /// Limits of 1 MiB per event, 32 levels of nesting and 64 KiB per string,
+    /// well above what notifications of common YANG models need.
+    pub fn new() -> Self {
+        Self {
+            max_event_bytes: 1024 * 1024,
+            max_depth: 32,
+            max_string_length: 64 * 1024,
+        }
+    }
+
+    /// Set the maximum size of an event, including all its fields, in bytes.
+    pub fn with_max_event_bytes(mut self, max_event_bytes: usize) -> Self {
+        self.max_event_bytes = max_event_bytes;
+        self
+    }
+
+    /// Set the maximum nesting depth of objects and arrays. The outermost
+    /// object of a payload is at depth 1.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Set the maximum length of a string or member name in bytes, as
+    /// encoded in the payload.
+    pub fn with_max_string_length(mut self, max_string_length: usize) -> Self {
+        self.max_string_length = max_string_length;
+        self
+    }
+
+    /// The maximum size of an event in bytes.
+    pub fn max_event_bytes(&self) -> usize {
+        self.max_event_bytes
+    }
+
+    /// The maximum nesting depth of objects and arrays.
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// The maximum length of a string in bytes.
+    pub fn max_string_length(&self) -> usize {
+        self.max_string_length
+    }
+
+    /// Check a JSON payload against the limits without deserializing it.
+    ///
+    /// Only the structure is scanned; malformed JSON within the limits is
+    /// left for the deserializer to report.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RpcError::DeserializationError` if the payload is larger than
+    /// an event may be, nests too deep or has a string that is too long.
+    pub fn check(&self, json: &[u8]) -> Result<(), RpcError> {
+        if json.len() > self.max_event_bytes {
+            return Err(RpcError::DeserializationError(format!(
+                "Payload of {} bytes exceeds the limit of {} bytes",
+                json.len(),
+                self.max_event_bytes
+            )));
+        }
+
+        let mut depth = 0usize;
+        let mut string_start = None;
+        let mut escaped = false;
+        for (i, &byte) in json.iter().enumerate() {
+            if let Some(start) = string_start {
+                if i - start > self.max_string_length {
+                    return Err(RpcError::DeserializationError(format!(
+                        "Payload has a string longer than the limit of {} bytes",
+                        self.max_string_length
+                    )));
+                }
+                match byte {
+                    _ if escaped => escaped = false,
+                    b'\\' => escaped = true,
+                    b'"' => string_start = None,
+                    _ => {}
+                }
+                continue;
+            }
+            match byte {
+                b'"' => string_start = Some(i + 1),
+                b'{' | b'[' => {
+                    depth += 1;
+                    if depth > self.max_depth {
+                        return Err(RpcError::DeserializationError(format!(
+                            "Payload nests deeper than the limit of {} levels",
+                            self.max_depth
+                        )));
+                    }
+                }
+                b'}' | b']' => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Check a JSON payload against the limits, then deserialize it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RpcError::DeserializationError` if the payload exceeds the
+    /// limits or does not deserialize into `T`.
+    pub fn from_slice<T: DeserializeOwned>(&self, json: &[u8]) -> Result<T, RpcError> {
+        self.check(json)?;
+        serde_json::from_slice(json).map_err(|e| {
+            RpcError::DeserializationError(format!("Failed to decode notification: {}", e))
+        })
+    }
+}
+
+impl Default for DecodeLimits {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A server-sent event.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Event {
+    /// The `event` field, naming the event type, if any.
+    pub event: Option<String>,
+    /// The last `id` field, if any.
+    pub id: Option<String>,
+    /// The `data` fields, joined by newlines.
+    pub data: String,
+}
+
+/// An item of a `text/event-stream` body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StreamItem {
+    /// An event with data.
+    Event(Event),
+    /// A comment line, which servers send as a keep-alive on an idle stream,
+    /// with the text after the colon.
+    KeepAlive(String),
+}
+
+/// Incremental decoder of a `text/event-stream` body.
+///
+/// Chunks of the body are fed as they arrive, in whatever sizes the
+/// transport delivers them, and complete events are returned. Comments, such
+/// as keep-alives, and events without data are skipped by
+/// [`feed`](Self::feed); [`feed_items`](Self::feed_items) returns the
+/// comments too.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::{DecodeLimits, EventStreamDecoder};
+/// use serde_json::Value;
+///
+/// let mut decoder = EventStreamDecoder::new(DecodeLimits::new());
+/// assert!(decoder.feed(b": keep-alive\n\ndata: {\"ietf-restconf:notifi").unwrap().is_empty());
+///
+/// let events = decoder.feed(b"cation\": {\"eventTime\": \"2024-01-01T00:00:00Z\"}}\n\n").unwrap();
+/// assert_eq!(events.len(), 1);
+/// let notification: Value = decoder.decode(&events[0]).unwrap();
+/// assert_eq!(
+///     notification["ietf-restconf:notification"]["eventTime"],
+///     "2024-01-01T00:00:00Z"
+/// );
+///
+/// // Events growing past the limit are rejected before they are complete
+/// let mut decoder = EventStreamDecoder::new(DecodeLimits::new().with_max_event_bytes(16));
+/// assert!(decoder.feed(b"data: 0123456789abcdef").is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct EventStreamDecoder {
+    limits: DecodeLimits,
+    line: Vec<u8>,
+    after_cr: bool,
+    event: Option<String>,
+    id: Option<String>,
+    data: Vec<u8>,
+    has_data: bool,
+}
+
+impl EventStreamDecoder {
+    /// Create a decoder enforcing `limits`.
+    pub fn new(limits: DecodeLimits) -> Self {
+        Self {
+            limits,
+            line: Vec::new(),
+            after_cr: false,
+            event: None,
+            id: None,
+            data: Vec::new(),
+            has_data: false,
+        }
+    }
+
+    /// The limits enforced by this decoder.
+    pub fn limits(&self) -> &DecodeLimits {
+        &self.limits
+    }
+
+    /// Feed the next chunk of the stream, returning the events it completes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RpcError::DeserializationError` if an event grows past the
+    /// size limit or its data is not UTF-8. The partial event is discarded;
+    /// as the rest of the stream can no longer be framed reliably, the
+    /// subscription should be re-established.
+    pub fn feed(&mut self, chunk: &[u8]) -> Result<Vec<Event>, RpcError> {
+        let items = self.feed_items(chunk)?;
+        Ok(items
+            .into_iter()
+            .filter_map(|item| match item {
+                StreamItem::Event(event) => Some(event),
+                StreamItem::KeepAlive(_) => None,
+            })
+            .collect())
+    }
+
+    /// Feed the next chunk of the stream, returning the events and comments
+    /// it completes, in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`feed`](Self::feed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustconf_runtime::{DecodeLimits, EventStreamDecoder, StreamItem};
+    ///
+    /// let mut decoder = EventStreamDecoder::new(DecodeLimits::new());
+    /// let items = decoder.feed_items(b": keep-alive\n\ndata: {}\n\n").unwrap();
+    /// assert_eq!(items[0], StreamItem::KeepAlive("keep-alive".to_string()));
+    /// assert!(matches!(&items[1], StreamItem::Event(event) if event.data == "{}"));
+    /// ```
+    pub fn feed_items(&mut self, chunk: &[u8]) -> Result<Vec<StreamItem>, RpcError> {
+        let mut items = Vec::new();
+        for &byte in chunk {
+            let after_cr = std::mem::replace(&mut self.after_cr, byte == b'\r');
+            match byte {
+                // The LF of a CRLF pair ends no further line
+                b'\n' if after_cr => {}
+                b'\n' | b'\r' => {
+                    let line = std::mem::take(&mut self.line);
+                    match self.process_line(&line) {
+                        Ok(Some(item)) => items.push(item),
+                        Ok(None) => {}
+                        Err(e) => {
+                            self.reset();
+                            return Err(e);
+                        }
+                    }
+                }
+                _ => {
+                    self.line.push(byte);
+                    if self.line.len() + self.data.len() > self.limits.max_event_bytes {
+                        self.reset();
+                        return Err(RpcError::DeserializationError(format!(
+                            "Event exceeds the limit of {} bytes",
+                            self.limits.max_event_bytes
+                        )));
+                    }
+                }
+            }
+        }
+        Ok(items)
+    }
+
+    /// Decode the data of an event under the decoder's limits.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RpcError::DeserializationError` if the data exceeds the
+    /// limits or does not deserialize into `T`.
+    pub fn decode<T: DeserializeOwned>(&self, event: &Event) -> Result<T, RpcError> {
+        self.limits.from_slice(event.data.as_bytes())
+    }
+
+    /// Handle one line, returning the event it completes or the comment it
+    /// is, if any.
+    fn process_line(&mut self, line: &[u8]) -> Result<Option<StreamItem>, RpcError> {
+        if line.is_empty() {
+            return Ok(self.dispatch()?.map(StreamItem::Event));
+        }
+        if let Some(comment) = line.strip_prefix(b":") {
+            let comment = comment.strip_prefix(b" ").unwrap_or(comment);
+            return Ok(Some(StreamItem::KeepAlive(
+                String::from_utf8_lossy(comment).into_owned(),
+            )));
+        }
+
+        let (field, value) = match line.iter().position(|&b| b == b':') {
+            Some(i) => {
+                let value = &line[i + 1..];
+                (&line[..i], value.strip_prefix(b" ").unwrap_or(value))
+            }
+            None => (line, &[][..]),
+        };
+        match field {
+            b"data" => {
+                if self.has_data {
+                    self.data.push(b'\n');
+                }
+                self.data.extend_from_slice(value);
+                self.has_data = true;
+            }
+            b"event" => self.event = Some(utf8(value)?),
+            b"id" if !value.contains(&0) => self.id = Some(utf8(value)?),
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    /// Complete the pending event, which is skipped if it has no data.
+    fn dispatch(&mut self) -> Result<Option<Event>, RpcError> {
+        let event = self.event.take();
+        if !std::mem::take(&mut self.has_data) {
+            return Ok(None);
+        }
+        let data = String::from_utf8(std::mem::take(&mut self.data))
+            .map_err(|_| RpcError::DeserializationError("Event data is not UTF-8".to_string()))?;
+        Ok(Some(Event {
+            event,
+            // The last event id carries over to later events
+            id: self.id.clone(),
+            data,
+        }))
+    }
+
+    /// Discard the partial event after an error.
+    fn reset(&mut self) {
+        self.line.clear();
+        self.event = None;
+        self.data.clear();
+        self.has_data = false;
+    }
+}
+
+fn utf8(value: &[u8]) -> Result<String, RpcError> {
+    String::from_utf8(value.to_vec())
+        .map_err(|_| RpcError::DeserializationError("Event field is not UTF-8".to_string()))
+}
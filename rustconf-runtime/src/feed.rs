@@ -0,0 +1,290 @@
+//! Change events for list entries.
+//!
+//! A [`ChangeFeed`] tracks the entries of a YANG list by their keys and turns
+//! whatever the client learns about the list into [`Change`] events. Polling
+//! clients hand it complete snapshots and get the difference to the previous
+//! one; clients receiving notifications hand it single entries as they are
+//! created, changed or removed. Either way the consumer sees the same
+//! `Created`, `Updated` and `Deleted` events.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::error::RpcError;
+
+/// A change to one list entry.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change<T> {
+    /// An entry with a new key appeared.
+    Created(T),
+    /// The entry with this key changed.
+    Updated { previous: T, current: T },
+    /// The entry with this key disappeared.
+    Deleted(T),
+}
+
+impl<T> Change<T> {
+    /// The entry after the change, or the removed entry for `Deleted`.
+    pub fn entry(&self) -> &T {
+        match self {
+            Change::Created(entry) | Change::Deleted(entry) => entry,
+            Change::Updated { current, .. } => current,
+        }
+    }
+}
+
+/// A tracked entry with its serialized form, used to detect updates without
+/// requiring `PartialEq` on generated types.
+struct Tracked<T> {
+    entry: T,
+    value: Option<Value>,
+}
+
+impl<T: Serialize> Tracked<T> {
+    fn new(entry: T) -> Self {
+        let value = serde_json::to_value(&entry).ok();
+        Self { entry, value }
+    }
+
+    fn same_as(&self, other: &Self) -> bool {
+        self.value.is_some() && self.value == other.value
+    }
+}
+
+/// The known entries of a list, keyed by the list's YANG keys.
+///
+/// Entries are compared by their serialized form, so any generated list
+/// entry type can be tracked. Events are reported in the order of the new
+/// entries, followed by deletions in the order the entries were known.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::{Change, ChangeFeed};
+/// use serde::Serialize;
+///
+/// #[derive(Debug, Clone, PartialEq, Serialize)]
+/// struct Interface {
+///     name: String,
+///     mtu: u16,
+/// }
+///
+/// let interface = |name: &str, mtu| Interface { name: name.to_string(), mtu };
+/// let mut feed = ChangeFeed::new(|entry: &Interface| entry.name.clone());
+/// feed.prime([interface("eth0", 1500), interface("eth1", 1500)]);
+///
+/// // A polled snapshot
+/// let changes = feed.apply_snapshot([interface("eth0", 9000), interface("eth2", 1500)]);
+/// assert_eq!(
+///     changes,
+///     [
+///         Change::Updated { previous: interface("eth0", 1500), current: interface("eth0", 9000) },
+///         Change::Created(interface("eth2", 1500)),
+///         Change::Deleted(interface("eth1", 1500)),
+///     ]
+/// );
+///
+/// // Entries reported by notifications
+/// assert_eq!(feed.upsert(interface("eth2", 1500)), None);
+/// assert_eq!(feed.remove(&"eth2".to_string()), Some(Change::Deleted(interface("eth2", 1500))));
+/// ```
+pub struct ChangeFeed<'a, T, K> {
+    key: Box<dyn Fn(&T) -> K + 'a>,
+    order: Vec<K>,
+    entries: HashMap<K, Tracked<T>>,
+}
+
+impl<'a, T, K> ChangeFeed<'a, T, K>
+where
+    T: Clone + Serialize,
+    K: Eq + Hash + Clone,
+{
+    /// Create an empty feed identifying entries by `key`, typically the
+    /// entry's YANG key leaves.
+    pub fn new(key: impl Fn(&T) -> K + 'a) -> Self {
+        Self {
+            key: Box::new(key),
+            order: Vec::new(),
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Replace the known entries without reporting changes, for example with
+    /// the state read before subscribing to notifications.
+    pub fn prime(&mut self, entries: impl IntoIterator<Item = T>) {
+        let mut snapshot = self.snapshot();
+        for entry in entries {
+            snapshot.push(entry);
+        }
+        snapshot.commit();
+    }
+
+    /// The known entry with the given key.
+    pub fn get(&self, key: &K) -> Option<&T> {
+        self.entries.get(key).map(|tracked| &tracked.entry)
+    }
+
+    /// The known entries in order.
+    pub fn entries(&self) -> impl Iterator<Item = &T> {
+        self.order.iter().map(|key| &self.entries[key].entry)
+    }
+
+    /// Number of known entries.
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Whether no entries are known.
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Replace the known entries with a complete snapshot of the list and
+    /// return the changes since the previous one.
+    pub fn apply_snapshot(&mut self, entries: impl IntoIterator<Item = T>) -> Vec<Change<T>> {
+        let mut snapshot = self.snapshot();
+        for entry in entries {
+            snapshot.push(entry);
+        }
+        snapshot.finish()
+    }
+
+    /// Start a snapshot that is filled one entry at a time, such as from
+    /// [`for_each_list_entry`](crate::for_each_list_entry).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustconf_runtime::{for_each_list_entry, Change, ChangeFeed};
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    /// struct Route {
+    ///     prefix: String,
+    /// }
+    ///
+    /// let mut feed = ChangeFeed::new(|route: &Route| route.prefix.clone());
+    /// let body = br#"{"rib:route": [{"prefix": "10.0.0.0/8"}]}"#;
+    ///
+    /// let mut snapshot = feed.snapshot();
+    /// for_each_list_entry(&body[..], "route", |route: Route| snapshot.push(route)).unwrap();
+    /// let changes = snapshot.finish();
+    /// assert_eq!(changes, [Change::Created(Route { prefix: "10.0.0.0/8".to_string() })]);
+    /// ```
+    pub fn snapshot(&mut self) -> Snapshot<'_, 'a, T, K> {
+        Snapshot {
+            feed: self,
+            order: Vec::new(),
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Fetch a snapshot with `fetch`, typically a generated GET of the list,
+    /// and return the changes since the previous one.
+    ///
+    /// Call this on a timer to turn polling into change events.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `fetch`; the known entries are left unchanged.
+    pub async fn poll<F>(&mut self, fetch: F) -> Result<Vec<Change<T>>, RpcError>
+    where
+        F: Future<Output = Result<Vec<T>, RpcError>>,
+    {
+        Ok(self.apply_snapshot(fetch.await?))
+    }
+
+    /// Record an entry reported as created or changed, returning the change
+    /// unless the entry is already known with the same content.
+    pub fn upsert(&mut self, entry: T) -> Option<Change<T>> {
+        let key = (self.key)(&entry);
+        let tracked = Tracked::new(entry.clone());
+        match self.entries.insert(key.clone(), tracked) {
+            None => {
+                self.order.push(key);
+                Some(Change::Created(entry))
+            }
+            Some(previous) if previous.same_as(&self.entries[&key]) => None,
+            Some(previous) => Some(Change::Updated {
+                previous: previous.entry,
+                current: entry,
+            }),
+        }
+    }
+
+    /// Record the removal of the entry with the given key, returning the
+    /// change if the entry was known.
+    pub fn remove(&mut self, key: &K) -> Option<Change<T>> {
+        let removed = self.entries.remove(key)?;
+        self.order.retain(|known| known != key);
+        Some(Change::Deleted(removed.entry))
+    }
+}
+
+/// A snapshot of a list being collected for a [`ChangeFeed`].
+///
+/// Created by [`ChangeFeed::snapshot`]. Dropping it without calling
+/// [`Snapshot::finish`] leaves the feed unchanged.
+pub struct Snapshot<'f, 'a, T, K> {
+    feed: &'f mut ChangeFeed<'a, T, K>,
+    order: Vec<K>,
+    entries: HashMap<K, Tracked<T>>,
+}
+
+impl<T, K> Snapshot<'_, '_, T, K>
+where
+    T: Clone + Serialize,
+    K: Eq + Hash + Clone,
+{
+    /// Add an entry to the snapshot. A later entry with the same key replaces
+    /// an earlier one.
+    pub fn push(&mut self, entry: T) {
+        let key = (self.feed.key)(&entry);
+        if self
+            .entries
+            .insert(key.clone(), Tracked::new(entry))
+            .is_none()
+        {
+            self.order.push(key);
+        }
+    }
+
+    /// Make the snapshot the feed's known entries and return the changes
+    /// since the previous snapshot.
+    pub fn finish(self) -> Vec<Change<T>> {
+        let mut previous = std::mem::take(&mut self.feed.entries);
+        let previous_order = std::mem::take(&mut self.feed.order);
+
+        let mut changes = Vec::new();
+        for key in &self.order {
+            let current = &self.entries[key];
+            match previous.remove(key) {
+                None => changes.push(Change::Created(current.entry.clone())),
+                Some(known) if known.same_as(current) => {}
+                Some(known) => changes.push(Change::Updated {
+                    previous: known.entry,
+                    current: current.entry.clone(),
+                }),
+            }
+        }
+        for key in previous_order {
+            if let Some(known) = previous.remove(&key) {
+                changes.push(Change::Deleted(known.entry));
+            }
+        }
+
+        self.feed.order = self.order;
+        self.feed.entries = self.entries;
+        changes
+    }
+
+    /// Make the snapshot the feed's known entries without computing changes.
+    fn commit(self) {
+        self.feed.order = self.order;
+        self.feed.entries = self.entries;
+    }
+}
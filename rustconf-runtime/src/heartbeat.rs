@@ -0,0 +1,222 @@
+//! Heartbeat monitoring of notification streams.
+//!
+//! A subscription whose connection dies without being closed, behind a NAT
+//! that dropped its mapping or a device that rebooted, looks the same as a
+//! quiet one: no notifications arrive. Servers send keep-alive comments on
+//! idle streams to tell the two apart. [`StreamMonitor`] decodes a stream
+//! like [`EventStreamDecoder`], hands the keep-alives to the consumer as
+//! [`StreamItem::KeepAlive`], and reports the stream as stalled once nothing
+//! at all arrived for its idle timeout. [`StreamHealth`] counts what it saw,
+//! for collectors to alert on stalled subscriptions.
+//!
+//! The monitor does no I/O and starts no timers. Wait for the next chunk of
+//! the body for at most [`time_until_idle`](StreamMonitor::time_until_idle),
+//! then [`check`](StreamMonitor::check) the stream:
+//!
+//! ```rust,ignore
+//! use std::time::Duration;
+//! use rustconf_runtime::{DecodeLimits, EventStreamDecoder, StreamItem, StreamMonitor};
+//!
+//! let decoder = EventStreamDecoder::new(DecodeLimits::new());
+//! let mut monitor = StreamMonitor::new(decoder, Duration::from_secs(90));
+//! loop {
+//!     match tokio::time::timeout(monitor.time_until_idle(), body.next()).await {
+//!         Ok(Some(chunk)) => {
+//!             for item in monitor.feed(&chunk?)? {
+//!                 if let StreamItem::Event(event) = item {
+//!                     handle(monitor.decode(&event)?);
+//!                 }
+//!             }
+//!         }
+//!         Ok(None) => break,
+//!         // Resubscribe on RpcError::StreamStalled
+//!         Err(_) => monitor.check()?,
+//!     }
+//! }
+//! ```
+
+use std::time::{Duration, Instant};
+
+use serde::de::DeserializeOwned;
+
+use crate::error::RpcError;
+use crate::events::{Event, EventStreamDecoder, StreamItem};
+
+/// Counters and timings of a monitored stream, from
+/// [`StreamMonitor::health`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StreamHealth {
+    /// Events with data received.
+    pub events: u64,
+    /// Keep-alive comments received.
+    pub keep_alives: u64,
+    /// Bytes of the stream received.
+    pub bytes: u64,
+    /// Chunks the decoder rejected, such as events over the size limit.
+    pub errors: u64,
+    /// Time since the monitor was created.
+    pub uptime: Duration,
+    /// Time since anything was received, or since the monitor was created.
+    pub since_last_activity: Duration,
+    /// Time since the last event, if there was one.
+    pub since_last_event: Option<Duration>,
+    /// Whether nothing was received for the idle timeout.
+    pub stalled: bool,
+}
+
+/// Decoder of a notification stream that watches for it going silent.
+///
+/// Any bytes count as activity: events, keep-alive comments, and parts of
+/// either.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use rustconf_runtime::{DecodeLimits, EventStreamDecoder, RpcError, StreamItem, StreamMonitor};
+///
+/// let decoder = EventStreamDecoder::new(DecodeLimits::new());
+/// let mut monitor = StreamMonitor::new(decoder, Duration::from_secs(60));
+///
+/// let items = monitor.feed(b": ping\n\ndata: {}\n\n").unwrap();
+/// assert_eq!(items[0], StreamItem::KeepAlive("ping".to_string()));
+/// assert!(monitor.check().is_ok());
+///
+/// let health = monitor.health();
+/// assert_eq!((health.events, health.keep_alives, health.bytes), (1, 1, 18));
+/// assert!(!health.stalled);
+///
+/// // A stream that may not be idle at all is stalled right away
+/// let decoder = EventStreamDecoder::new(DecodeLimits::new());
+/// let monitor = StreamMonitor::new(decoder, Duration::ZERO);
+/// assert!(monitor.health().stalled);
+/// assert!(matches!(monitor.check(), Err(RpcError::StreamStalled { .. })));
+/// ```
+#[derive(Debug, Clone)]
+pub struct StreamMonitor {
+    decoder: EventStreamDecoder,
+    idle_timeout: Duration,
+    started: Instant,
+    last_activity: Instant,
+    last_event: Option<Instant>,
+    events: u64,
+    keep_alives: u64,
+    bytes: u64,
+    errors: u64,
+}
+
+impl StreamMonitor {
+    /// Monitor the stream decoded by `decoder`, which is stalled once
+    /// nothing arrived for `idle_timeout`.
+    ///
+    /// Pick a timeout of a few keep-alive intervals of the server, so a
+    /// single late keep-alive does not count as a stall.
+    pub fn new(decoder: EventStreamDecoder, idle_timeout: Duration) -> Self {
+        let now = Instant::now();
+        Self {
+            decoder,
+            idle_timeout,
+            started: now,
+            last_activity: now,
+            last_event: None,
+            events: 0,
+            keep_alives: 0,
+            bytes: 0,
+            errors: 0,
+        }
+    }
+
+    /// The idle timeout of the stream.
+    pub fn idle_timeout(&self) -> Duration {
+        self.idle_timeout
+    }
+
+    /// The decoder of the stream.
+    pub fn decoder(&self) -> &EventStreamDecoder {
+        &self.decoder
+    }
+
+    /// Feed the next chunk of the stream, returning the events and
+    /// keep-alives it completes, in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`EventStreamDecoder::feed`].
+    pub fn feed(&mut self, chunk: &[u8]) -> Result<Vec<StreamItem>, RpcError> {
+        if chunk.is_empty() {
+            return Ok(Vec::new());
+        }
+        let now = Instant::now();
+        self.last_activity = now;
+        self.bytes += chunk.len() as u64;
+
+        let items = self.decoder.feed_items(chunk).inspect_err(|_| {
+            self.errors += 1;
+        })?;
+        for item in &items {
+            match item {
+                StreamItem::Event(_) => {
+                    self.events += 1;
+                    self.last_event = Some(now);
+                }
+                StreamItem::KeepAlive(_) => self.keep_alives += 1,
+            }
+        }
+        Ok(items)
+    }
+
+    /// Decode the data of an event under the decoder's limits.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`EventStreamDecoder::decode`].
+    pub fn decode<T: DeserializeOwned>(&self, event: &Event) -> Result<T, RpcError> {
+        self.decoder.decode(event)
+    }
+
+    /// The instant the stream stalls unless something arrives before.
+    pub fn idle_deadline(&self) -> Instant {
+        self.last_activity + self.idle_timeout
+    }
+
+    /// The time left before the stream stalls, or zero once it has.
+    pub fn time_until_idle(&self) -> Duration {
+        self.idle_deadline()
+            .saturating_duration_since(Instant::now())
+    }
+
+    /// Whether nothing arrived for the idle timeout.
+    pub fn is_stalled(&self) -> bool {
+        Instant::now() >= self.idle_deadline()
+    }
+
+    /// Fail if the stream has stalled.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RpcError::StreamStalled` once nothing arrived for the idle
+    /// timeout.
+    pub fn check(&self) -> Result<(), RpcError> {
+        if self.is_stalled() {
+            return Err(RpcError::StreamStalled {
+                idle: self.last_activity.elapsed(),
+                timeout: self.idle_timeout,
+            });
+        }
+        Ok(())
+    }
+
+    /// The counters and timings of the stream so far.
+    pub fn health(&self) -> StreamHealth {
+        StreamHealth {
+            events: self.events,
+            keep_alives: self.keep_alives,
+            bytes: self.bytes,
+            errors: self.errors,
+            uptime: self.started.elapsed(),
+            since_last_activity: self.last_activity.elapsed(),
+            since_last_event: self.last_event.map(|instant| instant.elapsed()),
+            stalled: self.is_stalled(),
+        }
+    }
+}
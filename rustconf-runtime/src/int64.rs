@@ -0,0 +1,316 @@
+//! JSON encoding of 64-bit integers as strings.
+//!
+//! RFC 7951 section 6.1 encodes `int64` and `uint64` values as JSON strings
+//! (`"18446744073709551615"`) because many JSON implementations cannot
+//! represent them exactly as numbers. Generated code uses these modules with
+//! `#[serde(with = "...")]` on `u64` and `i64` fields, including `Option` and
+//! `Vec` fields of either type:
+//!
+//! - [`string`] writes and accepts only strings, as RFC 7951 requires.
+//! - [`lenient`] writes strings but also accepts numbers, for servers that
+//!   send either form.
+//!
+//! # Examples
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Counters {
+//!     #[serde(with = "rustconf_runtime::int64::lenient")]
+//!     in_octets: u64,
+//!     #[serde(default, with = "rustconf_runtime::int64::lenient")]
+//!     out_octets: Option<u64>,
+//! }
+//!
+//! let counters: Counters =
+//!     serde_json::from_str(r#"{"in_octets": "18446744073709551615", "out_octets": 42}"#).unwrap();
+//! assert_eq!(counters.in_octets, u64::MAX);
+//! assert_eq!(counters.out_octets, Some(42));
+//!
+//! let json = serde_json::to_string(&counters).unwrap();
+//! assert_eq!(json, r#"{"in_octets":"18446744073709551615","out_octets":"42"}"#);
+//! ```
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+use serde::ser::Serializer;
+use serde::Serialize;
+
+/// Fields that can be encoded with [`string`] and [`lenient`]: `u64`, `i64`,
+/// and `Option` or `Vec` of either.
+pub trait Int64Field: Sized {
+    #[doc(hidden)]
+    fn serialize_as_string<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+
+    #[doc(hidden)]
+    fn deserialize_from_json<'de, D: Deserializer<'de>>(
+        deserializer: D,
+        lenient: bool,
+    ) -> Result<Self, D::Error>;
+}
+
+/// String-only encoding, as required by RFC 7951.
+///
+/// # Examples
+///
+/// ```
+/// #[derive(serde::Deserialize)]
+/// struct Offset {
+///     #[serde(with = "rustconf_runtime::int64::string")]
+///     value: i64,
+/// }
+///
+/// assert!(serde_json::from_str::<Offset>(r#"{"value": "-5"}"#).is_ok());
+/// assert!(serde_json::from_str::<Offset>(r#"{"value": -5}"#).is_err());
+/// ```
+pub mod string {
+    use super::Int64Field;
+    use serde::{Deserializer, Serializer};
+
+    /// Serialize the value as a JSON string.
+    pub fn serialize<T: Int64Field, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.serialize_as_string(serializer)
+    }
+
+    /// Deserialize the value from a JSON string, rejecting numbers.
+    pub fn deserialize<'de, T: Int64Field, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        T::deserialize_from_json(deserializer, false)
+    }
+}
+
+/// String encoding that also accepts JSON numbers when deserializing.
+///
+/// # Examples
+///
+/// ```
+/// #[derive(serde::Deserialize)]
+/// struct Samples {
+///     #[serde(default, with = "rustconf_runtime::int64::lenient")]
+///     values: Vec<u64>,
+/// }
+///
+/// let samples: Samples = serde_json::from_str(r#"{"values": ["1", 2]}"#).unwrap();
+/// assert_eq!(samples.values, vec![1, 2]);
+/// assert!(serde_json::from_str::<Samples>(r#"{"values": [-1]}"#).is_err());
+/// ```
+pub mod lenient {
+    use super::Int64Field;
+    use serde::{Deserializer, Serializer};
+
+    /// Serialize the value as a JSON string.
+    pub fn serialize<T: Int64Field, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.serialize_as_string(serializer)
+    }
+
+    /// Deserialize the value from a JSON string or number.
+    pub fn deserialize<'de, T: Int64Field, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        T::deserialize_from_json(deserializer, true)
+    }
+}
+
+/// A 64-bit integer type.
+trait Scalar: Copy + fmt::Display + FromStr + TryFrom<u64> + TryFrom<i64> {
+    const NAME: &'static str;
+}
+
+impl Scalar for u64 {
+    const NAME: &'static str = "uint64";
+}
+
+impl Scalar for i64 {
+    const NAME: &'static str = "int64";
+}
+
+struct ScalarVisitor<T> {
+    lenient: bool,
+    marker: PhantomData<T>,
+}
+
+impl<T: Scalar> ScalarVisitor<T> {
+    fn new(lenient: bool) -> Self {
+        Self {
+            lenient,
+            marker: PhantomData,
+        }
+    }
+
+    fn number<E: de::Error, N>(&self, value: N) -> Result<T, E>
+    where
+        N: fmt::Display + Copy,
+        T: TryFrom<N>,
+    {
+        if !self.lenient {
+            return Err(E::custom(format!(
+                "expected {} encoded as a string, found the number {}",
+                T::NAME,
+                value
+            )));
+        }
+        T::try_from(value)
+            .map_err(|_| E::custom(format!("{} is out of range for {}", value, T::NAME)))
+    }
+}
+
+impl<T: Scalar> Visitor<'_> for ScalarVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        if self.lenient {
+            write!(formatter, "{} as a string or number", T::NAME)
+        } else {
+            write!(formatter, "{} encoded as a string", T::NAME)
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<T, E> {
+        value
+            .parse()
+            .map_err(|_| E::custom(format!("invalid {} value \"{}\"", T::NAME, value)))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<T, E> {
+        self.number(value)
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<T, E> {
+        self.number(value)
+    }
+}
+
+/// A scalar deserialized with the given leniency, for use inside `Option`
+/// and `Vec`.
+struct Seeded<T> {
+    lenient: bool,
+    marker: PhantomData<T>,
+}
+
+impl<'de, T: Scalar> de::DeserializeSeed<'de> for Seeded<T> {
+    type Value = T;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+        deserializer.deserialize_any(ScalarVisitor::new(self.lenient))
+    }
+}
+
+/// Serializes a scalar as a string.
+struct AsString<T>(T);
+
+impl<T: Scalar> Serialize for AsString<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.0)
+    }
+}
+
+impl<T: Scalar> Int64Field for T {
+    fn serialize_as_string<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        AsString(*self).serialize(serializer)
+    }
+
+    fn deserialize_from_json<'de, D: Deserializer<'de>>(
+        deserializer: D,
+        lenient: bool,
+    ) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ScalarVisitor::new(lenient))
+    }
+}
+
+impl<T: Scalar> Int64Field for Option<T> {
+    fn serialize_as_string<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Some(value) => serializer.serialize_some(&AsString(*value)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    fn deserialize_from_json<'de, D: Deserializer<'de>>(
+        deserializer: D,
+        lenient: bool,
+    ) -> Result<Self, D::Error> {
+        struct OptionVisitor<T> {
+            lenient: bool,
+            marker: PhantomData<T>,
+        }
+
+        impl<'de, T: Scalar> Visitor<'de> for OptionVisitor<T> {
+            type Value = Option<T>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "an optional {}", T::NAME)
+            }
+
+            fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+                Ok(None)
+            }
+
+            fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+                Ok(None)
+            }
+
+            fn visit_some<D: Deserializer<'de>>(
+                self,
+                deserializer: D,
+            ) -> Result<Self::Value, D::Error> {
+                T::deserialize_from_json(deserializer, self.lenient).map(Some)
+            }
+        }
+
+        deserializer.deserialize_option(OptionVisitor {
+            lenient,
+            marker: PhantomData,
+        })
+    }
+}
+
+impl<T: Scalar> Int64Field for Vec<T> {
+    fn serialize_as_string<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter().map(|value| AsString(*value)))
+    }
+
+    fn deserialize_from_json<'de, D: Deserializer<'de>>(
+        deserializer: D,
+        lenient: bool,
+    ) -> Result<Self, D::Error> {
+        struct SeqVisitor<T> {
+            lenient: bool,
+            marker: PhantomData<T>,
+        }
+
+        impl<'de, T: Scalar> Visitor<'de> for SeqVisitor<T> {
+            type Value = Vec<T>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "a list of {}", T::NAME)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut values = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(value) = seq.next_element_seed(Seeded {
+                    lenient: self.lenient,
+                    marker: PhantomData,
+                })? {
+                    values.push(value);
+                }
+                Ok(values)
+            }
+        }
+
+        deserializer.deserialize_seq(SeqVisitor {
+            lenient,
+            marker: PhantomData,
+        })
+    }
+}
@@ -5,13 +5,58 @@
 //!
 //! - HTTP transport abstraction (`HttpTransport` trait)
 //! - RESTCONF client implementation (`RestconfClient`)
-//! - Error types (`RpcError`)
+//! - Type-erased clients for storing without generics (`DynRestconfClient`, `RestconfClientApi`)
+//! - Base URL parsing with IPv6 literals, zone ids and userinfo (`BaseUrl`), and
+//!   percent-encoding of path segments and keys (`percent_encode`)
+//! - Error types (`RpcError`, `RestconfError`) and a `MessageCatalog` hook for localized messages
+//! - RPC input and output envelopes (`wrap_envelope`, `unwrap_envelope`)
+//! - Resource capability discovery (`ResourceCapabilities`)
+//! - Response status and headers alongside typed bodies (`ResponseEnvelope`)
+//! - Deduplication of concurrent identical GETs (`CoalescingTransport`)
+//! - Rollback of failed multi-resource changes (`with_rollback`)
+//! - Dependency-ordered configuration pushes with rollback (`ChangePlan`)
+//! - RFC 7951 string encoding of 64-bit integers (`int64`)
+//! - Sets of named bits of YANG `bits` types, encoded as space-separated names (`Bits`)
+//! - Fixed-point `decimal64` values, encoded as RFC 7951 strings (`Decimal64`)
+//! - `date-and-time` values as `chrono` date-times in RFC 3339 form (`date_and_time`, feature `chrono`)
+//! - Rendering and parsing list entry keys as URL fragments (`list_key`)
+//! - Reading and writing generated data by schema-checked paths (`data_path`)
+//! - Readable rendering of generated types with units and defaults (`Pretty`)
+//! - Entry-by-entry deserialization of large lists (`for_each_list_entry`)
+//! - Content of `anydata` and `anyxml` nodes, decoded into the generated types of known modules (`AnyData`, `AnyDataRegistry`)
+//! - Notification stream decoding with size, depth and string length limits (`EventStreamDecoder`)
+//! - Keep-alives, idle timeouts and health counters of notification streams (`StreamMonitor`, `StreamHealth`)
+//! - Created/updated/deleted events for list entries from polls or notifications (`ChangeFeed`)
+//! - Typed per-request metadata for interceptors and policy layers (`RequestContext`)
+//! - Total time budgets for operations and their retries (`Deadline`, `OperationTimeout`)
+//! - Session cookie and CSRF token interceptors (`CookieJar`, `CsrfToken`)
+//! - Redacted secrets and constant-time comparison (`Secret`, `constant_time_eq`)
+//! - Client certificates, custom roots and key pinning for the adapters (`TlsConfig`)
+//! - Static host addresses, IPv4 preference and connect timeouts for the adapters (`ConnectConfig`)
+//! - RFC 7952 metadata annotations kept through (de)serialization (`Annotated`, `Metadata`)
+//! - Checking request bodies against the generated schema before sending (`ValidatingTransport`)
+//! - Retrying idempotent requests, with idempotency keys for creates (`RetryPolicy`, `RetryingTransport`, feature `retry`)
+//! - Loading documents saved by ygot or pyangbind into generated types (`PayloadSchema::load_tolerant`)
+//! - Origin metadata of the NMDA operational datastore (`origin`)
+//! - Checking the server's YANG library against the generated module revisions (`YangLibrary`)
+//! - Streaming request bodies with progress, e.g. firmware uploads (`StreamingBody`)
+//! - Scripted transports for testing retry, reconnect and rollback logic (`testing::ScenarioTransport`, feature `testing`)
 //! - Optional transport adapters for reqwest and hyper (feature-gated)
+//! - NETCONF-over-SSH transport for devices without RESTCONF (`netconf`, feature `netconf`)
+//! - Converting XML responses from servers that ignore `Accept` (`xml`, feature `xml`)
+//! - RFC 8071 call-home listener binding clients to device-initiated connections (`call_home`, feature `hyper`)
 //!
 //! # Features
 //!
 //! - `reqwest`: Enable the reqwest-based HTTP transport adapter
-//! - `hyper`: Enable the hyper-based HTTP transport adapter
+//! - `hyper`: Enable the hyper-based HTTP transport adapter and call-home listener
+//! - `uds`: Let the hyper adapter connect to a unix domain socket
+//! - `netconf`: Enable the NETCONF transport
+//! - `upload`: Read streaming request bodies from a tokio `AsyncRead` (`ReaderSource`)
+//! - `retry`: Retry failed requests with backoff (`RetryingTransport`)
+//! - `testing`: Play scripted requests and responses in tests (`testing`)
+//! - `rust_decimal`: Encode `rust_decimal::Decimal` fields as `decimal64` values (`decimal64::rust_decimal`)
+//! - `chrono`: Encode `chrono::DateTime<FixedOffset>` fields as `date-and-time` values (`date_and_time`)
 //!
 //! # Example
 //!
@@ -32,15 +77,96 @@
 //! ```
 
 pub mod adapters;
+pub mod anydata;
+pub mod base_url;
+pub mod bits;
+pub mod capabilities;
+pub mod chunked;
+pub mod coalescing;
+pub mod connect;
+pub mod context;
+pub mod data_path;
+#[cfg(feature = "chrono")]
+pub mod date_and_time;
+pub mod deadline;
+pub mod decimal64;
+pub mod defaults;
+pub mod dyn_client;
+pub mod envelope;
 pub mod error;
+pub mod events;
+pub mod feed;
+pub mod heartbeat;
+pub mod int64;
+pub mod list_key;
+pub mod metadata;
+#[cfg(feature = "netconf")]
+pub mod netconf;
+pub mod origin;
+pub mod payload;
+pub mod plan;
+pub mod pretty;
+pub mod proxy;
+pub mod response;
+pub mod retry;
+pub mod rollback;
+pub mod secret;
+pub mod session;
+pub mod streaming;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod tls;
+pub mod tolerant;
 pub mod transport;
+pub mod upload;
+#[cfg(feature = "xml")]
+pub mod xml;
+pub mod yang_library;
 
 // Re-export commonly used types
-pub use error::{DefaultErrorMapper, ErrorMapper, RpcError, ServerError};
+pub use anydata::{AnyData, AnyDataMember, AnyDataRegistry};
+pub use base_url::{percent_encode, BaseUrl};
+pub use bits::Bits;
+pub use bytes::Bytes;
+pub use capabilities::ResourceCapabilities;
+pub use chunked::{ChunkStrategy, ChunkedWrite, WriteProgress};
+pub use coalescing::CoalescingTransport;
+pub use connect::ConnectConfig;
+pub use context::RequestContext;
+pub use data_path::DataPathError;
+pub use deadline::{Deadline, OperationTimeout};
+pub use decimal64::Decimal64;
+pub use defaults::ClientDefaults;
+pub use dyn_client::{DynRestconfClient, RestconfClientApi};
+pub use envelope::{unwrap_envelope, wrap_envelope};
+pub use error::{
+    DefaultErrorMapper, ErrorMapper, MessageCatalog, RestconfError, RpcError, ServerError,
+};
+pub use events::{DecodeLimits, Event, EventStreamDecoder, StreamItem};
+pub use feed::{Change, ChangeFeed};
+pub use heartbeat::{StreamHealth, StreamMonitor};
+pub use list_key::ListKeyError;
+pub use metadata::{Annotated, Metadata};
+pub use origin::{Origin, OriginTable, WithOrigin};
+pub use payload::{PayloadSchema, ValidatingTransport};
+pub use plan::{ChangePlan, PlanStep};
+pub use pretty::{Pretty, PrettyPrinter};
+pub use proxy::ProxyConfig;
+pub use response::ResponseEnvelope;
+#[cfg(feature = "retry")]
+pub use retry::RetryingTransport;
+pub use retry::{IdempotencyKey, RetryPolicy};
+pub use rollback::{with_rollback, Transaction};
+pub use secret::{constant_time_eq, Secret};
+pub use session::{CookieJar, CsrfToken};
+pub use streaming::{for_each_list_entry, ListEntries};
+pub use tls::{spki_sha256, ClientIdentity, TlsConfig};
+pub use tolerant::TolerantLoad;
 pub use transport::{
     HttpMethod, HttpRequest, HttpResponse, HttpTransport, RequestInterceptor, RestconfClient,
     ServerRequest, ServerResponse, ServerTransport,
 };
+pub use upload::{BodySource, StreamingBody, UploadProgress};
 
 // Re-export adapter modules when features are enabled
 #[cfg(feature = "reqwest")]
@@ -48,3 +174,6 @@ pub use adapters::reqwest_adapter;
 
 #[cfg(feature = "hyper")]
 pub use adapters::hyper_adapter;
+
+#[cfg(feature = "hyper")]
+pub use adapters::call_home;
@@ -0,0 +1,145 @@
+//! List instance fragments of list entry keys.
+//!
+//! RFC 8040 section 3.5.3 identifies a list entry in a URL by the list name
+//! and its key values, each percent-encoded and separated by commas:
+//! `route=10.0.0.0%2F8,192.0.2.1`. Generated key structs such as `RouteKey`
+//! display as this fragment and parse from it with these functions, for
+//! logging and for building URLs by hand.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::fmt;
+//!
+//! use rustconf_runtime::list_key::{self, ListKeyError};
+//!
+//! struct RouteKey {
+//!     prefix: String,
+//!     metric: u32,
+//! }
+//!
+//! impl fmt::Display for RouteKey {
+//!     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+//!         let values = [list_key::key_value(&self.prefix), list_key::key_value(&self.metric)];
+//!         list_key::write_fragment(f, "route", &values)
+//!     }
+//! }
+//!
+//! impl std::str::FromStr for RouteKey {
+//!     type Err = ListKeyError;
+//!
+//!     fn from_str(fragment: &str) -> Result<Self, Self::Err> {
+//!         let values = list_key::parse_fragment(fragment, "route", 2)?;
+//!         Ok(Self {
+//!             prefix: list_key::parse_key_value(&values[0])?,
+//!             metric: list_key::parse_key_value(&values[1])?,
+//!         })
+//!     }
+//! }
+//!
+//! let key = RouteKey { prefix: "10.0.0.0/8".to_string(), metric: 20 };
+//! assert_eq!(key.to_string(), "route=10.0.0.0%2F8,20");
+//!
+//! let parsed: RouteKey = "route=192.0.2.0%2F24,5".parse()?;
+//! assert_eq!(parsed.prefix, "192.0.2.0/24");
+//! assert_eq!(parsed.metric, 5);
+//! # Ok::<(), ListKeyError>(())
+//! ```
+
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::base_url::{percent_decode, percent_encode};
+
+/// Error parsing a list instance fragment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListKeyError {
+    message: String,
+}
+
+impl ListKeyError {
+    fn new(message: String) -> Self {
+        Self { message }
+    }
+}
+
+impl fmt::Display for ListKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid list instance: {}", self.message)
+    }
+}
+
+impl std::error::Error for ListKeyError {}
+
+/// The string form of a key value: strings as they are, other values as
+/// their JSON text, such as `20` or `true`.
+pub fn key_value<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(Value::String(text)) => text,
+        Ok(value) => value.to_string(),
+        Err(_) => String::new(),
+    }
+}
+
+/// Parse the string form of a key value, as written by [`key_value`].
+///
+/// # Errors
+///
+/// Returns an error if `text` is not a valid value of `T`.
+pub fn parse_key_value<T: DeserializeOwned>(text: &str) -> Result<T, ListKeyError> {
+    serde_json::from_value(Value::String(text.to_string()))
+        .or_else(|_| serde_json::from_str(text))
+        .map_err(|e| ListKeyError::new(format!("invalid key value '{}': {}", text, e)))
+}
+
+/// Write the fragment `list=value,...`, percent-encoding each value.
+pub fn write_fragment(f: &mut fmt::Formatter<'_>, list: &str, values: &[String]) -> fmt::Result {
+    write!(f, "{}=", list)?;
+    for (index, value) in values.iter().enumerate() {
+        if index > 0 {
+            f.write_str(",")?;
+        }
+        f.write_str(&percent_encode(value))?;
+    }
+    Ok(())
+}
+
+/// Split the fragment of an entry of `list` into its `count` decoded key
+/// values.
+///
+/// The list name may be qualified with its module name, as in
+/// `ietf-interfaces:interface=eth0`.
+///
+/// # Errors
+///
+/// Returns an error if the fragment names another list or does not have
+/// `count` key values.
+pub fn parse_fragment(
+    fragment: &str,
+    list: &str,
+    count: usize,
+) -> Result<Vec<String>, ListKeyError> {
+    let (name, values) = fragment
+        .split_once('=')
+        .ok_or_else(|| ListKeyError::new(format!("'{}' has no key values", fragment)))?;
+    let unqualified = |name: &str| name.rsplit(':').next().unwrap_or(name).to_string();
+    if name != list && unqualified(name) != unqualified(list) {
+        return Err(ListKeyError::new(format!(
+            "'{}' is not an entry of list '{}'",
+            fragment, list
+        )));
+    }
+    let values: Vec<String> = values.split(',').map(percent_decode).collect();
+    if values.len() != count {
+        return Err(ListKeyError::new(format!(
+            "'{}' has {} key values, expected {}",
+            fragment,
+            values.len(),
+            count
+        )));
+    }
+    Ok(values)
+}
@@ -0,0 +1,295 @@
+//! YANG metadata annotations in JSON payloads (RFC 7952).
+//!
+//! Servers attach metadata such as origins, timestamps or lock owners to data
+//! nodes with `@`-prefixed members: `"@"` inside a container or list entry
+//! annotates that node, `"@name"` next to a leaf annotates the leaf, and an
+//! array in `"@name"` next to a leaf-list annotates its values one by one.
+//! The generated types have no fields for these members, so [`Annotated`]
+//! moves them into a [`Metadata`] table when deserializing and puts them
+//! back when serializing.
+
+use std::collections::BTreeMap;
+
+use serde::de::{DeserializeOwned, Error as _};
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+
+/// The annotations of each node of a JSON document.
+///
+/// Nodes are identified by JSON Pointers (RFC 6901) relative to the
+/// annotated value: `""` is the value itself, members are addressed by
+/// their JSON name and list entries and leaf-list values by their index,
+/// which is also their index in the deserialized `Vec`. The annotations of
+/// a node map each annotation name, such as `ietf-origin:origin`, to its
+/// value.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::Metadata;
+/// use serde_json::json;
+///
+/// let mut document = json!({
+///     "@": {"example:last-modified": "2024-05-01T10:00:00Z"},
+///     "hostname": "edge-1",
+///     "@hostname": {"example:locked-by": "alice"},
+///     "dns": ["192.0.2.53", "192.0.2.54"],
+///     "@dns": [null, {"example:reachable": false}]
+/// });
+/// let metadata = Metadata::extract(&mut document);
+///
+/// assert_eq!(document, json!({"hostname": "edge-1", "dns": ["192.0.2.53", "192.0.2.54"]}));
+/// assert_eq!(
+///     metadata.annotation("/hostname", "example:locked-by"),
+///     Some(&json!("alice"))
+/// );
+/// assert_eq!(
+///     metadata.annotation("/dns/1", "example:reachable"),
+///     Some(&json!(false))
+/// );
+///
+/// // Applying the table restores the annotations
+/// metadata.apply(&mut document);
+/// assert_eq!(document["@hostname"], json!({"example:locked-by": "alice"}));
+/// assert_eq!(document["@dns"], json!([null, {"example:reachable": false}]));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Metadata {
+    nodes: BTreeMap<String, Map<String, Value>>,
+}
+
+impl Metadata {
+    /// Create an empty table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remove the annotation members from `document`, returning them.
+    pub fn extract(document: &mut Value) -> Self {
+        let mut metadata = Self::default();
+        if let Value::Object(members) = document {
+            metadata.extract_object(members, "");
+        }
+        metadata
+    }
+
+    /// Collect the annotations of `document` without changing it.
+    pub fn collect(document: &Value) -> Self {
+        Self::extract(&mut document.clone())
+    }
+
+    /// Add the annotations to the nodes of `document` they belong to.
+    ///
+    /// Annotations of nodes that `document` does not contain are skipped.
+    pub fn apply(&self, document: &mut Value) {
+        for (pointer, annotations) in &self.nodes {
+            apply_node(document, pointer, annotations);
+        }
+    }
+
+    /// The annotations of the node at `pointer`.
+    pub fn get(&self, pointer: &str) -> Option<&Map<String, Value>> {
+        self.nodes.get(pointer)
+    }
+
+    /// The value of the annotation `name` of the node at `pointer`.
+    pub fn annotation(&self, pointer: &str, name: &str) -> Option<&Value> {
+        self.nodes.get(pointer)?.get(name)
+    }
+
+    /// Set the annotation `name` of the node at `pointer`.
+    pub fn insert(&mut self, pointer: impl Into<String>, name: impl Into<String>, value: Value) {
+        self.nodes
+            .entry(pointer.into())
+            .or_default()
+            .insert(name.into(), value);
+    }
+
+    /// Remove all annotations of the node at `pointer`.
+    pub fn remove(&mut self, pointer: &str) -> Option<Map<String, Value>> {
+        self.nodes.remove(pointer)
+    }
+
+    /// All annotated nodes and their annotations, in pointer order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Map<String, Value>)> {
+        self.nodes
+            .iter()
+            .map(|(pointer, annotations)| (pointer.as_str(), annotations))
+    }
+
+    /// Number of annotated nodes.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Check whether no node is annotated.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    fn extract_object(&mut self, members: &mut Map<String, Value>, pointer: &str) {
+        let names: Vec<String> = members
+            .keys()
+            .filter(|name| name.starts_with('@'))
+            .cloned()
+            .collect();
+        for name in names {
+            let Some(annotations) = members.remove(&name) else {
+                continue;
+            };
+            let node = match &name[1..] {
+                "" => pointer.to_string(),
+                leaf => format!("{}/{}", pointer, escape(leaf)),
+            };
+            match annotations {
+                Value::Object(annotations) => self.merge(node, annotations),
+                // Leaf-list annotations are aligned with the values
+                Value::Array(entries) => {
+                    for (index, annotations) in entries.into_iter().enumerate() {
+                        if let Value::Object(annotations) = annotations {
+                            self.merge(format!("{}/{}", node, index), annotations);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        for (name, value) in members.iter_mut() {
+            let child = format!("{}/{}", pointer, escape(name));
+            match value {
+                Value::Object(members) => self.extract_object(members, &child),
+                Value::Array(entries) => {
+                    for (index, entry) in entries.iter_mut().enumerate() {
+                        if let Value::Object(members) = entry {
+                            self.extract_object(members, &format!("{}/{}", child, index));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn merge(&mut self, pointer: String, annotations: Map<String, Value>) {
+        self.nodes.entry(pointer).or_default().extend(annotations);
+    }
+}
+
+/// Insert the annotations of the node at `pointer` into `document`.
+fn apply_node(document: &mut Value, pointer: &str, annotations: &Map<String, Value>) {
+    // Containers and list entries carry their own `@` member
+    if let Some(Value::Object(members)) = document.pointer_mut(pointer) {
+        members.insert("@".to_string(), Value::Object(annotations.clone()));
+        return;
+    }
+
+    let Some((parent, last)) = pointer.rsplit_once('/') else {
+        return;
+    };
+    match document.pointer_mut(parent) {
+        Some(Value::Object(members)) => {
+            let name = unescape(last);
+            if members.contains_key(&name) {
+                members.insert(format!("@{}", name), Value::Object(annotations.clone()));
+            }
+        }
+        // A value of a leaf-list, annotated in an array next to the leaf-list
+        Some(Value::Array(values)) => {
+            let length = values.len();
+            let Ok(index) = last.parse::<usize>() else {
+                return;
+            };
+            let Some((grandparent, name)) = parent.rsplit_once('/') else {
+                return;
+            };
+            if index >= length {
+                return;
+            }
+            let Some(Value::Object(members)) = document.pointer_mut(grandparent) else {
+                return;
+            };
+            let entry = members
+                .entry(format!("@{}", unescape(name)))
+                .or_insert_with(|| Value::Array(vec![Value::Null; length]));
+            if let Value::Array(entries) = entry {
+                entries.resize(length, Value::Null);
+                entries[index] = Value::Object(annotations.clone());
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Escape a member name for use as a JSON Pointer segment.
+fn escape(name: &str) -> String {
+    name.replace('~', "~0").replace('/', "~1")
+}
+
+fn unescape(segment: &str) -> String {
+    segment.replace("~1", "/").replace("~0", "~")
+}
+
+/// A value together with the metadata annotations of its nodes.
+///
+/// Deserializing moves the `@` members into [`metadata`](Self::metadata)
+/// before deserializing the value, and serializing writes them back, so
+/// annotations survive a read-modify-write cycle.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::{unwrap_envelope, wrap_envelope, Annotated};
+///
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct System {
+///     hostname: String,
+/// }
+///
+/// let body = br#"{"example:system": {"hostname": "edge-1", "@hostname": {"example:locked-by": "alice"}}}"#;
+/// let system: Annotated<System> = unwrap_envelope("example:system", body)?;
+/// assert_eq!(system.value.hostname, "edge-1");
+/// assert_eq!(
+///     system.metadata.annotation("/hostname", "example:locked-by"),
+///     Some(&serde_json::json!("alice"))
+/// );
+///
+/// let written = wrap_envelope("example:system", &system)?;
+/// assert!(String::from_utf8(written)?.contains("\"@hostname\""));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Annotated<T> {
+    /// The value, without annotations.
+    pub value: T,
+    /// Annotations of the nodes of the value.
+    pub metadata: Metadata,
+}
+
+impl<T> Annotated<T> {
+    /// Wrap a value without annotations.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            metadata: Metadata::default(),
+        }
+    }
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for Annotated<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut document = Value::deserialize(deserializer)?;
+        let metadata = Metadata::extract(&mut document);
+        let value = T::deserialize(document).map_err(D::Error::custom)?;
+        Ok(Self { value, metadata })
+    }
+}
+
+impl<T: Serialize> Serialize for Annotated<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut document = serde_json::to_value(&self.value).map_err(S::Error::custom)?;
+        self.metadata.apply(&mut document);
+        document.serialize(serializer)
+    }
+}
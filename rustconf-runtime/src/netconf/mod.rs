@@ -0,0 +1,814 @@
+//! NETCONF transport for devices without RESTCONF.
+//!
+//! [`NetconfTransport`] implements [`HttpTransport`] by translating the
+//! RESTCONF requests made by generated code into NETCONF operations, so the
+//! same generated types and functions work against NETCONF-only devices:
+//!
+//! | RESTCONF request                    | NETCONF operation                          |
+//! |-------------------------------------|--------------------------------------------|
+//! | `GET /restconf/data/...`            | `<get>` (`<get-config>` with `content=config`) |
+//! | `PUT /restconf/data/...`            | `<edit-config>` with `operation="replace"` |
+//! | `PATCH /restconf/data/...`          | `<edit-config>` with `operation="merge"`   |
+//! | `POST /restconf/data/...`           | `<edit-config>` with `operation="create"`  |
+//! | `DELETE /restconf/data/...`         | `<edit-config>` with `operation="delete"`  |
+//! | `POST /restconf/operations/m:rpc`   | `<rpc>` named after the operation          |
+//!
+//! Request paths become subtree filters, JSON bodies become XML using the
+//! namespaces and list keys of a [`NetconfSchema`], and replies are turned
+//! back into RFC 7951 JSON. `<rpc-error>`s become `ietf-restconf:errors`
+//! responses with the status RFC 8040 assigns to their error tag, so error
+//! handling is the same as with RESTCONF.
+//!
+//! The session runs over any byte stream connected to the device's
+//! `netconf` SSH subsystem, e.g. a channel stream from an SSH library.
+//!
+//! ```no_run
+//! use rustconf_runtime::netconf::{NetconfSession, NetconfTransport};
+//! use rustconf_runtime::RestconfClient;
+//!
+//! # async fn example(
+//! #     ssh_channel: tokio::io::DuplexStream,
+//! #     schema: rustconf_runtime::netconf::NetconfSchema,
+//! # ) -> Result<(), rustconf_runtime::RpcError> {
+//! // `ssh_channel` is connected to the `netconf` subsystem of the device;
+//! // `schema` comes from the generated `schema::netconf_schema()`
+//! let session = NetconfSession::connect(ssh_channel).await?;
+//! let transport = NetconfTransport::new(session, schema);
+//! let client = RestconfClient::new("https://device.example.com", transport)?;
+//! // Use the client with generated operations...
+//! # Ok(())
+//! # }
+//! ```
+
+mod session;
+
+pub use crate::xml::{LeafValue, NetconfSchema, NodeKind};
+pub use session::NetconfSession;
+
+use async_trait::async_trait;
+use serde_json::{json, Map, Value};
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::sync::Mutex;
+
+use self::session::BASE_NAMESPACE;
+use crate::base_url::percent_decode;
+use crate::error::RpcError;
+use crate::transport::{HttpMethod, HttpRequest, HttpResponse, HttpTransport};
+use crate::xml::{escape, Element};
+
+/// The datastore edits are written to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Datastore {
+    /// Edit the running configuration directly.
+    #[default]
+    Running,
+    /// Edit the candidate configuration and `<commit/>` after each edit,
+    /// for devices that do not support writing to `running`.
+    Candidate,
+}
+
+/// Sends RESTCONF requests of generated code over a NETCONF session.
+///
+/// Requests are sent one at a time; concurrent requests wait for the
+/// session.
+///
+/// # Examples
+///
+/// Requests become NETCONF operations and replies RFC 7951 JSON, in either
+/// framing, and `<rpc-error>`s become RESTCONF errors:
+///
+/// ```
+/// # use tokio::io::{AsyncReadExt, AsyncWriteExt, DuplexStream};
+/// # /// Read the next message of the client, checking its framing.
+/// # async fn receive(device: &mut DuplexStream, chunked: bool) -> String {
+/// #     let mut message = Vec::new();
+/// #     while !message.ends_with(b"]]>]]>") && !message.ends_with(b"\n##\n") {
+/// #         message.push(device.read_u8().await.unwrap());
+/// #     }
+/// #     assert_eq!(message.ends_with(b"\n##\n"), chunked, "unexpected framing");
+/// #     message.truncate(message.len() - if chunked { 4 } else { 6 });
+/// #     let message = String::from_utf8(message).unwrap();
+/// #     match chunked {
+/// #         true => message.splitn(3, '\n').nth(2).unwrap().to_string(),
+/// #         false => message,
+/// #     }
+/// # }
+/// # async fn send(device: &mut DuplexStream, chunked: bool, message: &str) {
+/// #     let framed = match chunked {
+/// #         true => format!("\n#{}\n{}\n##\n", message.len(), message),
+/// #         false => format!("{}]]>]]>", message),
+/// #     };
+/// #     device.write_all(framed.as_bytes()).await.unwrap();
+/// # }
+/// # /// Answer the client's hello, with NETCONF 1.1 for chunked framing.
+/// # async fn hello(device: &mut DuplexStream, chunked: bool) {
+/// #     receive(device, false).await;
+/// #     let version = if chunked { "1.1" } else { "1.0" };
+/// #     let hello = format!(
+/// #         "<hello xmlns=\"urn:ietf:params:xml:ns:netconf:base:1.0\"><capabilities>\
+/// #          <capability>urn:ietf:params:netconf:base:{}</capability>\
+/// #          </capabilities><session-id>1</session-id></hello>",
+/// #         version
+/// #     );
+/// #     send(device, false, &hello).await;
+/// # }
+/// use bytes::Bytes;
+/// use rustconf_runtime::netconf::{
+///     LeafValue, NetconfSchema, NetconfSession, NetconfTransport, NodeKind,
+/// };
+/// use rustconf_runtime::{HttpMethod, RestconfClient, RpcError};
+///
+/// let schema = NetconfSchema::new()
+///     .with_module("dev", "urn:dev")
+///     .with_node("/system", NodeKind::Container, &[])
+///     .with_node("/system/hostname", NodeKind::Leaf(LeafValue::String), &[])
+///     .with_node("/system/mtu", NodeKind::Leaf(LeafValue::Number), &[]);
+/// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+///
+/// for chunked in [false, true] {
+///     let (stream, mut device) = tokio::io::duplex(64 * 1024);
+///     let client = async {
+///         let session = NetconfSession::connect(stream).await?;
+///         let transport = NetconfTransport::new(session, schema.clone());
+///         let client = RestconfClient::new("https://device.example.com", transport)?;
+///         let path = "/restconf/data/dev:system";
+///         let system = client.raw_request(HttpMethod::GET, path, None).await?;
+///         let body = Bytes::from_static(br#"{"dev:system":{"hostname":"r2","mtu":9000}}"#);
+///         let put = client.raw_request(HttpMethod::PUT, path, Some(body)).await;
+///         Ok::<_, RpcError>((system, put))
+///     };
+///     let device = async {
+///         hello(&mut device, chunked).await;
+///         let get = receive(&mut device, chunked).await;
+///         send(&mut device, chunked, r#"<rpc-reply message-id="1"
+///             xmlns="urn:ietf:params:xml:ns:netconf:base:1.0"><data>
+///             <system xmlns="urn:dev"><hostname>r1</hostname><mtu>1500</mtu></system>
+///             </data></rpc-reply>"#).await;
+///         let edit = receive(&mut device, chunked).await;
+///         send(&mut device, chunked, r#"<rpc-reply message-id="2"
+///             xmlns="urn:ietf:params:xml:ns:netconf:base:1.0"><rpc-error>
+///             <error-type>application</error-type><error-tag>invalid-value</error-tag>
+///             <error-severity>error</error-severity><error-message>MTU out of range</error-message>
+///             </rpc-error></rpc-reply>"#).await;
+///         (get, edit)
+///     };
+///     let (result, (get, edit)) = runtime.block_on(async { tokio::join!(client, device) });
+///     let (system, put) = result.unwrap();
+///
+///     assert!(get.starts_with("<rpc message-id=\"1\""));
+///     assert!(get.contains("<get><filter type=\"subtree\"><system xmlns=\"urn:dev\"></system></filter></get>"));
+///     let system: serde_json::Value = serde_json::from_slice(&system.body).unwrap();
+///     assert_eq!(system, serde_json::json!({"dev:system": {"hostname": "r1", "mtu": 1500}}));
+///
+///     assert!(edit.contains("xc:operation=\"replace\""));
+///     assert!(edit.contains("<hostname>r2</hostname><mtu>9000</mtu>"));
+///     match put {
+///         Err(RpcError::HttpError { status_code, message }) => {
+///             assert_eq!(status_code, 400);
+///             assert!(message.contains("MTU out of range"));
+///         }
+///         other => panic!("expected the rpc-error, got {:?}", other),
+///     }
+/// }
+/// ```
+///
+/// When a deadline cancels a request after it was sent, its late reply is
+/// skipped rather than taken for the reply to the next request:
+///
+/// ```
+/// # use tokio::io::{AsyncReadExt, AsyncWriteExt, DuplexStream};
+/// # /// Read the next message of the client, checking its framing.
+/// # async fn receive(device: &mut DuplexStream, chunked: bool) -> String {
+/// #     let mut message = Vec::new();
+/// #     while !message.ends_with(b"]]>]]>") && !message.ends_with(b"\n##\n") {
+/// #         message.push(device.read_u8().await.unwrap());
+/// #     }
+/// #     assert_eq!(message.ends_with(b"\n##\n"), chunked, "unexpected framing");
+/// #     message.truncate(message.len() - if chunked { 4 } else { 6 });
+/// #     let message = String::from_utf8(message).unwrap();
+/// #     match chunked {
+/// #         true => message.splitn(3, '\n').nth(2).unwrap().to_string(),
+/// #         false => message,
+/// #     }
+/// # }
+/// # async fn send(device: &mut DuplexStream, chunked: bool, message: &str) {
+/// #     let framed = match chunked {
+/// #         true => format!("\n#{}\n{}\n##\n", message.len(), message),
+/// #         false => format!("{}]]>]]>", message),
+/// #     };
+/// #     device.write_all(framed.as_bytes()).await.unwrap();
+/// # }
+/// # /// Answer the client's hello, with NETCONF 1.1 for chunked framing.
+/// # async fn hello(device: &mut DuplexStream, chunked: bool) {
+/// #     receive(device, false).await;
+/// #     let version = if chunked { "1.1" } else { "1.0" };
+/// #     let hello = format!(
+/// #         "<hello xmlns=\"urn:ietf:params:xml:ns:netconf:base:1.0\"><capabilities>\
+/// #          <capability>urn:ietf:params:netconf:base:{}</capability>\
+/// #          </capabilities><session-id>1</session-id></hello>",
+/// #         version
+/// #     );
+/// #     send(device, false, &hello).await;
+/// # }
+/// use std::time::Duration;
+/// use rustconf_runtime::netconf::{NetconfSchema, NetconfSession, NetconfTransport};
+/// use rustconf_runtime::{Deadline, HttpMethod, HttpRequest, HttpTransport, RpcError};
+///
+/// let runtime = tokio::runtime::Builder::new_current_thread()
+///     .enable_time()
+///     .build()
+///     .unwrap();
+/// let (stream, mut device) = tokio::io::duplex(64 * 1024);
+/// let url = "https://device.example.com/restconf/data/system";
+/// let client = async {
+///     let session = NetconfSession::connect(stream).await.unwrap();
+///     let transport = NetconfTransport::new(session, NetconfSchema::new());
+///     let deadline = Deadline::after(Duration::from_millis(50));
+///     let late = transport
+///         .execute(HttpRequest::new(HttpMethod::GET, url).with_context(deadline))
+///         .await;
+///     assert!(matches!(late, Err(RpcError::DeadlineExceeded { .. })));
+///     transport.execute(HttpRequest::new(HttpMethod::GET, url)).await.unwrap()
+/// };
+/// let device = async {
+///     hello(&mut device, true).await;
+///     receive(&mut device, true).await;
+///     // Only answered once the second request arrives
+///     let second = receive(&mut device, true).await;
+///     for (id, hostname) in [(1, "stale"), (2, "fresh")] {
+///         let reply = format!(
+///             "<rpc-reply message-id=\"{}\" xmlns=\"urn:ietf:params:xml:ns:netconf:base:1.0\">\
+///              <data><system><hostname>{}</hostname></system></data></rpc-reply>",
+///             id, hostname
+///         );
+///         send(&mut device, true, &reply).await;
+///     }
+///     second
+/// };
+/// let (response, second) = runtime.block_on(async { tokio::join!(client, device) });
+///
+/// assert!(second.starts_with("<rpc message-id=\"2\""));
+/// let body: serde_json::Value = serde_json::from_slice(&response.body).unwrap();
+/// assert_eq!(body, serde_json::json!({"system": {"hostname": "fresh"}}));
+/// ```
+///
+/// Replies over 64 MiB are rejected in either framing instead of being
+/// buffered:
+///
+/// ```
+/// # use tokio::io::{AsyncReadExt, AsyncWriteExt, DuplexStream};
+/// # /// Read the next message of the client, checking its framing.
+/// # async fn receive(device: &mut DuplexStream, chunked: bool) -> String {
+/// #     let mut message = Vec::new();
+/// #     while !message.ends_with(b"]]>]]>") && !message.ends_with(b"\n##\n") {
+/// #         message.push(device.read_u8().await.unwrap());
+/// #     }
+/// #     assert_eq!(message.ends_with(b"\n##\n"), chunked, "unexpected framing");
+/// #     message.truncate(message.len() - if chunked { 4 } else { 6 });
+/// #     let message = String::from_utf8(message).unwrap();
+/// #     match chunked {
+/// #         true => message.splitn(3, '\n').nth(2).unwrap().to_string(),
+/// #         false => message,
+/// #     }
+/// # }
+/// # async fn send(device: &mut DuplexStream, chunked: bool, message: &str) {
+/// #     let framed = match chunked {
+/// #         true => format!("\n#{}\n{}\n##\n", message.len(), message),
+/// #         false => format!("{}]]>]]>", message),
+/// #     };
+/// #     device.write_all(framed.as_bytes()).await.unwrap();
+/// # }
+/// # /// Answer the client's hello, with NETCONF 1.1 for chunked framing.
+/// # async fn hello(device: &mut DuplexStream, chunked: bool) {
+/// #     receive(device, false).await;
+/// #     let version = if chunked { "1.1" } else { "1.0" };
+/// #     let hello = format!(
+/// #         "<hello xmlns=\"urn:ietf:params:xml:ns:netconf:base:1.0\"><capabilities>\
+/// #          <capability>urn:ietf:params:netconf:base:{}</capability>\
+/// #          </capabilities><session-id>1</session-id></hello>",
+/// #         version
+/// #     );
+/// #     send(device, false, &hello).await;
+/// # }
+/// use rustconf_runtime::netconf::{NetconfSchema, NetconfSession, NetconfTransport};
+/// use rustconf_runtime::{HttpMethod, HttpRequest, HttpTransport, RpcError};
+///
+/// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+/// for chunked in [false, true] {
+///     let error = runtime.block_on(async {
+///         let (stream, mut device) = tokio::io::duplex(64 * 1024);
+///         tokio::spawn(async move {
+///             hello(&mut device, chunked).await;
+///             receive(&mut device, chunked).await;
+///             if chunked {
+///                 let _ = device.write_all(b"\n#100000000\n").await;
+///             } else {
+///                 // No end-of-message marker, until the client hangs up
+///                 let block = vec![b' '; 1024 * 1024];
+///                 while device.write_all(&block).await.is_ok() {}
+///             }
+///         });
+///         let session = NetconfSession::connect(stream).await.unwrap();
+///         let transport = NetconfTransport::new(session, NetconfSchema::new());
+///         let url = "https://device.example.com/restconf/data/system";
+///         transport.execute(HttpRequest::new(HttpMethod::GET, url)).await.unwrap_err()
+///     });
+///     assert!(matches!(&error, RpcError::TransportError(message) if message.contains("exceeds")));
+/// }
+/// ```
+#[derive(Debug)]
+pub struct NetconfTransport<S> {
+    session: Mutex<NetconfSession<S>>,
+    schema: NetconfSchema,
+    datastore: Datastore,
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin + Send> NetconfTransport<S> {
+    /// Create a transport writing to the running datastore.
+    pub fn new(session: NetconfSession<S>, schema: NetconfSchema) -> Self {
+        Self {
+            session: Mutex::new(session),
+            schema,
+            datastore: Datastore::default(),
+        }
+    }
+
+    /// Write edits to `datastore`.
+    pub fn with_datastore(mut self, datastore: Datastore) -> Self {
+        self.datastore = datastore;
+        self
+    }
+
+    /// End the session with `<close-session/>`.
+    pub async fn close(self) -> Result<(), RpcError> {
+        self.session.into_inner().close().await
+    }
+
+    async fn rpc(&self, operation: &str) -> Result<Element, RpcError> {
+        self.session.lock().await.rpc(operation).await
+    }
+
+    async fn get(&self, path: &[Segment], query: &str) -> Result<HttpResponse, RpcError> {
+        let config_only = query
+            .split('&')
+            .any(|parameter| parameter == "content=config");
+        let filter = if path.is_empty() {
+            String::new()
+        } else {
+            let mut filter = String::from("<filter type=\"subtree\">");
+            self.path_xml(path, "", &mut filter);
+            filter.push_str("</filter>");
+            filter
+        };
+        let operation = if config_only {
+            format!(
+                "<get-config><source><running/></source>{}</get-config>",
+                filter
+            )
+        } else {
+            format!("<get>{}</get>", filter)
+        };
+
+        let reply = self.rpc(&operation).await?;
+        if let Some(response) = error_response(&reply) {
+            return Ok(response);
+        }
+        let data = reply.child("data").cloned().unwrap_or_default();
+
+        // Walk down the path to the requested nodes
+        let mut nodes = vec![&data];
+        let mut schema_path = String::new();
+        for segment in path {
+            schema_path = format!("{}/{}", schema_path, segment.name);
+            let keys = self.schema.keys(&schema_path);
+            nodes = nodes
+                .iter()
+                .flat_map(|node| node.children.iter())
+                .filter(|child| child.name == segment.name && segment.matches(child, keys))
+                .collect();
+        }
+
+        let body = if path.is_empty() {
+            let mut object = Map::new();
+            for child in &data.children {
+                let (name, value) = self
+                    .schema
+                    .to_json(child, &format!("/{}", child.name), None);
+                object.insert(name, value);
+            }
+            Value::Object(object)
+        } else {
+            let Some(first) = nodes.first() else {
+                return Ok(restconf_error(
+                    404,
+                    "invalid-value",
+                    "The requested resource does not exist",
+                ));
+            };
+            let (name, _) = self.schema.to_json(first, &schema_path, None);
+            let values = nodes
+                .iter()
+                .map(|node| self.schema.to_json(node, &schema_path, None).1);
+            let value = match self.schema.kind(&schema_path) {
+                Some(NodeKind::List | NodeKind::LeafList(_)) => Value::Array(values.collect()),
+                _ if nodes.len() > 1 => Value::Array(values.collect()),
+                _ => values.into_iter().next().unwrap_or(Value::Null),
+            };
+            json!({ qualified(&name, path) : value })
+        };
+        Ok(json_response(200, &body))
+    }
+
+    async fn edit(
+        &self,
+        method: HttpMethod,
+        path: &[Segment],
+        body: Option<&[u8]>,
+    ) -> Result<HttpResponse, RpcError> {
+        let operation = match method {
+            HttpMethod::PUT => "replace",
+            HttpMethod::PATCH => "merge",
+            HttpMethod::POST => "create",
+            _ => "delete",
+        };
+        let members = match body {
+            Some(body) if !body.is_empty() => serde_json::from_slice::<Map<String, Value>>(body)
+                .map_err(|e| RpcError::SerializationError(e.to_string()))?,
+            _ => Map::new(),
+        };
+
+        // The edited node is the last path segment, except for POST, which
+        // creates the body's members below the path
+        let (ancestors, target) = match (method, path.split_last()) {
+            (HttpMethod::POST, _) | (_, None) => (path, None),
+            (_, Some((last, ancestors))) => (ancestors, Some(last)),
+        };
+
+        let mut config = String::new();
+        let mut schema_path = String::new();
+        let mut namespace = None;
+        let mut closing = Vec::new();
+        for segment in ancestors {
+            schema_path = format!("{}/{}", schema_path, segment.name);
+            self.open_segment(segment, &schema_path, &mut namespace, None, &mut config);
+            closing.push(format!("</{}>", segment.name));
+        }
+        match (method, target) {
+            (HttpMethod::DELETE, Some(target)) => {
+                let target_path = format!("{}/{}", schema_path, target.name);
+                let mut target_namespace = namespace;
+                self.open_segment(
+                    target,
+                    &target_path,
+                    &mut target_namespace,
+                    Some(operation),
+                    &mut config,
+                );
+                config.push_str(&format!("</{}>", target.name));
+            }
+            _ => {
+                for (member, value) in &members {
+                    self.schema.to_xml(
+                        member,
+                        value,
+                        &schema_path,
+                        namespace,
+                        Some(operation),
+                        &mut config,
+                    );
+                }
+            }
+        }
+        for element in closing.iter().rev() {
+            config.push_str(element);
+        }
+
+        let target = match self.datastore {
+            Datastore::Running => "<running/>",
+            Datastore::Candidate => "<candidate/>",
+        };
+        let reply = self
+            .rpc(&format!(
+                "<edit-config><target>{}</target>\
+                 <config xmlns:xc=\"{}\">{}</config></edit-config>",
+                target, BASE_NAMESPACE, config
+            ))
+            .await?;
+        if let Some(response) = error_response(&reply) {
+            return Ok(response);
+        }
+        if self.datastore == Datastore::Candidate {
+            let reply = self.rpc("<commit/>").await?;
+            if let Some(response) = error_response(&reply) {
+                return Ok(response);
+            }
+        }
+        Ok(HttpResponse::new(if method == HttpMethod::POST {
+            201
+        } else {
+            204
+        }))
+    }
+
+    async fn operation(&self, name: &str, body: Option<&[u8]>) -> Result<HttpResponse, RpcError> {
+        let (module, operation) = name.split_once(':').unwrap_or(("", name));
+        let namespace = self.schema.namespace(module).ok_or_else(|| {
+            RpcError::ConfigurationError(format!(
+                "no namespace for module `{}` in the NETCONF schema",
+                module
+            ))
+        })?;
+
+        let mut input = Map::new();
+        if let Some(body) = body.filter(|body| !body.is_empty()) {
+            let envelope: Map<String, Value> = serde_json::from_slice(body)
+                .map_err(|e| RpcError::SerializationError(e.to_string()))?;
+            if let Some(Value::Object(members)) = envelope.into_values().next() {
+                input = members;
+            }
+        }
+        let mut rpc = format!("<{} xmlns=\"{}\">", operation, escape(namespace));
+        let path = format!("/{}/input", operation);
+        for (member, value) in &input {
+            self.schema
+                .to_xml(member, value, &path, Some(namespace), None, &mut rpc);
+        }
+        rpc.push_str(&format!("</{}>", operation));
+
+        let reply = self.rpc(&rpc).await?;
+        if let Some(response) = error_response(&reply) {
+            return Ok(response);
+        }
+        if reply.child("ok").is_some() || reply.children.is_empty() {
+            return Ok(HttpResponse::new(204));
+        }
+        let mut output = Map::new();
+        let path = format!("/{}/output", operation);
+        for child in &reply.children {
+            let (name, value) =
+                self.schema
+                    .to_json(child, &format!("{}/{}", path, child.name), Some(namespace));
+            output.insert(name, value);
+        }
+        Ok(json_response(
+            200,
+            &json!({ format!("{}:output", module): output }),
+        ))
+    }
+
+    /// Write the elements selecting `path`, as in a subtree filter.
+    fn path_xml(&self, path: &[Segment], schema_path: &str, output: &mut String) {
+        let mut schema_path = schema_path.to_string();
+        let mut namespace = None;
+        for segment in path {
+            schema_path = format!("{}/{}", schema_path, segment.name);
+            self.open_segment(segment, &schema_path, &mut namespace, None, output);
+        }
+        for segment in path.iter().rev() {
+            output.push_str(&format!("</{}>", segment.name));
+        }
+    }
+
+    /// Open the element of a path segment, followed by its key leaves.
+    fn open_segment<'a>(
+        &'a self,
+        segment: &Segment,
+        schema_path: &str,
+        namespace: &mut Option<&'a str>,
+        operation: Option<&str>,
+        output: &mut String,
+    ) {
+        output.push('<');
+        output.push_str(&segment.name);
+        let segment_namespace = segment
+            .module
+            .as_deref()
+            .and_then(|module| self.schema.namespace(module));
+        if segment_namespace.is_some() && segment_namespace != *namespace {
+            *namespace = segment_namespace;
+            output.push_str(&format!(" xmlns=\"{}\"", escape(namespace.unwrap_or(""))));
+        }
+        if let Some(operation) = operation {
+            output.push_str(&format!(" xc:operation=\"{}\"", operation));
+        }
+        output.push('>');
+        for (key, value) in self.schema.keys(schema_path).iter().zip(&segment.keys) {
+            output.push_str(&format!("<{0}>{1}</{0}>", key, escape(value)));
+        }
+    }
+}
+
+#[async_trait]
+impl<S: AsyncRead + AsyncWrite + Unpin + Send> HttpTransport for NetconfTransport<S> {
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, RpcError> {
+        let (path, query) = request.url.split_once('?').unwrap_or((&request.url, ""));
+        let Some(resource) = path.find("/restconf/").map(|start| &path[start + 10..]) else {
+            return Ok(restconf_error(
+                404,
+                "invalid-value",
+                "Only RESTCONF resources can be sent over NETCONF",
+            ));
+        };
+        let body = request.body.as_deref();
+
+        let exchange = async {
+            if let Some(operation) = resource.strip_prefix("operations/") {
+                return match request.method {
+                    HttpMethod::POST => self.operation(&percent_decode(operation), body).await,
+                    _ => Ok(restconf_error(
+                        405,
+                        "operation-not-supported",
+                        "Operations can only be invoked with POST",
+                    )),
+                };
+            }
+            let Some(data) = resource
+                .strip_prefix("data")
+                .filter(|rest| rest.is_empty() || rest.starts_with('/'))
+            else {
+                return Ok(restconf_error(
+                    501,
+                    "operation-not-supported",
+                    "Only the data and operations resources are available over NETCONF",
+                ));
+            };
+            let path = parse_path(data);
+            match request.method {
+                HttpMethod::GET | HttpMethod::HEAD => self.get(&path, query).await,
+                HttpMethod::PATCH
+                    if request.headers.iter().any(|(name, value)| {
+                        name.eq_ignore_ascii_case("content-type") && value.contains("yang-patch")
+                    }) =>
+                {
+                    Ok(restconf_error(
+                        415,
+                        "operation-not-supported",
+                        "YANG Patch is not available over NETCONF",
+                    ))
+                }
+                HttpMethod::PUT | HttpMethod::PATCH | HttpMethod::POST | HttpMethod::DELETE => {
+                    self.edit(request.method, &path, body).await
+                }
+                HttpMethod::OPTIONS => {
+                    let mut response = HttpResponse::new(200);
+                    response.headers.push((
+                        "Allow".to_string(),
+                        "GET, HEAD, PUT, PATCH, POST, DELETE, OPTIONS".to_string(),
+                    ));
+                    Ok(response)
+                }
+            }
+        };
+
+        match request.context.deadline() {
+            Some(deadline) => {
+                let remaining = deadline.check()?;
+                match tokio::time::timeout(remaining, exchange).await {
+                    Ok(result) => result,
+                    Err(_) => Err(deadline.exceeded()),
+                }
+            }
+            None => exchange.await,
+        }
+    }
+}
+
+/// A segment of a RESTCONF data path: `module:name=key1,key2`.
+#[derive(Debug)]
+struct Segment {
+    module: Option<String>,
+    name: String,
+    keys: Vec<String>,
+}
+
+impl Segment {
+    /// Whether a list entry element has the key values of this segment.
+    fn matches(&self, element: &Element, keys: &[String]) -> bool {
+        keys.iter().zip(&self.keys).all(|(key, value)| {
+            element
+                .child(key)
+                .is_some_and(|leaf| leaf.text.trim() == value)
+        })
+    }
+}
+
+fn parse_path(path: &str) -> Vec<Segment> {
+    path.split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| {
+            let (identifier, keys) = match segment.split_once('=') {
+                Some((identifier, keys)) => {
+                    (identifier, keys.split(',').map(percent_decode).collect())
+                }
+                None => (segment, Vec::new()),
+            };
+            let identifier = percent_decode(identifier);
+            let (module, name) = match identifier.split_once(':') {
+                Some((module, name)) => (Some(module.to_string()), name.to_string()),
+                None => (None, identifier),
+            };
+            Segment { module, name, keys }
+        })
+        .collect()
+}
+
+/// Qualify a member name with the module of the path, as RESTCONF does for
+/// the top-level member of a response.
+fn qualified(name: &str, path: &[Segment]) -> String {
+    if name.contains(':') {
+        return name.to_string();
+    }
+    match path
+        .iter()
+        .rev()
+        .find_map(|segment| segment.module.as_deref())
+    {
+        Some(module) => format!("{}:{}", module, name),
+        None => name.to_string(),
+    }
+}
+
+fn json_response(status_code: u16, body: &Value) -> HttpResponse {
+    let mut response = HttpResponse::new(status_code);
+    response.headers.push((
+        "Content-Type".to_string(),
+        "application/yang-data+json".to_string(),
+    ));
+    response.body = body.to_string().into_bytes();
+    response
+}
+
+fn restconf_error(status_code: u16, error_tag: &str, message: &str) -> HttpResponse {
+    json_response(
+        status_code,
+        &json!({"ietf-restconf:errors": {"error": [{
+            "error-type": "protocol",
+            "error-tag": error_tag,
+            "error-message": message,
+        }]}}),
+    )
+}
+
+/// Turn the `<rpc-error>`s of a reply into an `ietf-restconf:errors` response.
+fn error_response(reply: &Element) -> Option<HttpResponse> {
+    let errors: Vec<&Element> = reply
+        .children
+        .iter()
+        .filter(|child| child.name == "rpc-error")
+        .collect();
+    // Warnings do not fail the operation
+    let failed = errors.iter().any(|error| {
+        error
+            .child("error-severity")
+            .is_none_or(|severity| severity.text.trim() != "warning")
+    });
+    if !failed {
+        return None;
+    }
+
+    let text = |error: &Element, name: &str| {
+        error
+            .child(name)
+            .map(|child| Value::String(child.text.trim().to_string()))
+    };
+    let entries: Vec<Value> = errors
+        .iter()
+        .map(|error| {
+            let mut entry = Map::new();
+            for name in [
+                "error-type",
+                "error-tag",
+                "error-app-tag",
+                "error-path",
+                "error-message",
+            ] {
+                if let Some(value) = text(error, name) {
+                    entry.insert(name.to_string(), value);
+                }
+            }
+            Value::Object(entry)
+        })
+        .collect();
+    let status_code = errors
+        .first()
+        .and_then(|error| error.child("error-tag"))
+        .map_or(500, |tag| error_tag_status(tag.text.trim()));
+    Some(json_response(
+        status_code,
+        &json!({"ietf-restconf:errors": {"error": entries}}),
+    ))
+}
+
+/// HTTP status for a NETCONF error tag (RFC 8040 section 7).
+fn error_tag_status(error_tag: &str) -> u16 {
+    match error_tag {
+        "in-use" | "lock-denied" | "resource-denied" | "data-exists" | "data-missing" => 409,
+        "invalid-value" | "missing-attribute" | "bad-attribute" | "unknown-attribute"
+        | "missing-element" | "bad-element" | "unknown-element" | "unknown-namespace"
+        | "malformed-message" => 400,
+        "too-big" => 413,
+        "access-denied" => 403,
+        "operation-not-supported" => 501,
+        _ => 500,
+    }
+}
@@ -0,0 +1,321 @@
+//! NETCONF sessions: the hello exchange and RFC 6242 message framing.
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::error::RpcError;
+use crate::xml::{self, Element};
+
+/// Namespace of the NETCONF base protocol.
+pub(crate) const BASE_NAMESPACE: &str = "urn:ietf:params:xml:ns:netconf:base:1.0";
+
+const BASE_1_0: &str = "urn:ietf:params:netconf:base:1.0";
+const BASE_1_1: &str = "urn:ietf:params:netconf:base:1.1";
+
+/// End-of-message marker of NETCONF 1.0 framing.
+const END_OF_MESSAGE: &[u8] = b"]]>]]>";
+
+/// Replies larger than this are rejected rather than buffered.
+const MAX_MESSAGE_SIZE: usize = 64 * 1024 * 1024;
+
+/// A NETCONF session over the `netconf` SSH subsystem channel.
+///
+/// The session works on any byte stream, such as an SSH channel stream from
+/// the SSH library of choice or the standard input and output of
+/// `ssh -s device netconf`. [`connect`](Self::connect) exchanges hello
+/// messages and switches to chunked framing when both peers support
+/// NETCONF 1.1.
+#[derive(Debug)]
+pub struct NetconfSession<S> {
+    stream: S,
+    buffer: Vec<u8>,
+    chunked: bool,
+    session_id: Option<u32>,
+    capabilities: Vec<String>,
+    message_id: u64,
+    /// Bytes of `buffer` already searched for the end-of-message marker.
+    searched: usize,
+    /// Whether writing a message was cancelled part way, leaving a partial
+    /// message on the stream.
+    write_incomplete: bool,
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin + Send> NetconfSession<S> {
+    /// Exchange hello messages with the server.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RpcError::TransportError` if the stream fails or the server
+    /// does not send a valid hello.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustconf_runtime::netconf::NetconfSession;
+    /// use tokio::io::{AsyncReadExt, AsyncWriteExt, DuplexStream};
+    ///
+    /// async fn exchange(device: &mut DuplexStream, hello: &str) -> String {
+    ///     let mut received = Vec::new();
+    ///     while !received.ends_with(b"]]>]]>") {
+    ///         received.push(device.read_u8().await.unwrap());
+    ///     }
+    ///     device.write_all(format!("{}]]>]]>", hello).as_bytes()).await.unwrap();
+    ///     String::from_utf8(received).unwrap()
+    /// }
+    ///
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    /// runtime.block_on(async {
+    ///     let (stream, mut device) = tokio::io::duplex(64 * 1024);
+    ///     let hello = r#"<hello xmlns="urn:ietf:params:xml:ns:netconf:base:1.0"><capabilities>
+    ///         <capability>urn:ietf:params:netconf:base:1.1</capability>
+    ///         <capability>urn:ietf:params:netconf:capability:candidate:1.0</capability>
+    ///         </capabilities><session-id>42</session-id></hello>"#;
+    ///     let (session, sent) = tokio::join!(
+    ///         NetconfSession::connect(stream),
+    ///         exchange(&mut device, hello)
+    ///     );
+    ///     let session = session.unwrap();
+    ///
+    ///     assert!(sent.contains("urn:ietf:params:netconf:base:1.0"));
+    ///     assert!(sent.contains("urn:ietf:params:netconf:base:1.1"));
+    ///     assert_eq!(session.session_id(), Some(42));
+    ///     assert_eq!(session.capabilities().len(), 2);
+    ///     assert!(session.supports("urn:ietf:params:netconf:capability:candidate:1.0"));
+    ///
+    ///     // Anything but a hello fails the exchange
+    ///     let (stream, mut device) = tokio::io::duplex(64 * 1024);
+    ///     let not_hello = r#"<rpc-reply xmlns="urn:ietf:params:xml:ns:netconf:base:1.0"><ok/></rpc-reply>"#;
+    ///     let (session, _) = tokio::join!(
+    ///         NetconfSession::connect(stream),
+    ///         exchange(&mut device, not_hello)
+    ///     );
+    ///     assert!(session.is_err());
+    /// });
+    /// ```
+    pub async fn connect(stream: S) -> Result<Self, RpcError> {
+        let mut session = Self {
+            stream,
+            buffer: Vec::new(),
+            chunked: false,
+            session_id: None,
+            capabilities: Vec::new(),
+            message_id: 0,
+            searched: 0,
+            write_incomplete: false,
+        };
+
+        let hello = format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+             <hello xmlns=\"{}\"><capabilities>\
+             <capability>{}</capability><capability>{}</capability>\
+             </capabilities></hello>",
+            BASE_NAMESPACE, BASE_1_0, BASE_1_1
+        );
+        session.write_message(&hello).await?;
+
+        let hello = session.read_message().await?;
+        if hello.name != "hello" {
+            return Err(RpcError::TransportError(format!(
+                "expected a NETCONF hello, got <{}>",
+                hello.name
+            )));
+        }
+        session.capabilities = hello
+            .child("capabilities")
+            .map(|capabilities| {
+                capabilities
+                    .children
+                    .iter()
+                    .map(|capability| capability.text.trim().to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+        session.session_id = hello
+            .child("session-id")
+            .and_then(|id| id.text.trim().parse().ok());
+        session.chunked = session.supports(BASE_1_1);
+        Ok(session)
+    }
+
+    /// The session id assigned by the server.
+    pub fn session_id(&self) -> Option<u32> {
+        self.session_id
+    }
+
+    /// Capabilities advertised in the server's hello.
+    pub fn capabilities(&self) -> &[String] {
+        &self.capabilities
+    }
+
+    /// Whether the server advertised `capability`, ignoring any parameters
+    /// after `?`.
+    pub fn supports(&self, capability: &str) -> bool {
+        self.capabilities
+            .iter()
+            .any(|advertised| advertised.split('?').next() == Some(capability))
+    }
+
+    /// Send an `<rpc>` with `operation` as its content and return the
+    /// `<rpc-reply>` to it.
+    ///
+    /// Notifications received while waiting for the reply are discarded, and
+    /// so are the replies to earlier rpcs whose exchange was cancelled after
+    /// they were sent, such as by a deadline. A session whose message was
+    /// cancelled while being written cannot be used anymore.
+    pub(crate) async fn rpc(&mut self, operation: &str) -> Result<Element, RpcError> {
+        if self.write_incomplete {
+            return Err(RpcError::TransportError(
+                "NETCONF session was interrupted while writing a message; reconnect".to_string(),
+            ));
+        }
+        self.message_id += 1;
+        let message_id = self.message_id.to_string();
+        let rpc = format!(
+            "<rpc message-id=\"{}\" xmlns=\"{}\">{}</rpc>",
+            message_id, BASE_NAMESPACE, operation
+        );
+        self.write_message(&rpc).await?;
+
+        loop {
+            let reply = self.read_message().await?;
+            match reply.name.as_str() {
+                "rpc-reply"
+                    if reply
+                        .attribute("message-id")
+                        .is_none_or(|id| id == message_id) =>
+                {
+                    return Ok(reply)
+                }
+                "rpc-reply" | "notification" => continue,
+                other => {
+                    return Err(RpcError::TransportError(format!(
+                        "expected an rpc-reply, got <{}>",
+                        other
+                    )))
+                }
+            }
+        }
+    }
+
+    /// End the session with `<close-session/>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RpcError::TransportError` if the server does not confirm.
+    pub async fn close(mut self) -> Result<(), RpcError> {
+        self.rpc("<close-session/>").await?;
+        self.stream
+            .shutdown()
+            .await
+            .map_err(|e| RpcError::TransportError(e.to_string()))
+    }
+
+    async fn write_message(&mut self, message: &str) -> Result<(), RpcError> {
+        let framed = if self.chunked {
+            format!("\n#{}\n{}\n##\n", message.len(), message).into_bytes()
+        } else {
+            let mut framed = message.as_bytes().to_vec();
+            framed.extend_from_slice(END_OF_MESSAGE);
+            framed
+        };
+        let write_failed =
+            |e: std::io::Error| RpcError::TransportError(format!("NETCONF write failed: {}", e));
+        self.write_incomplete = true;
+        self.stream.write_all(&framed).await.map_err(write_failed)?;
+        self.stream.flush().await.map_err(write_failed)?;
+        self.write_incomplete = false;
+        Ok(())
+    }
+
+    async fn read_message(&mut self) -> Result<Element, RpcError> {
+        loop {
+            if let Some(message) = self.take_message()? {
+                let message = String::from_utf8(message).map_err(|_| {
+                    RpcError::TransportError("NETCONF message is not UTF-8".to_string())
+                })?;
+                return xml::parse(&message).map_err(|e| {
+                    RpcError::TransportError(format!("malformed NETCONF message: {}", e))
+                });
+            }
+            if self.buffer.len() > MAX_MESSAGE_SIZE {
+                return Err(too_large());
+            }
+            let mut chunk = [0u8; 8192];
+            let read = self
+                .stream
+                .read(&mut chunk)
+                .await
+                .map_err(|e| RpcError::TransportError(format!("NETCONF read failed: {}", e)))?;
+            if read == 0 {
+                return Err(RpcError::TransportError(
+                    "NETCONF session closed by the server".to_string(),
+                ));
+            }
+            self.buffer.extend_from_slice(&chunk[..read]);
+        }
+    }
+
+    /// Remove the first complete message from the buffer.
+    fn take_message(&mut self) -> Result<Option<Vec<u8>>, RpcError> {
+        if !self.chunked {
+            // The marker may straddle the bytes searched before
+            let start = self.searched.saturating_sub(END_OF_MESSAGE.len() - 1);
+            let Some(end) = find(&self.buffer[start..], END_OF_MESSAGE) else {
+                self.searched = self.buffer.len();
+                return Ok(None);
+            };
+            let end = start + end;
+            let message = self.buffer[..end].to_vec();
+            self.buffer.drain(..end + END_OF_MESSAGE.len());
+            self.searched = 0;
+            return Ok(Some(message));
+        }
+
+        // Chunked framing: `\n#<size>\n<data>` repeated, then `\n##\n`
+        let malformed = || RpcError::TransportError("malformed NETCONF chunk".to_string());
+        let mut message = Vec::new();
+        let mut position = 0;
+        loop {
+            let rest = &self.buffer[position..];
+            if rest.len() < 4 {
+                return Ok(None);
+            }
+            if !rest.starts_with(b"\n#") {
+                return Err(malformed());
+            }
+            if rest.starts_with(b"\n##\n") {
+                self.buffer.drain(..position + 4);
+                return Ok(Some(message));
+            }
+            let Some(header_end) = rest[2..].iter().position(|&b| b == b'\n') else {
+                return Ok(None);
+            };
+            let size: usize = std::str::from_utf8(&rest[2..2 + header_end])
+                .ok()
+                .and_then(|size| size.parse().ok())
+                .filter(|&size| size > 0)
+                .ok_or_else(malformed)?;
+            if message.len() + size > MAX_MESSAGE_SIZE {
+                return Err(too_large());
+            }
+            let data_start = 2 + header_end + 1;
+            if rest.len() < data_start + size {
+                return Ok(None);
+            }
+            message.extend_from_slice(&rest[data_start..data_start + size]);
+            position += data_start + size;
+        }
+    }
+}
+
+fn too_large() -> RpcError {
+    RpcError::TransportError(format!(
+        "NETCONF message exceeds {} bytes",
+        MAX_MESSAGE_SIZE
+    ))
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
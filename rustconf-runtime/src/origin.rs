@@ -0,0 +1,234 @@
+//! Origin metadata of the operational datastore (NMDA).
+//!
+//! Servers implementing the Network Management Datastore Architecture
+//! (RFC 8342) expose the operational state under
+//! `/restconf/ds/ietf-datastores:operational` (RFC 8527). With the
+//! `with-origin` query parameter, each node is annotated with where its
+//! value came from: the intended configuration, the system, a protocol that
+//! learned it, and so on. The annotations are RFC 7952 metadata, which the
+//! generated types ignore when deserializing, so [`OriginTable`] collects
+//! them from the response's [`Metadata`] into a side table next to the typed
+//! data.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde_json::Value;
+
+use crate::error::RpcError;
+use crate::metadata::Metadata;
+
+/// Path of the operational datastore (RFC 8527, section 3.1).
+pub const OPERATIONAL_PATH: &str = "/restconf/ds/ietf-datastores:operational";
+
+/// Query parameter asking for origin metadata (RFC 8527, section 3.2.2).
+pub const WITH_ORIGIN: &str = "with-origin";
+
+/// Name of the origin annotation in RFC 7952 JSON.
+const ORIGIN_ANNOTATION: &str = "ietf-origin:origin";
+
+/// Path of the unified datastore that generated requests use.
+const DATA_PATH: &str = "/restconf/data";
+
+/// Where the value of an operational node came from (RFC 8342, section 7.4).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Origin {
+    /// Configured in the intended datastore.
+    Intended,
+    /// Set by a dynamic configuration protocol.
+    Dynamic,
+    /// Created by the system itself, e.g. a loopback interface.
+    System,
+    /// Learned from a protocol, e.g. a routing peer.
+    Learned,
+    /// A schema default in use.
+    Default,
+    /// The origin cannot be determined.
+    Unknown,
+    /// Any other identity derived from `ietf-origin:origin`, as written by
+    /// the server.
+    Other(String),
+}
+
+impl Origin {
+    /// Parse the identity value of an origin annotation, e.g.
+    /// `ietf-origin:learned`.
+    pub fn parse(identity: &str) -> Self {
+        let local = match identity.split_once(':') {
+            Some(("ietf-origin", local)) => local,
+            Some(_) => return Origin::Other(identity.to_string()),
+            None => identity,
+        };
+        match local {
+            "intended" => Origin::Intended,
+            "dynamic" => Origin::Dynamic,
+            "system" => Origin::System,
+            "learned" => Origin::Learned,
+            "default" => Origin::Default,
+            "unknown" => Origin::Unknown,
+            _ => Origin::Other(identity.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Origin::Intended => f.write_str("ietf-origin:intended"),
+            Origin::Dynamic => f.write_str("ietf-origin:dynamic"),
+            Origin::System => f.write_str("ietf-origin:system"),
+            Origin::Learned => f.write_str("ietf-origin:learned"),
+            Origin::Default => f.write_str("ietf-origin:default"),
+            Origin::Unknown => f.write_str("ietf-origin:unknown"),
+            Origin::Other(identity) => f.write_str(identity),
+        }
+    }
+}
+
+/// Turn a `/restconf/data` path into the same resource of the operational
+/// datastore, asking for origin metadata.
+///
+/// Paths outside `/restconf/data` are only given the query parameter.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::origin::with_origin_path;
+///
+/// assert_eq!(
+///     with_origin_path("/restconf/data/device:interfaces=eth0"),
+///     "/restconf/ds/ietf-datastores:operational/device:interfaces=eth0?with-origin"
+/// );
+/// assert_eq!(
+///     with_origin_path("/restconf/data/device:system?depth=2"),
+///     "/restconf/ds/ietf-datastores:operational/device:system?depth=2&with-origin"
+/// );
+/// ```
+pub fn with_origin_path(data_path: &str) -> String {
+    let path = match data_path.strip_prefix(DATA_PATH) {
+        Some(rest) if rest.is_empty() || rest.starts_with(['/', '?']) => {
+            format!("{}{}", OPERATIONAL_PATH, rest)
+        }
+        _ => data_path.to_string(),
+    };
+    let separator = if path.contains('?') { '&' } else { '?' };
+    format!("{}{}{}", path, separator, WITH_ORIGIN)
+}
+
+/// The origins annotated in an operational datastore response.
+///
+/// Nodes are identified by JSON Pointers (RFC 6901) into the response body,
+/// so the member names are those of the JSON, qualified with their module
+/// where RFC 7951 qualifies them, and list entries and leaf-list values are
+/// addressed by their index. The index of an entry is its index in the `Vec`
+/// of the deserialized list.
+///
+/// Nodes without an annotation of their own inherit the origin of their
+/// parent, as RFC 8342 specifies; [`origin`](Self::origin) applies this.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::origin::{Origin, OriginTable};
+///
+/// let body = br#"{
+///   "device:interfaces": [
+///     {
+///       "@": {"ietf-origin:origin": "ietf-origin:intended"},
+///       "name": "eth0",
+///       "mtu": 1500,
+///       "speed": 1000,
+///       "@speed": {"ietf-origin:origin": "ietf-origin:learned"}
+///     },
+///     {
+///       "@": {"ietf-origin:origin": "ietf-origin:system"},
+///       "name": "lo"
+///     }
+///   ]
+/// }"#;
+/// let origins = OriginTable::from_json(body)?;
+///
+/// assert_eq!(origins.origin("/device:interfaces/0/speed"), Some(&Origin::Learned));
+/// // Inherited from the entry
+/// assert_eq!(origins.origin("/device:interfaces/0/mtu"), Some(&Origin::Intended));
+/// assert_eq!(origins.origin("/device:interfaces/1/name"), Some(&Origin::System));
+/// assert_eq!(origins.len(), 3);
+/// # Ok::<(), rustconf_runtime::RpcError>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OriginTable {
+    origins: BTreeMap<String, Origin>,
+}
+
+impl OriginTable {
+    /// Collect the origin annotations of a JSON response body.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RpcError::DeserializationError` if the body is not JSON.
+    pub fn from_json(body: &[u8]) -> Result<Self, RpcError> {
+        let document: Value = serde_json::from_slice(body)
+            .map_err(|e| RpcError::DeserializationError(e.to_string()))?;
+        Ok(Self::from_value(&document))
+    }
+
+    /// Collect the origin annotations of a JSON document.
+    pub fn from_value(document: &Value) -> Self {
+        Self::from_metadata(&Metadata::collect(document))
+    }
+
+    /// Collect the origin annotations of a metadata table.
+    pub fn from_metadata(metadata: &Metadata) -> Self {
+        let origins = metadata
+            .iter()
+            .filter_map(|(pointer, annotations)| {
+                let identity = annotations.get(ORIGIN_ANNOTATION)?.as_str()?;
+                Some((pointer.to_string(), Origin::parse(identity)))
+            })
+            .collect();
+        Self { origins }
+    }
+
+    /// The origin of the node at `pointer`, inherited from the closest
+    /// annotated ancestor if the node has none of its own.
+    pub fn origin(&self, pointer: &str) -> Option<&Origin> {
+        let mut pointer = pointer.trim_end_matches('/');
+        loop {
+            if let Some(origin) = self.origins.get(pointer) {
+                return Some(origin);
+            }
+            pointer = &pointer[..pointer.rfind('/')?];
+        }
+    }
+
+    /// The origin annotated on the node at `pointer` itself.
+    pub fn annotated(&self, pointer: &str) -> Option<&Origin> {
+        self.origins.get(pointer)
+    }
+
+    /// All annotated nodes and their origins, in pointer order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Origin)> {
+        self.origins
+            .iter()
+            .map(|(pointer, origin)| (pointer.as_str(), origin))
+    }
+
+    /// Number of annotated nodes.
+    pub fn len(&self) -> usize {
+        self.origins.len()
+    }
+
+    /// Check whether the response carried no origin annotations.
+    pub fn is_empty(&self) -> bool {
+        self.origins.is_empty()
+    }
+}
+
+/// Data read from the operational datastore with its origins.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WithOrigin<T> {
+    /// The deserialized data.
+    pub data: T,
+    /// Origins of the nodes in the response.
+    pub origins: OriginTable,
+}
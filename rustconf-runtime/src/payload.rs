@@ -0,0 +1,882 @@
+//! Checking request bodies against the schema of the generated modules
+//! before they are sent.
+//!
+//! A device that rejects a payload usually answers with a bare `400` or an
+//! `error-message` that does not say which value was wrong. [`PayloadSchema`]
+//! holds the value types, list keys, mandatory leaves, ranges and lengths of
+//! the generated modules (`schema::payload_schema()`, generated with
+//! `enable_payload_schema`), and [`ValidatingTransport`] checks every
+//! outgoing body against it, failing locally with a report naming each
+//! offending node.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::base_url::{percent_decode, resource_path};
+use crate::decimal64::{format_scaled, parse_scaled};
+use crate::error::{MessageCatalog, MessageKey, RpcError};
+use crate::transport::{HttpMethod, HttpRequest, HttpResponse, HttpTransport};
+
+/// Type of a leaf or leaf-list value in RFC 7951 JSON.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LeafType {
+    /// An integer within one of `ranges` (inclusive). `quoted` integers are
+    /// encoded as JSON strings, as RFC 7951 does for 64-bit integers.
+    Integer {
+        /// Allowed ranges.
+        ranges: Vec<(i128, i128)>,
+        /// Whether the value is a JSON string.
+        quoted: bool,
+    },
+    /// A `decimal64` string with at most `fraction_digits` digits after the
+    /// decimal point, within one of `ranges` (inclusive); any value when
+    /// empty. Ranges are scaled by 10^`fraction_digits`.
+    Decimal64 {
+        /// Digits after the decimal point.
+        fraction_digits: u8,
+        /// Allowed ranges of the scaled values.
+        ranges: Vec<(i64, i64)>,
+    },
+    /// A string whose length in characters is within one of `lengths`;
+    /// any length when empty.
+    String {
+        /// Allowed lengths.
+        lengths: Vec<(u64, u64)>,
+    },
+    /// `true` or `false`.
+    Boolean,
+    /// The `empty` type, encoded as `[null]`.
+    Empty,
+    /// One of the enum names.
+    Enumeration(Vec<String>),
+    /// Space-separated bit names, each at most once.
+    Bits(Vec<String>),
+    /// Base64-encoded binary data.
+    Binary,
+    /// A value of any of the member types.
+    Union(Vec<LeafType>),
+    /// Not checked, e.g. a leafref.
+    Any,
+}
+
+impl LeafType {
+    /// An integer encoded as a JSON number.
+    pub fn integer(ranges: &[(i128, i128)]) -> Self {
+        LeafType::Integer {
+            ranges: ranges.to_vec(),
+            quoted: false,
+        }
+    }
+
+    /// An integer encoded as a JSON string.
+    pub fn quoted_integer(ranges: &[(i128, i128)]) -> Self {
+        LeafType::Integer {
+            ranges: ranges.to_vec(),
+            quoted: true,
+        }
+    }
+
+    /// A `decimal64` with `fraction_digits` digits after the decimal point,
+    /// scaled by 10^`fraction_digits` within one of `ranges`.
+    pub fn decimal64(fraction_digits: u8, ranges: &[(i64, i64)]) -> Self {
+        LeafType::Decimal64 {
+            fraction_digits,
+            ranges: ranges.to_vec(),
+        }
+    }
+
+    /// A string with a length in one of `lengths`, or any length when empty.
+    pub fn string(lengths: &[(u64, u64)]) -> Self {
+        LeafType::String {
+            lengths: lengths.to_vec(),
+        }
+    }
+
+    /// One of the enum names `names`.
+    pub fn enumeration(names: &[&str]) -> Self {
+        LeafType::Enumeration(names.iter().map(|name| name.to_string()).collect())
+    }
+
+    /// A set of the bit names `names`.
+    pub fn bits(names: &[&str]) -> Self {
+        LeafType::Bits(names.iter().map(|name| name.to_string()).collect())
+    }
+
+    /// Check a value, describing the problem if it does not match.
+    pub(crate) fn check(&self, value: &Value) -> Result<(), (ViolationKind, String)> {
+        let wrong_type = || ViolationKind::WrongType(value.clone());
+        match self {
+            LeafType::Integer { ranges, quoted } => {
+                let integer = match (value, quoted) {
+                    (Value::Number(number), false) => number
+                        .as_i64()
+                        .map(i128::from)
+                        .or_else(|| number.as_u64().map(i128::from)),
+                    (Value::String(text), true) => text.parse::<i128>().ok(),
+                    _ => None,
+                };
+                let expected = if *quoted {
+                    "an integer string"
+                } else {
+                    "an integer"
+                };
+                match integer {
+                    None => Err((
+                        wrong_type(),
+                        format!("expected {}, got {}", expected, value),
+                    )),
+                    Some(integer) if !in_ranges(integer, ranges) => Err((
+                        ViolationKind::OutOfRange(value.clone()),
+                        format!("{} is out of range {}", integer, format_ranges(ranges)),
+                    )),
+                    Some(_) => Ok(()),
+                }
+            }
+            LeafType::Decimal64 {
+                fraction_digits,
+                ranges,
+            } => {
+                let scaled = match value {
+                    Value::String(text) => parse_scaled(text, *fraction_digits).ok(),
+                    _ => None,
+                };
+                match scaled {
+                    None => Err((
+                        wrong_type(),
+                        format!(
+                            "expected a decimal string with at most {} fraction digits, got {}",
+                            fraction_digits, value
+                        ),
+                    )),
+                    Some(scaled) if !in_ranges(scaled, ranges) => {
+                        let ranges: Vec<(String, String)> = ranges
+                            .iter()
+                            .map(|(min, max)| {
+                                (
+                                    format_scaled(*min, *fraction_digits),
+                                    format_scaled(*max, *fraction_digits),
+                                )
+                            })
+                            .collect();
+                        Err((
+                            ViolationKind::OutOfRange(value.clone()),
+                            format!(
+                                "{} is out of range {}",
+                                format_scaled(scaled, *fraction_digits),
+                                format_ranges(&ranges)
+                            ),
+                        ))
+                    }
+                    Some(_) => Ok(()),
+                }
+            }
+            LeafType::String { lengths } => match value {
+                Value::String(text) => {
+                    let length = text.chars().count() as u64;
+                    if in_ranges(length, lengths) {
+                        Ok(())
+                    } else {
+                        Err((
+                            ViolationKind::OutOfRange(value.clone()),
+                            format!(
+                                "length {} of {:?} is out of range {}",
+                                length,
+                                text,
+                                format_ranges(lengths)
+                            ),
+                        ))
+                    }
+                }
+                _ => Err((wrong_type(), format!("expected a string, got {}", value))),
+            },
+            LeafType::Boolean => match value {
+                Value::Bool(_) => Ok(()),
+                _ => Err((
+                    wrong_type(),
+                    format!("expected true or false, got {}", value),
+                )),
+            },
+            LeafType::Empty => match value {
+                Value::Array(values) if values.len() == 1 && values[0].is_null() => Ok(()),
+                _ => Err((
+                    wrong_type(),
+                    format!("expected [null] for an empty leaf, got {}", value),
+                )),
+            },
+            LeafType::Enumeration(names) => match value {
+                Value::String(name) if names.contains(name) => Ok(()),
+                _ => Err((
+                    ViolationKind::UnknownEnum(value.clone()),
+                    format!(
+                        "{} is not one of the enum values {}",
+                        value,
+                        names.join(", ")
+                    ),
+                )),
+            },
+            LeafType::Bits(names) => match value {
+                Value::String(text) => {
+                    let mut seen = Vec::new();
+                    for bit in text.split_whitespace() {
+                        if !names.iter().any(|name| name == bit) || seen.contains(&bit) {
+                            return Err((
+                                ViolationKind::UnknownBit(value.clone()),
+                                format!("{:?} is not a set of the bits {}", text, names.join(", ")),
+                            ));
+                        }
+                        seen.push(bit);
+                    }
+                    Ok(())
+                }
+                _ => Err((
+                    wrong_type(),
+                    format!("expected a string of bit names, got {}", value),
+                )),
+            },
+            LeafType::Binary => match value {
+                Value::String(_) => Ok(()),
+                _ => Err((
+                    wrong_type(),
+                    format!("expected base64-encoded binary, got {}", value),
+                )),
+            },
+            LeafType::Union(types) => {
+                if types.iter().any(|member| member.check(value).is_ok()) {
+                    Ok(())
+                } else {
+                    Err((
+                        ViolationKind::NoUnionMember(value.clone()),
+                        format!("{} matches none of the union member types", value),
+                    ))
+                }
+            }
+            LeafType::Any => Ok(()),
+        }
+    }
+}
+
+/// Whether `value` is in one of `ranges`; any value is when there are none.
+fn in_ranges<N: PartialOrd + Copy>(value: N, ranges: &[(N, N)]) -> bool {
+    ranges.is_empty()
+        || ranges
+            .iter()
+            .any(|(min, max)| *min <= value && value <= *max)
+}
+
+/// Ranges in YANG syntax, e.g. `1..10 | 20..30`.
+fn format_ranges<N: fmt::Display + PartialEq>(ranges: &[(N, N)]) -> String {
+    let ranges: Vec<String> = ranges
+        .iter()
+        .map(|(min, max)| {
+            if min == max {
+                min.to_string()
+            } else {
+                format!("{}..{}", min, max)
+            }
+        })
+        .collect();
+    ranges.join(" | ")
+}
+
+/// A data node of a [`PayloadSchema`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PayloadNode {
+    /// A container.
+    Container,
+    /// A list with its key leaves, in order.
+    List(Vec<String>),
+    /// A leaf, and whether it is mandatory.
+    Leaf(LeafType, bool),
+    /// A leaf-list.
+    LeafList(LeafType),
+}
+
+/// One problem found in a payload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// Path of the offending node, with the keys of the list entries on the
+    /// way, e.g. `/interfaces/interface[name='eth0']/mtu`.
+    pub path: String,
+    /// The kind of problem.
+    pub kind: ViolationKind,
+    /// What is wrong with it, in English.
+    pub message: String,
+}
+
+/// Kind of a [`Violation`], for phrasing it in other words, e.g. with a
+/// [`MessageCatalog`](crate::MessageCatalog).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ViolationKind {
+    /// A node the schema does not have.
+    UnknownNode,
+    /// A list entry with the same keys as an earlier one.
+    DuplicateEntry,
+    /// A list entry without the named key leaves.
+    MissingKeys(Vec<String>),
+    /// A mandatory leaf left out of its container or list entry.
+    MissingMandatory,
+    /// A value of the wrong JSON type for its node.
+    WrongType(Value),
+    /// A number, or the length of a string, outside the allowed ranges.
+    OutOfRange(Value),
+    /// A string that is not one of the enum names.
+    UnknownEnum(Value),
+    /// A string that is not a set of the bit names.
+    UnknownBit(Value),
+    /// A value of none of the member types of a union.
+    NoUnionMember(Value),
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// Value types, list keys, mandatory leaves, ranges and lengths of the data
+/// nodes of the generated modules.
+///
+/// Nodes are identified by their schema path without module qualifiers or
+/// keys, e.g. `/interfaces/interface/mtu`, and RPC input nodes by
+/// `/<rpc>/input/<leaf>`. Nodes below a node missing from the schema, e.g.
+/// of a module whose schema was not merged in, are not checked.
+///
+/// Patterns, `must` and `when` expressions are not checked, and mandatory
+/// leaves are only reported missing from the containers and list entries
+/// present in the payload, not inside choices.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::payload::{LeafType, PayloadSchema};
+/// use serde_json::json;
+///
+/// let schema = PayloadSchema::new()
+///     .container("/interfaces")
+///     .list("/interfaces/interface", &["name"])
+///     .leaf("/interfaces/interface/name", LeafType::string(&[]), false)
+///     .leaf("/interfaces/interface/mtu", LeafType::integer(&[(68, 9216)]), true);
+///
+/// let payload = json!({"interface": [{"name": "eth0", "mtu": 10}, {"name": "eth1"}]});
+/// let violations = schema.validate("/interfaces", &payload).unwrap_err();
+/// assert_eq!(violations[0].to_string(), "/interfaces/interface[name='eth0']/mtu: 10 is out of range 68..9216");
+/// assert_eq!(violations[1].to_string(), "/interfaces/interface[name='eth1']/mtu: mandatory leaf is missing");
+///
+/// // A merge may leave mandatory leaves out
+/// assert!(schema.validate_merge("/interfaces", &json!({"interface": [{"name": "eth1"}]})).is_ok());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PayloadSchema {
+    nodes: HashMap<String, PayloadNode>,
+    /// Names of the child nodes of each path, `""` for the top level.
+    children: HashMap<String, Vec<String>>,
+}
+
+impl PayloadSchema {
+    /// Create an empty schema.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a container.
+    pub fn container(self, path: &str) -> Self {
+        self.with_node(path, PayloadNode::Container)
+    }
+
+    /// Add a list with its key leaves, in order.
+    pub fn list(self, path: &str, keys: &[&str]) -> Self {
+        let keys = keys.iter().map(|key| key.to_string()).collect();
+        self.with_node(path, PayloadNode::List(keys))
+    }
+
+    /// Add a leaf.
+    pub fn leaf(self, path: &str, leaf_type: LeafType, mandatory: bool) -> Self {
+        self.with_node(path, PayloadNode::Leaf(leaf_type, mandatory))
+    }
+
+    /// Add a leaf-list.
+    pub fn leaf_list(self, path: &str, leaf_type: LeafType) -> Self {
+        self.with_node(path, PayloadNode::LeafList(leaf_type))
+    }
+
+    /// Add a data node.
+    pub fn with_node(mut self, path: &str, node: PayloadNode) -> Self {
+        let (parent, name) = path.rsplit_once('/').unwrap_or(("", path));
+        let siblings = self.children.entry(parent.to_string()).or_default();
+        if !siblings.iter().any(|sibling| sibling == name) {
+            siblings.push(name.to_string());
+        }
+        self.nodes.insert(path.to_string(), node);
+        self
+    }
+
+    /// Add the nodes of another schema, e.g. of another generated module.
+    pub fn merge(mut self, other: PayloadSchema) -> Self {
+        for (path, names) in other.children {
+            let siblings = self.children.entry(path).or_default();
+            for name in names {
+                if !siblings.contains(&name) {
+                    siblings.push(name);
+                }
+            }
+        }
+        self.nodes.extend(other.nodes);
+        self
+    }
+
+    /// The node at `path`, if known.
+    pub fn node(&self, path: &str) -> Option<&PayloadNode> {
+        self.nodes.get(path)
+    }
+
+    /// Check the members of `payload`, the body of a request replacing or
+    /// creating the children of the node at `path` (`""` for the top level).
+    ///
+    /// Returns every violation found, in payload order.
+    pub fn validate(&self, path: &str, payload: &Value) -> Result<(), Vec<Violation>> {
+        self.check_payload(path, path, payload, true)
+    }
+
+    /// Like [`validate`](Self::validate), but for a merge (`PATCH`), which
+    /// may leave out mandatory leaves that are already set.
+    pub fn validate_merge(&self, path: &str, payload: &Value) -> Result<(), Vec<Violation>> {
+        self.check_payload(path, path, payload, false)
+    }
+
+    /// Check a payload for the node at `path`, whose data path with the
+    /// keys of the list entries on the way is `data_path`.
+    fn check_payload(
+        &self,
+        path: &str,
+        data_path: &str,
+        payload: &Value,
+        complete: bool,
+    ) -> Result<(), Vec<Violation>> {
+        let mut checker = Checker {
+            schema: self,
+            complete,
+            violations: Vec::new(),
+        };
+        match payload {
+            Value::Object(members) => {
+                for (member, value) in members {
+                    checker.check_member(path, data_path, member, value);
+                }
+            }
+            _ => checker.report(
+                data_path,
+                ViolationKind::WrongType(payload.clone()),
+                format!("expected a JSON object, got {}", payload),
+            ),
+        }
+        if checker.violations.is_empty() {
+            Ok(())
+        } else {
+            Err(checker.violations)
+        }
+    }
+}
+
+/// Walks a payload, collecting violations.
+struct Checker<'a> {
+    schema: &'a PayloadSchema,
+    complete: bool,
+    violations: Vec<Violation>,
+}
+
+impl Checker<'_> {
+    fn report(&mut self, data_path: &str, kind: ViolationKind, message: String) {
+        self.violations.push(Violation {
+            path: if data_path.is_empty() {
+                "/".to_string()
+            } else {
+                data_path.to_string()
+            },
+            kind,
+            message,
+        });
+    }
+
+    /// Check a member of the object at `schema_path`.
+    fn check_member(&mut self, schema_path: &str, data_path: &str, member: &str, value: &Value) {
+        // RFC 7952 metadata annotations
+        if member.starts_with('@') {
+            return;
+        }
+        let name = member.split_once(':').map_or(member, |(_, name)| name);
+        let child_schema = format!("{}/{}", schema_path, name);
+        let child_data = format!("{}/{}", data_path, name);
+
+        let node = match self.schema.node(&child_schema) {
+            Some(node) => node,
+            None => {
+                // Top-level nodes may belong to modules missing from the schema
+                if self.schema.node(schema_path).is_some() {
+                    self.report(
+                        &child_data,
+                        ViolationKind::UnknownNode,
+                        "unknown node".to_string(),
+                    );
+                }
+                return;
+            }
+        };
+        match node {
+            PayloadNode::Container => self.check_object(&child_schema, &child_data, value),
+            PayloadNode::List(keys) => match value {
+                Value::Array(entries) => {
+                    let mut seen = HashSet::new();
+                    for entry in entries {
+                        let (entry_data, identified) = self.check_keys(&child_data, keys, entry);
+                        if identified && !seen.insert(entry_data.clone()) {
+                            self.report(
+                                &entry_data,
+                                ViolationKind::DuplicateEntry,
+                                "duplicate list entry".to_string(),
+                            );
+                        }
+                        self.check_object(&child_schema, &entry_data, entry);
+                    }
+                }
+                _ => self.report(
+                    &child_data,
+                    ViolationKind::WrongType(value.clone()),
+                    format!("expected an array of list entries, got {}", value),
+                ),
+            },
+            PayloadNode::Leaf(leaf_type, _) => {
+                if let Err((kind, message)) = leaf_type.check(value) {
+                    self.report(&child_data, kind, message);
+                }
+            }
+            PayloadNode::LeafList(leaf_type) => match value {
+                Value::Array(values) => {
+                    for value in values {
+                        if let Err((kind, message)) = leaf_type.check(value) {
+                            self.report(&child_data, kind, message);
+                        }
+                    }
+                }
+                _ => self.report(
+                    &child_data,
+                    ViolationKind::WrongType(value.clone()),
+                    format!("expected an array of values, got {}", value),
+                ),
+            },
+        }
+    }
+
+    /// Check a list entry has its keys, returning its data path with them
+    /// and whether the keys identify it.
+    fn check_keys(&mut self, data_path: &str, keys: &[String], entry: &Value) -> (String, bool) {
+        let mut entry_data = data_path.to_string();
+        let mut missing = Vec::new();
+        for key in keys {
+            let value = entry.as_object().and_then(|members| {
+                members
+                    .iter()
+                    .find(|(member, _)| {
+                        member
+                            .split_once(':')
+                            .map_or(member.as_str(), |(_, name)| name)
+                            == key
+                    })
+                    .map(|(_, value)| value)
+            });
+            match value {
+                Some(Value::String(text)) => entry_data.push_str(&format!("[{}='{}']", key, text)),
+                Some(value) => entry_data.push_str(&format!("[{}='{}']", key, value)),
+                None => missing.push(key.as_str()),
+            }
+        }
+        if !missing.is_empty() && entry.is_object() {
+            self.report(
+                &entry_data,
+                ViolationKind::MissingKeys(missing.iter().map(|key| key.to_string()).collect()),
+                format!("list entry is missing key {}", missing.join(", ")),
+            );
+        }
+        (entry_data, !keys.is_empty() && missing.is_empty())
+    }
+
+    /// Check the members of a container or list entry.
+    fn check_object(&mut self, schema_path: &str, data_path: &str, value: &Value) {
+        let members = match value {
+            Value::Object(members) => members,
+            _ => {
+                self.report(
+                    data_path,
+                    ViolationKind::WrongType(value.clone()),
+                    format!("expected a JSON object, got {}", value),
+                );
+                return;
+            }
+        };
+        for (member, value) in members {
+            self.check_member(schema_path, data_path, member, value);
+        }
+        if !self.complete {
+            return;
+        }
+        let names = self
+            .schema
+            .children
+            .get(schema_path)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        for name in names {
+            let path = format!("{}/{}", schema_path, name);
+            if !matches!(self.schema.node(&path), Some(PayloadNode::Leaf(_, true))) {
+                continue;
+            }
+            let present = members.keys().any(|member| {
+                member
+                    .split_once(':')
+                    .map_or(member.as_str(), |(_, local)| local)
+                    == name
+            });
+            if !present {
+                self.report(
+                    &format!("{}/{}", data_path, name),
+                    ViolationKind::MissingMandatory,
+                    "mandatory leaf is missing".to_string(),
+                );
+            }
+        }
+    }
+}
+
+/// Transport decorator that checks request bodies against a
+/// [`PayloadSchema`] before sending them.
+///
+/// `PUT`, `POST` and `PATCH` requests with a JSON body under
+/// `/restconf/data` or `/restconf/operations` are checked; a body that does
+/// not match fails with [`RpcError::ValidationError`] listing every
+/// violation, and is not sent. `PATCH` bodies may leave out mandatory
+/// leaves. YANG Patch bodies, other media types and other requests are
+/// passed through unchanged. With a [`MessageCatalog`], the violations are
+/// described in the words of the catalog.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use rustconf_runtime::{HttpTransport, HttpRequest, HttpResponse, RpcError};
+/// # use async_trait::async_trait;
+/// # struct MyTransport;
+/// # #[async_trait]
+/// # impl HttpTransport for MyTransport {
+/// #     async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, RpcError> {
+/// #         todo!()
+/// #     }
+/// # }
+/// use rustconf_runtime::payload::{PayloadSchema, ValidatingTransport};
+/// use rustconf_runtime::RestconfClient;
+///
+/// # fn example(schema: PayloadSchema) -> Result<(), RpcError> {
+/// // `schema` comes from the generated `schema::payload_schema()`
+/// let transport = ValidatingTransport::new(MyTransport, schema);
+/// let client = RestconfClient::new("https://device.example.com", transport)?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ValidatingTransport<T> {
+    inner: T,
+    schema: PayloadSchema,
+    catalog: Option<Arc<dyn MessageCatalog>>,
+}
+
+impl<T: fmt::Debug> fmt::Debug for ValidatingTransport<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ValidatingTransport")
+            .field("inner", &self.inner)
+            .field("schema", &self.schema)
+            .field("catalog", &self.catalog.is_some())
+            .finish()
+    }
+}
+
+impl<T: HttpTransport> ValidatingTransport<T> {
+    /// Wrap a transport, checking request bodies against `schema`.
+    pub fn new(inner: T, schema: PayloadSchema) -> Self {
+        Self {
+            inner,
+            schema,
+            catalog: None,
+        }
+    }
+
+    /// Describe violations with the messages of `catalog`, falling back to
+    /// the English message for those it has none for.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use rustconf_runtime::payload::{PayloadSchema, ValidatingTransport, ViolationKind};
+    /// # use rustconf_runtime::{HttpTransport, RpcError};
+    /// use rustconf_runtime::error::MessageKey;
+    ///
+    /// # fn example<T: HttpTransport>(transport: T, schema: PayloadSchema) {
+    /// let transport = ValidatingTransport::new(transport, schema).with_catalog(
+    ///     |key: &MessageKey<'_>| match key {
+    ///         MessageKey::Violation(violation) => match &violation.kind {
+    ///             ViolationKind::MissingMandatory => Some("Pflichtfeld fehlt".to_string()),
+    ///             ViolationKind::OutOfRange(value) => {
+    ///                 Some(format!("{} liegt außerhalb des Bereichs", value))
+    ///             }
+    ///             _ => None,
+    ///         },
+    ///         _ => None,
+    ///     },
+    /// );
+    /// # }
+    /// ```
+    pub fn with_catalog(mut self, catalog: impl MessageCatalog + 'static) -> Self {
+        self.catalog = Some(Arc::new(catalog));
+        self
+    }
+
+    /// Describe violations with the messages of a shared catalog.
+    pub fn with_shared_catalog(mut self, catalog: Arc<dyn MessageCatalog>) -> Self {
+        self.catalog = Some(catalog);
+        self
+    }
+
+    /// The wrapped transport.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// The schema request bodies are checked against.
+    pub fn schema(&self) -> &PayloadSchema {
+        &self.schema
+    }
+
+    /// Check the body of a request, if it is one that is checked.
+    fn check(&self, request: &HttpRequest) -> Result<(), RpcError> {
+        if !matches!(
+            request.method,
+            HttpMethod::PUT | HttpMethod::POST | HttpMethod::PATCH
+        ) {
+            return Ok(());
+        }
+        let Some(body) = request.body.as_ref().filter(|body| !body.is_empty()) else {
+            return Ok(());
+        };
+        let is_json = request.headers.iter().any(|(name, value)| {
+            let media_type = value.split(';').next().unwrap_or_default().trim();
+            name.eq_ignore_ascii_case("content-type")
+                && (media_type.eq_ignore_ascii_case("application/yang-data+json")
+                    || media_type.eq_ignore_ascii_case("application/json"))
+        });
+        let url = request.url.split(['?', '#']).next().unwrap_or_default();
+        let is_restconf = url.contains("/restconf/data") || url.contains("/restconf/operations");
+        if !is_json || !is_restconf {
+            return Ok(());
+        }
+        // Bodies that are not JSON at all are left to the server to reject
+        let Ok(payload) = serde_json::from_slice::<Value>(body) else {
+            return Ok(());
+        };
+
+        let (resource, _) = resource_path(url);
+        let mut path = String::new();
+        for name in &resource {
+            path.push('/');
+            path.push_str(name);
+        }
+        let mut data_segments = self.data_segments(url, &resource);
+        // A `PUT` of a resource names the resource itself in the body,
+        // while a `POST` names the child it creates or the operation input
+        let payload = match (&payload, resource.last()) {
+            (Value::Object(members), Some(last)) if members.len() == 1 => {
+                let member = members
+                    .keys()
+                    .next()
+                    .map(String::as_str)
+                    .unwrap_or_default();
+                let name = member.split_once(':').map_or(member, |(_, name)| name);
+                if name == last {
+                    path.truncate(path.len() - last.len() - 1);
+                    data_segments.pop();
+                }
+                payload
+            }
+            (Value::Object(members), None) => match members.get("ietf-restconf:data") {
+                Some(data) => data.clone(),
+                None => payload,
+            },
+            _ => payload,
+        };
+
+        let data_path: String = data_segments
+            .iter()
+            .map(|segment| format!("/{}", segment))
+            .collect();
+        let complete = request.method != HttpMethod::PATCH;
+        let result = self
+            .schema
+            .check_payload(&path, &data_path, &payload, complete);
+        result.map_err(|violations| {
+            let report: Vec<String> = violations
+                .iter()
+                .map(|violation| {
+                    let message = self
+                        .catalog
+                        .as_ref()
+                        .and_then(|catalog| catalog.message(&MessageKey::Violation(violation)))
+                        .unwrap_or_else(|| violation.message.clone());
+                    format!("  {}: {}", violation.path, message)
+                })
+                .collect();
+            RpcError::ValidationError(format!(
+                "request body of {} {} does not match the schema:\n{}",
+                request.method.as_str(),
+                request.url,
+                report.join("\n")
+            ))
+        })
+    }
+
+    /// The data path segments of the resource `url` addresses, with the keys
+    /// of list entries, e.g. `["interfaces", "interface[name='eth0']"]`.
+    fn data_segments(&self, url: &str, resource: &[String]) -> Vec<String> {
+        let path = url.split(['?', '#']).next().unwrap_or_default();
+        let segments = path
+            .split_once("/restconf/")
+            .map(|(_, rest)| rest)
+            .unwrap_or_default()
+            .split('/')
+            .skip(1)
+            .filter(|segment| !segment.is_empty());
+
+        let mut schema_path = String::new();
+        let mut data = Vec::new();
+        for (segment, name) in segments.zip(resource) {
+            schema_path.push('/');
+            schema_path.push_str(name);
+            let mut data_segment = name.clone();
+            if let (Some((_, values)), Some(PayloadNode::List(keys))) =
+                (segment.split_once('='), self.schema.node(&schema_path))
+            {
+                for (key, value) in keys.iter().zip(values.split(',')) {
+                    data_segment.push_str(&format!("[{}='{}']", key, percent_decode(value)));
+                }
+            }
+            data.push(data_segment);
+        }
+        data
+    }
+}
+
+#[async_trait]
+impl<T: HttpTransport> HttpTransport for ValidatingTransport<T> {
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, RpcError> {
+        self.check(&request)?;
+        self.inner.execute(request).await
+    }
+}
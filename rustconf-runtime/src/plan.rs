@@ -0,0 +1,340 @@
+//! Ordered execution of dependent configuration changes.
+//!
+//! Pushing configuration often has to follow the model's references: VLANs
+//! before the interfaces that use them, interfaces before the BGP sessions
+//! bound to them. A [`ChangePlan`] declares each change as a named step with
+//! the steps it depends on, runs independent steps concurrently, and undoes
+//! completed steps if a later one fails.
+
+use std::collections::{HashMap, HashSet};
+use std::future::{poll_fn, Future};
+use std::pin::Pin;
+use std::task::Poll;
+
+use crate::error::RpcError;
+
+type StepFuture<'a> = Pin<Box<dyn Future<Output = Result<(), RpcError>> + 'a>>;
+type StepFn<'a> = Box<dyn FnOnce() -> StepFuture<'a> + 'a>;
+
+/// A named change in a [`ChangePlan`].
+///
+/// The change is usually one or more generated operations; its result value
+/// is discarded.
+pub struct PlanStep<'a> {
+    name: String,
+    dependencies: Vec<String>,
+    apply: StepFn<'a>,
+    rollback: Option<StepFn<'a>>,
+}
+
+impl<'a> PlanStep<'a> {
+    /// Create a step that runs `apply`.
+    pub fn new<F, Fut, R>(name: impl Into<String>, apply: F) -> Self
+    where
+        F: FnOnce() -> Fut + 'a,
+        Fut: Future<Output = Result<R, RpcError>> + 'a,
+    {
+        Self {
+            name: name.into(),
+            dependencies: Vec::new(),
+            apply: boxed(apply),
+            rollback: None,
+        }
+    }
+
+    /// Run this step only after the named steps have succeeded.
+    pub fn after<I, S>(mut self, steps: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.dependencies.extend(steps.into_iter().map(Into::into));
+        self
+    }
+
+    /// Undo this step with `rollback` if a later step fails.
+    ///
+    /// Only called if the step itself succeeded.
+    pub fn on_rollback<F, Fut, R>(mut self, rollback: F) -> Self
+    where
+        F: FnOnce() -> Fut + 'a,
+        Fut: Future<Output = Result<R, RpcError>> + 'a,
+    {
+        self.rollback = Some(boxed(rollback));
+        self
+    }
+
+    /// Name of the step.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Names of the steps this step runs after.
+    pub fn dependencies(&self) -> &[String] {
+        &self.dependencies
+    }
+}
+
+fn boxed<'a, F, Fut, R>(f: F) -> StepFn<'a>
+where
+    F: FnOnce() -> Fut + 'a,
+    Fut: Future<Output = Result<R, RpcError>> + 'a,
+{
+    Box::new(move || {
+        let future = f();
+        Box::pin(async move { future.await.map(drop) }) as StepFuture<'a>
+    })
+}
+
+/// A set of changes with dependencies between them.
+///
+/// [`ChangePlan::run`] starts each step as soon as all of its dependencies
+/// have succeeded, so independent steps run concurrently. They run on the
+/// calling task; no tasks are spawned, so steps can borrow the client and
+/// local data. When a step fails, the other steps already running are allowed
+/// to finish, no new steps are started, and the rollbacks of every completed
+/// step run one at a time, most recently completed first.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use rustconf_runtime::{HttpTransport, HttpRequest, HttpResponse, HttpMethod, RpcError};
+/// # use async_trait::async_trait;
+/// # struct MyTransport;
+/// # #[async_trait]
+/// # impl HttpTransport for MyTransport {
+/// #     async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, RpcError> {
+/// #         todo!()
+/// #     }
+/// # }
+/// use rustconf_runtime::{ChangePlan, PlanStep, RestconfClient};
+/// use serde_json::json;
+///
+/// # async fn example() -> Result<(), RpcError> {
+/// let client = RestconfClient::new("https://device.example.com", MyTransport)?;
+/// let vlans = json!({ "vlans": { "vlan": [{ "id": 10 }] } });
+/// let interfaces = json!({ "interfaces": { "interface": [{ "name": "eth0", "vlan": 10 }] } });
+///
+/// ChangePlan::new()
+///     .step(
+///         PlanStep::new("vlans", || {
+///             client.raw_request_json::<_, ()>(HttpMethod::PATCH, "/restconf/data/vlans", Some(&vlans))
+///         })
+///         .on_rollback(|| {
+///             client.raw_request(HttpMethod::DELETE, "/restconf/data/vlans/vlan=10", None)
+///         }),
+///     )
+///     .step(
+///         PlanStep::new("interfaces", || {
+///             client.raw_request_json::<_, ()>(
+///                 HttpMethod::PATCH,
+///                 "/restconf/data/interfaces",
+///                 Some(&interfaces),
+///             )
+///         })
+///         .after(["vlans"]),
+///     )
+///     .run()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct ChangePlan<'a> {
+    steps: Vec<PlanStep<'a>>,
+}
+
+impl<'a> ChangePlan<'a> {
+    /// Create an empty plan.
+    pub fn new() -> Self {
+        Self { steps: Vec::new() }
+    }
+
+    /// Add a step to the plan.
+    pub fn step(mut self, step: PlanStep<'a>) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Names of the steps in an order that satisfies their dependencies.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RpcError::ConfigurationError` if step names are not unique, a
+    /// step depends on a step that is not in the plan, or the dependencies
+    /// form a cycle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustconf_runtime::{ChangePlan, PlanStep, RpcError};
+    ///
+    /// let noop = || async { Ok::<_, RpcError>(()) };
+    /// let plan = ChangePlan::new()
+    ///     .step(PlanStep::new("bgp", noop).after(["interfaces"]))
+    ///     .step(PlanStep::new("interfaces", noop).after(["vlans"]))
+    ///     .step(PlanStep::new("vlans", noop));
+    /// assert_eq!(plan.order().unwrap(), ["vlans", "interfaces", "bgp"]);
+    ///
+    /// let cyclic = ChangePlan::new()
+    ///     .step(PlanStep::new("a", noop).after(["b"]))
+    ///     .step(PlanStep::new("b", noop).after(["a"]));
+    /// assert!(cyclic.order().is_err());
+    /// ```
+    pub fn order(&self) -> Result<Vec<&str>, RpcError> {
+        Ok(self
+            .stages()?
+            .into_iter()
+            .flatten()
+            .map(|index| self.steps[index].name.as_str())
+            .collect())
+    }
+
+    /// Run every step in dependency order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RpcError::ConfigurationError` without running anything if the
+    /// plan is invalid (see [`ChangePlan::order`]). If a step fails, returns
+    /// `RpcError::StepFailed` naming it once the completed steps have been
+    /// rolled back, or `RpcError::RollbackFailed` wrapping it if some
+    /// rollbacks failed.
+    pub async fn run(self) -> Result<(), RpcError> {
+        // Validates the plan; afterwards every step is reachable
+        self.stages()?;
+
+        let mut waiting = self.steps;
+        let mut running: Vec<(String, Option<StepFn<'a>>, StepFuture<'a>)> = Vec::new();
+        let mut succeeded = HashSet::new();
+        let mut rollbacks = Vec::new();
+        let mut failure = None;
+
+        loop {
+            if failure.is_none() {
+                let (ready, blocked) = waiting.into_iter().partition(|step: &PlanStep<'a>| {
+                    step.dependencies
+                        .iter()
+                        .all(|dependency| succeeded.contains(dependency))
+                });
+                waiting = blocked;
+                for step in ready {
+                    let future = (step.apply)();
+                    running.push((step.name, step.rollback, future));
+                }
+            }
+            if running.is_empty() {
+                break;
+            }
+
+            // Wait for at least one running step to finish
+            let finished = poll_fn(|cx| {
+                let mut finished = Vec::new();
+                let mut index = 0;
+                while index < running.len() {
+                    if let Poll::Ready(result) = running[index].2.as_mut().poll(cx) {
+                        let (name, rollback, _) = running.remove(index);
+                        finished.push((name, rollback, result));
+                    } else {
+                        index += 1;
+                    }
+                }
+                if finished.is_empty() {
+                    Poll::Pending
+                } else {
+                    Poll::Ready(finished)
+                }
+            })
+            .await;
+
+            for (name, rollback, result) in finished {
+                match result {
+                    Ok(()) => {
+                        succeeded.insert(name.clone());
+                        rollbacks.push((name, rollback));
+                    }
+                    Err(error) if failure.is_none() => {
+                        failure = Some(RpcError::StepFailed {
+                            step: name,
+                            error: Box::new(error),
+                        });
+                    }
+                    Err(_) => {}
+                }
+            }
+        }
+
+        let Some(error) = failure else {
+            return Ok(());
+        };
+
+        let mut failed_rollbacks = Vec::new();
+        for (name, rollback) in rollbacks.into_iter().rev() {
+            if let Some(rollback) = rollback {
+                if let Err(rollback_error) = rollback().await {
+                    failed_rollbacks.push((name, rollback_error));
+                }
+            }
+        }
+        if failed_rollbacks.is_empty() {
+            Err(error)
+        } else {
+            Err(RpcError::RollbackFailed {
+                error: Box::new(error),
+                failed_restores: failed_rollbacks,
+            })
+        }
+    }
+
+    /// Group step indices into stages whose dependencies are all in earlier stages.
+    fn stages(&self) -> Result<Vec<Vec<usize>>, RpcError> {
+        let mut indices = HashMap::new();
+        for (index, step) in self.steps.iter().enumerate() {
+            if indices.insert(step.name.as_str(), index).is_some() {
+                return Err(RpcError::ConfigurationError(format!(
+                    "Duplicate plan step '{}'",
+                    step.name
+                )));
+            }
+        }
+        for step in &self.steps {
+            if let Some(missing) = step
+                .dependencies
+                .iter()
+                .find(|dependency| !indices.contains_key(dependency.as_str()))
+            {
+                return Err(RpcError::ConfigurationError(format!(
+                    "Plan step '{}' depends on unknown step '{}'",
+                    step.name, missing
+                )));
+            }
+        }
+
+        let mut done = HashSet::new();
+        let mut stages = Vec::new();
+        while done.len() < self.steps.len() {
+            let stage: Vec<usize> = (0..self.steps.len())
+                .filter(|index| !done.contains(index))
+                .filter(|&index| {
+                    self.steps[index]
+                        .dependencies
+                        .iter()
+                        .all(|dependency| done.contains(&indices[dependency.as_str()]))
+                })
+                .collect();
+            if stage.is_empty() {
+                let mut cycle: Vec<&str> = (0..self.steps.len())
+                    .filter(|index| !done.contains(index))
+                    .map(|index| self.steps[index].name.as_str())
+                    .collect();
+                cycle.sort_unstable();
+                return Err(RpcError::ConfigurationError(format!(
+                    "Plan steps have circular dependencies: {}",
+                    cycle.join(", ")
+                )));
+            }
+            done.extend(stage.iter().copied());
+            stages.push(stage);
+        }
+        Ok(stages)
+    }
+}
@@ -0,0 +1,207 @@
+//! Readable rendering of configuration and state trees.
+//!
+//! Generated types implement [`Pretty`] when pretty printers are enabled in
+//! the generator. The output uses YANG names rather than Rust field names,
+//! shows the units of leaves, and fills in unset leaves that have a default,
+//! marked as such, so the effective configuration can be read from a log:
+//!
+//! ```text
+//! hostname: edge-1
+//! mtu: 1500 bytes (default)
+//! dns-servers: [192.0.2.53, 198.51.100.53]
+//! interface[name=eth0]:
+//!   enabled: true
+//! ```
+
+use serde::Serialize;
+use serde_json::Value;
+
+/// A type that can be rendered by a [`PrettyPrinter`].
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::{Pretty, PrettyPrinter};
+///
+/// struct Interface {
+///     name: String,
+///     mtu: Option<u16>,
+/// }
+///
+/// impl Pretty for Interface {
+///     fn write_pretty(&self, printer: &mut PrettyPrinter) {
+///         printer.leaf("name", Some(&self.name), None, None);
+///         printer.leaf("mtu", self.mtu.as_ref(), Some("bytes"), Some("1500"));
+///     }
+/// }
+///
+/// let interface = Interface { name: "eth0".to_string(), mtu: None };
+/// assert_eq!(interface.pretty(), "name: eth0\nmtu: 1500 bytes (default)");
+/// ```
+pub trait Pretty {
+    /// Write the children of this node to the printer.
+    fn write_pretty(&self, printer: &mut PrettyPrinter);
+
+    /// The key of a list entry, such as `name=eth0`, shown after the list
+    /// name. Entries without keys are shown by position.
+    fn pretty_key(&self) -> String {
+        String::new()
+    }
+
+    /// Render this node as indented text.
+    fn pretty(&self) -> String {
+        let mut printer = PrettyPrinter::new();
+        self.write_pretty(&mut printer);
+        printer.finish()
+    }
+}
+
+/// Builds the indented text of a [`Pretty`] tree, one node per line.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::pretty::render;
+/// use rustconf_runtime::{Pretty, PrettyPrinter};
+///
+/// struct Interface {
+///     name: String,
+///     enabled: bool,
+/// }
+///
+/// impl Pretty for Interface {
+///     fn write_pretty(&self, printer: &mut PrettyPrinter) {
+///         printer.leaf("enabled", Some(&self.enabled), None, None);
+///     }
+///
+///     fn pretty_key(&self) -> String {
+///         format!("name={}", render(&self.name))
+///     }
+/// }
+///
+/// let interfaces = [Interface { name: "eth0".to_string(), enabled: true }];
+/// let mut printer = PrettyPrinter::new();
+/// printer.leaf_list("dns-servers", &["192.0.2.53", "198.51.100.53"], None);
+/// printer.list("interface", &interfaces);
+/// assert_eq!(
+///     printer.finish(),
+///     "dns-servers: [192.0.2.53, 198.51.100.53]\ninterface[name=eth0]:\n  enabled: true"
+/// );
+/// ```
+#[derive(Debug, Default)]
+pub struct PrettyPrinter {
+    output: String,
+    depth: usize,
+}
+
+impl PrettyPrinter {
+    /// Create an empty printer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Write a leaf. Unset leaves are shown with their default, if any, and
+    /// left out otherwise.
+    pub fn leaf<V: Serialize + ?Sized>(
+        &mut self,
+        name: &str,
+        value: Option<&V>,
+        units: Option<&str>,
+        default: Option<&str>,
+    ) {
+        let (value, annotation) = match (value, default) {
+            (Some(value), _) => (render(value), ""),
+            (None, Some(default)) => (default.to_string(), " (default)"),
+            (None, None) => return,
+        };
+        let mut line = name.to_string();
+        if !value.is_empty() {
+            line.push_str(": ");
+            line.push_str(&value);
+            if let Some(units) = units {
+                line.push(' ');
+                line.push_str(units);
+            }
+        }
+        line.push_str(annotation);
+        self.line(&line);
+    }
+
+    /// Write a leaf-list on one line, or nothing if it is empty.
+    pub fn leaf_list<V: Serialize>(&mut self, name: &str, values: &[V], units: Option<&str>) {
+        if values.is_empty() {
+            return;
+        }
+        let values: Vec<String> = values.iter().map(render).collect();
+        let mut line = format!("{}: [{}]", name, values.join(", "));
+        if let Some(units) = units {
+            line.push(' ');
+            line.push_str(units);
+        }
+        self.line(&line);
+    }
+
+    /// Write a container and its children, or nothing if it is absent.
+    pub fn container<T: Pretty + ?Sized>(&mut self, name: &str, value: Option<&T>) {
+        if let Some(value) = value {
+            self.nested(name, value);
+        }
+    }
+
+    /// Write each entry of a list with its key.
+    pub fn list<T: Pretty>(&mut self, name: &str, entries: &[T]) {
+        for (index, entry) in entries.iter().enumerate() {
+            let key = entry.pretty_key();
+            let key = if key.is_empty() {
+                index.to_string()
+            } else {
+                key
+            };
+            self.nested(&format!("{}[{}]", name, key), entry);
+        }
+    }
+
+    /// The rendered text, without a trailing newline.
+    pub fn finish(mut self) -> String {
+        if self.output.ends_with('\n') {
+            self.output.pop();
+        }
+        self.output
+    }
+
+    fn nested<T: Pretty + ?Sized>(&mut self, header: &str, value: &T) {
+        self.line(&format!("{}:", header));
+        self.depth += 1;
+        value.write_pretty(self);
+        self.depth -= 1;
+    }
+
+    fn line(&mut self, line: &str) {
+        for _ in 0..self.depth {
+            self.output.push_str("  ");
+        }
+        self.output.push_str(line);
+        self.output.push('\n');
+    }
+}
+
+/// Render a value for display: strings without quotes, empty leaves as
+/// nothing, and anything else as compact JSON.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::pretty::render;
+///
+/// assert_eq!(render("eth0"), "eth0");
+/// assert_eq!(render(&1500u16), "1500");
+/// assert_eq!(render(&()), "");
+/// ```
+pub fn render<V: Serialize + ?Sized>(value: &V) -> String {
+    match serde_json::to_value(value) {
+        Ok(Value::String(s)) => s,
+        Ok(Value::Null) => String::new(),
+        Ok(value) => value.to_string(),
+        Err(e) => format!("<{}>", e),
+    }
+}
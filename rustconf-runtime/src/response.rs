@@ -0,0 +1,84 @@
+//! Typed response bodies together with the status and headers they came with.
+//!
+//! Generated operations return only the decoded body. Their `*_full`
+//! variants return a [`ResponseEnvelope`] instead, for callers that need
+//! headers such as `ETag`, `Location` or a request id.
+
+use crate::transport::HttpResponse;
+
+/// The status and headers of a response, with its decoded body.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::{HttpResponse, ResponseEnvelope};
+///
+/// let mut response = HttpResponse::new(201);
+/// response.headers.push(("ETag".to_string(), "\"a1\"".to_string()));
+/// response.headers.push(("X-Request-Id".to_string(), "7f3c".to_string()));
+///
+/// let envelope = ResponseEnvelope::new(response, ());
+/// assert_eq!(envelope.status_code, 201);
+/// assert_eq!(envelope.etag(), Some("\"a1\""));
+/// assert_eq!(envelope.header("x-request-id"), Some("7f3c"));
+/// assert_eq!(envelope.location(), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseEnvelope<T> {
+    /// HTTP status code.
+    pub status_code: u16,
+    /// Response headers, in the order received.
+    pub headers: Vec<(String, String)>,
+    /// The decoded body.
+    pub body: T,
+}
+
+impl<T> ResponseEnvelope<T> {
+    /// Pair the status and headers of `response` with its decoded `body`.
+    ///
+    /// The raw body of `response` is dropped.
+    pub fn new(response: HttpResponse, body: T) -> Self {
+        Self {
+            status_code: response.status_code,
+            headers: response.headers,
+            body,
+        }
+    }
+
+    /// The first value of a header, ignoring the case of its name.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The `ETag` header, for conditional requests.
+    pub fn etag(&self) -> Option<&str> {
+        self.header("ETag")
+    }
+
+    /// The `Last-Modified` header.
+    pub fn last_modified(&self) -> Option<&str> {
+        self.header("Last-Modified")
+    }
+
+    /// The `Location` header, the URL of a resource created by POST.
+    pub fn location(&self) -> Option<&str> {
+        self.header("Location")
+    }
+
+    /// Convert the body, keeping the status and headers.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> ResponseEnvelope<U> {
+        ResponseEnvelope {
+            status_code: self.status_code,
+            headers: self.headers,
+            body: f(self.body),
+        }
+    }
+
+    /// The decoded body, discarding the status and headers.
+    pub fn into_body(self) -> T {
+        self.body
+    }
+}
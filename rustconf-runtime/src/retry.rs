@@ -0,0 +1,413 @@
+//! Retrying failed requests without duplicating creates.
+//!
+//! A `GET`, `PUT` or `DELETE` that failed on the way to the device can be
+//! sent again: repeating it leaves the datastore as one request would. A
+//! `POST` that creates a list entry or invokes an operation cannot, since
+//! the first attempt may have been applied before the connection dropped.
+//! [`RetryPolicy`] classifies requests accordingly and only retries the
+//! idempotent ones by default. With
+//! [`with_idempotency_keys`](RetryPolicy::with_idempotency_keys), each `POST`
+//! carries an [`IdempotencyKey`] header that stays the same across its
+//! retries, so a server that honors the header applies it once, and such
+//! requests are retried as well.
+//!
+//! `RetryingTransport` (feature `retry`) applies a policy to every request of
+//! a transport.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::error::RpcError;
+use crate::transport::{HttpRequest, HttpResponse};
+
+#[cfg(feature = "retry")]
+use crate::transport::{HttpMethod, HttpTransport};
+#[cfg(feature = "retry")]
+use async_trait::async_trait;
+
+/// Key identifying one logical operation across its retries.
+///
+/// `RetryingTransport` generates a key for every `POST` it sends when the
+/// policy has idempotency keys enabled. To keep the key across retries made
+/// by the application, for example after a restart, put the key in the
+/// request context and it is used instead.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::{IdempotencyKey, RequestContext};
+///
+/// let key = IdempotencyKey::generate();
+/// assert_eq!(key.as_str().len(), 36);
+/// assert_ne!(key, IdempotencyKey::generate());
+///
+/// let context = RequestContext::new().with(IdempotencyKey::new("order-1042"));
+/// assert_eq!(context.get::<IdempotencyKey>().unwrap().as_str(), "order-1042");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IdempotencyKey(String);
+
+impl IdempotencyKey {
+    /// A key with the given value.
+    pub fn new(key: impl Into<String>) -> Self {
+        Self(key.into())
+    }
+
+    /// A new random (version 4) UUID.
+    ///
+    /// The randomness comes from the standard library's randomly seeded
+    /// hasher: keys are unique, but not meant to be unguessable.
+    pub fn generate() -> Self {
+        let high = random_u64().to_be_bytes();
+        let low = random_u64().to_be_bytes();
+        let mut bytes = [0u8; 16];
+        bytes[..8].copy_from_slice(&high);
+        bytes[8..].copy_from_slice(&low);
+        bytes[6] = (bytes[6] & 0x0f) | 0x40;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+        let hex: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+        Self(format!(
+            "{}-{}-{}-{}-{}",
+            &hex[..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..]
+        ))
+    }
+
+    /// The key's value.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for IdempotencyKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+fn random_u64() -> u64 {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let mut hasher = RandomState::new().build_hasher();
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_nanos());
+    hasher.write_u128(nanos);
+    hasher.write_u64(COUNTER.fetch_add(1, Ordering::Relaxed));
+    hasher.finish()
+}
+
+/// When and how often to retry a failed request.
+///
+/// A request is retried when the transport fails to deliver it, or when the
+/// server answers `429 Too Many Requests`, `502 Bad Gateway`,
+/// `503 Service Unavailable` or `504 Gateway Timeout`. Only requests that
+/// are [safe to repeat](Self::is_retryable) are retried. The delay doubles
+/// after each attempt, up to the maximum backoff; a `Retry-After` header
+/// in seconds replaces it, within the same maximum.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use rustconf_runtime::{HttpMethod, HttpRequest, RetryPolicy};
+///
+/// let policy = RetryPolicy::new()
+///     .with_max_attempts(4)
+///     .with_backoff(Duration::from_millis(200), Duration::from_secs(2));
+/// assert_eq!(policy.backoff(1), Duration::from_millis(200));
+/// assert_eq!(policy.backoff(3), Duration::from_millis(800));
+/// assert_eq!(policy.backoff(10), Duration::from_secs(2));
+///
+/// let create = HttpRequest::new(HttpMethod::POST, "https://device/restconf/data/interfaces");
+/// assert!(!policy.is_retryable(&create));
+///
+/// let policy = policy.with_idempotency_keys("Idempotency-Key");
+/// assert!(!policy.is_retryable(&create));
+/// assert!(policy.is_retryable(&create.with_header("Idempotency-Key", "order-1042")));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    idempotency_header: Option<String>,
+    retry_unsafe: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+            idempotency_header: None,
+            retry_unsafe: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Three attempts of idempotent requests, waiting 100 ms and then
+    /// 200 ms, without idempotency keys.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send a request at most `attempts` times, including the first.
+    pub fn with_max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = attempts.max(1);
+        self
+    }
+
+    /// Wait `initial` before the first retry, doubling the wait after each
+    /// retry up to `max`.
+    pub fn with_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    /// Send an idempotency key in the `header` of every `POST`, and retry
+    /// `POST` requests that carry one.
+    ///
+    /// Only enable this for servers that recognize the header; others apply
+    /// every attempt, duplicating the create.
+    pub fn with_idempotency_keys(mut self, header: impl Into<String>) -> Self {
+        self.idempotency_header = Some(header.into());
+        self
+    }
+
+    /// Retry `POST` and `PATCH` requests without an idempotency key too.
+    pub fn with_unsafe_retries(mut self) -> Self {
+        self.retry_unsafe = true;
+        self
+    }
+
+    /// The maximum number of attempts of a request.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// The header idempotency keys are sent in, if enabled.
+    pub fn idempotency_header(&self) -> Option<&str> {
+        self.idempotency_header.as_deref()
+    }
+
+    /// The wait before retry number `retry`, counting from 1.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+
+    /// Whether the request may be sent again after a failure: it is
+    /// idempotent, it carries an idempotency key, or unsafe retries are
+    /// enabled.
+    pub fn is_retryable(&self, request: &HttpRequest) -> bool {
+        request.method.is_idempotent()
+            || self.retry_unsafe
+            || self.idempotency_header.as_deref().is_some_and(|header| {
+                request
+                    .headers
+                    .iter()
+                    .any(|(name, _)| name.eq_ignore_ascii_case(header))
+            })
+    }
+
+    /// The wait before retrying after `outcome`, or `None` if the outcome
+    /// is not worth retrying.
+    pub fn retry_delay(
+        &self,
+        outcome: &Result<HttpResponse, RpcError>,
+        retry: u32,
+    ) -> Option<Duration> {
+        match outcome {
+            Err(RpcError::TransportError(_)) => Some(self.backoff(retry)),
+            Err(RpcError::HttpError { status_code, .. }) if is_transient(*status_code) => {
+                Some(self.backoff(retry))
+            }
+            Ok(response) if is_transient(response.status_code) => {
+                let retry_after = response
+                    .headers
+                    .iter()
+                    .find(|(name, _)| name.eq_ignore_ascii_case("retry-after"))
+                    .and_then(|(_, value)| value.trim().parse::<u64>().ok())
+                    .map(Duration::from_secs);
+                Some(
+                    retry_after
+                        .map_or_else(|| self.backoff(retry), |delay| delay.min(self.max_backoff)),
+                )
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Statuses a server answers with when a later attempt may succeed.
+fn is_transient(status_code: u16) -> bool {
+    matches!(status_code, 429 | 502 | 503 | 504)
+}
+
+/// Transport decorator that retries failed requests according to a
+/// [`RetryPolicy`].
+///
+/// Retries stop early when the next wait would go past the request's
+/// [`Deadline`](crate::Deadline); the last outcome is returned. Requests
+/// sent with [`execute_streaming`](HttpTransport::execute_streaming) are
+/// not retried, as their body can only be read once.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use rustconf_runtime::{HttpTransport, HttpRequest, HttpResponse, RpcError};
+/// # use async_trait::async_trait;
+/// # struct MyTransport;
+/// # #[async_trait]
+/// # impl HttpTransport for MyTransport {
+/// #     async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, RpcError> {
+/// #         todo!()
+/// #     }
+/// # }
+/// use rustconf_runtime::{RestconfClient, RetryPolicy, RetryingTransport};
+///
+/// # fn example() -> Result<(), RpcError> {
+/// let policy = RetryPolicy::new().with_idempotency_keys("Idempotency-Key");
+/// let transport = RetryingTransport::new(MyTransport, policy);
+/// let client = RestconfClient::new("https://device.example.com", transport)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// A create that the server answers with `503` twice is sent three times
+/// with the same key:
+///
+/// ```
+/// use std::sync::Mutex;
+/// use std::time::Duration;
+///
+/// use async_trait::async_trait;
+/// use rustconf_runtime::{
+///     HttpMethod, HttpRequest, HttpResponse, HttpTransport, RetryPolicy, RetryingTransport,
+///     RpcError,
+/// };
+///
+/// #[derive(Default)]
+/// struct Busy {
+///     keys: Mutex<Vec<String>>,
+/// }
+///
+/// #[async_trait]
+/// impl HttpTransport for Busy {
+///     async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, RpcError> {
+///         let mut keys = self.keys.lock().unwrap();
+///         let (_, key) = request.headers.iter().find(|(name, _)| name == "Idempotency-Key").unwrap();
+///         keys.push(key.clone());
+///         let status_code = if keys.len() < 3 { 503 } else { 201 };
+///         Ok(HttpResponse { status_code, headers: Vec::new(), body: Vec::new() })
+///     }
+/// }
+///
+/// let policy = RetryPolicy::new()
+///     .with_backoff(Duration::ZERO, Duration::ZERO)
+///     .with_idempotency_keys("Idempotency-Key");
+/// let transport = RetryingTransport::new(Busy::default(), policy);
+/// let request = HttpRequest::new(HttpMethod::POST, "https://device/restconf/data/interfaces");
+///
+/// let runtime = tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap();
+/// let response = runtime.block_on(transport.execute(request)).unwrap();
+/// assert_eq!(response.status_code, 201);
+///
+/// let keys = transport.inner().keys.lock().unwrap();
+/// assert_eq!(keys.len(), 3);
+/// assert!(keys.iter().all(|key| key == &keys[0]));
+/// ```
+#[cfg(feature = "retry")]
+#[derive(Debug)]
+pub struct RetryingTransport<T> {
+    inner: T,
+    policy: RetryPolicy,
+}
+
+#[cfg(feature = "retry")]
+impl<T: HttpTransport> RetryingTransport<T> {
+    /// Wrap a transport, retrying its requests according to `policy`.
+    pub fn new(inner: T, policy: RetryPolicy) -> Self {
+        Self { inner, policy }
+    }
+
+    /// The wrapped transport.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// The policy requests are retried with.
+    pub fn policy(&self) -> &RetryPolicy {
+        &self.policy
+    }
+
+    /// Add the idempotency key header to a `POST`, unless it has one.
+    fn add_idempotency_key(&self, request: &mut HttpRequest) {
+        let Some(header) = self.policy.idempotency_header() else {
+            return;
+        };
+        let has_key = request
+            .headers
+            .iter()
+            .any(|(name, _)| name.eq_ignore_ascii_case(header));
+        if request.method != HttpMethod::POST || has_key {
+            return;
+        }
+        let key = request
+            .context
+            .get::<IdempotencyKey>()
+            .cloned()
+            .unwrap_or_else(IdempotencyKey::generate);
+        request.headers.push((header.to_string(), key.0));
+    }
+}
+
+#[cfg(feature = "retry")]
+#[async_trait]
+impl<T: HttpTransport> HttpTransport for RetryingTransport<T> {
+    async fn execute(&self, mut request: HttpRequest) -> Result<HttpResponse, RpcError> {
+        self.add_idempotency_key(&mut request);
+        if !self.policy.is_retryable(&request) {
+            return self.inner.execute(request).await;
+        }
+
+        let mut attempt = 1;
+        loop {
+            let outcome = self.inner.execute(request.clone()).await;
+            if attempt >= self.policy.max_attempts {
+                return outcome;
+            }
+            let Some(delay) = self.policy.retry_delay(&outcome, attempt) else {
+                return outcome;
+            };
+            if let Some(deadline) = request.context.deadline() {
+                if delay >= deadline.remaining() {
+                    return outcome;
+                }
+            }
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    async fn execute_streaming(
+        &self,
+        mut request: HttpRequest,
+        body: crate::upload::StreamingBody,
+    ) -> Result<HttpResponse, RpcError> {
+        self.add_idempotency_key(&mut request);
+        self.inner.execute_streaming(request, body).await
+    }
+}
@@ -0,0 +1,310 @@
+//! Best-effort transactional changes for devices without a candidate datastore.
+
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+
+use bytes::Bytes;
+use serde::Serialize;
+
+use crate::error::RpcError;
+use crate::transport::{HttpMethod, HttpRequest, HttpResponse, HttpTransport, RestconfClient};
+
+/// Media type used for RESTCONF JSON bodies (RFC 8040).
+const YANG_DATA_JSON: &str = "application/yang-data+json";
+
+/// Run a set of changes, restoring the affected resources if any change fails.
+///
+/// Every resource modified through the [`Transaction`] is read before its first
+/// change. If the closure returns an error, the snapshots are written back in
+/// reverse order: resources that existed are restored with PUT and resources
+/// that were created are deleted. The closure's error is then returned, or
+/// [`RpcError::RollbackFailed`] if some resources could not be restored.
+///
+/// This is not a real transaction: other clients can observe the intermediate
+/// state, and changes made through the client directly (rather than through the
+/// transaction) are not snapshotted unless [`Transaction::snapshot`] is called
+/// for them first.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use rustconf_runtime::{HttpTransport, HttpRequest, HttpResponse, RpcError};
+/// # use async_trait::async_trait;
+/// # struct MyTransport;
+/// # #[async_trait]
+/// # impl HttpTransport for MyTransport {
+/// #     async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, RpcError> {
+/// #         todo!()
+/// #     }
+/// # }
+/// use rustconf_runtime::{with_rollback, RestconfClient};
+/// use serde_json::json;
+///
+/// # async fn example() -> Result<(), RpcError> {
+/// let client = RestconfClient::new("https://device.example.com", MyTransport)?;
+///
+/// with_rollback(&client, |txn| async move {
+///     txn.patch(
+///         "/restconf/data/ietf-system:system",
+///         &json!({ "ietf-system:system": { "hostname": "edge-1" } }),
+///     )
+///     .await?;
+///     txn.delete("/restconf/data/ietf-interfaces:interfaces/interface=eth9")
+///         .await?;
+///     Ok(())
+/// })
+/// .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn with_rollback<'c, T, F, Fut, R>(
+    client: &'c RestconfClient<T>,
+    changes: F,
+) -> Result<R, RpcError>
+where
+    T: HttpTransport,
+    F: FnOnce(Transaction<'c, T>) -> Fut,
+    Fut: Future<Output = Result<R, RpcError>>,
+{
+    let transaction = Transaction::new(client);
+    match changes(transaction.clone()).await {
+        Ok(value) => Ok(value),
+        Err(error) => {
+            let failed_restores = transaction.restore().await;
+            if failed_restores.is_empty() {
+                Err(error)
+            } else {
+                Err(RpcError::RollbackFailed {
+                    error: Box::new(error),
+                    failed_restores,
+                })
+            }
+        }
+    }
+}
+
+/// Handle for making changes inside [`with_rollback`].
+///
+/// Cloning is cheap; clones share the same snapshots.
+pub struct Transaction<'c, T: HttpTransport> {
+    client: &'c RestconfClient<T>,
+    snapshots: Arc<Mutex<Vec<Snapshot>>>,
+}
+
+/// Contents of a resource before the transaction first changed it.
+struct Snapshot {
+    path: String,
+    /// `None` if the resource did not exist
+    original: Option<Vec<u8>>,
+}
+
+impl<T: HttpTransport> Clone for Transaction<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client,
+            snapshots: Arc::clone(&self.snapshots),
+        }
+    }
+}
+
+impl<'c, T: HttpTransport> Transaction<'c, T> {
+    fn new(client: &'c RestconfClient<T>) -> Self {
+        Self {
+            client,
+            snapshots: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// The client changes are sent through, for use with generated operations.
+    pub fn client(&self) -> &'c RestconfClient<T> {
+        self.client
+    }
+
+    /// Paths snapshotted so far, in the order they were taken.
+    pub fn snapshotted_paths(&self) -> Vec<String> {
+        self.lock_snapshots()
+            .iter()
+            .map(|snapshot| snapshot.path.clone())
+            .collect()
+    }
+
+    /// Record the current contents of a data resource (e.g.
+    /// `/restconf/data/ietf-system:system`) so it can be restored on rollback.
+    ///
+    /// Only the first snapshot of a path is kept. Call this before changing a
+    /// resource through generated operations or [`Transaction::client`].
+    ///
+    /// The snapshot is read with `content=config`: servers reject a PUT
+    /// carrying state data, so restoring a snapshot with it would fail.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Mutex;
+    ///
+    /// use async_trait::async_trait;
+    /// use rustconf_runtime::{
+    ///     with_rollback, HttpMethod, HttpRequest, HttpResponse, HttpTransport, RestconfClient,
+    ///     RpcError,
+    /// };
+    /// use serde_json::{json, Value};
+    ///
+    /// /// A device that answers reads with its configuration and rejects PATCH.
+    /// #[derive(Default)]
+    /// struct Device {
+    ///     requests: Mutex<Vec<(HttpMethod, String, Option<Value>)>>,
+    /// }
+    ///
+    /// #[async_trait]
+    /// impl HttpTransport for Device {
+    ///     async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, RpcError> {
+    ///         let body = request.body.as_ref().map(|body| serde_json::from_slice(body).unwrap());
+    ///         self.requests.lock().unwrap().push((request.method, request.url, body));
+    ///         let mut response = HttpResponse::new(match request.method {
+    ///             HttpMethod::GET => 200,
+    ///             HttpMethod::PATCH => 400,
+    ///             _ => 204,
+    ///         });
+    ///         if request.method == HttpMethod::GET {
+    ///             response.body = br#"{"ietf-system:system":{"hostname":"edge-1"}}"#.to_vec();
+    ///         }
+    ///         Ok(response)
+    ///     }
+    /// }
+    ///
+    /// let device = Device::default();
+    /// let client = RestconfClient::new("https://device.example.com", &device).unwrap();
+    /// let path = "/restconf/data/ietf-system:system";
+    ///
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    /// let result = runtime.block_on(with_rollback(&client, |txn| async move {
+    ///     txn.patch(path, &json!({ "ietf-system:system": { "hostname": "edge-2" } })).await
+    /// }));
+    /// assert!(matches!(result, Err(RpcError::HttpError { status_code: 400, .. })));
+    ///
+    /// // The configuration is read, and written back as it was
+    /// let requests = device.requests.lock().unwrap();
+    /// let url = format!("https://device.example.com{}", path);
+    /// assert_eq!(requests.len(), 3);
+    /// assert_eq!(requests[0], (HttpMethod::GET, format!("{}?content=config", url), None));
+    /// assert_eq!(requests[1].0, HttpMethod::PATCH);
+    /// assert_eq!(
+    ///     requests[2],
+    ///     (HttpMethod::PUT, url, Some(json!({ "ietf-system:system": { "hostname": "edge-1" } })))
+    /// );
+    /// ```
+    pub async fn snapshot(&self, path: &str) -> Result<(), RpcError> {
+        if self.is_snapshotted(path) {
+            return Ok(());
+        }
+
+        let separator = if path.contains('?') { '&' } else { '?' };
+        let config_path = format!("{}{}content=config", path, separator);
+        let request = HttpRequest::new(HttpMethod::GET, self.client.build_url(&config_path))
+            .with_header("Accept", YANG_DATA_JSON);
+        let response = self.client.execute(request).await?;
+        let original = match response.status_code {
+            404 => None,
+            _ if response.is_success() => Some(response.body),
+            _ => return Err(http_error(response)),
+        };
+
+        let mut snapshots = self.lock_snapshots();
+        if !snapshots.iter().any(|snapshot| snapshot.path == path) {
+            snapshots.push(Snapshot {
+                path: path.to_string(),
+                original,
+            });
+        }
+        Ok(())
+    }
+
+    /// Snapshot and replace a data resource.
+    pub async fn put<D: Serialize>(&self, path: &str, data: &D) -> Result<(), RpcError> {
+        self.snapshot(path).await?;
+        self.send(HttpMethod::PUT, path, Some(serialize(data)?))
+            .await
+    }
+
+    /// Snapshot and merge into a data resource.
+    pub async fn patch<D: Serialize>(&self, path: &str, data: &D) -> Result<(), RpcError> {
+        self.snapshot(path).await?;
+        self.send(HttpMethod::PATCH, path, Some(serialize(data)?))
+            .await
+    }
+
+    /// Snapshot and delete a data resource.
+    pub async fn delete(&self, path: &str) -> Result<(), RpcError> {
+        self.snapshot(path).await?;
+        self.send(HttpMethod::DELETE, path, None).await
+    }
+
+    async fn send(
+        &self,
+        method: HttpMethod,
+        path: &str,
+        body: Option<Bytes>,
+    ) -> Result<(), RpcError> {
+        let mut request = HttpRequest::new(method, self.client.build_url(path));
+        if let Some(body) = body {
+            request = request
+                .with_header("Content-Type", YANG_DATA_JSON)
+                .with_body(body);
+        }
+        let response = self.client.execute(request).await?;
+        if response.is_success() {
+            Ok(())
+        } else {
+            Err(http_error(response))
+        }
+    }
+
+    /// Write every snapshot back, newest first, returning the paths that failed.
+    async fn restore(&self) -> Vec<(String, RpcError)> {
+        let snapshots = std::mem::take(&mut *self.lock_snapshots());
+
+        let mut failed = Vec::new();
+        for snapshot in snapshots.into_iter().rev() {
+            let result = match snapshot.original {
+                Some(original) => {
+                    self.send(HttpMethod::PUT, &snapshot.path, Some(original.into()))
+                        .await
+                }
+                None => match self.send(HttpMethod::DELETE, &snapshot.path, None).await {
+                    // Never created, nothing to undo
+                    Err(RpcError::HttpError {
+                        status_code: 404, ..
+                    }) => Ok(()),
+                    result => result,
+                },
+            };
+            if let Err(error) = result {
+                failed.push((snapshot.path, error));
+            }
+        }
+        failed
+    }
+
+    fn is_snapshotted(&self, path: &str) -> bool {
+        self.lock_snapshots()
+            .iter()
+            .any(|snapshot| snapshot.path == path)
+    }
+
+    fn lock_snapshots(&self) -> std::sync::MutexGuard<'_, Vec<Snapshot>> {
+        self.snapshots.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn serialize<D: Serialize>(data: &D) -> Result<Bytes, RpcError> {
+    serde_json::to_vec(data)
+        .map(Bytes::from)
+        .map_err(|e| RpcError::SerializationError(format!("Failed to serialize data: {}", e)))
+}
+
+fn http_error(response: HttpResponse) -> RpcError {
+    RpcError::HttpError {
+        status_code: response.status_code,
+        message: String::from_utf8_lossy(&response.body).to_string(),
+    }
+}
@@ -0,0 +1,100 @@
+//! Handling of credentials and other secret values.
+//!
+//! Secrets held by the runtime, such as session cookies, CSRF tokens and TLS
+//! key passwords, are wrapped in [`Secret`] so that they never show up in
+//! `Debug` output, logs or error messages by accident. Comparing secrets
+//! uses [`constant_time_eq`], whose running time does not reveal how much of
+//! a guess was right.
+
+use std::fmt;
+
+/// A value that is redacted when formatted with `Debug`.
+///
+/// The value is only reachable through [`expose_secret`](Self::expose_secret),
+/// which makes every use easy to find. Secrets compare in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::Secret;
+///
+/// let token = Secret::new("s3cr3t".to_string());
+/// assert_eq!(format!("{:?}", token), "Secret(***)");
+/// assert_eq!(token.expose_secret(), "s3cr3t");
+/// assert_eq!(token, Secret::from("s3cr3t"));
+/// ```
+#[derive(Clone, Default)]
+pub struct Secret<T>(T);
+
+impl<T> Secret<T> {
+    /// Wrap a secret value.
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// The secret value.
+    pub fn expose_secret(&self) -> &T {
+        &self.0
+    }
+
+    /// Unwrap the secret value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> fmt::Debug for Secret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Secret(***)")
+    }
+}
+
+impl<T: AsRef<[u8]>> PartialEq for Secret<T> {
+    fn eq(&self, other: &Self) -> bool {
+        constant_time_eq(self.0.as_ref(), other.0.as_ref())
+    }
+}
+
+impl<T: AsRef<[u8]>> Eq for Secret<T> {}
+
+impl From<String> for Secret<String> {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for Secret<String> {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<Vec<u8>> for Secret<Vec<u8>> {
+    fn from(value: Vec<u8>) -> Self {
+        Self(value)
+    }
+}
+
+/// Compare two byte strings in time that depends only on their lengths.
+///
+/// Use this rather than `==` to check a token or password received from a
+/// peer, so that the time taken does not reveal the length of the matching
+/// prefix.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::constant_time_eq;
+///
+/// assert!(constant_time_eq(b"f00d", b"f00d"));
+/// assert!(!constant_time_eq(b"f00d", b"f00e"));
+/// assert!(!constant_time_eq(b"f00d", b"f00"));
+/// ```
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let difference = a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y));
+    // Keep the compiler from turning the fold into an early-exit comparison
+    std::hint::black_box(difference) == 0
+}
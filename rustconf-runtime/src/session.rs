@@ -0,0 +1,331 @@
+//! Session interceptors for servers behind cookie-authenticated web proxies.
+//!
+//! Some deployments put RESTCONF behind a web front end that authenticates
+//! with a session cookie and protects state-changing requests with a CSRF
+//! token. [`CookieJar`] replays cookies set by the server and [`CsrfToken`]
+//! echoes the server's token back on unsafe requests. Both are shared handles:
+//! clones refer to the same state, so a handle kept by the application sees
+//! what the client captured and can seed values obtained from a login flow.
+//! Cookie values and tokens are held as [`Secret`]s, so they are redacted
+//! when a jar or token is printed with `Debug`.
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::error::RpcError;
+use crate::secret::Secret;
+use crate::transport::{HttpMethod, HttpRequest, HttpResponse, RequestInterceptor};
+
+/// Interceptor that stores cookies from `Set-Cookie` response headers and
+/// sends them back in a `Cookie` header.
+///
+/// A jar belongs to the client it is registered with and therefore to a single
+/// server, so `Domain` and `Path` attributes are not used for matching. A
+/// cookie is removed when the server sets it with `Max-Age` of zero or less.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::{CookieJar, HttpMethod, HttpRequest, HttpResponse, RequestInterceptor};
+///
+/// let jar = CookieJar::new();
+///
+/// let mut response = HttpResponse::new(200);
+/// response.headers.push((
+///     "Set-Cookie".to_string(),
+///     "session=abc123; Path=/; HttpOnly".to_string(),
+/// ));
+/// jar.on_response(&response).unwrap();
+/// assert_eq!(jar.get("session").as_deref(), Some("abc123"));
+///
+/// let mut request = HttpRequest::new(HttpMethod::GET, "https://device.example.com/restconf/data");
+/// jar.intercept(&mut request).unwrap();
+/// assert_eq!(request.headers, [("Cookie".to_string(), "session=abc123".to_string())]);
+///
+/// // Values never show up in debug output
+/// assert!(!format!("{:?}", jar).contains("abc123"));
+/// ```
+///
+/// Sharing the jar with the client:
+///
+/// ```no_run
+/// # use rustconf_runtime::{CookieJar, RestconfClient, HttpTransport, HttpRequest, HttpResponse, RpcError};
+/// # use async_trait::async_trait;
+/// # struct MyTransport;
+/// # #[async_trait]
+/// # impl HttpTransport for MyTransport {
+/// #     async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, RpcError> {
+/// #         todo!()
+/// #     }
+/// # }
+/// # fn example() -> Result<(), RpcError> {
+/// let jar = CookieJar::new();
+/// jar.insert("session", "token-from-login");
+/// let client = RestconfClient::new("https://device.example.com", MyTransport)?
+///     .with_interceptor(jar.clone());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CookieJar {
+    cookies: Arc<Mutex<BTreeMap<String, Secret<String>>>>,
+}
+
+impl CookieJar {
+    /// Create an empty cookie jar.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Store a cookie, replacing any previous value.
+    pub fn insert(&self, name: impl Into<String>, value: impl Into<String>) {
+        self.lock().insert(name.into(), Secret::new(value.into()));
+    }
+
+    /// Get the value of a stored cookie.
+    pub fn get(&self, name: &str) -> Option<String> {
+        self.lock()
+            .get(name)
+            .map(|value| value.expose_secret().clone())
+    }
+
+    /// Remove a stored cookie.
+    pub fn remove(&self, name: &str) {
+        self.lock().remove(name);
+    }
+
+    /// Remove all stored cookies, e.g. after logging out.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Check whether the jar holds no cookies.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Apply the value of a `Set-Cookie` header to the jar.
+    ///
+    /// Headers without a `name=value` pair are ignored.
+    pub fn store_set_cookie(&self, header: &str) {
+        let Some(cookie) = SetCookie::parse(header) else {
+            return;
+        };
+        if cookie.expired {
+            self.remove(cookie.name);
+        } else {
+            self.insert(cookie.name, cookie.value);
+        }
+    }
+
+    /// The `Cookie` header value for the stored cookies, if there are any.
+    pub fn cookie_header(&self) -> Option<String> {
+        let cookies = self.lock();
+        if cookies.is_empty() {
+            return None;
+        }
+        let pairs: Vec<String> = cookies
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value.expose_secret()))
+            .collect();
+        Some(pairs.join("; "))
+    }
+
+    fn lock(&self) -> MutexGuard<'_, BTreeMap<String, Secret<String>>> {
+        self.cookies.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl RequestInterceptor for CookieJar {
+    fn intercept(&self, request: &mut HttpRequest) -> Result<(), RpcError> {
+        let Some(cookies) = self.cookie_header() else {
+            return Ok(());
+        };
+        // Extend a Cookie header set by the caller rather than sending two
+        match request
+            .headers
+            .iter_mut()
+            .find(|(name, _)| name.eq_ignore_ascii_case("cookie"))
+        {
+            Some((_, value)) => {
+                value.push_str("; ");
+                value.push_str(&cookies);
+            }
+            None => request.headers.push(("Cookie".to_string(), cookies)),
+        }
+        Ok(())
+    }
+
+    fn on_response(&self, response: &HttpResponse) -> Result<(), RpcError> {
+        for header in set_cookie_headers(response) {
+            self.store_set_cookie(header);
+        }
+        Ok(())
+    }
+}
+
+/// Default header carrying the CSRF token in both directions.
+const DEFAULT_CSRF_HEADER: &str = "X-CSRF-Token";
+
+/// Interceptor that echoes a CSRF token on state-changing requests.
+///
+/// The token is captured from the response header of the same name as the
+/// request header (`X-CSRF-Token` unless configured otherwise), or from a
+/// cookie for proxies using the double-submit pattern. It is sent on `POST`,
+/// `PUT`, `PATCH` and `DELETE` requests only; safe methods never carry it.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::{CsrfToken, HttpMethod, HttpRequest, HttpResponse, RequestInterceptor};
+///
+/// let csrf = CsrfToken::new()
+///     .with_header_name("X-XSRF-TOKEN")
+///     .with_cookie_name("XSRF-TOKEN");
+///
+/// let mut response = HttpResponse::new(200);
+/// response.headers.push(("Set-Cookie".to_string(), "XSRF-TOKEN=f00d; Path=/".to_string()));
+/// csrf.on_response(&response).unwrap();
+/// assert_eq!(csrf.token().as_deref(), Some("f00d"));
+///
+/// let mut get = HttpRequest::new(HttpMethod::GET, "https://device.example.com/restconf/data");
+/// csrf.intercept(&mut get).unwrap();
+/// assert!(get.headers.is_empty());
+///
+/// let mut put = HttpRequest::new(HttpMethod::PUT, "https://device.example.com/restconf/data");
+/// csrf.intercept(&mut put).unwrap();
+/// assert_eq!(put.headers, [("X-XSRF-TOKEN".to_string(), "f00d".to_string())]);
+/// assert!(!format!("{:?}", csrf).contains("f00d"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CsrfToken {
+    header_name: String,
+    cookie_name: Option<String>,
+    token: Arc<Mutex<Option<Secret<String>>>>,
+}
+
+impl CsrfToken {
+    /// Create an interceptor using the `X-CSRF-Token` header.
+    pub fn new() -> Self {
+        Self {
+            header_name: DEFAULT_CSRF_HEADER.to_string(),
+            cookie_name: None,
+            token: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Use a different header to receive and send the token.
+    pub fn with_header_name(mut self, name: impl Into<String>) -> Self {
+        self.header_name = name.into();
+        self
+    }
+
+    /// Also capture the token from the cookie with the given name.
+    pub fn with_cookie_name(mut self, name: impl Into<String>) -> Self {
+        self.cookie_name = Some(name.into());
+        self
+    }
+
+    /// Name of the header carrying the token.
+    pub fn header_name(&self) -> &str {
+        &self.header_name
+    }
+
+    /// The current token, if one has been captured or set.
+    pub fn token(&self) -> Option<String> {
+        self.lock()
+            .as_ref()
+            .map(|token| token.expose_secret().clone())
+    }
+
+    /// Set the token, e.g. one returned by a login request.
+    pub fn set_token(&self, token: impl Into<String>) {
+        *self.lock() = Some(Secret::new(token.into()));
+    }
+
+    /// Forget the current token.
+    pub fn clear(&self) {
+        *self.lock() = None;
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Option<Secret<String>>> {
+        self.token.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for CsrfToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestInterceptor for CsrfToken {
+    fn intercept(&self, request: &mut HttpRequest) -> Result<(), RpcError> {
+        let unsafe_method = matches!(
+            request.method,
+            HttpMethod::POST | HttpMethod::PUT | HttpMethod::PATCH | HttpMethod::DELETE
+        );
+        if !unsafe_method {
+            return Ok(());
+        }
+        if let Some(token) = self.token() {
+            request.headers.push((self.header_name.clone(), token));
+        }
+        Ok(())
+    }
+
+    fn on_response(&self, response: &HttpResponse) -> Result<(), RpcError> {
+        if let Some(token) = response.get_header(&self.header_name) {
+            self.set_token(token);
+        }
+        if let Some(cookie_name) = &self.cookie_name {
+            let cookie = set_cookie_headers(response)
+                .filter_map(SetCookie::parse)
+                .find(|cookie| cookie.name == cookie_name);
+            match cookie {
+                Some(cookie) if cookie.expired => self.clear(),
+                Some(cookie) => self.set_token(cookie.value),
+                None => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Values of all `Set-Cookie` headers of a response.
+fn set_cookie_headers(response: &HttpResponse) -> impl Iterator<Item = &str> {
+    response
+        .headers
+        .iter()
+        .filter(|(name, _)| name.eq_ignore_ascii_case("set-cookie"))
+        .map(|(_, value)| value.as_str())
+}
+
+/// The parts of a `Set-Cookie` header the interceptors care about.
+struct SetCookie<'a> {
+    name: &'a str,
+    value: &'a str,
+    expired: bool,
+}
+
+impl<'a> SetCookie<'a> {
+    fn parse(header: &'a str) -> Option<Self> {
+        let mut parts = header.split(';');
+        let (name, value) = parts.next()?.split_once('=')?;
+        let name = name.trim();
+        if name.is_empty() {
+            return None;
+        }
+        let expired = parts.any(|attribute| {
+            attribute.split_once('=').is_some_and(|(key, value)| {
+                key.trim().eq_ignore_ascii_case("max-age")
+                    && value.trim().parse::<i64>().is_ok_and(|age| age <= 0)
+            })
+        });
+        Some(Self {
+            name,
+            value: value.trim().trim_matches('"'),
+            expired,
+        })
+    }
+}
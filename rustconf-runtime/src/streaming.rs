@@ -0,0 +1,178 @@
+//! Incremental deserialization of large list responses.
+
+use std::fmt;
+use std::io::Read;
+use std::marker::PhantomData;
+
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Visitor,
+};
+use serde::Deserialize;
+
+use crate::error::RpcError;
+
+/// [`DeserializeSeed`] that hands the entries of a RESTCONF list to a callback
+/// one at a time instead of collecting them into a `Vec`.
+///
+/// The input is a list response body such as
+/// `{"example:interface": [{...}, {...}]}`. Entries of the member named
+/// `member` are passed to the callback as they are parsed; other members are
+/// skipped. The member matches with or without its module qualifier, and a
+/// missing member is treated as an empty list. The seed produces the number of
+/// entries seen.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::ListEntries;
+/// use serde::de::DeserializeSeed;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Route {
+///     prefix: String,
+/// }
+///
+/// let body = r#"{"rib:route": [{"prefix": "10.0.0.0/8"}, {"prefix": "192.168.0.0/16"}]}"#;
+/// let mut prefixes = Vec::new();
+/// let seed = ListEntries::new("route", |route: Route| prefixes.push(route.prefix));
+///
+/// let mut deserializer = serde_json::Deserializer::from_str(body);
+/// let count = seed.deserialize(&mut deserializer).unwrap();
+/// assert_eq!(count, 2);
+/// assert_eq!(prefixes, ["10.0.0.0/8", "192.168.0.0/16"]);
+/// ```
+#[must_use = "ListEntries does nothing until it deserializes a response body"]
+pub struct ListEntries<'a, T, F> {
+    member: &'a str,
+    on_entry: F,
+    entry: PhantomData<fn(T)>,
+}
+
+impl<'a, T, F: FnMut(T)> ListEntries<'a, T, F> {
+    /// Create a seed calling `on_entry` for every entry of the list `member`.
+    pub fn new(member: &'a str, on_entry: F) -> Self {
+        Self {
+            member,
+            on_entry,
+            entry: PhantomData,
+        }
+    }
+
+    fn matches(&self, key: &str) -> bool {
+        key == self.member
+            || key
+                .split_once(':')
+                .is_some_and(|(_, name)| name == self.member)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, F: FnMut(T)> DeserializeSeed<'de> for ListEntries<'_, T, F> {
+    type Value = usize;
+
+    fn deserialize<D: de::Deserializer<'de>>(self, deserializer: D) -> Result<usize, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, F: FnMut(T)> Visitor<'de> for ListEntries<'_, T, F> {
+    type Value = usize;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "an object containing the list '{}'", self.member)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(mut self, mut map: A) -> Result<usize, A::Error> {
+        let mut count = 0;
+        while let Some(key) = map.next_key::<String>()? {
+            if self.matches(&key) {
+                count += map.next_value_seed(Entries {
+                    on_entry: &mut self.on_entry,
+                    entry: PhantomData,
+                })?;
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+        Ok(count)
+    }
+}
+
+/// Seed for the array of entries itself.
+struct Entries<'f, T, F> {
+    on_entry: &'f mut F,
+    entry: PhantomData<fn(T)>,
+}
+
+impl<'de, T: Deserialize<'de>, F: FnMut(T)> DeserializeSeed<'de> for Entries<'_, T, F> {
+    type Value = usize;
+
+    fn deserialize<D: de::Deserializer<'de>>(self, deserializer: D) -> Result<usize, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, F: FnMut(T)> Visitor<'de> for Entries<'_, T, F> {
+    type Value = usize;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an array of list entries")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<usize, A::Error> {
+        let mut count = 0;
+        while let Some(entry) = seq.next_element::<T>()? {
+            (self.on_entry)(entry);
+            count += 1;
+        }
+        Ok(count)
+    }
+}
+
+/// Read a list response body from `reader`, calling `on_entry` for each entry
+/// of the list `member` as it is parsed.
+///
+/// Only one entry is held in memory at a time, so million-entry lists can be
+/// processed without building a `Vec`. Returns the number of entries.
+///
+/// # Errors
+///
+/// Returns [`RpcError::DeserializationError`] if the body is not valid JSON or
+/// an entry does not match `T`. Entries before the error have already been
+/// passed to `on_entry`.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::for_each_list_entry;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Route {
+///     prefix: String,
+/// }
+///
+/// let body = br#"{"rib:route": [{"prefix": "10.0.0.0/8"}]}"#;
+/// let count = for_each_list_entry(&body[..], "route", |route: Route| {
+///     println!("{}", route.prefix);
+/// })
+/// .unwrap();
+/// assert_eq!(count, 1);
+/// ```
+pub fn for_each_list_entry<R, T, F>(reader: R, member: &str, on_entry: F) -> Result<usize, RpcError>
+where
+    R: Read,
+    T: DeserializeOwned,
+    F: FnMut(T),
+{
+    let invalid = |e: serde_json::Error| {
+        RpcError::DeserializationError(format!("Failed to deserialize list '{}': {}", member, e))
+    };
+
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+    let count = ListEntries::new(member, on_entry)
+        .deserialize(&mut deserializer)
+        .map_err(invalid)?;
+    deserializer.end().map_err(invalid)?;
+    Ok(count)
+}
@@ -0,0 +1,371 @@
+//! Scripted transports for testing application code.
+//!
+//! [`ScenarioTransport`] plays a fixed sequence of [`ScenarioStep`]s: each
+//! step matches the next request and answers it with a response or an
+//! error, optionally after a delay. This makes the failures that retry,
+//! reconnect and rollback logic has to handle, such as a reset connection on
+//! the third request, reproducible in a unit test without a server.
+//!
+//! # Examples
+//!
+//! A failed change restored by [`with_rollback`](crate::with_rollback):
+//!
+//! ```
+//! use rustconf_runtime::testing::{ScenarioStep, ScenarioTransport};
+//! use rustconf_runtime::{with_rollback, HttpMethod, RestconfClient, RpcError};
+//! use serde_json::json;
+//!
+//! let system = "/restconf/data/ietf-system:system";
+//! let eth9 = "/restconf/data/ietf-interfaces:interfaces/interface=eth9";
+//! let transport = ScenarioTransport::new()
+//!     // Snapshot and change of the system container
+//!     .step(ScenarioStep::new(HttpMethod::GET, system)
+//!         .respond_json(200, &json!({ "ietf-system:system": { "hostname": "edge-1" } })))
+//!     .step(ScenarioStep::new(HttpMethod::PATCH, system).respond(204, ""))
+//!     // The connection drops while taking the snapshot of eth9
+//!     .step(ScenarioStep::new(HttpMethod::GET, eth9)
+//!         .fail(RpcError::TransportError("connection reset".to_string())))
+//!     // The system container is restored
+//!     .step(ScenarioStep::new(HttpMethod::PUT, system)
+//!         .with_body(json!({ "ietf-system:system": { "hostname": "edge-1" } }))
+//!         .respond(204, ""));
+//! let client = RestconfClient::new("https://device.example.com", &transport).unwrap();
+//!
+//! let runtime = tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap();
+//! let result = runtime.block_on(with_rollback(&client, |txn| async move {
+//!     txn.patch(system, &json!({ "ietf-system:system": { "hostname": "edge-2" } })).await?;
+//!     txn.delete(eth9).await?;
+//!     Ok(())
+//! }));
+//!
+//! assert!(matches!(result, Err(RpcError::TransportError(_))));
+//! transport.assert_finished();
+//! ```
+
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::error::RpcError;
+use crate::transport::{HttpMethod, HttpRequest, HttpResponse, HttpTransport};
+
+/// Media type used for RESTCONF JSON bodies (RFC 8040).
+const YANG_DATA_JSON: &str = "application/yang-data+json";
+
+/// Predicate on a request, for matching what method and path cannot.
+type RequestPredicate = Arc<dyn Fn(&HttpRequest) -> bool + Send + Sync>;
+
+/// One expected request of a [`ScenarioTransport`] and its outcome.
+///
+/// A step matches a request with its method and path, the part of the URL
+/// from the first `/` after the host. The query string is compared only if
+/// the step's path has one. A step answers with an empty `200` response
+/// unless given another outcome.
+#[derive(Clone)]
+pub struct ScenarioStep {
+    method: HttpMethod,
+    path: String,
+    body: Option<Value>,
+    predicate: Option<RequestPredicate>,
+    outcome: Result<HttpResponse, RpcError>,
+    delay: Option<Duration>,
+    times: usize,
+}
+
+impl ScenarioStep {
+    /// Expect a request with the given method and path.
+    pub fn new(method: HttpMethod, path: impl Into<String>) -> Self {
+        Self {
+            method,
+            path: path.into(),
+            body: None,
+            predicate: None,
+            outcome: Ok(HttpResponse::new(200)),
+            delay: None,
+            times: 1,
+        }
+    }
+
+    /// Also require the request body to be this JSON value.
+    pub fn with_body(mut self, body: Value) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    /// Also require the request to satisfy `predicate`, e.g. to carry a
+    /// header.
+    pub fn matching(
+        mut self,
+        predicate: impl Fn(&HttpRequest) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.predicate = Some(Arc::new(predicate));
+        self
+    }
+
+    /// Answer with the given status and body.
+    pub fn respond(self, status_code: u16, body: impl Into<Vec<u8>>) -> Self {
+        let mut response = HttpResponse::new(status_code);
+        response.body = body.into();
+        self.respond_with(response)
+    }
+
+    /// Answer with the given status and a RESTCONF JSON body.
+    pub fn respond_json(self, status_code: u16, body: &Value) -> Self {
+        let mut response = HttpResponse::new(status_code);
+        response
+            .headers
+            .push(("Content-Type".to_string(), YANG_DATA_JSON.to_string()));
+        response.body = body.to_string().into_bytes();
+        self.respond_with(response)
+    }
+
+    /// Answer with the given response.
+    pub fn respond_with(mut self, response: HttpResponse) -> Self {
+        self.outcome = Ok(response);
+        self
+    }
+
+    /// Fail the request with `error`, as a transport does when the
+    /// connection is lost.
+    pub fn fail(mut self, error: RpcError) -> Self {
+        self.outcome = Err(error);
+        self
+    }
+
+    /// Wait before answering, e.g. to run into a timeout or deadline.
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    /// Expect `times` consecutive matching requests, each answered the same
+    /// way, e.g. the attempts of a retried request.
+    pub fn times(mut self, times: usize) -> Self {
+        self.times = times.max(1);
+        self
+    }
+
+    fn matches(&self, request: &HttpRequest) -> bool {
+        let target = request_target(&request.url);
+        let target = match self.path.contains('?') {
+            true => target,
+            false => target.split('?').next().unwrap_or(target),
+        };
+        let body_matches = self.body.as_ref().is_none_or(|expected| {
+            request
+                .body
+                .as_ref()
+                .and_then(|body| serde_json::from_slice::<Value>(body).ok())
+                .is_some_and(|body| body == *expected)
+        });
+        request.method == self.method
+            && target == self.path
+            && body_matches
+            && self
+                .predicate
+                .as_ref()
+                .is_none_or(|predicate| predicate(request))
+    }
+}
+
+impl fmt::Debug for ScenarioStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScenarioStep")
+            .field("method", &self.method)
+            .field("path", &self.path)
+            .field("body", &self.body)
+            .field("outcome", &self.outcome)
+            .field("delay", &self.delay)
+            .field("times", &self.times)
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Display for ScenarioStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.method.as_str(), self.path)?;
+        if self.times != 1 {
+            write!(f, " (x{})", self.times)?;
+        }
+        Ok(())
+    }
+}
+
+/// Transport answering a scripted sequence of requests, in order.
+///
+/// Each request is checked against the next [`ScenarioStep`]. A request
+/// that does not match it, or comes after the last step, fails with
+/// [`RpcError::TransportError`] describing the mismatch and does not
+/// advance the scenario. [`assert_finished`](Self::assert_finished) checks
+/// at the end of a test that every step was played and nothing unexpected
+/// was requested. Delays need a tokio runtime with time enabled.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use rustconf_runtime::testing::{ScenarioStep, ScenarioTransport};
+/// use rustconf_runtime::{HttpMethod, HttpRequest, HttpTransport, RpcError};
+///
+/// let url = "https://device.example.com/restconf/data/ietf-system:system";
+/// let transport = ScenarioTransport::new()
+///     .step(ScenarioStep::new(HttpMethod::GET, "/restconf/data/ietf-system:system")
+///         .fail(RpcError::TransportError("connection refused".to_string()))
+///         .times(2))
+///     .step(ScenarioStep::new(HttpMethod::GET, "/restconf/data/ietf-system:system")
+///         .with_delay(Duration::from_millis(10))
+///         .respond(200, "{}"));
+///
+/// // The code under test: try up to three times
+/// let fetch = async {
+///     let mut outcome = Err(RpcError::NotImplemented);
+///     for _ in 0..3 {
+///         outcome = transport.execute(HttpRequest::new(HttpMethod::GET, url)).await;
+///         if outcome.is_ok() {
+///             break;
+///         }
+///     }
+///     outcome
+/// };
+///
+/// let runtime = tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap();
+/// assert_eq!(runtime.block_on(fetch).unwrap().status_code, 200);
+/// assert_eq!(transport.requests().len(), 3);
+/// assert!(transport.is_finished());
+///
+/// // Requests beyond the script fail and are reported
+/// let extra = runtime.block_on(transport.execute(HttpRequest::new(HttpMethod::DELETE, url)));
+/// assert!(matches!(extra, Err(RpcError::TransportError(_))));
+/// ```
+#[derive(Debug, Default)]
+pub struct ScenarioTransport {
+    state: Mutex<ScenarioState>,
+}
+
+#[derive(Debug, Default)]
+struct ScenarioState {
+    steps: Vec<ScenarioStep>,
+    /// Index of the next step.
+    next: usize,
+    /// Requests already matched by the next step.
+    repeats: usize,
+    requests: Vec<HttpRequest>,
+    unexpected: Vec<String>,
+}
+
+impl ScenarioTransport {
+    /// Create a transport without steps.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a step after the previous ones.
+    pub fn step(self, step: ScenarioStep) -> Self {
+        self.lock().steps.push(step);
+        self
+    }
+
+    /// Requests received so far, in order, including unexpected ones.
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.lock().requests.clone()
+    }
+
+    /// Number of steps fully played.
+    pub fn steps_played(&self) -> usize {
+        self.lock().next
+    }
+
+    /// Whether every step has been played.
+    pub fn is_finished(&self) -> bool {
+        let state = self.lock();
+        state.next == state.steps.len()
+    }
+
+    /// Assert that every step was played and no unexpected request was made.
+    ///
+    /// # Panics
+    ///
+    /// Panics, listing the unexpected requests and the remaining steps, if
+    /// either is not empty.
+    pub fn assert_finished(&self) {
+        let state = self.lock();
+        if state.next == state.steps.len() && state.unexpected.is_empty() {
+            return;
+        }
+        let mut message = String::from("scenario not played as scripted");
+        for unexpected in &state.unexpected {
+            message.push_str(&format!("\n  unexpected {}", unexpected));
+        }
+        for step in &state.steps[state.next..] {
+            message.push_str(&format!("\n  remaining {}", step));
+        }
+        panic!("{}", message);
+    }
+
+    fn lock(&self) -> MutexGuard<'_, ScenarioState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Match `request` against the next step, returning its delay and
+    /// outcome.
+    fn play(&self, request: HttpRequest) -> (Option<Duration>, Result<HttpResponse, RpcError>) {
+        let mut state = self.lock();
+        let description = format!(
+            "{} {}",
+            request.method.as_str(),
+            request_target(&request.url)
+        );
+        let played = state.steps.get(state.next).and_then(|step| {
+            step.matches(&request)
+                .then(|| (step.delay, step.outcome.clone(), step.times))
+        });
+        let expected = match state.steps.get(state.next) {
+            Some(step) => format!("expected step {}: {}", state.next + 1, step),
+            None => "expected no more requests".to_string(),
+        };
+        state.requests.push(request);
+
+        match played {
+            Some((delay, outcome, times)) => {
+                state.repeats += 1;
+                if state.repeats >= times {
+                    state.next += 1;
+                    state.repeats = 0;
+                }
+                (delay, outcome)
+            }
+            None => {
+                let message = format!("{} ({})", description, expected);
+                state.unexpected.push(message.clone());
+                (
+                    None,
+                    Err(RpcError::TransportError(format!(
+                        "unexpected request {}",
+                        message
+                    ))),
+                )
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl HttpTransport for ScenarioTransport {
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, RpcError> {
+        let (delay, outcome) = self.play(request);
+        if let Some(delay) = delay {
+            tokio::time::sleep(delay).await;
+        }
+        outcome
+    }
+}
+
+/// The path and query of `url`, from the first `/` after the host.
+fn request_target(url: &str) -> &str {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    rest.find('/').map_or("/", |index| &rest[index..])
+}
@@ -0,0 +1,384 @@
+//! Loading JSON documents written by other YANG tool chains.
+//!
+//! Configurations saved with ygot or pyangbind follow slightly different
+//! conventions than RFC 7951: members may lack module qualifiers, `empty`
+//! leaves are `true` (or `false` when unset), unset nodes are `null`,
+//! 64-bit integers are numbers, numbers and booleans may be strings, enum
+//! names may carry a module prefix, and pyangbind writes lists as objects
+//! keyed by the entries' key values. [`PayloadSchema::load_tolerant`]
+//! rewrites such a document into the encoding of the generated types,
+//! following the schema, and deserializes it. Members that cannot be mapped
+//! are left out and reported, so a migration can list what did not carry
+//! over instead of failing on the first mismatch.
+//!
+//! Members are written with their local names, as the generated types name
+//! them unless generated with `enable_namespace_prefixes`.
+
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use crate::payload::{LeafType, PayloadNode, PayloadSchema, Violation, ViolationKind};
+
+/// A value loaded with [`PayloadSchema::load_tolerant`], and the members of
+/// the document that were left out of it.
+#[derive(Debug, Clone)]
+pub struct TolerantLoad<T> {
+    /// The loaded value.
+    pub value: T,
+    /// The members that could not be mapped, in document order.
+    pub mismatches: Vec<Violation>,
+}
+
+impl PayloadSchema {
+    /// Rewrite `document`, the contents of the node at `path` (`""` for the
+    /// top level) in the conventions of another tool chain, into RFC 7951
+    /// JSON matching the schema.
+    ///
+    /// Returns the rewritten document and the members left out of it:
+    /// unknown nodes, and values that do not match their node's type even
+    /// after conversion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustconf_runtime::payload::{LeafType, PayloadSchema};
+    /// use serde_json::json;
+    ///
+    /// let schema = PayloadSchema::new()
+    ///     .container("/interfaces")
+    ///     .list("/interfaces/interface", &["name"])
+    ///     .leaf("/interfaces/interface/name", LeafType::string(&[]), false)
+    ///     .leaf("/interfaces/interface/mtu", LeafType::integer(&[(68, 9216)]), false)
+    ///     .leaf("/interfaces/interface/loopback-mode", LeafType::Empty, false);
+    ///
+    /// // pyangbind: lists keyed by name, numbers as strings, `empty` as `true`
+    /// let document = json!({
+    ///     "openconfig-interfaces:interfaces": {
+    ///         "interface": {
+    ///             "eth0": {"mtu": "1500", "loopback-mode": true, "speed": 1000},
+    ///             "eth1": {"mtu": 10},
+    ///         }
+    ///     }
+    /// });
+    /// let (normalized, mismatches) = schema.normalize("", &document);
+    /// assert_eq!(
+    ///     normalized,
+    ///     json!({
+    ///         "interfaces": {
+    ///             "interface": [
+    ///                 {"mtu": 1500, "loopback-mode": [null], "name": "eth0"},
+    ///                 {"name": "eth1"},
+    ///             ]
+    ///         }
+    ///     })
+    /// );
+    /// let mismatches: Vec<String> = mismatches.iter().map(ToString::to_string).collect();
+    /// assert_eq!(
+    ///     mismatches,
+    ///     [
+    ///         "/interfaces/interface[name='eth0']/speed: unknown node",
+    ///         "/interfaces/interface[name='eth1']/mtu: 10 is out of range 68..9216",
+    ///     ]
+    /// );
+    /// ```
+    pub fn normalize(&self, path: &str, document: &Value) -> (Value, Vec<Violation>) {
+        let mut normalizer = Normalizer {
+            schema: self,
+            mismatches: Vec::new(),
+        };
+        let normalized = match document {
+            Value::Object(members) => Value::Object(normalizer.object(path, path, members)),
+            _ => {
+                normalizer.report(
+                    path,
+                    ViolationKind::WrongType(document.clone()),
+                    format!("expected a JSON object, got {}", document),
+                );
+                Value::Object(Map::new())
+            }
+        };
+        (normalized, normalizer.mismatches)
+    }
+
+    /// Rewrite `document` like [`normalize`](Self::normalize) and
+    /// deserialize it into a generated type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rewritten document still does not match `T`,
+    /// e.g. because it lacks a mandatory leaf.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustconf_runtime::payload::{LeafType, PayloadSchema};
+    /// use serde::Deserialize;
+    /// use serde_json::json;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct System {
+    ///     hostname: Option<String>,
+    ///     #[serde(rename = "boot-count", default, with = "rustconf_runtime::int64::string")]
+    ///     boot_count: Option<u64>,
+    /// }
+    ///
+    /// let schema = PayloadSchema::new()
+    ///     .leaf("/hostname", LeafType::string(&[]), false)
+    ///     .leaf("/boot-count", LeafType::quoted_integer(&[]), false)
+    ///     .leaf("/domain", LeafType::string(&[]), false);
+    ///
+    /// // ygot: 64-bit integers as numbers, unset leaves as null
+    /// let document = json!({"hostname": "router1", "boot-count": 7, "domain": null, "motd": "hi"});
+    /// let loaded = schema.load_tolerant::<System>("", &document).unwrap();
+    /// assert_eq!(loaded.value.hostname.as_deref(), Some("router1"));
+    /// assert_eq!(loaded.value.boot_count, Some(7));
+    /// assert_eq!(loaded.mismatches[0].to_string(), "/motd: unknown node");
+    /// ```
+    pub fn load_tolerant<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        document: &Value,
+    ) -> Result<TolerantLoad<T>, serde_json::Error> {
+        let (normalized, mismatches) = self.normalize(path, document);
+        Ok(TolerantLoad {
+            value: T::deserialize(normalized)?,
+            mismatches,
+        })
+    }
+}
+
+/// Walks a document, rewriting it and collecting the members left out.
+struct Normalizer<'a> {
+    schema: &'a PayloadSchema,
+    mismatches: Vec<Violation>,
+}
+
+impl Normalizer<'_> {
+    fn report(&mut self, data_path: &str, kind: ViolationKind, message: String) {
+        self.mismatches.push(Violation {
+            path: if data_path.is_empty() {
+                "/".to_string()
+            } else {
+                data_path.to_string()
+            },
+            kind,
+            message,
+        });
+    }
+
+    /// Rewrite the members of the container or list entry at `schema_path`.
+    fn object(
+        &mut self,
+        schema_path: &str,
+        data_path: &str,
+        members: &Map<String, Value>,
+    ) -> Map<String, Value> {
+        let mut normalized = Map::new();
+        for (member, value) in members {
+            // RFC 7952 metadata annotations
+            if member.starts_with('@') {
+                normalized.insert(member.clone(), value.clone());
+                continue;
+            }
+            let name = local_name(member);
+            if let Some(value) = self.member(schema_path, data_path, name, value) {
+                normalized.insert(name.to_string(), value);
+            }
+        }
+        normalized
+    }
+
+    /// Rewrite the member `name` of the object at `schema_path`, or `None`
+    /// to leave it out.
+    fn member(
+        &mut self,
+        schema_path: &str,
+        data_path: &str,
+        name: &str,
+        value: &Value,
+    ) -> Option<Value> {
+        let child_schema = format!("{}/{}", schema_path, name);
+        let child_data = format!("{}/{}", data_path, name);
+        let Some(node) = self.schema.node(&child_schema) else {
+            self.report(
+                &child_data,
+                ViolationKind::UnknownNode,
+                "unknown node".to_string(),
+            );
+            return None;
+        };
+        // Unset nodes
+        if value.is_null() {
+            return None;
+        }
+        match node {
+            PayloadNode::Container => match value {
+                Value::Object(members) => Some(Value::Object(self.object(
+                    &child_schema,
+                    &child_data,
+                    members,
+                ))),
+                _ => {
+                    self.report(
+                        &child_data,
+                        ViolationKind::WrongType(value.clone()),
+                        format!("expected a JSON object, got {}", value),
+                    );
+                    None
+                }
+            },
+            PayloadNode::List(keys) => {
+                let entries: Vec<(Option<&str>, &Value)> = match value {
+                    Value::Array(entries) => entries.iter().map(|entry| (None, entry)).collect(),
+                    // Entries keyed by their key values, as pyangbind writes them
+                    Value::Object(entries) => entries
+                        .iter()
+                        .map(|(key, entry)| (Some(key.as_str()), entry))
+                        .collect(),
+                    _ => {
+                        self.report(
+                            &child_data,
+                            ViolationKind::WrongType(value.clone()),
+                            format!("expected an array of list entries, got {}", value),
+                        );
+                        return None;
+                    }
+                };
+                let entries = entries
+                    .into_iter()
+                    .filter_map(|(key_values, entry)| {
+                        self.entry(&child_schema, &child_data, keys, key_values, entry)
+                    })
+                    .collect();
+                Some(Value::Array(entries))
+            }
+            PayloadNode::Leaf(leaf_type, _) => self.leaf(leaf_type, &child_data, value),
+            PayloadNode::LeafList(leaf_type) => {
+                let values = match value {
+                    Value::Array(values) => values.iter().collect(),
+                    value => vec![value],
+                };
+                let values = values
+                    .into_iter()
+                    .filter_map(|value| self.leaf(leaf_type, &child_data, value))
+                    .collect();
+                Some(Value::Array(values))
+            }
+        }
+    }
+
+    /// Rewrite a list entry, taking missing key leaves from `key_values`,
+    /// the space-separated key values the entry was keyed by.
+    fn entry(
+        &mut self,
+        schema_path: &str,
+        data_path: &str,
+        keys: &[String],
+        key_values: Option<&str>,
+        entry: &Value,
+    ) -> Option<Value> {
+        let Value::Object(members) = entry else {
+            self.report(
+                data_path,
+                ViolationKind::WrongType(entry.clone()),
+                format!("expected a JSON object, got {}", entry),
+            );
+            return None;
+        };
+        let mut members = members.clone();
+        if let Some(key_values) = key_values {
+            let values: Vec<&str> = if keys.len() == 1 {
+                vec![key_values]
+            } else {
+                key_values.split_whitespace().collect()
+            };
+            for (key, value) in keys.iter().zip(values) {
+                if !members.keys().any(|member| local_name(member) == key) {
+                    members.insert(key.clone(), Value::String(value.to_string()));
+                }
+            }
+        }
+        let mut entry_data = data_path.to_string();
+        for key in keys {
+            match members.iter().find(|(member, _)| local_name(member) == key) {
+                Some((_, Value::String(text))) => {
+                    entry_data.push_str(&format!("[{}='{}']", key, text))
+                }
+                Some((_, value)) => entry_data.push_str(&format!("[{}='{}']", key, value)),
+                None => {}
+            }
+        }
+        Some(Value::Object(self.object(
+            schema_path,
+            &entry_data,
+            &members,
+        )))
+    }
+
+    /// Convert a leaf or leaf-list value to `leaf_type`, or `None` to leave
+    /// it out.
+    fn leaf(&mut self, leaf_type: &LeafType, data_path: &str, value: &Value) -> Option<Value> {
+        // `false` is how pyangbind writes an unset `empty` leaf
+        if *leaf_type == LeafType::Empty && *value == Value::Bool(false) {
+            return None;
+        }
+        match convert(leaf_type, value) {
+            Ok(value) => Some(value),
+            Err((kind, message)) => {
+                self.report(data_path, kind, message);
+                None
+            }
+        }
+    }
+}
+
+/// The value in the encoding of `leaf_type`, if it or one of its
+/// conversions matches the type.
+fn convert(leaf_type: &LeafType, value: &Value) -> Result<Value, (ViolationKind, String)> {
+    let error = match leaf_type.check(value) {
+        Ok(()) => return Ok(value.clone()),
+        Err(error) => error,
+    };
+    let converted = match (leaf_type, value) {
+        (LeafType::Empty, Value::Bool(true)) => Some(Value::Array(vec![Value::Null])),
+        (LeafType::Empty, Value::Object(members)) if members.is_empty() => {
+            Some(Value::Array(vec![Value::Null]))
+        }
+        (LeafType::Empty, Value::Array(values)) if values.is_empty() => {
+            Some(Value::Array(vec![Value::Null]))
+        }
+        (LeafType::Integer { quoted: true, .. }, Value::Number(number)) => {
+            Some(Value::String(number.to_string()))
+        }
+        (LeafType::Integer { quoted: false, .. }, Value::String(text)) => {
+            serde_json::from_str::<serde_json::Number>(text.trim())
+                .ok()
+                .map(Value::Number)
+        }
+        (LeafType::Boolean, Value::String(text)) => match text.as_str() {
+            "true" => Some(Value::Bool(true)),
+            "false" => Some(Value::Bool(false)),
+            _ => None,
+        },
+        (LeafType::Enumeration(_), Value::String(text)) if text.contains(':') => {
+            Some(Value::String(local_name(text).to_string()))
+        }
+        (LeafType::Union(types), value) => {
+            return types
+                .iter()
+                .find_map(|member| convert(member, value).ok())
+                .ok_or(error);
+        }
+        _ => None,
+    };
+    match converted {
+        // The converted value may still be out of range
+        Some(converted) => leaf_type.check(&converted).map(|()| converted),
+        None => Err(error),
+    }
+}
+
+/// The name of a member without its module qualifier.
+fn local_name(member: &str) -> &str {
+    member.split_once(':').map_or(member, |(_, name)| name)
+}
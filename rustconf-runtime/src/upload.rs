@@ -0,0 +1,352 @@
+//! Streaming request bodies, e.g. firmware images sent to an RPC.
+//!
+//! An RPC input carrying a multi-gigabyte image as a `binary` leaf would
+//! otherwise have to be read into memory, base64-encoded and serialized in
+//! one piece. A [`StreamingBody`] reads it from a [`BodySource`] in chunks
+//! instead, encoding each chunk as it goes, and reports progress as chunks
+//! are read. Bindings generate `*_streaming` variants of RPCs whose input
+//! has a single `binary` leaf, taking the leaf as a `StreamingBody`.
+//!
+//! Transports send it with [`HttpTransport::execute_streaming`]; the hyper
+//! adapter writes chunks to the connection as they are read, other
+//! transports collect the body first.
+//!
+//! Dropping the future of a streaming request stops reading the source and
+//! aborts the request. The body the server received is then cut off inside
+//! a JSON string, so the server rejects it rather than applying a truncated
+//! image. A streaming body is consumed by sending it, so a failed upload is
+//! never retried with a partial body; start over with a new source.
+//!
+//! [`HttpTransport::execute_streaming`]: crate::HttpTransport::execute_streaming
+
+use std::fmt;
+
+use async_trait::async_trait;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine as _;
+use bytes::Bytes;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::error::RpcError;
+
+/// Source of the bytes of a streaming body.
+#[async_trait]
+pub trait BodySource: Send {
+    /// Read the next chunk, or `None` at the end.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading fails; the upload is then aborted.
+    async fn read_chunk(&mut self) -> Result<Option<Bytes>, RpcError>;
+
+    /// Number of bytes left to read, if known.
+    fn size_hint(&self) -> Option<u64> {
+        None
+    }
+}
+
+/// An in-memory body, read in a single chunk.
+#[async_trait]
+impl BodySource for Bytes {
+    async fn read_chunk(&mut self) -> Result<Option<Bytes>, RpcError> {
+        let chunk = std::mem::take(self);
+        Ok((!chunk.is_empty()).then_some(chunk))
+    }
+
+    fn size_hint(&self) -> Option<u64> {
+        Some(self.len() as u64)
+    }
+}
+
+/// Reads a body from a tokio [`AsyncRead`](tokio::io::AsyncRead), e.g. a
+/// `tokio::fs::File`, in chunks of 64 KiB.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rustconf_runtime::upload::{ReaderSource, StreamingBody};
+///
+/// # async fn example(file: impl tokio::io::AsyncRead + Unpin + Send + 'static) {
+/// // e.g. a `tokio::fs::File`, with the length from its metadata
+/// let image = StreamingBody::new(ReaderSource::new(file).with_len(64 * 1024 * 1024));
+/// # }
+/// ```
+#[cfg(feature = "upload")]
+#[derive(Debug)]
+pub struct ReaderSource<R> {
+    reader: R,
+    remaining: Option<u64>,
+}
+
+#[cfg(feature = "upload")]
+impl<R: tokio::io::AsyncRead + Unpin + Send> ReaderSource<R> {
+    /// Read chunks from `reader`.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            remaining: None,
+        }
+    }
+
+    /// Declare the number of bytes `reader` will produce, so the total can
+    /// be reported as progress and sent as `Content-Length`.
+    pub fn with_len(mut self, len: u64) -> Self {
+        self.remaining = Some(len);
+        self
+    }
+}
+
+#[cfg(feature = "upload")]
+#[async_trait]
+impl<R: tokio::io::AsyncRead + Unpin + Send> BodySource for ReaderSource<R> {
+    async fn read_chunk(&mut self) -> Result<Option<Bytes>, RpcError> {
+        use tokio::io::AsyncReadExt;
+
+        let mut chunk = vec![0; 64 * 1024];
+        let read = self
+            .reader
+            .read(&mut chunk)
+            .await
+            .map_err(|e| RpcError::TransportError(format!("failed to read upload: {}", e)))?;
+        if read == 0 {
+            return Ok(None);
+        }
+        chunk.truncate(read);
+        if let Some(remaining) = &mut self.remaining {
+            *remaining = remaining.saturating_sub(read as u64);
+        }
+        Ok(Some(chunk.into()))
+    }
+
+    fn size_hint(&self) -> Option<u64> {
+        self.remaining
+    }
+}
+
+/// Progress of a streaming upload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UploadProgress {
+    /// Bytes read from the source so far.
+    pub sent: u64,
+    /// Total bytes of the source, if known.
+    pub total: Option<u64>,
+}
+
+type ProgressFn = Box<dyn FnMut(UploadProgress) + Send>;
+
+/// A request body read from a [`BodySource`] while it is sent.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::upload::StreamingBody;
+/// use rustconf_runtime::Bytes;
+///
+/// # async fn example() -> Result<(), rustconf_runtime::RpcError> {
+/// let body = StreamingBody::new(Bytes::from_static(b"image")).on_progress(|progress| {
+///     println!("{} of {:?} bytes", progress.sent, progress.total);
+/// });
+/// assert_eq!(body.collect().await?, "image");
+/// # Ok(())
+/// # }
+/// ```
+pub struct StreamingBody {
+    source: Box<dyn BodySource>,
+    total: Option<u64>,
+    sent: u64,
+    on_progress: Option<ProgressFn>,
+    /// Leading and trailing bytes around the source, e.g. the JSON a
+    /// `binary` leaf is embedded in.
+    prefix: Option<Bytes>,
+    suffix: Option<Bytes>,
+    /// Whether the source is base64-encoded, with the bytes left over from
+    /// the last chunk that did not fill a 3-byte group.
+    base64: bool,
+    carry: Vec<u8>,
+    finished: bool,
+}
+
+impl StreamingBody {
+    /// A body with the bytes of `source`.
+    pub fn new(source: impl BodySource + 'static) -> Self {
+        Self {
+            total: source.size_hint(),
+            source: Box::new(source),
+            sent: 0,
+            on_progress: None,
+            prefix: None,
+            suffix: None,
+            base64: false,
+            carry: Vec::new(),
+            finished: false,
+        }
+    }
+
+    /// Call `callback` after every chunk read from the source.
+    pub fn on_progress(mut self, callback: impl FnMut(UploadProgress) + Send + 'static) -> Self {
+        self.on_progress = Some(Box::new(callback));
+        self
+    }
+
+    /// Total bytes of the source, if known.
+    #[must_use]
+    pub fn source_len(&self) -> Option<u64> {
+        self.total
+    }
+
+    /// Length of the body as sent, if the length of the source is known;
+    /// only meaningful before the first chunk is read.
+    #[must_use]
+    pub fn content_length(&self) -> Option<u64> {
+        let source = if self.base64 {
+            self.total?.div_ceil(3) * 4
+        } else {
+            self.total?
+        };
+        let around = |bytes: &Option<Bytes>| bytes.as_ref().map_or(0, |bytes| bytes.len() as u64);
+        Some(around(&self.prefix) + source + around(&self.suffix))
+    }
+
+    /// Read the next chunk of the body, or `None` at the end.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the source.
+    pub async fn next_chunk(&mut self) -> Result<Option<Bytes>, RpcError> {
+        if let Some(prefix) = self.prefix.take() {
+            return Ok(Some(prefix));
+        }
+        while !self.finished {
+            match self.source.read_chunk().await? {
+                Some(chunk) => {
+                    self.sent += chunk.len() as u64;
+                    if let Some(callback) = &mut self.on_progress {
+                        callback(UploadProgress {
+                            sent: self.sent,
+                            total: self.total,
+                        });
+                    }
+                    if !self.base64 {
+                        return Ok(Some(chunk));
+                    }
+                    self.carry.extend_from_slice(&chunk);
+                    let whole = self.carry.len() / 3 * 3;
+                    if whole > 0 {
+                        let encoded = BASE64.encode(&self.carry[..whole]);
+                        self.carry.drain(..whole);
+                        return Ok(Some(encoded.into()));
+                    }
+                }
+                None => {
+                    self.finished = true;
+                    if !self.carry.is_empty() {
+                        let encoded = BASE64.encode(std::mem::take(&mut self.carry));
+                        return Ok(Some(encoded.into()));
+                    }
+                }
+            }
+        }
+        Ok(self.suffix.take())
+    }
+
+    /// Read the whole body into memory.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the source.
+    pub async fn collect(mut self) -> Result<Bytes, RpcError> {
+        let mut body = Vec::new();
+        while let Some(chunk) = self.next_chunk().await? {
+            body.extend_from_slice(&chunk);
+        }
+        Ok(body.into())
+    }
+}
+
+impl fmt::Debug for StreamingBody {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StreamingBody")
+            .field("total", &self.total)
+            .field("sent", &self.sent)
+            .field("base64", &self.base64)
+            .field("finished", &self.finished)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Embed `body` as the base64-encoded `binary` leaf `leaf` of `input`.
+///
+/// The other leaves of `input` are serialized as usual; any value `input`
+/// has for `leaf` is replaced. With `envelope`, the input is wrapped in that
+/// member, e.g. `example:input`.
+///
+/// # Errors
+///
+/// Returns `RpcError::SerializationError` if `input` does not serialize to
+/// a JSON object.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::upload::{binary_leaf_body, StreamingBody};
+/// use rustconf_runtime::Bytes;
+///
+/// # async fn example() -> Result<(), rustconf_runtime::RpcError> {
+/// let input = serde_json::json!({"version": "2.1", "image": []});
+/// let image = StreamingBody::new(Bytes::from_static(b"\x7fELF"));
+/// let body = binary_leaf_body(Some("fw:input"), &input, "image", image)?;
+/// assert_eq!(
+///     body.collect().await?,
+///     r#"{"fw:input":{"version":"2.1","image":"f0VMRg=="}}"#
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn binary_leaf_body<T: Serialize>(
+    envelope: Option<&str>,
+    input: &T,
+    leaf: &str,
+    mut body: StreamingBody,
+) -> Result<StreamingBody, RpcError> {
+    let error = |message: String| {
+        RpcError::SerializationError(format!("Failed to serialize input: {}", message))
+    };
+    let mut members = match serde_json::to_value(input).map_err(|e| error(e.to_string()))? {
+        Value::Object(members) => members,
+        other => return Err(error(format!("expected an object, got {}", other))),
+    };
+    let existing = members
+        .keys()
+        .find(|member| {
+            member
+                .split_once(':')
+                .map_or(member.as_str(), |(_, name)| name)
+                == leaf
+        })
+        .cloned();
+    let member = existing.unwrap_or_else(|| leaf.to_string());
+    members.remove(&member);
+
+    // `{"a":1}` becomes `{"a":1,"leaf":"` + base64 + `"}`
+    let object =
+        serde_json::to_string(&Value::Object(members)).map_err(|e| error(e.to_string()))?;
+    let separator = if object.len() > 2 { "," } else { "" };
+    let key = serde_json::to_string(&member).map_err(|e| error(e.to_string()))?;
+    let mut prefix = format!("{}{}{}:\"", &object[..object.len() - 1], separator, key);
+    let mut suffix = "\"}".to_string();
+    if let Some(envelope) = envelope {
+        let mut wrapper = Map::new();
+        wrapper.insert(envelope.to_string(), Value::Null);
+        let wrapper = serde_json::to_string(&wrapper).map_err(|e| error(e.to_string()))?;
+        // `{"example:input":null}` around the object
+        let open = &wrapper[..wrapper.len() - "null}".len()];
+        prefix = format!("{}{}", open, prefix);
+        suffix.push('}');
+    }
+
+    body.prefix = Some(prefix.into());
+    body.suffix = Some(suffix.into());
+    body.base64 = true;
+    Ok(body)
+}
@@ -0,0 +1,326 @@
+//! Parsing XML responses from servers that ignore the requested media type.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use async_trait::async_trait;
+use serde_json::{Map, Value};
+
+use super::{parse, Element, LeafValue, NetconfSchema, NodeKind};
+use crate::base_url::resource_path;
+use crate::error::RpcError;
+use crate::transport::{HttpMethod, HttpRequest, HttpResponse, HttpTransport};
+
+/// Namespace of the `ietf-restconf` module, used by `<errors>` bodies.
+const RESTCONF_NAMESPACE: &str = "urn:ietf:params:xml:ns:yang:ietf-restconf";
+
+type FallbackFn = Box<dyn Fn(&XmlFallback) + Send + Sync>;
+
+/// A response that was XML although the request did not accept XML.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XmlFallback {
+    /// Method of the request.
+    pub method: HttpMethod,
+    /// URL of the request.
+    pub url: String,
+    /// `Content-Type` the server responded with.
+    pub content_type: String,
+    /// Status code of the response.
+    pub status_code: u16,
+}
+
+/// Transport decorator that converts unrequested XML responses to JSON.
+///
+/// Some servers ignore the `Accept` header and answer with
+/// `application/yang-data+xml` (or plain `application/xml`). When a request
+/// that did not accept XML gets an XML body back, the body is converted to
+/// RFC 7951 JSON using the schema of the generated modules, so it
+/// deserializes into the same generated types instead of failing with a
+/// deserialization error. `ietf-restconf` `<errors>` bodies are converted
+/// too, so error mapping is unaffected.
+///
+/// The schema is the one generated with `enable_netconf`
+/// (`schema::netconf_schema()`); nodes missing from it are still converted,
+/// inferring lists from repeated elements and numbers and booleans from
+/// their text. Each deviation is reported to the callback set with
+/// [`XmlFallbackTransport::on_fallback`], e.g. to log it.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use rustconf_runtime::{HttpTransport, HttpRequest, HttpResponse, RpcError};
+/// # use async_trait::async_trait;
+/// # struct MyTransport;
+/// # #[async_trait]
+/// # impl HttpTransport for MyTransport {
+/// #     async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, RpcError> {
+/// #         todo!()
+/// #     }
+/// # }
+/// use rustconf_runtime::xml::{NetconfSchema, XmlFallbackTransport};
+/// use rustconf_runtime::RestconfClient;
+///
+/// # fn example(schema: NetconfSchema) -> Result<(), RpcError> {
+/// // `schema` comes from the generated `schema::netconf_schema()`
+/// let transport = XmlFallbackTransport::new(MyTransport, schema).on_fallback(|fallback| {
+///     eprintln!(
+///         "warning: {} returned {} instead of JSON",
+///         fallback.url, fallback.content_type
+///     );
+/// });
+/// let client = RestconfClient::new("https://device.example.com", transport)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// An XML body is converted with the schema, and one that cannot be
+/// converted, such as one nested too deeply, fails the request:
+///
+/// ```
+/// # use std::sync::{Arc, Mutex};
+/// # use rustconf_runtime::{HttpMethod, HttpTransport, HttpRequest, HttpResponse, RpcError};
+/// # use async_trait::async_trait;
+/// # /// Answers every request with an XML body.
+/// # struct MyTransport(String);
+/// # #[async_trait]
+/// # impl HttpTransport for MyTransport {
+/// #     async fn execute(&self, _request: HttpRequest) -> Result<HttpResponse, RpcError> {
+/// #         let mut response = HttpResponse::new(200);
+/// #         response.headers.push(("Content-Type".to_string(), "application/yang-data+xml".to_string()));
+/// #         response.body = self.0.clone().into_bytes();
+/// #         Ok(response)
+/// #     }
+/// # }
+/// use rustconf_runtime::xml::{LeafValue, NetconfSchema, NodeKind, XmlFallbackTransport};
+///
+/// let schema = NetconfSchema::new()
+///     .with_module("dev", "urn:dev")
+///     .with_node("/system", NodeKind::Container, &[])
+///     .with_node("/system/mtu", NodeKind::Leaf(LeafValue::Number), &[])
+///     .with_node("/system/server", NodeKind::List, &[])
+///     .with_node("/system/server/name", NodeKind::Leaf(LeafValue::String), &[]);
+/// let xml = r#"<?xml version="1.0"?><system xmlns="urn:dev"><mtu>1500</mtu>
+///     <server><name>ntp1</name></server></system>"#;
+/// let fallbacks = Arc::new(Mutex::new(Vec::new()));
+/// let seen = fallbacks.clone();
+/// let transport = XmlFallbackTransport::new(MyTransport(xml.to_string()), schema.clone())
+///     .on_fallback(move |fallback| seen.lock().unwrap().push(fallback.clone()));
+/// let url = "https://device.example.com/restconf/data/dev:system";
+/// # let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+///
+/// # runtime.block_on(async {
+/// let response = transport.execute(HttpRequest::new(HttpMethod::GET, url)).await.unwrap();
+/// assert_eq!(response.get_header("Content-Type"), Some("application/yang-data+json"));
+/// let body: serde_json::Value = serde_json::from_slice(&response.body).unwrap();
+/// assert_eq!(
+///     body,
+///     serde_json::json!({"dev:system": {"mtu": 1500, "server": [{"name": "ntp1"}]}})
+/// );
+/// let fallbacks = fallbacks.lock().unwrap();
+/// assert_eq!(fallbacks.len(), 1);
+/// assert_eq!(fallbacks[0].content_type, "application/yang-data+xml");
+/// assert_eq!(fallbacks[0].url, url);
+///
+/// // A request that accepts XML gets the body unchanged
+/// let request = HttpRequest::new(HttpMethod::GET, url).with_header("Accept", "application/yang-data+xml");
+/// let response = transport.execute(request).await.unwrap();
+/// assert_eq!(response.body, xml.as_bytes());
+///
+/// let nested = format!("{}{}", "<system>".repeat(100_000), "</system>".repeat(100_000));
+/// let transport = XmlFallbackTransport::new(MyTransport(nested), schema);
+/// let error = transport.execute(HttpRequest::new(HttpMethod::GET, url)).await.unwrap_err();
+/// assert!(matches!(error, RpcError::DeserializationError(message) if message.contains("nested")));
+/// # });
+/// ```
+pub struct XmlFallbackTransport<T> {
+    inner: T,
+    schema: NetconfSchema,
+    on_fallback: Option<FallbackFn>,
+}
+
+impl<T: HttpTransport> XmlFallbackTransport<T> {
+    /// Wrap a transport, converting XML responses with `schema`.
+    pub fn new(inner: T, schema: NetconfSchema) -> Self {
+        Self {
+            inner,
+            schema: restconf_schema().merge(schema),
+            on_fallback: None,
+        }
+    }
+
+    /// Call `callback` for every XML response that is converted.
+    ///
+    /// The callback runs before the body is converted, so it also sees
+    /// responses whose conversion fails.
+    pub fn on_fallback(mut self, callback: impl Fn(&XmlFallback) + Send + Sync + 'static) -> Self {
+        self.on_fallback = Some(Box::new(callback));
+        self
+    }
+
+    /// The wrapped transport.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Convert the XML body of a response to `url` to RFC 7951 JSON.
+    fn convert(&self, url: &str, body: &[u8]) -> Result<Vec<u8>, String> {
+        let text = std::str::from_utf8(body).map_err(|e| e.to_string())?;
+        // The declaration is only allowed at the start of the document, so
+        // it has to go before the elements are wrapped in a single root
+        let mut text = text.trim_start();
+        if text.starts_with("<?xml") {
+            let end = text
+                .find("?>")
+                .ok_or_else(|| "unterminated XML declaration".to_string())?;
+            text = &text[end + 2..];
+        }
+        // Several list entries may be returned without a common parent
+        let document = parse(&format!("<body>{}</body>", text))?;
+
+        let (resource, module) = resource_path(url);
+        let mut members: BTreeMap<String, (bool, Vec<Value>)> = BTreeMap::new();
+        for root in &document.children {
+            let (name, value, is_list) = self.root_to_json(root, &resource);
+            let name = match (&module, name.contains(':')) {
+                (Some(module), false) => format!("{}:{}", module, name),
+                _ => name,
+            };
+            let (list, values) = members.entry(name).or_default();
+            *list |= is_list;
+            values.push(value);
+        }
+
+        let mut object = Map::new();
+        for (name, (is_list, mut values)) in members {
+            let value = if is_list || values.len() > 1 {
+                Value::Array(values)
+            } else {
+                values.pop().unwrap_or(Value::Null)
+            };
+            object.insert(name, value);
+        }
+        serde_json::to_vec(&Value::Object(object)).map_err(|e| e.to_string())
+    }
+
+    /// Convert a top-level element of the body, returning its member name,
+    /// value and whether it is a list or leaf-list entry.
+    fn root_to_json(&self, root: &Element, resource: &[String]) -> (String, Value, bool) {
+        if root.namespace.as_deref() == Some(RESTCONF_NAMESPACE) && root.name == "data" {
+            // The datastore root: its children are top-level nodes
+            let mut data = Map::new();
+            for child in &root.children {
+                let (name, value) = self
+                    .schema
+                    .to_json(child, &format!("/{}", child.name), None);
+                data.insert(name, value);
+            }
+            return ("ietf-restconf:data".to_string(), Value::Object(data), false);
+        }
+
+        let path = if root.namespace.as_deref() == Some(RESTCONF_NAMESPACE) {
+            format!("/{}", root.name)
+        } else if resource.last() == Some(&root.name) {
+            // The requested node itself
+            format!("/{}", resource.join("/"))
+        } else {
+            // Below the requested resource, e.g. the `output` of an operation
+            let mut path: Vec<&str> = resource.iter().map(String::as_str).collect();
+            path.push(&root.name);
+            format!("/{}", path.join("/"))
+        };
+        let (name, value) = self.schema.to_json(root, &path, None);
+        let is_list = matches!(
+            self.schema.kind(&path),
+            Some(NodeKind::List | NodeKind::LeafList(_))
+        );
+        (name, value, is_list)
+    }
+}
+
+impl<T> fmt::Debug for XmlFallbackTransport<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("XmlFallbackTransport")
+            .field("inner", &self.inner)
+            .field("schema", &self.schema)
+            .field("on_fallback", &self.on_fallback.is_some())
+            .finish()
+    }
+}
+
+#[async_trait]
+impl<T: HttpTransport> HttpTransport for XmlFallbackTransport<T> {
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, RpcError> {
+        let accepts_xml = request.headers.iter().any(|(name, value)| {
+            name.eq_ignore_ascii_case("accept") && value.to_ascii_lowercase().contains("xml")
+        });
+        if accepts_xml {
+            return self.inner.execute(request).await;
+        }
+
+        let method = request.method;
+        let url = request.url.clone();
+        let mut response = self.inner.execute(request).await?;
+        let content_type = match response.get_header("Content-Type") {
+            Some(content_type) if is_xml(content_type) && !response.body.is_empty() => {
+                content_type.to_string()
+            }
+            _ => return Ok(response),
+        };
+
+        let fallback = XmlFallback {
+            method,
+            url,
+            content_type,
+            status_code: response.status_code,
+        };
+        if let Some(callback) = &self.on_fallback {
+            callback(&fallback);
+        }
+
+        let body = self.convert(&fallback.url, &response.body).map_err(|e| {
+            RpcError::DeserializationError(format!(
+                "{} returned {} instead of JSON, and the XML could not be converted: {}",
+                fallback.url, fallback.content_type, e
+            ))
+        })?;
+        response.headers.retain(|(name, _)| {
+            !name.eq_ignore_ascii_case("content-type")
+                && !name.eq_ignore_ascii_case("content-length")
+        });
+        response.headers.push((
+            "Content-Type".to_string(),
+            "application/yang-data+json".to_string(),
+        ));
+        response.body = body;
+        Ok(response)
+    }
+}
+
+/// Whether a `Content-Type` is an XML media type, e.g.
+/// `application/yang-data+xml` or `text/xml; charset=utf-8`.
+fn is_xml(content_type: &str) -> bool {
+    let media_type = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    media_type.ends_with("/xml") || media_type.ends_with("+xml")
+}
+
+/// The `ietf-restconf` nodes of error responses.
+fn restconf_schema() -> NetconfSchema {
+    let text = NodeKind::Leaf(LeafValue::String);
+    NetconfSchema::new()
+        .with_module("ietf-restconf", RESTCONF_NAMESPACE)
+        .with_node("/errors", NodeKind::Container, &[])
+        .with_node("/errors/error", NodeKind::List, &[])
+        .with_node("/errors/error/error-type", text, &[])
+        .with_node("/errors/error/error-tag", text, &[])
+        .with_node("/errors/error/error-app-tag", text, &[])
+        .with_node("/errors/error/error-path", text, &[])
+        .with_node("/errors/error/error-message", text, &[])
+}
@@ -0,0 +1,17 @@
+//! Converting XML bodies to the RFC 7951 JSON generated types expect.
+//!
+//! [`NetconfSchema`] holds the namespaces, list keys and value kinds needed
+//! to turn XML into JSON and back; the NETCONF transport uses it for
+//! replies, and [`XmlFallbackTransport`] for RESTCONF servers that answer
+//! with XML although JSON was requested.
+
+mod fallback;
+mod parser;
+mod schema;
+
+pub use fallback::{XmlFallback, XmlFallbackTransport};
+pub use schema::{LeafValue, NetconfSchema, NodeKind};
+
+#[cfg(feature = "netconf")]
+pub(crate) use parser::escape;
+pub(crate) use parser::{parse, Element};
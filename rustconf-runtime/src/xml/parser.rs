@@ -0,0 +1,280 @@
+//! Just enough XML for NETCONF replies and XML bodies from RESTCONF servers.
+//!
+//! Elements are parsed with their namespace resolved, their attributes and
+//! their text concatenated; processing instructions, comments and DTDs are
+//! skipped.
+
+use std::collections::HashMap;
+
+/// Deepest nesting of elements accepted, so that hostile documents cannot
+/// overflow the stack of the recursive parser (or of the conversions that
+/// walk the parsed tree).
+const MAX_DEPTH: usize = 256;
+
+/// An XML element.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Element {
+    /// Local name, without prefix.
+    pub name: String,
+    /// Namespace URI the element's prefix (or the default namespace) maps to.
+    pub namespace: Option<String>,
+    /// Attributes other than namespace declarations, by qualified name.
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<Element>,
+    /// Character data directly inside the element, entities decoded.
+    pub text: String,
+}
+
+impl Element {
+    /// The first child element named `name`.
+    #[cfg(feature = "netconf")]
+    pub fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    /// The value of the attribute named `name`.
+    #[cfg(feature = "netconf")]
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find_map(|(attribute, value)| (attribute == name).then_some(value.as_str()))
+    }
+}
+
+/// Parse an XML document into its root element.
+pub(crate) fn parse(document: &str) -> Result<Element, String> {
+    let mut parser = Parser {
+        input: document,
+        position: 0,
+    };
+    parser.skip_prolog()?;
+    parser.element(&HashMap::new(), 1)
+}
+
+/// Escape text for use in element content and attribute values.
+#[cfg(feature = "netconf")]
+pub(crate) fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '&' => escaped.push_str("&amp;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn rest(&self) -> &str {
+        &self.input[self.position..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    /// Skip past the next occurrence of `end`.
+    fn skip_past(&mut self, end: &str) -> Result<(), String> {
+        match self.rest().find(end) {
+            Some(offset) => {
+                self.position += offset + end.len();
+                Ok(())
+            }
+            None => Err(format!("unterminated markup, expected `{}`", end)),
+        }
+    }
+
+    /// Skip the XML declaration, comments and a DOCTYPE before the root.
+    fn skip_prolog(&mut self) -> Result<(), String> {
+        loop {
+            self.skip_whitespace();
+            let rest = self.rest();
+            if rest.starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if rest.starts_with("<!") {
+                self.skip_past(">")?;
+            } else if rest.starts_with('<') {
+                return Ok(());
+            } else {
+                return Err("document has no root element".to_string());
+            }
+        }
+    }
+
+    fn name(&mut self) -> &str {
+        let rest = self.rest();
+        let length = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '/' | '>' | '='))
+            .unwrap_or(rest.len());
+        let name = &self.input[self.position..self.position + length];
+        self.position += length;
+        name
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), String> {
+        if self.rest().starts_with(token) {
+            self.position += token.len();
+            Ok(())
+        } else {
+            Err(format!(
+                "expected `{}` at byte {} of the document",
+                token, self.position
+            ))
+        }
+    }
+
+    /// Parse the element starting at the current position, `depth` levels
+    /// below the document.
+    fn element(
+        &mut self,
+        scope: &HashMap<String, String>,
+        depth: usize,
+    ) -> Result<Element, String> {
+        if depth > MAX_DEPTH {
+            return Err(format!(
+                "elements nested deeper than {} levels at byte {}",
+                MAX_DEPTH, self.position
+            ));
+        }
+        self.expect("<")?;
+        let qualified = self.name().to_string();
+        if qualified.is_empty() {
+            return Err(format!("missing element name at byte {}", self.position));
+        }
+
+        let mut scope = scope.clone();
+        let mut attributes = Vec::new();
+        let self_closing = loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("/>") {
+                self.position += 2;
+                break true;
+            }
+            if self.rest().starts_with('>') {
+                self.position += 1;
+                break false;
+            }
+            let attribute = self.name().to_string();
+            if attribute.is_empty() {
+                return Err(format!("malformed attribute in <{}>", qualified));
+            }
+            self.skip_whitespace();
+            self.expect("=")?;
+            self.skip_whitespace();
+            let quote = match self.rest().chars().next() {
+                Some(quote @ ('"' | '\'')) => quote,
+                _ => return Err(format!("unquoted attribute value in <{}>", qualified)),
+            };
+            self.position += 1;
+            let length = self
+                .rest()
+                .find(quote)
+                .ok_or_else(|| format!("unterminated attribute value in <{}>", qualified))?;
+            let value = unescape(&self.rest()[..length]);
+            self.position += length + 1;
+
+            if attribute == "xmlns" {
+                scope.insert(String::new(), value);
+            } else if let Some(prefix) = attribute.strip_prefix("xmlns:") {
+                scope.insert(prefix.to_string(), value);
+            } else {
+                attributes.push((attribute, value));
+            }
+        };
+
+        let (prefix, name) = qualified.split_once(':').unwrap_or(("", &qualified));
+        let mut element = Element {
+            name: name.to_string(),
+            namespace: scope.get(prefix).cloned(),
+            attributes,
+            ..Element::default()
+        };
+        if self_closing {
+            return Ok(element);
+        }
+
+        loop {
+            let rest = self.rest();
+            if rest.is_empty() {
+                return Err(format!("unterminated element <{}>", qualified));
+            } else if rest.starts_with("</") {
+                self.position += 2;
+                let closing = self.name();
+                if closing != qualified {
+                    return Err(format!("</{}> does not close <{}>", closing, qualified));
+                }
+                self.skip_whitespace();
+                self.expect(">")?;
+                return Ok(element);
+            } else if rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if let Some(data) = rest.strip_prefix("<![CDATA[") {
+                let length = data
+                    .find("]]>")
+                    .ok_or_else(|| "unterminated CDATA section".to_string())?;
+                element.text.push_str(&data[..length]);
+                self.position += "<![CDATA[".len() + length + "]]>".len();
+            } else if rest.starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if rest.starts_with('<') {
+                let child = self.element(&scope, depth + 1)?;
+                element.children.push(child);
+            } else {
+                let length = rest.find('<').unwrap_or(rest.len());
+                element.text.push_str(&unescape(&rest[..length]));
+                self.position += length;
+            }
+        }
+    }
+}
+
+/// Decode the predefined and numeric character entities.
+fn unescape(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let Some(end) = rest.find(';') else {
+            break;
+        };
+        let entity = &rest[1..end];
+        let character = match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        };
+        match character {
+            Some(character) => {
+                decoded.push(character);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
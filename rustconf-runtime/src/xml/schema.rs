@@ -0,0 +1,277 @@
+//! The schema knowledge needed to convert between RFC 7951 JSON and XML.
+//!
+//! XML does not distinguish a list with one entry from a container, nor a
+//! number from a string, so converting a NETCONF reply or an XML response
+//! body into the JSON the generated types expect needs the kind of every
+//! node. Generated code provides it with `schema::netconf_schema()`.
+
+use std::collections::HashMap;
+
+use serde_json::{Map, Number, Value};
+
+#[cfg(feature = "netconf")]
+use super::parser::escape;
+use super::parser::Element;
+
+/// JSON representation of a leaf or leaf-list value (RFC 7951 section 6).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeafValue {
+    /// A JSON string, e.g. strings, enumerations and 64-bit integers.
+    String,
+    /// A JSON number.
+    Number,
+    /// `true` or `false`.
+    Boolean,
+    /// The `empty` type, encoded as `[null]`.
+    Empty,
+    /// Unknown, e.g. a union: numbers and booleans are recognised by their
+    /// text, anything else is a string.
+    Any,
+}
+
+/// Kind of a data node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeKind {
+    Container,
+    List,
+    Leaf(LeafValue),
+    LeafList(LeafValue),
+}
+
+/// Data nodes and module namespaces of the models used over NETCONF.
+///
+/// Nodes are identified by their schema path without module qualifiers or
+/// keys, e.g. `/interfaces/interface/mtu`, as returned by the generated
+/// `SchemaNode::schema_path`. Nodes not in the schema are still converted,
+/// treating repeated elements as lists and inferring leaf values from their
+/// text.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::xml::{LeafValue, NetconfSchema, NodeKind};
+///
+/// let schema = NetconfSchema::new()
+///     .with_module("example", "urn:example")
+///     .with_node("/interfaces", NodeKind::Container, &[])
+///     .with_node("/interfaces/interface", NodeKind::List, &["name"])
+///     .with_node("/interfaces/interface/name", NodeKind::Leaf(LeafValue::String), &[])
+///     .with_node("/interfaces/interface/mtu", NodeKind::Leaf(LeafValue::Number), &[]);
+/// assert_eq!(schema.keys("/interfaces/interface"), ["name"]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct NetconfSchema {
+    modules: Vec<(String, String)>,
+    nodes: HashMap<String, (NodeKind, Vec<String>)>,
+}
+
+impl NetconfSchema {
+    /// Create an empty schema.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a module and its XML namespace.
+    pub fn with_module(mut self, name: impl Into<String>, namespace: impl Into<String>) -> Self {
+        self.modules.push((name.into(), namespace.into()));
+        self
+    }
+
+    /// Add a data node; `keys` are the key leaves of a list, in order.
+    pub fn with_node(mut self, path: &str, kind: NodeKind, keys: &[&str]) -> Self {
+        self.nodes.insert(
+            path.to_string(),
+            (kind, keys.iter().map(|key| key.to_string()).collect()),
+        );
+        self
+    }
+
+    /// Add the modules and nodes of another schema, e.g. of another
+    /// generated module.
+    pub fn merge(mut self, other: NetconfSchema) -> Self {
+        self.modules.extend(other.modules);
+        self.nodes.extend(other.nodes);
+        self
+    }
+
+    /// Kind of the node at `path`, if known.
+    pub fn kind(&self, path: &str) -> Option<NodeKind> {
+        self.nodes.get(path).map(|(kind, _)| *kind)
+    }
+
+    /// Key leaves of the list at `path`.
+    pub fn keys(&self, path: &str) -> &[String] {
+        self.nodes
+            .get(path)
+            .map(|(_, keys)| keys.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// XML namespace of a module.
+    pub fn namespace(&self, module: &str) -> Option<&str> {
+        self.modules
+            .iter()
+            .find(|(name, _)| name == module)
+            .map(|(_, namespace)| namespace.as_str())
+    }
+
+    /// Module with the XML namespace `namespace`.
+    pub fn module(&self, namespace: &str) -> Option<&str> {
+        self.modules
+            .iter()
+            .find(|(_, uri)| uri == namespace)
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Convert an element at `path` to its JSON member name and value.
+    ///
+    /// The name is qualified with the element's module when its namespace
+    /// differs from `parent_namespace`.
+    pub(crate) fn to_json(
+        &self,
+        element: &Element,
+        path: &str,
+        parent_namespace: Option<&str>,
+    ) -> (String, Value) {
+        let name = match (&element.namespace, parent_namespace) {
+            (Some(namespace), parent) if Some(namespace.as_str()) != parent => {
+                match self.module(namespace) {
+                    Some(module) => format!("{}:{}", module, element.name),
+                    None => element.name.clone(),
+                }
+            }
+            _ => element.name.clone(),
+        };
+
+        let value = match self.kind(path) {
+            Some(NodeKind::Leaf(value) | NodeKind::LeafList(value)) => {
+                leaf_to_json(&element.text, value)
+            }
+            Some(NodeKind::Container | NodeKind::List) => self.object_to_json(element, path),
+            None if element.children.is_empty() => leaf_to_json(&element.text, LeafValue::Any),
+            None => self.object_to_json(element, path),
+        };
+        (name, value)
+    }
+
+    fn object_to_json(&self, element: &Element, path: &str) -> Value {
+        let mut object = Map::new();
+        let repeated = |name: &str| {
+            element
+                .children
+                .iter()
+                .filter(|child| child.name == name)
+                .count()
+                > 1
+        };
+        for child in &element.children {
+            let child_path = format!("{}/{}", path, child.name);
+            let is_array = match self.kind(&child_path) {
+                Some(NodeKind::List | NodeKind::LeafList(_)) => true,
+                Some(_) => false,
+                None => repeated(&child.name),
+            };
+            let (name, value) = self.to_json(child, &child_path, element.namespace.as_deref());
+            if is_array {
+                if let Value::Array(entries) = object
+                    .entry(name)
+                    .or_insert_with(|| Value::Array(Vec::new()))
+                {
+                    entries.push(value);
+                }
+            } else {
+                object.insert(name, value);
+            }
+        }
+        Value::Object(object)
+    }
+
+    /// Convert a JSON member at `path` to XML.
+    ///
+    /// Arrays become one element per entry, with list keys written first as
+    /// NETCONF requires. `operation` is added as an `xc:operation` attribute
+    /// to the outermost elements.
+    #[cfg(feature = "netconf")]
+    pub(crate) fn to_xml(
+        &self,
+        member: &str,
+        value: &Value,
+        path: &str,
+        parent_namespace: Option<&str>,
+        operation: Option<&str>,
+        output: &mut String,
+    ) {
+        let (module, name) = match member.split_once(':') {
+            Some((module, name)) => (Some(module), name),
+            None => (None, member),
+        };
+        let namespace = module
+            .and_then(|module| self.namespace(module))
+            .or(parent_namespace);
+        let path = format!("{}/{}", path, name);
+
+        // Lists and leaf-lists, and `[null]` for the `empty` type
+        let entries: &[Value] = match value {
+            Value::Array(entries) => entries,
+            _ => std::slice::from_ref(value),
+        };
+        for entry in entries {
+            output.push('<');
+            output.push_str(name);
+            if namespace != parent_namespace {
+                if let Some(namespace) = namespace {
+                    output.push_str(&format!(" xmlns=\"{}\"", escape(namespace)));
+                }
+            }
+            if let Some(operation) = operation {
+                output.push_str(&format!(" xc:operation=\"{}\"", operation));
+            }
+            output.push('>');
+            match entry {
+                Value::Object(members) => {
+                    let keys = self.keys(&path);
+                    let ordered = keys
+                        .iter()
+                        .filter_map(|key| members.get_key_value(key.as_str()))
+                        .chain(
+                            members
+                                .iter()
+                                .filter(|(member, _)| !keys.iter().any(|key| key == *member)),
+                        );
+                    for (member, value) in ordered {
+                        self.to_xml(member, value, &path, namespace, None, output);
+                    }
+                }
+                Value::String(text) => output.push_str(&escape(text)),
+                Value::Null => {}
+                other => output.push_str(&other.to_string()),
+            }
+            output.push_str("</");
+            output.push_str(name);
+            output.push('>');
+        }
+    }
+}
+
+/// Convert the text of a leaf to its JSON value.
+fn leaf_to_json(text: &str, value: LeafValue) -> Value {
+    let trimmed = text.trim();
+    let number = || {
+        serde_json::from_str::<Number>(trimmed)
+            .ok()
+            .map(Value::Number)
+    };
+    let boolean = || match trimmed {
+        "true" => Some(Value::Bool(true)),
+        "false" => Some(Value::Bool(false)),
+        _ => None,
+    };
+    match value {
+        LeafValue::String => Some(Value::String(text.to_string())),
+        LeafValue::Number => number(),
+        LeafValue::Boolean => boolean(),
+        LeafValue::Empty => Some(Value::Array(vec![Value::Null])),
+        LeafValue::Any => number().or_else(boolean),
+    }
+    .unwrap_or_else(|| Value::String(text.to_string()))
+}
@@ -0,0 +1,302 @@
+//! Checking a server's YANG library against the modules code was generated from.
+//!
+//! Servers list the modules they support in `ietf-yang-library` (RFC 8525, or
+//! the older `modules-state` of RFC 7895). Each module is either implemented,
+//! meaning the server serves its data nodes and operations, or import-only,
+//! meaning only its typedefs and groupings are used by other modules. Only
+//! implemented modules affect what the generated requests can do, so only
+//! they have to match the generated revisions.
+
+use std::fmt;
+
+use serde_json::Value;
+
+use crate::error::RpcError;
+use crate::transport::{HttpMethod, HttpTransport, RestconfClient};
+
+/// Path of the RFC 8525 YANG library.
+pub const YANG_LIBRARY_PATH: &str = "/restconf/data/ietf-yang-library:yang-library";
+
+/// Path of the RFC 7895 module list, for servers without RFC 8525.
+pub const MODULES_STATE_PATH: &str = "/restconf/data/ietf-yang-library:modules-state";
+
+/// How a server supports a module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Conformance {
+    /// The server implements the module's data nodes and operations.
+    Implement,
+    /// The module is only imported for its definitions.
+    Import,
+}
+
+/// A module listed in a server's YANG library.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LibraryModule {
+    /// Module name.
+    pub name: String,
+    /// Revision date, `None` when the module has no revision.
+    pub revision: Option<String>,
+    /// XML namespace, when listed.
+    pub namespace: Option<String>,
+    /// Whether the module is implemented or import-only.
+    pub conformance: Conformance,
+}
+
+/// The modules a server supports, from `ietf-yang-library`.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf_runtime::yang_library::YangLibrary;
+///
+/// let body = br#"{
+///   "ietf-yang-library:yang-library": {
+///     "module-set": [{
+///       "name": "default",
+///       "module": [{"name": "interfaces", "revision": "2024-01-01"}],
+///       "import-only-module": [{"name": "ietf-inet-types", "revision": "2013-07-15"}]
+///     }]
+///   }
+/// }"#;
+/// let library = YangLibrary::from_json(body)?;
+///
+/// // The import-only revision differs, which is only a warning
+/// let warnings = library.verify(
+///     &[("interfaces", Some("2024-01-01"))],
+///     &[("ietf-inet-types", Some("2021-02-22"))],
+/// )?;
+/// assert_eq!(warnings.len(), 1);
+///
+/// // A different implemented revision is an error
+/// assert!(library
+///     .verify(&[("interfaces", Some("2025-06-01"))], &[])
+///     .is_err());
+/// # Ok::<(), rustconf_runtime::RpcError>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct YangLibrary {
+    modules: Vec<LibraryModule>,
+}
+
+impl YangLibrary {
+    /// Create a library from a list of modules.
+    pub fn new(modules: Vec<LibraryModule>) -> Self {
+        Self { modules }
+    }
+
+    /// Parse an `ietf-yang-library:yang-library` (RFC 8525) or
+    /// `ietf-yang-library:modules-state` (RFC 7895) JSON document.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RpcError::DeserializationError` if the body is not JSON or
+    /// contains neither container.
+    pub fn from_json(body: &[u8]) -> Result<Self, RpcError> {
+        let document: Value = serde_json::from_slice(body)
+            .map_err(|e| RpcError::DeserializationError(e.to_string()))?;
+        let mut modules = Vec::new();
+
+        if let Some(library) = document.get("ietf-yang-library:yang-library") {
+            for module_set in entries(library, "module-set") {
+                for (member, conformance) in [
+                    ("module", Conformance::Implement),
+                    ("import-only-module", Conformance::Import),
+                ] {
+                    for module in entries(module_set, member) {
+                        if let Some(module) = library_module(module, conformance) {
+                            modules.push(module);
+                        }
+                    }
+                }
+            }
+        } else if let Some(state) = document.get("ietf-yang-library:modules-state") {
+            for module in entries(state, "module") {
+                let conformance = match module.get("conformance-type").and_then(Value::as_str) {
+                    Some("import") => Conformance::Import,
+                    _ => Conformance::Implement,
+                };
+                if let Some(module) = library_module(module, conformance) {
+                    modules.push(module);
+                }
+            }
+        } else {
+            return Err(RpcError::DeserializationError(
+                "response contains no ietf-yang-library:yang-library or modules-state".to_string(),
+            ));
+        }
+        Ok(Self { modules })
+    }
+
+    /// Read the server's YANG library, falling back to `modules-state` when
+    /// the server does not support RFC 8525.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if neither resource can be read or parsed.
+    pub async fn fetch<T: HttpTransport>(client: &RestconfClient<T>) -> Result<Self, RpcError> {
+        match client
+            .raw_request(HttpMethod::GET, YANG_LIBRARY_PATH, None)
+            .await
+        {
+            Ok(response) => Self::from_json(&response.body),
+            Err(RpcError::HttpError {
+                status_code: 400 | 404,
+                ..
+            }) => {
+                let response = client
+                    .raw_request(HttpMethod::GET, MODULES_STATE_PATH, None)
+                    .await?;
+                Self::from_json(&response.body)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// All listed modules.
+    pub fn modules(&self) -> &[LibraryModule] {
+        &self.modules
+    }
+
+    /// The implemented revision of a module, if the server implements it.
+    pub fn implemented(&self, name: &str) -> Option<&LibraryModule> {
+        self.modules
+            .iter()
+            .find(|module| module.name == name && module.conformance == Conformance::Implement)
+    }
+
+    /// Check that the server supports the modules code was generated from.
+    ///
+    /// `implemented` are the generated modules and `imported` the modules
+    /// they import, as `(name, revision)` pairs such as the generated
+    /// `consts::IMPLEMENTED_MODULES` and `consts::IMPORTED_MODULES`. A `None`
+    /// revision matches any revision.
+    ///
+    /// Implemented modules must be implemented by the server at the same
+    /// revision. Imported modules only need to be listed; a missing module or
+    /// a different revision is returned as a warning, since it only affects
+    /// shared definitions such as typedefs.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RpcError::ValidationError` describing every implemented
+    /// module that the server does not implement at the expected revision.
+    pub fn verify(
+        &self,
+        implemented: &[(&str, Option<&str>)],
+        imported: &[(&str, Option<&str>)],
+    ) -> Result<Vec<ModuleMismatch>, RpcError> {
+        let mut errors = Vec::new();
+        let mut warnings = Vec::new();
+
+        for &(name, revision) in implemented {
+            let found = self.implemented(name);
+            if found.is_some_and(|module| matches(revision, module.revision.as_deref())) {
+                continue;
+            }
+            // Fall back to an import-only entry to explain the mismatch
+            let listed = found.or_else(|| self.modules.iter().find(|module| module.name == name));
+            errors.push(ModuleMismatch {
+                module: name.to_string(),
+                expected_revision: revision.map(str::to_string),
+                found_revision: listed.and_then(|module| module.revision.clone()),
+                conformance: listed.map(|module| module.conformance),
+            });
+        }
+
+        for &(name, revision) in imported {
+            let listed: Vec<&LibraryModule> = self
+                .modules
+                .iter()
+                .filter(|module| module.name == name)
+                .collect();
+            if listed
+                .iter()
+                .any(|module| matches(revision, module.revision.as_deref()))
+            {
+                continue;
+            }
+            warnings.push(ModuleMismatch {
+                module: name.to_string(),
+                expected_revision: revision.map(str::to_string),
+                found_revision: listed.first().and_then(|module| module.revision.clone()),
+                conformance: listed.first().map(|module| module.conformance),
+            });
+        }
+
+        if errors.is_empty() {
+            Ok(warnings)
+        } else {
+            let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+            Err(RpcError::ValidationError(messages.join("; ")))
+        }
+    }
+}
+
+/// A module the server does not support as expected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleMismatch {
+    /// Module name.
+    pub module: String,
+    /// Revision the code was generated from.
+    pub expected_revision: Option<String>,
+    /// Revision listed by the server, if the module is listed.
+    pub found_revision: Option<String>,
+    /// How the server lists the module, `None` if it is not listed.
+    pub conformance: Option<Conformance>,
+}
+
+impl fmt::Display for ModuleMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "module {}", self.module)?;
+        if let Some(revision) = &self.expected_revision {
+            write!(f, "@{}", revision)?;
+        }
+        match (self.conformance, &self.found_revision) {
+            (None, _) => write!(f, " is not listed by the server"),
+            (Some(Conformance::Import), found)
+                if matches(self.expected_revision.as_deref(), found.as_deref()) =>
+            {
+                write!(f, " is only imported by the server")
+            }
+            (Some(conformance), found) => {
+                let listed = match conformance {
+                    Conformance::Implement => "implements",
+                    Conformance::Import => "imports",
+                };
+                match found {
+                    Some(found) => write!(f, " differs: the server {} revision {}", listed, found),
+                    None => write!(f, " differs: the server {} it without a revision", listed),
+                }
+            }
+        }
+    }
+}
+
+/// Entries of the list `member` of `object`.
+fn entries<'a>(object: &'a Value, member: &str) -> impl Iterator<Item = &'a Value> {
+    object
+        .get(member)
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+}
+
+fn library_module(module: &Value, conformance: Conformance) -> Option<LibraryModule> {
+    let text = |member: &str| {
+        module
+            .get(member)
+            .and_then(Value::as_str)
+            .filter(|text| !text.is_empty())
+            .map(str::to_string)
+    };
+    Some(LibraryModule {
+        name: text("name")?,
+        revision: text("revision"),
+        namespace: text("namespace"),
+        conformance,
+    })
+}
+
+fn matches(expected: Option<&str>, found: Option<&str>) -> bool {
+    expected.is_none_or(|expected| found == Some(expected))
+}
@@ -0,0 +1,71 @@
+//! The `rustconf api-diff` command.
+
+use std::fs;
+use std::process::ExitCode;
+
+use rustconf::generator::api::ApiManifest;
+
+const USAGE: &str = "\
+Usage: rustconf api-diff [options] <old-manifest> <new-manifest>
+
+Compares two API manifests written by RustconfBuilder::api_manifest, listing
+the breaking and additive changes and the version bump they call for.
+
+Options:
+  --version <version>    Current version of the crate, to print the next version
+  --deny-breaking        Exit with status 1 if any change is breaking";
+
+/// Run the `api-diff` command with the arguments following the command name.
+pub fn run(args: &[String]) -> Result<ExitCode, String> {
+    let mut version = None;
+    let mut deny_breaking = false;
+    let mut files = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--version" => {
+                let value = args.next().ok_or("--version requires a value")?;
+                version = Some(value.clone());
+            }
+            "--deny-breaking" => deny_breaking = true,
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(ExitCode::SUCCESS);
+            }
+            flag if flag.starts_with("--") => {
+                return Err(format!("unknown option '{}'\n\n{}", flag, USAGE));
+            }
+            file => files.push(file.to_string()),
+        }
+    }
+
+    let [old, new] = files.as_slice() else {
+        return Err(format!("expected two manifests\n\n{}", USAGE));
+    };
+    let old = read_manifest(old)?;
+    let new = read_manifest(new)?;
+
+    let diff = old.diff(&new);
+    print!("{}", diff);
+    let bump = diff.version_bump();
+    match version {
+        Some(version) => {
+            let next = bump
+                .next_version(&version)
+                .ok_or_else(|| format!("invalid version '{}'", version))?;
+            println!("\nVersion bump: {} ({} -> {})", bump, version, next);
+        }
+        None => println!("\nVersion bump: {}", bump),
+    }
+
+    if deny_breaking && diff.is_breaking() {
+        return Ok(ExitCode::FAILURE);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn read_manifest(file: &str) -> Result<ApiManifest, String> {
+    let json = fs::read_to_string(file).map_err(|e| format!("failed to read {}: {}", file, e))?;
+    ApiManifest::from_json(&json).map_err(|e| format!("{}: {}", file, e))
+}
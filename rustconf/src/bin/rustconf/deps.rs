@@ -0,0 +1,272 @@
+//! The `rustconf deps` command.
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use rustconf::parser::{Import, ModuleSource, YangModule};
+use rustconf::YangParser;
+
+const USAGE: &str = "\
+Usage: rustconf deps --yang <file> [options]
+
+Prints the import and include graph of a YANG module, showing the file and
+search path each dependency was loaded from and the revision that was found.
+
+Options:
+  --yang <file>          Entry YANG module
+  --search-path <dir>    Directory used to resolve imports and includes (repeatable)
+  --format <format>      Output format: text (default) or dot";
+
+/// Output format of the graph.
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    Text,
+    Dot,
+}
+
+/// Run the `deps` command with the arguments following the command name.
+pub fn run(args: &[String]) -> Result<ExitCode, String> {
+    let mut yang_file = None;
+    let mut search_paths = Vec::new();
+    let mut format = Format::Text;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--yang" => {
+                let value = args.next().ok_or("--yang requires a value")?;
+                yang_file = Some(PathBuf::from(value));
+            }
+            "--search-path" => {
+                let value = args.next().ok_or("--search-path requires a value")?;
+                search_paths.push(PathBuf::from(value));
+            }
+            "--format" => {
+                format = match args.next().map(String::as_str) {
+                    Some("text") => Format::Text,
+                    Some("dot") => Format::Dot,
+                    Some(other) => return Err(format!("unknown format '{}'", other)),
+                    None => return Err("--format requires a value".to_string()),
+                };
+            }
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(ExitCode::SUCCESS);
+            }
+            other => return Err(format!("unknown argument '{}'\n\n{}", other, USAGE)),
+        }
+    }
+
+    let yang_file = yang_file.ok_or_else(|| format!("--yang is required\n\n{}", USAGE))?;
+
+    let mut parser = YangParser::new();
+    for search_path in search_paths {
+        parser.add_search_path(search_path);
+    }
+    let module = parser
+        .parse_file(&yang_file)
+        .map_err(|e| format!("{}: {}", yang_file.display(), e))?;
+
+    let graph = DependencyGraph { parser: &parser };
+    let output = match format {
+        Format::Text => graph.text(&module),
+        Format::Dot => graph.dot(&module),
+    };
+    print!("{}", output);
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Renders the dependencies recorded by a parser.
+struct DependencyGraph<'p> {
+    parser: &'p YangParser,
+}
+
+impl DependencyGraph<'_> {
+    /// Indented tree; modules reached more than once are expanded only the first time.
+    fn text(&self, module: &YangModule) -> String {
+        let mut output = format!(
+            "{}{}\n",
+            name_with_revision(&module.name, self.source(&module.name)),
+            location(self.source(&module.name))
+        );
+        let mut expanded = HashSet::from([module.name.clone()]);
+        self.text_children(module, 1, &mut expanded, &mut output);
+        output
+    }
+
+    fn text_children(
+        &self,
+        module: &YangModule,
+        depth: usize,
+        expanded: &mut HashSet<String>,
+        output: &mut String,
+    ) {
+        let indent = "  ".repeat(depth);
+
+        if let Some(source) = self.source(&module.name) {
+            for submodule in &source.includes {
+                output.push_str(&format!(
+                    "{}include {}{}\n",
+                    indent,
+                    name_with_revision(submodule, self.source(submodule)),
+                    location(self.source(submodule))
+                ));
+            }
+        }
+
+        for import in &module.imports {
+            let Some(imported) = self.parser.get_loaded_module(&import.module) else {
+                output.push_str(&format!(
+                    "{}import {} as {}: not found{}\n",
+                    indent,
+                    import.module,
+                    import.prefix,
+                    requested_revision(import, None)
+                ));
+                continue;
+            };
+
+            let source = self.source(&import.module);
+            let first_visit = expanded.insert(import.module.clone());
+            output.push_str(&format!(
+                "{}import {} as {}{}{}{}\n",
+                indent,
+                name_with_revision(&import.module, source),
+                import.prefix,
+                location(source),
+                requested_revision(import, source),
+                if first_visit { "" } else { " (see above)" }
+            ));
+            if first_visit {
+                self.text_children(imported, depth + 1, expanded, output);
+            }
+        }
+    }
+
+    /// Graphviz digraph with one node per module and an edge per import or include.
+    fn dot(&self, module: &YangModule) -> String {
+        let mut output = String::from("digraph deps {\n    node [shape=box];\n");
+        let mut visited = HashSet::new();
+        self.dot_module(&module.name, Some(module), &mut visited, &mut output);
+        output.push_str("}\n");
+        output
+    }
+
+    fn dot_module(
+        &self,
+        name: &str,
+        module: Option<&YangModule>,
+        visited: &mut HashSet<String>,
+        output: &mut String,
+    ) {
+        if !visited.insert(name.to_string()) {
+            return;
+        }
+
+        let source = self.source(name);
+        let mut label = name.to_string();
+        match source {
+            Some(source) => {
+                if let Some(revision) = &source.revision {
+                    label.push_str(&format!("\\n{}", revision));
+                }
+                label.push_str(&format!("\\n{}", source.path.display()));
+            }
+            None => label.push_str("\\nnot found"),
+        }
+        let style = if module.is_none() {
+            ", style=dashed"
+        } else {
+            ""
+        };
+        output.push_str(&format!(
+            "    {} [label={}{}];\n",
+            quote(name),
+            quote(&label),
+            style
+        ));
+
+        let Some(module) = module else {
+            return;
+        };
+
+        if let Some(source) = source {
+            for submodule in &source.includes {
+                if visited.insert(submodule.clone()) {
+                    let mut label = submodule.clone();
+                    if let Some(sub_source) = self.source(submodule) {
+                        if let Some(revision) = &sub_source.revision {
+                            label.push_str(&format!("\\n{}", revision));
+                        }
+                        label.push_str(&format!("\\n{}", sub_source.path.display()));
+                    }
+                    output.push_str(&format!(
+                        "    {} [label={}, shape=note];\n",
+                        quote(submodule),
+                        quote(&label)
+                    ));
+                }
+                output.push_str(&format!(
+                    "    {} -> {} [label=\"include\", style=dotted];\n",
+                    quote(name),
+                    quote(submodule)
+                ));
+            }
+        }
+
+        for import in &module.imports {
+            output.push_str(&format!(
+                "    {} -> {} [label={}];\n",
+                quote(name),
+                quote(&import.module),
+                quote(&format!("import {}", import.prefix))
+            ));
+            let imported = self.parser.get_loaded_module(&import.module);
+            self.dot_module(&import.module, imported, visited, output);
+        }
+    }
+
+    fn source(&self, name: &str) -> Option<&ModuleSource> {
+        self.parser.module_source(name)
+    }
+}
+
+/// `name@revision`, or just the name when the revision is unknown.
+fn name_with_revision(name: &str, source: Option<&ModuleSource>) -> String {
+    match source.and_then(|s| s.revision.as_deref()) {
+        Some(revision) => format!("{}@{}", name, revision),
+        None => name.to_string(),
+    }
+}
+
+/// ` (path, search path dir)` describing where a module was loaded from.
+fn location(source: Option<&ModuleSource>) -> String {
+    match source {
+        Some(source) => match &source.search_path {
+            Some(search_path) => format!(
+                " ({}, search path {})",
+                source.path.display(),
+                search_path.display()
+            ),
+            None => format!(" ({})", source.path.display()),
+        },
+        None => String::new(),
+    }
+}
+
+/// Note about the revision an import asked for, when it was not the one loaded.
+fn requested_revision(import: &Import, source: Option<&ModuleSource>) -> String {
+    match &import.revision {
+        Some(requested) if source.and_then(|s| s.revision.as_ref()) != Some(requested) => {
+            format!(" [requested revision {}]", requested)
+        }
+        _ => String::new(),
+    }
+}
+
+/// Quote a string as a DOT identifier.
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('"', "\\\""))
+}
@@ -0,0 +1,91 @@
+//! The `rustconf fmt` command.
+
+use std::fs;
+use std::io::{self, Read, Write};
+use std::process::ExitCode;
+
+use rustconf::parser::YangFormatter;
+
+const USAGE: &str = "\
+Usage: rustconf fmt [--check] [--indent <n>] [<file>...]
+
+Formats YANG files in place. With no files, reads standard input and writes
+the formatted text to standard output.
+
+Options:
+  --check         Do not write files; exit with status 1 if any file is not formatted
+  --indent <n>    Spaces per indentation level (default: 2)";
+
+/// Run the `fmt` command with the arguments following the command name.
+pub fn run(args: &[String]) -> Result<ExitCode, String> {
+    let mut check = false;
+    let mut formatter = YangFormatter::new();
+    let mut files = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--check" => check = true,
+            "--indent" => {
+                let value = args.next().ok_or("--indent requires a value")?;
+                let indent = value
+                    .parse()
+                    .map_err(|_| format!("invalid indent '{}'", value))?;
+                formatter = formatter.with_indent(indent);
+            }
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(ExitCode::SUCCESS);
+            }
+            flag if flag.starts_with("--") => {
+                return Err(format!("unknown option '{}'\n\n{}", flag, USAGE));
+            }
+            file => files.push(file.to_string()),
+        }
+    }
+
+    if files.is_empty() {
+        let mut source = String::new();
+        io::stdin()
+            .read_to_string(&mut source)
+            .map_err(|e| format!("failed to read standard input: {}", e))?;
+        let formatted = formatter
+            .format(&source)
+            .map_err(|e| format!("<stdin>: {}", e))?;
+        if check {
+            return Ok(exit_status(formatted == source));
+        }
+        io::stdout()
+            .write_all(formatted.as_bytes())
+            .map_err(|e| format!("failed to write standard output: {}", e))?;
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let mut all_formatted = true;
+    for file in &files {
+        let source =
+            fs::read_to_string(file).map_err(|e| format!("failed to read {}: {}", file, e))?;
+        let formatted = formatter
+            .format(&source)
+            .map_err(|e| format!("{}: {}", file, e))?;
+        if formatted == source {
+            continue;
+        }
+        if check {
+            println!("{} is not formatted", file);
+            all_formatted = false;
+        } else {
+            fs::write(file, formatted).map_err(|e| format!("failed to write {}: {}", file, e))?;
+        }
+    }
+
+    Ok(exit_status(all_formatted))
+}
+
+fn exit_status(success: bool) -> ExitCode {
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,46 @@
+//! Command-line interface for rustconf.
+//!
+//! The build-time API in the `rustconf` library is the primary entry point;
+//! this binary bundles tooling for working with YANG model repositories.
+
+mod api_diff;
+mod deps;
+mod fmt;
+mod simulate;
+
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: rustconf <command> [options]
+
+Commands:
+  api-diff    Classify the changes between two API manifests as breaking or additive
+  deps        Print the import and include graph of a YANG module
+  fmt         Format YANG files following RFC 8407 style guidelines
+  simulate    Serve a simulated RESTCONF device for YANG modules
+
+Run 'rustconf <command> --help' for command-specific options.";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        Some("api-diff") => api_diff::run(&args[1..]),
+        Some("deps") => deps::run(&args[1..]),
+        Some("fmt") => fmt::run(&args[1..]),
+        Some("simulate") => simulate::run(&args[1..]),
+        Some("-h") | Some("--help") | None => {
+            println!("{}", USAGE);
+            Ok(ExitCode::SUCCESS)
+        }
+        Some(other) => Err(format!("unknown command '{}'\n\n{}", other, USAGE)),
+    };
+
+    match result {
+        Ok(code) => code,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            ExitCode::from(2)
+        }
+    }
+}
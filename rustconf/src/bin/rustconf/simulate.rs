@@ -0,0 +1,95 @@
+//! The `rustconf simulate` command.
+
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use rustconf::simulator::Simulator;
+use rustconf::YangParser;
+
+const USAGE: &str = "\
+Usage: rustconf simulate --yang <file> [--yang <file>...] [options]
+
+Starts a RESTCONF server for the given YANG modules, populated with example
+data, so client code can be tested without hardware.
+
+Options:
+  --yang <file>          YANG module to serve (repeatable)
+  --search-path <dir>    Directory used to resolve imports and includes (repeatable)
+  --host <addr>          Address to listen on (default: 127.0.0.1)
+  --port <port>          Port to listen on (default: 8080)";
+
+/// Run the `simulate` command with the arguments following the command name.
+pub fn run(args: &[String]) -> Result<ExitCode, String> {
+    let mut yang_files = Vec::new();
+    let mut search_paths = Vec::new();
+    let mut host = "127.0.0.1".to_string();
+    let mut port: u16 = 8080;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--yang" => {
+                let value = args.next().ok_or("--yang requires a value")?;
+                yang_files.push(PathBuf::from(value));
+            }
+            "--search-path" => {
+                let value = args.next().ok_or("--search-path requires a value")?;
+                search_paths.push(PathBuf::from(value));
+            }
+            "--host" => {
+                host = args.next().ok_or("--host requires a value")?.clone();
+            }
+            "--port" => {
+                let value = args.next().ok_or("--port requires a value")?;
+                port = value
+                    .parse()
+                    .map_err(|_| format!("invalid port '{}'", value))?;
+            }
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(ExitCode::SUCCESS);
+            }
+            other => return Err(format!("unknown argument '{}'\n\n{}", other, USAGE)),
+        }
+    }
+
+    if yang_files.is_empty() {
+        return Err(format!("at least one --yang file is required\n\n{}", USAGE));
+    }
+
+    let mut parser = YangParser::new();
+    for search_path in search_paths {
+        parser.add_search_path(search_path);
+    }
+
+    let mut modules = Vec::new();
+    for yang_file in &yang_files {
+        let mut module = parser
+            .parse_file(yang_file)
+            .map_err(|e| format!("{}: {}", yang_file.display(), e))?;
+        parser
+            .expand_module(&mut module)
+            .map_err(|e| format!("{}: {}", yang_file.display(), e))?;
+        modules.push(module);
+    }
+
+    let listener = TcpListener::bind((host.as_str(), port))
+        .map_err(|e| format!("failed to listen on {}:{}: {}", host, port, e))?;
+    let address = listener
+        .local_addr()
+        .map_err(|e| format!("failed to read listen address: {}", e))?;
+
+    let names: Vec<&str> = modules.iter().map(|m| m.name.as_str()).collect();
+    println!(
+        "Simulating {} at http://{}/restconf (Ctrl-C to stop)",
+        names.join(", "),
+        address
+    );
+
+    Simulator::new(&modules)
+        .serve(listener)
+        .map_err(|e| format!("server error: {}", e))?;
+
+    Ok(ExitCode::SUCCESS)
+}
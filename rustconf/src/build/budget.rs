@@ -0,0 +1,117 @@
+//! Size thresholds for generated code.
+//!
+//! Large models can generate files that take minutes to compile long before
+//! anyone looks at them. [`RustconfBuilder::write`](super::RustconfBuilder::write)
+//! compares the generated files against a [`CodeSizeBudget`] and reports those
+//! above it as cargo warnings, with ways to shrink them.
+
+use crate::generator::GeneratedFile;
+
+/// Sizes of generated code above which the build warns.
+///
+/// Set with [`RustconfBuilder::code_size_budget`](super::RustconfBuilder::code_size_budget).
+/// A threshold of `None` is not checked.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf::build::CodeSizeBudget;
+///
+/// let budget = CodeSizeBudget {
+///     max_types: Some(500),
+///     ..CodeSizeBudget::default()
+/// };
+/// assert_eq!(budget.max_file_lines, Some(50_000));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CodeSizeBudget {
+    /// Lines of a single generated file (default: 50 000).
+    pub max_file_lines: Option<usize>,
+    /// Bytes of a single generated file (default: 2 MiB).
+    pub max_file_bytes: Option<usize>,
+    /// Structs and enums across all generated files (default: 5 000).
+    pub max_types: Option<usize>,
+}
+
+impl CodeSizeBudget {
+    /// A budget that never warns.
+    pub fn unlimited() -> Self {
+        Self {
+            max_file_lines: None,
+            max_file_bytes: None,
+            max_types: None,
+        }
+    }
+
+    /// Describe the thresholds `files` exceed, suggesting ways to shrink
+    /// them. Splitting the output is only suggested when `modular_output` is
+    /// off.
+    pub(crate) fn check(&self, files: &[GeneratedFile], modular_output: bool) -> Vec<String> {
+        let shrink = "leave out unused subtrees with enabled_features() or by removing them between load_modules() and expand()";
+        let split_or_shrink = if modular_output {
+            shrink.to_string()
+        } else {
+            format!("split it with modular_output(true), or {}", shrink)
+        };
+
+        let mut warnings = Vec::new();
+        for file in files {
+            let lines = file.content.lines().count();
+            if let Some(max) = self.max_file_lines.filter(|max| lines > *max) {
+                warnings.push(format!(
+                    "{} has {} lines, over the budget of {}; {}",
+                    file.path.display(),
+                    lines,
+                    max,
+                    split_or_shrink
+                ));
+            }
+            let bytes = file.content.len();
+            if let Some(max) = self.max_file_bytes.filter(|max| bytes > *max) {
+                warnings.push(format!(
+                    "{} has {} bytes, over the budget of {}; {}",
+                    file.path.display(),
+                    bytes,
+                    max,
+                    split_or_shrink
+                ));
+            }
+        }
+
+        let types: usize = files.iter().map(|file| count_types(&file.content)).sum();
+        if let Some(max) = self.max_types.filter(|max| types > *max) {
+            warnings.push(format!(
+                "generated {} types, over the budget of {}; {}",
+                types, max, shrink
+            ));
+        }
+        warnings
+    }
+}
+
+impl Default for CodeSizeBudget {
+    fn default() -> Self {
+        Self {
+            max_file_lines: Some(50_000),
+            max_file_bytes: Some(2 * 1024 * 1024),
+            max_types: Some(5_000),
+        }
+    }
+}
+
+/// Count the struct and enum definitions in generated `code`.
+fn count_types(code: &str) -> usize {
+    code.lines()
+        .map(|line| {
+            let line = line.trim_start();
+            match line.strip_prefix("pub") {
+                Some(rest) if rest.starts_with('(') => {
+                    rest.split_once(") ").map_or("", |(_, item)| item)
+                }
+                Some(rest) => rest.trim_start(),
+                None => line,
+            }
+        })
+        .filter(|item| item.starts_with("struct ") || item.starts_with("enum "))
+        .count()
+}
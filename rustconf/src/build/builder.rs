@@ -1,28 +1,78 @@
 //! Builder API for rustconf build integration.
 
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
 
+use super::budget::CodeSizeBudget;
+use super::error::BuildErrorWithContext;
+use super::pipeline::{ExpandedModules, GeneratedModules, LoadedModules};
 use super::BuildError;
-use crate::generator::GeneratorConfig;
+use crate::generator::naming::{escape_keyword, to_field_name};
+use crate::generator::{
+    DateAndTimeRepresentation, Decimal64Representation, GeneratorConfig, Int64Encoding,
+    ItemVisibility, NamespaceQualifier, Target,
+};
+
+/// First line of files written by [`RustconfBuilder::generate_into_src`].
+///
+/// Marks the file as generated for tools such as code review and linters, and
+/// tells rustconf the file may be overwritten.
+pub const GENERATED_MARKER: &str =
+    "// @generated by rustconf. Do not edit; rerun the build to update.";
+
+/// Where generated files are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputTarget {
+    /// The build script's `OUT_DIR`, as no location was set.
+    OutDir,
+    /// A directory set with `output_dir`.
+    Dir,
+    /// A directory of committed sources set with `generate_into_src`.
+    Src,
+    /// Both `output_dir` and `generate_into_src` were called.
+    Conflict,
+}
 
 /// Builder for configuring and running rustconf code generation.
 pub struct RustconfBuilder {
     yang_files: Vec<PathBuf>,
+    openapi_files: Vec<PathBuf>,
     search_paths: Vec<PathBuf>,
     output_dir: PathBuf,
+    output_target: OutputTarget,
+    strict_augments: bool,
+    keep_comments: bool,
+    api_manifest: Option<PathBuf>,
+    code_size_budget: CodeSizeBudget,
     config: GeneratorConfig,
 }
 
 impl RustconfBuilder {
     /// Create a new builder with default settings.
+    ///
+    /// Code is generated into the build script's `OUT_DIR` unless
+    /// [`output_dir`](Self::output_dir) or
+    /// [`generate_into_src`](Self::generate_into_src) is called.
     pub fn new() -> Self {
+        let output_dir = std::env::var_os("OUT_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_default();
+        let config = GeneratorConfig {
+            output_dir: output_dir.clone(),
+            ..GeneratorConfig::default()
+        };
         Self {
             yang_files: Vec::new(),
+            openapi_files: Vec::new(),
             search_paths: Vec::new(),
-            output_dir: PathBuf::from(
-                std::env::var("OUT_DIR").unwrap_or_else(|_| "generated".to_string()),
-            ),
-            config: GeneratorConfig::default(),
+            output_dir,
+            output_target: OutputTarget::OutDir,
+            strict_augments: true,
+            keep_comments: false,
+            api_manifest: None,
+            code_size_budget: CodeSizeBudget::default(),
+            config,
         }
     }
 
@@ -32,16 +82,174 @@ impl RustconfBuilder {
         self
     }
 
+    /// Add an OpenAPI or JSON Schema document (JSON) to process.
+    ///
+    /// Its component schemas are imported as a YANG module named after the
+    /// file, as described in [`crate::parser::openapi`], for endpoints that
+    /// are only documented in OpenAPI. Schemas and properties that cannot be
+    /// imported are reported as cargo warnings.
+    ///
+    /// Generate the imported types in a builder of their own, with
+    /// [`Target::Types`] and a separate [`module_name`](Self::module_name):
+    ///
+    /// ```no_run
+    /// use rustconf::generator::Target;
+    /// use rustconf::RustconfBuilder;
+    ///
+    /// RustconfBuilder::new()
+    ///     .openapi_file("openapi/firmware.json")
+    ///     .targets(&[Target::Types])
+    ///     .module_name("firmware")
+    ///     .generate()?;
+    /// # Ok::<(), rustconf::BuildError>(())
+    /// ```
+    pub fn openapi_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.openapi_files.push(path.into());
+        self
+    }
+
     /// Add a search path for resolving YANG imports.
+    ///
+    /// The path may be a directory, a `.tar`, `.tar.gz` or `.tgz` archive of
+    /// YANG files as shipped by vendors, or a glob pattern such as
+    /// `"models/*/yang"`. Archives are read without extracting them, and files
+    /// may be named `name.yang` or `name@revision.yang`.
     pub fn search_path(mut self, path: impl Into<PathBuf>) -> Self {
         self.search_paths.push(path.into());
         self
     }
 
+    /// Fail the build when an augment target cannot be resolved (default).
+    ///
+    /// When disabled, augments whose target is not found in the loaded modules
+    /// are skipped with a cargo warning, for models whose augments reach into
+    /// modules that are not on the search path.
+    pub fn strict_augments(mut self, strict: bool) -> Self {
+        self.strict_augments = strict;
+        self
+    }
+
+    /// Add the comments right before YANG statements to the documentation of
+    /// the generated items (default: false).
+    ///
+    /// Models often explain leaves in `//` comments rather than in
+    /// `description` statements. See [`YangParser::keep_comments`] for which
+    /// comments are kept.
+    ///
+    /// [`YangParser::keep_comments`]: crate::parser::YangParser::keep_comments
+    pub fn keep_comments(mut self, keep: bool) -> Self {
+        self.keep_comments = keep;
+        self
+    }
+
     /// Set the output directory for generated code.
+    ///
+    /// Relative paths are resolved against the crate being built
+    /// (`CARGO_MANIFEST_DIR`), not the working directory. Defaults to
+    /// `OUT_DIR`; for code that is committed to the crate's sources, use
+    /// [`generate_into_src`](Self::generate_into_src) instead.
     pub fn output_dir(mut self, path: impl Into<PathBuf>) -> Self {
-        self.output_dir = path.into();
+        self.set_output_dir(path.into());
+        self.output_target = match self.output_target {
+            OutputTarget::Src | OutputTarget::Conflict => OutputTarget::Conflict,
+            OutputTarget::OutDir | OutputTarget::Dir => OutputTarget::Dir,
+        };
+        self
+    }
+
+    /// Generate code into a directory of the crate's sources, such as
+    /// `"src/generated"`, to be committed and reviewed like hand-written code.
+    ///
+    /// The path is resolved against the crate being built. Each file starts
+    /// with [`GENERATED_MARKER`], is formatted with rustfmt when it is
+    /// available, and carries no generation timestamp, so that regenerating
+    /// an unchanged model leaves the files untouched.
+    ///
+    /// Generation fails, rather than mixing committed and build output, if
+    /// the directory is inside `OUT_DIR`, if [`output_dir`](Self::output_dir)
+    /// is also called, or if it would overwrite a file without the marker.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// rustconf::RustconfBuilder::new()
+    ///     .yang_file("specs/example.yang")
+    ///     .generate_into_src("src/generated")
+    ///     .modular_output(true)
+    ///     .generate()
+    ///     .expect("Failed to generate RESTCONF bindings");
+    /// ```
+    pub fn generate_into_src(mut self, path: impl Into<PathBuf>) -> Self {
+        self.set_output_dir(path.into());
+        self.output_target = match self.output_target {
+            OutputTarget::Dir | OutputTarget::Conflict => OutputTarget::Conflict,
+            OutputTarget::OutDir | OutputTarget::Src => OutputTarget::Src,
+        };
+        self
+    }
+
+    fn set_output_dir(&mut self, path: PathBuf) {
+        self.output_dir = match std::env::var_os("CARGO_MANIFEST_DIR") {
+            Some(manifest_dir) if path.is_relative() => PathBuf::from(manifest_dir).join(path),
+            _ => path,
+        };
         self.config.output_dir = self.output_dir.clone();
+    }
+
+    /// Write the public API of the generated code to a JSON manifest at
+    /// `path`, resolved against the crate being built like
+    /// [`output_dir`](Self::output_dir).
+    ///
+    /// Commit the manifest with the crate. After a model update, compare the
+    /// committed and regenerated manifests with `rustconf api-diff` (or
+    /// [`ApiManifest::diff`](crate::generator::api::ApiManifest::diff)) to
+    /// see which changes are breaking and pick the version bump. The file is
+    /// only rewritten when the API changes.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// rustconf::RustconfBuilder::new()
+    ///     .yang_file("specs/example.yang")
+    ///     .generate_into_src("src/generated")
+    ///     .api_manifest("api-manifest.json")
+    ///     .generate()
+    ///     .expect("Failed to generate RESTCONF bindings");
+    /// ```
+    pub fn api_manifest(mut self, path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        self.api_manifest = Some(match std::env::var_os("CARGO_MANIFEST_DIR") {
+            Some(manifest_dir) if path.is_relative() => PathBuf::from(manifest_dir).join(path),
+            _ => path,
+        });
+        self
+    }
+
+    /// Warn when generated files grow past `budget` (default:
+    /// [`CodeSizeBudget::default`]).
+    ///
+    /// Files or types over a threshold are reported as cargo warnings when
+    /// they are written, suggesting [`modular_output`](Self::modular_output)
+    /// or leaving out unused subtrees, so an oversized model shows up before
+    /// compile times do. Pass [`CodeSizeBudget::unlimited`] to turn the
+    /// warnings off.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rustconf::build::CodeSizeBudget;
+    ///
+    /// rustconf::RustconfBuilder::new()
+    ///     .yang_file("specs/example.yang")
+    ///     .code_size_budget(CodeSizeBudget {
+    ///         max_file_lines: Some(20_000),
+    ///         ..CodeSizeBudget::default()
+    ///     })
+    ///     .generate()
+    ///     .expect("Failed to generate RESTCONF bindings");
+    /// ```
+    pub fn code_size_budget(mut self, budget: CodeSizeBudget) -> Self {
+        self.code_size_budget = budget;
         self
     }
 
@@ -58,11 +266,34 @@ impl RustconfBuilder {
     }
 
     /// Set the generated module name.
+    ///
+    /// The name is used as is and must be a valid Rust identifier; see
+    /// [`module_name_from_yang`](Self::module_name_from_yang) for deriving it
+    /// from a YANG module name.
     pub fn module_name(mut self, name: impl Into<String>) -> Self {
         self.config.module_name = name.into();
         self
     }
 
+    /// Set the generated module name from the name of a YANG module,
+    /// converted to a Rust identifier.
+    ///
+    /// Hyphens and dots become underscores, a leading digit gets an underscore
+    /// in front and keywords get one appended, so `802-dot1q` generates
+    /// `_802_dot1q.rs`.
+    ///
+    /// ```rust,no_run
+    /// // Included with `include!(concat!(env!("OUT_DIR"), "/openconfig_bgp_policy.rs"))`
+    /// rustconf::RustconfBuilder::new()
+    ///     .yang_file("yang/openconfig-bgp-policy.yang")
+    ///     .module_name_from_yang("openconfig-bgp-policy")
+    ///     .generate()
+    ///     .expect("Failed to generate RESTCONF bindings");
+    /// ```
+    pub fn module_name_from_yang(self, yang_name: &str) -> Self {
+        self.module_name(to_field_name(yang_name))
+    }
+
     /// Enable or disable RESTful RPC generation.
     ///
     /// When enabled, generates functional HTTP client implementations for RPCs.
@@ -142,95 +373,523 @@ impl RustconfBuilder {
         self
     }
 
+    /// Enable or disable namespace qualifiers in JSON member names and data paths.
+    ///
+    /// Names are qualified with the module name on top-level members, as required
+    /// by RFC 7951. See [`RustconfBuilder::namespace_qualifier`] for the legacy mode.
+    pub fn enable_namespace_prefixes(mut self, enable: bool) -> Self {
+        self.config.enable_namespace_prefixes = enable;
+        self
+    }
+
+    /// Set how names are qualified when namespace prefixes are enabled.
+    ///
+    /// `NamespaceQualifier::Prefix` restores the legacy behavior of prefixing every
+    /// member with the module prefix, for devices that expect it.
+    pub fn namespace_qualifier(mut self, qualifier: NamespaceQualifier) -> Self {
+        self.config.namespace_qualifier = qualifier;
+        self
+    }
+
+    /// Send RPC input and expect RPC output as bare objects instead of the
+    /// `{"module:input": ...}` / `{"module:output": ...}` envelope.
+    ///
+    /// RFC 8040 requires the envelope; enable this only for devices that omit it.
+    pub fn bare_rpc_bodies(mut self, enable: bool) -> Self {
+        self.config.bare_rpc_bodies = enable;
+        self
+    }
+
+    /// Enable or disable struct-level `#[serde(rename_all = "kebab-case")]`.
+    ///
+    /// When enabled, per-field `rename` attributes are only emitted for fields whose
+    /// JSON name is not the kebab-case form of the Rust field name.
+    pub fn serde_rename_all(mut self, enable: bool) -> Self {
+        self.config.serde_rename_all = enable;
+        self
+    }
+
+    /// Derive `Default` only for structs without mandatory fields, and
+    /// generate `new` constructors for the others (default: false).
+    ///
+    /// A blanket `Default` would leave list keys and mandatory leaves at
+    /// made-up values such as `""` or `0` that the server then rejects or,
+    /// worse, accepts. `new` takes the mandatory fields as arguments, in
+    /// schema order, and leaves the optional ones unset.
+    pub fn generate_constructors(mut self, enable: bool) -> Self {
+        self.config.generate_constructors = enable;
+        self
+    }
+
+    /// Set the JSON encoding of `int64` and `uint64` values.
+    ///
+    /// RFC 7951 encodes them as strings; `Int64Encoding::Number`, the default,
+    /// keeps the previous number encoding. `Int64Encoding::Lenient` writes
+    /// strings and accepts either form.
+    pub fn int64_encoding(mut self, encoding: Int64Encoding) -> Self {
+        self.config.int64_encoding = encoding;
+        self
+    }
+
+    /// Set the Rust representation of `decimal64` values.
+    ///
+    /// `Decimal64Representation::ScaledInteger`, the default, generates a
+    /// `Copy` newtype over the value scaled to an `i64` for each number of
+    /// fraction digits. `Decimal64Representation::RustDecimal` uses
+    /// `rust_decimal::Decimal` and needs the `rust_decimal` feature of
+    /// `rustconf-runtime`. Either way values serialize as RFC 7951 strings.
+    pub fn decimal64_representation(mut self, representation: Decimal64Representation) -> Self {
+        self.config.decimal64_representation = representation;
+        self
+    }
+
+    /// Set the Rust representation of `date-and-time` values of
+    /// `ietf-yang-types`.
+    ///
+    /// `DateAndTimeRepresentation::String`, the default, keeps them as
+    /// strings checked against the typedef's pattern.
+    /// `DateAndTimeRepresentation::Chrono` uses
+    /// `chrono::DateTime<FixedOffset>` and needs the `chrono` feature of
+    /// `rustconf-runtime`. Values are written in RFC 3339 form with seconds,
+    /// fractional seconds only when non-zero and `Z` for UTC, and read with
+    /// or without fractional seconds. References that add a `length` or
+    /// `pattern` stay strings.
+    pub fn date_and_time_representation(
+        mut self,
+        representation: DateAndTimeRepresentation,
+    ) -> Self {
+        self.config.date_and_time_representation = representation;
+        self
+    }
+
+    /// Enable or disable pretty printers for generated structs.
+    ///
+    /// When enabled, generated structs implement `rustconf_runtime::Pretty`,
+    /// whose `pretty()` renders the tree with YANG names, units and defaults.
+    pub fn enable_pretty_printers(mut self, enable: bool) -> Self {
+        self.config.enable_pretty_printers = enable;
+        self
+    }
+
+    /// Generate a clap command-line interface over the RPCs (default: false).
+    ///
+    /// The generated `cli` module has a `command()` with one subcommand per
+    /// RPC, whose flags are the input leaves with their YANG descriptions as
+    /// help, and a `run()` that calls the selected RPC. The crate including the
+    /// generated code needs a `clap` 4 dependency. Requires
+    /// `enable_restful_rpcs`.
+    pub fn enable_cli(mut self, enable: bool) -> Self {
+        self.config.enable_cli = enable;
+        self
+    }
+
+    /// Take clients as `&dyn RestconfClientApi` in generated functions
+    /// (default: false).
+    ///
+    /// Generated functions and resource handles then accept any
+    /// `RestconfClient` without a transport type parameter, so applications
+    /// can keep a `DynRestconfClient` in their own types. Requires
+    /// `enable_restful_rpcs`.
+    pub fn enable_dyn_client(mut self, enable: bool) -> Self {
+        self.config.enable_dyn_client = enable;
+        self
+    }
+
+    /// Generate a NETCONF schema for the module (default: false).
+    ///
+    /// The generated `schema::netconf_schema()` describes namespaces, list
+    /// keys and value kinds for `rustconf_runtime::netconf::NetconfTransport`,
+    /// which sends the generated requests to devices that only speak NETCONF.
+    /// The crate including the generated code needs `rustconf-runtime` with
+    /// the `netconf` feature. The schema also serves
+    /// `rustconf_runtime::xml::XmlFallbackTransport`, which converts XML
+    /// responses from RESTCONF servers that ignore `Accept`.
+    pub fn enable_netconf(mut self, enable: bool) -> Self {
+        self.config.enable_netconf = enable;
+        self
+    }
+
+    /// Generate a payload schema for the module (default: false).
+    ///
+    /// The generated `schema::payload_schema()` describes value types, list
+    /// keys, mandatory leaves, ranges and lengths for
+    /// `rustconf_runtime::payload::ValidatingTransport`, which checks the
+    /// bodies of `PUT`, `POST` and `PATCH` requests before sending them and
+    /// fails with a report of every offending node, instead of leaving the
+    /// device to answer with a `400`.
+    pub fn enable_payload_schema(mut self, enable: bool) -> Self {
+        self.config.enable_payload_schema = enable;
+        self
+    }
+
+    /// Import shared helpers from `rustconf-runtime` (default: false).
+    ///
+    /// Every generated module defines its own `percent_encode` helper, and,
+    /// without [`enable_restful_rpcs`](Self::enable_restful_rpcs), its own
+    /// `RpcError`. When several YANG modules are generated into one crate,
+    /// this imports them from `rustconf-runtime` instead, so they are defined
+    /// once and errors of every module have the same type. The client and
+    /// transport types always come from `rustconf-runtime`.
+    pub fn shared_helpers(mut self, enable: bool) -> Self {
+        self.config.shared_helpers = enable;
+        self
+    }
+
+    /// Set the visibility of each category of generated items (default: all
+    /// `pub`).
+    ///
+    /// For code embedded in a larger crate, keep the types public and make
+    /// the client functions or the `consts` and `schema` helpers
+    /// `pub(crate)`, so only the types are part of the crate's API:
+    ///
+    /// ```no_run
+    /// use rustconf::generator::{ItemVisibility, Visibility};
+    ///
+    /// rustconf::RustconfBuilder::new()
+    ///     .yang_file("yang/device.yang")
+    ///     .visibility(ItemVisibility {
+    ///         helpers: Visibility::Crate,
+    ///         ..ItemVisibility::default()
+    ///     })
+    ///     .generate()?;
+    /// # Ok::<(), rustconf::BuildError>(())
+    /// ```
+    pub fn visibility(mut self, visibility: ItemVisibility) -> Self {
+        self.config.visibility = visibility;
+        self
+    }
+
+    /// Name the entry type of a YANG list explicitly.
+    ///
+    /// List entry types are named after the singularized list name, so
+    /// `interfaces` gets `Interface` and `policies` gets `Policy`, while names
+    /// that are not plural, such as `status` or `chassis`, are kept. Use this
+    /// for lists the built-in rules get wrong, e.g.
+    /// `.list_item_name("media", "Medium")`.
+    pub fn list_item_name(mut self, list: impl Into<String>, type_name: impl Into<String>) -> Self {
+        self.config.list_item_name(list, type_name);
+        self
+    }
+
+    /// Generate only the given parts of the bindings (default: all of them).
+    ///
+    /// For example, `&[Target::Notifications]` generates the notification
+    /// structs a consumer of an event stream needs, without client functions
+    /// or imports of the client and transport types.
+    pub fn targets(mut self, targets: &[Target]) -> Self {
+        self.config.targets(targets);
+        self
+    }
+
+    /// Generate only the nodes available with the given YANG features
+    /// (default: every feature is supported).
+    ///
+    /// Name features as `feature` or `module:feature`, e.g.
+    /// `.enabled_features(["ietf-interfaces:if-mib"])`. Nodes with an
+    /// `if-feature` condition that does not hold, such as `if-feature if-mib`
+    /// on a device without the IF-MIB, are left out of the generated types.
+    pub fn enabled_features<I, S>(mut self, features: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.config.enabled_features(features);
+        self
+    }
+
+    /// Insert code at the top of every generated file.
+    ///
+    /// Typically used for crate-specific `use` statements or `#![allow]` attributes.
+    /// To keep the code in a separate file, pass `include_str!("prelude.rs")`.
+    pub fn prelude(mut self, code: impl Into<String>) -> Self {
+        self.config.prelude(code);
+        self
+    }
+
+    /// Append code to the end of the root generated file.
+    ///
+    /// Typically used for extension `impl` blocks on generated types.
+    pub fn epilogue(mut self, code: impl Into<String>) -> Self {
+        self.config.epilogue(code);
+        self
+    }
+
     /// Generate Rust bindings from configured YANG files.
+    ///
+    /// Runs [`load_modules`](Self::load_modules), [`expand`](Self::expand),
+    /// [`generate_code`](Self::generate_code) and [`write`](Self::write),
+    /// reporting errors and skipped augments to cargo.
     pub fn generate(self) -> Result<(), BuildError> {
-        // Validate configuration
-        if let Err(e) = self.validate_configuration() {
+        let result = self.load_modules().and_then(|loaded| {
+            let expanded = self.expand(loaded)?;
+            for warning in &expanded.warnings {
+                println!("cargo:warning=rustconf: {}", warning);
+            }
+            let generated = self.generate_code(&expanded)?;
+            self.write(&generated)
+        });
+        result.map_err(|e| {
             e.report_to_cargo();
-            return Err(e);
-        }
+            e.into_inner()
+        })
+    }
 
-        // Create YANG parser
-        let mut parser = crate::parser::YangParser::new();
+    /// Check the configuration, then parse the YANG files and the modules
+    /// they import.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first configuration or parse error, with the file it
+    /// occurred in.
+    ///
+    /// # Examples
+    ///
+    /// Stripping a vendor subtree before generating code:
+    ///
+    /// ```no_run
+    /// use rustconf::parser::DataNode;
+    ///
+    /// let builder = rustconf::RustconfBuilder::new().yang_file("yang/device.yang");
+    /// let mut loaded = builder.load_modules()?;
+    /// if let Some(device) = loaded.module_mut("device") {
+    ///     device
+    ///         .data_nodes
+    ///         .retain(|node| !matches!(node, DataNode::Container(c) if c.name == "vendor-debug"));
+    /// }
+    /// let expanded = builder.expand(loaded)?;
+    /// let generated = builder.generate_code(&expanded)?;
+    /// builder.write(&generated)?;
+    /// # Ok::<(), rustconf::build::BuildErrorWithContext>(())
+    /// ```
+    pub fn load_modules(&self) -> Result<LoadedModules, BuildErrorWithContext> {
+        self.validate()?;
 
-        // Add search paths
+        let mut parser = crate::parser::YangParser::new();
+        parser.keep_comments(self.keep_comments);
         for search_path in &self.search_paths {
             parser.add_search_path(search_path.clone());
         }
 
-        // Parse all YANG files
         let mut modules = Vec::new();
         for yang_file in &self.yang_files {
-            match parser.parse_file(yang_file) {
-                Ok(module) => modules.push(module),
-                Err(e) => {
-                    let build_error = BuildError::from(e);
-                    let error_with_context = build_error.with_file_context(yang_file.clone());
-                    error_with_context.report_to_cargo();
-                    return Err(error_with_context.into_inner());
+            let module = parser
+                .parse_file(yang_file)
+                .map_err(|e| BuildError::from(e).with_file_context(yang_file.clone()))?;
+            modules.push(module);
+        }
+
+        let mut files = self.yang_files.clone();
+        let mut warnings = revision_report(&parser, &modules);
+        for openapi_file in &self.openapi_files {
+            let name = openapi_file
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().replace(['.', ' '], "-"))
+                .unwrap_or_default();
+            let import = crate::parser::openapi::OpenApiImporter::new(name)
+                .import_file(openapi_file)
+                .map_err(|e| BuildError::from(e).with_file_context(openapi_file.clone()))?;
+            modules.push(import.module);
+            files.push(openapi_file.clone());
+            warnings.extend(
+                import
+                    .warnings
+                    .into_iter()
+                    .map(|warning| format!("{}: {}", openapi_file.display(), warning)),
+            );
+        }
+
+        Ok(LoadedModules {
+            modules,
+            parser,
+            files,
+            warnings,
+        })
+    }
+
+    /// Resolve the augments of the loaded modules against every loaded
+    /// module, expand the groupings they use and inline the typedefs they
+    /// use from imported modules. Augments and deviations targeting nodes of
+    /// the loaded modules are then applied to them.
+    ///
+    /// With [`strict_augments(false)`](Self::strict_augments), augments whose
+    /// target does not resolve are removed and described in
+    /// [`ExpandedModules::warnings`]. Current definitions using deprecated
+    /// typedefs or groupings of their module are described there too.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first unresolved augment target when augments are strict,
+    /// the first definition using an obsolete typedef or grouping of its
+    /// module, and the first deviation that cannot be applied.
+    pub fn expand(&self, loaded: LoadedModules) -> Result<ExpandedModules, BuildErrorWithContext> {
+        let LoadedModules {
+            mut modules,
+            parser,
+            files,
+            mut warnings,
+        } = loaded;
+
+        for (index, module) in modules.iter_mut().enumerate() {
+            let with_context = |e: crate::parser::ParseError| {
+                let error = BuildError::from(e);
+                match files.get(index) {
+                    Some(file) => error.with_file_context(file.clone()),
+                    None => error.into(),
                 }
-            }
+            };
+            let skipped = parser
+                .check_augment_targets(module, self.strict_augments)
+                .map_err(with_context)?;
+            warnings.extend(skipped);
+            warnings.extend(parser.check_status(module).map_err(with_context)?);
+            parser.expand_groupings(module).map_err(with_context)?;
+            parser
+                .inline_imported_typedefs(module)
+                .map_err(with_context)?;
+        }
+        parser
+            .apply_augments(&mut modules)
+            .and_then(|()| parser.apply_deviations(&mut modules))
+            .map_err(|e| BuildErrorWithContext::from(BuildError::from(e)))?;
+
+        Ok(ExpandedModules {
+            modules,
+            warnings,
+            parser,
+        })
+    }
+
+    /// Generate the source files of the expanded modules, without writing
+    /// them.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error of the code generator.
+    pub fn generate_code(
+        &self,
+        expanded: &ExpandedModules,
+    ) -> Result<GeneratedModules, BuildErrorWithContext> {
+        let parser = &expanded.parser;
+
+        // Revisions of the generated modules and their imports, for the
+        // generated YANG library checks
+        let revisions = expanded
+            .modules
+            .iter()
+            .map(|module| &module.name)
+            .chain(parser.get_all_loaded_modules().keys())
+            .filter_map(|name| {
+                let revision = parser.module_source(name)?.revision.clone()?;
+                Some((name.clone(), revision))
+            })
+            .collect::<Vec<_>>();
+        let generator = crate::generator::CodeGenerator::new(self.config.clone())
+            .with_module_revisions(revisions);
+
+        let mut files = Vec::new();
+        for module in &expanded.modules {
+            let generated = generator.generate(module).map_err(BuildError::from)?;
+            files.extend(generated.files);
         }
 
-        // Create code generator
-        let generator = crate::generator::CodeGenerator::new(self.config);
-
-        // Generate code for each module
-        for module in &modules {
-            match generator.generate(module) {
-                Ok(generated) => {
-                    // Write generated files to output directory
-                    for file in &generated.files {
-                        // Ensure parent directory exists
-                        if let Some(parent) = file.path.parent() {
-                            if let Err(e) = std::fs::create_dir_all(parent) {
-                                let build_error = BuildError::from(e);
-                                build_error.report_to_cargo();
-                                return Err(build_error);
-                            }
-                        }
-
-                        // Write the file
-                        if let Err(e) = std::fs::write(&file.path, &file.content) {
-                            let build_error = BuildError::from(e);
-                            let error_with_context =
-                                build_error.with_file_context(file.path.clone());
-                            error_with_context.report_to_cargo();
-                            return Err(error_with_context.into_inner());
-                        }
+        // The configured files, and every loaded module (imports) or the
+        // archive it was read from
+        let mut inputs = self.yang_files.clone();
+        inputs.extend(self.openapi_files.iter().cloned());
+        let mut watched = std::collections::BTreeSet::new();
+        for module in parser.get_all_loaded_modules().values() {
+            let Some(source) = parser.module_source(&module.name) else {
+                continue;
+            };
+            let names = std::iter::once(&module.name).chain(&source.includes);
+            for file in names.filter_map(|name| parser.module_source(name)) {
+                match &file.search_path {
+                    Some(search_path) if crate::parser::is_archive(search_path) => {
+                        watched.insert(search_path.clone());
                     }
-                }
-                Err(e) => {
-                    let build_error = BuildError::from(e);
-                    build_error.report_to_cargo();
-                    return Err(build_error);
+                    Some(_) => {
+                        watched.insert(file.path.clone());
+                    }
+                    None => {}
                 }
             }
         }
+        inputs.extend(watched);
 
-        // Emit cargo:rerun-if-changed directives for all input files
-        for yang_file in &self.yang_files {
-            println!("cargo:rerun-if-changed={}", yang_file.to_string_lossy());
+        Ok(GeneratedModules { files, inputs })
+    }
+
+    /// Write the generated files, and the [API manifest](Self::api_manifest)
+    /// if one is configured, and emit `cargo:rerun-if-changed` for their
+    /// inputs. Files over the [code size budget](Self::code_size_budget) are
+    /// reported as cargo warnings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the file that could not be written, including
+    /// when [`generate_into_src`](Self::generate_into_src) would overwrite a
+    /// hand-written file.
+    pub fn write(&self, generated: &GeneratedModules) -> Result<(), BuildErrorWithContext> {
+        let into_src = self.output_target == OutputTarget::Src;
+        for file in &generated.files {
+            // Ensure parent directory exists
+            if let Some(parent) = file.path.parent() {
+                std::fs::create_dir_all(parent).map_err(BuildError::from)?;
+            }
+            write_generated_file(&file.path, &file.content, into_src)
+                .map_err(|e| e.with_file_context(file.path.clone()))?;
         }
 
-        // Also emit directives for all loaded modules (imports)
-        for module in parser.get_all_loaded_modules().values() {
-            // Try to find the file path for this module
-            for search_path in &self.search_paths {
-                let module_path = search_path.join(format!("{}.yang", module.name));
-                if module_path.exists() {
-                    println!("cargo:rerun-if-changed={}", module_path.to_string_lossy());
+        if let Some(path) = &self.api_manifest {
+            let manifest = generated.api_manifest().map_err(BuildError::from)?;
+            let json = manifest.to_json();
+            if std::fs::read_to_string(path).ok().as_deref() != Some(json.as_str()) {
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent).map_err(BuildError::from)?;
                 }
+                std::fs::write(path, json)
+                    .map_err(|e| BuildError::from(e).with_file_context(path.clone()))?;
             }
         }
 
+        let oversized = self
+            .code_size_budget
+            .check(&generated.files, self.config.modular_output);
+        for warning in oversized {
+            println!("cargo:warning=rustconf: {}", warning);
+        }
+        for input in &generated.inputs {
+            println!("cargo:rerun-if-changed={}", input.to_string_lossy());
+        }
         Ok(())
     }
 
-    /// Validate the builder configuration.
-    fn validate_configuration(&self) -> Result<(), BuildError> {
+    /// Check the configuration without parsing or generating anything.
+    ///
+    /// [`generate`](Self::generate) runs this first, so calling it is only
+    /// needed to report misconfigurations early, for example from a test.
+    /// It checks that YANG files and search paths exist, that the output
+    /// location is set, that the generator settings are consistent, and, in
+    /// a build script, that the crate depends on the crates the generated
+    /// code uses, such as `rustconf-runtime` for `enable_restful_rpcs`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BuildError::ConfigurationError` describing the first problem
+    /// found and how to fix it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let error = rustconf::RustconfBuilder::new()
+    ///     .output_dir("generated")
+    ///     .validate()
+    ///     .unwrap_err();
+    /// assert!(error.to_string().contains("yang_file()"));
+    /// ```
+    pub fn validate(&self) -> Result<(), BuildError> {
         // 1. Validate required fields: at least one YANG file must be specified
-        if self.yang_files.is_empty() {
+        if self.yang_files.is_empty() && self.openapi_files.is_empty() {
             return Err(BuildError::ConfigurationError {
                 message: "No YANG files specified. Use yang_file() to add at least one YANG file."
                     .to_string(),
@@ -259,17 +918,64 @@ impl RustconfBuilder {
             }
         }
 
+        for openapi_file in &self.openapi_files {
+            if !openapi_file.is_file() {
+                return Err(BuildError::ConfigurationError {
+                    message: format!("OpenAPI file does not exist: {}", openapi_file.display()),
+                });
+            }
+        }
+
         // 3. Check that all search paths exist and are accessible
         for search_path in &self.search_paths {
+            if crate::parser::is_pattern(search_path) {
+                match crate::parser::expand_search_path(search_path) {
+                    Ok(matches) if matches.is_empty() => {
+                        return Err(BuildError::ConfigurationError {
+                            message: format!(
+                                "Search path pattern matches nothing: {}",
+                                search_path.display()
+                            ),
+                        });
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        return Err(BuildError::ConfigurationError {
+                            message: format!(
+                                "Invalid search path pattern {}: {}",
+                                search_path.display(),
+                                e
+                            ),
+                        });
+                    }
+                }
+                continue;
+            }
+
             if !search_path.exists() {
                 return Err(BuildError::ConfigurationError {
                     message: format!("Search path does not exist: {}", search_path.display()),
                 });
             }
 
+            if crate::parser::is_archive(search_path) {
+                if !search_path.is_file() {
+                    return Err(BuildError::ConfigurationError {
+                        message: format!(
+                            "Search path archive is not a file: {}",
+                            search_path.display()
+                        ),
+                    });
+                }
+                continue;
+            }
+
             if !search_path.is_dir() {
                 return Err(BuildError::ConfigurationError {
-                    message: format!("Search path is not a directory: {}", search_path.display()),
+                    message: format!(
+                        "Search path is not a directory or archive: {}",
+                        search_path.display()
+                    ),
                 });
             }
 
@@ -281,7 +987,34 @@ impl RustconfBuilder {
             }
         }
 
-        // 4. Validate output directory parent exists or can be created
+        // 4. Validate the output location was set unambiguously
+        match self.output_target {
+            OutputTarget::Conflict => {
+                return Err(BuildError::ConfigurationError {
+                    message: "Both output_dir() and generate_into_src() were called. Use output_dir() for code generated at build time, or generate_into_src() for committed code, not both.".to_string(),
+                });
+            }
+            OutputTarget::OutDir if self.output_dir.as_os_str().is_empty() => {
+                return Err(BuildError::ConfigurationError {
+                    message: "No output directory: OUT_DIR is not set outside a build script. Use output_dir() or generate_into_src().".to_string(),
+                });
+            }
+            OutputTarget::Src => {
+                if let Some(out_dir) = std::env::var_os("OUT_DIR") {
+                    if self.output_dir.starts_with(&out_dir) {
+                        return Err(BuildError::ConfigurationError {
+                            message: format!(
+                                "generate_into_src() directory {} is inside OUT_DIR. Use output_dir() for code generated at build time.",
+                                self.output_dir.display()
+                            ),
+                        });
+                    }
+                }
+            }
+            OutputTarget::OutDir | OutputTarget::Dir => {}
+        }
+
+        // 5. Validate output directory parent exists or can be created
         // We allow the output directory itself to not exist (we'll create it)
         // But we need at least one ancestor that exists
         if !self.output_dir.as_os_str().is_empty() {
@@ -313,7 +1046,7 @@ impl RustconfBuilder {
             }
         }
 
-        // 5. Validate module name is a valid Rust identifier
+        // 6. Validate module name is a valid Rust identifier
         let module_name = &self.config.module_name;
         if module_name.is_empty() {
             return Err(BuildError::ConfigurationError {
@@ -321,6 +1054,13 @@ impl RustconfBuilder {
             });
         }
 
+        // Errors suggest the name `module_name_from_yang` would produce
+        let suggestion = format!(
+            "use '{}' or module_name_from_yang(\"{}\")",
+            to_field_name(module_name),
+            module_name
+        );
+
         // Check if module name starts with a digit
         if module_name
             .chars()
@@ -328,7 +1068,10 @@ impl RustconfBuilder {
             .is_some_and(|c| c.is_ascii_digit())
         {
             return Err(BuildError::ConfigurationError {
-                message: format!("Module name '{}' cannot start with a digit", module_name),
+                message: format!(
+                    "Module name '{}' cannot start with a digit; {}",
+                    module_name, suggestion
+                ),
             });
         }
 
@@ -336,19 +1079,206 @@ impl RustconfBuilder {
         if !module_name.chars().all(|c| c.is_alphanumeric() || c == '_') {
             return Err(BuildError::ConfigurationError {
                 message: format!(
-                    "Module name '{}' contains invalid characters. Only alphanumeric characters and underscores are allowed",
-                    module_name
+                    "Module name '{}' contains invalid characters. Only alphanumeric characters and underscores are allowed; {}",
+                    module_name, suggestion
                 ),
             });
         }
 
-        // 6. Validate generator config (includes server generation settings)
+        // Keywords and `_` cannot name a module
+        if module_name == "_" || escape_keyword(module_name) != *module_name {
+            return Err(BuildError::ConfigurationError {
+                message: format!(
+                    "Module name '{}' is a Rust keyword; {}",
+                    module_name, suggestion
+                ),
+            });
+        }
+
+        // 7. Validate generator config (includes server generation settings)
         if let Err(msg) = self.config.validate() {
             return Err(BuildError::ConfigurationError { message: msg });
         }
 
+        // 8. In a build script, check the crate depends on what the generated
+        // code uses; otherwise the error only shows when compiling it
+        if std::env::var_os("TARGET").is_some() {
+            if let Some(manifest_dir) = std::env::var_os("CARGO_MANIFEST_DIR") {
+                let manifest_path = Path::new(&manifest_dir).join("Cargo.toml");
+                if let Ok(manifest) = std::fs::read_to_string(&manifest_path) {
+                    self.check_dependencies(&manifest, &manifest_path)?;
+                }
+            }
+        }
+
         Ok(())
     }
+
+    /// Crates the generated code uses, with the setting that requires each.
+    fn required_dependencies(&self) -> Vec<(&'static str, &'static str)> {
+        let config = &self.config;
+        let mut required = Vec::new();
+        let runtime_setting = if config.enable_restful_rpcs {
+            Some("enable_restful_rpcs(true)")
+        } else if config.enable_server_generation {
+            Some("enable_server_generation(true)")
+        } else if config.enable_netconf {
+            Some("enable_netconf(true)")
+        } else if config.enable_payload_schema {
+            Some("enable_payload_schema(true)")
+        } else if config.enable_pretty_printers {
+            Some("enable_pretty_printers(true)")
+        } else if config.shared_helpers {
+            Some("shared_helpers(true)")
+        } else if config.int64_encoding.serde_module().is_some() {
+            Some("int64_encoding()")
+        } else if config.date_and_time_representation.serde_module().is_some() {
+            Some("date_and_time_representation()")
+        } else {
+            None
+        };
+        if let Some(setting) = runtime_setting {
+            required.push(("rustconf-runtime", setting));
+        }
+        if config.enable_cli {
+            required.push(("clap", "enable_cli(true)"));
+        }
+        if config.decimal64_representation == Decimal64Representation::RustDecimal {
+            required.push(("rust_decimal", "decimal64_representation()"));
+        }
+        if config.date_and_time_representation == DateAndTimeRepresentation::Chrono {
+            required.push(("chrono", "date_and_time_representation()"));
+        }
+        required
+    }
+
+    /// Check that `manifest` declares the dependencies the generated code
+    /// needs.
+    fn check_dependencies(&self, manifest: &str, manifest_path: &Path) -> Result<(), BuildError> {
+        for (dependency, setting) in self.required_dependencies() {
+            if !declares_dependency(manifest, dependency) {
+                return Err(BuildError::ConfigurationError {
+                    message: format!(
+                        "{} generates code that uses the `{}` crate, but {} does not depend on it. Add {} to [dependencies].",
+                        setting,
+                        dependency.replace('-', "_"),
+                        manifest_path.display(),
+                        dependency
+                    ),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Whether a Cargo manifest declares `dependency` in a dependency table,
+/// directly, as a `[dependencies.name]` table or renamed with `package`.
+fn declares_dependency(manifest: &str, dependency: &str) -> bool {
+    let mut in_dependencies = false;
+    for line in manifest.lines() {
+        let line = line.trim();
+        if let Some(header) = line.strip_prefix('[') {
+            let header = header.trim_end_matches(']').trim();
+            let table = header.rsplit('.').next().unwrap_or(header);
+            if header.ends_with(&format!("dependencies.{}", dependency)) {
+                return true;
+            }
+            in_dependencies = table.ends_with("dependencies");
+            continue;
+        }
+        if !in_dependencies {
+            continue;
+        }
+        let Some((name, value)) = line.split_once('=') else {
+            continue;
+        };
+        // `name.workspace = true` declares `name` with a dotted key
+        let name = name.trim();
+        let name = name.split('.').next().unwrap_or(name).trim_matches('"');
+        let renamed = value.contains(&format!("package = \"{}\"", dependency));
+        if name == dependency || renamed {
+            return true;
+        }
+    }
+    false
+}
+
+/// Write a generated file, refusing to mix committed and build output.
+///
+/// Committed files (`into_src`) get the [`GENERATED_MARKER`], are formatted
+/// with rustfmt, and are only rewritten when their content changes. Files
+/// without the marker are never overwritten in that mode, and files with it
+/// are never overwritten by output meant for `OUT_DIR`.
+fn write_generated_file(path: &Path, content: &str, into_src: bool) -> Result<(), BuildError> {
+    let existing = std::fs::read_to_string(path).ok();
+    let committed = existing
+        .as_deref()
+        .is_some_and(|existing| existing.starts_with(GENERATED_MARKER));
+
+    if !into_src {
+        if committed {
+            return Err(BuildError::ConfigurationError {
+                message: format!(
+                    "{} was committed by generate_into_src(). Keep using generate_into_src(), or delete the file to generate into this directory with output_dir().",
+                    path.display()
+                ),
+            });
+        }
+        std::fs::write(path, content)?;
+        return Ok(());
+    }
+
+    if existing.is_some() && !committed {
+        return Err(BuildError::ConfigurationError {
+            message: format!(
+                "Refusing to overwrite {}: it does not start with the @generated marker, so it may be hand-written. Move it, or choose another directory for generate_into_src().",
+                path.display()
+            ),
+        });
+    }
+
+    // A timestamp would change the committed file on every build
+    let mut source = String::new();
+    for line in content.lines() {
+        if !line.starts_with("// Generated at: ") {
+            source.push_str(line);
+            source.push('\n');
+        }
+    }
+    let source = format!("{}\n{}", GENERATED_MARKER, rustfmt(&source));
+
+    if existing.as_deref() != Some(source.as_str()) {
+        std::fs::write(path, source)?;
+    }
+    Ok(())
+}
+
+/// Format source with rustfmt, leaving it as is when rustfmt is unavailable.
+fn rustfmt(source: &str) -> String {
+    let rustfmt = std::env::var_os("RUSTFMT").unwrap_or_else(|| "rustfmt".into());
+    let child = Command::new(rustfmt)
+        .args(["--edition", "2021", "--emit", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn();
+    let Ok(mut child) = child else {
+        println!("cargo:warning=rustconf: rustfmt not found; generated code is not formatted");
+        return source.to_string();
+    };
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = stdin.write_all(source.as_bytes());
+    }
+    match child.wait_with_output() {
+        Ok(output) if output.status.success() => {
+            String::from_utf8(output.stdout).unwrap_or_else(|_| source.to_string())
+        }
+        _ => {
+            println!("cargo:warning=rustconf: rustfmt failed; generated code is not formatted");
+            source.to_string()
+        }
+    }
 }
 
 impl Default for RustconfBuilder {
@@ -357,5 +1287,66 @@ impl Default for RustconfBuilder {
     }
 }
 
+/// Notes on the revisions loaded for imports and includes: which one was
+/// picked when several were found, and imports of a revision that was not
+/// found.
+fn revision_report(
+    parser: &crate::parser::YangParser,
+    modules: &[crate::parser::YangModule],
+) -> Vec<String> {
+    let loaded = parser.get_all_loaded_modules();
+    let mut importers: Vec<&crate::parser::YangModule> =
+        modules.iter().chain(loaded.values()).collect();
+    importers.sort_by(|a, b| a.name.cmp(&b.name));
+    importers.dedup_by(|a, b| a.name == b.name);
+
+    let mut names: Vec<&String> = loaded.keys().collect();
+    for module in &importers {
+        if let Some(source) = parser.module_source(&module.name) {
+            names.extend(&source.includes);
+        }
+    }
+    names.sort();
+    names.dedup();
+
+    let mut notes = Vec::new();
+    for name in names {
+        let Some(source) = parser.module_source(name) else {
+            continue;
+        };
+        if source.available_revisions.len() > 1 {
+            notes.push(format!(
+                "{}: loaded revision {} from {} (found {})",
+                name,
+                source.revision.as_deref().unwrap_or("without a date"),
+                source.path.display(),
+                source.available_revisions.join(", ")
+            ));
+        }
+    }
+    for module in importers {
+        for import in &module.imports {
+            let (Some(requested), Some(source)) =
+                (&import.revision, parser.module_source(&import.module))
+            else {
+                continue;
+            };
+            if source.revision.as_ref() != Some(requested) {
+                notes.push(format!(
+                    "{}: imports revision {} of {}, which was not found; loaded {} instead",
+                    module.name,
+                    requested,
+                    import.module,
+                    source
+                        .revision
+                        .as_deref()
+                        .unwrap_or("a file without a date")
+                ));
+            }
+        }
+    }
+    notes
+}
+
 #[cfg(test)]
 mod tests;
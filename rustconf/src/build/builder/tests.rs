@@ -1,8 +1,10 @@
 //! Tests for RustconfBuilder.
 
 use super::*;
+use crate::generator::api::ApiManifest;
 use std::error::Error;
 use std::fs;
+use std::path::Path;
 use tempfile::TempDir;
 
 #[test]
@@ -196,6 +198,441 @@ module main {
     assert!(generated_file.exists(), "Generated file not found");
 }
 
+#[test]
+fn test_generate_with_unresolved_augment() {
+    let temp_dir = TempDir::new().unwrap();
+    let yang_file = temp_dir.path().join("acme.yang");
+    fs::write(
+        &yang_file,
+        r#"
+module acme {
+    namespace "urn:acme";
+    prefix acme;
+
+    container system {
+        leaf hostname {
+            type string;
+        }
+    }
+
+    augment "/acme:system/acme:clock" {
+        leaf timezone {
+            type string;
+        }
+    }
+}
+"#,
+    )
+    .unwrap();
+
+    let result = RustconfBuilder::new()
+        .yang_file(&yang_file)
+        .output_dir(temp_dir.path().join("strict"))
+        .generate();
+    match result {
+        Err(BuildError::ParseError(e)) => {
+            assert!(
+                e.to_string().contains("no schema node '/system/clock'"),
+                "{}",
+                e
+            );
+        }
+        other => panic!(
+            "Expected ParseError for unresolved augment, got {:?}",
+            other
+        ),
+    }
+
+    let result = RustconfBuilder::new()
+        .yang_file(&yang_file)
+        .output_dir(temp_dir.path().join("lenient"))
+        .strict_augments(false)
+        .generate();
+    assert!(result.is_ok(), "Generation failed: {:?}", result.err());
+}
+
+#[test]
+fn test_pipeline_steps_allow_editing_between_steps() {
+    let temp_dir = TempDir::new().unwrap();
+    let yang_file = temp_dir.path().join("device.yang");
+    fs::write(
+        &yang_file,
+        r#"
+module device {
+    namespace "urn:device";
+    prefix dev;
+
+    container system {
+        leaf hostname {
+            type string;
+        }
+    }
+
+    container vendor-debug {
+        leaf trace-level {
+            type uint8;
+        }
+    }
+}
+"#,
+    )
+    .unwrap();
+
+    let output_dir = temp_dir.path().join("out");
+    let builder = RustconfBuilder::new()
+        .yang_file(&yang_file)
+        .output_dir(&output_dir);
+
+    let mut loaded = builder.load_modules().unwrap();
+    assert_eq!(loaded.modules.len(), 1);
+    loaded.module_mut("device").unwrap().data_nodes.retain(
+        |node| !matches!(node, crate::parser::DataNode::Container(c) if c.name == "vendor-debug"),
+    );
+
+    let expanded = builder.expand(loaded).unwrap();
+    assert!(expanded.warnings.is_empty());
+
+    let mut generated = builder.generate_code(&expanded).unwrap();
+    assert!(generated.inputs.contains(&yang_file));
+    assert!(!output_dir.exists(), "generate_code must not write files");
+
+    let bindings = generated
+        .file_mut(output_dir.join("yang_bindings.rs"))
+        .expect("bindings are generated");
+    assert!(bindings.content.contains("pub struct System"));
+    assert!(!bindings.content.contains("VendorDebug"));
+    bindings.content.push_str("\n// post-processed\n");
+
+    builder.write(&generated).unwrap();
+    let written = fs::read_to_string(output_dir.join("yang_bindings.rs")).unwrap();
+    assert!(written.ends_with("// post-processed\n"));
+}
+
+#[test]
+fn test_pipeline_expand_reports_skipped_augments() {
+    let temp_dir = TempDir::new().unwrap();
+    let yang_file = temp_dir.path().join("acme.yang");
+    fs::write(
+        &yang_file,
+        r#"
+module acme {
+    namespace "urn:acme";
+    prefix acme;
+
+    container system {
+        leaf hostname {
+            type string;
+        }
+    }
+
+    augment "/acme:system/acme:clock" {
+        leaf timezone {
+            type string;
+        }
+    }
+}
+"#,
+    )
+    .unwrap();
+
+    let strict = RustconfBuilder::new()
+        .yang_file(&yang_file)
+        .output_dir(temp_dir.path());
+    let loaded = strict.load_modules().unwrap();
+    let error = strict.expand(loaded).err().expect("augment is unresolved");
+    assert_eq!(error.file_path(), Some(yang_file.as_path()));
+
+    let lenient = strict.strict_augments(false);
+    let expanded = lenient.expand(lenient.load_modules().unwrap()).unwrap();
+    assert_eq!(expanded.warnings.len(), 1);
+    assert!(
+        expanded.warnings[0].contains("/system/clock"),
+        "{:?}",
+        expanded.warnings
+    );
+}
+
+#[test]
+fn test_load_modules_reports_chosen_revisions() {
+    let temp_dir = TempDir::new().unwrap();
+    let search_path = temp_dir.path().join("yang");
+    fs::create_dir_all(&search_path).unwrap();
+    for (name, revision) in [
+        ("types", "2020-01-01"),
+        ("types", "2022-01-01"),
+        ("metrics", "2021-06-01"),
+    ] {
+        fs::write(
+            search_path.join(format!("{}@{}.yang", name, revision)),
+            format!(
+                "module {} {{ namespace \"urn:{}\"; prefix {}; revision {}; }}",
+                name, name, name, revision
+            ),
+        )
+        .unwrap();
+    }
+    let yang_file = temp_dir.path().join("device.yang");
+    fs::write(
+        &yang_file,
+        r#"
+module device {
+    namespace "urn:device";
+    prefix dev;
+
+    import types { prefix t; }
+    import metrics { prefix m; revision-date 2020-01-01; }
+}
+"#,
+    )
+    .unwrap();
+
+    let builder = RustconfBuilder::new()
+        .yang_file(&yang_file)
+        .search_path(&search_path)
+        .output_dir(temp_dir.path());
+    let expanded = builder.expand(builder.load_modules().unwrap()).unwrap();
+
+    assert_eq!(
+        expanded.warnings,
+        vec![
+            format!(
+                "types: loaded revision 2022-01-01 from {} (found 2022-01-01, 2020-01-01)",
+                search_path.join("types@2022-01-01.yang").display()
+            ),
+            "device: imports revision 2020-01-01 of metrics, which was not found; \
+             loaded 2021-06-01 instead"
+                .to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_pipeline_load_modules_reports_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let yang_file = temp_dir.path().join("broken.yang");
+    fs::write(&yang_file, "module broken {").unwrap();
+
+    let error = RustconfBuilder::new()
+        .yang_file(&yang_file)
+        .output_dir(temp_dir.path())
+        .load_modules()
+        .err()
+        .expect("module does not parse");
+    assert_eq!(error.file_path(), Some(yang_file.as_path()));
+    assert!(matches!(error.into_inner(), BuildError::ParseError(_)));
+}
+
+#[test]
+fn test_expand_inlines_groupings() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("common.yang"),
+        r#"
+module common {
+    namespace "urn:common";
+    prefix cmn;
+
+    typedef percent {
+        type uint8 {
+            range "0..100";
+        }
+    }
+
+    grouping utilization {
+        leaf cpu-load {
+            type percent;
+        }
+    }
+}
+"#,
+    )
+    .unwrap();
+    let yang_file = temp_dir.path().join("device.yang");
+    fs::write(
+        &yang_file,
+        r#"
+module device {
+    namespace "urn:device";
+    prefix dev;
+
+    import common {
+        prefix cmn;
+    }
+
+    typedef host-name {
+        type string;
+    }
+
+    grouping system-config {
+        leaf hostname {
+            type host-name;
+        }
+    }
+
+    grouping system-top {
+        container system {
+            uses system-config;
+            uses cmn:utilization;
+        }
+    }
+
+    uses system-top;
+}
+"#,
+    )
+    .unwrap();
+
+    let output_dir = temp_dir.path().join("out");
+    let builder = RustconfBuilder::new()
+        .search_path(temp_dir.path())
+        .yang_file(&yang_file)
+        .output_dir(&output_dir);
+    let expanded = builder.expand(builder.load_modules().unwrap()).unwrap();
+    let generated = builder.generate_code(&expanded).unwrap();
+
+    let bindings = &generated.files[0].content;
+    assert!(bindings.contains("pub struct System"), "{}", bindings);
+    assert!(
+        bindings.contains("pub hostname: Option<HostName>"),
+        "{}",
+        bindings
+    );
+    assert!(bindings.contains("pub cpu_load: Option<"), "{}", bindings);
+    assert!(!bindings.contains("Option<Percent>"), "{}", bindings);
+}
+
+#[test]
+fn test_expand_splices_cross_module_augments() {
+    let temp_dir = TempDir::new().unwrap();
+    let device = temp_dir.path().join("device.yang");
+    fs::write(
+        &device,
+        r#"
+module device {
+    namespace "urn:device";
+    prefix dev;
+
+    container system {
+        leaf hostname {
+            type string;
+        }
+    }
+}
+"#,
+    )
+    .unwrap();
+    let vendor = temp_dir.path().join("vendor.yang");
+    fs::write(
+        &vendor,
+        r#"
+module vendor {
+    namespace "urn:vendor";
+    prefix vnd;
+
+    import device {
+        prefix dev;
+    }
+
+    augment "/dev:system" {
+        leaf boot-mtu {
+            type uint16;
+        }
+    }
+}
+"#,
+    )
+    .unwrap();
+
+    let builder = RustconfBuilder::new()
+        .search_path(temp_dir.path())
+        .yang_file(&device)
+        .yang_file(&vendor)
+        .output_dir(temp_dir.path().join("out"));
+    let expanded = builder.expand(builder.load_modules().unwrap()).unwrap();
+    assert!(expanded.modules[1].augments.is_empty());
+    let generated = builder.generate_code(&expanded).unwrap();
+
+    let bindings: String = generated
+        .files
+        .iter()
+        .map(|file| file.content.as_str())
+        .collect();
+    assert!(
+        bindings.contains("#[serde(rename = \"vendor:boot-mtu\""),
+        "{}",
+        bindings
+    );
+    assert!(
+        bindings.contains("pub vendor_boot_mtu: Option<u16>"),
+        "{}",
+        bindings
+    );
+}
+
+#[test]
+fn test_expand_applies_deviations() {
+    let temp_dir = TempDir::new().unwrap();
+    let device = temp_dir.path().join("device.yang");
+    fs::write(
+        &device,
+        r#"
+module device {
+    namespace "urn:device";
+    prefix dev;
+
+    container system {
+        leaf hostname {
+            type string;
+        }
+        leaf motd {
+            type string;
+        }
+    }
+}
+"#,
+    )
+    .unwrap();
+    let deviations = temp_dir.path().join("vendor-deviations.yang");
+    fs::write(
+        &deviations,
+        r#"
+module vendor-deviations {
+    namespace "urn:vendor:deviations";
+    prefix vnd-dev;
+
+    import device {
+        prefix dev;
+    }
+
+    deviation "/dev:system/dev:motd" {
+        deviate not-supported;
+    }
+}
+"#,
+    )
+    .unwrap();
+
+    let builder = RustconfBuilder::new()
+        .search_path(temp_dir.path())
+        .yang_file(&device)
+        .yang_file(&deviations)
+        .output_dir(temp_dir.path().join("out"));
+    let expanded = builder.expand(builder.load_modules().unwrap()).unwrap();
+    let generated = builder.generate_code(&expanded).unwrap();
+
+    let bindings: String = generated
+        .files
+        .iter()
+        .map(|file| file.content.as_str())
+        .collect();
+    assert!(
+        bindings.contains("pub hostname: Option<String>"),
+        "{}",
+        bindings
+    );
+    assert!(!bindings.contains("motd"), "{}", bindings);
+}
+
 #[test]
 fn test_generate_creates_output_directory() {
     let temp_dir = TempDir::new().unwrap();
@@ -299,6 +736,29 @@ fn test_validate_nonexistent_search_path() {
     }
 }
 
+#[test]
+fn test_validate_search_path_pattern_without_matches() {
+    let temp_dir = TempDir::new().unwrap();
+    let yang_file = temp_dir.path().join("test.yang");
+    fs::write(
+        &yang_file,
+        "module test { namespace \"http://test\"; prefix t; }",
+    )
+    .unwrap();
+
+    let result = RustconfBuilder::new()
+        .yang_file(&yang_file)
+        .search_path(temp_dir.path().join("vendor-*.tar.gz"))
+        .output_dir(temp_dir.path())
+        .generate();
+    match result {
+        Err(BuildError::ConfigurationError { message }) => {
+            assert!(message.contains("Search path pattern matches nothing"));
+        }
+        _ => panic!("Expected ConfigurationError for unmatched search path pattern"),
+    }
+}
+
 #[test]
 fn test_validate_search_path_is_file() {
     let temp_dir = TempDir::new().unwrap();
@@ -435,11 +895,67 @@ fn test_validate_module_name_invalid_characters() {
         Err(BuildError::ConfigurationError { message }) => {
             assert!(message.contains("contains invalid characters"));
             assert!(message.contains("invalid-name"));
+            assert!(message.contains("use 'invalid_name' or module_name_from_yang"));
         }
         _ => panic!("Expected ConfigurationError for module name with invalid characters"),
     }
 }
 
+#[test]
+fn test_validate_module_name_keyword() {
+    let temp_dir = TempDir::new().unwrap();
+    let yang_file = temp_dir.path().join("test.yang");
+    fs::write(
+        &yang_file,
+        "module test { namespace \"http://test\"; prefix t; }",
+    )
+    .unwrap();
+
+    let result = RustconfBuilder::new()
+        .yang_file(&yang_file)
+        .module_name("type")
+        .output_dir(temp_dir.path())
+        .generate();
+    match result {
+        Err(BuildError::ConfigurationError { message }) => {
+            assert!(message.contains("Module name 'type' is a Rust keyword; use 'type_'"));
+        }
+        other => panic!(
+            "Expected ConfigurationError for keyword, got {:?}",
+            other.err()
+        ),
+    }
+}
+
+#[test]
+fn test_module_name_from_yang() {
+    let names = [
+        ("openconfig-bgp-policy", "openconfig_bgp_policy"),
+        ("ietf-interfaces.yang", "ietf_interfaces_yang"),
+        ("802-dot1q", "_802_dot1q"),
+        ("type", "type_"),
+    ];
+    for (yang_name, module_name) in names {
+        let builder = RustconfBuilder::new().module_name_from_yang(yang_name);
+        assert_eq!(builder.config.module_name, module_name);
+    }
+
+    let temp_dir = TempDir::new().unwrap();
+    let yang_file = temp_dir.path().join("ieee802-dot1q.yang");
+    fs::write(
+        &yang_file,
+        "module ieee802-dot1q { namespace \"http://test\"; prefix q; container vlan { leaf id { type uint16; } } }",
+    )
+    .unwrap();
+    RustconfBuilder::new()
+        .yang_file(&yang_file)
+        .module_name_from_yang("ieee802-dot1q")
+        .output_dir(temp_dir.path())
+        .generate()
+        .unwrap();
+    assert!(temp_dir.path().join("ieee802_dot1q.rs").exists());
+}
+
 #[test]
 fn test_validate_module_name_valid() {
     let temp_dir = TempDir::new().unwrap();
@@ -1604,3 +2120,474 @@ module test {
         "Server directory should not exist when server generation is disabled"
     );
 }
+
+#[test]
+fn test_relative_output_dir_resolved_against_manifest_dir() {
+    let builder = RustconfBuilder::new().output_dir("generated");
+    let expected = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("generated");
+    assert_eq!(builder.output_dir, expected);
+    assert_eq!(builder.config.output_dir, expected);
+}
+
+fn write_simple_module(dir: &Path) -> PathBuf {
+    let yang_file = dir.join("simple.yang");
+    fs::write(
+        &yang_file,
+        r#"
+module simple {
+    namespace "http://example.com/simple";
+    prefix simple;
+    container settings { leaf name { type string; } }
+}
+"#,
+    )
+    .unwrap();
+    yang_file
+}
+
+#[test]
+fn test_generate_into_src_writes_marked_files_without_timestamps() {
+    let temp_dir = TempDir::new().unwrap();
+    let yang_file = write_simple_module(temp_dir.path());
+    let src_dir = temp_dir.path().join("src").join("generated");
+
+    let generate = || {
+        RustconfBuilder::new()
+            .yang_file(&yang_file)
+            .generate_into_src(&src_dir)
+            .modular_output(true)
+            .generate()
+    };
+    generate().unwrap();
+
+    let mod_file = src_dir.join("mod.rs");
+    let content = fs::read_to_string(&mod_file).unwrap();
+    assert!(content.starts_with(GENERATED_MARKER));
+    assert!(!content.contains("// Generated at:"));
+
+    // Regenerating an unchanged model leaves the files as they were
+    generate().unwrap();
+    assert_eq!(fs::read_to_string(&mod_file).unwrap(), content);
+}
+
+#[test]
+fn test_api_manifest_written_next_to_generated_code() {
+    let temp_dir = TempDir::new().unwrap();
+    let yang_file = write_simple_module(temp_dir.path());
+    let manifest_file = temp_dir.path().join("api").join("manifest.json");
+
+    RustconfBuilder::new()
+        .yang_file(&yang_file)
+        .generate_into_src(temp_dir.path().join("src").join("generated"))
+        .modular_output(true)
+        .api_manifest(&manifest_file)
+        .generate()
+        .unwrap();
+
+    let manifest = ApiManifest::from_json(&fs::read_to_string(&manifest_file).unwrap()).unwrap();
+    assert!(manifest
+        .items()
+        .iter()
+        .any(|item| item.path == "types::Settings::name"
+            && item.signature == "pub name: Option<String>"));
+}
+
+#[test]
+fn test_openapi_file_generates_types_and_reports_skipped_properties() {
+    let temp_dir = TempDir::new().unwrap();
+    let openapi_file = temp_dir.path().join("vendor-firmware.json");
+    fs::write(
+        &openapi_file,
+        r#"{
+            "openapi": "3.0.3",
+            "components": {
+                "schemas": {
+                    "FirmwareStatus": {
+                        "type": "object",
+                        "required": ["imageVersion"],
+                        "properties": {
+                            "imageVersion": {"type": "string"},
+                            "progress": {"type": "number"}
+                        }
+                    }
+                }
+            }
+        }"#,
+    )
+    .unwrap();
+    let output_dir = temp_dir.path().join("out");
+
+    let builder = RustconfBuilder::new()
+        .openapi_file(&openapi_file)
+        .targets(&[Target::Types])
+        .output_dir(&output_dir);
+    let loaded = builder.load_modules().unwrap();
+    assert_eq!(loaded.modules[0].name, "vendor-firmware");
+    let expanded = builder.expand(loaded).unwrap();
+    assert_eq!(expanded.warnings.len(), 1);
+    assert!(expanded.warnings[0].ends_with(
+        "vendor-firmware.json: /FirmwareStatus/progress: number is not supported; use integer or string"
+    ));
+
+    let generated = builder.generate_code(&expanded).unwrap();
+    assert!(generated.inputs.contains(&openapi_file));
+    builder.write(&generated).unwrap();
+    let code = fs::read_to_string(output_dir.join("yang_bindings.rs")).unwrap();
+    assert!(code.contains("pub struct FirmwareStatus"), "{}", code);
+    assert!(
+        code.contains("#[serde(rename = \"imageVersion\")]"),
+        "{}",
+        code
+    );
+    assert!(code.contains("pub image_version: String"), "{}", code);
+}
+
+#[test]
+fn test_validate_missing_openapi_file() {
+    let result = RustconfBuilder::new()
+        .openapi_file("/nonexistent/vendor.json")
+        .output_dir("generated")
+        .validate();
+
+    match result {
+        Err(BuildError::ConfigurationError { message }) => {
+            assert!(
+                message.contains("OpenAPI file does not exist"),
+                "{}",
+                message
+            );
+        }
+        other => panic!("Expected ConfigurationError, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_keep_comments_documents_generated_fields() {
+    let temp_dir = TempDir::new().unwrap();
+    let yang_file = temp_dir.path().join("device.yang");
+    fs::write(
+        &yang_file,
+        r#"
+        module device {
+            namespace "urn:device";
+            prefix dev;
+
+            container system {
+                // Applied after the next reboot.
+                leaf mtu { type uint16; }
+            }
+        }
+        "#,
+    )
+    .unwrap();
+    let output_dir = temp_dir.path().join("out");
+
+    RustconfBuilder::new()
+        .yang_file(&yang_file)
+        .output_dir(&output_dir)
+        .keep_comments(true)
+        .generate()
+        .unwrap();
+
+    let code = fs::read_to_string(output_dir.join("yang_bindings.rs")).unwrap();
+    assert!(
+        code.contains("/// Applied after the next reboot.\n"),
+        "{}",
+        code
+    );
+}
+
+#[test]
+fn test_generate_into_src_refuses_to_overwrite_hand_written_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let yang_file = write_simple_module(temp_dir.path());
+    let src_dir = temp_dir.path().join("src");
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(
+        src_dir.join("yang_bindings.rs"),
+        "pub fn hand_written() {}\n",
+    )
+    .unwrap();
+
+    let result = RustconfBuilder::new()
+        .yang_file(&yang_file)
+        .generate_into_src(&src_dir)
+        .generate();
+
+    match result {
+        Err(BuildError::ConfigurationError { message }) => {
+            assert!(message.contains("Refusing to overwrite"), "{}", message);
+        }
+        other => panic!("Expected ConfigurationError, got {:?}", other),
+    }
+    assert_eq!(
+        fs::read_to_string(src_dir.join("yang_bindings.rs")).unwrap(),
+        "pub fn hand_written() {}\n"
+    );
+}
+
+#[test]
+fn test_output_dir_does_not_overwrite_committed_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let yang_file = write_simple_module(temp_dir.path());
+    let dir = temp_dir.path().join("generated");
+
+    RustconfBuilder::new()
+        .yang_file(&yang_file)
+        .generate_into_src(&dir)
+        .generate()
+        .unwrap();
+    let result = RustconfBuilder::new()
+        .yang_file(&yang_file)
+        .output_dir(&dir)
+        .generate();
+
+    match result {
+        Err(BuildError::ConfigurationError { message }) => {
+            assert!(message.contains("generate_into_src()"), "{}", message);
+        }
+        other => panic!("Expected ConfigurationError, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_output_dir_and_generate_into_src_conflict() {
+    let temp_dir = TempDir::new().unwrap();
+    let yang_file = write_simple_module(temp_dir.path());
+
+    let result = RustconfBuilder::new()
+        .yang_file(&yang_file)
+        .output_dir(temp_dir.path().join("out"))
+        .generate_into_src(temp_dir.path().join("src"))
+        .generate();
+
+    match result {
+        Err(BuildError::ConfigurationError { message }) => {
+            assert!(message.contains("not both"), "{}", message);
+        }
+        other => panic!("Expected ConfigurationError, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_validate_reports_errors_before_parsing() {
+    let temp_dir = TempDir::new().unwrap();
+    let yang_file = temp_dir.path().join("broken.yang");
+    fs::write(&yang_file, "module broken {").unwrap();
+
+    // The YANG file is never parsed, so only the configuration error shows
+    let result = RustconfBuilder::new()
+        .yang_file(&yang_file)
+        .output_dir(temp_dir.path().join("out"))
+        .enable_cli(true)
+        .validate();
+    match result {
+        Err(BuildError::ConfigurationError { message }) => {
+            assert!(message.contains("enable_restful_rpcs"), "{}", message);
+        }
+        other => panic!("Expected ConfigurationError, got {:?}", other),
+    }
+
+    assert!(RustconfBuilder::new()
+        .yang_file(&yang_file)
+        .output_dir(temp_dir.path().join("out"))
+        .validate()
+        .is_ok());
+}
+
+#[test]
+fn test_check_dependencies_requires_runtime_for_generated_clients() {
+    let manifest_path = Path::new("device-client/Cargo.toml");
+    let manifest = r#"
+[package]
+name = "device-client"
+
+[dependencies]
+serde = { version = "1.0", features = ["derive"] }
+serde_json = "1.0"
+
+[build-dependencies]
+rustconf = "0.1"
+"#;
+
+    // Plain types only need serde
+    let builder = RustconfBuilder::new();
+    assert!(builder.check_dependencies(manifest, manifest_path).is_ok());
+
+    let builder = RustconfBuilder::new().enable_restful_rpcs(true);
+    match builder.check_dependencies(manifest, manifest_path) {
+        Err(BuildError::ConfigurationError { message }) => {
+            assert!(message.contains("enable_restful_rpcs(true)"), "{}", message);
+            assert!(message.contains("`rustconf_runtime`"), "{}", message);
+            assert!(message.contains("device-client/Cargo.toml"), "{}", message);
+        }
+        other => panic!("Expected ConfigurationError, got {:?}", other),
+    }
+
+    let builder = RustconfBuilder::new()
+        .enable_restful_rpcs(true)
+        .enable_cli(true);
+    let with_runtime = format!(
+        "{}\n[dependencies.rustconf-runtime]\nversion = \"0.1\"\n",
+        manifest
+    );
+    match builder.check_dependencies(&with_runtime, manifest_path) {
+        Err(BuildError::ConfigurationError { message }) => {
+            assert!(message.contains("enable_cli(true)"), "{}", message);
+        }
+        other => panic!("Expected ConfigurationError, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_declares_dependency_forms() {
+    assert!(declares_dependency(
+        "[dependencies]\nrustconf-runtime = { path = \"../rustconf-runtime\" }\n",
+        "rustconf-runtime"
+    ));
+    assert!(declares_dependency(
+        "[dependencies]\nruntime = { package = \"rustconf-runtime\", version = \"0.1\" }\n",
+        "rustconf-runtime"
+    ));
+    assert!(declares_dependency(
+        "[target.'cfg(unix)'.dependencies]\nrustconf-runtime.workspace = true\n",
+        "rustconf-runtime"
+    ));
+    assert!(declares_dependency(
+        "[dependencies.rustconf-runtime]\nworkspace = true\n",
+        "rustconf-runtime"
+    ));
+    assert!(!declares_dependency(
+        "[package]\nname = \"rustconf-runtime\"\n",
+        "rustconf-runtime"
+    ));
+}
+
+#[test]
+fn test_expand_resolves_module_imported_under_two_prefixes() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("common.yang"),
+        r#"
+module common {
+    namespace "urn:common";
+    prefix cmn;
+
+    typedef percent {
+        type uint8 {
+            range "0..100";
+        }
+    }
+
+    grouping utilization {
+        leaf cpu-load {
+            type percent;
+        }
+    }
+}
+"#,
+    )
+    .unwrap();
+    let yang_file = temp_dir.path().join("device.yang");
+    fs::write(
+        &yang_file,
+        r#"
+module device {
+    namespace "urn:device";
+    prefix dev;
+
+    import common {
+        prefix cmn;
+        description "Shared types.";
+        reference "RFC XXXX";
+    }
+    import common {
+        prefix c;
+    }
+
+    container system {
+        leaf memory-load {
+            type cmn:percent;
+        }
+        uses c:utilization;
+    }
+}
+"#,
+    )
+    .unwrap();
+
+    let output_dir = temp_dir.path().join("out");
+    let builder = RustconfBuilder::new()
+        .search_path(temp_dir.path())
+        .yang_file(&yang_file)
+        .output_dir(&output_dir);
+    let expanded = builder.expand(builder.load_modules().unwrap()).unwrap();
+    let generated = builder.generate_code(&expanded).unwrap();
+
+    let bindings = &generated.files[0].content;
+    assert!(
+        bindings.contains("pub memory_load: Option<"),
+        "{}",
+        bindings
+    );
+    assert!(bindings.contains("pub cpu_load: Option<"), "{}", bindings);
+    assert!(
+        bindings.contains(r#"IMPORTED_MODULES: &[(&str, Option<&str>)] = &[("common", None)];"#),
+        "{}",
+        bindings
+    );
+}
+
+#[test]
+fn test_code_size_budget_warns_about_oversized_output() {
+    let temp_dir = TempDir::new().unwrap();
+    let yang_file = temp_dir.path().join("device.yang");
+    fs::write(
+        &yang_file,
+        r#"
+module device {
+    namespace "urn:device";
+    prefix dev;
+
+    container system {
+        leaf hostname { type string; }
+        container clock {
+            leaf timezone { type string; }
+        }
+    }
+}
+"#,
+    )
+    .unwrap();
+
+    let builder = RustconfBuilder::new()
+        .yang_file(&yang_file)
+        .output_dir(temp_dir.path().join("out"));
+    let expanded = builder.expand(builder.load_modules().unwrap()).unwrap();
+    let generated = builder.generate_code(&expanded).unwrap();
+
+    assert!(builder
+        .code_size_budget
+        .check(&generated.files, false)
+        .is_empty());
+    assert!(CodeSizeBudget::unlimited()
+        .check(&generated.files, false)
+        .is_empty());
+
+    let tight = CodeSizeBudget {
+        max_file_lines: Some(10),
+        max_file_bytes: Some(100),
+        max_types: Some(1),
+    };
+    let warnings = tight.check(&generated.files, false);
+    assert_eq!(warnings.len(), 3, "{:?}", warnings);
+    assert!(
+        warnings[0].contains("lines, over the budget of 10; split it with modular_output(true)")
+    );
+    assert!(warnings[1].contains("bytes, over the budget of 100"));
+    assert!(warnings[2].starts_with("generated "));
+    assert!(warnings[2].contains("types, over the budget of 1; leave out unused subtrees"));
+
+    let warnings = tight.check(&generated.files, true);
+    assert!(!warnings[0].contains("modular_output"), "{}", warnings[0]);
+}
@@ -1,7 +1,7 @@
 //! Error types for build integration.
 
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
 use crate::generator::GeneratorError;
@@ -87,6 +87,16 @@ impl BuildError {
                 println!("cargo:warning=  in one of the search paths. Use search_path() to add");
                 println!("cargo:warning=  directories where YANG modules can be found.");
             }
+            ParseError::UnresolvedInclude { submodule } => {
+                println!("cargo:warning=  Unresolved include: {}", submodule);
+                println!("cargo:warning=");
+                println!(
+                    "cargo:warning=  Suggestion: Ensure the submodule '{}' is available",
+                    submodule
+                );
+                println!("cargo:warning=  in one of the search paths. Use search_path() to add");
+                println!("cargo:warning=  directories where YANG modules can be found.");
+            }
             ParseError::IoError(io_err) => {
                 println!("cargo:warning=  I/O error during parsing: {}", io_err);
                 println!("cargo:warning=");
@@ -116,6 +126,11 @@ impl BuildError {
                 println!("cargo:warning=");
                 println!("cargo:warning=  Suggestion: Check your RustconfBuilder configuration in build.rs.");
             }
+            GeneratorError::NameCollision { .. } => {
+                println!("cargo:warning=  {}", error);
+                println!("cargo:warning=");
+                println!("cargo:warning=  Suggestion: Rename one of the YANG nodes so their Rust names differ.");
+            }
             GeneratorError::CodeGeneration(message) => {
                 println!("cargo:warning=  Code generation error: {}", message);
                 println!("cargo:warning=");
@@ -193,12 +208,26 @@ impl BuildErrorWithContext {
         self.error.report_to_cargo();
     }
 
+    /// The file the error occurred in, if known.
+    pub fn file_path(&self) -> Option<&Path> {
+        self.file_path.as_deref()
+    }
+
     /// Extract the underlying BuildError.
     pub fn into_inner(self) -> BuildError {
         self.error
     }
 }
 
+impl From<BuildError> for BuildErrorWithContext {
+    fn from(error: BuildError) -> Self {
+        Self {
+            error,
+            file_path: None,
+        }
+    }
+}
+
 impl std::fmt::Display for BuildErrorWithContext {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if let Some(file_path) = &self.file_path {
@@ -1,7 +1,11 @@
 //! Build system integration module.
 
+pub mod budget;
 pub mod builder;
 pub mod error;
+pub mod pipeline;
 
+pub use budget::CodeSizeBudget;
 pub use builder::RustconfBuilder;
-pub use error::BuildError;
+pub use error::{BuildError, BuildErrorWithContext};
+pub use pipeline::{ExpandedModules, GeneratedModules, LoadedModules};
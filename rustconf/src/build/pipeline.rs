@@ -0,0 +1,107 @@
+//! Intermediate artifacts of the build steps.
+//!
+//! [`RustconfBuilder::generate`](super::RustconfBuilder::generate) runs
+//! [`load_modules`](super::RustconfBuilder::load_modules),
+//! [`expand`](super::RustconfBuilder::expand),
+//! [`generate_code`](super::RustconfBuilder::generate_code) and
+//! [`write`](super::RustconfBuilder::write) in order. Build scripts that need
+//! to change the modules or the generated code, for example to strip vendor
+//! subtrees, call the steps themselves and edit the artifact between two of
+//! them.
+
+use std::path::PathBuf;
+
+use crate::generator::api::ApiManifest;
+use crate::generator::{GeneratedFile, GeneratorError};
+use crate::parser::{YangModule, YangParser};
+
+/// The YANG modules parsed from the configured files, with their imports
+/// loaded.
+pub struct LoadedModules {
+    /// The parsed modules, in the order the files were added.
+    pub modules: Vec<YangModule>,
+    pub(crate) parser: YangParser,
+    pub(crate) files: Vec<PathBuf>,
+    pub(crate) warnings: Vec<String>,
+}
+
+impl LoadedModules {
+    /// The parser holding the imported modules and where each was read from.
+    pub fn parser(&self) -> &YangParser {
+        &self.parser
+    }
+
+    /// The parsed module named `name`.
+    pub fn module(&self, name: &str) -> Option<&YangModule> {
+        self.modules.iter().find(|module| module.name == name)
+    }
+
+    /// The parsed module named `name`, for changing it before it is expanded.
+    pub fn module_mut(&mut self, name: &str) -> Option<&mut YangModule> {
+        self.modules.iter_mut().find(|module| module.name == name)
+    }
+}
+
+/// The modules with their augments resolved against every loaded module,
+/// ready for code generation.
+pub struct ExpandedModules {
+    /// The modules to generate code for.
+    pub modules: Vec<YangModule>,
+    /// Augments skipped because their target does not resolve, when augments
+    /// are not strict, OpenAPI schemas and properties that could not be
+    /// imported, the revision loaded for imports found in several revisions,
+    /// imports of a revision that was not found, and current definitions
+    /// using deprecated typedefs or groupings of their module.
+    pub warnings: Vec<String>,
+    pub(crate) parser: YangParser,
+}
+
+impl ExpandedModules {
+    /// The parser holding the imported modules and where each was read from.
+    pub fn parser(&self) -> &YangParser {
+        &self.parser
+    }
+
+    /// The module named `name`.
+    pub fn module(&self, name: &str) -> Option<&YangModule> {
+        self.modules.iter().find(|module| module.name == name)
+    }
+
+    /// The module named `name`, for changing it before code is generated.
+    pub fn module_mut(&mut self, name: &str) -> Option<&mut YangModule> {
+        self.modules.iter_mut().find(|module| module.name == name)
+    }
+}
+
+/// The generated source files of every module, not yet written.
+#[derive(Debug, Clone)]
+pub struct GeneratedModules {
+    /// The files, with the paths they are written to.
+    pub files: Vec<GeneratedFile>,
+    /// YANG files and archives the code was generated from, for
+    /// `cargo:rerun-if-changed`.
+    pub inputs: Vec<PathBuf>,
+}
+
+impl GeneratedModules {
+    /// The file written to `path`.
+    pub fn file(&self, path: impl AsRef<std::path::Path>) -> Option<&GeneratedFile> {
+        self.files.iter().find(|file| file.path == path.as_ref())
+    }
+
+    /// The file written to `path`, for changing it before it is written.
+    pub fn file_mut(&mut self, path: impl AsRef<std::path::Path>) -> Option<&mut GeneratedFile> {
+        self.files
+            .iter_mut()
+            .find(|file| file.path == path.as_ref())
+    }
+    /// The public API of the files, for
+    /// [`RustconfBuilder::api_manifest`](super::RustconfBuilder::api_manifest).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a file is not valid Rust, e.g. after editing it.
+    pub fn api_manifest(&self) -> Result<ApiManifest, GeneratorError> {
+        ApiManifest::from_files(&self.files)
+    }
+}
@@ -0,0 +1,74 @@
+//! Registration of generated types for `anydata` content.
+//!
+//! Emits `register_anydata`, which adds the structs of a module's top-level
+//! containers and the `Vec`s of its top-level lists to a
+//! `rustconf_runtime::AnyDataRegistry` under their JSON names, so `anydata`
+//! nodes of other modules carrying this module's data decode into them.
+
+use crate::generator::naming::to_type_name;
+use crate::generator::GeneratorConfig;
+use crate::parser::{DataNode, YangModule};
+
+/// Generate `register_anydata` for `module`, or nothing if it has no
+/// top-level containers or lists.
+pub(crate) fn generate_anydata_registration(
+    module: &YangModule,
+    config: &GeneratorConfig,
+) -> String {
+    let mut registrations = Vec::new();
+    push_registrations(&mut registrations, &module.data_nodes, module, config);
+    if registrations.is_empty() {
+        return String::new();
+    }
+
+    let mut output = String::new();
+    output.push_str(&format!(
+        "/// Register the types of the top-level nodes of the `{}` module, for\n",
+        module.name
+    ));
+    output.push_str("/// decoding `anydata` content that carries them.\n");
+    output
+        .push_str("pub fn register_anydata(registry: &mut rustconf_runtime::AnyDataRegistry) {\n");
+    for registration in registrations {
+        output.push_str(&registration);
+    }
+    output.push_str("}\n");
+    output
+}
+
+/// Push the registration of each container and list of `nodes`, looking
+/// through choices whose nodes are members of the same object.
+fn push_registrations(
+    registrations: &mut Vec<String>,
+    nodes: &[DataNode],
+    module: &YangModule,
+    config: &GeneratorConfig,
+) {
+    for node in nodes {
+        let (name, type_name) = match node {
+            DataNode::Container(container) => (&container.name, to_type_name(&container.name)),
+            DataNode::List(list) => (
+                &list.name,
+                format!("Vec<{}>", config.item_type_name(&list.name)),
+            ),
+            DataNode::Choice(choice) => {
+                for case in &choice.cases {
+                    push_registrations(registrations, &case.data_nodes, module, config);
+                }
+                continue;
+            }
+            DataNode::Case(case) => {
+                push_registrations(registrations, &case.data_nodes, module, config);
+                continue;
+            }
+            DataNode::Leaf(_)
+            | DataNode::LeafList(_)
+            | DataNode::AnyData(_)
+            | DataNode::Uses(_) => continue,
+        };
+        registrations.push(format!(
+            "    registry.register::<{}>(\"{}:{}\");\n",
+            type_name, module.name, name
+        ));
+    }
+}
@@ -0,0 +1,785 @@
+//! Public API manifests of generated code, for picking version bumps.
+//!
+//! An [`ApiManifest`] lists the public items of the generated files: modules,
+//! types with their fields and variants, functions, methods, trait
+//! implementations and re-exports, each with its signature. Intermediate
+//! client crates write it next to the generated code with
+//! `RustconfBuilder::api_manifest` and commit it. After a model update,
+//! [`ApiManifest::diff`] (or `rustconf api-diff old.json new.json`) compares
+//! the manifests and classifies every change as breaking or additive.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use quote::{quote, ToTokens};
+use serde::{Deserialize, Serialize};
+
+use crate::generator::{GeneratedFile, GeneratorError};
+
+/// Version of the manifest format, bumped when manifests written by an older
+/// rustconf can no longer be compared with newer ones.
+const FORMAT_VERSION: u32 = 1;
+
+/// Kind of a public item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ItemKind {
+    /// A module.
+    Module,
+    /// A struct.
+    Struct,
+    /// An enum.
+    Enum,
+    /// A public field of a struct.
+    Field,
+    /// A variant of an enum.
+    Variant,
+    /// A free function.
+    Function,
+    /// A public method or associated function of a type.
+    Method,
+    /// A trait.
+    Trait,
+    /// A trait method implementors must provide.
+    RequiredMethod,
+    /// A trait method with a default implementation.
+    ProvidedMethod,
+    /// A trait implementation, written out or derived.
+    Impl,
+    /// A constant, free or associated.
+    Const,
+    /// A static.
+    Static,
+    /// A type alias.
+    TypeAlias,
+    /// A `pub use` re-export.
+    Reexport,
+}
+
+impl ItemKind {
+    fn name(self) -> &'static str {
+        match self {
+            ItemKind::Module => "module",
+            ItemKind::Struct => "struct",
+            ItemKind::Enum => "enum",
+            ItemKind::Field => "field",
+            ItemKind::Variant => "variant",
+            ItemKind::Function => "function",
+            ItemKind::Method => "method",
+            ItemKind::Trait => "trait",
+            ItemKind::RequiredMethod => "required method",
+            ItemKind::ProvidedMethod => "provided method",
+            ItemKind::Impl => "impl",
+            ItemKind::Const => "const",
+            ItemKind::Static => "static",
+            ItemKind::TypeAlias => "type alias",
+            ItemKind::Reexport => "re-export",
+        }
+    }
+}
+
+/// A public item of the generated code.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiItem {
+    /// Path of the item below the generated root module, e.g.
+    /// `types::Interface::mtu`; trait implementations are named
+    /// `types::<impl Clone for Interface>`.
+    pub path: String,
+    /// What the item is.
+    pub kind: ItemKind,
+    /// The declaration of the item without its body, e.g.
+    /// `pub mtu: Option<u16>`.
+    pub signature: String,
+}
+
+/// The public API of generated code.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf::generator::api::ApiManifest;
+///
+/// let old = ApiManifest::from_source("types", "pub struct Interface { pub name: String }")?;
+/// let new = ApiManifest::from_source(
+///     "types",
+///     "pub struct Interface { pub name: String, pub mtu: Option<u16> }",
+/// )?;
+///
+/// let diff = old.diff(&new);
+/// // Struct literals of `Interface` no longer compile
+/// assert!(diff.is_breaking());
+/// assert_eq!(diff.changes[0].path, "types::Interface::mtu");
+/// # Ok::<(), rustconf::GeneratorError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiManifest {
+    format_version: u32,
+    items: Vec<ApiItem>,
+}
+
+impl ApiManifest {
+    /// The public items of generated files.
+    ///
+    /// Module paths are taken from the file paths relative to the directory
+    /// the files were generated into, so `mod.rs` is the root module and
+    /// `server/handlers.rs` is `server::handlers`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GeneratorError::CodeGeneration` if a file is not valid Rust.
+    pub fn from_files(files: &[GeneratedFile]) -> Result<Self, GeneratorError> {
+        let root = common_dir(files.iter().map(|file| file.path.as_path()));
+        let mut collector = Collector::default();
+        for file in files {
+            if file
+                .path
+                .extension()
+                .is_none_or(|extension| extension != "rs")
+            {
+                continue;
+            }
+            let module = module_path(file.path.strip_prefix(&root).unwrap_or(&file.path));
+            let syntax = syn::parse_file(&file.content).map_err(|e| {
+                GeneratorError::CodeGeneration(format!(
+                    "failed to parse {}: {}",
+                    file.path.display(),
+                    e
+                ))
+            })?;
+            collector.items(&module, &syntax.items);
+        }
+        Ok(Self::new(collector.items))
+    }
+
+    /// The public items of the source of a single module at `module_path`,
+    /// e.g. `types`, or `""` for the root module.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GeneratorError::CodeGeneration` if `source` is not valid Rust.
+    pub fn from_source(module_path: &str, source: &str) -> Result<Self, GeneratorError> {
+        let syntax = syn::parse_file(source)
+            .map_err(|e| GeneratorError::CodeGeneration(format!("failed to parse: {}", e)))?;
+        let mut collector = Collector::default();
+        collector.items(module_path, &syntax.items);
+        Ok(Self::new(collector.items))
+    }
+
+    fn new(mut items: Vec<ApiItem>) -> Self {
+        items.sort_by(|a, b| (&a.path, a.kind).cmp(&(&b.path, b.kind)));
+        items.dedup();
+        Self {
+            format_version: FORMAT_VERSION,
+            items,
+        }
+    }
+
+    /// The items, sorted by path.
+    pub fn items(&self) -> &[ApiItem] {
+        &self.items
+    }
+
+    /// The manifest as pretty-printed JSON, one item per entry in a stable
+    /// order so that it diffs well under version control.
+    pub fn to_json(&self) -> String {
+        let mut json = serde_json::to_string_pretty(self).expect("manifests serialize to JSON");
+        json.push('\n');
+        json
+    }
+
+    /// Read a manifest written by [`to_json`](Self::to_json).
+    ///
+    /// # Errors
+    ///
+    /// Returns `GeneratorError::InvalidConfiguration` if `json` is not a
+    /// manifest, or one written in another format version.
+    pub fn from_json(json: &str) -> Result<Self, GeneratorError> {
+        let manifest: ApiManifest =
+            serde_json::from_str(json).map_err(|e| GeneratorError::InvalidConfiguration {
+                message: format!("invalid API manifest: {}", e),
+            })?;
+        if manifest.format_version != FORMAT_VERSION {
+            return Err(GeneratorError::InvalidConfiguration {
+                message: format!(
+                    "API manifest format version {} is not supported (expected {}); regenerate it with this version of rustconf",
+                    manifest.format_version, FORMAT_VERSION
+                ),
+            });
+        }
+        Ok(manifest)
+    }
+
+    /// The changes from this API to `new`.
+    ///
+    /// Removed items and changed signatures are breaking. Added items are
+    /// additive, except fields and variants added to existing structs and
+    /// enums that are not `#[non_exhaustive]`, which break struct literals
+    /// and exhaustive matches, and required methods added to existing
+    /// traits, which break their implementations.
+    pub fn diff(&self, new: &ApiManifest) -> ApiDiff {
+        let old_items = index(&self.items);
+        let new_items = index(&new.items);
+
+        let mut changes = Vec::new();
+        for (key, old) in &old_items {
+            match new_items.get(key) {
+                None => changes.push(ApiChange {
+                    path: old.path.clone(),
+                    kind: old.kind,
+                    change: Change::Removed {
+                        signature: old.signature.clone(),
+                    },
+                    breaking: true,
+                }),
+                Some(new) if new.signature != old.signature => changes.push(ApiChange {
+                    path: old.path.clone(),
+                    kind: old.kind,
+                    change: Change::Changed {
+                        old: old.signature.clone(),
+                        new: new.signature.clone(),
+                    },
+                    breaking: true,
+                }),
+                Some(_) => {}
+            }
+        }
+        for (key, new) in &new_items {
+            if old_items.contains_key(key) {
+                continue;
+            }
+            let parent = new.path.rsplit_once("::").map_or("", |(parent, _)| parent);
+            let exhaustive = |kind: ItemKind| {
+                old_items.contains_key(&(parent, kind))
+                    && new_items
+                        .get(&(parent, kind))
+                        .is_some_and(|item| !item.signature.starts_with("#[non_exhaustive]"))
+            };
+            let breaking = match new.kind {
+                ItemKind::Field => exhaustive(ItemKind::Struct),
+                ItemKind::Variant => exhaustive(ItemKind::Enum),
+                ItemKind::RequiredMethod => old_items.contains_key(&(parent, ItemKind::Trait)),
+                _ => false,
+            };
+            changes.push(ApiChange {
+                path: new.path.clone(),
+                kind: new.kind,
+                change: Change::Added {
+                    signature: new.signature.clone(),
+                },
+                breaking,
+            });
+        }
+        changes.sort_by(|a, b| (!a.breaking, &a.path).cmp(&(!b.breaking, &b.path)));
+
+        ApiDiff { changes }
+    }
+}
+
+/// Items by path and kind.
+fn index(items: &[ApiItem]) -> BTreeMap<(&str, ItemKind), &ApiItem> {
+    items
+        .iter()
+        .map(|item| ((item.path.as_str(), item.kind), item))
+        .collect()
+}
+
+/// The changes between two [`ApiManifest`]s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiDiff {
+    /// The changes, breaking ones first, each sorted by path.
+    pub changes: Vec<ApiChange>,
+}
+
+impl ApiDiff {
+    /// Whether any change is breaking.
+    pub fn is_breaking(&self) -> bool {
+        self.changes.iter().any(|change| change.breaking)
+    }
+
+    /// Whether the APIs are the same.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// The semver bump the changes call for.
+    pub fn version_bump(&self) -> VersionBump {
+        if self.is_breaking() {
+            VersionBump::Major
+        } else if self.is_empty() {
+            VersionBump::Patch
+        } else {
+            VersionBump::Minor
+        }
+    }
+}
+
+impl fmt::Display for ApiDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No API changes");
+        }
+        for (breaking, heading) in [(true, "Breaking changes:"), (false, "Additive changes:")] {
+            let mut changes = self
+                .changes
+                .iter()
+                .filter(|change| change.breaking == breaking)
+                .peekable();
+            if changes.peek().is_none() {
+                continue;
+            }
+            writeln!(f, "{}", heading)?;
+            for change in changes {
+                writeln!(f, "  {}", change)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A change to one item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiChange {
+    /// Path of the item.
+    pub path: String,
+    /// What the item is.
+    pub kind: ItemKind,
+    /// How it changed.
+    pub change: Change,
+    /// Whether code using the old API may no longer compile.
+    pub breaking: bool,
+}
+
+impl fmt::Display for ApiChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = self.kind.name();
+        match &self.change {
+            Change::Added { signature } => {
+                write!(f, "added {} {}: {}", kind, self.path, signature)
+            }
+            Change::Removed { signature } => {
+                write!(f, "removed {} {}: {}", kind, self.path, signature)
+            }
+            Change::Changed { old, new } => write!(
+                f,
+                "changed {} {}\n    was: {}\n    now: {}",
+                kind, self.path, old, new
+            ),
+        }
+    }
+}
+
+/// How an item changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    /// The item is new.
+    Added {
+        /// Signature of the new item.
+        signature: String,
+    },
+    /// The item is gone.
+    Removed {
+        /// Signature of the removed item.
+        signature: String,
+    },
+    /// The signature of the item changed.
+    Changed {
+        /// The old signature.
+        old: String,
+        /// The new signature.
+        new: String,
+    },
+}
+
+/// A semver version bump.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum VersionBump {
+    /// No API changes.
+    Patch,
+    /// Additive changes only.
+    Minor,
+    /// Breaking changes.
+    Major,
+}
+
+impl VersionBump {
+    /// The version following `current` (`major.minor.patch`), with Cargo's
+    /// rules for versions below 1.0: breaking changes to `0.y.z` bump `y`,
+    /// and any change to `0.0.z` bumps `z`.
+    ///
+    /// Returns `None` if `current` is not a version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustconf::generator::api::VersionBump;
+    ///
+    /// assert_eq!(VersionBump::Major.next_version("1.4.2").as_deref(), Some("2.0.0"));
+    /// assert_eq!(VersionBump::Major.next_version("0.4.2").as_deref(), Some("0.5.0"));
+    /// assert_eq!(VersionBump::Minor.next_version("0.4.2").as_deref(), Some("0.4.3"));
+    /// ```
+    pub fn next_version(self, current: &str) -> Option<String> {
+        let release = current.split(['-', '+']).next()?;
+        let mut parts = release.split('.').map(|part| part.parse::<u64>().ok());
+        let (major, minor, patch) = (parts.next()??, parts.next()??, parts.next()??);
+        if parts.next().is_some() {
+            return None;
+        }
+        let (major, minor, patch) = match (self, major, minor) {
+            (_, 0, 0) => (0, 0, patch + 1),
+            (VersionBump::Major, 0, _) => (0, minor + 1, 0),
+            (_, 0, _) => (0, minor, patch + 1),
+            (VersionBump::Major, _, _) => (major + 1, 0, 0),
+            (VersionBump::Minor, _, _) => (major, minor + 1, 0),
+            (VersionBump::Patch, _, _) => (major, minor, patch + 1),
+        };
+        Some(format!("{}.{}.{}", major, minor, patch))
+    }
+}
+
+impl fmt::Display for VersionBump {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            VersionBump::Patch => "patch",
+            VersionBump::Minor => "minor",
+            VersionBump::Major => "major",
+        })
+    }
+}
+
+/// The deepest directory containing all of `paths`.
+fn common_dir<'a>(paths: impl Iterator<Item = &'a Path>) -> PathBuf {
+    let mut common: Option<PathBuf> = None;
+    for path in paths {
+        let dir = path.parent().unwrap_or(Path::new(""));
+        common = Some(match common {
+            None => dir.to_path_buf(),
+            Some(common) => common
+                .components()
+                .zip(dir.components())
+                .take_while(|(a, b)| a == b)
+                .map(|(a, _)| a)
+                .collect(),
+        });
+    }
+    common.unwrap_or_default()
+}
+
+/// The module path of a file relative to the root directory, e.g.
+/// `server::handlers` for `server/handlers.rs`.
+fn module_path(relative: &Path) -> String {
+    let mut segments: Vec<String> = relative
+        .with_extension("")
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy().into_owned())
+        .collect();
+    if segments.last().is_some_and(|last| last == "mod") {
+        segments.pop();
+    }
+    segments.join("::")
+}
+
+/// Collects the public items of a module.
+#[derive(Default)]
+struct Collector {
+    items: Vec<ApiItem>,
+}
+
+impl Collector {
+    fn push(&mut self, path: String, kind: ItemKind, signature: impl ToTokens) {
+        self.items.push(ApiItem {
+            path,
+            kind,
+            signature: tidy(signature),
+        });
+    }
+
+    fn items(&mut self, module: &str, items: &[syn::Item]) {
+        for item in items {
+            self.item(module, item);
+        }
+    }
+
+    fn item(&mut self, module: &str, item: &syn::Item) {
+        match item {
+            syn::Item::Struct(item) if is_public(&item.vis) => {
+                let path = join(module, &item.ident.to_string());
+                let (vis, ident, generics) = (&item.vis, &item.ident, &item.generics);
+                let where_clause = &generics.where_clause;
+                let attrs = non_exhaustive(&item.attrs);
+                self.push(
+                    path.clone(),
+                    ItemKind::Struct,
+                    quote!(#attrs #vis struct #ident #generics #where_clause),
+                );
+                for (index, field) in item.fields.iter().enumerate() {
+                    if !is_public(&field.vis) {
+                        continue;
+                    }
+                    let (vis, ty) = (&field.vis, &field.ty);
+                    match &field.ident {
+                        Some(name) => self.push(
+                            join(&path, &name.to_string()),
+                            ItemKind::Field,
+                            quote!(#vis #name: #ty),
+                        ),
+                        None => self.push(
+                            join(&path, &index.to_string()),
+                            ItemKind::Field,
+                            quote!(#vis #ty),
+                        ),
+                    }
+                }
+                self.derives(module, &item.ident, &item.attrs);
+            }
+            syn::Item::Enum(item) if is_public(&item.vis) => {
+                let path = join(module, &item.ident.to_string());
+                let (vis, ident, generics) = (&item.vis, &item.ident, &item.generics);
+                let where_clause = &generics.where_clause;
+                let attrs = non_exhaustive(&item.attrs);
+                self.push(
+                    path.clone(),
+                    ItemKind::Enum,
+                    quote!(#attrs #vis enum #ident #generics #where_clause),
+                );
+                for variant in &item.variants {
+                    let mut variant = variant.clone();
+                    variant.attrs.clear();
+                    for field in variant.fields.iter_mut() {
+                        field.attrs.clear();
+                    }
+                    self.push(
+                        join(&path, &variant.ident.to_string()),
+                        ItemKind::Variant,
+                        variant,
+                    );
+                }
+                self.derives(module, &item.ident, &item.attrs);
+            }
+            syn::Item::Fn(item) if is_public(&item.vis) => {
+                let (vis, sig) = (&item.vis, &item.sig);
+                self.push(
+                    join(module, &sig.ident.to_string()),
+                    ItemKind::Function,
+                    quote!(#vis #sig),
+                );
+            }
+            syn::Item::Const(item) if is_public(&item.vis) => {
+                let (vis, ident, ty) = (&item.vis, &item.ident, &item.ty);
+                self.push(
+                    join(module, &ident.to_string()),
+                    ItemKind::Const,
+                    quote!(#vis const #ident: #ty),
+                );
+            }
+            syn::Item::Static(item) if is_public(&item.vis) => {
+                let (vis, mutability, ident, ty) =
+                    (&item.vis, &item.mutability, &item.ident, &item.ty);
+                self.push(
+                    join(module, &ident.to_string()),
+                    ItemKind::Static,
+                    quote!(#vis static #mutability #ident: #ty),
+                );
+            }
+            syn::Item::Type(item) if is_public(&item.vis) => {
+                let mut item = item.clone();
+                item.attrs.clear();
+                self.push(
+                    join(module, &item.ident.to_string()),
+                    ItemKind::TypeAlias,
+                    item,
+                );
+            }
+            syn::Item::Trait(item) if is_public(&item.vis) => {
+                let path = join(module, &item.ident.to_string());
+                let (vis, unsafety, ident, generics) =
+                    (&item.vis, &item.unsafety, &item.ident, &item.generics);
+                let (colon, supertraits) = (&item.colon_token, &item.supertraits);
+                let where_clause = &generics.where_clause;
+                self.push(
+                    path.clone(),
+                    ItemKind::Trait,
+                    quote!(#vis #unsafety trait #ident #generics #colon #supertraits #where_clause),
+                );
+                for trait_item in &item.items {
+                    if let syn::TraitItem::Fn(method) = trait_item {
+                        let kind = if method.default.is_some() {
+                            ItemKind::ProvidedMethod
+                        } else {
+                            ItemKind::RequiredMethod
+                        };
+                        let sig = &method.sig;
+                        self.push(join(&path, &sig.ident.to_string()), kind, sig);
+                    }
+                }
+            }
+            syn::Item::Impl(item) => self.implementation(module, item),
+            syn::Item::Mod(item) if is_public(&item.vis) => {
+                let path = join(module, &item.ident.to_string());
+                let (vis, ident) = (&item.vis, &item.ident);
+                self.push(path.clone(), ItemKind::Module, quote!(#vis mod #ident));
+                if let Some((_, items)) = &item.content {
+                    self.items(&path, items);
+                }
+            }
+            syn::Item::Use(item) if is_public(&item.vis) => {
+                self.reexports(module, "", &item.tree);
+            }
+            _ => {}
+        }
+    }
+
+    /// Inherent methods and constants, or the trait an impl block
+    /// implements.
+    fn implementation(&mut self, module: &str, item: &syn::ItemImpl) {
+        let self_ty = &item.self_ty;
+        if let Some((negative, trait_path, _)) = &item.trait_ {
+            let generics = &item.generics;
+            let where_clause = &generics.where_clause;
+            let name = tidy(quote!(#negative #trait_path for #self_ty));
+            self.push(
+                join(module, &format!("<impl {}>", name)),
+                ItemKind::Impl,
+                quote!(impl #generics #negative #trait_path for #self_ty #where_clause),
+            );
+            return;
+        }
+
+        let type_name = match &**self_ty {
+            syn::Type::Path(type_path) => type_path
+                .path
+                .segments
+                .last()
+                .map(|segment| segment.ident.to_string())
+                .unwrap_or_default(),
+            other => tidy(other),
+        };
+        let path = join(module, &type_name);
+        for impl_item in &item.items {
+            match impl_item {
+                syn::ImplItem::Fn(method) if is_public(&method.vis) => {
+                    let (vis, sig) = (&method.vis, &method.sig);
+                    self.push(
+                        join(&path, &sig.ident.to_string()),
+                        ItemKind::Method,
+                        quote!(#vis #sig),
+                    );
+                }
+                syn::ImplItem::Const(constant) if is_public(&constant.vis) => {
+                    let (vis, ident, ty) = (&constant.vis, &constant.ident, &constant.ty);
+                    self.push(
+                        join(&path, &ident.to_string()),
+                        ItemKind::Const,
+                        quote!(#vis const #ident: #ty),
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Derived traits, recorded like written-out impls so that a dropped
+    /// derive shows up as a removed impl.
+    fn derives(&mut self, module: &str, ident: &syn::Ident, attrs: &[syn::Attribute]) {
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("derive")) {
+            let _ = attr.parse_nested_meta(|meta| {
+                // `serde::Serialize` and `Serialize` are the same trait
+                if let Some(name) = meta.path.segments.last() {
+                    let name = &name.ident;
+                    self.push(
+                        join(module, &format!("<impl {} for {}>", name, ident)),
+                        ItemKind::Impl,
+                        quote!(impl #name for #ident),
+                    );
+                }
+                Ok(())
+            });
+        }
+    }
+
+    /// The names a `pub use` tree makes visible in `module`.
+    fn reexports(&mut self, module: &str, prefix: &str, tree: &syn::UseTree) {
+        match tree {
+            syn::UseTree::Path(path) => {
+                let prefix = join(prefix, &path.ident.to_string());
+                self.reexports(module, &prefix, &path.tree);
+            }
+            syn::UseTree::Name(name) => {
+                let target = join(prefix, &name.ident.to_string());
+                self.items.push(ApiItem {
+                    path: join(module, &name.ident.to_string()),
+                    kind: ItemKind::Reexport,
+                    signature: format!("pub use {}", target),
+                });
+            }
+            syn::UseTree::Rename(rename) => {
+                let target = join(prefix, &rename.ident.to_string());
+                self.items.push(ApiItem {
+                    path: join(module, &rename.rename.to_string()),
+                    kind: ItemKind::Reexport,
+                    signature: format!("pub use {} as {}", target, rename.rename),
+                });
+            }
+            syn::UseTree::Glob(_) => {
+                let target = join(prefix, "*");
+                self.items.push(ApiItem {
+                    path: join(module, &format!("<use {}>", target)),
+                    kind: ItemKind::Reexport,
+                    signature: format!("pub use {}", target),
+                });
+            }
+            syn::UseTree::Group(group) => {
+                for tree in &group.items {
+                    self.reexports(module, prefix, tree);
+                }
+            }
+        }
+    }
+}
+
+fn is_public(vis: &syn::Visibility) -> bool {
+    matches!(vis, syn::Visibility::Public(_))
+}
+
+/// `#[non_exhaustive]`, if the item has it.
+fn non_exhaustive(attrs: &[syn::Attribute]) -> Option<proc_macro2::TokenStream> {
+    attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("non_exhaustive"))
+        .then(|| quote!(#[non_exhaustive]))
+}
+
+fn join(module: &str, name: &str) -> String {
+    if module.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}::{}", module, name)
+    }
+}
+
+/// Tokens as Rust source, without the spaces `TokenStream::to_string` puts
+/// around punctuation.
+fn tidy(tokens: impl ToTokens) -> String {
+    let mut source = tokens.to_token_stream().to_string();
+    for (from, to) in [
+        (" :: ", "::"),
+        (":: ", "::"),
+        (" : ", ": "),
+        (" < ", "<"),
+        ("< ", "<"),
+        (" >", ">"),
+        (" ,", ","),
+        ("( ", "("),
+        (" )", ")"),
+        (" (", "("),
+        ("& ", "&"),
+        ("# [", "#["),
+        ("[ ", "["),
+        (" ]", "]"),
+        (" ;", ";"),
+        ("->(", "-> ("),
+    ] {
+        source = source.replace(from, to);
+    }
+    source
+}
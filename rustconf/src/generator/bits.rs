@@ -0,0 +1,182 @@
+//! Types for YANG `bits` types.
+//!
+//! Each distinct `bits` type with positions below 64 becomes a `Copy`
+//! newtype over `u64`, named like the validated types after a hash of its
+//! bits, with a constant for each bit. It implements
+//! `rustconf_runtime::Bits`, combines with `|`, and serializes, displays and
+//! parses as the RFC 7951 space-separated bit names. Typedefs of `bits`
+//! types alias it; `bits` types with higher positions stay `String`.
+
+use std::collections::{BTreeMap, HashSet};
+
+use crate::generator::docs;
+use crate::generator::naming::to_snake_case;
+use crate::generator::types::TypeGenerator;
+use crate::parser::{Bit, DataNodeVisitor, Leaf, LeafList, TypeSpec, YangModule};
+
+/// Collect the generated types of the `bits` types used by `module`, keyed
+/// by type name.
+pub(crate) fn collect_bits_types(
+    module: &YangModule,
+    type_gen: &TypeGenerator,
+) -> BTreeMap<String, Vec<Bit>> {
+    let mut collector = BitsTypeCollector {
+        types: BTreeMap::new(),
+        type_gen,
+    };
+
+    for typedef in &module.typedefs {
+        collector.collect_from_typespec(&typedef.type_spec);
+    }
+    let _ = crate::parser::walk_data_nodes(&module.data_nodes, &mut collector);
+    for rpc in &module.rpcs {
+        for nodes in [&rpc.input, &rpc.output].into_iter().flatten() {
+            let _ = crate::parser::walk_data_nodes(nodes, &mut collector);
+        }
+    }
+    for notification in &module.notifications {
+        let _ = crate::parser::walk_data_nodes(&notification.data_nodes, &mut collector);
+    }
+
+    collector.types
+}
+
+/// Visitor collecting the `bits` types of leaves and leaf-lists.
+struct BitsTypeCollector<'a> {
+    types: BTreeMap<String, Vec<Bit>>,
+    type_gen: &'a TypeGenerator<'a>,
+}
+
+impl BitsTypeCollector<'_> {
+    fn collect_from_typespec(&mut self, type_spec: &TypeSpec) {
+        if let (TypeSpec::Bits { bits }, Some(type_name)) =
+            (type_spec, self.type_gen.bits_type_name(type_spec))
+        {
+            self.types.insert(type_name, bits.clone());
+        }
+    }
+}
+
+impl DataNodeVisitor for BitsTypeCollector<'_> {
+    type Error = std::convert::Infallible;
+
+    fn visit_leaf(&mut self, leaf: &Leaf) -> Result<(), Self::Error> {
+        self.collect_from_typespec(&leaf.type_spec);
+        Ok(())
+    }
+
+    fn visit_leaf_list(&mut self, leaf_list: &LeafList) -> Result<(), Self::Error> {
+        self.collect_from_typespec(&leaf_list.type_spec);
+        Ok(())
+    }
+}
+
+/// Generate the type `type_name` of a `bits` type with the bits `bits`.
+///
+/// The bits are expected to have positions, as assigned by the parser.
+pub(crate) fn generate_bits_type(type_name: &str, bits: &[Bit], derive_debug: bool) -> String {
+    let names: Vec<String> = bits.iter().map(|bit| format!("`{}`", bit.name)).collect();
+    let mut derives = vec!["Clone", "Copy", "Default", "PartialEq", "Eq", "Hash"];
+    if derive_debug {
+        derives.insert(0, "Debug");
+    }
+
+    let mut output = String::new();
+    output.push_str(&format!(
+        "/// Set of the bits {} of a YANG `bits` type.\n",
+        names.join(", ")
+    ));
+    output.push_str("///\n");
+    output.push_str("/// Serializes as the names of the set bits, separated by spaces.\n");
+    output.push_str(&format!("#[derive({})]\n", derives.join(", ")));
+    output.push_str(&format!("pub struct {}(u64);\n\n", type_name));
+
+    output.push_str(&format!("impl {} {{\n", type_name));
+    let mut const_names = HashSet::new();
+    for (i, bit) in bits.iter().enumerate() {
+        let position = bit.position.unwrap_or(0);
+        let mut const_name = const_name(&bit.name);
+        if !const_names.insert(const_name.clone()) {
+            const_name = format!("{}_{}", const_name, position);
+            const_names.insert(const_name.clone());
+        }
+        if i > 0 {
+            output.push('\n');
+        }
+        output.push_str(&docs::rustdoc(
+            &docs::node_doc(bit.description.as_deref(), "bit", &bit.name),
+            "    ",
+        ));
+        output.push_str(&format!(
+            "    pub const {}: Self = Self(1 << {});\n",
+            const_name, position
+        ));
+    }
+    output.push_str("}\n\n");
+
+    let entries: Vec<String> = bits
+        .iter()
+        .map(|bit| format!("({:?}, {})", bit.name, bit.position.unwrap_or(0)))
+        .collect();
+    output.push_str(&format!(
+        "impl rustconf_runtime::Bits for {} {{\n",
+        type_name
+    ));
+    output.push_str(&format!(
+        "    const BITS: &'static [(&'static str, u32)] = &[{}];\n\n",
+        entries.join(", ")
+    ));
+    output.push_str("    fn from_raw(raw: u64) -> Self {\n");
+    output.push_str("        Self(raw)\n");
+    output.push_str("    }\n\n");
+    output.push_str("    fn raw(self) -> u64 {\n");
+    output.push_str("        self.0\n");
+    output.push_str("    }\n");
+    output.push_str("}\n\n");
+
+    output.push_str(&format!("impl std::ops::BitOr for {} {{\n", type_name));
+    output.push_str("    type Output = Self;\n\n");
+    output.push_str("    fn bitor(self, other: Self) -> Self {\n");
+    output.push_str("        Self(self.0 | other.0)\n");
+    output.push_str("    }\n");
+    output.push_str("}\n\n");
+
+    output.push_str(&format!("impl std::fmt::Display for {} {{\n", type_name));
+    output.push_str("    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {\n");
+    output.push_str("        rustconf_runtime::bits::format(*self, f)\n");
+    output.push_str("    }\n");
+    output.push_str("}\n\n");
+
+    output.push_str(&format!("impl std::str::FromStr for {} {{\n", type_name));
+    output.push_str("    type Err = rustconf_runtime::bits::UnknownBit;\n\n");
+    output.push_str("    fn from_str(text: &str) -> Result<Self, Self::Err> {\n");
+    output.push_str("        rustconf_runtime::bits::parse(text)\n");
+    output.push_str("    }\n");
+    output.push_str("}\n\n");
+
+    output.push_str(&format!("impl serde::Serialize for {} {{\n", type_name));
+    output.push_str(
+        "    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {\n",
+    );
+    output.push_str("        rustconf_runtime::bits::serialize(self, serializer)\n");
+    output.push_str("    }\n");
+    output.push_str("}\n\n");
+
+    output.push_str(&format!(
+        "impl<'de> serde::Deserialize<'de> for {} {{\n",
+        type_name
+    ));
+    output.push_str(
+        "    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {\n",
+    );
+    output.push_str("        rustconf_runtime::bits::deserialize(deserializer)\n");
+    output.push_str("    }\n");
+    output.push_str("}\n");
+
+    output
+}
+
+/// The name of the constant of the bit `name`, e.g. `LINK_UP` for `link-up`.
+fn const_name(name: &str) -> String {
+    to_snake_case(name).to_uppercase()
+}
@@ -0,0 +1,421 @@
+//! Command-line interface generation.
+//!
+//! Generates a clap command with one subcommand per RPC. Input leaves and
+//! leaf-lists become flags typed after their YANG types, with the YANG
+//! descriptions as help; the whole input can also be given as JSON with
+//! `--input-json`, which covers containers, lists and other nodes without a
+//! flag. The generated `run` function builds the input, calls the generated
+//! RPC client function and returns the output as JSON.
+
+use crate::generator::config::{GeneratorConfig, Int64Encoding};
+use crate::generator::restrictions::resolve_type;
+use crate::parser::{DataNode, Rpc, TypeSpec, YangModule};
+
+/// Argument id of the flag taking the whole RPC input as JSON.
+const INPUT_JSON: &str = "input-json";
+
+/// How a leaf is read from the command line.
+enum FlagKind {
+    /// A number, parsed by clap as the given Rust type. String-encoded
+    /// 64-bit integers are sent as JSON strings.
+    Number {
+        rust_type: &'static str,
+        string_encoded: bool,
+    },
+    Boolean,
+    Text,
+}
+
+/// A flag for an input leaf or leaf-list.
+struct Flag<'a> {
+    name: &'a str,
+    description: Option<&'a str>,
+    kind: FlagKind,
+    mandatory: bool,
+    multiple: bool,
+}
+
+/// Generate the items of the `cli` module, each line prefixed with `indent`.
+///
+/// The items refer to the RPC functions as `operations::*`, so the module
+/// must see the `operations` module as `super::operations` (single file) or
+/// through `use super::operations::*` (modular output).
+pub(crate) fn generate_cli_items(
+    module: &YangModule,
+    config: &GeneratorConfig,
+    indent: &str,
+) -> String {
+    let type_gen = crate::generator::types::TypeGenerator::new(config);
+    let mut output = String::new();
+    let rpcs: Vec<(&Rpc, Vec<Flag>)> = module
+        .rpcs
+        .iter()
+        .map(|rpc| (rpc, input_flags(rpc, module, config)))
+        .collect();
+    let any_input = rpcs.iter().any(|(rpc, _)| has_input(rpc));
+
+    output.push_str(&format!(
+        "/// Build the command line of the {} YANG module, with a subcommand per RPC.\n",
+        module.name
+    ));
+    output.push_str("pub fn command() -> clap::Command {\n");
+    output.push_str(&format!("    clap::Command::new({:?})\n", module.name));
+    if let Some((about, long_about)) = module.description.as_deref().map(help_text) {
+        output.push_str(&format!("        .about({:?})\n", about));
+        if let Some(long_about) = long_about {
+            output.push_str(&format!("        .long_about({:?})\n", long_about));
+        }
+    }
+    output.push_str("        .subcommand_required(true)\n");
+    output.push_str("        .arg_required_else_help(true)\n");
+    for (rpc, flags) in &rpcs {
+        output.push_str("        .subcommand(\n");
+        output.push_str(&format!("            clap::Command::new({:?})", rpc.name));
+        if let Some((about, long_about)) = rpc.description.as_deref().map(help_text) {
+            output.push_str(&format!("\n                .about({:?})", about));
+            if let Some(long_about) = long_about {
+                output.push_str(&format!("\n                .long_about({:?})", long_about));
+            }
+        }
+        if has_input(rpc) {
+            output.push_str("\n                .arg(\n");
+            output.push_str(&format!(
+                "                    clap::Arg::new({:?})\n",
+                INPUT_JSON
+            ));
+            output.push_str(&format!(
+                "                        .long({:?})\n",
+                INPUT_JSON
+            ));
+            output.push_str("                        .value_name(\"JSON\")\n");
+            output.push_str("                        .help(\"The RPC input as a JSON object; flags override its members\"),\n");
+            output.push_str("                )");
+        }
+        for flag in flags {
+            output.push_str("\n                .arg(\n");
+            output.push_str(&generate_arg(flag));
+            output.push_str("                )");
+        }
+        output.push_str(",\n        )\n");
+    }
+    output.push_str("}\n\n");
+
+    output
+        .push_str("/// Run the RPC selected on the command line, returning its output as JSON,\n");
+    output.push_str("/// or `null` for RPCs without output.\n");
+    output.push_str("///\n");
+    output.push_str("/// # Errors\n");
+    output.push_str("///\n");
+    output.push_str(
+        "/// Returns `RpcError::ValidationError` if the input given on the command line\n",
+    );
+    output.push_str("/// does not match the RPC, and any error of the RPC itself.\n");
+    if config.enable_dyn_client {
+        output.push_str("pub async fn run(\n");
+        output.push_str("    client: &dyn rustconf_runtime::RestconfClientApi,\n");
+    } else {
+        output.push_str("pub async fn run<T: rustconf_runtime::HttpTransport>(\n");
+        output.push_str("    client: &rustconf_runtime::RestconfClient<T>,\n");
+    }
+    output.push_str("    matches: &clap::ArgMatches,\n");
+    output.push_str(") -> Result<serde_json::Value, rustconf_runtime::RpcError> {\n");
+    output.push_str("    match matches.subcommand() {\n");
+    for (rpc, flags) in &rpcs {
+        let function_name = crate::generator::naming::to_field_name(&rpc.name);
+        let type_name = crate::generator::naming::to_type_name(&rpc.name);
+        let has_output = rpc.output.as_ref().is_some_and(|nodes| !nodes.is_empty());
+        let matches = if has_input(rpc) { "matches" } else { "_" };
+        output.push_str(&format!(
+            "        Some(({:?}, {})) => {{\n",
+            rpc.name, matches
+        ));
+        let call = if has_input(rpc) {
+            let binding = if flags.is_empty() {
+                "members"
+            } else {
+                "mut members"
+            };
+            output.push_str(&format!(
+                "            let {} = input_members(matches)?;\n",
+                binding
+            ));
+            for flag in flags {
+                output.push_str(&generate_member_insert(
+                    flag,
+                    &type_gen.get_json_field_name(flag.name, module),
+                ));
+            }
+            output.push_str(&format!(
+                "            let input: operations::{}Input = input_from(members, {:?})?;\n",
+                type_name, rpc.name
+            ));
+            format!("operations::{}(client, &input).await?", function_name)
+        } else {
+            format!("operations::{}(client).await?", function_name)
+        };
+        if has_output {
+            output.push_str(&format!("            output_json({})\n", call));
+        } else {
+            output.push_str(&format!("            {};\n", call));
+            output.push_str("            Ok(serde_json::Value::Null)\n");
+        }
+        output.push_str("        }\n");
+    }
+    output.push_str(
+        "        Some((name, _)) => Err(rustconf_runtime::RpcError::ValidationError(format!(\n",
+    );
+    output.push_str("            \"Unknown operation '{}'\",\n");
+    output.push_str("            name\n");
+    output.push_str("        ))),\n");
+    output.push_str("        None => Err(rustconf_runtime::RpcError::ValidationError(\n");
+    output.push_str("            \"No operation given\".to_string(),\n");
+    output.push_str("        )),\n");
+    output.push_str("    }\n");
+    output.push_str("}\n");
+
+    if any_input {
+        output.push('\n');
+        output.push_str("/// The members of `--input-json`, or none if it was not given.\n");
+        output.push_str("fn input_members(\n");
+        output.push_str("    matches: &clap::ArgMatches,\n");
+        output.push_str(") -> Result<serde_json::Map<String, serde_json::Value>, rustconf_runtime::RpcError> {\n");
+        output.push_str(&format!(
+            "    let Some(json) = matches.get_one::<String>({:?}) else {{\n",
+            INPUT_JSON
+        ));
+        output.push_str("        return Ok(serde_json::Map::new());\n");
+        output.push_str("    };\n");
+        output.push_str("    match serde_json::from_str(json) {\n");
+        output.push_str("        Ok(serde_json::Value::Object(members)) => Ok(members),\n");
+        output.push_str("        Ok(_) => Err(rustconf_runtime::RpcError::ValidationError(\n");
+        output.push_str(&format!(
+            "            \"--{} must be a JSON object\".to_string(),\n",
+            INPUT_JSON
+        ));
+        output.push_str("        )),\n");
+        output.push_str(
+            "        Err(e) => Err(rustconf_runtime::RpcError::ValidationError(format!(\n",
+        );
+        output.push_str(&format!(
+            "            \"Invalid --{}: {{}}\",\n",
+            INPUT_JSON
+        ));
+        output.push_str("            e\n");
+        output.push_str("        ))),\n");
+        output.push_str("    }\n");
+        output.push_str("}\n\n");
+        output.push_str("/// Deserialize the input of `rpc` from its JSON members.\n");
+        output.push_str("fn input_from<I: serde::de::DeserializeOwned>(\n");
+        output.push_str("    members: serde_json::Map<String, serde_json::Value>,\n");
+        output.push_str("    rpc: &str,\n");
+        output.push_str(") -> Result<I, rustconf_runtime::RpcError> {\n");
+        output.push_str(
+            "    serde_json::from_value(serde_json::Value::Object(members)).map_err(|e| {\n",
+        );
+        output.push_str("        rustconf_runtime::RpcError::ValidationError(format!(\"Invalid input for {}: {}\", rpc, e))\n");
+        output.push_str("    })\n");
+        output.push_str("}\n");
+    }
+
+    if rpcs
+        .iter()
+        .any(|(rpc, _)| rpc.output.as_ref().is_some_and(|nodes| !nodes.is_empty()))
+    {
+        output.push('\n');
+        output.push_str("/// Serialize an RPC output to JSON.\n");
+        output.push_str("fn output_json<O: serde::Serialize>(\n");
+        output.push_str("    output: O,\n");
+        output.push_str(") -> Result<serde_json::Value, rustconf_runtime::RpcError> {\n");
+        output.push_str("    serde_json::to_value(output)\n");
+        output.push_str(
+            "        .map_err(|e| rustconf_runtime::RpcError::SerializationError(e.to_string()))\n",
+        );
+        output.push_str("}\n");
+    }
+
+    output
+        .lines()
+        .map(|line| {
+            if line.is_empty() {
+                "\n".to_string()
+            } else {
+                format!("{}{}\n", indent, line)
+            }
+        })
+        .collect()
+}
+
+fn has_input(rpc: &Rpc) -> bool {
+    rpc.input.as_ref().is_some_and(|nodes| !nodes.is_empty())
+}
+
+/// The flags of the leaves and leaf-lists directly in the input of an RPC.
+///
+/// `empty` and `binary` leaves have no flag; like containers and lists, they
+/// are given with `--input-json`.
+fn input_flags<'a>(rpc: &'a Rpc, module: &YangModule, config: &GeneratorConfig) -> Vec<Flag<'a>> {
+    let Some(nodes) = &rpc.input else {
+        return Vec::new();
+    };
+    nodes
+        .iter()
+        .filter_map(|node| {
+            let (name, description, type_spec, mandatory, multiple) = match node {
+                DataNode::Leaf(leaf) => (
+                    &leaf.name,
+                    &leaf.description,
+                    &leaf.type_spec,
+                    leaf.mandatory,
+                    false,
+                ),
+                DataNode::LeafList(leaf_list) => (
+                    &leaf_list.name,
+                    &leaf_list.description,
+                    &leaf_list.type_spec,
+                    false,
+                    true,
+                ),
+                _ => return None,
+            };
+            if name == INPUT_JSON {
+                return None;
+            }
+            Some(Flag {
+                name,
+                description: description.as_deref(),
+                kind: flag_kind(type_spec, module, config)?,
+                mandatory,
+                multiple,
+            })
+        })
+        .collect()
+}
+
+/// How a value of `type_spec` is read, resolving typedefs, or `None` if it
+/// has no flag.
+fn flag_kind(
+    type_spec: &TypeSpec,
+    module: &YangModule,
+    config: &GeneratorConfig,
+) -> Option<FlagKind> {
+    let Some(type_spec) = resolve_type(type_spec, module).type_spec else {
+        return Some(FlagKind::Text);
+    };
+    let number = |rust_type| FlagKind::Number {
+        rust_type,
+        string_encoded: false,
+    };
+    let int64 = |rust_type| FlagKind::Number {
+        rust_type,
+        string_encoded: config.int64_encoding != Int64Encoding::Number,
+    };
+    match type_spec {
+        TypeSpec::Int8 { .. } => Some(number("i8")),
+        TypeSpec::Int16 { .. } => Some(number("i16")),
+        TypeSpec::Int32 { .. } => Some(number("i32")),
+        TypeSpec::Int64 { .. } => Some(int64("i64")),
+        TypeSpec::Uint8 { .. } => Some(number("u8")),
+        TypeSpec::Uint16 { .. } => Some(number("u16")),
+        TypeSpec::Uint32 { .. } => Some(number("u32")),
+        TypeSpec::Uint64 { .. } => Some(int64("u64")),
+        TypeSpec::Boolean => Some(FlagKind::Boolean),
+        TypeSpec::Empty | TypeSpec::Binary { .. } => None,
+        TypeSpec::String { .. }
+        | TypeSpec::Decimal64 { .. }
+        | TypeSpec::DateAndTime { .. }
+        | TypeSpec::Enumeration { .. }
+        | TypeSpec::Bits { .. }
+        | TypeSpec::Union { .. }
+        | TypeSpec::LeafRef { .. }
+        | TypeSpec::TypedefRef { .. } => Some(FlagKind::Text),
+    }
+}
+
+/// Generate the `clap::Arg` of a flag, indented for the subcommand builder.
+fn generate_arg(flag: &Flag) -> String {
+    let indent = "                        ";
+    let mut output = format!("                    clap::Arg::new({:?})\n", flag.name);
+    output.push_str(&format!("{}.long({:?})", indent, flag.name));
+    if let Some((help, long_help)) = flag.description.map(help_text) {
+        output.push_str(&format!("\n{}.help({:?})", indent, help));
+        if let Some(long_help) = long_help {
+            output.push_str(&format!("\n{}.long_help({:?})", indent, long_help));
+        }
+    }
+    match flag.kind {
+        FlagKind::Number { rust_type, .. } => output.push_str(&format!(
+            "\n{}.value_parser(clap::value_parser!({}))",
+            indent, rust_type
+        )),
+        FlagKind::Boolean => output.push_str(&format!(
+            "\n{}.value_parser(clap::value_parser!(bool))",
+            indent
+        )),
+        FlagKind::Text => {}
+    }
+    if flag.multiple {
+        output.push_str(&format!("\n{}.action(clap::ArgAction::Append)", indent));
+    }
+    if flag.mandatory {
+        output.push_str(&format!(
+            "\n{}.required_unless_present({:?})",
+            indent, INPUT_JSON
+        ));
+    }
+    output.push_str(",\n");
+    output
+}
+
+/// Generate the statement that sets the JSON member of a flag, if given.
+fn generate_member_insert(flag: &Flag, member: &str) -> String {
+    let (rust_type, to_json, collect) = match flag.kind {
+        FlagKind::Number {
+            rust_type,
+            string_encoded: true,
+        } => (
+            rust_type,
+            "value.to_string()",
+            "map(|value| value.to_string())",
+        ),
+        FlagKind::Number { rust_type, .. } => (rust_type, "*value", "copied()"),
+        FlagKind::Boolean => ("bool", "*value", "copied()"),
+        FlagKind::Text => ("String", "value.clone()", "cloned()"),
+    };
+    if flag.multiple {
+        format!(
+            "            if let Some(values) = matches.get_many::<{}>({:?}) {{\n                \
+             let values: Vec<_> = values.{}.collect();\n                \
+             members.insert({:?}.to_string(), serde_json::json!(values));\n            \
+             }}\n",
+            rust_type, flag.name, collect, member
+        )
+    } else {
+        format!(
+            "            if let Some(value) = matches.get_one::<{}>({:?}) {{\n                \
+             members.insert({:?}.to_string(), serde_json::json!({}));\n            \
+             }}\n",
+            rust_type, flag.name, member, to_json
+        )
+    }
+}
+
+/// The help text of a YANG description: its first paragraph on one line,
+/// and the whole description if it has more paragraphs.
+fn help_text(description: &str) -> (String, Option<String>) {
+    let mut paragraphs: Vec<Vec<&str>> = vec![Vec::new()];
+    for line in description.lines().map(str::trim) {
+        match paragraphs.last_mut() {
+            Some(paragraph) if !line.is_empty() => paragraph.push(line),
+            Some(paragraph) if !paragraph.is_empty() => paragraphs.push(Vec::new()),
+            _ => {}
+        }
+    }
+    let paragraphs: Vec<String> = paragraphs
+        .iter()
+        .filter(|paragraph| !paragraph.is_empty())
+        .map(|paragraph| paragraph.join(" "))
+        .collect();
+    let help = paragraphs.first().cloned().unwrap_or_default();
+    let long_help = (paragraphs.len() > 1).then(|| paragraphs.join("\n\n"));
+    (help, long_help)
+}
@@ -0,0 +1,163 @@
+//! Name collision detection.
+//!
+//! Distinct YANG names can map to the same Rust identifier: `foo-bar` and
+//! `foo_bar` both become the field `foo_bar`, and a container `user` and a
+//! list `users` both produce a struct named `User`. Such models are rejected
+//! before any code is generated, with both YANG paths in the error.
+
+use std::collections::HashMap;
+
+use crate::generator::naming::{to_field_name, to_type_name};
+use crate::generator::{GeneratorConfig, GeneratorError};
+use crate::parser::{Choice, DataNode, YangModule};
+
+/// Rust identifiers already claimed in one namespace, with the YANG path that
+/// claimed each of them.
+struct Namespace {
+    kind: &'static str,
+    names: HashMap<String, String>,
+}
+
+impl Namespace {
+    fn new(kind: &'static str) -> Self {
+        Self {
+            kind,
+            names: HashMap::new(),
+        }
+    }
+
+    fn claim(&mut self, name: String, path: String) -> Result<(), GeneratorError> {
+        match self.names.get(&name) {
+            Some(first) => Err(GeneratorError::NameCollision {
+                kind: self.kind,
+                name,
+                first: first.clone(),
+                second: path,
+            }),
+            None => {
+                self.names.insert(name, path);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Check that the Rust names generated for a module are unique.
+///
+/// Types generated from typedefs and data nodes share one namespace. Fields
+/// must be unique within their struct, and choice variants within their enum.
+/// RPC and notification types live in their own modules and are checked
+/// separately.
+pub(crate) fn check_name_collisions(
+    module: &YangModule,
+    config: &GeneratorConfig,
+) -> Result<(), GeneratorError> {
+    let mut types = Namespace::new("type");
+    for typedef in &module.typedefs {
+        types.claim(
+            to_type_name(&typedef.name),
+            format!("typedef {}", typedef.name),
+        )?;
+    }
+    check_struct(&module.data_nodes, "", config, &mut types)?;
+
+    let mut rpc_types = Namespace::new("type");
+    for rpc in &module.rpcs {
+        let path = format!("/{}", rpc.name);
+        let type_name = to_type_name(&rpc.name);
+        for (suffix, nodes) in [("Input", &rpc.input), ("Output", &rpc.output)] {
+            if let Some(nodes) = nodes {
+                let path = format!("{}/{}", path, suffix.to_lowercase());
+                rpc_types.claim(format!("{}{}", type_name, suffix), path.clone())?;
+                check_fields(nodes, &path)?;
+            }
+        }
+    }
+
+    let mut notification_types = Namespace::new("type");
+    for notification in &module.notifications {
+        let path = format!("/{}", notification.name);
+        notification_types.claim(to_type_name(&notification.name), path.clone())?;
+        check_fields(&notification.data_nodes, &path)?;
+    }
+
+    Ok(())
+}
+
+/// Check the fields of a struct generated from `nodes` and claim the types
+/// generated for them.
+fn check_struct(
+    nodes: &[DataNode],
+    parent: &str,
+    config: &GeneratorConfig,
+    types: &mut Namespace,
+) -> Result<(), GeneratorError> {
+    check_fields(nodes, parent)?;
+
+    for node in nodes {
+        match node {
+            DataNode::Container(container) => {
+                let path = format!("{}/{}", parent, container.name);
+                types.claim(to_type_name(&container.name), path.clone())?;
+                check_struct(&container.children, &path, config, types)?;
+            }
+            DataNode::List(list) => {
+                let path = format!("{}/{}", parent, list.name);
+                // List entries are named after the singular form of the list
+                types.claim(config.item_type_name(&list.name), path.clone())?;
+                check_struct(&list.children, &path, config, types)?;
+            }
+            DataNode::Choice(choice) => check_choice(choice, parent, config, types)?,
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Claim the enum generated for a choice and the structs of its cases.
+fn check_choice(
+    choice: &Choice,
+    parent: &str,
+    config: &GeneratorConfig,
+    types: &mut Namespace,
+) -> Result<(), GeneratorError> {
+    let path = format!("{}/{}", parent, choice.name);
+    types.claim(to_type_name(&choice.name), path.clone())?;
+
+    let mut variants = Namespace::new("variant");
+    for case in &choice.cases {
+        let case_path = format!("{}/{}", path, case.name);
+        let variant_name = to_type_name(&case.name);
+        variants.claim(variant_name.clone(), case_path.clone())?;
+
+        // Cases holding more than a single leaf get their own struct
+        let has_struct = case.data_nodes.len() > 1
+            || (case.data_nodes.len() == 1 && !matches!(case.data_nodes[0], DataNode::Leaf(_)));
+        if has_struct {
+            types.claim(format!("{}Data", variant_name), case_path.clone())?;
+        }
+
+        check_struct(&case.data_nodes, &case_path, config, types)?;
+    }
+
+    Ok(())
+}
+
+/// Check that the fields generated for `nodes` have distinct names.
+fn check_fields(nodes: &[DataNode], parent: &str) -> Result<(), GeneratorError> {
+    let mut fields = Namespace::new("field");
+    for node in nodes {
+        let name = match node {
+            DataNode::Container(container) => &container.name,
+            DataNode::List(list) => &list.name,
+            DataNode::Leaf(leaf) => &leaf.name,
+            DataNode::LeafList(leaf_list) => &leaf_list.name,
+            DataNode::AnyData(anydata) => &anydata.name,
+            DataNode::Choice(choice) => &choice.name,
+            DataNode::Case(_) | DataNode::Uses(_) => continue,
+        };
+        fields.claim(to_field_name(name), format!("{}/{}", parent, name))?;
+    }
+    Ok(())
+}
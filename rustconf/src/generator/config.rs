@@ -1,7 +1,10 @@
 //! Configuration types for code generation.
 
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::PathBuf;
 
+use crate::parser::YangModule;
+
 /// Namespace mode for RESTful RPC URL generation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum NamespaceMode {
@@ -15,6 +18,251 @@ pub enum NamespaceMode {
     Disabled,
 }
 
+/// Namespace qualifier used for JSON member names and RESTCONF data paths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NamespaceQualifier {
+    /// Qualify with the module name as required by RFC 7951 (default).
+    ///
+    /// Only top-level members are qualified (e.g. `/restconf/data/ietf-interfaces:interfaces`);
+    /// child members share their parent's namespace and are left unqualified.
+    #[default]
+    ModuleName,
+
+    /// Legacy behavior: qualify every member with the module prefix (e.g. `if:name`).
+    ///
+    /// Not RFC 7951 conformant; kept for devices and code that depend on it.
+    Prefix,
+}
+
+impl NamespaceQualifier {
+    /// Get the qualifier to use for members defined in the given module.
+    pub fn qualifier<'m>(&self, module: &'m YangModule) -> &'m str {
+        match self {
+            NamespaceQualifier::ModuleName => &module.name,
+            NamespaceQualifier::Prefix => &module.prefix,
+        }
+    }
+}
+
+/// JSON encoding of `int64` and `uint64` values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Int64Encoding {
+    /// Encode as JSON numbers (default).
+    ///
+    /// Not RFC 7951 conformant; kept for code and devices that depend on it.
+    #[default]
+    Number,
+
+    /// Encode as JSON strings and reject numbers, as required by RFC 7951.
+    String,
+
+    /// Encode as JSON strings but accept both strings and numbers, for
+    /// servers that are inconsistent about it.
+    Lenient,
+}
+
+impl Int64Encoding {
+    /// Path of the `rustconf_runtime::int64` module implementing this encoding,
+    /// or `None` when serde's default number encoding is used.
+    pub fn serde_module(&self) -> Option<&'static str> {
+        match self {
+            Int64Encoding::Number => None,
+            Int64Encoding::String => Some("rustconf_runtime::int64::string"),
+            Int64Encoding::Lenient => Some("rustconf_runtime::int64::lenient"),
+        }
+    }
+}
+
+/// Rust representation of `decimal64` values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Decimal64Representation {
+    /// A generated `Copy` newtype over the value scaled to an `i64`, one for
+    /// each number of fraction digits, e.g. `Decimal64Fd2` (default).
+    #[default]
+    ScaledInteger,
+
+    /// `rust_decimal::Decimal`, for arithmetic beyond what the scaled integer
+    /// offers. Values with more fraction digits than the type allows are
+    /// rejected by the server, not rounded.
+    RustDecimal,
+}
+
+impl Decimal64Representation {
+    /// The Rust type of values with `fraction_digits` fraction digits.
+    pub(crate) fn rust_type(&self, fraction_digits: u8) -> String {
+        match self {
+            Decimal64Representation::ScaledInteger => format!("Decimal64Fd{}", fraction_digits),
+            Decimal64Representation::RustDecimal => "rust_decimal::Decimal".to_string(),
+        }
+    }
+
+    /// Path of the `rustconf_runtime::decimal64` module encoding fields of
+    /// this representation, or `None` when the type encodes itself.
+    pub fn serde_module(&self) -> Option<&'static str> {
+        match self {
+            Decimal64Representation::ScaledInteger => None,
+            Decimal64Representation::RustDecimal => {
+                Some("rustconf_runtime::decimal64::rust_decimal")
+            }
+        }
+    }
+
+    /// An expression of the value `scaled` / 10^`fraction_digits`.
+    pub(crate) fn literal(&self, scaled: i64, fraction_digits: u8) -> String {
+        match self {
+            Decimal64Representation::ScaledInteger => {
+                format!("Decimal64Fd{}::from_scaled({})", fraction_digits, scaled)
+            }
+            Decimal64Representation::RustDecimal => {
+                format!(
+                    "rust_decimal::Decimal::new({}, {})",
+                    scaled, fraction_digits
+                )
+            }
+        }
+    }
+}
+
+/// Rust representation of `date-and-time` values of `ietf-yang-types`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DateAndTimeRepresentation {
+    /// `String`, checked against the typedef's pattern when validation is
+    /// enabled (default).
+    #[default]
+    String,
+
+    /// `chrono::DateTime<chrono::FixedOffset>`, keeping the offset the
+    /// device reported.
+    Chrono,
+}
+
+impl DateAndTimeRepresentation {
+    /// The Rust type of values, or `None` when they are strings.
+    pub(crate) fn rust_type(&self) -> Option<&'static str> {
+        match self {
+            DateAndTimeRepresentation::String => None,
+            DateAndTimeRepresentation::Chrono => Some("chrono::DateTime<chrono::FixedOffset>"),
+        }
+    }
+
+    /// Path of the `rustconf_runtime` module encoding fields of this
+    /// representation, or `None` when serde's default encoding is used.
+    pub fn serde_module(&self) -> Option<&'static str> {
+        match self {
+            DateAndTimeRepresentation::String => None,
+            DateAndTimeRepresentation::Chrono => Some("rustconf_runtime::date_and_time"),
+        }
+    }
+}
+
+/// Visibility of generated items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Visibility {
+    /// `pub` (default).
+    #[default]
+    Public,
+
+    /// `pub(crate)`, for code embedded in a larger crate that re-exports
+    /// only part of it.
+    Crate,
+}
+
+impl Visibility {
+    /// The visibility keyword, `pub` or `pub(crate)`.
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            Visibility::Public => "pub",
+            Visibility::Crate => "pub(crate)",
+        }
+    }
+
+    /// Attributes of an item with this visibility: crate-private items may
+    /// be unused by the crate including the generated code.
+    pub(crate) fn attributes(&self) -> &'static str {
+        match self {
+            Visibility::Public => "",
+            Visibility::Crate => "#[allow(dead_code)]\n",
+        }
+    }
+
+    /// Apply this visibility to the top-level `pub` items of `code`.
+    ///
+    /// Only lines starting with `pub ` are changed, so the fields and methods
+    /// of the items keep their visibility, which is bounded by the item's.
+    /// Top-level `impl` blocks get the attributes too, for their methods.
+    pub(crate) fn apply(&self, code: String) -> String {
+        if *self == Visibility::Public {
+            return code;
+        }
+        let mut output = String::with_capacity(code.len());
+        for line in code.split_inclusive('\n') {
+            if let Some(rest) = line.strip_prefix("pub ") {
+                output.push_str(self.attributes());
+                output.push_str(self.keyword());
+                output.push(' ');
+                output.push_str(rest);
+            } else {
+                if line.starts_with("impl") {
+                    output.push_str(self.attributes());
+                }
+                output.push_str(line);
+            }
+        }
+        output
+    }
+}
+
+/// Visibility of each category of generated items.
+///
+/// # Examples
+///
+/// Public types with crate-private client functions and helpers:
+///
+/// ```
+/// use rustconf::generator::{ItemVisibility, Visibility};
+///
+/// let visibility = ItemVisibility {
+///     operations: Visibility::Crate,
+///     helpers: Visibility::Crate,
+///     ..ItemVisibility::default()
+/// };
+/// # assert_eq!(visibility.types, Visibility::Public);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ItemVisibility {
+    /// Data structs, typedefs, validated types and notification payloads.
+    pub types: Visibility,
+
+    /// RPC and CRUD client functions, resource handles, the command-line
+    /// interface, and the re-exported client and transport types.
+    pub operations: Visibility,
+
+    /// The `consts` and `schema` modules.
+    pub helpers: Visibility,
+}
+
+/// A part of the bindings that can be generated on its own.
+///
+/// Typedefs, constants and validated types are generated for every target,
+/// as the other generated types refer to them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Target {
+    /// Structs for the data tree and the schema enum.
+    Types,
+
+    /// RPC input and output types and client functions. Together with
+    /// `Types`, also the client functions for data resources.
+    Rpcs,
+
+    /// Notification payload structs.
+    Notifications,
+}
+
+impl Target {
+    /// Every target, generated by default.
+    pub const ALL: [Target; 3] = [Target::Types, Target::Rpcs, Target::Notifications];
+}
+
 /// Configuration for code generation.
 #[derive(Debug, Clone)]
 pub struct GeneratorConfig {
@@ -36,10 +284,19 @@ pub struct GeneratorConfig {
     /// Derive Clone trait for generated types.
     pub derive_clone: bool,
 
-    /// Enable namespace prefixes in JSON field names for RESTCONF compliance.
-    /// When enabled, field names will be prefixed with the module prefix (e.g., "prefix:field-name").
+    /// Derive `Default` for structs without mandatory fields, and generate a
+    /// `new` constructor taking the mandatory fields (list keys, mandatory
+    /// leaves, containers and choices) for the others.
+    pub generate_constructors: bool,
+
+    /// Enable namespace qualifiers in JSON member names and data paths for RESTCONF compliance.
+    /// See `namespace_qualifier` for how names are qualified.
     pub enable_namespace_prefixes: bool,
 
+    /// How names are qualified when namespace prefixes are enabled.
+    /// Defaults to RFC 7951 module-name qualifiers on top-level members only.
+    pub namespace_qualifier: NamespaceQualifier,
+
     /// Enable RESTful RPC generation.
     /// When enabled, generates functional HTTP client implementations for RPCs.
     /// When disabled, generates stub functions returning NotImplemented errors.
@@ -49,6 +306,12 @@ pub struct GeneratorConfig {
     /// Controls whether YANG module namespaces are included in generated URLs.
     pub restful_namespace_mode: NamespaceMode,
 
+    /// Send RPC input and expect RPC output as bare objects.
+    /// By default bodies are wrapped in `{"module:input": ...}` and
+    /// `{"module:output": ...}` as required by RFC 8040; this is a compatibility
+    /// mode for devices that do not follow it.
+    pub bare_rpc_bodies: bool,
+
     /// Generate modular output (multiple files) vs single file.
     /// When enabled, generates separate files for types, operations, and validation.
     /// When disabled (default), generates a single file with all code.
@@ -62,6 +325,89 @@ pub struct GeneratorConfig {
     /// Server code output subdirectory (relative to output_dir).
     /// Server code will be generated in output_dir/server_output_subdir.
     pub server_output_subdir: String,
+
+    /// Emit a struct-level `#[serde(rename_all = "kebab-case")]` instead of per-field renames.
+    /// Fields whose JSON name is not the kebab-case form of the Rust field name keep an
+    /// explicit `rename`.
+    pub serde_rename_all: bool,
+
+    /// JSON encoding of `int64` and `uint64` leaves and leaf-lists.
+    /// Strings are required by RFC 7951; generated code depends on
+    /// `rustconf-runtime` for anything other than `Int64Encoding::Number`.
+    pub int64_encoding: Int64Encoding,
+
+    /// Rust representation of `decimal64` leaves and leaf-lists, always
+    /// encoded as RFC 7951 strings. Generated code depends on
+    /// `rustconf-runtime`, with the `rust_decimal` feature and the
+    /// `rust_decimal` crate for `Decimal64Representation::RustDecimal`.
+    pub decimal64_representation: Decimal64Representation,
+
+    /// Rust representation of `date-and-time` leaves and leaf-lists of
+    /// `ietf-yang-types`. Generated code depends on `rustconf-runtime`, with
+    /// the `chrono` feature, and the `chrono` crate for
+    /// `DateAndTimeRepresentation::Chrono`.
+    pub date_and_time_representation: DateAndTimeRepresentation,
+
+    /// Implement `rustconf_runtime::Pretty` on generated structs.
+    /// The rendering uses YANG names and shows units and defaults, for logs
+    /// meant to be read by operators. Generated code depends on `rustconf-runtime`.
+    pub enable_pretty_printers: bool,
+
+    /// Generate a `cli` module that builds a clap command with a subcommand per
+    /// RPC, taking the input leaves as flags. Requires `enable_restful_rpcs`;
+    /// generated code depends on `clap` 4.
+    pub enable_cli: bool,
+
+    /// Take clients as `&dyn RestconfClientApi` in generated functions, instead
+    /// of a generic `&RestconfClient<T>`, so applications can store a
+    /// `DynRestconfClient` without a transport type parameter. Requires
+    /// `enable_restful_rpcs`.
+    pub enable_dyn_client: bool,
+
+    /// Generate `schema::netconf_schema()`, describing the module for
+    /// `rustconf_runtime::netconf::NetconfTransport` so the generated
+    /// functions can be used with NETCONF-only devices. Generated code depends
+    /// on `rustconf-runtime` with the `netconf` feature.
+    pub enable_netconf: bool,
+
+    /// Generate `schema::payload_schema()`, describing value types, list keys,
+    /// mandatory leaves, ranges and lengths for
+    /// `rustconf_runtime::payload::ValidatingTransport`, which checks request
+    /// bodies before they are sent. Generated code depends on
+    /// `rustconf-runtime`.
+    pub enable_payload_schema: bool,
+
+    /// Import `RpcError` and the `percent_encode` helper from
+    /// `rustconf-runtime` instead of defining them in every generated module,
+    /// for crates that generate several modules. Generated code depends on
+    /// `rustconf-runtime`.
+    pub shared_helpers: bool,
+
+    /// Visibility of the generated types, operations and helpers (default:
+    /// all `pub`).
+    pub visibility: ItemVisibility,
+
+    /// Type names of list entries, keyed by YANG list name, overriding the
+    /// singularized list name (e.g. `"media" -> "Medium"`).
+    pub list_item_names: BTreeMap<String, String>,
+
+    /// Parts of the bindings to generate (default: all of them).
+    /// A crate that only consumes notifications can leave out the client
+    /// functions, and with them the imports of the client and transport types.
+    pub targets: Vec<Target>,
+
+    /// YANG features the server supports, as `feature` or `module:feature`.
+    /// Nodes whose `if-feature` conditions do not hold are left out of the
+    /// generated code. `None` (the default) treats every feature as supported.
+    pub enabled_features: Option<BTreeSet<String>>,
+
+    /// User-supplied code inserted at the top of every generated file, after the
+    /// header comments and inner attributes (e.g. extra `use` statements or `#![allow]`s).
+    pub prelude: Option<String>,
+
+    /// User-supplied code appended to the root generated file (the single output file,
+    /// or `mod.rs` in modular mode), e.g. extension `impl` blocks.
+    pub epilogue: Option<String>,
 }
 
 impl Default for GeneratorConfig {
@@ -73,12 +419,31 @@ impl Default for GeneratorConfig {
             enable_validation: true,
             derive_debug: true,
             derive_clone: true,
+            generate_constructors: false,
             enable_namespace_prefixes: false,
+            namespace_qualifier: NamespaceQualifier::default(),
             enable_restful_rpcs: false,
             restful_namespace_mode: NamespaceMode::default(),
+            bare_rpc_bodies: false,
             modular_output: false,
             enable_server_generation: false,
             server_output_subdir: "server".to_string(),
+            serde_rename_all: false,
+            int64_encoding: Int64Encoding::default(),
+            decimal64_representation: Decimal64Representation::default(),
+            date_and_time_representation: DateAndTimeRepresentation::default(),
+            enable_pretty_printers: false,
+            enable_cli: false,
+            enable_dyn_client: false,
+            enable_netconf: false,
+            enable_payload_schema: false,
+            shared_helpers: false,
+            visibility: ItemVisibility::default(),
+            list_item_names: BTreeMap::new(),
+            targets: Target::ALL.to_vec(),
+            enabled_features: None,
+            prelude: None,
+            epilogue: None,
         }
     }
 }
@@ -105,6 +470,15 @@ impl GeneratorConfig {
         self
     }
 
+    /// Send RPC input and expect RPC output as bare objects.
+    ///
+    /// Only needed for devices that do not wrap RPC bodies in the
+    /// `module:input` / `module:output` envelope required by RFC 8040.
+    pub fn bare_rpc_bodies(&mut self) -> &mut Self {
+        self.bare_rpc_bodies = true;
+        self
+    }
+
     /// Enable server-side code generation.
     ///
     /// When enabled, generates server handler traits, stub implementations, and routing logic.
@@ -126,6 +500,206 @@ impl GeneratorConfig {
         self
     }
 
+    /// Set how names are qualified when namespace prefixes are enabled.
+    ///
+    /// Use `NamespaceQualifier::Prefix` to keep the legacy prefix-on-every-member behavior.
+    pub fn namespace_qualifier(&mut self, qualifier: NamespaceQualifier) -> &mut Self {
+        self.namespace_qualifier = qualifier;
+        self
+    }
+
+    /// Emit `#[serde(rename_all = "kebab-case")]` on generated structs.
+    ///
+    /// Per-field `rename` attributes are only kept for fields whose JSON name does not
+    /// follow from the field name, which considerably reduces generated code size.
+    pub fn serde_rename_all(&mut self) -> &mut Self {
+        self.serde_rename_all = true;
+        self
+    }
+
+    /// Set the JSON encoding of `int64` and `uint64` values.
+    ///
+    /// Use `Int64Encoding::String` for RFC 7951 conformant servers, or
+    /// `Int64Encoding::Lenient` to also accept numbers.
+    pub fn int64_encoding(&mut self, encoding: Int64Encoding) -> &mut Self {
+        self.int64_encoding = encoding;
+        self
+    }
+
+    /// Set the Rust representation of `decimal64` values.
+    pub fn decimal64_representation(
+        &mut self,
+        representation: Decimal64Representation,
+    ) -> &mut Self {
+        self.decimal64_representation = representation;
+        self
+    }
+
+    /// Set the Rust representation of `date-and-time` values.
+    pub fn date_and_time_representation(
+        &mut self,
+        representation: DateAndTimeRepresentation,
+    ) -> &mut Self {
+        self.date_and_time_representation = representation;
+        self
+    }
+
+    /// Implement `rustconf_runtime::Pretty` on generated structs.
+    pub fn enable_pretty_printers(&mut self) -> &mut Self {
+        self.enable_pretty_printers = true;
+        self
+    }
+
+    /// Generate a clap command-line interface over the RPC client functions.
+    pub fn enable_cli(&mut self) -> &mut Self {
+        self.enable_cli = true;
+        self
+    }
+
+    /// Take clients as `&dyn RestconfClientApi` in generated functions.
+    pub fn enable_dyn_client(&mut self) -> &mut Self {
+        self.enable_dyn_client = true;
+        self
+    }
+
+    /// Derive `Default` for structs without mandatory fields and generate
+    /// `new` constructors for the others.
+    pub fn generate_constructors(&mut self) -> &mut Self {
+        self.generate_constructors = true;
+        self
+    }
+
+    /// Generate the NETCONF schema of the module.
+    pub fn enable_netconf(&mut self) -> &mut Self {
+        self.enable_netconf = true;
+        self
+    }
+
+    /// Generate the payload schema of the module.
+    pub fn enable_payload_schema(&mut self) -> &mut Self {
+        self.enable_payload_schema = true;
+        self
+    }
+
+    /// Import shared helpers from `rustconf-runtime` instead of generating them.
+    pub fn shared_helpers(&mut self) -> &mut Self {
+        self.shared_helpers = true;
+        self
+    }
+
+    /// Set the visibility of each category of generated items.
+    pub fn visibility(&mut self, visibility: ItemVisibility) -> &mut Self {
+        self.visibility = visibility;
+        self
+    }
+
+    /// Name the entry type of the YANG list `list` `type_name`, instead of
+    /// the singularized list name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustconf::generator::GeneratorConfig;
+    ///
+    /// let mut config = GeneratorConfig::default();
+    /// config.list_item_name("media", "Medium");
+    /// assert_eq!(config.item_type_name("media"), "Medium");
+    /// assert_eq!(config.item_type_name("interfaces"), "Interface");
+    /// ```
+    pub fn list_item_name(
+        &mut self,
+        list: impl Into<String>,
+        type_name: impl Into<String>,
+    ) -> &mut Self {
+        self.list_item_names.insert(list.into(), type_name.into());
+        self
+    }
+
+    /// The type name of the entries of the YANG list `list`.
+    pub fn item_type_name(&self, list: &str) -> String {
+        match self.list_item_names.get(list) {
+            Some(type_name) => type_name.clone(),
+            None => crate::generator::naming::to_item_type_name(list),
+        }
+    }
+
+    /// Generate only the given parts of the bindings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustconf::generator::{GeneratorConfig, Target};
+    ///
+    /// let mut config = GeneratorConfig::default();
+    /// config.targets(&[Target::Notifications]);
+    /// assert!(config.generates(Target::Notifications));
+    /// assert!(!config.generates(Target::Rpcs));
+    /// ```
+    pub fn targets(&mut self, targets: &[Target]) -> &mut Self {
+        self.targets = targets.to_vec();
+        self
+    }
+
+    /// Whether the given part of the bindings is generated.
+    pub fn generates(&self, target: Target) -> bool {
+        self.targets.contains(&target)
+    }
+
+    /// Generate only the nodes available with the given YANG features.
+    ///
+    /// Features are named as `feature`, matching the feature of that name in
+    /// any module, or as `module:feature`. Features not listed are treated as
+    /// unsupported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustconf::generator::GeneratorConfig;
+    ///
+    /// let mut config = GeneratorConfig::default();
+    /// config.enabled_features(["ietf-interfaces:if-mib", "pre-provisioning"]);
+    /// assert!(config.feature_enabled("ietf-interfaces:if-mib"));
+    /// assert!(config.feature_enabled("ietf-interfaces:pre-provisioning"));
+    /// assert!(!config.feature_enabled("ietf-interfaces:arbitrary-names"));
+    /// ```
+    pub fn enabled_features<I, S>(&mut self, features: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.enabled_features = Some(features.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Whether the feature `module:feature` is enabled.
+    pub fn feature_enabled(&self, qualified_name: &str) -> bool {
+        let Some(enabled) = &self.enabled_features else {
+            return true;
+        };
+        let local_name = qualified_name
+            .split_once(':')
+            .map_or(qualified_name, |(_, name)| name);
+        enabled.contains(qualified_name) || enabled.contains(local_name)
+    }
+
+    /// Insert code at the top of every generated file.
+    ///
+    /// The code is placed after the generated header comments and inner attributes, so it
+    /// may itself contain inner attributes such as `#![allow(clippy::all)]`.
+    pub fn prelude(&mut self, code: impl Into<String>) -> &mut Self {
+        self.prelude = Some(code.into());
+        self
+    }
+
+    /// Append code to the end of the root generated file.
+    ///
+    /// Useful for extension `impl` blocks on generated types. In modular mode the code is
+    /// appended to `mod.rs`, where all generated types are in scope via re-exports.
+    pub fn epilogue(&mut self, code: impl Into<String>) -> &mut Self {
+        self.epilogue = Some(code.into());
+        self
+    }
+
     /// Validate the configuration.
     ///
     /// # Errors
@@ -177,6 +751,63 @@ impl GeneratorConfig {
             }
         }
 
+        // List item names are used as Rust type names verbatim
+        for (list, type_name) in &self.list_item_names {
+            let mut chars = type_name.chars();
+            let valid = chars
+                .next()
+                .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+            if !valid {
+                return Err(format!(
+                    "list_item_name '{}' for list '{}' is not a valid Rust type name.",
+                    type_name, list
+                ));
+            }
+        }
+
+        if self.targets.is_empty() {
+            return Err("targets cannot be empty; generate at least one Target.".to_string());
+        }
+
+        // Server code implements the RPCs and data resources, and publishes notifications
+        if self.enable_server_generation && !Target::ALL.iter().all(|t| self.generates(*t)) {
+            return Err(
+                "server generation requires all targets. Remove the targets() restriction \
+                 or disable server generation."
+                    .to_string(),
+            );
+        }
+
+        // The command-line interface calls the RPC client functions
+        if self.enable_cli && !(self.enable_restful_rpcs && self.generates(Target::Rpcs)) {
+            return Err(
+                "enable_cli requires enable_restful_rpcs and the Rpcs target, as the \
+                 command-line interface calls the generated RPC functions."
+                    .to_string(),
+            );
+        }
+
+        // Only the RESTful client functions take a client
+        if self.enable_dyn_client && !self.enable_restful_rpcs {
+            return Err(
+                "enable_dyn_client requires enable_restful_rpcs, as only the RESTful \
+                 client functions take a client."
+                    .to_string(),
+            );
+        }
+
+        // Public functions cannot take or return crate-private types
+        if self.visibility.types == Visibility::Crate
+            && (self.visibility.operations == Visibility::Public || self.enable_server_generation)
+        {
+            return Err(
+                "visibility.types cannot be Crate while operations are public or server \
+                 generation is enabled, as their signatures use the generated types."
+                    .to_string(),
+            );
+        }
+
         // Validate that modular_output is enabled when server generation is enabled
         if self.enable_server_generation && !self.modular_output {
             return Err(
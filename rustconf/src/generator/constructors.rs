@@ -0,0 +1,72 @@
+//! Constructor generation.
+//!
+//! With [`GeneratorConfig::generate_constructors`](crate::generator::GeneratorConfig),
+//! structs whose fields are all optional derive `Default`, and the others get
+//! a `new` constructor taking their mandatory fields. A derived `Default`
+//! would otherwise fill list keys and mandatory leaves with `""` or `0`,
+//! which compiles but describes data nobody meant to send.
+
+use proc_macro2::Span;
+use quote::quote;
+use syn::{Ident, Type};
+
+use crate::generator::formatting::{format_token_stream, StructField};
+
+/// Constructors with more parameters than this are allowed to have them.
+const CLIPPY_MAX_ARGUMENTS: usize = 7;
+
+/// Whether a field may be left unset: an `Option` or a `Vec`.
+pub(crate) fn is_optional(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option" || segment.ident == "Vec"),
+        _ => false,
+    }
+}
+
+/// Whether a struct with `fields` can derive `Default` without making up
+/// values for mandatory fields.
+pub(crate) fn can_derive_default(fields: &[StructField]) -> bool {
+    fields.iter().all(|field| is_optional(&field.ty))
+}
+
+/// Generate an `impl` block with `new`, taking the mandatory `fields` as
+/// arguments in order and leaving the optional ones unset.
+pub(crate) fn generate_constructor(type_name: &str, fields: &[StructField]) -> String {
+    let struct_name = Ident::new(type_name, Span::call_site());
+    let mut parameters = Vec::new();
+    let mut initializers = Vec::new();
+    for field in fields {
+        let ident = Ident::new(&field.name, Span::call_site());
+        let ty = &field.ty;
+        if !is_optional(ty) {
+            parameters.push(quote! { #ident: #ty });
+            initializers.push(quote! { #ident });
+        } else if is_vec(ty) {
+            initializers.push(quote! { #ident: Vec::new() });
+        } else {
+            initializers.push(quote! { #ident: None });
+        }
+    }
+    let allow = (parameters.len() > CLIPPY_MAX_ARGUMENTS)
+        .then(|| quote! { #[allow(clippy::too_many_arguments)] });
+    let tokens = quote! {
+        impl #struct_name {
+            #[doc = " Create a value from its mandatory fields, leaving the others unset."]
+            #allow
+            pub fn new(#(#parameters),*) -> Self {
+                Self {
+                    #(#initializers),*
+                }
+            }
+        }
+    };
+    format_token_stream(tokens).unwrap_or_default()
+}
+
+fn is_vec(ty: &Type) -> bool {
+    matches!(ty, Type::Path(path) if path.path.segments.last().is_some_and(|segment| segment.ident == "Vec"))
+}
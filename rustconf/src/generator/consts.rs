@@ -0,0 +1,249 @@
+//! Constants module generation.
+//!
+//! Emits a `consts` module with the media types, URL fragments and stream names
+//! used by generated code, so custom transports and tests can refer to them
+//! instead of repeating string literals.
+
+use std::collections::HashMap;
+
+use crate::parser::{YangModule, EXTENSIONS_MODULE};
+
+/// Media types and RESTCONF URL fragments shared by every module.
+const WELL_KNOWN: &[(&str, &str, &str)] = &[
+    (
+        "YANG_DATA_JSON",
+        "application/yang-data+json",
+        "Media type for JSON data and operation bodies (RFC 8040).",
+    ),
+    (
+        "YANG_DATA_XML",
+        "application/yang-data+xml",
+        "Media type for XML data and operation bodies (RFC 8040).",
+    ),
+    (
+        "YANG_PATCH_JSON",
+        "application/yang-patch+json",
+        "Media type for YANG Patch documents (RFC 8072).",
+    ),
+    (
+        "EVENT_STREAM",
+        "text/event-stream",
+        "Media type of notification event streams.",
+    ),
+    ("RESTCONF_ROOT", "/restconf", "Root of the RESTCONF API."),
+    (
+        "DATA_PATH",
+        "/restconf/data",
+        "Root of the datastore resource.",
+    ),
+    (
+        "OPERATIONS_PATH",
+        "/restconf/operations",
+        "Root of the operations resource.",
+    ),
+    (
+        "STREAMS_PATH",
+        "/restconf/data/ietf-restconf-monitoring:restconf-state/streams",
+        "Stream list advertised by the server (RFC 8040 section 9.3).",
+    ),
+    (
+        "NETCONF_STREAM",
+        "NETCONF",
+        "Name of the default notification stream (RFC 8040 section 6.2).",
+    ),
+];
+
+/// Generate the items of the `consts` module for a YANG module.
+///
+/// Every line is prefixed with `indent`, so the items can be written either to
+/// their own file or inside a `pub mod consts { ... }` block. `revisions` maps
+/// module names to their latest revision.
+pub(crate) fn generate_consts_items(
+    module: &YangModule,
+    revisions: &HashMap<String, String>,
+    indent: &str,
+) -> String {
+    let mut output = String::new();
+    let mut push_const = |name: &str, ty: &str, value: &str, doc: &str| {
+        output.push_str(&format!("{}/// {}\n", indent, doc));
+        output.push_str(&format!(
+            "{}pub const {}: {} = {};\n",
+            indent, name, ty, value
+        ));
+    };
+
+    push_const(
+        "MODULE_NAME",
+        "&str",
+        &quote(&module.name),
+        "Name of the YANG module.",
+    );
+    push_const(
+        "MODULE_NAMESPACE",
+        "&str",
+        &quote(&module.namespace),
+        "XML namespace of the YANG module.",
+    );
+    push_const(
+        "MODULE_PREFIX",
+        "&str",
+        &quote(&module.prefix),
+        "Prefix of the YANG module.",
+    );
+    for (name, value, doc) in [
+        (
+            "MODULE_ORGANIZATION",
+            &module.organization,
+            "Organization responsible for the YANG module, if stated.",
+        ),
+        (
+            "MODULE_CONTACT",
+            &module.contact,
+            "Contact information for the YANG module's authors, if stated.",
+        ),
+        (
+            "MODULE_DESCRIPTION",
+            &module.description,
+            "Description of the YANG module, if stated.",
+        ),
+    ] {
+        let value = match value {
+            Some(text) => format!("Some({})", quote(text.trim())),
+            None => "None".to_string(),
+        };
+        push_const(name, "Option<&str>", &value, doc);
+    }
+
+    // Revisions for checking the server's YANG library: the generated module
+    // must be implemented, its imports only need to be listed
+    let revision = revisions.get(&module.name).map(String::as_str);
+    push_const(
+        "MODULE_REVISION",
+        "Option<&str>",
+        &optional(revision),
+        "Revision of the YANG module the code was generated from, if known.",
+    );
+    push_const(
+        "IMPLEMENTED_MODULES",
+        "&[(&str, Option<&str>)]",
+        &format!("&[({}, MODULE_REVISION)]", quote(&module.name)),
+        "Modules the server must implement, with their revisions.",
+    );
+    // The rustconf extensions only annotate the model for code generation.
+    // A module imported under several prefixes is listed once.
+    let mut imports: Vec<String> = Vec::new();
+    for import in &module.imports {
+        if import.module == EXTENSIONS_MODULE {
+            continue;
+        }
+        let revision = import
+            .revision
+            .as_deref()
+            .or_else(|| revisions.get(&import.module).map(String::as_str));
+        let entry = format!("({}, {})", quote(&import.module), optional(revision));
+        if !imports.contains(&entry) {
+            imports.push(entry);
+        }
+    }
+    push_const(
+        "IMPORTED_MODULES",
+        "&[(&str, Option<&str>)]",
+        &format!("&[{}]", imports.join(", ")),
+        "Modules imported by the YANG module, with the revisions used.",
+    );
+
+    for (name, value, doc) in WELL_KNOWN {
+        push_const(name, "&str", &quote(value), doc);
+    }
+
+    // Notifications are delivered on the default stream
+    let streams = if module.notifications.is_empty() {
+        "&[]".to_string()
+    } else {
+        "&[NETCONF_STREAM]".to_string()
+    };
+    push_const(
+        "STREAMS",
+        "&[&str]",
+        &streams,
+        "Notification streams carrying this module's notifications.",
+    );
+
+    let operations: Vec<String> = module
+        .rpcs
+        .iter()
+        .map(|rpc| format!("{}:{}", module.name, rpc.name))
+        .collect();
+    for (rpc, qualified) in module.rpcs.iter().zip(&operations) {
+        push_const(
+            &format!("OPERATION_{}", const_name(&rpc.name)),
+            "&str",
+            &quote(qualified),
+            &format!(
+                "Qualified name of the `{}` RPC, as used in its URL.",
+                rpc.name
+            ),
+        );
+    }
+    push_const(
+        "OPERATIONS",
+        "&[&str]",
+        &list(&operations),
+        "Qualified names of all RPCs defined by the module.",
+    );
+
+    let notifications: Vec<String> = module
+        .notifications
+        .iter()
+        .map(|notification| format!("{}:{}", module.name, notification.name))
+        .collect();
+    for (notification, qualified) in module.notifications.iter().zip(&notifications) {
+        push_const(
+            &format!("NOTIFICATION_{}", const_name(&notification.name)),
+            "&str",
+            &quote(qualified),
+            &format!(
+                "Qualified name of the `{}` notification, as it appears in events.",
+                notification.name
+            ),
+        );
+    }
+    push_const(
+        "NOTIFICATIONS",
+        "&[&str]",
+        &list(&notifications),
+        "Qualified names of all notifications defined by the module.",
+    );
+
+    output
+}
+
+/// Convert a YANG identifier into a SCREAMING_SNAKE_CASE constant name.
+fn const_name(yang_name: &str) -> String {
+    yang_name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn quote(value: &str) -> String {
+    format!("{:?}", value)
+}
+
+fn optional(value: Option<&str>) -> String {
+    match value {
+        Some(value) => format!("Some({})", quote(value)),
+        None => "None".to_string(),
+    }
+}
+
+fn list(values: &[String]) -> String {
+    let items: Vec<String> = values.iter().map(|value| quote(value)).collect();
+    format!("&[{}]", items.join(", "))
+}
@@ -0,0 +1,128 @@
+//! Path accessor generation.
+//!
+//! Emits `get_by_path` and `set_by_path` on the structs of containers and
+//! list entries, so that tooling can read and write nodes by a path string
+//! such as `config/mtu` without knowing the generated types. Paths are
+//! checked against the `schema` module and evaluated on the JSON form of the
+//! struct by `rustconf_runtime::data_path`.
+
+use crate::generator::naming::to_type_name;
+use crate::generator::GeneratorConfig;
+use crate::parser::{DataNode, YangModule};
+
+/// Generate the path accessors of every container and list entry struct of
+/// `module`.
+///
+/// `schema_module` is the path of the generated `schema` module from the
+/// generated types, such as `super::schema`.
+pub(crate) fn generate_data_path_impls(
+    module: &YangModule,
+    config: &GeneratorConfig,
+    schema_module: &str,
+) -> String {
+    let mut output = String::new();
+    push_impls(&mut output, &module.data_nodes, "", config, schema_module);
+    output
+}
+
+/// Push the accessors of the structs generated for `nodes`, whose parent is
+/// at the schema path `parent_path`.
+fn push_impls(
+    output: &mut String,
+    nodes: &[DataNode],
+    parent_path: &str,
+    config: &GeneratorConfig,
+    schema_module: &str,
+) {
+    for node in nodes {
+        let (name, type_name, children) = match node {
+            DataNode::Container(container) => (
+                &container.name,
+                to_type_name(&container.name),
+                &container.children,
+            ),
+            DataNode::List(list) => (
+                &list.name,
+                config.item_type_name(&list.name),
+                &list.children,
+            ),
+            DataNode::Choice(choice) => {
+                for case in &choice.cases {
+                    push_impls(output, &case.data_nodes, parent_path, config, schema_module);
+                }
+                continue;
+            }
+            DataNode::Case(case) => {
+                push_impls(output, &case.data_nodes, parent_path, config, schema_module);
+                continue;
+            }
+            DataNode::Leaf(_)
+            | DataNode::LeafList(_)
+            | DataNode::AnyData(_)
+            | DataNode::Uses(_) => continue,
+        };
+        // Nodes added by other modules' augments are named `module:name`
+        let local_name = name.rsplit(':').next().unwrap_or(name);
+        let path = format!("{}/{}", parent_path, local_name);
+        output.push_str(&generate_impl(&type_name, name, &path, schema_module));
+        output.push('\n');
+        push_impls(output, children, &path, config, schema_module);
+    }
+}
+
+/// Generate the accessors of the struct `type_name` of the node `name` at the
+/// schema path `path`.
+fn generate_impl(type_name: &str, name: &str, path: &str, schema_module: &str) -> String {
+    let schema = format!(
+        "|path| {{\n            {}::SchemaNode::from_error_path(path).map(|node| node.list_keys())\n        }}",
+        schema_module
+    );
+
+    let mut output = String::new();
+    output.push_str(&format!("impl {} {{\n", type_name));
+    output.push_str(&format!(
+        "    /// The value at the relative `path` below this `{}`, if the path is in\n",
+        name
+    ));
+    output.push_str("    /// the schema and a value is set there.\n");
+    output.push_str("    ///\n");
+    output.push_str(
+        "    /// List entries are selected by key as in RESTCONF URLs: `list=key1,key2`.\n",
+    );
+    output.push_str("    #[must_use]\n");
+    output.push_str("    pub fn get_by_path(&self, path: &str) -> Option<serde_json::Value> {\n");
+    output.push_str("        let data = serde_json::to_value(self).ok()?;\n");
+    output.push_str(&format!(
+        "        rustconf_runtime::data_path::get(&data, \"{}\", path, {})\n",
+        path, schema
+    ));
+    output.push_str("    }\n");
+    output.push('\n');
+    output.push_str(&format!(
+        "    /// Set the value at `path` below this `{}`, creating missing\n",
+        name
+    ));
+    output.push_str("    /// containers. A `null` value removes the node.\n");
+    output.push_str("    ///\n");
+    output.push_str("    /// # Errors\n");
+    output.push_str("    ///\n");
+    output.push_str(
+        "    /// Returns an error if the path is not in the schema, selects a list entry\n",
+    );
+    output.push_str("    /// that does not exist, or the value does not fit the node.\n");
+    output.push_str("    pub fn set_by_path(\n");
+    output.push_str("        &mut self,\n");
+    output.push_str("        path: &str,\n");
+    output.push_str("        value: serde_json::Value,\n");
+    output.push_str("    ) -> Result<(), rustconf_runtime::DataPathError> {\n");
+    output.push_str("        let mut data = serde_json::to_value(&*self)?;\n");
+    output.push_str(&format!(
+        "        rustconf_runtime::data_path::set(&mut data, \"{}\", path, value, {})?;\n",
+        path, schema
+    ));
+    output.push_str("        *self = serde_json::from_value(data)?;\n");
+    output.push_str("        Ok(())\n");
+    output.push_str("    }\n");
+    output.push_str("}\n");
+    output
+}
@@ -0,0 +1,30 @@
+//! `date-and-time` values of `ietf-yang-types`.
+//!
+//! Typedef expansion keeps `date-and-time` apart from other strings. With
+//! `DateAndTimeRepresentation::String` it is turned back into the string it
+//! is derived from before any code is generated, so it keeps generating
+//! `String` checked against the typedef's pattern; otherwise it generates the
+//! representation's type, encoded by `rustconf_runtime::date_and_time`.
+
+use crate::generator::{DateAndTimeRepresentation, GeneratorConfig};
+use crate::parser::{TypeSpec, YangModule};
+
+/// The module with `date-and-time` types as the configured representation
+/// needs them.
+pub(crate) fn apply_representation(module: &YangModule, config: &GeneratorConfig) -> YangModule {
+    let mut applied = module.clone();
+    if config.date_and_time_representation != DateAndTimeRepresentation::String {
+        return applied;
+    }
+
+    let _ = crate::parser::visit_module_type_specs(&mut applied, &mut |type_spec| {
+        if let TypeSpec::DateAndTime { pattern } = type_spec {
+            *type_spec = TypeSpec::String {
+                length: None,
+                pattern: pattern.take(),
+            };
+        }
+        Ok(())
+    });
+    applied
+}
@@ -0,0 +1,155 @@
+//! Types for YANG `decimal64` types.
+//!
+//! With `Decimal64Representation::ScaledInteger`, each number of fraction
+//! digits used by the module gets a `Copy` newtype over the value scaled to
+//! an `i64`, such as `Decimal64Fd2` holding `1.5` as `150`. It implements
+//! `rustconf_runtime::Decimal64` and orders, displays, parses and serializes
+//! as a decimal number, in the RFC 7951 string encoding. Range restrictions
+//! are checked by the validated types wrapping it.
+
+use std::collections::BTreeSet;
+
+use crate::parser::{DataNodeVisitor, Leaf, LeafList, TypeSpec, YangModule};
+
+/// Collect the fraction digits of the `decimal64` types used by `module`.
+pub(crate) fn collect_fraction_digits(module: &YangModule) -> BTreeSet<u8> {
+    let mut collector = FractionDigitsCollector {
+        fraction_digits: BTreeSet::new(),
+    };
+
+    for typedef in &module.typedefs {
+        collector.collect_from_typespec(&typedef.type_spec);
+    }
+    let _ = crate::parser::walk_data_nodes(&module.data_nodes, &mut collector);
+    for rpc in &module.rpcs {
+        for nodes in [&rpc.input, &rpc.output].into_iter().flatten() {
+            let _ = crate::parser::walk_data_nodes(nodes, &mut collector);
+        }
+    }
+    for notification in &module.notifications {
+        let _ = crate::parser::walk_data_nodes(&notification.data_nodes, &mut collector);
+    }
+
+    collector.fraction_digits
+}
+
+/// Visitor collecting the fraction digits of leaves and leaf-lists.
+struct FractionDigitsCollector {
+    fraction_digits: BTreeSet<u8>,
+}
+
+impl FractionDigitsCollector {
+    fn collect_from_typespec(&mut self, type_spec: &TypeSpec) {
+        if let TypeSpec::Decimal64 {
+            fraction_digits, ..
+        } = type_spec
+        {
+            self.fraction_digits.insert(*fraction_digits);
+        }
+    }
+}
+
+impl DataNodeVisitor for FractionDigitsCollector {
+    type Error = std::convert::Infallible;
+
+    fn visit_leaf(&mut self, leaf: &Leaf) -> Result<(), Self::Error> {
+        self.collect_from_typespec(&leaf.type_spec);
+        Ok(())
+    }
+
+    fn visit_leaf_list(&mut self, leaf_list: &LeafList) -> Result<(), Self::Error> {
+        self.collect_from_typespec(&leaf_list.type_spec);
+        Ok(())
+    }
+}
+
+/// Generate the scaled-integer type of `decimal64` values with
+/// `fraction_digits` fraction digits.
+pub(crate) fn generate_decimal64_type(fraction_digits: u8, derive_debug: bool) -> String {
+    let type_name = format!("Decimal64Fd{}", fraction_digits);
+    let factor = 10u64.pow(u32::from(fraction_digits));
+    let mut derives = vec![
+        "Clone",
+        "Copy",
+        "Default",
+        "PartialEq",
+        "Eq",
+        "PartialOrd",
+        "Ord",
+        "Hash",
+    ];
+    if derive_debug {
+        derives.insert(0, "Debug");
+    }
+
+    let mut output = String::new();
+    output.push_str(&format!(
+        "/// YANG `decimal64` value with {} fraction digits, held multiplied by {}.\n",
+        fraction_digits, factor
+    ));
+    output.push_str("///\n");
+    output.push_str("/// Serializes as a decimal string, e.g. `\"1.5\"`.\n");
+    output.push_str(&format!("#[derive({})]\n", derives.join(", ")));
+    output.push_str(&format!("pub struct {}(i64);\n\n", type_name));
+
+    output.push_str(&format!("impl {} {{\n", type_name));
+    output.push_str(&format!("    /// The value `scaled` / {}.\n", factor));
+    output.push_str("    pub const fn from_scaled(scaled: i64) -> Self {\n");
+    output.push_str("        Self(scaled)\n");
+    output.push_str("    }\n\n");
+    output.push_str(&format!("    /// The value multiplied by {}.\n", factor));
+    output.push_str("    pub const fn scaled(self) -> i64 {\n");
+    output.push_str("        self.0\n");
+    output.push_str("    }\n");
+    output.push_str("}\n\n");
+
+    output.push_str(&format!(
+        "impl rustconf_runtime::Decimal64 for {} {{\n",
+        type_name
+    ));
+    output.push_str(&format!(
+        "    const FRACTION_DIGITS: u8 = {};\n\n",
+        fraction_digits
+    ));
+    output.push_str("    fn from_scaled(scaled: i64) -> Self {\n");
+    output.push_str("        Self(scaled)\n");
+    output.push_str("    }\n\n");
+    output.push_str("    fn scaled(self) -> i64 {\n");
+    output.push_str("        self.0\n");
+    output.push_str("    }\n");
+    output.push_str("}\n\n");
+
+    output.push_str(&format!("impl std::fmt::Display for {} {{\n", type_name));
+    output.push_str("    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {\n");
+    output.push_str("        rustconf_runtime::decimal64::format(*self, f)\n");
+    output.push_str("    }\n");
+    output.push_str("}\n\n");
+
+    output.push_str(&format!("impl std::str::FromStr for {} {{\n", type_name));
+    output.push_str("    type Err = rustconf_runtime::decimal64::InvalidDecimal64;\n\n");
+    output.push_str("    fn from_str(text: &str) -> Result<Self, Self::Err> {\n");
+    output.push_str("        rustconf_runtime::decimal64::parse(text)\n");
+    output.push_str("    }\n");
+    output.push_str("}\n\n");
+
+    output.push_str(&format!("impl serde::Serialize for {} {{\n", type_name));
+    output.push_str(
+        "    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {\n",
+    );
+    output.push_str("        rustconf_runtime::decimal64::serialize(self, serializer)\n");
+    output.push_str("    }\n");
+    output.push_str("}\n\n");
+
+    output.push_str(&format!(
+        "impl<'de> serde::Deserialize<'de> for {} {{\n",
+        type_name
+    ));
+    output.push_str(
+        "    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {\n",
+    );
+    output.push_str("        rustconf_runtime::decimal64::deserialize(deserializer)\n");
+    output.push_str("    }\n");
+    output.push_str("}\n");
+
+    output
+}
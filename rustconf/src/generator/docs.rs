@@ -0,0 +1,151 @@
+//! Rustdoc generation from YANG text.
+//!
+//! YANG descriptions are free text written for RFCs, not Markdown. Left as
+//! is, `[foo]` becomes a broken intra-doc link, `<name>` an unclosed HTML
+//! tag, URLs trigger the `bare_urls` lint, and the indentation of
+//! continuation lines turns paragraphs into code blocks. The text is
+//! trimmed line by line, URLs are wrapped in angle brackets, paths and other
+//! code-looking words in backticks, and remaining Markdown syntax is escaped.
+
+/// Split YANG text into trimmed, escaped doc comment lines, keeping
+/// paragraph breaks as empty lines.
+pub(crate) fn doc_lines(text: &str) -> Vec<String> {
+    text.trim().lines().map(escape_line).collect()
+}
+
+/// Render YANG text as `///` comments, each line prefixed with `indent`.
+pub(crate) fn rustdoc(text: &str, indent: &str) -> String {
+    let mut rustdoc = String::new();
+    for line in doc_lines(text) {
+        if line.is_empty() {
+            rustdoc.push_str(&format!("{}///\n", indent));
+        } else {
+            rustdoc.push_str(&format!("{}/// {}\n", indent, line));
+        }
+    }
+    rustdoc
+}
+
+/// The description of a YANG node, or a sentence naming the node when it
+/// has none, so generated items pass the `missing_docs` lint.
+///
+/// `kind` is the YANG keyword, e.g. `leaf` or `container`.
+pub(crate) fn node_doc(description: Option<&str>, kind: &str, name: &str) -> String {
+    match description {
+        Some(description) => description.to_string(),
+        None => format!("The `{}` {}.", name, kind),
+    }
+}
+
+/// `doc` followed by a paragraph with the node's `when` condition, if any.
+pub(crate) fn with_when(doc: String, when: Option<&str>) -> String {
+    match when {
+        Some(condition) => {
+            let condition: Vec<&str> = condition.split_whitespace().collect();
+            format!("{}\n\nOnly valid when `{}`.", doc, condition.join(" "))
+        }
+        None => doc,
+    }
+}
+
+/// `doc` followed by a paragraph with the number of entries a list or
+/// leaf-list may hold, from its `min-elements` and `max-elements`, if bounded.
+pub(crate) fn with_elements(doc: String, min_elements: u32, max_elements: Option<u32>) -> String {
+    let entries = |count: u32| match count {
+        1 => "1 entry".to_string(),
+        count => format!("{} entries", count),
+    };
+    let bounds = match (min_elements, max_elements) {
+        (0, None) => return doc,
+        (min, Some(max)) if min == max => format!("Holds exactly {}.", entries(max)),
+        (0, Some(max)) => format!("Holds at most {}.", entries(max)),
+        (min, None) => format!("Holds at least {}.", entries(min)),
+        (min, Some(max)) => format!("Holds {} to {} entries.", min, max),
+    };
+    format!("{}\n\n{}", doc, bounds)
+}
+
+/// Escape one line of YANG text for rustdoc.
+fn escape_line(line: &str) -> String {
+    let line: String = line
+        .trim()
+        .chars()
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .collect();
+
+    let mut in_code = false;
+    let words: Vec<String> = line
+        .split(' ')
+        .map(|word| {
+            // Leave code spans already in the text alone
+            let backticks = word.matches('`').count();
+            if in_code || backticks > 0 {
+                in_code ^= backticks % 2 == 1;
+                return word.to_string();
+            }
+            escape_word(word)
+        })
+        .collect();
+    let mut escaped = words.join(" ");
+
+    // Lines that Markdown reads as headings or horizontal rules
+    let is_rule = escaped.len() >= 3
+        && escaped
+            .chars()
+            .all(|c| matches!(c, '-' | '=' | '*' | '_' | ' '));
+    if escaped.starts_with('#') || is_rule {
+        escaped.insert(0, '\\');
+    }
+    escaped
+}
+
+/// Escape one whitespace-separated word, keeping surrounding punctuation
+/// outside of any added brackets or backticks.
+fn escape_word(word: &str) -> String {
+    let start = word
+        .find(|c: char| !matches!(c, '(' | '"' | '\''))
+        .unwrap_or(word.len());
+    let (lead, rest) = word.split_at(start);
+    let end = rest
+        .trim_end_matches(['.', ',', ';', ':', '!', '?', ')', '"', '\''])
+        .len();
+    let (core, trail) = rest.split_at(end);
+
+    let core = if core.is_empty() {
+        String::new()
+    } else if is_url(core) {
+        format!("<{}>", core)
+    } else if looks_like_code(core) {
+        format!("`{}`", core)
+    } else {
+        let mut escaped = String::with_capacity(core.len());
+        for c in core.chars() {
+            if matches!(c, '[' | ']' | '<' | '>') {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+        escaped
+    };
+    format!("{}{}{}", lead, core, trail)
+}
+
+fn is_url(word: &str) -> bool {
+    ["http://", "https://", "ftp://"]
+        .iter()
+        .any(|scheme| word.starts_with(scheme) && word.len() > scheme.len())
+        && !word.contains(['<', '>'])
+}
+
+/// Whether a word reads as code: a data path, a placeholder such as
+/// `<name>`, an indexed name such as `interface[name='eth0']`, or a Rust or
+/// function-call-like identifier.
+fn looks_like_code(word: &str) -> bool {
+    let indexed = word
+        .find('[')
+        .is_some_and(|i| i > 0 && word[..i].ends_with(|c: char| c.is_alphanumeric()));
+    let path = word.len() > 1
+        && word.starts_with('/')
+        && word[1..].starts_with(|c: char| c.is_alphabetic());
+    word.contains(['<', '>']) || indexed || path || word.contains("::") || word.ends_with("()")
+}
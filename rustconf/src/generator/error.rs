@@ -12,6 +12,14 @@ pub enum GeneratorError {
     #[error("Invalid configuration: {message}")]
     InvalidConfiguration { message: String },
 
+    #[error("Name collision: {first} and {second} both map to the Rust {kind} `{name}`")]
+    NameCollision {
+        kind: &'static str,
+        name: String,
+        first: String,
+        second: String,
+    },
+
     #[error("Code generation error: {0}")]
     CodeGeneration(String),
 
@@ -0,0 +1,117 @@
+//! Removal of nodes that depend on unsupported features.
+//!
+//! When [`GeneratorConfig::enabled_features`] is set, nodes whose
+//! `if-feature` conditions do not hold are removed before any code is
+//! generated, so the generated types only have the fields the server
+//! supports.
+
+use crate::generator::GeneratorConfig;
+use crate::parser::{DataNode, IfFeature, YangModule};
+
+/// The module without the nodes, RPC inputs and outputs and notification
+/// contents whose `if-feature` conditions do not hold.
+///
+/// A feature of the module itself is only supported if it is enabled and its
+/// own `if-feature` conditions hold.
+pub(crate) fn remove_unsupported_nodes(
+    module: &YangModule,
+    config: &GeneratorConfig,
+) -> YangModule {
+    let mut supported = module.clone();
+    if config.enabled_features.is_none() {
+        return supported;
+    }
+
+    let enabled = |name: &str| feature_supported(module, config, name, &mut Vec::new());
+    retain_supported(&mut supported.data_nodes, &enabled);
+    for rpc in &mut supported.rpcs {
+        for nodes in [&mut rpc.input, &mut rpc.output].into_iter().flatten() {
+            retain_supported(nodes, &enabled);
+        }
+    }
+    for notification in &mut supported.notifications {
+        retain_supported(&mut notification.data_nodes, &enabled);
+    }
+    supported
+}
+
+/// Whether the feature `name`, qualified with its module, is supported.
+///
+/// `visiting` holds the features whose conditions are being evaluated, so a
+/// cycle of features depending on each other counts as unsupported.
+fn feature_supported<'m>(
+    module: &'m YangModule,
+    config: &GeneratorConfig,
+    name: &'m str,
+    visiting: &mut Vec<&'m str>,
+) -> bool {
+    if !config.feature_enabled(name) || visiting.contains(&name) {
+        return false;
+    }
+    let Some(feature) = name
+        .strip_prefix(module.name.as_str())
+        .and_then(|rest| rest.strip_prefix(':'))
+        .and_then(|local| module.features.iter().find(|f| f.name == local))
+    else {
+        return true;
+    };
+
+    visiting.push(name);
+    let supported = feature
+        .if_features
+        .iter()
+        .all(|condition| evaluate(condition, module, config, visiting));
+    visiting.pop();
+    supported
+}
+
+/// Evaluate `condition`, checking the features it names recursively.
+fn evaluate<'m>(
+    condition: &'m IfFeature,
+    module: &'m YangModule,
+    config: &GeneratorConfig,
+    visiting: &mut Vec<&'m str>,
+) -> bool {
+    match condition {
+        IfFeature::Feature(name) => feature_supported(module, config, name, visiting),
+        IfFeature::Not(inner) => !evaluate(inner, module, config, visiting),
+        IfFeature::And(left, right) => {
+            evaluate(left, module, config, visiting) && evaluate(right, module, config, visiting)
+        }
+        IfFeature::Or(left, right) => {
+            evaluate(left, module, config, visiting) || evaluate(right, module, config, visiting)
+        }
+    }
+}
+
+/// Remove the nodes among `nodes` and their descendants whose conditions do
+/// not hold.
+fn retain_supported(nodes: &mut Vec<DataNode>, enabled: &dyn Fn(&str) -> bool) {
+    nodes.retain_mut(|node| {
+        if !node
+            .if_features()
+            .iter()
+            .all(|condition| condition.evaluate(enabled))
+        {
+            return false;
+        }
+        match node {
+            DataNode::Container(container) => retain_supported(&mut container.children, enabled),
+            DataNode::List(list) => retain_supported(&mut list.children, enabled),
+            DataNode::Choice(choice) => {
+                choice.cases.retain_mut(|case| {
+                    retain_supported(&mut case.data_nodes, enabled);
+                    case.if_features
+                        .iter()
+                        .all(|condition| condition.evaluate(enabled))
+                });
+            }
+            DataNode::Case(case) => retain_supported(&mut case.data_nodes, enabled),
+            DataNode::Leaf(_)
+            | DataNode::LeafList(_)
+            | DataNode::AnyData(_)
+            | DataNode::Uses(_) => {}
+        }
+        true
+    });
+}
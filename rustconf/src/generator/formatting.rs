@@ -33,6 +33,21 @@ pub fn format_token_stream(tokens: TokenStream) -> Result<String, syn::Error> {
     Ok(formatted)
 }
 
+/// Split a doc comment into the values of one `#[doc]` attribute per line,
+/// so that it is printed as `///` comments.
+fn doc_attr_lines(doc: &str) -> Vec<String> {
+    crate::generator::docs::doc_lines(doc)
+        .into_iter()
+        .map(|line| {
+            if line.is_empty() {
+                line
+            } else {
+                format!(" {}", line)
+            }
+        })
+        .collect()
+}
+
 /// Generate a struct definition with the given name and fields.
 ///
 /// # Arguments
@@ -70,8 +85,9 @@ pub fn generate_struct(
 
     // Build the struct with optional doc comment
     let tokens = if let Some(doc) = doc_comment {
+        let doc = doc_attr_lines(doc);
         quote! {
-            #[doc = #doc]
+            #(#[doc = #doc])*
             #[derive(#(#derive_idents),*)]
             pub struct #struct_name {
                 #(#field_defs),*
@@ -95,6 +111,7 @@ pub fn generate_struct(
 /// * `name` - The name of the struct
 /// * `fields` - A vector of StructField with serde attributes
 /// * `derives` - A vector of trait names to derive
+/// * `serde_attrs` - Container-level serde attributes (e.g., "rename_all = \"kebab-case\"")
 /// * `doc_comment` - Optional documentation comment
 ///
 /// # Returns
@@ -103,6 +120,7 @@ pub fn generate_struct_with_serde(
     name: &str,
     fields: Vec<StructField>,
     derives: Vec<&str>,
+    serde_attrs: Vec<&str>,
     doc_comment: Option<&str>,
 ) -> Result<String, syn::Error> {
     let struct_name = Ident::new(name, proc_macro2::Span::call_site());
@@ -113,6 +131,15 @@ pub fn generate_struct_with_serde(
         .map(|d| Ident::new(d, proc_macro2::Span::call_site()))
         .collect();
 
+    // Build container-level serde attributes
+    let container_attr_tokens: Vec<TokenStream> = serde_attrs
+        .iter()
+        .map(|attr| {
+            let attr_tokens: TokenStream = attr.parse().unwrap_or_else(|_| quote! {});
+            quote! { #[serde(#attr_tokens)] }
+        })
+        .collect();
+
     // Build fields with serde attributes
     let field_defs: Vec<TokenStream> = fields
         .iter()
@@ -132,8 +159,9 @@ pub fn generate_struct_with_serde(
 
             // Add doc comment if present
             if let Some(ref doc) = field.doc_comment {
+                let doc = doc_attr_lines(doc);
                 quote! {
-                    #[doc = #doc]
+                    #(#[doc = #doc])*
                     #(#serde_attrs)*
                     pub #field_ident: #field_type
                 }
@@ -148,9 +176,11 @@ pub fn generate_struct_with_serde(
 
     // Build the struct with optional doc comment
     let tokens = if let Some(doc) = doc_comment {
+        let doc = doc_attr_lines(doc);
         quote! {
-            #[doc = #doc]
+            #(#[doc = #doc])*
             #[derive(#(#derive_idents),*)]
+            #(#container_attr_tokens)*
             pub struct #struct_name {
                 #(#field_defs),*
             }
@@ -158,6 +188,7 @@ pub fn generate_struct_with_serde(
     } else {
         quote! {
             #[derive(#(#derive_idents),*)]
+            #(#container_attr_tokens)*
             pub struct #struct_name {
                 #(#field_defs),*
             }
@@ -255,8 +286,9 @@ pub fn generate_enum(
 
     // Build the enum with optional doc comment
     let tokens = if let Some(doc) = doc_comment {
+        let doc = doc_attr_lines(doc);
         quote! {
-            #[doc = #doc]
+            #(#[doc = #doc])*
             #[derive(#(#derive_idents),*)]
             pub enum #enum_name {
                 #(#variant_defs),*
@@ -322,8 +354,9 @@ pub fn generate_enum_with_serde(
             };
 
             if let Some(ref doc) = variant.doc_comment {
+                let doc = doc_attr_lines(doc);
                 quote! {
-                    #[doc = #doc]
+                    #(#[doc = #doc])*
                     #variant_def
                 }
             } else {
@@ -334,8 +367,9 @@ pub fn generate_enum_with_serde(
 
     // Build the enum with optional doc comment
     let tokens = if let Some(doc) = doc_comment {
+        let doc = doc_attr_lines(doc);
         quote! {
-            #[doc = #doc]
+            #(#[doc = #doc])*
             #[derive(#(#derive_idents),*)]
             #(#serde_attr_tokens)*
             pub enum #enum_name {
@@ -372,8 +406,9 @@ pub fn generate_type_alias(
     let alias_ident = Ident::new(alias_name, proc_macro2::Span::call_site());
 
     let tokens = if let Some(doc) = doc_comment {
+        let doc = doc_attr_lines(doc);
         quote! {
-            #[doc = #doc]
+            #(#[doc = #doc])*
             pub type #alias_ident = #target_type;
         }
     } else {
@@ -555,6 +590,7 @@ mod tests {
             "User",
             fields,
             vec!["Debug", "Serialize", "Deserialize"],
+            vec![],
             Some("A user record"),
         );
 
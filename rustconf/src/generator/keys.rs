@@ -0,0 +1,128 @@
+//! List key struct generation.
+//!
+//! Emits a `{Item}Key` struct for each list with keys, holding the key
+//! leaves of an entry. Keys display as the entry's RESTCONF list instance
+//! fragment (`interface=eth0`), convert into a `String` and parse back from
+//! a fragment, using `rustconf_runtime::list_key`.
+
+use crate::generator::naming::to_field_name;
+use crate::parser::List;
+
+/// Generate the key struct of the list entry type `item_type`.
+///
+/// `key_types` holds the Rust type of each key leaf of `list`, in key order.
+/// Entries get a `key` method when generated types derive `Clone`.
+pub(crate) fn generate_key_items(
+    list: &List,
+    item_type: &str,
+    key_types: &[String],
+    derive_debug: bool,
+    derive_clone: bool,
+) -> String {
+    let key_type = format!("{}Key", item_type);
+    let fields: Vec<(String, &String)> = list
+        .keys
+        .iter()
+        .map(|key| to_field_name(key))
+        .zip(key_types)
+        .collect();
+    let placeholders: Vec<String> = list.keys.iter().map(|key| format!("<{}>", key)).collect();
+
+    let mut output = String::new();
+    output.push_str(&format!("/// Key of a `{}` entry.\n", list.name));
+    output.push_str("///\n");
+    output.push_str(&format!(
+        "/// Displays as the entry's RESTCONF list instance fragment, `{}={}`, with\n",
+        list.name,
+        placeholders.join(",")
+    ));
+    output.push_str("/// each value percent-encoded, and parses from it.\n");
+    let derives: Vec<&str> = [("Debug", derive_debug), ("Clone", derive_clone)]
+        .into_iter()
+        .filter_map(|(derive, enabled)| enabled.then_some(derive))
+        .collect();
+    if !derives.is_empty() {
+        output.push_str(&format!("#[derive({})]\n", derives.join(", ")));
+    }
+    output.push_str(&format!("pub struct {} {{\n", key_type));
+    for ((field, ty), key) in fields.iter().zip(&list.keys) {
+        output.push_str(&format!("    /// The `{}` key leaf.\n", key));
+        output.push_str(&format!("    pub {}: {},\n", field, ty));
+    }
+    output.push_str("}\n");
+
+    if derive_clone {
+        output.push('\n');
+        output.push_str(&format!("impl {} {{\n", item_type));
+        output.push_str("    /// The key of this entry.\n");
+        output.push_str("    #[must_use]\n");
+        output.push_str(&format!("    pub fn key(&self) -> {} {{\n", key_type));
+        output.push_str(&format!("        {} {{\n", key_type));
+        for (field, ty) in &fields {
+            if is_copy(ty) {
+                output.push_str(&format!("            {0}: self.{0},\n", field));
+            } else {
+                output.push_str(&format!("            {0}: self.{0}.clone(),\n", field));
+            }
+        }
+        output.push_str("        }\n");
+        output.push_str("    }\n");
+        output.push_str("}\n");
+    }
+
+    output.push('\n');
+    output.push_str(&format!("impl std::fmt::Display for {} {{\n", key_type));
+    output.push_str("    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {\n");
+    output.push_str("        let values = [\n");
+    for (field, _) in &fields {
+        output.push_str(&format!(
+            "            rustconf_runtime::list_key::key_value(&self.{}),\n",
+            field
+        ));
+    }
+    output.push_str("        ];\n");
+    output.push_str(&format!(
+        "        rustconf_runtime::list_key::write_fragment(f, \"{}\", &values)\n",
+        list.name
+    ));
+    output.push_str("    }\n");
+    output.push_str("}\n");
+
+    output.push('\n');
+    output.push_str(&format!("impl From<{}> for String {{\n", key_type));
+    output.push_str(&format!("    fn from(key: {}) -> Self {{\n", key_type));
+    output.push_str("        key.to_string()\n");
+    output.push_str("    }\n");
+    output.push_str("}\n");
+
+    output.push('\n');
+    output.push_str(&format!("impl std::str::FromStr for {} {{\n", key_type));
+    output.push_str("    type Err = rustconf_runtime::ListKeyError;\n");
+    output.push('\n');
+    output.push_str("    fn from_str(fragment: &str) -> Result<Self, Self::Err> {\n");
+    output.push_str(&format!(
+        "        let values = rustconf_runtime::list_key::parse_fragment(fragment, \"{}\", {})?;\n",
+        list.name,
+        fields.len()
+    ));
+    output.push_str("        Ok(Self {\n");
+    for (index, (field, _)) in fields.iter().enumerate() {
+        output.push_str(&format!(
+            "            {}: rustconf_runtime::list_key::parse_key_value(&values[{}])?,\n",
+            field, index
+        ));
+    }
+    output.push_str("        })\n");
+    output.push_str("    }\n");
+    output.push_str("}\n");
+
+    output
+}
+
+/// Whether `ty` is a primitive type, copied rather than cloned.
+fn is_copy(ty: &str) -> bool {
+    matches!(
+        ty,
+        "bool" | "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64" | "f64"
+    )
+}
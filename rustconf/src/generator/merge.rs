@@ -0,0 +1,184 @@
+//! Merge method generation.
+//!
+//! Emits `merge(&mut self, other: &Self)` on generated structs so that
+//! configuration from several sources (defaults, site overrides, per-device
+//! intent) can be layered before it is sent in a single request.
+
+use crate::generator::naming::to_field_name;
+use crate::parser::DataNode;
+
+/// Generate an `impl` block with `merge` for the struct generated from `children`.
+///
+/// For list entry structs, `keys` names the key leaves; a private `merge_key`
+/// method is generated alongside so that parents can match entries by key.
+pub(crate) fn generate_merge_impl(
+    type_name: &str,
+    children: &[DataNode],
+    keys: Option<&[String]>,
+) -> String {
+    let mut body = String::new();
+    for child in children {
+        push_merge_statement(&mut body, child, keys.unwrap_or_default());
+    }
+
+    let mut output = String::new();
+    output.push_str(&format!("impl {} {{\n", type_name));
+    output.push_str("    /// Merge `other` into `self` following YANG merge semantics.\n");
+    output.push_str("    ///\n");
+    output.push_str(
+        "    /// Leaves set in `other` overwrite those in `self` and unset optional leaves\n",
+    );
+    output.push_str("    /// are left alone, so `other` acts as a patch. Containers are merged\n");
+    output.push_str(
+        "    /// recursively, list entries are merged by key with new entries appended,\n",
+    );
+    output.push_str("    /// and leaf-list values are added if not already present. Choices are\n");
+    output.push_str("    /// replaced as a whole.\n");
+    if body.is_empty() {
+        output.push_str("    pub fn merge(&mut self, _other: &Self) {}\n");
+    } else {
+        output.push_str("    pub fn merge(&mut self, other: &Self) {\n");
+        output.push_str(&body);
+        output.push_str("    }\n");
+    }
+
+    if let Some(keys) = keys.filter(|keys| !keys.is_empty()) {
+        let fields: Vec<String> = keys
+            .iter()
+            .map(|key| format!("&self.{},", to_field_name(key)))
+            .collect();
+        output.push('\n');
+        output.push_str("    /// Serialized key, identifying the entry when lists are merged.\n");
+        // Unused for top-level lists, which have no parent to merge them
+        output.push_str("    #[allow(dead_code)]\n");
+        output.push_str("    fn merge_key(&self) -> String {\n");
+        output.push_str(&format!(
+            "        serde_json::to_string(&({})).unwrap_or_default()\n",
+            fields.join(" ")
+        ));
+        output.push_str("    }\n");
+    }
+
+    output.push_str("}\n");
+    output
+}
+
+/// Push the statement merging the field generated for `node`.
+fn push_merge_statement(body: &mut String, node: &DataNode, keys: &[String]) {
+    match node {
+        DataNode::Leaf(leaf) => {
+            // Entries are only merged when their keys are equal
+            if keys.contains(&leaf.name) {
+                return;
+            }
+            let field = to_field_name(&leaf.name);
+            if leaf.mandatory {
+                push_overwrite(body, &field);
+            } else {
+                push_overwrite_if_set(body, &field);
+            }
+        }
+        DataNode::AnyData(anydata) => {
+            let field = to_field_name(&anydata.name);
+            if anydata.mandatory {
+                push_overwrite(body, &field);
+            } else {
+                push_overwrite_if_set(body, &field);
+            }
+        }
+        DataNode::LeafList(leaf_list) => {
+            let field = to_field_name(&leaf_list.name);
+            body.push_str("        {\n");
+            body.push_str(&format!(
+                "            let mut seen: std::collections::HashSet<String> = self.{}.iter().map(|value| serde_json::to_string(value).unwrap_or_default()).collect();\n",
+                field
+            ));
+            body.push_str(&format!(
+                "            self.{0}.extend(other.{0}.iter().filter(|value| seen.insert(serde_json::to_string(value).unwrap_or_default())).cloned());\n",
+                field
+            ));
+            body.push_str("        }\n");
+        }
+        DataNode::Container(container) => {
+            let field = to_field_name(&container.name);
+            if container.mandatory {
+                body.push_str(&format!("        self.{0}.merge(&other.{0});\n", field));
+            } else {
+                body.push_str(&format!(
+                    "        if let Some(theirs) = &other.{} {{\n",
+                    field
+                ));
+                body.push_str(&format!("            match &mut self.{} {{\n", field));
+                body.push_str("                Some(mine) => mine.merge(theirs),\n");
+                body.push_str(&format!(
+                    "                None => self.{} = Some(theirs.clone()),\n",
+                    field
+                ));
+                body.push_str("            }\n");
+                body.push_str("        }\n");
+            }
+        }
+        DataNode::List(list) => {
+            let field = to_field_name(&list.name);
+            if list.keys.is_empty() {
+                // Entries cannot be matched without keys, so the list is replaced
+                body.push_str(&format!("        if !other.{}.is_empty() {{\n", field));
+                body.push_str(&format!(
+                    "            self.{0}.clone_from(&other.{0});\n",
+                    field
+                ));
+                body.push_str("        }\n");
+            } else {
+                body.push_str("        {\n");
+                body.push_str(&format!(
+                    "            let mut index: std::collections::HashMap<String, usize> = self.{}.iter().enumerate().map(|(i, entry)| (entry.merge_key(), i)).collect();\n",
+                    field
+                ));
+                body.push_str(&format!("            for theirs in &other.{} {{\n", field));
+                body.push_str("                match index.get(&theirs.merge_key()) {\n");
+                body.push_str(&format!(
+                    "                    Some(&i) => self.{}[i].merge(theirs),\n",
+                    field
+                ));
+                body.push_str("                    None => {\n");
+                body.push_str(&format!(
+                    "                        index.insert(theirs.merge_key(), self.{}.len());\n",
+                    field
+                ));
+                body.push_str(&format!(
+                    "                        self.{}.push(theirs.clone());\n",
+                    field
+                ));
+                body.push_str("                    }\n");
+                body.push_str("                }\n");
+                body.push_str("            }\n");
+                body.push_str("        }\n");
+            }
+        }
+        DataNode::Choice(choice) => {
+            let field = to_field_name(&choice.name);
+            if choice.mandatory {
+                push_overwrite(body, &field);
+            } else {
+                push_overwrite_if_set(body, &field);
+            }
+        }
+        DataNode::Case(_) | DataNode::Uses(_) => {}
+    }
+}
+
+fn push_overwrite(body: &mut String, field: &str) {
+    body.push_str(&format!(
+        "        self.{0}.clone_from(&other.{0});\n",
+        field
+    ));
+}
+
+fn push_overwrite_if_set(body: &mut String, field: &str) {
+    body.push_str(&format!("        if other.{}.is_some() {{\n", field));
+    body.push_str(&format!(
+        "            self.{0}.clone_from(&other.{0});\n",
+        field
+    ));
+    body.push_str("        }\n");
+}
@@ -5,6 +5,7 @@ use std::path::PathBuf;
 
 use crate::parser::YangModule;
 
+pub mod api;
 pub mod config;
 pub mod error;
 pub mod formatting;
@@ -13,9 +14,29 @@ pub mod url_builder;
 pub mod validation;
 
 // Sub-generators for modular code generation
+mod anydata;
+mod bits;
+mod cli;
+mod collisions;
+mod constructors;
+mod consts;
+mod data_path;
+mod date_and_time;
+mod decimal64;
+mod docs;
+mod features;
+mod keys;
+mod merge;
+mod must;
+mod netconf;
 mod notifications;
 mod operations;
 mod paths;
+mod payload;
+mod pretty;
+mod resources;
+mod restrictions;
+mod schema;
 mod server_handlers;
 pub(crate) mod server_notifications;
 mod server_registry;
@@ -23,7 +44,10 @@ mod server_router;
 mod server_stubs;
 mod types;
 
-pub use config::{GeneratorConfig, NamespaceMode};
+pub use config::{
+    DateAndTimeRepresentation, Decimal64Representation, GeneratorConfig, Int64Encoding,
+    ItemVisibility, NamespaceMode, NamespaceQualifier, Target, Visibility,
+};
 pub use error::GeneratorError;
 
 /// Lint suppression line emitted at the top of every generated file.
@@ -34,18 +58,23 @@ const GENERATED_FILE_ALLOW: &str =
 /// Code generator that transforms YANG AST into Rust code.
 pub struct CodeGenerator {
     config: GeneratorConfig,
+    /// Latest revision of each loaded module, by module name.
+    revisions: std::collections::HashMap<String, String>,
 }
 
 /// Visitor for collecting validated types from data nodes.
+///
+/// Types are kept sorted by name so they are generated in the same order on
+/// every run.
 struct ValidationTypeCollector<'a> {
-    types: std::collections::HashMap<String, crate::parser::TypeSpec>,
+    types: std::collections::BTreeMap<String, crate::parser::TypeSpec>,
     type_gen: &'a types::TypeGenerator<'a>,
 }
 
 impl<'a> ValidationTypeCollector<'a> {
     fn new(type_gen: &'a types::TypeGenerator<'a>) -> Self {
         Self {
-            types: std::collections::HashMap::new(),
+            types: std::collections::BTreeMap::new(),
             type_gen,
         }
     }
@@ -57,7 +86,7 @@ impl<'a> ValidationTypeCollector<'a> {
         }
     }
 
-    fn into_types(self) -> std::collections::HashMap<String, crate::parser::TypeSpec> {
+    fn into_types(self) -> std::collections::BTreeMap<String, crate::parser::TypeSpec> {
         self.types
     }
 }
@@ -79,15 +108,69 @@ impl<'a> crate::parser::DataNodeVisitor for ValidationTypeCollector<'a> {
 impl CodeGenerator {
     /// Create a new code generator with the given configuration.
     pub fn new(config: GeneratorConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            revisions: std::collections::HashMap::new(),
+        }
+    }
+
+    /// Set the revisions of the generated modules and their imports.
+    ///
+    /// The YANG AST does not carry revisions, so they are taken from the
+    /// parser's module sources. They are emitted as `consts::MODULE_REVISION`,
+    /// `consts::IMPLEMENTED_MODULES` and `consts::IMPORTED_MODULES` for
+    /// checking a server's YANG library; modules without a known revision
+    /// match any revision.
+    pub fn with_module_revisions(
+        mut self,
+        revisions: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        self.revisions.extend(revisions);
+        self
     }
 
     /// Generate Rust code from a YANG module.
     pub fn generate(&self, module: &YangModule) -> Result<GeneratedCode, GeneratorError> {
-        if self.config.modular_output {
-            self.generate_modular(module)
+        let module = &features::remove_unsupported_nodes(module, &self.config);
+        let module = &restrictions::resolve_restricted_typedefs(module)?;
+        let module = &date_and_time::apply_representation(module, &self.config);
+        collisions::check_name_collisions(module, &self.config)?;
+        let mut generated = if self.config.modular_output {
+            self.generate_modular(module)?
         } else {
-            self.generate_single_file(module)
+            self.generate_single_file(module)?
+        };
+        self.inject_user_code(&mut generated);
+        Ok(generated)
+    }
+
+    /// Insert the configured prelude into every file and append the epilogue to the root file.
+    fn inject_user_code(&self, generated: &mut GeneratedCode) {
+        if let Some(prelude) = &self.config.prelude {
+            for file in &mut generated.files {
+                let offset = prelude_offset(&file.content);
+                let mut code = prelude.trim_end().to_string();
+                code.push_str("\n\n");
+                file.content.insert_str(offset, &code);
+            }
+        }
+
+        if let Some(epilogue) = &self.config.epilogue {
+            let root = if self.config.modular_output {
+                self.config.output_dir.join("mod.rs")
+            } else {
+                self.config
+                    .output_dir
+                    .join(format!("{}.rs", self.config.module_name))
+            };
+            if let Some(file) = generated.files.iter_mut().find(|f| f.path == root) {
+                if !file.content.ends_with('\n') {
+                    file.content.push('\n');
+                }
+                file.content.push('\n');
+                file.content.push_str(epilogue.trim_end());
+                file.content.push('\n');
+            }
         }
     }
 
@@ -112,21 +195,35 @@ impl CodeGenerator {
 
     /// Generate code as multiple files (modular mode).
     fn generate_modular(&self, module: &YangModule) -> Result<GeneratedCode, GeneratorError> {
-        let mut files = Vec::new();
-
-        // Generate mod.rs with module declarations and re-exports
-        files.push(self.generate_mod_file(module)?);
-
-        // Generate types.rs with YANG-derived types only
-        files.push(self.generate_types_file(module)?);
+        let mut files = vec![
+            // mod.rs with module declarations and re-exports
+            self.generate_mod_file(module)?,
+            // types.rs with YANG-derived types only
+            self.generate_types_file(module)?,
+            // consts.rs with media types, URL fragments and stream names
+            self.generate_consts_file(module)?,
+        ];
+
+        // Generate schema.rs with the SchemaNode enum if there are data nodes
+        if self.generates_schema(module) {
+            files.push(self.generate_schema_file(module)?);
+        }
 
         // Generate operations.rs with RPC functions (uses rustconf-runtime types)
-        if self.config.enable_restful_rpcs
-            && (!module.rpcs.is_empty() || !module.data_nodes.is_empty())
-        {
+        if self.config.enable_restful_rpcs && self.generates_operations(module) {
             files.push(self.generate_operations_file(module)?);
         }
 
+        // Generate cli.rs with the command-line interface over the RPCs
+        if self.generates_cli(module) {
+            files.push(self.generate_cli_file(module)?);
+        }
+
+        // Generate notifications.rs with notification payload types
+        if self.generates_notifications(module) {
+            files.push(self.generate_notifications_file(module)?);
+        }
+
         // Generate validation.rs if needed
         if self.config.enable_validation {
             files.push(self.generate_validation_file(module)?);
@@ -164,30 +261,80 @@ impl CodeGenerator {
             chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC")
         ));
         content.push('\n');
+        let docs = self.generate_module_docs(module);
+        if !docs.is_empty() {
+            content.push_str(&docs);
+            content.push('\n');
+        }
         content.push_str(GENERATED_FILE_ALLOW);
         content.push('\n');
 
         // Declare submodules
-        content.push_str("pub mod types;\n");
-        if self.config.enable_restful_rpcs
-            && (!module.rpcs.is_empty() || !module.data_nodes.is_empty())
-        {
-            content.push_str("pub mod operations;\n");
+        content.push_str(&format!(
+            "/// Data types of the {} YANG module.\n",
+            module.name
+        ));
+        let visibility = self.config.visibility;
+        content.push_str(&format!("{} mod types;\n", visibility.types.keyword()));
+        content.push_str(&format!(
+            "/// RESTCONF constants for the {} YANG module.\n",
+            module.name
+        ));
+        content.push_str(&format!("{} mod consts;\n", visibility.helpers.keyword()));
+        if self.generates_schema(module) {
+            content.push_str(&format!(
+                "/// Schema nodes of the {} YANG module.\n",
+                module.name
+            ));
+            content.push_str(&format!("{} mod schema;\n", visibility.helpers.keyword()));
+        }
+        if self.config.enable_restful_rpcs && self.generates_operations(module) {
+            content.push_str("/// RESTCONF operations.\n");
+            content.push_str(&format!(
+                "{} mod operations;\n",
+                visibility.operations.keyword()
+            ));
+        }
+        if self.generates_cli(module) {
+            content.push_str(&format!(
+                "/// Command-line interface over the RPCs of the {} YANG module.\n",
+                module.name
+            ));
+            content.push_str(&format!("{} mod cli;\n", visibility.operations.keyword()));
+        }
+        if self.generates_notifications(module) {
+            content.push_str("/// RESTCONF notification types.\n");
+            content.push_str(&format!(
+                "{} mod notifications;\n",
+                visibility.types.keyword()
+            ));
         }
         if self.config.enable_validation {
-            content.push_str("pub mod validation;\n");
+            content.push_str("/// Types enforcing YANG range, length and pattern restrictions.\n");
+            content.push_str(&format!("{} mod validation;\n", visibility.types.keyword()));
         }
         if self.config.enable_server_generation {
+            content.push_str(&format!(
+                "/// RESTCONF server for the {} YANG module.\n",
+                module.name
+            ));
             content.push_str(&format!("pub mod {};\n", self.config.server_output_subdir));
         }
         content.push('\n');
 
         // Re-export commonly used items
-        content.push_str("pub use types::*;\n");
-        if self.config.enable_restful_rpcs
-            && (!module.rpcs.is_empty() || !module.data_nodes.is_empty())
-        {
-            content.push_str("pub use operations::*;\n");
+        content.push_str(&format!("{} use types::*;\n", visibility.types.keyword()));
+        if self.config.enable_restful_rpcs && self.generates_operations(module) {
+            content.push_str(&format!(
+                "{} use operations::*;\n",
+                visibility.operations.keyword()
+            ));
+        }
+        if self.generates_notifications(module) {
+            content.push_str(&format!(
+                "{} use notifications::*;\n",
+                visibility.types.keyword()
+            ));
         }
         if self.config.enable_server_generation {
             content.push_str(&format!(
@@ -197,16 +344,28 @@ impl CodeGenerator {
         }
         content.push('\n');
 
-        // Re-export rustconf-runtime items
-        content.push_str("pub use rustconf_runtime::{\n");
-        content.push_str("    RestconfClient,\n");
-        content.push_str("    HttpTransport,\n");
-        content.push_str("    HttpRequest,\n");
-        content.push_str("    HttpResponse,\n");
-        content.push_str("    HttpMethod,\n");
-        content.push_str("    RpcError,\n");
-        content.push_str("    RequestInterceptor,\n");
-        content.push_str("};\n");
+        // Re-export rustconf-runtime items used by the client functions
+        if self.config.generates(Target::Rpcs) {
+            content.push_str(&format!(
+                "{} use rustconf_runtime::{{\n",
+                visibility.operations.keyword()
+            ));
+            content.push_str("    RestconfClient,\n");
+            content.push_str("    HttpTransport,\n");
+            content.push_str("    HttpRequest,\n");
+            content.push_str("    HttpResponse,\n");
+            content.push_str("    HttpMethod,\n");
+            content.push_str("    RpcError,\n");
+            content.push_str("    ResponseEnvelope,\n");
+            content.push_str("    RequestInterceptor,\n");
+            content.push_str("    Transaction,\n");
+            content.push_str("    with_rollback,\n");
+            if self.config.enable_dyn_client {
+                content.push_str("    DynRestconfClient,\n");
+                content.push_str("    RestconfClientApi,\n");
+            }
+            content.push_str("};\n");
+        }
 
         Ok(GeneratedFile {
             path: self.config.output_dir.join("mod.rs"),
@@ -234,6 +393,25 @@ impl CodeGenerator {
         // Create type generator
         let type_gen = types::TypeGenerator::new(&self.config);
 
+        // Generate the scaled-integer types of decimal64 types
+        for fraction_digits in self.decimal64_fraction_digits(module) {
+            content.push_str(&decimal64::generate_decimal64_type(
+                fraction_digits,
+                self.config.derive_debug,
+            ));
+            content.push('\n');
+        }
+
+        // Generate the types of bits types
+        for (type_name, bits) in bits::collect_bits_types(module, &type_gen) {
+            content.push_str(&bits::generate_bits_type(
+                &type_name,
+                &bits,
+                self.config.derive_debug,
+            ));
+            content.push('\n');
+        }
+
         // Generate typedef type aliases
         for typedef in &module.typedefs {
             content.push_str(&type_gen.generate_typedef(typedef)?);
@@ -241,9 +419,24 @@ impl CodeGenerator {
         }
 
         // Generate type definitions from data nodes
-        for data_node in &module.data_nodes {
-            content.push_str(&type_gen.generate_data_node(data_node, module)?);
-            content.push('\n');
+        if self.config.generates(Target::Types) {
+            for data_node in &module.data_nodes {
+                content.push_str(&type_gen.generate_data_node(data_node, module)?);
+                content.push('\n');
+            }
+        }
+
+        // Path accessors, checked against the schema module
+        if self.generates_schema(module) {
+            content.push_str(&data_path::generate_data_path_impls(
+                module,
+                &self.config,
+                "super::schema",
+            ));
+            content.push_str(&anydata::generate_anydata_registration(
+                module,
+                &self.config,
+            ));
         }
 
         Ok(GeneratedFile {
@@ -252,6 +445,56 @@ impl CodeGenerator {
         })
     }
 
+    /// Generate consts.rs with constants shared by generated code.
+    fn generate_consts_file(&self, module: &YangModule) -> Result<GeneratedFile, GeneratorError> {
+        let mut content = String::new();
+
+        // Add file header
+        content.push_str("// This file is automatically generated by rustconf.\n");
+        content.push_str("// DO NOT EDIT MANUALLY.\n\n");
+        content.push_str(GENERATED_FILE_ALLOW);
+        content.push('\n');
+        content.push_str(&consts::generate_consts_items(module, &self.revisions, ""));
+
+        Ok(GeneratedFile {
+            path: self.config.output_dir.join("consts.rs"),
+            content,
+        })
+    }
+
+    /// Generate schema.rs with the enum of data nodes.
+    fn generate_schema_file(&self, module: &YangModule) -> Result<GeneratedFile, GeneratorError> {
+        let mut content = String::new();
+
+        // Add file header
+        content.push_str("// This file is automatically generated by rustconf.\n");
+        content.push_str("// DO NOT EDIT MANUALLY.\n\n");
+        content.push_str(GENERATED_FILE_ALLOW);
+        content.push('\n');
+        content.push_str(&schema::generate_schema_items(module, ""));
+        if self.config.enable_netconf {
+            content.push('\n');
+            content.push_str(&netconf::generate_netconf_schema_items(
+                module,
+                &self.config,
+                "",
+            ));
+        }
+        if self.config.enable_payload_schema {
+            content.push('\n');
+            content.push_str(&payload::generate_payload_schema_items(
+                module,
+                &self.config,
+                "",
+            ));
+        }
+
+        Ok(GeneratedFile {
+            path: self.config.output_dir.join("schema.rs"),
+            content,
+        })
+    }
+
     /// Generate operations.rs with RPC functions using rustconf-runtime types.
     fn generate_operations_file(
         &self,
@@ -266,9 +509,16 @@ impl CodeGenerator {
         content.push('\n');
 
         // Add use statements (imports from rustconf-runtime)
-        content.push_str("use rustconf_runtime::{RestconfClient, HttpTransport, HttpRequest, HttpMethod, RpcError};\n");
+        content.push_str("use rustconf_runtime::{RestconfClient, HttpTransport, HttpRequest, HttpResponse, HttpMethod, RpcError, ResourceCapabilities, ResponseEnvelope};\n");
+        if self.config.enable_dyn_client {
+            content.push_str("use rustconf_runtime::RestconfClientApi;\n");
+        }
         content.push_str("use serde::{Deserialize, Serialize};\n");
         content.push_str("use super::types::*;\n");
+        if self.config.enable_validation {
+            content.push_str("use super::validation::*;\n");
+        }
+        content.push_str("use super::consts;\n");
         content.push('\n');
 
         // Generate operation functions (uses rustconf-runtime types)
@@ -281,6 +531,55 @@ impl CodeGenerator {
         })
     }
 
+    /// Generate cli.rs with the command-line interface over the RPCs.
+    fn generate_cli_file(&self, module: &YangModule) -> Result<GeneratedFile, GeneratorError> {
+        let mut content = String::new();
+
+        // Add file header
+        content.push_str("// This file is automatically generated by rustconf.\n");
+        content.push_str("// DO NOT EDIT MANUALLY.\n\n");
+        content.push_str(GENERATED_FILE_ALLOW);
+        content.push('\n');
+        content.push_str("use super::operations::operations;\n");
+        content.push('\n');
+        content.push_str(&cli::generate_cli_items(module, &self.config, ""));
+
+        Ok(GeneratedFile {
+            path: self.config.output_dir.join("cli.rs"),
+            content,
+        })
+    }
+
+    /// Generate notifications.rs with notification payload types.
+    fn generate_notifications_file(
+        &self,
+        module: &YangModule,
+    ) -> Result<GeneratedFile, GeneratorError> {
+        let mut content = String::new();
+
+        // Add file header
+        content.push_str("// This file is automatically generated by rustconf.\n");
+        content.push_str("// DO NOT EDIT MANUALLY.\n\n");
+        content.push_str(GENERATED_FILE_ALLOW);
+        content.push('\n');
+
+        // Add use statements
+        content.push_str("use serde::{Deserialize, Serialize};\n");
+        content.push_str("use super::types::*;\n");
+        if self.config.enable_validation {
+            content.push_str("use super::validation::*;\n");
+        }
+        content.push('\n');
+
+        let notif_gen = notifications::NotificationGenerator::new(&self.config);
+        content.push_str(&notif_gen.generate_notifications(module)?);
+
+        Ok(GeneratedFile {
+            path: self.config.output_dir.join("notifications.rs"),
+            content,
+        })
+    }
+
     /// Generate validation.rs with validation types and logic.
     fn generate_validation_file(
         &self,
@@ -296,14 +595,23 @@ impl CodeGenerator {
 
         // Add use statements
         content.push_str("use serde::{Deserialize, Serialize};\n");
+        if !self.decimal64_fraction_digits(module).is_empty() {
+            // Validated decimal64 types wrap the scaled-integer types
+            content.push_str("use super::types::*;\n");
+        }
         content.push('\n');
 
         // Generate ValidationError type
         content.push_str(&validation::generate_validation_error(
             self.config.derive_debug,
             self.config.derive_clone,
+            must::has_element_counts(module),
         ));
         content.push('\n');
+        if must::has_must(&module.data_nodes) {
+            content.push_str(&validation::generate_must_helpers());
+            content.push('\n');
+        }
 
         // Collect all validated types needed
         let validated_types = self.collect_validated_types(module);
@@ -315,6 +623,8 @@ impl CodeGenerator {
                 &type_spec,
                 self.config.derive_debug,
                 self.config.derive_clone,
+                self.config.int64_encoding,
+                self.config.decimal64_representation,
             ) {
                 content.push_str(&validated_type);
                 content.push('\n');
@@ -346,11 +656,16 @@ impl CodeGenerator {
         content.push('\n');
 
         // Declare server submodules
+        content.push_str("/// Handler trait implemented by the server application.\n");
         content.push_str("pub mod handlers;\n");
+        content.push_str("/// Stub handler returning default values, for testing.\n");
         content.push_str("pub mod stubs;\n");
+        content.push_str("/// Routing of RESTCONF requests to handler methods.\n");
         content.push_str("pub mod router;\n");
+        content.push_str("/// Registry of handler functions by path.\n");
         content.push_str("pub mod registry;\n");
         if !module.notifications.is_empty() {
+            content.push_str("/// Publishing notifications to subscribers.\n");
             content.push_str("pub mod notifications;\n");
         }
         content.push('\n');
@@ -491,13 +806,65 @@ impl CodeGenerator {
             content.push('\n');
         }
 
+        // Generate constants shared by generated code
+        content.push_str(&format!(
+            "/// RESTCONF constants for the {} YANG module.\n",
+            module.name
+        ));
+        let visibility = self.config.visibility;
+        content.push_str(visibility.helpers.attributes());
+        content.push_str(&format!("{} mod consts {{\n", visibility.helpers.keyword()));
+        content.push_str(&consts::generate_consts_items(
+            module,
+            &self.revisions,
+            "    ",
+        ));
+        content.push_str("}\n\n");
+
+        // Generate the enum of data nodes
+        if self.generates_schema(module) {
+            content.push_str(&format!(
+                "/// Schema nodes of the {} YANG module.\n",
+                module.name
+            ));
+            content.push_str(visibility.helpers.attributes());
+            content.push_str(&format!("{} mod schema {{\n", visibility.helpers.keyword()));
+            content.push_str(&schema::generate_schema_items(module, "    "));
+            if self.config.enable_netconf {
+                content.push('\n');
+                content.push_str(&netconf::generate_netconf_schema_items(
+                    module,
+                    &self.config,
+                    "    ",
+                ));
+            }
+            if self.config.enable_payload_schema {
+                content.push('\n');
+                content.push_str(&payload::generate_payload_schema_items(
+                    module,
+                    &self.config,
+                    "    ",
+                ));
+            }
+            content.push_str("}\n\n");
+        }
+
         // Generate ValidationError type if validation is enabled
         if self.config.enable_validation {
-            content.push_str(&validation::generate_validation_error(
-                self.config.derive_debug,
-                self.config.derive_clone,
-            ));
+            content.push_str(
+                &visibility
+                    .types
+                    .apply(validation::generate_validation_error(
+                        self.config.derive_debug,
+                        self.config.derive_clone,
+                        must::has_element_counts(module),
+                    )),
+            );
             content.push('\n');
+            if must::has_must(&module.data_nodes) {
+                content.push_str(&visibility.types.apply(validation::generate_must_helpers()));
+                content.push('\n');
+            }
         }
 
         // Collect all validated types needed
@@ -510,8 +877,10 @@ impl CodeGenerator {
                 &type_spec,
                 self.config.derive_debug,
                 self.config.derive_clone,
+                self.config.int64_encoding,
+                self.config.decimal64_representation,
             ) {
-                content.push_str(&validated_type);
+                content.push_str(&visibility.types.apply(validated_type));
                 content.push('\n');
             }
         }
@@ -519,20 +888,59 @@ impl CodeGenerator {
         // Create type generator
         let type_gen = types::TypeGenerator::new(&self.config);
 
+        // Generate the scaled-integer types of decimal64 types
+        for fraction_digits in self.decimal64_fraction_digits(module) {
+            content.push_str(&visibility.types.apply(decimal64::generate_decimal64_type(
+                fraction_digits,
+                self.config.derive_debug,
+            )));
+            content.push('\n');
+        }
+
+        // Generate the types of bits types
+        for (type_name, bits) in bits::collect_bits_types(module, &type_gen) {
+            content.push_str(&visibility.types.apply(bits::generate_bits_type(
+                &type_name,
+                &bits,
+                self.config.derive_debug,
+            )));
+            content.push('\n');
+        }
+
         // Generate typedef type aliases
         for typedef in &module.typedefs {
-            content.push_str(&type_gen.generate_typedef(typedef)?);
+            content.push_str(&visibility.types.apply(type_gen.generate_typedef(typedef)?));
             content.push('\n');
         }
 
         // Generate type definitions from data nodes
-        for data_node in &module.data_nodes {
-            content.push_str(&type_gen.generate_data_node(data_node, module)?);
-            content.push('\n');
+        if self.config.generates(Target::Types) {
+            for data_node in &module.data_nodes {
+                content.push_str(
+                    &visibility
+                        .types
+                        .apply(type_gen.generate_data_node(data_node, module)?),
+                );
+                content.push('\n');
+            }
+        }
+
+        // Path accessors, checked against the schema module
+        if self.generates_schema(module) {
+            content.push_str(&visibility.types.apply(data_path::generate_data_path_impls(
+                module,
+                &self.config,
+                "schema",
+            )));
+            content.push_str(
+                &visibility
+                    .types
+                    .apply(anydata::generate_anydata_registration(module, &self.config)),
+            );
         }
 
         // Generate RPC operations and CRUD operations
-        if !module.rpcs.is_empty() || !module.data_nodes.is_empty() {
+        if self.generates_operations(module) {
             let ops_gen = operations::OperationsGenerator::new(&self.config);
 
             // Note: HTTP abstractions (HttpMethod, HttpRequest, HttpResponse, HttpTransport,
@@ -542,8 +950,14 @@ impl CodeGenerator {
 
             // Only generate RpcError if RESTful RPCs are NOT enabled
             // (when RESTful RPCs are enabled, RpcError comes from rustconf-runtime)
-            if !self.config.enable_restful_rpcs {
-                content.push_str(&ops_gen.generate_rpc_error());
+            if self.config.shared_helpers && !self.config.enable_restful_rpcs {
+                content.push_str(visibility.operations.attributes());
+                content.push_str(&format!(
+                    "{} use rustconf_runtime::RpcError;\n\n",
+                    visibility.operations.keyword()
+                ));
+            } else if !self.config.enable_restful_rpcs {
+                content.push_str(&visibility.operations.apply(ops_gen.generate_rpc_error()));
                 content.push('\n');
             }
 
@@ -551,8 +965,22 @@ impl CodeGenerator {
             content.push('\n');
         }
 
+        // Generate the command-line interface over the RPCs
+        if self.generates_cli(module) {
+            content.push_str(&format!(
+                "/// Command-line interface over the RPCs of the {} YANG module.\n",
+                module.name
+            ));
+            content.push_str(visibility.operations.attributes());
+            content.push_str(&format!("{} mod cli {{\n", visibility.operations.keyword()));
+            content.push_str("    use super::operations;\n");
+            content.push('\n');
+            content.push_str(&cli::generate_cli_items(module, &self.config, "    "));
+            content.push_str("}\n\n");
+        }
+
         // Generate notification types
-        if !module.notifications.is_empty() {
+        if self.generates_notifications(module) {
             let notif_gen = notifications::NotificationGenerator::new(&self.config);
             content.push_str(&notif_gen.generate_notifications(module)?);
             content.push('\n');
@@ -561,6 +989,40 @@ impl CodeGenerator {
         Ok(content)
     }
 
+    /// Whether the schema enum of data nodes is generated.
+    fn generates_schema(&self, module: &YangModule) -> bool {
+        self.config.generates(Target::Types) && !module.data_nodes.is_empty()
+    }
+
+    /// Whether RPC functions, or CRUD functions for data resources, are generated.
+    fn generates_operations(&self, module: &YangModule) -> bool {
+        self.config.generates(Target::Rpcs)
+            && (!module.rpcs.is_empty()
+                || (self.config.generates(Target::Types) && !module.data_nodes.is_empty()))
+    }
+
+    /// Whether the command-line interface over the RPCs is generated.
+    fn generates_cli(&self, module: &YangModule) -> bool {
+        self.config.enable_cli
+            && self.config.enable_restful_rpcs
+            && self.generates_operations(module)
+            && !module.rpcs.is_empty()
+    }
+
+    /// Whether notification payload types are generated.
+    fn generates_notifications(&self, module: &YangModule) -> bool {
+        self.config.generates(Target::Notifications) && !module.notifications.is_empty()
+    }
+
+    /// Fraction digits of the scaled-integer types to generate for the
+    /// `decimal64` types of the module.
+    fn decimal64_fraction_digits(&self, module: &YangModule) -> std::collections::BTreeSet<u8> {
+        match self.config.decimal64_representation {
+            Decimal64Representation::ScaledInteger => decimal64::collect_fraction_digits(module),
+            Decimal64Representation::RustDecimal => std::collections::BTreeSet::new(),
+        }
+    }
+
     /// Collect all validated types needed for the module.
     fn collect_validated_types(
         &self,
@@ -606,6 +1068,19 @@ impl CodeGenerator {
             header.push_str(&format!("// YANG version: {}\n", version_str));
         }
 
+        for (label, text) in [
+            ("Organization", &module.organization),
+            ("Contact", &module.contact),
+            ("Description", &module.description),
+        ] {
+            if let Some(text) = text {
+                header.push_str(&format!("// {}:\n", label));
+                for line in text.lines().map(str::trim).filter(|line| !line.is_empty()) {
+                    header.push_str(&format!("//   {}\n", line));
+                }
+            }
+        }
+
         header.push_str(&format!(
             "// Generated at: {}\n",
             chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC")
@@ -614,6 +1089,56 @@ impl CodeGenerator {
         header
     }
 
+    /// Generate inner doc comments for `mod.rs` from the module's description,
+    /// organization and contact statements.
+    ///
+    /// Single-file output carries these in its header comment instead, since
+    /// it is typically `include!`d where inner attributes are not allowed.
+    fn generate_module_docs(&self, module: &YangModule) -> String {
+        let mut sections = Vec::new();
+
+        if let Some(ref description) = module.description {
+            sections.push(docs::doc_lines(description));
+        }
+        if let Some(ref organization) = module.organization {
+            let mut section = vec!["# Organization".to_string(), String::new()];
+            section.extend(docs::doc_lines(organization));
+            sections.push(section);
+        }
+        if let Some(ref contact) = module.contact {
+            // Contact statements are line-oriented, so keep their line breaks
+            let mut section = vec![
+                "# Contact".to_string(),
+                String::new(),
+                "```text".to_string(),
+            ];
+            section.extend(
+                contact
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty())
+                    .map(str::to_string),
+            );
+            section.push("```".to_string());
+            sections.push(section);
+        }
+
+        let mut docs = String::new();
+        for (i, section) in sections.iter().enumerate() {
+            if i > 0 {
+                docs.push_str("//!\n");
+            }
+            for line in section {
+                if line.is_empty() {
+                    docs.push_str("//!\n");
+                } else {
+                    docs.push_str(&format!("//! {}\n", line));
+                }
+            }
+        }
+        docs
+    }
+
     /// Generate use statements for the module.
     fn generate_use_statements(&self) -> String {
         let mut uses = String::new();
@@ -628,7 +1153,7 @@ impl CodeGenerator {
         }
 
         // Import from rustconf-runtime if RESTful RPCs are enabled
-        if self.config.enable_restful_rpcs {
+        if self.config.enable_restful_rpcs && self.config.generates(Target::Rpcs) {
             uses.push('\n');
             uses.push_str("// Import runtime types from rustconf-runtime\n");
             uses.push_str("#[allow(unused_imports)]\n");
@@ -642,6 +1167,11 @@ impl CodeGenerator {
             uses.push_str("    RequestInterceptor,\n");
             uses.push_str("    ErrorMapper,\n");
             uses.push_str("    DefaultErrorMapper,\n");
+            uses.push_str("    ResourceCapabilities,\n");
+            uses.push_str("    ResponseEnvelope,\n");
+            if self.config.enable_dyn_client {
+                uses.push_str("    RestconfClientApi,\n");
+            }
             uses.push_str("};\n");
         }
 
@@ -671,6 +1201,21 @@ impl CodeGenerator {
     }
 }
 
+/// Byte offset just past the leading comments, inner attributes and blank lines of a
+/// generated file, where user prelude code can be inserted.
+fn prelude_offset(content: &str) -> usize {
+    let mut offset = 0;
+    for line in content.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with("//") || trimmed.starts_with("#![") {
+            offset += line.len();
+        } else {
+            break;
+        }
+    }
+    offset
+}
+
 /// Generated code output.
 #[derive(Debug, Clone)]
 pub struct GeneratedCode {
@@ -0,0 +1,560 @@
+//! `validate` method generation for `must` conditions and element counts.
+//!
+//! Conditions are compiled to Rust expressions over the fields of the
+//! generated structs. A subset of XPath 1.0 is supported: comparisons
+//! (`=`, `!=`, `<`, `<=`, `>`, `>=`) between the node itself (`.` or
+//! `current()`), sibling leaves (`../mtu`), child leaves of a container or
+//! list entry (`mtu`) and literals; existence tests; `and`, `or`, `not()`,
+//! `true()`, `false()` and parentheses. Values are compared through their
+//! JSON encoding, as XPath compares node values: as numbers if either side is
+//! a number, otherwise as strings. Conditions outside the subset are not
+//! checked and are listed in the docs of `validate`.
+//!
+//! `validate` also checks the number of entries of child lists and
+//! leaf-lists against their `min-elements` and `max-elements`, except for
+//! nodes with a `when` condition, which need not hold entries at all.
+
+use crate::generator::naming::to_field_name;
+use crate::parser::{DataNode, Must, YangModule};
+
+/// Whether any of `nodes` or their descendants, outside choices, has a
+/// `must` condition or a checked element count.
+pub(crate) fn has_must(nodes: &[DataNode]) -> bool {
+    nodes.iter().any(|node| match node {
+        DataNode::Container(container) => {
+            !container.must.is_empty() || has_must(&container.children)
+        }
+        DataNode::List(list) => {
+            !list.must.is_empty() || element_bounds(node).is_some() || has_must(&list.children)
+        }
+        DataNode::Leaf(leaf) => !leaf.must.is_empty(),
+        DataNode::LeafList(leaf_list) => {
+            !leaf_list.must.is_empty() || element_bounds(node).is_some()
+        }
+        // Conditions on unknown data are not checked
+        DataNode::AnyData(_) | DataNode::Choice(_) | DataNode::Case(_) | DataNode::Uses(_) => false,
+    })
+}
+
+/// Whether any node of `module` has its number of entries checked, so that
+/// `ValidationError::InvalidElementCount` is needed.
+///
+/// Unlike [`has_must`], this looks into choices and the data of operations
+/// and notifications too, as structs are generated for all of them.
+pub(crate) fn has_element_counts(module: &YangModule) -> bool {
+    fn any(nodes: &[DataNode]) -> bool {
+        nodes.iter().any(|node| {
+            element_bounds(node).is_some()
+                || match node {
+                    DataNode::Container(container) => any(&container.children),
+                    DataNode::List(list) => any(&list.children),
+                    DataNode::Choice(choice) => {
+                        choice.cases.iter().any(|case| any(&case.data_nodes))
+                    }
+                    DataNode::Case(case) => any(&case.data_nodes),
+                    _ => false,
+                }
+        })
+    }
+
+    any(&module.data_nodes)
+        || module.rpcs.iter().any(|rpc| {
+            [&rpc.input, &rpc.output]
+                .into_iter()
+                .any(|nodes| nodes.as_deref().is_some_and(any))
+        })
+        || module
+            .notifications
+            .iter()
+            .any(|notification| any(&notification.data_nodes))
+}
+
+/// Whether `node` is a list or leaf-list whose number of entries is checked,
+/// with its name, `min-elements` and `max-elements`.
+fn element_bounds(node: &DataNode) -> Option<(&str, u32, Option<u32>)> {
+    let (name, min_elements, max_elements, when) = match node {
+        DataNode::List(list) => (&list.name, list.min_elements, list.max_elements, &list.when),
+        DataNode::LeafList(leaf_list) => (
+            &leaf_list.name,
+            leaf_list.min_elements,
+            leaf_list.max_elements,
+            &leaf_list.when,
+        ),
+        _ => return None,
+    };
+    (when.is_none() && (min_elements > 0 || max_elements.is_some())).then_some((
+        name.as_str(),
+        min_elements,
+        max_elements,
+    ))
+}
+
+/// Generate an `impl` block with `validate` and `check_must` for the struct
+/// generated from a container or list entry with the conditions `own` and
+/// the child nodes `children`, or nothing if neither has conditions or
+/// element counts to check.
+pub(crate) fn generate_validate_impl(
+    type_name: &str,
+    own: &[Must],
+    children: &[DataNode],
+) -> String {
+    if own.is_empty() && !has_must(children) {
+        return String::new();
+    }
+
+    let mut body = String::new();
+    let mut unchecked = Vec::new();
+    for must in own {
+        match compile_condition(&must.condition, &Context::Struct, children) {
+            Some(condition) => push_check(&mut body, "        ", &condition, "path", must),
+            None => unchecked.push(&must.condition),
+        }
+    }
+    for child in children {
+        push_child_checks(&mut body, child, children, &mut unchecked);
+    }
+
+    let mut output = String::new();
+    output.push_str(&format!("impl {} {{\n", type_name));
+    output
+        .push_str("    /// Check the `must` conditions and element counts of this node and its\n");
+    output.push_str("    /// descendants.\n");
+    output.push_str("    ///\n");
+    output.push_str(
+        "    /// Returns every condition that does not hold and every list or leaf-list\n",
+    );
+    output.push_str("    /// with too few or too many entries, with the data path of its node\n");
+    output.push_str("    /// relative to this one. Nodes inside choices are not checked.\n");
+    if !unchecked.is_empty() {
+        output.push_str("    ///\n");
+        output.push_str("    /// Conditions beyond simple comparisons are not checked:\n");
+        for condition in &unchecked {
+            output.push_str(&format!(
+                "    /// - `{}`\n",
+                condition.split_whitespace().collect::<Vec<_>>().join(" ")
+            ));
+        }
+    }
+    output.push_str("    pub fn validate(&self) -> Result<(), Vec<ValidationError>> {\n");
+    output.push_str("        let mut errors = Vec::new();\n");
+    output.push_str("        self.check_must(\"\", &mut errors);\n");
+    output.push_str("        if errors.is_empty() {\n");
+    output.push_str("            Ok(())\n");
+    output.push_str("        } else {\n");
+    output.push_str("            Err(errors)\n");
+    output.push_str("        }\n");
+    output.push_str("    }\n");
+    output.push('\n');
+    output.push_str(
+        "    /// Check the `must` conditions and element counts of the node at `path`.\n",
+    );
+    output.push_str(
+        "    pub(crate) fn check_must(&self, path: &str, errors: &mut Vec<ValidationError>) {\n",
+    );
+    output.push_str(&body);
+    output.push_str("    }\n");
+    output.push_str("}\n");
+    // Compiled conditions make long lines
+    match syn::parse_file(&output) {
+        Ok(file) => prettyplease::unparse(&file),
+        Err(_) => output,
+    }
+}
+
+/// Push the checks of the conditions of `child` and of its descendants.
+fn push_child_checks<'m>(
+    body: &mut String,
+    child: &'m DataNode,
+    siblings: &[DataNode],
+    unchecked: &mut Vec<&'m String>,
+) {
+    if let Some((name, min_elements, max_elements)) = element_bounds(child) {
+        push_count_checks(body, name, min_elements, max_elements);
+    }
+    match child {
+        DataNode::Leaf(leaf) if !leaf.must.is_empty() => {
+            let field = to_field_name(&leaf.name);
+            let context = Context::Leaf(format!("&self.{}", field));
+            let path = format!("&format!(\"{{}}/{}\", path)", leaf.name);
+            for must in &leaf.must {
+                match compile_condition(&must.condition, &context, siblings) {
+                    // Conditions of unset leaves do not apply
+                    Some(condition) => push_check(
+                        body,
+                        "        ",
+                        &format!(
+                            "!must_exists(&must_value(&self.{})) || ({})",
+                            field, condition
+                        ),
+                        &path,
+                        must,
+                    ),
+                    None => unchecked.push(&must.condition),
+                }
+            }
+        }
+        DataNode::LeafList(leaf_list) if !leaf_list.must.is_empty() => {
+            let field = to_field_name(&leaf_list.name);
+            let context = Context::Leaf("value".to_string());
+            let path = format!("&format!(\"{{}}/{}\", path)", leaf_list.name);
+            let mut checks = String::new();
+            for must in &leaf_list.must {
+                match compile_condition(&must.condition, &context, siblings) {
+                    Some(condition) => {
+                        push_check(&mut checks, "            ", &condition, &path, must)
+                    }
+                    None => unchecked.push(&must.condition),
+                }
+            }
+            if !checks.is_empty() {
+                body.push_str(&format!("        for value in &self.{} {{\n", field));
+                body.push_str(&checks);
+                body.push_str("        }\n");
+            }
+        }
+        DataNode::Container(container)
+            if !container.must.is_empty() || has_must(&container.children) =>
+        {
+            let field = to_field_name(&container.name);
+            let path = format!("&format!(\"{{}}/{}\", path)", container.name);
+            if container.mandatory {
+                body.push_str(&format!(
+                    "        self.{}.check_must({}, errors);\n",
+                    field, path
+                ));
+            } else {
+                body.push_str(&format!(
+                    "        if let Some(child) = &self.{} {{\n",
+                    field
+                ));
+                body.push_str(&format!(
+                    "            child.check_must({}, errors);\n",
+                    path
+                ));
+                body.push_str("        }\n");
+            }
+        }
+        DataNode::List(list) if !list.must.is_empty() || has_must(&list.children) => {
+            let keys: Vec<String> = list
+                .keys
+                .iter()
+                .map(|key| format!("(\"{}\", must_value(&entry.{}))", key, to_field_name(key)))
+                .collect();
+            body.push_str(&format!(
+                "        for entry in &self.{} {{\n",
+                to_field_name(&list.name)
+            ));
+            body.push_str(&format!(
+                "            let predicate = must_predicate(&[{}]);\n",
+                keys.join(", ")
+            ));
+            body.push_str(&format!(
+                "            entry.check_must(&format!(\"{{}}/{}{{}}\", path, predicate), errors);\n",
+                list.name
+            ));
+            body.push_str("        }\n");
+        }
+        _ => {}
+    }
+}
+
+/// Push the statements reporting the list or leaf-list `name` if it holds
+/// fewer than `min_elements` or more than `max_elements` entries.
+fn push_count_checks(body: &mut String, name: &str, min_elements: u32, max_elements: Option<u32>) {
+    let field = to_field_name(name);
+    let bounds = [
+        (min_elements > 0).then(|| {
+            // `len() < 1` trips clippy's `len_zero` lint
+            let condition = match min_elements {
+                1 => format!("self.{}.is_empty()", field),
+                min => format!("self.{}.len() < {}", field, min),
+            };
+            (condition, format!("min-elements {}", min_elements))
+        }),
+        max_elements.map(|max| {
+            (
+                format!("self.{}.len() > {}", field, max),
+                format!("max-elements {}", max),
+            )
+        }),
+    ];
+    for (condition, constraint) in bounds.into_iter().flatten() {
+        body.push_str(&format!("        if {} {{\n", condition));
+        body.push_str(&format!(
+            "            errors.push(ValidationError::InvalidElementCount {{ path: format!(\"{{}}/{}\", path), count: self.{}.len(), constraint: {:?}.to_string() }});\n",
+            name, field, constraint
+        ));
+        body.push_str("        }\n");
+    }
+}
+
+/// Push the statement reporting `must` at `path` unless `condition` holds.
+fn push_check(body: &mut String, indent: &str, condition: &str, path: &str, must: &Must) {
+    body.push_str(&format!("{}if !({}) {{\n", indent, condition));
+    body.push_str(&format!(
+        "{}    errors.push(must_violation({}, {:?}, {:?}, {:?}));\n",
+        indent,
+        path,
+        must.condition
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" "),
+        must.error_message,
+        must.error_app_tag
+    ));
+    body.push_str(&format!("{}}}\n", indent));
+}
+
+/// Node a condition is evaluated for.
+enum Context {
+    /// A container or list entry; names refer to its children.
+    Struct,
+    /// A leaf or leaf-list value, given as a Rust expression borrowing it;
+    /// `..` refers to its parent.
+    Leaf(String),
+}
+
+/// Compile `condition` to a Rust `bool` expression, or `None` if it is
+/// outside the supported subset or names nodes without fields.
+fn compile_condition(condition: &str, context: &Context, children: &[DataNode]) -> Option<String> {
+    let tokens = tokenize(condition)?;
+    let mut parser = Parser {
+        tokens: &tokens,
+        position: 0,
+        context,
+        children,
+    };
+    let expression = parser.or()?;
+    (parser.position == tokens.len()).then_some(expression)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum XPathToken {
+    Name(String),
+    Number(String),
+    Literal(String),
+    Operator(&'static str),
+    LeftParen,
+    RightParen,
+}
+
+fn tokenize(condition: &str) -> Option<Vec<XPathToken>> {
+    let mut tokens = Vec::new();
+    let chars: Vec<char> = condition.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            c if c.is_whitespace() => i += 1,
+            '(' => {
+                tokens.push(XPathToken::LeftParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(XPathToken::RightParen);
+                i += 1;
+            }
+            '\'' | '"' => {
+                let end = chars[i + 1..].iter().position(|&d| d == c)? + i + 1;
+                tokens.push(XPathToken::Literal(chars[i + 1..end].iter().collect()));
+                i = end + 1;
+            }
+            '=' => {
+                tokens.push(XPathToken::Operator("="));
+                i += 1;
+            }
+            '!' | '<' | '>' => {
+                let equals = chars.get(i + 1) == Some(&'=');
+                let operator = match (c, equals) {
+                    ('!', true) => "!=",
+                    ('<', true) => "<=",
+                    ('>', true) => ">=",
+                    ('<', false) => "<",
+                    ('>', false) => ">",
+                    _ => return None,
+                };
+                tokens.push(XPathToken::Operator(operator));
+                i += if equals { 2 } else { 1 };
+            }
+            c if c.is_ascii_digit() || (c == '-' && starts_operand(&tokens)) => {
+                let start = i;
+                i += 1;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                tokens.push(XPathToken::Number(chars[start..i].iter().collect()));
+            }
+            c if c.is_alphabetic() || c == '_' || c == '.' || c == '/' => {
+                let start = i;
+                while i < chars.len()
+                    && (chars[i].is_alphanumeric()
+                        || matches!(chars[i], '_' | '-' | '.' | ':' | '/'))
+                {
+                    i += 1;
+                }
+                tokens.push(XPathToken::Name(chars[start..i].iter().collect()));
+            }
+            _ => return None,
+        }
+    }
+    Some(tokens)
+}
+
+/// Whether the next token starts an operand, so a `-` is a sign.
+fn starts_operand(tokens: &[XPathToken]) -> bool {
+    match tokens.last() {
+        None | Some(XPathToken::Operator(_)) | Some(XPathToken::LeftParen) => true,
+        Some(XPathToken::Name(name)) => name == "and" || name == "or",
+        _ => false,
+    }
+}
+
+/// Recursive descent over the supported subset, emitting Rust expressions.
+struct Parser<'a> {
+    tokens: &'a [XPathToken],
+    position: usize,
+    context: &'a Context,
+    children: &'a [DataNode],
+}
+
+/// An operand of a comparison.
+enum Operand {
+    /// A node, as a `serde_json::Value` expression.
+    Node(String),
+    /// A literal, as a `serde_json::Value` expression.
+    Literal(String),
+    /// A boolean expression.
+    Boolean(String),
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&XPathToken> {
+        self.tokens.get(self.position)
+    }
+
+    fn next_is_name(&self, name: &str) -> bool {
+        matches!(self.peek(), Some(XPathToken::Name(n)) if n == name)
+    }
+
+    fn or(&mut self) -> Option<String> {
+        let mut expression = self.and()?;
+        while self.next_is_name("or") {
+            self.position += 1;
+            expression = format!("{} || {}", expression, self.and()?);
+        }
+        Some(expression)
+    }
+
+    fn and(&mut self) -> Option<String> {
+        let mut expression = self.comparison()?;
+        while self.next_is_name("and") {
+            self.position += 1;
+            expression = format!("{} && {}", expression, self.comparison()?);
+        }
+        Some(expression)
+    }
+
+    fn comparison(&mut self) -> Option<String> {
+        let left = self.operand()?;
+        let Some(XPathToken::Operator(operator)) = self.peek().cloned() else {
+            return match left {
+                Operand::Node(value) => Some(format!("must_exists(&{})", value)),
+                Operand::Boolean(expression) => Some(expression),
+                // A non-empty string or non-zero number is true, but no one writes that
+                Operand::Literal(_) => None,
+            };
+        };
+        self.position += 1;
+        let right = self.operand()?;
+        match (left, right) {
+            (
+                Operand::Node(left) | Operand::Literal(left),
+                Operand::Node(right) | Operand::Literal(right),
+            ) => Some(format!(
+                "must_compare(&{}, {:?}, &{})",
+                left, operator, right
+            )),
+            _ => None,
+        }
+    }
+
+    fn operand(&mut self) -> Option<Operand> {
+        let token = self.peek()?.clone();
+        self.position += 1;
+        match token {
+            XPathToken::Literal(text) => Some(Operand::Literal(format!(
+                "serde_json::Value::from({:?})",
+                text
+            ))),
+            XPathToken::Number(number) => {
+                number.parse::<f64>().ok()?;
+                let number = if number.contains('.') {
+                    number
+                } else {
+                    format!("{}_i64", number)
+                };
+                Some(Operand::Literal(format!(
+                    "serde_json::Value::from({})",
+                    number
+                )))
+            }
+            XPathToken::LeftParen => {
+                let expression = self.or()?;
+                self.expect(XPathToken::RightParen)?;
+                Some(Operand::Boolean(format!("({})", expression)))
+            }
+            XPathToken::Name(name) if self.peek() == Some(&XPathToken::LeftParen) => {
+                self.position += 1;
+                match name.as_str() {
+                    "not" => {
+                        let expression = self.or()?;
+                        self.expect(XPathToken::RightParen)?;
+                        Some(Operand::Boolean(format!("!({})", expression)))
+                    }
+                    "true" | "false" => {
+                        self.expect(XPathToken::RightParen)?;
+                        Some(Operand::Boolean(name))
+                    }
+                    "current" => {
+                        self.expect(XPathToken::RightParen)?;
+                        self.node(".").map(Operand::Node)
+                    }
+                    _ => None,
+                }
+            }
+            XPathToken::Name(name) => self.node(&name).map(Operand::Node),
+            XPathToken::Operator(_) | XPathToken::RightParen => None,
+        }
+    }
+
+    fn expect(&mut self, token: XPathToken) -> Option<()> {
+        (self.peek() == Some(&token)).then(|| self.position += 1)
+    }
+
+    /// The value of the node at `path`, relative to the context node.
+    fn node(&self, path: &str) -> Option<String> {
+        let steps: Vec<&str> = path
+            .split('/')
+            .map(|step| step.split_once(':').map_or(step, |(_, name)| name))
+            .collect();
+        let name = match (self.context, steps.as_slice()) {
+            (Context::Leaf(value), ["."]) => return Some(format!("must_value({})", value)),
+            (Context::Leaf(_), ["..", name]) => name,
+            (Context::Struct, [name]) | (Context::Struct, [".", name]) => name,
+            _ => return None,
+        };
+        self.children
+            .iter()
+            .find(|child| match child {
+                DataNode::Leaf(leaf) => leaf.name == *name,
+                DataNode::LeafList(leaf_list) => leaf_list.name == *name,
+                DataNode::Container(container) => container.name == *name,
+                DataNode::List(list) => list.name == *name,
+                DataNode::AnyData(_)
+                | DataNode::Choice(_)
+                | DataNode::Case(_)
+                | DataNode::Uses(_) => false,
+            })
+            .map(|_| format!("must_value(&self.{})", to_field_name(name)))
+    }
+}
@@ -13,6 +13,7 @@
 /// assert_eq!(to_snake_case("IPAddress"), "ip_address");
 /// assert_eq!(to_snake_case("already_snake"), "already_snake");
 /// assert_eq!(to_snake_case("HTTPSConnection"), "https_connection");
+/// assert_eq!(to_snake_case("ext:mtu"), "ext_mtu");
 /// ```
 pub fn to_snake_case(identifier: &str) -> String {
     if identifier.is_empty() {
@@ -27,7 +28,7 @@ pub fn to_snake_case(identifier: &str) -> String {
     while let Some(ch) = chars.next() {
         match ch {
             // Convert delimiters to underscores
-            '-' | ' ' | '.' => {
+            '-' | ' ' | '.' | ':' => {
                 if !result.is_empty() && !prev_was_delimiter {
                     result.push('_');
                     prev_was_delimiter = true;
@@ -83,6 +84,7 @@ pub fn to_snake_case(identifier: &str) -> String {
 /// assert_eq!(to_pascal_case("ip-address"), "IpAddress");
 /// assert_eq!(to_pascal_case("already_snake"), "AlreadySnake");
 /// assert_eq!(to_pascal_case("PascalCase"), "PascalCase");
+/// assert_eq!(to_pascal_case("ext:tunnel"), "ExtTunnel");
 /// ```
 pub fn to_pascal_case(identifier: &str) -> String {
     if identifier.is_empty() {
@@ -95,7 +97,7 @@ pub fn to_pascal_case(identifier: &str) -> String {
     for ch in identifier.chars() {
         match ch {
             // Delimiters trigger capitalization of next character
-            '-' | '_' | ' ' | '.' => {
+            '-' | '_' | ' ' | '.' | ':' => {
                 capitalize_next = true;
             }
             // Capitalize or keep as-is
@@ -148,16 +150,30 @@ fn is_rust_keyword(identifier: &str) -> bool {
         "abstract", "become", "box", "do", "final", "macro", "override", "priv", "typeof",
         "unsized", "virtual", "yield",
         // Keywords in specific contexts (we escape these to be safe)
-        "async", "await", "dyn", "try", // Edition-specific keywords
+        "async", "await", "dyn", "try", "gen", // Edition-specific keywords
         "union",
     ];
 
     KEYWORDS.contains(&identifier)
 }
 
+/// Escape an identifier that does not start with a letter or underscore.
+///
+/// Names such as enum values may start with a digit (`10g`), which Rust
+/// identifiers cannot, so an underscore is prepended. Names left empty after
+/// conversion, such as a lone hyphen, become `_empty`.
+fn escape_leading_digit(identifier: String) -> String {
+    match identifier.chars().next() {
+        None => "_empty".to_string(),
+        Some(c) if c.is_ascii_digit() => format!("_{}", identifier),
+        Some(_) => identifier,
+    }
+}
+
 /// Convert a YANG identifier to a safe Rust field name.
 ///
-/// This combines snake_case conversion with keyword escaping.
+/// This combines snake_case conversion with keyword escaping. Names starting
+/// with a digit are prefixed with an underscore.
 ///
 /// # Examples
 ///
@@ -166,10 +182,11 @@ fn is_rust_keyword(identifier: &str) -> bool {
 /// assert_eq!(to_field_name("interface-type"), "interface_type");
 /// assert_eq!(to_field_name("type"), "type_");
 /// assert_eq!(to_field_name("match-rule"), "match_rule");
+/// assert_eq!(to_field_name("2g-settings"), "_2g_settings");
 /// ```
 pub fn to_field_name(identifier: &str) -> String {
     let snake = to_snake_case(identifier);
-    escape_keyword(&snake)
+    escape_leading_digit(escape_keyword(&snake))
 }
 
 /// Convert a YANG identifier to a safe Rust type name.
@@ -183,10 +200,132 @@ pub fn to_field_name(identifier: &str) -> String {
 /// # use rustconf::generator::naming::to_type_name;
 /// assert_eq!(to_type_name("interface-config"), "InterfaceConfig");
 /// assert_eq!(to_type_name("ip-address"), "IpAddress");
+/// assert_eq!(to_type_name("10g"), "_10g");
 /// ```
 pub fn to_type_name(identifier: &str) -> String {
     let pascal = to_pascal_case(identifier);
-    escape_keyword(&pascal)
+    escape_leading_digit(escape_keyword(&pascal))
+}
+
+/// Plurals that the suffix rules of [`to_item_type_name`] get wrong, as
+/// `(plural, singular)`.
+const IRREGULAR_PLURALS: &[(&str, &str)] = &[
+    ("aliases", "alias"),
+    ("analyses", "analysis"),
+    ("appendices", "appendix"),
+    ("axes", "axis"),
+    ("buses", "bus"),
+    ("caches", "cache"),
+    ("children", "child"),
+    ("cookies", "cookie"),
+    ("criteria", "criterion"),
+    ("feet", "foot"),
+    ("halves", "half"),
+    ("indices", "index"),
+    ("leaves", "leaf"),
+    ("lives", "life"),
+    ("matrices", "matrix"),
+    ("men", "man"),
+    ("menus", "menu"),
+    ("mice", "mouse"),
+    ("movies", "movie"),
+    ("people", "person"),
+    ("radii", "radius"),
+    ("shelves", "shelf"),
+    ("statuses", "status"),
+    ("teeth", "tooth"),
+    ("vertices", "vertex"),
+    ("viruses", "virus"),
+    ("women", "woman"),
+];
+
+/// Words ending in `s` that are already singular, or have no singular.
+const SINGULAR_WORDS: &[&str] = &[
+    "apparatus",
+    "analytics",
+    "atlas",
+    "bias",
+    "canvas",
+    "diagnostics",
+    "dns",
+    "ethics",
+    "gas",
+    "lens",
+    "news",
+    "os",
+    "physics",
+    "qos",
+    "series",
+    "species",
+    "statistics",
+];
+
+/// Convert a YANG list name to the Rust type name of its entries.
+///
+/// The last word of the name is singularized: irregular plurals come from a
+/// dictionary, words that are not plural (`status`, `chassis`, `address`)
+/// are left alone, and regular plurals lose `s`, `es` or `ies`. Lists whose
+/// names are not plural keep their type name.
+///
+/// # Examples
+///
+/// ```
+/// # use rustconf::generator::naming::to_item_type_name;
+/// assert_eq!(to_item_type_name("interfaces"), "Interface");
+/// assert_eq!(to_item_type_name("ip-addresses"), "IpAddress");
+/// assert_eq!(to_item_type_name("policies"), "Policy");
+/// assert_eq!(to_item_type_name("people"), "Person");
+/// assert_eq!(to_item_type_name("chassis"), "Chassis");
+/// assert_eq!(to_item_type_name("interface-status"), "InterfaceStatus");
+/// assert_eq!(to_item_type_name("route"), "Route");
+/// ```
+pub fn to_item_type_name(list_name: &str) -> String {
+    let type_name = to_type_name(list_name);
+    // The last word starts at the last uppercase letter of the PascalCase name
+    let start = type_name
+        .rfind(|c: char| c.is_ascii_uppercase())
+        .unwrap_or(0);
+    let (prefix, word) = type_name.split_at(start);
+    format!("{}{}", prefix, singularize(word))
+}
+
+/// Singularize a single capitalized word.
+fn singularize(word: &str) -> String {
+    let lower = word.to_ascii_lowercase();
+    if let Some((_, singular)) = IRREGULAR_PLURALS
+        .iter()
+        .find(|(plural, _)| *plural == lower)
+    {
+        // Keep the capitalization of the first letter
+        let mut chars = singular.chars();
+        return match (word.chars().next(), chars.next()) {
+            (Some(first), Some(singular_first)) if first.is_uppercase() => {
+                format!("{}{}", singular_first.to_ascii_uppercase(), chars.as_str())
+            }
+            _ => singular.to_string(),
+        };
+    }
+    if SINGULAR_WORDS.contains(&lower.as_str())
+        || !lower.ends_with('s')
+        || lower.len() < 3
+        || ["ss", "us", "is"]
+            .iter()
+            .any(|suffix| lower.ends_with(suffix))
+    {
+        return word.to_string();
+    }
+
+    let stem = &word[..word.len() - 1];
+    if lower.ends_with("ies") {
+        format!("{}y", &word[..word.len() - 3])
+    } else if ["sses", "xes", "ches", "shes", "zzes"]
+        .iter()
+        .any(|suffix| lower.ends_with(suffix))
+    {
+        word[..word.len() - 2].to_string()
+    } else {
+        stem.to_string()
+    }
 }
 
 #[cfg(test)]
@@ -396,7 +535,7 @@ mod tests {
         // Test reserved keywords
         let reserved = vec![
             "abstract", "become", "box", "do", "final", "macro", "override", "priv", "typeof",
-            "unsized", "virtual", "yield", "async", "await", "dyn", "try", "union",
+            "unsized", "virtual", "yield", "async", "await", "dyn", "try", "gen", "union",
         ];
 
         for keyword in reserved {
@@ -412,6 +551,27 @@ mod tests {
         assert_eq!(to_field_name("IMPL"), "impl_");
     }
 
+    #[test]
+    fn test_names_starting_with_digits() {
+        assert_eq!(to_field_name("2g-settings"), "_2g_settings");
+        assert_eq!(to_field_name("100m"), "_100m");
+        assert_eq!(to_type_name("2g-settings"), "_2gSettings");
+        assert_eq!(to_type_name("10g"), "_10g");
+        // Digits after the first character are left alone
+        assert_eq!(to_field_name("ipv6"), "ipv6");
+        assert_eq!(to_type_name("ipv6"), "Ipv6");
+    }
+
+    #[test]
+    fn test_names_with_leading_and_trailing_hyphens() {
+        assert_eq!(to_field_name("-x"), "x");
+        assert_eq!(to_field_name("x-"), "x_");
+        assert_eq!(to_type_name("x-"), "X");
+        assert_eq!(to_field_name("-2g"), "_2g");
+        assert_eq!(to_field_name("-"), "_empty");
+        assert_eq!(to_type_name("-"), "_empty");
+    }
+
     #[test]
     fn test_to_snake_case_consecutive_uppercase() {
         // Test handling of consecutive uppercase letters
@@ -443,4 +603,37 @@ mod tests {
         assert_eq!(to_pascal_case("http-server.port"), "HttpServerPort");
         assert_eq!(to_pascal_case("ip-address_v4"), "IpAddressV4");
     }
+
+    #[test]
+    fn test_to_item_type_name_regular_plurals() {
+        assert_eq!(to_item_type_name("interfaces"), "Interface");
+        assert_eq!(to_item_type_name("dns-servers"), "DnsServer");
+        assert_eq!(to_item_type_name("policies"), "Policy");
+        assert_eq!(to_item_type_name("ip-addresses"), "IpAddress");
+        assert_eq!(to_item_type_name("prefixes"), "Prefix");
+        assert_eq!(to_item_type_name("switches"), "Switch");
+        assert_eq!(to_item_type_name("responses"), "Response");
+    }
+
+    #[test]
+    fn test_to_item_type_name_irregular_plurals() {
+        assert_eq!(to_item_type_name("people"), "Person");
+        assert_eq!(to_item_type_name("child-nodes"), "ChildNode");
+        assert_eq!(to_item_type_name("children"), "Child");
+        assert_eq!(to_item_type_name("route-leaves"), "RouteLeaf");
+        assert_eq!(to_item_type_name("statuses"), "Status");
+        assert_eq!(to_item_type_name("indices"), "Index");
+    }
+
+    #[test]
+    fn test_to_item_type_name_keeps_singular_names() {
+        assert_eq!(to_item_type_name("status"), "Status");
+        assert_eq!(to_item_type_name("chassis"), "Chassis");
+        assert_eq!(to_item_type_name("address"), "Address");
+        assert_eq!(to_item_type_name("bus"), "Bus");
+        assert_eq!(to_item_type_name("series"), "Series");
+        assert_eq!(to_item_type_name("dns"), "Dns");
+        assert_eq!(to_item_type_name("route"), "Route");
+        assert_eq!(to_item_type_name("s"), "S");
+    }
 }
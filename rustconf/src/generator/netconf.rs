@@ -0,0 +1,154 @@
+//! NETCONF schema generation.
+//!
+//! Emits a `netconf_schema()` function returning the module namespace, list
+//! keys and JSON value kinds that `rustconf_runtime::netconf::NetconfTransport`
+//! needs to translate the generated RESTCONF requests to NETCONF.
+
+use crate::generator::config::{GeneratorConfig, Int64Encoding};
+use crate::generator::restrictions::resolve_type;
+use crate::parser::{DataNode, TypeSpec, YangModule};
+
+/// Generate the `netconf_schema` function for a YANG module.
+///
+/// Every line is prefixed with `indent`, so the function can be written into
+/// the `schema` module whether it is its own file or an inline block.
+pub(crate) fn generate_netconf_schema_items(
+    module: &YangModule,
+    config: &GeneratorConfig,
+    indent: &str,
+) -> String {
+    let mut nodes = Vec::new();
+    collect_nodes(&module.data_nodes, "", module, config, &mut nodes);
+    for rpc in &module.rpcs {
+        for (direction, children) in [("input", &rpc.input), ("output", &rpc.output)] {
+            if let Some(children) = children {
+                let path = format!("/{}/{}", rpc.name, direction);
+                collect_nodes(children, &path, module, config, &mut nodes);
+            }
+        }
+    }
+
+    let mut lines = vec![
+        format!(
+            "/// Namespace, list keys and value kinds of the {} YANG module, for",
+            module.name
+        ),
+        "/// sending the generated requests with `rustconf_runtime::netconf::NetconfTransport`."
+            .to_string(),
+        "#[must_use]".to_string(),
+        "pub fn netconf_schema() -> rustconf_runtime::netconf::NetconfSchema {".to_string(),
+        "    #[allow(unused_imports)]".to_string(),
+        "    use rustconf_runtime::netconf::{LeafValue, NetconfSchema, NodeKind};".to_string(),
+        String::new(),
+        "    NetconfSchema::new()".to_string(),
+        format!(
+            "        .with_module({:?}, {:?})",
+            module.name, module.namespace
+        ),
+    ];
+    for (path, kind, keys) in nodes {
+        let keys: Vec<String> = keys.iter().map(|key| format!("{:?}", key)).collect();
+        lines.push(format!(
+            "        .with_node({:?}, {}, &[{}])",
+            path,
+            kind,
+            keys.join(", ")
+        ));
+    }
+    lines.push("}".to_string());
+
+    let mut output = String::new();
+    for line in lines {
+        if line.is_empty() {
+            output.push('\n');
+        } else {
+            output.push_str(&format!("{}{}\n", indent, line));
+        }
+    }
+    output
+}
+
+/// Collect `(path, kind expression, keys)` for the data tree nodes below
+/// `parent`. Choices and cases are not part of the data tree, so their
+/// children are collected at the parent's level.
+fn collect_nodes(
+    nodes: &[DataNode],
+    parent: &str,
+    module: &YangModule,
+    config: &GeneratorConfig,
+    collected: &mut Vec<(String, String, Vec<String>)>,
+) {
+    for node in nodes {
+        match node {
+            DataNode::Container(container) => {
+                let path = format!("{}/{}", parent, container.name);
+                collected.push((path.clone(), "NodeKind::Container".to_string(), Vec::new()));
+                collect_nodes(&container.children, &path, module, config, collected);
+            }
+            DataNode::List(list) => {
+                let path = format!("{}/{}", parent, list.name);
+                collected.push((
+                    path.clone(),
+                    "NodeKind::List".to_string(),
+                    list.keys.clone(),
+                ));
+                collect_nodes(&list.children, &path, module, config, collected);
+            }
+            DataNode::Leaf(leaf) => collected.push((
+                format!("{}/{}", parent, leaf.name),
+                format!(
+                    "NodeKind::Leaf(LeafValue::{})",
+                    leaf_value(&leaf.type_spec, module, config)
+                ),
+                Vec::new(),
+            )),
+            DataNode::LeafList(leaf_list) => collected.push((
+                format!("{}/{}", parent, leaf_list.name),
+                format!(
+                    "NodeKind::LeafList(LeafValue::{})",
+                    leaf_value(&leaf_list.type_spec, module, config)
+                ),
+                Vec::new(),
+            )),
+            DataNode::Choice(choice) => {
+                for case in &choice.cases {
+                    collect_nodes(&case.data_nodes, parent, module, config, collected);
+                }
+            }
+            DataNode::Case(case) => {
+                collect_nodes(&case.data_nodes, parent, module, config, collected);
+            }
+            // The content of anydata is converted without a schema
+            DataNode::AnyData(_) | DataNode::Uses(_) => {}
+        }
+    }
+}
+
+/// The `LeafValue` variant for the JSON encoding of a type.
+fn leaf_value(type_spec: &TypeSpec, module: &YangModule, config: &GeneratorConfig) -> &'static str {
+    let Some(type_spec) = resolve_type(type_spec, module).type_spec else {
+        return "Any";
+    };
+    match type_spec {
+        TypeSpec::Int8 { .. }
+        | TypeSpec::Int16 { .. }
+        | TypeSpec::Int32 { .. }
+        | TypeSpec::Uint8 { .. }
+        | TypeSpec::Uint16 { .. }
+        | TypeSpec::Uint32 { .. } => "Number",
+        TypeSpec::Int64 { .. } | TypeSpec::Uint64 { .. } => match config.int64_encoding {
+            Int64Encoding::Number => "Number",
+            Int64Encoding::String | Int64Encoding::Lenient => "String",
+        },
+        TypeSpec::Boolean => "Boolean",
+        TypeSpec::Empty => "Empty",
+        TypeSpec::String { .. }
+        | TypeSpec::Decimal64 { .. }
+        | TypeSpec::DateAndTime { .. }
+        | TypeSpec::Enumeration { .. }
+        | TypeSpec::Bits { .. }
+        | TypeSpec::LeafRef { .. }
+        | TypeSpec::Binary { .. } => "String",
+        TypeSpec::Union { .. } | TypeSpec::TypedefRef { .. } => "Any",
+    }
+}
@@ -24,7 +24,11 @@ impl<'a> NotificationGenerator<'a> {
         let mut output = String::new();
 
         output.push_str("/// RESTCONF notification types.\n");
-        output.push_str("pub mod notifications {\n");
+        output.push_str(self.config.visibility.types.attributes());
+        output.push_str(&format!(
+            "{} mod notifications {{\n",
+            self.config.visibility.types.keyword()
+        ));
         output.push_str("    use super::*;\n");
         output.push('\n');
 
@@ -51,7 +55,7 @@ impl<'a> NotificationGenerator<'a> {
 
         // Generate rustdoc comment from notification description
         if let Some(ref description) = notification.description {
-            output.push_str(&format!("    {}", self.generate_rustdoc(description)));
+            output.push_str(&crate::generator::docs::rustdoc(description, "    "));
         } else {
             output.push_str(&format!(
                 "    /// Notification payload for {}.\n",
@@ -79,23 +83,6 @@ impl<'a> NotificationGenerator<'a> {
         Ok(output)
     }
 
-    /// Generate rustdoc comments from a YANG description.
-    fn generate_rustdoc(&self, description: &str) -> String {
-        let mut rustdoc = String::new();
-
-        // Split description into lines and format as rustdoc comments
-        for line in description.lines() {
-            let trimmed = line.trim();
-            if trimmed.is_empty() {
-                rustdoc.push_str("///\n");
-            } else {
-                rustdoc.push_str(&format!("/// {}\n", trimmed));
-            }
-        }
-
-        rustdoc
-    }
-
     /// Generate derive attributes based on configuration.
     fn generate_derive_attributes(&self) -> String {
         let mut derives = vec!["Serialize", "Deserialize"];
@@ -1,12 +1,15 @@
 //! Operations generation module for RESTCONF CRUD and RPC operations.
 //!
 //! This module handles the generation of RESTCONF operations including:
-//! - CRUD operations (GET, POST, PUT, PATCH, DELETE) for containers and lists
-//! - RPC function definitions and types
+//! - CRUD operations (GET, POST, PUT, PATCH, DELETE) for containers, lists and
+//!   top-level leaves
+//! - RPC function definitions and types, with streaming variants for RPCs
+//!   uploading a single `binary` leaf
 //! - Error types for operations
 
-use crate::generator::{GeneratorConfig, GeneratorError};
-use crate::parser::{Rpc, YangModule};
+use crate::generator::restrictions::resolve_type;
+use crate::generator::{GeneratorConfig, GeneratorError, Target};
+use crate::parser::{Leaf, Rpc, TypeSpec, YangModule};
 
 /// CRUD operation types for RESTCONF.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -80,6 +83,8 @@ pub enum ResourceType {
     Collection,
     /// List item (single item by key)
     Item,
+    /// Top-level leaf (single scalar value)
+    Leaf,
 }
 
 /// Generator for RESTCONF operations and RPC functions.
@@ -92,6 +97,17 @@ impl<'a> OperationsGenerator<'a> {
     pub fn new(config: &'a GeneratorConfig) -> Self {
         Self { config }
     }
+
+    /// Generic parameters and client parameter of functions taking a client.
+    ///
+    /// Functions are generic over the transport unless dyn clients are enabled.
+    fn client_signature(&self) -> (&'static str, &'static str) {
+        if self.config.enable_dyn_client {
+            ("", "client: &dyn RestconfClientApi")
+        } else {
+            ("<T: HttpTransport>", "client: &RestconfClient<T>")
+        }
+    }
 }
 
 use crate::parser::DataNode;
@@ -176,7 +192,11 @@ impl<'a> OperationsGenerator<'a> {
         let path_gen = crate::generator::paths::PathGenerator::new(self.config);
 
         output.push_str("/// RESTCONF operations.\n");
-        output.push_str("pub mod operations {\n");
+        output.push_str(self.config.visibility.operations.attributes());
+        output.push_str(&format!(
+            "{} mod operations {{\n",
+            self.config.visibility.operations.keyword()
+        ));
         output.push_str("    use super::*;\n");
         output.push('\n');
 
@@ -195,9 +215,17 @@ impl<'a> OperationsGenerator<'a> {
             }
         }
 
-        // Generate RESTCONF CRUD operations for data nodes
-        if !module.data_nodes.is_empty() {
+        // Generate RESTCONF CRUD operations for data nodes, which use the data types
+        if self.config.generates(Target::Types) && !module.data_nodes.is_empty() {
             output.push_str(&self.generate_crud_operations(module)?);
+
+            // Typed resource handles, which send requests through the client
+            if self.config.enable_restful_rpcs {
+                output.push_str(&crate::generator::resources::generate_resources_module(
+                    module,
+                    self.config,
+                ));
+            }
         }
 
         output.push_str("}\n");
@@ -235,8 +263,9 @@ impl<'a> OperationsGenerator<'a> {
                 self.generate_container_crud_operations(container, module)
             }
             DataNode::List(list) => self.generate_list_crud_operations(list, module),
-            DataNode::Leaf(_) => Ok(String::new()), // Top-level leaves are rare
+            DataNode::Leaf(leaf) => Ok(self.generate_leaf_crud_operations(leaf, module)),
             DataNode::LeafList(_) => Ok(String::new()),
+            DataNode::AnyData(_) => Ok(String::new()),
             DataNode::Choice(_) => Ok(String::new()),
             DataNode::Case(_) => Ok(String::new()),
             DataNode::Uses(_) => Ok(String::new()),
@@ -278,6 +307,7 @@ impl<'a> OperationsGenerator<'a> {
         let description_verb = operation.description_verb();
         let resource_desc = match (resource_type, operation) {
             (ResourceType::Container, _) => format!("the {} container", resource_name),
+            (ResourceType::Leaf, _) => format!("the {} leaf", resource_name),
             (ResourceType::Collection, CrudOperation::Get) => {
                 format!("all {} items", resource_name)
             }
@@ -296,6 +326,7 @@ impl<'a> OperationsGenerator<'a> {
                 ResourceType::Container => "container",
                 ResourceType::Collection => "collection",
                 ResourceType::Item => "item",
+                ResourceType::Leaf => "leaf",
             }
         }
 
@@ -356,6 +387,214 @@ impl<'a> OperationsGenerator<'a> {
         output
     }
 
+    /// Generate an OPTIONS helper that reports which methods a resource permits.
+    ///
+    /// The helper issues OPTIONS and parses the `Allow` and `Accept-Patch`
+    /// headers into a `ResourceCapabilities`. Only generated when RESTful
+    /// operations are enabled, since it needs a `RestconfClient`.
+    fn generate_options_operation(
+        &self,
+        resource_type: ResourceType,
+        resource_name: &str,
+        path_helper: &str,
+        key_params: Option<&str>,
+    ) -> String {
+        if !self.config.enable_restful_rpcs {
+            return String::new();
+        }
+
+        let mut output = String::new();
+        let function_prefix = crate::generator::naming::to_field_name(resource_name);
+        let (function_name, resource_desc) = match resource_type {
+            ResourceType::Container => (
+                format!("options_{}", function_prefix),
+                format!("the {} container", resource_name),
+            ),
+            ResourceType::Collection => (
+                format!("options_{}", function_prefix),
+                format!("the {} collection", resource_name),
+            ),
+            ResourceType::Item => (
+                format!("options_{}_by_key", function_prefix),
+                format!("a {} item by key", resource_name),
+            ),
+            ResourceType::Leaf => (
+                format!("options_{}", function_prefix),
+                format!("the {} leaf", resource_name),
+            ),
+        };
+
+        output.push_str(&format!(
+            "        /// Discover the methods permitted on {}.\n",
+            resource_desc
+        ));
+        output.push_str("        ///\n");
+        output.push_str(
+            "        /// Issues OPTIONS and parses the `Allow` and `Accept-Patch` headers.\n",
+        );
+        output.push_str("        ///\n");
+        output.push_str("        /// # Errors\n");
+        output.push_str("        ///\n");
+        output.push_str("        /// Returns an error if the request fails or the server responds with a non-2xx status.\n");
+
+        let (generics, client_param) = self.client_signature();
+        let mut params = vec![client_param.to_string()];
+        if let Some(keys) = key_params {
+            params.push(keys.to_string());
+        }
+        output.push_str(&format!(
+            "        pub async fn {}{}({}) -> Result<ResourceCapabilities, RpcError> {{\n",
+            function_name,
+            generics,
+            params.join(", ")
+        ));
+        output.push_str(&format!(
+            "            let url = format!(\"{{}}{{}}\", client.base_url().trim_end_matches('/'), {});\n",
+            path_helper
+        ));
+        output.push_str("            let request = HttpRequest {\n");
+        output.push_str("                method: HttpMethod::OPTIONS,\n");
+        output.push_str("                url,\n");
+        output.push_str("                headers: vec![],\n");
+        output.push_str("                body: None,\n");
+        output.push_str("                context: Default::default(),\n");
+        output.push_str("            };\n");
+        output.push_str("            let response = client.execute(request).await?;\n");
+        output.push_str("            ResourceCapabilities::try_from(&response)\n");
+        output.push_str("        }\n\n");
+
+        output
+    }
+
+    /// Generate an existence check that asks for a resource without its payload.
+    ///
+    /// The helper delegates to `RestconfClient::exists`, which issues HEAD and
+    /// falls back to GET with `depth=1` when HEAD is not supported. Only generated
+    /// when RESTful operations are enabled, since it needs a `RestconfClient`.
+    fn generate_exists_operation(
+        &self,
+        resource_type: ResourceType,
+        resource_name: &str,
+        path_helper: &str,
+        key_params: Option<&str>,
+    ) -> String {
+        if !self.config.enable_restful_rpcs {
+            return String::new();
+        }
+
+        let mut output = String::new();
+        let function_prefix = crate::generator::naming::to_field_name(resource_name);
+        let (function_name, resource_desc) = match resource_type {
+            ResourceType::Container => (
+                format!("exists_{}", function_prefix),
+                format!("the {} container", resource_name),
+            ),
+            ResourceType::Collection => (
+                format!("exists_{}", function_prefix),
+                format!("the {} collection", resource_name),
+            ),
+            ResourceType::Item => (
+                format!("exists_{}_by_key", function_prefix),
+                format!("a {} item", resource_name),
+            ),
+            ResourceType::Leaf => (
+                format!("exists_{}", function_prefix),
+                format!("the {} leaf", resource_name),
+            ),
+        };
+
+        output.push_str(&format!(
+            "        /// Check whether {} exists.\n",
+            resource_desc
+        ));
+        output.push_str("        ///\n");
+        output.push_str(
+            "        /// Issues HEAD, falling back to GET with `depth=1` if the server rejects HEAD.\n",
+        );
+        output.push_str("        ///\n");
+        output.push_str("        /// # Errors\n");
+        output.push_str("        ///\n");
+        output.push_str("        /// Returns an error if the request fails or the server responds with a status other than 2xx or 404.\n");
+
+        let (generics, client_param) = self.client_signature();
+        let mut params = vec![client_param.to_string()];
+        if let Some(keys) = key_params {
+            params.push(keys.to_string());
+        }
+        output.push_str(&format!(
+            "        pub async fn {}{}({}) -> Result<bool, RpcError> {{\n",
+            function_name,
+            generics,
+            params.join(", ")
+        ));
+        output.push_str(&format!(
+            "            client.exists(&{}).await\n",
+            path_helper
+        ));
+        output.push_str("        }\n\n");
+
+        output
+    }
+
+    /// Generate helpers that deserialize a list collection entry by entry.
+    ///
+    /// `{list}_entries` returns a `DeserializeSeed` for use with any serde
+    /// deserializer, and `for_each_{list}` reads a collection response from an
+    /// `io::Read`. Neither builds a `Vec` of entries. Only generated when RESTful
+    /// operations are enabled, since they use rustconf-runtime.
+    fn generate_streaming_operations(&self, list_name: &str, item_type_name: &str) -> String {
+        if !self.config.enable_restful_rpcs {
+            return String::new();
+        }
+
+        let mut output = String::new();
+        let function_prefix = crate::generator::naming::to_field_name(list_name);
+
+        output.push_str(&format!(
+            "        /// Deserialize the entries of a {} collection one at a time.\n",
+            list_name
+        ));
+        output.push_str("        ///\n");
+        output.push_str("        /// The returned `DeserializeSeed` calls `on_entry` for every entry and produces\n");
+        output
+            .push_str("        /// the number of entries, without collecting them into a `Vec`.\n");
+        output.push_str(&format!(
+            "        pub fn {}_entries<F: FnMut({})>(on_entry: F) -> rustconf_runtime::ListEntries<'static, {}, F> {{\n",
+            function_prefix, item_type_name, item_type_name
+        ));
+        output.push_str(&format!(
+            "            rustconf_runtime::ListEntries::new(\"{}\", on_entry)\n",
+            list_name
+        ));
+        output.push_str("        }\n\n");
+
+        output.push_str(&format!(
+            "        /// Read a {} collection response from `body`, calling `on_entry` for each entry.\n",
+            list_name
+        ));
+        output.push_str("        ///\n");
+        output.push_str(
+            "        /// Only one entry is held in memory at a time. Returns the number of entries.\n",
+        );
+        output.push_str("        ///\n");
+        output.push_str("        /// # Errors\n");
+        output.push_str("        ///\n");
+        output.push_str(
+            "        /// Returns an error if the body is not valid JSON or an entry does not match.\n",
+        );
+        output.push_str(&format!(
+            "        pub fn for_each_{}<R: std::io::Read>(body: R, on_entry: impl FnMut({})) -> Result<usize, RpcError> {{\n",
+            function_prefix, item_type_name
+        ));
+        output.push_str(&format!(
+            "            rustconf_runtime::for_each_list_entry(body, \"{}\", on_entry)\n",
+            list_name
+        ));
+        output.push_str("        }\n\n");
+
+        output
+    }
+
     /// Generate CRUD operations for a container.
     fn generate_container_crud_operations(
         &self,
@@ -382,6 +621,22 @@ impl<'a> OperationsGenerator<'a> {
             None,
         ));
 
+        // Generate OPTIONS capability probe
+        output.push_str(&self.generate_options_operation(
+            ResourceType::Container,
+            &container.name,
+            &path_helper,
+            None,
+        ));
+
+        // Generate existence check
+        output.push_str(&self.generate_exists_operation(
+            ResourceType::Container,
+            &container.name,
+            &path_helper,
+            None,
+        ));
+
         // Generate config-based operations (PUT, PATCH, DELETE) only if config is true
         if container.config {
             // PUT operation - replace entire container
@@ -418,6 +673,63 @@ impl<'a> OperationsGenerator<'a> {
         Ok(output)
     }
 
+    /// Generate CRUD operations for a top-level leaf.
+    ///
+    /// A leaf is read, replaced and deleted as a whole, so there is no PATCH;
+    /// values use the same Rust type as the leaf's struct fields.
+    fn generate_leaf_crud_operations(
+        &self,
+        leaf: &crate::parser::Leaf,
+        module: &YangModule,
+    ) -> String {
+        let mut output = String::new();
+        let type_gen = crate::generator::types::TypeGenerator::new(self.config);
+        let value_type = type_gen.generate_leaf_type(&leaf.type_spec, true);
+        let function_prefix = crate::generator::naming::to_field_name(&leaf.name);
+        let path_gen = crate::generator::paths::PathGenerator::new(self.config);
+
+        // Generate path helper function
+        output.push_str(&path_gen.generate_leaf_path_helper(leaf, module));
+        output.push('\n');
+
+        let path_helper = format!("{}_path()", function_prefix);
+        output.push_str(&self.generate_crud_operation(
+            CrudOperation::Get,
+            ResourceType::Leaf,
+            &leaf.name,
+            &value_type,
+            &path_helper,
+            None,
+        ));
+        output.push_str(&self.generate_options_operation(
+            ResourceType::Leaf,
+            &leaf.name,
+            &path_helper,
+            None,
+        ));
+        output.push_str(&self.generate_exists_operation(
+            ResourceType::Leaf,
+            &leaf.name,
+            &path_helper,
+            None,
+        ));
+
+        if leaf.config {
+            for operation in [CrudOperation::Put, CrudOperation::Delete] {
+                output.push_str(&self.generate_crud_operation(
+                    operation,
+                    ResourceType::Leaf,
+                    &leaf.name,
+                    &value_type,
+                    &path_helper,
+                    None,
+                ));
+            }
+        }
+
+        output
+    }
+
     /// Generate CRUD operations for a list.
     fn generate_list_crud_operations(
         &self,
@@ -425,16 +737,10 @@ impl<'a> OperationsGenerator<'a> {
         module: &YangModule,
     ) -> Result<String, GeneratorError> {
         let mut output = String::new();
-        let type_name = crate::generator::naming::to_type_name(&list.name);
         let function_prefix = crate::generator::naming::to_field_name(&list.name);
         let path_gen = crate::generator::paths::PathGenerator::new(self.config);
 
-        // Determine item type name (singular)
-        let item_type_name = if type_name.ends_with('s') && type_name.len() > 1 {
-            type_name[..type_name.len() - 1].to_string()
-        } else {
-            type_name.clone()
-        };
+        let item_type_name = self.config.item_type_name(&list.name);
 
         // Generate path helper functions
         output.push_str(&path_gen.generate_list_path_helpers(list, module)?);
@@ -466,6 +772,31 @@ impl<'a> OperationsGenerator<'a> {
             Some(&key_params),
         ));
 
+        // Generate OPTIONS capability probes for the collection and for items
+        output.push_str(&self.generate_options_operation(
+            ResourceType::Collection,
+            &list.name,
+            &collection_path,
+            None,
+        ));
+        output.push_str(&self.generate_options_operation(
+            ResourceType::Item,
+            &list.name,
+            &item_path,
+            Some(&key_params),
+        ));
+
+        // Generate entry-by-entry deserialization for large collections
+        output.push_str(&self.generate_streaming_operations(&list.name, &item_type_name));
+
+        // Generate existence check for single items
+        output.push_str(&self.generate_exists_operation(
+            ResourceType::Item,
+            &list.name,
+            &item_path,
+            Some(&key_params),
+        ));
+
         // Generate config-based operations only if config is true
         if list.config {
             // POST operation - create new item
@@ -555,12 +886,67 @@ impl<'a> OperationsGenerator<'a> {
                 }
 
                 output.push_str("    }\n\n");
+
+                if self.config.enable_restful_rpcs {
+                    let member = format!("{}:output", module.name);
+                    output.push_str(&self.generate_output_try_from(&rpc_type_name, &member));
+                }
             }
         }
 
         Ok(output)
     }
 
+    /// Generate a `TryFrom<&HttpResponse>` implementation for an RPC output type.
+    ///
+    /// This holds the status mapping and deserialization logic so that callers
+    /// driving a custom transport directly can reuse it. The body is expected
+    /// inside the `member` envelope unless bare RPC bodies are configured.
+    fn generate_output_try_from(&self, rpc_type_name: &str, member: &str) -> String {
+        let mut output = String::new();
+
+        output.push_str(&format!(
+            "    impl TryFrom<&HttpResponse> for {}Output {{\n",
+            rpc_type_name
+        ));
+        output.push_str("        type Error = RpcError;\n\n");
+        output.push_str("        /// Map a RESTCONF response to the RPC output.\n");
+        output.push_str("        ///\n");
+        output.push_str(
+            "        /// Success status codes deserialize the body; any other status becomes\n",
+        );
+        output.push_str("        /// `RpcError::HttpError`.\n");
+        output.push_str(
+            "        fn try_from(response: &HttpResponse) -> Result<Self, Self::Error> {\n",
+        );
+        output.push_str("            match response.status_code {\n");
+        if self.config.bare_rpc_bodies {
+            output
+                .push_str("                200..=299 => serde_json::from_slice(&response.body)\n");
+        } else {
+            output.push_str(&format!(
+                "                200..=299 => rustconf_runtime::unwrap_envelope(\"{}\", &response.body)\n",
+                member
+            ));
+        }
+        output.push_str("                    .map_err(|e| RpcError::DeserializationError(\n");
+        output.push_str(
+            "                        format!(\"Failed to deserialize response: {}\", e)\n",
+        );
+        output.push_str("                    )),\n");
+        output.push_str("                _ => Err(RpcError::HttpError {\n");
+        output.push_str("                    status_code: response.status_code,\n");
+        output.push_str(
+            "                    message: String::from_utf8_lossy(&response.body).to_string(),\n",
+        );
+        output.push_str("                }),\n");
+        output.push_str("            }\n");
+        output.push_str("        }\n");
+        output.push_str("    }\n\n");
+
+        output
+    }
+
     /// Generate an async function for an RPC operation.
     fn generate_rpc_function(
         &self,
@@ -573,7 +959,7 @@ impl<'a> OperationsGenerator<'a> {
 
         // Generate rustdoc comment from RPC description
         if let Some(ref description) = rpc.description {
-            output.push_str(&format!("    {}", self.generate_rustdoc(description)));
+            output.push_str(&crate::generator::docs::rustdoc(description, "    "));
         } else {
             output.push_str(&format!(
                 "    /// Execute the {} RPC operation.\n",
@@ -617,6 +1003,17 @@ impl<'a> OperationsGenerator<'a> {
         }
         output.push_str("    ///\n");
 
+        if let (Some(timeout), true) = (rpc.timeout, self.config.enable_restful_rpcs) {
+            output.push_str("    /// # Timeout\n");
+            output.push_str("    ///\n");
+            output.push_str(&format!(
+                "    /// The request is given {} seconds unless the client's context has a\n",
+                timeout
+            ));
+            output.push_str("    /// `Deadline`.\n");
+            output.push_str("    ///\n");
+        }
+
         // Add error handling documentation
         output.push_str("    /// # Errors\n");
         output.push_str("    ///\n");
@@ -679,7 +1076,7 @@ impl<'a> OperationsGenerator<'a> {
                 output.push_str("    ///\n");
                 output.push_str("    ///     // Execute the RPC operation\n");
                 output.push_str(&format!(
-                    "    ///     let result = {}(&client, input).await?;\n",
+                    "    ///     let result = {}(&client, &input).await?;\n",
                     function_name
                 ));
             } else {
@@ -705,7 +1102,7 @@ impl<'a> OperationsGenerator<'a> {
         // Determine input parameter type
         let input_param = if let Some(ref input_nodes) = rpc.input {
             if !input_nodes.is_empty() {
-                format!("input: {}Input", rpc_type_name)
+                format!("input: &{}Input", rpc_type_name)
             } else {
                 String::new()
             }
@@ -736,6 +1133,18 @@ impl<'a> OperationsGenerator<'a> {
                 &input_param,
                 &return_type,
             )?;
+            if let Some(leaf) = streamed_binary_leaf(rpc, module) {
+                output.push('\n');
+                self.generate_streaming_rpc_function(
+                    &mut output,
+                    rpc,
+                    module,
+                    leaf,
+                    &function_name,
+                    &rpc_type_name,
+                    &return_type,
+                );
+            }
         } else {
             // Generate stub function that returns NotImplemented
             self.generate_stub_rpc_function(
@@ -771,6 +1180,9 @@ impl<'a> OperationsGenerator<'a> {
         }
 
         // Generate stub body that returns NotImplemented
+        if !input_param.is_empty() {
+            output.push_str("        let _ = input;\n");
+        }
         output.push_str("        Err(RpcError::NotImplemented)\n");
         output.push_str("    }\n");
     }
@@ -782,37 +1194,183 @@ impl<'a> OperationsGenerator<'a> {
         rpc: &Rpc,
         module: &YangModule,
         function_name: &str,
-        _rpc_type_name: &str,
+        rpc_type_name: &str,
         input_param: &str,
         return_type: &str,
     ) -> Result<(), GeneratorError> {
-        // Generate function signature with client parameter
-        if input_param.is_empty() {
-            output.push_str(&format!(
-                "    pub async fn {}<T: HttpTransport>(client: &RestconfClient<T>) -> {} {{\n",
-                function_name, return_type
-            ));
-        } else {
-            output.push_str(&format!(
-                "    pub async fn {}<T: HttpTransport>(client: &RestconfClient<T>, {}) -> {} {{\n",
-                function_name, input_param, return_type
-            ));
-        }
-
         // Determine if we have input to serialize
         let has_input = rpc.input.as_ref().is_some_and(|nodes| !nodes.is_empty());
 
         // Determine if we have output to deserialize
         let has_output = rpc.output.as_ref().is_some_and(|nodes| !nodes.is_empty());
 
+        // Generate function signature with client parameter
+        let (generics, client_param) = self.client_signature();
+        let params = if input_param.is_empty() {
+            client_param.to_string()
+        } else {
+            format!("{}, {}", client_param, input_param)
+        };
+        let args = if has_input { "client, input" } else { "client" };
+
+        // The plain function drops the status and headers of the `_full` variant
+        output.push_str(&format!(
+            "    pub async fn {}{}({}) -> {} {{\n",
+            function_name, generics, params, return_type
+        ));
+        output.push_str(&format!(
+            "        {}_full({}).await.map(ResponseEnvelope::into_body)\n",
+            function_name, args
+        ));
+        output.push_str("    }\n\n");
+
+        let body_type = if has_output {
+            format!("{}Output", rpc_type_name)
+        } else {
+            "()".to_string()
+        };
+        output.push_str(&format!(
+            "    /// Like [`{}`], also returning the response status and headers.\n",
+            function_name
+        ));
+        output.push_str("    ///\n");
+        output.push_str("    /// # Errors\n");
+        output.push_str("    ///\n");
+        output.push_str(&format!(
+            "    /// Returns the same errors as [`{}`].\n",
+            function_name
+        ));
+        output.push_str(&format!(
+            "    pub async fn {}_full{}({}) -> Result<ResponseEnvelope<{}>, RpcError> {{\n",
+            function_name, generics, params, body_type
+        ));
+
         // Generate function body
         if has_input {
             // Serialize input to JSON
             output.push_str("        // Serialize input to JSON\n");
-            output.push_str("        let body = serde_json::to_vec(&input)\n");
+            if self.config.bare_rpc_bodies {
+                output.push_str("        let body = serde_json::to_vec(input)\n");
+            } else {
+                output.push_str(&format!(
+                    "        let body = rustconf_runtime::wrap_envelope(\"{}:input\", input)\n",
+                    module.name
+                ));
+            }
             output.push_str("            .map_err(|e| RpcError::SerializationError(format!(\"Failed to serialize input: {}\", e)))?;\n\n");
         }
 
+        self.push_rpc_request(
+            output,
+            rpc,
+            module,
+            if has_input {
+                "Some(body.into())"
+            } else {
+                "None"
+            },
+        );
+
+        // Call client.execute()
+        output.push_str("        // Execute request through client\n");
+        output.push_str("        let response = client.execute(request).await?;\n\n");
+        self.push_rpc_response(output, rpc_type_name, has_output);
+        output.push_str("    }\n");
+
+        Ok(())
+    }
+
+    /// Generate the `_streaming` variant of an RPC function, which reads the
+    /// `binary` leaf `leaf` of the input from a `StreamingBody` while the
+    /// request is sent.
+    #[allow(clippy::too_many_arguments)]
+    fn generate_streaming_rpc_function(
+        &self,
+        output: &mut String,
+        rpc: &Rpc,
+        module: &YangModule,
+        leaf: &Leaf,
+        function_name: &str,
+        rpc_type_name: &str,
+        return_type: &str,
+    ) {
+        let has_output = rpc.output.as_ref().is_some_and(|nodes| !nodes.is_empty());
+        let field_name = crate::generator::naming::to_field_name(&leaf.name);
+        // Names taken by the generated function body
+        let param = match field_name.as_str() {
+            "client" | "input" | "body" | "base" | "url" | "request" | "response" | "output" => {
+                "content"
+            }
+            name => name,
+        };
+
+        output.push_str(&format!(
+            "    /// Like [`{}`], reading the `{}` leaf from `{}` while the request is\n",
+            function_name, leaf.name, param
+        ));
+        output.push_str(
+            "    /// sent instead of taking it from `input`, so it is never held in memory\n",
+        );
+        output.push_str(&format!(
+            "    /// as a whole. The value of `input.{}` is ignored; progress is reported\n",
+            field_name
+        ));
+        output.push_str("    /// through `StreamingBody::on_progress`.\n");
+        output.push_str("    ///\n");
+        output.push_str("    /// # Errors\n");
+        output.push_str("    ///\n");
+        output.push_str(&format!(
+            "    /// Returns the same errors as [`{}`], and the error of the source of\n",
+            function_name
+        ));
+        output.push_str(&format!("    /// `{}` if reading it fails.\n", param));
+
+        let (generics, client_param) = self.client_signature();
+        output.push_str(&format!(
+            "    pub async fn {}_streaming{}({}, input: &{}Input, {}: rustconf_runtime::StreamingBody) -> {} {{\n",
+            function_name, generics, client_param, rpc_type_name, param, return_type
+        ));
+        let envelope = if self.config.bare_rpc_bodies {
+            "None".to_string()
+        } else {
+            format!("Some(\"{}:input\")", module.name)
+        };
+        output.push_str(&format!(
+            "        let body = rustconf_runtime::upload::binary_leaf_body({}, input, \"{}\", {})?;\n\n",
+            envelope, leaf.name, param
+        ));
+
+        self.push_rpc_request(output, rpc, module, "None");
+
+        output.push_str("        // Execute request through client, streaming the body\n");
+        output
+            .push_str("        let response = client.execute_streaming(request, body).await?;\n\n");
+        let body_type = if has_output {
+            format!("{}Output", rpc_type_name)
+        } else {
+            "()".to_string()
+        };
+        output.push_str(&format!(
+            "        let envelope: Result<ResponseEnvelope<{}>, RpcError> = {{\n",
+            body_type
+        ));
+        let mut mapping = String::new();
+        self.push_rpc_response(&mut mapping, rpc_type_name, has_output);
+        for line in mapping.lines() {
+            if line.is_empty() {
+                output.push('\n');
+            } else {
+                output.push_str(&format!("    {}\n", line));
+            }
+        }
+        output.push_str("        };\n");
+        output.push_str("        envelope.map(ResponseEnvelope::into_body)\n");
+        output.push_str("    }\n");
+    }
+
+    /// Push the construction of the `url` and `request` of an RPC, with
+    /// `body` as the request body expression.
+    fn push_rpc_request(&self, output: &mut String, rpc: &Rpc, module: &YangModule, body: &str) {
         // Construct RESTCONF URL inline
         output.push_str("        // Construct RESTCONF URL\n");
         output.push_str("        let base = client.base_url().trim_end_matches('/');\n");
@@ -820,13 +1378,13 @@ impl<'a> OperationsGenerator<'a> {
         match self.config.restful_namespace_mode {
             crate::generator::config::NamespaceMode::Enabled => {
                 output.push_str(&format!(
-                    "        let url = format!(\"{{}}/ restconf/operations/{{}}:{{}}\", base, percent_encode(\"{}\"), percent_encode(\"{}\"));\n\n",
+                    "        let url = format!(\"{{}}{{}}/{{}}:{{}}\", base, consts::OPERATIONS_PATH, percent_encode(\"{}\"), percent_encode(\"{}\"));\n\n",
                     module.name, rpc.name
                 ));
             }
             crate::generator::config::NamespaceMode::Disabled => {
                 output.push_str(&format!(
-                    "        let url = format!(\"{{}}/restconf/operations/{{}}\", base, percent_encode(\"{}\"));\n\n",
+                    "        let url = format!(\"{{}}{{}}/{{}}\", base, consts::OPERATIONS_PATH, percent_encode(\"{}\"));\n\n",
                     rpc.name
                 ));
             }
@@ -838,73 +1396,57 @@ impl<'a> OperationsGenerator<'a> {
         output.push_str("            method: HttpMethod::POST,\n");
         output.push_str("            url,\n");
         output.push_str("            headers: vec![\n");
-        output.push_str("                (\"Content-Type\".to_string(), \"application/yang-data+json\".to_string()),\n");
-        output.push_str("                (\"Accept\".to_string(), \"application/yang-data+json\".to_string()),\n");
+        output.push_str(
+            "                (\"Content-Type\".to_string(), consts::YANG_DATA_JSON.to_string()),\n",
+        );
+        output.push_str(
+            "                (\"Accept\".to_string(), consts::YANG_DATA_JSON.to_string()),\n",
+        );
         output.push_str("            ],\n");
 
-        if has_input {
-            output.push_str("            body: Some(body),\n");
-        } else {
-            output.push_str("            body: None,\n");
+        output.push_str(&format!("            body: {},\n", body));
+        match rpc.timeout {
+            // Slow operations get a longer budget than the client's default
+            Some(timeout) => output.push_str(&format!(
+                "            context: rustconf_runtime::RequestContext::new().with(\
+                 rustconf_runtime::OperationTimeout(std::time::Duration::from_secs({}))),\n",
+                timeout
+            )),
+            None => output.push_str("            context: Default::default(),\n"),
         }
 
         output.push_str("        };\n\n");
+    }
 
-        // Call client.execute()
-        output.push_str("        // Execute request through client\n");
-        output.push_str("        let response = client.execute(request).await?;\n\n");
-
-        // Map HTTP status codes to RpcError variants
-        output.push_str("        // Map HTTP status to error or deserialize response\n");
-        output.push_str("        match response.status_code {\n");
-        output.push_str("            200..=299 => {\n");
-
+    /// Push the mapping of the `response` of an RPC to the envelope returned
+    /// by the `_full` and `_streaming` functions.
+    fn push_rpc_response(&self, output: &mut String, rpc_type_name: &str, has_output: bool) {
         if has_output {
-            // Deserialize response body for 2xx status codes
-            output.push_str("                // Success - deserialize response body\n");
-            output.push_str("                serde_json::from_slice(&response.body)\n");
-            output.push_str("                    .map_err(|e| RpcError::DeserializationError(\n");
-            output.push_str(
-                "                        format!(\"Failed to deserialize response: {}\", e)\n",
-            );
-            output.push_str("                    ))\n");
+            // Status mapping and deserialization live in the TryFrom implementation
+            output.push_str("        // Map HTTP status to error or deserialize response\n");
+            output.push_str(&format!(
+                "        let output = {}Output::try_from(&response)?;\n",
+                rpc_type_name
+            ));
+            output.push_str("        Ok(ResponseEnvelope::new(response, output))\n");
         } else {
-            // No output expected, just return Ok(())
+            // Map HTTP status codes to RpcError variants
+            output.push_str("        // Map HTTP status to error\n");
+            output.push_str("        match response.status_code {\n");
+            output.push_str("            200..=299 => {\n");
             output.push_str("                // Success - no output expected\n");
-            output.push_str("                Ok(())\n");
-        }
-
-        output.push_str("            }\n");
-
-        // Map all error status codes to HttpError (matching rustconf-runtime)
-        output.push_str("            _ => Err(RpcError::HttpError {\n");
-        output.push_str("                status_code: response.status_code,\n");
-        output.push_str(
-            "                message: String::from_utf8_lossy(&response.body).to_string(),\n",
-        );
-        output.push_str("            }),\n");
-        output.push_str("        }\n");
-
-        output.push_str("    }\n");
-
-        Ok(())
-    }
-
-    /// Generate rustdoc comments from a YANG description.
-    fn generate_rustdoc(&self, description: &str) -> String {
-        let mut rustdoc = String::new();
+            output.push_str("                Ok(ResponseEnvelope::new(response, ()))\n");
+            output.push_str("            }\n");
 
-        // Split description into lines and format as rustdoc comments
-        for line in description.lines() {
-            let trimmed = line.trim();
-            if trimmed.is_empty() {
-                rustdoc.push_str("///\n");
-            } else {
-                rustdoc.push_str(&format!("/// {}\n", trimmed));
-            }
+            // Map all error status codes to HttpError (matching rustconf-runtime)
+            output.push_str("            _ => Err(RpcError::HttpError {\n");
+            output.push_str("                status_code: response.status_code,\n");
+            output.push_str(
+                "                message: String::from_utf8_lossy(&response.body).to_string(),\n",
+            );
+            output.push_str("            }),\n");
+            output.push_str("        }\n");
         }
-
-        rustdoc
     }
 
     /// Generate derive attributes based on configuration.
@@ -922,3 +1464,26 @@ impl<'a> OperationsGenerator<'a> {
         format!("#[derive({})]\n", derives.join(", "))
     }
 }
+
+/// The `binary` leaf an RPC uploads, if its input has exactly one.
+///
+/// Such RPCs, e.g. firmware installs, get a `_streaming` variant reading the
+/// leaf from a `StreamingBody`.
+fn streamed_binary_leaf<'r>(rpc: &'r Rpc, module: &YangModule) -> Option<&'r Leaf> {
+    let mut binary = rpc.input.iter().flatten().filter_map(|node| match node {
+        DataNode::Leaf(leaf) if is_binary(&leaf.type_spec, module) => Some(leaf),
+        _ => None,
+    });
+    match (binary.next(), binary.next()) {
+        (Some(leaf), None) => Some(leaf),
+        _ => None,
+    }
+}
+
+/// Whether a type is `binary`, directly or through typedefs of the module.
+fn is_binary(type_spec: &TypeSpec, module: &YangModule) -> bool {
+    matches!(
+        resolve_type(type_spec, module).type_spec,
+        Some(TypeSpec::Binary { .. })
+    )
+}
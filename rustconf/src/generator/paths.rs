@@ -4,7 +4,7 @@
 //! RESTCONF operations, including path construction and key encoding.
 
 use crate::generator::{GeneratorConfig, GeneratorError};
-use crate::parser::{Container, List, YangModule};
+use crate::parser::{Container, Leaf, List, YangModule};
 
 /// Generator for RESTCONF URL path helpers.
 pub struct PathGenerator<'a> {
@@ -38,12 +38,7 @@ impl<'a> PathGenerator<'a> {
         output.push_str("        #[allow(dead_code)]\n");
         output.push_str(&format!("        fn {}() -> String {{\n", function_name));
 
-        // Build the path: /restconf/data/{module}:{container}
-        let path = if self.config.enable_namespace_prefixes {
-            format!("/restconf/data/{}:{}", module.prefix, container.name)
-        } else {
-            format!("/restconf/data/{}", container.name)
-        };
+        let path = self.data_path(&container.name, module);
 
         output.push_str(&format!("            \"{}\".to_string()\n", path));
         output.push_str("        }\n");
@@ -51,6 +46,29 @@ impl<'a> PathGenerator<'a> {
         Ok(output)
     }
 
+    /// Generate path helper function for a top-level leaf.
+    pub fn generate_leaf_path_helper(&self, leaf: &Leaf, module: &YangModule) -> String {
+        let mut output = String::new();
+        let function_name = format!(
+            "{}_path",
+            crate::generator::naming::to_field_name(&leaf.name)
+        );
+
+        output.push_str(&format!(
+            "        /// Build the RESTCONF URL path for the {} leaf.\n",
+            leaf.name
+        ));
+        output.push_str("        #[allow(dead_code)]\n");
+        output.push_str(&format!("        fn {}() -> String {{\n", function_name));
+        output.push_str(&format!(
+            "            \"{}\".to_string()\n",
+            self.data_path(&leaf.name, module)
+        ));
+        output.push_str("        }\n");
+
+        output
+    }
+
     /// Generate path helper functions for a list (collection and item paths).
     pub fn generate_list_path_helpers(
         &self,
@@ -71,11 +89,7 @@ impl<'a> PathGenerator<'a> {
             function_prefix
         ));
 
-        let collection_path = if self.config.enable_namespace_prefixes {
-            format!("/restconf/data/{}:{}", module.prefix, list.name)
-        } else {
-            format!("/restconf/data/{}", list.name)
-        };
+        let collection_path = self.data_path(&list.name, module);
 
         output.push_str(&format!(
             "            \"{}\".to_string()\n",
@@ -98,16 +112,9 @@ impl<'a> PathGenerator<'a> {
             function_prefix, key_params
         ));
 
-        // Build the base path
-        let base_path = if self.config.enable_namespace_prefixes {
-            format!("/restconf/data/{}:{}", module.prefix, list.name)
-        } else {
-            format!("/restconf/data/{}", list.name)
-        };
-
         output.push_str(&format!(
             "            let mut path = \"{}\".to_string();\n",
-            base_path
+            collection_path
         ));
 
         // Add key encoding for each key
@@ -125,18 +132,42 @@ impl<'a> PathGenerator<'a> {
         Ok(output)
     }
 
+    /// Build the RESTCONF path of a top-level data node.
+    ///
+    /// Top-level data nodes are the members that carry a namespace qualifier,
+    /// so the path is `/restconf/data/{module}:{name}` when namespace prefixes
+    /// are enabled.
+    pub fn data_path(&self, name: &str, module: &YangModule) -> String {
+        if self.config.enable_namespace_prefixes {
+            format!(
+                "/restconf/data/{}:{}",
+                self.config.namespace_qualifier.qualifier(module),
+                name
+            )
+        } else {
+            format!("/restconf/data/{}", name)
+        }
+    }
+
     /// Generate parameter list for list key fields.
     pub fn generate_list_key_params(&self, list: &List) -> String {
-        let mut params = Vec::new();
-
-        for key in &list.keys {
-            // Find the key field in the list's children to get its type
-            let key_type = self.find_key_type(key, &list.children);
-            let param_name = crate::generator::naming::to_field_name(key);
-            params.push(format!("{}: {}", param_name, key_type));
-        }
+        self.list_keys(list)
+            .iter()
+            .map(|(name, key_type)| format!("{}: {}", name, key_type))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
 
-        params.join(", ")
+    /// Parameter names and Rust types of the keys of a list, in key order.
+    pub fn list_keys(&self, list: &List) -> Vec<(String, String)> {
+        list.keys
+            .iter()
+            .map(|key| {
+                // Find the key field in the list's children to get its type
+                let key_type = self.find_key_type(key, &list.children);
+                (crate::generator::naming::to_field_name(key), key_type)
+            })
+            .collect()
     }
 
     /// Find the type of a key field in a list's children.
@@ -167,10 +198,17 @@ impl<'a> PathGenerator<'a> {
             .join(", ")
     }
 
-    /// Generate percent encoding helper function in the operations module.
+    /// Generate percent encoding helper function in the operations module,
+    /// or its import with shared helpers.
     pub fn generate_percent_encode_helper(&self) -> String {
         let mut output = String::new();
 
+        if self.config.shared_helpers {
+            output.push_str("    #[allow(unused_imports)]\n");
+            output.push_str("    use rustconf_runtime::percent_encode;\n\n");
+            return output;
+        }
+
         output.push_str("    /// Percent-encode a string for use in URLs.\n");
         output.push_str("    ///\n");
         output
@@ -0,0 +1,211 @@
+//! Payload schema generation.
+//!
+//! Emits a `payload_schema()` function returning the value types, list keys,
+//! mandatory leaves, ranges and lengths of the module's data nodes and RPC
+//! inputs, which `rustconf_runtime::payload::ValidatingTransport` checks
+//! request bodies against before sending them.
+
+use crate::generator::config::{GeneratorConfig, Int64Encoding};
+use crate::generator::restrictions::resolve_type;
+use crate::parser::{DataNode, LengthConstraint, RangeConstraint, TypeSpec, YangModule};
+
+/// Generate the `payload_schema` function for a YANG module.
+///
+/// Every line is prefixed with `indent`, so the function can be written into
+/// the `schema` module whether it is its own file or an inline block.
+pub(crate) fn generate_payload_schema_items(
+    module: &YangModule,
+    config: &GeneratorConfig,
+    indent: &str,
+) -> String {
+    let mut calls = Vec::new();
+    collect_nodes(&module.data_nodes, "", false, module, config, &mut calls);
+    for rpc in &module.rpcs {
+        if let Some(input) = &rpc.input {
+            let path = format!("/{}/input", rpc.name);
+            calls.push(format!(".container({:?})", format!("/{}", rpc.name)));
+            calls.push(format!(".container({:?})", path));
+            collect_nodes(input, &path, false, module, config, &mut calls);
+        }
+    }
+
+    let mut lines = vec![
+        format!(
+            "/// Value types, list keys, mandatory leaves, ranges and lengths of the {}",
+            module.name
+        ),
+        "/// YANG module, for checking request bodies before they are sent with".to_string(),
+        "/// `rustconf_runtime::payload::ValidatingTransport`.".to_string(),
+        "#[must_use]".to_string(),
+        "pub fn payload_schema() -> rustconf_runtime::payload::PayloadSchema {".to_string(),
+        "    #[allow(unused_imports)]".to_string(),
+        "    use rustconf_runtime::payload::{LeafType, PayloadSchema};".to_string(),
+        String::new(),
+        "    PayloadSchema::new()".to_string(),
+    ];
+    for call in calls {
+        lines.push(format!("        {}", call));
+    }
+    lines.push("}".to_string());
+
+    let mut output = String::new();
+    for line in lines {
+        if line.is_empty() {
+            output.push('\n');
+        } else {
+            output.push_str(&format!("{}{}\n", indent, line));
+        }
+    }
+    output
+}
+
+/// Collect the builder calls for the data tree nodes below `parent`.
+///
+/// Choices and cases are not part of the data tree, so their children are
+/// collected at the parent's level; leaves in a case are only mandatory when
+/// the case is chosen, so they are not marked mandatory.
+fn collect_nodes(
+    nodes: &[DataNode],
+    parent: &str,
+    in_choice: bool,
+    module: &YangModule,
+    config: &GeneratorConfig,
+    calls: &mut Vec<String>,
+) {
+    for node in nodes {
+        match node {
+            DataNode::Container(container) => {
+                let path = format!("{}/{}", parent, container.name);
+                calls.push(format!(".container({:?})", path));
+                collect_nodes(&container.children, &path, false, module, config, calls);
+            }
+            DataNode::List(list) => {
+                let path = format!("{}/{}", parent, list.name);
+                let keys: Vec<String> = list.keys.iter().map(|key| format!("{:?}", key)).collect();
+                calls.push(format!(".list({:?}, &[{}])", path, keys.join(", ")));
+                collect_nodes(&list.children, &path, false, module, config, calls);
+            }
+            DataNode::Leaf(leaf) => calls.push(format!(
+                ".leaf({:?}, {}, {})",
+                format!("{}/{}", parent, leaf.name),
+                leaf_type(&leaf.type_spec, module, config, 0),
+                leaf.mandatory && !in_choice
+            )),
+            DataNode::LeafList(leaf_list) => calls.push(format!(
+                ".leaf_list({:?}, {})",
+                format!("{}/{}", parent, leaf_list.name),
+                leaf_type(&leaf_list.type_spec, module, config, 0)
+            )),
+            // The content of anydata is not described by the schema
+            DataNode::AnyData(anydata) => calls.push(format!(
+                ".leaf({:?}, LeafType::Any, {})",
+                format!("{}/{}", parent, anydata.name),
+                anydata.mandatory && !in_choice
+            )),
+            DataNode::Choice(choice) => {
+                for case in &choice.cases {
+                    collect_nodes(&case.data_nodes, parent, true, module, config, calls);
+                }
+            }
+            DataNode::Case(case) => {
+                collect_nodes(&case.data_nodes, parent, true, module, config, calls);
+            }
+            DataNode::Uses(_) => {}
+        }
+    }
+}
+
+/// The `LeafType` expression for a type.
+///
+/// `depth` counts the typedefs followed, bounded by the number of typedefs in
+/// case of circular references.
+fn leaf_type(
+    type_spec: &TypeSpec,
+    module: &YangModule,
+    config: &GeneratorConfig,
+    depth: usize,
+) -> String {
+    let quoted = config.int64_encoding != Int64Encoding::Number;
+    match type_spec {
+        TypeSpec::Int8 { range } => integer(range, i8::MIN.into(), i8::MAX.into(), false),
+        TypeSpec::Int16 { range } => integer(range, i16::MIN.into(), i16::MAX.into(), false),
+        TypeSpec::Int32 { range } => integer(range, i32::MIN.into(), i32::MAX.into(), false),
+        TypeSpec::Int64 { range } => integer(range, i64::MIN.into(), i64::MAX.into(), quoted),
+        TypeSpec::Uint8 { range } => integer(range, 0, u8::MAX.into(), false),
+        TypeSpec::Uint16 { range } => integer(range, 0, u16::MAX.into(), false),
+        TypeSpec::Uint32 { range } => integer(range, 0, u32::MAX.into(), false),
+        TypeSpec::Uint64 { range } => integer(range, 0, u64::MAX.into(), quoted),
+        TypeSpec::Decimal64 {
+            fraction_digits,
+            range,
+        } => {
+            let ranges: Vec<String> = range
+                .iter()
+                .flat_map(|range| &range.ranges)
+                .map(|range| format!("({}, {})", range.min, range.max))
+                .collect();
+            format!(
+                "LeafType::decimal64({}, &[{}])",
+                fraction_digits,
+                ranges.join(", ")
+            )
+        }
+        TypeSpec::String { length, .. } => format!("LeafType::string(&[{}])", lengths(length)),
+        TypeSpec::DateAndTime { .. } => "LeafType::string(&[])".to_string(),
+        TypeSpec::Boolean => "LeafType::Boolean".to_string(),
+        TypeSpec::Empty => "LeafType::Empty".to_string(),
+        TypeSpec::Binary { .. } => "LeafType::Binary".to_string(),
+        TypeSpec::Enumeration { values } => {
+            let names: Vec<String> = values
+                .iter()
+                .map(|value| format!("{:?}", value.name))
+                .collect();
+            format!("LeafType::enumeration(&[{}])", names.join(", "))
+        }
+        TypeSpec::Bits { bits } => {
+            let names: Vec<String> = bits.iter().map(|bit| format!("{:?}", bit.name)).collect();
+            format!("LeafType::bits(&[{}])", names.join(", "))
+        }
+        TypeSpec::Union { types } => {
+            let members: Vec<String> = types
+                .iter()
+                .map(|member| leaf_type(member, module, config, depth))
+                .collect();
+            format!("LeafType::Union(vec![{}])", members.join(", "))
+        }
+        TypeSpec::LeafRef { .. } => "LeafType::Any".to_string(),
+        TypeSpec::TypedefRef { .. } => match resolve_type(type_spec, module).type_spec {
+            // Unions of the typedef may reference it again
+            Some(resolved) if depth < module.typedefs.len() => {
+                leaf_type(&resolved, module, config, depth + 1)
+            }
+            // Imported typedefs are not known here
+            _ => "LeafType::Any".to_string(),
+        },
+    }
+}
+
+/// A `LeafType::integer` expression, limited to the range of the built-in
+/// type when there is no `range` restriction.
+fn integer(range: &Option<RangeConstraint>, min: i128, max: i128, quoted: bool) -> String {
+    let ranges: Vec<String> = match range {
+        Some(range) => range
+            .ranges
+            .iter()
+            .map(|range| format!("({}, {})", range.min, range.max))
+            .collect(),
+        None => vec![format!("({}, {})", min, max)],
+    };
+    let constructor = if quoted { "quoted_integer" } else { "integer" };
+    format!("LeafType::{}(&[{}])", constructor, ranges.join(", "))
+}
+
+/// The length ranges of a `length` restriction, if any.
+fn lengths(length: &Option<LengthConstraint>) -> String {
+    let lengths: Vec<String> = length
+        .iter()
+        .flat_map(|length| &length.lengths)
+        .map(|range| format!("({}, {})", range.min, range.max))
+        .collect();
+    lengths.join(", ")
+}
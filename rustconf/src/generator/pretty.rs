@@ -0,0 +1,170 @@
+//! Pretty printer generation.
+//!
+//! Emits `rustconf_runtime::Pretty` implementations on generated structs so
+//! that configuration and state trees can be logged with their YANG names,
+//! units and defaults instead of the derived `Debug` output.
+
+use crate::generator::naming::to_field_name;
+use crate::generator::restrictions::resolve_type;
+use crate::parser::{DataNode, Leaf, YangModule};
+
+/// Generate the `Pretty` implementation for the struct generated from `children`.
+///
+/// For list entry structs, `keys` names the key leaves, which are shown in
+/// the entry header instead of as leaves.
+pub(crate) fn generate_pretty_impl(
+    type_name: &str,
+    children: &[DataNode],
+    keys: Option<&[String]>,
+    module: &YangModule,
+) -> String {
+    let keys = keys.unwrap_or_default();
+    let mut body = String::new();
+    for child in children {
+        push_pretty_statement(&mut body, child, keys, module);
+    }
+
+    let mut output = String::new();
+    output.push_str(&format!(
+        "impl rustconf_runtime::Pretty for {} {{\n",
+        type_name
+    ));
+    if body.is_empty() {
+        output.push_str(
+            "    fn write_pretty(&self, _printer: &mut rustconf_runtime::PrettyPrinter) {}\n",
+        );
+    } else {
+        output.push_str(
+            "    fn write_pretty(&self, printer: &mut rustconf_runtime::PrettyPrinter) {\n",
+        );
+        output.push_str(&body);
+        output.push_str("    }\n");
+    }
+
+    if !keys.is_empty() {
+        let format: Vec<String> = keys.iter().map(|key| format!("{}={{}}", key)).collect();
+        let args: Vec<String> = keys
+            .iter()
+            .map(|key| {
+                format!(
+                    "rustconf_runtime::pretty::render(&self.{})",
+                    to_field_name(key)
+                )
+            })
+            .collect();
+        output.push('\n');
+        output.push_str("    fn pretty_key(&self) -> String {\n");
+        output.push_str(&format!(
+            "        format!(\"{}\", {})\n",
+            format.join(" "),
+            args.join(", ")
+        ));
+        output.push_str("    }\n");
+    }
+
+    output.push_str("}\n");
+    output
+}
+
+/// Push the statement printing the field generated for `node`.
+fn push_pretty_statement(body: &mut String, node: &DataNode, keys: &[String], module: &YangModule) {
+    match node {
+        DataNode::Leaf(leaf) => {
+            if keys.contains(&leaf.name) {
+                return;
+            }
+            let field = to_field_name(&leaf.name);
+            let value = if leaf.mandatory {
+                format!("Some(&self.{})", field)
+            } else {
+                format!("self.{}.as_ref()", field)
+            };
+            let (units, default) = leaf_annotations(leaf, module);
+            body.push_str(&format!(
+                "        printer.leaf(\"{}\", {}, {}, {});\n",
+                leaf.name,
+                value,
+                string_option(units),
+                string_option(default)
+            ));
+        }
+        DataNode::LeafList(leaf_list) => {
+            body.push_str(&format!(
+                "        printer.leaf_list(\"{}\", &self.{}, None);\n",
+                leaf_list.name,
+                to_field_name(&leaf_list.name)
+            ));
+        }
+        DataNode::Container(container) => {
+            let field = to_field_name(&container.name);
+            let value = if container.mandatory {
+                format!("Some(&self.{})", field)
+            } else {
+                format!("self.{}.as_ref()", field)
+            };
+            body.push_str(&format!(
+                "        printer.container(\"{}\", {});\n",
+                container.name, value
+            ));
+        }
+        DataNode::List(list) => {
+            body.push_str(&format!(
+                "        printer.list(\"{}\", &self.{});\n",
+                list.name,
+                to_field_name(&list.name)
+            ));
+        }
+        DataNode::AnyData(anydata) => {
+            // The content is shown in its JSON form
+            let field = to_field_name(&anydata.name);
+            let value = if anydata.mandatory {
+                format!("Some(&self.{})", field)
+            } else {
+                format!("self.{}.as_ref()", field)
+            };
+            body.push_str(&format!(
+                "        printer.leaf(\"{}\", {}, None, None);\n",
+                anydata.name, value
+            ));
+        }
+        DataNode::Choice(choice) => {
+            // The selected case is shown in its JSON form
+            let field = to_field_name(&choice.name);
+            let value = if choice.mandatory {
+                format!("Some(&self.{})", field)
+            } else {
+                format!("self.{}.as_ref()", field)
+            };
+            body.push_str(&format!(
+                "        printer.leaf(\"{}\", {}, None, None);\n",
+                choice.name, value
+            ));
+        }
+        DataNode::Case(_) | DataNode::Uses(_) => {}
+    }
+}
+
+/// The units and default of a leaf, falling back to those of its typedefs.
+fn leaf_annotations<'a>(
+    leaf: &'a Leaf,
+    module: &'a YangModule,
+) -> (Option<&'a str>, Option<&'a str>) {
+    let typedefs = resolve_type(&leaf.type_spec, module).typedefs;
+    let units = leaf
+        .units
+        .as_deref()
+        .or_else(|| typedefs.iter().find_map(|typedef| typedef.units.as_deref()));
+    let default = leaf.default.as_deref().or_else(|| {
+        typedefs
+            .iter()
+            .find_map(|typedef| typedef.default.as_deref())
+    });
+    (units, default)
+}
+
+fn string_option(value: Option<&str>) -> String {
+    match value {
+        Some(value) => format!("Some({:?})", value),
+        None => "None".to_string(),
+    }
+}
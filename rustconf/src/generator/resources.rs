@@ -0,0 +1,937 @@
+//! Resource handle generation.
+//!
+//! Generates a `resources` module with a handle type for each top-level
+//! container, list and leaf, reached through an extension trait on
+//! `RestconfClient`: `client.interfaces().item("eth0").delete()`. For large
+//! models the handles and their methods show up in IDE completion, where the
+//! free functions of the `crud` module have to be searched for by name.
+//! Handles build their path once and send bodies in the RFC 7951 envelope of
+//! their node; write methods are only generated for configuration data.
+//! Container handles also get and set their scalar leaves one at a time:
+//! `client.system().set_mtu(9000)`.
+
+use crate::generator::config::GeneratorConfig;
+use crate::generator::docs::rustdoc;
+use crate::generator::naming::{to_field_name, to_snake_case, to_type_name};
+use crate::generator::paths::PathGenerator;
+use crate::generator::restrictions::resolve_type;
+use crate::generator::types::TypeGenerator;
+use crate::parser::{Container, DataNode, Leaf, List, TypeSpec, YangModule};
+
+/// Statement sending a GET for the resource into `response`.
+const GET_REQUEST: &str =
+    "let response = self.client.raw_request(HttpMethod::GET, &self.path, None).await?;";
+
+/// Doc comment lines for the errors of a request, at the method indentation.
+const ERRORS_DOC: &str = "            ///\n            /// # Errors\n            ///\n            /// Returns an error if the request fails or the server responds with a non-2xx status.\n";
+
+/// A top-level data node that gets a resource handle.
+enum Resource<'a> {
+    Container(&'a Container),
+    List(&'a List),
+    Leaf(&'a Leaf),
+}
+
+impl Resource<'_> {
+    fn name(&self) -> &str {
+        match self {
+            Resource::Container(container) => &container.name,
+            Resource::List(list) => &list.name,
+            Resource::Leaf(leaf) => &leaf.name,
+        }
+    }
+
+    fn description(&self) -> Option<&str> {
+        match self {
+            Resource::Container(container) => container.description.as_deref(),
+            Resource::List(list) => list.description.as_deref(),
+            Resource::Leaf(leaf) => leaf.description.as_deref(),
+        }
+    }
+
+    fn config(&self) -> bool {
+        match self {
+            Resource::Container(container) => container.config,
+            Resource::List(list) => list.config,
+            Resource::Leaf(leaf) => leaf.config,
+        }
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Resource::Container(_) => "container",
+            Resource::List(_) => "list",
+            Resource::Leaf(_) => "leaf",
+        }
+    }
+
+    /// Name of the handle type for the node.
+    fn handle_name(&self) -> String {
+        format!("{}Resource", to_type_name(self.name()))
+    }
+}
+
+/// How handles refer to the client: generic over the transport, or as
+/// `&dyn RestconfClientApi` when dyn clients are enabled.
+#[derive(Clone, Copy)]
+struct Generics {
+    dyn_client: bool,
+}
+
+impl Generics {
+    /// Type of a handle, borrowing the client for `lifetime`.
+    fn handle_type(self, handle: &str, lifetime: &str) -> String {
+        if self.dyn_client {
+            format!("{}<{}>", handle, lifetime)
+        } else {
+            format!("{}<{}, T>", handle, lifetime)
+        }
+    }
+
+    /// Header of an inherent `impl` block of a handle, naming the lifetime of
+    /// the client if `named`.
+    fn impl_header(self, handle: &str, named: bool) -> String {
+        let lifetime = if named { "'a" } else { "'_" };
+        let params = match (named, self.dyn_client) {
+            (true, true) => "<'a>",
+            (true, false) => "<'a, T: HttpTransport>",
+            (false, true) => "",
+            (false, false) => "<T: HttpTransport>",
+        };
+        format!(
+            "        impl{} {} {{\n",
+            params,
+            self.handle_type(handle, lifetime)
+        )
+    }
+}
+
+/// Generate the `resources` module for the top-level data nodes of a module.
+///
+/// The module is placed inside `operations`, so it sees the data types,
+/// `consts` and the `percent_encode` helper through `use super::*`. Returns
+/// an empty string if the module has no containers, lists or leaves at the
+/// top level.
+pub(crate) fn generate_resources_module(module: &YangModule, config: &GeneratorConfig) -> String {
+    let resources: Vec<Resource> = module
+        .data_nodes
+        .iter()
+        .filter_map(|node| match node {
+            DataNode::Container(container) => Some(Resource::Container(container)),
+            DataNode::List(list) => Some(Resource::List(list)),
+            DataNode::Leaf(leaf) => Some(Resource::Leaf(leaf)),
+            _ => None,
+        })
+        .collect();
+    if resources.is_empty() {
+        return String::new();
+    }
+
+    let path_gen = PathGenerator::new(config);
+    let generics = Generics {
+        dyn_client: config.enable_dyn_client,
+    };
+    let trait_name = format!("{}Resources", to_type_name(&module.name));
+    let mut output = String::new();
+
+    output.push_str("    /// Typed handles for the data resources of the module.\n");
+    output.push_str("    ///\n");
+    output.push_str(&format!(
+        "    /// Bring `resources::{}` into scope to reach them from a `RestconfClient`.\n",
+        trait_name
+    ));
+    output.push_str("    pub mod resources {\n");
+    output.push_str("        use super::*;\n\n");
+
+    // Envelope helpers shared by the handles
+    output
+        .push_str("        /// Deserialize the contents of the `member` envelope of a response.\n");
+    output.push_str("        fn decode<V: serde::de::DeserializeOwned>(member: &str, response: &HttpResponse) -> Result<V, RpcError> {\n");
+    output.push_str("            rustconf_runtime::unwrap_envelope(member, &response.body)\n");
+    output.push_str("                .map_err(|e| RpcError::DeserializationError(format!(\"Failed to deserialize {}: {}\", member, e)))\n");
+    output.push_str("        }\n\n");
+    if resources.iter().any(Resource::config) {
+        output.push_str("        /// Serialize `value` inside the `member` envelope.\n");
+        output.push_str("        fn encode<V: serde::Serialize>(member: &str, value: &V) -> Result<Vec<u8>, RpcError> {\n");
+        output.push_str("            rustconf_runtime::wrap_envelope(member, value)\n");
+        output.push_str("                .map_err(|e| RpcError::SerializationError(format!(\"Failed to serialize {}: {}\", member, e)))\n");
+        output.push_str("        }\n\n");
+    }
+
+    // Extension trait giving the client a method per resource
+    output.push_str(&format!(
+        "        /// Entry points to the data resources of the `{}` module.\n",
+        module.name
+    ));
+    output.push_str(&format!(
+        "        pub trait {}{} {{\n",
+        trait_name,
+        if generics.dyn_client {
+            ""
+        } else {
+            "<T: HttpTransport>"
+        }
+    ));
+    for resource in &resources {
+        match resource.description() {
+            Some(description) => output.push_str(&rustdoc(description, "            ")),
+            None => output.push_str(&format!(
+                "            /// The `{}` {}.\n",
+                resource.name(),
+                resource.kind()
+            )),
+        }
+        output.push_str(&format!(
+            "            fn {}(&self) -> {};\n",
+            to_field_name(resource.name()),
+            generics.handle_type(&resource.handle_name(), "'_")
+        ));
+    }
+    output.push_str("        }\n\n");
+
+    // Dyn handles are also reachable from a `&dyn RestconfClientApi`
+    let implementors: &[(&str, &str)] = if generics.dyn_client {
+        &[
+            ("<T: HttpTransport>", "RestconfClient<T>"),
+            ("", "dyn RestconfClientApi + '_"),
+        ]
+    } else {
+        &[("<T: HttpTransport>", "RestconfClient<T>")]
+    };
+    for (params, implementor) in implementors {
+        output.push_str(&format!(
+            "        impl{} {}{} for {} {{\n",
+            params,
+            trait_name,
+            if generics.dyn_client { "" } else { "<T>" },
+            implementor
+        ));
+        for resource in &resources {
+            output.push_str(&format!(
+                "            fn {}(&self) -> {} {{\n",
+                to_field_name(resource.name()),
+                generics.handle_type(&resource.handle_name(), "'_")
+            ));
+            output.push_str(&format!(
+                "                {} {{ client: self, path: \"{}\".to_string() }}\n",
+                resource.handle_name(),
+                path_gen.data_path(resource.name(), module)
+            ));
+            output.push_str("            }\n");
+        }
+        output.push_str("        }\n");
+    }
+
+    for resource in &resources {
+        // Bodies use the module-qualified member name of RFC 7951
+        let member = member_name(module, resource.name());
+        output.push('\n');
+        match resource {
+            Resource::Container(container) => {
+                output.push_str(&generate_container_handle(
+                    container, &member, module, config, generics,
+                ));
+            }
+            Resource::List(list) => {
+                output.push_str(&generate_list_handles(
+                    list, &member, &path_gen, config, generics,
+                ));
+            }
+            Resource::Leaf(leaf) => {
+                output.push_str(&generate_leaf_handle(
+                    leaf, &member, module, config, generics,
+                ));
+            }
+        }
+    }
+
+    output.push_str("    }\n");
+    output
+}
+
+/// Generate the handle type of a container.
+///
+/// Scalar configuration leaves of the container also get `get_<leaf>` and
+/// `set_<leaf>` methods addressing the leaf alone, so changing one of them
+/// does not send the whole container.
+fn generate_container_handle(
+    container: &Container,
+    member: &str,
+    module: &YangModule,
+    config: &GeneratorConfig,
+    generics: Generics,
+) -> String {
+    let handle = format!("{}Resource", to_type_name(&container.name));
+    let type_name = to_type_name(&container.name);
+    let type_gen = TypeGenerator::new(config);
+    let accessors = leaf_accessors(container, module);
+    let mut output = String::new();
+
+    for (leaf, _) in &accessors {
+        output.push_str(&int64_wrapper(
+            &format!("{}{}", type_name, to_type_name(&leaf.name)),
+            leaf,
+            module,
+            &type_gen,
+        ));
+    }
+
+    output.push_str(&handle_struct(
+        &handle,
+        &format!("Handle for the `{}` container.", container.name),
+        generics,
+    ));
+    output.push_str(&generics.impl_header(&handle, false));
+    output.push_str(&path_method());
+
+    let decode = [format!("let value = decode(\"{}\", &response)?;", member)];
+    output.push_str(&method_with_full(
+        "get",
+        "Retrieve the container.",
+        "",
+        &type_name,
+        &[&[GET_REQUEST.to_string()], &decode[..]].concat(),
+        "value",
+    ));
+    output.push_str(&get_with_origin_method("container", &type_name, &decode));
+
+    if container.config {
+        output.push_str(&write_method(
+            "put",
+            "PUT",
+            "Replace the container with `data`.",
+            &format!("data: &{}", type_name),
+            &format!("encode(\"{}\", data)", member),
+        ));
+        output.push_str(&write_method(
+            "patch",
+            "PATCH",
+            "Merge `data` into the container.",
+            &format!("data: &{}", type_name),
+            &format!("encode(\"{}\", data)", member),
+        ));
+        output.push_str(&delete_method("Delete the container."));
+    }
+
+    for (leaf, name) in &accessors {
+        output.push_str(&leaf_accessor_methods(
+            leaf,
+            name,
+            &format!("{}{}", type_name, to_type_name(&leaf.name)),
+            module,
+            &type_gen,
+        ));
+    }
+
+    output.push_str(&exists_and_options_methods());
+    output.push_str("        }\n");
+    output
+}
+
+/// Generate the handle type of a top-level leaf.
+///
+/// The body is the RFC 7951 scalar inside the leaf's member. 64-bit integers
+/// are wrapped in a newtype carrying the configured string encoding, and the
+/// `empty` type is encoded as `[null]`, so its handle has no value to pass.
+fn generate_leaf_handle(
+    leaf: &Leaf,
+    member: &str,
+    module: &YangModule,
+    config: &GeneratorConfig,
+    generics: Generics,
+) -> String {
+    let handle = format!("{}Resource", to_type_name(&leaf.name));
+    let type_gen = TypeGenerator::new(config);
+    let value_type = type_gen.generate_leaf_type(&leaf.type_spec, true);
+    let mut int64_attrs = Vec::new();
+    type_gen.push_int64_serde_attrs(&mut int64_attrs, &leaf.type_spec, false, module);
+    let empty = is_empty_type(&leaf.type_spec, module);
+    let mut output = String::new();
+
+    let wrapper = format!("{}Value", to_type_name(&leaf.name));
+    output.push_str(&int64_wrapper(
+        &to_type_name(&leaf.name),
+        leaf,
+        module,
+        &type_gen,
+    ));
+
+    output.push_str(&handle_struct(
+        &handle,
+        &format!("Handle for the `{}` leaf.", leaf.name),
+        generics,
+    ));
+    output.push_str(&generics.impl_header(&handle, false));
+    output.push_str(&path_method());
+
+    let (doc, body_type, decode, value) = if empty {
+        (
+            "Check that the leaf is set by retrieving it.",
+            "()",
+            format!("decode::<[(); 1]>(\"{}\", &response)?;", member),
+            "()",
+        )
+    } else if int64_attrs.is_empty() {
+        (
+            "Retrieve the leaf value.",
+            value_type.as_str(),
+            format!("let value = decode(\"{}\", &response)?;", member),
+            "value",
+        )
+    } else {
+        (
+            "Retrieve the leaf value.",
+            value_type.as_str(),
+            format!(
+                "let value: {} = decode(\"{}\", &response)?;",
+                wrapper, member
+            ),
+            "value.0",
+        )
+    };
+    output.push_str(&method_with_full(
+        "get",
+        doc,
+        "",
+        body_type,
+        &[GET_REQUEST.to_string(), decode],
+        value,
+    ));
+
+    if leaf.config {
+        if empty {
+            output.push_str(&write_method(
+                "put",
+                "PUT",
+                "Set the leaf.",
+                "",
+                &format!("encode(\"{}\", &[()])", member),
+            ));
+        } else {
+            let value = if int64_attrs.is_empty() {
+                "&value".to_string()
+            } else {
+                format!("&{}(value)", wrapper)
+            };
+            output.push_str(&write_method(
+                "put",
+                "PUT",
+                "Set the leaf to `value`.",
+                &format!("value: {}", value_type),
+                &format!("encode(\"{}\", {})", member, value),
+            ));
+        }
+        output.push_str(&delete_method("Delete the leaf."));
+    }
+
+    output.push_str(&exists_and_options_methods());
+    output.push_str("        }\n");
+    output
+}
+
+/// Scalar configuration leaves of a container that get their own accessors,
+/// with the name the accessors are derived from.
+///
+/// `empty` leaves have no value to get or set, and leaves named `full` or
+/// `with-origin` would clash with `get_full` and `get_with_origin` of the
+/// container.
+fn leaf_accessors<'a>(container: &'a Container, module: &YangModule) -> Vec<(&'a Leaf, String)> {
+    if !container.config {
+        return Vec::new();
+    }
+    container
+        .children
+        .iter()
+        .filter_map(|node| match node {
+            DataNode::Leaf(leaf) if leaf.config && !is_empty_type(&leaf.type_spec, module) => {
+                let name = to_snake_case(&leaf.name);
+                (name != "full" && name != "with_origin").then_some((leaf, name))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Generate the newtype applying the 64-bit integer encoding to the bare
+/// value of a leaf, named `{prefix}Value`.
+///
+/// Returns an empty string if the leaf is not a 64-bit integer.
+fn int64_wrapper(
+    prefix: &str,
+    leaf: &Leaf,
+    module: &YangModule,
+    type_gen: &TypeGenerator,
+) -> String {
+    let mut int64_attrs = Vec::new();
+    type_gen.push_int64_serde_attrs(&mut int64_attrs, &leaf.type_spec, false, module);
+    if int64_attrs.is_empty() {
+        return String::new();
+    }
+    let mut output = String::new();
+    output.push_str(&format!(
+        "        /// RFC 7951 encoding of the `{}` leaf value.\n",
+        leaf.name
+    ));
+    output.push_str("        #[derive(serde::Serialize, serde::Deserialize)]\n");
+    output.push_str(&format!(
+        "        struct {}Value(#[serde({})] {});\n\n",
+        prefix,
+        int64_attrs.join(", "),
+        type_gen.generate_leaf_type(&leaf.type_spec, true)
+    ));
+    output
+}
+
+/// Generate `get_<name>` and `set_<name>` for a leaf of a container handle,
+/// sending requests to the leaf's own path below the container.
+///
+/// Like a top-level leaf, the body is the RFC 7951 scalar inside the
+/// module-qualified member of the leaf. `prefix` names the 64-bit integer
+/// newtype from [`int64_wrapper`].
+fn leaf_accessor_methods(
+    leaf: &Leaf,
+    name: &str,
+    prefix: &str,
+    module: &YangModule,
+    type_gen: &TypeGenerator,
+) -> String {
+    let member = member_name(module, &leaf.name);
+    let value_type = type_gen.generate_leaf_type(&leaf.type_spec, true);
+    let mut int64_attrs = Vec::new();
+    type_gen.push_int64_serde_attrs(&mut int64_attrs, &leaf.type_spec, false, module);
+    let path = format!("let path = format!(\"{{}}/{}\", self.path);", leaf.name);
+    let (decode, value, encoded) = if int64_attrs.is_empty() {
+        (
+            format!("let value = decode(\"{}\", &response)?;", member),
+            "value",
+            "&value".to_string(),
+        )
+    } else {
+        (
+            format!(
+                "let value: {}Value = decode(\"{}\", &response)?;",
+                prefix, member
+            ),
+            "value.0",
+            format!("&{}Value(value)", prefix),
+        )
+    };
+    let mut output = String::new();
+
+    output.push('\n');
+    output.push_str(&method_with_full(
+        &format!("get_{}", name),
+        &format!("Retrieve the value of the `{}` leaf alone.", leaf.name),
+        "",
+        &value_type,
+        &[
+            path.clone(),
+            "let response = self.client.raw_request(HttpMethod::GET, &path, None).await?;"
+                .to_string(),
+            decode,
+        ],
+        value,
+    ));
+    output.push('\n');
+    output.push_str(&method_with_full(
+        &format!("set_{}", name),
+        &format!(
+            "Set the `{}` leaf to `value`, leaving the rest of the container unchanged.",
+            leaf.name
+        ),
+        &format!("value: {}", value_type),
+        "()",
+        &[
+            path,
+            format!("let body = encode(\"{}\", {})?;", member, encoded),
+            "let response = self.client.raw_request(HttpMethod::PUT, &path, Some(body.into())).await?;"
+                .to_string(),
+        ],
+        "()",
+    ));
+    output
+}
+
+/// The RFC 7951 member of the node `name` of `module`.
+///
+/// Nodes augmented in from another module are already named
+/// `module:name` after the module of their namespace, and are kept as is.
+fn member_name(module: &YangModule, name: &str) -> String {
+    if name.contains(':') {
+        name.to_string()
+    } else {
+        format!("{}:{}", module.name, name)
+    }
+}
+
+/// Whether a type is `empty`, possibly through typedefs.
+fn is_empty_type(type_spec: &TypeSpec, module: &YangModule) -> bool {
+    matches!(
+        resolve_type(type_spec, module).type_spec,
+        Some(TypeSpec::Empty)
+    )
+}
+
+/// Generate the collection and entry handle types of a list.
+fn generate_list_handles(
+    list: &List,
+    member: &str,
+    path_gen: &PathGenerator,
+    config: &GeneratorConfig,
+    generics: Generics,
+) -> String {
+    let type_name = to_type_name(&list.name);
+    let handle = format!("{}Resource", type_name);
+    let item_handle = format!("{}ItemResource", type_name);
+    // Entries use the singular type name of the list
+    let item_type_name = config.item_type_name(&list.name);
+    let mut output = String::new();
+
+    // Collection handle
+    output.push_str(&handle_struct(
+        &handle,
+        &format!("Handle for the `{}` list.", list.name),
+        generics,
+    ));
+    // Entries can only be addressed in lists with keys
+    let keys = path_gen.list_keys(list);
+    output.push_str(&generics.impl_header(&handle, !keys.is_empty()));
+    output.push_str(&path_method());
+
+    let decode = [format!("let value = decode(\"{}\", &response)?;", member)];
+    output.push_str(&method_with_full(
+        "get",
+        "Retrieve all entries of the list.",
+        "",
+        &format!("Vec<{}>", item_type_name),
+        &[&[GET_REQUEST.to_string()], &decode[..]].concat(),
+        "value",
+    ));
+    output.push_str(&get_with_origin_method(
+        "list",
+        &format!("Vec<{}>", item_type_name),
+        &decode,
+    ));
+
+    if list.config {
+        output.push('\n');
+        // Entries are created by posting them to the parent of the list
+        output.push_str(&method_with_full(
+            "post",
+            "Create a new entry, which must not exist yet.",
+            &format!("entry: &{}", item_type_name),
+            "()",
+            &[
+                format!("let body = encode(\"{}\", &[entry])?;", member),
+                "let response = self.client.raw_request(HttpMethod::POST, consts::DATA_PATH, Some(body.into())).await?;".to_string(),
+            ],
+            "()",
+        ));
+    }
+
+    if keys.is_empty() {
+        output.push_str(&exists_and_options_methods());
+        output.push_str("        }\n");
+        return output;
+    }
+
+    let params: Vec<String> = keys
+        .iter()
+        .map(|(name, key_type)| match key_type.as_str() {
+            "String" => format!("{}: impl Into<String>", name),
+            _ => format!("{}: {}", name, key_type),
+        })
+        .collect();
+    output.push('\n');
+    output.push_str("            /// Handle for the entry with the given keys.\n");
+    output.push_str(&format!(
+        "            pub fn item(&self, {}) -> {} {{\n",
+        params.join(", "),
+        generics.handle_type(&item_handle, "'a")
+    ));
+    let mut encoded_keys = Vec::new();
+    for (name, key_type) in &keys {
+        if key_type == "String" {
+            output.push_str(&format!(
+                "                let {}: String = {}.into();\n",
+                name, name
+            ));
+            encoded_keys.push(format!("percent_encode(&{})", name));
+        } else {
+            encoded_keys.push(format!("percent_encode(&{}.to_string())", name));
+        }
+    }
+    output.push_str(&format!("                {} {{\n", item_handle));
+    output.push_str("                    client: self.client,\n");
+    output.push_str(&format!(
+        "                    path: format!(\"{{}}={}\", self.path, {}),\n",
+        vec!["{}"; encoded_keys.len()].join(","),
+        encoded_keys.join(", ")
+    ));
+    output.push_str("                }\n");
+    output.push_str("            }\n");
+
+    if list.config {
+        let key_names: Vec<String> = list.keys.iter().map(|key| format!("\"{}\"", key)).collect();
+        output.push('\n');
+        output.push_str(
+            "            /// Replace all entries of the list with `entries`, a batch at a time.\n",
+        );
+        output.push_str("            ///\n");
+        output.push_str("            /// Set the batch size, progress callback or point to resume from on the\n");
+        output.push_str("            /// returned [`ChunkedWrite`](rustconf_runtime::ChunkedWrite) and `run` it.\n");
+        output.push_str(&format!(
+            "            pub fn replace_chunked(&self, entries: &'a [{}]) -> rustconf_runtime::ChunkedWrite<'a, {}> {{\n",
+            item_type_name, item_type_name
+        ));
+        output.push_str(&format!(
+            "                rustconf_runtime::ChunkedWrite::new(self.client, self.path.clone(), \"{}\", &[{}], entries)\n",
+            member,
+            key_names.join(", ")
+        ));
+        output.push_str("            }\n");
+    }
+
+    output.push_str(&exists_and_options_methods());
+    output.push_str("        }\n\n");
+
+    // Entry handle
+    output.push_str(&handle_struct(
+        &item_handle,
+        &format!("Handle for an entry of the `{}` list.", list.name),
+        generics,
+    ));
+    output.push_str(&generics.impl_header(&item_handle, false));
+    output.push_str(&path_method());
+
+    // An entry is returned as a list with a single element
+    let decode = [
+        format!(
+            "let entries: Vec<{}> = decode(\"{}\", &response)?;",
+            item_type_name, member
+        ),
+        "let value = entries.into_iter().next().ok_or_else(|| {".to_string(),
+        format!(
+            "    RpcError::DeserializationError(\"Response has no `{}` entry\".to_string())",
+            member
+        ),
+        "})?;".to_string(),
+    ];
+    output.push_str(&method_with_full(
+        "get",
+        "Retrieve the entry.",
+        "",
+        &item_type_name,
+        &[&[GET_REQUEST.to_string()], &decode[..]].concat(),
+        "value",
+    ));
+    output.push_str(&get_with_origin_method("entry", &item_type_name, &decode));
+
+    if list.config {
+        output.push_str(&write_method(
+            "put",
+            "PUT",
+            "Create or replace the entry with `entry`.",
+            &format!("entry: &{}", item_type_name),
+            &format!("encode(\"{}\", &[entry])", member),
+        ));
+        output.push_str(&write_method(
+            "patch",
+            "PATCH",
+            "Merge `entry` into the entry.",
+            &format!("entry: &{}", item_type_name),
+            &format!("encode(\"{}\", &[entry])", member),
+        ));
+        output.push_str(&delete_method("Delete the entry."));
+    }
+
+    output.push_str(&exists_and_options_methods());
+    output.push_str("        }\n");
+    output
+}
+
+/// Generate a handle struct borrowing the client.
+fn handle_struct(name: &str, doc: &str, generics: Generics) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("        /// {}\n", doc));
+    output.push_str(
+        "        #[must_use = \"a resource handle does nothing until one of its methods is called\"]\n",
+    );
+    if generics.dyn_client {
+        output.push_str(&format!("        pub struct {}<'a> {{\n", name));
+        output.push_str("            client: &'a dyn RestconfClientApi,\n");
+    } else {
+        output.push_str(&format!(
+            "        pub struct {}<'a, T: HttpTransport> {{\n",
+            name
+        ));
+        output.push_str("            client: &'a RestconfClient<T>,\n");
+    }
+    output.push_str("            path: String,\n");
+    output.push_str("        }\n\n");
+    output
+}
+
+fn path_method() -> String {
+    let mut output = String::new();
+    output.push_str("            /// The RESTCONF path of the resource.\n");
+    output.push_str("            #[must_use]\n");
+    output.push_str("            pub fn path(&self) -> &str {\n");
+    output.push_str("                &self.path\n");
+    output.push_str("            }\n\n");
+    output
+}
+
+/// Generate a method and its `_full` variant, which also returns the status
+/// and headers of the response.
+///
+/// `lines` send the request into `response` and decode the body, which
+/// `value` then evaluates to. The plain method delegates to the variant.
+fn method_with_full(
+    name: &str,
+    doc: &str,
+    param: &str,
+    body_type: &str,
+    lines: &[String],
+    value: &str,
+) -> String {
+    let (params, args) = match param.split_once(':') {
+        Some((arg, _)) => (format!("&self, {}", param), arg),
+        None => ("&self".to_string(), ""),
+    };
+    let mut output = String::new();
+
+    output.push_str(&format!("            /// {}\n", doc));
+    output.push_str(ERRORS_DOC);
+    output.push_str(&format!(
+        "            pub async fn {}({}) -> Result<{}, RpcError> {{\n",
+        name, params, body_type
+    ));
+    output.push_str(&format!(
+        "                self.{}_full({}).await.map(ResponseEnvelope::into_body)\n",
+        name, args
+    ));
+    output.push_str("            }\n\n");
+
+    output.push_str(&format!(
+        "            /// Like [`{}`](Self::{}), also returning the response status and headers.\n",
+        name, name
+    ));
+    output.push_str(ERRORS_DOC);
+    output.push_str(&format!(
+        "            pub async fn {}_full({}) -> Result<ResponseEnvelope<{}>, RpcError> {{\n",
+        name, params, body_type
+    ));
+    for line in lines {
+        output.push_str(&format!("                {}\n", line));
+    }
+    output.push_str(&format!(
+        "                Ok(ResponseEnvelope::new(response, {}))\n",
+        value
+    ));
+    output.push_str("            }\n");
+    output
+}
+
+/// Generate `get_with_origin`, reading the resource from the operational
+/// datastore together with the origin of each node.
+///
+/// `decode` turns `response` into `value`, as for `get`.
+fn get_with_origin_method(kind: &str, body_type: &str, decode: &[String]) -> String {
+    let mut output = String::new();
+    output.push('\n');
+    output.push_str(&format!(
+        "            /// Retrieve the operational state of the {} with the origin of each node.\n",
+        kind
+    ));
+    output.push_str("            ///\n");
+    output.push_str(
+        "            /// Requires a server implementing NMDA (RFC 8527). Origins are keyed by\n",
+    );
+    output.push_str(
+        "            /// JSON Pointers into the response, starting with the module-qualified\n",
+    );
+    output.push_str("            /// member of the resource.\n");
+    output.push_str(ERRORS_DOC);
+    output.push_str(&format!(
+        "            pub async fn get_with_origin(&self) -> Result<rustconf_runtime::WithOrigin<{}>, RpcError> {{\n",
+        body_type
+    ));
+    output.push_str(
+        "                let path = rustconf_runtime::origin::with_origin_path(&self.path);\n",
+    );
+    output.push_str(
+        "                let response = self.client.raw_request(HttpMethod::GET, &path, None).await?;\n",
+    );
+    for line in decode {
+        output.push_str(&format!("                {}\n", line));
+    }
+    output.push_str(
+        "                let origins = rustconf_runtime::OriginTable::from_json(&response.body)?;\n",
+    );
+    output.push_str("                Ok(rustconf_runtime::WithOrigin { data: value, origins })\n");
+    output.push_str("            }\n");
+    output
+}
+
+/// Generate a method sending a body with `method` to the resource.
+fn write_method(name: &str, method: &str, doc: &str, param: &str, body: &str) -> String {
+    let mut output = String::new();
+    output.push('\n');
+    output.push_str(&method_with_full(
+        name,
+        doc,
+        param,
+        "()",
+        &[
+            format!("let body = {}?;", body),
+            format!(
+                "let response = self.client.raw_request(HttpMethod::{}, &self.path, Some(body.into())).await?;",
+                method
+            ),
+        ],
+        "()",
+    ));
+    output
+}
+
+fn delete_method(doc: &str) -> String {
+    let mut output = String::new();
+    output.push('\n');
+    output.push_str(&method_with_full(
+        "delete",
+        doc,
+        "",
+        "()",
+        &[
+            "let response = self.client.raw_request(HttpMethod::DELETE, &self.path, None).await?;"
+                .to_string(),
+        ],
+        "()",
+    ));
+    output
+}
+
+fn exists_and_options_methods() -> String {
+    let mut output = String::new();
+    output.push('\n');
+    output.push_str("            /// Check whether the resource exists.\n");
+    output.push_str("            ///\n");
+    output.push_str("            /// Issues HEAD, falling back to GET with `depth=1` if the server rejects HEAD.\n");
+    output.push_str("            ///\n");
+    output.push_str("            /// # Errors\n");
+    output.push_str("            ///\n");
+    output.push_str("            /// Returns an error if the request fails or the server responds with a status other than 2xx or 404.\n");
+    output.push_str("            pub async fn exists(&self) -> Result<bool, RpcError> {\n");
+    output.push_str("                self.client.exists(&self.path).await\n");
+    output.push_str("            }\n\n");
+    output.push_str("            /// Discover the methods permitted on the resource.\n");
+    output.push_str(ERRORS_DOC);
+    output.push_str(
+        "            pub async fn options(&self) -> Result<ResourceCapabilities, RpcError> {\n",
+    );
+    output.push_str("                let response = self.client.raw_request(HttpMethod::OPTIONS, &self.path, None).await?;\n");
+    output.push_str("                ResourceCapabilities::try_from(&response)\n");
+    output.push_str("            }\n");
+    output
+}
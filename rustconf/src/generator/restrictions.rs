@@ -0,0 +1,226 @@
+//! Resolution of restricted typedef references prior to code generation.
+
+use std::collections::HashMap;
+
+use crate::parser::{
+    DataNode, LengthConstraint, PatternConstraint, RangeConstraint, TypeDef, TypeSpec, YangModule,
+};
+
+use super::GeneratorError;
+
+/// Typedefs defined in the module, by name.
+type TypedefTable<'m> = HashMap<&'m str, &'m TypeDef>;
+
+/// A type with the typedefs of the module it references resolved, from
+/// [`resolve_type`].
+pub(crate) struct ResolvedType<'m> {
+    /// The built-in type with the restrictions of the whole chain merged, or
+    /// `None` if the chain is circular or leads to a typedef that is not
+    /// defined in the module.
+    pub(crate) type_spec: Option<TypeSpec>,
+    /// The typedefs the type goes through, starting with the one it
+    /// references.
+    pub(crate) typedefs: Vec<&'m TypeDef>,
+}
+
+/// Resolve the typedefs of the module that a type references, whether the
+/// reference restricts them or not.
+pub(crate) fn resolve_type<'m>(type_spec: &TypeSpec, module: &'m YangModule) -> ResolvedType<'m> {
+    if !matches!(type_spec, TypeSpec::TypedefRef { .. }) {
+        return ResolvedType {
+            type_spec: Some(type_spec.clone()),
+            typedefs: Vec::new(),
+        };
+    }
+    let mut typedefs = Vec::new();
+    let type_spec = resolve_reference(type_spec, &typedef_table(module), &mut typedefs)
+        .ok()
+        .flatten();
+    ResolvedType {
+        type_spec,
+        typedefs,
+    }
+}
+
+/// Resolve typedef references that narrow their typedef, such as
+/// `type port-number { range "1..1024"; }`, into the typedef's underlying type
+/// with the restrictions of the whole chain merged.
+///
+/// Unrestricted references are left alone so they keep generating type aliases.
+/// Only typedefs defined in the module itself can be resolved; restricted
+/// references to imported typedefs are left unchanged.
+pub(crate) fn resolve_restricted_typedefs(
+    module: &YangModule,
+) -> Result<YangModule, GeneratorError> {
+    let typedefs = typedef_table(module);
+
+    let mut resolved = module.clone();
+    for typedef in &mut resolved.typedefs {
+        resolve_typespec(&mut typedef.type_spec, &typedefs)?;
+    }
+    resolve_data_nodes(&mut resolved.data_nodes, &typedefs)?;
+    for grouping in &mut resolved.groupings {
+        resolve_data_nodes(&mut grouping.data_nodes, &typedefs)?;
+    }
+    for rpc in &mut resolved.rpcs {
+        if let Some(input) = &mut rpc.input {
+            resolve_data_nodes(input, &typedefs)?;
+        }
+        if let Some(output) = &mut rpc.output {
+            resolve_data_nodes(output, &typedefs)?;
+        }
+    }
+    for notification in &mut resolved.notifications {
+        resolve_data_nodes(&mut notification.data_nodes, &typedefs)?;
+    }
+    Ok(resolved)
+}
+
+fn typedef_table(module: &YangModule) -> TypedefTable<'_> {
+    module
+        .typedefs
+        .iter()
+        .map(|typedef| (typedef.name.as_str(), typedef))
+        .collect()
+}
+
+fn resolve_data_nodes(
+    data_nodes: &mut [DataNode],
+    typedefs: &TypedefTable,
+) -> Result<(), GeneratorError> {
+    for data_node in data_nodes {
+        match data_node {
+            DataNode::Container(container) => {
+                resolve_data_nodes(&mut container.children, typedefs)?
+            }
+            DataNode::List(list) => resolve_data_nodes(&mut list.children, typedefs)?,
+            DataNode::Leaf(leaf) => resolve_typespec(&mut leaf.type_spec, typedefs)?,
+            DataNode::LeafList(leaf_list) => resolve_typespec(&mut leaf_list.type_spec, typedefs)?,
+            DataNode::Choice(choice) => {
+                for case in &mut choice.cases {
+                    resolve_data_nodes(&mut case.data_nodes, typedefs)?;
+                }
+            }
+            DataNode::Case(case) => resolve_data_nodes(&mut case.data_nodes, typedefs)?,
+            DataNode::AnyData(_) | DataNode::Uses(_) => {}
+        }
+    }
+    Ok(())
+}
+
+/// Replace a restricted typedef reference with its merged underlying type.
+fn resolve_typespec(
+    type_spec: &mut TypeSpec,
+    typedefs: &TypedefTable,
+) -> Result<(), GeneratorError> {
+    if !type_spec.is_restricted_typedef_ref() {
+        return Ok(());
+    }
+    if let Some(resolved) = resolve_reference(type_spec, typedefs, &mut Vec::new())? {
+        *type_spec = resolved;
+    }
+    Ok(())
+}
+
+/// Resolve a typedef reference to its built-in type, with the restrictions of
+/// the reference and of the typedefs it goes through merged. The typedefs
+/// are left in `chain`, in order.
+///
+/// Returns `None` for other types and if the chain leads to a typedef that is
+/// not defined in the module.
+fn resolve_reference<'m>(
+    type_spec: &TypeSpec,
+    typedefs: &TypedefTable<'m>,
+    chain: &mut Vec<&'m TypeDef>,
+) -> Result<Option<TypeSpec>, GeneratorError> {
+    let TypeSpec::TypedefRef {
+        name,
+        range,
+        length,
+        pattern,
+    } = type_spec
+    else {
+        return Ok(None);
+    };
+    let Some(mut resolved) = resolve_chain(name, typedefs, chain)? else {
+        return Ok(None);
+    };
+    restrict(
+        &mut resolved,
+        name,
+        range.clone(),
+        length.clone(),
+        pattern.clone(),
+    )?;
+    Ok(Some(resolved))
+}
+
+/// Resolve a typedef to its built-in type, merging restrictions along the way.
+///
+/// Returns `None` if the chain leads to a typedef that is not defined in the module.
+fn resolve_chain<'m>(
+    name: &str,
+    typedefs: &TypedefTable<'m>,
+    chain: &mut Vec<&'m TypeDef>,
+) -> Result<Option<TypeSpec>, GeneratorError> {
+    let Some(typedef) = typedefs.get(name).copied() else {
+        return Ok(None);
+    };
+    if chain.iter().any(|visited| visited.name == typedef.name) {
+        return Err(GeneratorError::CodeGeneration(format!(
+            "Circular typedef reference: {}",
+            name
+        )));
+    }
+
+    chain.push(typedef);
+    let resolved = match &typedef.type_spec {
+        TypeSpec::TypedefRef {
+            name: base,
+            range,
+            length,
+            pattern,
+        } => match resolve_chain(base, typedefs, chain)? {
+            Some(mut resolved) => {
+                restrict(
+                    &mut resolved,
+                    base,
+                    range.clone(),
+                    length.clone(),
+                    pattern.clone(),
+                )?;
+                Some(resolved)
+            }
+            None => None,
+        },
+        other => Some(other.clone()),
+    };
+
+    Ok(resolved)
+}
+
+/// Apply a derived type's restrictions to the type of the typedef it references.
+fn restrict(
+    type_spec: &mut TypeSpec,
+    typedef: &str,
+    range: Option<RangeConstraint>,
+    length: Option<LengthConstraint>,
+    pattern: Option<PatternConstraint>,
+) -> Result<(), GeneratorError> {
+    let invalid = |message: String| {
+        GeneratorError::CodeGeneration(format!(
+            "Invalid restriction of typedef '{}': {}",
+            typedef, message
+        ))
+    };
+    if let Some(range) = range {
+        type_spec.restrict_derived_range(range).map_err(invalid)?;
+    }
+    if let Some(length) = length {
+        type_spec.restrict_length(length).map_err(invalid)?;
+    }
+    if let Some(pattern) = pattern {
+        type_spec.restrict_pattern(pattern).map_err(invalid)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,261 @@
+//! Schema node enum generation.
+//!
+//! Emits a `schema` module with a `SchemaNode` enum naming every data node of
+//! the module, so the `error-path` of a RESTCONF error can be mapped back to
+//! the field that caused it.
+
+use std::collections::HashSet;
+
+use crate::generator::naming::{to_field_name, to_type_name};
+use crate::parser::{DataNode, YangModule};
+
+/// A data node as it appears in the data tree.
+struct SchemaEntry {
+    variant: String,
+    path: String,
+    field_name: String,
+    parent: Option<usize>,
+    /// Key leaves of a list, empty for other nodes.
+    keys: Vec<String>,
+    when: Option<String>,
+}
+
+/// Generate the items of the `schema` module for a YANG module.
+///
+/// Every line is prefixed with `indent`, so the items can be written either to
+/// their own file or inside a `pub mod schema { ... }` block.
+pub(crate) fn generate_schema_items(module: &YangModule, indent: &str) -> String {
+    let mut entries = Vec::new();
+    let mut variants = HashSet::new();
+    collect_entries(&module.data_nodes, None, &mut entries, &mut variants);
+
+    let mut lines: Vec<String> = Vec::new();
+    lines.push(format!(
+        "/// Data nodes of the {} YANG module, e.g. for mapping the `error-path` of",
+        module.name
+    ));
+    lines.push("/// a RESTCONF error to the field that caused it.".to_string());
+    lines.push("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]".to_string());
+    lines.push("pub enum SchemaNode {".to_string());
+    for entry in &entries {
+        lines.push(format!("    /// `{}`", entry.path));
+        lines.push(format!("    {},", entry.variant));
+    }
+    lines.push("}".to_string());
+    lines.push(String::new());
+
+    lines.push("impl SchemaNode {".to_string());
+    lines.push("    /// All data nodes, in schema order.".to_string());
+    let all: Vec<String> = entries
+        .iter()
+        .map(|entry| format!("SchemaNode::{}", entry.variant))
+        .collect();
+    lines.push(format!(
+        "    pub const ALL: &[SchemaNode] = &[{}];",
+        all.join(", ")
+    ));
+    lines.push(String::new());
+
+    lines.push(
+        "    /// Schema path without module qualifiers or list keys, e.g. `/a/b`.".to_string(),
+    );
+    push_match(
+        &mut lines,
+        "schema_path",
+        "&'static str",
+        &entries,
+        |entry| format!("{:?}", entry.path),
+    );
+    lines.push(String::new());
+
+    lines.push("    /// Name of the Rust field holding the node in its parent struct.".to_string());
+    push_match(
+        &mut lines,
+        "field_name",
+        "&'static str",
+        &entries,
+        |entry| format!("{:?}", entry.field_name),
+    );
+    lines.push(String::new());
+
+    lines.push("    /// Parent node, or `None` for top-level nodes.".to_string());
+    push_match(
+        &mut lines,
+        "parent",
+        "Option<SchemaNode>",
+        &entries,
+        |entry| match entry.parent {
+            Some(parent) => format!("Some(SchemaNode::{})", entries[parent].variant),
+            None => "None".to_string(),
+        },
+    );
+    lines.push(String::new());
+
+    lines.push("    /// Names of the key leaves of a list, empty for other nodes.".to_string());
+    push_match(
+        &mut lines,
+        "list_keys",
+        "&'static [&'static str]",
+        &entries,
+        |entry| {
+            let keys: Vec<String> = entry.keys.iter().map(|key| format!("{:?}", key)).collect();
+            format!("&[{}]", keys.join(", "))
+        },
+    );
+    lines.push(String::new());
+
+    lines.push(
+        "    /// XPath condition of the node's `when` statement, for tooling that".to_string(),
+    );
+    lines
+        .push("    /// checks data against it. The node is only valid while it holds.".to_string());
+    push_match(
+        &mut lines,
+        "when",
+        "Option<&'static str>",
+        &entries,
+        |entry| match &entry.when {
+            Some(condition) => format!("Some({:?})", condition),
+            None => "None".to_string(),
+        },
+    );
+    lines.push(String::new());
+
+    lines.push("    /// Map a RESTCONF `error-path` to the node it identifies.".to_string());
+    lines.push("    ///".to_string());
+    lines.push("    /// Module qualifiers and list key predicates are ignored, so".to_string());
+    lines.push("    /// `/example:a/b[name='x']/c` identifies the node at `/a/b/c`.".to_string());
+    lines.push("    #[must_use]".to_string());
+    lines.push("    pub fn from_error_path(error_path: &str) -> Option<SchemaNode> {".to_string());
+    lines.push("        let path = strip_error_path(error_path);".to_string());
+    lines.push("        SchemaNode::ALL".to_string());
+    lines.push("            .iter()".to_string());
+    lines.push("            .copied()".to_string());
+    lines.push("            .find(|node| node.schema_path() == path)".to_string());
+    lines.push("    }".to_string());
+    lines.push("}".to_string());
+    lines.push(String::new());
+
+    lines
+        .push("/// Remove module qualifiers and key predicates from an instance path.".to_string());
+    lines.push("fn strip_error_path(error_path: &str) -> String {".to_string());
+    lines.push("    let mut path = String::new();".to_string());
+    lines.push("    let mut depth = 0;".to_string());
+    lines.push("    let mut quote = None;".to_string());
+    lines.push("    for c in error_path.chars() {".to_string());
+    lines.push("        match (quote, c) {".to_string());
+    lines.push("            (Some(q), _) if c == q => quote = None,".to_string());
+    lines.push("            (Some(_), _) => {}".to_string());
+    lines.push("            (None, '\\'' | '\"') if depth > 0 => quote = Some(c),".to_string());
+    lines.push("            (None, '[') => depth += 1,".to_string());
+    lines.push("            (None, ']') => depth -= 1,".to_string());
+    lines.push("            (None, _) if depth == 0 => path.push(c),".to_string());
+    lines.push("            _ => {}".to_string());
+    lines.push("        }".to_string());
+    lines.push("    }".to_string());
+    lines.push("    path.split('/')".to_string());
+    lines.push("        .filter(|segment| !segment.is_empty())".to_string());
+    lines.push("        .map(|segment| segment.rsplit(':').next().unwrap_or(segment))".to_string());
+    lines.push("        .fold(String::new(), |path, segment| path + \"/\" + segment)".to_string());
+    lines.push("}".to_string());
+
+    let mut output = String::new();
+    for line in lines {
+        if line.is_empty() {
+            output.push('\n');
+        } else {
+            output.push_str(&format!("{}{}\n", indent, line));
+        }
+    }
+    output
+}
+
+/// Push a `match *self` method returning one expression per entry.
+fn push_match(
+    lines: &mut Vec<String>,
+    name: &str,
+    return_type: &str,
+    entries: &[SchemaEntry],
+    value: impl Fn(&SchemaEntry) -> String,
+) {
+    lines.push("    #[must_use]".to_string());
+    lines.push(format!("    pub fn {}(&self) -> {} {{", name, return_type));
+    lines.push("        match *self {".to_string());
+    for entry in entries {
+        lines.push(format!(
+            "            SchemaNode::{} => {},",
+            entry.variant,
+            value(entry)
+        ));
+    }
+    lines.push("        }".to_string());
+    lines.push("    }".to_string());
+}
+
+/// Collect the data tree nodes below `parent`. Choices and cases are not part
+/// of the data tree, so their children are collected at the parent's level.
+fn collect_entries(
+    nodes: &[DataNode],
+    parent: Option<usize>,
+    entries: &mut Vec<SchemaEntry>,
+    variants: &mut HashSet<String>,
+) {
+    for node in nodes {
+        let (name, children, keys, when): (&str, &[DataNode], &[String], &Option<String>) =
+            match node {
+                DataNode::Container(container) => {
+                    (&container.name, &container.children, &[], &container.when)
+                }
+                DataNode::List(list) => (&list.name, &list.children, &list.keys, &list.when),
+                DataNode::Leaf(leaf) => (&leaf.name, &[], &[], &leaf.when),
+                DataNode::LeafList(leaf_list) => (&leaf_list.name, &[], &[], &leaf_list.when),
+                DataNode::AnyData(anydata) => (&anydata.name, &[], &[], &anydata.when),
+                DataNode::Choice(choice) => {
+                    for case in &choice.cases {
+                        collect_entries(&case.data_nodes, parent, entries, variants);
+                    }
+                    continue;
+                }
+                DataNode::Case(case) => {
+                    collect_entries(&case.data_nodes, parent, entries, variants);
+                    continue;
+                }
+                DataNode::Uses(_) => continue,
+            };
+
+        let (parent_variant, parent_path) = match parent {
+            Some(index) => (
+                entries[index].variant.as_str(),
+                entries[index].path.as_str(),
+            ),
+            None => ("", ""),
+        };
+        // Escaped names (`_2g`) lose their underscore when appended to a parent
+        let type_name = to_type_name(name);
+        let mut variant = if parent_variant.is_empty() {
+            type_name
+        } else {
+            format!("{}{}", parent_variant, type_name.trim_start_matches('_'))
+        };
+        // Different paths can concatenate to the same name (`a-b/c` and `a/b-c`)
+        let base = variant.clone();
+        let mut suffix = 2;
+        while !variants.insert(variant.clone()) {
+            variant = format!("{}{}", base, suffix);
+            suffix += 1;
+        }
+
+        // Nodes added by other modules' augments are named `module:name`
+        let local_name = name.rsplit(':').next().unwrap_or(name);
+        entries.push(SchemaEntry {
+            variant,
+            path: format!("{}/{}", parent_path, local_name),
+            field_name: to_field_name(name).trim_start_matches("r#").to_string(),
+            parent,
+            keys: keys.to_vec(),
+            when: when.clone(),
+        });
+        let index = entries.len() - 1;
+        collect_entries(children, Some(index), entries, variants);
+    }
+}
@@ -95,7 +95,7 @@ impl<'a> ServerHandlerGenerator<'a> {
 
         // Generate method documentation
         if let Some(ref description) = rpc.description {
-            output.push_str(&self.generate_rustdoc(description));
+            output.push_str(&crate::generator::docs::rustdoc(description, "    "));
         } else {
             output.push_str(&format!("    /// Execute {} RPC operation.\n", rpc.name));
         }
@@ -215,15 +215,9 @@ impl<'a> ServerHandlerGenerator<'a> {
         _module: &YangModule,
     ) -> Result<String, GeneratorError> {
         let mut output = String::new();
-        let type_name = crate::generator::naming::to_type_name(&list.name);
         let method_prefix = crate::generator::naming::to_field_name(&list.name);
 
-        // Determine item type name (singular)
-        let item_type_name = if type_name.ends_with('s') && type_name.len() > 1 {
-            type_name[..type_name.len() - 1].to_string()
-        } else {
-            type_name.clone()
-        };
+        let item_type_name = self.config.item_type_name(&list.name);
 
         // Generate key parameters
         let key_params = self.generate_list_key_params(list);
@@ -340,7 +334,7 @@ impl<'a> ServerHandlerGenerator<'a> {
         let mut doc = String::new();
 
         if let Some(desc) = description {
-            doc.push_str(&self.generate_rustdoc(desc));
+            doc.push_str(&crate::generator::docs::rustdoc(desc, "    "));
             doc.push_str("    ///\n");
         }
 
@@ -350,20 +344,4 @@ impl<'a> ServerHandlerGenerator<'a> {
         ));
         doc
     }
-
-    /// Generate rustdoc comments from a YANG description.
-    fn generate_rustdoc(&self, description: &str) -> String {
-        let mut rustdoc = String::new();
-
-        for line in description.lines() {
-            let trimmed = line.trim();
-            if trimmed.is_empty() {
-                rustdoc.push_str("    ///\n");
-            } else {
-                rustdoc.push_str(&format!("    /// {}\n", trimmed));
-            }
-        }
-
-        rustdoc
-    }
 }
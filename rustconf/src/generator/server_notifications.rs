@@ -74,7 +74,7 @@ impl<'a> ServerNotificationGenerator<'a> {
 
         // Generate rustdoc comment
         if let Some(ref description) = notification.description {
-            output.push_str(&format!("    {}", self.generate_rustdoc(description)));
+            output.push_str(&crate::generator::docs::rustdoc(description, "    "));
         } else {
             output.push_str(&format!(
                 "    /// Notification payload for {}.\n",
@@ -102,22 +102,6 @@ impl<'a> ServerNotificationGenerator<'a> {
         Ok(output)
     }
 
-    /// Generate rustdoc comments from a YANG description.
-    fn generate_rustdoc(&self, description: &str) -> String {
-        let mut rustdoc = String::new();
-
-        for line in description.lines() {
-            let trimmed = line.trim();
-            if trimmed.is_empty() {
-                rustdoc.push_str("///\n");
-            } else {
-                rustdoc.push_str(&format!("/// {}\n", trimmed));
-            }
-        }
-
-        rustdoc
-    }
-
     /// Generate derive attributes based on configuration.
     fn generate_derive_attributes(&self) -> String {
         let mut derives = vec!["Serialize", "Deserialize"];
@@ -168,9 +152,6 @@ impl<'a> ServerNotificationGenerator<'a> {
         output.push_str(
             "/// This struct manages subscriber registration and notification delivery.\n",
         );
-        if self.config.derive_debug {
-            output.push_str("#[derive(Debug)]\n");
-        }
         output.push_str("pub struct NotificationPublisher {\n");
         output.push_str("    subscribers: Arc<RwLock<Vec<Arc<dyn NotificationSubscriber>>>>,\n");
         output.push_str("}\n\n");
@@ -180,6 +161,7 @@ impl<'a> ServerNotificationGenerator<'a> {
 
         // Constructor
         output.push_str("    /// Create a new notification publisher.\n");
+        output.push_str("    #[must_use]\n");
         output.push_str("    pub fn new() -> Self {\n");
         output.push_str("        Self {\n");
         output.push_str("            subscribers: Arc::new(RwLock::new(Vec::new())),\n");
@@ -227,6 +209,19 @@ impl<'a> ServerNotificationGenerator<'a> {
         output.push_str("    }\n");
         output.push_str("}\n");
 
+        // Subscribers are trait objects, so Debug cannot be derived
+        if self.config.derive_debug {
+            output.push_str("\nimpl std::fmt::Debug for NotificationPublisher {\n");
+            output.push_str(
+                "    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {\n",
+            );
+            output.push_str(
+                "        f.debug_struct(\"NotificationPublisher\").finish_non_exhaustive()\n",
+            );
+            output.push_str("    }\n");
+            output.push_str("}\n");
+        }
+
         Ok(output)
     }
 
@@ -249,10 +244,9 @@ impl<'a> ServerNotificationGenerator<'a> {
                 notification.name
             ));
             output.push_str("    ///\n");
-            for line in description.lines() {
-                let trimmed = line.trim();
-                if !trimmed.is_empty() {
-                    output.push_str(&format!("    /// {}\n", trimmed));
+            for line in crate::generator::docs::doc_lines(description) {
+                if !line.is_empty() {
+                    output.push_str(&format!("    /// {}\n", line));
                 }
             }
         } else {
@@ -135,6 +135,7 @@ impl<'a> RegistryGenerator<'a> {
         output.push_str(
             "    /// not-implemented handler if no handler is registered for this path.\n",
         );
+        output.push_str("    #[must_use]\n");
         output.push_str("    pub fn lookup(&self, path: &str) -> HandlerFn<H> {\n");
         output.push_str("        // Try exact match first\n");
         output.push_str("        if let Some(handler) = self.handlers.get(path) {\n");
@@ -161,6 +162,7 @@ impl<'a> RegistryGenerator<'a> {
         output.push_str("    /// # Returns\n");
         output.push_str("    ///\n");
         output.push_str("    /// Returns a reference to the default handler.\n");
+        output.push_str("    #[must_use]\n");
         output.push_str("    pub fn default_handler(&self) -> &Arc<H> {\n");
         output.push_str("        &self.default_handler\n");
         output.push_str("    }\n\n");
@@ -171,6 +173,7 @@ impl<'a> RegistryGenerator<'a> {
         output.push_str("    /// # Returns\n");
         output.push_str("    ///\n");
         output.push_str("    /// Returns a vector of all registered path patterns.\n");
+        output.push_str("    #[must_use]\n");
         output.push_str("    pub fn registered_paths(&self) -> Vec<String> {\n");
         output.push_str("        self.handlers.keys().cloned().collect()\n");
         output.push_str("    }\n");
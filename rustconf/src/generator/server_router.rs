@@ -13,6 +13,28 @@ pub struct RouterGenerator<'a> {
 }
 
 #[allow(dead_code)]
+/// Error mapping appended to request body deserialization in generated helpers,
+/// turning constraint violations into validation errors.
+const BODY_ERROR_MAPPING: &str = r#".map_err(|e| {
+            let error_msg = e.to_string();
+            // Check if this is a validation error
+            if error_msg.contains("outside allowed range")
+                || error_msg.contains("invalid length")
+                || error_msg.contains("does not match pattern")
+            {
+                ServerError::ValidationError(format!(
+                    "Request validation failed: {}",
+                    error_msg
+                ))
+            } else {
+                ServerError::DeserializationError(format!(
+                    "Failed to deserialize request body: {}",
+                    error_msg
+                ))
+            }
+        })
+"#;
+
 impl<'a> RouterGenerator<'a> {
     /// Create a new router generator with the given configuration.
     pub fn new(config: &'a GeneratorConfig) -> Self {
@@ -213,26 +235,26 @@ impl<'a> RouterGenerator<'a> {
         output.push_str("        // Deserialize JSON with automatic validation\n");
         output
             .push_str("        // Validated types will check constraints during deserialization\n");
-        output.push_str("        serde_json::from_slice(body).map_err(|e| {\n");
-        output.push_str("            let error_msg = e.to_string();\n");
-        output.push_str("            // Check if this is a validation error\n");
-        output.push_str("            if error_msg.contains(\"outside allowed range\")\n");
-        output.push_str("                || error_msg.contains(\"invalid length\")\n");
-        output.push_str("                || error_msg.contains(\"does not match pattern\")\n");
-        output.push_str("            {\n");
-        output.push_str("                ServerError::ValidationError(format!(\n");
-        output.push_str("                    \"Request validation failed: {}\",\n");
-        output.push_str("                    error_msg\n");
-        output.push_str("                ))\n");
-        output.push_str("            } else {\n");
-        output.push_str("                ServerError::DeserializationError(format!(\n");
-        output.push_str("                    \"Failed to deserialize request body: {}\",\n");
-        output.push_str("                    error_msg\n");
-        output.push_str("                ))\n");
-        output.push_str("            }\n");
-        output.push_str("        })\n");
+        output.push_str("        serde_json::from_slice(body)");
+        output.push_str(BODY_ERROR_MAPPING);
         output.push_str("    }\n\n");
 
+        if !self.config.bare_rpc_bodies {
+            output.push_str(
+                "    /// Deserialize and validate an RPC body wrapped in the `member` envelope.\n",
+            );
+            output.push_str("    fn deserialize_envelope<T: serde::de::DeserializeOwned>(\n");
+            output.push_str("        request: &ServerRequest,\n");
+            output.push_str("        member: &str,\n");
+            output.push_str("    ) -> Result<T, ServerError> {\n");
+            output.push_str("        let body = request.body.as_ref().ok_or_else(|| {\n");
+            output.push_str("            ServerError::DeserializationError(\"Request body is required\".to_string())\n");
+            output.push_str("        })?;\n\n");
+            output.push_str("        rustconf_runtime::unwrap_envelope(member, body)");
+            output.push_str(BODY_ERROR_MAPPING);
+            output.push_str("    }\n\n");
+        }
+
         // Add content negotiation helper
         output.push_str("    /// Determine the response content type based on Accept header.\n");
         output.push_str("    ///\n");
@@ -360,11 +382,18 @@ impl<'a> RouterGenerator<'a> {
                 output.push_str(&format!("            \"{}\" => {{\n", rpc.name));
 
                 // Deserialize input if needed
-                if has_input {
+                if has_input && self.config.bare_rpc_bodies {
                     output.push_str(&format!(
                         "                let input: {}Input = match Self::deserialize_body(request) {{\n",
                         rpc_type_name
                     ));
+                } else if has_input {
+                    output.push_str(&format!(
+                        "                let input: {}Input = match Self::deserialize_envelope(request, \"{}:input\") {{\n",
+                        rpc_type_name, module.name
+                    ));
+                }
+                if has_input {
                     output.push_str("                    Ok(data) => data,\n");
                     output.push_str(
                         "                    Err(e) => return ServerResponse::from_error(e),\n",
@@ -387,9 +416,19 @@ impl<'a> RouterGenerator<'a> {
                 // Handle response
                 if has_output {
                     output.push_str("                    Ok(output) => {\n");
-                    output.push_str(
-                        "                        match Self::serialize_response(output, request) {\n",
-                    );
+                    if self.config.bare_rpc_bodies {
+                        output.push_str(
+                            "                        match Self::serialize_response(output, request) {\n",
+                        );
+                    } else {
+                        output.push_str(&format!(
+                            "                        let output = std::collections::BTreeMap::from([(\"{}:output\", output)]);\n",
+                            module.name
+                        ));
+                        output.push_str(
+                            "                        match Self::serialize_response(output, request) {\n",
+                        );
+                    }
                     output.push_str("                            Ok(response) => response,\n");
                     output.push_str(
                         "                            Err(e) => ServerResponse::from_error(e),\n",
@@ -592,14 +631,6 @@ impl<'a> RouterGenerator<'a> {
     fn generate_list_routing(&self, list: &crate::parser::List) -> Result<String, GeneratorError> {
         let mut output = String::new();
         let method_prefix = crate::generator::naming::to_field_name(&list.name);
-        let type_name = crate::generator::naming::to_type_name(&list.name);
-
-        // Determine item type name (singular)
-        let _item_type_name = if type_name.ends_with('s') && type_name.len() > 1 {
-            type_name[..type_name.len() - 1].to_string()
-        } else {
-            type_name.clone()
-        };
 
         output.push_str(&format!("            \"{}\" => {{\n", list.name));
         output.push_str("                // List operations\n");
@@ -4,6 +4,7 @@
 //! sensible default values. Stub handlers can be used as-is for testing or
 //! selectively overridden for production use.
 
+use crate::generator::restrictions::resolve_type;
 use crate::generator::{GeneratorConfig, GeneratorError};
 use crate::parser::{DataNode, Rpc, TypeSpec, YangModule};
 
@@ -68,6 +69,7 @@ impl<'a> StubHandlerGenerator<'a> {
         // Generate impl block with constructor and accessor
         output.push_str(&format!("impl {} {{\n", struct_name));
         output.push_str("    /// Create a new stub handler.\n");
+        output.push_str("    #[must_use]\n");
         output.push_str("    pub fn new() -> Self {\n");
         output.push_str("        Self {\n");
         output.push_str("            call_log: Arc::new(Mutex::new(Vec::new())),\n");
@@ -75,6 +77,7 @@ impl<'a> StubHandlerGenerator<'a> {
         output.push_str("    }\n\n");
 
         output.push_str("    /// Get the log of handler calls for debugging and testing.\n");
+        output.push_str("    #[must_use]\n");
         output.push_str("    pub fn get_call_log(&self) -> Vec<String> {\n");
         output.push_str("        self.call_log.lock().unwrap().clone()\n");
         output.push_str("    }\n");
@@ -117,7 +120,7 @@ impl<'a> StubHandlerGenerator<'a> {
     fn generate_rpc_stub_method(
         &self,
         rpc: &Rpc,
-        _module: &YangModule,
+        module: &YangModule,
     ) -> Result<String, GeneratorError> {
         let mut output = String::new();
 
@@ -167,17 +170,13 @@ impl<'a> StubHandlerGenerator<'a> {
         if has_output {
             // Generate default value for output type
             if let Some(ref output_nodes) = rpc.output {
-                output.push_str(&format!("        Ok({} {{\n", output_type));
-                for node in output_nodes {
-                    if let DataNode::Leaf(leaf) = node {
-                        let field_name = crate::generator::naming::to_field_name(&leaf.name);
-                        let default_value =
-                            self.default_value_for_type(&leaf.type_spec, leaf.mandatory)?;
-                        output
-                            .push_str(&format!("            {}: {},\n", field_name, default_value));
-                    }
-                }
-                output.push_str("        })\n");
+                output.push_str(&self.generate_default_value(
+                    &output_type,
+                    output_nodes,
+                    &[],
+                    &method_name,
+                    module,
+                ));
             } else {
                 output.push_str("        Ok(Default::default())\n");
             }
@@ -209,7 +208,7 @@ impl<'a> StubHandlerGenerator<'a> {
     fn generate_container_stub_methods(
         &self,
         container: &crate::parser::Container,
-        _module: &YangModule,
+        module: &YangModule,
     ) -> Result<String, GeneratorError> {
         let mut output = String::new();
         let type_name = crate::generator::naming::to_type_name(&container.name);
@@ -226,31 +225,13 @@ impl<'a> StubHandlerGenerator<'a> {
             method_prefix
         ));
         output.push_str("        );\n\n");
-        output.push_str(&format!("        Ok({} {{\n", type_name));
-
-        // Generate default values for all fields
-        for child in &container.children {
-            if let DataNode::Leaf(leaf) = child {
-                let field_name = crate::generator::naming::to_field_name(&leaf.name);
-                let default_value = self.default_value_for_type(&leaf.type_spec, leaf.mandatory)?;
-                output.push_str(&format!("            {}: {},\n", field_name, default_value));
-            } else if let DataNode::Container(nested) = child {
-                let field_name = crate::generator::naming::to_field_name(&nested.name);
-                if nested.mandatory {
-                    output.push_str(&format!(
-                        "            {}: Default::default(),\n",
-                        field_name
-                    ));
-                } else {
-                    output.push_str(&format!("            {}: None,\n", field_name));
-                }
-            } else if let DataNode::List(_) = child {
-                let field_name = crate::generator::naming::to_field_name(child.name());
-                output.push_str(&format!("            {}: Vec::new(),\n", field_name));
-            }
-        }
-
-        output.push_str("        })\n");
+        output.push_str(&self.generate_default_value(
+            &type_name,
+            &container.children,
+            &[],
+            &format!("get_{}", method_prefix),
+            module,
+        ));
         output.push_str("    }\n\n");
 
         // Config-based methods
@@ -305,18 +286,12 @@ impl<'a> StubHandlerGenerator<'a> {
     fn generate_list_stub_methods(
         &self,
         list: &crate::parser::List,
-        _module: &YangModule,
+        module: &YangModule,
     ) -> Result<String, GeneratorError> {
         let mut output = String::new();
-        let type_name = crate::generator::naming::to_type_name(&list.name);
         let method_prefix = crate::generator::naming::to_field_name(&list.name);
 
-        // Determine item type name (singular)
-        let item_type_name = if type_name.ends_with('s') && type_name.len() > 1 {
-            type_name[..type_name.len() - 1].to_string()
-        } else {
-            type_name.clone()
-        };
+        let item_type_name = self.config.item_type_name(&list.name);
 
         // Generate key parameters
         let key_params = self.generate_list_key_params(list);
@@ -351,30 +326,13 @@ impl<'a> StubHandlerGenerator<'a> {
         output.push_str("        );\n\n");
 
         // Generate default item
-        output.push_str(&format!("        Ok({} {{\n", item_type_name));
-        for child in &list.children {
-            if let DataNode::Leaf(leaf) = child {
-                let field_name = crate::generator::naming::to_field_name(&leaf.name);
-                let is_key = list.keys.contains(&leaf.name);
-                let default_value =
-                    self.default_value_for_type(&leaf.type_spec, is_key || leaf.mandatory)?;
-                output.push_str(&format!("            {}: {},\n", field_name, default_value));
-            } else if let DataNode::Container(nested) = child {
-                let field_name = crate::generator::naming::to_field_name(&nested.name);
-                if nested.mandatory {
-                    output.push_str(&format!(
-                        "            {}: Default::default(),\n",
-                        field_name
-                    ));
-                } else {
-                    output.push_str(&format!("            {}: None,\n", field_name));
-                }
-            } else if let DataNode::List(_) = child {
-                let field_name = crate::generator::naming::to_field_name(child.name());
-                output.push_str(&format!("            {}: Vec::new(),\n", field_name));
-            }
-        }
-        output.push_str("        })\n");
+        output.push_str(&self.generate_default_value(
+            &item_type_name,
+            &list.children,
+            &list.keys,
+            &format!("get_{}_by_key", method_prefix),
+            module,
+        ));
         output.push_str("    }\n\n");
 
         // Config-based methods
@@ -493,6 +451,51 @@ impl<'a> StubHandlerGenerator<'a> {
         "String".to_string()
     }
 
+    /// Generate the body returning a default value of a generated struct.
+    ///
+    /// Returns a `HandlerError` instead when a mandatory field has no value
+    /// that is valid for its type, such as a pattern-restricted string.
+    fn generate_default_value(
+        &self,
+        type_name: &str,
+        children: &[DataNode],
+        keys: &[String],
+        method_name: &str,
+        module: &YangModule,
+    ) -> String {
+        let fields: Option<Vec<String>> = children
+            .iter()
+            .filter_map(|child| {
+                let default_value = match child {
+                    DataNode::Leaf(leaf) => self.default_value_for_type(
+                        &leaf.type_spec,
+                        keys.contains(&leaf.name) || leaf.mandatory,
+                        module,
+                    ),
+                    DataNode::Container(nested) => (!nested.mandatory).then(|| "None".to_string()),
+                    DataNode::Choice(choice) => (!choice.mandatory).then(|| "None".to_string()),
+                    DataNode::AnyData(anydata) => Some(if anydata.mandatory {
+                        "rustconf_runtime::AnyData::default()".to_string()
+                    } else {
+                        "None".to_string()
+                    }),
+                    DataNode::List(_) | DataNode::LeafList(_) => Some("Vec::new()".to_string()),
+                    DataNode::Case(_) | DataNode::Uses(_) => return None,
+                };
+                let field_name = crate::generator::naming::to_field_name(child.name());
+                Some(default_value.map(|value| format!("            {}: {},\n", field_name, value)))
+            })
+            .collect();
+
+        match fields {
+            Some(fields) => format!("        Ok({} {{\n{}        }})\n", type_name, fields.concat()),
+            None => format!(
+                "        Err(ServerError::HandlerError(\n            \"{} has no default value in the stub\".to_string(),\n        ))\n",
+                method_name
+            ),
+        }
+    }
+
     /// Generate a default value for a YANG type.
     ///
     /// Returns sensible defaults:
@@ -500,16 +503,26 @@ impl<'a> StubHandlerGenerator<'a> {
     /// - "" for strings
     /// - false for booleans
     /// - None for optional types
-    /// - Default::default() for complex types
+    ///
+    /// Returns `None` for mandatory validated types, which have no default
+    /// that is guaranteed to satisfy their constraints.
     fn default_value_for_type(
         &self,
         type_spec: &TypeSpec,
         mandatory: bool,
-    ) -> Result<String, GeneratorError> {
+        module: &YangModule,
+    ) -> Option<String> {
         if !mandatory {
-            return Ok("None".to_string());
+            return Some("None".to_string());
         }
 
+        let type_gen = crate::generator::types::TypeGenerator::new(self.config);
+        let Some(type_spec) = resolve_type(type_spec, module).type_spec else {
+            return Some("Default::default()".to_string());
+        };
+        if self.config.enable_validation && type_gen.needs_validation(&type_spec) {
+            return None;
+        }
         let default = match type_spec {
             TypeSpec::Int8 { .. } => "0i8",
             TypeSpec::Int16 { .. } => "0i16",
@@ -519,35 +532,18 @@ impl<'a> StubHandlerGenerator<'a> {
             TypeSpec::Uint16 { .. } => "0u16",
             TypeSpec::Uint32 { .. } => "0u32",
             TypeSpec::Uint64 { .. } => "0u64",
+            TypeSpec::Decimal64 { .. } => "Default::default()",
+            TypeSpec::DateAndTime { .. } => "Default::default()",
             TypeSpec::String { .. } => "String::new()",
             TypeSpec::Boolean => "false",
             TypeSpec::Empty => "()",
             TypeSpec::Binary { .. } => "Vec::new()",
             TypeSpec::Enumeration { .. } => "String::new()",
+            TypeSpec::Bits { .. } => "Default::default()",
             TypeSpec::Union { .. } => "String::new()",
             TypeSpec::LeafRef { .. } => "String::new()",
             TypeSpec::TypedefRef { .. } => "Default::default()",
         };
-
-        Ok(default.to_string())
-    }
-}
-
-// Helper trait to get the name of a DataNode
-trait DataNodeName {
-    fn name(&self) -> &str;
-}
-
-impl DataNodeName for DataNode {
-    fn name(&self) -> &str {
-        match self {
-            DataNode::Container(c) => &c.name,
-            DataNode::List(l) => &l.name,
-            DataNode::Leaf(l) => &l.name,
-            DataNode::LeafList(l) => &l.name,
-            DataNode::Choice(c) => &c.name,
-            DataNode::Case(c) => &c.name,
-            DataNode::Uses(u) => &u.name,
-        }
+        Some(default.to_string())
     }
 }
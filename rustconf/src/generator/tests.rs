@@ -32,12 +32,20 @@ fn test_generate_creates_generated_code() {
         namespace: "urn:test:module".to_string(),
         prefix: "test".to_string(),
         yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate(&module);
@@ -63,12 +71,20 @@ fn test_generated_file_has_correct_path() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate(&module);
@@ -99,12 +115,20 @@ fn test_write_files_creates_output_directory() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -114,23 +138,157 @@ fn test_write_files_creates_output_directory() {
     assert!(output_path.exists());
 }
 
+/// A device module shared by the submodules, with a node of every kind the
+/// generated code handles differently.
+const DEVICE: &str = r#"
+    module device {
+        namespace "urn:device";
+        prefix dev;
+        description "Device operations.";
+
+        typedef seconds { type uint32; }
+
+        typedef counter { type uint64; }
+
+        typedef percent {
+            type uint8 {
+                range "0..100";
+            }
+        }
+
+        container system {
+            description "System settings.";
+            leaf hostname { type string; }
+            leaf domain {
+                type string {
+                    length "1..63";
+                }
+                mandatory true;
+            }
+            leaf mtu {
+                type uint16 { range "68..9000"; }
+            }
+            leaf enabled { type boolean; }
+            leaf debug { type empty; }
+            leaf load { type percent; }
+            leaf in-octets { type counter; }
+            leaf mode {
+                type enumeration {
+                    enum auto;
+                    enum manual;
+                }
+            }
+            leaf flags {
+                type bits {
+                    bit up;
+                    bit running;
+                }
+            }
+            leaf location {
+                type union {
+                    type string;
+                    type int32;
+                }
+            }
+            choice transport {
+                case tcp {
+                    leaf port {
+                        type uint16;
+                        mandatory true;
+                    }
+                }
+            }
+        }
+
+        container state {
+            config false;
+            leaf uptime { type uint32; }
+        }
+
+        list interfaces {
+            key "name";
+            leaf name { type string; }
+            leaf mtu { type uint16; }
+            leaf-list tags { type string; }
+        }
+
+        list route {
+            key "destination prefix-length";
+            leaf destination { type string; }
+            leaf prefix-length { type uint8; }
+        }
+
+        rpc reboot {
+            description
+              "Reboot the device.
+
+               Sessions are closed first.";
+            input {
+                leaf delay {
+                    type seconds;
+                    description "Seconds to wait before rebooting.";
+                }
+                leaf reason { type string; mandatory true; }
+                leaf force { type boolean; }
+                leaf-list modules { type string; }
+                leaf sequence { type uint64; }
+                leaf quiet { type empty; }
+                container options { leaf verbose { type boolean; } }
+            }
+            output {
+                leaf scheduled { type boolean; }
+                leaf uptime { type int64; }
+            }
+        }
+
+        rpc ping {
+            description "Check that the device responds.";
+        }
+    }
+"#;
+
+fn module() -> YangModule {
+    crate::parser::YangParser::new()
+        .parse_string(DEVICE, "device.yang")
+        .unwrap()
+}
+
+/// Generate single-file code for [`DEVICE`], checking that it parses.
+fn generate(config: GeneratorConfig) -> String {
+    let generated = CodeGenerator::new(config).generate(&module()).unwrap();
+    let content = generated.files[0].content.clone();
+    syn::parse_file(&content).unwrap_or_else(|e| panic!("{}\n{}", e, content));
+    content
+}
+
 // Submodules for organized tests
+mod api_manifest;
 mod backward_compatibility;
+mod cli_generation;
 mod client_server_roundtrip;
 mod config_validation;
 mod crud_operations;
+mod date_and_time;
+mod decimal64;
+mod dyn_client;
 mod error_mapper_test;
 mod error_mapping_test;
 mod handler_registry;
 mod hyper_adapter;
+mod int64_encoding;
 mod integration;
 mod modular_generation;
 mod modular_server_generation;
+mod name_collisions;
+mod netconf_schema;
 mod notifications;
+mod payload_schema;
 mod reqwest_adapter;
+mod resource_handles;
 mod restconf_client;
 mod rpc_error_generation;
 mod rpc_operations;
+mod rustdoc;
 mod server_handler_generation;
 mod server_notification_generation;
 mod server_router_generation;
@@ -142,3 +300,4 @@ mod url_path;
 mod url_path_example;
 mod validation_integration;
 mod validation_preservation;
+mod visibility;
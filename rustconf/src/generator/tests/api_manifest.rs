@@ -0,0 +1,187 @@
+//! Tests for API manifests of generated code.
+
+use crate::generator::api::{ApiManifest, Change, ItemKind, VersionBump};
+use crate::generator::{CodeGenerator, GeneratorConfig};
+use crate::parser::YangParser;
+
+const DEVICE: &str = r#"
+    module device {
+        namespace "urn:device";
+        prefix dev;
+
+        container system {
+            leaf hostname { type string; }
+            leaf mtu { type uint16; }
+        }
+
+        rpc reboot {
+            input {
+                leaf delay { type uint32; }
+            }
+        }
+    }
+"#;
+
+fn manifest(source: &str) -> ApiManifest {
+    let module = YangParser::new()
+        .parse_string(source, "device.yang")
+        .unwrap();
+    let mut config = GeneratorConfig {
+        modular_output: true,
+        output_dir: "out".into(),
+        ..GeneratorConfig::default()
+    };
+    config.enable_restful_rpcs();
+    let generated = CodeGenerator::new(config).generate(&module).unwrap();
+    ApiManifest::from_files(&generated.files).unwrap()
+}
+
+#[test]
+fn test_manifest_lists_public_items_by_module() {
+    let manifest = manifest(DEVICE);
+    let item = |path: &str, kind: ItemKind| {
+        manifest
+            .items()
+            .iter()
+            .find(|item| item.path == path && item.kind == kind)
+            .unwrap_or_else(|| panic!("no {:?} {} in {:#?}", kind, path, manifest.items()))
+            .signature
+            .clone()
+    };
+
+    assert_eq!(item("types::System", ItemKind::Struct), "pub struct System");
+    assert_eq!(
+        item("types::System::mtu", ItemKind::Field),
+        "pub mtu: Option<u16>"
+    );
+    assert_eq!(
+        item("types::<impl Clone for System>", ItemKind::Impl),
+        "impl Clone for System"
+    );
+    assert!(item("operations::operations::reboot", ItemKind::Function)
+        .starts_with("pub async fn reboot<T: HttpTransport>(client: &RestconfClient<T>"));
+    assert_eq!(item("types", ItemKind::Module), "pub mod types");
+    assert_eq!(
+        item("<use types::*>", ItemKind::Reexport),
+        "pub use types::*"
+    );
+}
+
+#[test]
+fn test_manifest_round_trips_through_json() {
+    let manifest = manifest(DEVICE);
+    let json = manifest.to_json();
+
+    assert!(json.contains(r#""format_version": 1"#));
+    assert_eq!(ApiManifest::from_json(&json).unwrap(), manifest);
+    assert!(ApiManifest::from_json(r#"{"format_version": 99, "items": []}"#).is_err());
+}
+
+#[test]
+fn test_unchanged_model_has_no_changes() {
+    let diff = manifest(DEVICE).diff(&manifest(DEVICE));
+
+    assert!(diff.is_empty());
+    assert_eq!(diff.version_bump(), VersionBump::Patch);
+    assert_eq!(diff.to_string(), "No API changes\n");
+}
+
+#[test]
+fn test_removed_and_retyped_leaves_are_breaking() {
+    let new = DEVICE
+        .replace("leaf hostname { type string; }", "")
+        .replace("leaf mtu { type uint16; }", "leaf mtu { type uint32; }");
+    let diff = manifest(DEVICE).diff(&manifest(&new));
+
+    assert!(diff.is_breaking());
+    assert_eq!(diff.version_bump(), VersionBump::Major);
+    let mtu = diff
+        .changes
+        .iter()
+        .find(|change| change.path == "types::System::mtu")
+        .unwrap();
+    assert_eq!(
+        mtu.change,
+        Change::Changed {
+            old: "pub mtu: Option<u16>".to_string(),
+            new: "pub mtu: Option<u32>".to_string(),
+        }
+    );
+    assert!(diff.changes.iter().any(|change| {
+        change.path == "types::System::hostname"
+            && matches!(change.change, Change::Removed { .. })
+            && change.breaking
+    }));
+}
+
+#[test]
+fn test_new_rpc_is_additive() {
+    let new = DEVICE.replace(
+        "rpc reboot {",
+        "rpc ping { input { leaf host { type string; } } }\n        rpc reboot {",
+    );
+    let diff = manifest(DEVICE).diff(&manifest(&new));
+
+    assert!(!diff.is_empty());
+    assert!(!diff.is_breaking(), "{}", diff);
+    assert_eq!(diff.version_bump(), VersionBump::Minor);
+    assert!(diff
+        .changes
+        .iter()
+        .any(|change| change.path == "operations::operations::ping"
+            && change.kind == ItemKind::Function));
+    assert!(diff.to_string().starts_with("Additive changes:\n  added "));
+}
+
+#[test]
+fn test_added_field_breaks_exhaustive_structs_only() {
+    let old = ApiManifest::from_source(
+        "",
+        "pub struct Open { pub a: u8 }
+         #[non_exhaustive] pub struct Closed { pub a: u8 }
+         pub enum Mode { Auto }
+         pub trait Handler { fn get(&self); }",
+    )
+    .unwrap();
+    let new = ApiManifest::from_source(
+        "",
+        "pub struct Open { pub a: u8, pub b: u8 }
+         #[non_exhaustive] pub struct Closed { pub a: u8, pub b: u8 }
+         pub enum Mode { Auto, Manual }
+         pub trait Handler { fn get(&self); fn put(&self); fn head(&self) {} }
+         pub struct Fresh { pub a: u8 }",
+    )
+    .unwrap();
+    let diff = old.diff(&new);
+
+    let breaking = |path: &str| {
+        diff.changes
+            .iter()
+            .find(|change| change.path == path)
+            .unwrap_or_else(|| panic!("no change to {}", path))
+            .breaking
+    };
+    assert!(breaking("Open::b"));
+    assert!(!breaking("Closed::b"));
+    assert!(breaking("Mode::Manual"));
+    assert!(breaking("Handler::put"));
+    assert!(!breaking("Handler::head"));
+    assert!(!breaking("Fresh::a"));
+}
+
+#[test]
+fn test_next_version_follows_cargo_rules_below_one() {
+    assert_eq!(
+        VersionBump::Minor.next_version("1.4.2").as_deref(),
+        Some("1.5.0")
+    );
+    assert_eq!(
+        VersionBump::Patch.next_version("1.4.2-rc.1").as_deref(),
+        Some("1.4.3")
+    );
+    assert_eq!(
+        VersionBump::Major.next_version("0.0.3").as_deref(),
+        Some("0.0.4")
+    );
+    assert_eq!(VersionBump::Major.next_version("1.4"), None);
+}
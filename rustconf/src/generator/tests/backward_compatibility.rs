@@ -4,7 +4,7 @@
 //! produces the same output as before the RESTful RPC feature was added.
 
 use crate::generator::{CodeGenerator, GeneratorConfig, NamespaceMode};
-use crate::parser::{DataNode, Leaf, Rpc, TypeSpec, YangModule};
+use crate::parser::{DataNode, Leaf, Rpc, Status, TypeSpec, YangModule};
 
 /// Test that stub functions are generated when enable_restful_rpcs is false
 #[test]
@@ -21,16 +21,23 @@ fn test_stub_generation_with_restful_rpcs_disabled() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![
             Rpc {
                 name: "simple-rpc".to_string(),
                 description: Some("A simple RPC with no parameters".to_string()),
                 input: None,
                 output: None,
+                timeout: None,
+                unknown_statements: vec![],
             },
             Rpc {
                 name: "rpc-with-input".to_string(),
@@ -44,9 +51,17 @@ fn test_stub_generation_with_restful_rpcs_disabled() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 })]),
                 output: None,
+                timeout: None,
+                unknown_statements: vec![],
             },
             Rpc {
                 name: "rpc-with-output".to_string(),
@@ -61,8 +76,16 @@ fn test_stub_generation_with_restful_rpcs_disabled() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 })]),
+                timeout: None,
+                unknown_statements: vec![],
             },
             Rpc {
                 name: "full-rpc".to_string(),
@@ -73,7 +96,13 @@ fn test_stub_generation_with_restful_rpcs_disabled() {
                     type_spec: TypeSpec::Int32 { range: None },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 })]),
                 output: Some(vec![DataNode::Leaf(Leaf {
                     name: "output-result".to_string(),
@@ -81,11 +110,22 @@ fn test_stub_generation_with_restful_rpcs_disabled() {
                     type_spec: TypeSpec::Int32 { range: None },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 })]),
+                timeout: None,
+                unknown_statements: vec![],
             },
         ],
         notifications: vec![],
+        features: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -98,7 +138,7 @@ fn test_stub_generation_with_restful_rpcs_disabled() {
     );
     assert!(
         content.contains(
-            "pub async fn rpc_with_input(input: RpcWithInputInput) -> Result<(), RpcError>"
+            "pub async fn rpc_with_input(input: &RpcWithInputInput) -> Result<(), RpcError>"
         ),
         "RPC with input stub function should be generated"
     );
@@ -108,7 +148,7 @@ fn test_stub_generation_with_restful_rpcs_disabled() {
     );
     assert!(
         content.contains(
-            "pub async fn full_rpc(input: FullRpcInput) -> Result<FullRpcOutput, RpcError>"
+            "pub async fn full_rpc(input: &FullRpcInput) -> Result<FullRpcOutput, RpcError>"
         ),
         "Full RPC stub function should be generated"
     );
@@ -145,10 +185,16 @@ fn test_function_signatures_unchanged_with_restful_rpcs_disabled() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-operation".to_string(),
             description: Some("Test operation".to_string()),
@@ -161,7 +207,13 @@ fn test_function_signatures_unchanged_with_restful_rpcs_disabled() {
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })]),
             output: Some(vec![DataNode::Leaf(Leaf {
                 name: "result".to_string(),
@@ -172,10 +224,20 @@ fn test_function_signatures_unchanged_with_restful_rpcs_disabled() {
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: false,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })]),
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -183,7 +245,7 @@ fn test_function_signatures_unchanged_with_restful_rpcs_disabled() {
 
     // Verify exact function signature (no client parameter, no generic)
     assert!(
-        content.contains("pub async fn test_operation(input: TestOperationInput) -> Result<TestOperationOutput, RpcError>"),
+        content.contains("pub async fn test_operation(input: &TestOperationInput) -> Result<TestOperationOutput, RpcError>"),
         "Function signature should match pre-RESTful format exactly"
     );
 
@@ -222,17 +284,27 @@ fn test_documentation_indicates_not_implemented() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-rpc".to_string(),
             description: Some("Test RPC".to_string()),
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -275,17 +347,27 @@ fn test_no_http_abstractions_when_restful_rpcs_disabled() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-rpc".to_string(),
             description: None,
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -332,17 +414,27 @@ fn test_no_transport_adapters_when_restful_rpcs_disabled() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-rpc".to_string(),
             description: None,
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -381,17 +473,27 @@ fn test_no_error_mapper_when_restful_rpcs_disabled() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-rpc".to_string(),
             description: None,
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -422,17 +524,27 @@ fn test_no_cargo_toml_when_restful_rpcs_disabled() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-rpc".to_string(),
             description: None,
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -510,10 +622,16 @@ fn test_generated_code_compiles_without_restful_dependencies() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-rpc".to_string(),
             description: Some("Test RPC".to_string()),
@@ -526,7 +644,13 @@ fn test_generated_code_compiles_without_restful_dependencies() {
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })]),
             output: Some(vec![DataNode::Leaf(Leaf {
                 name: "result".to_string(),
@@ -537,10 +661,20 @@ fn test_generated_code_compiles_without_restful_dependencies() {
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: false,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })]),
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -599,17 +733,27 @@ fn test_no_restful_dependencies_in_cargo_toml() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-rpc".to_string(),
             description: None,
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -637,17 +781,27 @@ fn test_minimal_code_structure_without_restful_rpcs() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "simple-rpc".to_string(),
             description: None,
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -717,10 +871,16 @@ serde_json = "1.0"
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-operation".to_string(),
             description: Some("Test operation".to_string()),
@@ -733,7 +893,13 @@ serde_json = "1.0"
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })]),
             output: Some(vec![DataNode::Leaf(Leaf {
                 name: "result".to_string(),
@@ -744,10 +910,20 @@ serde_json = "1.0"
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: false,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })]),
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -788,17 +964,27 @@ fn test_module_names_preserved() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-rpc".to_string(),
             description: None,
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -830,10 +1016,16 @@ fn test_type_names_and_visibility_preserved() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "my-operation".to_string(),
             description: Some("My operation".to_string()),
@@ -846,7 +1038,13 @@ fn test_type_names_and_visibility_preserved() {
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })]),
             output: Some(vec![DataNode::Leaf(Leaf {
                 name: "output-field".to_string(),
@@ -857,10 +1055,20 @@ fn test_type_names_and_visibility_preserved() {
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: false,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })]),
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -915,10 +1123,16 @@ fn test_no_breaking_changes_to_existing_types() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-rpc".to_string(),
             description: None,
@@ -931,7 +1145,13 @@ fn test_no_breaking_changes_to_existing_types() {
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })]),
             output: Some(vec![DataNode::Leaf(Leaf {
                 name: "result".to_string(),
@@ -942,10 +1162,20 @@ fn test_no_breaking_changes_to_existing_types() {
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: false,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })]),
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -988,17 +1218,27 @@ fn test_operations_module_structure_preserved() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-rpc".to_string(),
             description: None,
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -1035,10 +1275,16 @@ fn test_serde_attributes_preserved() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-rpc".to_string(),
             description: None,
@@ -1051,11 +1297,21 @@ fn test_serde_attributes_preserved() {
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })]),
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -1090,10 +1346,16 @@ fn test_existing_usage_pattern_still_works() {
         namespace: "urn:legacy".to_string(),
         prefix: "leg".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "legacy-operation".to_string(),
             description: Some("A legacy operation".to_string()),
@@ -1103,7 +1365,13 @@ fn test_existing_usage_pattern_still_works() {
                 type_spec: TypeSpec::Int32 { range: None },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })]),
             output: Some(vec![DataNode::Leaf(Leaf {
                 name: "result".to_string(),
@@ -1111,10 +1379,20 @@ fn test_existing_usage_pattern_still_works() {
                 type_spec: TypeSpec::Int32 { range: None },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: false,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })]),
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     // Generate code - this should work exactly as before
@@ -1134,7 +1412,7 @@ fn test_existing_usage_pattern_still_works() {
         "Output type should be generated"
     );
     assert!(
-        content.contains("pub async fn legacy_operation(input: LegacyOperationInput) -> Result<LegacyOperationOutput, RpcError>"),
+        content.contains("pub async fn legacy_operation(input: &LegacyOperationInput) -> Result<LegacyOperationOutput, RpcError>"),
         "Function should have the expected signature"
     );
     assert!(
@@ -1167,17 +1445,27 @@ fn test_file_count_unchanged() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-rpc".to_string(),
             description: None,
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -0,0 +1,110 @@
+//! Tests for the generated command-line interface.
+
+use super::{generate, module};
+use crate::generator::{CodeGenerator, GeneratorConfig, Int64Encoding};
+
+fn cli_config() -> GeneratorConfig {
+    let mut config = GeneratorConfig::default();
+    config.enable_restful_rpcs().enable_cli();
+    config
+}
+
+#[test]
+fn test_cli_not_generated_by_default() {
+    let mut config = GeneratorConfig::default();
+    config.enable_restful_rpcs();
+    assert!(!generate(config).contains("clap::"));
+}
+
+#[test]
+fn test_cli_has_subcommand_per_rpc_with_flags_from_input_leaves() {
+    let content = &generate(cli_config());
+
+    assert!(content.contains("pub mod cli {"));
+    assert!(content.contains("clap::Command::new(\"device\")"));
+    assert!(content.contains(".about(\"Device operations.\")"));
+    assert!(content.contains("clap::Command::new(\"reboot\")"));
+    assert!(content.contains(".about(\"Reboot the device.\")"));
+    assert!(content.contains(".long_about(\"Reboot the device.\\n\\nSessions are closed first.\")"));
+    assert!(content.contains("clap::Command::new(\"ping\")"));
+
+    // Flags are typed after the YANG type, resolving typedefs
+    assert!(content.contains(".long(\"delay\")"));
+    assert!(content.contains(".help(\"Seconds to wait before rebooting.\")"));
+    assert!(content.contains(".value_parser(clap::value_parser!(u32))"));
+    assert!(content.contains(".value_parser(clap::value_parser!(bool))"));
+    assert!(content.contains(".action(clap::ArgAction::Append)"));
+    assert!(content.contains(".required_unless_present(\"input-json\")"));
+    assert!(content.contains(".long(\"input-json\")"));
+
+    // Nodes without a flag are given with --input-json
+    assert!(!content.contains(".long(\"quiet\")"));
+    assert!(!content.contains(".long(\"options\")"));
+}
+
+#[test]
+fn test_cli_run_calls_rpc_functions() {
+    let content = &generate(cli_config());
+
+    assert!(content.contains("pub async fn run<T: rustconf_runtime::HttpTransport>("));
+    assert!(
+        content.contains("let input: operations::RebootInput = input_from(members, \"reboot\")?;")
+    );
+    assert!(content.contains("output_json(operations::reboot(client, &input).await?)"));
+    assert!(content.contains("operations::ping(client).await?;"));
+    assert!(content.contains("members.insert(\"delay\".to_string(), serde_json::json!(*value));"));
+    assert!(
+        content.contains("members.insert(\"sequence\".to_string(), serde_json::json!(*value));")
+    );
+}
+
+#[test]
+fn test_cli_sends_string_encoded_int64_as_strings() {
+    let mut config = cli_config();
+    config.int64_encoding(Int64Encoding::String);
+    let content = &generate(config);
+
+    assert!(content.contains(
+        "members.insert(\"sequence\".to_string(), serde_json::json!(value.to_string()));"
+    ));
+}
+
+#[test]
+fn test_cli_modular_output() {
+    let mut config = cli_config();
+    config.modular_output = true;
+    let generated = CodeGenerator::new(config).generate(&module()).unwrap();
+
+    let file = |name: &str| {
+        generated
+            .files
+            .iter()
+            .find(|file| file.path.ends_with(name))
+            .unwrap_or_else(|| panic!("{} not generated", name))
+    };
+    assert!(file("mod.rs").content.contains("pub mod cli;\n"));
+    let cli = &file("cli.rs").content;
+    syn::parse_file(cli).unwrap_or_else(|e| panic!("{}\n{}", e, cli));
+    assert!(cli.contains("use super::operations::operations;"));
+    assert!(cli.contains("pub fn command() -> clap::Command {"));
+}
+
+#[test]
+fn test_cli_requires_restful_rpcs() {
+    let mut config = GeneratorConfig::default();
+    config.enable_cli();
+
+    let result = config.validate();
+    assert!(result.unwrap_err().contains("enable_cli"));
+}
+
+#[test]
+fn test_cli_run_takes_dyn_client() {
+    let mut config = cli_config();
+    config.enable_dyn_client();
+    let content = &generate(config);
+
+    assert!(content.contains(
+        "pub async fn run(\n        client: &dyn rustconf_runtime::RestconfClientApi,\n"
+    ));
+}
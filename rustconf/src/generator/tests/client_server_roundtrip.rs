@@ -12,7 +12,9 @@
 //!   - Verify data equivalence
 
 use crate::generator::{CodeGenerator, GeneratorConfig};
-use crate::parser::{Container, DataNode, Leaf, List, Rpc, TypeSpec, YangModule, YangVersion};
+use crate::parser::{
+    Container, DataNode, Leaf, List, Rpc, Status, TypeSpec, YangModule, YangVersion,
+};
 
 /// Helper to create a YANG module with RPCs (input/output), containers, and lists
 /// to exercise various type generation paths.
@@ -22,6 +24,9 @@ fn create_roundtrip_module() -> YangModule {
         namespace: "http://example.com/roundtrip".to_string(),
         prefix: "rt".to_string(),
         yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
         typedefs: vec![],
         groupings: vec![],
@@ -41,7 +46,13 @@ fn create_roundtrip_module() -> YangModule {
                         },
                         mandatory: true,
                         default: None,
+                        units: None,
                         config: true,
+                        if_features: vec![],
+                        when: None,
+                        must: vec![],
+                        unknown_statements: vec![],
+                        status: Status::Current,
                     }),
                     DataNode::Leaf(Leaf {
                         name: "port".to_string(),
@@ -49,7 +60,13 @@ fn create_roundtrip_module() -> YangModule {
                         type_spec: TypeSpec::Uint16 { range: None },
                         mandatory: false,
                         default: None,
+                        units: None,
                         config: true,
+                        if_features: vec![],
+                        when: None,
+                        must: vec![],
+                        unknown_statements: vec![],
+                        status: Status::Current,
                     }),
                     DataNode::Leaf(Leaf {
                         name: "enabled".to_string(),
@@ -57,9 +74,20 @@ fn create_roundtrip_module() -> YangModule {
                         type_spec: TypeSpec::Boolean,
                         mandatory: false,
                         default: None,
+                        units: None,
                         config: true,
+                        if_features: vec![],
+                        when: None,
+                        must: vec![],
+                        unknown_statements: vec![],
+                        status: Status::Current,
                     }),
                 ],
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             }),
             DataNode::Container(Container {
                 name: "status".to_string(),
@@ -72,8 +100,19 @@ fn create_roundtrip_module() -> YangModule {
                     type_spec: TypeSpec::Uint32 { range: None },
                     mandatory: false,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 })],
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             }),
             DataNode::List(List {
                 name: "interface".to_string(),
@@ -90,7 +129,13 @@ fn create_roundtrip_module() -> YangModule {
                         },
                         mandatory: true,
                         default: None,
+                        units: None,
                         config: true,
+                        if_features: vec![],
+                        when: None,
+                        must: vec![],
+                        unknown_statements: vec![],
+                        status: Status::Current,
                     }),
                     DataNode::Leaf(Leaf {
                         name: "mtu".to_string(),
@@ -98,11 +143,26 @@ fn create_roundtrip_module() -> YangModule {
                         type_spec: TypeSpec::Uint16 { range: None },
                         mandatory: false,
                         default: None,
+                        units: None,
                         config: true,
+                        if_features: vec![],
+                        when: None,
+                        must: vec![],
+                        unknown_statements: vec![],
+                        status: Status::Current,
                     }),
                 ],
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                min_elements: 0,
+                max_elements: None,
+                unknown_statements: vec![],
+                status: Status::Current,
             }),
         ],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![
             Rpc {
                 name: "restart-device".to_string(),
@@ -113,7 +173,13 @@ fn create_roundtrip_module() -> YangModule {
                     type_spec: TypeSpec::Uint32 { range: None },
                     mandatory: false,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 })]),
                 output: Some(vec![
                     DataNode::Leaf(Leaf {
@@ -122,7 +188,13 @@ fn create_roundtrip_module() -> YangModule {
                         type_spec: TypeSpec::Boolean,
                         mandatory: false,
                         default: None,
+                        units: None,
                         config: false,
+                        if_features: vec![],
+                        when: None,
+                        must: vec![],
+                        unknown_statements: vec![],
+                        status: Status::Current,
                     }),
                     DataNode::Leaf(Leaf {
                         name: "message".to_string(),
@@ -133,9 +205,17 @@ fn create_roundtrip_module() -> YangModule {
                         },
                         mandatory: false,
                         default: None,
+                        units: None,
                         config: false,
+                        if_features: vec![],
+                        when: None,
+                        must: vec![],
+                        unknown_statements: vec![],
+                        status: Status::Current,
                     }),
                 ]),
+                timeout: None,
+                unknown_statements: vec![],
             },
             Rpc {
                 name: "get-system-info".to_string(),
@@ -150,11 +230,22 @@ fn create_roundtrip_module() -> YangModule {
                     },
                     mandatory: false,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 })]),
+                timeout: None,
+                unknown_statements: vec![],
             },
         ],
         notifications: vec![],
+        features: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     }
 }
 
@@ -1,6 +1,6 @@
 //! Tests for configuration validation (Task 1)
 
-use crate::generator::{GeneratorConfig, NamespaceMode};
+use crate::generator::{GeneratorConfig, NamespaceMode, Target};
 
 #[test]
 fn test_enable_restful_rpcs_builder() {
@@ -186,3 +186,29 @@ fn test_server_generation_validation_only_when_enabled() {
 
     assert!(config.validate().is_ok());
 }
+
+#[test]
+fn test_targets_default_to_all() {
+    let config = GeneratorConfig::default();
+    assert!(Target::ALL.iter().all(|target| config.generates(*target)));
+}
+
+#[test]
+fn test_config_validation_fails_with_no_targets() {
+    let mut config = GeneratorConfig::default();
+    config.targets(&[]);
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_config_validation_fails_when_server_generation_lacks_targets() {
+    let mut config = GeneratorConfig {
+        modular_output: true,
+        ..Default::default()
+    };
+    config.enable_server_generation();
+    config.targets(&[Target::Types, Target::Rpcs]);
+
+    let error = config.validate().unwrap_err();
+    assert!(error.contains("requires all targets"));
+}
@@ -1,7 +1,7 @@
 //! Tests for RESTCONF CRUD operation generation.
 
 use crate::generator::{CodeGenerator, GeneratorConfig};
-use crate::parser::{Container, DataNode, Leaf, List, TypeSpec, YangModule};
+use crate::parser::{Container, DataNode, Leaf, List, Status, TypeSpec, YangModule};
 
 #[test]
 fn test_generate_crud_for_config_container() {
@@ -13,7 +13,11 @@ fn test_generate_crud_for_config_container() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::Container(Container {
@@ -30,11 +34,26 @@ fn test_generate_crud_for_config_container() {
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -76,7 +95,11 @@ fn test_generate_crud_for_state_container() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::Container(Container {
@@ -90,11 +113,26 @@ fn test_generate_crud_for_state_container() {
                 type_spec: TypeSpec::Uint64 { range: None },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: false,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -119,7 +157,11 @@ fn test_generate_crud_for_list_with_single_key() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::List(List {
@@ -137,7 +179,13 @@ fn test_generate_crud_for_list_with_single_key() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
                 DataNode::Leaf(Leaf {
                     name: "enabled".to_string(),
@@ -145,12 +193,29 @@ fn test_generate_crud_for_list_with_single_key() {
                     type_spec: TypeSpec::Boolean,
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
             ],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            min_elements: 0,
+            max_elements: None,
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -200,6 +265,9 @@ fn test_generate_crud_for_list_with_multiple_keys() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
         typedefs: vec![],
         groupings: vec![],
@@ -218,7 +286,13 @@ fn test_generate_crud_for_list_with_multiple_keys() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
                 DataNode::Leaf(Leaf {
                     name: "prefix-length".to_string(),
@@ -226,7 +300,13 @@ fn test_generate_crud_for_list_with_multiple_keys() {
                     type_spec: TypeSpec::Uint8 { range: None },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
                 DataNode::Leaf(Leaf {
                     name: "next-hop".to_string(),
@@ -237,12 +317,30 @@ fn test_generate_crud_for_list_with_multiple_keys() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
             ],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            min_elements: 0,
+            max_elements: None,
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        features: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -265,7 +363,11 @@ fn test_generate_crud_for_state_list() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::List(List {
@@ -280,7 +382,13 @@ fn test_generate_crud_for_state_list() {
                     type_spec: TypeSpec::Uint32 { range: None },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
                 DataNode::Leaf(Leaf {
                     name: "state".to_string(),
@@ -291,12 +399,29 @@ fn test_generate_crud_for_state_list() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
             ],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            min_elements: 0,
+            max_elements: None,
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -324,6 +449,9 @@ fn test_generate_crud_for_multiple_data_nodes() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
         typedefs: vec![],
         groupings: vec![],
@@ -334,6 +462,11 @@ fn test_generate_crud_for_multiple_data_nodes() {
                 config: true,
                 mandatory: false,
                 children: vec![],
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             }),
             DataNode::Container(Container {
                 name: "state".to_string(),
@@ -341,6 +474,11 @@ fn test_generate_crud_for_multiple_data_nodes() {
                 config: false,
                 mandatory: false,
                 children: vec![],
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             }),
             DataNode::List(List {
                 name: "users".to_string(),
@@ -356,12 +494,30 @@ fn test_generate_crud_for_multiple_data_nodes() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 })],
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                min_elements: 0,
+                max_elements: None,
+                unknown_statements: vec![],
+                status: Status::Current,
             }),
         ],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        features: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -390,12 +546,20 @@ fn test_crud_not_generated_when_no_data_nodes() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -415,7 +579,11 @@ fn test_crud_operations_use_rpc_error() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::Container(Container {
@@ -424,9 +592,18 @@ fn test_crud_operations_use_rpc_error() {
             config: true,
             mandatory: false,
             children: vec![],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -436,3 +613,364 @@ fn test_crud_operations_use_rpc_error() {
     assert!(content.contains("Result<TestContainer, RpcError>"));
     assert!(content.contains("Result<(), RpcError>"));
 }
+
+#[test]
+fn test_options_helpers_generated_with_restful_rpcs() {
+    let mut config = GeneratorConfig::default();
+    config.enable_restful_rpcs();
+    let generator = CodeGenerator::new(config);
+
+    let module = YangModule {
+        name: "test".to_string(),
+        namespace: "urn:test".to_string(),
+        prefix: "t".to_string(),
+        yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
+        imports: vec![],
+        features: vec![],
+        typedefs: vec![],
+        groupings: vec![],
+        data_nodes: vec![
+            DataNode::Container(Container {
+                name: "system".to_string(),
+                description: None,
+                config: true,
+                mandatory: false,
+                children: vec![],
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
+            }),
+            DataNode::List(List {
+                name: "interface".to_string(),
+                description: None,
+                config: true,
+                keys: vec!["name".to_string()],
+                children: vec![DataNode::Leaf(Leaf {
+                    name: "name".to_string(),
+                    description: None,
+                    type_spec: TypeSpec::String {
+                        length: None,
+                        pattern: None,
+                    },
+                    mandatory: true,
+                    default: None,
+                    units: None,
+                    config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
+                })],
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                min_elements: 0,
+                max_elements: None,
+                unknown_statements: vec![],
+                status: Status::Current,
+            }),
+        ],
+        augments: vec![],
+        deviations: vec![],
+        rpcs: vec![],
+        notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
+    };
+
+    let generated = generator.generate(&module).unwrap();
+    let content = &generated.files[0].content;
+
+    assert!(content.contains("ResourceCapabilities,"));
+    assert!(content.contains("pub async fn options_system<T: HttpTransport>("));
+    assert!(content.contains("pub async fn options_interface<T: HttpTransport>("));
+    assert!(content.contains("pub async fn options_interface_by_key<T: HttpTransport>("));
+    assert!(content.contains("method: HttpMethod::OPTIONS"));
+    assert!(content.contains("ResourceCapabilities::try_from(&response)"));
+}
+
+#[test]
+fn test_exists_helpers_generated_with_restful_rpcs() {
+    let mut config = GeneratorConfig::default();
+    config.enable_restful_rpcs();
+    let generator = CodeGenerator::new(config);
+
+    let module = YangModule {
+        name: "test".to_string(),
+        namespace: "urn:test".to_string(),
+        prefix: "t".to_string(),
+        yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
+        imports: vec![],
+        features: vec![],
+        typedefs: vec![],
+        groupings: vec![],
+        data_nodes: vec![
+            DataNode::Container(Container {
+                name: "system".to_string(),
+                description: None,
+                config: true,
+                mandatory: false,
+                children: vec![],
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
+            }),
+            DataNode::List(List {
+                name: "interface".to_string(),
+                description: None,
+                config: true,
+                keys: vec!["name".to_string()],
+                children: vec![DataNode::Leaf(Leaf {
+                    name: "name".to_string(),
+                    description: None,
+                    type_spec: TypeSpec::String {
+                        length: None,
+                        pattern: None,
+                    },
+                    mandatory: true,
+                    default: None,
+                    units: None,
+                    config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
+                })],
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                min_elements: 0,
+                max_elements: None,
+                unknown_statements: vec![],
+                status: Status::Current,
+            }),
+        ],
+        augments: vec![],
+        deviations: vec![],
+        rpcs: vec![],
+        notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
+    };
+
+    let generated = generator.generate(&module).unwrap();
+    let content = &generated.files[0].content;
+
+    assert!(content.contains(
+        "pub async fn exists_system<T: HttpTransport>(client: &RestconfClient<T>) -> Result<bool, RpcError>"
+    ));
+    assert!(content.contains("client.exists(&system_path()).await"));
+    assert!(content.contains("pub async fn exists_interface_by_key<T: HttpTransport>("));
+    assert!(!content.contains("pub async fn exists_interface<T: HttpTransport>("));
+}
+
+#[test]
+fn test_list_streaming_helpers_generated_with_restful_rpcs() {
+    let mut config = GeneratorConfig::default();
+    config.enable_restful_rpcs();
+    let generator = CodeGenerator::new(config);
+
+    let module = YangModule {
+        name: "test".to_string(),
+        namespace: "urn:test".to_string(),
+        prefix: "t".to_string(),
+        yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
+        imports: vec![],
+        features: vec![],
+        typedefs: vec![],
+        groupings: vec![],
+        data_nodes: vec![
+            DataNode::Container(Container {
+                name: "system".to_string(),
+                description: None,
+                config: true,
+                mandatory: false,
+                children: vec![],
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
+            }),
+            DataNode::List(List {
+                name: "interface".to_string(),
+                description: None,
+                config: true,
+                keys: vec!["name".to_string()],
+                children: vec![DataNode::Leaf(Leaf {
+                    name: "name".to_string(),
+                    description: None,
+                    type_spec: TypeSpec::String {
+                        length: None,
+                        pattern: None,
+                    },
+                    mandatory: true,
+                    default: None,
+                    units: None,
+                    config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
+                })],
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                min_elements: 0,
+                max_elements: None,
+                unknown_statements: vec![],
+                status: Status::Current,
+            }),
+        ],
+        augments: vec![],
+        deviations: vec![],
+        rpcs: vec![],
+        notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
+    };
+
+    let generated = generator.generate(&module).unwrap();
+    let content = &generated.files[0].content;
+
+    assert!(content.contains(
+        "pub fn interface_entries<F: FnMut(Interface)>(on_entry: F) -> rustconf_runtime::ListEntries<'static, Interface, F>"
+    ));
+    assert!(content.contains(
+        "pub fn for_each_interface<R: std::io::Read>(body: R, on_entry: impl FnMut(Interface)) -> Result<usize, RpcError>"
+    ));
+    assert!(
+        content.contains(r#"rustconf_runtime::for_each_list_entry(body, "interface", on_entry)"#)
+    );
+    assert!(!content.contains("system_entries"));
+}
+
+#[test]
+fn test_options_helpers_not_generated_without_restful_rpcs() {
+    let config = GeneratorConfig::default();
+    let generator = CodeGenerator::new(config);
+
+    let module = YangModule {
+        name: "test".to_string(),
+        namespace: "urn:test".to_string(),
+        prefix: "t".to_string(),
+        yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
+        imports: vec![],
+        features: vec![],
+        typedefs: vec![],
+        groupings: vec![],
+        data_nodes: vec![DataNode::Container(Container {
+            name: "system".to_string(),
+            description: None,
+            config: true,
+            mandatory: false,
+            children: vec![],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
+        })],
+        augments: vec![],
+        deviations: vec![],
+        rpcs: vec![],
+        notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
+    };
+
+    let generated = generator.generate(&module).unwrap();
+    let content = &generated.files[0].content;
+
+    assert!(!content.contains("options_system"));
+    assert!(!content.contains("exists_system"));
+    assert!(!content.contains("for_each_"));
+}
+
+#[test]
+fn test_generate_crud_for_top_level_leaves() {
+    let leaf = |name: &str, type_spec: TypeSpec, config: bool| {
+        DataNode::Leaf(Leaf {
+            name: name.to_string(),
+            description: None,
+            type_spec,
+            mandatory: false,
+            default: None,
+            units: None,
+            config,
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
+        })
+    };
+    let module = YangModule {
+        name: "test".to_string(),
+        namespace: "urn:test".to_string(),
+        prefix: "t".to_string(),
+        yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
+        imports: vec![],
+        features: vec![],
+        typedefs: vec![],
+        groupings: vec![],
+        data_nodes: vec![
+            leaf(
+                "hostname",
+                TypeSpec::String {
+                    length: None,
+                    pattern: None,
+                },
+                true,
+            ),
+            leaf("boot-count", TypeSpec::Uint32 { range: None }, false),
+        ],
+        augments: vec![],
+        deviations: vec![],
+        rpcs: vec![],
+        notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
+    };
+
+    let generated = CodeGenerator::new(GeneratorConfig::default())
+        .generate(&module)
+        .unwrap();
+    let content = &generated.files[0].content;
+
+    assert!(content.contains("fn hostname_path() -> String {"));
+    assert!(content.contains("\"/restconf/data/hostname\".to_string()"));
+    assert!(content.contains("/// Retrieve the hostname leaf."));
+    assert!(content.contains("pub async fn get_hostname() -> Result<String, RpcError>"));
+    assert!(content.contains("pub async fn put_hostname(_data: String) -> Result<(), RpcError>"));
+    assert!(content.contains("pub async fn delete_hostname() -> Result<(), RpcError>"));
+    // A leaf is replaced as a whole
+    assert!(!content.contains("patch_hostname"));
+
+    // State leaves are read-only
+    assert!(content.contains("pub async fn get_boot_count() -> Result<u32, RpcError>"));
+    assert!(!content.contains("put_boot_count"));
+    assert!(!content.contains("delete_boot_count"));
+}
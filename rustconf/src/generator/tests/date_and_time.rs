@@ -0,0 +1,84 @@
+//! Tests for the representation of `date-and-time` values.
+
+use crate::generator::{CodeGenerator, DateAndTimeRepresentation, GeneratorConfig};
+use crate::parser::YangParser;
+
+const YANG_TYPES: &str = r#"
+    module ietf-yang-types {
+        namespace "urn:ietf:params:xml:ns:yang:ietf-yang-types";
+        prefix yang;
+
+        typedef date-and-time {
+            type string {
+                pattern '\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(\.\d+)?'
+                      + '(Z|[\+\-]\d{2}:\d{2})';
+            }
+        }
+    }
+"#;
+
+const CLOCK: &str = r#"
+    module clock {
+        namespace "urn:clock";
+        prefix clk;
+
+        import ietf-yang-types { prefix yang; }
+
+        typedef timestamp { type yang:date-and-time; }
+
+        container clock {
+            leaf current-datetime { type yang:date-and-time; }
+            leaf boot-datetime { type timestamp; mandatory true; }
+            leaf-list reboots { type yang:date-and-time; }
+            leaf utc-datetime {
+                type yang:date-and-time { pattern '.*Z'; }
+            }
+        }
+    }
+"#;
+
+fn generate(representation: DateAndTimeRepresentation) -> String {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    std::fs::write(temp_dir.path().join("ietf-yang-types.yang"), YANG_TYPES).unwrap();
+
+    let mut parser = YangParser::new();
+    parser.add_search_path(temp_dir.path().to_path_buf());
+    let mut module = parser.parse_string(CLOCK, "clock.yang").unwrap();
+    parser.inline_imported_typedefs(&mut module).unwrap();
+
+    let mut config = GeneratorConfig::default();
+    config.date_and_time_representation(representation);
+    config.enable_validation = true;
+    let generated = CodeGenerator::new(config).generate(&module).unwrap();
+    generated.files[0].content.clone()
+}
+
+#[test]
+fn test_date_and_time_stays_a_validated_string_by_default() {
+    let content = generate(DateAndTimeRepresentation::String);
+
+    assert!(!content.contains("chrono"));
+    assert!(!content.contains("rustconf_runtime::date_and_time"));
+    assert!(content.contains("pub type Timestamp = ValidatedStringId"));
+    assert!(content.contains("pub current_datetime: Option<ValidatedStringId"));
+    assert!(content.contains("pub reboots: Vec<ValidatedStringId"));
+}
+
+#[test]
+fn test_chrono_fields_use_the_runtime_encoding() {
+    let content = generate(DateAndTimeRepresentation::Chrono);
+
+    let with = r#"with = "rustconf_runtime::date_and_time""#;
+    assert!(content.contains("pub type Timestamp = chrono::DateTime<chrono::FixedOffset>;"));
+    assert!(content.contains(&format!(
+        "#[serde(default)]\n    #[serde({})]\n    pub current_datetime: Option<chrono::DateTime<chrono::FixedOffset>>,",
+        with
+    )));
+    assert!(content.contains(&format!(
+        "#[serde({})]\n    pub boot_datetime: Timestamp,",
+        with
+    )));
+    assert!(content.contains("pub reboots: Vec<chrono::DateTime<chrono::FixedOffset>>,"));
+    // A reference adding a pattern stays a string checked against both
+    assert!(content.contains("pub utc_datetime: Option<ValidatedStringId"));
+}
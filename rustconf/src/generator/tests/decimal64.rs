@@ -0,0 +1,90 @@
+//! Tests for the representation of `decimal64` values.
+
+use crate::generator::{CodeGenerator, Decimal64Representation, GeneratorConfig};
+use crate::parser::YangParser;
+
+const SENSORS: &str = r#"
+    typedef celsius {
+        type decimal64 {
+            fraction-digits 1;
+            range "-40..125.5";
+        }
+    }
+    typedef ratio { type decimal64 { fraction-digits 2; } }
+    container sensors {
+        leaf temperature { type celsius; }
+        leaf utilization { type ratio; mandatory true; }
+        leaf latitude {
+            type decimal64 { fraction-digits 6; }
+        }
+        leaf-list samples { type decimal64 { fraction-digits 2; } }
+    }
+"#;
+
+fn generate(representation: Decimal64Representation, enable_validation: bool) -> String {
+    let input = format!(
+        "module test {{ namespace \"urn:test\"; prefix t; {} }}",
+        SENSORS
+    );
+    let module = YangParser::new().parse_string(&input, "test.yang").unwrap();
+    let mut config = GeneratorConfig::default();
+    config.decimal64_representation(representation);
+    config.enable_validation = enable_validation;
+    let generated = CodeGenerator::new(config).generate(&module).unwrap();
+    generated.files[0].content.clone()
+}
+
+#[test]
+fn test_scaled_integer_types_per_fraction_digits() {
+    let content = generate(Decimal64Representation::ScaledInteger, false);
+
+    for fraction_digits in [1, 2, 6] {
+        assert!(content.contains(&format!("pub struct Decimal64Fd{}(i64);", fraction_digits)));
+    }
+    assert_eq!(content.matches("(i64);").count(), 3);
+    assert!(content.contains("impl rustconf_runtime::Decimal64 for Decimal64Fd2 {"));
+    assert!(content.contains("const FRACTION_DIGITS: u8 = 6;"));
+    assert!(content.contains("rustconf_runtime::decimal64::serialize(self, serializer)"));
+    assert!(content.contains("pub type Ratio = Decimal64Fd2;"));
+    assert!(content.contains("pub utilization: Ratio,"));
+    assert!(content.contains("pub latitude: Option<Decimal64Fd6>,"));
+    assert!(content.contains("pub samples: Vec<Decimal64Fd2>,"));
+    assert!(!content.contains("rust_decimal"));
+}
+
+#[test]
+fn test_validated_scaled_integer_checks_the_range() {
+    let content = generate(Decimal64Representation::ScaledInteger, true);
+
+    assert!(content.contains("pub type Celsius = ValidatedDecimal64Id"));
+    assert!(content.contains("    value: Decimal64Fd1,\n"));
+    assert!(content.contains(
+        "(Decimal64Fd1::from_scaled(-400)..=Decimal64Fd1::from_scaled(1255)).contains(&value)"
+    ));
+    assert!(content.contains("constraint: \"-40.0..125.5\".to_string(),"));
+    assert!(content.contains("/// - -40.0 to 125.5\n"));
+}
+
+#[test]
+fn test_rust_decimal_fields_use_the_runtime_encoding() {
+    let content = generate(Decimal64Representation::RustDecimal, true);
+
+    let with = r#"with = "rustconf_runtime::decimal64::rust_decimal""#;
+    assert!(!content.contains("Decimal64Fd"));
+    assert!(content.contains("pub type Ratio = rust_decimal::Decimal;"));
+    assert!(content.contains(&format!("#[serde({})]\n    pub utilization: Ratio,", with)));
+    assert!(content.contains(&format!(
+        "#[serde(default)]\n    #[serde({})]\n    pub latitude: Option<rust_decimal::Decimal>,",
+        with
+    )));
+    assert!(content.contains("pub samples: Vec<rust_decimal::Decimal>,"));
+
+    // The validated type applies the encoding itself
+    assert!(content.contains("    value: rust_decimal::Decimal,\n"));
+    assert!(content.contains(
+        "(rust_decimal::Decimal::new(-400, 1)..=rust_decimal::Decimal::new(1255, 1)).contains(&value)"
+    ));
+    assert!(content
+        .contains("rustconf_runtime::decimal64::rust_decimal::serialize(&self.value, serializer)"));
+    assert!(content.contains("pub temperature: Option<Celsius>,"));
+}
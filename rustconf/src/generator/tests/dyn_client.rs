@@ -0,0 +1,85 @@
+//! Tests for generated code taking type-erased clients.
+
+use super::{generate, module};
+use crate::generator::{CodeGenerator, GeneratorConfig};
+
+fn dyn_config() -> GeneratorConfig {
+    let mut config = GeneratorConfig::default();
+    config.enable_restful_rpcs().enable_dyn_client();
+    config
+}
+
+#[test]
+fn test_functions_are_generic_over_the_transport_by_default() {
+    let mut config = GeneratorConfig::default();
+    config.enable_restful_rpcs();
+    let content = generate(config);
+
+    assert!(content.contains(
+        "pub async fn reboot<T: HttpTransport>(client: &RestconfClient<T>, input: &RebootInput)"
+    ));
+    assert!(!content.contains("RestconfClientApi"));
+}
+
+#[test]
+fn test_functions_take_dyn_client() {
+    let content = generate(dyn_config());
+
+    assert!(content.contains("    RestconfClientApi,\n"));
+    assert!(content.contains(
+        "pub async fn reboot(client: &dyn RestconfClientApi, input: &RebootInput) -> Result<RebootOutput, RpcError> {"
+    ));
+    assert!(content.contains(
+        "pub async fn options_system(client: &dyn RestconfClientApi) -> Result<ResourceCapabilities, RpcError> {"
+    ));
+    assert!(content.contains(
+        "pub async fn exists_interfaces_by_key(client: &dyn RestconfClientApi, name: String) -> Result<bool, RpcError> {"
+    ));
+    assert!(!content.contains("<T: HttpTransport>(client"));
+}
+
+#[test]
+fn test_resource_handles_hold_dyn_client() {
+    let content = generate(dyn_config());
+
+    assert!(content.contains("pub trait DeviceResources {"));
+    assert!(content.contains("fn system(&self) -> SystemResource<'_>;"));
+    assert!(content.contains("impl<T: HttpTransport> DeviceResources for RestconfClient<T> {"));
+    assert!(content.contains("impl DeviceResources for dyn RestconfClientApi + '_ {"));
+    assert!(content.contains(
+        "pub struct SystemResource<'a> {\n            client: &'a dyn RestconfClientApi,"
+    ));
+    assert!(content.contains("impl SystemResource<'_> {"));
+    assert!(content.contains("impl<'a> InterfacesResource<'a> {"));
+    assert!(content
+        .contains("pub fn item(&self, name: impl Into<String>) -> InterfacesItemResource<'a> {"));
+}
+
+#[test]
+fn test_modular_output_reexports_dyn_client() {
+    let mut config = dyn_config();
+    config.modular_output = true;
+    let generated = CodeGenerator::new(config).generate(&module()).unwrap();
+
+    let file = |name: &str| {
+        generated
+            .files
+            .iter()
+            .find(|file| file.path.ends_with(name))
+            .unwrap_or_else(|| panic!("{} not generated", name))
+    };
+    let root = &file("mod.rs").content;
+    assert!(root.contains("    DynRestconfClient,\n    RestconfClientApi,\n"));
+    let operations = &file("operations.rs").content;
+    syn::parse_file(operations).unwrap();
+    assert!(operations.contains("use rustconf_runtime::RestconfClientApi;\n"));
+    assert!(operations.contains("pub async fn reboot(client: &dyn RestconfClientApi,"));
+}
+
+#[test]
+fn test_dyn_client_requires_restful_rpcs() {
+    let mut config = GeneratorConfig::default();
+    config.enable_dyn_client();
+
+    assert!(config.validate().unwrap_err().contains("enable_dyn_client"));
+}
@@ -10,17 +10,27 @@ fn create_test_module() -> YangModule {
         namespace: "urn:test:module".to_string(),
         prefix: "test".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![crate::parser::Rpc {
             name: "test-rpc".to_string(),
             description: None,
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     }
 }
 
@@ -1,7 +1,7 @@
 //! Test for error mapping logic in generated RESTful RPC functions (Task 5.3)
 
 use crate::generator::{CodeGenerator, GeneratorConfig};
-use crate::parser::{DataNode, Leaf, Rpc, TypeSpec, YangModule};
+use crate::parser::{DataNode, Leaf, Rpc, Status, TypeSpec, YangModule};
 
 #[test]
 fn test_error_mapping_logic_in_generated_code() {
@@ -15,10 +15,16 @@ fn test_error_mapping_logic_in_generated_code() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-operation".to_string(),
             description: Some("Test RPC operation".to_string()),
@@ -31,7 +37,13 @@ fn test_error_mapping_logic_in_generated_code() {
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })]),
             output: Some(vec![DataNode::Leaf(Leaf {
                 name: "result".to_string(),
@@ -42,10 +54,20 @@ fn test_error_mapping_logic_in_generated_code() {
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })]),
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -59,11 +81,9 @@ fn test_error_mapping_logic_in_generated_code() {
 
     // Verify 200-299 range attempts deserialization
     assert!(
-        content.contains("200..=299 => {"),
-        "Should handle 200-299 status codes"
-    );
-    assert!(
-        content.contains("serde_json::from_slice(&response.body)"),
+        content.contains(
+            "200..=299 => rustconf_runtime::unwrap_envelope(\"test:output\", &response.body)"
+        ),
         "Should attempt deserialization for success status codes"
     );
     assert!(
@@ -98,17 +118,27 @@ fn test_error_mapping_with_no_output() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "reset".to_string(),
             description: Some("Reset operation".to_string()),
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -146,17 +176,27 @@ fn test_all_rpc_error_variants_exist() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-rpc".to_string(),
             description: None,
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
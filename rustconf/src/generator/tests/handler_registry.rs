@@ -6,8 +6,13 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::generator::{server_registry::RegistryGenerator, GeneratorConfig, NamespaceMode};
-    use crate::parser::{Container, DataNode, Leaf, Rpc, TypeSpec, YangModule, YangVersion};
+    use crate::generator::{
+        server_registry::RegistryGenerator, GeneratorConfig, NamespaceMode, NamespaceQualifier,
+        Target,
+    };
+    use crate::parser::{
+        Container, DataNode, Leaf, Rpc, Status, TypeSpec, YangModule, YangVersion,
+    };
     use proptest::prelude::*;
     use tempfile::TempDir;
 
@@ -33,12 +38,20 @@ mod tests {
                     namespace: format!("http://example.com/{}", name),
                     prefix: name.chars().take(3).collect(),
                     yang_version: Some(YangVersion::V1_1),
+                    organization: None,
+                    contact: None,
+                    description: None,
                     imports: Vec::new(),
+                    features: Vec::new(),
                     typedefs: Vec::new(),
                     groupings: Vec::new(),
                     data_nodes,
+                    augments: Vec::new(),
+                    deviations: Vec::new(),
                     rpcs: Vec::new(),
                     notifications: Vec::new(),
+                    extensions: vec![],
+                    unknown_statements: vec![],
                 }
             })
     }
@@ -51,6 +64,11 @@ mod tests {
             config: true,
             mandatory: false,
             children: Vec::new(),
+            if_features: Vec::new(),
+            when: None,
+            must: Vec::new(),
+            unknown_statements: vec![],
+            status: Status::Current,
         })
     }
 
@@ -63,7 +81,11 @@ mod tests {
             namespace: "http://example.com/test".to_string(),
             prefix: "test".to_string(),
             yang_version: Some(YangVersion::V1_1),
+            organization: None,
+            contact: None,
+            description: None,
             imports: Vec::new(),
+            features: Vec::new(),
             typedefs: Vec::new(),
             groupings: Vec::new(),
             data_nodes: vec![DataNode::Container(Container {
@@ -72,9 +94,18 @@ mod tests {
                 config: true,
                 mandatory: false,
                 children: Vec::new(),
+                if_features: Vec::new(),
+                when: None,
+                must: Vec::new(),
+                unknown_statements: vec![],
+                status: Status::Current,
             })],
+            augments: Vec::new(),
+            deviations: Vec::new(),
             rpcs: Vec::new(),
             notifications: Vec::new(),
+            extensions: vec![],
+            unknown_statements: vec![],
         };
 
         let config = GeneratorConfig {
@@ -86,10 +117,29 @@ mod tests {
             modular_output: false,
             derive_debug: true,
             derive_clone: true,
+            generate_constructors: false,
             enable_namespace_prefixes: false,
             restful_namespace_mode: NamespaceMode::Enabled,
+            bare_rpc_bodies: false,
             enable_server_generation: true,
             server_output_subdir: "server".to_string(),
+            serde_rename_all: false,
+            int64_encoding: Default::default(),
+            decimal64_representation: Default::default(),
+            date_and_time_representation: Default::default(),
+            enable_pretty_printers: false,
+            enable_cli: false,
+            enable_dyn_client: false,
+            enable_netconf: false,
+            enable_payload_schema: false,
+            shared_helpers: false,
+            visibility: Default::default(),
+            list_item_names: Default::default(),
+            targets: Target::ALL.to_vec(),
+            enabled_features: None,
+            namespace_qualifier: NamespaceQualifier::default(),
+            prelude: None,
+            epilogue: None,
         };
 
         let generator = RegistryGenerator::new(&config);
@@ -115,10 +165,16 @@ mod tests {
             namespace: "http://example.com/device".to_string(),
             prefix: "dev".to_string(),
             yang_version: Some(YangVersion::V1_1),
+            organization: None,
+            contact: None,
+            description: None,
             imports: Vec::new(),
+            features: Vec::new(),
             typedefs: Vec::new(),
             groupings: Vec::new(),
             data_nodes: Vec::new(),
+            augments: vec![],
+            deviations: vec![],
             rpcs: vec![Rpc {
                 name: "restart-device".to_string(),
                 description: None,
@@ -129,10 +185,20 @@ mod tests {
                     mandatory: false,
                     config: true,
                     default: None,
+                    units: None,
+                    if_features: Vec::new(),
+                    when: None,
+                    must: Vec::new(),
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 })]),
                 output: None,
+                timeout: None,
+                unknown_statements: vec![],
             }],
             notifications: Vec::new(),
+            extensions: vec![],
+            unknown_statements: vec![],
         };
 
         let config = GeneratorConfig {
@@ -144,10 +210,29 @@ mod tests {
             modular_output: false,
             derive_debug: true,
             derive_clone: true,
+            generate_constructors: false,
             enable_namespace_prefixes: false,
             restful_namespace_mode: NamespaceMode::Enabled,
+            bare_rpc_bodies: false,
             enable_server_generation: true,
             server_output_subdir: "server".to_string(),
+            serde_rename_all: false,
+            int64_encoding: Default::default(),
+            decimal64_representation: Default::default(),
+            date_and_time_representation: Default::default(),
+            enable_pretty_printers: false,
+            enable_cli: false,
+            enable_dyn_client: false,
+            enable_netconf: false,
+            enable_payload_schema: false,
+            shared_helpers: false,
+            visibility: Default::default(),
+            list_item_names: Default::default(),
+            targets: Target::ALL.to_vec(),
+            enabled_features: None,
+            namespace_qualifier: NamespaceQualifier::default(),
+            prelude: None,
+            epilogue: None,
         };
 
         let generator = RegistryGenerator::new(&config);
@@ -167,7 +252,11 @@ mod tests {
             namespace: "http://example.com/simple".to_string(),
             prefix: "simple".to_string(),
             yang_version: Some(YangVersion::V1_1),
+            organization: None,
+            contact: None,
+            description: None,
             imports: Vec::new(),
+            features: Vec::new(),
             typedefs: Vec::new(),
             groupings: Vec::new(),
             data_nodes: vec![DataNode::Container(Container {
@@ -176,9 +265,18 @@ mod tests {
                 config: true,
                 mandatory: false,
                 children: Vec::new(),
+                if_features: Vec::new(),
+                when: None,
+                must: Vec::new(),
+                unknown_statements: vec![],
+                status: Status::Current,
             })],
+            augments: Vec::new(),
+            deviations: Vec::new(),
             rpcs: Vec::new(),
             notifications: Vec::new(),
+            extensions: vec![],
+            unknown_statements: vec![],
         };
 
         let temp_dir = TempDir::new().unwrap();
@@ -191,10 +289,29 @@ mod tests {
             modular_output: true,
             derive_debug: true,
             derive_clone: true,
+            generate_constructors: false,
             enable_namespace_prefixes: false,
             restful_namespace_mode: NamespaceMode::Enabled,
+            bare_rpc_bodies: false,
             enable_server_generation: true,
             server_output_subdir: "server".to_string(),
+            serde_rename_all: false,
+            int64_encoding: Default::default(),
+            decimal64_representation: Default::default(),
+            date_and_time_representation: Default::default(),
+            enable_pretty_printers: false,
+            enable_cli: false,
+            enable_dyn_client: false,
+            enable_netconf: false,
+            enable_payload_schema: false,
+            shared_helpers: false,
+            visibility: Default::default(),
+            list_item_names: Default::default(),
+            targets: Target::ALL.to_vec(),
+            enabled_features: None,
+            namespace_qualifier: NamespaceQualifier::default(),
+            prelude: None,
+            epilogue: None,
         };
 
         let generator = RegistryGenerator::new(&config);
@@ -227,10 +344,29 @@ mod tests {
                 modular_output: false,
                 derive_debug: true,
                 derive_clone: true,
+                generate_constructors: false,
                 enable_namespace_prefixes: false,
                 restful_namespace_mode: NamespaceMode::Enabled,
+                bare_rpc_bodies: false,
                 enable_server_generation: true,
                 server_output_subdir: "server".to_string(),
+                serde_rename_all: false,
+                int64_encoding: Default::default(),
+                decimal64_representation: Default::default(),
+                date_and_time_representation: Default::default(),
+                enable_pretty_printers: false,
+                enable_cli: false,
+                enable_dyn_client: false,
+                enable_netconf: false,
+                enable_payload_schema: false,
+                shared_helpers: false,
+                visibility: Default::default(),
+                list_item_names: Default::default(),
+                targets: Target::ALL.to_vec(),
+                enabled_features: None,
+                namespace_qualifier: NamespaceQualifier::default(),
+                prelude: None,
+                epilogue: None,
             };
 
             let generator = RegistryGenerator::new(&config);
@@ -275,10 +411,29 @@ mod tests {
                 modular_output: false,
                 derive_debug: true,
                 derive_clone: true,
+                generate_constructors: false,
                 enable_namespace_prefixes: false,
                 restful_namespace_mode: NamespaceMode::Enabled,
+                bare_rpc_bodies: false,
                 enable_server_generation: true,
                 server_output_subdir: "server".to_string(),
+                serde_rename_all: false,
+                int64_encoding: Default::default(),
+                decimal64_representation: Default::default(),
+                date_and_time_representation: Default::default(),
+                enable_pretty_printers: false,
+                enable_cli: false,
+                enable_dyn_client: false,
+                enable_netconf: false,
+                enable_payload_schema: false,
+                shared_helpers: false,
+                visibility: Default::default(),
+                list_item_names: Default::default(),
+                targets: Target::ALL.to_vec(),
+                enabled_features: None,
+                namespace_qualifier: NamespaceQualifier::default(),
+                prelude: None,
+                epilogue: None,
             };
 
             let generator = RegistryGenerator::new(&config);
@@ -310,10 +465,29 @@ mod tests {
                 modular_output: false,
                 derive_debug: true,
                 derive_clone: true,
+                generate_constructors: false,
                 enable_namespace_prefixes: false,
                 restful_namespace_mode: NamespaceMode::Enabled,
+                bare_rpc_bodies: false,
                 enable_server_generation: true,
                 server_output_subdir: "server".to_string(),
+                serde_rename_all: false,
+                int64_encoding: Default::default(),
+                decimal64_representation: Default::default(),
+                date_and_time_representation: Default::default(),
+                enable_pretty_printers: false,
+                enable_cli: false,
+                enable_dyn_client: false,
+                enable_netconf: false,
+                enable_payload_schema: false,
+                shared_helpers: false,
+                visibility: Default::default(),
+                list_item_names: Default::default(),
+                targets: Target::ALL.to_vec(),
+                enabled_features: None,
+                namespace_qualifier: NamespaceQualifier::default(),
+                prelude: None,
+                epilogue: None,
             };
 
             let generator = RegistryGenerator::new(&config);
@@ -345,10 +519,29 @@ mod tests {
                 modular_output: false,
                 derive_debug: true,
                 derive_clone: true,
+                generate_constructors: false,
                 enable_namespace_prefixes: false,
                 restful_namespace_mode: NamespaceMode::Enabled,
+                bare_rpc_bodies: false,
                 enable_server_generation: true,
                 server_output_subdir: "server".to_string(),
+                serde_rename_all: false,
+                int64_encoding: Default::default(),
+                decimal64_representation: Default::default(),
+                date_and_time_representation: Default::default(),
+                enable_pretty_printers: false,
+                enable_cli: false,
+                enable_dyn_client: false,
+                enable_netconf: false,
+                enable_payload_schema: false,
+                shared_helpers: false,
+                visibility: Default::default(),
+                list_item_names: Default::default(),
+                targets: Target::ALL.to_vec(),
+                enabled_features: None,
+                namespace_qualifier: NamespaceQualifier::default(),
+                prelude: None,
+                epilogue: None,
             };
 
             let generator = RegistryGenerator::new(&config);
@@ -377,12 +570,20 @@ mod tests {
                 namespace: format!("http://example.com/{}", module_name),
                 prefix: module_name.chars().take(3).collect(),
                 yang_version: Some(YangVersion::V1_1),
+                organization: None,
+                contact: None,
+                description: None,
                 imports: Vec::new(),
+                features: Vec::new(),
                 typedefs: Vec::new(),
                 groupings: Vec::new(),
                 data_nodes: Vec::new(),
+                augments: Vec::new(),
+                deviations: Vec::new(),
                 rpcs: Vec::new(),
                 notifications: Vec::new(),
+                extensions: vec![],
+                unknown_statements: vec![],
             };
 
             let config = GeneratorConfig {
@@ -394,10 +595,29 @@ mod tests {
                 modular_output: false,
                 derive_debug: true,
                 derive_clone: true,
+                generate_constructors: false,
                 enable_namespace_prefixes: false,
                 restful_namespace_mode: NamespaceMode::Enabled,
+                bare_rpc_bodies: false,
                 enable_server_generation: true,
                 server_output_subdir: "server".to_string(),
+                serde_rename_all: false,
+                int64_encoding: Default::default(),
+                decimal64_representation: Default::default(),
+                date_and_time_representation: Default::default(),
+                enable_pretty_printers: false,
+                enable_cli: false,
+                enable_dyn_client: false,
+                enable_netconf: false,
+                enable_payload_schema: false,
+                shared_helpers: false,
+                visibility: Default::default(),
+                list_item_names: Default::default(),
+                targets: Target::ALL.to_vec(),
+                enabled_features: None,
+                namespace_qualifier: NamespaceQualifier::default(),
+                prelude: None,
+                epilogue: None,
             };
 
             let generator = RegistryGenerator::new(&config);
@@ -0,0 +1,111 @@
+//! Tests for the JSON encoding of 64-bit integers.
+
+use crate::generator::{CodeGenerator, GeneratorConfig, Int64Encoding};
+use crate::parser::YangParser;
+
+const COUNTERS: &str = r#"
+    typedef counter64 { type uint64; }
+    typedef bounded { type int64 { range "-100..100"; } }
+    container counters {
+        leaf in-octets { type uint64; mandatory true; }
+        leaf out-octets { type counter64; }
+        leaf offset { type int64; }
+        leaf delta { type bounded; }
+        leaf mtu { type uint32; }
+        leaf-list samples { type uint64; }
+    }
+"#;
+
+fn generate(encoding: Int64Encoding) -> String {
+    let input = format!(
+        "module test {{ namespace \"urn:test\"; prefix t; {} }}",
+        COUNTERS
+    );
+    let module = YangParser::new().parse_string(&input, "test.yang").unwrap();
+    let mut config = GeneratorConfig::default();
+    config.int64_encoding(encoding);
+    let generated = CodeGenerator::new(config).generate(&module).unwrap();
+    generated.files[0].content.clone()
+}
+
+#[test]
+fn test_number_encoding_is_default() {
+    let content = generate(Int64Encoding::default());
+
+    assert!(!content.contains("rustconf_runtime::int64"));
+    assert!(content.contains("pub in_octets: u64,"));
+}
+
+/// The serde attributes emitted directly above `field`, in order.
+fn serde_attrs(content: &str, field: &str) -> Vec<String> {
+    let lines: Vec<&str> = content.lines().collect();
+    let index = lines
+        .iter()
+        .position(|line| line.trim_start().starts_with(field))
+        .unwrap_or_else(|| panic!("field {} not generated", field));
+    lines[..index]
+        .iter()
+        .rev()
+        .take_while(|line| line.trim_start().starts_with("#[serde("))
+        .map(|line| line.trim().to_string())
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .collect()
+}
+
+const STRING_WITH: &str = r#"#[serde(with = "rustconf_runtime::int64::string")]"#;
+
+#[test]
+fn test_string_encoding_on_leaves_and_leaf_lists() {
+    let content = generate(Int64Encoding::String);
+
+    assert_eq!(
+        serde_attrs(&content, "pub in_octets: u64,"),
+        [r#"#[serde(rename = "in-octets")]"#, STRING_WITH]
+    );
+    // Missing optional leaves still deserialize to None
+    assert_eq!(
+        serde_attrs(&content, "pub offset: Option<i64>,"),
+        [
+            r#"#[serde(rename = "offset")]"#,
+            r#"#[serde(skip_serializing_if = "Option::is_none")]"#,
+            "#[serde(default)]",
+            STRING_WITH,
+        ]
+    );
+    assert!(serde_attrs(&content, "pub samples: Vec<u64>,").contains(&STRING_WITH.to_string()));
+    // Other integer types keep the number encoding
+    assert!(!serde_attrs(&content, "pub mtu: Option<u32>,").contains(&STRING_WITH.to_string()));
+}
+
+#[test]
+fn test_string_encoding_through_typedefs() {
+    let content = generate(Int64Encoding::String);
+
+    assert!(content.contains("pub type Counter64 = u64;"));
+    assert!(serde_attrs(&content, "pub out_octets: Option<Counter64>,")
+        .contains(&STRING_WITH.to_string()));
+}
+
+#[test]
+fn test_validated_types_encode_their_value() {
+    let content = generate(Int64Encoding::String);
+
+    // The validated type applies the encoding itself
+    assert!(
+        !serde_attrs(&content, "pub delta: Option<Bounded>,").contains(&STRING_WITH.to_string())
+    );
+    assert!(content.contains("rustconf_runtime::int64::string::serialize(&self.value, serializer)"));
+    assert!(content
+        .contains("let value: i64 = rustconf_runtime::int64::string::deserialize(deserializer)?;"));
+}
+
+#[test]
+fn test_lenient_encoding() {
+    let content = generate(Int64Encoding::Lenient);
+
+    assert!(serde_attrs(&content, "pub in_octets: u64,")
+        .contains(&r#"#[serde(with = "rustconf_runtime::int64::lenient")]"#.to_string()));
+    assert!(!content.contains("rustconf_runtime::int64::string"));
+}
@@ -1,7 +1,7 @@
 //! Integration tests for generated code compilation (Task 12.1-12.3)
 
-use crate::generator::{CodeGenerator, GeneratorConfig, NamespaceMode};
-use crate::parser::{Container, DataNode, Leaf, Notification, Rpc, TypeSpec, YangModule};
+use crate::generator::{CodeGenerator, GeneratorConfig, NamespaceMode, NamespaceQualifier, Target};
+use crate::parser::{Container, DataNode, Leaf, Notification, Rpc, Status, TypeSpec, YangModule};
 use std::fs;
 use tempfile::TempDir;
 
@@ -25,6 +25,9 @@ fn test_generated_container_code_compiles() {
         namespace: "urn:test:module".to_string(),
         prefix: "test".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
         typedefs: vec![],
         groupings: vec![],
@@ -43,7 +46,13 @@ fn test_generated_container_code_compiles() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
                 DataNode::Leaf(Leaf {
                     name: "enabled".to_string(),
@@ -51,7 +60,13 @@ fn test_generated_container_code_compiles() {
                     type_spec: TypeSpec::Boolean,
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
                 DataNode::Leaf(Leaf {
                     name: "mtu".to_string(),
@@ -59,12 +74,28 @@ fn test_generated_container_code_compiles() {
                     type_spec: TypeSpec::Uint16 { range: None },
                     mandatory: false,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
             ],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        features: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     // Generate code
@@ -118,6 +149,9 @@ fn test_generated_nested_container_code_compiles() {
         namespace: "urn:test:nested".to_string(),
         prefix: "nt".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
         typedefs: vec![],
         groupings: vec![],
@@ -136,7 +170,13 @@ fn test_generated_nested_container_code_compiles() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
                 DataNode::Container(Container {
                     name: "config".to_string(),
@@ -150,7 +190,13 @@ fn test_generated_nested_container_code_compiles() {
                             type_spec: TypeSpec::Boolean,
                             mandatory: true,
                             default: None,
+                            units: None,
                             config: true,
+                            if_features: vec![],
+                            when: None,
+                            must: vec![],
+                            unknown_statements: vec![],
+                            status: Status::Current,
                         }),
                         DataNode::Leaf(Leaf {
                             name: "mtu".to_string(),
@@ -158,9 +204,20 @@ fn test_generated_nested_container_code_compiles() {
                             type_spec: TypeSpec::Uint32 { range: None },
                             mandatory: false,
                             default: None,
+                            units: None,
                             config: true,
+                            if_features: vec![],
+                            when: None,
+                            must: vec![],
+                            unknown_statements: vec![],
+                            status: Status::Current,
                         }),
                     ],
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
                 DataNode::Container(Container {
                     name: "state".to_string(),
@@ -176,13 +233,34 @@ fn test_generated_nested_container_code_compiles() {
                         },
                         mandatory: true,
                         default: None,
+                        units: None,
                         config: false,
+                        if_features: vec![],
+                        when: None,
+                        must: vec![],
+                        unknown_statements: vec![],
+                        status: Status::Current,
                     })],
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
             ],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        features: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     // Generate code
@@ -231,6 +309,9 @@ fn test_generated_validation_code_compiles_and_validates() {
         namespace: "urn:test:validation".to_string(),
         prefix: "vt".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
         typedefs: vec![],
         groupings: vec![],
@@ -248,7 +329,13 @@ fn test_generated_validation_code_compiles_and_validates() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
                 DataNode::Leaf(Leaf {
                     name: "timeout".to_string(),
@@ -258,12 +345,28 @@ fn test_generated_validation_code_compiles_and_validates() {
                     },
                     mandatory: false,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
             ],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        features: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     // Generate code
@@ -336,10 +439,15 @@ fn test_generated_rpc_code_compiles() {
         namespace: "urn:example:interface-mgmt".to_string(),
         prefix: "if-mgmt".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![
             Rpc {
                 name: "reset-interface".to_string(),
@@ -353,9 +461,17 @@ fn test_generated_rpc_code_compiles() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 })]),
                 output: None,
+                timeout: None,
+                unknown_statements: vec![],
             },
             Rpc {
                 name: "get-statistics".to_string(),
@@ -369,7 +485,13 @@ fn test_generated_rpc_code_compiles() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 })]),
                 output: Some(vec![
                     DataNode::Leaf(Leaf {
@@ -378,7 +500,13 @@ fn test_generated_rpc_code_compiles() {
                         type_spec: TypeSpec::Uint64 { range: None },
                         mandatory: true,
                         default: None,
+                        units: None,
                         config: false,
+                        if_features: vec![],
+                        when: None,
+                        must: vec![],
+                        unknown_statements: vec![],
+                        status: Status::Current,
                     }),
                     DataNode::Leaf(Leaf {
                         name: "tx-packets".to_string(),
@@ -386,12 +514,23 @@ fn test_generated_rpc_code_compiles() {
                         type_spec: TypeSpec::Uint64 { range: None },
                         mandatory: true,
                         default: None,
+                        units: None,
                         config: false,
+                        if_features: vec![],
+                        when: None,
+                        must: vec![],
+                        unknown_statements: vec![],
+                        status: Status::Current,
                     }),
                 ]),
+                timeout: None,
+                unknown_statements: vec![],
             },
         ],
         notifications: vec![],
+        features: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -416,8 +555,10 @@ fn test_generated_rpc_code_compiles() {
     assert!(content.contains("NotImplemented"));
 
     // Verify function signatures
-    assert!(content.contains("input: ResetInterfaceInput) -> Result<(), RpcError>"));
-    assert!(content.contains("input: GetStatisticsInput) -> Result<GetStatisticsOutput, RpcError>"));
+    assert!(content.contains("input: &ResetInterfaceInput) -> Result<(), RpcError>"));
+    assert!(
+        content.contains("input: &GetStatisticsInput) -> Result<GetStatisticsOutput, RpcError>")
+    );
 
     // Verify rustdoc comments
     assert!(content.contains("/// Reset an interface to default state"));
@@ -440,12 +581,31 @@ fn test_generated_notification_code_compiles() {
         enable_validation: true,
         derive_debug: true,
         derive_clone: true,
+        generate_constructors: false,
         enable_namespace_prefixes: false,
         enable_restful_rpcs: false,
         restful_namespace_mode: NamespaceMode::default(),
+        bare_rpc_bodies: false,
         modular_output: false,
         enable_server_generation: false,
         server_output_subdir: "server".to_string(),
+        serde_rename_all: false,
+        int64_encoding: Default::default(),
+        decimal64_representation: Default::default(),
+        date_and_time_representation: Default::default(),
+        enable_pretty_printers: false,
+        enable_cli: false,
+        enable_dyn_client: false,
+        enable_netconf: false,
+        enable_payload_schema: false,
+        shared_helpers: false,
+        visibility: Default::default(),
+        list_item_names: Default::default(),
+        targets: Target::ALL.to_vec(),
+        enabled_features: None,
+        namespace_qualifier: NamespaceQualifier::default(),
+        prelude: None,
+        epilogue: None,
     };
 
     let generator = CodeGenerator::new(config);
@@ -456,10 +616,15 @@ fn test_generated_notification_code_compiles() {
         namespace: "urn:test:notifications".to_string(),
         prefix: "tn".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![
             Notification {
@@ -475,7 +640,13 @@ fn test_generated_notification_code_compiles() {
                         },
                         mandatory: true,
                         default: None,
+                        units: None,
                         config: false,
+                        if_features: vec![],
+                        when: None,
+                        must: vec![],
+                        unknown_statements: vec![],
+                        status: Status::Current,
                     }),
                     DataNode::Leaf(Leaf {
                         name: "speed".to_string(),
@@ -483,9 +654,16 @@ fn test_generated_notification_code_compiles() {
                         type_spec: TypeSpec::Uint32 { range: None },
                         mandatory: true,
                         default: None,
+                        units: None,
                         config: false,
+                        if_features: vec![],
+                        when: None,
+                        must: vec![],
+                        unknown_statements: vec![],
+                        status: Status::Current,
                     }),
                 ],
+                unknown_statements: vec![],
             },
             Notification {
                 name: "link-down".to_string(),
@@ -500,7 +678,13 @@ fn test_generated_notification_code_compiles() {
                         },
                         mandatory: true,
                         default: None,
+                        units: None,
                         config: false,
+                        if_features: vec![],
+                        when: None,
+                        must: vec![],
+                        unknown_statements: vec![],
+                        status: Status::Current,
                     }),
                     DataNode::Leaf(Leaf {
                         name: "reason".to_string(),
@@ -511,11 +695,21 @@ fn test_generated_notification_code_compiles() {
                         },
                         mandatory: false,
                         default: None,
+                        units: None,
                         config: false,
+                        if_features: vec![],
+                        when: None,
+                        must: vec![],
+                        unknown_statements: vec![],
+                        status: Status::Current,
                     }),
                 ],
+                unknown_statements: vec![],
             },
         ],
+        features: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     // Generate code
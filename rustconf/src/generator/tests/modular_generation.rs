@@ -1,7 +1,10 @@
 //! Tests for modular code generation.
 
-use crate::generator::{CodeGenerator, GeneratorConfig};
-use crate::parser::{Container, DataNode, Leaf, TypeSpec, YangModule, YangVersion};
+use crate::generator::{CodeGenerator, GeneratorConfig, Target};
+use crate::parser::{
+    Case, Choice, Container, DataNode, Import, Leaf, List, Notification, Rpc, Status, TypeSpec,
+    YangModule, YangVersion,
+};
 
 #[test]
 fn test_modular_generation_creates_multiple_files() {
@@ -19,7 +22,11 @@ fn test_modular_generation_creates_multiple_files() {
         namespace: "http://example.com/test".to_string(),
         prefix: "test".to_string(),
         yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::Container(Container {
@@ -36,11 +43,26 @@ fn test_modular_generation_creates_multiple_files() {
                 },
                 mandatory: false,
                 default: None,
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate(&module);
@@ -48,8 +70,8 @@ fn test_modular_generation_creates_multiple_files() {
 
     let generated = result.unwrap();
 
-    // Should generate 4 files: mod.rs, types.rs, operations.rs, validation.rs
-    assert_eq!(generated.file_count(), 4);
+    // Should generate 6 files: mod.rs, types.rs, consts.rs, schema.rs, operations.rs, validation.rs
+    assert_eq!(generated.file_count(), 6);
 
     // Check that mod.rs exists
     let mod_file = generated.files.iter().find(|f| f.path.ends_with("mod.rs"));
@@ -78,7 +100,7 @@ fn test_modular_generation_creates_multiple_files() {
     assert!(ops_file.is_some());
     let ops_content = &ops_file.unwrap().content;
     assert!(ops_content.contains(
-        "use rustconf_runtime::{RestconfClient, HttpTransport, HttpRequest, HttpMethod, RpcError};"
+        "use rustconf_runtime::{RestconfClient, HttpTransport, HttpRequest, HttpResponse, HttpMethod, RpcError, ResourceCapabilities, ResponseEnvelope};"
     ));
 
     // Check that validation.rs exists
@@ -107,7 +129,11 @@ fn test_modular_generation_without_validation() {
         namespace: "http://example.com/test".to_string(),
         prefix: "test".to_string(),
         yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::Container(Container {
@@ -116,9 +142,18 @@ fn test_modular_generation_without_validation() {
             config: true,
             mandatory: false,
             children: vec![],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate(&module);
@@ -126,8 +161,8 @@ fn test_modular_generation_without_validation() {
 
     let generated = result.unwrap();
 
-    // Should generate 3 files: mod.rs, types.rs, operations.rs (no validation.rs)
-    assert_eq!(generated.file_count(), 3);
+    // Should generate 5 files: mod.rs, types.rs, consts.rs, schema.rs, operations.rs (no validation.rs)
+    assert_eq!(generated.file_count(), 5);
 
     // Check that validation.rs does not exist
     let val_file = generated
@@ -153,12 +188,20 @@ fn test_modular_generation_without_operations() {
         namespace: "http://example.com/test".to_string(),
         prefix: "test".to_string(),
         yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate(&module);
@@ -166,8 +209,8 @@ fn test_modular_generation_without_operations() {
 
     let generated = result.unwrap();
 
-    // Should generate 3 files: mod.rs, types.rs, validation.rs (no operations.rs)
-    assert_eq!(generated.file_count(), 3);
+    // Should generate 4 files: mod.rs, types.rs, consts.rs, validation.rs (no operations.rs)
+    assert_eq!(generated.file_count(), 4);
 
     // Check that operations.rs does not exist
     let ops_file = generated
@@ -193,7 +236,11 @@ fn test_single_file_generation_still_works() {
         namespace: "http://example.com/test".to_string(),
         prefix: "test".to_string(),
         yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::Container(Container {
@@ -202,9 +249,18 @@ fn test_single_file_generation_still_works() {
             config: true,
             mandatory: false,
             children: vec![],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate(&module);
@@ -215,3 +271,520 @@ fn test_single_file_generation_still_works() {
     // Should generate 1 file
     assert_eq!(generated.file_count(), 1);
 }
+
+fn hostname_module() -> YangModule {
+    YangModule {
+        name: "test-module".to_string(),
+        namespace: "http://example.com/test".to_string(),
+        prefix: "test".to_string(),
+        yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
+        imports: vec![],
+        features: vec![],
+        typedefs: vec![],
+        groupings: vec![],
+        data_nodes: vec![DataNode::Container(Container {
+            name: "config".to_string(),
+            description: None,
+            config: true,
+            mandatory: false,
+            children: vec![DataNode::Leaf(Leaf {
+                name: "hostname".to_string(),
+                description: None,
+                type_spec: TypeSpec::String {
+                    length: None,
+                    pattern: None,
+                },
+                mandatory: false,
+                default: None,
+                units: None,
+                config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
+            })],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
+        })],
+        augments: vec![],
+        deviations: vec![],
+        rpcs: vec![],
+        notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
+    }
+}
+
+#[test]
+fn test_prelude_and_epilogue_in_modular_output() {
+    let mut config = GeneratorConfig {
+        modular_output: true,
+        enable_restful_rpcs: true,
+        ..Default::default()
+    };
+    config
+        .prelude("#![allow(clippy::all)]\nuse crate::ext::Extra;")
+        .epilogue(
+            "impl Config {\n    pub fn is_named(&self) -> bool { self.hostname.is_some() }\n}",
+        );
+
+    let generated = CodeGenerator::new(config)
+        .generate(&hostname_module())
+        .unwrap();
+
+    for file in &generated.files {
+        // Prelude follows the header and generated inner attributes, before any items
+        let prelude = file.content.find("#![allow(clippy::all)]").unwrap();
+        let generated_allow = file.content.find("#![allow(unused_imports").unwrap();
+        let first_use = file.content.find("use ").unwrap();
+        assert!(generated_allow < prelude, "{}", file.path.display());
+        assert!(prelude < first_use, "{}", file.path.display());
+        assert!(file.content.contains("use crate::ext::Extra;"));
+    }
+
+    let mod_file = generated
+        .files
+        .iter()
+        .find(|f| f.path.ends_with("mod.rs"))
+        .unwrap();
+    assert!(mod_file.content.trim_end().ends_with("}"));
+    assert!(mod_file.content.contains("pub fn is_named(&self)"));
+    let epilogue_count = generated
+        .files
+        .iter()
+        .filter(|f| f.content.contains("pub fn is_named(&self)"))
+        .count();
+    assert_eq!(epilogue_count, 1);
+}
+
+#[test]
+fn test_prelude_and_epilogue_in_single_file_output() {
+    let mut config = GeneratorConfig::default();
+    config
+        .prelude("use std::fmt;")
+        .epilogue("impl fmt::Display for Config {}");
+
+    let generated = CodeGenerator::new(config)
+        .generate(&hostname_module())
+        .unwrap();
+    assert_eq!(generated.file_count(), 1);
+
+    let content = &generated.files[0].content;
+    assert!(content.starts_with("// This file is automatically generated by rustconf."));
+    assert!(content.find("use std::fmt;").unwrap() < content.find("use serde::").unwrap());
+    assert!(content.ends_with("impl fmt::Display for Config {}\n"));
+}
+
+#[test]
+fn test_consts_file_lists_media_types_and_streams() {
+    let config = GeneratorConfig {
+        modular_output: true,
+        enable_restful_rpcs: true,
+        ..Default::default()
+    };
+
+    let mut module = hostname_module();
+    module.rpcs.push(Rpc {
+        name: "restart-device".to_string(),
+        description: None,
+        input: None,
+        output: None,
+        timeout: None,
+        unknown_statements: vec![],
+    });
+    module.notifications.push(Notification {
+        name: "link-down".to_string(),
+        description: None,
+        data_nodes: vec![],
+        unknown_statements: vec![],
+    });
+
+    let generated = CodeGenerator::new(config).generate(&module).unwrap();
+    let file = |name: &str| {
+        generated
+            .files
+            .iter()
+            .find(|f| f.path.ends_with(name))
+            .unwrap()
+    };
+
+    let consts = &file("consts.rs").content;
+    assert!(consts.contains(r#"pub const YANG_DATA_JSON: &str = "application/yang-data+json";"#));
+    assert!(consts.contains(r#"pub const OPERATIONS_PATH: &str = "/restconf/operations";"#));
+    assert!(consts.contains("pub const STREAMS: &[&str] = &[NETCONF_STREAM];"));
+    assert!(consts
+        .contains(r#"pub const OPERATION_RESTART_DEVICE: &str = "test-module:restart-device";"#));
+    assert!(consts.contains(r#"pub const NOTIFICATIONS: &[&str] = &["test-module:link-down"];"#));
+
+    assert!(file("mod.rs").content.contains("pub mod consts;"));
+    let operations = &file("operations.rs").content;
+    assert!(operations.contains("use super::consts;"));
+    assert!(operations.contains("consts::OPERATIONS_PATH"));
+    assert!(operations.contains("consts::YANG_DATA_JSON.to_string()"));
+    assert!(!operations.contains("\"application/yang-data+json\""));
+}
+
+#[test]
+fn test_consts_list_module_revisions_for_yang_library_checks() {
+    let mut module = hostname_module();
+    module.imports = vec![
+        Import {
+            module: "ietf-inet-types".to_string(),
+            prefix: "inet".to_string(),
+            revision: Some("2013-07-15".to_string()),
+            description: None,
+            reference: None,
+        },
+        Import {
+            module: "ietf-yang-types".to_string(),
+            prefix: "yang".to_string(),
+            revision: None,
+            description: None,
+            reference: None,
+        },
+        Import {
+            module: "vendor-types".to_string(),
+            prefix: "vt".to_string(),
+            revision: None,
+            description: None,
+            reference: None,
+        },
+    ];
+
+    let generated = CodeGenerator::new(GeneratorConfig::default())
+        .with_module_revisions([
+            ("test-module".to_string(), "2024-03-01".to_string()),
+            ("ietf-inet-types".to_string(), "2021-02-22".to_string()),
+            ("ietf-yang-types".to_string(), "2023-01-23".to_string()),
+        ])
+        .generate(&module)
+        .unwrap();
+    let content = &generated.files[0].content;
+
+    assert!(content.contains(r#"pub const MODULE_REVISION: Option<&str> = Some("2024-03-01");"#));
+    assert!(content.contains(
+        r#"pub const IMPLEMENTED_MODULES: &[(&str, Option<&str>)] = &[("test-module", MODULE_REVISION)];"#
+    ));
+    // An import's own revision-date wins over the loaded revision
+    assert!(content.contains(
+        r#"pub const IMPORTED_MODULES: &[(&str, Option<&str>)] = &[("ietf-inet-types", Some("2013-07-15")), ("ietf-yang-types", Some("2023-01-23")), ("vendor-types", None)];"#
+    ));
+
+    // Without known revisions any server revision is accepted
+    let generated = CodeGenerator::new(GeneratorConfig::default())
+        .generate(&hostname_module())
+        .unwrap();
+    let content = &generated.files[0].content;
+    assert!(content.contains("pub const MODULE_REVISION: Option<&str> = None;"));
+    assert!(content.contains("pub const IMPORTED_MODULES: &[(&str, Option<&str>)] = &[];"));
+}
+
+#[test]
+fn test_module_metadata_in_docs_and_consts() {
+    let config = GeneratorConfig {
+        modular_output: true,
+        ..Default::default()
+    };
+
+    let mut module = hostname_module();
+    module.organization = Some("Example Networks".to_string());
+    module.contact = Some(
+        "Web:   <https://example.com/yang>\n     Email: <mailto:yang@example.com>".to_string(),
+    );
+    module.description = Some("Hostname configuration.\n\nSecond paragraph.".to_string());
+
+    let generated = CodeGenerator::new(config).generate(&module).unwrap();
+    let file = |name: &str| {
+        generated
+            .files
+            .iter()
+            .find(|f| f.path.ends_with(name))
+            .unwrap()
+    };
+
+    let mod_file = &file("mod.rs").content;
+    assert!(mod_file.contains(
+        "//! Hostname configuration.\n//!\n//! Second paragraph.\n//!\n//! # Organization\n//!\n//! Example Networks\n"
+    ));
+    assert!(mod_file.contains(
+        "//! # Contact\n//!\n//! ```text\n//! Web:   <https://example.com/yang>\n//! Email: <mailto:yang@example.com>\n//! ```\n"
+    ));
+    assert!(mod_file.find("//! Hostname").unwrap() < mod_file.find("#![allow").unwrap());
+
+    let consts = &file("consts.rs").content;
+    assert!(consts
+        .contains(r#"pub const MODULE_ORGANIZATION: Option<&str> = Some("Example Networks");"#));
+    assert!(consts.contains(r#"Some("Web:   <https://example.com/yang>\n     Email:"#));
+    assert!(consts.contains(
+        r#"pub const MODULE_DESCRIPTION: Option<&str> = Some("Hostname configuration.\n\nSecond paragraph.");"#
+    ));
+
+    // Modules without metadata get no crate-level docs
+    let generated = CodeGenerator::new(GeneratorConfig {
+        modular_output: true,
+        ..Default::default()
+    })
+    .generate(&hostname_module())
+    .unwrap();
+    let mod_file = &generated.files[0].content;
+    assert!(!mod_file.contains("//!"));
+    let consts = generated
+        .files
+        .iter()
+        .find(|f| f.path.ends_with("consts.rs"))
+        .unwrap();
+    assert!(consts
+        .content
+        .contains("pub const MODULE_ORGANIZATION: Option<&str> = None;"));
+}
+
+#[test]
+fn test_module_metadata_in_single_file_header() {
+    let mut module = hostname_module();
+    module.organization = Some("Example Networks".to_string());
+
+    let generated = CodeGenerator::new(GeneratorConfig::default())
+        .generate(&module)
+        .unwrap();
+
+    let content = &generated.files[0].content;
+    assert!(content.contains("// Organization:\n//   Example Networks\n"));
+    assert!(!content.contains("//!"));
+}
+
+#[test]
+fn test_schema_file_maps_data_nodes_for_error_paths() {
+    let config = GeneratorConfig {
+        modular_output: true,
+        ..Default::default()
+    };
+
+    let leaf = |name: &str| {
+        DataNode::Leaf(Leaf {
+            name: name.to_string(),
+            description: None,
+            type_spec: TypeSpec::Uint16 { range: None },
+            mandatory: false,
+            default: None,
+            units: None,
+            config: true,
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
+        })
+    };
+    let mut module = hostname_module();
+    module.data_nodes.push(DataNode::List(List {
+        name: "server".to_string(),
+        description: None,
+        config: true,
+        keys: vec!["port".to_string()],
+        children: vec![
+            leaf("port"),
+            DataNode::Choice(Choice {
+                name: "transport".to_string(),
+                description: None,
+                mandatory: false,
+                cases: vec![Case {
+                    name: "tcp".to_string(),
+                    description: None,
+                    data_nodes: vec![leaf("type")],
+                    if_features: vec![],
+                    when: None,
+                    unknown_statements: vec![],
+                    status: Status::Current,
+                }],
+                if_features: vec![],
+                when: None,
+                unknown_statements: vec![],
+                status: Status::Current,
+            }),
+        ],
+        if_features: vec![],
+        when: None,
+        must: vec![],
+        min_elements: 0,
+        max_elements: None,
+        unknown_statements: vec![],
+        status: Status::Current,
+    }));
+
+    let generated = CodeGenerator::new(config).generate(&module).unwrap();
+    let file = |name: &str| {
+        generated
+            .files
+            .iter()
+            .find(|f| f.path.ends_with(name))
+            .unwrap()
+    };
+
+    let schema = &file("schema.rs").content;
+    assert!(schema.contains("pub enum SchemaNode {"));
+    assert!(schema.contains("SchemaNode::ConfigHostname => \"/config/hostname\","));
+    assert!(schema.contains("SchemaNode::ServerPort => Some(SchemaNode::Server),"));
+    // Choice and case are not part of the data tree
+    assert!(schema.contains("SchemaNode::ServerType => \"/server/type\","));
+    // Keyword-escaped fields are named without the raw identifier prefix
+    assert!(schema.contains("SchemaNode::ServerType => \"type_\","));
+    assert!(schema.contains("pub fn from_error_path(error_path: &str) -> Option<SchemaNode> {"));
+
+    assert!(file("mod.rs").content.contains("pub mod schema;"));
+}
+
+#[test]
+fn test_types_get_and_set_by_schema_path() {
+    let config = GeneratorConfig {
+        modular_output: true,
+        ..Default::default()
+    };
+
+    let mut module = hostname_module();
+    module.data_nodes.push(DataNode::List(List {
+        name: "server".to_string(),
+        description: None,
+        config: true,
+        keys: vec!["name".to_string()],
+        children: vec![DataNode::Leaf(Leaf {
+            name: "name".to_string(),
+            description: None,
+            type_spec: TypeSpec::String {
+                length: None,
+                pattern: None,
+            },
+            mandatory: true,
+            default: None,
+            units: None,
+            config: true,
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
+        })],
+        if_features: vec![],
+        when: None,
+        must: vec![],
+        min_elements: 0,
+        max_elements: None,
+        unknown_statements: vec![],
+        status: Status::Current,
+    }));
+
+    let generated = CodeGenerator::new(config).generate(&module).unwrap();
+    let file = |name: &str| {
+        generated
+            .files
+            .iter()
+            .find(|f| f.path.ends_with(name))
+            .unwrap()
+    };
+
+    let schema = &file("schema.rs").content;
+    assert!(schema.contains("pub fn list_keys(&self) -> &'static [&'static str] {"));
+    assert!(schema.contains("SchemaNode::Server => &[\"name\"],"));
+    assert!(schema.contains("SchemaNode::ConfigHostname => &[],"));
+
+    let types = &file("types.rs").content;
+    assert!(types.contains("impl Config {"));
+    assert!(types.contains("impl Server {"));
+    assert!(types.contains("pub fn get_by_path(&self, path: &str) -> Option<serde_json::Value> {"));
+    assert!(types.contains("rustconf_runtime::data_path::get(&data, \"/server\", path, |path| {"));
+    assert!(types
+        .contains("super::schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())"));
+    assert!(types.contains(") -> Result<(), rustconf_runtime::DataPathError> {"));
+}
+
+fn module_with_all_targets() -> YangModule {
+    let mut module = hostname_module();
+    module.rpcs.push(Rpc {
+        name: "restart-device".to_string(),
+        description: None,
+        input: None,
+        output: None,
+        timeout: None,
+        unknown_statements: vec![],
+    });
+    module.notifications.push(Notification {
+        name: "link-down".to_string(),
+        description: None,
+        data_nodes: vec![DataNode::Leaf(Leaf {
+            name: "if-name".to_string(),
+            description: None,
+            type_spec: TypeSpec::String {
+                length: None,
+                pattern: None,
+            },
+            mandatory: true,
+            default: None,
+            units: None,
+            config: false,
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
+        })],
+        unknown_statements: vec![],
+    });
+    module
+}
+
+#[test]
+fn test_notifications_target_leaves_out_client_code() {
+    let mut config = GeneratorConfig {
+        modular_output: true,
+        enable_restful_rpcs: true,
+        ..Default::default()
+    };
+    config.targets(&[Target::Notifications]);
+
+    let generated = CodeGenerator::new(config)
+        .generate(&module_with_all_targets())
+        .unwrap();
+    let file = |name: &str| {
+        generated
+            .files
+            .iter()
+            .find(|f| f.path.ends_with(name))
+            .map(|f| f.content.as_str())
+    };
+
+    assert!(file("operations.rs").is_none());
+    assert!(file("schema.rs").is_none());
+    assert!(!file("types.rs").unwrap().contains("pub struct Config"));
+
+    let notifications = file("notifications.rs").unwrap();
+    assert!(notifications.contains("pub struct LinkDown"));
+
+    let mod_content = file("mod.rs").unwrap();
+    assert!(mod_content.contains("pub mod notifications;"));
+    assert!(!mod_content.contains("rustconf_runtime"));
+}
+
+#[test]
+fn test_rpcs_target_without_types_skips_data_resource_functions() {
+    let mut config = GeneratorConfig {
+        enable_restful_rpcs: true,
+        ..Default::default()
+    };
+    config.targets(&[Target::Rpcs]);
+
+    let generated = CodeGenerator::new(config)
+        .generate(&module_with_all_targets())
+        .unwrap();
+    let content = &generated.files[0].content;
+
+    assert!(content.contains("pub async fn restart_device"));
+    assert!(!content.contains("pub struct Config"));
+    assert!(!content.contains("get_config"));
+    assert!(!content.contains("pub mod notifications"));
+}
@@ -2,7 +2,7 @@
 
 use crate::generator::{CodeGenerator, GeneratorConfig};
 use crate::parser::{
-    Container, DataNode, Leaf, Notification, Rpc, TypeSpec, YangModule, YangVersion,
+    Container, DataNode, Leaf, Notification, Rpc, Status, TypeSpec, YangModule, YangVersion,
 };
 
 /// Helper to create a test YANG module with data nodes and RPCs.
@@ -12,7 +12,11 @@ fn create_test_module() -> YangModule {
         namespace: "http://example.com/test".to_string(),
         prefix: "test".to_string(),
         yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::Container(Container {
@@ -29,16 +33,33 @@ fn create_test_module() -> YangModule {
                 },
                 mandatory: false,
                 default: None,
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "restart-device".to_string(),
             description: Some("Restart the device".to_string()),
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     }
 }
 
@@ -64,10 +85,10 @@ fn test_modular_server_generation_creates_server_files() {
 
     let generated = result.unwrap();
 
-    // Should have: mod.rs, types.rs, operations.rs, validation.rs,
+    // Should have: mod.rs, types.rs, consts.rs, schema.rs, operations.rs, validation.rs,
     // + server/mod.rs, server/handlers.rs, server/stubs.rs, server/router.rs, server/registry.rs
-    // = 9 files total
-    assert_eq!(generated.file_count(), 9);
+    // = 11 files total
+    assert_eq!(generated.file_count(), 11);
 
     // Verify server files exist
     let server_mod = generated
@@ -171,8 +192,15 @@ fn test_server_mod_includes_notifications_when_present() {
             },
             mandatory: false,
             default: None,
+            units: None,
             config: false,
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        unknown_statements: vec![],
     });
 
     let config = server_config();
@@ -180,8 +208,8 @@ fn test_server_mod_includes_notifications_when_present() {
 
     let generated = generator.generate(&module).unwrap();
 
-    // Should have 10 files (9 + server/notifications.rs)
-    assert_eq!(generated.file_count(), 10);
+    // Should have 13 files (11 + notifications.rs + server/notifications.rs)
+    assert_eq!(generated.file_count(), 13);
 
     let server_mod = generated
         .files
@@ -254,8 +282,8 @@ fn test_no_server_files_when_disabled() {
 
     let generated = generator.generate(&module).unwrap();
 
-    // Should only have client files: mod.rs, types.rs, operations.rs, validation.rs
-    assert_eq!(generated.file_count(), 4);
+    // Should only have client files: mod.rs, types.rs, consts.rs, schema.rs, operations.rs, validation.rs
+    assert_eq!(generated.file_count(), 6);
 
     // No server files
     let server_files: Vec<_> = generated
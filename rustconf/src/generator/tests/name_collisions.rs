@@ -0,0 +1,153 @@
+//! Tests for detecting YANG names that map to the same Rust identifier.
+
+use crate::generator::{CodeGenerator, GeneratorConfig, GeneratorError};
+use crate::parser::{YangModule, YangParser};
+
+fn parse(body: &str) -> YangModule {
+    let input = format!(
+        "module test {{ namespace \"urn:test\"; prefix t; {} }}",
+        body
+    );
+    YangParser::new().parse_string(&input, "test.yang").unwrap()
+}
+
+fn collision(body: &str) -> (String, String, String, String) {
+    match CodeGenerator::new(GeneratorConfig::default()).generate(&parse(body)) {
+        Err(GeneratorError::NameCollision {
+            kind,
+            name,
+            first,
+            second,
+        }) => (kind.to_string(), name, first, second),
+        other => panic!("expected a name collision, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_fields_differing_by_hyphen_and_underscore_collide() {
+    let (kind, name, first, second) = collision(
+        r#"
+        container system {
+            leaf host-name { type string; }
+            leaf host_name { type string; }
+        }
+        "#,
+    );
+
+    assert_eq!(kind, "field");
+    assert_eq!(name, "host_name");
+    assert_eq!(first, "/system/host-name");
+    assert_eq!(second, "/system/host_name");
+}
+
+#[test]
+fn test_same_named_containers_in_different_parents_collide() {
+    let (kind, name, first, second) = collision(
+        r#"
+        container interfaces { container config { leaf mtu { type uint16; } } }
+        container routing { container config { leaf asn { type uint32; } } }
+        "#,
+    );
+
+    assert_eq!(kind, "type");
+    assert_eq!(name, "Config");
+    assert_eq!(first, "/interfaces/config");
+    assert_eq!(second, "/routing/config");
+}
+
+#[test]
+fn test_list_entry_type_collides_with_container() {
+    let (kind, name, first, second) = collision(
+        r#"
+        container user { leaf name { type string; } }
+        list users { key name; leaf name { type string; } }
+        "#,
+    );
+
+    assert_eq!(kind, "type");
+    assert_eq!(name, "User");
+    assert_eq!(first, "/user");
+    assert_eq!(second, "/users");
+}
+
+#[test]
+fn test_choice_cases_collide() {
+    let (kind, name, first, second) = collision(
+        r#"
+        container transport {
+            choice protocol {
+                case tcp-v4 { leaf a { type string; } }
+                case tcp_v4 { leaf b { type string; } }
+            }
+        }
+        "#,
+    );
+
+    assert_eq!(kind, "variant");
+    assert_eq!(name, "TcpV4");
+    assert_eq!(first, "/transport/protocol/tcp-v4");
+    assert_eq!(second, "/transport/protocol/tcp_v4");
+}
+
+#[test]
+fn test_rpc_input_fields_collide() {
+    let (kind, _, first, second) = collision(
+        r#"
+        rpc reboot {
+            input {
+                leaf delay-seconds { type uint32; }
+                leaf delay_seconds { type uint32; }
+            }
+        }
+        "#,
+    );
+
+    assert_eq!(kind, "field");
+    assert_eq!(first, "/reboot/input/delay-seconds");
+    assert_eq!(second, "/reboot/input/delay_seconds");
+}
+
+#[test]
+fn test_collision_error_names_both_paths() {
+    let error = CodeGenerator::new(GeneratorConfig::default())
+        .generate(&parse(
+            r#"
+            container system {
+                leaf x { type string; }
+                leaf x- { type string; }
+                leaf x_ { type string; }
+            }
+            "#,
+        ))
+        .unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "Name collision: /system/x- and /system/x_ both map to the Rust field `x_`"
+    );
+}
+
+#[test]
+fn test_distinct_names_generate() {
+    let module = parse(
+        r#"
+        container system {
+            leaf x { type string; }
+            leaf x- { type string; }
+            leaf type { type string; }
+            container config { leaf mtu { type uint16; } }
+        }
+        list servers { key name; leaf name { type string; } }
+        "#,
+    );
+
+    let generated = CodeGenerator::new(GeneratorConfig::default())
+        .generate(&module)
+        .unwrap();
+    let content = &generated.files[0].content;
+    assert!(content.contains("pub x: Option<String>,"));
+    assert!(content.contains("pub x_: Option<String>,"));
+    assert!(content.contains("pub type_: Option<String>,"));
+    assert!(content.contains("pub struct Config"));
+    assert!(content.contains("pub struct Server"));
+}
@@ -0,0 +1,110 @@
+//! Tests for the generated NETCONF schema.
+
+use super::{generate, module};
+use crate::generator::{CodeGenerator, GeneratorConfig, Int64Encoding};
+
+fn netconf_config() -> GeneratorConfig {
+    let mut config = GeneratorConfig::default();
+    config.enable_netconf();
+    config
+}
+
+#[test]
+fn test_netconf_schema_is_not_generated_by_default() {
+    let content = generate(GeneratorConfig::default());
+
+    assert!(!content.contains("netconf_schema"));
+    assert!(!content.contains("rustconf_runtime::netconf"));
+}
+
+#[test]
+fn test_netconf_schema_names_module_namespace() {
+    let content = generate(netconf_config());
+
+    assert!(
+        content.contains("pub fn netconf_schema() -> rustconf_runtime::netconf::NetconfSchema {")
+    );
+    assert!(content.contains(".with_module(\"device\", \"urn:device\")"));
+}
+
+#[test]
+fn test_netconf_schema_lists_keys_in_order() {
+    let content = generate(netconf_config());
+
+    assert!(content.contains(".with_node(\"/system\", NodeKind::Container, &[])"));
+    assert!(content
+        .contains(".with_node(\"/route\", NodeKind::List, &[\"destination\", \"prefix-length\"])"));
+    assert!(content
+        .contains(".with_node(\"/interfaces/tags\", NodeKind::LeafList(LeafValue::String), &[])"));
+}
+
+#[test]
+fn test_netconf_schema_leaf_values_follow_rfc7951() {
+    let content = generate(netconf_config());
+
+    for (path, value) in [
+        ("/system/hostname", "String"),
+        ("/system/mtu", "Number"),
+        ("/system/enabled", "Boolean"),
+        ("/system/debug", "Empty"),
+        ("/system/location", "Any"),
+        ("/route/prefix-length", "Number"),
+    ] {
+        let expected = format!(
+            ".with_node({:?}, NodeKind::Leaf(LeafValue::{}), &[])",
+            path, value
+        );
+        assert!(content.contains(&expected), "missing {}", expected);
+    }
+}
+
+#[test]
+fn test_netconf_schema_skips_choice_and_case() {
+    let content = generate(netconf_config());
+
+    assert!(
+        content.contains(".with_node(\"/system/port\", NodeKind::Leaf(LeafValue::Number), &[])")
+    );
+    assert!(!content.contains("/transport"));
+    assert!(!content.contains("/tcp"));
+}
+
+#[test]
+fn test_netconf_schema_int64_follows_encoding() {
+    let content = generate(netconf_config());
+    assert!(content
+        .contains(".with_node(\"/system/in-octets\", NodeKind::Leaf(LeafValue::Number), &[])"));
+
+    let mut config = netconf_config();
+    config.int64_encoding(Int64Encoding::String);
+    let content = generate(config);
+    assert!(content
+        .contains(".with_node(\"/system/in-octets\", NodeKind::Leaf(LeafValue::String), &[])"));
+    assert!(content
+        .contains(".with_node(\"/reboot/output/uptime\", NodeKind::Leaf(LeafValue::String), &[])"));
+}
+
+#[test]
+fn test_netconf_schema_includes_rpc_input_and_output() {
+    let content = generate(netconf_config());
+
+    assert!(content
+        .contains(".with_node(\"/reboot/input/delay\", NodeKind::Leaf(LeafValue::Number), &[])"));
+    assert!(content
+        .contains(".with_node(\"/reboot/output/uptime\", NodeKind::Leaf(LeafValue::Number), &[])"));
+}
+
+#[test]
+fn test_netconf_schema_in_modular_output() {
+    let mut config = netconf_config();
+    config.modular_output = true;
+    let generated = CodeGenerator::new(config).generate(&module()).unwrap();
+    let schema = generated
+        .files
+        .iter()
+        .find(|file| file.path.ends_with("schema.rs"))
+        .expect("schema.rs is generated");
+
+    syn::parse_file(&schema.content).unwrap();
+    assert!(schema.content.contains("pub fn netconf_schema()"));
+}
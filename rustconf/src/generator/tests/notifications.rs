@@ -1,7 +1,7 @@
 //! Unit tests for notification generation (Task 11.1)
 
-use crate::generator::{CodeGenerator, GeneratorConfig};
-use crate::parser::{DataNode, Leaf, Notification, TypeSpec, YangModule};
+use crate::generator::{CodeGenerator, GeneratorConfig, NamespaceQualifier};
+use crate::parser::{DataNode, Leaf, Notification, Status, TypeSpec, YangModule};
 
 #[test]
 fn test_generate_notification_with_no_data() {
@@ -13,16 +13,25 @@ fn test_generate_notification_with_no_data() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![Notification {
             name: "system-restart".to_string(),
             description: Some("System is restarting".to_string()),
             data_nodes: vec![],
+            unknown_statements: vec![],
         }],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -53,10 +62,15 @@ fn test_generate_notification_with_data_nodes() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![Notification {
             name: "interface-state-change".to_string(),
@@ -71,7 +85,13 @@ fn test_generate_notification_with_data_nodes() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
                 DataNode::Leaf(Leaf {
                     name: "new-state".to_string(),
@@ -82,7 +102,13 @@ fn test_generate_notification_with_data_nodes() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
                 DataNode::Leaf(Leaf {
                     name: "timestamp".to_string(),
@@ -90,10 +116,20 @@ fn test_generate_notification_with_data_nodes() {
                     type_spec: TypeSpec::Uint64 { range: None },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
             ],
+            unknown_statements: vec![],
         }],
+        features: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -127,10 +163,16 @@ fn test_generate_notification_with_optional_fields() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![Notification {
             name: "alarm".to_string(),
@@ -145,7 +187,13 @@ fn test_generate_notification_with_optional_fields() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
                 DataNode::Leaf(Leaf {
                     name: "message".to_string(),
@@ -156,10 +204,19 @@ fn test_generate_notification_with_optional_fields() {
                     },
                     mandatory: false,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
             ],
+            unknown_statements: vec![],
         }],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -185,10 +242,15 @@ fn test_generate_multiple_notifications() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![
             Notification {
@@ -203,8 +265,15 @@ fn test_generate_multiple_notifications() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 })],
+                unknown_statements: vec![],
             },
             Notification {
                 name: "link-down".to_string(),
@@ -218,10 +287,20 @@ fn test_generate_multiple_notifications() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 })],
+                unknown_statements: vec![],
             },
         ],
+        features: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -248,12 +327,20 @@ fn test_notification_not_generated_when_no_notifications() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -273,16 +360,25 @@ fn test_notification_without_description() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![Notification {
             name: "event".to_string(),
             description: None,
             data_nodes: vec![],
+            unknown_statements: vec![],
         }],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -296,6 +392,7 @@ fn test_notification_without_description() {
 fn test_notification_with_namespace_prefixes() {
     let config = GeneratorConfig {
         enable_namespace_prefixes: true,
+        namespace_qualifier: NamespaceQualifier::Prefix,
         ..Default::default()
     };
     let generator = CodeGenerator::new(config);
@@ -305,10 +402,16 @@ fn test_notification_with_namespace_prefixes() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![Notification {
             name: "status-change".to_string(),
@@ -322,9 +425,18 @@ fn test_notification_with_namespace_prefixes() {
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: false,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })],
+            unknown_statements: vec![],
         }],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -333,3 +445,111 @@ fn test_notification_with_namespace_prefixes() {
     // Check namespace prefix in serde rename
     assert!(content.contains(r#"#[serde(rename = "t:status")]"#));
 }
+
+#[test]
+fn test_notification_fields_unqualified_with_module_name_qualifiers() {
+    let config = GeneratorConfig {
+        enable_namespace_prefixes: true,
+        ..Default::default()
+    };
+    let generator = CodeGenerator::new(config);
+
+    let module = YangModule {
+        name: "test".to_string(),
+        namespace: "urn:test".to_string(),
+        prefix: "t".to_string(),
+        yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
+        imports: vec![],
+        features: vec![],
+        typedefs: vec![],
+        groupings: vec![],
+        data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
+        rpcs: vec![],
+        notifications: vec![Notification {
+            name: "status-change".to_string(),
+            description: None,
+            data_nodes: vec![DataNode::Leaf(Leaf {
+                name: "status".to_string(),
+                description: None,
+                type_spec: TypeSpec::String {
+                    length: None,
+                    pattern: None,
+                },
+                mandatory: true,
+                default: None,
+                units: None,
+                config: false,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
+            })],
+            unknown_statements: vec![],
+        }],
+        extensions: vec![],
+        unknown_statements: vec![],
+    };
+
+    let generated = generator.generate(&module).unwrap();
+    let content = &generated.files[0].content;
+
+    // RFC 7951: members in the same namespace as their parent are not qualified
+    assert!(content.contains(r#"#[serde(rename = "status")]"#));
+    assert!(!content.contains(r#""t:status""#));
+}
+
+#[test]
+fn test_notification_anydata_fields_and_registration() {
+    let mut parser = crate::parser::YangParser::new();
+    let mut module = parser
+        .parse_string(
+            r#"
+            module events {
+                namespace "urn:events";
+                prefix ev;
+
+                container settings {
+                    leaf level { type uint8; }
+                }
+                list sink {
+                    key "name";
+                    leaf name { type string; }
+                }
+
+                notification config-changed {
+                    leaf user { type string; }
+                    anydata changes {
+                        mandatory true;
+                        description "The changed subtrees.";
+                    }
+                    anyxml raw;
+                }
+            }
+        "#,
+            "events.yang",
+        )
+        .unwrap();
+    parser.expand_module(&mut module).unwrap();
+
+    let generated = CodeGenerator::new(GeneratorConfig::default())
+        .generate(&module)
+        .unwrap();
+    let content = &generated.files[0].content;
+
+    assert!(content.contains("/// The changed subtrees."), "{}", content);
+    assert!(content.contains("pub changes: rustconf_runtime::AnyData,"));
+    assert!(content.contains("/// The `raw` anyxml."));
+    assert!(content.contains("pub raw: Option<rustconf_runtime::AnyData>,"));
+
+    // The module's own top-level nodes are registered for other modules' anydata
+    assert!(content
+        .contains("pub fn register_anydata(registry: &mut rustconf_runtime::AnyDataRegistry) {"));
+    assert!(content.contains("registry.register::<Settings>(\"events:settings\");"));
+    assert!(content.contains("registry.register::<Vec<Sink>>(\"events:sink\");"));
+}
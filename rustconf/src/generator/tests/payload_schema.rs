@@ -0,0 +1,80 @@
+//! Tests for the generated payload schema.
+
+use super::generate;
+use crate::generator::{GeneratorConfig, Int64Encoding};
+
+fn payload_config() -> GeneratorConfig {
+    let mut config = GeneratorConfig::default();
+    config.enable_payload_schema();
+    config
+}
+
+#[test]
+fn test_payload_schema_is_not_generated_by_default() {
+    let content = generate(GeneratorConfig::default());
+
+    assert!(!content.contains("payload_schema"));
+    assert!(!content.contains("rustconf_runtime::payload"));
+}
+
+#[test]
+fn test_payload_schema_lists_nodes_and_keys() {
+    let content = generate(payload_config());
+
+    assert!(
+        content.contains("pub fn payload_schema() -> rustconf_runtime::payload::PayloadSchema {")
+    );
+    assert!(content.contains(".container(\"/system\")"));
+    assert!(content.contains(".list(\"/route\", &[\"destination\", \"prefix-length\"])"));
+    assert!(content.contains(".leaf_list(\"/interfaces/tags\", LeafType::string(&[]))"));
+}
+
+#[test]
+fn test_payload_schema_leaf_types_carry_restrictions() {
+    let content = generate(payload_config());
+
+    for expected in [
+        ".leaf(\"/system/hostname\", LeafType::string(&[]), false)",
+        ".leaf(\"/system/domain\", LeafType::string(&[(1, 63)]), true)",
+        ".leaf(\"/system/mtu\", LeafType::integer(&[(68, 9000)]), false)",
+        ".leaf(\"/system/load\", LeafType::integer(&[(0, 100)]), false)",
+        ".leaf(\"/system/mode\", LeafType::enumeration(&[\"auto\", \"manual\"]), false)",
+        ".leaf(\"/system/flags\", LeafType::bits(&[\"up\", \"running\"]), false)",
+        ".leaf(\"/system/location\", LeafType::Union(vec![LeafType::string(&[]), LeafType::integer(&[(-2147483648, 2147483647)])]), false)",
+    ] {
+        assert!(content.contains(expected), "missing {}", expected);
+    }
+}
+
+#[test]
+fn test_payload_schema_leaves_in_choices_are_not_mandatory() {
+    let content = generate(payload_config());
+
+    assert!(content.contains(".leaf(\"/system/port\", LeafType::integer(&[(0, 65535)]), false)"));
+    assert!(!content.contains("/transport"));
+}
+
+#[test]
+fn test_payload_schema_int64_follows_encoding() {
+    let content = generate(payload_config());
+    assert!(content.contains(
+        ".leaf(\"/system/in-octets\", LeafType::integer(&[(0, 18446744073709551615)]), false)"
+    ));
+
+    let mut config = payload_config();
+    config.int64_encoding(Int64Encoding::String);
+    let content = generate(config);
+    assert!(content.contains(
+        ".leaf(\"/system/in-octets\", LeafType::quoted_integer(&[(0, 18446744073709551615)]), false)"
+    ));
+}
+
+#[test]
+fn test_payload_schema_includes_rpc_input() {
+    let content = generate(payload_config());
+
+    assert!(content.contains(".container(\"/reboot\")"));
+    assert!(content.contains(".container(\"/reboot/input\")"));
+    assert!(content
+        .contains(".leaf(\"/reboot/input/delay\", LeafType::integer(&[(0, 4294967295)]), false)"));
+}
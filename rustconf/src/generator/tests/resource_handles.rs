@@ -0,0 +1,414 @@
+//! Tests for the generated resource handles.
+
+use super::{generate, module, DEVICE};
+use crate::generator::{CodeGenerator, GeneratorConfig};
+use crate::parser::YangParser;
+
+fn restful_config() -> GeneratorConfig {
+    let mut config = GeneratorConfig::default();
+    config.enable_restful_rpcs();
+    config
+}
+
+#[test]
+fn test_resources_not_generated_without_restful_rpcs() {
+    let content = generate(GeneratorConfig::default());
+
+    assert!(!content.contains("pub mod resources"));
+}
+
+#[test]
+fn test_client_gets_a_method_per_top_level_node() {
+    let content = generate(restful_config());
+
+    assert!(content.contains("pub mod resources {"));
+    assert!(content.contains("pub trait DeviceResources<T: HttpTransport> {"));
+    assert!(content.contains("impl<T: HttpTransport> DeviceResources<T> for RestconfClient<T> {"));
+    assert!(content
+        .contains("/// System settings.\n            fn system(&self) -> SystemResource<'_, T>;"));
+    assert!(content.contains(
+        "/// The `state` container.\n            fn state(&self) -> StateResource<'_, T>;"
+    ));
+    assert!(content.contains("fn interfaces(&self) -> InterfacesResource<'_, T>;"));
+    assert!(content
+        .contains("SystemResource { client: self, path: \"/restconf/data/system\".to_string() }"));
+}
+
+#[test]
+fn test_container_handle_methods() {
+    let content = generate(restful_config());
+    let system = handle_impl(&content, "SystemResource");
+
+    assert!(system.contains("pub async fn get(&self) -> Result<System, RpcError> {"));
+    assert!(system.contains("decode(\"device:system\", &response)"));
+    assert!(system.contains("pub async fn put(&self, data: &System) -> Result<(), RpcError> {"));
+    assert!(system.contains("pub async fn patch(&self, data: &System) -> Result<(), RpcError> {"));
+    assert!(system.contains("pub async fn delete(&self) -> Result<(), RpcError> {"));
+    assert!(system.contains("pub async fn exists(&self) -> Result<bool, RpcError> {"));
+    assert!(
+        system.contains("pub async fn options(&self) -> Result<ResourceCapabilities, RpcError> {")
+    );
+
+    // State data can only be read
+    let state = handle_impl(&content, "StateResource");
+    assert!(state.contains("pub async fn get(&self) -> Result<State, RpcError> {"));
+    assert!(!state.contains("pub async fn put("));
+    assert!(!state.contains("pub async fn delete("));
+}
+
+#[test]
+fn test_leaf_accessors_of_augmented_leaves_keep_their_module() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    std::fs::write(temp_dir.path().join("device.yang"), DEVICE).unwrap();
+    let mut parser = YangParser::new();
+    parser.add_search_path(temp_dir.path().to_path_buf());
+    let device = parser.parse_string(DEVICE, "device.yang").unwrap();
+    let ext = parser
+        .parse_string(
+            r#"
+            module device-ext {
+                namespace "urn:device-ext";
+                prefix ext;
+                import device { prefix dev; }
+
+                augment "/dev:system" {
+                    leaf location { type string; }
+                }
+            }
+        "#,
+            "device-ext.yang",
+        )
+        .unwrap();
+    let mut modules = vec![device, ext];
+    parser.apply_augments(&mut modules).unwrap();
+
+    let generated = CodeGenerator::new(restful_config())
+        .generate(&modules[0])
+        .unwrap();
+    let content = &generated.files[0].content;
+    let system = handle_impl(content, "SystemResource");
+
+    // The member is the augmenting module's, not prefixed a second time
+    assert!(system.contains("decode(\"device:hostname\", &response)"));
+    assert!(system.contains("decode(\"device-ext:location\", &response)"));
+    assert!(system.contains("encode(\"device-ext:location\", &value)"));
+    assert!(!system.contains("device:device-ext:location"));
+}
+
+#[test]
+fn test_handles_are_must_use() {
+    let content = generate(restful_config());
+
+    assert!(content.contains(
+        "#[must_use = \"a resource handle does nothing until one of its methods is called\"]\n        pub struct SystemResource<'a, T: HttpTransport> {"
+    ));
+    assert!(content.contains("#[must_use]\n            pub fn path(&self) -> &str {"));
+}
+
+#[test]
+fn test_handle_methods_have_full_variants() {
+    let content = generate(restful_config());
+    let system = handle_impl(&content, "SystemResource");
+
+    assert!(system
+        .contains("pub async fn get_full(&self) -> Result<ResponseEnvelope<System>, RpcError> {"));
+    assert!(system.contains("self.get_full().await.map(ResponseEnvelope::into_body)"));
+    assert!(system.contains(
+        "pub async fn put_full(&self, data: &System) -> Result<ResponseEnvelope<()>, RpcError> {"
+    ));
+    assert!(system.contains("self.put_full(data).await.map(ResponseEnvelope::into_body)"));
+    assert!(system
+        .contains("pub async fn delete_full(&self) -> Result<ResponseEnvelope<()>, RpcError> {"));
+    assert!(system.contains("Ok(ResponseEnvelope::new(response, ()))"));
+
+    // Probing the resource has no `_full` variant
+    assert!(!system.contains("exists_full"));
+    assert!(!system.contains("options_full"));
+
+    let item = handle_impl(&content, "InterfacesItemResource");
+    assert!(item.contains(
+        "pub async fn get_full(&self) -> Result<ResponseEnvelope<Interface>, RpcError> {"
+    ));
+    assert!(item.contains("Ok(ResponseEnvelope::new(response, value))"));
+}
+
+#[test]
+fn test_handles_read_operational_state_with_origin() {
+    let content = generate(restful_config());
+
+    let system = handle_impl(&content, "SystemResource");
+    assert!(system.contains(
+        "pub async fn get_with_origin(&self) -> Result<rustconf_runtime::WithOrigin<System>, RpcError> {"
+    ));
+    assert!(system.contains("let path = rustconf_runtime::origin::with_origin_path(&self.path);"));
+    assert!(
+        system.contains("let origins = rustconf_runtime::OriginTable::from_json(&response.body)?;")
+    );
+
+    let interfaces = handle_impl(&content, "InterfacesResource");
+    assert!(interfaces.contains(
+        "pub async fn get_with_origin(&self) -> Result<rustconf_runtime::WithOrigin<Vec<Interface>>, RpcError> {"
+    ));
+    let item = handle_impl(&content, "InterfacesItemResource");
+    assert!(item.contains(
+        "pub async fn get_with_origin(&self) -> Result<rustconf_runtime::WithOrigin<Interface>, RpcError> {"
+    ));
+}
+
+#[test]
+fn test_list_handles_address_entries_by_key() {
+    let content = generate(restful_config());
+    let interfaces = handle_impl(&content, "InterfacesResource");
+
+    assert!(interfaces.contains("pub async fn get(&self) -> Result<Vec<Interface>, RpcError> {"));
+    assert!(interfaces
+        .contains("pub async fn post(&self, entry: &Interface) -> Result<(), RpcError> {"));
+    assert!(interfaces.contains("let body = encode(\"device:interfaces\", &[entry])?;"));
+    assert!(interfaces.contains("HttpMethod::POST, consts::DATA_PATH,"));
+    assert!(interfaces.contains(
+        "pub fn item(&self, name: impl Into<String>) -> InterfacesItemResource<'a, T> {"
+    ));
+    assert!(interfaces.contains("path: format!(\"{}={}\", self.path, percent_encode(&name)),"));
+
+    let item = handle_impl(&content, "InterfacesItemResource");
+    assert!(item.contains("pub async fn get(&self) -> Result<Interface, RpcError> {"));
+    assert!(item.contains("pub async fn put(&self, entry: &Interface) -> Result<(), RpcError> {"));
+    assert!(item.contains("pub async fn delete(&self) -> Result<(), RpcError> {"));
+
+    // Multiple keys are separated by commas (RFC 8040, section 3.5.3)
+    let route = handle_impl(&content, "RouteResource");
+    assert!(route.contains(
+        "pub fn item(&self, destination: impl Into<String>, prefix_length: u8) -> RouteItemResource<'a, T> {"
+    ));
+    assert!(route.contains(
+        "path: format!(\"{}={},{}\", self.path, percent_encode(&destination), percent_encode(&prefix_length.to_string())),"
+    ));
+}
+
+#[test]
+fn test_list_handles_replace_entries_in_batches() {
+    let content = generate(restful_config());
+
+    let route = handle_impl(&content, "RouteResource");
+    assert!(route.contains(
+        "pub fn replace_chunked(&self, entries: &'a [Route]) -> rustconf_runtime::ChunkedWrite<'a, Route> {"
+    ));
+    assert!(route.contains(
+        "rustconf_runtime::ChunkedWrite::new(self.client, self.path.clone(), \"device:route\", &[\"destination\", \"prefix-length\"], entries)"
+    ));
+
+    // Operational state lists cannot be written
+    let module = YangParser::new()
+        .parse_string(
+            r#"
+            module device {
+                namespace "urn:device";
+                prefix dev;
+
+                list neighbor {
+                    key "address";
+                    config false;
+                    leaf address { type string; }
+                }
+            }
+            "#,
+            "device.yang",
+        )
+        .unwrap();
+    let generated = CodeGenerator::new(restful_config())
+        .generate(&module)
+        .unwrap();
+    assert!(!generated.files[0].content.contains("replace_chunked"));
+}
+
+#[test]
+fn test_resource_paths_follow_namespace_prefixes() {
+    let mut config = restful_config();
+    config.enable_namespace_prefixes = true;
+    let content = generate(config);
+
+    assert!(content.contains(
+        "InterfacesResource { client: self, path: \"/restconf/data/device:interfaces\".to_string() }"
+    ));
+}
+
+#[test]
+fn test_resources_modular_output() {
+    let mut config = restful_config();
+    config.modular_output = true;
+    let generated = CodeGenerator::new(config).generate(&module()).unwrap();
+
+    let operations = generated
+        .files
+        .iter()
+        .find(|file| file.path.ends_with("operations.rs"))
+        .unwrap();
+    syn::parse_file(&operations.content).unwrap();
+    assert!(operations
+        .content
+        .contains("pub trait DeviceResources<T: HttpTransport> {"));
+}
+
+/// The `impl` block of a handle type.
+fn handle_impl<'a>(content: &'a str, handle: &str) -> &'a str {
+    let start = content
+        .find(&format!("impl<T: HttpTransport> {}<'_, T> {{", handle))
+        .or_else(|| content.find(&format!("impl<'a, T: HttpTransport> {}<'a, T> {{", handle)))
+        .unwrap_or_else(|| panic!("no impl for {}", handle));
+    let end = content[start..]
+        .find("\n        }\n")
+        .map_or(content.len(), |end| start + end);
+    &content[start..end]
+}
+
+const SHORTCUTS: &str = r#"
+    module shortcuts {
+        namespace "urn:shortcuts";
+        prefix sc;
+
+        typedef flag { type empty; }
+
+        leaf hostname {
+            description "Host name of the device.";
+            type string;
+        }
+        leaf in-octets {
+            config false;
+            type uint64;
+        }
+        leaf maintenance { type flag; }
+    }
+"#;
+
+fn generate_shortcuts(config: GeneratorConfig) -> String {
+    let module = YangParser::new()
+        .parse_string(SHORTCUTS, "shortcuts.yang")
+        .unwrap();
+    let generated = CodeGenerator::new(config).generate(&module).unwrap();
+    let content = generated.files[0].content.clone();
+    syn::parse_file(&content).unwrap_or_else(|e| panic!("{}\n{}", e, content));
+    content
+}
+
+#[test]
+fn test_top_level_leaf_handles() {
+    let content = generate_shortcuts(restful_config());
+
+    assert!(content.contains(
+        "/// Host name of the device.\n            fn hostname(&self) -> HostnameResource<'_, T>;"
+    ));
+    assert!(content.contains(
+        "HostnameResource { client: self, path: \"/restconf/data/hostname\".to_string() }"
+    ));
+
+    let hostname = handle_impl(&content, "HostnameResource");
+    assert!(hostname.contains("pub async fn get(&self) -> Result<String, RpcError> {"));
+    assert!(hostname.contains("decode(\"shortcuts:hostname\", &response)"));
+    assert!(hostname.contains("pub async fn put(&self, value: String) -> Result<(), RpcError> {"));
+    assert!(hostname.contains("encode(\"shortcuts:hostname\", &value)?"));
+    assert!(hostname.contains("pub async fn delete(&self)"));
+    assert!(!hostname.contains("pub async fn patch("));
+
+    // State leaves are read-only
+    let in_octets = handle_impl(&content, "InOctetsResource");
+    assert!(in_octets.contains("pub async fn get(&self) -> Result<u64, RpcError> {"));
+    assert!(!in_octets.contains("pub async fn put("));
+}
+
+#[test]
+fn test_top_level_leaf_handles_use_rfc7951_scalars() {
+    let mut config = restful_config();
+    config.int64_encoding(crate::generator::Int64Encoding::String);
+    let content = generate_shortcuts(config);
+
+    // 64-bit integers go through the configured string encoding
+    assert!(content.contains(
+        "struct InOctetsValue(#[serde(with = \"rustconf_runtime::int64::string\")] u64);"
+    ));
+    let in_octets = handle_impl(&content, "InOctetsResource");
+    assert!(in_octets
+        .contains("let value: InOctetsValue = decode(\"shortcuts:in-octets\", &response)?;"));
+    assert!(in_octets.contains("Ok(ResponseEnvelope::new(response, value.0))"));
+
+    // `empty` leaves are `[null]` and take no value
+    let maintenance = handle_impl(&content, "MaintenanceResource");
+    assert!(maintenance.contains("pub async fn get(&self) -> Result<(), RpcError> {"));
+    assert!(maintenance.contains("decode::<[(); 1]>(\"shortcuts:maintenance\", &response)"));
+    assert!(maintenance.contains("pub async fn put(&self) -> Result<(), RpcError> {"));
+    assert!(maintenance.contains("encode(\"shortcuts:maintenance\", &[()])?"));
+}
+
+const SYSTEM: &str = r#"
+    module system {
+        namespace "urn:system";
+        prefix sys;
+
+        container system {
+            leaf hostname { type string; }
+            leaf mtu { type uint16; }
+            leaf type { type string; }
+            leaf counter { type int64; }
+            leaf maintenance { type empty; }
+            leaf uptime { config false; type uint32; }
+            container ntp {
+                leaf server { type string; }
+            }
+        }
+
+        container state {
+            config false;
+            leaf boot-count { type uint32; }
+        }
+    }
+"#;
+
+fn generate_system(config: GeneratorConfig) -> String {
+    let module = YangParser::new()
+        .parse_string(SYSTEM, "system.yang")
+        .unwrap();
+    let generated = CodeGenerator::new(config).generate(&module).unwrap();
+    let content = generated.files[0].content.clone();
+    syn::parse_file(&content).unwrap_or_else(|e| panic!("{}\n{}", e, content));
+    content
+}
+
+#[test]
+fn test_container_handles_get_and_set_single_leaves() {
+    let content = generate_system(restful_config());
+    let system = handle_impl(&content, "SystemResource");
+
+    assert!(system.contains("pub async fn get_mtu(&self) -> Result<u16, RpcError> {"));
+    assert!(system.contains("pub async fn set_mtu(&self, value: u16) -> Result<(), RpcError> {"));
+    assert!(system.contains(
+        "pub async fn set_mtu_full(&self, value: u16) -> Result<ResponseEnvelope<()>, RpcError> {"
+    ));
+    assert!(system.contains("let path = format!(\"{}/mtu\", self.path);"));
+    assert!(system.contains("decode(\"system:mtu\", &response)"));
+    assert!(system.contains("let body = encode(\"system:mtu\", &value)?;"));
+    assert!(system.contains("raw_request(HttpMethod::PUT, &path, Some(body.into()))"));
+    assert!(system.contains("pub async fn set_type(&self, value: String)"));
+
+    // Only scalar configuration leaves get accessors
+    assert!(!system.contains("fn get_maintenance("));
+    assert!(!system.contains("fn get_uptime("));
+    assert!(!system.contains("fn get_ntp("));
+    assert!(!system.contains("fn get_server("));
+    let state = handle_impl(&content, "StateResource");
+    assert!(!state.contains("fn get_boot_count("));
+}
+
+#[test]
+fn test_container_leaf_accessors_use_rfc7951_scalars() {
+    let mut config = restful_config();
+    config.int64_encoding(crate::generator::Int64Encoding::String);
+    let content = generate_system(config);
+
+    assert!(content.contains(
+        "struct SystemCounterValue(#[serde(with = \"rustconf_runtime::int64::string\")] i64);"
+    ));
+    let system = handle_impl(&content, "SystemResource");
+    assert!(
+        system.contains("let value: SystemCounterValue = decode(\"system:counter\", &response)?;")
+    );
+    assert!(system.contains("let body = encode(\"system:counter\", &SystemCounterValue(value))?;"));
+}
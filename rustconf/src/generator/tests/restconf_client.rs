@@ -26,17 +26,27 @@ fn test_restconf_client_not_generated_when_disabled() {
         namespace: "urn:test:module".to_string(),
         prefix: "test".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-operation".to_string(),
             description: Some("Test RPC operation".to_string()),
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     // Generate code
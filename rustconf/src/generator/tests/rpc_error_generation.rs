@@ -1,5 +1,6 @@
 //! Tests for RpcError generation (Task 1)
 
+use super::generate;
 use crate::generator::{operations::OperationsGenerator, GeneratorConfig};
 
 #[test]
@@ -90,3 +91,39 @@ fn test_rpc_error_respects_derive_config() {
     assert!(!error_code.contains("#[derive(Debug)]"));
     assert!(!error_code.contains("#[derive(Clone)]"));
 }
+
+#[test]
+fn test_shared_helpers_import_rpc_error_and_percent_encode() {
+    let config = GeneratorConfig {
+        shared_helpers: true,
+        ..Default::default()
+    };
+    let code = generate(config);
+
+    assert!(code.contains("pub use rustconf_runtime::RpcError;"));
+    assert!(!code.contains("pub enum RpcError"));
+    assert!(code.contains("use rustconf_runtime::percent_encode;"));
+    assert!(!code.contains("fn percent_encode"));
+}
+
+#[test]
+fn test_shared_helpers_with_restful_rpcs() {
+    let mut config = GeneratorConfig {
+        shared_helpers: true,
+        ..Default::default()
+    };
+    config.enable_restful_rpcs();
+    let code = generate(config);
+
+    assert!(!code.contains("pub use rustconf_runtime::RpcError;"));
+    assert!(code.contains("use rustconf_runtime::percent_encode;"));
+    assert!(!code.contains("fn percent_encode"));
+}
+
+#[test]
+fn test_helpers_are_generated_by_default() {
+    let code = generate(GeneratorConfig::default());
+
+    assert!(code.contains("pub enum RpcError"));
+    assert!(code.contains("fn percent_encode"));
+}
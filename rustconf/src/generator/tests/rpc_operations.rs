@@ -1,7 +1,7 @@
 //! Unit tests for RPC generation (Task 10.1)
 
 use crate::generator::{CodeGenerator, GeneratorConfig};
-use crate::parser::{DataNode, Leaf, Rpc, TypeSpec, YangModule};
+use crate::parser::{DataNode, Leaf, Rpc, Status, TypeSpec, YangModule};
 
 #[test]
 fn test_generate_rpc_with_no_input_or_output() {
@@ -13,17 +13,27 @@ fn test_generate_rpc_with_no_input_or_output() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "reset-system".to_string(),
             description: Some("Reset the system to default state".to_string()),
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -59,10 +69,15 @@ fn test_generate_rpc_with_input_only() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "set-config".to_string(),
             description: Some("Set configuration parameters".to_string()),
@@ -76,7 +91,13 @@ fn test_generate_rpc_with_input_only() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
                 DataNode::Leaf(Leaf {
                     name: "value".to_string(),
@@ -87,12 +108,23 @@ fn test_generate_rpc_with_input_only() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
             ]),
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        features: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -109,7 +141,7 @@ fn test_generate_rpc_with_input_only() {
 
     // Check function signature with input parameter
     assert!(
-        content.contains("pub async fn set_config(input: SetConfigInput) -> Result<(), RpcError>")
+        content.contains("pub async fn set_config(input: &SetConfigInput) -> Result<(), RpcError>")
     );
 }
 
@@ -123,10 +155,16 @@ fn test_generate_rpc_with_output_only() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "get-statistics".to_string(),
             description: Some("Retrieve system statistics".to_string()),
@@ -138,7 +176,13 @@ fn test_generate_rpc_with_output_only() {
                     type_spec: TypeSpec::Uint64 { range: None },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
                 DataNode::Leaf(Leaf {
                     name: "cpu-usage".to_string(),
@@ -146,11 +190,21 @@ fn test_generate_rpc_with_output_only() {
                     type_spec: TypeSpec::Uint8 { range: None },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
             ]),
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -181,10 +235,15 @@ fn test_generate_rpc_with_input_and_output() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "calculate-sum".to_string(),
             description: Some("Calculate the sum of two numbers".to_string()),
@@ -195,7 +254,13 @@ fn test_generate_rpc_with_input_and_output() {
                     type_spec: TypeSpec::Int32 { range: None },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
                 DataNode::Leaf(Leaf {
                     name: "b".to_string(),
@@ -203,7 +268,13 @@ fn test_generate_rpc_with_input_and_output() {
                     type_spec: TypeSpec::Int32 { range: None },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
             ]),
             output: Some(vec![DataNode::Leaf(Leaf {
@@ -212,10 +283,21 @@ fn test_generate_rpc_with_input_and_output() {
                 type_spec: TypeSpec::Int32 { range: None },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: false,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })]),
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        features: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -234,7 +316,7 @@ fn test_generate_rpc_with_input_and_output() {
     assert!(content.contains("/// Sum of a and b"));
 
     // Check function signature
-    assert!(content.contains("pub async fn calculate_sum(input: CalculateSumInput) -> Result<CalculateSumOutput, RpcError>"));
+    assert!(content.contains("pub async fn calculate_sum(input: &CalculateSumInput) -> Result<CalculateSumOutput, RpcError>"));
 
     // Check rustdoc
     assert!(content.contains("/// Calculate the sum of two numbers"));
@@ -250,10 +332,15 @@ fn test_generate_multiple_rpcs() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![
             Rpc {
                 name: "start-service".to_string(),
@@ -267,9 +354,17 @@ fn test_generate_multiple_rpcs() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 })]),
                 output: None,
+                timeout: None,
+                unknown_statements: vec![],
             },
             Rpc {
                 name: "stop-service".to_string(),
@@ -283,12 +378,23 @@ fn test_generate_multiple_rpcs() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 })]),
                 output: None,
+                timeout: None,
+                unknown_statements: vec![],
             },
         ],
         notifications: vec![],
+        features: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -297,12 +403,12 @@ fn test_generate_multiple_rpcs() {
     // Check both RPCs are generated
     assert!(content.contains("pub struct StartServiceInput {"));
     assert!(content
-        .contains("pub async fn start_service(input: StartServiceInput) -> Result<(), RpcError>"));
+        .contains("pub async fn start_service(input: &StartServiceInput) -> Result<(), RpcError>"));
     assert!(content.contains("/// Start a service"));
 
     assert!(content.contains("pub struct StopServiceInput {"));
     assert!(content
-        .contains("pub async fn stop_service(input: StopServiceInput) -> Result<(), RpcError>"));
+        .contains("pub async fn stop_service(input: &StopServiceInput) -> Result<(), RpcError>"));
     assert!(content.contains("/// Stop a service"));
 }
 
@@ -316,17 +422,27 @@ fn test_rpc_error_type_generation() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-rpc".to_string(),
             description: None,
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -359,12 +475,20 @@ fn test_rpc_not_generated_when_no_rpcs() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -385,17 +509,27 @@ fn test_rpc_with_empty_input_list() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "ping".to_string(),
             description: Some("Ping the system".to_string()),
             input: Some(vec![]),
             output: Some(vec![]),
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -420,17 +554,27 @@ fn test_http_method_generated_when_restful_rpcs_enabled() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-rpc".to_string(),
             description: None,
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -463,17 +607,27 @@ fn test_http_method_not_generated_when_restful_rpcs_disabled() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-rpc".to_string(),
             description: None,
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -495,17 +649,27 @@ fn test_http_request_generated_when_restful_rpcs_enabled() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-rpc".to_string(),
             description: None,
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -538,17 +702,27 @@ fn test_http_request_not_generated_when_restful_rpcs_disabled() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-rpc".to_string(),
             description: None,
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -570,17 +744,27 @@ fn test_http_response_generated_when_restful_rpcs_enabled() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-rpc".to_string(),
             description: None,
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -613,17 +797,27 @@ fn test_http_response_not_generated_when_restful_rpcs_disabled() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-rpc".to_string(),
             description: None,
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -645,17 +839,27 @@ fn test_http_transport_generated_when_restful_rpcs_enabled() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-rpc".to_string(),
             description: None,
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -689,17 +893,27 @@ fn test_http_transport_custom_example_includes_complete_implementation() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-rpc".to_string(),
             description: None,
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -729,17 +943,27 @@ fn test_http_transport_not_generated_when_restful_rpcs_disabled() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-rpc".to_string(),
             description: None,
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -762,17 +986,27 @@ fn test_request_interceptor_generated_when_restful_rpcs_enabled() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-rpc".to_string(),
             description: None,
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -805,17 +1039,27 @@ fn test_request_interceptor_not_generated_when_restful_rpcs_disabled() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-rpc".to_string(),
             description: None,
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -839,10 +1083,16 @@ fn test_stub_rpc_function_when_restful_rpcs_disabled() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-operation".to_string(),
             description: Some("Test RPC operation".to_string()),
@@ -855,7 +1105,13 @@ fn test_stub_rpc_function_when_restful_rpcs_disabled() {
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })]),
             output: Some(vec![DataNode::Leaf(Leaf {
                 name: "result".to_string(),
@@ -866,10 +1122,20 @@ fn test_stub_rpc_function_when_restful_rpcs_disabled() {
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })]),
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -877,7 +1143,7 @@ fn test_stub_rpc_function_when_restful_rpcs_disabled() {
 
     // Check that stub function is generated
     assert!(
-        content.contains("pub async fn test_operation(input: TestOperationInput) -> Result<TestOperationOutput, RpcError>"),
+        content.contains("pub async fn test_operation(input: &TestOperationInput) -> Result<TestOperationOutput, RpcError>"),
         "Stub function signature should be generated"
     );
 
@@ -906,10 +1172,16 @@ fn test_restful_rpc_function_when_restful_rpcs_enabled() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-operation".to_string(),
             description: Some("Test RPC operation".to_string()),
@@ -922,7 +1194,13 @@ fn test_restful_rpc_function_when_restful_rpcs_enabled() {
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })]),
             output: Some(vec![DataNode::Leaf(Leaf {
                 name: "result".to_string(),
@@ -933,10 +1211,20 @@ fn test_restful_rpc_function_when_restful_rpcs_enabled() {
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })]),
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -944,7 +1232,7 @@ fn test_restful_rpc_function_when_restful_rpcs_enabled() {
 
     // Check that RESTful function is generated with client parameter
     assert!(
-        content.contains("pub async fn test_operation<T: HttpTransport>(client: &RestconfClient<T>, input: TestOperationInput) -> Result<TestOperationOutput, RpcError>"),
+        content.contains("pub async fn test_operation<T: HttpTransport>(client: &RestconfClient<T>, input: &TestOperationInput) -> Result<TestOperationOutput, RpcError>"),
         "RESTful function signature should include client parameter and generic type"
     );
 
@@ -973,17 +1261,27 @@ fn test_restful_rpc_function_without_input() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "ping".to_string(),
             description: Some("Ping operation".to_string()),
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -1007,17 +1305,27 @@ fn test_stub_rpc_function_without_input() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "ping".to_string(),
             description: Some("Ping operation".to_string()),
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -1035,3 +1343,298 @@ fn test_stub_rpc_function_without_input() {
         "Stub function should return NotImplemented error"
     );
 }
+
+#[test]
+fn test_restful_rpc_output_implements_try_from_response() {
+    let mut config = GeneratorConfig::default();
+    config.enable_restful_rpcs();
+    let generator = CodeGenerator::new(config);
+
+    let module = YangModule {
+        name: "test".to_string(),
+        namespace: "urn:test".to_string(),
+        prefix: "t".to_string(),
+        yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
+        imports: vec![],
+        features: vec![],
+        typedefs: vec![],
+        groupings: vec![],
+        data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
+        rpcs: vec![Rpc {
+            name: "get-status".to_string(),
+            description: None,
+            input: None,
+            output: Some(vec![DataNode::Leaf(Leaf {
+                name: "uptime".to_string(),
+                description: None,
+                type_spec: TypeSpec::Uint32 { range: None },
+                mandatory: false,
+                default: None,
+                units: None,
+                config: false,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
+            })]),
+            timeout: None,
+            unknown_statements: vec![],
+        }],
+        notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
+    };
+
+    let generated = generator.generate(&module).unwrap();
+    let content = &generated.files[0].content;
+
+    assert!(content.contains("impl TryFrom<&HttpResponse> for GetStatusOutput {"));
+    assert!(content.contains("type Error = RpcError;"));
+    assert!(content.contains("fn try_from(response: &HttpResponse) -> Result<Self, Self::Error> {"));
+
+    // The generated function delegates to the TryFrom implementation
+    assert!(content.contains("GetStatusOutput::try_from(&response)"));
+}
+
+#[test]
+fn test_try_from_response_not_generated_without_restful_rpcs() {
+    let config = GeneratorConfig::default();
+    let generator = CodeGenerator::new(config);
+
+    let module = YangModule {
+        name: "test".to_string(),
+        namespace: "urn:test".to_string(),
+        prefix: "t".to_string(),
+        yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
+        imports: vec![],
+        features: vec![],
+        typedefs: vec![],
+        groupings: vec![],
+        data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
+        rpcs: vec![Rpc {
+            name: "get-status".to_string(),
+            description: None,
+            input: None,
+            output: Some(vec![DataNode::Leaf(Leaf {
+                name: "uptime".to_string(),
+                description: None,
+                type_spec: TypeSpec::Uint32 { range: None },
+                mandatory: false,
+                default: None,
+                units: None,
+                config: false,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
+            })]),
+            timeout: None,
+            unknown_statements: vec![],
+        }],
+        notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
+    };
+
+    let generated = generator.generate(&module).unwrap();
+    let content = &generated.files[0].content;
+
+    // HttpResponse is only available when the runtime types are imported
+    assert!(!content.contains("TryFrom<&HttpResponse>"));
+}
+
+/// Module with an RPC taking input and returning output.
+fn envelope_test_module() -> YangModule {
+    let leaf = |name: &str| {
+        DataNode::Leaf(Leaf {
+            name: name.to_string(),
+            description: None,
+            type_spec: TypeSpec::Uint32 { range: None },
+            mandatory: false,
+            default: None,
+            units: None,
+            config: true,
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
+        })
+    };
+    YangModule {
+        name: "device".to_string(),
+        namespace: "urn:device".to_string(),
+        prefix: "dev".to_string(),
+        yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
+        imports: vec![],
+        features: vec![],
+        typedefs: vec![],
+        groupings: vec![],
+        data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
+        rpcs: vec![Rpc {
+            name: "reboot".to_string(),
+            description: None,
+            input: Some(vec![leaf("delay")]),
+            output: Some(vec![leaf("uptime")]),
+            timeout: None,
+            unknown_statements: vec![],
+        }],
+        notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
+    }
+}
+
+#[test]
+fn test_restful_rpc_bodies_use_module_envelopes() {
+    let mut config = GeneratorConfig::default();
+    config.enable_restful_rpcs();
+    let generator = CodeGenerator::new(config);
+
+    let generated = generator.generate(&envelope_test_module()).unwrap();
+    let content = &generated.files[0].content;
+
+    assert!(content.contains("rustconf_runtime::wrap_envelope(\"device:input\", input)"));
+    assert!(content.contains(
+        "200..=299 => rustconf_runtime::unwrap_envelope(\"device:output\", &response.body)"
+    ));
+    assert!(!content.contains("serde_json::to_vec(input)"));
+}
+
+#[test]
+fn test_bare_rpc_bodies_skip_envelopes() {
+    let mut config = GeneratorConfig::default();
+    config.enable_restful_rpcs().bare_rpc_bodies();
+    let generator = CodeGenerator::new(config);
+
+    let generated = generator.generate(&envelope_test_module()).unwrap();
+    let content = &generated.files[0].content;
+
+    assert!(content.contains("serde_json::to_vec(input)"));
+    assert!(content.contains("body: Some(body.into()),"));
+    assert!(content.contains("200..=299 => serde_json::from_slice(&response.body)"));
+    assert!(!content.contains("_envelope("));
+}
+
+#[test]
+fn test_rpc_timeout_extension_sets_operation_timeout() {
+    let mut module = envelope_test_module();
+    module.rpcs[0].timeout = Some(600);
+    let mut config = GeneratorConfig::default();
+    config.enable_restful_rpcs();
+    let generator = CodeGenerator::new(config);
+
+    let generated = generator.generate(&module).unwrap();
+    let content = &generated.files[0].content;
+
+    assert!(
+        content.contains("rustconf_runtime::OperationTimeout(std::time::Duration::from_secs(600))")
+    );
+    assert!(content.contains("/// The request is given 600 seconds"));
+    assert!(!content.contains("context: Default::default()"));
+}
+
+#[test]
+fn test_restful_rpc_full_variant_returns_response_envelope() {
+    let mut config = GeneratorConfig::default();
+    config.enable_restful_rpcs();
+    let generator = CodeGenerator::new(config);
+
+    let generated = generator.generate(&envelope_test_module()).unwrap();
+    let content = &generated.files[0].content;
+    syn::parse_file(content).unwrap_or_else(|e| panic!("{}\n{}", e, content));
+
+    assert!(content.contains(
+        "pub async fn reboot_full<T: HttpTransport>(client: &RestconfClient<T>, input: &RebootInput) -> Result<ResponseEnvelope<RebootOutput>, RpcError> {"
+    ));
+    assert!(content.contains("let output = RebootOutput::try_from(&response)?;"));
+    assert!(content.contains("Ok(ResponseEnvelope::new(response, output))"));
+
+    // The plain function keeps its signature and drops the headers
+    assert!(content.contains(
+        "pub async fn reboot<T: HttpTransport>(client: &RestconfClient<T>, input: &RebootInput) -> Result<RebootOutput, RpcError> {"
+    ));
+    assert!(content.contains("reboot_full(client, input).await.map(ResponseEnvelope::into_body)"));
+}
+
+fn firmware_module() -> YangModule {
+    crate::parser::YangParser::new()
+        .parse_string(
+            r#"
+            module firmware {
+                namespace "urn:firmware";
+                prefix fw;
+
+                typedef image-data {
+                    type binary;
+                }
+
+                rpc install {
+                    input {
+                        leaf version { type string; }
+                        leaf image { type image-data; }
+                    }
+                    output {
+                        leaf status { type string; }
+                    }
+                }
+
+                rpc compare {
+                    input {
+                        leaf left { type binary; }
+                        leaf right { type binary; }
+                    }
+                }
+            }
+            "#,
+            "firmware.yang",
+        )
+        .unwrap()
+}
+
+#[test]
+fn test_rpc_with_single_binary_leaf_gets_streaming_variant() {
+    let mut config = GeneratorConfig::default();
+    config.enable_restful_rpcs();
+    let generator = CodeGenerator::new(config);
+
+    let generated = generator.generate(&firmware_module()).unwrap();
+    let content = &generated.files[0].content;
+    syn::parse_file(content).unwrap_or_else(|e| panic!("{}\n{}", e, content));
+
+    assert!(content.contains(
+        "pub async fn install_streaming<T: HttpTransport>(client: &RestconfClient<T>, input: &InstallInput, image: rustconf_runtime::StreamingBody) -> Result<InstallOutput, RpcError> {"
+    ));
+    assert!(content.contains(
+        r#"rustconf_runtime::upload::binary_leaf_body(Some("firmware:input"), input, "image", image)?;"#
+    ));
+    assert!(content.contains("client.execute_streaming(request, body).await?;"));
+
+    // With two binary leaves there is no single one to stream
+    assert!(!content.contains("compare_streaming"));
+}
+
+#[test]
+fn test_streaming_variant_requires_restful_rpcs() {
+    let generator = CodeGenerator::new(GeneratorConfig::default());
+
+    let generated = generator.generate(&firmware_module()).unwrap();
+    assert!(!generated.files[0].content.contains("_streaming"));
+}
@@ -0,0 +1,173 @@
+//! Tests for rustdoc generated from YANG descriptions.
+
+use crate::generator::docs::doc_lines;
+use crate::generator::{CodeGenerator, GeneratorConfig};
+use crate::parser::{Container, DataNode, Leaf, Status, TypeSpec, YangModule, YangVersion};
+
+#[test]
+fn test_doc_lines_trim_continuation_lines() {
+    let lines =
+        doc_lines("The interface name.\n        Must be unique.\n\n        See the vendor guide.");
+    assert_eq!(
+        lines,
+        vec![
+            "The interface name.",
+            "Must be unique.",
+            "",
+            "See the vendor guide."
+        ]
+    );
+}
+
+#[test]
+fn test_doc_lines_wrap_urls_and_code() {
+    let lines = doc_lines(
+        "Defined in RFC 8343 (https://www.rfc-editor.org/rfc/rfc8343).\n\
+         Refers to /if:interfaces/if:interface and interface[name='eth0'].\n\
+         The <name> placeholder is replaced; see `already code` and [RFC 7950].",
+    );
+    assert_eq!(
+        lines,
+        vec![
+            "Defined in RFC 8343 (<https://www.rfc-editor.org/rfc/rfc8343>).",
+            "Refers to `/if:interfaces/if:interface` and `interface[name='eth0']`.",
+            "The `<name>` placeholder is replaced; see `already code` and \\[RFC 7950\\].",
+        ]
+    );
+}
+
+#[test]
+fn test_doc_lines_escape_markdown_structure() {
+    let lines = doc_lines("Counts:\n# of packets\n-----\nnot a */ comment end\r");
+    assert_eq!(
+        lines,
+        vec![
+            "Counts:",
+            "\\# of packets",
+            "\\-----",
+            "not a */ comment end"
+        ]
+    );
+}
+
+#[test]
+fn test_generated_docs_with_markdown_characters_parse() {
+    let description = "Traffic class.\n     Values are */ [0..7]; see https://example.com/qos.\n     Set via <class-name>.";
+    let module = YangModule {
+        name: "qos".to_string(),
+        namespace: "urn:example:qos".to_string(),
+        prefix: "qos".to_string(),
+        yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: Some(description.to_string()),
+        imports: vec![],
+        features: vec![],
+        typedefs: vec![],
+        groupings: vec![],
+        data_nodes: vec![DataNode::Container(Container {
+            name: "policy".to_string(),
+            description: Some(description.to_string()),
+            config: true,
+            mandatory: false,
+            children: vec![DataNode::Leaf(Leaf {
+                name: "class".to_string(),
+                description: Some(description.to_string()),
+                type_spec: TypeSpec::String {
+                    length: None,
+                    pattern: None,
+                },
+                mandatory: false,
+                default: None,
+                units: None,
+                config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
+            })],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
+        })],
+        augments: vec![],
+        deviations: vec![],
+        rpcs: vec![],
+        notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
+    };
+
+    for modular_output in [false, true] {
+        let config = GeneratorConfig {
+            modular_output,
+            ..Default::default()
+        };
+        let generated = CodeGenerator::new(config).generate(&module).unwrap();
+        for file in &generated.files {
+            syn::parse_file(&file.content).unwrap_or_else(|e| {
+                panic!(
+                    "{} does not parse: {}\n{}",
+                    file.path.display(),
+                    e,
+                    file.content
+                )
+            });
+            assert!(!file.content.contains("/**"), "{}", file.content);
+        }
+
+        let types = generated
+            .files
+            .iter()
+            .find(|f| f.content.contains("pub struct Policy"))
+            .unwrap();
+        assert!(types
+            .content
+            .contains("/// Values are */ \\[0..7\\]; see <https://example.com/qos>."));
+        assert!(types.content.contains("/// Set via `<class-name>`."));
+    }
+}
+
+#[test]
+fn test_nodes_without_description_are_documented() {
+    let module = crate::parser::YangParser::new()
+        .parse_string(
+            r#"
+            module qos {
+                namespace "urn:example:qos";
+                prefix qos;
+
+                typedef class-id { type uint8; }
+
+                container policy {
+                    leaf class { type class-id; }
+                    leaf-list marking { type string; }
+                    list rule {
+                        key "name";
+                        leaf name { type string; }
+                    }
+                }
+            }
+            "#,
+            "qos.yang",
+        )
+        .unwrap();
+
+    let generated = CodeGenerator::new(GeneratorConfig::default())
+        .generate(&module)
+        .unwrap();
+    let content = &generated.files[0].content;
+
+    for doc in [
+        "/// The `class-id` typedef.\npub type ClassId = u8;",
+        "/// The `policy` container.\n",
+        "/// The `class` leaf.\n",
+        "/// The `marking` leaf-list.\n",
+        "/// The `rule` list.\n",
+    ] {
+        assert!(content.contains(doc), "missing {:?} in\n{}", doc, content);
+    }
+}
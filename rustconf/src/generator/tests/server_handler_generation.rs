@@ -2,7 +2,7 @@
 
 use crate::generator::server_handlers::ServerHandlerGenerator;
 use crate::generator::GeneratorConfig;
-use crate::parser::{Container, DataNode, Leaf, List, Rpc, TypeSpec, YangModule};
+use crate::parser::{Container, DataNode, Leaf, List, Rpc, Status, TypeSpec, YangModule};
 
 #[test]
 fn test_generate_handler_trait_with_rpcs() {
@@ -14,10 +14,15 @@ fn test_generate_handler_trait_with_rpcs() {
         namespace: "http://example.com/device-management".to_string(),
         prefix: "dm".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![
             Rpc {
                 name: "restart-device".to_string(),
@@ -28,7 +33,13 @@ fn test_generate_handler_trait_with_rpcs() {
                     type_spec: TypeSpec::Uint32 { range: None },
                     mandatory: false,
                     default: Some("0".to_string()),
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 })]),
                 output: Some(vec![DataNode::Leaf(Leaf {
                     name: "success".to_string(),
@@ -36,8 +47,16 @@ fn test_generate_handler_trait_with_rpcs() {
                     type_spec: TypeSpec::Boolean,
                     mandatory: false,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 })]),
+                timeout: None,
+                unknown_statements: vec![],
             },
             Rpc {
                 name: "get-system-info".to_string(),
@@ -52,11 +71,22 @@ fn test_generate_handler_trait_with_rpcs() {
                     },
                     mandatory: false,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 })]),
+                timeout: None,
+                unknown_statements: vec![],
             },
         ],
         notifications: vec![],
+        features: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_handler_trait(&module);
@@ -89,7 +119,11 @@ fn test_generate_handler_trait_with_container() {
         namespace: "http://example.com/device-management".to_string(),
         prefix: "dm".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::Container(Container {
@@ -98,9 +132,18 @@ fn test_generate_handler_trait_with_container() {
             config: true,
             mandatory: false,
             children: vec![],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_handler_trait(&module);
@@ -127,7 +170,11 @@ fn test_generate_handler_trait_with_container_config_false() {
         namespace: "http://example.com/device-management".to_string(),
         prefix: "dm".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::Container(Container {
@@ -136,9 +183,18 @@ fn test_generate_handler_trait_with_container_config_false() {
             config: false,
             mandatory: false,
             children: vec![],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_handler_trait(&module);
@@ -163,7 +219,11 @@ fn test_generate_handler_trait_with_list() {
         namespace: "http://example.com/device-management".to_string(),
         prefix: "dm".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::List(List {
@@ -180,11 +240,28 @@ fn test_generate_handler_trait_with_list() {
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            min_elements: 0,
+            max_elements: None,
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_handler_trait(&module);
@@ -214,7 +291,11 @@ fn test_generate_handler_trait_with_list_config_false() {
         namespace: "http://example.com/device-management".to_string(),
         prefix: "dm".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::List(List {
@@ -228,11 +309,28 @@ fn test_generate_handler_trait_with_list_config_false() {
                 type_spec: TypeSpec::Uint32 { range: None },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: false,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            min_elements: 0,
+            max_elements: None,
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_handler_trait(&module);
@@ -259,7 +357,11 @@ fn test_generate_full_handler_trait() {
         namespace: "http://example.com/device-management".to_string(),
         prefix: "dm".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::Container(Container {
@@ -268,14 +370,25 @@ fn test_generate_full_handler_trait() {
             config: true,
             mandatory: false,
             children: vec![],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "restart-device".to_string(),
             description: Some("Restart the device".to_string()),
             input: Some(vec![]),
             output: Some(vec![]),
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_handler_trait(&module);
@@ -2,7 +2,7 @@
 
 use crate::generator::server_notifications::ServerNotificationGenerator;
 use crate::generator::GeneratorConfig;
-use crate::parser::{DataNode, Leaf, Notification, TypeSpec, YangModule};
+use crate::parser::{DataNode, Leaf, Notification, Status, TypeSpec, YangModule};
 
 #[test]
 fn test_generate_notification_publisher_empty_module() {
@@ -14,12 +14,20 @@ fn test_generate_notification_publisher_empty_module() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_notification_publisher(&module).unwrap();
@@ -36,16 +44,25 @@ fn test_generate_notification_publisher_struct() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![Notification {
             name: "system-restart".to_string(),
             description: Some("System is restarting".to_string()),
             data_nodes: vec![],
+            unknown_statements: vec![],
         }],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_notification_publisher(&module).unwrap();
@@ -78,16 +95,25 @@ fn test_generate_notification_subscriber_trait() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![Notification {
             name: "alarm".to_string(),
             description: Some("System alarm".to_string()),
             data_nodes: vec![],
+            unknown_statements: vec![],
         }],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_notification_publisher(&module).unwrap();
@@ -110,10 +136,16 @@ fn test_generate_publish_method_for_notification() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![Notification {
             name: "link-up".to_string(),
@@ -127,9 +159,18 @@ fn test_generate_publish_method_for_notification() {
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: false,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })],
+            unknown_statements: vec![],
         }],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_notification_publisher(&module).unwrap();
@@ -154,10 +195,16 @@ fn test_generate_notification_types() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![Notification {
             name: "interface-state-change".to_string(),
@@ -172,7 +219,13 @@ fn test_generate_notification_types() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
                 DataNode::Leaf(Leaf {
                     name: "new-state".to_string(),
@@ -183,10 +236,19 @@ fn test_generate_notification_types() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
             ],
+            unknown_statements: vec![],
         }],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_notification_publisher(&module).unwrap();
@@ -217,23 +279,33 @@ fn test_generate_multiple_publish_methods() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![
             Notification {
                 name: "link-up".to_string(),
                 description: Some("Link is up".to_string()),
                 data_nodes: vec![],
+                unknown_statements: vec![],
             },
             Notification {
                 name: "link-down".to_string(),
                 description: Some("Link is down".to_string()),
                 data_nodes: vec![],
+                unknown_statements: vec![],
             },
         ],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_notification_publisher(&module).unwrap();
@@ -256,16 +328,25 @@ fn test_notification_with_description() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![Notification {
             name: "alarm".to_string(),
             description: Some("Critical system alarm\nRequires immediate attention".to_string()),
             data_nodes: vec![],
+            unknown_statements: vec![],
         }],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_notification_publisher(&module).unwrap();
@@ -285,16 +366,25 @@ fn test_notification_without_description() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![Notification {
             name: "event".to_string(),
             description: None,
             data_nodes: vec![],
+            unknown_statements: vec![],
         }],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_notification_publisher(&module).unwrap();
@@ -313,16 +403,25 @@ fn test_subscriber_management_methods() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![Notification {
             name: "event".to_string(),
             description: None,
             data_nodes: vec![],
+            unknown_statements: vec![],
         }],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_notification_publisher(&module).unwrap();
@@ -344,16 +443,25 @@ fn test_concurrent_subscriber_support() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![Notification {
             name: "event".to_string(),
             description: None,
             data_nodes: vec![],
+            unknown_statements: vec![],
         }],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_notification_publisher(&module).unwrap();
@@ -378,10 +486,16 @@ fn test_notification_serialization_according_to_yang() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![Notification {
             name: "alarm".to_string(),
@@ -395,9 +509,18 @@ fn test_notification_serialization_according_to_yang() {
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: false,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })],
+            unknown_statements: vec![],
         }],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_notification_publisher(&module).unwrap();
@@ -417,16 +540,25 @@ fn test_delivery_failure_handling() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![Notification {
             name: "event".to_string(),
             description: None,
             data_nodes: vec![],
+            unknown_statements: vec![],
         }],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_notification_publisher(&module).unwrap();
@@ -454,16 +586,25 @@ fn test_transport_delivery_mechanism() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![Notification {
             name: "status-update".to_string(),
             description: Some("Status update notification".to_string()),
             data_nodes: vec![],
+            unknown_statements: vec![],
         }],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_notification_publisher(&module).unwrap();
@@ -485,16 +626,25 @@ fn test_concurrent_notification_delivery() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![Notification {
             name: "event".to_string(),
             description: None,
             data_nodes: vec![],
+            unknown_statements: vec![],
         }],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_notification_publisher(&module).unwrap();
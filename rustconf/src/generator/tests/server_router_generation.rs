@@ -2,7 +2,7 @@
 
 use crate::generator::server_router::RouterGenerator;
 use crate::generator::GeneratorConfig;
-use crate::parser::{Container, DataNode, Leaf, Rpc, TypeSpec, YangModule, YangVersion};
+use crate::parser::{Container, DataNode, Leaf, Rpc, Status, TypeSpec, YangModule, YangVersion};
 
 #[test]
 fn test_router_generation_basic() {
@@ -14,12 +14,20 @@ fn test_router_generation_basic() {
         namespace: "http://example.com/test".to_string(),
         prefix: "test".to_string(),
         yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_router(&module);
@@ -45,7 +53,13 @@ fn test_router_generation_with_rpc() {
             type_spec: TypeSpec::Uint32 { range: None },
             mandatory: false,
             default: None,
+            units: None,
             config: true,
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })]),
         output: Some(vec![DataNode::Leaf(Leaf {
             name: "success".to_string(),
@@ -53,8 +67,16 @@ fn test_router_generation_with_rpc() {
             type_spec: TypeSpec::Boolean,
             mandatory: true,
             default: None,
+            units: None,
             config: false,
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })]),
+        timeout: None,
+        unknown_statements: vec![],
     };
 
     let module = YangModule {
@@ -62,12 +84,20 @@ fn test_router_generation_with_rpc() {
         namespace: "http://example.com/device".to_string(),
         prefix: "dm".to_string(),
         yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![rpc],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_router(&module);
@@ -77,6 +107,18 @@ fn test_router_generation_with_rpc() {
     assert!(code.contains("route_rpc"));
     assert!(code.contains("\"restart-device\""));
     assert!(code.contains("restart_device"));
+
+    // RPC bodies are wrapped in module envelopes unless bare bodies are configured
+    assert!(code.contains("Self::deserialize_envelope(request, \"device-management:input\")"));
+    assert!(code.contains("BTreeMap::from([(\"device-management:output\", output)])"));
+
+    let mut bare = config.clone();
+    bare.bare_rpc_bodies();
+    let code = RouterGenerator::new(&bare)
+        .generate_router(&module)
+        .unwrap();
+    assert!(code.contains("let input: RestartDeviceInput = match Self::deserialize_body(request)"));
+    assert!(!code.contains("deserialize_envelope"));
 }
 
 #[test]
@@ -98,8 +140,19 @@ fn test_router_generation_with_container() {
             },
             mandatory: true,
             default: None,
+            units: None,
             config: true,
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        if_features: vec![],
+        when: None,
+        must: vec![],
+        unknown_statements: vec![],
+        status: Status::Current,
     };
 
     let module = YangModule {
@@ -107,12 +160,20 @@ fn test_router_generation_with_container() {
         namespace: "http://example.com/network".to_string(),
         prefix: "net".to_string(),
         yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::Container(container)],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_router(&module);
@@ -137,12 +198,20 @@ fn test_router_includes_percent_decoding() {
         namespace: "http://example.com/test".to_string(),
         prefix: "test".to_string(),
         yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_router(&module);
@@ -163,12 +232,20 @@ fn test_router_includes_serialization_helpers() {
         namespace: "http://example.com/test".to_string(),
         prefix: "test".to_string(),
         yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_router(&module);
@@ -190,12 +267,20 @@ fn test_router_includes_validation_in_deserialization() {
         namespace: "http://example.com/test".to_string(),
         prefix: "test".to_string(),
         yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_router(&module);
@@ -220,12 +305,20 @@ fn test_router_includes_validation_in_serialization() {
         namespace: "http://example.com/test".to_string(),
         prefix: "test".to_string(),
         yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_router(&module);
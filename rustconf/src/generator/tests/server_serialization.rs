@@ -5,7 +5,7 @@
 
 use crate::generator::server_router::RouterGenerator;
 use crate::generator::GeneratorConfig;
-use crate::parser::{Container, DataNode, Leaf, Rpc, TypeSpec, YangModule};
+use crate::parser::{Container, DataNode, Leaf, Rpc, Status, TypeSpec, YangModule};
 
 /// Feature: server-side-generation
 /// Property 9: Response Serialization Round-Trip
@@ -25,6 +25,9 @@ fn test_response_serialization_round_trip() {
         namespace: "http://example.com/test-serialization".to_string(),
         prefix: "ts".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
         typedefs: vec![],
         groupings: vec![],
@@ -43,7 +46,13 @@ fn test_response_serialization_round_trip() {
                     },
                     mandatory: false,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
                 DataNode::Leaf(Leaf {
                     name: "port".to_string(),
@@ -51,7 +60,13 @@ fn test_response_serialization_round_trip() {
                     type_spec: TypeSpec::Uint16 { range: None },
                     mandatory: false,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
                 DataNode::Leaf(Leaf {
                     name: "enabled".to_string(),
@@ -59,10 +74,23 @@ fn test_response_serialization_round_trip() {
                     type_spec: TypeSpec::Boolean,
                     mandatory: false,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
             ],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "get-status".to_string(),
             description: Some("Get status".to_string()),
@@ -77,7 +105,13 @@ fn test_response_serialization_round_trip() {
                     },
                     mandatory: false,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
                 DataNode::Leaf(Leaf {
                     name: "uptime".to_string(),
@@ -85,11 +119,22 @@ fn test_response_serialization_round_trip() {
                     type_spec: TypeSpec::Uint32 { range: None },
                     mandatory: false,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
             ]),
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        features: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     // Generate router code
@@ -133,10 +178,16 @@ fn test_content_type_header_presence() {
         namespace: "http://example.com/test-content-type".to_string(),
         prefix: "tct".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "simple-operation".to_string(),
             description: Some("Simple operation".to_string()),
@@ -150,10 +201,20 @@ fn test_content_type_header_presence() {
                 },
                 mandatory: false,
                 default: None,
+                units: None,
                 config: false,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })]),
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let router_code = generator
@@ -196,7 +257,11 @@ fn test_content_negotiation() {
         namespace: "http://example.com/test-negotiation".to_string(),
         prefix: "tn".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::Container(Container {
@@ -213,11 +278,26 @@ fn test_content_negotiation() {
                 },
                 mandatory: false,
                 default: None,
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let router_code = generator
@@ -266,10 +346,16 @@ fn test_serialize_response_signature() {
         namespace: "http://example.com/test-signature".to_string(),
         prefix: "ts".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-op".to_string(),
             description: Some("Test operation".to_string()),
@@ -283,10 +369,20 @@ fn test_serialize_response_signature() {
                 },
                 mandatory: false,
                 default: None,
+                units: None,
                 config: false,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })]),
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let router_code = generator
@@ -317,17 +413,27 @@ fn test_error_response_formatting() {
         namespace: "http://example.com/test-errors".to_string(),
         prefix: "te".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-op".to_string(),
             description: Some("Test operation".to_string()),
             input: None,
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let router_code = generator
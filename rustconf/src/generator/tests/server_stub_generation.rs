@@ -2,7 +2,9 @@
 
 use crate::generator::server_stubs::StubHandlerGenerator;
 use crate::generator::GeneratorConfig;
-use crate::parser::{Container, DataNode, Leaf, List, Rpc, TypeSpec, YangModule};
+use crate::parser::{
+    Container, DataNode, Leaf, LeafList, List, PatternConstraint, Rpc, Status, TypeSpec, YangModule,
+};
 
 #[test]
 fn test_generate_stub_impl_with_rpcs() {
@@ -14,10 +16,15 @@ fn test_generate_stub_impl_with_rpcs() {
         namespace: "http://example.com/device-management".to_string(),
         prefix: "dm".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![
             Rpc {
                 name: "restart-device".to_string(),
@@ -28,7 +35,13 @@ fn test_generate_stub_impl_with_rpcs() {
                     type_spec: TypeSpec::Uint32 { range: None },
                     mandatory: false,
                     default: Some("0".to_string()),
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 })]),
                 output: Some(vec![DataNode::Leaf(Leaf {
                     name: "success".to_string(),
@@ -36,8 +49,16 @@ fn test_generate_stub_impl_with_rpcs() {
                     type_spec: TypeSpec::Boolean,
                     mandatory: false,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 })]),
+                timeout: None,
+                unknown_statements: vec![],
             },
             Rpc {
                 name: "get-system-info".to_string(),
@@ -52,11 +73,22 @@ fn test_generate_stub_impl_with_rpcs() {
                     },
                     mandatory: false,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 })]),
+                timeout: None,
+                unknown_statements: vec![],
             },
         ],
         notifications: vec![],
+        features: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_stub_impl(&module);
@@ -96,7 +128,11 @@ fn test_generate_stub_impl_with_container() {
         namespace: "http://example.com/device-management".to_string(),
         prefix: "dm".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::Container(Container {
@@ -110,11 +146,26 @@ fn test_generate_stub_impl_with_container() {
                 type_spec: TypeSpec::Boolean,
                 mandatory: false,
                 default: None,
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_stub_impl(&module);
@@ -137,6 +188,97 @@ fn test_generate_stub_impl_with_container() {
     assert!(stub_code.contains("delete_interfaces()"));
 }
 
+#[test]
+fn test_stub_default_value_for_validated_and_collection_fields() {
+    let module = YangModule {
+        name: "device-management".to_string(),
+        namespace: "http://example.com/device-management".to_string(),
+        prefix: "dm".to_string(),
+        yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
+        imports: vec![],
+        typedefs: vec![],
+        groupings: vec![],
+        data_nodes: vec![DataNode::Container(Container {
+            name: "system".to_string(),
+            description: None,
+            config: true,
+            mandatory: false,
+            children: vec![
+                DataNode::Leaf(Leaf {
+                    name: "hostname".to_string(),
+                    description: None,
+                    type_spec: TypeSpec::String {
+                        length: None,
+                        pattern: Some(PatternConstraint {
+                            pattern: "[a-z]+".to_string(),
+                            additional: vec![],
+                        }),
+                    },
+                    mandatory: true,
+                    default: None,
+                    units: None,
+                    config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
+                }),
+                DataNode::LeafList(LeafList {
+                    name: "dns-servers".to_string(),
+                    description: None,
+                    type_spec: TypeSpec::String {
+                        length: None,
+                        pattern: None,
+                    },
+                    config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    min_elements: 0,
+                    max_elements: None,
+                    unknown_statements: vec![],
+                    status: Status::Current,
+                }),
+            ],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
+        })],
+        augments: vec![],
+        deviations: vec![],
+        rpcs: vec![],
+        notifications: vec![],
+        features: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
+    };
+
+    // Without validation the hostname is a plain String
+    let config = GeneratorConfig {
+        enable_validation: false,
+        ..Default::default()
+    };
+    let stub_code = StubHandlerGenerator::new(&config)
+        .generate_stub_impl(&module)
+        .unwrap();
+    assert!(stub_code.contains("hostname: String::new(),"));
+    assert!(stub_code.contains("dns_servers: Vec::new(),"));
+
+    // An empty string may not match the pattern of the validated type
+    let config = GeneratorConfig::default();
+    let stub_code = StubHandlerGenerator::new(&config)
+        .generate_stub_impl(&module)
+        .unwrap();
+    assert!(!stub_code.contains("Ok(System {"));
+    assert!(stub_code.contains("get_system has no default value in the stub"));
+}
+
 #[test]
 fn test_generate_stub_impl_with_list() {
     let config = GeneratorConfig::default();
@@ -147,7 +289,11 @@ fn test_generate_stub_impl_with_list() {
         namespace: "http://example.com/device-management".to_string(),
         prefix: "dm".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::List(List {
@@ -164,11 +310,28 @@ fn test_generate_stub_impl_with_list() {
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            min_elements: 0,
+            max_elements: None,
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_stub_impl(&module);
@@ -202,10 +365,15 @@ fn test_default_value_generation() {
         namespace: "http://example.com/test".to_string(),
         prefix: "test".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-types".to_string(),
             description: None,
@@ -217,7 +385,13 @@ fn test_default_value_generation() {
                     type_spec: TypeSpec::Int8 { range: None },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
                 DataNode::Leaf(Leaf {
                     name: "uint32-field".to_string(),
@@ -225,7 +399,13 @@ fn test_default_value_generation() {
                     type_spec: TypeSpec::Uint32 { range: None },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
                 DataNode::Leaf(Leaf {
                     name: "string-field".to_string(),
@@ -236,7 +416,13 @@ fn test_default_value_generation() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
                 DataNode::Leaf(Leaf {
                     name: "bool-field".to_string(),
@@ -244,7 +430,13 @@ fn test_default_value_generation() {
                     type_spec: TypeSpec::Boolean,
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
                 DataNode::Leaf(Leaf {
                     name: "optional-field".to_string(),
@@ -255,11 +447,22 @@ fn test_default_value_generation() {
                     },
                     mandatory: false,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
             ]),
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        features: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_stub_impl(&module);
@@ -285,7 +488,11 @@ fn test_stub_impl_with_config_false_container() {
         namespace: "http://example.com/device-management".to_string(),
         prefix: "dm".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::Container(Container {
@@ -294,9 +501,18 @@ fn test_stub_impl_with_config_false_container() {
             config: false,
             mandatory: false,
             children: vec![],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_stub_impl(&module);
@@ -321,7 +537,11 @@ fn test_stub_impl_with_config_false_list() {
         namespace: "http://example.com/device-management".to_string(),
         prefix: "dm".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::List(List {
@@ -335,11 +555,28 @@ fn test_stub_impl_with_config_false_list() {
                 type_spec: TypeSpec::Uint32 { range: None },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: false,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            min_elements: 0,
+            max_elements: None,
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate_stub_impl(&module);
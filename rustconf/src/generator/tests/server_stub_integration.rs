@@ -3,7 +3,7 @@
 use crate::generator::server_handlers::ServerHandlerGenerator;
 use crate::generator::server_stubs::StubHandlerGenerator;
 use crate::generator::GeneratorConfig;
-use crate::parser::{Container, DataNode, Leaf, Rpc, TypeSpec, YangModule};
+use crate::parser::{Container, DataNode, Leaf, Rpc, Status, TypeSpec, YangModule};
 
 #[test]
 fn test_stub_implements_handler_trait() {
@@ -16,6 +16,9 @@ fn test_stub_implements_handler_trait() {
         namespace: "http://example.com/device-management".to_string(),
         prefix: "dm".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
         typedefs: vec![],
         groupings: vec![],
@@ -30,9 +33,22 @@ fn test_stub_implements_handler_trait() {
                 type_spec: TypeSpec::Boolean,
                 mandatory: false,
                 default: None,
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "restart-device".to_string(),
             description: Some("Restart the device".to_string()),
@@ -42,7 +58,13 @@ fn test_stub_implements_handler_trait() {
                 type_spec: TypeSpec::Uint32 { range: None },
                 mandatory: false,
                 default: Some("0".to_string()),
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })]),
             output: Some(vec![DataNode::Leaf(Leaf {
                 name: "success".to_string(),
@@ -50,10 +72,21 @@ fn test_stub_implements_handler_trait() {
                 type_spec: TypeSpec::Boolean,
                 mandatory: false,
                 default: None,
+                units: None,
                 config: false,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })]),
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        features: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     // Generate handler trait
@@ -97,16 +130,24 @@ fn test_stub_call_logging_format() {
         namespace: "http://example.com/test".to_string(),
         prefix: "test".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![
             Rpc {
                 name: "no-input-no-output".to_string(),
                 description: None,
                 input: None,
                 output: None,
+                timeout: None,
+                unknown_statements: vec![],
             },
             Rpc {
                 name: "with-input".to_string(),
@@ -120,12 +161,22 @@ fn test_stub_call_logging_format() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 })]),
                 output: None,
+                timeout: None,
+                unknown_statements: vec![],
             },
         ],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = stub_gen.generate_stub_impl(&module);
@@ -149,10 +200,15 @@ fn test_stub_default_values_are_valid() {
         namespace: "http://example.com/test".to_string(),
         prefix: "test".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-defaults".to_string(),
             description: None,
@@ -164,7 +220,13 @@ fn test_stub_default_values_are_valid() {
                     type_spec: TypeSpec::Int32 { range: None },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
                 DataNode::Leaf(Leaf {
                     name: "string-field".to_string(),
@@ -175,7 +237,13 @@ fn test_stub_default_values_are_valid() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
                 DataNode::Leaf(Leaf {
                     name: "bool-field".to_string(),
@@ -183,11 +251,22 @@ fn test_stub_default_values_are_valid() {
                     type_spec: TypeSpec::Boolean,
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: false,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
             ]),
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        features: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = stub_gen.generate_stub_impl(&module);
@@ -1,8 +1,9 @@
 //! Tests for type generation (structs, enums, typedefs).
 
-use crate::generator::{CodeGenerator, GeneratorConfig};
+use crate::generator::{CodeGenerator, GeneratorConfig, NamespaceQualifier};
 use crate::parser::{
-    Case, Choice, Container, DataNode, Leaf, List, TypeDef, TypeSpec, YangModule, YangVersion,
+    Case, Choice, Container, DataNode, Leaf, LeafList, List, Range, RangeConstraint, Status,
+    TypeDef, TypeSpec, YangModule, YangVersion,
 };
 use std::path::PathBuf;
 
@@ -30,8 +31,19 @@ fn test_generate_simple_container() {
             },
             mandatory: true,
             default: None,
+            units: None,
             config: true,
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        if_features: vec![],
+        when: None,
+        must: vec![],
+        unknown_statements: vec![],
+        status: Status::Current,
     };
 
     let module = YangModule {
@@ -39,12 +51,20 @@ fn test_generate_simple_container() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::Container(container)],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate(&module);
@@ -83,7 +103,13 @@ fn test_generate_list() {
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             }),
             DataNode::Leaf(Leaf {
                 name: "email".to_string(),
@@ -94,9 +120,22 @@ fn test_generate_list() {
                 },
                 mandatory: false,
                 default: None,
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             }),
         ],
+        if_features: vec![],
+        when: None,
+        must: vec![],
+        min_elements: 0,
+        max_elements: None,
+        unknown_statements: vec![],
+        status: Status::Current,
     };
 
     let module = YangModule {
@@ -104,12 +143,20 @@ fn test_generate_list() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::List(list)],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate(&module);
@@ -124,6 +171,492 @@ fn test_generate_list() {
     assert!(content.contains("pub email: Option<String>"));
 }
 
+#[test]
+fn test_generate_list_key_structs() {
+    let leaf = |name: &str, type_spec: TypeSpec| {
+        DataNode::Leaf(Leaf {
+            name: name.to_string(),
+            description: None,
+            type_spec,
+            mandatory: false,
+            default: None,
+            units: None,
+            config: true,
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
+        })
+    };
+    let module = YangModule {
+        name: "test".to_string(),
+        namespace: "urn:test".to_string(),
+        prefix: "t".to_string(),
+        yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
+        imports: vec![],
+        features: vec![],
+        typedefs: vec![],
+        groupings: vec![],
+        data_nodes: vec![DataNode::List(List {
+            name: "sub-interface".to_string(),
+            description: None,
+            config: true,
+            keys: vec!["name".to_string(), "unit-id".to_string()],
+            children: vec![
+                leaf(
+                    "name",
+                    TypeSpec::String {
+                        length: None,
+                        pattern: None,
+                    },
+                ),
+                leaf("unit-id", TypeSpec::Uint32 { range: None }),
+                leaf("enabled", TypeSpec::Boolean),
+            ],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            min_elements: 0,
+            max_elements: None,
+            unknown_statements: vec![],
+            status: Status::Current,
+        })],
+        augments: vec![],
+        deviations: vec![],
+        rpcs: vec![],
+        notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
+    };
+
+    let generated = CodeGenerator::new(GeneratorConfig::default())
+        .generate(&module)
+        .unwrap();
+    let content = &generated.files[0].content;
+
+    assert!(
+        content.contains("pub struct SubInterfaceKey {"),
+        "{}",
+        content
+    );
+    assert!(content.contains("pub name: String,"), "{}", content);
+    assert!(content.contains("pub unit_id: u32,"), "{}", content);
+    assert!(content.contains("pub fn key(&self) -> SubInterfaceKey"));
+    assert!(content.contains("impl std::fmt::Display for SubInterfaceKey"));
+    assert!(content
+        .contains("rustconf_runtime::list_key::write_fragment(f, \"sub-interface\", &values)"));
+    assert!(content.contains("impl From<SubInterfaceKey> for String"));
+    assert!(content.contains("impl std::str::FromStr for SubInterfaceKey"));
+    assert!(content
+        .contains("rustconf_runtime::list_key::parse_fragment(fragment, \"sub-interface\", 2)?"));
+
+    // Without Clone, entries have no key method
+    let config = GeneratorConfig {
+        derive_clone: false,
+        ..Default::default()
+    };
+    let generated = CodeGenerator::new(config).generate(&module).unwrap();
+    let content = &generated.files[0].content;
+    assert!(content.contains("pub struct SubInterfaceKey {"));
+    assert!(!content.contains("pub fn key(&self)"));
+}
+
+#[test]
+fn test_generate_merge_methods() {
+    let config = GeneratorConfig {
+        output_dir: PathBuf::from("test_output"),
+        module_name: "test".to_string(),
+        ..Default::default()
+    };
+
+    let generator = CodeGenerator::new(config);
+
+    let string_leaf = |name: &str, mandatory: bool| {
+        DataNode::Leaf(Leaf {
+            name: name.to_string(),
+            description: None,
+            type_spec: TypeSpec::String {
+                length: None,
+                pattern: None,
+            },
+            mandatory,
+            default: None,
+            units: None,
+            config: true,
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
+        })
+    };
+
+    let container = Container {
+        name: "system".to_string(),
+        description: None,
+        config: true,
+        mandatory: false,
+        children: vec![
+            string_leaf("hostname", false),
+            DataNode::LeafList(LeafList {
+                name: "search-domain".to_string(),
+                description: None,
+                type_spec: TypeSpec::String {
+                    length: None,
+                    pattern: None,
+                },
+                config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                min_elements: 0,
+                max_elements: None,
+                unknown_statements: vec![],
+                status: Status::Current,
+            }),
+            DataNode::Container(Container {
+                name: "clock".to_string(),
+                description: None,
+                config: true,
+                mandatory: false,
+                children: vec![string_leaf("timezone", false)],
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
+            }),
+            DataNode::List(List {
+                name: "users".to_string(),
+                description: None,
+                config: true,
+                keys: vec!["username".to_string()],
+                children: vec![string_leaf("username", true), string_leaf("email", false)],
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                min_elements: 0,
+                max_elements: None,
+                unknown_statements: vec![],
+                status: Status::Current,
+            }),
+        ],
+        if_features: vec![],
+        when: None,
+        must: vec![],
+        unknown_statements: vec![],
+        status: Status::Current,
+    };
+
+    let module = YangModule {
+        name: "test".to_string(),
+        namespace: "urn:test".to_string(),
+        prefix: "t".to_string(),
+        yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
+        imports: vec![],
+        features: vec![],
+        typedefs: vec![],
+        groupings: vec![],
+        data_nodes: vec![DataNode::Container(container)],
+        augments: vec![],
+        deviations: vec![],
+        rpcs: vec![],
+        notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
+    };
+
+    let generated = generator.generate(&module).unwrap();
+    let content = &generated.files[0].content;
+
+    assert!(content.contains("impl System {"));
+    assert!(content.contains("pub fn merge(&mut self, other: &Self) {"));
+    // Leaves set in the patch overwrite
+    assert!(content.contains("if other.hostname.is_some() {"));
+    assert!(content.contains("self.hostname.clone_from(&other.hostname);"));
+    // Leaf-list values are added once
+    assert!(content.contains("self.search_domain.extend("));
+    // Containers are merged recursively
+    assert!(content.contains("Some(mine) => mine.merge(theirs),"));
+    assert!(content.contains("None => self.clock = Some(theirs.clone()),"));
+    // List entries are matched by key, and keys are never overwritten
+    assert!(content.contains("match index.get(&theirs.merge_key()) {"));
+    assert!(content.contains("fn merge_key(&self) -> String {"));
+    assert!(content.contains("serde_json::to_string(&(&self.username,)).unwrap_or_default()"));
+    assert!(!content.contains("self.username.clone_from(&other.username);"));
+    assert!(content.contains("self.email.clone_from(&other.email);"));
+}
+
+#[test]
+fn test_merge_methods_require_clone() {
+    let config = GeneratorConfig {
+        derive_clone: false,
+        ..Default::default()
+    };
+
+    let module = YangModule {
+        name: "test".to_string(),
+        namespace: "urn:test".to_string(),
+        prefix: "t".to_string(),
+        yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
+        imports: vec![],
+        features: vec![],
+        typedefs: vec![],
+        groupings: vec![],
+        data_nodes: vec![DataNode::Container(Container {
+            name: "system".to_string(),
+            description: None,
+            config: true,
+            mandatory: false,
+            children: vec![],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
+        })],
+        augments: vec![],
+        deviations: vec![],
+        rpcs: vec![],
+        notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
+    };
+
+    let generated = CodeGenerator::new(config).generate(&module).unwrap();
+    assert!(!generated.files[0].content.contains("pub fn merge("));
+}
+
+#[test]
+fn test_generate_pretty_printers() {
+    let input = r#"
+        module test {
+            namespace "urn:test";
+            prefix t;
+
+            typedef mtu-size {
+                type uint16;
+                units "bytes";
+                default "1500";
+            }
+
+            container interfaces {
+                list interface {
+                    key "name";
+                    leaf name { type string; }
+                    leaf mtu { type mtu-size; }
+                    leaf speed {
+                        type uint32;
+                        units "Mbit/s";
+                    }
+                    leaf-list address { type string; }
+                    container config {
+                        leaf enabled { type boolean; }
+                    }
+                }
+            }
+        }
+    "#;
+
+    let mut parser = crate::parser::YangParser::new();
+    let module = parser.parse_string(input, "test.yang").unwrap();
+    let leaf = |name: &str| match &module.data_nodes[0] {
+        DataNode::Container(interfaces) => match &interfaces.children[0] {
+            DataNode::List(list) => list
+                .children
+                .iter()
+                .find_map(|child| match child {
+                    DataNode::Leaf(leaf) if leaf.name == name => Some(leaf.clone()),
+                    _ => None,
+                })
+                .unwrap(),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+    assert_eq!(leaf("speed").units.as_deref(), Some("Mbit/s"));
+
+    let config = GeneratorConfig {
+        enable_pretty_printers: true,
+        ..Default::default()
+    };
+    let generated = CodeGenerator::new(config).generate(&module).unwrap();
+    let content = &generated.files[0].content;
+
+    assert!(content.contains("impl rustconf_runtime::Pretty for Interfaces {"));
+    assert!(content.contains("printer.list(\"interface\", &self.interface);"));
+    // Units and defaults come from the leaf or its typedef
+    assert!(content
+        .contains("printer.leaf(\"mtu\", self.mtu.as_ref(), Some(\"bytes\"), Some(\"1500\"));"));
+    assert!(
+        content.contains("printer.leaf(\"speed\", self.speed.as_ref(), Some(\"Mbit/s\"), None);")
+    );
+    assert!(content.contains("printer.leaf_list(\"address\", &self.address, None);"));
+    assert!(content.contains("printer.container(\"config\", self.config.as_ref());"));
+    // Keys are shown in the entry header rather than as leaves
+    assert!(content.contains("format!(\"name={}\", rustconf_runtime::pretty::render(&self.name))"));
+    assert!(!content.contains("printer.leaf(\"name\""));
+}
+
+#[test]
+fn test_pretty_printers_disabled_by_default() {
+    let module = YangModule {
+        name: "test".to_string(),
+        namespace: "urn:test".to_string(),
+        prefix: "t".to_string(),
+        yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
+        imports: vec![],
+        features: vec![],
+        typedefs: vec![],
+        groupings: vec![],
+        data_nodes: vec![DataNode::Container(Container {
+            name: "system".to_string(),
+            description: None,
+            config: true,
+            mandatory: false,
+            children: vec![],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
+        })],
+        augments: vec![],
+        deviations: vec![],
+        rpcs: vec![],
+        notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
+    };
+
+    let generated = CodeGenerator::new(GeneratorConfig::default())
+        .generate(&module)
+        .unwrap();
+    assert!(!generated.files[0]
+        .content
+        .contains("rustconf_runtime::Pretty"));
+}
+
+#[test]
+fn test_list_and_leaf_list_fields_default_when_absent() {
+    let config = GeneratorConfig {
+        output_dir: PathBuf::from("test_output"),
+        module_name: "test".to_string(),
+        ..Default::default()
+    };
+
+    let generator = CodeGenerator::new(config);
+
+    let container = Container {
+        name: "dns".to_string(),
+        description: None,
+        config: true,
+        mandatory: false,
+        children: vec![
+            DataNode::LeafList(LeafList {
+                name: "search-domain".to_string(),
+                description: Some("Domains appended to short names".to_string()),
+                type_spec: TypeSpec::String {
+                    length: None,
+                    pattern: None,
+                },
+                config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                min_elements: 0,
+                max_elements: None,
+                unknown_statements: vec![],
+                status: Status::Current,
+            }),
+            DataNode::List(List {
+                name: "servers".to_string(),
+                description: None,
+                config: true,
+                keys: vec!["address".to_string()],
+                children: vec![DataNode::Leaf(Leaf {
+                    name: "address".to_string(),
+                    description: None,
+                    type_spec: TypeSpec::String {
+                        length: None,
+                        pattern: None,
+                    },
+                    mandatory: true,
+                    default: None,
+                    units: None,
+                    config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
+                })],
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                min_elements: 0,
+                max_elements: None,
+                unknown_statements: vec![],
+                status: Status::Current,
+            }),
+        ],
+        if_features: vec![],
+        when: None,
+        must: vec![],
+        unknown_statements: vec![],
+        status: Status::Current,
+    };
+
+    let module = YangModule {
+        name: "test".to_string(),
+        namespace: "urn:test".to_string(),
+        prefix: "t".to_string(),
+        yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
+        imports: vec![],
+        features: vec![],
+        typedefs: vec![],
+        groupings: vec![],
+        data_nodes: vec![DataNode::Container(container)],
+        augments: vec![],
+        deviations: vec![],
+        rpcs: vec![],
+        notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
+    };
+
+    let generated = generator.generate(&module).unwrap();
+    let content = &generated.files[0].content;
+
+    for field in ["search_domain: Vec<String>", "servers: Vec<Server>"] {
+        let field_start = content.find(&format!("pub {}", field)).unwrap();
+        let attrs_start = content[..field_start].rfind(['{', ',']).unwrap();
+        let attrs = &content[attrs_start..field_start];
+        assert!(attrs.contains("#[serde(default)]"), "{}", attrs);
+        assert!(
+            attrs.contains(r#"#[serde(skip_serializing_if = "Vec::is_empty")]"#),
+            "{}",
+            attrs
+        );
+    }
+}
+
 #[test]
 fn test_generate_choice() {
     let config = GeneratorConfig {
@@ -148,8 +681,18 @@ fn test_generate_choice() {
                     type_spec: TypeSpec::Uint16 { range: None },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 })],
+                if_features: vec![],
+                when: None,
+                unknown_statements: vec![],
+                status: Status::Current,
             },
             Case {
                 name: "udp".to_string(),
@@ -160,10 +703,24 @@ fn test_generate_choice() {
                     type_spec: TypeSpec::Uint16 { range: None },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 })],
+                if_features: vec![],
+                when: None,
+                unknown_statements: vec![],
+                status: Status::Current,
             },
         ],
+        if_features: vec![],
+        when: None,
+        unknown_statements: vec![],
+        status: Status::Current,
     };
 
     let module = YangModule {
@@ -171,12 +728,20 @@ fn test_generate_choice() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::Choice(choice)],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate(&module);
@@ -208,6 +773,7 @@ fn test_generate_typedef() {
         type_spec: TypeSpec::Uint8 { range: None },
         default: None,
         units: None,
+        status: Status::Current,
     };
 
     let module = YangModule {
@@ -215,12 +781,20 @@ fn test_generate_typedef() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![typedef],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = generator.generate(&module);
@@ -232,3 +806,534 @@ fn test_generate_typedef() {
     // Check that type alias is generated
     assert!(content.contains("pub type Percentage = u8"));
 }
+
+#[test]
+fn test_serde_rename_all_replaces_per_field_renames() {
+    let mut config = GeneratorConfig {
+        output_dir: PathBuf::from("test_output"),
+        module_name: "test".to_string(),
+        ..Default::default()
+    };
+    config.serde_rename_all();
+
+    let generator = CodeGenerator::new(config);
+
+    let container = Container {
+        name: "interface-settings".to_string(),
+        description: None,
+        config: true,
+        mandatory: false,
+        children: vec![
+            DataNode::Leaf(Leaf {
+                name: "admin-status".to_string(),
+                description: None,
+                type_spec: TypeSpec::Boolean,
+                mandatory: false,
+                default: None,
+                units: None,
+                config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
+            }),
+            DataNode::Leaf(Leaf {
+                name: "type".to_string(),
+                description: None,
+                type_spec: TypeSpec::String {
+                    length: None,
+                    pattern: None,
+                },
+                mandatory: true,
+                default: None,
+                units: None,
+                config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
+            }),
+            DataNode::Leaf(Leaf {
+                name: "max_mtu".to_string(),
+                description: None,
+                type_spec: TypeSpec::Uint16 { range: None },
+                mandatory: true,
+                default: None,
+                units: None,
+                config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
+            }),
+        ],
+        if_features: vec![],
+        when: None,
+        must: vec![],
+        unknown_statements: vec![],
+        status: Status::Current,
+    };
+
+    let module = YangModule {
+        name: "test".to_string(),
+        namespace: "urn:test".to_string(),
+        prefix: "t".to_string(),
+        yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
+        imports: vec![],
+        features: vec![],
+        typedefs: vec![],
+        groupings: vec![],
+        data_nodes: vec![DataNode::Container(container)],
+        augments: vec![],
+        deviations: vec![],
+        rpcs: vec![],
+        notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
+    };
+
+    let generated = generator.generate(&module).unwrap();
+    let content = &generated.files[0].content;
+
+    assert!(content.contains(r#"#[serde(rename_all = "kebab-case")]"#));
+    // Names that follow from the field name need no explicit rename
+    assert!(!content.contains(r#"rename = "admin-status""#));
+    assert!(content.contains(r#"#[serde(skip_serializing_if = "Option::is_none")]"#));
+    // Keyword-escaped fields and underscores are kept as exceptions
+    assert!(content.contains(r#"#[serde(rename = "type")]"#));
+    assert!(content.contains(r#"#[serde(rename = "max_mtu")]"#));
+}
+
+#[test]
+fn test_serde_rename_all_ignored_with_legacy_prefixes() {
+    let mut config = GeneratorConfig {
+        output_dir: PathBuf::from("test_output"),
+        module_name: "test".to_string(),
+        enable_namespace_prefixes: true,
+        ..Default::default()
+    };
+    config
+        .serde_rename_all()
+        .namespace_qualifier(NamespaceQualifier::Prefix);
+
+    let generator = CodeGenerator::new(config);
+
+    let container = Container {
+        name: "system".to_string(),
+        description: None,
+        config: true,
+        mandatory: false,
+        children: vec![DataNode::Leaf(Leaf {
+            name: "host-name".to_string(),
+            description: None,
+            type_spec: TypeSpec::String {
+                length: None,
+                pattern: None,
+            },
+            mandatory: true,
+            default: None,
+            units: None,
+            config: true,
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
+        })],
+        if_features: vec![],
+        when: None,
+        must: vec![],
+        unknown_statements: vec![],
+        status: Status::Current,
+    };
+
+    let module = YangModule {
+        name: "test".to_string(),
+        namespace: "urn:test".to_string(),
+        prefix: "t".to_string(),
+        yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
+        imports: vec![],
+        features: vec![],
+        typedefs: vec![],
+        groupings: vec![],
+        data_nodes: vec![DataNode::Container(container)],
+        augments: vec![],
+        deviations: vec![],
+        rpcs: vec![],
+        notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
+    };
+
+    let generated = generator.generate(&module).unwrap();
+    let content = &generated.files[0].content;
+
+    assert!(!content.contains("rename_all"));
+    assert!(content.contains(r#"rename = "t:host-name""#));
+}
+
+#[test]
+fn test_restricted_typedef_references_generate_merged_validation() {
+    let input = r#"
+        module test {
+            namespace "urn:test";
+            prefix t;
+
+            typedef port-number {
+                type uint16 {
+                    range "1..65535";
+                }
+            }
+
+            typedef well-known-port {
+                type port-number {
+                    range "0..1023";
+                }
+            }
+
+            container server {
+                leaf port {
+                    type port-number;
+                }
+                leaf admin-port {
+                    type well-known-port {
+                        range "1..100";
+                    }
+                }
+            }
+        }
+    "#;
+
+    let mut parser = crate::parser::YangParser::new();
+    let module = parser.parse_string(input, "test.yang").unwrap();
+
+    let config = GeneratorConfig::default();
+    let type_gen = crate::generator::types::TypeGenerator::new(&config);
+    let validated_name = |min, max| {
+        type_gen.get_validated_type_name(&TypeSpec::Uint16 {
+            range: Some(RangeConstraint::new(vec![Range::new(min, max)])),
+        })
+    };
+
+    let generated = CodeGenerator::new(config.clone())
+        .generate(&module)
+        .unwrap();
+    let content = &generated.files[0].content;
+
+    // The derived typedef narrows its base: 1..65535 ∩ 0..1023
+    assert!(content.contains(&format!(
+        "pub type WellKnownPort = {};",
+        validated_name(1, 1023)
+    )));
+    // Unrestricted references stay aliases
+    assert!(content.contains("pub port: Option<PortNumber>"));
+    // Restricted leaf references use the fully merged constraint
+    assert!(content.contains(&format!(
+        "pub admin_port: Option<{}>",
+        validated_name(1, 100)
+    )));
+    assert!(content.contains(&format!("pub struct {}", validated_name(1, 100))));
+}
+
+#[test]
+fn test_resolve_type_follows_unrestricted_typedef_chains() {
+    let input = r#"
+        module test {
+            namespace "urn:test";
+            prefix t;
+
+            typedef port-number {
+                type uint16 {
+                    range "1..65535";
+                }
+            }
+
+            typedef service-port {
+                type port-number;
+            }
+
+            typedef loop-a {
+                type loop-b;
+            }
+
+            typedef loop-b {
+                type loop-a;
+            }
+        }
+    "#;
+    let module = crate::parser::YangParser::new()
+        .parse_string(input, "test.yang")
+        .unwrap();
+    let reference = |name: &str| TypeSpec::TypedefRef {
+        name: name.to_string(),
+        range: None,
+        length: None,
+        pattern: None,
+    };
+    let resolve = |type_spec: &TypeSpec| {
+        let resolved = crate::generator::restrictions::resolve_type(type_spec, &module);
+        let names: Vec<&str> = resolved
+            .typedefs
+            .iter()
+            .map(|typedef| typedef.name.as_str())
+            .collect();
+        (resolved.type_spec, names)
+    };
+
+    let (type_spec, typedefs) = resolve(&reference("service-port"));
+    assert_eq!(
+        type_spec,
+        Some(TypeSpec::Uint16 {
+            range: Some(RangeConstraint::new(vec![Range::new(1, 65535)])),
+        })
+    );
+    assert_eq!(typedefs, ["service-port", "port-number"]);
+
+    // Built-in types are returned as they are
+    assert_eq!(
+        resolve(&TypeSpec::Boolean),
+        (Some(TypeSpec::Boolean), vec![])
+    );
+
+    // Imported and circular typedefs have no type to resolve to
+    assert_eq!(resolve(&reference("inet:port-number")), (None, vec![]));
+    assert_eq!(
+        resolve(&reference("loop-a")),
+        (None, vec!["loop-a", "loop-b"])
+    );
+}
+
+#[test]
+fn test_bits_types_generate_flag_types() {
+    let input = r#"
+        module test {
+            namespace "urn:test";
+            prefix t;
+
+            typedef interface-flags {
+                type bits {
+                    bit up;
+                    bit link-up { position 3; description "Carrier detected"; }
+                    bit ten-gb;
+                }
+            }
+
+            container interface {
+                leaf flags { type interface-flags; }
+                leaf-list capabilities {
+                    type bits { bit rx; bit tx; }
+                }
+                leaf wide {
+                    type bits { bit high { position 64; } }
+                }
+            }
+        }
+    "#;
+    let module = crate::parser::YangParser::new()
+        .parse_string(input, "test.yang")
+        .unwrap();
+
+    let config = GeneratorConfig::default();
+    let type_gen = crate::generator::types::TypeGenerator::new(&config);
+    let type_name = match &module.typedefs[0].type_spec {
+        type_spec @ TypeSpec::Bits { .. } => type_gen.bits_type_name(type_spec).unwrap(),
+        other => panic!("Expected Bits type, got {:?}", other),
+    };
+
+    let generated = CodeGenerator::new(config.clone())
+        .generate(&module)
+        .unwrap();
+    let content = &generated.files[0].content;
+
+    assert!(content.contains(&format!("pub struct {}(u64);", type_name)));
+    assert!(content.contains("pub const UP: Self = Self(1 << 0);"));
+    assert!(content.contains("/// Carrier detected\n    pub const LINK_UP: Self = Self(1 << 3);"));
+    assert!(content.contains("pub const TEN_GB: Self = Self(1 << 4);"));
+    assert!(content.contains(&format!("impl rustconf_runtime::Bits for {} {{", type_name)));
+    assert!(content.contains(r#"&[("up", 0), ("link-up", 3), ("ten-gb", 4)]"#));
+    assert!(content.contains("rustconf_runtime::bits::serialize(self, serializer)"));
+    assert!(content.contains(&format!("pub type InterfaceFlags = {};", type_name)));
+    assert!(content.contains("pub flags: Option<InterfaceFlags>"));
+    assert!(content.contains("pub capabilities: Vec<BitsId"));
+    // Positions beyond a u64 keep the string form
+    assert!(content.contains("pub wide: Option<String>"));
+    assert_eq!(content.matches("(u64);").count(), 2);
+}
+
+#[test]
+fn test_list_entry_types_are_singularized() {
+    let input = r#"
+        module test {
+            namespace "urn:test";
+            prefix t;
+
+            container system {
+                list policies { key name; leaf name { type string; } }
+                list ip-addresses { key ip; leaf ip { type string; } }
+                list chassis { key slot; leaf slot { type uint8; } }
+                list status { key id; leaf id { type uint8; } }
+                list media { key id; leaf id { type uint8; } }
+            }
+        }
+    "#;
+    let module = crate::parser::YangParser::new()
+        .parse_string(input, "test.yang")
+        .unwrap();
+
+    let mut config = GeneratorConfig::default();
+    config.list_item_name("media", "Medium");
+    let generated = CodeGenerator::new(config).generate(&module).unwrap();
+    let content = &generated.files[0].content;
+
+    assert!(content.contains("pub struct Policy {"));
+    assert!(content.contains("pub policies: Vec<Policy>"));
+    assert!(content.contains("pub struct IpAddress {"));
+    // Names that are not plural are kept
+    assert!(content.contains("pub struct Chassis {"));
+    assert!(content.contains("pub chassis: Vec<Chassis>"));
+    assert!(content.contains("pub struct Status {"));
+    // Per-list overrides win over the rules
+    assert!(content.contains("pub struct Medium {"));
+    assert!(content.contains("pub media: Vec<Medium>"));
+}
+
+#[test]
+fn test_invalid_list_item_name_is_rejected() {
+    let mut config = GeneratorConfig::default();
+    config.list_item_name("media", "media-item");
+    let error = config.validate().unwrap_err();
+    assert!(error.contains("media-item"));
+}
+
+#[test]
+fn test_enabled_features_select_generated_fields() {
+    let mut module = crate::parser::YangParser::new()
+        .parse_string(
+            r#"
+            module interfaces {
+                yang-version 1.1;
+                namespace "urn:interfaces";
+                prefix if;
+
+                feature if-mib;
+                feature pre-provisioning;
+                feature if-mib-counters { if-feature if-mib; }
+
+                container interfaces {
+                    leaf name { type string; }
+                    leaf if-index {
+                        type int32;
+                        if-feature if-mib;
+                    }
+                    leaf provisioned {
+                        type boolean;
+                        if-feature "pre-provisioning and not if-mib";
+                    }
+                    leaf in-octets {
+                        type uint64;
+                        if-feature if-mib-counters;
+                    }
+                }
+            }
+        "#,
+            "interfaces.yang",
+        )
+        .unwrap();
+    crate::parser::YangParser::new()
+        .expand_module(&mut module)
+        .unwrap();
+
+    // Every feature is supported by default
+    let generated = CodeGenerator::new(GeneratorConfig::default())
+        .generate(&module)
+        .unwrap();
+    let content = &generated.files[0].content;
+    assert!(
+        content.contains("pub if_index: Option<i32>,"),
+        "{}",
+        content
+    );
+    assert!(content.contains("pub provisioned: Option<bool>,"));
+    assert!(content.contains("pub in_octets: Option<"));
+
+    // if-mib-counters depends on if-mib, which is not enabled
+    let mut config = GeneratorConfig::default();
+    config.enabled_features(["pre-provisioning", "interfaces:if-mib-counters"]);
+    let generated = CodeGenerator::new(config).generate(&module).unwrap();
+    let content = &generated.files[0].content;
+    assert!(content.contains("pub name: Option<String>,"), "{}", content);
+    assert!(!content.contains("if_index"), "{}", content);
+    assert!(content.contains("pub provisioned: Option<bool>,"));
+    assert!(!content.contains("in_octets"));
+}
+
+#[test]
+fn test_constructors_only_default_all_optional_structs() {
+    let mut module = crate::parser::YangParser::new()
+        .parse_string(
+            r#"
+            module interfaces {
+                namespace "urn:interfaces";
+                prefix if;
+
+                container interfaces {
+                    leaf description { type string; }
+                    list interface {
+                        key "name";
+                        leaf name { type string; }
+                        leaf type {
+                            type string;
+                            mandatory true;
+                        }
+                        leaf mtu { type uint16; }
+                        leaf-list alias { type string; }
+                    }
+                }
+            }
+        "#,
+            "interfaces.yang",
+        )
+        .unwrap();
+    crate::parser::YangParser::new()
+        .expand_module(&mut module)
+        .unwrap();
+
+    // Not generated by default
+    let generated = CodeGenerator::new(GeneratorConfig::default())
+        .generate(&module)
+        .unwrap();
+    let content = &generated.files[0].content;
+    assert!(!content.contains("Default"), "{}", content);
+    assert!(!content.contains("pub fn new("), "{}", content);
+
+    let mut config = GeneratorConfig::default();
+    config.generate_constructors();
+    let generated = CodeGenerator::new(config).generate(&module).unwrap();
+    let content = &generated.files[0].content;
+    assert!(
+        content.contains(
+            "#[derive(Debug, Clone, Default, Serialize, Deserialize)]\npub struct Interfaces {"
+        ),
+        "{}",
+        content
+    );
+    assert!(
+        content.contains("#[derive(Debug, Clone, Serialize, Deserialize)]\npub struct Interface {")
+    );
+    assert!(
+        content.contains("pub fn new(name: String, type_: String) -> Self {"),
+        "{}",
+        content
+    );
+    assert!(content.contains("mtu: None,"));
+    assert!(content.contains("alias: Vec::new(),"));
+}
@@ -1,6 +1,6 @@
 //! Unit tests for URL path construction (Task 10.4)
 
-use crate::parser::{Container, DataNode, Leaf, List, TypeSpec, YangModule};
+use crate::parser::{Container, DataNode, Leaf, List, Status, TypeSpec, YangModule};
 
 use super::*;
 
@@ -14,7 +14,11 @@ fn test_generate_container_path_helper() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::Container(Container {
@@ -23,9 +27,18 @@ fn test_generate_container_path_helper() {
             config: true,
             mandatory: false,
             children: vec![],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -55,7 +68,11 @@ fn test_generate_container_path_with_namespace_prefix() {
         namespace: "urn:test".to_string(),
         prefix: "test".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::Container(Container {
@@ -64,9 +81,18 @@ fn test_generate_container_path_with_namespace_prefix() {
             config: true,
             mandatory: false,
             children: vec![],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -76,6 +102,61 @@ fn test_generate_container_path_with_namespace_prefix() {
     assert!(content.contains("\"/restconf/data/test:config\".to_string()"));
 }
 
+#[test]
+fn test_container_path_uses_module_name_qualifier() {
+    let module = YangModule {
+        name: "example-system".to_string(),
+        namespace: "urn:example:system".to_string(),
+        prefix: "sys".to_string(),
+        yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
+        imports: vec![],
+        features: vec![],
+        typedefs: vec![],
+        groupings: vec![],
+        data_nodes: vec![DataNode::Container(Container {
+            name: "system".to_string(),
+            description: None,
+            config: true,
+            mandatory: false,
+            children: vec![],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
+        })],
+        augments: vec![],
+        deviations: vec![],
+        rpcs: vec![],
+        notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
+    };
+
+    let config = GeneratorConfig {
+        enable_namespace_prefixes: true,
+        ..Default::default()
+    };
+    let generated = CodeGenerator::new(config).generate(&module).unwrap();
+    assert!(generated.files[0]
+        .content
+        .contains("\"/restconf/data/example-system:system\".to_string()"));
+
+    // The legacy compatibility mode keeps the module prefix
+    let config = GeneratorConfig {
+        enable_namespace_prefixes: true,
+        namespace_qualifier: NamespaceQualifier::Prefix,
+        ..Default::default()
+    };
+    let generated = CodeGenerator::new(config).generate(&module).unwrap();
+    assert!(generated.files[0]
+        .content
+        .contains("\"/restconf/data/sys:system\".to_string()"));
+}
+
 #[test]
 fn test_generate_list_path_helpers() {
     let config = GeneratorConfig::default();
@@ -86,7 +167,11 @@ fn test_generate_list_path_helpers() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::List(List {
@@ -103,11 +188,28 @@ fn test_generate_list_path_helpers() {
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            min_elements: 0,
+            max_elements: None,
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -144,7 +246,11 @@ fn test_generate_list_path_with_multiple_keys() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::List(List {
@@ -162,7 +268,13 @@ fn test_generate_list_path_with_multiple_keys() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
                 DataNode::Leaf(Leaf {
                     name: "prefix-length".to_string(),
@@ -170,12 +282,29 @@ fn test_generate_list_path_with_multiple_keys() {
                     type_spec: TypeSpec::Uint8 { range: None },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
             ],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            min_elements: 0,
+            max_elements: None,
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -210,7 +339,11 @@ fn test_generate_list_path_with_namespace_prefix() {
         namespace: "urn:test".to_string(),
         prefix: "test".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::List(List {
@@ -224,11 +357,28 @@ fn test_generate_list_path_with_namespace_prefix() {
                 type_spec: TypeSpec::Uint32 { range: None },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            min_elements: 0,
+            max_elements: None,
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -249,7 +399,11 @@ fn test_percent_encode_helper_generated() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::List(List {
@@ -266,11 +420,28 @@ fn test_percent_encode_helper_generated() {
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            min_elements: 0,
+            max_elements: None,
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -296,7 +467,11 @@ fn test_path_helpers_for_state_containers() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::Container(Container {
@@ -305,9 +480,18 @@ fn test_path_helpers_for_state_containers() {
             config: false,
             mandatory: false,
             children: vec![],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -328,7 +512,11 @@ fn test_path_helpers_for_multiple_data_nodes() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![
@@ -338,6 +526,11 @@ fn test_path_helpers_for_multiple_data_nodes() {
                 config: true,
                 mandatory: false,
                 children: vec![],
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             }),
             DataNode::List(List {
                 name: "items".to_string(),
@@ -353,12 +546,29 @@ fn test_path_helpers_for_multiple_data_nodes() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 })],
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                min_elements: 0,
+                max_elements: None,
+                unknown_statements: vec![],
+                status: Status::Current,
             }),
         ],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -380,7 +590,11 @@ fn test_path_helpers_marked_as_allow_dead_code() {
         namespace: "urn:test".to_string(),
         prefix: "t".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::Container(Container {
@@ -389,9 +603,18 @@ fn test_path_helpers_marked_as_allow_dead_code() {
             config: true,
             mandatory: false,
             children: vec![],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
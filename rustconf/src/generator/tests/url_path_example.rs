@@ -1,6 +1,6 @@
 //! Example test to demonstrate URL path construction output
 
-use crate::parser::{Container, DataNode, Leaf, List, TypeSpec, YangModule};
+use crate::parser::{Container, DataNode, Leaf, List, Status, TypeSpec, YangModule};
 
 use super::*;
 
@@ -14,6 +14,9 @@ fn test_url_path_construction_example() {
         namespace: "urn:example:network".to_string(),
         prefix: "net".to_string(),
         yang_version: None,
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
         typedefs: vec![],
         groupings: vec![],
@@ -32,8 +35,19 @@ fn test_url_path_construction_example() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 })],
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             }),
             DataNode::List(List {
                 name: "interfaces".to_string(),
@@ -50,7 +64,13 @@ fn test_url_path_construction_example() {
                         },
                         mandatory: true,
                         default: None,
+                        units: None,
                         config: true,
+                        if_features: vec![],
+                        when: None,
+                        must: vec![],
+                        unknown_statements: vec![],
+                        status: Status::Current,
                     }),
                     DataNode::Leaf(Leaf {
                         name: "enabled".to_string(),
@@ -58,13 +78,31 @@ fn test_url_path_construction_example() {
                         type_spec: TypeSpec::Boolean,
                         mandatory: true,
                         default: None,
+                        units: None,
                         config: true,
+                        if_features: vec![],
+                        when: None,
+                        must: vec![],
+                        unknown_statements: vec![],
+                        status: Status::Current,
                     }),
                 ],
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                min_elements: 0,
+                max_elements: None,
+                unknown_statements: vec![],
+                status: Status::Current,
             }),
         ],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        features: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let generated = generator.generate(&module).unwrap();
@@ -8,7 +8,7 @@ use crate::generator::types::TypeGenerator;
 use crate::generator::GeneratorConfig;
 use crate::parser::{
     Container, DataNode, Leaf, LengthConstraint, LengthRange, PatternConstraint, Range,
-    RangeConstraint, Rpc, TypeSpec, YangModule, YangVersion,
+    RangeConstraint, Rpc, Status, TypeSpec, YangModule, YangVersion,
 };
 
 #[test]
@@ -26,10 +26,16 @@ fn test_validation_in_request_deserialization() {
         namespace: "http://example.com/test".to_string(),
         prefix: "test".to_string(),
         yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-rpc".to_string(),
             description: Some("Test RPC with validation".to_string()),
@@ -43,11 +49,21 @@ fn test_validation_in_request_deserialization() {
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })]),
             output: None,
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = router_gen.generate_router(&module);
@@ -80,10 +96,16 @@ fn test_validation_in_response_serialization() {
         namespace: "http://example.com/test".to_string(),
         prefix: "test".to_string(),
         yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![Rpc {
             name: "test-rpc".to_string(),
             description: Some("Test RPC with validation".to_string()),
@@ -98,10 +120,20 @@ fn test_validation_in_response_serialization() {
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: false,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })]),
+            timeout: None,
+            unknown_statements: vec![],
         }],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = router_gen.generate_router(&module);
@@ -144,6 +176,9 @@ fn test_validation_with_string_constraints() {
         namespace: "http://example.com/test".to_string(),
         prefix: "test".to_string(),
         yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
         typedefs: vec![],
         groupings: vec![],
@@ -164,25 +199,47 @@ fn test_validation_with_string_constraints() {
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
                 DataNode::Leaf(Leaf {
                     name: "ip-address".to_string(),
                     description: Some("IPv4 address".to_string()),
                     type_spec: TypeSpec::String {
                         length: None,
-                        pattern: Some(PatternConstraint {
-                            pattern: r"^(\d{1,3}\.){3}\d{1,3}$".to_string(),
-                        }),
+                        pattern: Some(PatternConstraint::new(
+                            r"^(\d{1,3}\.){3}\d{1,3}$".to_string(),
+                        )),
                     },
                     mandatory: true,
                     default: None,
+                    units: None,
                     config: true,
+                    if_features: vec![],
+                    when: None,
+                    must: vec![],
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 }),
             ],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        features: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     // Generate types to ensure validated types are created
@@ -214,12 +271,20 @@ fn test_validation_error_status_codes() {
         namespace: "http://example.com/test".to_string(),
         prefix: "test".to_string(),
         yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = router_gen.generate_router(&module);
@@ -268,7 +333,11 @@ fn test_validation_preserves_handler_invocation_flow() {
         namespace: "http://example.com/test".to_string(),
         prefix: "test".to_string(),
         yang_version: Some(YangVersion::V1_1),
+        organization: None,
+        contact: None,
+        description: None,
         imports: vec![],
+        features: vec![],
         typedefs: vec![],
         groupings: vec![],
         data_nodes: vec![DataNode::Container(Container {
@@ -286,11 +355,26 @@ fn test_validation_preserves_handler_invocation_flow() {
                 },
                 mandatory: true,
                 default: None,
+                units: None,
                 config: true,
+                if_features: vec![],
+                when: None,
+                must: vec![],
+                unknown_statements: vec![],
+                status: Status::Current,
             })],
+            if_features: vec![],
+            when: None,
+            must: vec![],
+            unknown_statements: vec![],
+            status: Status::Current,
         })],
+        augments: vec![],
+        deviations: vec![],
         rpcs: vec![],
         notifications: vec![],
+        extensions: vec![],
+        unknown_statements: vec![],
     };
 
     let result = router_gen.generate_router(&module);
@@ -316,3 +400,151 @@ fn test_validation_preserves_handler_invocation_flow() {
         assert!(put_section.contains("self.handler.put_"));
     }
 }
+
+#[test]
+fn test_must_conditions_generate_validate() {
+    let mut module = crate::parser::YangParser::new()
+        .parse_string(
+            r#"
+            module interfaces {
+                namespace "urn:interfaces";
+                prefix if;
+
+                container interfaces {
+                    list interface {
+                        key "name";
+                        must "mtu >= 1280 or not(ipv6)" {
+                            error-message "IPv6 needs an MTU of at least 1280";
+                        }
+                        must "count(../interface) < 10";
+                        leaf name { type string; }
+                        leaf ipv6 { type boolean; }
+                        leaf mtu {
+                            type uint16;
+                            must ". >= 68";
+                        }
+                        leaf-list alias {
+                            type string;
+                            must ". != ../name";
+                        }
+                    }
+                }
+            }
+        "#,
+            "interfaces.yang",
+        )
+        .unwrap();
+    crate::parser::YangParser::new()
+        .expand_module(&mut module)
+        .unwrap();
+
+    let generated = crate::generator::CodeGenerator::new(GeneratorConfig::default())
+        .generate(&module)
+        .unwrap();
+    let content = &generated.files[0].content;
+    assert!(content.contains("    MustViolation {\n"), "{}", content);
+    assert!(content.contains("pub fn must_compare("));
+    // No list has its entries counted
+    assert!(!content.contains("InvalidElementCount"));
+
+    // The container validates its entries, naming them by key
+    assert!(content.contains("impl Interfaces {\n    /// Check the `must` conditions"));
+    assert!(
+        content.contains("let predicate = must_predicate(&[(\"name\", must_value(&entry.name))]);")
+    );
+    assert!(content
+        .contains("entry.check_must(&format!(\"{}/interface{}\", path, predicate), errors);"));
+
+    // Conditions of the entry and of its leaves
+    assert!(content.contains("Some(\"IPv6 needs an MTU of at least 1280\")"));
+    assert!(content.contains(") || !(must_exists(&must_value(&self.ipv6))))"));
+    assert!(content.contains("if !(!must_exists(&must_value(&self.mtu))\n"));
+    assert!(content.contains("&serde_json::Value::from(68_i64),"));
+    assert!(content.contains("must_violation(&format!(\"{}/mtu\", path), \". >= 68\", None, None)"));
+    assert!(content.contains("for value in &self.alias {"));
+    assert!(content.contains("must_compare(&must_value(value), \"!=\", &must_value(&self.name))"));
+
+    // Unsupported conditions are listed instead of checked
+    assert!(content.contains("    /// - `count(../interface) < 10`\n"));
+
+    // Nothing without validation
+    let config = GeneratorConfig {
+        enable_validation: false,
+        ..GeneratorConfig::default()
+    };
+    let generated = crate::generator::CodeGenerator::new(config)
+        .generate(&module)
+        .unwrap();
+    assert!(!generated.files[0].content.contains("fn validate("));
+}
+
+#[test]
+fn test_element_counts_generate_validate() {
+    let module = crate::parser::YangParser::new()
+        .parse_string(
+            r#"
+            module dns {
+                namespace "urn:dns";
+                prefix dns;
+
+                container resolver {
+                    leaf-list search {
+                        type string;
+                        max-elements 6;
+                    }
+                    list server {
+                        key "name";
+                        min-elements 1;
+                        max-elements 3;
+                        leaf name { type string; }
+                    }
+                    leaf-list option {
+                        type string;
+                        min-elements 2;
+                        when "../search";
+                    }
+                }
+            }
+        "#,
+            "dns.yang",
+        )
+        .unwrap();
+
+    let generated = crate::generator::CodeGenerator::new(GeneratorConfig::default())
+        .generate(&module)
+        .unwrap();
+    let content = &generated.files[0].content;
+    assert!(
+        content.contains("    InvalidElementCount {\n"),
+        "{}",
+        content
+    );
+
+    // The bounds are documented on the fields
+    assert!(content.contains("    /// Holds at most 6 entries.\n    #[serde"));
+    assert!(content.contains("    /// Holds 1 to 3 entries.\n    #[serde"));
+
+    // and checked by validate
+    assert!(content.contains("impl Resolver {\n    /// Check the `must` conditions"));
+    assert!(content.contains("if self.search.len() > 6 {"));
+    assert!(content.contains("if self.server.is_empty() {"));
+    assert!(content.contains("if self.server.len() > 3 {"));
+    assert!(content.contains("path: format!(\"{}/server\", path),"));
+    assert!(content.contains("constraint: \"min-elements 1\".to_string(),"));
+
+    // Nodes with a when condition need not hold entries
+    assert!(content.contains("    /// Holds at least 2 entries.\n"));
+    assert!(!content.contains("self.option.len()"));
+
+    // Documented but not checked without validation
+    let config = GeneratorConfig {
+        enable_validation: false,
+        ..GeneratorConfig::default()
+    };
+    let generated = crate::generator::CodeGenerator::new(config)
+        .generate(&module)
+        .unwrap();
+    let content = &generated.files[0].content;
+    assert!(content.contains("    /// Holds 1 to 3 entries.\n"));
+    assert!(!content.contains("fn validate("));
+}
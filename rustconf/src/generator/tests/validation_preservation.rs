@@ -5,9 +5,11 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::generator::{CodeGenerator, GeneratorConfig, NamespaceMode};
+    use crate::generator::{
+        CodeGenerator, GeneratorConfig, NamespaceMode, NamespaceQualifier, Target,
+    };
     use crate::parser::{
-        DataNode, Leaf, LengthConstraint, LengthRange, Range, RangeConstraint, TypeSpec,
+        DataNode, Leaf, LengthConstraint, LengthRange, Range, RangeConstraint, Status, TypeSpec,
         YangModule, YangVersion,
     };
     use proptest::prelude::*;
@@ -152,6 +154,12 @@ mod tests {
                 mandatory: false,
                 config: true,
                 default: None,
+                units: None,
+                if_features: Vec::new(),
+                when: None,
+                must: Vec::new(),
+                unknown_statements: vec![],
+                status: Status::Current,
             };
 
             let module = YangModule {
@@ -159,12 +167,20 @@ mod tests {
                 namespace: format!("http://example.com/{}", module_name),
                 prefix: module_name[..2].to_string(),
                 yang_version: Some(YangVersion::V1_1),
+                organization: None,
+                contact: None,
+                description: None,
                 imports: Vec::new(),
+                features: Vec::new(),
                 typedefs: Vec::new(),
                 groupings: Vec::new(),
                 data_nodes: vec![DataNode::Leaf(leaf)],
+                augments: Vec::new(),
+                deviations: Vec::new(),
                 rpcs: Vec::new(),
                 notifications: Vec::new(),
+                extensions: vec![],
+                unknown_statements: vec![],
             };
 
             // Generate in single-file mode (OUT_DIR style)
@@ -178,10 +194,29 @@ mod tests {
                 modular_output: false,
                 derive_debug: true,
                 derive_clone: true,
+                generate_constructors: false,
                 enable_namespace_prefixes: false,
                 restful_namespace_mode: NamespaceMode::Enabled,
+                bare_rpc_bodies: false,
                 enable_server_generation: false,
                 server_output_subdir: "server".to_string(),
+                serde_rename_all: false,
+                int64_encoding: Default::default(),
+                decimal64_representation: Default::default(),
+                date_and_time_representation: Default::default(),
+                enable_pretty_printers: false,
+                enable_cli: false,
+                enable_dyn_client: false,
+                enable_netconf: false,
+                enable_payload_schema: false,
+                shared_helpers: false,
+                visibility: Default::default(),
+                list_item_names: Default::default(),
+                targets: Target::ALL.to_vec(),
+                enabled_features: None,
+                namespace_qualifier: NamespaceQualifier::default(),
+                prelude: None,
+                epilogue: None,
             };
 
             let generator_single = CodeGenerator::new(config_single);
@@ -200,10 +235,29 @@ mod tests {
                 modular_output: true,
                 derive_debug: true,
                 derive_clone: true,
+                generate_constructors: false,
                 enable_namespace_prefixes: false,
                 restful_namespace_mode: NamespaceMode::Enabled,
+                bare_rpc_bodies: false,
                 enable_server_generation: false,
                 server_output_subdir: "server".to_string(),
+                serde_rename_all: false,
+                int64_encoding: Default::default(),
+                decimal64_representation: Default::default(),
+                date_and_time_representation: Default::default(),
+                enable_pretty_printers: false,
+                enable_cli: false,
+                enable_dyn_client: false,
+                enable_netconf: false,
+                enable_payload_schema: false,
+                shared_helpers: false,
+                visibility: Default::default(),
+                list_item_names: Default::default(),
+                targets: Target::ALL.to_vec(),
+                enabled_features: None,
+                namespace_qualifier: NamespaceQualifier::default(),
+                prelude: None,
+                epilogue: None,
             };
 
             let generator_modular = CodeGenerator::new(config_modular);
@@ -245,12 +299,20 @@ mod tests {
                 namespace: format!("http://example.com/{}", module_name),
                 prefix: module_name[..2].to_string(),
                 yang_version: Some(YangVersion::V1_1),
+                organization: None,
+                contact: None,
+                description: None,
                 imports: Vec::new(),
+                features: Vec::new(),
                 typedefs: Vec::new(),
                 groupings: Vec::new(),
                 data_nodes: Vec::new(),
+                augments: Vec::new(),
+                deviations: Vec::new(),
                 rpcs: Vec::new(),
                 notifications: Vec::new(),
+                extensions: vec![],
+                unknown_statements: vec![],
             };
 
             // Generate in both modes
@@ -264,10 +326,29 @@ mod tests {
                 modular_output: false,
                 derive_debug: true,
                 derive_clone: true,
+                generate_constructors: false,
                 enable_namespace_prefixes: false,
                 restful_namespace_mode: NamespaceMode::Enabled,
+                bare_rpc_bodies: false,
                 enable_server_generation: false,
                 server_output_subdir: "server".to_string(),
+                serde_rename_all: false,
+                int64_encoding: Default::default(),
+                decimal64_representation: Default::default(),
+                date_and_time_representation: Default::default(),
+                enable_pretty_printers: false,
+                enable_cli: false,
+                enable_dyn_client: false,
+                enable_netconf: false,
+                enable_payload_schema: false,
+                shared_helpers: false,
+                visibility: Default::default(),
+                list_item_names: Default::default(),
+                targets: Target::ALL.to_vec(),
+                enabled_features: None,
+                namespace_qualifier: NamespaceQualifier::default(),
+                prelude: None,
+                epilogue: None,
             };
 
             let generator_single = CodeGenerator::new(config_single);
@@ -285,10 +366,29 @@ mod tests {
                 modular_output: true,
                 derive_debug: true,
                 derive_clone: true,
+                generate_constructors: false,
                 enable_namespace_prefixes: false,
                 restful_namespace_mode: NamespaceMode::Enabled,
+                bare_rpc_bodies: false,
                 enable_server_generation: false,
                 server_output_subdir: "server".to_string(),
+                serde_rename_all: false,
+                int64_encoding: Default::default(),
+                decimal64_representation: Default::default(),
+                date_and_time_representation: Default::default(),
+                enable_pretty_printers: false,
+                enable_cli: false,
+                enable_dyn_client: false,
+                enable_netconf: false,
+                enable_payload_schema: false,
+                shared_helpers: false,
+                visibility: Default::default(),
+                list_item_names: Default::default(),
+                targets: Target::ALL.to_vec(),
+                enabled_features: None,
+                namespace_qualifier: NamespaceQualifier::default(),
+                prelude: None,
+                epilogue: None,
             };
 
             let generator_modular = CodeGenerator::new(config_modular);
@@ -316,12 +416,20 @@ mod tests {
             namespace: "http://example.com/test".to_string(),
             prefix: "t".to_string(),
             yang_version: Some(YangVersion::V1_1),
+            organization: None,
+            contact: None,
+            description: None,
             imports: Vec::new(),
+            features: Vec::new(),
             typedefs: Vec::new(),
             groupings: Vec::new(),
             data_nodes: Vec::new(),
+            augments: Vec::new(),
+            deviations: Vec::new(),
             rpcs: Vec::new(),
             notifications: Vec::new(),
+            extensions: vec![],
+            unknown_statements: vec![],
         };
 
         let temp_dir = TempDir::new().unwrap();
@@ -334,10 +442,29 @@ mod tests {
             modular_output: true,
             derive_debug: true,
             derive_clone: true,
+            generate_constructors: false,
             enable_namespace_prefixes: false,
             restful_namespace_mode: NamespaceMode::Enabled,
+            bare_rpc_bodies: false,
             enable_server_generation: false,
             server_output_subdir: "server".to_string(),
+            serde_rename_all: false,
+            int64_encoding: Default::default(),
+            decimal64_representation: Default::default(),
+            date_and_time_representation: Default::default(),
+            enable_pretty_printers: false,
+            enable_cli: false,
+            enable_dyn_client: false,
+            enable_netconf: false,
+            enable_payload_schema: false,
+            shared_helpers: false,
+            visibility: Default::default(),
+            list_item_names: Default::default(),
+            targets: Target::ALL.to_vec(),
+            enabled_features: None,
+            namespace_qualifier: NamespaceQualifier::default(),
+            prelude: None,
+            epilogue: None,
         };
 
         let generator = CodeGenerator::new(config);
@@ -376,6 +503,12 @@ mod tests {
             mandatory: false,
             config: true,
             default: None,
+            units: None,
+            if_features: Vec::new(),
+            when: None,
+            must: Vec::new(),
+            unknown_statements: vec![],
+            status: Status::Current,
         };
 
         let module = YangModule {
@@ -383,12 +516,20 @@ mod tests {
             namespace: "http://example.com/test".to_string(),
             prefix: "t".to_string(),
             yang_version: Some(YangVersion::V1_1),
+            organization: None,
+            contact: None,
+            description: None,
             imports: Vec::new(),
+            features: Vec::new(),
             typedefs: Vec::new(),
             groupings: Vec::new(),
             data_nodes: vec![DataNode::Leaf(leaf)],
+            augments: Vec::new(),
+            deviations: Vec::new(),
             rpcs: Vec::new(),
             notifications: Vec::new(),
+            extensions: vec![],
+            unknown_statements: vec![],
         };
 
         let temp_dir = TempDir::new().unwrap();
@@ -401,10 +542,29 @@ mod tests {
             modular_output: true,
             derive_debug: true,
             derive_clone: true,
+            generate_constructors: false,
             enable_namespace_prefixes: false,
             restful_namespace_mode: NamespaceMode::Enabled,
+            bare_rpc_bodies: false,
             enable_server_generation: false,
             server_output_subdir: "server".to_string(),
+            serde_rename_all: false,
+            int64_encoding: Default::default(),
+            decimal64_representation: Default::default(),
+            date_and_time_representation: Default::default(),
+            enable_pretty_printers: false,
+            enable_cli: false,
+            enable_dyn_client: false,
+            enable_netconf: false,
+            enable_payload_schema: false,
+            shared_helpers: false,
+            visibility: Default::default(),
+            list_item_names: Default::default(),
+            targets: Target::ALL.to_vec(),
+            enabled_features: None,
+            namespace_qualifier: NamespaceQualifier::default(),
+            prelude: None,
+            epilogue: None,
         };
 
         let generator = CodeGenerator::new(config);
@@ -0,0 +1,88 @@
+//! Tests for the visibility of generated items.
+
+use super::{generate, module};
+use crate::generator::{CodeGenerator, GeneratorConfig, ItemVisibility, Visibility};
+
+fn restful(visibility: ItemVisibility) -> GeneratorConfig {
+    let mut config = GeneratorConfig::default();
+    config.enable_restful_rpcs().visibility(visibility);
+    config
+}
+
+#[test]
+fn test_everything_is_public_by_default() {
+    let code = generate(restful(ItemVisibility::default()));
+
+    assert!(code.contains("pub struct System {"));
+    assert!(code.contains("pub mod operations {"));
+    assert!(code.contains("pub mod consts {"));
+    assert!(!code.contains("pub(crate)"));
+}
+
+#[test]
+fn test_crate_private_operations_and_helpers() {
+    let code = generate(restful(ItemVisibility {
+        operations: Visibility::Crate,
+        helpers: Visibility::Crate,
+        ..ItemVisibility::default()
+    }));
+
+    assert!(code.contains("pub struct System {"));
+    assert!(code.contains("#[allow(dead_code)]\npub(crate) mod operations {"));
+    assert!(code.contains("#[allow(dead_code)]\npub(crate) mod consts {"));
+    assert!(code.contains("#[allow(dead_code)]\npub(crate) mod schema {"));
+    // Items inside the modules are bounded by the module's visibility
+    assert!(code.contains("    pub async fn reboot"));
+}
+
+#[test]
+fn test_crate_private_types() {
+    let code = generate(restful(ItemVisibility {
+        types: Visibility::Crate,
+        operations: Visibility::Crate,
+        helpers: Visibility::Public,
+    }));
+
+    assert!(code.contains("pub(crate) struct System {"));
+    assert!(code.contains("pub(crate) struct ValidatedUint16"));
+    assert!(code.contains("pub(crate) enum ValidationError"));
+    // Fields keep their visibility, bounded by the struct's
+    assert!(code.contains("pub hostname: Option<String>"));
+    assert!(code.contains("pub mod consts {"));
+    assert!(!code.contains("\npub struct"));
+}
+
+#[test]
+fn test_modular_declarations_and_re_exports() {
+    let mut config = restful(ItemVisibility {
+        operations: Visibility::Crate,
+        helpers: Visibility::Crate,
+        ..ItemVisibility::default()
+    });
+    config.modular_output = true;
+    let generated = CodeGenerator::new(config).generate(&module()).unwrap();
+    let mod_rs = &generated
+        .files
+        .iter()
+        .find(|file| file.path.ends_with("mod.rs"))
+        .unwrap()
+        .content;
+
+    assert!(mod_rs.contains("pub mod types;"));
+    assert!(mod_rs.contains("pub use types::*;"));
+    assert!(mod_rs.contains("pub(crate) mod operations;"));
+    assert!(mod_rs.contains("pub(crate) use operations::*;"));
+    assert!(mod_rs.contains("pub(crate) mod consts;"));
+    assert!(mod_rs.contains("pub(crate) use rustconf_runtime::{"));
+}
+
+#[test]
+fn test_types_cannot_be_less_visible_than_operations() {
+    let config = restful(ItemVisibility {
+        types: Visibility::Crate,
+        ..ItemVisibility::default()
+    });
+
+    let error = config.validate().unwrap_err();
+    assert!(error.contains("visibility.types"), "{}", error);
+}
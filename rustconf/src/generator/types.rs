@@ -3,7 +3,9 @@
 //! This module handles the generation of Rust types (structs, enums, type aliases)
 //! from YANG data definitions including containers, lists, choices, and typedefs.
 
-use crate::generator::{GeneratorConfig, GeneratorError};
+use crate::generator::docs;
+use crate::generator::restrictions::resolve_type;
+use crate::generator::{GeneratorConfig, GeneratorError, NamespaceQualifier};
 use crate::parser::{Case, Choice, Container, DataNode, List, TypeDef, YangModule};
 
 /// Generator for Rust type definitions from YANG data nodes.
@@ -44,6 +46,7 @@ impl<'a> TypeGenerator<'a> {
                     | TypeSpec::Uint16 { range: Some(_) }
                     | TypeSpec::Uint32 { range: Some(_) }
                     | TypeSpec::Uint64 { range: Some(_) }
+                    | TypeSpec::Decimal64 { range: Some(_), .. }
             );
 
         let target_type_str = if needs_validation {
@@ -65,10 +68,10 @@ impl<'a> TypeGenerator<'a> {
         })?;
 
         // Use the formatting module to generate the type alias
-        formatting::generate_type_alias(&type_name, target_type, typedef.description.as_deref())
-            .map_err(|e| {
-                GeneratorError::CodeGeneration(format!("Failed to generate type alias: {}", e))
-            })
+        let doc = docs::node_doc(typedef.description.as_deref(), "typedef", &typedef.name);
+        formatting::generate_type_alias(&type_name, target_type, Some(&doc)).map_err(|e| {
+            GeneratorError::CodeGeneration(format!("Failed to generate type alias: {}", e))
+        })
     }
 
     /// Generate code for a data node.
@@ -82,6 +85,7 @@ impl<'a> TypeGenerator<'a> {
             DataNode::List(list) => self.generate_list(list, module),
             DataNode::Leaf(_) => Ok(String::new()), // Leaves are handled as struct fields
             DataNode::LeafList(_) => Ok(String::new()), // Will be implemented later
+            DataNode::AnyData(_) => Ok(String::new()), // Content has no generated type
             DataNode::Choice(choice) => self.generate_choice(choice, module),
             DataNode::Case(_) => Ok(String::new()), // Cases are handled within choices
             DataNode::Uses(_) => Ok(String::new()), // Uses should be expanded during parsing
@@ -108,17 +112,28 @@ impl<'a> TypeGenerator<'a> {
 
         // Generate struct using formatting module
         let type_name = crate::generator::naming::to_type_name(&container.name);
-        let derives = self.get_derive_traits();
+        let mut derives = self.get_derive_traits();
+        let constructor = self.generate_constructor(&type_name, &fields, &mut derives);
+        let serde_attrs = self.apply_rename_all(&mut fields);
 
         let struct_code = formatting::generate_struct_with_serde(
             &type_name,
             fields,
             derives,
-            container.description.as_deref(),
+            serde_attrs,
+            Some(&docs::node_doc(
+                container.description.as_deref(),
+                "container",
+                &container.name,
+            )),
         )
         .map_err(|e| GeneratorError::CodeGeneration(format!("Failed to generate struct: {}", e)))?;
 
         output.push_str(&struct_code);
+        output.push_str(&constructor);
+        output.push_str(&self.generate_merge(&type_name, &container.children, None));
+        output.push_str(&self.generate_pretty(&type_name, &container.children, None, module));
+        output.push_str(&self.generate_validate(&type_name, &container.must, &container.children));
 
         // Recursively generate types for nested containers, lists, and choices
         for child in &container.children {
@@ -203,7 +218,10 @@ impl<'a> TypeGenerator<'a> {
             variants.push(EnumVariant {
                 name: variant_name,
                 data_type,
-                doc_comment: case.description.clone(),
+                doc_comment: Some(docs::with_when(
+                    docs::node_doc(case.description.as_deref(), "case", &case.name),
+                    case.when.as_deref(),
+                )),
             });
         }
 
@@ -217,7 +235,11 @@ impl<'a> TypeGenerator<'a> {
             variants,
             derives,
             serde_attrs,
-            choice.description.as_deref(),
+            Some(&docs::node_doc(
+                choice.description.as_deref(),
+                "choice",
+                &choice.name,
+            )),
         )
         .map_err(|e| GeneratorError::CodeGeneration(format!("Failed to generate enum: {}", e)))?;
 
@@ -272,17 +294,109 @@ impl<'a> TypeGenerator<'a> {
         // Generate struct definition
         let variant_name = crate::generator::naming::to_type_name(&case.name);
         let struct_name = format!("{}Data", variant_name);
-        let derives = self.get_derive_traits();
+        let mut derives = self.get_derive_traits();
+        let constructor = self.generate_constructor(&struct_name, &fields, &mut derives);
+        let serde_attrs = self.apply_rename_all(&mut fields);
 
-        formatting::generate_struct_with_serde(
+        let struct_code = formatting::generate_struct_with_serde(
             &struct_name,
             fields,
             derives,
-            case.description.as_deref(),
+            serde_attrs,
+            Some(&docs::node_doc(
+                case.description.as_deref(),
+                "case",
+                &case.name,
+            )),
         )
         .map_err(|e| {
             GeneratorError::CodeGeneration(format!("Failed to generate case struct: {}", e))
-        })
+        })?;
+
+        Ok(struct_code
+            + &constructor
+            + &self.generate_merge(&struct_name, &case.data_nodes, None)
+            + &self.generate_pretty(&struct_name, &case.data_nodes, None, module))
+    }
+
+    /// Derive `Default` for a struct without mandatory fields, or generate its
+    /// `new` constructor separated by a blank line, if constructors are enabled.
+    fn generate_constructor(
+        &self,
+        type_name: &str,
+        fields: &[crate::generator::formatting::StructField],
+        derives: &mut Vec<&'static str>,
+    ) -> String {
+        use crate::generator::constructors;
+
+        if !self.config.generate_constructors {
+            return String::new();
+        }
+        if constructors::can_derive_default(fields) {
+            // Before `Serialize, Deserialize`, which always come last
+            let position = derives.len() - 2;
+            derives.insert(position, "Default");
+            return String::new();
+        }
+        format!(
+            "\n{}",
+            constructors::generate_constructor(type_name, fields)
+        )
+    }
+
+    /// Generate the `merge` method for a struct, separated by a blank line.
+    ///
+    /// Merging clones values out of the other struct, so nothing is generated
+    /// unless `Clone` is derived.
+    fn generate_merge(
+        &self,
+        type_name: &str,
+        children: &[DataNode],
+        keys: Option<&[String]>,
+    ) -> String {
+        if !self.config.derive_clone {
+            return String::new();
+        }
+        format!(
+            "\n{}",
+            crate::generator::merge::generate_merge_impl(type_name, children, keys)
+        )
+    }
+
+    /// Generate the `validate` method for a struct with `must` conditions,
+    /// separated by a blank line, if validation is enabled.
+    fn generate_validate(
+        &self,
+        type_name: &str,
+        must: &[crate::parser::Must],
+        children: &[DataNode],
+    ) -> String {
+        if !self.config.enable_validation {
+            return String::new();
+        }
+        let validate = crate::generator::must::generate_validate_impl(type_name, must, children);
+        if validate.is_empty() {
+            return validate;
+        }
+        format!("\n{}", validate)
+    }
+
+    /// Generate the `Pretty` implementation for a struct, separated by a blank
+    /// line, if pretty printers are enabled.
+    fn generate_pretty(
+        &self,
+        type_name: &str,
+        children: &[DataNode],
+        keys: Option<&[String]>,
+        module: &YangModule,
+    ) -> String {
+        if !self.config.enable_pretty_printers {
+            return String::new();
+        }
+        format!(
+            "\n{}",
+            crate::generator::pretty::generate_pretty_impl(type_name, children, keys, module)
+        )
     }
 
     /// Generate a Rust struct and Vec type alias from a YANG list.
@@ -304,29 +418,62 @@ impl<'a> TypeGenerator<'a> {
         }
 
         // Generate struct definition for list items
-        let type_name = crate::generator::naming::to_type_name(&list.name);
-        // Remove trailing 's' for singular item type name if present
-        let item_type_name = if type_name.ends_with('s') && type_name.len() > 1 {
-            type_name[..type_name.len() - 1].to_string()
-        } else {
-            type_name.clone()
-        };
+        let item_type_name = self.config.item_type_name(&list.name);
 
-        let derives = self.get_derive_traits();
+        let mut derives = self.get_derive_traits();
+        let constructor = self.generate_constructor(&item_type_name, &fields, &mut derives);
+        let serde_attrs = self.apply_rename_all(&mut fields);
 
         let struct_code = formatting::generate_struct_with_serde(
             &item_type_name,
             fields,
             derives,
-            list.description.as_deref(),
+            serde_attrs,
+            Some(&docs::node_doc(
+                list.description.as_deref(),
+                "list",
+                &list.name,
+            )),
         )
         .map_err(|e| {
             GeneratorError::CodeGeneration(format!("Failed to generate list struct: {}", e))
         })?;
 
         output.push_str(&struct_code);
+        output.push_str(&constructor);
+        output.push_str(&self.generate_merge(&item_type_name, &list.children, Some(&list.keys)));
+        output.push_str(&self.generate_pretty(
+            &item_type_name,
+            &list.children,
+            Some(&list.keys),
+            module,
+        ));
+        output.push_str(&self.generate_validate(&item_type_name, &list.must, &list.children));
         output.push('\n');
 
+        // Key struct, if every key is a leaf of the entry
+        let key_types: Vec<String> = list
+            .keys
+            .iter()
+            .filter_map(|key| {
+                list.children.iter().find_map(|child| match child {
+                    DataNode::Leaf(leaf) if leaf.name == *key => {
+                        Some(self.generate_leaf_type(&leaf.type_spec, true))
+                    }
+                    _ => None,
+                })
+            })
+            .collect();
+        if !list.keys.is_empty() && key_types.len() == list.keys.len() {
+            output.push_str(&crate::generator::keys::generate_key_items(
+                list,
+                &item_type_name,
+                &key_types,
+                self.config.derive_debug,
+                self.config.derive_clone,
+            ));
+        }
+
         // Recursively generate types for nested containers, lists, and choices
         for child in &list.children {
             match child {
@@ -361,10 +508,13 @@ impl<'a> TypeGenerator<'a> {
             DataNode::Leaf(leaf) => {
                 let mut field = String::new();
 
-                // Add rustdoc comment if description exists
-                if let Some(ref description) = leaf.description {
-                    field.push_str(&format!("    {}", self.generate_rustdoc(description)));
-                }
+                field.push_str(&docs::rustdoc(
+                    &docs::with_when(
+                        docs::node_doc(leaf.description.as_deref(), "leaf", &leaf.name),
+                        leaf.when.as_deref(),
+                    ),
+                    "    ",
+                ));
 
                 // Check if this leaf is a key field
                 let is_key = keys.is_some_and(|k| k.contains(&leaf.name));
@@ -376,6 +526,12 @@ impl<'a> TypeGenerator<'a> {
                 if !is_key && !leaf.mandatory {
                     serde_attrs.push("skip_serializing_if = \"Option::is_none\"".to_string());
                 }
+                self.push_int64_serde_attrs(
+                    &mut serde_attrs,
+                    &leaf.type_spec,
+                    !is_key && !leaf.mandatory,
+                    module,
+                );
                 field.push_str(&format!("    #[serde({})]\n", serde_attrs.join(", ")));
 
                 // Generate field name and type
@@ -393,10 +549,17 @@ impl<'a> TypeGenerator<'a> {
             DataNode::Container(container) => {
                 let mut field = String::new();
 
-                // Add rustdoc comment if description exists
-                if let Some(ref description) = container.description {
-                    field.push_str(&format!("    {}", self.generate_rustdoc(description)));
-                }
+                field.push_str(&docs::rustdoc(
+                    &docs::with_when(
+                        docs::node_doc(
+                            container.description.as_deref(),
+                            "container",
+                            &container.name,
+                        ),
+                        container.when.as_deref(),
+                    ),
+                    "    ",
+                ));
 
                 // Build serde attributes
                 let field_name_json = self.get_json_field_name(&container.name, module);
@@ -421,24 +584,26 @@ impl<'a> TypeGenerator<'a> {
             DataNode::List(list) => {
                 let mut field = String::new();
 
-                // Add rustdoc comment if description exists
-                if let Some(ref description) = list.description {
-                    field.push_str(&format!("    {}", self.generate_rustdoc(description)));
-                }
+                field.push_str(&docs::rustdoc(
+                    &docs::with_when(
+                        docs::with_elements(
+                            docs::node_doc(list.description.as_deref(), "list", &list.name),
+                            list.min_elements,
+                            list.max_elements,
+                        ),
+                        list.when.as_deref(),
+                    ),
+                    "    ",
+                ));
 
                 // Build serde attributes
                 let field_name_json = self.get_json_field_name(&list.name, module);
-                field.push_str(&format!("    #[serde(rename = \"{}\")]\n", field_name_json));
+                let serde_attrs = self.collection_serde_attrs(&field_name_json);
+                field.push_str(&format!("    #[serde({})]\n", serde_attrs.join(", ")));
 
                 // Generate field name and type
                 let field_name = crate::generator::naming::to_field_name(&list.name);
-                let type_name = crate::generator::naming::to_type_name(&list.name);
-                // Determine item type name (singular)
-                let item_type_name = if type_name.ends_with('s') && type_name.len() > 1 {
-                    &type_name[..type_name.len() - 1]
-                } else {
-                    &type_name
-                };
+                let item_type_name = self.config.item_type_name(&list.name);
                 // Lists are always collections (Vec)
                 field.push_str(&format!(
                     "    pub {}: Vec<{}>,\n",
@@ -447,14 +612,78 @@ impl<'a> TypeGenerator<'a> {
 
                 Ok(field)
             }
-            DataNode::LeafList(_) => Ok(String::new()), // Will be implemented later
-            DataNode::Choice(choice) => {
+            DataNode::AnyData(anydata) => {
                 let mut field = String::new();
 
-                // Add rustdoc comment if description exists
-                if let Some(ref description) = choice.description {
-                    field.push_str(&format!("    {}", self.generate_rustdoc(description)));
+                field.push_str(&docs::rustdoc(
+                    &docs::with_when(
+                        docs::node_doc(
+                            anydata.description.as_deref(),
+                            anydata.kind.keyword(),
+                            &anydata.name,
+                        ),
+                        anydata.when.as_deref(),
+                    ),
+                    "    ",
+                ));
+
+                // Build serde attributes
+                let field_name_json = self.get_json_field_name(&anydata.name, module);
+                let mut serde_attrs = vec![format!("rename = \"{}\"", field_name_json)];
+                if !anydata.mandatory {
+                    serde_attrs.push("skip_serializing_if = \"Option::is_none\"".to_string());
                 }
+                field.push_str(&format!("    #[serde({})]\n", serde_attrs.join(", ")));
+
+                // Generate field name and type
+                let field_name = crate::generator::naming::to_field_name(&anydata.name);
+                let field_type = anydata_type(anydata.mandatory);
+                field.push_str(&format!("    pub {}: {},\n", field_name, field_type));
+
+                Ok(field)
+            }
+            DataNode::LeafList(leaf_list) => {
+                let mut field = String::new();
+
+                field.push_str(&docs::rustdoc(
+                    &docs::with_when(
+                        docs::with_elements(
+                            docs::node_doc(
+                                leaf_list.description.as_deref(),
+                                "leaf-list",
+                                &leaf_list.name,
+                            ),
+                            leaf_list.min_elements,
+                            leaf_list.max_elements,
+                        ),
+                        leaf_list.when.as_deref(),
+                    ),
+                    "    ",
+                ));
+
+                // Build serde attributes
+                let field_name_json = self.get_json_field_name(&leaf_list.name, module);
+                let mut serde_attrs = self.collection_serde_attrs(&field_name_json);
+                self.push_int64_serde_attrs(&mut serde_attrs, &leaf_list.type_spec, false, module);
+                field.push_str(&format!("    #[serde({})]\n", serde_attrs.join(", ")));
+
+                // Generate field name and type
+                let field_name = crate::generator::naming::to_field_name(&leaf_list.name);
+                let item_type = self.generate_leaf_type(&leaf_list.type_spec, true);
+                field.push_str(&format!("    pub {}: Vec<{}>,\n", field_name, item_type));
+
+                Ok(field)
+            }
+            DataNode::Choice(choice) => {
+                let mut field = String::new();
+
+                field.push_str(&docs::rustdoc(
+                    &docs::with_when(
+                        docs::node_doc(choice.description.as_deref(), "choice", &choice.name),
+                        choice.when.as_deref(),
+                    ),
+                    "    ",
+                ));
 
                 // Build serde attributes
                 let field_name_json = self.get_json_field_name(&choice.name, module);
@@ -499,6 +728,14 @@ impl<'a> TypeGenerator<'a> {
             }
         }
 
+        if let Some(bits_type_name) = self.bits_type_name(type_spec) {
+            if mandatory {
+                return bits_type_name;
+            } else {
+                return format!("Option<{}>", bits_type_name);
+            }
+        }
+
         let base_type = match type_spec {
             TypeSpec::Int8 { .. } => "i8",
             TypeSpec::Int16 { .. } => "i16",
@@ -508,14 +745,26 @@ impl<'a> TypeGenerator<'a> {
             TypeSpec::Uint16 { .. } => "u16",
             TypeSpec::Uint32 { .. } => "u32",
             TypeSpec::Uint64 { .. } => "u64",
+            TypeSpec::Decimal64 {
+                fraction_digits, ..
+            } => &self
+                .config
+                .decimal64_representation
+                .rust_type(*fraction_digits),
             TypeSpec::String { .. } => "String",
+            TypeSpec::DateAndTime { .. } => self
+                .config
+                .date_and_time_representation
+                .rust_type()
+                .unwrap_or("String"),
             TypeSpec::Boolean => "bool",
             TypeSpec::Empty => "()",
             TypeSpec::Binary { .. } => "Vec<u8>",
             TypeSpec::Enumeration { .. } => "String", // Will be improved in later tasks
+            TypeSpec::Bits { .. } => "String",        // Positions beyond a u64
             TypeSpec::Union { .. } => "String",       // Will be improved in later tasks
             TypeSpec::LeafRef { .. } => "String",     // Will be improved in later tasks
-            TypeSpec::TypedefRef { name } => {
+            TypeSpec::TypedefRef { name, .. } => {
                 // Use the typedef name as the type
                 &crate::generator::naming::to_type_name(name)
             }
@@ -541,6 +790,7 @@ impl<'a> TypeGenerator<'a> {
             TypeSpec::Uint16 { range } => range.is_some(),
             TypeSpec::Uint32 { range } => range.is_some(),
             TypeSpec::Uint64 { range } => range.is_some(),
+            TypeSpec::Decimal64 { range, .. } => range.is_some(),
             TypeSpec::String { length, pattern } => length.is_some() || pattern.is_some(),
             TypeSpec::Binary { length } => length.is_some(),
             _ => false,
@@ -576,6 +826,9 @@ impl<'a> TypeGenerator<'a> {
             TypeSpec::Uint64 { range } if range.is_some() => {
                 format!("ValidatedUint64Id{}", self.constraint_hash(type_spec))
             }
+            TypeSpec::Decimal64 { range, .. } if range.is_some() => {
+                format!("ValidatedDecimal64Id{}", self.constraint_hash(type_spec))
+            }
             TypeSpec::String { length, pattern } if length.is_some() || pattern.is_some() => {
                 format!("ValidatedStringId{}", self.constraint_hash(type_spec))
             }
@@ -586,6 +839,21 @@ impl<'a> TypeGenerator<'a> {
         }
     }
 
+    /// Get the name of the generated type of a `bits` type, or `None` if it
+    /// is not a `bits` type or has a position beyond the 64 bits of a `u64`.
+    pub fn bits_type_name(&self, type_spec: &crate::parser::TypeSpec) -> Option<String> {
+        use crate::parser::TypeSpec;
+
+        match type_spec {
+            TypeSpec::Bits { bits }
+                if bits.iter().all(|bit| bit.position.is_some_and(|p| p < 64)) =>
+            {
+                Some(format!("BitsId{}", self.constraint_hash(type_spec)))
+            }
+            _ => None,
+        }
+    }
+
     /// Generate a hash for constraint uniqueness.
     pub fn constraint_hash(&self, type_spec: &crate::parser::TypeSpec) -> String {
         use crate::parser::TypeSpec;
@@ -608,6 +876,16 @@ impl<'a> TypeGenerator<'a> {
                     range.max.hash(&mut hasher);
                 }
             }
+            TypeSpec::Decimal64 {
+                fraction_digits,
+                range,
+            } => {
+                fraction_digits.hash(&mut hasher);
+                for range in range.iter().flat_map(|r| &r.ranges) {
+                    range.min.hash(&mut hasher);
+                    range.max.hash(&mut hasher);
+                }
+            }
             TypeSpec::String { length, pattern } => {
                 if let Some(l) = length {
                     for len in &l.lengths {
@@ -615,8 +893,8 @@ impl<'a> TypeGenerator<'a> {
                         len.max.hash(&mut hasher);
                     }
                 }
-                if let Some(p) = pattern {
-                    p.pattern.hash(&mut hasher);
+                for p in pattern.iter().flat_map(|p| p.patterns()) {
+                    p.hash(&mut hasher);
                 }
             }
             TypeSpec::Binary { length: Some(l) } => {
@@ -625,38 +903,116 @@ impl<'a> TypeGenerator<'a> {
                     len.max.hash(&mut hasher);
                 }
             }
+            TypeSpec::Bits { bits } => {
+                for bit in bits {
+                    bit.name.hash(&mut hasher);
+                    bit.position.hash(&mut hasher);
+                }
+            }
             _ => {}
         }
 
         format!("{:x}", hasher.finish())
     }
 
-    /// Generate rustdoc comments from a YANG description.
-    fn generate_rustdoc(&self, description: &str) -> String {
-        let mut rustdoc = String::new();
+    /// Get the JSON field name for a YANG node, with optional namespace qualifier.
+    ///
+    /// Generated struct fields are children of another node from the same module, so
+    /// RFC 7951 requires no qualifier, except for nodes added by another module's
+    /// augment, which are already named `module:name`. The legacy
+    /// `NamespaceQualifier::Prefix` mode prefixes every other member with the module
+    /// prefix.
+    pub(crate) fn get_json_field_name(&self, yang_name: &str, module: &YangModule) -> String {
+        if yang_name.contains(':') {
+            return yang_name.to_string();
+        }
+        match self.config.namespace_qualifier {
+            NamespaceQualifier::Prefix if self.config.enable_namespace_prefixes => {
+                format!("{}:{}", module.prefix, yang_name)
+            }
+            _ => yang_name.to_string(),
+        }
+    }
 
-        // Split description into lines and format as rustdoc comments
-        for line in description.lines() {
-            let trimmed = line.trim();
-            if trimmed.is_empty() {
-                rustdoc.push_str("///\n");
-            } else {
-                rustdoc.push_str(&format!("/// {}\n", trimmed));
+    /// Add the serde attributes that string-encode 64-bit integers, if
+    /// configured, `decimal64` values represented as `rust_decimal` and
+    /// `date-and-time` values represented as `chrono` date-times.
+    ///
+    /// Applies to leaves and leaf-lists that are plain `i64`, `u64`,
+    /// `rust_decimal::Decimal` or `chrono::DateTime`, possibly through typedefs. Range-validated
+    /// types and the generated scaled-integer types apply the encoding in
+    /// their own serde implementations.
+    pub(crate) fn push_int64_serde_attrs(
+        &self,
+        serde_attrs: &mut Vec<String>,
+        type_spec: &crate::parser::TypeSpec,
+        optional: bool,
+        module: &YangModule,
+    ) {
+        use crate::parser::TypeSpec;
+
+        let Some(type_spec) = resolve_type(type_spec, module).type_spec else {
+            return;
+        };
+        let serde_module = match type_spec {
+            TypeSpec::Int64 { .. } | TypeSpec::Uint64 { .. } => {
+                self.config.int64_encoding.serde_module()
             }
+            TypeSpec::Decimal64 { .. } => self.config.decimal64_representation.serde_module(),
+            TypeSpec::DateAndTime { .. } => self.config.date_and_time_representation.serde_module(),
+            _ => None,
+        };
+        let Some(serde_module) = serde_module else {
+            return;
+        };
+        if self.config.enable_validation && self.needs_validation(&type_spec) {
+            return;
         }
+        // `with` disables serde's implicit `None` for missing Option fields
+        if optional {
+            serde_attrs.push("default".to_string());
+        }
+        serde_attrs.push(format!("with = \"{}\"", serde_module));
+    }
 
-        rustdoc
+    /// Serde attributes for list and leaf-list fields.
+    ///
+    /// Servers omit empty lists entirely, so the field defaults to an empty `Vec`
+    /// when absent and is left out when serializing an empty `Vec`.
+    fn collection_serde_attrs(&self, field_name_json: &str) -> Vec<String> {
+        vec![
+            format!("rename = \"{}\"", field_name_json),
+            "default".to_string(),
+            "skip_serializing_if = \"Vec::is_empty\"".to_string(),
+        ]
     }
 
-    /// Get the JSON field name for a YANG node, with optional namespace prefix.
+    /// Replace per-field renames with a struct-level `rename_all` when configured.
     ///
-    /// For RESTCONF JSON compliance (RFC 8040), field names can be prefixed with
-    /// the module prefix when namespace prefixes are enabled.
-    fn get_json_field_name(&self, yang_name: &str, module: &YangModule) -> String {
-        if self.config.enable_namespace_prefixes {
-            format!("{}:{}", module.prefix, yang_name)
+    /// Renames that match the kebab-case form of the field name are dropped; any other
+    /// rename (keyword-escaped fields, names with underscores, namespace-qualified names)
+    /// is kept as an exception.
+    /// Returns the struct-level serde attributes to emit.
+    fn apply_rename_all(
+        &self,
+        fields: &mut [crate::generator::formatting::StructField],
+    ) -> Vec<&'static str> {
+        if !self.config.serde_rename_all {
+            return vec![];
+        }
+
+        let mut renamed = false;
+        for field in fields.iter_mut() {
+            let implied = format!("rename = \"{}\"", field.name.replace('_', "-"));
+            let before = field.serde_attrs.len();
+            field.serde_attrs.retain(|attr| *attr != implied);
+            renamed |= field.serde_attrs.len() != before;
+        }
+
+        if renamed {
+            vec![r#"rename_all = "kebab-case""#]
         } else {
-            yang_name.to_string()
+            vec![]
         }
     }
 
@@ -697,6 +1053,12 @@ impl<'a> TypeGenerator<'a> {
                 if !is_key && !leaf.mandatory {
                     serde_attrs.push("skip_serializing_if = \"Option::is_none\"".to_string());
                 }
+                self.push_int64_serde_attrs(
+                    &mut serde_attrs,
+                    &leaf.type_spec,
+                    !is_key && !leaf.mandatory,
+                    module,
+                );
 
                 // Generate field name and type
                 let field_name = crate::generator::naming::to_field_name(&leaf.name);
@@ -719,7 +1081,10 @@ impl<'a> TypeGenerator<'a> {
                     name: field_name,
                     ty: field_type,
                     serde_attrs,
-                    doc_comment: leaf.description.clone(),
+                    doc_comment: Some(docs::with_when(
+                        docs::node_doc(leaf.description.as_deref(), "leaf", &leaf.name),
+                        leaf.when.as_deref(),
+                    )),
                 }))
             }
             DataNode::Container(container) => {
@@ -750,24 +1115,25 @@ impl<'a> TypeGenerator<'a> {
                     name: field_name,
                     ty: field_type,
                     serde_attrs,
-                    doc_comment: container.description.clone(),
+                    doc_comment: Some(docs::with_when(
+                        docs::node_doc(
+                            container.description.as_deref(),
+                            "container",
+                            &container.name,
+                        ),
+                        container.when.as_deref(),
+                    )),
                 }))
             }
             DataNode::List(list) => {
                 // Build serde attributes
                 let field_name_json = self.get_json_field_name(&list.name, module);
-                let serde_attrs = vec![format!("rename = \"{}\"", field_name_json)];
+                let serde_attrs = self.collection_serde_attrs(&field_name_json);
 
                 // Generate field name and type
                 let field_name = crate::generator::naming::to_field_name(&list.name);
-                let type_name = crate::generator::naming::to_type_name(&list.name);
 
-                // Determine item type name (singular)
-                let item_type_name = if type_name.ends_with('s') && type_name.len() > 1 {
-                    &type_name[..type_name.len() - 1]
-                } else {
-                    &type_name
-                };
+                let item_type_name = self.config.item_type_name(&list.name);
 
                 let field_type_str = format!("Vec<{}>", item_type_name);
                 let field_type: syn::Type = syn::parse_str(&field_type_str).map_err(|e| {
@@ -781,7 +1147,82 @@ impl<'a> TypeGenerator<'a> {
                     name: field_name,
                     ty: field_type,
                     serde_attrs,
-                    doc_comment: list.description.clone(),
+                    doc_comment: Some(docs::with_when(
+                        docs::with_elements(
+                            docs::node_doc(list.description.as_deref(), "list", &list.name),
+                            list.min_elements,
+                            list.max_elements,
+                        ),
+                        list.when.as_deref(),
+                    )),
+                }))
+            }
+            DataNode::AnyData(anydata) => {
+                // Build serde attributes
+                let field_name_json = self.get_json_field_name(&anydata.name, module);
+                let mut serde_attrs = vec![format!("rename = \"{}\"", field_name_json)];
+                if !anydata.mandatory {
+                    serde_attrs.push("skip_serializing_if = \"Option::is_none\"".to_string());
+                }
+
+                // Generate field name and type
+                let field_name = crate::generator::naming::to_field_name(&anydata.name);
+                let field_type_str = anydata_type(anydata.mandatory);
+                let field_type: syn::Type = syn::parse_str(&field_type_str).map_err(|e| {
+                    GeneratorError::CodeGeneration(format!(
+                        "Failed to parse field type '{}': {}",
+                        field_type_str, e
+                    ))
+                })?;
+
+                Ok(Some(StructField {
+                    name: field_name,
+                    ty: field_type,
+                    serde_attrs,
+                    doc_comment: Some(docs::with_when(
+                        docs::node_doc(
+                            anydata.description.as_deref(),
+                            anydata.kind.keyword(),
+                            &anydata.name,
+                        ),
+                        anydata.when.as_deref(),
+                    )),
+                }))
+            }
+            DataNode::LeafList(leaf_list) => {
+                // Build serde attributes
+                let field_name_json = self.get_json_field_name(&leaf_list.name, module);
+                let mut serde_attrs = self.collection_serde_attrs(&field_name_json);
+                self.push_int64_serde_attrs(&mut serde_attrs, &leaf_list.type_spec, false, module);
+
+                // Generate field name and type
+                let field_name = crate::generator::naming::to_field_name(&leaf_list.name);
+                let item_type = self.generate_leaf_type(&leaf_list.type_spec, true);
+
+                let field_type_str = format!("Vec<{}>", item_type);
+                let field_type: syn::Type = syn::parse_str(&field_type_str).map_err(|e| {
+                    GeneratorError::CodeGeneration(format!(
+                        "Failed to parse field type '{}': {}",
+                        field_type_str, e
+                    ))
+                })?;
+
+                Ok(Some(StructField {
+                    name: field_name,
+                    ty: field_type,
+                    serde_attrs,
+                    doc_comment: Some(docs::with_when(
+                        docs::with_elements(
+                            docs::node_doc(
+                                leaf_list.description.as_deref(),
+                                "leaf-list",
+                                &leaf_list.name,
+                            ),
+                            leaf_list.min_elements,
+                            leaf_list.max_elements,
+                        ),
+                        leaf_list.when.as_deref(),
+                    )),
                 }))
             }
             DataNode::Choice(choice) => {
@@ -812,10 +1253,22 @@ impl<'a> TypeGenerator<'a> {
                     name: field_name,
                     ty: field_type,
                     serde_attrs,
-                    doc_comment: choice.description.clone(),
+                    doc_comment: Some(docs::with_when(
+                        docs::node_doc(choice.description.as_deref(), "choice", &choice.name),
+                        choice.when.as_deref(),
+                    )),
                 }))
             }
             _ => Ok(None), // Other node types don't generate fields
         }
     }
 }
+
+/// The field type of an `anydata` or `anyxml` node.
+fn anydata_type(mandatory: bool) -> String {
+    if mandatory {
+        "rustconf_runtime::AnyData".to_string()
+    } else {
+        "Option<rustconf_runtime::AnyData>".to_string()
+    }
+}
@@ -1,6 +1,9 @@
 //! Validation type generation for constrained YANG types.
 
-use crate::parser::{LengthConstraint, PatternConstraint, RangeConstraint, TypeSpec};
+use crate::generator::config::{Decimal64Representation, Int64Encoding};
+use crate::parser::{
+    format_decimal64, LengthConstraint, PatternConstraint, RangeConstraint, TypeSpec,
+};
 
 /// Generate a validated type wrapper for a constrained type.
 pub fn generate_validated_type(
@@ -8,6 +11,8 @@ pub fn generate_validated_type(
     type_spec: &TypeSpec,
     derive_debug: bool,
     derive_clone: bool,
+    int64_encoding: Int64Encoding,
+    decimal64_representation: Decimal64Representation,
 ) -> Option<String> {
     match type_spec {
         TypeSpec::Int8 { range: Some(range) } => Some(generate_range_validated_type(
@@ -16,6 +21,7 @@ pub fn generate_validated_type(
             range,
             derive_debug,
             derive_clone,
+            None,
         )),
         TypeSpec::Int16 { range: Some(range) } => Some(generate_range_validated_type(
             type_name,
@@ -23,6 +29,7 @@ pub fn generate_validated_type(
             range,
             derive_debug,
             derive_clone,
+            None,
         )),
         TypeSpec::Int32 { range: Some(range) } => Some(generate_range_validated_type(
             type_name,
@@ -30,6 +37,7 @@ pub fn generate_validated_type(
             range,
             derive_debug,
             derive_clone,
+            None,
         )),
         TypeSpec::Int64 { range: Some(range) } => Some(generate_range_validated_type(
             type_name,
@@ -37,6 +45,7 @@ pub fn generate_validated_type(
             range,
             derive_debug,
             derive_clone,
+            int64_encoding.serde_module(),
         )),
         TypeSpec::Uint8 { range: Some(range) } => Some(generate_range_validated_type(
             type_name,
@@ -44,6 +53,7 @@ pub fn generate_validated_type(
             range,
             derive_debug,
             derive_clone,
+            None,
         )),
         TypeSpec::Uint16 { range: Some(range) } => Some(generate_range_validated_type(
             type_name,
@@ -51,6 +61,7 @@ pub fn generate_validated_type(
             range,
             derive_debug,
             derive_clone,
+            None,
         )),
         TypeSpec::Uint32 { range: Some(range) } => Some(generate_range_validated_type(
             type_name,
@@ -58,6 +69,7 @@ pub fn generate_validated_type(
             range,
             derive_debug,
             derive_clone,
+            None,
         )),
         TypeSpec::Uint64 { range: Some(range) } => Some(generate_range_validated_type(
             type_name,
@@ -65,6 +77,18 @@ pub fn generate_validated_type(
             range,
             derive_debug,
             derive_clone,
+            int64_encoding.serde_module(),
+        )),
+        TypeSpec::Decimal64 {
+            fraction_digits,
+            range: Some(range),
+        } => Some(generate_decimal64_validated_type(
+            type_name,
+            *fraction_digits,
+            range,
+            derive_debug,
+            derive_clone,
+            decimal64_representation,
         )),
         TypeSpec::String { length, pattern } => {
             if length.is_some() || pattern.is_some() {
@@ -92,12 +116,16 @@ pub fn generate_validated_type(
 }
 
 /// Generate a range-validated numeric type.
+///
+/// `serde_module` names a `serialize_with`/`deserialize_with` module for the
+/// inner value, used for 64-bit types encoded as JSON strings.
 fn generate_range_validated_type(
     type_name: &str,
     base_type: &str,
     range: &RangeConstraint,
     derive_debug: bool,
     derive_clone: bool,
+    serde_module: Option<&str>,
 ) -> String {
     let mut output = String::new();
 
@@ -173,6 +201,7 @@ fn generate_range_validated_type(
 
     // Generate value getter
     output.push_str("    /// Get the inner value.\n");
+    output.push_str("    #[must_use]\n");
     output.push_str(&format!("    pub fn value(&self) -> {} {{\n", base_type));
     output.push_str("        self.value\n");
     output.push_str("    }\n");
@@ -198,7 +227,13 @@ fn generate_range_validated_type(
     output.push_str("    where\n");
     output.push_str("        S: serde::Serializer,\n");
     output.push_str("    {\n");
-    output.push_str("        self.value.serialize(serializer)\n");
+    match serde_module {
+        Some(module) => output.push_str(&format!(
+            "        {}::serialize(&self.value, serializer)\n",
+            module
+        )),
+        None => output.push_str("        self.value.serialize(serializer)\n"),
+    }
     output.push_str("    }\n");
     output.push_str("}\n\n");
 
@@ -211,10 +246,173 @@ fn generate_range_validated_type(
     output.push_str("    where\n");
     output.push_str("        D: serde::Deserializer<'de>,\n");
     output.push_str("    {\n");
+    match serde_module {
+        Some(module) => output.push_str(&format!(
+            "        let value: {} = {}::deserialize(deserializer)?;\n",
+            base_type, module
+        )),
+        None => output.push_str(&format!(
+            "        let value = {}::deserialize(deserializer)?;\n",
+            base_type
+        )),
+    }
+    output.push_str("        Self::new(value).map_err(serde::de::Error::custom)\n");
+    output.push_str("    }\n");
+    output.push_str("}\n");
+
+    output
+}
+
+/// Generate a range-validated `decimal64` type.
+///
+/// The ranges are scaled by 10^`fraction_digits`, as parsed.
+fn generate_decimal64_validated_type(
+    type_name: &str,
+    fraction_digits: u8,
+    range: &RangeConstraint,
+    derive_debug: bool,
+    derive_clone: bool,
+    representation: Decimal64Representation,
+) -> String {
+    let base_type = representation.rust_type(fraction_digits);
+    let decimal = |scaled: i64| format_decimal64(scaled, fraction_digits);
+    let mut output = String::new();
+
+    // Generate rustdoc comment
+    output.push_str(&format!(
+        "/// Validated decimal64 type with {} fraction digits and range constraints.\n",
+        fraction_digits
+    ));
+    output.push_str("///\n");
+    output.push_str("/// Allowed ranges:\n");
+    for r in &range.ranges {
+        output.push_str(&format!("/// - {} to {}\n", decimal(r.min), decimal(r.max)));
+    }
+
+    // Generate derive attributes
+    let mut derives = vec!["PartialEq", "Eq"];
+    if derive_debug {
+        derives.insert(0, "Debug");
+    }
+    if derive_clone {
+        derives.insert(if derive_debug { 1 } else { 0 }, "Clone");
+    }
+    output.push_str(&format!("#[derive({})]\n", derives.join(", ")));
+
+    output.push_str(&format!("pub struct {} {{\n", type_name));
+    output.push_str(&format!("    value: {},\n", base_type));
+    output.push_str("}\n\n");
+
+    // Generate implementation
+    output.push_str(&format!("impl {} {{\n", type_name));
+
+    // Generate new method with validation
+    output.push_str("    /// Create a new validated value.\n");
+    output.push_str("    ///\n");
+    output.push_str("    /// # Errors\n");
+    output.push_str("    ///\n");
+    output.push_str("    /// Returns `ValidationError::OutOfRange` if the value is outside the allowed ranges.\n");
+    output.push_str(&format!(
+        "    pub fn new(value: {}) -> Result<Self, ValidationError> {{\n",
+        base_type
+    ));
+
+    // Generate validation logic
+    output.push_str("        let valid = ");
+    let range_checks: Vec<String> = range
+        .ranges
+        .iter()
+        .map(|r| {
+            format!(
+                "({}..={}).contains(&value)",
+                representation.literal(r.min, fraction_digits),
+                representation.literal(r.max, fraction_digits)
+            )
+        })
+        .collect();
+    output.push_str(&range_checks.join(" || "));
+    output.push_str(";\n\n");
+
+    output.push_str("        if valid {\n");
+    output.push_str("            Ok(Self { value })\n");
+    output.push_str("        } else {\n");
+    output.push_str("            Err(ValidationError::OutOfRange {\n");
+    output.push_str("                value: value.to_string(),\n");
+
+    // Generate constraint string
+    let constraint_str = range
+        .ranges
+        .iter()
+        .map(|r| format!("{}..{}", decimal(r.min), decimal(r.max)))
+        .collect::<Vec<_>>()
+        .join(" | ");
+    output.push_str(&format!(
+        "                constraint: \"{}\".to_string(),\n",
+        constraint_str
+    ));
+    output.push_str("            })\n");
+    output.push_str("        }\n");
+    output.push_str("    }\n\n");
+
+    // Generate value getter
+    output.push_str("    /// Get the inner value.\n");
+    output.push_str("    #[must_use]\n");
+    output.push_str(&format!("    pub fn value(&self) -> {} {{\n", base_type));
+    output.push_str("        self.value\n");
+    output.push_str("    }\n");
+    output.push_str("}\n\n");
+
+    // Generate TryFrom implementation
+    output.push_str(&format!(
+        "impl TryFrom<{}> for {} {{\n",
+        base_type, type_name
+    ));
+    output.push_str("    type Error = ValidationError;\n\n");
     output.push_str(&format!(
-        "        let value = {}::deserialize(deserializer)?;\n",
+        "    fn try_from(value: {}) -> Result<Self, Self::Error> {{\n",
         base_type
     ));
+    output.push_str("        Self::new(value)\n");
+    output.push_str("    }\n");
+    output.push_str("}\n\n");
+
+    let serde_module = representation.serde_module();
+
+    // Generate Serialize implementation
+    output.push_str(&format!("impl serde::Serialize for {} {{\n", type_name));
+    output.push_str("    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>\n");
+    output.push_str("    where\n");
+    output.push_str("        S: serde::Serializer,\n");
+    output.push_str("    {\n");
+    match serde_module {
+        Some(module) => output.push_str(&format!(
+            "        {}::serialize(&self.value, serializer)\n",
+            module
+        )),
+        None => output.push_str("        self.value.serialize(serializer)\n"),
+    }
+    output.push_str("    }\n");
+    output.push_str("}\n\n");
+
+    // Generate Deserialize implementation with validation
+    output.push_str(&format!(
+        "impl<'de> serde::Deserialize<'de> for {} {{\n",
+        type_name
+    ));
+    output.push_str("    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>\n");
+    output.push_str("    where\n");
+    output.push_str("        D: serde::Deserializer<'de>,\n");
+    output.push_str("    {\n");
+    match serde_module {
+        Some(module) => output.push_str(&format!(
+            "        let value: {} = {}::deserialize(deserializer)?;\n",
+            base_type, module
+        )),
+        None => output.push_str(&format!(
+            "        let value = {}::deserialize(deserializer)?;\n",
+            base_type
+        )),
+    }
     output.push_str("        Self::new(value).map_err(serde::de::Error::custom)\n");
     output.push_str("    }\n");
     output.push_str("}\n");
@@ -242,8 +440,8 @@ fn generate_string_validated_type(
         }
         output.push_str("///\n");
     }
-    if let Some(pat) = pattern {
-        output.push_str(&format!("/// Pattern: {}\n", pat.pattern));
+    for pat in pattern.iter().flat_map(|p| p.patterns()) {
+        output.push_str(&format!("/// Pattern: {}\n", pat));
     }
 
     // Generate derive attributes
@@ -282,15 +480,12 @@ fn generate_string_validated_type(
         ));
     }
 
-    // Generate pattern validation
-    if let Some(pat) = pattern {
+    // Generate pattern validation; the value must match every pattern
+    for pat in pattern.iter().flat_map(|p| p.patterns()) {
         output.push_str("        // Pattern validation\n");
-        output.push_str(&format!("        let pattern = regex::Regex::new(r\"{}\").map_err(|_| ValidationError::InvalidPattern {{\n", pat.pattern));
+        output.push_str(&format!("        let pattern = regex::Regex::new(r\"{}\").map_err(|_| ValidationError::InvalidPattern {{\n", pat));
         output.push_str("            value: value.clone(),\n");
-        output.push_str(&format!(
-            "            pattern: r\"{}\".to_string(),\n",
-            pat.pattern
-        ));
+        output.push_str(&format!("            pattern: r\"{}\".to_string(),\n", pat));
         output.push_str("        })?;\n\n");
 
         output.push_str("        if !pattern.is_match(&value) {\n");
@@ -298,7 +493,7 @@ fn generate_string_validated_type(
         output.push_str("                value: value.clone(),\n");
         output.push_str(&format!(
             "                pattern: r\"{}\".to_string(),\n",
-            pat.pattern
+            pat
         ));
         output.push_str("            });\n");
         output.push_str("        }\n\n");
@@ -309,6 +504,7 @@ fn generate_string_validated_type(
 
     // Generate value getter
     output.push_str("    /// Get the inner value.\n");
+    output.push_str("    #[must_use]\n");
     output.push_str("    pub fn value(&self) -> &str {\n");
     output.push_str("        &self.value\n");
     output.push_str("    }\n");
@@ -446,6 +642,7 @@ fn generate_binary_validated_type(
 
     // Generate value getter
     output.push_str("    /// Get the inner value.\n");
+    output.push_str("    #[must_use]\n");
     output.push_str("    pub fn value(&self) -> &[u8] {\n");
     output.push_str("        &self.value\n");
     output.push_str("    }\n");
@@ -487,7 +684,14 @@ fn generate_binary_validated_type(
 }
 
 /// Generate the ValidationError type.
-pub fn generate_validation_error(derive_debug: bool, derive_clone: bool) -> String {
+///
+/// The `InvalidElementCount` variant is only generated with `element_counts`,
+/// when some list or leaf-list has its number of entries checked.
+pub fn generate_validation_error(
+    derive_debug: bool,
+    derive_clone: bool,
+    element_counts: bool,
+) -> String {
     let mut output = String::new();
 
     output.push_str("/// Validation error for constrained types.\n");
@@ -505,19 +709,47 @@ pub fn generate_validation_error(derive_debug: bool, derive_clone: bool) -> Stri
     output.push_str("pub enum ValidationError {\n");
     output.push_str("    /// Value is outside the allowed range.\n");
     output.push_str("    OutOfRange {\n");
+    output.push_str("        /// The rejected value.\n");
     output.push_str("        value: String,\n");
+    output.push_str("        /// The constraint it violates, as written in YANG.\n");
     output.push_str("        constraint: String,\n");
     output.push_str("    },\n");
     output.push_str("    /// String length is outside the allowed range.\n");
     output.push_str("    InvalidLength {\n");
+    output.push_str("        /// The rejected value.\n");
     output.push_str("        value: String,\n");
+    output.push_str("        /// The constraint it violates, as written in YANG.\n");
     output.push_str("        constraint: String,\n");
     output.push_str("    },\n");
     output.push_str("    /// String does not match the required pattern.\n");
     output.push_str("    InvalidPattern {\n");
+    output.push_str("        /// The rejected value.\n");
     output.push_str("        value: String,\n");
+    output.push_str("        /// The pattern it does not match.\n");
     output.push_str("        pattern: String,\n");
     output.push_str("    },\n");
+    output.push_str("    /// A `must` condition does not hold.\n");
+    output.push_str("    MustViolation {\n");
+    output.push_str("        /// Data path of the node, relative to the validated one.\n");
+    output.push_str("        path: String,\n");
+    output.push_str("        /// The condition, as written in YANG.\n");
+    output.push_str("        condition: String,\n");
+    output.push_str("        /// The `error-message` of the `must` statement, if any.\n");
+    output.push_str("        message: Option<String>,\n");
+    output.push_str("        /// The `error-app-tag` of the `must` statement, if any.\n");
+    output.push_str("        app_tag: Option<String>,\n");
+    output.push_str("    },\n");
+    if element_counts {
+        output.push_str("    /// A list or leaf-list has too few or too many entries.\n");
+        output.push_str("    InvalidElementCount {\n");
+        output.push_str("        /// Data path of the node, relative to the validated one.\n");
+        output.push_str("        path: String,\n");
+        output.push_str("        /// The number of entries.\n");
+        output.push_str("        count: usize,\n");
+        output.push_str("        /// The constraint it violates, as written in YANG.\n");
+        output.push_str("        constraint: String,\n");
+        output.push_str("    },\n");
+    }
     output.push_str("}\n\n");
 
     output.push_str("impl std::fmt::Display for ValidationError {\n");
@@ -534,6 +766,24 @@ pub fn generate_validation_error(derive_debug: bool, derive_clone: bool) -> Stri
         "                write!(f, \"Value '{}' does not match pattern: {}\", value, pattern)\n",
     );
     output.push_str("            }\n");
+    output.push_str("            ValidationError::MustViolation {\n");
+    output.push_str("                path,\n");
+    output.push_str("                condition,\n");
+    output.push_str("                message,\n");
+    output.push_str("                ..\n");
+    output.push_str("            } => match message {\n");
+    output.push_str("                Some(message) => write!(f, \"{}: {}\", path, message),\n");
+    output.push_str(
+        "                None => write!(f, \"{}: must condition failed: {}\", path, condition),\n",
+    );
+    output.push_str("            },\n");
+    if element_counts {
+        output.push_str("            ValidationError::InvalidElementCount {\n");
+        output.push_str("                path,\n");
+        output.push_str("                count,\n");
+        output.push_str("                constraint,\n");
+        output.push_str("            } => write!(f, \"{}: has {} entries, violating {}\", path, count, constraint),\n");
+    }
     output.push_str("        }\n");
     output.push_str("    }\n");
     output.push_str("}\n\n");
@@ -542,3 +792,103 @@ pub fn generate_validation_error(derive_debug: bool, derive_clone: bool) -> Stri
 
     output
 }
+
+/// Generate the helpers the `validate` methods of structs with `must`
+/// conditions call.
+pub fn generate_must_helpers() -> String {
+    r#"/// Value of a node in a `must` condition, `Null` when it is not set.
+#[doc(hidden)]
+pub fn must_value<T: Serialize>(node: &T) -> serde_json::Value {
+    serde_json::to_value(node).unwrap_or(serde_json::Value::Null)
+}
+
+/// Whether a node is set, as a node-set is true in XPath when not empty.
+#[doc(hidden)]
+pub fn must_exists(value: &serde_json::Value) -> bool {
+    match value {
+        serde_json::Value::Null => false,
+        serde_json::Value::Array(values) => !values.is_empty(),
+        _ => true,
+    }
+}
+
+/// Compare two node values as XPath 1.0 does: true if any value of `left`
+/// and any value of `right` compare so, as numbers if either is a number or
+/// the operator is an ordering, otherwise as strings.
+#[doc(hidden)]
+pub fn must_compare(left: &serde_json::Value, operator: &str, right: &serde_json::Value) -> bool {
+    fn values(value: &serde_json::Value) -> Vec<&serde_json::Value> {
+        match value {
+            serde_json::Value::Null => Vec::new(),
+            serde_json::Value::Array(values) => values.iter().collect(),
+            value => vec![value],
+        }
+    }
+    fn number(value: &serde_json::Value) -> f64 {
+        match value {
+            serde_json::Value::Number(number) => number.as_f64().unwrap_or(f64::NAN),
+            serde_json::Value::String(text) => text.trim().parse().unwrap_or(f64::NAN),
+            serde_json::Value::Bool(flag) => f64::from(u8::from(*flag)),
+            _ => f64::NAN,
+        }
+    }
+    fn string(value: &serde_json::Value) -> String {
+        match value {
+            serde_json::Value::String(text) => text.clone(),
+            value => value.to_string(),
+        }
+    }
+    values(left).into_iter().any(|left| {
+        values(right).into_iter().any(|right| {
+            if left.is_number() || right.is_number() || !matches!(operator, "=" | "!=") {
+                let (left, right) = (number(left), number(right));
+                match operator {
+                    "=" => left == right,
+                    "!=" => left != right,
+                    "<" => left < right,
+                    "<=" => left <= right,
+                    ">" => left > right,
+                    _ => left >= right,
+                }
+            } else if operator == "=" {
+                string(left) == string(right)
+            } else {
+                string(left) != string(right)
+            }
+        })
+    })
+}
+
+/// Predicate of a list entry with its keys, e.g. `[name='eth0']`.
+#[doc(hidden)]
+pub fn must_predicate(keys: &[(&str, serde_json::Value)]) -> String {
+    keys.iter()
+        .map(|(name, value)| match value {
+            serde_json::Value::String(text) => format!("[{}='{}']", name, text),
+            value => format!("[{}='{}']", name, value),
+        })
+        .collect()
+}
+
+/// The error for a `must` condition of the node at `path` that does not hold.
+#[doc(hidden)]
+pub fn must_violation(
+    path: &str,
+    condition: &str,
+    message: Option<&str>,
+    app_tag: Option<&str>,
+) -> ValidationError {
+    ValidationError::MustViolation {
+        path: if path.is_empty() {
+            "/".to_string()
+        } else {
+            path.to_string()
+        },
+        condition: condition.to_string(),
+        message: message.map(str::to_string),
+        app_tag: app_tag.map(str::to_string),
+    }
+}
+"#
+    .to_string()
+}
@@ -25,6 +25,8 @@
 pub mod build;
 pub mod generator;
 pub mod parser;
+pub mod simulator;
+pub mod testkit;
 
 // Re-export main API types
 pub use build::{BuildError, RustconfBuilder};
@@ -1,4 +1,13 @@
 //! Abstract Syntax Tree (AST) types for YANG specifications.
+//!
+//! # Stability
+//!
+//! The AST grows as the parser learns more of YANG, so its structs and the
+//! [`DataNode`], [`TypeSpec`] and [`Deviate`] enums are `#[non_exhaustive]`:
+//! outside this crate, create nodes with their `new` constructors and set the
+//! public fields afterwards, and match the enums with a wildcard arm. Adding
+//! a field, a variant or a method is not a breaking change. Renaming or
+//! removing one, or changing what a field holds, is, and follows semver.
 
 /// YANG version enumeration.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -11,6 +20,7 @@ pub enum YangVersion {
 
 /// Module header containing version and namespace information.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct ModuleHeader {
     pub yang_version: YangVersion,
     pub name: String,
@@ -18,142 +28,1070 @@ pub struct ModuleHeader {
     pub prefix: String,
 }
 
+impl ModuleHeader {
+    /// Create a module header.
+    pub fn new(
+        yang_version: YangVersion,
+        name: impl Into<String>,
+        namespace: impl Into<String>,
+        prefix: impl Into<String>,
+    ) -> Self {
+        Self {
+            yang_version,
+            name: name.into(),
+            namespace: namespace.into(),
+            prefix: prefix.into(),
+        }
+    }
+}
+
 /// A parsed YANG module.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct YangModule {
     pub name: String,
     pub namespace: String,
     pub prefix: String,
     pub yang_version: Option<YangVersion>,
+    /// Party responsible for the module (`organization` statement).
+    pub organization: Option<String>,
+    /// Contact information for the module's authors (`contact` statement).
+    pub contact: Option<String>,
+    /// Module description (`description` statement).
+    pub description: Option<String>,
     pub imports: Vec<Import>,
+    /// Features the module defines (`feature` statements).
+    pub features: Vec<Feature>,
+    /// Extensions the module defines (`extension` statements).
+    pub extensions: Vec<Extension>,
     pub typedefs: Vec<TypeDef>,
     pub groupings: Vec<Grouping>,
     pub data_nodes: Vec<DataNode>,
+    pub augments: Vec<Augment>,
+    pub deviations: Vec<Deviation>,
     pub rpcs: Vec<Rpc>,
     pub notifications: Vec<Notification>,
+    /// Extension statements in the module body, such as
+    /// `oc-ext:openconfig-version`.
+    pub unknown_statements: Vec<UnknownStatement>,
+}
+
+impl YangModule {
+    /// Create an empty module.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustconf::parser::{DataNode, Leaf, TypeSpec, YangModule};
+    ///
+    /// let mut module = YangModule::new("device", "urn:example:device", "dev");
+    /// let mut hostname = Leaf::new("hostname", TypeSpec::String { length: None, pattern: None });
+    /// hostname.mandatory = true;
+    /// module.data_nodes.push(DataNode::Leaf(hostname));
+    ///
+    /// assert_eq!(module.data_nodes[0].name(), "hostname");
+    /// ```
+    pub fn new(
+        name: impl Into<String>,
+        namespace: impl Into<String>,
+        prefix: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            namespace: namespace.into(),
+            prefix: prefix.into(),
+            yang_version: None,
+            organization: None,
+            contact: None,
+            description: None,
+            imports: Vec::new(),
+            features: Vec::new(),
+            extensions: Vec::new(),
+            typedefs: Vec::new(),
+            groupings: Vec::new(),
+            data_nodes: Vec::new(),
+            augments: Vec::new(),
+            deviations: Vec::new(),
+            rpcs: Vec::new(),
+            notifications: Vec::new(),
+            unknown_statements: Vec::new(),
+        }
+    }
 }
 
 /// Module import statement.
+///
+/// A module may import the same module more than once under different
+/// prefixes; each `import` statement is kept as its own entry.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct Import {
     pub module: String,
     pub prefix: String,
     pub revision: Option<String>,
+    pub description: Option<String>,
+    pub reference: Option<String>,
+}
+
+impl Import {
+    /// Create an import of any revision of `module`.
+    pub fn new(module: impl Into<String>, prefix: impl Into<String>) -> Self {
+        Self {
+            module: module.into(),
+            prefix: prefix.into(),
+            revision: None,
+            description: None,
+            reference: None,
+        }
+    }
+}
+
+/// Submodule include statement.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Include {
+    pub submodule: String,
+    pub revision: Option<String>,
+}
+
+impl Include {
+    /// Create an include of any revision of `submodule`.
+    pub fn new(submodule: impl Into<String>) -> Self {
+        Self {
+            submodule: submodule.into(),
+            revision: None,
+        }
+    }
+}
+
+/// Submodule `belongs-to` statement naming the parent module and the prefix
+/// the submodule uses to refer to it.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct BelongsTo {
+    pub module: String,
+    pub prefix: String,
+}
+
+impl BelongsTo {
+    /// Create a `belongs-to` statement.
+    pub fn new(module: impl Into<String>, prefix: impl Into<String>) -> Self {
+        Self {
+            module: module.into(),
+            prefix: prefix.into(),
+        }
+    }
+}
+
+/// A parsed YANG submodule.
+///
+/// Submodules are never generated on their own; their definitions are merged
+/// into the module named by `belongs_to` when that module includes them.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct YangSubmodule {
+    pub name: String,
+    pub belongs_to: BelongsTo,
+    pub yang_version: Option<YangVersion>,
+    pub imports: Vec<Import>,
+    pub includes: Vec<Include>,
+    pub features: Vec<Feature>,
+    pub extensions: Vec<Extension>,
+    pub typedefs: Vec<TypeDef>,
+    pub groupings: Vec<Grouping>,
+    pub data_nodes: Vec<DataNode>,
+    pub augments: Vec<Augment>,
+    pub deviations: Vec<Deviation>,
+    pub rpcs: Vec<Rpc>,
+    pub notifications: Vec<Notification>,
+    pub unknown_statements: Vec<UnknownStatement>,
+}
+
+impl YangSubmodule {
+    /// Create an empty submodule of the module named by `belongs_to`.
+    pub fn new(name: impl Into<String>, belongs_to: BelongsTo) -> Self {
+        Self {
+            name: name.into(),
+            belongs_to,
+            yang_version: None,
+            imports: Vec::new(),
+            includes: Vec::new(),
+            features: Vec::new(),
+            extensions: Vec::new(),
+            typedefs: Vec::new(),
+            groupings: Vec::new(),
+            data_nodes: Vec::new(),
+            augments: Vec::new(),
+            deviations: Vec::new(),
+            rpcs: Vec::new(),
+            notifications: Vec::new(),
+            unknown_statements: Vec::new(),
+        }
+    }
 }
 
 /// Type definition.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct TypeDef {
     pub name: String,
     pub type_spec: TypeSpec,
     pub units: Option<String>,
     pub default: Option<String>,
     pub description: Option<String>,
+    /// Status from the typedef's `status` statement, `current` without one.
+    pub status: Status,
+}
+
+impl TypeDef {
+    /// Create a typedef without units or default.
+    pub fn new(name: impl Into<String>, type_spec: TypeSpec) -> Self {
+        Self {
+            name: name.into(),
+            type_spec,
+            units: None,
+            default: None,
+            description: None,
+            status: Status::Current,
+        }
+    }
+}
+
+/// Feature definition, naming an optional part of the module.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Feature {
+    pub name: String,
+    pub description: Option<String>,
+    /// Features this one depends on, from its `if-feature` statements.
+    pub if_features: Vec<IfFeature>,
+}
+
+impl Feature {
+    /// Create a feature that depends on no other features.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            if_features: Vec::new(),
+        }
+    }
+}
+
+/// Extension definition, declaring a statement other modules can use.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Extension {
+    pub name: String,
+    /// Name of the statement's argument, if it takes one.
+    pub argument: Option<String>,
+    pub description: Option<String>,
+}
+
+impl Extension {
+    /// Create an extension without an argument.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            argument: None,
+            description: None,
+        }
+    }
+}
+
+/// Statement defined by an extension, such as `tailf:info "..."` or
+/// `oc-ext:openconfig-version "2.4.3"`.
+///
+/// Parsers and generators skip them, but keep them on the nodes they appear
+/// on for tools that understand them.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct UnknownStatement {
+    /// Prefix the statement is written with; empty for standard YANG
+    /// statements inside an extension statement.
+    pub prefix: String,
+    /// Name of the extension, or keyword of a standard statement.
+    pub name: String,
+    /// Module defining the extension, resolved from the prefix; `None` when
+    /// the prefix is not the module's own or one of its imports.
+    pub module: Option<String>,
+    /// The statement's argument, if it has one.
+    pub argument: Option<String>,
+    /// Statements inside the statement's block.
+    pub substatements: Vec<UnknownStatement>,
+}
+
+impl UnknownStatement {
+    /// Create a statement `prefix:name` without an argument, whose prefix
+    /// is not resolved to a module.
+    pub fn new(prefix: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            prefix: prefix.into(),
+            name: name.into(),
+            module: None,
+            argument: None,
+            substatements: Vec::new(),
+        }
+    }
+
+    /// The keyword as written: `prefix:name`, or the name of a standard
+    /// statement.
+    pub fn keyword(&self) -> String {
+        if self.prefix.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}:{}", self.prefix, self.name)
+        }
+    }
+}
+
+/// Condition of an `if-feature` statement.
+///
+/// Feature names are qualified with the name of the module defining them,
+/// as in `ietf-interfaces:if-mib`, so conditions keep their meaning when
+/// nodes are moved into other modules by `uses` or `augment`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IfFeature {
+    Feature(String),
+    Not(Box<IfFeature>),
+    And(Box<IfFeature>, Box<IfFeature>),
+    Or(Box<IfFeature>, Box<IfFeature>),
+}
+
+impl IfFeature {
+    /// Whether the condition holds when `enabled` tells which features are
+    /// supported.
+    pub fn evaluate(&self, enabled: &dyn Fn(&str) -> bool) -> bool {
+        match self {
+            IfFeature::Feature(name) => enabled(name),
+            IfFeature::Not(condition) => !condition.evaluate(enabled),
+            IfFeature::And(left, right) => left.evaluate(enabled) && right.evaluate(enabled),
+            IfFeature::Or(left, right) => left.evaluate(enabled) || right.evaluate(enabled),
+        }
+    }
+
+    /// Every feature name in the condition.
+    pub fn features(&self) -> Vec<&str> {
+        match self {
+            IfFeature::Feature(name) => vec![name],
+            IfFeature::Not(condition) => condition.features(),
+            IfFeature::And(left, right) | IfFeature::Or(left, right) => {
+                let mut names = left.features();
+                names.extend(right.features());
+                names
+            }
+        }
+    }
+
+    /// Apply `rename` to every feature name in the condition.
+    pub(crate) fn rename_features<E>(
+        &mut self,
+        rename: &mut impl FnMut(&str) -> Result<String, E>,
+    ) -> Result<(), E> {
+        match self {
+            IfFeature::Feature(name) => *name = rename(name)?,
+            IfFeature::Not(condition) => condition.rename_features(rename)?,
+            IfFeature::And(left, right) | IfFeature::Or(left, right) => {
+                left.rename_features(rename)?;
+                right.rename_features(rename)?;
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Grouping definition for reusable data node collections.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct Grouping {
     pub name: String,
     pub description: Option<String>,
+    /// Status from the grouping's `status` statement, `current` without one.
+    pub status: Status,
     pub data_nodes: Vec<DataNode>,
 }
 
+impl Grouping {
+    /// Create an empty grouping.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            data_nodes: Vec::new(),
+            status: Status::Current,
+        }
+    }
+}
+
+/// Status of a definition, from its `status` statement.
+///
+/// Statuses are ordered from `current` to `obsolete`, so the status of a
+/// node inside a deprecated one is the greater of the two.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Status {
+    /// The definition is current and valid.
+    #[default]
+    Current,
+    /// The definition is still supported but should no longer be used.
+    Deprecated,
+    /// The definition is no longer supported.
+    Obsolete,
+}
+
+impl Status {
+    /// The argument of the `status` statement.
+    pub fn keyword(self) -> &'static str {
+        match self {
+            Status::Current => "current",
+            Status::Deprecated => "deprecated",
+            Status::Obsolete => "obsolete",
+        }
+    }
+}
+
 /// Data node variants in the YANG data tree.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum DataNode {
     Container(Container),
     List(List),
     Leaf(Leaf),
     LeafList(LeafList),
+    AnyData(AnyData),
     Choice(Choice),
     Case(Case),
     Uses(Uses),
 }
 
+impl DataNode {
+    /// The node's name; for `uses`, the name of the grouping.
+    pub fn name(&self) -> &str {
+        match self {
+            DataNode::Container(container) => &container.name,
+            DataNode::List(list) => &list.name,
+            DataNode::Leaf(leaf) => &leaf.name,
+            DataNode::LeafList(leaf_list) => &leaf_list.name,
+            DataNode::AnyData(anydata) => &anydata.name,
+            DataNode::Choice(choice) => &choice.name,
+            DataNode::Case(case) => &case.name,
+            DataNode::Uses(uses) => &uses.name,
+        }
+    }
+
+    /// The node's `description`.
+    pub fn description(&self) -> Option<&str> {
+        match self {
+            DataNode::Container(container) => container.description.as_deref(),
+            DataNode::List(list) => list.description.as_deref(),
+            DataNode::Leaf(leaf) => leaf.description.as_deref(),
+            DataNode::LeafList(leaf_list) => leaf_list.description.as_deref(),
+            DataNode::AnyData(anydata) => anydata.description.as_deref(),
+            DataNode::Choice(choice) => choice.description.as_deref(),
+            DataNode::Case(case) => case.description.as_deref(),
+            DataNode::Uses(uses) => uses.description.as_deref(),
+        }
+    }
+
+    /// The XPath condition of the node's `when` statement; `uses` statements
+    /// have none.
+    pub fn when(&self) -> Option<&str> {
+        match self {
+            DataNode::Container(container) => container.when.as_deref(),
+            DataNode::List(list) => list.when.as_deref(),
+            DataNode::Leaf(leaf) => leaf.when.as_deref(),
+            DataNode::LeafList(leaf_list) => leaf_list.when.as_deref(),
+            DataNode::AnyData(anydata) => anydata.when.as_deref(),
+            DataNode::Choice(choice) => choice.when.as_deref(),
+            DataNode::Case(case) => case.when.as_deref(),
+            DataNode::Uses(_) => None,
+        }
+    }
+
+    /// The node's status; `uses` statements are `current`.
+    pub fn status(&self) -> Status {
+        match self {
+            DataNode::Container(container) => container.status,
+            DataNode::List(list) => list.status,
+            DataNode::Leaf(leaf) => leaf.status,
+            DataNode::LeafList(leaf_list) => leaf_list.status,
+            DataNode::AnyData(anydata) => anydata.status,
+            DataNode::Choice(choice) => choice.status,
+            DataNode::Case(case) => case.status,
+            DataNode::Uses(_) => Status::Current,
+        }
+    }
+
+    /// The child nodes of a container, list or case; other nodes have none,
+    /// and the children of a choice are its cases.
+    pub fn children(&self) -> &[DataNode] {
+        match self {
+            DataNode::Container(container) => &container.children,
+            DataNode::List(list) => &list.children,
+            DataNode::Case(case) => &case.data_nodes,
+            DataNode::Leaf(_)
+            | DataNode::LeafList(_)
+            | DataNode::AnyData(_)
+            | DataNode::Choice(_)
+            | DataNode::Uses(_) => &[],
+        }
+    }
+
+    /// The conditions of the node's `if-feature` statements.
+    pub fn if_features(&self) -> &[IfFeature] {
+        match self {
+            DataNode::Container(container) => &container.if_features,
+            DataNode::List(list) => &list.if_features,
+            DataNode::Leaf(leaf) => &leaf.if_features,
+            DataNode::LeafList(leaf_list) => &leaf_list.if_features,
+            DataNode::AnyData(anydata) => &anydata.if_features,
+            DataNode::Choice(choice) => &choice.if_features,
+            DataNode::Case(case) => &case.if_features,
+            DataNode::Uses(uses) => &uses.if_features,
+        }
+    }
+
+    /// Extension statements on the node; `uses` statements have none.
+    pub fn unknown_statements(&self) -> &[UnknownStatement] {
+        match self {
+            DataNode::Container(container) => &container.unknown_statements,
+            DataNode::List(list) => &list.unknown_statements,
+            DataNode::Leaf(leaf) => &leaf.unknown_statements,
+            DataNode::LeafList(leaf_list) => &leaf_list.unknown_statements,
+            DataNode::AnyData(anydata) => &anydata.unknown_statements,
+            DataNode::Choice(choice) => &choice.unknown_statements,
+            DataNode::Case(case) => &case.unknown_statements,
+            DataNode::Uses(_) => &[],
+        }
+    }
+
+    /// Mutable access to the conditions of the node's `if-feature` statements.
+    pub fn if_features_mut(&mut self) -> &mut Vec<IfFeature> {
+        match self {
+            DataNode::Container(container) => &mut container.if_features,
+            DataNode::List(list) => &mut list.if_features,
+            DataNode::Leaf(leaf) => &mut leaf.if_features,
+            DataNode::LeafList(leaf_list) => &mut leaf_list.if_features,
+            DataNode::AnyData(anydata) => &mut anydata.if_features,
+            DataNode::Choice(choice) => &mut choice.if_features,
+            DataNode::Case(case) => &mut case.if_features,
+            DataNode::Uses(uses) => &mut uses.if_features,
+        }
+    }
+}
+
+/// Constraint of a `must` statement on a data node.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Must {
+    /// XPath condition that must hold for the data to be valid.
+    pub condition: String,
+    /// Message of the error reported when the condition does not hold.
+    pub error_message: Option<String>,
+    /// Application tag of the error reported when the condition does not hold.
+    pub error_app_tag: Option<String>,
+}
+
+impl Must {
+    /// Create a constraint reporting the default error when it fails.
+    pub fn new(condition: impl Into<String>) -> Self {
+        Self {
+            condition: condition.into(),
+            error_message: None,
+            error_app_tag: None,
+        }
+    }
+}
+
 /// Uses statement for grouping expansion.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct Uses {
     pub name: String,
     pub description: Option<String>,
+    /// Conditions of the `if-feature` statements, added to each node of the
+    /// grouping when it is expanded.
+    pub if_features: Vec<IfFeature>,
+    /// Augments of the grouping's nodes, applied where the grouping is
+    /// expanded. Their targets are descendant schema node identifiers, relative
+    /// to the `uses`: `config/ipv4` is `["config", "ipv4"]`.
+    pub augments: Vec<Augment>,
+}
+
+impl Uses {
+    /// Create a `uses` of the grouping `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            if_features: Vec::new(),
+            augments: Vec::new(),
+        }
+    }
 }
 
 /// Container node.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct Container {
     pub name: String,
     pub description: Option<String>,
+    /// Status from the node's `status` statement, `current` without one.
+    pub status: Status,
     pub config: bool,
     pub mandatory: bool,
     pub children: Vec<DataNode>,
+    /// Conditions of the node's `if-feature` statements, all of which must hold
+    /// for the node to be part of the schema.
+    pub if_features: Vec<IfFeature>,
+    /// XPath condition of the node's `when` statement. The node is only
+    /// valid in the data tree while the condition holds.
+    pub when: Option<String>,
+    /// Constraints of the node's `must` statements.
+    pub must: Vec<Must>,
+    /// Extension statements on the node, such as `tailf:info`.
+    pub unknown_statements: Vec<UnknownStatement>,
+}
+
+impl Container {
+    /// Create an empty, optional configuration container.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            config: true,
+            mandatory: false,
+            children: Vec::new(),
+            if_features: Vec::new(),
+            when: None,
+            must: Vec::new(),
+            unknown_statements: Vec::new(),
+            status: Status::Current,
+        }
+    }
 }
 
 /// List node.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct List {
     pub name: String,
     pub description: Option<String>,
+    /// Status from the node's `status` statement, `current` without one.
+    pub status: Status,
     pub config: bool,
     pub keys: Vec<String>,
     pub children: Vec<DataNode>,
+    /// Conditions of the node's `if-feature` statements, all of which must hold
+    /// for the node to be part of the schema.
+    pub if_features: Vec<IfFeature>,
+    /// XPath condition of the node's `when` statement. The node is only
+    /// valid in the data tree while the condition holds.
+    pub when: Option<String>,
+    /// Constraints of the node's `must` statements.
+    pub must: Vec<Must>,
+    /// Fewest entries allowed, from the node's `min-elements` statement,
+    /// 0 without one.
+    pub min_elements: u32,
+    /// Most entries allowed, from the node's `max-elements` statement,
+    /// `None` when unbounded.
+    pub max_elements: Option<u32>,
+    /// Extension statements on the node, such as `tailf:info`.
+    pub unknown_statements: Vec<UnknownStatement>,
+}
+
+impl List {
+    /// Create an empty configuration list with the given key leaves.
+    pub fn new(name: impl Into<String>, keys: Vec<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            config: true,
+            keys,
+            children: Vec::new(),
+            if_features: Vec::new(),
+            when: None,
+            must: Vec::new(),
+            min_elements: 0,
+            max_elements: None,
+            unknown_statements: Vec::new(),
+            status: Status::Current,
+        }
+    }
 }
 
 /// Leaf node.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct Leaf {
     pub name: String,
     pub description: Option<String>,
+    /// Status from the node's `status` statement, `current` without one.
+    pub status: Status,
     pub type_spec: TypeSpec,
     pub mandatory: bool,
     pub default: Option<String>,
+    pub units: Option<String>,
     pub config: bool,
+    /// Conditions of the node's `if-feature` statements, all of which must hold
+    /// for the node to be part of the schema.
+    pub if_features: Vec<IfFeature>,
+    /// XPath condition of the node's `when` statement. The node is only
+    /// valid in the data tree while the condition holds.
+    pub when: Option<String>,
+    /// Constraints of the node's `must` statements.
+    pub must: Vec<Must>,
+    /// Extension statements on the node, such as `tailf:info`.
+    pub unknown_statements: Vec<UnknownStatement>,
+}
+
+impl Leaf {
+    /// Create an optional configuration leaf without a default.
+    pub fn new(name: impl Into<String>, type_spec: TypeSpec) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            type_spec,
+            mandatory: false,
+            default: None,
+            units: None,
+            config: true,
+            if_features: Vec::new(),
+            when: None,
+            must: Vec::new(),
+            unknown_statements: Vec::new(),
+            status: Status::Current,
+        }
+    }
 }
 
 /// Leaf-list node.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct LeafList {
     pub name: String,
     pub description: Option<String>,
+    /// Status from the node's `status` statement, `current` without one.
+    pub status: Status,
     pub type_spec: TypeSpec,
     pub config: bool,
+    /// Conditions of the node's `if-feature` statements, all of which must hold
+    /// for the node to be part of the schema.
+    pub if_features: Vec<IfFeature>,
+    /// XPath condition of the node's `when` statement. The node is only
+    /// valid in the data tree while the condition holds.
+    pub when: Option<String>,
+    /// Constraints of the node's `must` statements.
+    pub must: Vec<Must>,
+    /// Fewest entries allowed, from the node's `min-elements` statement,
+    /// 0 without one.
+    pub min_elements: u32,
+    /// Most entries allowed, from the node's `max-elements` statement,
+    /// `None` when unbounded.
+    pub max_elements: Option<u32>,
+    /// Extension statements on the node, such as `tailf:info`.
+    pub unknown_statements: Vec<UnknownStatement>,
+}
+
+impl LeafList {
+    /// Create a configuration leaf-list.
+    pub fn new(name: impl Into<String>, type_spec: TypeSpec) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            type_spec,
+            config: true,
+            if_features: Vec::new(),
+            when: None,
+            must: Vec::new(),
+            min_elements: 0,
+            max_elements: None,
+            unknown_statements: Vec::new(),
+            status: Status::Current,
+        }
+    }
+}
+
+/// `anydata` or `anyxml` node, holding a subtree the module does not describe.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct AnyData {
+    pub name: String,
+    pub description: Option<String>,
+    /// Status from the node's `status` statement, `current` without one.
+    pub status: Status,
+    /// Whether the node was declared with `anydata` or `anyxml`.
+    pub kind: AnyDataKind,
+    pub mandatory: bool,
+    pub config: bool,
+    /// Conditions of the node's `if-feature` statements, all of which must hold
+    /// for the node to be part of the schema.
+    pub if_features: Vec<IfFeature>,
+    /// XPath condition of the node's `when` statement. The node is only
+    /// valid in the data tree while the condition holds.
+    pub when: Option<String>,
+    /// Constraints of the node's `must` statements.
+    pub must: Vec<Must>,
+    /// Extension statements on the node, such as `tailf:info`.
+    pub unknown_statements: Vec<UnknownStatement>,
+}
+
+impl AnyData {
+    /// Create an optional configuration node.
+    pub fn new(name: impl Into<String>, kind: AnyDataKind) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            kind,
+            mandatory: false,
+            config: true,
+            if_features: Vec::new(),
+            when: None,
+            must: Vec::new(),
+            unknown_statements: Vec::new(),
+            status: Status::Current,
+        }
+    }
+}
+
+/// The statement an [`AnyData`] node was declared with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnyDataKind {
+    /// `anydata`: unknown data that is still modeled YANG data.
+    AnyData,
+    /// `anyxml`: an arbitrary chunk of XML (or JSON).
+    AnyXml,
+}
+
+impl AnyDataKind {
+    /// The YANG keyword of the statement.
+    pub fn keyword(self) -> &'static str {
+        match self {
+            AnyDataKind::AnyData => "anydata",
+            AnyDataKind::AnyXml => "anyxml",
+        }
+    }
 }
 
 /// Choice node for mutually exclusive options.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct Choice {
     pub name: String,
     pub description: Option<String>,
+    /// Status from the node's `status` statement, `current` without one.
+    pub status: Status,
     pub mandatory: bool,
     pub cases: Vec<Case>,
+    /// Conditions of the node's `if-feature` statements, all of which must hold
+    /// for the node to be part of the schema.
+    pub if_features: Vec<IfFeature>,
+    /// XPath condition of the node's `when` statement. The node is only
+    /// valid in the data tree while the condition holds.
+    pub when: Option<String>,
+    /// Extension statements on the node, such as `tailf:info`.
+    pub unknown_statements: Vec<UnknownStatement>,
+}
+
+impl Choice {
+    /// Create an optional choice without cases.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            mandatory: false,
+            cases: Vec::new(),
+            if_features: Vec::new(),
+            when: None,
+            unknown_statements: Vec::new(),
+            status: Status::Current,
+        }
+    }
 }
 
 /// Case within a choice.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct Case {
     pub name: String,
     pub description: Option<String>,
+    /// Status from the node's `status` statement, `current` without one.
+    pub status: Status,
     pub data_nodes: Vec<DataNode>,
+    /// Conditions of the node's `if-feature` statements, all of which must hold
+    /// for the node to be part of the schema.
+    pub if_features: Vec<IfFeature>,
+    /// XPath condition of the node's `when` statement. The node is only
+    /// valid in the data tree while the condition holds.
+    pub when: Option<String>,
+    /// Extension statements on the node, such as `tailf:info`.
+    pub unknown_statements: Vec<UnknownStatement>,
+}
+
+impl Case {
+    /// Create an empty case.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            data_nodes: Vec::new(),
+            if_features: Vec::new(),
+            when: None,
+            unknown_statements: Vec::new(),
+            status: Status::Current,
+        }
+    }
 }
 
 /// RPC operation definition.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct Rpc {
     pub name: String,
     pub description: Option<String>,
     pub input: Option<Vec<DataNode>>,
     pub output: Option<Vec<DataNode>>,
+    /// Timeout in seconds from the `rustconf-extensions` `timeout` extension,
+    /// for operations that take longer than a client's default timeout.
+    pub timeout: Option<u64>,
+    /// Extension statements on the operation, other than `timeout`.
+    pub unknown_statements: Vec<UnknownStatement>,
+}
+
+impl Rpc {
+    /// Create an operation without input or output.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            input: None,
+            output: None,
+            timeout: None,
+            unknown_statements: Vec::new(),
+        }
+    }
 }
 
 /// Notification definition.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct Notification {
     pub name: String,
     pub description: Option<String>,
     pub data_nodes: Vec<DataNode>,
+    /// Extension statements on the notification.
+    pub unknown_statements: Vec<UnknownStatement>,
+}
+
+impl Notification {
+    /// Create a notification without data.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            data_nodes: Vec::new(),
+            unknown_statements: Vec::new(),
+        }
+    }
+}
+
+/// Augment statement adding data nodes to a schema node defined elsewhere.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Augment {
+    /// Steps of the absolute schema node identifier of the target, each an
+    /// optionally prefixed node name: `/if:interfaces/if:interface` is
+    /// `["if:interfaces", "if:interface"]`. Steps using the augmenting
+    /// module's own prefix are stored without it. The augments of a
+    /// [`Uses`] have descendant identifiers, without the leading `/`.
+    pub target: Vec<String>,
+    pub description: Option<String>,
+    pub data_nodes: Vec<DataNode>,
+}
+
+impl Augment {
+    /// Create an augment of `target`, given as in [`Augment::target`],
+    /// that adds no nodes.
+    pub fn new(target: Vec<String>) -> Self {
+        Self {
+            target,
+            description: None,
+            data_nodes: Vec::new(),
+        }
+    }
+
+    /// The target as an absolute schema node identifier.
+    pub fn target_path(&self) -> String {
+        format!("/{}", self.target.join("/"))
+    }
+}
+
+/// Deviation statement describing how a server's implementation of a schema
+/// node differs from the module defining it.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Deviation {
+    /// Steps of the absolute schema node identifier of the target, stored as
+    /// [`Augment::target`] is.
+    pub target: Vec<String>,
+    pub description: Option<String>,
+    pub deviates: Vec<Deviate>,
+}
+
+impl Deviation {
+    /// Create a deviation of `target`, given as in [`Augment::target`],
+    /// without `deviate` statements.
+    pub fn new(target: Vec<String>) -> Self {
+        Self {
+            target,
+            description: None,
+            deviates: Vec::new(),
+        }
+    }
+
+    /// The target as an absolute schema node identifier.
+    pub fn target_path(&self) -> String {
+        format!("/{}", self.target.join("/"))
+    }
+}
+
+/// `deviate` statement of a deviation.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum Deviate {
+    /// The target node is not implemented.
+    NotSupported,
+    /// Properties the implementation adds to the target.
+    Add(DeviateProperties),
+    /// Properties the implementation defines differently.
+    Replace(DeviateProperties),
+    /// Properties the implementation does not have.
+    Delete(DeviateProperties),
+}
+
+impl Deviate {
+    /// The type a `replace` sets, if any.
+    pub fn type_spec_mut(&mut self) -> Option<&mut TypeSpec> {
+        match self {
+            Deviate::Replace(properties) => properties.type_spec.as_mut(),
+            Deviate::NotSupported | Deviate::Add(_) | Deviate::Delete(_) => None,
+        }
+    }
+}
+
+/// Properties of a `deviate` statement; those not given are `None`.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct DeviateProperties {
+    pub type_spec: Option<TypeSpec>,
+    pub units: Option<String>,
+    pub default: Option<String>,
+    pub config: Option<bool>,
+    pub mandatory: Option<bool>,
 }
 
 /// YANG type specification.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum TypeSpec {
     Int8 {
         range: Option<RangeConstraint>,
@@ -179,6 +1117,13 @@ pub enum TypeSpec {
     Uint64 {
         range: Option<RangeConstraint>,
     },
+    /// Fixed-point number with `fraction_digits` digits after the decimal
+    /// point, between 1 and 18. The range is scaled by 10^`fraction_digits`,
+    /// e.g. `1.5..2` with two fraction digits is `150..200`.
+    Decimal64 {
+        fraction_digits: u8,
+        range: Option<RangeConstraint>,
+    },
     String {
         length: Option<LengthConstraint>,
         pattern: Option<PatternConstraint>,
@@ -187,6 +1132,10 @@ pub enum TypeSpec {
     Enumeration {
         values: Vec<EnumValue>,
     },
+    /// Set of named bits, each at a fixed position.
+    Bits {
+        bits: Vec<Bit>,
+    },
     Union {
         types: Vec<TypeSpec>,
     },
@@ -197,14 +1146,191 @@ pub enum TypeSpec {
     Binary {
         length: Option<LengthConstraint>,
     },
+    /// `date-and-time` of `ietf-yang-types`, a string in the RFC 3339
+    /// date-time format with the typedef's `pattern`. Typedef expansion
+    /// produces it for unrestricted references, so it can be generated as a
+    /// date type.
+    DateAndTime {
+        pattern: Option<PatternConstraint>,
+    },
     /// Reference to a typedef that needs to be resolved
     TypedefRef {
         name: String,
+        /// Restrictions the derived type places on the typedef, merged on expansion
+        range: Option<RangeConstraint>,
+        length: Option<LengthConstraint>,
+        pattern: Option<PatternConstraint>,
     },
 }
 
+impl TypeSpec {
+    /// Create an unrestricted reference to a typedef.
+    pub fn typedef_ref(name: impl Into<String>) -> Self {
+        TypeSpec::TypedefRef {
+            name: name.into(),
+            range: None,
+            length: None,
+            pattern: None,
+        }
+    }
+
+    /// Check whether this is a typedef reference that narrows the typedef.
+    pub fn is_restricted_typedef_ref(&self) -> bool {
+        matches!(
+            self,
+            TypeSpec::TypedefRef { range, length, pattern, .. }
+                if range.is_some() || length.is_some() || pattern.is_some()
+        )
+    }
+
+    /// Narrow the allowed values with a `range` restriction.
+    ///
+    /// The effective range is the intersection with any range already present
+    /// (RFC 7950 section 9.2.4). On a typedef reference the restriction is kept
+    /// until the reference is resolved.
+    pub fn restrict_range(&mut self, restriction: RangeConstraint) -> Result<(), String> {
+        match self {
+            TypeSpec::Int8 { range }
+            | TypeSpec::Int16 { range }
+            | TypeSpec::Int32 { range }
+            | TypeSpec::Int64 { range }
+            | TypeSpec::Uint8 { range }
+            | TypeSpec::Uint16 { range }
+            | TypeSpec::Uint32 { range }
+            | TypeSpec::Uint64 { range }
+            | TypeSpec::Decimal64 { range, .. }
+            | TypeSpec::TypedefRef { range, .. } => {
+                let merged = match range.take() {
+                    Some(base) => base.intersect(&restriction),
+                    None => restriction,
+                };
+                if merged.ranges.is_empty() {
+                    return Err("range restriction does not overlap the base range".to_string());
+                }
+                *range = Some(merged);
+                Ok(())
+            }
+            other => Err(format!(
+                "range restriction is not valid for {}",
+                other.kind()
+            )),
+        }
+    }
+
+    /// Narrow the allowed values with the `range` restriction of a type
+    /// derived from this one.
+    ///
+    /// Derived types are parsed without knowing their base type, so their
+    /// ranges are whole numbers; on a `decimal64` they are scaled by its
+    /// fraction digits first.
+    pub fn restrict_derived_range(&mut self, restriction: RangeConstraint) -> Result<(), String> {
+        match self {
+            TypeSpec::Decimal64 {
+                fraction_digits, ..
+            } => {
+                let factor = 10i64.pow(u32::from(*fraction_digits));
+                let scale = |value: i64| {
+                    value.checked_mul(factor).ok_or_else(|| {
+                        format!("range value {} is out of range for decimal64", value)
+                    })
+                };
+                let ranges = restriction
+                    .ranges
+                    .iter()
+                    .map(|range| Ok(Range::new(scale(range.min)?, scale(range.max)?)))
+                    .collect::<Result<Vec<_>, String>>()?;
+                self.restrict_range(RangeConstraint::new(ranges))
+            }
+            _ => self.restrict_range(restriction),
+        }
+    }
+
+    /// Narrow the allowed lengths with a `length` restriction.
+    ///
+    /// The effective length is the intersection with any length already present
+    /// (RFC 7950 section 9.4.4).
+    pub fn restrict_length(&mut self, restriction: LengthConstraint) -> Result<(), String> {
+        self.narrow_date_and_time();
+        match self {
+            TypeSpec::String { length, .. }
+            | TypeSpec::Binary { length }
+            | TypeSpec::TypedefRef { length, .. } => {
+                let merged = match length.take() {
+                    Some(base) => base.intersect(&restriction),
+                    None => restriction,
+                };
+                if merged.lengths.is_empty() {
+                    return Err("length restriction does not overlap the base length".to_string());
+                }
+                *length = Some(merged);
+                Ok(())
+            }
+            other => Err(format!(
+                "length restriction is not valid for {}",
+                other.kind()
+            )),
+        }
+    }
+
+    /// Add a `pattern` restriction. Values must match every pattern of the type
+    /// and of the types it is derived from (RFC 7950 section 9.4.5).
+    pub fn restrict_pattern(&mut self, restriction: PatternConstraint) -> Result<(), String> {
+        self.narrow_date_and_time();
+        match self {
+            TypeSpec::String { pattern, .. } | TypeSpec::TypedefRef { pattern, .. } => {
+                *pattern = Some(match pattern.take() {
+                    Some(base) => base.and(restriction),
+                    None => restriction,
+                });
+                Ok(())
+            }
+            other => Err(format!(
+                "pattern restriction is not valid for {}",
+                other.kind()
+            )),
+        }
+    }
+
+    /// Turn a `date-and-time` into the string it is derived from, before it
+    /// is narrowed like one.
+    fn narrow_date_and_time(&mut self) {
+        if let TypeSpec::DateAndTime { pattern } = self {
+            *self = TypeSpec::String {
+                length: None,
+                pattern: pattern.take(),
+            };
+        }
+    }
+
+    /// YANG name of the type, for error messages.
+    fn kind(&self) -> &str {
+        match self {
+            TypeSpec::Int8 { .. } => "int8",
+            TypeSpec::Int16 { .. } => "int16",
+            TypeSpec::Int32 { .. } => "int32",
+            TypeSpec::Int64 { .. } => "int64",
+            TypeSpec::Uint8 { .. } => "uint8",
+            TypeSpec::Uint16 { .. } => "uint16",
+            TypeSpec::Uint32 { .. } => "uint32",
+            TypeSpec::Uint64 { .. } => "uint64",
+            TypeSpec::Decimal64 { .. } => "decimal64",
+            TypeSpec::String { .. } => "string",
+            TypeSpec::Boolean => "boolean",
+            TypeSpec::Enumeration { .. } => "enumeration",
+            TypeSpec::Bits { .. } => "bits",
+            TypeSpec::Union { .. } => "union",
+            TypeSpec::LeafRef { .. } => "leafref",
+            TypeSpec::Empty => "empty",
+            TypeSpec::Binary { .. } => "binary",
+            TypeSpec::DateAndTime { .. } => "date-and-time",
+            TypeSpec::TypedefRef { name, .. } => name,
+        }
+    }
+}
+
 /// Range constraint for numeric types.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct RangeConstraint {
     pub ranges: Vec<Range>,
 }
@@ -219,10 +1345,26 @@ impl RangeConstraint {
     pub fn validate(&self, value: i64) -> bool {
         self.ranges.iter().any(|range| range.contains(value))
     }
+
+    /// Values allowed by both constraints.
+    pub fn intersect(&self, other: &RangeConstraint) -> RangeConstraint {
+        let mut ranges = Vec::new();
+        for a in &self.ranges {
+            for b in &other.ranges {
+                let (min, max) = (a.min.max(b.min), a.max.min(b.max));
+                if min <= max {
+                    ranges.push(Range::new(min, max));
+                }
+            }
+        }
+        ranges.sort_by_key(|range| range.min);
+        RangeConstraint::new(ranges)
+    }
 }
 
 /// A single range with min and max values.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct Range {
     pub min: i64,
     pub max: i64,
@@ -240,8 +1382,52 @@ impl Range {
     }
 }
 
+/// Parse a `decimal64` value with at most `fraction_digits` digits after
+/// the decimal point, scaled by 10^`fraction_digits`.
+pub(crate) fn parse_decimal64(text: &str, fraction_digits: u8) -> Option<i64> {
+    let (negative, unsigned) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+    let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    if integer.is_empty()
+        || !digits(integer)
+        || !digits(fraction)
+        || (unsigned.contains('.') && fraction.is_empty())
+        || fraction.len() > usize::from(fraction_digits)
+    {
+        return None;
+    }
+    let padding = usize::from(fraction_digits) - fraction.len();
+    let magnitude: i128 = format!("{}{}{}", integer, fraction, "0".repeat(padding))
+        .parse()
+        .ok()?;
+    i64::try_from(if negative { -magnitude } else { magnitude }).ok()
+}
+
+/// The canonical form (RFC 7950 section 9.3.2) of the `decimal64` value
+/// `scaled` / 10^`fraction_digits`, e.g. `1.5` or `2.0`.
+pub(crate) fn format_decimal64(scaled: i64, fraction_digits: u8) -> String {
+    let divisor = 10u64.pow(u32::from(fraction_digits));
+    let magnitude = scaled.unsigned_abs();
+    let fraction = format!(
+        "{:0width$}",
+        magnitude % divisor,
+        width = usize::from(fraction_digits)
+    );
+    let fraction = fraction.trim_end_matches('0');
+    format!(
+        "{}{}.{}",
+        if scaled < 0 { "-" } else { "" },
+        magnitude / divisor,
+        if fraction.is_empty() { "0" } else { fraction }
+    )
+}
+
 /// Length constraint for string and binary types.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct LengthConstraint {
     pub lengths: Vec<LengthRange>,
 }
@@ -256,10 +1442,26 @@ impl LengthConstraint {
     pub fn validate(&self, length: u64) -> bool {
         self.lengths.iter().any(|range| range.contains(length))
     }
+
+    /// Lengths allowed by both constraints.
+    pub fn intersect(&self, other: &LengthConstraint) -> LengthConstraint {
+        let mut lengths = Vec::new();
+        for a in &self.lengths {
+            for b in &other.lengths {
+                let (min, max) = (a.min.max(b.min), a.max.min(b.max));
+                if min <= max {
+                    lengths.push(LengthRange::new(min, max));
+                }
+            }
+        }
+        lengths.sort_by_key(|range| range.min);
+        LengthConstraint::new(lengths)
+    }
 }
 
 /// A single length range.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct LengthRange {
     pub min: u64,
     pub max: u64,
@@ -279,14 +1481,33 @@ impl LengthRange {
 
 /// Pattern constraint for string types (regular expression).
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct PatternConstraint {
     pub pattern: String,
+    /// Further patterns the value must also match, from repeated `pattern`
+    /// statements or from the types this one is derived from.
+    pub additional: Vec<String>,
 }
 
 impl PatternConstraint {
     /// Create a new pattern constraint with the given regex pattern.
     pub fn new(pattern: String) -> Self {
-        Self { pattern }
+        Self {
+            pattern,
+            additional: Vec::new(),
+        }
+    }
+
+    /// Combine two constraints into one that requires both to match.
+    pub fn and(mut self, other: PatternConstraint) -> Self {
+        self.additional.push(other.pattern);
+        self.additional.extend(other.additional);
+        self
+    }
+
+    /// All patterns a value must match.
+    pub fn patterns(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.pattern.as_str()).chain(self.additional.iter().map(String::as_str))
     }
 
     /// Validate that a string matches the pattern.
@@ -302,12 +1523,46 @@ impl PatternConstraint {
 
 /// Enumeration value.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct EnumValue {
     pub name: String,
     pub value: Option<i32>,
     pub description: Option<String>,
 }
 
+impl EnumValue {
+    /// Create an enum value without an explicit `value`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: None,
+            description: None,
+        }
+    }
+}
+
+/// Bit of a `bits` type.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Bit {
+    pub name: String,
+    /// Position of the bit. Bits without a `position` statement are given
+    /// one when parsed, one greater than the highest before them.
+    pub position: Option<u32>,
+    pub description: Option<String>,
+}
+
+impl Bit {
+    /// Create a bit without an explicit `position`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            position: None,
+            description: None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -343,6 +1598,23 @@ mod tests {
         assert!(!range.contains(21));
     }
 
+    #[test]
+    fn test_decimal64_parse_and_format() {
+        assert_eq!(parse_decimal64("1.5", 2), Some(150));
+        assert_eq!(parse_decimal64("-0.05", 2), Some(-5));
+        assert_eq!(parse_decimal64("+3", 2), Some(300));
+        assert_eq!(parse_decimal64("0.125", 2), None);
+        assert_eq!(parse_decimal64("1.", 2), None);
+        assert_eq!(parse_decimal64(".5", 2), None);
+        assert_eq!(parse_decimal64("92233720368547758.08", 2), None);
+        assert_eq!(parse_decimal64("-9.223372036854775808", 18), Some(i64::MIN));
+
+        assert_eq!(format_decimal64(150, 2), "1.5");
+        assert_eq!(format_decimal64(-5, 2), "-0.05");
+        assert_eq!(format_decimal64(0, 3), "0.0");
+        assert_eq!(format_decimal64(i64::MIN, 18), "-9.223372036854775808");
+    }
+
     #[test]
     fn test_length_constraint_validation() {
         let constraint =
@@ -385,6 +1657,63 @@ mod tests {
         assert!(pattern.validate("abc"));
     }
 
+    #[test]
+    fn test_restrict_range_intersects_base_range() {
+        let mut type_spec = TypeSpec::Uint16 {
+            range: Some(RangeConstraint::new(vec![
+                Range::new(1, 100),
+                Range::new(200, 300),
+            ])),
+        };
+
+        type_spec
+            .restrict_range(RangeConstraint::new(vec![Range::new(50, 250)]))
+            .unwrap();
+        assert_eq!(
+            type_spec,
+            TypeSpec::Uint16 {
+                range: Some(RangeConstraint::new(vec![
+                    Range::new(50, 100),
+                    Range::new(200, 250)
+                ])),
+            }
+        );
+
+        assert!(type_spec
+            .restrict_range(RangeConstraint::new(vec![Range::new(400, 500)]))
+            .is_err());
+    }
+
+    #[test]
+    fn test_restrict_length_and_pattern_on_string() {
+        let mut type_spec = TypeSpec::String {
+            length: Some(LengthConstraint::new(vec![LengthRange::new(1, 64)])),
+            pattern: Some(PatternConstraint::new("[a-z]+".to_string())),
+        };
+
+        type_spec
+            .restrict_length(LengthConstraint::new(vec![LengthRange::new(8, 128)]))
+            .unwrap();
+        type_spec
+            .restrict_pattern(PatternConstraint::new("[a-m]+".to_string()))
+            .unwrap();
+
+        if let TypeSpec::String { length, pattern } = &type_spec {
+            assert_eq!(
+                length.as_ref().unwrap().lengths,
+                vec![LengthRange::new(8, 64)]
+            );
+            let patterns: Vec<&str> = pattern.as_ref().unwrap().patterns().collect();
+            assert_eq!(patterns, vec!["[a-z]+", "[a-m]+"]);
+        } else {
+            panic!("Expected String type");
+        }
+
+        assert!(type_spec
+            .restrict_range(RangeConstraint::new(vec![Range::new(1, 2)]))
+            .is_err());
+    }
+
     #[test]
     fn test_yang_version_debug() {
         let v1_0 = YangVersion::V1_0;
@@ -408,4 +1737,32 @@ mod tests {
         assert_eq!(header.namespace, "urn:test:module");
         assert_eq!(header.prefix, "test");
     }
+
+    #[test]
+    fn test_data_node_constructors_and_accessors() {
+        let mut mtu = Leaf::new("mtu", TypeSpec::Uint16 { range: None });
+        mtu.when = Some("../type = 'ethernet'".to_string());
+        let mut interface = List::new("interface", vec!["name".to_string()]);
+        interface.children.push(DataNode::Leaf(mtu));
+        let mut interfaces = Container::new("interfaces");
+        interfaces.description = Some("Network interfaces.".to_string());
+        interfaces.children.push(DataNode::List(interface));
+        let node = DataNode::Container(interfaces);
+
+        assert_eq!(node.name(), "interfaces");
+        assert_eq!(node.description(), Some("Network interfaces."));
+        assert_eq!(node.when(), None);
+        let interface = &node.children()[0];
+        assert_eq!(interface.name(), "interface");
+        let mtu = &interface.children()[0];
+        assert_eq!(mtu.when(), Some("../type = 'ethernet'"));
+        assert!(mtu.children().is_empty());
+        let DataNode::Leaf(leaf) = mtu else {
+            panic!("expected leaf");
+        };
+        assert!(leaf.config);
+        assert!(!leaf.mandatory);
+
+        assert_eq!(DataNode::Uses(Uses::new("endpoint")).name(), "endpoint");
+    }
 }
@@ -0,0 +1,568 @@
+//! Resolution of augment targets.
+//!
+//! An augment target is an absolute schema node identifier such as
+//! `/oc-if:interfaces/oc-if:interface/oc-if:config`. Each step names a node
+//! in the module its prefix refers to, so a target is resolved by walking the
+//! schema tree of the loaded modules, expanding groupings on the way and
+//! following nodes added by other modules' augments.
+//!
+//! Once groupings are expanded, [`YangParser::apply_augments`] splices the
+//! data nodes of each augment into its target, so the generated types of the
+//! target include them.
+
+use super::ast::{Augment, Case, DataNode, Status, TypeSpec, YangModule};
+use super::error::ParseError;
+use super::inheritance::inherit_config;
+use super::prefix::{PrefixTable, ResolvedName};
+use super::{visit_data_node_type_specs, DefinitionScope, YangParser};
+
+/// A node in the schema tree that can have children.
+#[derive(Clone, Copy)]
+enum SchemaNode<'a> {
+    Data(&'a DataNode),
+    /// The data nodes of an RPC's `input`, `output` or a notification.
+    Nodes(&'a [DataNode]),
+    Rpc {
+        input: &'a [DataNode],
+        output: &'a [DataNode],
+    },
+}
+
+/// A child found while resolving a target, with the module whose namespace
+/// it is in and the module its groupings are looked up in.
+struct Child<'a> {
+    namespace: &'a str,
+    scope: &'a YangModule,
+    name: &'a str,
+    node: SchemaNode<'a>,
+}
+
+impl YangParser {
+    /// Check that every augment target in the module resolves to a schema
+    /// node of the loaded modules.
+    ///
+    /// When `strict` is true the first unresolved target is returned as an
+    /// error naming both the augmenting and the target module. Otherwise the
+    /// augments with unresolved targets are removed from the module and a
+    /// warning is returned for each.
+    pub fn check_augment_targets(
+        &self,
+        module: &mut YangModule,
+        strict: bool,
+    ) -> Result<Vec<String>, ParseError> {
+        let mut unresolved = Vec::new();
+        for (index, augment) in module.augments.iter().enumerate() {
+            if let Err(e) = self.resolve_augment_target(module, augment) {
+                if strict {
+                    return Err(e);
+                }
+                unresolved.push((index, e.to_string()));
+            }
+        }
+
+        let mut warnings = Vec::new();
+        for (index, message) in unresolved.into_iter().rev() {
+            let augment = module.augments.remove(index);
+            warnings.push(format!(
+                "Skipping augment '{}': {}",
+                augment.target_path(),
+                message
+            ));
+        }
+        warnings.reverse();
+        Ok(warnings)
+    }
+
+    /// Validate that every augment target in the module resolves.
+    pub(super) fn validate_augment_targets(&self, module: &YangModule) -> Result<(), ParseError> {
+        for augment in &module.augments {
+            self.resolve_augment_target(module, augment)?;
+        }
+        Ok(())
+    }
+
+    /// Resolve an augment target of `module`.
+    fn resolve_augment_target(
+        &self,
+        module: &YangModule,
+        augment: &Augment,
+    ) -> Result<(), ParseError> {
+        let unresolved = |target_module: &str, reason: String| ParseError::SemanticError {
+            message: format!(
+                "Augment target '{}' in module '{}' does not resolve in module '{}': {}",
+                augment.target_path(),
+                module.name,
+                target_module,
+                reason
+            ),
+        };
+
+        // Qualify every step with the name of the module it refers to
+        let prefixes = PrefixTable::for_module(module);
+        let mut steps = Vec::new();
+        for step in &augment.target {
+            let qualified = match prefixes.resolve(step) {
+                Ok(ResolvedName::Local(name)) => (module.name.as_str(), name),
+                Ok(ResolvedName::Imported {
+                    module: imported,
+                    name,
+                }) => {
+                    let imported = self
+                        .loaded_module(module, &imported)
+                        .map(|m| m.name.as_str())
+                        .ok_or_else(|| {
+                            unresolved(&imported, "the module is not loaded".to_string())
+                        })?;
+                    (imported, name)
+                }
+                Err(e) => return Err(unresolved(module.name.as_str(), e.to_string())),
+            };
+            steps.push(qualified);
+        }
+
+        let Some(&(target_module, _)) = steps.first() else {
+            return Err(unresolved(&module.name, "the target is empty".to_string()));
+        };
+        let root = self
+            .loaded_module(module, target_module)
+            .expect("step modules are loaded");
+
+        let mut children = self.top_level_children(root);
+        for (depth, &(namespace, name)) in steps.iter().enumerate() {
+            // Nodes in another module's namespace were added by its augments
+            if depth > 0 && !children.iter().any(|child| child.namespace == namespace) {
+                let augmenting = self
+                    .loaded_module(module, namespace)
+                    .expect("step modules are loaded");
+                children.extend(self.augmented_children(augmenting, &steps[..depth]));
+            }
+
+            let Some(child) = children
+                .into_iter()
+                .find(|child| child.namespace == namespace && child.name == name)
+            else {
+                let path: Vec<&str> = steps[..=depth].iter().map(|(_, name)| *name).collect();
+                return Err(unresolved(
+                    target_module,
+                    format!("no schema node '/{}'", path.join("/")),
+                ));
+            };
+            children = self.children_of(&child);
+        }
+        Ok(())
+    }
+
+    /// Look up a module by name, preferring the module being checked.
+    fn loaded_module<'a>(&'a self, current: &'a YangModule, name: &str) -> Option<&'a YangModule> {
+        if current.name == name {
+            Some(current)
+        } else {
+            self.loaded_modules.get(name)
+        }
+    }
+
+    /// The data nodes, RPCs and notifications at the top of a module.
+    fn top_level_children<'a>(&'a self, module: &'a YangModule) -> Vec<Child<'a>> {
+        let mut children = self.data_children(&module.name, module, &module.data_nodes);
+        for rpc in &module.rpcs {
+            children.push(Child {
+                namespace: &module.name,
+                scope: module,
+                name: &rpc.name,
+                node: SchemaNode::Rpc {
+                    input: rpc.input.as_deref().unwrap_or_default(),
+                    output: rpc.output.as_deref().unwrap_or_default(),
+                },
+            });
+        }
+        for notification in &module.notifications {
+            children.push(Child {
+                namespace: &module.name,
+                scope: module,
+                name: &notification.name,
+                node: SchemaNode::Nodes(&notification.data_nodes),
+            });
+        }
+        children
+    }
+
+    /// The nodes `module` adds through augments of the given target.
+    fn augmented_children<'a>(
+        &'a self,
+        module: &'a YangModule,
+        target: &[(&str, &str)],
+    ) -> Vec<Child<'a>> {
+        let prefixes = PrefixTable::for_module(module);
+        let targets_path = |augment: &Augment| {
+            augment.target.len() == target.len()
+                && augment.target.iter().zip(target).all(|(step, expected)| {
+                    let qualified = match prefixes.resolve(step) {
+                        Ok(ResolvedName::Local(name)) => (module.name.clone(), name),
+                        Ok(ResolvedName::Imported { module, name }) => (module, name),
+                        Err(_) => return false,
+                    };
+                    qualified.0 == expected.0 && qualified.1 == expected.1
+                })
+        };
+
+        module
+            .augments
+            .iter()
+            .filter(|augment| targets_path(augment))
+            .flat_map(|augment| self.data_children(&module.name, module, &augment.data_nodes))
+            .collect()
+    }
+
+    /// The children of a node found on the target path.
+    fn children_of<'a>(&'a self, child: &Child<'a>) -> Vec<Child<'a>> {
+        let data = |nodes| self.data_children(child.namespace, child.scope, nodes);
+        match child.node {
+            SchemaNode::Data(DataNode::Container(container)) => data(&container.children),
+            SchemaNode::Data(DataNode::List(list)) => data(&list.children),
+            SchemaNode::Data(DataNode::Case(case)) => data(&case.data_nodes),
+            SchemaNode::Data(DataNode::Choice(choice)) => choice
+                .cases
+                .iter()
+                .map(|case| Child {
+                    namespace: child.namespace,
+                    scope: child.scope,
+                    name: &case.name,
+                    node: SchemaNode::Nodes(&case.data_nodes),
+                })
+                .collect(),
+            SchemaNode::Data(_) => Vec::new(),
+            SchemaNode::Nodes(nodes) => data(nodes),
+            SchemaNode::Rpc { input, output } => ["input", "output"]
+                .into_iter()
+                .zip([input, output])
+                .map(|(name, nodes)| Child {
+                    namespace: child.namespace,
+                    scope: child.scope,
+                    name,
+                    node: SchemaNode::Nodes(nodes),
+                })
+                .collect(),
+        }
+    }
+
+    /// Named schema nodes among `nodes`, with groupings expanded in place.
+    fn data_children<'a>(
+        &'a self,
+        namespace: &'a str,
+        scope: &'a YangModule,
+        nodes: &'a [DataNode],
+    ) -> Vec<Child<'a>> {
+        let mut children = Vec::new();
+        for node in nodes {
+            let name = match node {
+                DataNode::Container(container) => &container.name,
+                DataNode::List(list) => &list.name,
+                DataNode::Leaf(leaf) => &leaf.name,
+                DataNode::LeafList(leaf_list) => &leaf_list.name,
+                DataNode::AnyData(anydata) => &anydata.name,
+                DataNode::Choice(choice) => &choice.name,
+                DataNode::Case(case) => &case.name,
+                DataNode::Uses(uses) => {
+                    // Grouping nodes take the namespace of the module using them
+                    let definitions = DefinitionScope::for_module(scope, &self.loaded_modules);
+                    if let Ok(Some((grouping, _))) = definitions.find_grouping(&uses.name) {
+                        let grouping_scope = match definitions.prefixes.resolve(&uses.name) {
+                            Ok(ResolvedName::Imported { module, .. }) => {
+                                self.loaded_modules.get(&module).unwrap_or(scope)
+                            }
+                            _ => scope,
+                        };
+                        children.extend(self.data_children(
+                            namespace,
+                            grouping_scope,
+                            &grouping.data_nodes,
+                        ));
+                    }
+                    continue;
+                }
+            };
+            children.push(Child {
+                namespace,
+                scope,
+                name,
+                node: SchemaNode::Data(node),
+            });
+        }
+        children
+    }
+
+    /// Add the data nodes of augments to the schema nodes they target.
+    ///
+    /// Augments targeting a node of one of `modules`, the augmenting module
+    /// included, are applied and removed from the augmenting module; other
+    /// augments are kept. Nodes added to a node of another module are named
+    /// `module:name`, their RFC 7951 member name. The augmenting module's own
+    /// typedefs are inlined into nodes added to another module's tree, since
+    /// the generated code of that module declares no aliases for them.
+    ///
+    /// Groupings are expected to be expanded, as by
+    /// [`expand_groupings`](Self::expand_groupings).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a typedef used by a node added to another module
+    /// cannot be resolved.
+    pub fn apply_augments(&self, modules: &mut [YangModule]) -> Result<(), ParseError> {
+        // Augments may target nodes added by other augments, so passes are
+        // repeated until no augment finds its target
+        loop {
+            let mut applied = false;
+            for index in 0..modules.len() {
+                let mut kept = Vec::new();
+                for augment in std::mem::take(&mut modules[index].augments) {
+                    if self.apply_augment(modules, index, &augment)? {
+                        applied = true;
+                    } else {
+                        kept.push(augment);
+                    }
+                }
+                modules[index].augments = kept;
+            }
+            if !applied {
+                // Added nodes below state data are state data too
+                for module in modules.iter_mut() {
+                    inherit_config(&mut module.data_nodes, true);
+                }
+                return Ok(());
+            }
+        }
+    }
+
+    /// Splice the data nodes of an augment of `modules[index]` into its
+    /// target, returning whether the target was found.
+    fn apply_augment(
+        &self,
+        modules: &mut [YangModule],
+        index: usize,
+        augment: &Augment,
+    ) -> Result<bool, ParseError> {
+        let Some(target) = resolve_target(modules, index, &augment.target) else {
+            return Ok(false);
+        };
+
+        let module = &modules[index];
+        let mut nodes = augment.data_nodes.clone();
+        if target.module != index {
+            let scope = DefinitionScope::for_module(module, &self.loaded_modules);
+            visit_data_node_type_specs(&mut nodes, &mut |type_spec| match type_spec {
+                TypeSpec::TypedefRef { name, .. } if !name.contains(':') => {
+                    Self::expand_typedef_in_typespec(type_spec, &scope)
+                }
+                _ => Ok(()),
+            })?;
+        }
+        // Members are qualified where their namespace differs from their parent's
+        if target.namespace != module.name {
+            for node in &mut nodes {
+                qualify(node, &module.name);
+            }
+        }
+
+        let Some(children) = find_children(&mut modules[target.module], &target.names) else {
+            return Ok(false);
+        };
+        children.extend(nodes);
+        Ok(true)
+    }
+}
+
+/// The target of an augment or deviation among the modules being expanded.
+pub(super) struct Target {
+    /// Index of the module whose tree holds the target.
+    pub(super) module: usize,
+    /// Names of the nodes on the path, `module:name` for nodes added by
+    /// other modules.
+    pub(super) names: Vec<String>,
+    /// The module whose namespace the target node is in.
+    pub(super) namespace: String,
+}
+
+/// Resolve a target of `modules[index]`, if the module whose tree it is in
+/// is among `modules`.
+pub(super) fn resolve_target(
+    modules: &[YangModule],
+    index: usize,
+    target: &[String],
+) -> Option<Target> {
+    let module = &modules[index];
+    let prefixes = PrefixTable::for_module(module);
+    let mut steps: Vec<(String, String)> = Vec::new();
+    for step in target {
+        match prefixes.resolve(step).ok()? {
+            ResolvedName::Local(name) => steps.push((module.name.clone(), name.to_string())),
+            ResolvedName::Imported { module, name } => steps.push((module, name.to_string())),
+        }
+    }
+    let (first, _) = steps.first()?;
+    let tree = modules.iter().position(|module| module.name == *first)?;
+    // Nodes from other modules' augments carry their module's name
+    let names = steps
+        .iter()
+        .map(|(namespace, name)| {
+            if *namespace == *first {
+                name.clone()
+            } else {
+                format!("{}:{}", namespace, name)
+            }
+        })
+        .collect();
+    let (namespace, _) = steps.pop()?;
+    Some(Target {
+        module: tree,
+        names,
+        namespace,
+    })
+}
+
+/// Where the data nodes of an augment are added.
+pub(super) enum Children<'a> {
+    Nodes(&'a mut Vec<DataNode>),
+    /// The `input` or `output` of an RPC, which may not be defined yet.
+    Optional(&'a mut Option<Vec<DataNode>>),
+    Cases(&'a mut Vec<Case>),
+}
+
+impl Children<'_> {
+    /// Add the data nodes of an augment.
+    pub(super) fn extend(self, nodes: Vec<DataNode>) {
+        match self {
+            Children::Nodes(children) => children.extend(nodes),
+            Children::Optional(children) => children.get_or_insert_with(Vec::new).extend(nodes),
+            Children::Cases(cases) => {
+                // Nodes other than cases are shorthand for a case of their own
+                cases.extend(nodes.into_iter().map(|node| match node {
+                    DataNode::Case(case) => case,
+                    node => Case {
+                        name: data_node_name(&node).unwrap_or_default().to_string(),
+                        description: None,
+                        data_nodes: vec![node],
+                        if_features: vec![],
+                        when: None,
+                        unknown_statements: vec![],
+                        status: Status::Current,
+                    },
+                }))
+            }
+        }
+    }
+}
+
+/// The children of the schema node at the path `names` of `module`, if the
+/// node exists and can have children.
+pub(super) fn find_children<'a>(
+    module: &'a mut YangModule,
+    names: &[String],
+) -> Option<Children<'a>> {
+    let (first, rest) = names.split_first()?;
+    if module
+        .data_nodes
+        .iter()
+        .any(|node| data_node_name(node) == Some(first.as_str()))
+    {
+        return node_children(&mut module.data_nodes, names);
+    }
+    if let Some(rpc) = module.rpcs.iter_mut().find(|rpc| rpc.name == *first) {
+        let (step, rest) = rest.split_first()?;
+        let nodes = match step.as_str() {
+            "input" => &mut rpc.input,
+            "output" => &mut rpc.output,
+            _ => return None,
+        };
+        if rest.is_empty() {
+            return Some(Children::Optional(nodes));
+        }
+        return node_children(nodes.as_mut()?, rest);
+    }
+    let notification = module
+        .notifications
+        .iter_mut()
+        .find(|notification| notification.name == *first)?;
+    descend(&mut notification.data_nodes, rest)
+}
+
+/// The children of the node at the path `names` among `nodes`, such as the
+/// target of an augment inside a `uses` among the grouping's nodes.
+pub(super) fn node_children<'a>(
+    nodes: &'a mut [DataNode],
+    names: &[String],
+) -> Option<Children<'a>> {
+    let (first, rest) = names.split_first()?;
+    let node = nodes
+        .iter_mut()
+        .find(|node| data_node_name(node) == Some(first.as_str()))?;
+    match node {
+        DataNode::Container(container) => descend(&mut container.children, rest),
+        DataNode::List(list) => descend(&mut list.children, rest),
+        DataNode::Case(case) => descend(&mut case.data_nodes, rest),
+        DataNode::Choice(choice) => {
+            let Some((step, rest)) = rest.split_first() else {
+                return Some(Children::Cases(&mut choice.cases));
+            };
+            let case = choice.cases.iter_mut().find(|case| case.name == *step)?;
+            descend(&mut case.data_nodes, rest)
+        }
+        DataNode::Leaf(_) | DataNode::LeafList(_) | DataNode::AnyData(_) | DataNode::Uses(_) => {
+            None
+        }
+    }
+}
+
+/// `children` itself at the end of a path, otherwise the children of the
+/// node at the rest of the path.
+fn descend<'a>(children: &'a mut Vec<DataNode>, names: &[String]) -> Option<Children<'a>> {
+    if names.is_empty() {
+        Some(Children::Nodes(children))
+    } else {
+        node_children(children, names)
+    }
+}
+
+/// The name of a data node, `None` for uses.
+pub(super) fn data_node_name(node: &DataNode) -> Option<&str> {
+    match node {
+        DataNode::Container(container) => Some(&container.name),
+        DataNode::List(list) => Some(&list.name),
+        DataNode::Leaf(leaf) => Some(&leaf.name),
+        DataNode::LeafList(leaf_list) => Some(&leaf_list.name),
+        DataNode::AnyData(anydata) => Some(&anydata.name),
+        DataNode::Choice(choice) => Some(&choice.name),
+        DataNode::Case(case) => Some(&case.name),
+        DataNode::Uses(_) => None,
+    }
+}
+
+/// Name a node added to another module's tree `module:name`.
+///
+/// Choices and cases have no member of their own, so the nodes of their
+/// cases are named too.
+fn qualify(node: &mut DataNode, module: &str) {
+    let qualified = |name: &mut String| *name = format!("{}:{}", module, name);
+    match node {
+        DataNode::Container(container) => qualified(&mut container.name),
+        DataNode::List(list) => qualified(&mut list.name),
+        DataNode::Leaf(leaf) => qualified(&mut leaf.name),
+        DataNode::LeafList(leaf_list) => qualified(&mut leaf_list.name),
+        DataNode::AnyData(anydata) => qualified(&mut anydata.name),
+        DataNode::Choice(choice) => {
+            qualified(&mut choice.name);
+            for case in &mut choice.cases {
+                qualified(&mut case.name);
+                for node in &mut case.data_nodes {
+                    qualify(node, module);
+                }
+            }
+        }
+        DataNode::Case(case) => {
+            qualified(&mut case.name);
+            for node in &mut case.data_nodes {
+                qualify(node, module);
+            }
+        }
+        DataNode::Uses(_) => {}
+    }
+}
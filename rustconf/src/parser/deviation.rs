@@ -0,0 +1,233 @@
+//! Application of deviations.
+//!
+//! A deviation module describes how a server implements the nodes of another
+//! module: `deviate not-supported` removes a node, and `deviate add`,
+//! `replace` and `delete` change its type, units, default, config and
+//! mandatory properties. [`YangParser::apply_deviations`] applies them to the
+//! modules being generated, so the generated types match what the server
+//! implements. Targets are resolved as augment targets are, and may name
+//! nodes added by augments.
+
+use super::ast::{Case, DataNode, Deviate, Deviation, Notification, Rpc, TypeSpec, YangModule};
+use super::augment::{data_node_name, find_children, resolve_target, Children};
+use super::error::ParseError;
+use super::inheritance::inherit_config;
+use super::{DefinitionScope, YangParser};
+
+/// The nodes among which a deviation target is found.
+enum Siblings<'a> {
+    Nodes(&'a mut Vec<DataNode>),
+    Cases(&'a mut Vec<Case>),
+    Rpcs(&'a mut Vec<Rpc>),
+    Notifications(&'a mut Vec<Notification>),
+}
+
+impl YangParser {
+    /// Apply the deviations of `modules` to the schema nodes they target.
+    ///
+    /// Deviations targeting a node of one of `modules`, the deviating module
+    /// included, are applied and removed from the deviating module; other
+    /// deviations are kept. A type replaced in another module's node using
+    /// the deviating module's own typedefs has them inlined.
+    ///
+    /// Augments are expected to be applied first, as by
+    /// [`apply_augments`](Self::apply_augments).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a target does not exist in its module, if a
+    /// property does not apply to the target, if `deviate add` adds units or
+    /// a default the target already has, or if a replaced type uses a
+    /// typedef that cannot be resolved.
+    pub fn apply_deviations(&self, modules: &mut [YangModule]) -> Result<(), ParseError> {
+        for index in 0..modules.len() {
+            let mut kept = Vec::new();
+            for deviation in std::mem::take(&mut modules[index].deviations) {
+                if !self.apply_deviation(modules, index, &deviation)? {
+                    kept.push(deviation);
+                }
+            }
+            modules[index].deviations = kept;
+        }
+        // A node deviated to config false makes its descendants state data
+        for module in modules.iter_mut() {
+            inherit_config(&mut module.data_nodes, true);
+        }
+        Ok(())
+    }
+
+    /// Apply a deviation of `modules[index]`, returning whether the module
+    /// holding its target is among `modules`.
+    fn apply_deviation(
+        &self,
+        modules: &mut [YangModule],
+        index: usize,
+        deviation: &Deviation,
+    ) -> Result<bool, ParseError> {
+        let Some(target) = resolve_target(modules, index, &deviation.target) else {
+            return Ok(false);
+        };
+
+        let module = &modules[index];
+        let mut deviates = deviation.deviates.clone();
+        if target.module != index {
+            let scope = DefinitionScope::for_module(module, &self.loaded_modules);
+            for type_spec in deviates.iter_mut().filter_map(Deviate::type_spec_mut) {
+                if matches!(type_spec, TypeSpec::TypedefRef { name, .. } if !name.contains(':')) {
+                    Self::expand_typedef_in_typespec(type_spec, &scope)?;
+                }
+            }
+        }
+
+        let deviating = module.name.clone();
+        let error = |message: String| ParseError::SemanticError {
+            message: format!(
+                "Deviation '{}' in module '{}': {}",
+                deviation.target_path(),
+                deviating,
+                message
+            ),
+        };
+        let tree = &mut modules[target.module];
+        let not_found = format!(
+            "no schema node '/{}' in module '{}'",
+            target.names.join("/"),
+            tree.name
+        );
+        let name = target.names.last().map(String::as_str).unwrap_or_default();
+        let Some(siblings) = siblings(tree, &target.names) else {
+            return Err(error(not_found));
+        };
+
+        if deviates.contains(&Deviate::NotSupported) {
+            let removed = match siblings {
+                Siblings::Nodes(nodes) => remove(nodes, |node| data_node_name(node) == Some(name)),
+                Siblings::Cases(cases) => remove(cases, |case| case.name == name),
+                Siblings::Rpcs(rpcs) => remove(rpcs, |rpc| rpc.name == name),
+                Siblings::Notifications(notifications) => {
+                    remove(notifications, |notification| notification.name == name)
+                }
+            };
+            return if removed {
+                Ok(true)
+            } else {
+                Err(error(not_found))
+            };
+        }
+
+        let node = match siblings {
+            Siblings::Nodes(nodes) => nodes
+                .iter_mut()
+                .find(|node| data_node_name(node) == Some(name))
+                .ok_or_else(|| error(not_found))?,
+            Siblings::Cases(_) | Siblings::Rpcs(_) | Siblings::Notifications(_) => {
+                return Err(error(format!("'{}' has no properties to deviate", name)))
+            }
+        };
+        for deviate in &deviates {
+            deviate_properties(node, deviate).map_err(error)?;
+        }
+        Ok(true)
+    }
+}
+
+/// The nodes among which the node at the path `names` of `module` is, if
+/// its parent exists.
+fn siblings<'a>(module: &'a mut YangModule, names: &[String]) -> Option<Siblings<'a>> {
+    let (name, parent) = names.split_last()?;
+    if !parent.is_empty() {
+        return match find_children(module, parent)? {
+            Children::Nodes(nodes) => Some(Siblings::Nodes(nodes)),
+            Children::Optional(nodes) => nodes.as_mut().map(Siblings::Nodes),
+            Children::Cases(cases) => Some(Siblings::Cases(cases)),
+        };
+    }
+    if module.rpcs.iter().any(|rpc| rpc.name == *name) {
+        Some(Siblings::Rpcs(&mut module.rpcs))
+    } else if module
+        .notifications
+        .iter()
+        .any(|notification| notification.name == *name)
+    {
+        Some(Siblings::Notifications(&mut module.notifications))
+    } else {
+        Some(Siblings::Nodes(&mut module.data_nodes))
+    }
+}
+
+/// Remove the first item matching `matches`, returning whether one did.
+fn remove<T>(items: &mut Vec<T>, matches: impl Fn(&T) -> bool) -> bool {
+    match items.iter().position(matches) {
+        Some(position) => {
+            items.remove(position);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Change the properties of `node` as `deviate` does.
+fn deviate_properties(node: &mut DataNode, deviate: &Deviate) -> Result<(), String> {
+    let properties = match deviate {
+        Deviate::NotSupported => return Ok(()),
+        Deviate::Add(properties) | Deviate::Replace(properties) | Deviate::Delete(properties) => {
+            properties
+        }
+    };
+    let name = data_node_name(node).unwrap_or_default().to_string();
+    let not_applicable = |property: &str| format!("'{}' has no {}", name, property);
+
+    if let Some(type_spec) = &properties.type_spec {
+        match node {
+            DataNode::Leaf(leaf) => leaf.type_spec = type_spec.clone(),
+            DataNode::LeafList(leaf_list) => leaf_list.type_spec = type_spec.clone(),
+            _ => return Err(not_applicable("type")),
+        }
+    }
+
+    for (property, value) in [
+        ("units", &properties.units),
+        ("default", &properties.default),
+    ] {
+        let Some(value) = value else {
+            continue;
+        };
+        let DataNode::Leaf(leaf) = &mut *node else {
+            return Err(not_applicable(property));
+        };
+        let current = if property == "units" {
+            &mut leaf.units
+        } else {
+            &mut leaf.default
+        };
+        match deviate {
+            Deviate::Add(_) if current.is_some() => {
+                return Err(format!(
+                    "'{}' already has a {}; use deviate replace",
+                    name, property
+                ))
+            }
+            Deviate::Delete(_) => *current = None,
+            _ => *current = Some(value.clone()),
+        }
+    }
+
+    if let Some(config) = properties.config {
+        match node {
+            DataNode::Container(container) => container.config = config,
+            DataNode::List(list) => list.config = config,
+            DataNode::Leaf(leaf) => leaf.config = config,
+            DataNode::LeafList(leaf_list) => leaf_list.config = config,
+            _ => return Err(not_applicable("config")),
+        }
+    }
+
+    if let Some(mandatory) = properties.mandatory {
+        match node {
+            DataNode::Leaf(leaf) => leaf.mandatory = mandatory,
+            DataNode::Choice(choice) => choice.mandatory = mandatory,
+            _ => return Err(not_applicable("mandatory")),
+        }
+    }
+    Ok(())
+}
@@ -21,4 +21,7 @@ pub enum ParseError {
 
     #[error("Unresolved import: {module}")]
     UnresolvedImport { module: String },
+
+    #[error("Unresolved include: {submodule}")]
+    UnresolvedInclude { submodule: String },
 }
@@ -0,0 +1,203 @@
+//! Features and `if-feature` conditions.
+//!
+//! `feature` statements name optional parts of a module, and `if-feature`
+//! statements make definitions conditional on them. A condition is a single
+//! feature name in YANG 1.0 and a boolean expression over feature names
+//! (`not`, `and`, `or` and parentheses) in YANG 1.1. Conditions are parsed
+//! with the statements and their names qualified with the defining module
+//! once the module is complete; which features are supported is decided
+//! when code is generated.
+
+use super::ast::{Augment, DataNode, Feature, Grouping, IfFeature, Notification, Rpc, YangModule};
+use super::error::ParseError;
+use super::prefix::{PrefixTable, ResolvedName};
+
+/// Parse the argument of an `if-feature` statement.
+///
+/// `not` binds tighter than `and`, which binds tighter than `or`.
+pub(super) fn parse_if_feature_expression(text: &str) -> Result<IfFeature, String> {
+    let spaced = text.replace('(', " ( ").replace(')', " ) ");
+    let tokens: Vec<&str> = spaced.split_whitespace().collect();
+    let mut position = 0;
+    let condition = parse_or(&tokens, &mut position, text)?;
+    match tokens.get(position) {
+        None => Ok(condition),
+        Some(token) => Err(format!("Unexpected '{}' in if-feature \"{}\"", token, text)),
+    }
+}
+
+fn parse_or(tokens: &[&str], position: &mut usize, text: &str) -> Result<IfFeature, String> {
+    let mut condition = parse_and(tokens, position, text)?;
+    while tokens.get(*position) == Some(&"or") {
+        *position += 1;
+        let right = parse_and(tokens, position, text)?;
+        condition = IfFeature::Or(Box::new(condition), Box::new(right));
+    }
+    Ok(condition)
+}
+
+fn parse_and(tokens: &[&str], position: &mut usize, text: &str) -> Result<IfFeature, String> {
+    let mut condition = parse_factor(tokens, position, text)?;
+    while tokens.get(*position) == Some(&"and") {
+        *position += 1;
+        let right = parse_factor(tokens, position, text)?;
+        condition = IfFeature::And(Box::new(condition), Box::new(right));
+    }
+    Ok(condition)
+}
+
+fn parse_factor(tokens: &[&str], position: &mut usize, text: &str) -> Result<IfFeature, String> {
+    let token = tokens
+        .get(*position)
+        .ok_or_else(|| format!("Incomplete if-feature \"{}\"", text))?;
+    *position += 1;
+    match *token {
+        "not" => Ok(IfFeature::Not(Box::new(parse_factor(
+            tokens, position, text,
+        )?))),
+        "(" => {
+            let condition = parse_or(tokens, position, text)?;
+            if tokens.get(*position) != Some(&")") {
+                return Err(format!("Unbalanced parentheses in if-feature \"{}\"", text));
+            }
+            *position += 1;
+            Ok(condition)
+        }
+        ")" | "and" | "or" => Err(format!("Unexpected '{}' in if-feature \"{}\"", token, text)),
+        name => Ok(IfFeature::Feature(name.to_string())),
+    }
+}
+
+/// Qualify the feature names of every condition in a (sub)module body with
+/// the module defining the feature, resolving prefixes with `prefixes`.
+pub(super) fn qualify_feature_references(
+    features: &mut [Feature],
+    groupings: &mut [Grouping],
+    data_nodes: &mut [DataNode],
+    augments: &mut [Augment],
+    rpcs: &mut [Rpc],
+    notifications: &mut [Notification],
+    prefixes: &PrefixTable,
+) -> Result<(), ParseError> {
+    let mut qualify = |reference: &str| -> Result<String, ParseError> {
+        Ok(match prefixes.resolve(reference)? {
+            ResolvedName::Local(name) => format!("{}:{}", prefixes.module(), name),
+            ResolvedName::Imported { module, name } => format!("{}:{}", module, name),
+        })
+    };
+    for feature in features {
+        for condition in &mut feature.if_features {
+            condition.rename_features(&mut qualify)?;
+        }
+    }
+    for grouping in groupings {
+        qualify_data_nodes(&mut grouping.data_nodes, &mut qualify)?;
+    }
+    qualify_data_nodes(data_nodes, &mut qualify)?;
+    for augment in augments {
+        qualify_data_nodes(&mut augment.data_nodes, &mut qualify)?;
+    }
+    for rpc in rpcs {
+        for nodes in [&mut rpc.input, &mut rpc.output].into_iter().flatten() {
+            qualify_data_nodes(nodes, &mut qualify)?;
+        }
+    }
+    for notification in notifications {
+        qualify_data_nodes(&mut notification.data_nodes, &mut qualify)?;
+    }
+    Ok(())
+}
+
+fn qualify_data_nodes(
+    nodes: &mut [DataNode],
+    qualify: &mut impl FnMut(&str) -> Result<String, ParseError>,
+) -> Result<(), ParseError> {
+    for node in nodes {
+        for condition in node.if_features_mut() {
+            condition.rename_features(qualify)?;
+        }
+        match node {
+            DataNode::Container(container) => qualify_data_nodes(&mut container.children, qualify)?,
+            DataNode::List(list) => qualify_data_nodes(&mut list.children, qualify)?,
+            DataNode::Choice(choice) => {
+                for case in &mut choice.cases {
+                    for condition in &mut case.if_features {
+                        condition.rename_features(qualify)?;
+                    }
+                    qualify_data_nodes(&mut case.data_nodes, qualify)?;
+                }
+            }
+            DataNode::Case(case) => qualify_data_nodes(&mut case.data_nodes, qualify)?,
+            DataNode::Uses(uses) => {
+                for augment in &mut uses.augments {
+                    qualify_data_nodes(&mut augment.data_nodes, qualify)?;
+                }
+            }
+            DataNode::Leaf(_) | DataNode::LeafList(_) | DataNode::AnyData(_) => {}
+        }
+    }
+    Ok(())
+}
+
+/// Check that every condition naming a feature of `module` itself names a
+/// feature the module defines.
+pub(super) fn check_local_features(module: &YangModule) -> Result<(), ParseError> {
+    let mut conditions: Vec<&IfFeature> = module
+        .features
+        .iter()
+        .flat_map(|feature| &feature.if_features)
+        .collect();
+    for grouping in &module.groupings {
+        collect_conditions(&grouping.data_nodes, &mut conditions);
+    }
+    collect_conditions(&module.data_nodes, &mut conditions);
+    for augment in &module.augments {
+        collect_conditions(&augment.data_nodes, &mut conditions);
+    }
+    for rpc in &module.rpcs {
+        for nodes in [&rpc.input, &rpc.output].into_iter().flatten() {
+            collect_conditions(nodes, &mut conditions);
+        }
+    }
+    for notification in &module.notifications {
+        collect_conditions(&notification.data_nodes, &mut conditions);
+    }
+
+    for name in conditions.iter().flat_map(|condition| condition.features()) {
+        let Some((defining_module, feature)) = name.split_once(':') else {
+            continue;
+        };
+        if defining_module == module.name && !module.features.iter().any(|f| f.name == feature) {
+            return Err(ParseError::SemanticError {
+                message: format!(
+                    "Undefined feature '{}' in if-feature of module '{}'",
+                    feature, module.name
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn collect_conditions<'m>(nodes: &'m [DataNode], conditions: &mut Vec<&'m IfFeature>) {
+    for node in nodes {
+        conditions.extend(node.if_features());
+        match node {
+            DataNode::Container(container) => collect_conditions(&container.children, conditions),
+            DataNode::List(list) => collect_conditions(&list.children, conditions),
+            DataNode::Choice(choice) => {
+                for case in &choice.cases {
+                    conditions.extend(&case.if_features);
+                    collect_conditions(&case.data_nodes, conditions);
+                }
+            }
+            DataNode::Case(case) => collect_conditions(&case.data_nodes, conditions),
+            DataNode::Uses(uses) => {
+                for augment in &uses.augments {
+                    collect_conditions(&augment.data_nodes, conditions);
+                }
+            }
+            DataNode::Leaf(_) | DataNode::LeafList(_) | DataNode::AnyData(_) => {}
+        }
+    }
+}
@@ -0,0 +1,483 @@
+//! Source formatter for YANG files.
+//!
+//! The formatter works on the lossless token stream produced by
+//! [`Lexer::tokenize_lossless`], so comments survive formatting. It rebuilds a
+//! generic statement tree and prints it back following the RFC 8407 style
+//! guidelines:
+//!
+//! - one statement per line, indented by a fixed number of spaces per level
+//! - double quotes for string arguments unless the content requires single
+//!   quotes, with `pattern` arguments left as written
+//! - arguments that start on their own line (long strings and `+` concatenations)
+//!   stay on their own line, indented one level deeper than the keyword
+//! - module header statements ordered as header, linkage, meta and revision
+//!   statements ahead of the body, with revisions newest first
+//!
+//! Argument text is otherwise preserved verbatim, except that the continuation
+//! lines of multi-line double-quoted strings move along with the opening quote:
+//! their indentation is relative to its column (RFC 7950 section 6.1.3), so the
+//! value of the string stays the same.
+
+use super::error::ParseError;
+use super::lexer::{display_width, strip_indentation, Lexer, SpannedToken, Token, Trivia};
+
+/// Statements whose argument is free-form text and should always be quoted.
+const TEXT_STATEMENTS: &[&str] = &["contact", "description", "organization", "reference"];
+
+/// Formatter for YANG source text.
+#[derive(Debug, Clone)]
+pub struct YangFormatter {
+    indent: usize,
+}
+
+impl Default for YangFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl YangFormatter {
+    /// Create a formatter using the RFC 8407 recommended two-space indentation.
+    pub fn new() -> Self {
+        Self { indent: 2 }
+    }
+
+    /// Set the number of spaces used per indentation level.
+    pub fn with_indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Format YANG source text.
+    ///
+    /// Formatting is idempotent: formatting already formatted text returns it unchanged.
+    pub fn format(&self, source: &str) -> Result<String, ParseError> {
+        let tokens =
+            Lexer::new(source)
+                .tokenize_lossless()
+                .map_err(|message| ParseError::SyntaxError {
+                    line: 1,
+                    column: 1,
+                    message,
+                })?;
+
+        let mut reader = StatementReader::new(source, &tokens);
+        let (mut statements, trailing_comments) = reader.read_block()?;
+        if let Some(token) = reader.tokens.get(reader.pos) {
+            if token.token != Token::Eof {
+                return Err(syntax_error(token, "unexpected '}'"));
+            }
+        }
+
+        for statement in &mut statements {
+            if matches!(statement.keyword.as_str(), "module" | "submodule") {
+                order_module_statements(statement);
+            }
+        }
+
+        let mut output = String::new();
+        for (index, statement) in statements.iter().enumerate() {
+            self.write_statement(&mut output, statement, 0, index == 0);
+        }
+        for comment in &trailing_comments {
+            output.push_str(comment);
+            output.push('\n');
+        }
+        Ok(output)
+    }
+
+    /// Check whether the source is already formatted.
+    pub fn is_formatted(&self, source: &str) -> Result<bool, ParseError> {
+        Ok(self.format(source)? == source)
+    }
+
+    fn write_statement(&self, out: &mut String, statement: &Statement, depth: usize, first: bool) {
+        let indent = " ".repeat(self.indent * depth);
+        if statement.blank_line_before && !first {
+            out.push('\n');
+        }
+        for comment in &statement.leading_comments {
+            out.push_str(&indent);
+            out.push_str(comment);
+            out.push('\n');
+        }
+
+        out.push_str(&indent);
+        out.push_str(&statement.keyword);
+        for argument in &statement.arguments {
+            if argument.on_new_line {
+                out.push('\n');
+                out.push_str(&" ".repeat(self.indent * (depth + 1)));
+            } else {
+                out.push(' ');
+            }
+            let line_start = out.rfind('\n').map_or(0, |i| i + 1);
+            let column = display_width(&out[line_start..]);
+            match argument.string_column {
+                Some(from) => out.push_str(&reindent_string(&argument.text, from, column)),
+                None => out.push_str(&argument.text),
+            }
+        }
+
+        match &statement.children {
+            Some(children) if !children.is_empty() || !statement.closing_comments.is_empty() => {
+                out.push_str(" {");
+                push_trailing_comment(out, statement.trailing_comment.as_deref());
+                for (index, child) in children.iter().enumerate() {
+                    self.write_statement(out, child, depth + 1, index == 0);
+                }
+                let inner_indent = " ".repeat(self.indent * (depth + 1));
+                for comment in &statement.closing_comments {
+                    out.push_str(&inner_indent);
+                    out.push_str(comment);
+                    out.push('\n');
+                }
+                out.push_str(&indent);
+                out.push('}');
+                push_trailing_comment(out, statement.closing_trailing_comment.as_deref());
+            }
+            _ => {
+                // An empty block is equivalent to a terminating semicolon
+                out.push(';');
+                push_trailing_comment(
+                    out,
+                    statement
+                        .trailing_comment
+                        .as_deref()
+                        .or(statement.closing_trailing_comment.as_deref()),
+                );
+            }
+        }
+    }
+}
+
+/// Format YANG source text with the default formatter settings.
+pub fn format_yang(source: &str) -> Result<String, ParseError> {
+    YangFormatter::new().format(source)
+}
+
+fn push_trailing_comment(out: &mut String, comment: Option<&str>) {
+    if let Some(comment) = comment {
+        out.push(' ');
+        out.push_str(comment);
+    }
+    out.push('\n');
+}
+
+/// A statement in the generic YANG statement tree.
+#[derive(Debug, Default)]
+struct Statement {
+    leading_comments: Vec<String>,
+    blank_line_before: bool,
+    keyword: String,
+    arguments: Vec<Argument>,
+    trailing_comment: Option<String>,
+    children: Option<Vec<Statement>>,
+    closing_comments: Vec<String>,
+    closing_trailing_comment: Option<String>,
+}
+
+impl Statement {
+    /// Argument text with surrounding quotes removed.
+    fn unquoted_argument(&self) -> &str {
+        self.arguments
+            .first()
+            .map(|arg| arg.text.trim_matches(|c| c == '"' || c == '\''))
+            .unwrap_or("")
+    }
+}
+
+/// A whitespace-separated piece of a statement argument.
+#[derive(Debug)]
+struct Argument {
+    text: String,
+    /// Whether the piece started on a new line in the source.
+    on_new_line: bool,
+    /// Column of the opening quote in the source, counting from 0, when the
+    /// piece is a single quoted string.
+    string_column: Option<usize>,
+}
+
+/// Comments and blank-line information extracted from a token's leading trivia.
+#[derive(Debug, Default)]
+struct TriviaInfo {
+    /// Comment on the same line as the previous statement terminator.
+    same_line_comment: Option<String>,
+    /// Comments on their own lines before the token.
+    comments: Vec<String>,
+    /// Whether a blank line separates the token from what precedes it.
+    blank_line: bool,
+}
+
+impl TriviaInfo {
+    fn from_trivia(trivia: &[Trivia], after_terminator: bool) -> Self {
+        let mut info = TriviaInfo::default();
+        let mut seen_newline = false;
+        for item in trivia {
+            match item {
+                Trivia::Whitespace(ws) => {
+                    let newlines = ws.matches('\n').count();
+                    if newlines >= 2 && info.comments.is_empty() {
+                        info.blank_line = true;
+                    }
+                    seen_newline |= newlines > 0;
+                }
+                _ => {
+                    let comment = item.as_comment().unwrap_or_default().to_string();
+                    if after_terminator && !seen_newline && info.same_line_comment.is_none() {
+                        info.same_line_comment = Some(comment);
+                    } else {
+                        info.comments.push(comment);
+                    }
+                }
+            }
+        }
+        info
+    }
+}
+
+/// Reads the generic statement tree from a lossless token stream.
+struct StatementReader<'a> {
+    source: &'a str,
+    tokens: &'a [SpannedToken],
+    trivia: Vec<TriviaInfo>,
+    pos: usize,
+}
+
+impl<'a> StatementReader<'a> {
+    fn new(source: &'a str, tokens: &'a [SpannedToken]) -> Self {
+        let trivia = tokens
+            .iter()
+            .enumerate()
+            .map(|(index, token)| {
+                let after_terminator = index > 0
+                    && matches!(
+                        tokens[index - 1].token,
+                        Token::Semicolon | Token::LeftBrace | Token::RightBrace
+                    );
+                TriviaInfo::from_trivia(&token.leading_trivia, after_terminator)
+            })
+            .collect();
+        Self {
+            source,
+            tokens,
+            trivia,
+            pos: 0,
+        }
+    }
+
+    fn current(&self) -> &'a SpannedToken {
+        &self.tokens[self.pos.min(self.tokens.len() - 1)]
+    }
+
+    /// Read statements until a closing brace or the end of input.
+    ///
+    /// Returns the statements and the comments that precede the terminator.
+    fn read_block(&mut self) -> Result<(Vec<Statement>, Vec<String>), ParseError> {
+        let mut statements = Vec::new();
+        loop {
+            match self.current().token {
+                Token::RightBrace | Token::Eof => {
+                    let comments = std::mem::take(&mut self.trivia[self.pos].comments);
+                    return Ok((statements, comments));
+                }
+                _ => statements.push(self.read_statement()?),
+            }
+        }
+    }
+
+    fn read_statement(&mut self) -> Result<Statement, ParseError> {
+        let mut statement = Statement {
+            leading_comments: std::mem::take(&mut self.trivia[self.pos].comments),
+            blank_line_before: self.trivia[self.pos].blank_line,
+            ..Statement::default()
+        };
+        statement.keyword = self.read_run();
+
+        loop {
+            let token = self.current();
+            match token.token {
+                Token::Semicolon => {
+                    self.pos += 1;
+                    statement.trailing_comment = self.trivia[self.pos].same_line_comment.take();
+                    return Ok(statement);
+                }
+                Token::LeftBrace => {
+                    self.pos += 1;
+                    statement.trailing_comment = self.trivia[self.pos].same_line_comment.take();
+                    let (children, closing_comments) = self.read_block()?;
+                    let closing = self.current();
+                    if closing.token != Token::RightBrace {
+                        return Err(syntax_error(closing, "expected '}'"));
+                    }
+                    self.pos += 1;
+                    statement.children = Some(children);
+                    statement.closing_comments = closing_comments;
+                    statement.closing_trailing_comment =
+                        self.trivia[self.pos].same_line_comment.take();
+                    return Ok(statement);
+                }
+                Token::RightBrace | Token::Eof => {
+                    return Err(syntax_error(token, "expected ';' or '{'"));
+                }
+                _ => {
+                    // Comments between arguments are kept ahead of the statement
+                    let comments = std::mem::take(&mut self.trivia[self.pos].comments);
+                    statement.leading_comments.extend(comments);
+                    let on_new_line = token.leading_trivia.iter().any(
+                        |trivia| matches!(trivia, Trivia::Whitespace(ws) if ws.contains('\n')),
+                    );
+                    let line_start = self.source[..token.span.start]
+                        .rfind('\n')
+                        .map_or(0, |i| i + 1);
+                    let column = display_width(&self.source[line_start..token.span.start]);
+                    let keyword = statement.keyword.clone();
+                    let start = self.pos;
+                    let argument = self.read_run();
+                    let is_string = matches!(token.token, Token::StringLiteral(_));
+                    statement.arguments.push(Argument {
+                        text: normalize_argument(&keyword, &argument),
+                        on_new_line,
+                        string_column: (is_string && self.pos == start + 1).then_some(column),
+                    });
+                }
+            }
+        }
+    }
+
+    /// Read a run of tokens that are not separated by whitespace or comments.
+    fn read_run(&mut self) -> String {
+        let first = self.current();
+        let mut last = first;
+        self.pos += 1;
+        while self.pos < self.tokens.len() {
+            let next = &self.tokens[self.pos];
+            if !next.leading_trivia.is_empty()
+                || matches!(
+                    next.token,
+                    Token::Semicolon | Token::LeftBrace | Token::RightBrace | Token::Eof
+                )
+            {
+                break;
+            }
+            last = next;
+            self.pos += 1;
+        }
+        self.source[first.span.start..last.span.end].to_string()
+    }
+}
+
+fn syntax_error(token: &SpannedToken, message: &str) -> ParseError {
+    ParseError::SyntaxError {
+        line: token.span.line,
+        column: token.span.column,
+        message: message.to_string(),
+    }
+}
+
+/// Normalize the quoting of a single argument.
+///
+/// Single-quoted strings become double-quoted when the content allows it, and
+/// unquoted free-form text is quoted. Anything else is returned unchanged.
+///
+/// Double-quoted strings lose the indentation after line breaks and the
+/// whitespace before them (RFC 7950 section 6.1.3), so single-quoted strings
+/// spanning lines or starting or ending with whitespace keep their quotes.
+fn normalize_argument(keyword: &str, raw: &str) -> String {
+    let needs_single_quotes = |content: &str| {
+        content.contains(['"', '\\', '\n'])
+            || content.starts_with(char::is_whitespace)
+            || content.ends_with(char::is_whitespace)
+    };
+
+    if keyword == "pattern" {
+        // RFC 8407 recommends single quotes for patterns, so leave them as written
+        return raw.to_string();
+    }
+
+    if raw.len() >= 2 && raw.starts_with('\'') && raw.ends_with('\'') {
+        let content = &raw[1..raw.len() - 1];
+        if !needs_single_quotes(content) && !content.contains('\'') {
+            return format!("\"{}\"", content);
+        }
+        return raw.to_string();
+    }
+
+    let is_quoted = raw.starts_with('"') || raw.starts_with('\'');
+    if !is_quoted && TEXT_STATEMENTS.contains(&keyword) && !needs_single_quotes(raw) {
+        return format!("\"{}\"", raw);
+    }
+    raw.to_string()
+}
+
+/// Move a double-quoted string spanning lines from column `from` to column
+/// `to`.
+///
+/// Continuation lines are indented to just past the new opening quote, plus
+/// whatever indentation they had beyond the old one, which is what the string
+/// keeps of it. Other arguments are returned unchanged.
+fn reindent_string(text: &str, from: usize, to: usize) -> String {
+    if from == to || !(text.starts_with('"') && text.ends_with('"') && text.contains('\n')) {
+        return text.to_string();
+    }
+    let mut lines = text.split('\n');
+    let mut reindented = lines.next().unwrap_or_default().to_string();
+    for line in lines {
+        reindented.push('\n');
+        let kept = strip_indentation(line, from + 1);
+        if !kept.trim_end_matches([' ', '\t', '\r']).is_empty() {
+            reindented.push_str(&" ".repeat(to + 1));
+            reindented.push_str(kept);
+        }
+    }
+    reindented
+}
+
+/// Section of a module body and position within it, used for RFC 8407 statement ordering.
+fn module_section(keyword: &str) -> (u8, u8) {
+    match keyword {
+        "yang-version" => (0, 0),
+        "namespace" => (0, 1),
+        "prefix" => (0, 2),
+        "belongs-to" => (0, 3),
+        "import" | "include" => (1, 0),
+        "organization" => (2, 0),
+        "contact" => (2, 1),
+        "description" => (2, 2),
+        "reference" => (2, 3),
+        "revision" => (3, 0),
+        _ => (4, 0),
+    }
+}
+
+/// Order module header statements and revisions, leaving the body untouched.
+fn order_module_statements(module: &mut Statement) {
+    let Some(children) = module.children.as_mut() else {
+        return;
+    };
+
+    let mut ordered: Vec<Statement> = std::mem::take(children);
+    // The sort is stable, so body statements and statements within a section keep their order
+    ordered.sort_by(|a, b| {
+        let section = module_section(&a.keyword).cmp(&module_section(&b.keyword));
+        if section.is_eq() && a.keyword == "revision" && b.keyword == "revision" {
+            return b.unquoted_argument().cmp(a.unquoted_argument());
+        }
+        section
+    });
+
+    let mut previous_section = None;
+    for statement in &mut ordered {
+        let (section, _) = module_section(&statement.keyword);
+        match previous_section {
+            Some(previous) if previous != section => statement.blank_line_before = true,
+            Some(_) if section < 4 && statement.leading_comments.is_empty() => {
+                statement.blank_line_before = false;
+            }
+            _ => {}
+        }
+        previous_section = Some(section);
+    }
+
+    *children = ordered;
+}
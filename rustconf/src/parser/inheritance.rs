@@ -0,0 +1,239 @@
+//! Inheritance of `config` and `status` down the schema tree.
+//!
+//! A node without a `config` statement takes the value of its parent, so
+//! every node below a `config false` node is state data (RFC 7950, section
+//! 7.21.1). An explicit `config true` below it is rejected when parsed, and
+//! [`inherit_config`] marks the rest once groupings, augments and deviations
+//! have been applied.
+//!
+//! A definition must not reference a definition of its own module with a
+//! worse status (section 7.21.2). The status of a node is the worse of its
+//! own and its ancestors', so the nodes of a deprecated container may use
+//! deprecated groupings and typedefs.
+//! [`YangParser::check_status`] rejects references to obsolete definitions
+//! and warns about current definitions referencing deprecated ones.
+
+use super::ast::{DataNode, Status, TypeSpec, YangModule};
+use super::error::ParseError;
+use super::prefix::{PrefixTable, ResolvedName};
+use super::YangParser;
+
+/// Set `config` to false on every node below a node that is not
+/// configuration, `config` being that of the parent of `nodes`.
+pub(super) fn inherit_config(nodes: &mut [DataNode], config: bool) {
+    for node in nodes {
+        match node {
+            DataNode::Container(container) => {
+                container.config &= config;
+                inherit_config(&mut container.children, container.config);
+            }
+            DataNode::List(list) => {
+                list.config &= config;
+                inherit_config(&mut list.children, list.config);
+            }
+            DataNode::Leaf(leaf) => leaf.config &= config,
+            DataNode::LeafList(leaf_list) => leaf_list.config &= config,
+            DataNode::AnyData(anydata) => anydata.config &= config,
+            DataNode::Choice(choice) => {
+                for case in &mut choice.cases {
+                    inherit_config(&mut case.data_nodes, config);
+                }
+            }
+            DataNode::Case(case) => inherit_config(&mut case.data_nodes, config),
+            DataNode::Uses(_) => {}
+        }
+    }
+}
+
+impl YangParser {
+    /// Check that no definition of `module` references a typedef or grouping
+    /// of the module with a worse status.
+    ///
+    /// Returns a warning for each current definition referencing a
+    /// deprecated one. Groupings are expected not to be expanded yet, as by
+    /// [`expand_groupings`](Self::expand_groupings).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for the first definition referencing an obsolete
+    /// one, unless it is obsolete itself.
+    pub fn check_status(&self, module: &YangModule) -> Result<Vec<String>, ParseError> {
+        let mut checker = StatusChecker {
+            module,
+            prefixes: PrefixTable::for_module(module),
+            warnings: Vec::new(),
+        };
+
+        for typedef in &module.typedefs {
+            let referrer = format!("typedef '{}'", typedef.name);
+            checker.check_type_spec(&typedef.type_spec, typedef.status, &referrer)?;
+        }
+        for grouping in &module.groupings {
+            let referrer = format!("grouping '{}'", grouping.name);
+            checker.check_nodes(&grouping.data_nodes, grouping.status, &referrer)?;
+        }
+        let referrer = format!("module '{}'", module.name);
+        checker.check_nodes(&module.data_nodes, Status::Current, &referrer)?;
+        for augment in &module.augments {
+            let referrer = format!("augment '{}'", augment.target_path());
+            checker.check_nodes(&augment.data_nodes, Status::Current, &referrer)?;
+        }
+        for rpc in &module.rpcs {
+            let referrer = format!("rpc '{}'", rpc.name);
+            for nodes in [&rpc.input, &rpc.output].into_iter().flatten() {
+                checker.check_nodes(nodes, Status::Current, &referrer)?;
+            }
+        }
+        for notification in &module.notifications {
+            let referrer = format!("notification '{}'", notification.name);
+            checker.check_nodes(&notification.data_nodes, Status::Current, &referrer)?;
+        }
+        Ok(checker.warnings)
+    }
+}
+
+/// Walks the definitions of a module, comparing the status of each with the
+/// status of the definitions it references.
+struct StatusChecker<'a> {
+    module: &'a YangModule,
+    prefixes: PrefixTable,
+    warnings: Vec<String>,
+}
+
+impl StatusChecker<'_> {
+    /// Check `nodes`, whose parent `parent` has the status `inherited`.
+    fn check_nodes(
+        &mut self,
+        nodes: &[DataNode],
+        inherited: Status,
+        parent: &str,
+    ) -> Result<(), ParseError> {
+        for node in nodes {
+            let status = inherited.max(node.status());
+            let referrer = format!("{} '{}'", keyword(node), node.name());
+            match node {
+                DataNode::Leaf(leaf) => self.check_type_spec(&leaf.type_spec, status, &referrer)?,
+                DataNode::LeafList(leaf_list) => {
+                    self.check_type_spec(&leaf_list.type_spec, status, &referrer)?
+                }
+                DataNode::Choice(choice) => {
+                    for case in &choice.cases {
+                        let case_status = status.max(case.status);
+                        let referrer = format!("case '{}'", case.name);
+                        self.check_nodes(&case.data_nodes, case_status, &referrer)?;
+                    }
+                }
+                DataNode::Uses(uses) => {
+                    let grouping = self.local_name(&uses.name).and_then(|name| {
+                        self.module
+                            .groupings
+                            .iter()
+                            .find(|grouping| grouping.name == name)
+                    });
+                    if let Some(grouping) = grouping {
+                        self.check_reference(
+                            status,
+                            parent,
+                            "grouping",
+                            &grouping.name,
+                            grouping.status,
+                        )?;
+                    }
+                    for augment in &uses.augments {
+                        self.check_nodes(&augment.data_nodes, status, parent)?;
+                    }
+                }
+                DataNode::Container(_) | DataNode::List(_) | DataNode::Case(_) => {
+                    self.check_nodes(node.children(), status, &referrer)?
+                }
+                DataNode::AnyData(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Check the typedefs referenced by `type_spec`, the type of `referrer`.
+    fn check_type_spec(
+        &mut self,
+        type_spec: &TypeSpec,
+        status: Status,
+        referrer: &str,
+    ) -> Result<(), ParseError> {
+        match type_spec {
+            TypeSpec::TypedefRef { name, .. } => {
+                let typedef = self.local_name(name).and_then(|name| {
+                    self.module
+                        .typedefs
+                        .iter()
+                        .find(|typedef| typedef.name == name)
+                });
+                if let Some(typedef) = typedef {
+                    self.check_reference(
+                        status,
+                        referrer,
+                        "typedef",
+                        &typedef.name,
+                        typedef.status,
+                    )?;
+                }
+            }
+            TypeSpec::Union { types } => {
+                for member in types {
+                    self.check_type_spec(member, status, referrer)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// The name of a definition of the module itself, `None` for
+    /// definitions of imported modules.
+    fn local_name<'n>(&self, name: &'n str) -> Option<&'n str> {
+        match self.prefixes.resolve(name) {
+            Ok(ResolvedName::Local(name)) => Some(name),
+            Ok(ResolvedName::Imported { .. }) | Err(_) => None,
+        }
+    }
+
+    fn check_reference(
+        &mut self,
+        status: Status,
+        referrer: &str,
+        kind: &str,
+        name: &str,
+        referenced: Status,
+    ) -> Result<(), ParseError> {
+        if referenced <= status {
+            return Ok(());
+        }
+        let message = format!(
+            "{} {} in module '{}' references {} {} '{}'",
+            status.keyword(),
+            referrer,
+            self.module.name,
+            referenced.keyword(),
+            kind,
+            name
+        );
+        if referenced == Status::Obsolete {
+            return Err(ParseError::SemanticError { message });
+        }
+        self.warnings.push(message);
+        Ok(())
+    }
+}
+
+/// The keyword of the statement declaring `node`.
+fn keyword(node: &DataNode) -> &'static str {
+    match node {
+        DataNode::Container(_) => "container",
+        DataNode::List(_) => "list",
+        DataNode::Leaf(_) => "leaf",
+        DataNode::LeafList(_) => "leaf-list",
+        DataNode::AnyData(anydata) => anydata.kind.keyword(),
+        DataNode::Choice(_) => "choice",
+        DataNode::Case(_) => "case",
+        DataNode::Uses(_) => "uses",
+    }
+}
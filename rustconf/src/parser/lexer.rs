@@ -121,6 +121,14 @@ pub enum Token {
 }
 
 impl Token {
+    /// The keyword the token stands for, as written in YANG.
+    pub fn keyword(&self) -> Option<&'static str> {
+        KEYWORDS
+            .iter()
+            .find(|(_, token)| token == self)
+            .map(|(keyword, _)| *keyword)
+    }
+
     /// Returns true if this token is a keyword.
     pub fn is_keyword(&self) -> bool {
         matches!(
@@ -222,19 +230,49 @@ impl<'a> Lexer<'a> {
             return Ok(Token::Eof);
         }
 
-        let remaining = &self.input[self.position..];
+        let (end, tok) = self.token_at(self.position)?;
+        self.position = end;
+        Ok(tok)
+    }
 
-        // Try to parse a token
-        match token(remaining) {
-            Ok((rest, tok)) => {
-                self.position = self.input.len() - rest.len();
-                Ok(tok)
-            }
-            Err(e) => Err(format!(
-                "Lexer error at position {}: {:?}",
-                self.position, e
-            )),
+    /// Parse the token starting at `start`, returning where it ends.
+    ///
+    /// Double-quoted strings spanning lines lose the indentation of their
+    /// continuation lines, up to the column after the opening quote, and the
+    /// whitespace before each line break (RFC 7950 section 6.1.3).
+    fn token_at(&self, start: usize) -> Result<(usize, Token), String> {
+        let (rest, tok) = token(&self.input[start..])
+            .map_err(|e| format!("Lexer error at position {}: {:?}", start, e))?;
+        let end = self.input.len() - rest.len();
+
+        let raw = &self.input[start..end];
+        if !(matches!(tok, Token::StringLiteral(_)) && raw.starts_with('"') && raw.contains('\n')) {
+            return Ok((end, tok));
         }
+        let line_start = self.input[..start].rfind('\n').map_or(0, |i| i + 1);
+        let quote_column = display_width(&self.input[line_start..start]);
+        let content = &raw[1..raw.len() - 1];
+        let lines: Vec<&str> = content.split('\n').collect();
+        let trimmed: Vec<&str> = lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| {
+                let line = if index + 1 < lines.len() {
+                    line.trim_end_matches([' ', '\t', '\r'])
+                } else {
+                    line
+                };
+                if index == 0 {
+                    line
+                } else {
+                    strip_indentation(line, quote_column + 1)
+                }
+            })
+            .collect();
+        Ok((
+            end,
+            Token::StringLiteral(unescape_string(&trimmed.join("\n"))),
+        ))
     }
 
     /// Tokenize the entire input into a vector of tokens.
@@ -251,40 +289,181 @@ impl<'a> Lexer<'a> {
         Ok(tokens)
     }
 
-    /// Skip whitespace and comments.
-    fn skip_whitespace_and_comments(&mut self) -> Result<(), String> {
+    /// Tokenize the entire input without discarding anything.
+    ///
+    /// Every token carries its [`Span`] and the whitespace and comments that
+    /// precede it, so the original text can be reconstructed exactly. The
+    /// final token is always [`Token::Eof`], whose trivia holds anything that
+    /// follows the last real token.
+    pub fn tokenize_lossless(&mut self) -> Result<Vec<SpannedToken>, String> {
+        let line_starts: Vec<usize> = std::iter::once(0)
+            .chain(self.input.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        let mut tokens = Vec::new();
         loop {
-            let remaining = &self.input[self.position..];
-
-            // Skip whitespace
-            if let Ok((rest, _)) = multispace1::<_, nom::error::Error<_>>(remaining) {
-                self.position = self.input.len() - rest.len();
-                continue;
+            let mut leading_trivia = Vec::new();
+            while let Some(trivia) = self.next_trivia()? {
+                leading_trivia.push(trivia);
             }
 
-            // Skip single-line comments (//)
-            if remaining.starts_with("//") {
-                if let Some(newline_pos) = remaining.find('\n') {
-                    self.position += newline_pos + 1;
-                } else {
-                    self.position = self.input.len();
-                }
-                continue;
+            let start = self.position;
+            let token = if start >= self.input.len() {
+                Token::Eof
+            } else {
+                let (end, tok) = self.token_at(start)?;
+                self.position = end;
+                tok
+            };
+
+            let line = line_starts.partition_point(|&line_start| line_start <= start);
+            let span = Span {
+                start,
+                end: self.position,
+                line,
+                column: start - line_starts[line - 1] + 1,
+            };
+            let is_eof = token == Token::Eof;
+            tokens.push(SpannedToken {
+                token,
+                span,
+                leading_trivia,
+            });
+            if is_eof {
+                break;
             }
+        }
+        Ok(tokens)
+    }
 
-            // Skip multi-line comments (/* ... */)
-            if remaining.starts_with("/*") {
-                if let Some(end_pos) = remaining.find("*/") {
+    /// Skip whitespace and comments.
+    fn skip_whitespace_and_comments(&mut self) -> Result<(), String> {
+        while self.next_trivia()?.is_some() {}
+        Ok(())
+    }
+
+    /// Consume the next run of whitespace or a single comment, if any.
+    fn next_trivia(&mut self) -> Result<Option<Trivia>, String> {
+        let remaining = &self.input[self.position..];
+
+        if let Ok((rest, ws)) = multispace1::<_, nom::error::Error<_>>(remaining) {
+            self.position = self.input.len() - rest.len();
+            return Ok(Some(Trivia::Whitespace(ws.to_string())));
+        }
+
+        // Single-line comments (//) end before the newline, which is left as whitespace
+        if remaining.starts_with("//") {
+            let end = remaining.find('\n').unwrap_or(remaining.len());
+            let comment = remaining[..end].trim_end_matches('\r');
+            self.position += comment.len();
+            return Ok(Some(Trivia::LineComment(comment.to_string())));
+        }
+
+        // Multi-line comments (/* ... */)
+        if remaining.starts_with("/*") {
+            return match remaining.find("*/") {
+                Some(end_pos) => {
                     self.position += end_pos + 2;
-                } else {
-                    return Err("Unterminated comment".to_string());
+                    Ok(Some(Trivia::BlockComment(
+                        remaining[..end_pos + 2].to_string(),
+                    )))
                 }
-                continue;
-            }
+                None => Err("Unterminated comment".to_string()),
+            };
+        }
 
-            break;
+        Ok(None)
+    }
+}
+
+/// Location of a token within the lexer input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    /// Byte offset of the first character.
+    pub start: usize,
+    /// Byte offset one past the last character.
+    pub end: usize,
+    /// 1-based line number of the first character.
+    pub line: usize,
+    /// 1-based column of the first character.
+    pub column: usize,
+}
+
+/// Source text that has no meaning to the parser.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Trivia {
+    /// A run of whitespace, including newlines.
+    Whitespace(String),
+    /// A `//` comment, without the terminating newline.
+    LineComment(String),
+    /// A `/* ... */` comment, including the delimiters.
+    BlockComment(String),
+}
+
+impl Trivia {
+    /// Returns the comment text if this trivia is a comment.
+    pub fn as_comment(&self) -> Option<&str> {
+        match self {
+            Trivia::LineComment(text) | Trivia::BlockComment(text) => Some(text),
+            Trivia::Whitespace(_) => None,
         }
-        Ok(())
+    }
+}
+
+/// A token produced by [`Lexer::tokenize_lossless`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpannedToken {
+    pub token: Token,
+    pub span: Span,
+    /// Whitespace and comments between the previous token and this one.
+    pub leading_trivia: Vec<Trivia>,
+}
+
+impl SpannedToken {
+    /// Returns the exact source text of this token, including quotes.
+    pub fn text<'s>(&self, source: &'s str) -> &'s str {
+        &source[self.span.start..self.span.end]
+    }
+
+    /// Returns the text of the comments on the lines right before this
+    /// token, without the comment delimiters.
+    ///
+    /// Only comments starting a line count, and a blank line between a
+    /// comment and the token detaches it, so trailing comments of the
+    /// previous statement and section headers are left out.
+    pub fn leading_comment(&self) -> Option<String> {
+        let mut lines: Vec<&str> = Vec::new();
+        let mut own_line = false;
+        for trivia in &self.leading_trivia {
+            match trivia {
+                Trivia::Whitespace(whitespace) => {
+                    let newlines = whitespace.matches('\n').count();
+                    if newlines > 1 {
+                        lines.clear();
+                    }
+                    own_line = own_line || newlines > 0;
+                }
+                Trivia::LineComment(text) => {
+                    if own_line {
+                        let text = text.trim_start_matches('/');
+                        lines.push(text.strip_prefix(' ').unwrap_or(text).trim_end());
+                    }
+                    own_line = false;
+                }
+                Trivia::BlockComment(text) => {
+                    if own_line {
+                        let text = &text[2..text.len() - 2];
+                        lines.extend(text.lines().map(|line| {
+                            let line = line.trim();
+                            let line = line.strip_prefix('*').unwrap_or(line);
+                            line.strip_prefix(' ').unwrap_or(line)
+                        }));
+                    }
+                    own_line = false;
+                }
+            }
+        }
+        let text = lines.join("\n").trim().to_string();
+        (!text.is_empty()).then_some(text)
     }
 }
 
@@ -293,6 +472,82 @@ fn token(input: &str) -> IResult<&str, Token> {
     alt((keyword, operator, number, string_literal, identifier))(input)
 }
 
+/// Keywords and their tokens.
+static KEYWORDS: &[(&str, Token)] = &[
+    ("yang-version", Token::YangVersion),
+    ("instance-identifier", Token::InstanceIdentifier),
+    ("namespace", Token::Namespace),
+    ("organization", Token::Organization),
+    ("description", Token::Description),
+    ("enumeration", Token::Enumeration),
+    ("identityref", Token::IdentityRef),
+    ("notification", Token::Notification),
+    ("min-elements", Token::MinElements),
+    ("max-elements", Token::MaxElements),
+    ("if-feature", Token::IfFeature),
+    ("ordered-by", Token::OrderedBy),
+    ("mandatory", Token::Mandatory),
+    ("deviation", Token::Deviation),
+    ("extension", Token::Extension),
+    ("reference", Token::Reference),
+    ("submodule", Token::Submodule),
+    ("container", Token::Container),
+    ("leaf-list", Token::LeafList),
+    ("identity", Token::Identity),
+    ("grouping", Token::Grouping),
+    ("presence", Token::Presence),
+    ("argument", Token::Argument),
+    ("revision", Token::Revision),
+    ("augment", Token::Augment),
+    ("deviate", Token::Deviate),
+    ("boolean", Token::Boolean),
+    ("contact", Token::Contact),
+    ("typedef", Token::Typedef),
+    ("leafref", Token::LeafRef),
+    ("pattern", Token::Pattern),
+    ("feature", Token::Feature),
+    ("default", Token::Default),
+    ("include", Token::Include),
+    ("prefix", Token::Prefix),
+    ("import", Token::Import),
+    ("module", Token::Module),
+    ("choice", Token::Choice),
+    ("config", Token::Config),
+    ("status", Token::Status),
+    ("string", Token::String),
+    ("action", Token::Action),
+    ("binary", Token::Binary),
+    ("unique", Token::Unique),
+    ("length", Token::Length),
+    ("output", Token::Output),
+    ("uint64", Token::Uint64),
+    ("uint32", Token::Uint32),
+    ("uint16", Token::Uint16),
+    ("uint8", Token::Uint8),
+    ("int64", Token::Int64),
+    ("int32", Token::Int32),
+    ("int16", Token::Int16),
+    ("int8", Token::Int8),
+    ("range", Token::Range),
+    ("units", Token::Units),
+    ("union", Token::Union),
+    ("input", Token::Input),
+    ("empty", Token::Empty),
+    ("enum", Token::Enum),
+    ("bits", Token::Bits),
+    ("leaf", Token::Leaf),
+    ("list", Token::List),
+    ("case", Token::Case),
+    ("uses", Token::Uses),
+    ("type", Token::Type),
+    ("must", Token::Must),
+    ("when", Token::When),
+    ("base", Token::Base),
+    ("bit", Token::Bit),
+    ("key", Token::Key),
+    ("rpc", Token::Rpc),
+];
+
 /// Parse a keyword token with word boundary checking.
 fn keyword(input: &str) -> IResult<&str, Token> {
     // Try to match the longest keyword first
@@ -301,87 +556,13 @@ fn keyword(input: &str) -> IResult<&str, Token> {
         take_while(|c: char| c.is_ascii_alphanumeric() || c == '_' || c == '-'),
     ))(input)?;
 
-    // Map the word to a token
-    let token = match word {
-        "yang-version" => Token::YangVersion,
-        "instance-identifier" => Token::InstanceIdentifier,
-        "namespace" => Token::Namespace,
-        "organization" => Token::Organization,
-        "description" => Token::Description,
-        "enumeration" => Token::Enumeration,
-        "identityref" => Token::IdentityRef,
-        "notification" => Token::Notification,
-        "min-elements" => Token::MinElements,
-        "max-elements" => Token::MaxElements,
-        "if-feature" => Token::IfFeature,
-        "ordered-by" => Token::OrderedBy,
-        "mandatory" => Token::Mandatory,
-        "deviation" => Token::Deviation,
-        "extension" => Token::Extension,
-        "reference" => Token::Reference,
-        "submodule" => Token::Submodule,
-        "container" => Token::Container,
-        "leaf-list" => Token::LeafList,
-        "identity" => Token::Identity,
-        "grouping" => Token::Grouping,
-        "presence" => Token::Presence,
-        "argument" => Token::Argument,
-        "revision" => Token::Revision,
-        "augment" => Token::Augment,
-        "deviate" => Token::Deviate,
-        "boolean" => Token::Boolean,
-        "contact" => Token::Contact,
-        "typedef" => Token::Typedef,
-        "leafref" => Token::LeafRef,
-        "pattern" => Token::Pattern,
-        "feature" => Token::Feature,
-        "default" => Token::Default,
-        "include" => Token::Include,
-        "prefix" => Token::Prefix,
-        "import" => Token::Import,
-        "module" => Token::Module,
-        "choice" => Token::Choice,
-        "config" => Token::Config,
-        "status" => Token::Status,
-        "string" => Token::String,
-        "action" => Token::Action,
-        "binary" => Token::Binary,
-        "unique" => Token::Unique,
-        "length" => Token::Length,
-        "output" => Token::Output,
-        "uint64" => Token::Uint64,
-        "uint32" => Token::Uint32,
-        "uint16" => Token::Uint16,
-        "uint8" => Token::Uint8,
-        "int64" => Token::Int64,
-        "int32" => Token::Int32,
-        "int16" => Token::Int16,
-        "int8" => Token::Int8,
-        "range" => Token::Range,
-        "units" => Token::Units,
-        "union" => Token::Union,
-        "input" => Token::Input,
-        "empty" => Token::Empty,
-        "enum" => Token::Enum,
-        "bits" => Token::Bits,
-        "leaf" => Token::Leaf,
-        "list" => Token::List,
-        "case" => Token::Case,
-        "uses" => Token::Uses,
-        "type" => Token::Type,
-        "must" => Token::Must,
-        "when" => Token::When,
-        "base" => Token::Base,
-        "bit" => Token::Bit,
-        "key" => Token::Key,
-        "rpc" => Token::Rpc,
-        _ => {
-            return Err(nom::Err::Error(nom::error::Error::new(
-                input,
-                nom::error::ErrorKind::Tag,
-            )))
-        }
-    };
+    let token = KEYWORDS
+        .iter()
+        .find(|(keyword, _)| *keyword == word)
+        .map(|(_, token)| token.clone())
+        .ok_or_else(|| {
+            nom::Err::Error(nom::error::Error::new(input, nom::error::ErrorKind::Tag))
+        })?;
 
     Ok((rest, token))
 }
@@ -466,6 +647,27 @@ fn identifier(input: &str) -> IResult<&str, Token> {
     )(input)
 }
 
+/// Width of `text` in columns, with tabs advancing to the next multiple of 8.
+pub(super) fn display_width(text: &str) -> usize {
+    text.chars().fold(0, |column, c| match c {
+        '\t' => (column / 8 + 1) * 8,
+        _ => column + 1,
+    })
+}
+
+/// Strip the spaces and tabs at the start of `line` that fall within the
+/// first `columns` columns.
+pub(super) fn strip_indentation(line: &str, columns: usize) -> &str {
+    let mut column = 0;
+    for (index, c) in line.char_indices() {
+        if column >= columns || !matches!(c, ' ' | '\t') {
+            return &line[index..];
+        }
+        column = display_width(&line[..index + 1]);
+    }
+    ""
+}
+
 /// Unescape a string literal.
 fn unescape_string(s: &str) -> String {
     let mut result = String::new();
@@ -3,35 +3,135 @@
 //! This module provides functionality to parse YANG 1.0 and 1.1 specification files
 //! into an abstract syntax tree (AST).
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 
 pub mod ast;
 pub mod error;
+pub mod formatter;
 pub mod lexer;
+pub mod openapi;
+pub mod prefix;
+
+mod augment;
+mod deviation;
+mod feature;
+mod inheritance;
+mod search;
 
 pub use ast::*;
 pub use error::ParseError;
+pub use formatter::{format_yang, YangFormatter};
 pub use lexer::{Lexer, Token};
+pub use prefix::{PrefixTable, ResolvedName};
+
+use search::SearchPaths;
+pub(crate) use search::{expand as expand_search_path, is_archive, is_pattern};
+
+/// Name of the YANG module defining the extensions rustconf understands.
+pub const EXTENSIONS_MODULE: &str = "rustconf-extensions";
+
+/// Source of the `rustconf-extensions` module, for adding it to a model's
+/// search path.
+pub const EXTENSIONS_YANG: &str = include_str!("../../yang/rustconf-extensions.yang");
+
+/// Typedefs and groupings visible from one module.
+///
+/// Unprefixed references (and references using the module's own prefix)
+/// resolve against the module's definitions; prefixed references to imported
+/// modules resolve against the loaded module with that name.
+struct DefinitionScope<'a> {
+    prefixes: PrefixTable,
+    typedefs: &'a [TypeDef],
+    groupings: &'a [Grouping],
+    modules: &'a HashMap<String, YangModule>,
+}
+
+impl<'a> DefinitionScope<'a> {
+    /// Create the scope for a loaded module.
+    fn for_module(module: &'a YangModule, modules: &'a HashMap<String, YangModule>) -> Self {
+        Self {
+            prefixes: PrefixTable::for_module(module),
+            typedefs: &module.typedefs,
+            groupings: &module.groupings,
+            modules,
+        }
+    }
+
+    /// Look up the module an imported reference points into.
+    fn imported_module(&self, module: &str) -> Result<&'a YangModule, ParseError> {
+        self.modules
+            .get(module)
+            .ok_or_else(|| ParseError::UnresolvedImport {
+                module: module.to_string(),
+            })
+    }
+
+    /// Find a typedef by reference, along with the scope of an imported module
+    /// when it is defined elsewhere.
+    fn find_typedef(
+        &self,
+        reference: &str,
+    ) -> Result<Option<(&'a TypeDef, Option<DefinitionScope<'a>>)>, ParseError> {
+        Ok(match self.prefixes.resolve(reference)? {
+            ResolvedName::Local(name) => self
+                .typedefs
+                .iter()
+                .find(|t| t.name == name)
+                .map(|t| (t, None)),
+            ResolvedName::Imported { module, name } => {
+                let module = self.imported_module(&module)?;
+                module
+                    .typedefs
+                    .iter()
+                    .find(|t| t.name == name)
+                    .map(|t| (t, Some(Self::for_module(module, self.modules))))
+            }
+        })
+    }
+
+    /// Find a grouping by reference, along with the scope of an imported module
+    /// when it is defined elsewhere.
+    fn find_grouping(
+        &self,
+        reference: &str,
+    ) -> Result<Option<(&'a Grouping, Option<DefinitionScope<'a>>)>, ParseError> {
+        Ok(match self.prefixes.resolve(reference)? {
+            ResolvedName::Local(name) => self
+                .groupings
+                .iter()
+                .find(|g| g.name == name)
+                .map(|g| (g, None)),
+            ResolvedName::Imported { module, name } => {
+                let module = self.imported_module(&module)?;
+                module
+                    .groupings
+                    .iter()
+                    .find(|g| g.name == name)
+                    .map(|g| (g, Some(Self::for_module(module, self.modules))))
+            }
+        })
+    }
+}
 
 /// Visitor for validating typedef references in data nodes.
 ///
 /// This visitor traverses the data node tree and validates that all typedef
 /// references point to defined typedefs.
 struct TypedefRefValidator<'a> {
-    typedefs: &'a [TypeDef],
+    scope: &'a DefinitionScope<'a>,
 }
 
 impl<'a> DataNodeVisitor for TypedefRefValidator<'a> {
     type Error = ParseError;
 
     fn visit_leaf(&mut self, leaf: &Leaf) -> Result<(), Self::Error> {
-        YangParser::validate_typespec_references(&leaf.type_spec, self.typedefs)
+        YangParser::validate_typespec_references(&leaf.type_spec, self.scope)
     }
 
     fn visit_leaf_list(&mut self, leaf_list: &LeafList) -> Result<(), Self::Error> {
-        YangParser::validate_typespec_references(&leaf_list.type_spec, self.typedefs)
+        YangParser::validate_typespec_references(&leaf_list.type_spec, self.scope)
     }
 
     // Use default implementations for container, list, choice, case
@@ -43,7 +143,7 @@ impl<'a> DataNodeVisitor for TypedefRefValidator<'a> {
 /// This visitor traverses the data node tree and validates that all uses
 /// statements reference defined groupings.
 struct GroupingRefValidator<'a> {
-    groupings: &'a [Grouping],
+    scope: &'a DefinitionScope<'a>,
 }
 
 impl<'a> DataNodeVisitor for GroupingRefValidator<'a> {
@@ -51,7 +151,7 @@ impl<'a> DataNodeVisitor for GroupingRefValidator<'a> {
 
     fn visit_uses(&mut self, uses: &Uses) -> Result<(), Self::Error> {
         // Check if the grouping is defined
-        if !self.groupings.iter().any(|g| g.name == uses.name) {
+        if self.scope.find_grouping(&uses.name)?.is_none() {
             return Err(ParseError::SemanticError {
                 message: format!("Undefined grouping reference: {}", uses.name),
             });
@@ -108,24 +208,91 @@ impl<'a> DataNodeVisitor for ConstraintValidator<'a> {
     // which will recursively visit children
 }
 
+/// Where a module or submodule was loaded from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModuleSource {
+    /// File the module was read from (the name passed to `parse_string` for
+    /// modules parsed from memory).
+    pub path: PathBuf,
+    /// Search path the file was found in; `None` for modules parsed directly.
+    pub search_path: Option<PathBuf>,
+    /// Most recent `revision` date of the module, if it has any.
+    pub revision: Option<String>,
+    /// Every revision of the module found in the search paths, newest
+    /// first; empty for modules parsed directly.
+    pub available_revisions: Vec<String>,
+    /// Submodules merged into the module, including nested includes.
+    pub includes: Vec<String>,
+}
+
 /// YANG parser with configurable search paths for module resolution.
 pub struct YangParser {
-    search_paths: Vec<PathBuf>,
+    search_paths: SearchPaths,
     loaded_modules: HashMap<String, YangModule>,
+    sources: HashMap<String, ModuleSource>,
+    keep_comments: bool,
 }
 
 impl YangParser {
     /// Create a new YANG parser with default settings.
     pub fn new() -> Self {
         Self {
-            search_paths: Vec::new(),
+            search_paths: SearchPaths::default(),
             loaded_modules: HashMap::new(),
+            sources: HashMap::new(),
+            keep_comments: false,
         }
     }
 
+    /// Keep the `//` and `/* */` comments on the lines right before
+    /// statements (default: false).
+    ///
+    /// The comment before a typedef, data node, RPC or notification is added
+    /// to its description as a separate paragraph, so it shows up in the
+    /// documentation of the generated items. Comments followed by a blank
+    /// line and comments at the end of a line are not kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustconf::parser::{DataNode, YangParser};
+    ///
+    /// let mut parser = YangParser::new();
+    /// parser.keep_comments(true);
+    /// let module = parser.parse_string(
+    ///     r#"
+    ///     module device {
+    ///         namespace "urn:device";
+    ///         prefix dev;
+    ///
+    ///         // Applied after the next reboot.
+    ///         leaf mtu { type uint16; }
+    ///     }
+    ///     "#,
+    ///     "device.yang",
+    /// )?;
+    /// let DataNode::Leaf(mtu) = &module.data_nodes[0] else {
+    ///     panic!("expected a leaf");
+    /// };
+    /// assert_eq!(mtu.description.as_deref(), Some("Applied after the next reboot."));
+    /// # Ok::<(), rustconf::ParseError>(())
+    /// ```
+    pub fn keep_comments(&mut self, keep: bool) {
+        self.keep_comments = keep;
+    }
+
     /// Add a search path for resolving YANG module imports.
+    ///
+    /// The path may be a directory, a `.tar`, `.tar.gz` or `.tgz` archive of
+    /// YANG files, or a glob pattern such as `vendor/*/yang` matching any of
+    /// these. Archives are read in memory; files may be named `name.yang` or
+    /// `name@revision.yang`.
+    ///
+    /// Every search path is considered: an import with a `revision-date`
+    /// loads that revision, and other imports (or imports of a revision that
+    /// is not found) load the newest revision.
     pub fn add_search_path(&mut self, path: PathBuf) {
-        self.search_paths.push(path);
+        self.search_paths.add(path);
     }
 
     /// Parse a YANG file from the given path.
@@ -141,15 +308,8 @@ impl YangParser {
         content: &str,
         filename: &str,
     ) -> Result<YangModule, ParseError> {
-        let mut lexer = Lexer::new(content);
-        let tokens = lexer.tokenize().map_err(|e| ParseError::SyntaxError {
-            line: 1,
-            column: 1,
-            message: e,
-        })?;
-
-        let mut parser = ModuleParser::new(tokens, filename);
-        let module = parser.parse_module()?;
+        let mut parser = self.module_parser(content, filename)?;
+        let module = self.finish_module(&mut parser, PathBuf::from(filename), None)?;
 
         // Try to resolve imports recursively (non-fatal if imports can't be found)
         // This allows parsing modules without having all dependencies available
@@ -162,8 +322,34 @@ impl YangParser {
         Ok(module)
     }
 
+    /// Tokenize `content` for parsing, keeping the comments before each
+    /// token if [`keep_comments`](Self::keep_comments) is set.
+    fn module_parser(&self, content: &str, filename: &str) -> Result<ModuleParser, ParseError> {
+        let mut lexer = Lexer::new(content);
+        let to_error = |message| ParseError::SyntaxError {
+            line: 1,
+            column: 1,
+            message,
+        };
+        let parser = if self.keep_comments {
+            let spanned = lexer.tokenize_lossless().map_err(to_error)?;
+            let comments = spanned
+                .iter()
+                .map(|token| token.leading_comment())
+                .collect();
+            let tokens = spanned.into_iter().map(|token| token.token).collect();
+            ModuleParser::new(tokens, filename).with_comments(comments)
+        } else {
+            ModuleParser::new(lexer.tokenize().map_err(to_error)?, filename)
+        };
+        Ok(parser)
+    }
+
     /// Resolve all imports for a module by recursively loading imported modules.
+    ///
+    /// Every import is attempted; the first failure is returned afterwards.
     fn resolve_imports(&mut self, module: &YangModule) -> Result<(), ParseError> {
+        let mut first_error = None;
         for import in &module.imports {
             // Skip if already loaded
             if self.loaded_modules.contains_key(&import.module) {
@@ -171,45 +357,246 @@ impl YangParser {
             }
 
             // Try to find and load the imported module
-            let imported_module = self.find_and_load_module(&import.module)?;
+            let imported_module =
+                match self.find_and_load_module(&import.module, import.revision.as_deref()) {
+                    Ok(imported_module) => imported_module,
+                    Err(e) => {
+                        first_error.get_or_insert(e);
+                        continue;
+                    }
+                };
 
             // Recursively resolve imports of the imported module
-            self.resolve_imports(&imported_module)?;
+            if let Err(e) = self.resolve_imports(&imported_module) {
+                first_error.get_or_insert(e);
+            }
 
             // Store the loaded module
             self.loaded_modules
                 .insert(import.module.clone(), imported_module);
         }
 
-        Ok(())
+        first_error.map_or(Ok(()), Err)
+    }
+
+    /// Get where a loaded module or submodule was read from.
+    pub fn module_source(&self, name: &str) -> Option<&ModuleSource> {
+        self.sources.get(name)
     }
 
     /// Find and load a module by searching through the search paths.
-    fn find_and_load_module(&mut self, module_name: &str) -> Result<YangModule, ParseError> {
-        // Try each search path
-        for search_path in &self.search_paths {
-            // Try with .yang extension
-            let module_path = search_path.join(format!("{}.yang", module_name));
-            if module_path.exists() {
-                let content = fs::read_to_string(&module_path)?;
-                let filename = module_path.to_string_lossy().to_string();
-
-                let mut lexer = Lexer::new(&content);
-                let tokens = lexer.tokenize().map_err(|e| ParseError::SyntaxError {
-                    line: 1,
-                    column: 1,
-                    message: e,
-                })?;
-
-                let mut parser = ModuleParser::new(tokens, &filename);
-                return parser.parse_module();
-            }
-        }
-
-        // Module not found in any search path
-        Err(ParseError::UnresolvedImport {
-            module: module_name.to_string(),
-        })
+    fn find_and_load_module(
+        &mut self,
+        module_name: &str,
+        revision: Option<&str>,
+    ) -> Result<YangModule, ParseError> {
+        let Some(found) = self.search_paths.find(module_name, revision)? else {
+            // Module not found in any search path
+            return Err(ParseError::UnresolvedImport {
+                module: module_name.to_string(),
+            });
+        };
+
+        let filename = found.path.to_string_lossy().to_string();
+        let mut parser = self.module_parser(&found.content, &filename)?;
+        let module = self.finish_module(&mut parser, found.path, Some(found.search_path))?;
+        if let Some(source) = self.sources.get_mut(&module.name) {
+            source.available_revisions = found.revisions;
+        }
+        Ok(module)
+    }
+
+    /// Parse a module and fold its included submodules into it.
+    ///
+    /// References written against the module's own prefix are stored without
+    /// the prefix, and references inside submodules are rewritten from the
+    /// submodule's prefix table to the module's, so that every reference in
+    /// the result can be resolved with [`PrefixTable::for_module`].
+    fn finish_module(
+        &mut self,
+        parser: &mut ModuleParser,
+        path: PathBuf,
+        search_path: Option<PathBuf>,
+    ) -> Result<YangModule, ParseError> {
+        let mut module = parser.parse_module()?;
+        let includes = std::mem::take(&mut parser.includes);
+
+        let prefixes = PrefixTable::for_module(&module);
+        rewrite_module_references(
+            &mut module.typedefs,
+            &mut module.groupings,
+            &mut module.data_nodes,
+            &mut module.augments,
+            &mut module.deviations,
+            &mut module.rpcs,
+            &mut module.notifications,
+            &mut |reference| {
+                Ok(match prefixes.resolve(reference)? {
+                    ResolvedName::Local(name) => name.to_string(),
+                    ResolvedName::Imported { .. } => reference.to_string(),
+                })
+            },
+        )?;
+        feature::qualify_feature_references(
+            &mut module.features,
+            &mut module.groupings,
+            &mut module.data_nodes,
+            &mut module.augments,
+            &mut module.rpcs,
+            &mut module.notifications,
+            &prefixes,
+        )?;
+
+        let mut merged = Vec::new();
+        self.merge_includes(&mut module, &includes, &mut merged)?;
+        feature::check_local_features(&module)?;
+
+        self.sources.insert(
+            module.name.clone(),
+            ModuleSource {
+                path,
+                search_path,
+                revision: latest_revision(&parser.revisions),
+                available_revisions: Vec::new(),
+                includes: merged,
+            },
+        );
+
+        Ok(module)
+    }
+
+    /// Load included submodules (and the submodules they include) and merge
+    /// their definitions into the module.
+    fn merge_includes(
+        &mut self,
+        module: &mut YangModule,
+        includes: &[Include],
+        merged: &mut Vec<String>,
+    ) -> Result<(), ParseError> {
+        for include in includes {
+            // YANG 1.1 submodules may include each other; merge each only once
+            if merged.contains(&include.submodule) {
+                continue;
+            }
+            merged.push(include.submodule.clone());
+
+            let submodule =
+                self.find_and_load_submodule(&include.submodule, include.revision.as_deref())?;
+            if submodule.belongs_to.module != module.name {
+                return Err(ParseError::SemanticError {
+                    message: format!(
+                        "Submodule '{}' belongs to '{}' but is included by '{}'",
+                        submodule.name, submodule.belongs_to.module, module.name
+                    ),
+                });
+            }
+
+            let nested = submodule.includes.clone();
+            Self::merge_submodule(module, submodule)?;
+            self.merge_includes(module, &nested, merged)?;
+        }
+        Ok(())
+    }
+
+    /// Find and parse a submodule by searching through the search paths.
+    fn find_and_load_submodule(
+        &mut self,
+        submodule_name: &str,
+        revision: Option<&str>,
+    ) -> Result<YangSubmodule, ParseError> {
+        let Some(found) = self.search_paths.find(submodule_name, revision)? else {
+            return Err(ParseError::UnresolvedInclude {
+                submodule: submodule_name.to_string(),
+            });
+        };
+
+        let filename = found.path.to_string_lossy().to_string();
+        let mut parser = self.module_parser(&found.content, &filename)?;
+        let submodule = parser.parse_submodule()?;
+        self.sources.insert(
+            submodule.name.clone(),
+            ModuleSource {
+                path: found.path,
+                search_path: Some(found.search_path),
+                revision: latest_revision(&parser.revisions),
+                available_revisions: found.revisions,
+                includes: Vec::new(),
+            },
+        );
+        Ok(submodule)
+    }
+
+    /// Merge a submodule's definitions into its parent module.
+    fn merge_submodule(
+        module: &mut YangModule,
+        mut submodule: YangSubmodule,
+    ) -> Result<(), ParseError> {
+        // Imports made by the submodule become visible to the module. Reuse the
+        // module's own prefix when it already imports the same module.
+        for import in &submodule.imports {
+            if module.imports.iter().any(|i| i.module == import.module) {
+                continue;
+            }
+            if import.prefix == module.prefix
+                || module.imports.iter().any(|i| i.prefix == import.prefix)
+            {
+                return Err(ParseError::SemanticError {
+                    message: format!(
+                        "Prefix '{}' imported by submodule '{}' conflicts with a prefix in module '{}'",
+                        import.prefix, submodule.name, module.name
+                    ),
+                });
+            }
+            module.imports.push(import.clone());
+        }
+
+        let prefixes = PrefixTable::for_submodule(&submodule);
+        let module_prefixes: HashMap<String, String> = module
+            .imports
+            .iter()
+            .map(|i| (i.module.clone(), i.prefix.clone()))
+            .collect();
+        rewrite_module_references(
+            &mut submodule.typedefs,
+            &mut submodule.groupings,
+            &mut submodule.data_nodes,
+            &mut submodule.augments,
+            &mut submodule.deviations,
+            &mut submodule.rpcs,
+            &mut submodule.notifications,
+            &mut |reference| {
+                Ok(match prefixes.resolve(reference)? {
+                    ResolvedName::Local(name) => name.to_string(),
+                    ResolvedName::Imported { module, name } => {
+                        format!("{}:{}", module_prefixes[&module], name)
+                    }
+                })
+            },
+        )?;
+        feature::qualify_feature_references(
+            &mut submodule.features,
+            &mut submodule.groupings,
+            &mut submodule.data_nodes,
+            &mut submodule.augments,
+            &mut submodule.rpcs,
+            &mut submodule.notifications,
+            &prefixes,
+        )?;
+
+        module.features.append(&mut submodule.features);
+        module.extensions.append(&mut submodule.extensions);
+        module.typedefs.append(&mut submodule.typedefs);
+        module.groupings.append(&mut submodule.groupings);
+        module.data_nodes.append(&mut submodule.data_nodes);
+        module.augments.append(&mut submodule.augments);
+        module.deviations.append(&mut submodule.deviations);
+        module.rpcs.append(&mut submodule.rpcs);
+        module.notifications.append(&mut submodule.notifications);
+        module
+            .unknown_statements
+            .append(&mut submodule.unknown_statements);
+
+        Ok(())
     }
 
     /// Get a loaded module by name.
@@ -230,6 +617,10 @@ impl YangParser {
     /// - No circular dependencies in imports
     /// - No circular dependencies in groupings
     /// - Type constraints are well-formed (range min < max, etc.)
+    /// - Enum names and values are unique within each enumeration
+    /// - Augment targets resolve to schema nodes of the loaded modules
+    /// - No definition references an obsolete typedef or grouping of the
+    ///   module, unless it is obsolete itself
     pub fn validate_module(&self, module: &YangModule) -> Result<(), ParseError> {
         // Validate circular import dependencies
         self.validate_no_circular_imports(module, &mut Vec::new())?;
@@ -241,6 +632,8 @@ impl YangParser {
         self.validate_typedef_references(module)?;
         self.validate_grouping_references(module)?;
         self.validate_leafref_paths(module)?;
+        self.validate_augment_targets(module)?;
+        self.check_status(module)?;
 
         // Validate type constraints are well-formed
         self.validate_type_constraints(module)?;
@@ -346,6 +739,9 @@ impl YangParser {
                         visited,
                     )?;
                 }
+                for node in uses.augments.iter().flat_map(|augment| &augment.data_nodes) {
+                    self.check_data_node_for_circular_uses(node, all_groupings, visited)?;
+                }
             }
             DataNode::Container(container) => {
                 for child in &container.children {
@@ -376,15 +772,15 @@ impl YangParser {
 
     /// Validate that all typedef references are defined.
     fn validate_typedef_references(&self, module: &YangModule) -> Result<(), ParseError> {
+        let scope = DefinitionScope::for_module(module, &self.loaded_modules);
+
         // Check typedefs in the module's own typedefs
         for typedef in &module.typedefs {
-            Self::validate_typespec_references(&typedef.type_spec, &module.typedefs)?;
+            Self::validate_typespec_references(&typedef.type_spec, &scope)?;
         }
 
         // Check typedefs in data nodes using visitor pattern
-        let mut validator = TypedefRefValidator {
-            typedefs: &module.typedefs,
-        };
+        let mut validator = TypedefRefValidator { scope: &scope };
         walk_data_nodes(&module.data_nodes, &mut validator)?;
 
         Ok(())
@@ -393,12 +789,12 @@ impl YangParser {
     /// Validate typedef references in a TypeSpec.
     fn validate_typespec_references(
         type_spec: &TypeSpec,
-        typedefs: &[TypeDef],
+        scope: &DefinitionScope,
     ) -> Result<(), ParseError> {
         match type_spec {
-            TypeSpec::TypedefRef { name }
+            TypeSpec::TypedefRef { name, .. }
                 // Check if the typedef is defined
-                if !typedefs.iter().any(|t| t.name == *name) => {
+                if scope.find_typedef(name)?.is_none() => {
                     return Err(ParseError::SemanticError {
                         message: format!("Undefined typedef reference: {}", name),
                     });
@@ -406,7 +802,7 @@ impl YangParser {
             TypeSpec::Union { types } => {
                 // Validate each type in the union
                 for t in types {
-                    Self::validate_typespec_references(t, typedefs)?;
+                    Self::validate_typespec_references(t, scope)?;
                 }
             }
             _ => {}
@@ -416,9 +812,8 @@ impl YangParser {
 
     /// Validate that all grouping references are defined.
     fn validate_grouping_references(&self, module: &YangModule) -> Result<(), ParseError> {
-        let mut validator = GroupingRefValidator {
-            groupings: &module.groupings,
-        };
+        let scope = DefinitionScope::for_module(module, &self.loaded_modules);
+        let mut validator = GroupingRefValidator { scope: &scope };
 
         // Check groupings in module data nodes
         walk_data_nodes(&module.data_nodes, &mut validator)?;
@@ -482,7 +877,8 @@ impl YangParser {
             | TypeSpec::Uint8 { range }
             | TypeSpec::Uint16 { range }
             | TypeSpec::Uint32 { range }
-            | TypeSpec::Uint64 { range } => {
+            | TypeSpec::Uint64 { range }
+            | TypeSpec::Decimal64 { range, .. } => {
                 if let Some(range_constraint) = range {
                     self.validate_range_constraint(range_constraint)?;
                 }
@@ -492,16 +888,78 @@ impl YangParser {
                     self.validate_length_constraint(length_constraint)?;
                 }
             }
+            TypeSpec::TypedefRef { range, length, .. } => {
+                if let Some(range_constraint) = range {
+                    self.validate_range_constraint(range_constraint)?;
+                }
+                if let Some(length_constraint) = length {
+                    self.validate_length_constraint(length_constraint)?;
+                }
+            }
             TypeSpec::Union { types } => {
                 for t in types {
                     self.validate_typespec_constraints(t)?;
                 }
             }
+            TypeSpec::Enumeration { values } => {
+                self.validate_enum_values(values)?;
+            }
+            TypeSpec::Bits { bits } => {
+                self.validate_bits(bits)?;
+            }
             _ => {}
         }
         Ok(())
     }
 
+    /// Validate that enum names and assigned values are unique within an enumeration.
+    fn validate_enum_values(&self, values: &[EnumValue]) -> Result<(), ParseError> {
+        let mut names = HashSet::new();
+        let mut assigned = HashMap::new();
+        for enum_value in values {
+            if !names.insert(enum_value.name.as_str()) {
+                return Err(ParseError::SemanticError {
+                    message: format!("Duplicate enum name '{}'", enum_value.name),
+                });
+            }
+            if let Some(value) = enum_value.value {
+                if let Some(other) = assigned.insert(value, enum_value.name.as_str()) {
+                    return Err(ParseError::SemanticError {
+                        message: format!(
+                            "Duplicate enum value {}: used by both '{}' and '{}'",
+                            value, other, enum_value.name
+                        ),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate that bit names and positions are unique within a bits type.
+    fn validate_bits(&self, bits: &[Bit]) -> Result<(), ParseError> {
+        let mut names = HashSet::new();
+        let mut assigned = HashMap::new();
+        for bit in bits {
+            if !names.insert(bit.name.as_str()) {
+                return Err(ParseError::SemanticError {
+                    message: format!("Duplicate bit name '{}'", bit.name),
+                });
+            }
+            if let Some(position) = bit.position {
+                if let Some(other) = assigned.insert(position, bit.name.as_str()) {
+                    return Err(ParseError::SemanticError {
+                        message: format!(
+                            "Duplicate bit position {}: used by both '{}' and '{}'",
+                            position, other, bit.name
+                        ),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Validate that a range constraint is well-formed (min <= max).
     fn validate_range_constraint(&self, constraint: &RangeConstraint) -> Result<(), ParseError> {
         for range in &constraint.ranges {
@@ -534,51 +992,280 @@ impl YangParser {
 
     /// Expand typedef references and grouping uses in a module.
     /// This resolves all TypedefRef types to their concrete types and
-    /// expands all Uses nodes to their grouping definitions.
+    /// expands all Uses nodes to their grouping definitions. Prefixed
+    /// references to imported modules are resolved against the loaded modules.
+    /// Augments and deviations of the module's own nodes are applied as by
+    /// [`apply_augments`](Self::apply_augments) and
+    /// [`apply_deviations`](Self::apply_deviations).
     pub fn expand_module(&self, module: &mut YangModule) -> Result<(), ParseError> {
-        // First, expand typedefs in the module's own typedefs (for nested typedefs)
         // We need to collect typedef information first to avoid borrow issues
         let typedefs = module.typedefs.clone();
+        let groupings = module.groupings.clone();
+        let scope = DefinitionScope {
+            prefixes: PrefixTable::for_module(module),
+            typedefs: &typedefs,
+            groupings: &groupings,
+            modules: &self.loaded_modules,
+        };
 
+        // First, expand typedefs in the module's own typedefs (for nested typedefs)
         for typedef in &mut module.typedefs {
-            Self::expand_typedef_in_typespec_with_defs(&mut typedef.type_spec, &typedefs)?;
+            Self::expand_typedef_in_typespec(&mut typedef.type_spec, &scope)?;
         }
 
         // Expand data nodes
+        for data_node in &mut module.data_nodes {
+            Self::expand_data_node(data_node, &scope)?;
+        }
+
+        // Splice the module's augments of its own nodes into their targets
+        for augment in &mut module.augments {
+            for data_node in &mut augment.data_nodes {
+                Self::expand_data_node(data_node, &scope)?;
+            }
+        }
+        self.apply_augments(std::slice::from_mut(module))?;
+
+        // Then its deviations of its own nodes
+        for deviation in &mut module.deviations {
+            for type_spec in deviation
+                .deviates
+                .iter_mut()
+                .filter_map(Deviate::type_spec_mut)
+            {
+                Self::expand_typedef_in_typespec(type_spec, &scope)?;
+            }
+        }
+        self.apply_deviations(std::slice::from_mut(module))
+    }
+
+    /// Inline references to typedefs of imported modules.
+    ///
+    /// Generated code declares type aliases for a module's own typedefs only,
+    /// so each reference to an imported typedef, such as `yang:counter64`, is
+    /// replaced with the type it resolves to, restrictions included.
+    /// References to the module's own typedefs are kept.
+    pub fn inline_imported_typedefs(&self, module: &mut YangModule) -> Result<(), ParseError> {
         let typedefs = module.typedefs.clone();
         let groupings = module.groupings.clone();
+        let scope = DefinitionScope {
+            prefixes: PrefixTable::for_module(module),
+            typedefs: &typedefs,
+            groupings: &groupings,
+            modules: &self.loaded_modules,
+        };
 
-        for data_node in &mut module.data_nodes {
-            Self::expand_data_node_with_defs(data_node, &typedefs, &groupings)?;
+        // References to the module's own typedefs are stored unprefixed
+        visit_module_type_specs(module, &mut |type_spec| match type_spec {
+            TypeSpec::TypedefRef { name, .. } if name.contains(':') => {
+                Self::expand_typedef_in_typespec(type_spec, &scope)
+            }
+            _ => Ok(()),
+        })
+    }
+
+    /// Replace grouping uses with the data nodes of their groupings.
+    ///
+    /// Unlike [`expand_module`](Self::expand_module), references to the
+    /// module's own typedefs are kept, so generated code still uses their
+    /// type aliases. Typedefs referenced by a grouping of an imported module
+    /// are inlined, since they are named relative to that module.
+    pub fn expand_groupings(&self, module: &mut YangModule) -> Result<(), ParseError> {
+        let typedefs = module.typedefs.clone();
+        let groupings = module.groupings.clone();
+        let scope = DefinitionScope {
+            prefixes: PrefixTable::for_module(module),
+            typedefs: &typedefs,
+            groupings: &groupings,
+            modules: &self.loaded_modules,
+        };
+
+        Self::expand_uses(&mut module.data_nodes, &scope, false)?;
+        for augment in &mut module.augments {
+            Self::expand_uses(&mut augment.data_nodes, &scope, false)?;
+        }
+        for rpc in &mut module.rpcs {
+            if let Some(input) = &mut rpc.input {
+                Self::expand_uses(input, &scope, false)?;
+            }
+            if let Some(output) = &mut rpc.output {
+                Self::expand_uses(output, &scope, false)?;
+            }
+        }
+        for notification in &mut module.notifications {
+            Self::expand_uses(&mut notification.data_nodes, &scope, false)?;
+        }
+        inheritance::inherit_config(&mut module.data_nodes, true);
+        Ok(())
+    }
+
+    /// Replace Uses nodes among `children` and their descendants, inlining
+    /// typedef references when `inline_typedefs` is set.
+    fn expand_uses(
+        children: &mut Vec<DataNode>,
+        scope: &DefinitionScope,
+        inline_typedefs: bool,
+    ) -> Result<(), ParseError> {
+        let mut expanded = Vec::with_capacity(children.len());
+
+        for mut child in std::mem::take(children) {
+            match &mut child {
+                DataNode::Uses(uses) => {
+                    let (grouping, defining_scope) =
+                        scope.find_grouping(&uses.name)?.ok_or_else(|| {
+                            ParseError::SemanticError {
+                                message: format!("Undefined grouping: {}", uses.name),
+                            }
+                        })?;
+
+                    let mut grouping_nodes = grouping.data_nodes.clone();
+                    match &defining_scope {
+                        Some(imported) => Self::expand_uses(&mut grouping_nodes, imported, true)?,
+                        None => Self::expand_uses(&mut grouping_nodes, scope, inline_typedefs)?,
+                    }
+                    // Augment nodes are written in the module doing the uses
+                    Self::apply_uses_augments(&mut grouping_nodes, uses, &mut |nodes| {
+                        Self::expand_uses(nodes, scope, inline_typedefs)
+                    })?;
+                    // The conditions of the uses apply to every node it adds
+                    for node in &mut grouping_nodes {
+                        node.if_features_mut()
+                            .extend(uses.if_features.iter().cloned());
+                    }
+                    expanded.extend(grouping_nodes);
+                    continue;
+                }
+                DataNode::Container(container) => {
+                    Self::expand_uses(&mut container.children, scope, inline_typedefs)?;
+                }
+                DataNode::List(list) => {
+                    Self::expand_uses(&mut list.children, scope, inline_typedefs)?;
+                }
+                DataNode::Choice(choice) => {
+                    for case in &mut choice.cases {
+                        Self::expand_uses(&mut case.data_nodes, scope, inline_typedefs)?;
+                    }
+                }
+                DataNode::Case(case) => {
+                    Self::expand_uses(&mut case.data_nodes, scope, inline_typedefs)?;
+                }
+                DataNode::Leaf(leaf) if inline_typedefs => {
+                    Self::expand_typedef_in_typespec(&mut leaf.type_spec, scope)?;
+                }
+                DataNode::LeafList(leaf_list) if inline_typedefs => {
+                    Self::expand_typedef_in_typespec(&mut leaf_list.type_spec, scope)?;
+                }
+                DataNode::Leaf(_) | DataNode::LeafList(_) | DataNode::AnyData(_) => {}
+            }
+            expanded.push(child);
         }
 
+        *children = expanded;
+        Ok(())
+    }
+
+    /// Add the nodes of the augments of `uses` to the expanded nodes of its
+    /// grouping, expanding them first with `expand`.
+    fn apply_uses_augments(
+        grouping_nodes: &mut [DataNode],
+        uses: &Uses,
+        expand: &mut dyn FnMut(&mut Vec<DataNode>) -> Result<(), ParseError>,
+    ) -> Result<(), ParseError> {
+        for augment in &uses.augments {
+            let mut nodes = augment.data_nodes.clone();
+            expand(&mut nodes)?;
+            // Grouping nodes carry no prefixes
+            let names: Vec<String> = augment
+                .target
+                .iter()
+                .map(|step| step.rsplit(':').next().unwrap_or(step).to_string())
+                .collect();
+            let children = augment::node_children(grouping_nodes, &names).ok_or_else(|| {
+                ParseError::SemanticError {
+                    message: format!(
+                        "Augment target '{}' not found in grouping {}",
+                        augment.target.join("/"),
+                        uses.name
+                    ),
+                }
+            })?;
+            children.extend(nodes);
+        }
         Ok(())
     }
 
-    /// Expand typedef references in a TypeSpec using a list of typedef definitions.
-    fn expand_typedef_in_typespec_with_defs(
+    /// Expand typedef references in a TypeSpec using the definitions in scope.
+    fn expand_typedef_in_typespec(
         type_spec: &mut TypeSpec,
-        typedefs: &[TypeDef],
+        scope: &DefinitionScope,
     ) -> Result<(), ParseError> {
         match type_spec {
-            TypeSpec::TypedefRef { name } => {
+            TypeSpec::TypedefRef {
+                name,
+                range,
+                length,
+                pattern,
+            } => {
                 // Find the typedef definition
-                let typedef = typedefs.iter().find(|t| t.name == *name).ok_or_else(|| {
-                    ParseError::SemanticError {
-                        message: format!("Undefined typedef: {}", name),
-                    }
-                })?;
-
-                // Replace with the typedef's type_spec
-                *type_spec = typedef.type_spec.clone();
+                let (typedef, defining_scope) =
+                    scope
+                        .find_typedef(name)?
+                        .ok_or_else(|| ParseError::SemanticError {
+                            message: format!("Undefined typedef: {}", name),
+                        })?;
+
+                // Recursively expand in case the typedef itself references another
+                // typedef, resolving names relative to the module that defines it
+                let mut resolved = typedef.type_spec.clone();
+                Self::expand_typedef_in_typespec(
+                    &mut resolved,
+                    defining_scope.as_ref().unwrap_or(scope),
+                )?;
+
+                // Kept apart from other strings so it can be generated as a
+                // date type, unless the reference narrows it further
+                let date_and_time = length.is_none()
+                    && pattern.is_none()
+                    && matches!(
+                        scope.prefixes.resolve(name)?,
+                        ResolvedName::Imported { module, name }
+                            if module == "ietf-yang-types" && name == "date-and-time"
+                    );
+
+                // Narrow the resolved type with the derived type's own restrictions
+                let restricted = |result: Result<(), String>| {
+                    result.map_err(|message| ParseError::SemanticError {
+                        message: format!("Invalid restriction of typedef '{}': {}", name, message),
+                    })
+                };
+                if let Some(range) = range.take() {
+                    restricted(resolved.restrict_derived_range(range))?;
+                }
+                if let Some(length) = length.take() {
+                    restricted(resolved.restrict_length(length))?;
+                }
+                if let Some(pattern) = pattern.take() {
+                    restricted(resolved.restrict_pattern(pattern))?;
+                }
+                if let (
+                    true,
+                    TypeSpec::String {
+                        length: None,
+                        pattern,
+                    },
+                ) = (date_and_time, &mut resolved)
+                {
+                    resolved = TypeSpec::DateAndTime {
+                        pattern: pattern.take(),
+                    };
+                }
 
-                // Recursively expand in case the typedef itself references another typedef
-                Self::expand_typedef_in_typespec_with_defs(type_spec, typedefs)?;
+                *type_spec = resolved;
             }
             TypeSpec::Union { types } => {
                 // Expand each type in the union
                 for t in types {
-                    Self::expand_typedef_in_typespec_with_defs(t, typedefs)?;
+                    Self::expand_typedef_in_typespec(t, scope)?;
                 }
             }
             _ => {
@@ -589,44 +1276,43 @@ impl YangParser {
     }
 
     /// Expand typedef references and grouping uses in a data node.
-    fn expand_data_node_with_defs(
+    fn expand_data_node(
         data_node: &mut DataNode,
-        typedefs: &[TypeDef],
-        groupings: &[Grouping],
+        scope: &DefinitionScope,
     ) -> Result<(), ParseError> {
         match data_node {
             DataNode::Container(container) => {
-                Self::expand_children_with_defs(&mut container.children, typedefs, groupings)?;
+                Self::expand_children(&mut container.children, scope)?;
             }
             DataNode::List(list) => {
-                Self::expand_children_with_defs(&mut list.children, typedefs, groupings)?;
+                Self::expand_children(&mut list.children, scope)?;
             }
             DataNode::Leaf(leaf) => {
-                Self::expand_typedef_in_typespec_with_defs(&mut leaf.type_spec, typedefs)?;
+                Self::expand_typedef_in_typespec(&mut leaf.type_spec, scope)?;
             }
             DataNode::LeafList(leaf_list) => {
-                Self::expand_typedef_in_typespec_with_defs(&mut leaf_list.type_spec, typedefs)?;
+                Self::expand_typedef_in_typespec(&mut leaf_list.type_spec, scope)?;
             }
             DataNode::Choice(choice) => {
                 for case in &mut choice.cases {
-                    Self::expand_children_with_defs(&mut case.data_nodes, typedefs, groupings)?;
+                    Self::expand_children(&mut case.data_nodes, scope)?;
                 }
             }
             DataNode::Case(case) => {
-                Self::expand_children_with_defs(&mut case.data_nodes, typedefs, groupings)?;
+                Self::expand_children(&mut case.data_nodes, scope)?;
             }
+            DataNode::AnyData(_) => {}
             DataNode::Uses(_) => {
-                // Uses nodes will be expanded by expand_children_with_defs
+                // Uses nodes will be expanded by expand_children
             }
         }
         Ok(())
     }
 
     /// Expand children nodes, replacing Uses nodes with their grouping definitions.
-    fn expand_children_with_defs(
+    fn expand_children(
         children: &mut Vec<DataNode>,
-        typedefs: &[TypeDef],
-        groupings: &[Grouping],
+        scope: &DefinitionScope,
     ) -> Result<(), ParseError> {
         let mut expanded = Vec::new();
 
@@ -634,19 +1320,29 @@ impl YangParser {
             match child {
                 DataNode::Uses(uses) => {
                     // Find the grouping definition
-                    let grouping =
-                        groupings
-                            .iter()
-                            .find(|g| g.name == uses.name)
-                            .ok_or_else(|| ParseError::SemanticError {
+                    let (grouping, defining_scope) =
+                        scope.find_grouping(&uses.name)?.ok_or_else(|| {
+                            ParseError::SemanticError {
                                 message: format!("Undefined grouping: {}", uses.name),
-                            })?;
+                            }
+                        })?;
 
                     // Clone the grouping's data nodes and expand them recursively
                     let mut cloned_nodes = grouping.data_nodes.clone();
 
-                    // Recursively expand the cloned nodes (this handles nested uses)
-                    Self::expand_children_with_defs(&mut cloned_nodes, typedefs, groupings)?;
+                    // Recursively expand the cloned nodes (this handles nested uses),
+                    // resolving names relative to the module that defines the grouping
+                    Self::expand_children(
+                        &mut cloned_nodes,
+                        defining_scope.as_ref().unwrap_or(scope),
+                    )?;
+                    Self::apply_uses_augments(&mut cloned_nodes, uses, &mut |nodes| {
+                        Self::expand_children(nodes, scope)
+                    })?;
+                    for node in &mut cloned_nodes {
+                        node.if_features_mut()
+                            .extend(uses.if_features.iter().cloned());
+                    }
 
                     // Add the expanded nodes to the result
                     expanded.extend(cloned_nodes);
@@ -654,7 +1350,7 @@ impl YangParser {
                 _ => {
                     // Clone the child and recursively expand it
                     let mut cloned_child = child.clone();
-                    Self::expand_data_node_with_defs(&mut cloned_child, typedefs, groupings)?;
+                    Self::expand_data_node(&mut cloned_child, scope)?;
                     expanded.push(cloned_child);
                 }
             }
@@ -712,6 +1408,13 @@ pub trait DataNodeVisitor: Sized {
         Ok(())
     }
 
+    /// Visit an anydata or anyxml node.
+    ///
+    /// Default implementation does nothing.
+    fn visit_anydata(&mut self, _anydata: &AnyData) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
     /// Visit a container node.
     ///
     /// Default implementation recursively visits all children.
@@ -782,6 +1485,7 @@ pub fn walk_data_node<V: DataNodeVisitor>(
     match node {
         DataNode::Leaf(leaf) => visitor.visit_leaf(leaf),
         DataNode::LeafList(leaf_list) => visitor.visit_leaf_list(leaf_list),
+        DataNode::AnyData(anydata) => visitor.visit_anydata(anydata),
         DataNode::Container(container) => visitor.visit_container(container),
         DataNode::List(list) => visitor.visit_list(list),
         DataNode::Choice(choice) => visitor.visit_choice(choice),
@@ -813,34 +1517,352 @@ pub fn walk_data_nodes<V: DataNodeVisitor>(
     Ok(())
 }
 
-/// Internal parser for processing tokens into AST.
-struct ModuleParser {
-    tokens: Vec<Token>,
-    position: usize,
-    _filename: String,
+/// Split an absolute schema node identifier such as
+/// `/if:interfaces/if:interface` into its steps.
+fn parse_schema_node_identifier(path: &str) -> Result<Vec<String>, String> {
+    let Some(steps) = path.trim().strip_prefix('/') else {
+        return Err(format!(
+            "Invalid schema node identifier '{}': must be an absolute path starting with '/'",
+            path
+        ));
+    };
+    parse_node_identifier_steps(path, steps)
 }
 
-impl ModuleParser {
-    fn new(tokens: Vec<Token>, filename: &str) -> Self {
-        Self {
-            tokens,
-            position: 0,
-            _filename: filename.to_string(),
-        }
+/// Split a descendant schema node identifier such as `config/ipv4`, the
+/// target of an augment inside a `uses`, into its steps.
+fn parse_descendant_schema_node_identifier(path: &str) -> Result<Vec<String>, String> {
+    let steps = path.trim();
+    if steps.starts_with('/') {
+        return Err(format!(
+            "Invalid schema node identifier '{}': must be a relative path",
+            path
+        ));
     }
+    parse_node_identifier_steps(path, steps)
+}
 
-    /// Get the current token without consuming it.
-    fn peek(&self) -> &Token {
-        self.tokens.get(self.position).unwrap_or(&Token::Eof)
-    }
+/// Split the `/`-separated `steps` of the identifier `path`, checking that
+/// each is an optionally prefixed node name.
+fn parse_node_identifier_steps(path: &str, steps: &str) -> Result<Vec<String>, String> {
+    let invalid = |reason: &str| format!("Invalid schema node identifier '{}': {}", path, reason);
+    let is_identifier = |name: &str| {
+        name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+    };
+    steps
+        .split('/')
+        .map(|step| {
+            let step = step.trim();
+            let (prefix, name) = match step.split_once(':') {
+                Some((prefix, name)) => (Some(prefix), name),
+                None => (None, step),
+            };
+            if is_identifier(name) && prefix.is_none_or(is_identifier) {
+                Ok(step.to_string())
+            } else {
+                Err(invalid(&format!("'{}' is not a node name", step)))
+            }
+        })
+        .collect()
+}
 
-    /// Consume and return the current token.
-    fn advance(&mut self) -> Token {
-        let token = self.peek().clone();
-        if self.position < self.tokens.len() {
-            self.position += 1;
-        }
-        token
+/// The most recent of a module's revision dates.
+fn latest_revision(revisions: &[String]) -> Option<String> {
+    // YYYY-MM-DD dates order correctly as strings
+    revisions.iter().max().cloned()
+}
+
+/// Callback used to rewrite a typedef or grouping reference.
+type ReferenceRewriter<'a> = dyn FnMut(&str) -> Result<String, ParseError> + 'a;
+
+/// Rewrite every typedef and grouping reference in a (sub)module body.
+fn rewrite_module_references(
+    typedefs: &mut [TypeDef],
+    groupings: &mut [Grouping],
+    data_nodes: &mut [DataNode],
+    augments: &mut [Augment],
+    deviations: &mut [Deviation],
+    rpcs: &mut [Rpc],
+    notifications: &mut [Notification],
+    rewrite: &mut ReferenceRewriter<'_>,
+) -> Result<(), ParseError> {
+    for typedef in typedefs {
+        rewrite_typespec_references(&mut typedef.type_spec, rewrite)?;
+    }
+    for grouping in groupings {
+        rewrite_data_node_references(&mut grouping.data_nodes, rewrite)?;
+    }
+    rewrite_data_node_references(data_nodes, rewrite)?;
+    for augment in augments {
+        for step in &mut augment.target {
+            *step = rewrite(step)?;
+        }
+        rewrite_data_node_references(&mut augment.data_nodes, rewrite)?;
+    }
+    for deviation in deviations {
+        for step in &mut deviation.target {
+            *step = rewrite(step)?;
+        }
+        for type_spec in deviation
+            .deviates
+            .iter_mut()
+            .filter_map(Deviate::type_spec_mut)
+        {
+            rewrite_typespec_references(type_spec, rewrite)?;
+        }
+    }
+    for rpc in rpcs {
+        if let Some(input) = &mut rpc.input {
+            rewrite_data_node_references(input, rewrite)?;
+        }
+        if let Some(output) = &mut rpc.output {
+            rewrite_data_node_references(output, rewrite)?;
+        }
+    }
+    for notification in notifications {
+        rewrite_data_node_references(&mut notification.data_nodes, rewrite)?;
+    }
+    Ok(())
+}
+
+/// Rewrite typedef and grouping references in a list of data nodes.
+fn rewrite_data_node_references(
+    data_nodes: &mut [DataNode],
+    rewrite: &mut ReferenceRewriter<'_>,
+) -> Result<(), ParseError> {
+    for data_node in data_nodes {
+        match data_node {
+            DataNode::Container(container) => {
+                rewrite_data_node_references(&mut container.children, rewrite)?;
+            }
+            DataNode::List(list) => {
+                rewrite_data_node_references(&mut list.children, rewrite)?;
+            }
+            DataNode::Leaf(leaf) => {
+                rewrite_typespec_references(&mut leaf.type_spec, rewrite)?;
+            }
+            DataNode::LeafList(leaf_list) => {
+                rewrite_typespec_references(&mut leaf_list.type_spec, rewrite)?;
+            }
+            DataNode::Choice(choice) => {
+                for case in &mut choice.cases {
+                    rewrite_data_node_references(&mut case.data_nodes, rewrite)?;
+                }
+            }
+            DataNode::Case(case) => {
+                rewrite_data_node_references(&mut case.data_nodes, rewrite)?;
+            }
+            DataNode::AnyData(_) => {}
+            DataNode::Uses(uses) => {
+                uses.name = rewrite(&uses.name)?;
+                for augment in &mut uses.augments {
+                    rewrite_data_node_references(&mut augment.data_nodes, rewrite)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Rewrite typedef references in a TypeSpec.
+fn rewrite_typespec_references(
+    type_spec: &mut TypeSpec,
+    rewrite: &mut ReferenceRewriter<'_>,
+) -> Result<(), ParseError> {
+    match type_spec {
+        TypeSpec::TypedefRef { name, .. } => {
+            *name = rewrite(name)?;
+        }
+        TypeSpec::Union { types } => {
+            for t in types {
+                rewrite_typespec_references(t, rewrite)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Callback used to visit or replace a type.
+type TypeSpecVisitor<'a> = dyn FnMut(&mut TypeSpec) -> Result<(), ParseError> + 'a;
+
+/// Visit every type of a module body, and the members of unions.
+pub(crate) fn visit_module_type_specs(
+    module: &mut YangModule,
+    visit: &mut TypeSpecVisitor<'_>,
+) -> Result<(), ParseError> {
+    for typedef in &mut module.typedefs {
+        visit_type_spec(&mut typedef.type_spec, visit)?;
+    }
+    for grouping in &mut module.groupings {
+        visit_data_node_type_specs(&mut grouping.data_nodes, visit)?;
+    }
+    visit_data_node_type_specs(&mut module.data_nodes, visit)?;
+    for augment in &mut module.augments {
+        visit_data_node_type_specs(&mut augment.data_nodes, visit)?;
+    }
+    for deviation in &mut module.deviations {
+        for type_spec in deviation
+            .deviates
+            .iter_mut()
+            .filter_map(Deviate::type_spec_mut)
+        {
+            visit_type_spec(type_spec, visit)?;
+        }
+    }
+    for rpc in &mut module.rpcs {
+        if let Some(input) = &mut rpc.input {
+            visit_data_node_type_specs(input, visit)?;
+        }
+        if let Some(output) = &mut rpc.output {
+            visit_data_node_type_specs(output, visit)?;
+        }
+    }
+    for notification in &mut module.notifications {
+        visit_data_node_type_specs(&mut notification.data_nodes, visit)?;
+    }
+    Ok(())
+}
+
+/// Visit the types of leaves and leaf-lists in a list of data nodes.
+fn visit_data_node_type_specs(
+    data_nodes: &mut [DataNode],
+    visit: &mut TypeSpecVisitor<'_>,
+) -> Result<(), ParseError> {
+    for data_node in data_nodes {
+        match data_node {
+            DataNode::Container(container) => {
+                visit_data_node_type_specs(&mut container.children, visit)?;
+            }
+            DataNode::List(list) => visit_data_node_type_specs(&mut list.children, visit)?,
+            DataNode::Leaf(leaf) => visit_type_spec(&mut leaf.type_spec, visit)?,
+            DataNode::LeafList(leaf_list) => visit_type_spec(&mut leaf_list.type_spec, visit)?,
+            DataNode::Choice(choice) => {
+                for case in &mut choice.cases {
+                    visit_data_node_type_specs(&mut case.data_nodes, visit)?;
+                }
+            }
+            DataNode::Case(case) => visit_data_node_type_specs(&mut case.data_nodes, visit)?,
+            DataNode::AnyData(_) | DataNode::Uses(_) => {}
+        }
+    }
+    Ok(())
+}
+
+fn visit_type_spec(
+    type_spec: &mut TypeSpec,
+    visit: &mut TypeSpecVisitor<'_>,
+) -> Result<(), ParseError> {
+    if let TypeSpec::Union { types } = type_spec {
+        for member in types {
+            visit_type_spec(member, visit)?;
+        }
+        return Ok(());
+    }
+    visit(type_spec)
+}
+
+/// Add a comment kept from before a statement to its description, as a
+/// separate paragraph.
+fn with_comment(description: Option<String>, comment: Option<String>) -> Option<String> {
+    match (description, comment) {
+        (Some(description), Some(comment)) => Some(format!("{}\n\n{}", description, comment)),
+        (description, comment) => description.or(comment),
+    }
+}
+
+/// Internal parser for processing tokens into AST.
+struct ModuleParser {
+    tokens: Vec<Token>,
+    position: usize,
+    _filename: String,
+    /// Include statements of the most recently parsed module.
+    includes: Vec<Include>,
+    /// Revision dates of the most recently parsed module or submodule.
+    revisions: Vec<String>,
+    /// Modules imported by the module being parsed, by prefix. The same
+    /// module may be imported under several prefixes.
+    import_prefixes: HashMap<String, String>,
+    /// The prefix of the module being parsed (the `belongs-to` prefix of a
+    /// submodule) and the module's name.
+    local_prefix: Option<(String, String)>,
+    /// The comment before each token, when comments are kept.
+    comments: Vec<Option<String>>,
+    /// Names of the nodes parsed so far with an explicit `config true`.
+    config_true: Vec<String>,
+}
+
+/// Statements shared by module and submodule bodies.
+#[derive(Default)]
+struct ModuleBody {
+    yang_version: Option<YangVersion>,
+    organization: Option<String>,
+    contact: Option<String>,
+    description: Option<String>,
+    imports: Vec<Import>,
+    includes: Vec<Include>,
+    revisions: Vec<String>,
+    features: Vec<Feature>,
+    extensions: Vec<Extension>,
+    typedefs: Vec<TypeDef>,
+    groupings: Vec<Grouping>,
+    data_nodes: Vec<DataNode>,
+    augments: Vec<Augment>,
+    deviations: Vec<Deviation>,
+    rpcs: Vec<Rpc>,
+    notifications: Vec<Notification>,
+    unknown_statements: Vec<UnknownStatement>,
+}
+
+impl ModuleParser {
+    fn new(tokens: Vec<Token>, filename: &str) -> Self {
+        Self {
+            tokens,
+            position: 0,
+            _filename: filename.to_string(),
+            includes: Vec::new(),
+            revisions: Vec::new(),
+            import_prefixes: HashMap::new(),
+            local_prefix: None,
+            comments: Vec::new(),
+            config_true: Vec::new(),
+        }
+    }
+
+    /// Keep `comments`, the comment before each token, for the descriptions
+    /// of the statements they precede.
+    fn with_comments(mut self, comments: Vec<Option<String>>) -> Self {
+        self.comments = comments;
+        self
+    }
+
+    /// The comment before the current token, if comments are kept.
+    fn leading_comment(&self) -> Option<String> {
+        self.comments.get(self.position).cloned().flatten()
+    }
+
+    /// Get the current token without consuming it.
+    fn peek(&self) -> &Token {
+        self.tokens.get(self.position).unwrap_or(&Token::Eof)
+    }
+
+    /// Get the token `offset` positions after the current one without consuming anything.
+    fn peek_ahead(&self, offset: usize) -> &Token {
+        self.tokens
+            .get(self.position + offset)
+            .unwrap_or(&Token::Eof)
+    }
+
+    /// Consume and return the current token.
+    fn advance(&mut self) -> Token {
+        let token = self.peek().clone();
+        if self.position < self.tokens.len() {
+            self.position += 1;
+        }
+        token
     }
 
     /// Expect a specific token and consume it, or return an error.
@@ -880,10 +1902,26 @@ impl ModuleParser {
             Token::Output => Ok("output".to_string()),
             Token::Action => Ok("action".to_string()),
             Token::Notification => Ok("notification".to_string()),
+            Token::Prefix => Ok("prefix".to_string()),
+            Token::Key => Ok("key".to_string()),
+            Token::Units => Ok("units".to_string()),
+            Token::Range => Ok("range".to_string()),
+            Token::Length => Ok("length".to_string()),
             token => Err(self.error(format!("Expected identifier, found {:?}", token))),
         }
     }
 
+    /// Complete a possibly prefixed name (`prefix:name`) whose first identifier
+    /// has already been consumed.
+    fn parse_prefixed_name(&mut self, first: String) -> Result<String, ParseError> {
+        if self.peek() != &Token::Colon {
+            return Ok(first);
+        }
+        self.advance();
+        let name = self.parse_identifier_or_keyword()?;
+        Ok(format!("{}:{}", first, name))
+    }
+
     /// Parse a string that may be concatenated with + operator (YANG 1.1 feature).
     /// Handles: "string1" + "string2" + "string3" -> "string1string2string3"
     fn parse_concatenated_string(&mut self) -> Result<String, ParseError> {
@@ -923,6 +1961,15 @@ impl ModuleParser {
         Ok(description)
     }
 
+    /// Parse a text statement such as `organization` or `contact`:
+    /// <keyword> <string> ;
+    fn parse_text_statement(&mut self, keyword: Token) -> Result<String, ParseError> {
+        self.expect(keyword)?;
+        let text = self.parse_concatenated_string()?;
+        self.expect(Token::Semicolon)?;
+        Ok(text)
+    }
+
     /// Parse a complete YANG module.
     fn parse_module(&mut self) -> Result<YangModule, ParseError> {
         // Expect: module <identifier> { <statements> }
@@ -936,76 +1983,21 @@ impl ModuleParser {
         self.expect(Token::LeftBrace)?;
 
         // Parse module statements
-        let mut yang_version = None;
         let mut namespace = None;
         let mut prefix = None;
-        let mut imports = Vec::new();
-        let mut typedefs = Vec::new();
-        let mut groupings = Vec::new();
-        let mut data_nodes = Vec::new();
-        let mut rpcs = Vec::new();
-        let mut notifications = Vec::new();
+        let mut body = ModuleBody::default();
 
         while self.peek() != &Token::RightBrace && self.peek() != &Token::Eof {
             match self.peek() {
-                Token::YangVersion => {
-                    yang_version = Some(self.parse_yang_version()?);
-                }
                 Token::Namespace => {
                     namespace = Some(self.parse_namespace()?);
                 }
                 Token::Prefix => {
-                    prefix = Some(self.parse_prefix()?);
-                }
-                Token::Import => {
-                    imports.push(self.parse_import()?);
-                }
-                Token::Organization
-                | Token::Contact
-                | Token::Description
-                | Token::Reference
-                | Token::Revision => {
-                    // Skip module metadata statements for now
-                    self.skip_statement()?;
-                }
-                Token::Typedef => {
-                    typedefs.push(self.parse_typedef()?);
-                }
-                Token::Grouping => {
-                    groupings.push(self.parse_grouping()?);
-                }
-                Token::Container => {
-                    data_nodes.push(DataNode::Container(self.parse_container()?));
-                }
-                Token::List => {
-                    data_nodes.push(DataNode::List(self.parse_list()?));
-                }
-                Token::Leaf => {
-                    data_nodes.push(DataNode::Leaf(self.parse_leaf()?));
-                }
-                Token::LeafList => {
-                    data_nodes.push(DataNode::LeafList(self.parse_leaf_list()?));
-                }
-                Token::Choice => {
-                    data_nodes.push(DataNode::Choice(self.parse_choice()?));
-                }
-                Token::Uses => {
-                    data_nodes.push(DataNode::Uses(self.parse_uses()?));
-                }
-                Token::Rpc => {
-                    rpcs.push(self.parse_rpc()?);
-                }
-                Token::Notification => {
-                    notifications.push(self.parse_notification()?);
-                }
-                Token::Action => {
-                    // Skip action statements for now
-                    self.skip_statement()?;
-                }
-                _ => {
-                    // Skip unknown statements for now
-                    self.skip_statement()?;
+                    let parsed = self.parse_prefix()?;
+                    self.local_prefix = Some((parsed.clone(), name.clone()));
+                    prefix = Some(parsed);
                 }
+                _ => self.parse_body_statement(&mut body)?,
             }
         }
 
@@ -1018,27 +2010,207 @@ impl ModuleParser {
         let prefix = prefix.ok_or_else(|| {
             self.error("Missing required 'prefix' statement in module".to_string())
         })?;
+        self.check_local_prefix(&prefix)?;
+
+        self.includes = body.includes;
+        self.revisions = body.revisions;
 
         Ok(YangModule {
             name,
             namespace,
             prefix,
-            yang_version,
-            imports,
-            typedefs,
-            groupings,
-            data_nodes,
-            rpcs,
-            notifications,
+            yang_version: body.yang_version,
+            organization: body.organization,
+            contact: body.contact,
+            description: body.description,
+            imports: body.imports,
+            features: body.features,
+            extensions: body.extensions,
+            typedefs: body.typedefs,
+            groupings: body.groupings,
+            data_nodes: body.data_nodes,
+            augments: body.augments,
+            deviations: body.deviations,
+            rpcs: body.rpcs,
+            notifications: body.notifications,
+            unknown_statements: body.unknown_statements,
+        })
+    }
+
+    /// Parse a complete YANG submodule.
+    fn parse_submodule(&mut self) -> Result<YangSubmodule, ParseError> {
+        // Expect: submodule <identifier> { <statements> }
+        self.expect(Token::Submodule)?;
+
+        let name = match self.advance() {
+            Token::Identifier(id) => id,
+            token => return Err(self.error(format!("Expected submodule name, found {:?}", token))),
+        };
+
+        self.expect(Token::LeftBrace)?;
+
+        let mut belongs_to = None;
+        let mut body = ModuleBody::default();
+
+        while self.peek() != &Token::RightBrace && self.peek() != &Token::Eof {
+            match self.peek() {
+                Token::Identifier(id) if id == "belongs-to" => {
+                    let parsed = self.parse_belongs_to()?;
+                    self.local_prefix = Some((parsed.prefix.clone(), parsed.module.clone()));
+                    belongs_to = Some(parsed);
+                }
+                _ => self.parse_body_statement(&mut body)?,
+            }
+        }
+
+        self.expect(Token::RightBrace)?;
+
+        let belongs_to = belongs_to.ok_or_else(|| {
+            self.error("Missing required 'belongs-to' statement in submodule".to_string())
+        })?;
+        self.check_local_prefix(&belongs_to.prefix)?;
+        self.revisions = body.revisions;
+
+        Ok(YangSubmodule {
+            name,
+            belongs_to,
+            yang_version: body.yang_version,
+            imports: body.imports,
+            features: body.features,
+            extensions: body.extensions,
+            includes: body.includes,
+            typedefs: body.typedefs,
+            groupings: body.groupings,
+            data_nodes: body.data_nodes,
+            augments: body.augments,
+            deviations: body.deviations,
+            rpcs: body.rpcs,
+            notifications: body.notifications,
+            unknown_statements: body.unknown_statements,
         })
     }
 
-    /// Parse yang-version statement: yang-version "1.0" | "1.1" | 1.0 | 1.1 ;
+    /// Reject a module's own prefix (or a submodule's `belongs-to` prefix)
+    /// that is also used by one of its imports.
+    fn check_local_prefix(&self, prefix: &str) -> Result<(), ParseError> {
+        match self.import_prefixes.get(prefix) {
+            Some(module) => Err(self.error(format!(
+                "Prefix '{}' is used both for the module itself and for the import of '{}'",
+                prefix, module
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Parse one statement shared by module and submodule bodies.
+    fn parse_body_statement(&mut self, body: &mut ModuleBody) -> Result<(), ParseError> {
+        match self.peek() {
+            Token::YangVersion => {
+                body.yang_version = Some(self.parse_yang_version()?);
+            }
+            Token::Import => {
+                let import = self.parse_import()?;
+                if let Some(module) = self
+                    .import_prefixes
+                    .insert(import.prefix.clone(), import.module.clone())
+                {
+                    return Err(self.error(format!(
+                        "Prefix '{}' is already used by the import of '{}'",
+                        import.prefix, module
+                    )));
+                }
+                body.imports.push(import);
+            }
+            Token::Include => {
+                body.includes.push(self.parse_include()?);
+            }
+            Token::Revision => {
+                body.revisions.push(self.parse_revision()?);
+            }
+            Token::Organization => {
+                body.organization = Some(self.parse_text_statement(Token::Organization)?);
+            }
+            Token::Contact => {
+                body.contact = Some(self.parse_text_statement(Token::Contact)?);
+            }
+            Token::Description => {
+                body.description = Some(self.parse_description_statement()?);
+            }
+            Token::Reference => {
+                // Skip module reference statements for now
+                self.skip_statement()?;
+            }
+            Token::Feature => {
+                body.features.push(self.parse_feature()?);
+            }
+            Token::Extension => {
+                body.extensions.push(self.parse_extension()?);
+            }
+            Token::Typedef => {
+                body.typedefs.push(self.parse_typedef()?);
+            }
+            Token::Grouping => {
+                body.groupings.push(self.parse_grouping()?);
+            }
+            Token::Container => {
+                body.data_nodes
+                    .push(DataNode::Container(self.parse_container()?));
+            }
+            Token::List => {
+                body.data_nodes.push(DataNode::List(self.parse_list()?));
+            }
+            Token::Leaf => {
+                body.data_nodes.push(DataNode::Leaf(self.parse_leaf()?));
+            }
+            Token::LeafList => {
+                body.data_nodes
+                    .push(DataNode::LeafList(self.parse_leaf_list()?));
+            }
+            _ if self.at_anydata() => {
+                body.data_nodes
+                    .push(DataNode::AnyData(self.parse_anydata()?));
+            }
+            Token::Choice => {
+                body.data_nodes.push(DataNode::Choice(self.parse_choice()?));
+            }
+            Token::Uses => {
+                body.data_nodes.push(DataNode::Uses(self.parse_uses()?));
+            }
+            Token::Augment => {
+                body.augments.push(self.parse_augment()?);
+            }
+            Token::Deviation => {
+                body.deviations.push(self.parse_deviation()?);
+            }
+            Token::Rpc => {
+                body.rpcs.push(self.parse_rpc()?);
+            }
+            Token::Notification => {
+                body.notifications.push(self.parse_notification()?);
+            }
+            Token::Action => {
+                // Skip action statements for now
+                self.skip_statement()?;
+            }
+            _ if self.at_unknown_statement() => {
+                body.unknown_statements
+                    .push(self.parse_unknown_statement()?);
+            }
+            _ => {
+                // Skip unknown statements for now
+                self.skip_statement()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Parse yang-version statement: yang-version "1" | "1.0" | "1.1" | 1 | 1.0 | 1.1 ;
     fn parse_yang_version(&mut self) -> Result<YangVersion, ParseError> {
         self.expect(Token::YangVersion)?;
 
         let version = match self.peek() {
-            Token::StringLiteral(s) if s == "1.0" => {
+            // RFC 6020 writes the version of YANG 1.0 as "1"
+            Token::StringLiteral(s) if s == "1.0" || s == "1" => {
                 self.advance();
                 YangVersion::V1_0
             }
@@ -1104,12 +2276,15 @@ impl ModuleParser {
         Ok(prefix)
     }
 
-    /// Parse import statement: import <identifier> { prefix <identifier>; [revision-date <string>;] }
+    /// Parse import statement:
+    /// import <identifier> { prefix <identifier>; [revision-date <string>;]
+    /// [description <string>;] [reference <string>;] }
     fn parse_import(&mut self) -> Result<Import, ParseError> {
         self.expect(Token::Import)?;
 
         let module = match self.advance() {
             Token::Identifier(id) => id,
+            Token::StringLiteral(s) => s,
             token => {
                 return Err(self.error(format!("Expected import module name, found {:?}", token)))
             }
@@ -1118,25 +2293,29 @@ impl ModuleParser {
         self.expect(Token::LeftBrace)?;
 
         let mut prefix = None;
-        let revision = None;
+        let mut revision = None;
+        let mut description = None;
+        let mut reference = None;
 
         while self.peek() != &Token::RightBrace && self.peek() != &Token::Eof {
             match self.peek() {
                 Token::Prefix => {
-                    self.advance();
-                    prefix = Some(match self.advance() {
-                        Token::Identifier(id) => id,
-                        token => {
-                            return Err(self
-                                .error(format!("Expected prefix identifier, found {:?}", token)))
-                        }
-                    });
-                    self.expect(Token::Semicolon)?;
+                    if prefix.is_some() {
+                        return Err(self.error(format!(
+                            "Duplicate 'prefix' statement in import of '{}'",
+                            module
+                        )));
+                    }
+                    prefix = Some(self.parse_prefix()?);
                 }
-                Token::Revision => {
-                    self.advance();
-                    // Skip revision-date for now
-                    self.skip_until_semicolon()?;
+                Token::Identifier(id) if id == "revision-date" => {
+                    revision = Some(self.parse_revision_date()?);
+                }
+                Token::Description => {
+                    description = Some(self.parse_description_statement()?);
+                }
+                Token::Reference => {
+                    reference = Some(self.parse_text_statement(Token::Reference)?);
                 }
                 _ => {
                     self.skip_statement()?;
@@ -1154,31 +2333,155 @@ impl ModuleParser {
             module,
             prefix,
             revision,
+            description,
+            reference,
         })
     }
 
-    /// Parse typedef statement: typedef <identifier> { type <type-spec>; [units <string>;] [default <string>;] [description <string>;] }
-    fn parse_typedef(&mut self) -> Result<TypeDef, ParseError> {
-        self.expect(Token::Typedef)?;
+    /// Parse include statement: include <identifier> [{ [revision-date <string>;] }]
+    fn parse_include(&mut self) -> Result<Include, ParseError> {
+        self.expect(Token::Include)?;
 
-        let name = match self.advance() {
+        let submodule = match self.advance() {
             Token::Identifier(id) => id,
-            token => return Err(self.error(format!("Expected typedef name, found {:?}", token))),
+            token => return Err(self.error(format!("Expected submodule name, found {:?}", token))),
         };
 
-        self.expect(Token::LeftBrace)?;
-
-        let mut type_spec = None;
-        let mut units = None;
-        let mut default = None;
-        let mut description = None;
-
-        while self.peek() != &Token::RightBrace && self.peek() != &Token::Eof {
-            match self.peek() {
-                Token::Type => {
-                    type_spec = Some(self.parse_type_spec()?);
+        let mut revision = None;
+        if self.peek() == &Token::LeftBrace {
+            self.advance();
+            while self.peek() != &Token::RightBrace && self.peek() != &Token::Eof {
+                match self.peek() {
+                    Token::Identifier(id) if id == "revision-date" => {
+                        revision = Some(self.parse_revision_date()?);
+                    }
+                    _ => {
+                        // Skip description and reference
+                        self.skip_statement()?;
+                    }
                 }
-                Token::Units => {
+            }
+            self.expect(Token::RightBrace)?;
+        } else {
+            self.expect(Token::Semicolon)?;
+        }
+
+        Ok(Include {
+            submodule,
+            revision,
+        })
+    }
+
+    /// Parse revision-date statement: revision-date <date>;
+    fn parse_revision_date(&mut self) -> Result<String, ParseError> {
+        // The `revision-date` keyword is lexed as an identifier
+        self.advance();
+        let date = self.parse_date_argument()?;
+        self.expect(Token::Semicolon)?;
+        Ok(date)
+    }
+
+    /// Parse revision statement: revision <date> [{ description <string>; ... }]
+    ///
+    /// Only the date is kept.
+    fn parse_revision(&mut self) -> Result<String, ParseError> {
+        self.expect(Token::Revision)?;
+        let date = self.parse_date_argument()?;
+
+        if self.peek() == &Token::LeftBrace {
+            self.advance();
+            while self.peek() != &Token::RightBrace && self.peek() != &Token::Eof {
+                self.skip_statement()?;
+            }
+            self.expect(Token::RightBrace)?;
+        } else {
+            self.expect(Token::Semicolon)?;
+        }
+
+        Ok(date)
+    }
+
+    /// Parse a date argument (YYYY-MM-DD), quoted or not.
+    ///
+    /// An unquoted date is lexed as three numbers (`2024`, `-01`, `-15`).
+    fn parse_date_argument(&mut self) -> Result<String, ParseError> {
+        match self.advance() {
+            Token::StringLiteral(date) | Token::Identifier(date) => Ok(date),
+            Token::Number(year) => match (self.peek().clone(), self.peek_ahead(1).clone()) {
+                (Token::Number(month), Token::Number(day)) if month < 0 && day < 0 => {
+                    self.advance();
+                    self.advance();
+                    Ok(format!("{:04}-{:02}-{:02}", year, -month, -day))
+                }
+                _ => Err(self.error(format!("Expected date, found {}", year))),
+            },
+            token => Err(self.error(format!("Expected date, found {:?}", token))),
+        }
+    }
+
+    /// Parse belongs-to statement: belongs-to <identifier> { prefix <identifier>; }
+    fn parse_belongs_to(&mut self) -> Result<BelongsTo, ParseError> {
+        // The `belongs-to` keyword is lexed as an identifier
+        self.advance();
+
+        let module = match self.advance() {
+            Token::Identifier(id) => id,
+            token => {
+                return Err(self.error(format!(
+                    "Expected belongs-to module name, found {:?}",
+                    token
+                )))
+            }
+        };
+
+        self.expect(Token::LeftBrace)?;
+
+        let mut prefix = None;
+
+        while self.peek() != &Token::RightBrace && self.peek() != &Token::Eof {
+            match self.peek() {
+                Token::Prefix => {
+                    prefix = Some(self.parse_prefix()?);
+                }
+                _ => {
+                    self.skip_statement()?;
+                }
+            }
+        }
+
+        self.expect(Token::RightBrace)?;
+
+        let prefix = prefix.ok_or_else(|| {
+            self.error("Missing required 'prefix' statement in belongs-to".to_string())
+        })?;
+
+        Ok(BelongsTo { module, prefix })
+    }
+
+    /// Parse typedef statement: typedef <identifier> { type <type-spec>; [units <string>;] [default <string>;] [description <string>;] }
+    fn parse_typedef(&mut self) -> Result<TypeDef, ParseError> {
+        let comment = self.leading_comment();
+        self.expect(Token::Typedef)?;
+
+        let name = match self.advance() {
+            Token::Identifier(id) => id,
+            token => return Err(self.error(format!("Expected typedef name, found {:?}", token))),
+        };
+
+        self.expect(Token::LeftBrace)?;
+
+        let mut type_spec = None;
+        let mut units = None;
+        let mut default = None;
+        let mut description = None;
+        let mut status = Status::Current;
+
+        while self.peek() != &Token::RightBrace && self.peek() != &Token::Eof {
+            match self.peek() {
+                Token::Type => {
+                    type_spec = Some(self.parse_type_spec()?);
+                }
+                Token::Units => {
                     self.advance();
                     units = Some(match self.advance() {
                         Token::StringLiteral(s) | Token::Identifier(s) => s,
@@ -1204,6 +2507,9 @@ impl ModuleParser {
                     });
                     self.expect(Token::Semicolon)?;
                 }
+                Token::Status => {
+                    status = self.parse_status_statement()?;
+                }
                 Token::Description => {
                     description = Some(self.parse_description_statement()?);
                 }
@@ -1224,7 +2530,8 @@ impl ModuleParser {
             type_spec,
             units,
             default,
-            description,
+            description: with_comment(description, comment),
+            status,
         })
     }
 
@@ -1290,6 +2597,10 @@ impl ModuleParser {
                 self.advance();
                 TypeSpec::Enumeration { values: Vec::new() }
             }
+            Token::Bits => {
+                self.advance();
+                TypeSpec::Bits { bits: Vec::new() }
+            }
             Token::Union => {
                 self.advance();
                 TypeSpec::Union { types: Vec::new() }
@@ -1307,7 +2618,16 @@ impl ModuleParser {
                     Token::Identifier(id) => id,
                     _ => unreachable!(),
                 };
-                TypeSpec::TypedefRef { name }
+                if name == "decimal64" {
+                    // Fraction digits are required and given in the body
+                    TypeSpec::Decimal64 {
+                        fraction_digits: 0,
+                        range: None,
+                    }
+                } else {
+                    let name = self.parse_prefixed_name(name)?;
+                    TypeSpec::typedef_ref(name)
+                }
             }
             _ => return Err(self.error(format!("Expected type name, found {:?}", base_type))),
         };
@@ -1323,49 +2643,44 @@ impl ModuleParser {
             self.expect(Token::Semicolon)?;
         }
 
+        if let TypeSpec::Decimal64 {
+            fraction_digits: 0, ..
+        } = type_spec
+        {
+            return Err(
+                self.error("decimal64 type requires a fraction-digits statement".to_string())
+            );
+        }
+
         Ok(type_spec)
     }
 
     /// Parse type body (constraints, enum values, union types)
     fn parse_type_body(&mut self, mut type_spec: TypeSpec) -> Result<TypeSpec, ParseError> {
+        let mut decimal_ranges = Vec::new();
         while self.peek() != &Token::RightBrace && self.peek() != &Token::Eof {
             match self.peek() {
+                Token::Range if matches!(type_spec, TypeSpec::Decimal64 { .. }) => {
+                    // Scaled once the fraction digits are known
+                    decimal_ranges.push(self.parse_range_argument()?);
+                }
                 Token::Range => {
                     let range = self.parse_range_constraint()?;
-                    type_spec = match type_spec {
-                        TypeSpec::Int8 { .. } => TypeSpec::Int8 { range: Some(range) },
-                        TypeSpec::Int16 { .. } => TypeSpec::Int16 { range: Some(range) },
-                        TypeSpec::Int32 { .. } => TypeSpec::Int32 { range: Some(range) },
-                        TypeSpec::Int64 { .. } => TypeSpec::Int64 { range: Some(range) },
-                        TypeSpec::Uint8 { .. } => TypeSpec::Uint8 { range: Some(range) },
-                        TypeSpec::Uint16 { .. } => TypeSpec::Uint16 { range: Some(range) },
-                        TypeSpec::Uint32 { .. } => TypeSpec::Uint32 { range: Some(range) },
-                        TypeSpec::Uint64 { .. } => TypeSpec::Uint64 { range: Some(range) },
-                        _ => type_spec,
-                    };
+                    type_spec
+                        .restrict_range(range)
+                        .map_err(|message| self.error(message))?;
                 }
                 Token::Length => {
                     let length = self.parse_length_constraint()?;
-                    type_spec = match type_spec {
-                        TypeSpec::String { pattern, .. } => TypeSpec::String {
-                            length: Some(length),
-                            pattern,
-                        },
-                        TypeSpec::Binary { .. } => TypeSpec::Binary {
-                            length: Some(length),
-                        },
-                        _ => type_spec,
-                    };
+                    type_spec
+                        .restrict_length(length)
+                        .map_err(|message| self.error(message))?;
                 }
                 Token::Pattern => {
                     let pattern = self.parse_pattern_constraint()?;
-                    type_spec = match type_spec {
-                        TypeSpec::String { length, .. } => TypeSpec::String {
-                            length,
-                            pattern: Some(pattern),
-                        },
-                        _ => type_spec,
-                    };
+                    type_spec
+                        .restrict_pattern(pattern)
+                        .map_err(|message| self.error(message))?;
                 }
                 Token::Enum => {
                     let enum_value = self.parse_enum_value()?;
@@ -1373,6 +2688,12 @@ impl ModuleParser {
                         values.push(enum_value);
                     }
                 }
+                Token::Bit => {
+                    let bit = self.parse_bit()?;
+                    if let TypeSpec::Bits { ref mut bits } = type_spec {
+                        bits.push(bit);
+                    }
+                }
                 Token::Type => {
                     // Union type member
                     let member_type = self.parse_type_spec()?;
@@ -1380,6 +2701,35 @@ impl ModuleParser {
                         types.push(member_type);
                     }
                 }
+                Token::Identifier(ref id) if id == "fraction-digits" => {
+                    self.advance();
+                    let number = match self.advance() {
+                        Token::Number(n) => Some(n),
+                        Token::StringLiteral(s) => s.trim().parse::<i64>().ok(),
+                        token => {
+                            return Err(self.error(format!(
+                                "Expected fraction-digits value, found {:?}",
+                                token
+                            )))
+                        }
+                    };
+                    let digits = match number {
+                        Some(n @ 1..=18) => n as u8,
+                        _ => {
+                            return Err(self.error(
+                                "fraction-digits must be an integer from 1 to 18".to_string(),
+                            ))
+                        }
+                    };
+                    self.expect(Token::Semicolon)?;
+                    if let TypeSpec::Decimal64 {
+                        ref mut fraction_digits,
+                        ..
+                    } = type_spec
+                    {
+                        *fraction_digits = digits;
+                    }
+                }
                 Token::Identifier(ref id) if id == "path" => {
                     // leafref path
                     self.advance();
@@ -1401,11 +2751,91 @@ impl ModuleParser {
                 }
             }
         }
+
+        if let TypeSpec::Enumeration { ref mut values } = type_spec {
+            self.assign_enum_values(values)?;
+        }
+        if let TypeSpec::Bits { ref mut bits } = type_spec {
+            self.assign_bit_positions(bits)?;
+        }
+        if let TypeSpec::Decimal64 {
+            fraction_digits: fraction_digits @ 1..,
+            ..
+        } = type_spec
+        {
+            for range_str in decimal_ranges {
+                let ranges = self.parse_decimal_range_string(&range_str, fraction_digits)?;
+                type_spec
+                    .restrict_range(RangeConstraint::new(ranges))
+                    .map_err(|message| self.error(message))?;
+            }
+        }
         Ok(type_spec)
     }
 
+    /// Assign implicit enum values (RFC 7950 section 9.6.4.2).
+    ///
+    /// An enum without a `value` statement gets one greater than the highest value
+    /// assigned so far, or zero for the first enum.
+    fn assign_enum_values(&self, values: &mut [EnumValue]) -> Result<(), ParseError> {
+        let mut highest: Option<i32> = None;
+        for enum_value in values {
+            let value = match enum_value.value {
+                Some(value) => value,
+                None => match highest {
+                    None => 0,
+                    Some(highest) => highest.checked_add(1).ok_or_else(|| {
+                        self.error(format!(
+                            "Enum '{}' needs an explicit value: the implicit value would exceed {}",
+                            enum_value.name,
+                            i32::MAX
+                        ))
+                    })?,
+                },
+            };
+            enum_value.value = Some(value);
+            highest = Some(highest.map_or(value, |h| h.max(value)));
+        }
+        Ok(())
+    }
+
+    /// Assign implicit bit positions (RFC 7950 section 9.7.4.2).
+    ///
+    /// A bit without a `position` statement gets one greater than the highest
+    /// position assigned so far, or zero for the first bit.
+    fn assign_bit_positions(&self, bits: &mut [Bit]) -> Result<(), ParseError> {
+        let mut highest: Option<u32> = None;
+        for bit in bits {
+            let position = match bit.position {
+                Some(position) => position,
+                None => match highest {
+                    None => 0,
+                    Some(highest) => highest.checked_add(1).ok_or_else(|| {
+                        self.error(format!(
+                            "Bit '{}' needs an explicit position: the implicit position would exceed {}",
+                            bit.name,
+                            u32::MAX
+                        ))
+                    })?,
+                },
+            };
+            bit.position = Some(position);
+            highest = Some(highest.map_or(position, |h| h.max(position)));
+        }
+        Ok(())
+    }
+
     /// Parse range constraint: range "min..max | min..max"
     fn parse_range_constraint(&mut self) -> Result<RangeConstraint, ParseError> {
+        let range_str = self.parse_range_argument()?;
+
+        // Parse range string: "1..10 | 20..30"
+        let ranges = self.parse_range_string(&range_str)?;
+        Ok(RangeConstraint::new(ranges))
+    }
+
+    /// Parse the argument of a range statement, e.g. `"1..10 | 20..30"`
+    fn parse_range_argument(&mut self) -> Result<String, ParseError> {
         self.expect(Token::Range)?;
 
         let range_str = match self.advance() {
@@ -1414,10 +2844,36 @@ impl ModuleParser {
         };
 
         self.expect(Token::Semicolon)?;
+        Ok(range_str)
+    }
 
-        // Parse range string: "1..10 | 20..30"
-        let ranges = self.parse_range_string(&range_str)?;
-        Ok(RangeConstraint::new(ranges))
+    /// Parse a decimal64 range string into ranges scaled by 10^`fraction_digits`
+    fn parse_decimal_range_string(
+        &self,
+        range_str: &str,
+        fraction_digits: u8,
+    ) -> Result<Vec<Range>, ParseError> {
+        let value = |text: &str| {
+            parse_decimal64(text.trim(), fraction_digits).ok_or_else(|| {
+                self.error(format!(
+                    "Invalid decimal64 range value with {} fraction digits: {}",
+                    fraction_digits,
+                    text.trim()
+                ))
+            })
+        };
+
+        let mut ranges = Vec::new();
+        for part in range_str.split('|') {
+            match part.split_once("..") {
+                Some((min, max)) => ranges.push(Range::new(value(min)?, value(max)?)),
+                None => {
+                    let single = value(part)?;
+                    ranges.push(Range::new(single, single));
+                }
+            }
+        }
+        Ok(ranges)
     }
 
     /// Parse range string into Range objects
@@ -1524,15 +2980,25 @@ impl ModuleParser {
                 match self.peek() {
                     Token::Identifier(ref id) if id == "value" => {
                         self.advance();
-                        value = Some(match self.advance() {
-                            Token::Number(n) => n as i32,
+                        let number = match self.advance() {
+                            Token::Number(n) => Some(n),
+                            Token::StringLiteral(s) => s.trim().parse::<i64>().ok(),
                             token => {
                                 return Err(self.error(format!(
                                     "Expected enum value number, found {:?}",
                                     token
                                 )))
                             }
-                        });
+                        };
+                        value = match number.map(i32::try_from) {
+                            Some(Ok(n)) => Some(n),
+                            _ => {
+                                return Err(self.error(format!(
+                                    "Enum value for '{}' must be an integer in the int32 range",
+                                    name
+                                )))
+                            }
+                        };
                         self.expect(Token::Semicolon)?;
                     }
                     Token::Description => {
@@ -1566,6 +3032,58 @@ impl ModuleParser {
         })
     }
 
+    /// Parse bit statement: bit <identifier> [{ position <n>; description "..."; }]
+    fn parse_bit(&mut self) -> Result<Bit, ParseError> {
+        self.expect(Token::Bit)?;
+
+        let name = self.parse_identifier_or_keyword()?;
+        let mut bit = Bit::new(name);
+
+        if self.peek() == &Token::LeftBrace {
+            self.advance();
+
+            while self.peek() != &Token::RightBrace && self.peek() != &Token::Eof {
+                match self.peek() {
+                    Token::Identifier(ref id) if id == "position" => {
+                        self.advance();
+                        let number = match self.advance() {
+                            Token::Number(n) => Some(n),
+                            Token::StringLiteral(s) => s.trim().parse::<i64>().ok(),
+                            token => {
+                                return Err(self.error(format!(
+                                    "Expected bit position number, found {:?}",
+                                    token
+                                )))
+                            }
+                        };
+                        bit.position = match number.map(u32::try_from) {
+                            Some(Ok(n)) => Some(n),
+                            _ => {
+                                return Err(self.error(format!(
+                                    "Position of bit '{}' must be an integer in the uint32 range",
+                                    bit.name
+                                )))
+                            }
+                        };
+                        self.expect(Token::Semicolon)?;
+                    }
+                    Token::Description => {
+                        bit.description = Some(self.parse_description_statement()?);
+                    }
+                    _ => {
+                        self.skip_statement()?;
+                    }
+                }
+            }
+
+            self.expect(Token::RightBrace)?;
+        } else {
+            self.expect(Token::Semicolon)?;
+        }
+
+        Ok(bit)
+    }
+
     /// Parse grouping statement: grouping <identifier> { <data-definition-statements> }
     fn parse_grouping(&mut self) -> Result<Grouping, ParseError> {
         self.expect(Token::Grouping)?;
@@ -1578,10 +3096,14 @@ impl ModuleParser {
         self.expect(Token::LeftBrace)?;
 
         let mut description = None;
+        let mut status = Status::Current;
         let mut data_nodes = Vec::new();
 
         while self.peek() != &Token::RightBrace && self.peek() != &Token::Eof {
             match self.peek() {
+                Token::Status => {
+                    status = self.parse_status_statement()?;
+                }
                 Token::Description => {
                     description = Some(self.parse_description_statement()?);
                 }
@@ -1597,6 +3119,9 @@ impl ModuleParser {
                 Token::LeafList => {
                     data_nodes.push(DataNode::LeafList(self.parse_leaf_list()?));
                 }
+                _ if self.at_anydata() => {
+                    data_nodes.push(DataNode::AnyData(self.parse_anydata()?));
+                }
                 Token::Uses => {
                     data_nodes.push(DataNode::Uses(self.parse_uses()?));
                 }
@@ -1611,12 +3136,14 @@ impl ModuleParser {
         Ok(Grouping {
             name,
             description,
+            status,
             data_nodes,
         })
     }
 
     /// Parse container statement: container <identifier> { <statements> }
     fn parse_container(&mut self) -> Result<Container, ParseError> {
+        let comment = self.leading_comment();
         self.expect(Token::Container)?;
 
         let name = self.parse_identifier_or_keyword()?;
@@ -1624,26 +3151,35 @@ impl ModuleParser {
         self.expect(Token::LeftBrace)?;
 
         let mut description = None;
+        let mut status = Status::Current;
+        let mut unknown_statements = Vec::new();
+        let mut if_features = Vec::new();
+        let mut when = None;
+        let mut must = Vec::new();
         let mut config = true;
+        let config_true_since = self.config_true.len();
         let mut mandatory = false;
         let mut children = Vec::new();
 
         while self.peek() != &Token::RightBrace && self.peek() != &Token::Eof {
             match self.peek() {
+                Token::Status => {
+                    status = self.parse_status_statement()?;
+                }
                 Token::Description => {
                     description = Some(self.parse_description_statement()?);
                 }
+                Token::IfFeature => {
+                    if_features.push(self.parse_if_feature()?);
+                }
+                Token::When => {
+                    when = Some(self.parse_when()?);
+                }
+                Token::Must => {
+                    must.push(self.parse_must()?);
+                }
                 Token::Config => {
-                    self.advance();
-                    config = match self.advance() {
-                        Token::Identifier(s) if s == "true" => true,
-                        Token::Identifier(s) if s == "false" => false,
-                        token => {
-                            return Err(self
-                                .error(format!("Expected 'true' or 'false', found {:?}", token)))
-                        }
-                    };
-                    self.expect(Token::Semicolon)?;
+                    config = self.parse_config_statement(&name)?;
                 }
                 Token::Mandatory => {
                     self.advance();
@@ -1669,12 +3205,18 @@ impl ModuleParser {
                 Token::LeafList => {
                     children.push(DataNode::LeafList(self.parse_leaf_list()?));
                 }
+                _ if self.at_anydata() => {
+                    children.push(DataNode::AnyData(self.parse_anydata()?));
+                }
                 Token::Choice => {
                     children.push(DataNode::Choice(self.parse_choice()?));
                 }
                 Token::Uses => {
                     children.push(DataNode::Uses(self.parse_uses()?));
                 }
+                _ if self.at_unknown_statement() => {
+                    unknown_statements.push(self.parse_unknown_statement()?);
+                }
                 _ => {
                     self.skip_statement()?;
                 }
@@ -1682,18 +3224,27 @@ impl ModuleParser {
         }
 
         self.expect(Token::RightBrace)?;
+        if !config {
+            self.reject_config_true_below(&name, config_true_since)?;
+        }
 
         Ok(Container {
             name,
-            description,
+            description: with_comment(description, comment),
+            status,
             config,
             mandatory,
             children,
+            if_features,
+            when,
+            must,
+            unknown_statements,
         })
     }
 
     /// Parse list statement: list <identifier> { <statements> }
     fn parse_list(&mut self) -> Result<List, ParseError> {
+        let comment = self.leading_comment();
         self.expect(Token::List)?;
 
         let name = self.parse_identifier_or_keyword()?;
@@ -1701,26 +3252,43 @@ impl ModuleParser {
         self.expect(Token::LeftBrace)?;
 
         let mut description = None;
+        let mut status = Status::Current;
+        let mut unknown_statements = Vec::new();
+        let mut if_features = Vec::new();
+        let mut when = None;
+        let mut must = Vec::new();
+        let mut min_elements = 0;
+        let mut max_elements = None;
         let mut config = true;
+        let config_true_since = self.config_true.len();
         let mut keys = Vec::new();
         let mut children = Vec::new();
 
         while self.peek() != &Token::RightBrace && self.peek() != &Token::Eof {
             match self.peek() {
+                Token::Status => {
+                    status = self.parse_status_statement()?;
+                }
                 Token::Description => {
                     description = Some(self.parse_description_statement()?);
                 }
+                Token::IfFeature => {
+                    if_features.push(self.parse_if_feature()?);
+                }
+                Token::When => {
+                    when = Some(self.parse_when()?);
+                }
+                Token::Must => {
+                    must.push(self.parse_must()?);
+                }
                 Token::Config => {
-                    self.advance();
-                    config = match self.advance() {
-                        Token::Identifier(s) if s == "true" => true,
-                        Token::Identifier(s) if s == "false" => false,
-                        token => {
-                            return Err(self
-                                .error(format!("Expected 'true' or 'false', found {:?}", token)))
-                        }
-                    };
-                    self.expect(Token::Semicolon)?;
+                    config = self.parse_config_statement(&name)?;
+                }
+                Token::MinElements => {
+                    min_elements = self.parse_min_elements()?;
+                }
+                Token::MaxElements => {
+                    max_elements = self.parse_max_elements()?;
                 }
                 Token::Key => {
                     self.advance();
@@ -1746,12 +3314,18 @@ impl ModuleParser {
                 Token::LeafList => {
                     children.push(DataNode::LeafList(self.parse_leaf_list()?));
                 }
+                _ if self.at_anydata() => {
+                    children.push(DataNode::AnyData(self.parse_anydata()?));
+                }
                 Token::Choice => {
                     children.push(DataNode::Choice(self.parse_choice()?));
                 }
                 Token::Uses => {
                     children.push(DataNode::Uses(self.parse_uses()?));
                 }
+                _ if self.at_unknown_statement() => {
+                    unknown_statements.push(self.parse_unknown_statement()?);
+                }
                 _ => {
                     self.skip_statement()?;
                 }
@@ -1759,18 +3333,30 @@ impl ModuleParser {
         }
 
         self.expect(Token::RightBrace)?;
+        self.check_element_bounds(&name, min_elements, max_elements)?;
+        if !config {
+            self.reject_config_true_below(&name, config_true_since)?;
+        }
 
         Ok(List {
             name,
-            description,
+            description: with_comment(description, comment),
+            status,
             config,
             keys,
             children,
+            if_features,
+            when,
+            must,
+            min_elements,
+            max_elements,
+            unknown_statements,
         })
     }
 
     /// Parse leaf statement: leaf <identifier> { type <type-spec>; <statements> }
     fn parse_leaf(&mut self) -> Result<Leaf, ParseError> {
+        let comment = self.leading_comment();
         self.expect(Token::Leaf)?;
 
         let name = self.parse_identifier_or_keyword()?;
@@ -1779,8 +3365,14 @@ impl ModuleParser {
 
         let mut type_spec = None;
         let mut description = None;
+        let mut status = Status::Current;
+        let mut unknown_statements = Vec::new();
+        let mut if_features = Vec::new();
+        let mut when = None;
+        let mut must = Vec::new();
         let mut mandatory = false;
         let mut default = None;
+        let mut units = None;
         let mut config = true;
 
         while self.peek() != &Token::RightBrace && self.peek() != &Token::Eof {
@@ -1788,9 +3380,21 @@ impl ModuleParser {
                 Token::Type => {
                     type_spec = Some(self.parse_type_spec()?);
                 }
+                Token::Status => {
+                    status = self.parse_status_statement()?;
+                }
                 Token::Description => {
                     description = Some(self.parse_description_statement()?);
                 }
+                Token::IfFeature => {
+                    if_features.push(self.parse_if_feature()?);
+                }
+                Token::When => {
+                    when = Some(self.parse_when()?);
+                }
+                Token::Must => {
+                    must.push(self.parse_must()?);
+                }
                 Token::Mandatory => {
                     self.advance();
                     mandatory = match self.advance() {
@@ -1816,18 +3420,24 @@ impl ModuleParser {
                     });
                     self.expect(Token::Semicolon)?;
                 }
-                Token::Config => {
+                Token::Units => {
                     self.advance();
-                    config = match self.advance() {
-                        Token::Identifier(s) if s == "true" => true,
-                        Token::Identifier(s) if s == "false" => false,
+                    units = Some(match self.advance() {
+                        Token::StringLiteral(s) | Token::Identifier(s) => s,
                         token => {
-                            return Err(self
-                                .error(format!("Expected 'true' or 'false', found {:?}", token)))
+                            return Err(
+                                self.error(format!("Expected units value, found {:?}", token))
+                            )
                         }
-                    };
+                    });
                     self.expect(Token::Semicolon)?;
                 }
+                Token::Config => {
+                    config = self.parse_config_statement(&name)?;
+                }
+                _ if self.at_unknown_statement() => {
+                    unknown_statements.push(self.parse_unknown_statement()?);
+                }
                 _ => {
                     self.skip_statement()?;
                 }
@@ -1841,16 +3451,23 @@ impl ModuleParser {
 
         Ok(Leaf {
             name,
-            description,
+            description: with_comment(description, comment),
+            status,
             type_spec,
             mandatory,
             default,
+            units,
             config,
+            if_features,
+            when,
+            must,
+            unknown_statements,
         })
     }
 
     /// Parse leaf-list statement: leaf-list <identifier> { type <type-spec>; <statements> }
     fn parse_leaf_list(&mut self) -> Result<LeafList, ParseError> {
+        let comment = self.leading_comment();
         self.expect(Token::LeafList)?;
 
         let name = self.parse_identifier_or_keyword()?;
@@ -1859,6 +3476,13 @@ impl ModuleParser {
 
         let mut type_spec = None;
         let mut description = None;
+        let mut status = Status::Current;
+        let mut unknown_statements = Vec::new();
+        let mut if_features = Vec::new();
+        let mut when = None;
+        let mut must = Vec::new();
+        let mut min_elements = 0;
+        let mut max_elements = None;
         let mut config = true;
 
         while self.peek() != &Token::RightBrace && self.peek() != &Token::Eof {
@@ -1866,20 +3490,32 @@ impl ModuleParser {
                 Token::Type => {
                     type_spec = Some(self.parse_type_spec()?);
                 }
+                Token::Status => {
+                    status = self.parse_status_statement()?;
+                }
                 Token::Description => {
                     description = Some(self.parse_description_statement()?);
                 }
+                Token::IfFeature => {
+                    if_features.push(self.parse_if_feature()?);
+                }
+                Token::When => {
+                    when = Some(self.parse_when()?);
+                }
+                Token::Must => {
+                    must.push(self.parse_must()?);
+                }
                 Token::Config => {
-                    self.advance();
-                    config = match self.advance() {
-                        Token::Identifier(s) if s == "true" => true,
-                        Token::Identifier(s) if s == "false" => false,
-                        token => {
-                            return Err(self
-                                .error(format!("Expected 'true' or 'false', found {:?}", token)))
-                        }
-                    };
-                    self.expect(Token::Semicolon)?;
+                    config = self.parse_config_statement(&name)?;
+                }
+                Token::MinElements => {
+                    min_elements = self.parse_min_elements()?;
+                }
+                Token::MaxElements => {
+                    max_elements = self.parse_max_elements()?;
+                }
+                _ if self.at_unknown_statement() => {
+                    unknown_statements.push(self.parse_unknown_statement()?);
                 }
                 _ => {
                     self.skip_statement()?;
@@ -1888,6 +3524,7 @@ impl ModuleParser {
         }
 
         self.expect(Token::RightBrace)?;
+        self.check_element_bounds(&name, min_elements, max_elements)?;
 
         let type_spec = type_spec.ok_or_else(|| {
             self.error("Missing required 'type' statement in leaf-list".to_string())
@@ -1895,88 +3532,517 @@ impl ModuleParser {
 
         Ok(LeafList {
             name,
-            description,
+            description: with_comment(description, comment),
+            status,
             type_spec,
             config,
+            if_features,
+            when,
+            must,
+            min_elements,
+            max_elements,
+            unknown_statements,
+        })
+    }
+
+    /// Whether the current statement is an `anydata` or `anyxml` node.
+    fn at_anydata(&self) -> bool {
+        matches!(self.peek(), Token::Identifier(id) if id == "anydata" || id == "anyxml")
+    }
+
+    /// Parse anydata or anyxml statement: (anydata | anyxml) <identifier> [{ ... }]
+    fn parse_anydata(&mut self) -> Result<AnyData, ParseError> {
+        let comment = self.leading_comment();
+        let kind = match self.advance() {
+            Token::Identifier(id) if id == "anyxml" => AnyDataKind::AnyXml,
+            _ => AnyDataKind::AnyData,
+        };
+
+        let name = self.parse_identifier_or_keyword()?;
+
+        let mut description = None;
+        let mut status = Status::Current;
+        let mut unknown_statements = Vec::new();
+        let mut if_features = Vec::new();
+        let mut when = None;
+        let mut must = Vec::new();
+        let mut mandatory = false;
+        let mut config = true;
+
+        if self.peek() == &Token::LeftBrace {
+            self.advance();
+
+            while self.peek() != &Token::RightBrace && self.peek() != &Token::Eof {
+                match self.peek() {
+                    Token::Status => {
+                        status = self.parse_status_statement()?;
+                    }
+                    Token::Description => {
+                        description = Some(self.parse_description_statement()?);
+                    }
+                    Token::IfFeature => {
+                        if_features.push(self.parse_if_feature()?);
+                    }
+                    Token::When => {
+                        when = Some(self.parse_when()?);
+                    }
+                    Token::Must => {
+                        must.push(self.parse_must()?);
+                    }
+                    Token::Mandatory => {
+                        mandatory = self.parse_boolean_statement(Token::Mandatory)?;
+                    }
+                    Token::Config => {
+                        config = self.parse_config_statement(&name)?;
+                    }
+                    _ if self.at_unknown_statement() => {
+                        unknown_statements.push(self.parse_unknown_statement()?);
+                    }
+                    _ => {
+                        self.skip_statement()?;
+                    }
+                }
+            }
+
+            self.expect(Token::RightBrace)?;
+        } else {
+            self.expect(Token::Semicolon)?;
+        }
+
+        Ok(AnyData {
+            name,
+            description: with_comment(description, comment),
+            status,
+            kind,
+            mandatory,
+            config,
+            if_features,
+            when,
+            must,
+            unknown_statements,
         })
     }
 
     /// Parse choice statement: choice <identifier> { <case-statements> }
     fn parse_choice(&mut self) -> Result<Choice, ParseError> {
+        let comment = self.leading_comment();
         self.expect(Token::Choice)?;
 
-        let name = self.parse_identifier_or_keyword()?;
+        let name = self.parse_identifier_or_keyword()?;
+
+        self.expect(Token::LeftBrace)?;
+
+        let mut description = None;
+        let mut status = Status::Current;
+        let mut unknown_statements = Vec::new();
+        let mut if_features = Vec::new();
+        let mut when = None;
+        let mut mandatory = false;
+        let mut cases = Vec::new();
+
+        while self.peek() != &Token::RightBrace && self.peek() != &Token::Eof {
+            match self.peek() {
+                Token::Status => {
+                    status = self.parse_status_statement()?;
+                }
+                Token::Description => {
+                    description = Some(self.parse_description_statement()?);
+                }
+                Token::IfFeature => {
+                    if_features.push(self.parse_if_feature()?);
+                }
+                Token::When => {
+                    when = Some(self.parse_when()?);
+                }
+                Token::Mandatory => {
+                    self.advance();
+                    mandatory = match self.advance() {
+                        Token::Identifier(s) if s == "true" => true,
+                        Token::Identifier(s) if s == "false" => false,
+                        token => {
+                            return Err(self
+                                .error(format!("Expected 'true' or 'false', found {:?}", token)))
+                        }
+                    };
+                    self.expect(Token::Semicolon)?;
+                }
+                Token::Case => {
+                    cases.push(self.parse_case()?);
+                }
+                // Shorthand: data nodes directly in choice are implicitly wrapped in a case
+                token
+                    if matches!(
+                        token,
+                        Token::Container | Token::List | Token::Leaf | Token::LeafList
+                    ) || self.at_anydata() =>
+                {
+                    let data_node = match self.peek() {
+                        Token::Container => DataNode::Container(self.parse_container()?),
+                        Token::List => DataNode::List(self.parse_list()?),
+                        Token::Leaf => DataNode::Leaf(self.parse_leaf()?),
+                        Token::LeafList => DataNode::LeafList(self.parse_leaf_list()?),
+                        _ => DataNode::AnyData(self.parse_anydata()?),
+                    };
+                    // Create an implicit case with the same name as the data node
+                    let case_name = match &data_node {
+                        DataNode::Container(c) => c.name.clone(),
+                        DataNode::List(l) => l.name.clone(),
+                        DataNode::Leaf(l) => l.name.clone(),
+                        DataNode::LeafList(l) => l.name.clone(),
+                        DataNode::AnyData(a) => a.name.clone(),
+                        _ => unreachable!(),
+                    };
+                    cases.push(Case {
+                        name: case_name,
+                        description: None,
+                        data_nodes: vec![data_node],
+                        if_features: Vec::new(),
+                        when: None,
+                        unknown_statements: Vec::new(),
+                        status: Status::Current,
+                    });
+                }
+                _ if self.at_unknown_statement() => {
+                    unknown_statements.push(self.parse_unknown_statement()?);
+                }
+                _ => {
+                    self.skip_statement()?;
+                }
+            }
+        }
+
+        self.expect(Token::RightBrace)?;
+
+        Ok(Choice {
+            name,
+            description: with_comment(description, comment),
+            status,
+            mandatory,
+            cases,
+            if_features,
+            when,
+            unknown_statements,
+        })
+    }
+
+    /// Parse case statement: case <identifier> { <data-definition-statements> }
+    fn parse_case(&mut self) -> Result<Case, ParseError> {
+        let comment = self.leading_comment();
+        self.expect(Token::Case)?;
+
+        let name = self.parse_identifier_or_keyword()?;
+
+        self.expect(Token::LeftBrace)?;
+
+        let mut description = None;
+        let mut status = Status::Current;
+        let mut unknown_statements = Vec::new();
+        let mut if_features = Vec::new();
+        let mut when = None;
+        let mut data_nodes = Vec::new();
+
+        while self.peek() != &Token::RightBrace && self.peek() != &Token::Eof {
+            match self.peek() {
+                Token::Status => {
+                    status = self.parse_status_statement()?;
+                }
+                Token::Description => {
+                    description = Some(self.parse_description_statement()?);
+                }
+                Token::IfFeature => {
+                    if_features.push(self.parse_if_feature()?);
+                }
+                Token::When => {
+                    when = Some(self.parse_when()?);
+                }
+                Token::Container => {
+                    data_nodes.push(DataNode::Container(self.parse_container()?));
+                }
+                Token::List => {
+                    data_nodes.push(DataNode::List(self.parse_list()?));
+                }
+                Token::Leaf => {
+                    data_nodes.push(DataNode::Leaf(self.parse_leaf()?));
+                }
+                Token::LeafList => {
+                    data_nodes.push(DataNode::LeafList(self.parse_leaf_list()?));
+                }
+                _ if self.at_anydata() => {
+                    data_nodes.push(DataNode::AnyData(self.parse_anydata()?));
+                }
+                Token::Choice => {
+                    data_nodes.push(DataNode::Choice(self.parse_choice()?));
+                }
+                Token::Uses => {
+                    data_nodes.push(DataNode::Uses(self.parse_uses()?));
+                }
+                _ if self.at_unknown_statement() => {
+                    unknown_statements.push(self.parse_unknown_statement()?);
+                }
+                _ => {
+                    self.skip_statement()?;
+                }
+            }
+        }
+
+        self.expect(Token::RightBrace)?;
+
+        Ok(Case {
+            name,
+            description: with_comment(description, comment),
+            status,
+            data_nodes,
+            if_features,
+            when,
+            unknown_statements,
+        })
+    }
+
+    /// Parse uses statement: uses <identifier> [{ <statements> }]
+    fn parse_uses(&mut self) -> Result<Uses, ParseError> {
+        self.expect(Token::Uses)?;
+
+        let name = match self.advance() {
+            Token::Identifier(id) => id,
+            token => return Err(self.error(format!("Expected grouping name, found {:?}", token))),
+        };
+        let name = self.parse_prefixed_name(name)?;
+
+        let mut description = None;
+        let mut if_features = Vec::new();
+        let mut augments = Vec::new();
+
+        // Check for optional body
+        if self.peek() == &Token::LeftBrace {
+            self.advance();
+
+            while self.peek() != &Token::RightBrace && self.peek() != &Token::Eof {
+                match self.peek() {
+                    Token::Description => {
+                        self.advance();
+                        description = Some(match self.advance() {
+                            Token::StringLiteral(s) => s,
+                            token => {
+                                return Err(self.error(format!(
+                                    "Expected description string, found {:?}",
+                                    token
+                                )))
+                            }
+                        });
+                        self.expect(Token::Semicolon)?;
+                    }
+                    Token::IfFeature => {
+                        if_features.push(self.parse_if_feature()?);
+                    }
+                    Token::Augment => {
+                        augments
+                            .push(self.parse_augment_of(parse_descendant_schema_node_identifier)?);
+                    }
+                    _ => {
+                        self.skip_statement()?;
+                    }
+                }
+            }
+
+            self.expect(Token::RightBrace)?;
+        } else {
+            self.expect(Token::Semicolon)?;
+        }
+
+        Ok(Uses {
+            name,
+            description,
+            if_features,
+            augments,
+        })
+    }
+
+    /// Parse feature statement: feature <identifier> [{ <statements> }]
+    fn parse_feature(&mut self) -> Result<Feature, ParseError> {
+        self.expect(Token::Feature)?;
+
+        let name = self.parse_identifier_or_keyword()?;
+
+        let mut description = None;
+        let mut if_features = Vec::new();
+
+        if self.peek() == &Token::LeftBrace {
+            self.advance();
+
+            while self.peek() != &Token::RightBrace && self.peek() != &Token::Eof {
+                match self.peek() {
+                    Token::Description => {
+                        description = Some(self.parse_description_statement()?);
+                    }
+                    Token::IfFeature => {
+                        if_features.push(self.parse_if_feature()?);
+                    }
+                    _ => {
+                        // Skip status and reference statements
+                        self.skip_statement()?;
+                    }
+                }
+            }
+
+            self.expect(Token::RightBrace)?;
+        } else {
+            self.expect(Token::Semicolon)?;
+        }
+
+        Ok(Feature {
+            name,
+            description,
+            if_features,
+        })
+    }
+
+    /// Parse extension statement: extension <identifier> [{ argument <identifier>; ... }]
+    fn parse_extension(&mut self) -> Result<Extension, ParseError> {
+        self.expect(Token::Extension)?;
+
+        let name = self.parse_identifier_or_keyword()?;
+
+        let mut argument = None;
+        let mut description = None;
+
+        if self.peek() == &Token::LeftBrace {
+            self.advance();
+
+            while self.peek() != &Token::RightBrace && self.peek() != &Token::Eof {
+                match self.peek() {
+                    Token::Description => {
+                        description = Some(self.parse_description_statement()?);
+                    }
+                    Token::Argument => {
+                        self.advance();
+                        argument = Some(match self.peek() {
+                            Token::StringLiteral(_) => self.parse_concatenated_string()?,
+                            _ => self.parse_identifier_or_keyword()?,
+                        });
+                        // `yin-element` does not matter for the JSON encoding
+                        if self.peek() == &Token::LeftBrace {
+                            self.advance();
+                            self.skip_block()?;
+                        } else {
+                            self.expect(Token::Semicolon)?;
+                        }
+                    }
+                    _ => {
+                        // Skip status and reference statements
+                        self.skip_statement()?;
+                    }
+                }
+            }
+
+            self.expect(Token::RightBrace)?;
+        } else {
+            self.expect(Token::Semicolon)?;
+        }
+
+        Ok(Extension {
+            name,
+            argument,
+            description,
+        })
+    }
+
+    /// Parse if-feature statement: if-feature <if-feature-expr> ;
+    fn parse_if_feature(&mut self) -> Result<IfFeature, ParseError> {
+        self.expect(Token::IfFeature)?;
+
+        let expression = match self.peek() {
+            Token::StringLiteral(_) => self.parse_concatenated_string()?,
+            _ => {
+                let first = self.parse_identifier_or_keyword()?;
+                self.parse_prefixed_name(first)?
+            }
+        };
+        self.expect(Token::Semicolon)?;
+
+        feature::parse_if_feature_expression(&expression).map_err(|message| self.error(message))
+    }
+
+    /// Parse when statement: when "<xpath>" [{ <statements> }]
+    fn parse_when(&mut self) -> Result<String, ParseError> {
+        self.expect(Token::When)?;
+
+        let condition = match self.peek() {
+            Token::StringLiteral(_) => self.parse_concatenated_string()?,
+            Token::Identifier(_) => self.parse_identifier_or_keyword()?,
+            token => return Err(self.error(format!("Expected when condition, found {:?}", token))),
+        };
+
+        // Description and reference substatements are not kept
+        if self.peek() == &Token::LeftBrace {
+            self.advance();
+            self.skip_block()?;
+        } else {
+            self.expect(Token::Semicolon)?;
+        }
+
+        Ok(condition)
+    }
 
-        self.expect(Token::LeftBrace)?;
+    /// Parse a `must` statement with its `error-message` and `error-app-tag`.
+    fn parse_must(&mut self) -> Result<Must, ParseError> {
+        self.expect(Token::Must)?;
 
-        let mut description = None;
-        let mut mandatory = false;
-        let mut cases = Vec::new();
+        let condition = match self.peek() {
+            Token::StringLiteral(_) => self.parse_concatenated_string()?,
+            Token::Identifier(_) => self.parse_identifier_or_keyword()?,
+            token => return Err(self.error(format!("Expected must condition, found {:?}", token))),
+        };
+        let mut must = Must {
+            condition,
+            error_message: None,
+            error_app_tag: None,
+        };
 
+        if self.peek() != &Token::LeftBrace {
+            self.expect(Token::Semicolon)?;
+            return Ok(must);
+        }
+        self.advance();
         while self.peek() != &Token::RightBrace && self.peek() != &Token::Eof {
             match self.peek() {
-                Token::Description => {
-                    description = Some(self.parse_description_statement()?);
-                }
-                Token::Mandatory => {
+                Token::Identifier(keyword) if keyword == "error-message" => {
                     self.advance();
-                    mandatory = match self.advance() {
-                        Token::Identifier(s) if s == "true" => true,
-                        Token::Identifier(s) if s == "false" => false,
-                        token => {
-                            return Err(self
-                                .error(format!("Expected 'true' or 'false', found {:?}", token)))
-                        }
-                    };
+                    must.error_message = Some(self.parse_concatenated_string()?);
                     self.expect(Token::Semicolon)?;
                 }
-                Token::Case => {
-                    cases.push(self.parse_case()?);
-                }
-                // Shorthand: data nodes directly in choice are implicitly wrapped in a case
-                Token::Container | Token::List | Token::Leaf | Token::LeafList => {
-                    let data_node = match self.peek() {
-                        Token::Container => DataNode::Container(self.parse_container()?),
-                        Token::List => DataNode::List(self.parse_list()?),
-                        Token::Leaf => DataNode::Leaf(self.parse_leaf()?),
-                        Token::LeafList => DataNode::LeafList(self.parse_leaf_list()?),
-                        _ => unreachable!(),
-                    };
-                    // Create an implicit case with the same name as the data node
-                    let case_name = match &data_node {
-                        DataNode::Container(c) => c.name.clone(),
-                        DataNode::List(l) => l.name.clone(),
-                        DataNode::Leaf(l) => l.name.clone(),
-                        DataNode::LeafList(l) => l.name.clone(),
-                        _ => unreachable!(),
-                    };
-                    cases.push(Case {
-                        name: case_name,
-                        description: None,
-                        data_nodes: vec![data_node],
-                    });
-                }
-                _ => {
-                    self.skip_statement()?;
+                Token::Identifier(keyword) if keyword == "error-app-tag" => {
+                    self.advance();
+                    must.error_app_tag = Some(self.parse_concatenated_string()?);
+                    self.expect(Token::Semicolon)?;
                 }
+                _ => self.skip_statement()?,
             }
         }
-
         self.expect(Token::RightBrace)?;
 
-        Ok(Choice {
-            name,
-            description,
-            mandatory,
-            cases,
-        })
+        Ok(must)
     }
 
-    /// Parse case statement: case <identifier> { <data-definition-statements> }
-    fn parse_case(&mut self) -> Result<Case, ParseError> {
-        self.expect(Token::Case)?;
+    /// Parse augment statement: augment "<absolute-schema-nodeid>" { <statements> }
+    fn parse_augment(&mut self) -> Result<Augment, ParseError> {
+        self.parse_augment_of(parse_schema_node_identifier)
+    }
 
-        let name = self.parse_identifier_or_keyword()?;
+    /// Parse an augment statement whose target is split into steps by
+    /// `parse_target`.
+    fn parse_augment_of(
+        &mut self,
+        parse_target: fn(&str) -> Result<Vec<String>, String>,
+    ) -> Result<Augment, ParseError> {
+        self.expect(Token::Augment)?;
+
+        let target = match self.peek() {
+            Token::StringLiteral(_) => self.parse_concatenated_string()?,
+            token => {
+                return Err(self.error(format!("Expected augment target path, found {:?}", token)))
+            }
+        };
+        let target = parse_target(&target).map_err(|message| self.error(message))?;
 
         self.expect(Token::LeftBrace)?;
 
@@ -2000,13 +4066,20 @@ impl ModuleParser {
                 Token::LeafList => {
                     data_nodes.push(DataNode::LeafList(self.parse_leaf_list()?));
                 }
+                _ if self.at_anydata() => {
+                    data_nodes.push(DataNode::AnyData(self.parse_anydata()?));
+                }
                 Token::Choice => {
                     data_nodes.push(DataNode::Choice(self.parse_choice()?));
                 }
+                Token::Case => {
+                    data_nodes.push(DataNode::Case(self.parse_case()?));
+                }
                 Token::Uses => {
                     data_nodes.push(DataNode::Uses(self.parse_uses()?));
                 }
                 _ => {
+                    // Skip when, if-feature, status, reference, etc.
                     self.skip_statement()?;
                 }
             }
@@ -2014,68 +4087,284 @@ impl ModuleParser {
 
         self.expect(Token::RightBrace)?;
 
-        Ok(Case {
-            name,
+        Ok(Augment {
+            target,
             description,
             data_nodes,
         })
     }
 
-    /// Parse uses statement: uses <identifier> [{ <statements> }]
-    fn parse_uses(&mut self) -> Result<Uses, ParseError> {
-        self.expect(Token::Uses)?;
+    /// Parse deviation statement: deviation "<absolute-schema-nodeid>" { <deviate statements> }
+    fn parse_deviation(&mut self) -> Result<Deviation, ParseError> {
+        self.expect(Token::Deviation)?;
 
-        let name = match self.advance() {
-            Token::Identifier(id) => id,
-            token => return Err(self.error(format!("Expected grouping name, found {:?}", token))),
+        let target = match self.peek() {
+            Token::StringLiteral(_) => self.parse_concatenated_string()?,
+            token => {
+                return Err(self.error(format!("Expected deviation target path, found {:?}", token)))
+            }
         };
+        let target =
+            parse_schema_node_identifier(&target).map_err(|message| self.error(message))?;
+
+        self.expect(Token::LeftBrace)?;
 
         let mut description = None;
+        let mut deviates = Vec::new();
 
-        // Check for optional body
-        if self.peek() == &Token::LeftBrace {
-            self.advance();
+        while self.peek() != &Token::RightBrace && self.peek() != &Token::Eof {
+            match self.peek() {
+                Token::Description => {
+                    description = Some(self.parse_description_statement()?);
+                }
+                Token::Deviate => {
+                    deviates.push(self.parse_deviate()?);
+                }
+                _ => {
+                    // Skip reference and extension statements
+                    self.skip_statement()?;
+                }
+            }
+        }
+
+        self.expect(Token::RightBrace)?;
+
+        if deviates.is_empty() {
+            return Err(self.error(format!(
+                "Deviation '/{}' has no deviate statement",
+                target.join("/")
+            )));
+        }
+
+        Ok(Deviation {
+            target,
+            description,
+            deviates,
+        })
+    }
+
+    /// Parse deviate statement: deviate (not-supported | add | replace | delete) [{ <properties> }]
+    fn parse_deviate(&mut self) -> Result<Deviate, ParseError> {
+        self.expect(Token::Deviate)?;
+
+        let kind = match self.advance() {
+            Token::Identifier(kind) | Token::StringLiteral(kind) => kind,
+            token => {
+                return Err(self.error(format!("Expected deviate argument, found {:?}", token)))
+            }
+        };
 
+        let mut properties = DeviateProperties::default();
+        if self.peek() == &Token::Semicolon {
+            self.advance();
+        } else {
+            self.expect(Token::LeftBrace)?;
             while self.peek() != &Token::RightBrace && self.peek() != &Token::Eof {
                 match self.peek() {
-                    Token::Description => {
-                        self.advance();
-                        description = Some(match self.advance() {
-                            Token::StringLiteral(s) => s,
-                            token => {
-                                return Err(self.error(format!(
-                                    "Expected description string, found {:?}",
-                                    token
-                                )))
-                            }
-                        });
-                        self.expect(Token::Semicolon)?;
+                    Token::Type => {
+                        properties.type_spec = Some(self.parse_type_spec()?);
+                    }
+                    Token::Units => {
+                        properties.units = Some(self.parse_deviate_value(Token::Units)?);
+                    }
+                    Token::Default => {
+                        properties.default = Some(self.parse_deviate_value(Token::Default)?);
+                    }
+                    Token::Config => {
+                        properties.config = Some(self.parse_boolean_statement(Token::Config)?);
+                    }
+                    Token::Mandatory => {
+                        properties.mandatory =
+                            Some(self.parse_boolean_statement(Token::Mandatory)?);
                     }
                     _ => {
+                        // Skip must, unique, min-elements and max-elements
                         self.skip_statement()?;
                     }
                 }
             }
-
             self.expect(Token::RightBrace)?;
-        } else {
-            self.expect(Token::Semicolon)?;
         }
 
-        Ok(Uses { name, description })
+        let deviate = match kind.as_str() {
+            "not-supported" if properties == DeviateProperties::default() => Deviate::NotSupported,
+            "not-supported" => {
+                return Err(self.error("deviate not-supported takes no properties".to_string()))
+            }
+            "add" if properties.type_spec.is_none() => Deviate::Add(properties),
+            "replace" => Deviate::Replace(properties),
+            "delete"
+                if properties.type_spec.is_none()
+                    && properties.config.is_none()
+                    && properties.mandatory.is_none() =>
+            {
+                Deviate::Delete(properties)
+            }
+            "add" | "delete" => {
+                return Err(self.error(format!(
+                    "deviate {} cannot change type, config or mandatory; use deviate replace",
+                    kind
+                )))
+            }
+            kind => {
+                return Err(self.error(format!(
+                    "Expected not-supported, add, replace or delete, found '{}'",
+                    kind
+                )))
+            }
+        };
+        Ok(deviate)
+    }
+
+    /// Parse the value of a `units` or `default` statement of a deviate.
+    fn parse_deviate_value(&mut self, keyword: Token) -> Result<String, ParseError> {
+        self.expect(keyword)?;
+        let value = match self.advance() {
+            Token::StringLiteral(s) | Token::Identifier(s) => s,
+            Token::Number(n) => n.to_string(),
+            token => return Err(self.error(format!("Expected value, found {:?}", token))),
+        };
+        self.expect(Token::Semicolon)?;
+        Ok(value)
+    }
+
+    /// Parse a status statement: status current|deprecated|obsolete;
+    fn parse_status_statement(&mut self) -> Result<Status, ParseError> {
+        self.expect(Token::Status)?;
+        let status = match self.advance() {
+            Token::Identifier(s) if s == "current" => Status::Current,
+            Token::Identifier(s) if s == "deprecated" => Status::Deprecated,
+            Token::Identifier(s) if s == "obsolete" => Status::Obsolete,
+            token => {
+                return Err(self.error(format!(
+                    "Expected 'current', 'deprecated' or 'obsolete', found {:?}",
+                    token
+                )))
+            }
+        };
+        self.expect(Token::Semicolon)?;
+        Ok(status)
+    }
+
+    /// Parse the config statement of the node `name`, recording an explicit
+    /// `config true` for [`reject_config_true_below`](Self::reject_config_true_below).
+    fn parse_config_statement(&mut self, name: &str) -> Result<bool, ParseError> {
+        let config = self.parse_boolean_statement(Token::Config)?;
+        if config {
+            self.config_true.push(name.to_string());
+        }
+        Ok(config)
+    }
+
+    /// Fail if a node parsed since `since` entries of `config_true` were
+    /// recorded, a descendant of the `config false` node `name`, has an
+    /// explicit `config true` (RFC 7950, section 7.21.1).
+    fn reject_config_true_below(&self, name: &str, since: usize) -> Result<(), ParseError> {
+        match self.config_true.get(since) {
+            Some(descendant) => Err(self.error(format!(
+                "'{}' cannot be config true, as its ancestor '{}' is config false",
+                descendant, name
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Parse a statement with a boolean argument, such as `config false;`.
+    fn parse_boolean_statement(&mut self, keyword: Token) -> Result<bool, ParseError> {
+        self.expect(keyword)?;
+        let value = match self.advance() {
+            Token::Identifier(s) if s == "true" => true,
+            Token::Identifier(s) if s == "false" => false,
+            token => {
+                return Err(self.error(format!("Expected 'true' or 'false', found {:?}", token)))
+            }
+        };
+        self.expect(Token::Semicolon)?;
+        Ok(value)
+    }
+
+    /// Parse min-elements statement: min-elements <non-negative-integer>;
+    fn parse_min_elements(&mut self) -> Result<u32, ParseError> {
+        self.expect(Token::MinElements)?;
+        let value = match self.advance() {
+            Token::Number(n) => u32::try_from(n).ok(),
+            Token::StringLiteral(s) => s.trim().parse().ok(),
+            token => {
+                return Err(self.error(format!("Expected min-elements value, found {:?}", token)))
+            }
+        };
+        let value = value
+            .ok_or_else(|| self.error("min-elements must be a non-negative integer".to_string()))?;
+        self.expect(Token::Semicolon)?;
+        Ok(value)
+    }
+
+    /// Parse max-elements statement: max-elements (<positive-integer> | unbounded);
+    ///
+    /// Returns `None` for `unbounded`.
+    fn parse_max_elements(&mut self) -> Result<Option<u32>, ParseError> {
+        self.expect(Token::MaxElements)?;
+        let value = match self.advance() {
+            Token::Identifier(s) | Token::StringLiteral(s) if s.trim() == "unbounded" => {
+                self.expect(Token::Semicolon)?;
+                return Ok(None);
+            }
+            Token::Number(n) => u32::try_from(n).ok(),
+            Token::StringLiteral(s) => s.trim().parse().ok(),
+            token => {
+                return Err(self.error(format!("Expected max-elements value, found {:?}", token)))
+            }
+        };
+        let value = value.filter(|&n| n > 0).ok_or_else(|| {
+            self.error("max-elements must be a positive integer or 'unbounded'".to_string())
+        })?;
+        self.expect(Token::Semicolon)?;
+        Ok(Some(value))
+    }
+
+    /// Reject a list or leaf-list that needs more entries than it may hold.
+    fn check_element_bounds(
+        &self,
+        name: &str,
+        min_elements: u32,
+        max_elements: Option<u32>,
+    ) -> Result<(), ParseError> {
+        match max_elements {
+            Some(max) if min_elements > max => Err(self.error(format!(
+                "'{}' has min-elements {} greater than max-elements {}",
+                name, min_elements, max
+            ))),
+            _ => Ok(()),
+        }
     }
 
     /// Parse RPC statement: rpc <identifier> { [input { <data-definition-statements> }] [output { <data-definition-statements> }] }
     fn parse_rpc(&mut self) -> Result<Rpc, ParseError> {
+        let comment = self.leading_comment();
         self.expect(Token::Rpc)?;
 
         let name = self.parse_identifier_or_keyword()?;
 
+        // `rpc reboot;` has no input, output or description
+        if self.peek() == &Token::Semicolon {
+            self.advance();
+            return Ok(Rpc {
+                name,
+                description: comment,
+                input: None,
+                output: None,
+                timeout: None,
+                unknown_statements: Vec::new(),
+            });
+        }
+
         self.expect(Token::LeftBrace)?;
 
         let mut description = None;
+        let mut unknown_statements = Vec::new();
         let mut input = None;
         let mut output = None;
+        let mut timeout = None;
 
         while self.peek() != &Token::RightBrace && self.peek() != &Token::Eof {
             match self.peek() {
@@ -2102,6 +4391,9 @@ impl ModuleParser {
                             Token::LeafList => {
                                 input_nodes.push(DataNode::LeafList(self.parse_leaf_list()?));
                             }
+                            _ if self.at_anydata() => {
+                                input_nodes.push(DataNode::AnyData(self.parse_anydata()?));
+                            }
                             Token::Choice => {
                                 input_nodes.push(DataNode::Choice(self.parse_choice()?));
                             }
@@ -2137,6 +4429,9 @@ impl ModuleParser {
                             Token::LeafList => {
                                 output_nodes.push(DataNode::LeafList(self.parse_leaf_list()?));
                             }
+                            _ if self.at_anydata() => {
+                                output_nodes.push(DataNode::AnyData(self.parse_anydata()?));
+                            }
                             Token::Choice => {
                                 output_nodes.push(DataNode::Choice(self.parse_choice()?));
                             }
@@ -2152,6 +4447,12 @@ impl ModuleParser {
                     self.expect(Token::RightBrace)?;
                     output = Some(output_nodes);
                 }
+                _ if self.at_extension("timeout") => {
+                    timeout = Some(self.parse_timeout_extension()?);
+                }
+                _ if self.at_unknown_statement() => {
+                    unknown_statements.push(self.parse_unknown_statement()?);
+                }
                 _ => {
                     self.skip_statement()?;
                 }
@@ -2162,21 +4463,36 @@ impl ModuleParser {
 
         Ok(Rpc {
             name,
-            description,
+            description: with_comment(description, comment),
             input,
             output,
+            timeout,
+            unknown_statements,
         })
     }
 
     /// Parse notification statement: notification <identifier> { <data-definition-statements> }
     fn parse_notification(&mut self) -> Result<Notification, ParseError> {
+        let comment = self.leading_comment();
         self.expect(Token::Notification)?;
 
         let name = self.parse_identifier_or_keyword()?;
 
+        // `notification restarted;` carries no data
+        if self.peek() == &Token::Semicolon {
+            self.advance();
+            return Ok(Notification {
+                name,
+                description: comment,
+                data_nodes: Vec::new(),
+                unknown_statements: Vec::new(),
+            });
+        }
+
         self.expect(Token::LeftBrace)?;
 
         let mut description = None;
+        let mut unknown_statements = Vec::new();
         let mut data_nodes = Vec::new();
 
         while self.peek() != &Token::RightBrace && self.peek() != &Token::Eof {
@@ -2196,12 +4512,18 @@ impl ModuleParser {
                 Token::LeafList => {
                     data_nodes.push(DataNode::LeafList(self.parse_leaf_list()?));
                 }
+                _ if self.at_anydata() => {
+                    data_nodes.push(DataNode::AnyData(self.parse_anydata()?));
+                }
                 Token::Choice => {
                     data_nodes.push(DataNode::Choice(self.parse_choice()?));
                 }
                 Token::Uses => {
                     data_nodes.push(DataNode::Uses(self.parse_uses()?));
                 }
+                _ if self.at_unknown_statement() => {
+                    unknown_statements.push(self.parse_unknown_statement()?);
+                }
                 _ => {
                     self.skip_statement()?;
                 }
@@ -2212,8 +4534,9 @@ impl ModuleParser {
 
         Ok(Notification {
             name,
-            description,
+            description: with_comment(description, comment),
             data_nodes,
+            unknown_statements,
         })
     }
 
@@ -2246,6 +4569,117 @@ impl ModuleParser {
         }
     }
 
+    /// Whether the current statement is a prefixed extension statement.
+    fn at_unknown_statement(&self) -> bool {
+        matches!(
+            (self.peek(), self.peek_ahead(1), self.peek_ahead(2)),
+            (Token::Identifier(_), Token::Colon, name)
+                if matches!(name, Token::Identifier(_)) || name.is_keyword()
+        )
+    }
+
+    /// Parse an extension statement, or a standard statement inside one:
+    /// [<prefix>:]<keyword> [<argument>] (; | { <statements> })
+    fn parse_unknown_statement(&mut self) -> Result<UnknownStatement, ParseError> {
+        let (prefix, name) = if self.at_unknown_statement() {
+            let prefix = match self.advance() {
+                Token::Identifier(prefix) => prefix,
+                _ => unreachable!(),
+            };
+            self.advance();
+            (prefix, self.parse_identifier_or_keyword()?)
+        } else {
+            (String::new(), self.parse_identifier_or_keyword()?)
+        };
+        let module = if prefix.is_empty() {
+            None
+        } else {
+            match &self.local_prefix {
+                Some((local, module)) if *local == prefix => Some(module.clone()),
+                _ => self.import_prefixes.get(&prefix).cloned(),
+            }
+        };
+
+        // The argument is a single string, possibly split into tokens
+        let mut argument = String::new();
+        let mut has_argument = false;
+        let mut previous_number = false;
+        loop {
+            let text = match self.peek() {
+                Token::Semicolon | Token::LeftBrace | Token::RightBrace | Token::Eof => break,
+                // Concatenation of quoted strings
+                Token::Plus => String::new(),
+                Token::StringLiteral(text) | Token::Identifier(text) => text.clone(),
+                // An unquoted date is lexed as three numbers (`2024`, `-01`, `-15`)
+                Token::Number(number) if previous_number && *number < 0 => {
+                    format!("-{:02}", -number)
+                }
+                Token::Number(number) => number.to_string(),
+                Token::Colon => ":".to_string(),
+                Token::Slash => "/".to_string(),
+                Token::Dot => ".".to_string(),
+                Token::DoubleDot => "..".to_string(),
+                Token::Pipe => "|".to_string(),
+                token => token.keyword().unwrap_or_default().to_string(),
+            };
+            previous_number = matches!(self.peek(), Token::Number(_));
+            argument.push_str(&text);
+            has_argument = true;
+            self.advance();
+        }
+
+        let mut substatements = Vec::new();
+        if self.peek() == &Token::LeftBrace {
+            self.advance();
+            while self.peek() != &Token::RightBrace && self.peek() != &Token::Eof {
+                substatements.push(self.parse_unknown_statement()?);
+            }
+            self.expect(Token::RightBrace)?;
+        } else {
+            self.expect(Token::Semicolon)?;
+        }
+
+        Ok(UnknownStatement {
+            prefix,
+            name,
+            module,
+            argument: has_argument.then_some(argument),
+            substatements,
+        })
+    }
+
+    /// Whether the current statement is the `rustconf-extensions` extension
+    /// `keyword`, written as `<prefix>:<keyword>`.
+    fn at_extension(&self, keyword: &str) -> bool {
+        match (self.peek(), self.peek_ahead(1), self.peek_ahead(2)) {
+            (Token::Identifier(prefix), Token::Colon, Token::Identifier(name)) => {
+                self.import_prefixes.get(prefix).map(String::as_str) == Some(EXTENSIONS_MODULE)
+                    && name == keyword
+            }
+            _ => false,
+        }
+    }
+
+    /// Parse the timeout extension: <prefix>:timeout <seconds>;
+    fn parse_timeout_extension(&mut self) -> Result<u64, ParseError> {
+        // Prefix, colon and keyword
+        self.advance();
+        self.advance();
+        self.advance();
+
+        let seconds = match self.advance() {
+            Token::StringLiteral(value) | Token::Identifier(value) => value.trim().parse().ok(),
+            Token::Number(value) => u64::try_from(value).ok(),
+            _ => None,
+        }
+        .filter(|&seconds| seconds > 0)
+        .ok_or_else(|| {
+            self.error("Expected a positive number of seconds in timeout extension".to_string())
+        })?;
+        self.expect(Token::Semicolon)?;
+        Ok(seconds)
+    }
+
     /// Skip a block (everything between { and }).
     fn skip_block(&mut self) -> Result<(), ParseError> {
         let mut depth = 1;
@@ -2258,17 +4692,6 @@ impl ModuleParser {
         }
         Ok(())
     }
-
-    /// Skip until semicolon.
-    fn skip_until_semicolon(&mut self) -> Result<(), ParseError> {
-        while self.peek() != &Token::Semicolon && self.peek() != &Token::Eof {
-            self.advance();
-        }
-        if self.peek() == &Token::Semicolon {
-            self.advance();
-        }
-        Ok(())
-    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,616 @@
+//! Importing OpenAPI and JSON Schema definitions as a YANG module.
+//!
+//! Some vendor endpoints are only documented in OpenAPI. [`OpenApiImporter`]
+//! converts the component schemas of an OpenAPI document (or the definitions
+//! of a JSON Schema) into the AST of a YANG module, so the code generator
+//! produces their types the same way as for YANG models:
+//!
+//! - an object schema becomes a top-level container, and a scalar schema a
+//!   typedef;
+//! - properties become leaves, nested objects containers, arrays of objects
+//!   keyless lists and arrays of scalars leaf-lists, keeping the property
+//!   names as they are on the wire;
+//! - `required` properties are mandatory and `readOnly` ones `config false`;
+//! - `minimum`/`maximum`, `minLength`/`maxLength`, `pattern` and `enum`
+//!   become range, length, pattern and enumeration restrictions, and
+//!   `minItems`/`maxItems` the `min-elements`/`max-elements` of lists and
+//!   leaf-lists;
+//! - `$ref`s to object schemas are expanded in place, `allOf` members are
+//!   merged, and `oneOf`/`anyOf` of scalars become unions.
+//!
+//! Documents must be JSON. Properties that have no YANG counterpart yet,
+//! such as `number`, maps (`additionalProperties`), `oneOf` of objects and
+//! recursive references, are left out and reported as warnings.
+
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+use crate::parser::{
+    Container, DataNode, EnumValue, Leaf, LeafList, LengthConstraint, LengthRange, List,
+    ParseError, PatternConstraint, Range, RangeConstraint, Status, TypeDef, TypeSpec, YangModule,
+};
+
+/// Converts OpenAPI component schemas into a YANG module AST.
+///
+/// # Examples
+///
+/// ```
+/// use rustconf::parser::openapi::OpenApiImporter;
+/// use rustconf::parser::DataNode;
+///
+/// let document = r#"{
+///     "openapi": "3.0.3",
+///     "components": {
+///         "schemas": {
+///             "FirmwareStatus": {
+///                 "type": "object",
+///                 "required": ["version"],
+///                 "properties": {
+///                     "version": {"type": "string"},
+///                     "progressPct": {"type": "integer", "minimum": 0, "maximum": 100}
+///                 }
+///             }
+///         }
+///     }
+/// }"#;
+///
+/// let import = OpenApiImporter::new("vendor-api").import_str(document)?;
+/// let DataNode::Container(status) = &import.module.data_nodes[0] else {
+///     panic!("expected a container");
+/// };
+/// assert_eq!(status.name, "FirmwareStatus");
+/// assert_eq!(status.children.len(), 2);
+/// assert!(import.warnings.is_empty());
+/// # Ok::<(), rustconf::ParseError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct OpenApiImporter {
+    module_name: String,
+    namespace: Option<String>,
+    prefix: Option<String>,
+}
+
+/// The result of importing an OpenAPI document.
+#[derive(Debug, Clone)]
+pub struct OpenApiImport {
+    /// The module holding the converted schemas.
+    pub module: YangModule,
+    /// Schemas and properties that were left out, with the reason.
+    pub warnings: Vec<String>,
+}
+
+impl OpenApiImporter {
+    /// An importer producing a module named `module_name`.
+    pub fn new(module_name: impl Into<String>) -> Self {
+        Self {
+            module_name: module_name.into(),
+            namespace: None,
+            prefix: None,
+        }
+    }
+
+    /// Set the namespace of the module (default `urn:openapi:<module name>`).
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Set the prefix of the module (default the module name).
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Import an OpenAPI document from a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not an OpenAPI or
+    /// JSON Schema document.
+    pub fn import_file(&self, path: impl AsRef<Path>) -> Result<OpenApiImport, ParseError> {
+        let json = std::fs::read_to_string(path)?;
+        self.import_str(&json)
+    }
+
+    /// Import an OpenAPI document from JSON.
+    ///
+    /// The schemas are read from `components.schemas` (OpenAPI 3),
+    /// `definitions` (Swagger 2 and JSON Schema drafts up to 7) or `$defs`
+    /// (JSON Schema 2019-09 and later).
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::SemanticError` if `json` is not JSON or has none
+    /// of those sections.
+    pub fn import_str(&self, json: &str) -> Result<OpenApiImport, ParseError> {
+        let document: Value = serde_json::from_str(json).map_err(|e| semantic(e.to_string()))?;
+        let schemas = document
+            .pointer("/components/schemas")
+            .or_else(|| document.get("definitions"))
+            .or_else(|| document.get("$defs"))
+            .and_then(Value::as_object)
+            .ok_or_else(|| {
+                semantic(
+                    "no schemas found; expected components.schemas, definitions or $defs"
+                        .to_string(),
+                )
+            })?;
+
+        let mut converter = Converter {
+            schemas,
+            warnings: Vec::new(),
+            stack: Vec::new(),
+        };
+        let mut data_nodes = Vec::new();
+        let mut typedefs = Vec::new();
+        for (name, schema) in schemas {
+            let path = format!("/{}", name);
+            converter.stack.push(name.clone());
+            if converter.is_object(schema) {
+                data_nodes.push(DataNode::Container(Container {
+                    name: name.clone(),
+                    description: description(schema),
+                    config: true,
+                    mandatory: false,
+                    children: converter.children(&path, schema),
+                    if_features: Vec::new(),
+                    when: None,
+                    must: Vec::new(),
+                    unknown_statements: Vec::new(),
+                    status: Status::Current,
+                }));
+            } else if let Some(type_spec) = converter.scalar_type(&path, schema) {
+                typedefs.push(TypeDef {
+                    name: name.clone(),
+                    type_spec,
+                    units: None,
+                    default: default_value(schema),
+                    description: description(schema),
+                    status: Status::Current,
+                });
+            }
+            converter.stack.pop();
+        }
+
+        let description = document
+            .pointer("/info/description")
+            .or_else(|| document.pointer("/info/title"))
+            .or_else(|| document.get("description"))
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        let module = YangModule {
+            name: self.module_name.clone(),
+            namespace: self
+                .namespace
+                .clone()
+                .unwrap_or_else(|| format!("urn:openapi:{}", self.module_name)),
+            prefix: self
+                .prefix
+                .clone()
+                .unwrap_or_else(|| self.module_name.clone()),
+            yang_version: None,
+            organization: None,
+            contact: None,
+            description,
+            imports: Vec::new(),
+            features: Vec::new(),
+            typedefs,
+            groupings: Vec::new(),
+            data_nodes,
+            augments: Vec::new(),
+            deviations: Vec::new(),
+            rpcs: Vec::new(),
+            notifications: Vec::new(),
+            extensions: Vec::new(),
+            unknown_statements: Vec::new(),
+        };
+        Ok(OpenApiImport {
+            module,
+            warnings: converter.warnings,
+        })
+    }
+}
+
+fn semantic(message: String) -> ParseError {
+    ParseError::SemanticError {
+        message: format!("invalid OpenAPI document: {}", message),
+    }
+}
+
+/// Converts the schemas of one document, tracking the references being
+/// expanded to detect recursion.
+struct Converter<'a> {
+    schemas: &'a Map<String, Value>,
+    warnings: Vec<String>,
+    stack: Vec<String>,
+}
+
+impl<'a> Converter<'a> {
+    fn warn(&mut self, path: &str, message: impl std::fmt::Display) {
+        self.warnings.push(format!("{}: {}", path, message));
+    }
+
+    /// The name and schema a `$ref` points to, if `schema` is a reference
+    /// into the document.
+    fn reference(&self, schema: &Value) -> Option<(&'a str, &'a Value)> {
+        let reference = schema.get("$ref")?.as_str()?;
+        let name = reference
+            .strip_prefix("#/components/schemas/")
+            .or_else(|| reference.strip_prefix("#/definitions/"))
+            .or_else(|| reference.strip_prefix("#/$defs/"))?;
+        self.schemas
+            .get_key_value(name)
+            .map(|(k, v)| (k.as_str(), v))
+    }
+
+    /// Whether a schema describes an object, following references.
+    fn is_object(&self, schema: &Value) -> bool {
+        if let Some((_, target)) = self.reference(schema) {
+            return self.is_object(target);
+        }
+        schema_type(schema) == Some("object")
+            || schema.get("properties").is_some()
+            || schema
+                .get("allOf")
+                .and_then(Value::as_array)
+                .is_some_and(|members| members.iter().any(|member| self.is_object(member)))
+    }
+
+    /// The data nodes of the properties of an object schema, including those
+    /// of referenced and `allOf` schemas.
+    fn children(&mut self, path: &str, schema: &Value) -> Vec<DataNode> {
+        if let Some((name, target)) = self.reference(schema) {
+            return match self.enter(path, name) {
+                true => {
+                    let children = self.children(path, target);
+                    self.stack.pop();
+                    children
+                }
+                false => Vec::new(),
+            };
+        }
+
+        let mut children = Vec::new();
+        for member in schema
+            .get("allOf")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            children.extend(self.children(path, member));
+        }
+        if schema.get("additionalProperties").is_some_and(|extra| {
+            extra.is_object() && extra.as_object().is_some_and(|extra| !extra.is_empty())
+        }) {
+            self.warn(path, "additionalProperties (maps) are not supported");
+        }
+
+        let required: Vec<&str> = schema
+            .get("required")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .collect();
+        let properties = schema.get("properties").and_then(Value::as_object);
+        for (name, property) in properties.into_iter().flatten() {
+            let mandatory = required.contains(&name.as_str());
+            if let Some(node) = self.property(path, name, property, mandatory) {
+                children.push(node);
+            }
+        }
+        children
+    }
+
+    /// Start expanding the reference `name`, unless it is already being
+    /// expanded.
+    fn enter(&mut self, path: &str, name: &str) -> bool {
+        if self.stack.iter().any(|entered| entered == name) {
+            self.warn(
+                path,
+                format!("recursive reference to {} is not supported", name),
+            );
+            return false;
+        }
+        self.stack.push(name.to_string());
+        true
+    }
+
+    /// Whether `schema` refers to a schema being expanded, warning if so.
+    fn is_recursive(&mut self, path: &str, schema: &Value) -> bool {
+        match self.reference(schema) {
+            Some((name, _)) if self.stack.iter().any(|entered| entered == name) => {
+                self.warn(
+                    path,
+                    format!("recursive reference to {} is not supported", name),
+                );
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// The data node of a property.
+    fn property(
+        &mut self,
+        parent: &str,
+        name: &str,
+        schema: &Value,
+        mandatory: bool,
+    ) -> Option<DataNode> {
+        let path = format!("{}/{}", parent, name);
+        let config = !schema
+            .get("readOnly")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        let description = description(schema).or_else(|| {
+            self.reference(schema)
+                .and_then(|(_, target)| description(target))
+        });
+
+        if self.is_object(schema) {
+            if self.is_recursive(&path, schema) {
+                return None;
+            }
+            return Some(DataNode::Container(Container {
+                name: name.to_string(),
+                description,
+                config,
+                mandatory,
+                children: self.children(&path, schema),
+                if_features: Vec::new(),
+                when: None,
+                must: Vec::new(),
+                unknown_statements: Vec::new(),
+                status: Status::Current,
+            }));
+        }
+
+        let items = self
+            .resolve(schema)
+            .filter(|schema| schema_type(schema) == Some("array"));
+        if let Some(array) = items {
+            let Some(items) = array.get("items") else {
+                self.warn(&path, "arrays without items are not supported");
+                return None;
+            };
+            let (min_elements, max_elements) = element_bounds(array);
+            if self.is_object(items) {
+                if self.is_recursive(&path, items) {
+                    return None;
+                }
+                return Some(DataNode::List(List {
+                    name: name.to_string(),
+                    description,
+                    config,
+                    keys: Vec::new(),
+                    children: self.children(&path, items),
+                    if_features: Vec::new(),
+                    when: None,
+                    must: Vec::new(),
+                    min_elements,
+                    max_elements,
+                    unknown_statements: Vec::new(),
+                    status: Status::Current,
+                }));
+            }
+            if self.resolve(items).and_then(schema_type) == Some("array") {
+                self.warn(&path, "arrays of arrays are not supported");
+                return None;
+            }
+            let type_spec = self.scalar_type(&path, items)?;
+            return Some(DataNode::LeafList(LeafList {
+                name: name.to_string(),
+                description,
+                type_spec,
+                config,
+                if_features: Vec::new(),
+                when: None,
+                must: Vec::new(),
+                min_elements,
+                max_elements,
+                unknown_statements: Vec::new(),
+                status: Status::Current,
+            }));
+        }
+
+        let type_spec = self.scalar_type(&path, schema)?;
+        Some(DataNode::Leaf(Leaf {
+            name: name.to_string(),
+            description,
+            type_spec,
+            mandatory,
+            default: default_value(schema),
+            units: None,
+            config,
+            if_features: Vec::new(),
+            when: None,
+            must: Vec::new(),
+            unknown_statements: Vec::new(),
+            status: Status::Current,
+        }))
+    }
+
+    /// A schema with references followed, or `None` for a reference that
+    /// does not resolve.
+    fn resolve(&self, schema: &'a Value) -> Option<&'a Value> {
+        match schema.get("$ref") {
+            Some(_) => self
+                .reference(schema)
+                .and_then(|(_, target)| self.resolve(target)),
+            None => Some(schema),
+        }
+    }
+
+    /// The YANG type of a scalar schema, or `None` (with a warning) if it has
+    /// none.
+    fn scalar_type(&mut self, path: &str, schema: &Value) -> Option<TypeSpec> {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            return match self.reference(schema) {
+                // Named scalar schemas are typedefs
+                Some((name, _)) => Some(TypeSpec::typedef_ref(name)),
+                None => {
+                    self.warn(path, format!("unresolved reference {}", reference));
+                    None
+                }
+            };
+        }
+
+        if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+            let names: Option<Vec<&str>> = values
+                .iter()
+                .filter(|value| !value.is_null())
+                .map(Value::as_str)
+                .collect();
+            return match names {
+                Some(names) => Some(TypeSpec::Enumeration {
+                    values: names
+                        .into_iter()
+                        .map(|name| EnumValue {
+                            name: name.to_string(),
+                            value: None,
+                            description: None,
+                        })
+                        .collect(),
+                }),
+                None => {
+                    self.warn(path, "enums of values other than strings are not supported");
+                    None
+                }
+            };
+        }
+
+        for keyword in ["oneOf", "anyOf"] {
+            let Some(members) = schema.get(keyword).and_then(Value::as_array) else {
+                continue;
+            };
+            let members: Vec<&Value> = members
+                .iter()
+                .filter(|member| schema_type(member) != Some("null"))
+                .collect();
+            if members.iter().any(|member| self.is_object(member)) {
+                self.warn(path, format!("{} of objects is not supported", keyword));
+                return None;
+            }
+            let mut types = Vec::new();
+            for member in members {
+                types.push(self.scalar_type(path, member)?);
+            }
+            return match types.len() {
+                1 => types.pop(),
+                _ => Some(TypeSpec::Union { types }),
+            };
+        }
+
+        match schema_type(schema) {
+            Some("string") => Some(TypeSpec::String {
+                length: length(schema),
+                pattern: schema
+                    .get("pattern")
+                    .and_then(Value::as_str)
+                    .map(|pattern| PatternConstraint::new(pattern.to_string())),
+            }),
+            Some("integer") => {
+                let range = range(schema);
+                Some(match schema.get("format").and_then(Value::as_str) {
+                    Some("int32") => TypeSpec::Int32 { range },
+                    _ => TypeSpec::Int64 { range },
+                })
+            }
+            Some("boolean") => Some(TypeSpec::Boolean),
+            Some("number") => {
+                self.warn(path, "number is not supported; use integer or string");
+                None
+            }
+            Some(other) => {
+                self.warn(path, format!("type {} is not supported here", other));
+                None
+            }
+            None => {
+                self.warn(path, "schema without a type is not supported");
+                None
+            }
+        }
+    }
+}
+
+/// The `type` of a schema; of `["string", "null"]` (OpenAPI 3.1 nullable
+/// types), the type other than `null`.
+fn schema_type(schema: &Value) -> Option<&str> {
+    match schema.get("type")? {
+        Value::String(name) => Some(name),
+        Value::Array(names) => names
+            .iter()
+            .filter_map(Value::as_str)
+            .find(|name| *name != "null"),
+        _ => None,
+    }
+}
+
+fn description(schema: &Value) -> Option<String> {
+    schema
+        .get("description")
+        .or_else(|| schema.get("title"))
+        .and_then(Value::as_str)
+        .map(str::to_string)
+}
+
+fn default_value(schema: &Value) -> Option<String> {
+    match schema.get("default")? {
+        Value::String(value) => Some(value.clone()),
+        Value::Number(value) => Some(value.to_string()),
+        Value::Bool(value) => Some(value.to_string()),
+        _ => None,
+    }
+}
+
+/// The range of `minimum` and `maximum`, with `exclusiveMinimum` and
+/// `exclusiveMaximum` as numbers (OpenAPI 3.1) or flags (OpenAPI 3.0).
+fn range(schema: &Value) -> Option<RangeConstraint> {
+    let bound = |inclusive: &str, exclusive: &str, step: i64| {
+        let flag = schema.get(exclusive).and_then(Value::as_bool) == Some(true);
+        match (
+            schema.get(inclusive).and_then(Value::as_i64),
+            schema.get(exclusive),
+        ) {
+            (Some(value), _) if flag => Some(value.saturating_add(step)),
+            (Some(value), _) => Some(value),
+            (None, Some(value)) => value.as_i64().map(|value| value.saturating_add(step)),
+            (None, None) => None,
+        }
+    };
+    let min = bound("minimum", "exclusiveMinimum", 1);
+    let max = bound("maximum", "exclusiveMaximum", -1);
+    if min.is_none() && max.is_none() {
+        return None;
+    }
+    Some(RangeConstraint::new(vec![Range::new(
+        min.unwrap_or(i64::MIN),
+        max.unwrap_or(i64::MAX),
+    )]))
+}
+
+/// The `minItems` and `maxItems` of an array schema, as the `min-elements`
+/// and `max-elements` of its list or leaf-list.
+fn element_bounds(schema: &Value) -> (u32, Option<u32>) {
+    let bound = |keyword| {
+        schema
+            .get(keyword)
+            .and_then(Value::as_u64)
+            .map(|n| u32::try_from(n).unwrap_or(u32::MAX))
+    };
+    (bound("minItems").unwrap_or(0), bound("maxItems"))
+}
+
+fn length(schema: &Value) -> Option<LengthConstraint> {
+    let min = schema.get("minLength").and_then(Value::as_u64);
+    let max = schema.get("maxLength").and_then(Value::as_u64);
+    if min.is_none() && max.is_none() {
+        return None;
+    }
+    Some(LengthConstraint::new(vec![LengthRange::new(
+        min.unwrap_or(0),
+        max.unwrap_or(u64::MAX),
+    )]))
+}
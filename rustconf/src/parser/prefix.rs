@@ -0,0 +1,88 @@
+//! Prefix resolution for qualified YANG references.
+//!
+//! Typedef and grouping references may be written as `prefix:name`. Which
+//! module a prefix names depends on where the reference appears: a module's
+//! own `prefix` and `import` statements, or a submodule's `belongs-to` and
+//! `import` statements. [`PrefixTable`] captures that mapping for a single
+//! (sub)module so references can be resolved relative to the file they were
+//! written in.
+
+use std::collections::HashMap;
+
+use super::ast::{YangModule, YangSubmodule};
+use super::error::ParseError;
+
+/// Mapping from the prefixes visible in a (sub)module to module names.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrefixTable {
+    module: String,
+    local_prefix: String,
+    imports: HashMap<String, String>,
+}
+
+/// The target of a resolved reference.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolvedName<'r> {
+    /// Defined in the module itself (or one of its submodules).
+    Local(&'r str),
+    /// Defined in an imported module.
+    Imported { module: String, name: &'r str },
+}
+
+impl PrefixTable {
+    /// Build the prefix table for a module.
+    pub fn for_module(module: &YangModule) -> Self {
+        Self {
+            module: module.name.clone(),
+            local_prefix: module.prefix.clone(),
+            imports: module
+                .imports
+                .iter()
+                .map(|import| (import.prefix.clone(), import.module.clone()))
+                .collect(),
+        }
+    }
+
+    /// Build the prefix table for a submodule.
+    ///
+    /// The `belongs-to` prefix refers to the parent module, so references
+    /// through it resolve as local.
+    pub fn for_submodule(submodule: &YangSubmodule) -> Self {
+        Self {
+            module: submodule.belongs_to.module.clone(),
+            local_prefix: submodule.belongs_to.prefix.clone(),
+            imports: submodule
+                .imports
+                .iter()
+                .map(|import| (import.prefix.clone(), import.module.clone()))
+                .collect(),
+        }
+    }
+
+    /// Name of the module that local references belong to.
+    pub fn module(&self) -> &str {
+        &self.module
+    }
+
+    /// Resolve a possibly prefixed reference.
+    pub fn resolve<'r>(&self, reference: &'r str) -> Result<ResolvedName<'r>, ParseError> {
+        let Some((prefix, name)) = reference.split_once(':') else {
+            return Ok(ResolvedName::Local(reference));
+        };
+
+        if prefix == self.local_prefix {
+            return Ok(ResolvedName::Local(name));
+        }
+
+        match self.imports.get(prefix) {
+            Some(module) if *module == self.module => Ok(ResolvedName::Local(name)),
+            Some(module) => Ok(ResolvedName::Imported {
+                module: module.clone(),
+                name,
+            }),
+            None => Err(ParseError::SemanticError {
+                message: format!("Unknown prefix '{}' in reference: {}", prefix, reference),
+            }),
+        }
+    }
+}
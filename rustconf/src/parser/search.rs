@@ -0,0 +1,273 @@
+//! Module lookup in search paths.
+//!
+//! A search path is a directory, a `.tar`, `.tar.gz` or `.tgz` archive of
+//! YANG files, or a glob pattern matching any number of these. Archives are
+//! indexed in memory the first time a module is looked up in them, so vendor
+//! bundles can be used without extracting them.
+//!
+//! Files may be named `name.yang` or `name@revision.yang`; the revision of a
+//! `name.yang` file is read from its `revision` statements. An import or
+//! include with a `revision-date` loads that revision, as RFC 7950 requires.
+//! Otherwise, or when that revision is not found, the newest revision found
+//! in any search path is loaded, the earlier search path winning when
+//! several have it.
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use flate2::read::GzDecoder;
+
+use super::lexer::{Lexer, Token};
+
+/// A module file found in a search path.
+pub(crate) struct FoundModule {
+    /// Path of the file; for archives, the archive path joined with the
+    /// path of the entry inside it.
+    pub path: PathBuf,
+    /// The search path the file was found in.
+    pub search_path: PathBuf,
+    pub content: String,
+    /// Every revision of the module found in the search paths, newest first.
+    pub revisions: Vec<String>,
+}
+
+/// A file of the module being looked up, before one is picked.
+struct Candidate {
+    path: PathBuf,
+    search_path: PathBuf,
+    revision: Option<String>,
+    content: String,
+}
+
+/// A `.yang` file inside an archive.
+struct ArchiveFile {
+    name: String,
+    revision: Option<String>,
+    path: PathBuf,
+    content: String,
+}
+
+/// Ordered search paths, with the contents of archives indexed on first use.
+#[derive(Default)]
+pub(crate) struct SearchPaths {
+    paths: Vec<PathBuf>,
+    archives: HashMap<PathBuf, Vec<ArchiveFile>>,
+}
+
+impl SearchPaths {
+    /// Add a search path, expanding glob patterns in sorted order.
+    pub fn add(&mut self, path: PathBuf) {
+        self.paths
+            .extend(expand(&path).unwrap_or_else(|_| vec![path]));
+    }
+
+    /// Find the file for a module or submodule: the requested revision, or
+    /// else the newest one.
+    pub fn find(&mut self, name: &str, revision: Option<&str>) -> io::Result<Option<FoundModule>> {
+        let mut candidates = Vec::new();
+        for search_path in self.paths.clone() {
+            let found = if is_archive(&search_path) {
+                self.find_in_archive(&search_path, name)?
+            } else {
+                find_in_directory(&search_path, name)?
+            };
+            candidates.extend(found.into_iter().map(|(path, revision, content)| {
+                // Trust the file name, and read the others
+                let revision = revision.or_else(|| content_revision(&content));
+                Candidate {
+                    path,
+                    search_path: search_path.clone(),
+                    revision,
+                    content,
+                }
+            }));
+        }
+
+        let mut revisions: Vec<String> = candidates
+            .iter()
+            .filter_map(|candidate| candidate.revision.clone())
+            .collect();
+        revisions.sort_by(|a, b| b.cmp(a));
+        revisions.dedup();
+
+        let requested = revision.and_then(|requested| {
+            candidates
+                .iter()
+                .position(|candidate| candidate.revision.as_deref() == Some(requested))
+        });
+        // YYYY-MM-DD dates order correctly as strings; `max_by_key` keeps the
+        // last maximum, so search from the end to prefer the first
+        let selected = requested.or_else(|| {
+            (0..candidates.len())
+                .rev()
+                .max_by_key(|&index| candidates[index].revision.as_deref())
+        });
+        Ok(selected.map(|index| {
+            let candidate = candidates.swap_remove(index);
+            FoundModule {
+                path: candidate.path,
+                search_path: candidate.search_path,
+                content: candidate.content,
+                revisions,
+            }
+        }))
+    }
+
+    fn find_in_archive(
+        &mut self,
+        archive: &Path,
+        name: &str,
+    ) -> io::Result<Vec<(PathBuf, Option<String>, String)>> {
+        if !self.archives.contains_key(archive) {
+            let files = read_archive(archive).map_err(|e| {
+                io::Error::new(
+                    e.kind(),
+                    format!("Cannot read archive {}: {}", archive.display(), e),
+                )
+            })?;
+            self.archives.insert(archive.to_path_buf(), files);
+        }
+
+        Ok(self.archives[archive]
+            .iter()
+            .filter(|file| file.name == name)
+            .map(|file| {
+                (
+                    archive.join(&file.path),
+                    file.revision.clone(),
+                    file.content.clone(),
+                )
+            })
+            .collect())
+    }
+}
+
+/// Whether the path contains glob metacharacters.
+pub(crate) fn is_pattern(path: &Path) -> bool {
+    path.to_string_lossy().contains(['*', '?', '['])
+}
+
+/// Whether the path names a tar archive, compressed or not.
+pub(crate) fn is_archive(path: &Path) -> bool {
+    let name = path.to_string_lossy();
+    name.ends_with(".tar") || name.ends_with(".tar.gz") || name.ends_with(".tgz")
+}
+
+/// The paths a search path stands for: the matches of a glob pattern, or the
+/// path itself.
+pub(crate) fn expand(path: &Path) -> Result<Vec<PathBuf>, glob::PatternError> {
+    if !is_pattern(path) {
+        return Ok(vec![path.to_path_buf()]);
+    }
+    Ok(glob::glob(&path.to_string_lossy())?
+        .filter_map(Result::ok)
+        .collect())
+}
+
+/// Split a file name such as `ietf-interfaces@2018-02-20.yang` into the
+/// module name and revision.
+fn parse_file_name(file_name: &str) -> Option<(&str, Option<&str>)> {
+    let stem = file_name.strip_suffix(".yang")?;
+    Some(match stem.split_once('@') {
+        Some((name, revision)) => (name, Some(revision)),
+        None => (stem, None),
+    })
+}
+
+/// The most recent `revision` date in a module's text, if it has any.
+fn content_revision(content: &str) -> Option<String> {
+    let tokens = Lexer::new(content).tokenize().ok()?;
+    tokens
+        .windows(4)
+        .filter_map(|window| match window {
+            [Token::Revision, Token::StringLiteral(date) | Token::Identifier(date), ..] => {
+                Some(date.clone())
+            }
+            // An unquoted date is lexed as three numbers (`2024`, `-01`, `-15`)
+            [Token::Revision, Token::Number(year), Token::Number(month), Token::Number(day)]
+                if *month < 0 && *day < 0 =>
+            {
+                Some(format!("{:04}-{:02}-{:02}", year, -month, -day))
+            }
+            _ => None,
+        })
+        .max()
+}
+
+/// The files of a module in a directory, with the revisions in their names.
+fn find_in_directory(
+    directory: &Path,
+    name: &str,
+) -> io::Result<Vec<(PathBuf, Option<String>, String)>> {
+    let mut files = Vec::new();
+    let plain = directory.join(format!("{}.yang", name));
+    if plain.exists() {
+        files.push((None, plain));
+    }
+    if let Ok(entries) = fs::read_dir(directory) {
+        let mut revisioned = Vec::new();
+        for entry in entries.flatten() {
+            let file_name = entry.file_name();
+            if let Some((file_module, Some(file_revision))) =
+                parse_file_name(&file_name.to_string_lossy())
+            {
+                if file_module == name {
+                    revisioned.push((Some(file_revision.to_string()), entry.path()));
+                }
+            }
+        }
+        // Directory order is arbitrary
+        revisioned.sort();
+        files.extend(revisioned);
+    }
+
+    files
+        .into_iter()
+        .map(|(revision, path)| {
+            let content = fs::read_to_string(&path)?;
+            Ok((path, revision, content))
+        })
+        .collect()
+}
+
+/// Read every `.yang` file in a tar archive, decompressing gzip archives.
+fn read_archive(path: &Path) -> io::Result<Vec<ArchiveFile>> {
+    let file = File::open(path)?;
+    let reader: Box<dyn Read> = if path.to_string_lossy().ends_with(".tar") {
+        Box::new(file)
+    } else {
+        Box::new(GzDecoder::new(file))
+    };
+
+    let mut files = Vec::new();
+    for entry in tar::Archive::new(reader).entries()? {
+        let mut entry = entry?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let entry_path = entry.path()?.into_owned();
+        let file_name = entry_path
+            .file_name()
+            .map(|file_name| file_name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let Some((name, revision)) = parse_file_name(&file_name)
+            .map(|(name, revision)| (name.to_string(), revision.map(str::to_string)))
+        else {
+            continue;
+        };
+
+        let mut content = String::new();
+        entry
+            .read_to_string(&mut content)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", entry_path.display(), e)))?;
+        files.push(ArchiveFile {
+            name,
+            revision,
+            path: entry_path,
+            content,
+        });
+    }
+    Ok(files)
+}
@@ -9,3 +9,19 @@ mod visitor;
 mod error;
 
 mod lexer;
+
+mod formatter;
+
+mod submodules;
+
+mod search_paths;
+
+mod augments;
+
+mod deviations;
+
+mod features;
+
+mod openapi;
+
+mod comments;
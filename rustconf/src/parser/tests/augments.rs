@@ -0,0 +1,374 @@
+//! Unit tests for augment parsing and target validation
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::{DataNode, ParseError, TypeSpec, YangParser};
+    use std::fs;
+    use tempfile::TempDir;
+
+    const INTERFACES: &str = r#"
+        module openconfig-interfaces {
+            namespace "http://openconfig.net/yang/interfaces";
+            prefix oc-if;
+
+            grouping interface-config {
+                leaf name { type string; }
+                leaf mtu { type uint16; }
+            }
+
+            container interfaces {
+                list interface {
+                    key "name";
+                    leaf name { type string; }
+                    container config {
+                        uses interface-config;
+                    }
+                }
+            }
+
+            rpc reset {
+                input {
+                    leaf name { type string; }
+                }
+            }
+        }
+    "#;
+
+    /// A parser with `openconfig-interfaces` on its search path.
+    fn parser(temp_dir: &TempDir) -> YangParser {
+        fs::write(
+            temp_dir.path().join("openconfig-interfaces.yang"),
+            INTERFACES,
+        )
+        .unwrap();
+        let mut parser = YangParser::new();
+        parser.add_search_path(temp_dir.path().to_path_buf());
+        parser
+    }
+
+    fn augmenting_module(target: &str) -> String {
+        format!(
+            r#"
+            module acme-if {{
+                namespace "urn:acme:if";
+                prefix acme;
+                import openconfig-interfaces {{ prefix oc-if; }}
+
+                augment "{}" {{
+                    description "Vendor extensions";
+                    leaf fec {{ type boolean; }}
+                }}
+            }}
+        "#,
+            target
+        )
+    }
+
+    #[test]
+    fn test_parse_augment() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut parser = parser(&temp_dir);
+        let module = parser
+            .parse_string(
+                &augmenting_module("/oc-if:interfaces/oc-if:interface/oc-if:config"),
+                "acme-if.yang",
+            )
+            .unwrap();
+
+        assert_eq!(module.augments.len(), 1);
+        let augment = &module.augments[0];
+        assert_eq!(
+            augment.target,
+            ["oc-if:interfaces", "oc-if:interface", "oc-if:config"]
+        );
+        assert_eq!(
+            augment.target_path(),
+            "/oc-if:interfaces/oc-if:interface/oc-if:config"
+        );
+        assert_eq!(augment.description.as_deref(), Some("Vendor extensions"));
+        assert!(matches!(&augment.data_nodes[..], [DataNode::Leaf(leaf)] if leaf.name == "fec"));
+    }
+
+    #[test]
+    fn test_relative_augment_target_is_rejected() {
+        let mut parser = YangParser::new();
+        let result = parser.parse_string(&augmenting_module("oc-if:interfaces"), "acme-if.yang");
+
+        match result {
+            Err(ParseError::SyntaxError { message, .. }) => {
+                assert!(message.contains("must be an absolute path"), "{}", message);
+            }
+            other => panic!("Expected syntax error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_targets_resolve_through_groupings_and_rpcs() {
+        let temp_dir = TempDir::new().unwrap();
+        for target in [
+            "/oc-if:interfaces/oc-if:interface/oc-if:config",
+            "/oc-if:interfaces/oc-if:interface/oc-if:config/oc-if:mtu",
+            "/oc-if:reset/oc-if:input",
+        ] {
+            let mut parser = parser(&temp_dir);
+            let module = parser
+                .parse_string(&augmenting_module(target), "acme-if.yang")
+                .unwrap();
+            parser
+                .validate_module(&module)
+                .unwrap_or_else(|e| panic!("{}: {}", target, e));
+        }
+    }
+
+    #[test]
+    fn test_unresolved_target_names_both_modules() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut parser = parser(&temp_dir);
+        let module = parser
+            .parse_string(
+                &augmenting_module("/oc-if:interfaces/oc-if:interface/oc-if:state"),
+                "acme-if.yang",
+            )
+            .unwrap();
+
+        match parser.validate_module(&module) {
+            Err(ParseError::SemanticError { message }) => {
+                assert!(message.contains("in module 'acme-if'"), "{}", message);
+                assert!(
+                    message.contains("in module 'openconfig-interfaces'"),
+                    "{}",
+                    message
+                );
+                assert!(
+                    message.contains("no schema node '/interfaces/interface/state'"),
+                    "{}",
+                    message
+                );
+            }
+            other => panic!("Expected semantic error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_target_module_not_loaded() {
+        let mut parser = YangParser::new();
+        let mut module = parser
+            .parse_string(
+                &augmenting_module("/oc-if:interfaces/oc-if:interface"),
+                "acme-if.yang",
+            )
+            .unwrap();
+
+        match parser.check_augment_targets(&mut module, true) {
+            Err(ParseError::SemanticError { message }) => {
+                assert!(message.contains("module is not loaded"), "{}", message);
+            }
+            other => panic!("Expected semantic error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_non_strict_mode_skips_unresolved_augments() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut parser = parser(&temp_dir);
+        let mut module = parser
+            .parse_string(
+                r#"
+                module acme-if {
+                    namespace "urn:acme:if";
+                    prefix acme;
+                    import openconfig-interfaces { prefix oc-if; }
+
+                    augment "/oc-if:interfaces/oc-if:interface" {
+                        leaf fec { type boolean; }
+                    }
+                    augment "/oc-if:interfaces/oc-if:subinterfaces" {
+                        leaf vlan { type uint16; }
+                    }
+                }
+            "#,
+                "acme-if.yang",
+            )
+            .unwrap();
+
+        let warnings = parser.check_augment_targets(&mut module, false).unwrap();
+
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].starts_with("Skipping augment '/oc-if:interfaces/oc-if:subinterfaces'"));
+        assert_eq!(module.augments.len(), 1);
+        assert_eq!(
+            module.augments[0].target,
+            ["oc-if:interfaces", "oc-if:interface"]
+        );
+    }
+
+    #[test]
+    fn test_target_through_another_augment() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut parser = parser(&temp_dir);
+        let module = parser
+            .parse_string(
+                r#"
+                module acme-if {
+                    namespace "urn:acme:if";
+                    prefix acme;
+                    import openconfig-interfaces { prefix oc-if; }
+
+                    augment "/oc-if:interfaces/oc-if:interface" {
+                        container optics {
+                            leaf power { type int32; }
+                        }
+                    }
+                    augment "/oc-if:interfaces/oc-if:interface/acme:optics" {
+                        leaf wavelength { type uint32; }
+                    }
+                }
+            "#,
+                "acme-if.yang",
+            )
+            .unwrap();
+
+        // The module's own prefix is dropped from local steps
+        assert_eq!(
+            module.augments[1].target,
+            ["oc-if:interfaces", "oc-if:interface", "optics"]
+        );
+        parser.validate_module(&module).unwrap();
+    }
+
+    /// The names of `nodes`.
+    fn names(nodes: &[DataNode]) -> Vec<&str> {
+        nodes
+            .iter()
+            .map(|node| match node {
+                DataNode::Container(container) => container.name.as_str(),
+                DataNode::List(list) => list.name.as_str(),
+                DataNode::Leaf(leaf) => leaf.name.as_str(),
+                DataNode::AnyData(anydata) => anydata.name.as_str(),
+                DataNode::LeafList(leaf_list) => leaf_list.name.as_str(),
+                DataNode::Choice(choice) => choice.name.as_str(),
+                DataNode::Case(case) => case.name.as_str(),
+                DataNode::Uses(uses) => uses.name.as_str(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_expand_module_splices_own_augments() {
+        let mut parser = YangParser::new();
+        let mut module = parser
+            .parse_string(
+                r#"
+                module system {
+                    namespace "urn:system";
+                    prefix sys;
+
+                    grouping timing {
+                        leaf timeout { type uint32; }
+                    }
+
+                    container system {
+                        leaf hostname { type string; }
+                    }
+
+                    augment "/sys:system" {
+                        container clock {
+                            uses timing;
+                        }
+                    }
+                    augment "/system/clock" {
+                        leaf timezone { type string; }
+                    }
+                }
+            "#,
+                "system.yang",
+            )
+            .unwrap();
+
+        parser.expand_module(&mut module).unwrap();
+
+        assert!(module.augments.is_empty());
+        let DataNode::Container(system) = &module.data_nodes[0] else {
+            panic!("Expected container");
+        };
+        assert_eq!(names(&system.children), ["hostname", "clock"]);
+        let DataNode::Container(clock) = &system.children[1] else {
+            panic!("Expected container");
+        };
+        assert_eq!(names(&clock.children), ["timeout", "timezone"]);
+    }
+
+    #[test]
+    fn test_apply_cross_module_augments() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut parser = parser(&temp_dir);
+        let interfaces = parser
+            .parse_string(INTERFACES, "openconfig-interfaces.yang")
+            .unwrap();
+        let acme = parser
+            .parse_string(
+                r#"
+                module acme-if {
+                    namespace "urn:acme:if";
+                    prefix acme;
+                    import openconfig-interfaces { prefix oc-if; }
+
+                    typedef milliwatts { type int32; }
+
+                    augment "/oc-if:interfaces/oc-if:interface" {
+                        container optics {
+                            leaf power { type milliwatts; }
+                        }
+                    }
+                    augment "/oc-if:interfaces/oc-if:interface/acme:optics" {
+                        leaf wavelength { type uint32; }
+                    }
+                    augment "/oc-if:reset/oc-if:output" {
+                        leaf cleared { type boolean; }
+                    }
+                    augment "/oc-if:interfaces/oc-if:subinterfaces" {
+                        leaf vlan { type uint16; }
+                    }
+                }
+            "#,
+                "acme-if.yang",
+            )
+            .unwrap();
+        let mut modules = vec![interfaces, acme];
+        for module in &mut modules {
+            parser.expand_groupings(module).unwrap();
+        }
+
+        parser.apply_augments(&mut modules).unwrap();
+
+        // Only the augment without a target is kept
+        assert_eq!(modules[1].augments.len(), 1);
+        assert_eq!(
+            modules[1].augments[0].target,
+            ["oc-if:interfaces", "oc-if:subinterfaces"]
+        );
+
+        let DataNode::Container(interfaces) = &modules[0].data_nodes[0] else {
+            panic!("Expected container");
+        };
+        let DataNode::List(interface) = &interfaces.children[0] else {
+            panic!("Expected list");
+        };
+        assert_eq!(
+            names(&interface.children),
+            ["name", "config", "acme-if:optics"]
+        );
+        let DataNode::Container(optics) = &interface.children[2] else {
+            panic!("Expected container");
+        };
+        // Children share their parent's namespace, and local typedefs are inlined
+        assert_eq!(names(&optics.children), ["power", "wavelength"]);
+        let DataNode::Leaf(power) = &optics.children[0] else {
+            panic!("Expected leaf");
+        };
+        assert_eq!(power.type_spec, TypeSpec::Int32 { range: None });
+
+        let output = modules[0].rpcs[0].output.as_deref().unwrap();
+        assert_eq!(names(output), ["acme-if:cleared"]);
+    }
+}
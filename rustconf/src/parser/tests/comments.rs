@@ -0,0 +1,115 @@
+//! Unit tests for keeping YANG comments as descriptions
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::{DataNode, Lexer, YangParser};
+
+    const DEVICE: &str = r#"
+        module device {
+            namespace "urn:device";
+            prefix dev;
+
+            // Interface settings
+
+            // Applied after the next reboot.
+            // Values above 1500 need jumbo frame support.
+            leaf mtu {
+                type uint16;
+                description "Maximum transmission unit.";
+            }
+
+            /*
+             * Name shown in prompts.
+             */
+            leaf hostname { type string; } // trailing comment
+
+            leaf domain { type string; }
+
+            container system {
+                // Seconds between polls.
+                leaf interval { type uint32; }
+            }
+
+            // Reboot the device.
+            rpc reboot {
+                input {
+                    leaf delay { type uint32; }
+                }
+            }
+        }
+    "#;
+
+    fn description(nodes: &[DataNode], name: &str) -> Option<String> {
+        nodes.iter().find_map(|node| match node {
+            DataNode::Leaf(leaf) if leaf.name == name => Some(leaf.description.clone()),
+            DataNode::Container(container) if container.name == name => {
+                Some(container.description.clone())
+            }
+            _ => None,
+        })?
+    }
+
+    fn parse(keep_comments: bool) -> crate::parser::YangModule {
+        let mut parser = YangParser::new();
+        parser.keep_comments(keep_comments);
+        parser.parse_string(DEVICE, "device.yang").unwrap()
+    }
+
+    #[test]
+    fn test_comments_are_dropped_by_default() {
+        let module = parse(false);
+        assert_eq!(
+            description(&module.data_nodes, "mtu").as_deref(),
+            Some("Maximum transmission unit.")
+        );
+        assert_eq!(description(&module.data_nodes, "hostname"), None);
+    }
+
+    #[test]
+    fn test_comments_are_appended_to_descriptions() {
+        let module = parse(true);
+        assert_eq!(
+            description(&module.data_nodes, "mtu").as_deref(),
+            Some(
+                "Maximum transmission unit.\n\nApplied after the next reboot.\n\
+                 Values above 1500 need jumbo frame support."
+            )
+        );
+        assert_eq!(
+            description(&module.data_nodes, "hostname").as_deref(),
+            Some("Name shown in prompts.")
+        );
+        assert_eq!(
+            module.rpcs[0].description.as_deref(),
+            Some("Reboot the device.")
+        );
+
+        let DataNode::Container(system) = &module.data_nodes[3] else {
+            panic!("expected the system container");
+        };
+        assert_eq!(
+            description(&system.children, "interval").as_deref(),
+            Some("Seconds between polls.")
+        );
+    }
+
+    #[test]
+    fn test_trailing_and_detached_comments_are_not_kept() {
+        let module = parse(true);
+        // The trailing comment of hostname is not about domain
+        assert_eq!(description(&module.data_nodes, "domain"), None);
+        // The section header is followed by a blank line
+        assert!(!description(&module.data_nodes, "mtu")
+            .unwrap()
+            .contains("Interface settings"));
+    }
+
+    #[test]
+    fn test_leading_comment_of_spanned_token() {
+        let tokens = Lexer::new("a; // one\n// two\n/* three */\nb;")
+            .tokenize_lossless()
+            .unwrap();
+        assert_eq!(tokens[0].leading_comment(), None);
+        assert_eq!(tokens[2].leading_comment().as_deref(), Some("two\nthree"));
+    }
+}
@@ -0,0 +1,260 @@
+//! Unit tests for deviation parsing and application
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::{
+        DataNode, Deviate, DeviateProperties, ParseError, TypeSpec, YangModule, YangParser,
+    };
+
+    const SYSTEM: &str = r#"
+        module system {
+            namespace "urn:system";
+            prefix sys;
+
+            container system {
+                leaf hostname { type string; }
+                leaf mtu {
+                    type uint16;
+                    units "octets";
+                }
+                leaf motd { type string; }
+                container clock {
+                    leaf timezone { type string; }
+                }
+            }
+
+            rpc reboot;
+        }
+    "#;
+
+    fn deviating_module(body: &str) -> String {
+        format!(
+            r#"
+            module acme-deviations {{
+                namespace "urn:acme:deviations";
+                prefix acme-dev;
+                import system {{ prefix sys; }}
+
+                typedef small-mtu {{
+                    type uint16 {{ range "68..1500"; }}
+                }}
+
+                {}
+            }}
+        "#,
+            body
+        )
+    }
+
+    /// Parse `system` and a deviation module with `body`.
+    fn modules(body: &str) -> (YangParser, Vec<YangModule>) {
+        let mut parser = YangParser::new();
+        let system = parser.parse_string(SYSTEM, "system.yang").unwrap();
+        let deviations = parser
+            .parse_string(&deviating_module(body), "acme-deviations.yang")
+            .unwrap();
+        (parser, vec![system, deviations])
+    }
+
+    fn system_leaves(module: &YangModule) -> Vec<&str> {
+        let DataNode::Container(system) = &module.data_nodes[0] else {
+            panic!("Expected container");
+        };
+        system
+            .children
+            .iter()
+            .filter_map(|node| match node {
+                DataNode::Leaf(leaf) => Some(leaf.name.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_deviation() {
+        let (_, modules) = modules(
+            r#"
+            deviation "/sys:system/sys:motd" {
+                description "Not implemented";
+                deviate not-supported;
+            }
+            deviation "/sys:system/sys:mtu" {
+                deviate replace {
+                    type small-mtu;
+                    config false;
+                }
+                deviate delete {
+                    units "octets";
+                }
+            }
+            "#,
+        );
+        let deviations = &modules[1].deviations;
+
+        assert_eq!(deviations.len(), 2);
+        assert_eq!(deviations[0].target, ["sys:system", "sys:motd"]);
+        assert_eq!(
+            deviations[0].description.as_deref(),
+            Some("Not implemented")
+        );
+        assert_eq!(deviations[0].deviates, [Deviate::NotSupported]);
+        assert_eq!(deviations[1].target_path(), "/sys:system/sys:mtu");
+        assert_eq!(
+            deviations[1].deviates,
+            [
+                Deviate::Replace(DeviateProperties {
+                    type_spec: Some(TypeSpec::TypedefRef {
+                        name: "small-mtu".to_string(),
+                        range: None,
+                        length: None,
+                        pattern: None,
+                    }),
+                    config: Some(false),
+                    ..DeviateProperties::default()
+                }),
+                Deviate::Delete(DeviateProperties {
+                    units: Some("octets".to_string()),
+                    ..DeviateProperties::default()
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_deviates() {
+        for body in [
+            r#"deviation "/sys:system" { description "empty"; }"#,
+            r#"deviation "/sys:system/sys:mtu" { deviate add { type string; } }"#,
+            r#"deviation "/sys:system/sys:mtu" { deviate delete { config false; } }"#,
+            r#"deviation "/sys:system/sys:mtu" { deviate not-supported { units "bytes"; } }"#,
+            r#"deviation "/sys:system/sys:mtu" { deviate remove; }"#,
+        ] {
+            let mut parser = YangParser::new();
+            parser.parse_string(SYSTEM, "system.yang").unwrap();
+            let result = parser.parse_string(&deviating_module(body), "acme-deviations.yang");
+            assert!(
+                matches!(result, Err(ParseError::SyntaxError { .. })),
+                "{}: {:?}",
+                body,
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_apply_deviations() {
+        let (parser, mut modules) = modules(
+            r#"
+            deviation "/sys:system/sys:motd" {
+                deviate not-supported;
+            }
+            deviation "/sys:reboot" {
+                deviate not-supported;
+            }
+            deviation "/sys:system/sys:mtu" {
+                deviate replace {
+                    type small-mtu;
+                }
+                deviate delete {
+                    units "octets";
+                }
+            }
+            deviation "/sys:system/sys:hostname" {
+                deviate add {
+                    default "router";
+                    mandatory false;
+                }
+                deviate replace {
+                    config false;
+                }
+            }
+            "#,
+        );
+
+        // Deviations of modules that are not being expanded are kept
+        parser.apply_deviations(&mut modules[1..]).unwrap();
+        assert_eq!(modules[1].deviations.len(), 4);
+
+        parser.apply_deviations(&mut modules).unwrap();
+
+        assert_eq!(system_leaves(&modules[0]), ["hostname", "mtu"]);
+        assert!(modules[0].rpcs.is_empty());
+        let DataNode::Container(system) = &modules[0].data_nodes[0] else {
+            panic!("Expected container");
+        };
+        let DataNode::Leaf(hostname) = &system.children[0] else {
+            panic!("Expected leaf");
+        };
+        assert_eq!(hostname.default.as_deref(), Some("router"));
+        assert!(!hostname.config);
+        // The deviating module's typedef is inlined into the other module
+        let DataNode::Leaf(mtu) = &system.children[1] else {
+            panic!("Expected leaf");
+        };
+        assert!(matches!(mtu.type_spec, TypeSpec::Uint16 { range: Some(_) }));
+        assert_eq!(mtu.units, None);
+        assert!(modules[1].deviations.is_empty());
+    }
+
+    #[test]
+    fn test_apply_deviation_errors() {
+        for (body, expected) in [
+            (
+                r#"deviation "/sys:system/sys:uptime" { deviate not-supported; }"#,
+                "no schema node '/system/uptime' in module 'system'",
+            ),
+            (
+                r#"deviation "/sys:system/sys:mtu" { deviate add { units "bytes"; } }"#,
+                "'mtu' already has a units; use deviate replace",
+            ),
+            (
+                r#"deviation "/sys:system/sys:clock" { deviate replace { type string; } }"#,
+                "'clock' has no type",
+            ),
+        ] {
+            let (parser, mut modules) = modules(body);
+            match parser.apply_deviations(&mut modules) {
+                Err(ParseError::SemanticError { message }) => {
+                    assert!(
+                        message.starts_with("Deviation '/sys:system/"),
+                        "{}",
+                        message
+                    );
+                    assert!(message.contains("module 'acme-deviations'"), "{}", message);
+                    assert!(message.contains(expected), "{}", message);
+                }
+                other => panic!("Expected semantic error, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_expand_module_applies_own_deviations() {
+        let mut parser = YangParser::new();
+        let mut module = parser
+            .parse_string(
+                r#"
+                module device {
+                    namespace "urn:device";
+                    prefix dev;
+
+                    container system {
+                        leaf hostname { type string; }
+                        leaf legacy { type string; }
+                    }
+
+                    deviation "/dev:system/dev:legacy" {
+                        deviate not-supported;
+                    }
+                }
+            "#,
+                "device.yang",
+            )
+            .unwrap();
+
+        parser.expand_module(&mut module).unwrap();
+
+        assert!(module.deviations.is_empty());
+        assert_eq!(system_leaves(&module), ["hostname"]);
+    }
+}
@@ -4,7 +4,9 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::parser::{DataNode, TypeSpec, YangParser};
+    use crate::parser::{
+        DataNode, LengthRange, ParseError, Range, RangeConstraint, TypeSpec, YangParser,
+    };
 
     #[test]
     fn test_expand_simple_typedef() {
@@ -355,6 +357,116 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_expand_uses_with_augment() {
+        let input = r#"
+            module test {
+                namespace "urn:test";
+                prefix test;
+
+                grouping address {
+                    container config {
+                        leaf ip {
+                            type string;
+                        }
+                    }
+                    list neighbor {
+                        key "address";
+                        leaf address {
+                            type string;
+                        }
+                        container state {
+                            leaf reachable {
+                                type boolean;
+                            }
+                        }
+                    }
+                }
+
+                grouping metric {
+                    leaf metric {
+                        type uint32;
+                    }
+                }
+
+                container interface {
+                    uses address {
+                        augment "test:config" {
+                            uses metric;
+                        }
+                        augment "neighbor/state" {
+                            leaf last-seen {
+                                type uint64;
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let mut parser = YangParser::new();
+        let mut module = parser.parse_string(input, "test.yang").unwrap();
+        parser.expand_module(&mut module).unwrap();
+
+        let DataNode::Container(interface) = &module.data_nodes[0] else {
+            panic!("Expected Container data node");
+        };
+        let DataNode::Container(config) = &interface.children[0] else {
+            panic!("Expected Container data node");
+        };
+        let names: Vec<&str> = config.children.iter().map(DataNode::name).collect();
+        assert_eq!(names, ["ip", "metric"]);
+
+        let DataNode::List(neighbor) = &interface.children[1] else {
+            panic!("Expected List data node");
+        };
+        let DataNode::Container(state) = &neighbor.children[1] else {
+            panic!("Expected Container data node");
+        };
+        let names: Vec<&str> = state.children.iter().map(DataNode::name).collect();
+        assert_eq!(names, ["reachable", "last-seen"]);
+
+        // Code generation expands groupings alone, keeping typedef references
+        let mut module = parser.parse_string(input, "test.yang").unwrap();
+        parser.expand_groupings(&mut module).unwrap();
+        let config = &module.data_nodes[0].children()[0];
+        assert_eq!(config.children().len(), 2);
+    }
+
+    #[test]
+    fn test_error_uses_augment_target_not_in_grouping() {
+        let input = r#"
+            module test {
+                namespace "urn:test";
+                prefix test;
+
+                grouping common {
+                    leaf id {
+                        type uint32;
+                    }
+                }
+
+                container first {
+                    uses common {
+                        augment "state" {
+                            leaf counter {
+                                type uint64;
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let mut parser = YangParser::new();
+        let mut module = parser.parse_string(input, "test.yang").unwrap();
+        let error = parser.expand_module(&mut module).unwrap_err();
+
+        assert!(error
+            .to_string()
+            .contains("Augment target 'state' not found in grouping common"));
+    }
+
     #[test]
     fn test_error_undefined_typedef() {
         let input = r#"
@@ -456,4 +568,111 @@ mod tests {
             panic!("Expected List data node");
         }
     }
+
+    #[test]
+    fn test_expand_typedef_chain_merges_restrictions() {
+        let input = r#"
+            module test {
+                namespace "urn:test";
+                prefix test;
+
+                typedef port-number {
+                    type uint16 {
+                        range "1..65535";
+                    }
+                }
+
+                typedef well-known-port {
+                    type port-number {
+                        range "0..1023";
+                    }
+                }
+
+                typedef identifier {
+                    type string {
+                        length "1..64";
+                        pattern "[a-z0-9-]+";
+                    }
+                }
+
+                leaf admin-port {
+                    type well-known-port {
+                        range "1..100 | 900..2000";
+                    }
+                }
+
+                leaf short-id {
+                    type identifier {
+                        length "4..128";
+                        pattern "[a-z].*";
+                    }
+                }
+            }
+        "#;
+
+        let mut parser = YangParser::new();
+        let mut module = parser.parse_string(input, "test.yang").unwrap();
+        parser.validate_module(&module).unwrap();
+        parser.expand_module(&mut module).unwrap();
+
+        let leaf_type = |index: usize| match &module.data_nodes[index] {
+            DataNode::Leaf(leaf) => leaf.type_spec.clone(),
+            other => panic!("Expected Leaf data node, got {:?}", other),
+        };
+
+        // 1..65535 ∩ 0..1023 ∩ (1..100 | 900..2000)
+        assert_eq!(
+            leaf_type(0),
+            TypeSpec::Uint16 {
+                range: Some(RangeConstraint::new(vec![
+                    Range::new(1, 100),
+                    Range::new(900, 1023)
+                ])),
+            }
+        );
+
+        match leaf_type(1) {
+            TypeSpec::String { length, pattern } => {
+                assert_eq!(length.unwrap().lengths, vec![LengthRange::new(4, 64)]);
+                let pattern = pattern.unwrap();
+                assert_eq!(
+                    pattern.patterns().collect::<Vec<_>>(),
+                    vec!["[a-z0-9-]+", "[a-z].*"]
+                );
+            }
+            other => panic!("Expected String type, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_expand_rejects_disjoint_typedef_restriction() {
+        let input = r#"
+            module test {
+                namespace "urn:test";
+                prefix test;
+
+                typedef percent {
+                    type uint8 {
+                        range "0..100";
+                    }
+                }
+
+                leaf ratio {
+                    type percent {
+                        range "200..250";
+                    }
+                }
+            }
+        "#;
+
+        let mut parser = YangParser::new();
+        let mut module = parser.parse_string(input, "test.yang").unwrap();
+
+        match parser.expand_module(&mut module).unwrap_err() {
+            ParseError::SemanticError { message } => {
+                assert!(message.contains("Invalid restriction of typedef 'percent'"));
+            }
+            other => panic!("Expected SemanticError, got {:?}", other),
+        }
+    }
 }
@@ -0,0 +1,198 @@
+//! Unit tests for feature and if-feature parsing
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::{DataNode, IfFeature, ParseError, YangParser};
+
+    fn feature(name: &str) -> IfFeature {
+        IfFeature::Feature(name.to_string())
+    }
+
+    #[test]
+    fn test_parse_feature_definitions() {
+        let mut parser = YangParser::new();
+        let module = parser
+            .parse_string(
+                r#"
+                module interfaces {
+                    namespace "urn:interfaces";
+                    prefix if;
+
+                    feature if-mib {
+                        description "Supports the IF-MIB.";
+                    }
+                    feature pre-provisioning;
+                    feature if-mib-counters {
+                        if-feature if-mib;
+                        status current;
+                    }
+                }
+            "#,
+                "interfaces.yang",
+            )
+            .unwrap();
+
+        let names: Vec<&str> = module.features.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, ["if-mib", "pre-provisioning", "if-mib-counters"]);
+        assert_eq!(
+            module.features[0].description.as_deref(),
+            Some("Supports the IF-MIB.")
+        );
+        assert_eq!(
+            module.features[2].if_features,
+            [feature("interfaces:if-mib")]
+        );
+    }
+
+    #[test]
+    fn test_parse_if_feature_expressions_qualified_with_their_module() {
+        let mut parser = YangParser::new();
+        let module = parser
+            .parse_string(
+                r#"
+                module interfaces {
+                    yang-version 1.1;
+                    namespace "urn:interfaces";
+                    prefix if;
+                    import hardware { prefix hw; }
+
+                    feature a;
+                    feature b;
+                    feature c;
+
+                    container interfaces {
+                        leaf name {
+                            type string;
+                            if-feature a;
+                            if-feature "if:b or not hw:c";
+                        }
+                        leaf speed {
+                            type uint32;
+                            if-feature "(a or b) and c";
+                        }
+                    }
+                }
+            "#,
+                "interfaces.yang",
+            )
+            .unwrap();
+
+        let DataNode::Container(interfaces) = &module.data_nodes[0] else {
+            panic!("expected container");
+        };
+        assert_eq!(
+            interfaces.children[0].if_features(),
+            [
+                feature("interfaces:a"),
+                IfFeature::Or(
+                    Box::new(feature("interfaces:b")),
+                    Box::new(IfFeature::Not(Box::new(feature("hardware:c")))),
+                ),
+            ]
+        );
+        assert_eq!(
+            interfaces.children[1].if_features(),
+            [IfFeature::And(
+                Box::new(IfFeature::Or(
+                    Box::new(feature("interfaces:a")),
+                    Box::new(feature("interfaces:b")),
+                )),
+                Box::new(feature("interfaces:c")),
+            )]
+        );
+    }
+
+    #[test]
+    fn test_if_feature_evaluation() {
+        let condition = IfFeature::And(
+            Box::new(feature("m:a")),
+            Box::new(IfFeature::Not(Box::new(feature("m:b")))),
+        );
+        assert!(condition.evaluate(&|name| name == "m:a"));
+        assert!(!condition.evaluate(&|name| name == "m:a" || name == "m:b"));
+        assert!(!condition.evaluate(&|_| false));
+    }
+
+    #[test]
+    fn test_uses_conditions_apply_to_grouping_nodes() {
+        let mut parser = YangParser::new();
+        let mut module = parser
+            .parse_string(
+                r#"
+                module system {
+                    namespace "urn:system";
+                    prefix sys;
+
+                    feature ntp;
+
+                    grouping ntp-settings {
+                        leaf server { type string; }
+                    }
+
+                    container system {
+                        uses ntp-settings {
+                            if-feature ntp;
+                        }
+                    }
+                }
+            "#,
+                "system.yang",
+            )
+            .unwrap();
+        parser.expand_module(&mut module).unwrap();
+
+        let DataNode::Container(system) = &module.data_nodes[0] else {
+            panic!("expected container");
+        };
+        assert_eq!(system.children[0].if_features(), [feature("system:ntp")]);
+    }
+
+    #[test]
+    fn test_undefined_local_feature_is_rejected() {
+        let mut parser = YangParser::new();
+        let result = parser.parse_string(
+            r#"
+            module system {
+                namespace "urn:system";
+                prefix sys;
+
+                leaf hostname {
+                    type string;
+                    if-feature hostname-setting;
+                }
+            }
+        "#,
+            "system.yang",
+        );
+
+        match result {
+            Err(ParseError::SemanticError { message }) => {
+                assert!(message.contains("Undefined feature 'hostname-setting'"));
+            }
+            other => panic!("expected semantic error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_malformed_if_feature_expression_is_rejected() {
+        let mut parser = YangParser::new();
+        let result = parser.parse_string(
+            r#"
+            module system {
+                namespace "urn:system";
+                prefix sys;
+
+                feature a;
+
+                leaf hostname {
+                    type string;
+                    if-feature "(a and";
+                }
+            }
+        "#,
+            "system.yang",
+        );
+
+        assert!(matches!(result, Err(ParseError::SyntaxError { .. })));
+    }
+}
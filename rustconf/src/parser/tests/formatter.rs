@@ -0,0 +1,277 @@
+//! Unit tests for the YANG source formatter
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::{format_yang, DataNode, ParseError, YangFormatter, YangParser};
+
+    #[test]
+    fn test_format_normalizes_indentation() {
+        let input = "module test {\nnamespace \"urn:test\";\n    prefix t;\ncontainer system {\n        leaf hostname { type string; }\n}\n}\n";
+        let expected = r#"module test {
+  namespace "urn:test";
+  prefix t;
+
+  container system {
+    leaf hostname {
+      type string;
+    }
+  }
+}
+"#;
+        assert_eq!(format_yang(input).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_format_custom_indent() {
+        let input = "module test { namespace \"urn:test\"; prefix t; leaf a { type string; } }";
+        let formatted = YangFormatter::new().with_indent(4).format(input).unwrap();
+        assert!(formatted.contains("\n    leaf a {\n        type string;\n    }\n"));
+    }
+
+    #[test]
+    fn test_format_normalizes_quoting() {
+        let input = r#"module test {
+  namespace 'urn:test';
+  prefix t;
+  description simple;
+  leaf a {
+    type string {
+      pattern '[a-z]+\d*';
+    }
+    description 'says "hi"';
+  }
+}
+"#;
+        let formatted = format_yang(input).unwrap();
+        assert!(formatted.contains("namespace \"urn:test\";"));
+        assert!(formatted.contains("description \"simple\";"));
+        // Content that needs single quotes keeps them
+        assert!(formatted.contains(r"pattern '[a-z]+\d*';"));
+        assert!(formatted.contains(r#"description 'says "hi"';"#));
+    }
+
+    #[test]
+    fn test_format_keeps_single_quotes_where_double_quotes_trim() {
+        // Double-quoted strings lose the indentation of continuation lines
+        // and trailing whitespace (RFC 7950 section 6.1.3); single-quoted
+        // ones keep them
+        let input = "module test {
+namespace 'urn:test';
+prefix t;
+container system {
+description 'Line one
+    indented line two';
+leaf a {
+type string;
+description ' padded ';
+units 'per second ';
+}
+}
+}
+";
+        let formatted = format_yang(input).unwrap();
+        assert!(formatted.contains("namespace \"urn:test\";"));
+        assert!(formatted.contains("description ' padded ';"));
+        assert!(formatted.contains("units 'per second ';"));
+
+        let descriptions = |source: &str| {
+            let module = YangParser::new().parse_string(source, "test.yang").unwrap();
+            let DataNode::Container(system) = &module.data_nodes[0] else {
+                panic!("Expected container");
+            };
+            let DataNode::Leaf(leaf) = &system.children[0] else {
+                panic!("Expected leaf");
+            };
+            (
+                system.description.clone(),
+                leaf.description.clone(),
+                leaf.units.clone(),
+            )
+        };
+        assert_eq!(descriptions(&formatted), descriptions(input));
+        assert_eq!(
+            descriptions(&formatted).0.as_deref(),
+            Some("Line one\n    indented line two")
+        );
+    }
+
+    #[test]
+    fn test_format_orders_module_header() {
+        let input = r#"module test {
+  revision 2020-01-01 { description "Initial"; }
+  leaf b { type string; }
+  description "Test module";
+  prefix t;
+  import ietf-inet-types { prefix inet; }
+  namespace "urn:test";
+  revision 2021-06-01 { description "Update"; }
+  yang-version 1.1;
+  leaf a { type string; }
+}
+"#;
+        let expected = r#"module test {
+  yang-version 1.1;
+  namespace "urn:test";
+  prefix t;
+
+  import ietf-inet-types {
+    prefix inet;
+  }
+
+  description "Test module";
+
+  revision 2021-06-01 {
+    description "Update";
+  }
+  revision 2020-01-01 {
+    description "Initial";
+  }
+
+  leaf b {
+    type string;
+  }
+  leaf a {
+    type string;
+  }
+}
+"#;
+        assert_eq!(format_yang(input).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_format_preserves_comments() {
+        let input = r#"// File header
+module test {
+  namespace "urn:test"; // trailing
+  prefix t;
+
+  /* The MTU */
+  leaf mtu {
+    type uint16;
+    // end of leaf
+  }
+} // end of module
+"#;
+        let formatted = format_yang(input).unwrap();
+        assert_eq!(formatted, input);
+    }
+
+    #[test]
+    fn test_format_preserves_blank_lines_in_body() {
+        let input = "module test {\n  namespace \"urn:test\";\n  prefix t;\n\n  leaf a {\n    type string;\n  }\n\n\n  leaf b {\n    type string;\n  }\n  leaf c {\n    type string;\n  }\n}\n";
+        let expected = "module test {\n  namespace \"urn:test\";\n  prefix t;\n\n  leaf a {\n    type string;\n  }\n\n  leaf b {\n    type string;\n  }\n  leaf c {\n    type string;\n  }\n}\n";
+        assert_eq!(format_yang(input).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_format_keeps_arguments_verbatim() {
+        let input = r#"module test {
+  namespace "urn:test";
+  prefix t;
+  leaf a {
+    type int32 {
+      range "1..10 | 20..max";
+    }
+    tailf:info "vendor extension";
+    description
+      "Line one
+       line two";
+  }
+  leaf b { type int8 { range 1..5; } }
+}
+"#;
+        let formatted = format_yang(input).unwrap();
+        assert!(formatted.contains("range \"1..10 | 20..max\";"));
+        assert!(formatted.contains("tailf:info \"vendor extension\";"));
+        assert!(formatted.contains("description\n      \"Line one\n       line two\";"));
+        assert!(formatted.contains("range 1..5;"));
+    }
+
+    #[test]
+    fn test_format_is_idempotent_and_preserves_meaning() {
+        let input = r#"module test { yang-version 1.1; namespace 'urn:test'; prefix t;
+            // Groupings
+            grouping endpoint { leaf address { type string; } leaf port { type uint16 { range "1..65535"; } } }
+            container server { uses endpoint; leaf enabled { type boolean; default true; } }
+            list user { key name; leaf name { type string; } }
+            rpc restart { input { leaf delay { type uint32; } } }
+            notification started { leaf at { type string; } } }"#;
+
+        let formatter = YangFormatter::new();
+        let formatted = formatter.format(input).unwrap();
+        assert!(formatter.is_formatted(&formatted).unwrap());
+        assert_eq!(formatter.format(&formatted).unwrap(), formatted);
+
+        let original = YangParser::new().parse_string(input, "test.yang").unwrap();
+        let reparsed = YangParser::new()
+            .parse_string(&formatted, "test.yang")
+            .unwrap();
+        assert_eq!(original, reparsed);
+    }
+
+    #[test]
+    fn test_format_collapses_empty_blocks() {
+        let formatted =
+            format_yang("module test { namespace \"urn:test\"; prefix t; container c {} }")
+                .unwrap();
+        assert!(formatted.contains("  container c;\n"));
+    }
+
+    #[test]
+    fn test_format_reports_unbalanced_braces() {
+        let missing = format_yang("module test {\n  leaf a {\n    type string;\n  }\n");
+        assert!(matches!(missing, Err(ParseError::SyntaxError { .. })));
+
+        let extra = format_yang("module test {\n}\n}\n");
+        match extra {
+            Err(ParseError::SyntaxError { line, .. }) => assert_eq!(line, 3),
+            other => panic!("Expected syntax error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_format_reports_missing_terminator() {
+        let result = format_yang("module test {\n  leaf a\n}\n");
+        match result {
+            Err(ParseError::SyntaxError { line, column, .. }) => {
+                assert_eq!((line, column), (3, 1));
+            }
+            other => panic!("Expected syntax error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_format_keeps_long_arguments_on_their_own_line() {
+        let input = r#"module test {
+namespace "urn:test";
+prefix t;
+description
+        "A long module description that does not fit on the keyword line.";
+leaf prefix {
+type string {
+pattern
+'[0-9]+'
+          + '(/[0-9]+)?';
+}
+}
+}
+"#;
+        let expected = r#"module test {
+  namespace "urn:test";
+  prefix t;
+
+  description
+    "A long module description that does not fit on the keyword line.";
+
+  leaf prefix {
+    type string {
+      pattern
+        '[0-9]+'
+        + '(/[0-9]+)?';
+    }
+  }
+}
+"#;
+        assert_eq!(format_yang(input).unwrap(), expected);
+    }
+}
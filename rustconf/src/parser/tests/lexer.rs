@@ -335,4 +335,96 @@ mod tests {
         assert_eq!(lexer.next_token().unwrap(), Token::Leaf);
         assert_eq!(lexer.next_token().unwrap(), Token::LeafList);
     }
+
+    #[test]
+    fn test_tokenize_lossless_preserves_trivia_and_spans() {
+        use crate::parser::lexer::Trivia;
+
+        let input = "module m { // header\n  /* block */ prefix m;\n}";
+        let tokens = Lexer::new(input).tokenize_lossless().unwrap();
+
+        assert_eq!(tokens[0].token, Token::Module);
+        assert_eq!(tokens[0].span.line, 1);
+        assert_eq!(tokens[0].span.column, 1);
+
+        let prefix = &tokens[3];
+        assert_eq!(prefix.token, Token::Prefix);
+        assert_eq!(prefix.span.line, 2);
+        assert_eq!(prefix.span.column, 15);
+        assert_eq!(
+            prefix.leading_trivia,
+            vec![
+                Trivia::Whitespace(" ".to_string()),
+                Trivia::LineComment("// header".to_string()),
+                Trivia::Whitespace("\n  ".to_string()),
+                Trivia::BlockComment("/* block */".to_string()),
+                Trivia::Whitespace(" ".to_string()),
+            ]
+        );
+        assert_eq!(tokens.last().unwrap().token, Token::Eof);
+
+        // Concatenating trivia and token text reproduces the input exactly
+        let rebuilt: String = tokens
+            .iter()
+            .map(|t| {
+                let trivia: String = t
+                    .leading_trivia
+                    .iter()
+                    .map(|tr| match tr {
+                        Trivia::Whitespace(s)
+                        | Trivia::LineComment(s)
+                        | Trivia::BlockComment(s) => s.clone(),
+                    })
+                    .collect();
+                trivia + t.text(input)
+            })
+            .collect();
+        assert_eq!(rebuilt, input);
+    }
+
+    #[test]
+    fn test_tokenize_lossless_matches_tokenize() {
+        let input = r#"
+            module example {
+                // comment
+                leaf name { type string; description 'a "quoted" name'; }
+            }
+        "#;
+        let plain = Lexer::new(input).tokenize().unwrap();
+        let lossless: Vec<Token> = Lexer::new(input)
+            .tokenize_lossless()
+            .unwrap()
+            .into_iter()
+            .map(|t| t.token)
+            .collect();
+        assert_eq!(plain, lossless);
+    }
+
+    #[test]
+    fn test_double_quoted_strings_trim_line_layout() {
+        let strings = |input: &str| -> Vec<String> {
+            Lexer::new(input)
+                .tokenize()
+                .unwrap()
+                .into_iter()
+                .filter_map(|t| match t {
+                    Token::StringLiteral(s) => Some(s),
+                    _ => None,
+                })
+                .collect()
+        };
+
+        // Indentation up to the column after the quote and trailing
+        // whitespace are stripped; deeper indentation is kept
+        let input = "  description \"one  \n               two\n                 three\n   four\";";
+        assert_eq!(strings(input), ["one\ntwo\n  three\nfour"]);
+
+        // A tab counts as 8 columns
+        let input = "\tdescription \"one\n\t\t     two\";";
+        assert_eq!(strings(input), ["one\ntwo"]);
+
+        // Single-quoted strings are kept as written
+        let input = "  description 'one  \n                 two';";
+        assert_eq!(strings(input), ["one  \n                 two"]);
+    }
 }
@@ -0,0 +1,268 @@
+//! Unit tests for importing OpenAPI and JSON Schema definitions
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::openapi::OpenApiImporter;
+    use crate::parser::{Container, DataNode, ParseError, TypeSpec};
+
+    const FIRMWARE: &str = r##"{
+        "openapi": "3.0.3",
+        "info": {"title": "Firmware API", "version": "1.0"},
+        "components": {
+            "schemas": {
+                "Version": {
+                    "type": "string",
+                    "pattern": "[0-9]+\\.[0-9]+",
+                    "description": "A firmware version."
+                },
+                "Image": {
+                    "type": "object",
+                    "required": ["url"],
+                    "properties": {
+                        "url": {"type": "string", "maxLength": 2048},
+                        "checksum": {"type": "string", "readOnly": true}
+                    }
+                },
+                "FirmwareStatus": {
+                    "allOf": [
+                        {"$ref": "#/components/schemas/Image"},
+                        {
+                            "type": "object",
+                            "properties": {
+                                "version": {"$ref": "#/components/schemas/Version"},
+                                "progressPct": {
+                                    "type": "integer",
+                                    "format": "int32",
+                                    "minimum": 0,
+                                    "maximum": 100
+                                },
+                                "state": {"type": "string", "enum": ["idle", "installing"]},
+                                "previous": {"type": "array", "maxItems": 5, "items": {"$ref": "#/components/schemas/Image"}},
+                                "tags": {"type": "array", "minItems": 1, "items": {"type": "string"}},
+                                "slot": {"oneOf": [{"type": "integer"}, {"type": "string"}]},
+                                "rollback": {"type": "boolean", "default": false},
+                                "ratio": {"type": "number"}
+                            }
+                        }
+                    ]
+                },
+                "Node": {
+                    "type": "object",
+                    "properties": {
+                        "name": {"type": "string"},
+                        "children": {"type": "array", "items": {"$ref": "#/components/schemas/Node"}}
+                    }
+                }
+            }
+        }
+    }"##;
+
+    fn container<'a>(nodes: &'a [DataNode], name: &str) -> &'a Container {
+        nodes
+            .iter()
+            .find_map(|node| match node {
+                DataNode::Container(c) if c.name == name => Some(c),
+                _ => None,
+            })
+            .unwrap_or_else(|| panic!("no container {}", name))
+    }
+
+    fn node_name(node: &DataNode) -> &str {
+        match node {
+            DataNode::Container(c) => &c.name,
+            DataNode::List(l) => &l.name,
+            DataNode::Leaf(l) => &l.name,
+            DataNode::LeafList(l) => &l.name,
+            other => panic!("unexpected node {:?}", other),
+        }
+    }
+
+    fn child<'a>(nodes: &'a [DataNode], name: &str) -> &'a DataNode {
+        nodes
+            .iter()
+            .find(|node| node_name(node) == name)
+            .unwrap_or_else(|| panic!("no node {}", name))
+    }
+
+    #[test]
+    fn test_object_schemas_become_containers() {
+        let import = OpenApiImporter::new("firmware")
+            .import_str(FIRMWARE)
+            .unwrap();
+        let module = &import.module;
+        assert_eq!(module.name, "firmware");
+        assert_eq!(module.namespace, "urn:openapi:firmware");
+        assert_eq!(module.prefix, "firmware");
+        assert_eq!(module.description.as_deref(), Some("Firmware API"));
+
+        let image = container(&module.data_nodes, "Image");
+        match child(&image.children, "url") {
+            DataNode::Leaf(leaf) => {
+                assert!(leaf.mandatory);
+                assert!(leaf.config);
+                assert!(matches!(
+                    &leaf.type_spec,
+                    TypeSpec::String {
+                        length: Some(_),
+                        pattern: None
+                    }
+                ));
+            }
+            other => panic!("expected a leaf, got {:?}", other),
+        }
+        match child(&image.children, "checksum") {
+            DataNode::Leaf(leaf) => {
+                assert!(!leaf.mandatory);
+                assert!(!leaf.config);
+            }
+            other => panic!("expected a leaf, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_scalar_schemas_become_typedefs() {
+        let import = OpenApiImporter::new("firmware")
+            .import_str(FIRMWARE)
+            .unwrap();
+        let typedef = &import.module.typedefs[0];
+        assert_eq!(typedef.name, "Version");
+        assert_eq!(typedef.description.as_deref(), Some("A firmware version."));
+        assert!(matches!(
+            &typedef.type_spec,
+            TypeSpec::String {
+                pattern: Some(_),
+                ..
+            }
+        ));
+
+        let status = container(&import.module.data_nodes, "FirmwareStatus");
+        match child(&status.children, "version") {
+            DataNode::Leaf(leaf) => {
+                assert!(
+                    matches!(&leaf.type_spec, TypeSpec::TypedefRef { name, .. } if name == "Version")
+                )
+            }
+            other => panic!("expected a leaf, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_properties_map_to_yang_statements() {
+        let import = OpenApiImporter::new("firmware")
+            .import_str(FIRMWARE)
+            .unwrap();
+        let status = container(&import.module.data_nodes, "FirmwareStatus");
+
+        // allOf members are merged, references to objects expanded
+        assert!(matches!(child(&status.children, "url"), DataNode::Leaf(l) if l.mandatory));
+        match child(&status.children, "progressPct") {
+            DataNode::Leaf(leaf) => {
+                let TypeSpec::Int32 { range: Some(range) } = &leaf.type_spec else {
+                    panic!("expected an int32 with a range, got {:?}", leaf.type_spec);
+                };
+                assert_eq!((range.ranges[0].min, range.ranges[0].max), (0, 100));
+            }
+            other => panic!("expected a leaf, got {:?}", other),
+        }
+        assert!(matches!(
+            child(&status.children, "state"),
+            DataNode::Leaf(l) if matches!(&l.type_spec, TypeSpec::Enumeration { values } if values.len() == 2)
+        ));
+        match child(&status.children, "previous") {
+            DataNode::List(list) => {
+                assert!(list.keys.is_empty());
+                assert_eq!(list.children.len(), 2);
+                assert_eq!((list.min_elements, list.max_elements), (0, Some(5)));
+            }
+            other => panic!("expected a list, got {:?}", other),
+        }
+        assert!(matches!(
+            child(&status.children, "tags"),
+            DataNode::LeafList(l) if matches!(l.type_spec, TypeSpec::String { .. })
+                && (l.min_elements, l.max_elements) == (1, None)
+        ));
+        assert!(matches!(
+            child(&status.children, "slot"),
+            DataNode::Leaf(l) if matches!(&l.type_spec, TypeSpec::Union { types } if types.len() == 2)
+        ));
+        assert!(matches!(
+            child(&status.children, "rollback"),
+            DataNode::Leaf(l) if l.default.as_deref() == Some("false")
+        ));
+    }
+
+    #[test]
+    fn test_unsupported_schemas_are_skipped_with_warnings() {
+        let import = OpenApiImporter::new("firmware")
+            .import_str(FIRMWARE)
+            .unwrap();
+        let status = container(&import.module.data_nodes, "FirmwareStatus");
+        assert!(!status
+            .children
+            .iter()
+            .any(|node| node_name(node) == "ratio"));
+
+        let node = container(&import.module.data_nodes, "Node");
+        assert!(!node
+            .children
+            .iter()
+            .any(|node| node_name(node) == "children"));
+
+        assert_eq!(
+            import.warnings,
+            vec![
+                "/FirmwareStatus/ratio: number is not supported; use integer or string",
+                "/Node/children: recursive reference to Node is not supported",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_json_schema_definitions_and_settings() {
+        let schema = r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "$defs": {
+                "Port": {
+                    "type": "object",
+                    "properties": {
+                        "speed": {"type": ["integer", "null"], "exclusiveMinimum": 0}
+                    }
+                }
+            }
+        }"#;
+        let import = OpenApiImporter::new("ports")
+            .namespace("urn:example:ports")
+            .prefix("p")
+            .import_str(schema)
+            .unwrap();
+        assert_eq!(import.module.namespace, "urn:example:ports");
+        assert_eq!(import.module.prefix, "p");
+
+        let port = container(&import.module.data_nodes, "Port");
+        match child(&port.children, "speed") {
+            DataNode::Leaf(leaf) => {
+                let TypeSpec::Int64 { range: Some(range) } = &leaf.type_spec else {
+                    panic!("expected an int64 with a range, got {:?}", leaf.type_spec);
+                };
+                assert_eq!(range.ranges[0].min, 1);
+            }
+            other => panic!("expected a leaf, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_documents_without_schemas_are_rejected() {
+        for document in ["not json", r#"{"openapi": "3.0.3", "paths": {}}"#] {
+            match OpenApiImporter::new("empty").import_str(document) {
+                Err(ParseError::SemanticError { message }) => {
+                    assert!(
+                        message.starts_with("invalid OpenAPI document"),
+                        "{}",
+                        message
+                    )
+                }
+                other => panic!("Expected SemanticError, got {:?}", other),
+            }
+        }
+    }
+}
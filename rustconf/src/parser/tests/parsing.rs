@@ -35,6 +35,37 @@ mod tests {
         assert!(module.imports.is_empty());
     }
 
+    #[test]
+    fn test_parse_module_metadata() {
+        let input = r#"
+            module example {
+                namespace "http://example.com/example";
+                prefix ex;
+
+                organization "Example Networks";
+                contact
+                  "Web:   <https://example.com/yang>
+                   Email: <mailto:yang@example.com>";
+                description
+                  "Interface configuration " +
+                  "for example devices.";
+                reference "RFC 8040";
+            }
+        "#;
+
+        let mut parser = YangParser::new();
+        let module = parser.parse_string(input, "test.yang").unwrap();
+
+        assert_eq!(module.organization.as_deref(), Some("Example Networks"));
+        let contact = module.contact.unwrap();
+        assert!(contact.contains("Web:   <https://example.com/yang>"));
+        assert!(contact.contains("Email: <mailto:yang@example.com>"));
+        assert_eq!(
+            module.description.as_deref(),
+            Some("Interface configuration for example devices.")
+        );
+    }
+
     #[test]
     fn test_parse_module_with_yang_version() {
         let input = r#"
@@ -57,6 +88,134 @@ mod tests {
         assert_eq!(module.prefix, "test");
     }
 
+    #[test]
+    fn test_parse_module_with_quoted_yang_version_1() {
+        let input = r#"
+            module test-module {
+                yang-version "1";
+                namespace "urn:test:module";
+                prefix test;
+            }
+        "#;
+
+        let module = YangParser::new().parse_string(input, "test.yang").unwrap();
+        assert_eq!(module.yang_version, Some(YangVersion::V1_0));
+    }
+
+    #[test]
+    fn test_parse_import_with_quoted_prefix() {
+        let input = r#"
+            module main {
+                namespace "urn:main";
+                prefix "main";
+
+                import ietf-yang-types { prefix "yang"; }
+            }
+        "#;
+
+        let module = YangParser::new().parse_string(input, "main.yang").unwrap();
+        assert_eq!(module.imports[0].prefix, "yang");
+    }
+
+    #[test]
+    fn test_parse_import_substatements() {
+        let input = r#"
+            module main {
+                namespace "urn:main";
+                prefix main;
+
+                import "ietf-yang-types" {
+                    prefix yang;
+                    revision-date 2013-07-15;
+                    description
+                      "Counters and "
+                    + "identifiers.";
+                    reference "RFC 6991";
+                }
+                import ietf-yang-types { prefix yt; }
+            }
+        "#;
+
+        let module = YangParser::new().parse_string(input, "main.yang").unwrap();
+        assert_eq!(module.imports.len(), 2);
+        let import = &module.imports[0];
+        assert_eq!(import.module, "ietf-yang-types");
+        assert_eq!(import.prefix, "yang");
+        assert_eq!(import.revision.as_deref(), Some("2013-07-15"));
+        assert_eq!(
+            import.description.as_deref(),
+            Some("Counters and identifiers.")
+        );
+        assert_eq!(import.reference.as_deref(), Some("RFC 6991"));
+        assert_eq!(module.imports[1].module, "ietf-yang-types");
+        assert_eq!(module.imports[1].prefix, "yt");
+    }
+
+    #[test]
+    fn test_reject_prefix_used_twice() {
+        for input in [
+            r#"module main {
+                namespace "urn:main";
+                prefix main;
+                import ietf-yang-types { prefix yang; }
+                import ietf-inet-types { prefix yang; }
+            }"#,
+            r#"module main {
+                namespace "urn:main";
+                prefix yang;
+                import ietf-yang-types { prefix yang; }
+            }"#,
+            r#"module main {
+                namespace "urn:main";
+                prefix main;
+                import ietf-yang-types { prefix yang; prefix yt; }
+            }"#,
+        ] {
+            let result = YangParser::new().parse_string(input, "main.yang");
+            assert!(
+                matches!(result, Err(ParseError::SyntaxError { .. })),
+                "{:?}",
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_leaves_named_after_keywords() {
+        let input = r#"
+            module routes {
+                namespace "urn:routes";
+                prefix rt;
+
+                list route {
+                    key "prefix";
+                    leaf prefix { type string; }
+                    leaf key { type string; }
+                    leaf units { type string; }
+                    leaf range { type string; }
+                    leaf length { type uint32; }
+                }
+            }
+        "#;
+
+        let module = YangParser::new()
+            .parse_string(input, "routes.yang")
+            .unwrap();
+        let crate::parser::DataNode::List(list) = &module.data_nodes[0] else {
+            panic!("expected a list");
+        };
+        assert_eq!(list.keys, vec!["prefix".to_string()]);
+        let names: Vec<_> = list
+            .children
+            .iter()
+            .map(|child| match child {
+                crate::parser::DataNode::Leaf(leaf) => leaf.name.as_str(),
+                other => panic!("unexpected node {:?}", other),
+            })
+            .collect();
+        assert_eq!(names, ["prefix", "key", "units", "range", "length"]);
+    }
+
     #[test]
     fn test_parse_module_with_import_statements() {
         let input = r#"
@@ -491,6 +650,69 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_element_bounds() {
+        let input = r#"
+            module test {
+                namespace "urn:test";
+                prefix test;
+
+                list server {
+                    key "address";
+                    min-elements 1;
+                    max-elements "3";
+                    leaf address { type string; }
+                }
+                leaf-list search {
+                    type string;
+                    max-elements unbounded;
+                }
+                leaf-list option {
+                    type string;
+                }
+            }
+        "#;
+
+        let module = YangParser::new().parse_string(input, "test.yang").unwrap();
+
+        let crate::parser::DataNode::List(server) = &module.data_nodes[0] else {
+            panic!("Expected List data node");
+        };
+        assert_eq!((server.min_elements, server.max_elements), (1, Some(3)));
+        let crate::parser::DataNode::LeafList(search) = &module.data_nodes[1] else {
+            panic!("Expected LeafList data node");
+        };
+        assert_eq!((search.min_elements, search.max_elements), (0, None));
+        let crate::parser::DataNode::LeafList(option) = &module.data_nodes[2] else {
+            panic!("Expected LeafList data node");
+        };
+        assert_eq!((option.min_elements, option.max_elements), (0, None));
+    }
+
+    #[test]
+    fn test_error_invalid_element_bounds() {
+        let module = |statements: &str| {
+            format!(
+                "module test {{ namespace \"urn:test\"; prefix test; leaf-list tag {{ type string; {} }} }}",
+                statements
+            )
+        };
+
+        for (statements, message) in [
+            ("max-elements 0;", "max-elements must be a positive integer"),
+            ("min-elements unbounded;", "Expected min-elements value"),
+            (
+                "min-elements 3; max-elements 2;",
+                "'tag' has min-elements 3 greater than max-elements 2",
+            ),
+        ] {
+            let err = YangParser::new()
+                .parse_string(&module(statements), "test.yang")
+                .unwrap_err();
+            assert!(err.to_string().contains(message), "{}: {}", statements, err);
+        }
+    }
+
     #[test]
     fn test_parse_choice_with_cases() {
         let input = r#"
@@ -2691,6 +2913,84 @@ mod tests {
         assert!(rpc.output.is_none());
     }
 
+    #[test]
+    fn test_parse_rpc_without_body() {
+        let input = r#"
+            module test-rpc {
+                namespace "urn:test:rpc";
+                prefix tr;
+
+                rpc reboot;
+
+                rpc ping {
+                    input {
+                        leaf host { type string; }
+                    }
+                }
+            }
+        "#;
+
+        let mut parser = YangParser::new();
+        let module = parser.parse_string(input, "test.yang").unwrap();
+
+        assert_eq!(module.rpcs.len(), 2);
+        assert_eq!(module.rpcs[0].name, "reboot");
+        assert!(module.rpcs[0].input.is_none());
+        assert!(module.rpcs[0].output.is_none());
+        assert_eq!(module.rpcs[1].name, "ping");
+        assert_eq!(module.rpcs[1].input.as_ref().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_parse_rpc_timeout_extension() {
+        let input = r#"
+            module test-rpc {
+                namespace "urn:test:rpc";
+                prefix tr;
+
+                import rustconf-extensions { prefix rc; }
+                import vendor-extensions { prefix v; }
+
+                rpc upgrade-firmware {
+                    rc:timeout "600";
+                }
+                rpc reboot {
+                    rc:timeout 120;
+                }
+                rpc ping {
+                    v:timeout "5";
+                }
+            }
+        "#;
+
+        let mut parser = YangParser::new();
+        let module = parser.parse_string(input, "test.yang").unwrap();
+
+        assert_eq!(module.rpcs[0].timeout, Some(600));
+        assert_eq!(module.rpcs[1].timeout, Some(120));
+        // Extensions of other modules are skipped
+        assert_eq!(module.rpcs[2].timeout, None);
+    }
+
+    #[test]
+    fn test_parse_rpc_timeout_extension_rejects_invalid_seconds() {
+        let input = r#"
+            module test-rpc {
+                namespace "urn:test:rpc";
+                prefix tr;
+
+                import rustconf-extensions { prefix rc; }
+
+                rpc upgrade-firmware {
+                    rc:timeout "ten minutes";
+                }
+            }
+        "#;
+
+        let mut parser = YangParser::new();
+        assert!(parser.parse_string(input, "test.yang").is_err());
+    }
+
     #[test]
     fn test_parse_multiple_rpcs() {
         let input = r#"
@@ -2811,6 +3111,31 @@ mod tests {
         assert_eq!(notification.data_nodes.len(), 0);
     }
 
+    #[test]
+    fn test_parse_notification_without_body() {
+        let input = r#"
+            module test-notif {
+                namespace "urn:test:notif";
+                prefix tn;
+
+                notification restarted;
+
+                notification link-down {
+                    leaf interface { type string; }
+                }
+            }
+        "#;
+
+        let mut parser = YangParser::new();
+        let module = parser.parse_string(input, "test.yang").unwrap();
+
+        assert_eq!(module.notifications.len(), 2);
+        assert_eq!(module.notifications[0].name, "restarted");
+        assert!(module.notifications[0].data_nodes.is_empty());
+        assert_eq!(module.notifications[1].name, "link-down");
+        assert_eq!(module.notifications[1].data_nodes.len(), 1);
+    }
+
     #[test]
     fn test_parse_notification_with_leaf_nodes() {
         let input = r#"
@@ -3031,4 +3356,243 @@ mod tests {
         assert_eq!(module.rpcs[0].name, "do-something");
         assert_eq!(module.notifications[0].name, "state-changed");
     }
+
+    #[test]
+    fn test_parse_when_conditions() {
+        use crate::parser::DataNode;
+
+        let mut parser = YangParser::new();
+        let module = parser
+            .parse_string(
+                r#"
+                module interfaces {
+                    namespace "urn:interfaces";
+                    prefix if;
+
+                    container interface {
+                        leaf type { type string; }
+                        container ethernet {
+                            when "type = 'ethernet'" {
+                                description "Only for Ethernet interfaces.";
+                            }
+                            leaf duplex { type string; }
+                        }
+                        choice encapsulation {
+                            case vlan {
+                                when "../type = " + "'l2vlan'";
+                                leaf vlan-id { type uint16; }
+                            }
+                        }
+                    }
+                }
+            "#,
+                "interfaces.yang",
+            )
+            .unwrap();
+
+        let DataNode::Container(interface) = &module.data_nodes[0] else {
+            panic!("expected container");
+        };
+        let DataNode::Leaf(leaf) = &interface.children[0] else {
+            panic!("expected leaf");
+        };
+        assert_eq!(leaf.when, None);
+        let DataNode::Container(ethernet) = &interface.children[1] else {
+            panic!("expected container");
+        };
+        assert_eq!(ethernet.when.as_deref(), Some("type = 'ethernet'"));
+        assert_eq!(ethernet.children.len(), 1);
+        let DataNode::Choice(choice) = &interface.children[2] else {
+            panic!("expected choice");
+        };
+        assert_eq!(choice.cases[0].when.as_deref(), Some("../type = 'l2vlan'"));
+    }
+
+    #[test]
+    fn test_parse_must_statements() {
+        use crate::parser::{DataNode, Must};
+
+        let mut parser = YangParser::new();
+        let module = parser
+            .parse_string(
+                r#"
+                module interfaces {
+                    namespace "urn:interfaces";
+                    prefix if;
+
+                    container interface {
+                        must "mtu >= 1280 or not(ipv6)" {
+                            error-message "IPv6 needs an MTU of at least 1280";
+                            error-app-tag "mtu-too-small";
+                            description "RFC 8200 minimum link MTU.";
+                        }
+                        leaf ipv6 { type boolean; }
+                        leaf mtu {
+                            type uint16;
+                            must ". >= 68";
+                        }
+                    }
+                }
+            "#,
+                "interfaces.yang",
+            )
+            .unwrap();
+
+        let DataNode::Container(interface) = &module.data_nodes[0] else {
+            panic!("expected container");
+        };
+        assert_eq!(
+            interface.must,
+            [Must {
+                condition: "mtu >= 1280 or not(ipv6)".to_string(),
+                error_message: Some("IPv6 needs an MTU of at least 1280".to_string()),
+                error_app_tag: Some("mtu-too-small".to_string()),
+            }]
+        );
+        let DataNode::Leaf(mtu) = &interface.children[1] else {
+            panic!("expected leaf");
+        };
+        assert_eq!(mtu.must.len(), 1);
+        assert_eq!(mtu.must[0].condition, ". >= 68");
+        assert_eq!(mtu.must[0].error_message, None);
+    }
+
+    #[test]
+    fn test_parse_extension_statements() {
+        use crate::parser::{DataNode, Extension};
+
+        let mut parser = YangParser::new();
+        let module = parser
+            .parse_string(
+                r#"
+                module devices {
+                    namespace "urn:devices";
+                    prefix dev;
+
+                    import tailf-common { prefix tailf; }
+                    import openconfig-extensions { prefix oc-ext; }
+                    import rustconf-extensions { prefix rc; }
+
+                    oc-ext:openconfig-version "1.2.0";
+
+                    extension hidden {
+                        argument "tag";
+                        description "Hide the node from the CLI.";
+                    }
+
+                    container device {
+                        tailf:info "Device settings" {
+                            tailf:cli-suppress-mode;
+                        }
+                        leaf hostname {
+                            type string;
+                            dev:hidden debug;
+                        }
+                    }
+
+                    rpc reboot {
+                        rc:timeout 300;
+                        tailf:actionpoint reboot-point;
+                    }
+                }
+            "#,
+                "devices.yang",
+            )
+            .unwrap();
+
+        assert_eq!(
+            module.extensions,
+            [Extension {
+                name: "hidden".to_string(),
+                argument: Some("tag".to_string()),
+                description: Some("Hide the node from the CLI.".to_string()),
+            }]
+        );
+        assert_eq!(module.unknown_statements.len(), 1);
+        let version = &module.unknown_statements[0];
+        assert_eq!(version.keyword(), "oc-ext:openconfig-version");
+        assert_eq!(version.module.as_deref(), Some("openconfig-extensions"));
+        assert_eq!(version.argument.as_deref(), Some("1.2.0"));
+
+        let DataNode::Container(device) = &module.data_nodes[0] else {
+            panic!("expected container");
+        };
+        let info = &device.unknown_statements[0];
+        assert_eq!(info.keyword(), "tailf:info");
+        assert_eq!(info.module.as_deref(), Some("tailf-common"));
+        assert_eq!(info.argument.as_deref(), Some("Device settings"));
+        assert_eq!(info.substatements.len(), 1);
+        assert_eq!(info.substatements[0].name, "cli-suppress-mode");
+        assert_eq!(info.substatements[0].argument, None);
+
+        let DataNode::Leaf(hostname) = &device.children[0] else {
+            panic!("expected leaf");
+        };
+        assert_eq!(
+            hostname.unknown_statements[0].module.as_deref(),
+            Some("devices")
+        );
+        assert_eq!(
+            hostname.unknown_statements[0].argument.as_deref(),
+            Some("debug")
+        );
+
+        // The timeout extension is still read into the RPC
+        let reboot = &module.rpcs[0];
+        assert_eq!(reboot.timeout, Some(300));
+        assert_eq!(reboot.unknown_statements.len(), 1);
+        assert_eq!(reboot.unknown_statements[0].keyword(), "tailf:actionpoint");
+    }
+
+    #[test]
+    fn test_parse_anydata_and_anyxml() {
+        use crate::parser::{AnyDataKind, DataNode};
+
+        let mut parser = YangParser::new();
+        let module = parser
+            .parse_string(
+                r#"
+                module events {
+                    namespace "urn:events";
+                    prefix ev;
+
+                    container log {
+                        anydata entry {
+                            description "Data of any module.";
+                            mandatory true;
+                            config false;
+                        }
+                        choice payload {
+                            anyxml raw;
+                        }
+                    }
+                }
+            "#,
+                "events.yang",
+            )
+            .unwrap();
+
+        let DataNode::Container(log) = &module.data_nodes[0] else {
+            panic!("expected container");
+        };
+        let DataNode::AnyData(entry) = &log.children[0] else {
+            panic!("expected anydata");
+        };
+        assert_eq!(entry.name, "entry");
+        assert_eq!(entry.kind, AnyDataKind::AnyData);
+        assert_eq!(entry.description.as_deref(), Some("Data of any module."));
+        assert!(entry.mandatory);
+        assert!(!entry.config);
+
+        // Shorthand cases wrap anyxml like other data nodes
+        let DataNode::Choice(payload) = &log.children[1] else {
+            panic!("expected choice");
+        };
+        assert_eq!(payload.cases[0].name, "raw");
+        let DataNode::AnyData(raw) = &payload.cases[0].data_nodes[0] else {
+            panic!("expected anyxml");
+        };
+        assert_eq!(raw.kind, AnyDataKind::AnyXml);
+        assert!(!raw.mandatory);
+    }
 }
@@ -0,0 +1,257 @@
+//! Unit tests for archive and glob search paths
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::{ParseError, YangParser};
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::fs;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    const MAIN: &str = r#"
+        module main {
+            namespace "urn:main";
+            prefix m;
+            import types {
+                prefix t;
+            }
+        }
+    "#;
+
+    fn types_module(revision: &str) -> String {
+        format!(
+            "module types {{ namespace \"urn:types\"; prefix t; revision {}; }}",
+            revision
+        )
+    }
+
+    fn main_importing(revision: &str) -> String {
+        format!(
+            "module main {{ namespace \"urn:main\"; prefix m; \
+             import types {{ prefix t; revision-date {}; }} }}",
+            revision
+        )
+    }
+
+    /// Write a gzip-compressed tarball containing the given files.
+    fn write_tarball(path: &Path, files: &[(&str, &str)]) {
+        let encoder = GzEncoder::new(fs::File::create(path).unwrap(), Compression::default());
+        let mut builder = tar::Builder::new(encoder);
+        for (name, content) in files {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(content.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder
+                .append_data(&mut header, name, content.as_bytes())
+                .unwrap();
+        }
+        builder.into_inner().unwrap().finish().unwrap();
+    }
+
+    #[test]
+    fn test_resolve_imports_from_tarball() {
+        let temp_dir = TempDir::new().unwrap();
+        let archive = temp_dir.path().join("vendor-models.tar.gz");
+        let types = types_module("2021-01-01");
+        write_tarball(
+            &archive,
+            &[
+                ("vendor/README.md", "not a module"),
+                ("vendor/yang/types@2021-01-01.yang", &types),
+            ],
+        );
+
+        let mut parser = YangParser::new();
+        parser.add_search_path(archive.clone());
+        parser.parse_string(MAIN, "main.yang").unwrap();
+
+        let source = parser.module_source("types").unwrap();
+        assert_eq!(
+            source.path,
+            archive.join("vendor/yang/types@2021-01-01.yang")
+        );
+        assert_eq!(source.search_path.as_deref(), Some(archive.as_path()));
+        assert_eq!(source.revision.as_deref(), Some("2021-01-01"));
+        // Nothing is extracted next to the archive
+        assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_import_revision_selects_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let old = types_module("2019-01-01");
+        let new = types_module("2022-01-01");
+        let archive = temp_dir.path().join("models.tgz");
+        write_tarball(
+            &archive,
+            &[
+                ("types@2019-01-01.yang", &old),
+                ("types@2022-01-01.yang", &new),
+            ],
+        );
+
+        // Without a revision-date the latest revision is used
+        let mut parser = YangParser::new();
+        parser.add_search_path(archive.clone());
+        parser.parse_string(MAIN, "main.yang").unwrap();
+        let source = parser.module_source("types").unwrap();
+        assert_eq!(source.revision.as_deref(), Some("2022-01-01"));
+
+        let mut parser = YangParser::new();
+        parser.add_search_path(archive);
+        parser
+            .parse_string(
+                r#"
+                module main {
+                    namespace "urn:main";
+                    prefix m;
+                    import types {
+                        prefix t;
+                        revision-date 2019-01-01;
+                    }
+                }
+            "#,
+                "main.yang",
+            )
+            .unwrap();
+        let source = parser.module_source("types").unwrap();
+        assert_eq!(source.revision.as_deref(), Some("2019-01-01"));
+    }
+
+    #[test]
+    fn test_newest_revision_across_search_paths() {
+        let temp_dir = TempDir::new().unwrap();
+        let first = temp_dir.path().join("first");
+        let second = temp_dir.path().join("second");
+        fs::create_dir_all(&first).unwrap();
+        fs::create_dir_all(&second).unwrap();
+        // The revision of a file without one in its name is read from it
+        fs::write(first.join("types.yang"), types_module("2020-01-01")).unwrap();
+        fs::write(
+            second.join("types@2023-01-01.yang"),
+            types_module("2023-01-01"),
+        )
+        .unwrap();
+        fs::write(
+            first.join("types@2018-01-01.yang"),
+            types_module("2018-01-01"),
+        )
+        .unwrap();
+
+        let mut parser = YangParser::new();
+        parser.add_search_path(first.clone());
+        parser.add_search_path(second.clone());
+        parser.parse_string(MAIN, "main.yang").unwrap();
+
+        let source = parser.module_source("types").unwrap();
+        assert_eq!(source.path, second.join("types@2023-01-01.yang"));
+        assert_eq!(source.revision.as_deref(), Some("2023-01-01"));
+        assert_eq!(
+            source.available_revisions,
+            vec!["2023-01-01", "2020-01-01", "2018-01-01"]
+        );
+
+        // A revision-date can be met by the file without a revision in its name
+        let mut parser = YangParser::new();
+        parser.add_search_path(first.clone());
+        parser.add_search_path(second);
+        parser
+            .parse_string(&main_importing("2020-01-01"), "main.yang")
+            .unwrap();
+        let source = parser.module_source("types").unwrap();
+        assert_eq!(source.path, first.join("types.yang"));
+    }
+
+    #[test]
+    fn test_missing_import_revision_loads_newest() {
+        let temp_dir = TempDir::new().unwrap();
+        for revision in ["2021-01-01", "2023-01-01"] {
+            fs::write(
+                temp_dir.path().join(format!("types@{}.yang", revision)),
+                types_module(revision),
+            )
+            .unwrap();
+        }
+
+        let mut parser = YangParser::new();
+        parser.add_search_path(temp_dir.path().to_path_buf());
+        parser
+            .parse_string(&main_importing("2019-01-01"), "main.yang")
+            .unwrap();
+
+        let source = parser.module_source("types").unwrap();
+        assert_eq!(source.revision.as_deref(), Some("2023-01-01"));
+    }
+
+    #[test]
+    fn test_glob_search_path() {
+        let temp_dir = TempDir::new().unwrap();
+        for vendor in ["acme", "globex"] {
+            fs::create_dir_all(temp_dir.path().join(vendor).join("yang")).unwrap();
+        }
+        fs::write(
+            temp_dir.path().join("globex/yang/types.yang"),
+            types_module("2020-01-01"),
+        )
+        .unwrap();
+        write_tarball(
+            &temp_dir.path().join("bundle.tar.gz"),
+            &[(
+                "extra.yang",
+                "module extra { namespace \"urn:extra\"; prefix e; }",
+            )],
+        );
+
+        let mut parser = YangParser::new();
+        parser.add_search_path(temp_dir.path().join("*/yang"));
+        parser.add_search_path(temp_dir.path().join("*.tar.gz"));
+        parser
+            .parse_string(
+                r#"
+                module main {
+                    namespace "urn:main";
+                    prefix m;
+                    import types { prefix t; }
+                    import extra { prefix e; }
+                }
+            "#,
+                "main.yang",
+            )
+            .unwrap();
+
+        assert_eq!(
+            parser.module_source("types").unwrap().search_path,
+            Some(temp_dir.path().join("globex/yang"))
+        );
+        assert!(parser.module_source("extra").is_some());
+    }
+
+    #[test]
+    fn test_unreadable_archive_is_reported() {
+        let temp_dir = TempDir::new().unwrap();
+        let archive = temp_dir.path().join("broken.tar.gz");
+        fs::write(&archive, "not gzip").unwrap();
+
+        let mut parser = YangParser::new();
+        parser.add_search_path(archive);
+        let result = parser.parse_string(
+            r#"
+            module main {
+                namespace "urn:main";
+                prefix m;
+                include main-sub;
+            }
+        "#,
+            "main.yang",
+        );
+
+        match result {
+            Err(ParseError::IoError(e)) => {
+                assert!(e.to_string().contains("broken.tar.gz"), "{}", e);
+            }
+            other => panic!("Expected I/O error, got {:?}", other),
+        }
+    }
+}
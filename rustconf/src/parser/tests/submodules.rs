@@ -0,0 +1,397 @@
+//! Unit tests for submodule includes and prefix resolution
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::{DataNode, ParseError, TypeSpec, YangParser};
+    use std::fs;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    fn write(dir: &TempDir, name: &str, content: &str) {
+        fs::write(dir.path().join(format!("{}.yang", name)), content).unwrap();
+    }
+
+    #[test]
+    fn test_include_merges_submodule_definitions() {
+        let temp_dir = TempDir::new().unwrap();
+        write(
+            &temp_dir,
+            "main-types",
+            r#"
+            submodule main-types {
+                belongs-to main {
+                    prefix m;
+                }
+
+                typedef percent {
+                    type uint8;
+                }
+
+                container stats {
+                    leaf usage {
+                        type m:percent;
+                    }
+                }
+            }
+        "#,
+        );
+
+        let mut parser = YangParser::new();
+        parser.add_search_path(temp_dir.path().to_path_buf());
+
+        let mut module = parser
+            .parse_string(
+                r#"
+                module main {
+                    namespace "urn:main";
+                    prefix m;
+                    include main-types;
+
+                    leaf load {
+                        type m:percent;
+                    }
+                }
+            "#,
+                "main.yang",
+            )
+            .unwrap();
+
+        assert_eq!(module.typedefs.len(), 1);
+        assert_eq!(module.data_nodes.len(), 2);
+
+        // Local prefixes are stripped so references resolve by plain name
+        if let DataNode::Leaf(leaf) = &module.data_nodes[0] {
+            assert_eq!(leaf.type_spec, TypeSpec::typedef_ref("percent"));
+        } else {
+            panic!("Expected Leaf data node");
+        }
+
+        parser.validate_module(&module).unwrap();
+        parser.expand_module(&mut module).unwrap();
+
+        if let DataNode::Container(container) = &module.data_nodes[1] {
+            if let DataNode::Leaf(leaf) = &container.children[0] {
+                assert!(matches!(leaf.type_spec, TypeSpec::Uint8 { .. }));
+            } else {
+                panic!("Expected Leaf child");
+            }
+        } else {
+            panic!("Expected Container data node");
+        }
+    }
+
+    #[test]
+    fn test_submodule_belongs_to_prefix_differs_from_module_prefix() {
+        let temp_dir = TempDir::new().unwrap();
+        write(
+            &temp_dir,
+            "main-groupings",
+            r#"
+            submodule main-groupings {
+                belongs-to main {
+                    prefix parent;
+                }
+
+                grouping endpoint {
+                    leaf port {
+                        type parent:port-number;
+                    }
+                }
+            }
+        "#,
+        );
+
+        let mut parser = YangParser::new();
+        parser.add_search_path(temp_dir.path().to_path_buf());
+
+        let mut module = parser
+            .parse_string(
+                r#"
+                module main {
+                    namespace "urn:main";
+                    prefix m;
+                    include main-groupings;
+
+                    typedef port-number {
+                        type uint16;
+                    }
+
+                    container server {
+                        uses m:endpoint;
+                    }
+                }
+            "#,
+                "main.yang",
+            )
+            .unwrap();
+
+        parser.validate_module(&module).unwrap();
+        parser.expand_module(&mut module).unwrap();
+
+        if let DataNode::Container(container) = &module.data_nodes[0] {
+            assert_eq!(container.children.len(), 1);
+            if let DataNode::Leaf(leaf) = &container.children[0] {
+                assert_eq!(leaf.name, "port");
+                assert!(matches!(leaf.type_spec, TypeSpec::Uint16 { .. }));
+            } else {
+                panic!("Expected Leaf child");
+            }
+        } else {
+            panic!("Expected Container data node");
+        }
+    }
+
+    #[test]
+    fn test_submodule_imports_are_requalified_with_module_prefix() {
+        let temp_dir = TempDir::new().unwrap();
+        write(
+            &temp_dir,
+            "common-types",
+            r#"
+            module common-types {
+                namespace "urn:common";
+                prefix ct;
+
+                typedef name {
+                    type string;
+                }
+            }
+        "#,
+        );
+        write(
+            &temp_dir,
+            "main-sub",
+            r#"
+            submodule main-sub {
+                belongs-to main {
+                    prefix m;
+                }
+                import common-types {
+                    prefix types;
+                }
+
+                leaf hostname {
+                    type types:name;
+                }
+            }
+        "#,
+        );
+
+        let mut parser = YangParser::new();
+        parser.add_search_path(temp_dir.path().to_path_buf());
+
+        let mut module = parser
+            .parse_string(
+                r#"
+                module main {
+                    namespace "urn:main";
+                    prefix m;
+                    import common-types {
+                        prefix ct;
+                    }
+                    include main-sub;
+                }
+            "#,
+                "main.yang",
+            )
+            .unwrap();
+
+        // The submodule's `types` prefix is rewritten to the module's `ct`
+        if let DataNode::Leaf(leaf) = &module.data_nodes[0] {
+            assert_eq!(leaf.type_spec, TypeSpec::typedef_ref("ct:name"));
+        } else {
+            panic!("Expected Leaf data node");
+        }
+        assert_eq!(module.imports.len(), 1);
+
+        parser.validate_module(&module).unwrap();
+        parser.expand_module(&mut module).unwrap();
+
+        if let DataNode::Leaf(leaf) = &module.data_nodes[0] {
+            assert!(matches!(leaf.type_spec, TypeSpec::String { .. }));
+        }
+    }
+
+    #[test]
+    fn test_missing_submodule_is_an_error() {
+        let mut parser = YangParser::new();
+        let result = parser.parse_string(
+            r#"
+            module main {
+                namespace "urn:main";
+                prefix m;
+                include missing-sub;
+            }
+        "#,
+            "main.yang",
+        );
+
+        assert!(matches!(
+            result,
+            Err(ParseError::UnresolvedInclude { submodule }) if submodule == "missing-sub"
+        ));
+    }
+
+    #[test]
+    fn test_submodule_belonging_to_other_module_is_rejected() {
+        let temp_dir = TempDir::new().unwrap();
+        write(
+            &temp_dir,
+            "other-sub",
+            r#"
+            submodule other-sub {
+                belongs-to other {
+                    prefix o;
+                }
+            }
+        "#,
+        );
+
+        let mut parser = YangParser::new();
+        parser.add_search_path(temp_dir.path().to_path_buf());
+
+        let result = parser.parse_string(
+            r#"
+            module main {
+                namespace "urn:main";
+                prefix m;
+                include other-sub;
+            }
+        "#,
+            "main.yang",
+        );
+
+        assert!(matches!(result, Err(ParseError::SemanticError { .. })));
+    }
+
+    #[test]
+    fn test_unknown_prefix_is_rejected() {
+        let mut parser = YangParser::new();
+        let result = parser.parse_string(
+            r#"
+            module main {
+                namespace "urn:main";
+                prefix m;
+
+                leaf name {
+                    type nope:name;
+                }
+            }
+        "#,
+            "main.yang",
+        );
+
+        match result {
+            Err(ParseError::SemanticError { message }) => {
+                assert!(message.contains("Unknown prefix 'nope'"), "{}", message);
+            }
+            other => panic!("Expected semantic error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_module_source_records_search_path_revision_and_includes() {
+        let temp_dir = TempDir::new().unwrap();
+        write(
+            &temp_dir,
+            "types",
+            r#"
+            module types {
+                namespace "urn:types";
+                prefix t;
+                include types-sub;
+                revision 2020-05-05;
+                revision "2022-01-10" {
+                    description "Newer";
+                }
+            }
+        "#,
+        );
+        write(
+            &temp_dir,
+            "types-sub",
+            r#"
+            submodule types-sub {
+                belongs-to types {
+                    prefix t;
+                }
+                revision 2021-03-03;
+            }
+        "#,
+        );
+
+        let mut parser = YangParser::new();
+        parser.add_search_path(temp_dir.path().to_path_buf());
+
+        let module = parser
+            .parse_string(
+                r#"
+                module main {
+                    namespace "urn:main";
+                    prefix m;
+                    import types {
+                        prefix t;
+                        revision-date 2019-01-01;
+                    }
+                }
+            "#,
+                "main.yang",
+            )
+            .unwrap();
+
+        assert_eq!(module.imports[0].revision.as_deref(), Some("2019-01-01"));
+
+        let main = parser.module_source("main").unwrap();
+        assert_eq!(main.path, PathBuf::from("main.yang"));
+        assert_eq!(main.search_path, None);
+        assert_eq!(main.revision, None);
+
+        let types = parser.module_source("types").unwrap();
+        assert_eq!(types.path, temp_dir.path().join("types.yang"));
+        assert_eq!(types.search_path.as_deref(), Some(temp_dir.path()));
+        assert_eq!(types.revision.as_deref(), Some("2022-01-10"));
+        assert_eq!(types.includes, vec!["types-sub".to_string()]);
+
+        let submodule = parser.module_source("types-sub").unwrap();
+        assert_eq!(submodule.path, temp_dir.path().join("types-sub.yang"));
+        assert_eq!(submodule.revision.as_deref(), Some("2021-03-03"));
+    }
+
+    #[test]
+    fn test_unresolved_import_does_not_stop_other_imports() {
+        let temp_dir = TempDir::new().unwrap();
+        write(
+            &temp_dir,
+            "types",
+            r#"
+            module types {
+                namespace "urn:types";
+                prefix t;
+            }
+        "#,
+        );
+
+        let mut parser = YangParser::new();
+        parser.add_search_path(temp_dir.path().to_path_buf());
+
+        let result = parser.parse_string(
+            r#"
+            module main {
+                namespace "urn:main";
+                prefix m;
+                import missing {
+                    prefix x;
+                }
+                import types {
+                    prefix t;
+                }
+            }
+        "#,
+            "main.yang",
+        );
+
+        assert!(result.is_ok());
+        assert!(parser.get_loaded_module("types").is_some());
+        assert!(parser.module_source("missing").is_none());
+    }
+}
@@ -4,7 +4,7 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::parser::{ParseError, YangParser};
+    use crate::parser::{DataNode, ParseError, Range, RangeConstraint, YangParser};
 
     // ========== Undefined Reference Tests ==========
 
@@ -697,4 +697,493 @@ mod tests {
             validation_result.err()
         );
     }
+
+    // ========== Enumeration Tests ==========
+
+    fn enum_module(enums: &str) -> String {
+        format!(
+            r#"
+            module test {{
+                namespace "urn:test";
+                prefix test;
+
+                leaf state {{
+                    type enumeration {{
+                        {}
+                    }}
+                }}
+            }}
+        "#,
+            enums
+        )
+    }
+
+    fn enum_values(module: &crate::parser::YangModule) -> Vec<(String, Option<i32>)> {
+        match &module.data_nodes[0] {
+            crate::parser::DataNode::Leaf(leaf) => match &leaf.type_spec {
+                crate::parser::TypeSpec::Enumeration { values } => {
+                    values.iter().map(|v| (v.name.clone(), v.value)).collect()
+                }
+                other => panic!("Expected Enumeration type, got {:?}", other),
+            },
+            other => panic!("Expected Leaf data node, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_enum_values_are_auto_assigned() {
+        let input = enum_module(
+            "enum zero; enum ten { value 10; } enum eleven; enum minus { value -5; } enum twelve;",
+        );
+
+        let mut parser = YangParser::new();
+        let module = parser.parse_string(&input, "test.yang").unwrap();
+
+        assert_eq!(
+            enum_values(&module),
+            vec![
+                ("zero".to_string(), Some(0)),
+                ("ten".to_string(), Some(10)),
+                ("eleven".to_string(), Some(11)),
+                ("minus".to_string(), Some(-5)),
+                ("twelve".to_string(), Some(12)),
+            ]
+        );
+        assert!(parser.validate_module(&module).is_ok());
+    }
+
+    #[test]
+    fn test_detect_duplicate_enum_value() {
+        // "b" is implicitly assigned 1, which "c" also claims explicitly
+        let input = enum_module("enum a { value 0; } enum b; enum c { value 1; }");
+
+        let mut parser = YangParser::new();
+        let module = parser.parse_string(&input, "test.yang").unwrap();
+
+        match parser.validate_module(&module).unwrap_err() {
+            ParseError::SemanticError { message } => {
+                assert!(message.contains("Duplicate enum value 1"));
+                assert!(message.contains("'b'"));
+                assert!(message.contains("'c'"));
+            }
+            other => panic!("Expected SemanticError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_detect_duplicate_enum_name() {
+        let input = enum_module("enum up; enum up;");
+
+        let mut parser = YangParser::new();
+        let module = parser.parse_string(&input, "test.yang").unwrap();
+
+        match parser.validate_module(&module).unwrap_err() {
+            ParseError::SemanticError { message } => {
+                assert!(message.contains("Duplicate enum name 'up'"));
+            }
+            other => panic!("Expected SemanticError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_enum_implicit_value_overflow_is_rejected() {
+        let input = enum_module("enum max { value 2147483647; } enum next;");
+
+        let mut parser = YangParser::new();
+        let result = parser.parse_string(&input, "test.yang");
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Enum 'next' needs an explicit value"));
+
+        let input = enum_module("enum big { value 2147483648; }");
+        assert!(parser.parse_string(&input, "test.yang").is_err());
+    }
+
+    fn bits_module(bits: &str) -> String {
+        format!(
+            r#"
+            module test {{
+                namespace "urn:test";
+                prefix test;
+
+                leaf flags {{
+                    type bits {{
+                        {}
+                    }}
+                }}
+            }}
+        "#,
+            bits
+        )
+    }
+
+    fn bit_positions(module: &crate::parser::YangModule) -> Vec<(String, Option<u32>)> {
+        match &module.data_nodes[0] {
+            DataNode::Leaf(leaf) => match &leaf.type_spec {
+                crate::parser::TypeSpec::Bits { bits } => bits
+                    .iter()
+                    .map(|bit| (bit.name.clone(), bit.position))
+                    .collect(),
+                other => panic!("Expected Bits type, got {:?}", other),
+            },
+            other => panic!("Expected Leaf data node, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_bit_positions_are_auto_assigned() {
+        let input = bits_module(
+            "bit up; bit running { position 4; description \"Running\"; } bit dormant; bit low { position 2; } bit testing;",
+        );
+
+        let mut parser = YangParser::new();
+        let module = parser.parse_string(&input, "test.yang").unwrap();
+
+        assert_eq!(
+            bit_positions(&module),
+            vec![
+                ("up".to_string(), Some(0)),
+                ("running".to_string(), Some(4)),
+                ("dormant".to_string(), Some(5)),
+                ("low".to_string(), Some(2)),
+                ("testing".to_string(), Some(6)),
+            ]
+        );
+        assert!(parser.validate_module(&module).is_ok());
+    }
+
+    #[test]
+    fn test_detect_duplicate_bit_position() {
+        // "b" is implicitly assigned 1, which "c" also claims explicitly
+        let input = bits_module("bit a; bit b; bit c { position 1; }");
+
+        let mut parser = YangParser::new();
+        let module = parser.parse_string(&input, "test.yang").unwrap();
+
+        match parser.validate_module(&module).unwrap_err() {
+            ParseError::SemanticError { message } => {
+                assert!(message.contains("Duplicate bit position 1"));
+                assert!(message.contains("'b'"));
+                assert!(message.contains("'c'"));
+            }
+            other => panic!("Expected SemanticError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_detect_duplicate_bit_name() {
+        let input = bits_module("bit up; bit up;");
+
+        let mut parser = YangParser::new();
+        let module = parser.parse_string(&input, "test.yang").unwrap();
+
+        match parser.validate_module(&module).unwrap_err() {
+            ParseError::SemanticError { message } => {
+                assert!(message.contains("Duplicate bit name 'up'"));
+            }
+            other => panic!("Expected SemanticError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_bit_position_out_of_range_is_rejected() {
+        let mut parser = YangParser::new();
+        let input = bits_module("bit max { position 4294967295; } bit next;");
+        let result = parser.parse_string(&input, "test.yang");
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Bit 'next' needs an explicit position"));
+
+        let input = bits_module("bit negative { position -1; }");
+        assert!(parser.parse_string(&input, "test.yang").is_err());
+    }
+
+    fn decimal64_module(body: &str) -> String {
+        format!(
+            r#"
+            module test {{
+                namespace "urn:test";
+                prefix test;
+
+                typedef celsius {{
+                    type decimal64 {{
+                        fraction-digits 1;
+                        range "-40..125.5";
+                    }}
+                }}
+
+                leaf value {{
+                    type {}
+                }}
+            }}
+        "#,
+            body
+        )
+    }
+
+    fn leaf_type(module: &crate::parser::YangModule) -> &crate::parser::TypeSpec {
+        match &module.data_nodes[0] {
+            DataNode::Leaf(leaf) => &leaf.type_spec,
+            other => panic!("Expected Leaf data node, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_decimal64_range_is_scaled_by_fraction_digits() {
+        // The range may come before the fraction digits
+        let input = decimal64_module("decimal64 { range \"-1.5..2 | 10.25\"; fraction-digits 2; }");
+
+        let mut parser = YangParser::new();
+        let module = parser.parse_string(&input, "test.yang").unwrap();
+
+        assert_eq!(
+            leaf_type(&module),
+            &crate::parser::TypeSpec::Decimal64 {
+                fraction_digits: 2,
+                range: Some(RangeConstraint::new(vec![
+                    Range::new(-150, 200),
+                    Range::new(1025, 1025),
+                ])),
+            }
+        );
+        assert!(parser.validate_module(&module).is_ok());
+    }
+
+    #[test]
+    fn test_decimal64_derived_range_is_scaled() {
+        let input = decimal64_module("celsius { range \"0..100\"; }");
+
+        let mut parser = YangParser::new();
+        let mut module = parser.parse_string(&input, "test.yang").unwrap();
+        parser.expand_module(&mut module).unwrap();
+
+        assert_eq!(
+            leaf_type(&module),
+            &crate::parser::TypeSpec::Decimal64 {
+                fraction_digits: 1,
+                range: Some(RangeConstraint::new(vec![Range::new(0, 1000)])),
+            }
+        );
+    }
+
+    #[test]
+    fn test_decimal64_requires_valid_fraction_digits() {
+        let mut parser = YangParser::new();
+        for (body, message) in [
+            ("decimal64;", "requires a fraction-digits statement"),
+            (
+                "decimal64 { range \"1..2\"; }",
+                "requires a fraction-digits statement",
+            ),
+            ("decimal64 { fraction-digits 19; }", "from 1 to 18"),
+            ("decimal64 { fraction-digits 0; }", "from 1 to 18"),
+            (
+                "decimal64 { fraction-digits 1; range \"0.25..1\"; }",
+                "Invalid decimal64 range value with 1 fraction digits: 0.25",
+            ),
+        ] {
+            let input = decimal64_module(body);
+            let error = parser.parse_string(&input, "test.yang").unwrap_err();
+            assert!(error.to_string().contains(message), "{}: {}", body, error);
+        }
+    }
+
+    // ========== Config and Status Inheritance Tests ==========
+
+    #[test]
+    fn test_config_true_under_config_false_is_rejected() {
+        let input = r#"
+            module test {
+                namespace "urn:test";
+                prefix test;
+
+                container state {
+                    config false;
+                    container counters {
+                        leaf resets {
+                            config true;
+                            type uint32;
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let mut parser = YangParser::new();
+        let error = parser.parse_string(input, "test.yang").unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("'resets' cannot be config true, as its ancestor 'state' is config false"));
+    }
+
+    #[test]
+    fn test_config_false_is_inherited_after_expansion() {
+        let input = r#"
+            module test {
+                namespace "urn:test";
+                prefix test;
+
+                grouping counters {
+                    leaf in-octets {
+                        type uint64;
+                    }
+                }
+
+                container interface {
+                    leaf name {
+                        type string;
+                    }
+                    container state {
+                        config false;
+                        uses counters;
+                        list errors {
+                            key "code";
+                            leaf code {
+                                type uint32;
+                            }
+                        }
+                    }
+                }
+
+                augment "/test:interface/test:state" {
+                    leaf oper-status {
+                        type string;
+                    }
+                }
+            }
+        "#;
+
+        let mut parser = YangParser::new();
+        let mut module = parser.parse_string(input, "test.yang").unwrap();
+        parser.expand_groupings(&mut module).unwrap();
+        parser
+            .apply_augments(std::slice::from_mut(&mut module))
+            .unwrap();
+
+        let DataNode::Container(interface) = &module.data_nodes[0] else {
+            panic!("Expected Container data node");
+        };
+        let DataNode::Leaf(name) = &interface.children[0] else {
+            panic!("Expected Leaf data node");
+        };
+        assert!(name.config);
+        let DataNode::Container(state) = &interface.children[1] else {
+            panic!("Expected Container data node");
+        };
+        for child in &state.children {
+            let config = match child {
+                DataNode::Leaf(leaf) => leaf.config,
+                DataNode::List(list) => {
+                    assert!(list.children.iter().all(|key| matches!(
+                        key,
+                        DataNode::Leaf(leaf) if !leaf.config
+                    )));
+                    list.config
+                }
+                other => panic!("Unexpected node {:?}", other),
+            };
+            assert!(!config, "{} should be config false", child.name());
+        }
+        assert_eq!(state.children.len(), 3);
+    }
+
+    fn status_module(typedef_status: &str, leaf_status: &str) -> String {
+        format!(
+            r#"
+            module test {{
+                namespace "urn:test";
+                prefix test;
+
+                typedef old-mtu {{
+                    status {};
+                    type uint16;
+                }}
+
+                container interface {{
+                    leaf mtu {{
+                        status {};
+                        type test:old-mtu;
+                    }}
+                }}
+            }}
+        "#,
+            typedef_status, leaf_status
+        )
+    }
+
+    #[test]
+    fn test_current_node_using_obsolete_typedef_is_rejected() {
+        let mut parser = YangParser::new();
+        let module = parser
+            .parse_string(&status_module("obsolete", "current"), "test.yang")
+            .unwrap();
+
+        match parser.validate_module(&module).unwrap_err() {
+            ParseError::SemanticError { message } => assert_eq!(
+                message,
+                "current leaf 'mtu' in module 'test' references obsolete typedef 'old-mtu'"
+            ),
+            other => panic!("Expected SemanticError, got {:?}", other),
+        }
+
+        // Obsolete nodes may use obsolete definitions
+        let module = parser
+            .parse_string(&status_module("obsolete", "obsolete"), "test.yang")
+            .unwrap();
+        assert!(parser.validate_module(&module).is_ok());
+    }
+
+    #[test]
+    fn test_current_node_using_deprecated_definition_warns() {
+        let mut parser = YangParser::new();
+        let module = parser
+            .parse_string(&status_module("deprecated", "current"), "test.yang")
+            .unwrap();
+
+        let warnings = parser.check_status(&module).unwrap();
+        assert_eq!(
+            warnings,
+            ["current leaf 'mtu' in module 'test' references deprecated typedef 'old-mtu'"]
+        );
+        assert!(parser.validate_module(&module).is_ok());
+    }
+
+    #[test]
+    fn test_status_is_inherited_from_ancestors() {
+        let input = r#"
+            module test {
+                namespace "urn:test";
+                prefix test;
+
+                grouping legacy-counters {
+                    status obsolete;
+                    leaf drops {
+                        type uint32;
+                    }
+                }
+
+                container legacy {
+                    status obsolete;
+                    container counters {
+                        uses legacy-counters;
+                    }
+                }
+
+                container current {
+                    uses legacy-counters;
+                }
+            }
+        "#;
+
+        let mut parser = YangParser::new();
+        let module = parser.parse_string(input, "test.yang").unwrap();
+
+        let error = parser.check_status(&module).unwrap_err();
+        assert!(error.to_string().contains(
+            "current container 'current' in module 'test' references obsolete grouping 'legacy-counters'"
+        ));
+    }
 }
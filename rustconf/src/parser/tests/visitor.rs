@@ -6,7 +6,7 @@
 mod tests {
     use crate::parser::{
         walk_data_node, walk_data_nodes, Case, Choice, Container, DataNode, DataNodeVisitor, Leaf,
-        LeafList, List, TypeSpec, Uses,
+        LeafList, List, Status, TypeSpec, Uses,
     };
 
     // ========== Test Visitor Implementations ==========
@@ -199,7 +199,13 @@ mod tests {
             },
             mandatory: false,
             default: None,
+            units: None,
             config: true,
+            if_features: Vec::new(),
+            when: None,
+            must: Vec::new(),
+            unknown_statements: vec![],
+            status: Status::Current,
         }
     }
 
@@ -212,6 +218,13 @@ mod tests {
                 pattern: None,
             },
             config: true,
+            if_features: Vec::new(),
+            when: None,
+            must: Vec::new(),
+            min_elements: 0,
+            max_elements: None,
+            unknown_statements: vec![],
+            status: Status::Current,
         }
     }
 
@@ -222,6 +235,11 @@ mod tests {
             config: true,
             mandatory: false,
             children,
+            if_features: Vec::new(),
+            when: None,
+            must: Vec::new(),
+            unknown_statements: vec![],
+            status: Status::Current,
         }
     }
 
@@ -232,6 +250,13 @@ mod tests {
             config: true,
             keys: vec!["id".to_string()],
             children,
+            if_features: Vec::new(),
+            when: None,
+            must: Vec::new(),
+            min_elements: 0,
+            max_elements: None,
+            unknown_statements: vec![],
+            status: Status::Current,
         }
     }
 
@@ -335,6 +360,10 @@ mod tests {
             name: "case1".to_string(),
             description: None,
             data_nodes: vec![DataNode::Leaf(create_test_leaf("case1-leaf"))],
+            if_features: Vec::new(),
+            when: None,
+            unknown_statements: vec![],
+            status: Status::Current,
         };
 
         let case2 = Case {
@@ -344,6 +373,10 @@ mod tests {
                 DataNode::Leaf(create_test_leaf("case2-leaf1")),
                 DataNode::Leaf(create_test_leaf("case2-leaf2")),
             ],
+            if_features: Vec::new(),
+            when: None,
+            unknown_statements: vec![],
+            status: Status::Current,
         };
 
         let choice = Choice {
@@ -351,6 +384,10 @@ mod tests {
             description: None,
             mandatory: false,
             cases: vec![case1, case2],
+            if_features: Vec::new(),
+            when: None,
+            unknown_statements: vec![],
+            status: Status::Current,
         };
 
         let node = DataNode::Choice(choice);
@@ -369,6 +406,8 @@ mod tests {
         let uses = Uses {
             name: "test-grouping".to_string(),
             description: None,
+            if_features: Vec::new(),
+            augments: Vec::new(),
         };
         let node = DataNode::Uses(uses);
 
@@ -489,13 +528,25 @@ mod tests {
                     name: "tcp".to_string(),
                     description: None,
                     data_nodes: vec![DataNode::Leaf(create_test_leaf("tcp-port"))],
+                    if_features: Vec::new(),
+                    when: None,
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 },
                 Case {
                     name: "udp".to_string(),
                     description: None,
                     data_nodes: vec![DataNode::Leaf(create_test_leaf("udp-port"))],
+                    if_features: Vec::new(),
+                    when: None,
+                    unknown_statements: vec![],
+                    status: Status::Current,
                 },
             ],
+            if_features: Vec::new(),
+            when: None,
+            unknown_statements: vec![],
+            status: Status::Current,
         };
 
         let outer_container = create_test_container(
@@ -0,0 +1,476 @@
+//! In-memory RESTCONF datastore.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use serde_json::{Map, Value};
+
+use crate::parser::{DataNode, YangModule};
+
+/// Errors returned by [`MemoryDatastore`] operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DatastoreError {
+    /// The target resource (or one of its ancestors) does not exist.
+    NotFound(String),
+    /// The resource being created already exists.
+    Conflict(String),
+    /// The request path or body is malformed.
+    BadRequest(String),
+}
+
+impl fmt::Display for DatastoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DatastoreError::NotFound(path) => write!(f, "Resource not found: {}", path),
+            DatastoreError::Conflict(path) => write!(f, "Resource already exists: {}", path),
+            DatastoreError::BadRequest(message) => write!(f, "Bad request: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for DatastoreError {}
+
+/// A JSON data tree addressed by RESTCONF data resource paths.
+///
+/// Paths are the part of the URL after `/restconf/data/`, for example
+/// `example:interfaces/interface=eth0/mtu`. List entries are matched using
+/// the key leaves declared in the schema.
+#[derive(Debug, Clone)]
+pub struct MemoryDatastore {
+    root: Map<String, Value>,
+    list_keys: HashMap<String, Vec<String>>,
+}
+
+/// One parsed segment of a data resource path.
+struct Segment {
+    name: String,
+    keys: Option<Vec<String>>,
+}
+
+impl MemoryDatastore {
+    /// Create an empty datastore for the given modules.
+    pub fn new(modules: &[YangModule]) -> Self {
+        let mut list_keys = HashMap::new();
+        for module in modules {
+            collect_list_keys(&module.data_nodes, "", &mut list_keys);
+        }
+        Self {
+            root: Map::new(),
+            list_keys,
+        }
+    }
+
+    /// Replace the whole data tree.
+    pub fn load(&mut self, data: Value) -> Result<(), DatastoreError> {
+        match data {
+            Value::Object(root) => {
+                self.root = root;
+                Ok(())
+            }
+            _ => Err(DatastoreError::BadRequest(
+                "datastore root must be a JSON object".to_string(),
+            )),
+        }
+    }
+
+    /// The whole data tree.
+    pub fn root(&self) -> Value {
+        Value::Object(self.root.clone())
+    }
+
+    /// Read a resource, wrapped in its member name as RESTCONF responses are.
+    pub fn get(&self, path: &str) -> Result<Value, DatastoreError> {
+        let segments = parse_path(path)?;
+        let Some(last) = segments.last() else {
+            return Ok(self.root());
+        };
+
+        let parent = self.locate(&segments[..segments.len() - 1], path)?;
+        let member = find_member(parent, &last.name)
+            .ok_or_else(|| DatastoreError::NotFound(path.to_string()))?;
+        let value = &parent[&member];
+
+        let mut body = Map::new();
+        match &last.keys {
+            Some(keys) => {
+                let schema_path = schema_path(&segments);
+                let entry = self
+                    .find_entry(value, &schema_path, keys)
+                    .ok_or_else(|| DatastoreError::NotFound(path.to_string()))?;
+                body.insert(member, Value::Array(vec![value[entry].clone()]));
+            }
+            None => {
+                body.insert(member, value.clone());
+            }
+        }
+        Ok(Value::Object(body))
+    }
+
+    /// Create or replace a resource. Returns `true` if the resource was created.
+    pub fn put(&mut self, path: &str, body: Value) -> Result<bool, DatastoreError> {
+        let segments = parse_path(path)?;
+        let Some(last) = segments.last() else {
+            self.load(body)?;
+            return Ok(false);
+        };
+        let value = body_member(body, &last.name)?;
+        let schema_path = schema_path(&segments);
+        let list_keys = self.list_keys.clone();
+
+        let parent = self.locate_mut(&segments[..segments.len() - 1], path)?;
+        let member = find_member(parent, &last.name)
+            .unwrap_or_else(|| member_name(&last.name, segments.len() == 1));
+
+        match &last.keys {
+            Some(keys) => {
+                let entry = single_entry(value)?;
+                let list = parent
+                    .entry(member)
+                    .or_insert_with(|| Value::Array(Vec::new()));
+                let existing = find_entry_in(&list_keys, list, &schema_path, keys);
+                let Value::Array(entries) = list else {
+                    return Err(DatastoreError::BadRequest(format!(
+                        "{} is not a list",
+                        path
+                    )));
+                };
+                match existing {
+                    Some(index) => {
+                        entries[index] = entry;
+                        Ok(false)
+                    }
+                    None => {
+                        entries.push(entry);
+                        Ok(true)
+                    }
+                }
+            }
+            None => Ok(parent.insert(member, value).is_none()),
+        }
+    }
+
+    /// Merge a resource into the existing data (PATCH). The target must exist.
+    pub fn merge(&mut self, path: &str, body: Value) -> Result<(), DatastoreError> {
+        let segments = parse_path(path)?;
+        let Some(last) = segments.last() else {
+            let Value::Object(patch) = body else {
+                return Err(DatastoreError::BadRequest(
+                    "request body must be a JSON object".to_string(),
+                ));
+            };
+            for (name, value) in patch {
+                merge_value(self.root.entry(name).or_insert(Value::Null), value);
+            }
+            return Ok(());
+        };
+        let mut value = body_member(body, &last.name)?;
+        if last.keys.is_some() {
+            value = single_entry(value)?;
+        }
+        let target = self.target_mut(&segments, path)?;
+        merge_value(target, value);
+        Ok(())
+    }
+
+    /// Create child resources under the target (POST).
+    pub fn create(&mut self, path: &str, body: Value) -> Result<(), DatastoreError> {
+        let segments = parse_path(path)?;
+        let Value::Object(children) = body else {
+            return Err(DatastoreError::BadRequest(
+                "request body must be a JSON object".to_string(),
+            ));
+        };
+        let top_level = segments.is_empty();
+        let target = if top_level {
+            &mut self.root
+        } else {
+            match self.target_mut(&segments, path)? {
+                Value::Object(object) => object,
+                _ => {
+                    return Err(DatastoreError::BadRequest(format!(
+                        "{} cannot contain child resources",
+                        path
+                    )))
+                }
+            }
+        };
+
+        for (name, value) in children {
+            let member =
+                find_member(target, &name).unwrap_or_else(|| member_name(&name, top_level));
+            match (target.get_mut(&member), value) {
+                (Some(Value::Array(entries)), Value::Array(new_entries)) => {
+                    entries.extend(new_entries);
+                }
+                (Some(_), _) => {
+                    return Err(DatastoreError::Conflict(format!("{}/{}", path, name)));
+                }
+                (None, value) => {
+                    target.insert(member, value);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Delete a resource.
+    pub fn delete(&mut self, path: &str) -> Result<(), DatastoreError> {
+        let segments = parse_path(path)?;
+        let Some(last) = segments.last() else {
+            self.root.clear();
+            return Ok(());
+        };
+        let schema_path = schema_path(&segments);
+        let list_keys = self.list_keys.clone();
+
+        let parent = self.locate_mut(&segments[..segments.len() - 1], path)?;
+        let member = find_member(parent, &last.name)
+            .ok_or_else(|| DatastoreError::NotFound(path.to_string()))?;
+
+        match &last.keys {
+            Some(keys) => {
+                let list = parent.get_mut(&member).expect("member exists");
+                let index = find_entry_in(&list_keys, list, &schema_path, keys)
+                    .ok_or_else(|| DatastoreError::NotFound(path.to_string()))?;
+                if let Value::Array(entries) = list {
+                    entries.remove(index);
+                }
+            }
+            None => {
+                parent.remove(&member);
+            }
+        }
+        Ok(())
+    }
+
+    /// Walk to the object addressed by `segments`.
+    fn locate(
+        &self,
+        segments: &[Segment],
+        path: &str,
+    ) -> Result<&Map<String, Value>, DatastoreError> {
+        let mut current = &self.root;
+        for (depth, segment) in segments.iter().enumerate() {
+            let member = find_member(current, &segment.name)
+                .ok_or_else(|| DatastoreError::NotFound(path.to_string()))?;
+            let mut value = &current[&member];
+            if let Some(keys) = &segment.keys {
+                let index = self
+                    .find_entry(value, &schema_path(&segments[..=depth]), keys)
+                    .ok_or_else(|| DatastoreError::NotFound(path.to_string()))?;
+                value = &value[index];
+            }
+            current = value
+                .as_object()
+                .ok_or_else(|| DatastoreError::NotFound(path.to_string()))?;
+        }
+        Ok(current)
+    }
+
+    /// Walk to the object addressed by `segments`, mutably.
+    fn locate_mut(
+        &mut self,
+        segments: &[Segment],
+        path: &str,
+    ) -> Result<&mut Map<String, Value>, DatastoreError> {
+        if segments.is_empty() {
+            return Ok(&mut self.root);
+        }
+        match self.target_mut(segments, path)? {
+            Value::Object(object) => Ok(object),
+            _ => Err(DatastoreError::NotFound(path.to_string())),
+        }
+    }
+
+    /// Walk to the value addressed by a non-empty list of segments, mutably.
+    fn target_mut(
+        &mut self,
+        segments: &[Segment],
+        path: &str,
+    ) -> Result<&mut Value, DatastoreError> {
+        let not_found = || DatastoreError::NotFound(path.to_string());
+        let mut current = &mut self.root;
+        for (depth, segment) in segments.iter().enumerate() {
+            let member = find_member(current, &segment.name).ok_or_else(not_found)?;
+            let mut value = current.get_mut(&member).ok_or_else(not_found)?;
+            if let Some(keys) = &segment.keys {
+                let index = find_entry_in(
+                    &self.list_keys,
+                    value,
+                    &schema_path(&segments[..=depth]),
+                    keys,
+                )
+                .ok_or_else(not_found)?;
+                value = &mut value[index];
+            }
+            if depth + 1 == segments.len() {
+                return Ok(value);
+            }
+            current = value.as_object_mut().ok_or_else(not_found)?;
+        }
+        unreachable!("segments is non-empty")
+    }
+
+    /// Find the index of the list entry matching the given key values.
+    fn find_entry(&self, list: &Value, schema_path: &str, keys: &[String]) -> Option<usize> {
+        find_entry_in(&self.list_keys, list, schema_path, keys)
+    }
+}
+
+/// Record the key leaves of every list, indexed by schema path.
+fn collect_list_keys(nodes: &[DataNode], prefix: &str, keys: &mut HashMap<String, Vec<String>>) {
+    for node in nodes {
+        let path = |name: &str| {
+            if prefix.is_empty() {
+                name.to_string()
+            } else {
+                format!("{}/{}", prefix, name)
+            }
+        };
+        match node {
+            DataNode::Container(container) => {
+                collect_list_keys(&container.children, &path(&container.name), keys);
+            }
+            DataNode::List(list) => {
+                let list_path = path(&list.name);
+                keys.insert(list_path.clone(), list.keys.clone());
+                collect_list_keys(&list.children, &list_path, keys);
+            }
+            DataNode::Choice(choice) => {
+                for case in &choice.cases {
+                    collect_list_keys(&case.data_nodes, prefix, keys);
+                }
+            }
+            DataNode::Case(case) => collect_list_keys(&case.data_nodes, prefix, keys),
+            DataNode::Leaf(_)
+            | DataNode::LeafList(_)
+            | DataNode::AnyData(_)
+            | DataNode::Uses(_) => {}
+        }
+    }
+}
+
+/// Find the index of the list entry matching the given key values.
+fn find_entry_in(
+    list_keys: &HashMap<String, Vec<String>>,
+    list: &Value,
+    schema_path: &str,
+    keys: &[String],
+) -> Option<usize> {
+    let names = list_keys.get(schema_path)?;
+    list.as_array()?.iter().position(|entry| {
+        names.len() == keys.len()
+            && names
+                .iter()
+                .zip(keys)
+                .all(|(name, key)| entry.get(name).map(key_text).as_deref() == Some(key))
+    })
+}
+
+/// Render a key leaf value the way it appears in a URL.
+fn key_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Parse a data resource path into segments.
+fn parse_path(path: &str) -> Result<Vec<Segment>, DatastoreError> {
+    path.split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| {
+            let (name, keys) = match segment.split_once('=') {
+                Some((name, keys)) => (name, Some(keys)),
+                None => (segment, None),
+            };
+            let keys = keys
+                .map(|keys| {
+                    keys.split(',')
+                        .map(|key| {
+                            urlencoding::decode(key)
+                                .map(|k| k.into_owned())
+                                .map_err(|e| DatastoreError::BadRequest(e.to_string()))
+                        })
+                        .collect::<Result<Vec<_>, _>>()
+                })
+                .transpose()?;
+            Ok(Segment {
+                name: name.to_string(),
+                keys,
+            })
+        })
+        .collect()
+}
+
+/// Schema path (unqualified node names) for a list of segments.
+fn schema_path(segments: &[Segment]) -> String {
+    segments
+        .iter()
+        .map(|segment| local_name(&segment.name))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Strip a module qualifier from a node name.
+fn local_name(name: &str) -> &str {
+    name.split_once(':').map(|(_, local)| local).unwrap_or(name)
+}
+
+/// Name under which a new member is stored: qualified at the top level only.
+fn member_name(name: &str, top_level: bool) -> String {
+    if top_level {
+        name.to_string()
+    } else {
+        local_name(name).to_string()
+    }
+}
+
+/// Find the stored member name matching a (possibly qualified) node name.
+fn find_member(object: &Map<String, Value>, name: &str) -> Option<String> {
+    if object.contains_key(name) {
+        return Some(name.to_string());
+    }
+    let local = local_name(name);
+    object.keys().find(|key| local_name(key) == local).cloned()
+}
+
+/// Extract the single member of a request body that names the target node.
+fn body_member(body: Value, name: &str) -> Result<Value, DatastoreError> {
+    let Value::Object(members) = body else {
+        return Err(DatastoreError::BadRequest(
+            "request body must be a JSON object".to_string(),
+        ));
+    };
+    let local = local_name(name);
+    members
+        .into_iter()
+        .find(|(key, _)| local_name(key) == local)
+        .map(|(_, value)| value)
+        .ok_or_else(|| {
+            DatastoreError::BadRequest(format!("request body must contain member '{}'", name))
+        })
+}
+
+/// Unwrap the one-element array used to carry a list entry.
+fn single_entry(value: Value) -> Result<Value, DatastoreError> {
+    match value {
+        Value::Array(mut entries) if entries.len() == 1 => Ok(entries.remove(0)),
+        Value::Object(_) => Ok(value),
+        _ => Err(DatastoreError::BadRequest(
+            "list entry body must contain exactly one entry".to_string(),
+        )),
+    }
+}
+
+/// Merge `patch` into `target`, recursing into objects.
+fn merge_value(target: &mut Value, patch: Value) {
+    match (target, patch) {
+        (Value::Object(target), Value::Object(patch)) => {
+            for (name, value) in patch {
+                merge_value(target.entry(name).or_insert(Value::Null), value);
+            }
+        }
+        (target, patch) => *target = patch,
+    }
+}
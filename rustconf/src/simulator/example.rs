@@ -0,0 +1,163 @@
+//! Example instance data derived from a YANG schema.
+
+use serde_json::{Map, Value};
+
+use crate::parser::{format_decimal64, DataNode, TypeSpec, YangModule};
+
+/// Build an example data tree for a module, encoded per RFC 7951.
+///
+/// Top-level nodes are qualified with the module name; every list receives a
+/// single entry and every leaf a value that satisfies its type constraints.
+/// The module should be expanded first so that `uses` and typedef references
+/// have been resolved.
+pub fn example_data(module: &YangModule) -> Value {
+    let mut root = Map::new();
+    for node in &module.data_nodes {
+        add_example_node(&mut root, node, Some(&module.name));
+    }
+    Value::Object(root)
+}
+
+/// Build the example body for a list of data nodes (RPC output, list entry, ...).
+pub fn example_nodes(nodes: &[DataNode]) -> Map<String, Value> {
+    let mut object = Map::new();
+    for node in nodes {
+        add_example_node(&mut object, node, None);
+    }
+    object
+}
+
+/// Add the example value for one node to a JSON object.
+fn add_example_node(object: &mut Map<String, Value>, node: &DataNode, module: Option<&str>) {
+    let qualify = |name: &str| match module {
+        Some(module) => format!("{}:{}", module, name),
+        None => name.to_string(),
+    };
+
+    match node {
+        DataNode::Container(container) => {
+            object.insert(
+                qualify(&container.name),
+                Value::Object(example_nodes(&container.children)),
+            );
+        }
+        DataNode::List(list) => {
+            object.insert(
+                qualify(&list.name),
+                Value::Array(vec![Value::Object(example_nodes(&list.children))]),
+            );
+        }
+        DataNode::Leaf(leaf) => {
+            let value = leaf
+                .default
+                .as_deref()
+                .map(|default| default_value(default, &leaf.type_spec))
+                .unwrap_or_else(|| example_value(&leaf.name, &leaf.type_spec));
+            object.insert(qualify(&leaf.name), value);
+        }
+        DataNode::LeafList(leaf_list) => {
+            object.insert(
+                qualify(&leaf_list.name),
+                Value::Array(vec![example_value(&leaf_list.name, &leaf_list.type_spec)]),
+            );
+        }
+        DataNode::AnyData(anydata) => {
+            object.insert(qualify(&anydata.name), Value::Object(Map::new()));
+        }
+        DataNode::Choice(choice) => {
+            // Only one case of a choice may be present; use the first
+            if let Some(case) = choice.cases.first() {
+                for child in &case.data_nodes {
+                    add_example_node(object, child, module);
+                }
+            }
+        }
+        DataNode::Case(case) => {
+            for child in &case.data_nodes {
+                add_example_node(object, child, module);
+            }
+        }
+        DataNode::Uses(_) => {
+            // Uses should be expanded before generating example data
+        }
+    }
+}
+
+/// Example value for a leaf of the given type.
+fn example_value(name: &str, type_spec: &TypeSpec) -> Value {
+    match type_spec {
+        TypeSpec::Int8 { range }
+        | TypeSpec::Int16 { range }
+        | TypeSpec::Int32 { range }
+        | TypeSpec::Int64 { range }
+        | TypeSpec::Uint8 { range }
+        | TypeSpec::Uint16 { range }
+        | TypeSpec::Uint32 { range }
+        | TypeSpec::Uint64 { range } => {
+            let min = range
+                .as_ref()
+                .and_then(|r| r.ranges.first())
+                .map(|r| r.min)
+                .unwrap_or(0);
+            Value::from(min)
+        }
+        TypeSpec::Decimal64 {
+            fraction_digits,
+            range,
+        } => {
+            let min = range
+                .as_ref()
+                .and_then(|r| r.ranges.first())
+                .map(|r| r.min)
+                .unwrap_or(0);
+            Value::String(format_decimal64(min, *fraction_digits))
+        }
+        TypeSpec::String { length, .. } => {
+            let mut value = format!("{}-1", name);
+            if let Some(range) = length.as_ref().and_then(|l| l.lengths.first()) {
+                while (value.len() as u64) < range.min {
+                    value.push('x');
+                }
+                value.truncate(range.max.try_into().unwrap_or(usize::MAX));
+            }
+            Value::String(value)
+        }
+        TypeSpec::DateAndTime { .. } => Value::String("1970-01-01T00:00:00Z".to_string()),
+        TypeSpec::Boolean => Value::Bool(false),
+        TypeSpec::Empty => Value::Array(vec![Value::Null]),
+        TypeSpec::Enumeration { values } => values
+            .first()
+            .map(|v| Value::String(v.name.clone()))
+            .unwrap_or(Value::Null),
+        TypeSpec::Bits { bits } => {
+            Value::String(bits.first().map(|bit| bit.name.clone()).unwrap_or_default())
+        }
+        TypeSpec::Union { types } => types
+            .first()
+            .map(|t| example_value(name, t))
+            .unwrap_or(Value::Null),
+        TypeSpec::Binary { .. } => Value::String(String::new()),
+        TypeSpec::LeafRef { .. } | TypeSpec::TypedefRef { .. } => {
+            Value::String(format!("{}-1", name))
+        }
+    }
+}
+
+/// Convert a YANG `default` statement argument into a JSON value.
+fn default_value(default: &str, type_spec: &TypeSpec) -> Value {
+    match type_spec {
+        TypeSpec::Int8 { .. }
+        | TypeSpec::Int16 { .. }
+        | TypeSpec::Int32 { .. }
+        | TypeSpec::Int64 { .. }
+        | TypeSpec::Uint8 { .. }
+        | TypeSpec::Uint16 { .. }
+        | TypeSpec::Uint32 { .. }
+        | TypeSpec::Uint64 { .. } => default
+            .parse::<i64>()
+            .map(Value::from)
+            .unwrap_or_else(|_| Value::String(default.to_string())),
+        TypeSpec::Boolean => Value::Bool(default == "true"),
+        _ => Value::String(default.to_string()),
+    }
+}
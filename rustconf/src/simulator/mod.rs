@@ -0,0 +1,29 @@
+//! RESTCONF device simulator.
+//!
+//! Serves a RESTCONF API for a set of YANG modules from an in-memory
+//! datastore seeded with example data, so client code can be exercised
+//! without hardware. Used by the `rustconf simulate` command.
+//!
+//! ```rust,no_run
+//! use rustconf::simulator::Simulator;
+//! use rustconf::YangParser;
+//!
+//! let mut parser = YangParser::new();
+//! let mut module = parser.parse_file("yang/device.yang".as_ref()).unwrap();
+//! parser.expand_module(&mut module).unwrap();
+//!
+//! let simulator = Simulator::new(&[module]);
+//! let listener = std::net::TcpListener::bind("127.0.0.1:8080").unwrap();
+//! simulator.serve(listener).unwrap();
+//! ```
+
+mod datastore;
+mod example;
+mod server;
+
+pub use datastore::{DatastoreError, MemoryDatastore};
+pub use example::{example_data, example_nodes};
+pub use server::{Simulator, SimulatorResponse};
+
+#[cfg(test)]
+mod tests;
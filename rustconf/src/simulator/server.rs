@@ -0,0 +1,305 @@
+//! Minimal HTTP/1.1 RESTCONF server backed by a [`MemoryDatastore`].
+
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use serde_json::{json, Map, Value};
+
+use super::datastore::{DatastoreError, MemoryDatastore};
+use super::example::{example_data, example_nodes};
+use crate::parser::YangModule;
+
+/// Media type used for RESTCONF JSON bodies (RFC 8040).
+const YANG_DATA_JSON: &str = "application/yang-data+json";
+
+/// Largest request body accepted; larger ones are answered with 413 without
+/// being read.
+const MAX_BODY_SIZE: usize = 4 * 1024 * 1024;
+
+/// A RESTCONF device simulator.
+///
+/// The simulator serves the `/restconf/data` and `/restconf/operations`
+/// resources for a set of modules. The datastore starts out populated with
+/// example data for every module; RPCs succeed and return example output.
+#[derive(Clone)]
+pub struct Simulator {
+    datastore: Arc<Mutex<MemoryDatastore>>,
+    operations: Arc<HashMap<String, Option<Value>>>,
+}
+
+/// Response produced by [`Simulator::handle`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulatorResponse {
+    /// HTTP status code.
+    pub status: u16,
+    /// JSON body, if any.
+    pub body: Option<Value>,
+}
+
+impl SimulatorResponse {
+    fn new(status: u16, body: Option<Value>) -> Self {
+        Self { status, body }
+    }
+
+    /// RESTCONF error response (RFC 8040 section 7.1).
+    fn error(status: u16, tag: &str, message: String) -> Self {
+        Self::new(
+            status,
+            Some(json!({
+                "ietf-restconf:errors": {
+                    "error": [{
+                        "error-type": "protocol",
+                        "error-tag": tag,
+                        "error-message": message,
+                    }]
+                }
+            })),
+        )
+    }
+}
+
+impl From<DatastoreError> for SimulatorResponse {
+    fn from(error: DatastoreError) -> Self {
+        let message = error.to_string();
+        match error {
+            DatastoreError::NotFound(_) => Self::error(404, "invalid-value", message),
+            DatastoreError::Conflict(_) => Self::error(409, "data-exists", message),
+            DatastoreError::BadRequest(_) => Self::error(400, "malformed-message", message),
+        }
+    }
+}
+
+impl Simulator {
+    /// Create a simulator for the given (expanded) modules, populated with
+    /// example data.
+    pub fn new(modules: &[YangModule]) -> Self {
+        let mut datastore = MemoryDatastore::new(modules);
+        let mut data = Map::new();
+        for module in modules {
+            if let Value::Object(module_data) = example_data(module) {
+                data.extend(module_data);
+            }
+        }
+        datastore
+            .load(Value::Object(data))
+            .expect("example data is an object");
+
+        let operations = modules
+            .iter()
+            .flat_map(|module| {
+                module.rpcs.iter().map(move |rpc| {
+                    let output = rpc.output.as_ref().map(
+                        |nodes| json!({ format!("{}:output", module.name): example_nodes(nodes) }),
+                    );
+                    (format!("{}:{}", module.name, rpc.name), output)
+                })
+            })
+            .collect();
+
+        Self {
+            datastore: Arc::new(Mutex::new(datastore)),
+            operations: Arc::new(operations),
+        }
+    }
+
+    /// Shared handle to the simulator's datastore.
+    pub fn datastore(&self) -> Arc<Mutex<MemoryDatastore>> {
+        Arc::clone(&self.datastore)
+    }
+
+    /// Handle a single request.
+    pub fn handle(&self, method: &str, target: &str, body: &[u8]) -> SimulatorResponse {
+        let path = target.split_once('?').map(|(p, _)| p).unwrap_or(target);
+
+        let body = if body.is_empty() {
+            None
+        } else {
+            match serde_json::from_slice::<Value>(body) {
+                Ok(value) => Some(value),
+                Err(e) => {
+                    return SimulatorResponse::error(
+                        400,
+                        "malformed-message",
+                        format!("Invalid JSON body: {}", e),
+                    )
+                }
+            }
+        };
+
+        if path == "/restconf" || path == "/restconf/" {
+            return SimulatorResponse::new(
+                200,
+                Some(json!({
+                    "ietf-restconf:restconf": {
+                        "data": {},
+                        "operations": {},
+                        "yang-library-version": "2019-01-04"
+                    }
+                })),
+            );
+        }
+
+        if let Some(operation) = path.strip_prefix("/restconf/operations/") {
+            if method != "POST" {
+                return SimulatorResponse::error(
+                    405,
+                    "operation-not-supported",
+                    format!("{} is not supported on operation resources", method),
+                );
+            }
+            return match self.operations.get(operation) {
+                Some(Some(output)) => SimulatorResponse::new(200, Some(output.clone())),
+                Some(None) => SimulatorResponse::new(204, None),
+                None => SimulatorResponse::error(
+                    404,
+                    "invalid-value",
+                    format!("Unknown operation: {}", operation),
+                ),
+            };
+        }
+
+        let data_path = match path.strip_prefix("/restconf/data") {
+            Some(rest) if rest.is_empty() || rest.starts_with('/') => rest,
+            _ => {
+                return SimulatorResponse::error(
+                    404,
+                    "invalid-value",
+                    format!("Unknown resource: {}", path),
+                )
+            }
+        };
+
+        let mut datastore = self.datastore.lock().unwrap_or_else(|e| e.into_inner());
+        let result = match (method, body) {
+            ("GET", _) | ("HEAD", _) => datastore
+                .get(data_path)
+                .map(|value| SimulatorResponse::new(200, Some(value))),
+            ("PUT", Some(body)) => datastore
+                .put(data_path, body)
+                .map(|created| SimulatorResponse::new(if created { 201 } else { 204 }, None)),
+            ("PATCH", Some(body)) => datastore
+                .merge(data_path, body)
+                .map(|()| SimulatorResponse::new(204, None)),
+            ("POST", Some(body)) => datastore
+                .create(data_path, body)
+                .map(|()| SimulatorResponse::new(201, None)),
+            ("DELETE", _) => datastore
+                .delete(data_path)
+                .map(|()| SimulatorResponse::new(204, None)),
+            ("PUT" | "PATCH" | "POST", None) => Err(DatastoreError::BadRequest(
+                "request body is required".to_string(),
+            )),
+            (method, _) => {
+                return SimulatorResponse::error(
+                    405,
+                    "operation-not-supported",
+                    format!("{} is not supported", method),
+                )
+            }
+        };
+        result.unwrap_or_else(SimulatorResponse::from)
+    }
+
+    /// Serve requests from the listener until it fails. Each connection is
+    /// handled on its own thread.
+    pub fn serve(&self, listener: TcpListener) -> io::Result<()> {
+        for stream in listener.incoming() {
+            let stream = stream?;
+            let simulator = self.clone();
+            thread::spawn(move || {
+                // Errors on one connection should not stop the server
+                let _ = simulator.handle_connection(stream, |_, _, _, _| {});
+            });
+        }
+        Ok(())
+    }
+
+    /// Read one HTTP request from the stream and write the response.
+    ///
+    /// `observe` is called with the method, request target, body and response
+    /// before the response is written.
+    pub(crate) fn handle_connection(
+        &self,
+        stream: TcpStream,
+        observe: impl FnOnce(&str, &str, &[u8], &SimulatorResponse),
+    ) -> io::Result<()> {
+        let mut reader = BufReader::new(stream.try_clone()?);
+
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+        let mut parts = request_line.split_whitespace();
+        let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+            return Ok(());
+        };
+
+        let mut content_length = 0;
+        loop {
+            let mut header = String::new();
+            if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                if name.trim().eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap_or(0);
+                }
+            }
+        }
+
+        let (body, response) = if content_length > MAX_BODY_SIZE {
+            let message = format!(
+                "Request body of {} bytes exceeds the limit of {} bytes",
+                content_length, MAX_BODY_SIZE
+            );
+            (
+                Vec::new(),
+                SimulatorResponse::error(413, "too-big", message),
+            )
+        } else {
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body)?;
+            let response = self.handle(method, target, &body);
+            (body, response)
+        };
+        observe(method, target, &body, &response);
+        let payload = match (&response.body, method) {
+            (_, "HEAD") | (None, _) => Vec::new(),
+            (Some(body), _) => serde_json::to_vec_pretty(body).unwrap_or_default(),
+        };
+        let content_length = match &response.body {
+            Some(body) if method == "HEAD" => serde_json::to_vec_pretty(body)
+                .map(|b| b.len())
+                .unwrap_or(0),
+            _ => payload.len(),
+        };
+
+        let mut stream = stream;
+        write!(
+            stream,
+            "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            response.status,
+            reason_phrase(response.status),
+            YANG_DATA_JSON,
+            content_length
+        )?;
+        stream.write_all(&payload)?;
+        stream.flush()
+    }
+}
+
+/// Reason phrase for the status codes the simulator produces.
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        204 => "No Content",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        409 => "Conflict",
+        413 => "Content Too Large",
+        _ => "",
+    }
+}
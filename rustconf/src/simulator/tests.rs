@@ -0,0 +1,189 @@
+//! Tests for the RESTCONF simulator.
+
+use super::*;
+use crate::parser::YangParser;
+use serde_json::json;
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+
+const DEVICE_YANG: &str = r#"
+    module device {
+        namespace "urn:device";
+        prefix dev;
+
+        container system {
+            leaf hostname {
+                type string;
+            }
+            leaf mtu {
+                type uint16 {
+                    range "68..9000";
+                }
+            }
+        }
+
+        container interfaces {
+            list interface {
+                key "name";
+                leaf name {
+                    type string;
+                }
+                leaf enabled {
+                    type boolean;
+                    default "true";
+                }
+            }
+        }
+
+        rpc reboot {
+            output {
+                leaf status {
+                    type string;
+                }
+            }
+        }
+    }
+"#;
+
+fn simulator() -> Simulator {
+    let mut parser = YangParser::new();
+    let mut module = parser.parse_string(DEVICE_YANG, "device.yang").unwrap();
+    parser.expand_module(&mut module).unwrap();
+    Simulator::new(&[module])
+}
+
+#[test]
+fn test_example_data_follows_schema() {
+    let mut parser = YangParser::new();
+    let module = parser.parse_string(DEVICE_YANG, "device.yang").unwrap();
+
+    let data = example_data(&module);
+    assert_eq!(data["device:system"]["hostname"], json!("hostname-1"));
+    assert_eq!(data["device:system"]["mtu"], json!(68));
+    assert_eq!(
+        data["device:interfaces"]["interface"],
+        json!([{ "name": "name-1", "enabled": true }])
+    );
+}
+
+#[test]
+fn test_get_container_and_list_entry() {
+    let simulator = simulator();
+
+    let response = simulator.handle("GET", "/restconf/data/device:system", b"");
+    assert_eq!(response.status, 200);
+    assert_eq!(
+        response.body,
+        Some(json!({ "device:system": { "hostname": "hostname-1", "mtu": 68 } }))
+    );
+
+    let response = simulator.handle(
+        "GET",
+        "/restconf/data/device:interfaces/interface=name-1/enabled",
+        b"",
+    );
+    assert_eq!(response.body, Some(json!({ "enabled": true })));
+
+    let response = simulator.handle(
+        "GET",
+        "/restconf/data/device:interfaces/interface=eth9",
+        b"",
+    );
+    assert_eq!(response.status, 404);
+}
+
+#[test]
+fn test_put_patch_and_delete_round_trip() {
+    let simulator = simulator();
+
+    let response = simulator.handle(
+        "PUT",
+        "/restconf/data/device:interfaces/interface=eth0",
+        br#"{"device:interface":[{"name":"eth0","enabled":false}]}"#,
+    );
+    assert_eq!(response.status, 201);
+
+    let response = simulator.handle(
+        "PATCH",
+        "/restconf/data/device:system",
+        br#"{"device:system":{"hostname":"router"}}"#,
+    );
+    assert_eq!(response.status, 204);
+
+    let data = simulator.datastore().lock().unwrap().root();
+    assert_eq!(
+        data["device:system"],
+        json!({ "hostname": "router", "mtu": 68 })
+    );
+    assert_eq!(
+        data["device:interfaces"]["interface"][1],
+        json!({ "name": "eth0", "enabled": false })
+    );
+
+    let response = simulator.handle(
+        "DELETE",
+        "/restconf/data/device:interfaces/interface=eth0",
+        b"",
+    );
+    assert_eq!(response.status, 204);
+    let response = simulator.handle(
+        "GET",
+        "/restconf/data/device:interfaces/interface=eth0",
+        b"",
+    );
+    assert_eq!(response.status, 404);
+}
+
+#[test]
+fn test_post_existing_resource_conflicts() {
+    let simulator = simulator();
+
+    let response = simulator.handle(
+        "POST",
+        "/restconf/data",
+        br#"{"device:system":{"hostname":"dup"}}"#,
+    );
+    assert_eq!(response.status, 409);
+    let body = response.body.unwrap();
+    assert_eq!(
+        body["ietf-restconf:errors"]["error"][0]["error-tag"],
+        json!("data-exists")
+    );
+}
+
+#[test]
+fn test_rpc_returns_example_output() {
+    let simulator = simulator();
+
+    let response = simulator.handle("POST", "/restconf/operations/device:reboot", b"");
+    assert_eq!(response.status, 200);
+    assert_eq!(
+        response.body,
+        Some(json!({ "device:output": { "status": "status-1" } }))
+    );
+
+    let response = simulator.handle("POST", "/restconf/operations/device:unknown", b"");
+    assert_eq!(response.status, 404);
+}
+
+#[test]
+fn test_oversized_body_is_rejected_unread() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let simulator = simulator();
+    std::thread::spawn(move || simulator.serve(listener));
+
+    // The announced body is never sent, so reading it would block
+    let mut stream = TcpStream::connect(address).unwrap();
+    write!(
+        stream,
+        "PUT /restconf/data/device:system HTTP/1.1\r\nContent-Length: {}\r\n\r\n",
+        usize::MAX
+    )
+    .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    assert!(response.starts_with("HTTP/1.1 413 Content Too Large\r\n"));
+    assert!(response.contains("\"error-tag\": \"too-big\""));
+}
@@ -0,0 +1,343 @@
+//! Harness for end-to-end tests against a live RESTCONF server.
+//!
+//! [`TestServer`] runs the [simulator](crate::simulator) on a free local port
+//! for the lifetime of a test, so generated clients can be exercised over real
+//! HTTP. Every request is recorded and can be asserted on afterwards. The
+//! server shuts down when the [`TestServer`] is dropped.
+//!
+//! ```rust,no_run
+//! use rustconf::testkit::TestServer;
+//! use serde_json::json;
+//!
+//! let server = TestServer::builder()
+//!     .yang_file("yang/device.yang")
+//!     .search_path("yang/")
+//!     .data(json!({ "device:system": { "hostname": "edge-1" } }))
+//!     .start()
+//!     .unwrap();
+//!
+//! // Point the client under test at `server.base_url()`, then:
+//! server.assert_requested("GET", "/restconf/data/device:system");
+//! assert_eq!(server.data()["device:system"]["hostname"], json!("edge-1"));
+//! ```
+
+use std::io;
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::parser::{ParseError, YangModule, YangParser};
+use crate::simulator::{DatastoreError, Simulator, SimulatorResponse};
+
+/// Errors that can occur while starting a [`TestServer`].
+#[derive(Debug, Error)]
+pub enum TestkitError {
+    #[error("Parse error: {0}")]
+    ParseError(#[from] ParseError),
+
+    #[error("Invalid initial data: {0}")]
+    DataError(#[from] DatastoreError),
+
+    #[error("I/O error: {0}")]
+    IoError(#[from] io::Error),
+}
+
+/// A request received by a [`TestServer`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedRequest {
+    /// HTTP method, e.g. `GET`.
+    pub method: String,
+    /// Request path without the query string.
+    pub path: String,
+    /// Query string without the leading `?`, if any.
+    pub query: Option<String>,
+    /// JSON body, if the request had a valid one.
+    pub body: Option<Value>,
+    /// Status code the server responded with.
+    pub status: u16,
+}
+
+/// Builder for a [`TestServer`].
+#[derive(Debug, Default)]
+pub struct TestServerBuilder {
+    yang_files: Vec<PathBuf>,
+    yang_sources: Vec<String>,
+    search_paths: Vec<PathBuf>,
+    modules: Vec<YangModule>,
+    data: Option<Value>,
+}
+
+impl TestServerBuilder {
+    /// Serve a YANG module loaded from a file.
+    pub fn yang_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.yang_files.push(path.into());
+        self
+    }
+
+    /// Serve a YANG module given as source text.
+    pub fn yang_source(mut self, source: impl Into<String>) -> Self {
+        self.yang_sources.push(source.into());
+        self
+    }
+
+    /// Add a directory used to resolve imports and includes.
+    pub fn search_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.search_paths.push(path.into());
+        self
+    }
+
+    /// Serve an already parsed (and expanded) module.
+    pub fn module(mut self, module: YangModule) -> Self {
+        self.modules.push(module);
+        self
+    }
+
+    /// Replace the generated example data with the given data tree.
+    ///
+    /// The tree uses RFC 7951 JSON, with top-level members qualified by module
+    /// name, e.g. `{"device:system": {"hostname": "edge-1"}}`.
+    pub fn data(mut self, data: Value) -> Self {
+        self.data = Some(data);
+        self
+    }
+
+    /// Parse the modules and start serving on a free port on localhost.
+    pub fn start(self) -> Result<TestServer, TestkitError> {
+        let mut parser = YangParser::new();
+        for search_path in self.search_paths {
+            parser.add_search_path(search_path);
+        }
+
+        let mut modules = self.modules;
+        for path in &self.yang_files {
+            let mut module = parser.parse_file(path)?;
+            parser.expand_module(&mut module)?;
+            modules.push(module);
+        }
+        for (index, source) in self.yang_sources.iter().enumerate() {
+            let mut module = parser.parse_string(source, &format!("source-{}.yang", index))?;
+            parser.expand_module(&mut module)?;
+            modules.push(module);
+        }
+
+        let simulator = Simulator::new(&modules);
+        if let Some(data) = self.data {
+            simulator
+                .datastore()
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .load(data)?;
+        }
+
+        TestServer::serve(simulator)
+    }
+}
+
+/// A RESTCONF server running in the background for the duration of a test.
+pub struct TestServer {
+    simulator: Simulator,
+    addr: SocketAddr,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+    shutdown: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl TestServer {
+    /// Create a builder for a test server.
+    pub fn builder() -> TestServerBuilder {
+        TestServerBuilder::default()
+    }
+
+    /// Start serving the given (expanded) modules with example data.
+    pub fn start(modules: &[YangModule]) -> Result<Self, TestkitError> {
+        Self::serve(Simulator::new(modules))
+    }
+
+    fn serve(simulator: Simulator) -> Result<Self, TestkitError> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let thread = {
+            let simulator = simulator.clone();
+            let requests = Arc::clone(&requests);
+            let shutdown = Arc::clone(&shutdown);
+            thread::spawn(move || accept_loop(listener, simulator, requests, shutdown))
+        };
+
+        Ok(Self {
+            simulator,
+            addr,
+            requests,
+            shutdown,
+            thread: Some(thread),
+        })
+    }
+
+    /// Address the server listens on.
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Base URL to configure clients with, e.g. `http://127.0.0.1:41234`.
+    pub fn base_url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// The simulator serving requests, for direct access to its datastore.
+    pub fn simulator(&self) -> &Simulator {
+        &self.simulator
+    }
+
+    /// Current contents of the datastore.
+    pub fn data(&self) -> Value {
+        self.simulator
+            .datastore()
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .root()
+    }
+
+    /// Requests received so far, in the order they arrived.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.lock_requests().clone()
+    }
+
+    /// Forget the requests received so far.
+    pub fn clear_requests(&self) {
+        self.lock_requests().clear();
+    }
+
+    /// Number of requests received with the given method and path.
+    pub fn request_count(&self, method: &str, path: &str) -> usize {
+        self.lock_requests()
+            .iter()
+            .filter(|request| request.method == method && request.path == path)
+            .count()
+    }
+
+    /// Assert that a request with the given method and path was received,
+    /// returning the most recent one.
+    ///
+    /// # Panics
+    ///
+    /// Panics, listing the requests that were received, if there was none.
+    pub fn assert_requested(&self, method: &str, path: &str) -> RecordedRequest {
+        let requests = self.lock_requests();
+        match requests
+            .iter()
+            .rev()
+            .find(|request| request.method == method && request.path == path)
+        {
+            Some(request) => request.clone(),
+            None => panic!(
+                "expected a {} {} request, received:\n{}",
+                method,
+                path,
+                describe(&requests)
+            ),
+        }
+    }
+
+    /// Assert that no request with the given method and path was received.
+    ///
+    /// # Panics
+    ///
+    /// Panics, listing the requests that were received, if there was one.
+    pub fn assert_not_requested(&self, method: &str, path: &str) {
+        let requests = self.lock_requests();
+        if requests
+            .iter()
+            .any(|request| request.method == method && request.path == path)
+        {
+            panic!(
+                "expected no {} {} request, received:\n{}",
+                method,
+                path,
+                describe(&requests)
+            );
+        }
+    }
+
+    fn lock_requests(&self) -> std::sync::MutexGuard<'_, Vec<RecordedRequest>> {
+        self.requests.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        // Wake the accept loop so it notices the shutdown flag
+        let _ = TcpStream::connect(self.addr);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+fn accept_loop(
+    listener: TcpListener,
+    simulator: Simulator,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+    shutdown: Arc<AtomicBool>,
+) {
+    for stream in listener.incoming() {
+        if shutdown.load(Ordering::SeqCst) {
+            break;
+        }
+        let Ok(stream) = stream else {
+            continue;
+        };
+        let simulator = simulator.clone();
+        let requests = Arc::clone(&requests);
+        thread::spawn(move || {
+            let _ = simulator.handle_connection(stream, |method, target, body, response| {
+                requests
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .push(record(method, target, body, response));
+            });
+        });
+    }
+}
+
+fn record(
+    method: &str,
+    target: &str,
+    body: &[u8],
+    response: &SimulatorResponse,
+) -> RecordedRequest {
+    let (path, query) = match target.split_once('?') {
+        Some((path, query)) => (path, Some(query.to_string())),
+        None => (target, None),
+    };
+    RecordedRequest {
+        method: method.to_string(),
+        path: path.to_string(),
+        query,
+        body: serde_json::from_slice(body).ok(),
+        status: response.status,
+    }
+}
+
+fn describe(requests: &[RecordedRequest]) -> String {
+    if requests.is_empty() {
+        return "  (none)".to_string();
+    }
+    requests
+        .iter()
+        .map(|request| {
+            format!(
+                "  {} {} -> {}",
+                request.method, request.path, request.status
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,1237 @@
+// This file is automatically generated by rustconf.
+// DO NOT EDIT MANUALLY.
+//
+// Source YANG module: ietf-interfaces
+// Namespace: urn:ietf:params:xml:ns:yang:ietf-interfaces
+// Prefix: if
+// YANG version: 1.1
+// Organization:
+//   IETF NETMOD (Network Modeling) Working Group
+// Contact:
+//   WG Web:   <https://datatracker.ietf.org/wg/netmod/>
+//   WG List:  <mailto:netmod@ietf.org>
+// Description:
+//   This module contains a collection of YANG definitions for
+//   managing network interfaces.
+//   Abridged for the rustconf test corpus: the deprecated
+//   interfaces-state tree and most statistics are left out, and the
+//   interface type is a string rather than an identityref.
+
+use serde::{Deserialize, Serialize};
+
+// Import runtime types from rustconf-runtime
+#[allow(unused_imports)]
+use rustconf_runtime::{
+    RestconfClient,
+    HttpTransport,
+    HttpRequest,
+    HttpResponse,
+    HttpMethod,
+    RpcError,
+    RequestInterceptor,
+    ErrorMapper,
+    DefaultErrorMapper,
+    ResourceCapabilities,
+    ResponseEnvelope,
+};
+
+// Generated Rust bindings for YANG module: ietf-interfaces
+// Namespace: urn:ietf:params:xml:ns:yang:ietf-interfaces
+
+/// RESTCONF constants for the ietf-interfaces YANG module.
+pub mod consts {
+    /// Name of the YANG module.
+    pub const MODULE_NAME: &str = "ietf-interfaces";
+    /// XML namespace of the YANG module.
+    pub const MODULE_NAMESPACE: &str = "urn:ietf:params:xml:ns:yang:ietf-interfaces";
+    /// Prefix of the YANG module.
+    pub const MODULE_PREFIX: &str = "if";
+    /// Organization responsible for the YANG module, if stated.
+    pub const MODULE_ORGANIZATION: Option<&str> = Some("IETF NETMOD (Network Modeling) Working Group");
+    /// Contact information for the YANG module's authors, if stated.
+    pub const MODULE_CONTACT: Option<&str> = Some("WG Web:   <https://datatracker.ietf.org/wg/netmod/>\nWG List:  <mailto:netmod@ietf.org>");
+    /// Description of the YANG module, if stated.
+    pub const MODULE_DESCRIPTION: Option<&str> = Some("This module contains a collection of YANG definitions for\nmanaging network interfaces.\n\nAbridged for the rustconf test corpus: the deprecated\ninterfaces-state tree and most statistics are left out, and the\ninterface type is a string rather than an identityref.");
+    /// Revision of the YANG module the code was generated from, if known.
+    pub const MODULE_REVISION: Option<&str> = Some("2018-02-20");
+    /// Modules the server must implement, with their revisions.
+    pub const IMPLEMENTED_MODULES: &[(&str, Option<&str>)] = &[("ietf-interfaces", MODULE_REVISION)];
+    /// Modules imported by the YANG module, with the revisions used.
+    pub const IMPORTED_MODULES: &[(&str, Option<&str>)] = &[("ietf-yang-types", Some("2013-07-15"))];
+    /// Media type for JSON data and operation bodies (RFC 8040).
+    pub const YANG_DATA_JSON: &str = "application/yang-data+json";
+    /// Media type for XML data and operation bodies (RFC 8040).
+    pub const YANG_DATA_XML: &str = "application/yang-data+xml";
+    /// Media type for YANG Patch documents (RFC 8072).
+    pub const YANG_PATCH_JSON: &str = "application/yang-patch+json";
+    /// Media type of notification event streams.
+    pub const EVENT_STREAM: &str = "text/event-stream";
+    /// Root of the RESTCONF API.
+    pub const RESTCONF_ROOT: &str = "/restconf";
+    /// Root of the datastore resource.
+    pub const DATA_PATH: &str = "/restconf/data";
+    /// Root of the operations resource.
+    pub const OPERATIONS_PATH: &str = "/restconf/operations";
+    /// Stream list advertised by the server (RFC 8040 section 9.3).
+    pub const STREAMS_PATH: &str = "/restconf/data/ietf-restconf-monitoring:restconf-state/streams";
+    /// Name of the default notification stream (RFC 8040 section 6.2).
+    pub const NETCONF_STREAM: &str = "NETCONF";
+    /// Notification streams carrying this module's notifications.
+    pub const STREAMS: &[&str] = &[];
+    /// Qualified names of all RPCs defined by the module.
+    pub const OPERATIONS: &[&str] = &[];
+    /// Qualified names of all notifications defined by the module.
+    pub const NOTIFICATIONS: &[&str] = &[];
+}
+
+/// Schema nodes of the ietf-interfaces YANG module.
+pub mod schema {
+    /// Data nodes of the ietf-interfaces YANG module, e.g. for mapping the `error-path` of
+    /// a RESTCONF error to the field that caused it.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum SchemaNode {
+        /// `/interfaces`
+        Interfaces,
+        /// `/interfaces/interface`
+        InterfacesInterface,
+        /// `/interfaces/interface/name`
+        InterfacesInterfaceName,
+        /// `/interfaces/interface/description`
+        InterfacesInterfaceDescription,
+        /// `/interfaces/interface/type`
+        InterfacesInterfaceType,
+        /// `/interfaces/interface/enabled`
+        InterfacesInterfaceEnabled,
+        /// `/interfaces/interface/link-up-down-trap-enable`
+        InterfacesInterfaceLinkUpDownTrapEnable,
+        /// `/interfaces/interface/admin-status`
+        InterfacesInterfaceAdminStatus,
+        /// `/interfaces/interface/oper-status`
+        InterfacesInterfaceOperStatus,
+        /// `/interfaces/interface/last-change`
+        InterfacesInterfaceLastChange,
+        /// `/interfaces/interface/if-index`
+        InterfacesInterfaceIfIndex,
+        /// `/interfaces/interface/phys-address`
+        InterfacesInterfacePhysAddress,
+        /// `/interfaces/interface/higher-layer-if`
+        InterfacesInterfaceHigherLayerIf,
+        /// `/interfaces/interface/lower-layer-if`
+        InterfacesInterfaceLowerLayerIf,
+        /// `/interfaces/interface/speed`
+        InterfacesInterfaceSpeed,
+        /// `/interfaces/interface/statistics`
+        InterfacesInterfaceStatistics,
+        /// `/interfaces/interface/statistics/discontinuity-time`
+        InterfacesInterfaceStatisticsDiscontinuityTime,
+        /// `/interfaces/interface/statistics/in-octets`
+        InterfacesInterfaceStatisticsInOctets,
+        /// `/interfaces/interface/statistics/in-discards`
+        InterfacesInterfaceStatisticsInDiscards,
+        /// `/interfaces/interface/statistics/in-errors`
+        InterfacesInterfaceStatisticsInErrors,
+        /// `/interfaces/interface/statistics/out-octets`
+        InterfacesInterfaceStatisticsOutOctets,
+        /// `/interfaces/interface/statistics/out-discards`
+        InterfacesInterfaceStatisticsOutDiscards,
+        /// `/interfaces/interface/statistics/out-errors`
+        InterfacesInterfaceStatisticsOutErrors,
+    }
+
+    impl SchemaNode {
+        /// All data nodes, in schema order.
+        pub const ALL: &[SchemaNode] = &[SchemaNode::Interfaces, SchemaNode::InterfacesInterface, SchemaNode::InterfacesInterfaceName, SchemaNode::InterfacesInterfaceDescription, SchemaNode::InterfacesInterfaceType, SchemaNode::InterfacesInterfaceEnabled, SchemaNode::InterfacesInterfaceLinkUpDownTrapEnable, SchemaNode::InterfacesInterfaceAdminStatus, SchemaNode::InterfacesInterfaceOperStatus, SchemaNode::InterfacesInterfaceLastChange, SchemaNode::InterfacesInterfaceIfIndex, SchemaNode::InterfacesInterfacePhysAddress, SchemaNode::InterfacesInterfaceHigherLayerIf, SchemaNode::InterfacesInterfaceLowerLayerIf, SchemaNode::InterfacesInterfaceSpeed, SchemaNode::InterfacesInterfaceStatistics, SchemaNode::InterfacesInterfaceStatisticsDiscontinuityTime, SchemaNode::InterfacesInterfaceStatisticsInOctets, SchemaNode::InterfacesInterfaceStatisticsInDiscards, SchemaNode::InterfacesInterfaceStatisticsInErrors, SchemaNode::InterfacesInterfaceStatisticsOutOctets, SchemaNode::InterfacesInterfaceStatisticsOutDiscards, SchemaNode::InterfacesInterfaceStatisticsOutErrors];
+
+        /// Schema path without module qualifiers or list keys, e.g. `/a/b`.
+        #[must_use]
+        pub fn schema_path(&self) -> &'static str {
+            match *self {
+                SchemaNode::Interfaces => "/interfaces",
+                SchemaNode::InterfacesInterface => "/interfaces/interface",
+                SchemaNode::InterfacesInterfaceName => "/interfaces/interface/name",
+                SchemaNode::InterfacesInterfaceDescription => "/interfaces/interface/description",
+                SchemaNode::InterfacesInterfaceType => "/interfaces/interface/type",
+                SchemaNode::InterfacesInterfaceEnabled => "/interfaces/interface/enabled",
+                SchemaNode::InterfacesInterfaceLinkUpDownTrapEnable => "/interfaces/interface/link-up-down-trap-enable",
+                SchemaNode::InterfacesInterfaceAdminStatus => "/interfaces/interface/admin-status",
+                SchemaNode::InterfacesInterfaceOperStatus => "/interfaces/interface/oper-status",
+                SchemaNode::InterfacesInterfaceLastChange => "/interfaces/interface/last-change",
+                SchemaNode::InterfacesInterfaceIfIndex => "/interfaces/interface/if-index",
+                SchemaNode::InterfacesInterfacePhysAddress => "/interfaces/interface/phys-address",
+                SchemaNode::InterfacesInterfaceHigherLayerIf => "/interfaces/interface/higher-layer-if",
+                SchemaNode::InterfacesInterfaceLowerLayerIf => "/interfaces/interface/lower-layer-if",
+                SchemaNode::InterfacesInterfaceSpeed => "/interfaces/interface/speed",
+                SchemaNode::InterfacesInterfaceStatistics => "/interfaces/interface/statistics",
+                SchemaNode::InterfacesInterfaceStatisticsDiscontinuityTime => "/interfaces/interface/statistics/discontinuity-time",
+                SchemaNode::InterfacesInterfaceStatisticsInOctets => "/interfaces/interface/statistics/in-octets",
+                SchemaNode::InterfacesInterfaceStatisticsInDiscards => "/interfaces/interface/statistics/in-discards",
+                SchemaNode::InterfacesInterfaceStatisticsInErrors => "/interfaces/interface/statistics/in-errors",
+                SchemaNode::InterfacesInterfaceStatisticsOutOctets => "/interfaces/interface/statistics/out-octets",
+                SchemaNode::InterfacesInterfaceStatisticsOutDiscards => "/interfaces/interface/statistics/out-discards",
+                SchemaNode::InterfacesInterfaceStatisticsOutErrors => "/interfaces/interface/statistics/out-errors",
+            }
+        }
+
+        /// Name of the Rust field holding the node in its parent struct.
+        #[must_use]
+        pub fn field_name(&self) -> &'static str {
+            match *self {
+                SchemaNode::Interfaces => "interfaces",
+                SchemaNode::InterfacesInterface => "interface",
+                SchemaNode::InterfacesInterfaceName => "name",
+                SchemaNode::InterfacesInterfaceDescription => "description",
+                SchemaNode::InterfacesInterfaceType => "type_",
+                SchemaNode::InterfacesInterfaceEnabled => "enabled",
+                SchemaNode::InterfacesInterfaceLinkUpDownTrapEnable => "link_up_down_trap_enable",
+                SchemaNode::InterfacesInterfaceAdminStatus => "admin_status",
+                SchemaNode::InterfacesInterfaceOperStatus => "oper_status",
+                SchemaNode::InterfacesInterfaceLastChange => "last_change",
+                SchemaNode::InterfacesInterfaceIfIndex => "if_index",
+                SchemaNode::InterfacesInterfacePhysAddress => "phys_address",
+                SchemaNode::InterfacesInterfaceHigherLayerIf => "higher_layer_if",
+                SchemaNode::InterfacesInterfaceLowerLayerIf => "lower_layer_if",
+                SchemaNode::InterfacesInterfaceSpeed => "speed",
+                SchemaNode::InterfacesInterfaceStatistics => "statistics",
+                SchemaNode::InterfacesInterfaceStatisticsDiscontinuityTime => "discontinuity_time",
+                SchemaNode::InterfacesInterfaceStatisticsInOctets => "in_octets",
+                SchemaNode::InterfacesInterfaceStatisticsInDiscards => "in_discards",
+                SchemaNode::InterfacesInterfaceStatisticsInErrors => "in_errors",
+                SchemaNode::InterfacesInterfaceStatisticsOutOctets => "out_octets",
+                SchemaNode::InterfacesInterfaceStatisticsOutDiscards => "out_discards",
+                SchemaNode::InterfacesInterfaceStatisticsOutErrors => "out_errors",
+            }
+        }
+
+        /// Parent node, or `None` for top-level nodes.
+        #[must_use]
+        pub fn parent(&self) -> Option<SchemaNode> {
+            match *self {
+                SchemaNode::Interfaces => None,
+                SchemaNode::InterfacesInterface => Some(SchemaNode::Interfaces),
+                SchemaNode::InterfacesInterfaceName => Some(SchemaNode::InterfacesInterface),
+                SchemaNode::InterfacesInterfaceDescription => Some(SchemaNode::InterfacesInterface),
+                SchemaNode::InterfacesInterfaceType => Some(SchemaNode::InterfacesInterface),
+                SchemaNode::InterfacesInterfaceEnabled => Some(SchemaNode::InterfacesInterface),
+                SchemaNode::InterfacesInterfaceLinkUpDownTrapEnable => Some(SchemaNode::InterfacesInterface),
+                SchemaNode::InterfacesInterfaceAdminStatus => Some(SchemaNode::InterfacesInterface),
+                SchemaNode::InterfacesInterfaceOperStatus => Some(SchemaNode::InterfacesInterface),
+                SchemaNode::InterfacesInterfaceLastChange => Some(SchemaNode::InterfacesInterface),
+                SchemaNode::InterfacesInterfaceIfIndex => Some(SchemaNode::InterfacesInterface),
+                SchemaNode::InterfacesInterfacePhysAddress => Some(SchemaNode::InterfacesInterface),
+                SchemaNode::InterfacesInterfaceHigherLayerIf => Some(SchemaNode::InterfacesInterface),
+                SchemaNode::InterfacesInterfaceLowerLayerIf => Some(SchemaNode::InterfacesInterface),
+                SchemaNode::InterfacesInterfaceSpeed => Some(SchemaNode::InterfacesInterface),
+                SchemaNode::InterfacesInterfaceStatistics => Some(SchemaNode::InterfacesInterface),
+                SchemaNode::InterfacesInterfaceStatisticsDiscontinuityTime => Some(SchemaNode::InterfacesInterfaceStatistics),
+                SchemaNode::InterfacesInterfaceStatisticsInOctets => Some(SchemaNode::InterfacesInterfaceStatistics),
+                SchemaNode::InterfacesInterfaceStatisticsInDiscards => Some(SchemaNode::InterfacesInterfaceStatistics),
+                SchemaNode::InterfacesInterfaceStatisticsInErrors => Some(SchemaNode::InterfacesInterfaceStatistics),
+                SchemaNode::InterfacesInterfaceStatisticsOutOctets => Some(SchemaNode::InterfacesInterfaceStatistics),
+                SchemaNode::InterfacesInterfaceStatisticsOutDiscards => Some(SchemaNode::InterfacesInterfaceStatistics),
+                SchemaNode::InterfacesInterfaceStatisticsOutErrors => Some(SchemaNode::InterfacesInterfaceStatistics),
+            }
+        }
+
+        /// Names of the key leaves of a list, empty for other nodes.
+        #[must_use]
+        pub fn list_keys(&self) -> &'static [&'static str] {
+            match *self {
+                SchemaNode::Interfaces => &[],
+                SchemaNode::InterfacesInterface => &["name"],
+                SchemaNode::InterfacesInterfaceName => &[],
+                SchemaNode::InterfacesInterfaceDescription => &[],
+                SchemaNode::InterfacesInterfaceType => &[],
+                SchemaNode::InterfacesInterfaceEnabled => &[],
+                SchemaNode::InterfacesInterfaceLinkUpDownTrapEnable => &[],
+                SchemaNode::InterfacesInterfaceAdminStatus => &[],
+                SchemaNode::InterfacesInterfaceOperStatus => &[],
+                SchemaNode::InterfacesInterfaceLastChange => &[],
+                SchemaNode::InterfacesInterfaceIfIndex => &[],
+                SchemaNode::InterfacesInterfacePhysAddress => &[],
+                SchemaNode::InterfacesInterfaceHigherLayerIf => &[],
+                SchemaNode::InterfacesInterfaceLowerLayerIf => &[],
+                SchemaNode::InterfacesInterfaceSpeed => &[],
+                SchemaNode::InterfacesInterfaceStatistics => &[],
+                SchemaNode::InterfacesInterfaceStatisticsDiscontinuityTime => &[],
+                SchemaNode::InterfacesInterfaceStatisticsInOctets => &[],
+                SchemaNode::InterfacesInterfaceStatisticsInDiscards => &[],
+                SchemaNode::InterfacesInterfaceStatisticsInErrors => &[],
+                SchemaNode::InterfacesInterfaceStatisticsOutOctets => &[],
+                SchemaNode::InterfacesInterfaceStatisticsOutDiscards => &[],
+                SchemaNode::InterfacesInterfaceStatisticsOutErrors => &[],
+            }
+        }
+
+        /// XPath condition of the node's `when` statement, for tooling that
+        /// checks data against it. The node is only valid while it holds.
+        #[must_use]
+        pub fn when(&self) -> Option<&'static str> {
+            match *self {
+                SchemaNode::Interfaces => None,
+                SchemaNode::InterfacesInterface => None,
+                SchemaNode::InterfacesInterfaceName => None,
+                SchemaNode::InterfacesInterfaceDescription => None,
+                SchemaNode::InterfacesInterfaceType => None,
+                SchemaNode::InterfacesInterfaceEnabled => None,
+                SchemaNode::InterfacesInterfaceLinkUpDownTrapEnable => None,
+                SchemaNode::InterfacesInterfaceAdminStatus => None,
+                SchemaNode::InterfacesInterfaceOperStatus => None,
+                SchemaNode::InterfacesInterfaceLastChange => None,
+                SchemaNode::InterfacesInterfaceIfIndex => None,
+                SchemaNode::InterfacesInterfacePhysAddress => None,
+                SchemaNode::InterfacesInterfaceHigherLayerIf => None,
+                SchemaNode::InterfacesInterfaceLowerLayerIf => None,
+                SchemaNode::InterfacesInterfaceSpeed => None,
+                SchemaNode::InterfacesInterfaceStatistics => None,
+                SchemaNode::InterfacesInterfaceStatisticsDiscontinuityTime => None,
+                SchemaNode::InterfacesInterfaceStatisticsInOctets => None,
+                SchemaNode::InterfacesInterfaceStatisticsInDiscards => None,
+                SchemaNode::InterfacesInterfaceStatisticsInErrors => None,
+                SchemaNode::InterfacesInterfaceStatisticsOutOctets => None,
+                SchemaNode::InterfacesInterfaceStatisticsOutDiscards => None,
+                SchemaNode::InterfacesInterfaceStatisticsOutErrors => None,
+            }
+        }
+
+        /// Map a RESTCONF `error-path` to the node it identifies.
+        ///
+        /// Module qualifiers and list key predicates are ignored, so
+        /// `/example:a/b[name='x']/c` identifies the node at `/a/b/c`.
+        #[must_use]
+        pub fn from_error_path(error_path: &str) -> Option<SchemaNode> {
+            let path = strip_error_path(error_path);
+            SchemaNode::ALL
+                .iter()
+                .copied()
+                .find(|node| node.schema_path() == path)
+        }
+    }
+
+    /// Remove module qualifiers and key predicates from an instance path.
+    fn strip_error_path(error_path: &str) -> String {
+        let mut path = String::new();
+        let mut depth = 0;
+        let mut quote = None;
+        for c in error_path.chars() {
+            match (quote, c) {
+                (Some(q), _) if c == q => quote = None,
+                (Some(_), _) => {}
+                (None, '\'' | '"') if depth > 0 => quote = Some(c),
+                (None, '[') => depth += 1,
+                (None, ']') => depth -= 1,
+                (None, _) if depth == 0 => path.push(c),
+                _ => {}
+            }
+        }
+        path.split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| segment.rsplit(':').next().unwrap_or(segment))
+            .fold(String::new(), |path, segment| path + "/" + segment)
+    }
+}
+
+/// Validation error for constrained types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    /// Value is outside the allowed range.
+    OutOfRange {
+        /// The rejected value.
+        value: String,
+        /// The constraint it violates, as written in YANG.
+        constraint: String,
+    },
+    /// String length is outside the allowed range.
+    InvalidLength {
+        /// The rejected value.
+        value: String,
+        /// The constraint it violates, as written in YANG.
+        constraint: String,
+    },
+    /// String does not match the required pattern.
+    InvalidPattern {
+        /// The rejected value.
+        value: String,
+        /// The pattern it does not match.
+        pattern: String,
+    },
+    /// A `must` condition does not hold.
+    MustViolation {
+        /// Data path of the node, relative to the validated one.
+        path: String,
+        /// The condition, as written in YANG.
+        condition: String,
+        /// The `error-message` of the `must` statement, if any.
+        message: Option<String>,
+        /// The `error-app-tag` of the `must` statement, if any.
+        app_tag: Option<String>,
+    },
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationError::OutOfRange { value, constraint } => {
+                write!(f, "Value '{}' is outside allowed range: {}", value, constraint)
+            }
+            ValidationError::InvalidLength { value, constraint } => {
+                write!(f, "Value '{}' has invalid length, expected: {}", value, constraint)
+            }
+            ValidationError::InvalidPattern { value, pattern } => {
+                write!(f, "Value '{}' does not match pattern: {}", value, pattern)
+            }
+            ValidationError::MustViolation {
+                path,
+                condition,
+                message,
+                ..
+            } => match message {
+                Some(message) => write!(f, "{}: {}", path, message),
+                None => write!(f, "{}: must condition failed: {}", path, condition),
+            },
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Validated i32 type with range constraints.
+///
+/// Allowed ranges:
+/// - 1 to 2147483647
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidatedInt32Id3f017c88fb5c5e34 {
+    value: i32,
+}
+
+impl ValidatedInt32Id3f017c88fb5c5e34 {
+    /// Create a new validated value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError::OutOfRange` if the value is outside the allowed ranges.
+    pub fn new(value: i32) -> Result<Self, ValidationError> {
+        let valid = (1..=2147483647).contains(&value);
+
+        if valid {
+            Ok(Self { value })
+        } else {
+            Err(ValidationError::OutOfRange {
+                value: value.to_string(),
+                constraint: "1..2147483647".to_string(),
+            })
+        }
+    }
+
+    /// Get the inner value.
+    #[must_use]
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+}
+
+impl TryFrom<i32> for ValidatedInt32Id3f017c88fb5c5e34 {
+    type Error = ValidationError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl serde::Serialize for ValidatedInt32Id3f017c88fb5c5e34 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ValidatedInt32Id3f017c88fb5c5e34 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = i32::deserialize(deserializer)?;
+        Self::new(value).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Validated String type with constraints.
+///
+/// Pattern: \d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(\.\d+)?(Z|[\+\-]\d{2}:\d{2})
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidatedStringIdbf9149867bc51280 {
+    value: String,
+}
+
+impl ValidatedStringIdbf9149867bc51280 {
+    /// Create a new validated string.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError` if the string violates length or pattern constraints.
+    pub fn new(value: String) -> Result<Self, ValidationError> {
+        // Pattern validation
+        let pattern = regex::Regex::new(r"\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(\.\d+)?(Z|[\+\-]\d{2}:\d{2})").map_err(|_| ValidationError::InvalidPattern {
+            value: value.clone(),
+            pattern: r"\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(\.\d+)?(Z|[\+\-]\d{2}:\d{2})".to_string(),
+        })?;
+
+        if !pattern.is_match(&value) {
+            return Err(ValidationError::InvalidPattern {
+                value: value.clone(),
+                pattern: r"\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(\.\d+)?(Z|[\+\-]\d{2}:\d{2})".to_string(),
+            });
+        }
+
+        Ok(Self { value })
+    }
+
+    /// Get the inner value.
+    #[must_use]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl TryFrom<String> for ValidatedStringIdbf9149867bc51280 {
+    type Error = ValidationError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl serde::Serialize for ValidatedStringIdbf9149867bc51280 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ValidatedStringIdbf9149867bc51280 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Self::new(value).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Validated String type with constraints.
+///
+/// Pattern: ([0-9a-fA-F]{2}(:[0-9a-fA-F]{2})*)?
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidatedStringIddaaafcd1ba5ba890 {
+    value: String,
+}
+
+impl ValidatedStringIddaaafcd1ba5ba890 {
+    /// Create a new validated string.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError` if the string violates length or pattern constraints.
+    pub fn new(value: String) -> Result<Self, ValidationError> {
+        // Pattern validation
+        let pattern = regex::Regex::new(r"([0-9a-fA-F]{2}(:[0-9a-fA-F]{2})*)?").map_err(|_| ValidationError::InvalidPattern {
+            value: value.clone(),
+            pattern: r"([0-9a-fA-F]{2}(:[0-9a-fA-F]{2})*)?".to_string(),
+        })?;
+
+        if !pattern.is_match(&value) {
+            return Err(ValidationError::InvalidPattern {
+                value: value.clone(),
+                pattern: r"([0-9a-fA-F]{2}(:[0-9a-fA-F]{2})*)?".to_string(),
+            });
+        }
+
+        Ok(Self { value })
+    }
+
+    /// Get the inner value.
+    #[must_use]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl TryFrom<String> for ValidatedStringIddaaafcd1ba5ba890 {
+    type Error = ValidationError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl serde::Serialize for ValidatedStringIddaaafcd1ba5ba890 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ValidatedStringIddaaafcd1ba5ba890 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Self::new(value).map_err(serde::de::Error::custom)
+    }
+}
+
+/// This type is used by data models that need to reference
+/// interfaces.
+pub type InterfaceRef = String;
+
+/// Interface parameters.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interfaces {
+    /// The list of interfaces on the device.
+    #[serde(rename = "interface")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub interface: Vec<Interface>,
+}
+
+impl Interfaces {
+    /// Merge `other` into `self` following YANG merge semantics.
+    ///
+    /// Leaves set in `other` overwrite those in `self` and unset optional leaves
+    /// are left alone, so `other` acts as a patch. Containers are merged
+    /// recursively, list entries are merged by key with new entries appended,
+    /// and leaf-list values are added if not already present. Choices are
+    /// replaced as a whole.
+    pub fn merge(&mut self, other: &Self) {
+        {
+            let mut index: std::collections::HashMap<String, usize> = self.interface.iter().enumerate().map(|(i, entry)| (entry.merge_key(), i)).collect();
+            for theirs in &other.interface {
+                match index.get(&theirs.merge_key()) {
+                    Some(&i) => self.interface[i].merge(theirs),
+                    None => {
+                        index.insert(theirs.merge_key(), self.interface.len());
+                        self.interface.push(theirs.clone());
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The list of interfaces on the device.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interface {
+    /// The name of the interface.
+    #[serde(rename = "name")]
+    pub name: String,
+    /// A textual description of the interface.
+    #[serde(rename = "description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The type of the interface.
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// This leaf contains the configured, desired state of the
+    /// interface.
+    #[serde(rename = "enabled")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Controls whether linkUp/linkDown SNMP notifications
+    /// should be generated for this interface.
+    #[serde(rename = "link-up-down-trap-enable")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_up_down_trap_enable: Option<String>,
+    /// The desired state of the interface.
+    #[serde(rename = "admin-status")]
+    pub admin_status: String,
+    /// The current operational state of the interface.
+    #[serde(rename = "oper-status")]
+    pub oper_status: String,
+    /// The time the interface entered its current operational
+    /// state.
+    #[serde(rename = "last-change")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_change: Option<ValidatedStringIdbf9149867bc51280>,
+    /// The ifIndex value for the ifEntry represented by this
+    /// interface.
+    #[serde(rename = "if-index")]
+    pub if_index: ValidatedInt32Id3f017c88fb5c5e34,
+    /// The interface's address at its protocol sub-layer.
+    #[serde(rename = "phys-address")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phys_address: Option<ValidatedStringIddaaafcd1ba5ba890>,
+    /// A list of references to interfaces layered on top of this
+    /// interface.
+    #[serde(rename = "higher-layer-if")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub higher_layer_if: Vec<InterfaceRef>,
+    /// A list of references to interfaces layered underneath this
+    /// interface.
+    #[serde(rename = "lower-layer-if")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub lower_layer_if: Vec<InterfaceRef>,
+    /// An estimate of the interface's current bandwidth in bits
+    /// per second.
+    #[serde(rename = "speed")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speed: Option<u64>,
+    /// A collection of interface-related statistics objects.
+    #[serde(rename = "statistics")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statistics: Option<Statistics>,
+}
+
+impl Interface {
+    /// Merge `other` into `self` following YANG merge semantics.
+    ///
+    /// Leaves set in `other` overwrite those in `self` and unset optional leaves
+    /// are left alone, so `other` acts as a patch. Containers are merged
+    /// recursively, list entries are merged by key with new entries appended,
+    /// and leaf-list values are added if not already present. Choices are
+    /// replaced as a whole.
+    pub fn merge(&mut self, other: &Self) {
+        if other.description.is_some() {
+            self.description.clone_from(&other.description);
+        }
+        self.type_.clone_from(&other.type_);
+        if other.enabled.is_some() {
+            self.enabled.clone_from(&other.enabled);
+        }
+        if other.link_up_down_trap_enable.is_some() {
+            self.link_up_down_trap_enable.clone_from(&other.link_up_down_trap_enable);
+        }
+        self.admin_status.clone_from(&other.admin_status);
+        self.oper_status.clone_from(&other.oper_status);
+        if other.last_change.is_some() {
+            self.last_change.clone_from(&other.last_change);
+        }
+        self.if_index.clone_from(&other.if_index);
+        if other.phys_address.is_some() {
+            self.phys_address.clone_from(&other.phys_address);
+        }
+        {
+            let mut seen: std::collections::HashSet<String> = self.higher_layer_if.iter().map(|value| serde_json::to_string(value).unwrap_or_default()).collect();
+            self.higher_layer_if.extend(other.higher_layer_if.iter().filter(|value| seen.insert(serde_json::to_string(value).unwrap_or_default())).cloned());
+        }
+        {
+            let mut seen: std::collections::HashSet<String> = self.lower_layer_if.iter().map(|value| serde_json::to_string(value).unwrap_or_default()).collect();
+            self.lower_layer_if.extend(other.lower_layer_if.iter().filter(|value| seen.insert(serde_json::to_string(value).unwrap_or_default())).cloned());
+        }
+        if other.speed.is_some() {
+            self.speed.clone_from(&other.speed);
+        }
+        if let Some(theirs) = &other.statistics {
+            match &mut self.statistics {
+                Some(mine) => mine.merge(theirs),
+                None => self.statistics = Some(theirs.clone()),
+            }
+        }
+    }
+
+    /// Serialized key, identifying the entry when lists are merged.
+    #[allow(dead_code)]
+    fn merge_key(&self) -> String {
+        serde_json::to_string(&(&self.name,)).unwrap_or_default()
+    }
+}
+
+/// Key of a `interface` entry.
+///
+/// Displays as the entry's RESTCONF list instance fragment, `interface=<name>`, with
+/// each value percent-encoded, and parses from it.
+#[derive(Debug, Clone)]
+pub struct InterfaceKey {
+    /// The `name` key leaf.
+    pub name: String,
+}
+
+impl Interface {
+    /// The key of this entry.
+    #[must_use]
+    pub fn key(&self) -> InterfaceKey {
+        InterfaceKey {
+            name: self.name.clone(),
+        }
+    }
+}
+
+impl std::fmt::Display for InterfaceKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let values = [
+            rustconf_runtime::list_key::key_value(&self.name),
+        ];
+        rustconf_runtime::list_key::write_fragment(f, "interface", &values)
+    }
+}
+
+impl From<InterfaceKey> for String {
+    fn from(key: InterfaceKey) -> Self {
+        key.to_string()
+    }
+}
+
+impl std::str::FromStr for InterfaceKey {
+    type Err = rustconf_runtime::ListKeyError;
+
+    fn from_str(fragment: &str) -> Result<Self, Self::Err> {
+        let values = rustconf_runtime::list_key::parse_fragment(fragment, "interface", 1)?;
+        Ok(Self {
+            name: rustconf_runtime::list_key::parse_key_value(&values[0])?,
+        })
+    }
+}
+
+/// A collection of interface-related statistics objects.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Statistics {
+    /// The time on the most recent occasion at which any one or
+    /// more of this interface's counters suffered a
+    /// discontinuity.
+    #[serde(rename = "discontinuity-time")]
+    pub discontinuity_time: ValidatedStringIdbf9149867bc51280,
+    /// The total number of octets received on the interface,
+    /// including framing characters.
+    #[serde(rename = "in-octets")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_octets: Option<u64>,
+    /// The number of inbound packets that were chosen to be
+    /// discarded even though no errors had been detected.
+    #[serde(rename = "in-discards")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_discards: Option<u32>,
+    /// The number of inbound packets that contained errors
+    /// preventing them from being deliverable to a higher-layer
+    /// protocol.
+    #[serde(rename = "in-errors")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_errors: Option<u32>,
+    /// The total number of octets transmitted out of the
+    /// interface, including framing characters.
+    #[serde(rename = "out-octets")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub out_octets: Option<u64>,
+    /// The number of outbound packets that were chosen to be
+    /// discarded even though no errors had been detected.
+    #[serde(rename = "out-discards")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub out_discards: Option<u32>,
+    /// The number of outbound packets that could not be
+    /// transmitted because of errors.
+    #[serde(rename = "out-errors")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub out_errors: Option<u32>,
+}
+
+impl Statistics {
+    /// Merge `other` into `self` following YANG merge semantics.
+    ///
+    /// Leaves set in `other` overwrite those in `self` and unset optional leaves
+    /// are left alone, so `other` acts as a patch. Containers are merged
+    /// recursively, list entries are merged by key with new entries appended,
+    /// and leaf-list values are added if not already present. Choices are
+    /// replaced as a whole.
+    pub fn merge(&mut self, other: &Self) {
+        self.discontinuity_time.clone_from(&other.discontinuity_time);
+        if other.in_octets.is_some() {
+            self.in_octets.clone_from(&other.in_octets);
+        }
+        if other.in_discards.is_some() {
+            self.in_discards.clone_from(&other.in_discards);
+        }
+        if other.in_errors.is_some() {
+            self.in_errors.clone_from(&other.in_errors);
+        }
+        if other.out_octets.is_some() {
+            self.out_octets.clone_from(&other.out_octets);
+        }
+        if other.out_discards.is_some() {
+            self.out_discards.clone_from(&other.out_discards);
+        }
+        if other.out_errors.is_some() {
+            self.out_errors.clone_from(&other.out_errors);
+        }
+    }
+}
+
+impl Interfaces {
+    /// The value at the relative `path` below this `interfaces`, if the path is in
+    /// the schema and a value is set there.
+    ///
+    /// List entries are selected by key as in RESTCONF URLs: `list=key1,key2`.
+    #[must_use]
+    pub fn get_by_path(&self, path: &str) -> Option<serde_json::Value> {
+        let data = serde_json::to_value(self).ok()?;
+        rustconf_runtime::data_path::get(&data, "/interfaces", path, |path| {
+            schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())
+        })
+    }
+
+    /// Set the value at `path` below this `interfaces`, creating missing
+    /// containers. A `null` value removes the node.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is not in the schema, selects a list entry
+    /// that does not exist, or the value does not fit the node.
+    pub fn set_by_path(
+        &mut self,
+        path: &str,
+        value: serde_json::Value,
+    ) -> Result<(), rustconf_runtime::DataPathError> {
+        let mut data = serde_json::to_value(&*self)?;
+        rustconf_runtime::data_path::set(&mut data, "/interfaces", path, value, |path| {
+            schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())
+        })?;
+        *self = serde_json::from_value(data)?;
+        Ok(())
+    }
+}
+
+impl Interface {
+    /// The value at the relative `path` below this `interface`, if the path is in
+    /// the schema and a value is set there.
+    ///
+    /// List entries are selected by key as in RESTCONF URLs: `list=key1,key2`.
+    #[must_use]
+    pub fn get_by_path(&self, path: &str) -> Option<serde_json::Value> {
+        let data = serde_json::to_value(self).ok()?;
+        rustconf_runtime::data_path::get(&data, "/interfaces/interface", path, |path| {
+            schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())
+        })
+    }
+
+    /// Set the value at `path` below this `interface`, creating missing
+    /// containers. A `null` value removes the node.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is not in the schema, selects a list entry
+    /// that does not exist, or the value does not fit the node.
+    pub fn set_by_path(
+        &mut self,
+        path: &str,
+        value: serde_json::Value,
+    ) -> Result<(), rustconf_runtime::DataPathError> {
+        let mut data = serde_json::to_value(&*self)?;
+        rustconf_runtime::data_path::set(&mut data, "/interfaces/interface", path, value, |path| {
+            schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())
+        })?;
+        *self = serde_json::from_value(data)?;
+        Ok(())
+    }
+}
+
+impl Statistics {
+    /// The value at the relative `path` below this `statistics`, if the path is in
+    /// the schema and a value is set there.
+    ///
+    /// List entries are selected by key as in RESTCONF URLs: `list=key1,key2`.
+    #[must_use]
+    pub fn get_by_path(&self, path: &str) -> Option<serde_json::Value> {
+        let data = serde_json::to_value(self).ok()?;
+        rustconf_runtime::data_path::get(&data, "/interfaces/interface/statistics", path, |path| {
+            schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())
+        })
+    }
+
+    /// Set the value at `path` below this `statistics`, creating missing
+    /// containers. A `null` value removes the node.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is not in the schema, selects a list entry
+    /// that does not exist, or the value does not fit the node.
+    pub fn set_by_path(
+        &mut self,
+        path: &str,
+        value: serde_json::Value,
+    ) -> Result<(), rustconf_runtime::DataPathError> {
+        let mut data = serde_json::to_value(&*self)?;
+        rustconf_runtime::data_path::set(&mut data, "/interfaces/interface/statistics", path, value, |path| {
+            schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())
+        })?;
+        *self = serde_json::from_value(data)?;
+        Ok(())
+    }
+}
+
+/// Register the types of the top-level nodes of the `ietf-interfaces` module, for
+/// decoding `anydata` content that carries them.
+pub fn register_anydata(registry: &mut rustconf_runtime::AnyDataRegistry) {
+    registry.register::<Interfaces>("ietf-interfaces:interfaces");
+}
+/// RESTCONF operations.
+pub mod operations {
+    use super::*;
+
+    /// Percent-encode a string for use in URLs.
+    ///
+    /// This function encodes special characters according to RFC 3986.
+    #[allow(dead_code)]
+    fn percent_encode(s: &str) -> String {
+        s.chars()
+            .map(|c| match c {
+                'A'..='Z' | 'a'..='z' | '0'..='9' | '-' | '_' | '.' | '~' => c.to_string(),
+                _ => format!("%{:02X}", c as u8),
+            })
+            .collect()
+    }
+
+    /// RESTCONF CRUD operations for data resources.
+    pub mod crud {
+        use super::*;
+
+        /// Build the RESTCONF URL path for the interfaces container.
+        #[allow(dead_code)]
+        fn interfaces_path() -> String {
+            "/restconf/data/interfaces".to_string()
+        }
+
+        /// Retrieve the interfaces container.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the operation fails.
+        pub async fn get_interfaces() -> Result<Interfaces, RpcError> {
+            let _path = interfaces_path();
+            // TODO: Implement GET request to RESTCONF server
+            unimplemented!("GET operation not yet implemented")
+        }
+
+        /// Discover the methods permitted on the interfaces container.
+        ///
+        /// Issues OPTIONS and parses the `Allow` and `Accept-Patch` headers.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the request fails or the server responds with a non-2xx status.
+        pub async fn options_interfaces<T: HttpTransport>(client: &RestconfClient<T>) -> Result<ResourceCapabilities, RpcError> {
+            let url = format!("{}{}", client.base_url().trim_end_matches('/'), interfaces_path());
+            let request = HttpRequest {
+                method: HttpMethod::OPTIONS,
+                url,
+                headers: vec![],
+                body: None,
+                context: Default::default(),
+            };
+            let response = client.execute(request).await?;
+            ResourceCapabilities::try_from(&response)
+        }
+
+        /// Check whether the interfaces container exists.
+        ///
+        /// Issues HEAD, falling back to GET with `depth=1` if the server rejects HEAD.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the request fails or the server responds with a status other than 2xx or 404.
+        pub async fn exists_interfaces<T: HttpTransport>(client: &RestconfClient<T>) -> Result<bool, RpcError> {
+            client.exists(&interfaces_path()).await
+        }
+
+        /// Replace the interfaces container.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the operation fails.
+        pub async fn put_interfaces(_data: Interfaces) -> Result<(), RpcError> {
+            let _path = interfaces_path();
+            // TODO: Implement PUT request to RESTCONF server
+            unimplemented!("PUT operation not yet implemented")
+        }
+
+        /// Partially update the interfaces container.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the operation fails.
+        pub async fn patch_interfaces(_data: Interfaces) -> Result<(), RpcError> {
+            let _path = interfaces_path();
+            // TODO: Implement PATCH request to RESTCONF server
+            unimplemented!("PATCH operation not yet implemented")
+        }
+
+        /// Delete the interfaces container.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the operation fails.
+        pub async fn delete_interfaces() -> Result<(), RpcError> {
+            let _path = interfaces_path();
+            // TODO: Implement DELETE request to RESTCONF server
+            unimplemented!("DELETE operation not yet implemented")
+        }
+
+    }
+    /// Typed handles for the data resources of the module.
+    ///
+    /// Bring `resources::IetfInterfacesResources` into scope to reach them from a `RestconfClient`.
+    pub mod resources {
+        use super::*;
+
+        /// Deserialize the contents of the `member` envelope of a response.
+        fn decode<V: serde::de::DeserializeOwned>(member: &str, response: &HttpResponse) -> Result<V, RpcError> {
+            rustconf_runtime::unwrap_envelope(member, &response.body)
+                .map_err(|e| RpcError::DeserializationError(format!("Failed to deserialize {}: {}", member, e)))
+        }
+
+        /// Serialize `value` inside the `member` envelope.
+        fn encode<V: serde::Serialize>(member: &str, value: &V) -> Result<Vec<u8>, RpcError> {
+            rustconf_runtime::wrap_envelope(member, value)
+                .map_err(|e| RpcError::SerializationError(format!("Failed to serialize {}: {}", member, e)))
+        }
+
+        /// Entry points to the data resources of the `ietf-interfaces` module.
+        pub trait IetfInterfacesResources<T: HttpTransport> {
+            /// Interface parameters.
+            fn interfaces(&self) -> InterfacesResource<'_, T>;
+        }
+
+        impl<T: HttpTransport> IetfInterfacesResources<T> for RestconfClient<T> {
+            fn interfaces(&self) -> InterfacesResource<'_, T> {
+                InterfacesResource { client: self, path: "/restconf/data/interfaces".to_string() }
+            }
+        }
+
+        /// Handle for the `interfaces` container.
+        #[must_use = "a resource handle does nothing until one of its methods is called"]
+        pub struct InterfacesResource<'a, T: HttpTransport> {
+            client: &'a RestconfClient<T>,
+            path: String,
+        }
+
+        impl<T: HttpTransport> InterfacesResource<'_, T> {
+            /// The RESTCONF path of the resource.
+            #[must_use]
+            pub fn path(&self) -> &str {
+                &self.path
+            }
+
+            /// Retrieve the container.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get(&self) -> Result<Interfaces, RpcError> {
+                self.get_full().await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`get`](Self::get), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get_full(&self) -> Result<ResponseEnvelope<Interfaces>, RpcError> {
+                let response = self.client.raw_request(HttpMethod::GET, &self.path, None).await?;
+                let value = decode("ietf-interfaces:interfaces", &response)?;
+                Ok(ResponseEnvelope::new(response, value))
+            }
+
+            /// Retrieve the operational state of the container with the origin of each node.
+            ///
+            /// Requires a server implementing NMDA (RFC 8527). Origins are keyed by
+            /// JSON Pointers into the response, starting with the module-qualified
+            /// member of the resource.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get_with_origin(&self) -> Result<rustconf_runtime::WithOrigin<Interfaces>, RpcError> {
+                let path = rustconf_runtime::origin::with_origin_path(&self.path);
+                let response = self.client.raw_request(HttpMethod::GET, &path, None).await?;
+                let value = decode("ietf-interfaces:interfaces", &response)?;
+                let origins = rustconf_runtime::OriginTable::from_json(&response.body)?;
+                Ok(rustconf_runtime::WithOrigin { data: value, origins })
+            }
+
+            /// Replace the container with `data`.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn put(&self, data: &Interfaces) -> Result<(), RpcError> {
+                self.put_full(data).await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`put`](Self::put), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn put_full(&self, data: &Interfaces) -> Result<ResponseEnvelope<()>, RpcError> {
+                let body = encode("ietf-interfaces:interfaces", data)?;
+                let response = self.client.raw_request(HttpMethod::PUT, &self.path, Some(body.into())).await?;
+                Ok(ResponseEnvelope::new(response, ()))
+            }
+
+            /// Merge `data` into the container.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn patch(&self, data: &Interfaces) -> Result<(), RpcError> {
+                self.patch_full(data).await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`patch`](Self::patch), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn patch_full(&self, data: &Interfaces) -> Result<ResponseEnvelope<()>, RpcError> {
+                let body = encode("ietf-interfaces:interfaces", data)?;
+                let response = self.client.raw_request(HttpMethod::PATCH, &self.path, Some(body.into())).await?;
+                Ok(ResponseEnvelope::new(response, ()))
+            }
+
+            /// Delete the container.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn delete(&self) -> Result<(), RpcError> {
+                self.delete_full().await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`delete`](Self::delete), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn delete_full(&self) -> Result<ResponseEnvelope<()>, RpcError> {
+                let response = self.client.raw_request(HttpMethod::DELETE, &self.path, None).await?;
+                Ok(ResponseEnvelope::new(response, ()))
+            }
+
+            /// Check whether the resource exists.
+            ///
+            /// Issues HEAD, falling back to GET with `depth=1` if the server rejects HEAD.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a status other than 2xx or 404.
+            pub async fn exists(&self) -> Result<bool, RpcError> {
+                self.client.exists(&self.path).await
+            }
+
+            /// Discover the methods permitted on the resource.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn options(&self) -> Result<ResourceCapabilities, RpcError> {
+                let response = self.client.raw_request(HttpMethod::OPTIONS, &self.path, None).await?;
+                ResourceCapabilities::try_from(&response)
+            }
+        }
+    }
+}
+
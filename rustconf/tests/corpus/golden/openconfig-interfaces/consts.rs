@@ -0,0 +1,47 @@
+// This file is automatically generated by rustconf.
+// DO NOT EDIT MANUALLY.
+
+#![allow(unused_imports, unused_variables, dead_code, clippy::module_inception)]
+
+/// Name of the YANG module.
+pub const MODULE_NAME: &str = "openconfig-interfaces";
+/// XML namespace of the YANG module.
+pub const MODULE_NAMESPACE: &str = "http://openconfig.net/yang/interfaces";
+/// Prefix of the YANG module.
+pub const MODULE_PREFIX: &str = "oc-if";
+/// Organization responsible for the YANG module, if stated.
+pub const MODULE_ORGANIZATION: Option<&str> = Some("OpenConfig working group");
+/// Contact information for the YANG module's authors, if stated.
+pub const MODULE_CONTACT: Option<&str> = Some("OpenConfig working group\nnetopenconfig@googlegroups.com");
+/// Description of the YANG module, if stated.
+pub const MODULE_DESCRIPTION: Option<&str> = Some("Model for managing network interfaces and subinterfaces.  This\nmodule also defines convenience types / groupings for other\nmodels to create references to interfaces:\n\n base-interface-ref (type) -  reference to a base interface\n interface-ref (grouping) -  container for reference to a\n   interface + subinterface\n interface-ref-state (grouping) - container for read-only\n   (opstate) reference to interface + subinterface\n\nThis model reuses data items defined in the IETF YANG model for\ninterfaces described by RFC 7223 with an alternate structure\n(particularly for operational state data) and with\nadditional configuration items.\n\nPortions of this code were derived from IETF RFC 7223.\nPlease reproduce this note if possible.\n\nAbridged for the rustconf test corpus: the interface type is a\nstring rather than an identityref, and hold-time, counters other\nthan octets and the interface-ref groupings are left out.\nSubinterfaces are left out too, since their config and state\ncontainers would generate the same Rust types as those of the\ninterface.");
+/// Revision of the YANG module the code was generated from, if known.
+pub const MODULE_REVISION: Option<&str> = Some("2019-11-19");
+/// Modules the server must implement, with their revisions.
+pub const IMPLEMENTED_MODULES: &[(&str, Option<&str>)] = &[("openconfig-interfaces", MODULE_REVISION)];
+/// Modules imported by the YANG module, with the revisions used.
+pub const IMPORTED_MODULES: &[(&str, Option<&str>)] = &[("ietf-interfaces", Some("2018-02-20")), ("openconfig-yang-types", Some("2018-11-21")), ("openconfig-types", Some("2019-04-16")), ("openconfig-extensions", Some("2020-06-16"))];
+/// Media type for JSON data and operation bodies (RFC 8040).
+pub const YANG_DATA_JSON: &str = "application/yang-data+json";
+/// Media type for XML data and operation bodies (RFC 8040).
+pub const YANG_DATA_XML: &str = "application/yang-data+xml";
+/// Media type for YANG Patch documents (RFC 8072).
+pub const YANG_PATCH_JSON: &str = "application/yang-patch+json";
+/// Media type of notification event streams.
+pub const EVENT_STREAM: &str = "text/event-stream";
+/// Root of the RESTCONF API.
+pub const RESTCONF_ROOT: &str = "/restconf";
+/// Root of the datastore resource.
+pub const DATA_PATH: &str = "/restconf/data";
+/// Root of the operations resource.
+pub const OPERATIONS_PATH: &str = "/restconf/operations";
+/// Stream list advertised by the server (RFC 8040 section 9.3).
+pub const STREAMS_PATH: &str = "/restconf/data/ietf-restconf-monitoring:restconf-state/streams";
+/// Name of the default notification stream (RFC 8040 section 6.2).
+pub const NETCONF_STREAM: &str = "NETCONF";
+/// Notification streams carrying this module's notifications.
+pub const STREAMS: &[&str] = &[];
+/// Qualified names of all RPCs defined by the module.
+pub const OPERATIONS: &[&str] = &[];
+/// Qualified names of all notifications defined by the module.
+pub const NOTIFICATIONS: &[&str] = &[];
@@ -0,0 +1,71 @@
+// This file is automatically generated by rustconf.
+// DO NOT EDIT MANUALLY.
+//
+// Source YANG module: openconfig-interfaces
+// Namespace: http://openconfig.net/yang/interfaces
+// Prefix: oc-if
+
+//! Model for managing network interfaces and subinterfaces.  This
+//! module also defines convenience types / groupings for other
+//! models to create references to interfaces:
+//!
+//! base-interface-ref (type) -  reference to a base interface
+//! interface-ref (grouping) -  container for reference to a
+//! interface + subinterface
+//! interface-ref-state (grouping) - container for read-only
+//! (opstate) reference to interface + subinterface
+//!
+//! This model reuses data items defined in the IETF YANG model for
+//! interfaces described by RFC 7223 with an alternate structure
+//! (particularly for operational state data) and with
+//! additional configuration items.
+//!
+//! Portions of this code were derived from IETF RFC 7223.
+//! Please reproduce this note if possible.
+//!
+//! Abridged for the rustconf test corpus: the interface type is a
+//! string rather than an identityref, and hold-time, counters other
+//! than octets and the interface-ref groupings are left out.
+//! Subinterfaces are left out too, since their config and state
+//! containers would generate the same Rust types as those of the
+//! interface.
+//!
+//! # Organization
+//!
+//! OpenConfig working group
+//!
+//! # Contact
+//!
+//! ```text
+//! OpenConfig working group
+//! netopenconfig@googlegroups.com
+//! ```
+
+#![allow(unused_imports, unused_variables, dead_code, clippy::module_inception)]
+
+/// Data types of the openconfig-interfaces YANG module.
+pub mod types;
+/// RESTCONF constants for the openconfig-interfaces YANG module.
+pub mod consts;
+/// Schema nodes of the openconfig-interfaces YANG module.
+pub mod schema;
+/// RESTCONF operations.
+pub mod operations;
+/// Types enforcing YANG range, length and pattern restrictions.
+pub mod validation;
+
+pub use types::*;
+pub use operations::*;
+
+pub use rustconf_runtime::{
+    RestconfClient,
+    HttpTransport,
+    HttpRequest,
+    HttpResponse,
+    HttpMethod,
+    RpcError,
+    ResponseEnvelope,
+    RequestInterceptor,
+    Transaction,
+    with_rollback,
+};
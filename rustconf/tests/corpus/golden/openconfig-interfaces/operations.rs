@@ -0,0 +1,278 @@
+// This file is automatically generated by rustconf.
+// DO NOT EDIT MANUALLY.
+
+#![allow(unused_imports, unused_variables, dead_code, clippy::module_inception)]
+
+use rustconf_runtime::{RestconfClient, HttpTransport, HttpRequest, HttpResponse, HttpMethod, RpcError, ResourceCapabilities, ResponseEnvelope};
+use serde::{Deserialize, Serialize};
+use super::types::*;
+use super::validation::*;
+use super::consts;
+
+/// RESTCONF operations.
+pub mod operations {
+    use super::*;
+
+    /// Percent-encode a string for use in URLs.
+    ///
+    /// This function encodes special characters according to RFC 3986.
+    #[allow(dead_code)]
+    fn percent_encode(s: &str) -> String {
+        s.chars()
+            .map(|c| match c {
+                'A'..='Z' | 'a'..='z' | '0'..='9' | '-' | '_' | '.' | '~' => c.to_string(),
+                _ => format!("%{:02X}", c as u8),
+            })
+            .collect()
+    }
+
+    /// RESTCONF CRUD operations for data resources.
+    pub mod crud {
+        use super::*;
+
+        /// Build the RESTCONF URL path for the interfaces container.
+        #[allow(dead_code)]
+        fn interfaces_path() -> String {
+            "/restconf/data/interfaces".to_string()
+        }
+
+        /// Retrieve the interfaces container.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the operation fails.
+        pub async fn get_interfaces() -> Result<Interfaces, RpcError> {
+            let _path = interfaces_path();
+            // TODO: Implement GET request to RESTCONF server
+            unimplemented!("GET operation not yet implemented")
+        }
+
+        /// Discover the methods permitted on the interfaces container.
+        ///
+        /// Issues OPTIONS and parses the `Allow` and `Accept-Patch` headers.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the request fails or the server responds with a non-2xx status.
+        pub async fn options_interfaces<T: HttpTransport>(client: &RestconfClient<T>) -> Result<ResourceCapabilities, RpcError> {
+            let url = format!("{}{}", client.base_url().trim_end_matches('/'), interfaces_path());
+            let request = HttpRequest {
+                method: HttpMethod::OPTIONS,
+                url,
+                headers: vec![],
+                body: None,
+                context: Default::default(),
+            };
+            let response = client.execute(request).await?;
+            ResourceCapabilities::try_from(&response)
+        }
+
+        /// Check whether the interfaces container exists.
+        ///
+        /// Issues HEAD, falling back to GET with `depth=1` if the server rejects HEAD.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the request fails or the server responds with a status other than 2xx or 404.
+        pub async fn exists_interfaces<T: HttpTransport>(client: &RestconfClient<T>) -> Result<bool, RpcError> {
+            client.exists(&interfaces_path()).await
+        }
+
+        /// Replace the interfaces container.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the operation fails.
+        pub async fn put_interfaces(_data: Interfaces) -> Result<(), RpcError> {
+            let _path = interfaces_path();
+            // TODO: Implement PUT request to RESTCONF server
+            unimplemented!("PUT operation not yet implemented")
+        }
+
+        /// Partially update the interfaces container.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the operation fails.
+        pub async fn patch_interfaces(_data: Interfaces) -> Result<(), RpcError> {
+            let _path = interfaces_path();
+            // TODO: Implement PATCH request to RESTCONF server
+            unimplemented!("PATCH operation not yet implemented")
+        }
+
+        /// Delete the interfaces container.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the operation fails.
+        pub async fn delete_interfaces() -> Result<(), RpcError> {
+            let _path = interfaces_path();
+            // TODO: Implement DELETE request to RESTCONF server
+            unimplemented!("DELETE operation not yet implemented")
+        }
+
+    }
+    /// Typed handles for the data resources of the module.
+    ///
+    /// Bring `resources::OpenconfigInterfacesResources` into scope to reach them from a `RestconfClient`.
+    pub mod resources {
+        use super::*;
+
+        /// Deserialize the contents of the `member` envelope of a response.
+        fn decode<V: serde::de::DeserializeOwned>(member: &str, response: &HttpResponse) -> Result<V, RpcError> {
+            rustconf_runtime::unwrap_envelope(member, &response.body)
+                .map_err(|e| RpcError::DeserializationError(format!("Failed to deserialize {}: {}", member, e)))
+        }
+
+        /// Serialize `value` inside the `member` envelope.
+        fn encode<V: serde::Serialize>(member: &str, value: &V) -> Result<Vec<u8>, RpcError> {
+            rustconf_runtime::wrap_envelope(member, value)
+                .map_err(|e| RpcError::SerializationError(format!("Failed to serialize {}: {}", member, e)))
+        }
+
+        /// Entry points to the data resources of the `openconfig-interfaces` module.
+        pub trait OpenconfigInterfacesResources<T: HttpTransport> {
+            /// Top level container for interfaces, including configuration
+            /// and state data.
+            fn interfaces(&self) -> InterfacesResource<'_, T>;
+        }
+
+        impl<T: HttpTransport> OpenconfigInterfacesResources<T> for RestconfClient<T> {
+            fn interfaces(&self) -> InterfacesResource<'_, T> {
+                InterfacesResource { client: self, path: "/restconf/data/interfaces".to_string() }
+            }
+        }
+
+        /// Handle for the `interfaces` container.
+        #[must_use = "a resource handle does nothing until one of its methods is called"]
+        pub struct InterfacesResource<'a, T: HttpTransport> {
+            client: &'a RestconfClient<T>,
+            path: String,
+        }
+
+        impl<T: HttpTransport> InterfacesResource<'_, T> {
+            /// The RESTCONF path of the resource.
+            #[must_use]
+            pub fn path(&self) -> &str {
+                &self.path
+            }
+
+            /// Retrieve the container.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get(&self) -> Result<Interfaces, RpcError> {
+                self.get_full().await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`get`](Self::get), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get_full(&self) -> Result<ResponseEnvelope<Interfaces>, RpcError> {
+                let response = self.client.raw_request(HttpMethod::GET, &self.path, None).await?;
+                let value = decode("openconfig-interfaces:interfaces", &response)?;
+                Ok(ResponseEnvelope::new(response, value))
+            }
+
+            /// Retrieve the operational state of the container with the origin of each node.
+            ///
+            /// Requires a server implementing NMDA (RFC 8527). Origins are keyed by
+            /// JSON Pointers into the response, starting with the module-qualified
+            /// member of the resource.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get_with_origin(&self) -> Result<rustconf_runtime::WithOrigin<Interfaces>, RpcError> {
+                let path = rustconf_runtime::origin::with_origin_path(&self.path);
+                let response = self.client.raw_request(HttpMethod::GET, &path, None).await?;
+                let value = decode("openconfig-interfaces:interfaces", &response)?;
+                let origins = rustconf_runtime::OriginTable::from_json(&response.body)?;
+                Ok(rustconf_runtime::WithOrigin { data: value, origins })
+            }
+
+            /// Replace the container with `data`.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn put(&self, data: &Interfaces) -> Result<(), RpcError> {
+                self.put_full(data).await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`put`](Self::put), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn put_full(&self, data: &Interfaces) -> Result<ResponseEnvelope<()>, RpcError> {
+                let body = encode("openconfig-interfaces:interfaces", data)?;
+                let response = self.client.raw_request(HttpMethod::PUT, &self.path, Some(body.into())).await?;
+                Ok(ResponseEnvelope::new(response, ()))
+            }
+
+            /// Merge `data` into the container.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn patch(&self, data: &Interfaces) -> Result<(), RpcError> {
+                self.patch_full(data).await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`patch`](Self::patch), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn patch_full(&self, data: &Interfaces) -> Result<ResponseEnvelope<()>, RpcError> {
+                let body = encode("openconfig-interfaces:interfaces", data)?;
+                let response = self.client.raw_request(HttpMethod::PATCH, &self.path, Some(body.into())).await?;
+                Ok(ResponseEnvelope::new(response, ()))
+            }
+
+            /// Delete the container.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn delete(&self) -> Result<(), RpcError> {
+                self.delete_full().await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`delete`](Self::delete), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn delete_full(&self) -> Result<ResponseEnvelope<()>, RpcError> {
+                let response = self.client.raw_request(HttpMethod::DELETE, &self.path, None).await?;
+                Ok(ResponseEnvelope::new(response, ()))
+            }
+
+            /// Check whether the resource exists.
+            ///
+            /// Issues HEAD, falling back to GET with `depth=1` if the server rejects HEAD.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a status other than 2xx or 404.
+            pub async fn exists(&self) -> Result<bool, RpcError> {
+                self.client.exists(&self.path).await
+            }
+
+            /// Discover the methods permitted on the resource.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn options(&self) -> Result<ResourceCapabilities, RpcError> {
+                let response = self.client.raw_request(HttpMethod::OPTIONS, &self.path, None).await?;
+                ResourceCapabilities::try_from(&response)
+            }
+        }
+    }
+}
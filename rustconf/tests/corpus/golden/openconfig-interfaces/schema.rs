@@ -0,0 +1,261 @@
+// This file is automatically generated by rustconf.
+// DO NOT EDIT MANUALLY.
+
+#![allow(unused_imports, unused_variables, dead_code, clippy::module_inception)]
+
+/// Data nodes of the openconfig-interfaces YANG module, e.g. for mapping the `error-path` of
+/// a RESTCONF error to the field that caused it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SchemaNode {
+    /// `/interfaces`
+    Interfaces,
+    /// `/interfaces/interface`
+    InterfacesInterface,
+    /// `/interfaces/interface/name`
+    InterfacesInterfaceName,
+    /// `/interfaces/interface/config`
+    InterfacesInterfaceConfig,
+    /// `/interfaces/interface/config/name`
+    InterfacesInterfaceConfigName,
+    /// `/interfaces/interface/config/type`
+    InterfacesInterfaceConfigType,
+    /// `/interfaces/interface/config/mtu`
+    InterfacesInterfaceConfigMtu,
+    /// `/interfaces/interface/config/loopback-mode`
+    InterfacesInterfaceConfigLoopbackMode,
+    /// `/interfaces/interface/config/description`
+    InterfacesInterfaceConfigDescription,
+    /// `/interfaces/interface/config/enabled`
+    InterfacesInterfaceConfigEnabled,
+    /// `/interfaces/interface/state`
+    InterfacesInterfaceState,
+    /// `/interfaces/interface/state/name`
+    InterfacesInterfaceStateName,
+    /// `/interfaces/interface/state/type`
+    InterfacesInterfaceStateType,
+    /// `/interfaces/interface/state/mtu`
+    InterfacesInterfaceStateMtu,
+    /// `/interfaces/interface/state/loopback-mode`
+    InterfacesInterfaceStateLoopbackMode,
+    /// `/interfaces/interface/state/description`
+    InterfacesInterfaceStateDescription,
+    /// `/interfaces/interface/state/enabled`
+    InterfacesInterfaceStateEnabled,
+    /// `/interfaces/interface/state/ifindex`
+    InterfacesInterfaceStateIfindex,
+    /// `/interfaces/interface/state/admin-status`
+    InterfacesInterfaceStateAdminStatus,
+    /// `/interfaces/interface/state/oper-status`
+    InterfacesInterfaceStateOperStatus,
+    /// `/interfaces/interface/state/last-change`
+    InterfacesInterfaceStateLastChange,
+    /// `/interfaces/interface/state/counters`
+    InterfacesInterfaceStateCounters,
+    /// `/interfaces/interface/state/counters/in-octets`
+    InterfacesInterfaceStateCountersInOctets,
+    /// `/interfaces/interface/state/counters/out-octets`
+    InterfacesInterfaceStateCountersOutOctets,
+    /// `/interfaces/interface/state/counters/last-clear`
+    InterfacesInterfaceStateCountersLastClear,
+}
+
+impl SchemaNode {
+    /// All data nodes, in schema order.
+    pub const ALL: &[SchemaNode] = &[SchemaNode::Interfaces, SchemaNode::InterfacesInterface, SchemaNode::InterfacesInterfaceName, SchemaNode::InterfacesInterfaceConfig, SchemaNode::InterfacesInterfaceConfigName, SchemaNode::InterfacesInterfaceConfigType, SchemaNode::InterfacesInterfaceConfigMtu, SchemaNode::InterfacesInterfaceConfigLoopbackMode, SchemaNode::InterfacesInterfaceConfigDescription, SchemaNode::InterfacesInterfaceConfigEnabled, SchemaNode::InterfacesInterfaceState, SchemaNode::InterfacesInterfaceStateName, SchemaNode::InterfacesInterfaceStateType, SchemaNode::InterfacesInterfaceStateMtu, SchemaNode::InterfacesInterfaceStateLoopbackMode, SchemaNode::InterfacesInterfaceStateDescription, SchemaNode::InterfacesInterfaceStateEnabled, SchemaNode::InterfacesInterfaceStateIfindex, SchemaNode::InterfacesInterfaceStateAdminStatus, SchemaNode::InterfacesInterfaceStateOperStatus, SchemaNode::InterfacesInterfaceStateLastChange, SchemaNode::InterfacesInterfaceStateCounters, SchemaNode::InterfacesInterfaceStateCountersInOctets, SchemaNode::InterfacesInterfaceStateCountersOutOctets, SchemaNode::InterfacesInterfaceStateCountersLastClear];
+
+    /// Schema path without module qualifiers or list keys, e.g. `/a/b`.
+    #[must_use]
+    pub fn schema_path(&self) -> &'static str {
+        match *self {
+            SchemaNode::Interfaces => "/interfaces",
+            SchemaNode::InterfacesInterface => "/interfaces/interface",
+            SchemaNode::InterfacesInterfaceName => "/interfaces/interface/name",
+            SchemaNode::InterfacesInterfaceConfig => "/interfaces/interface/config",
+            SchemaNode::InterfacesInterfaceConfigName => "/interfaces/interface/config/name",
+            SchemaNode::InterfacesInterfaceConfigType => "/interfaces/interface/config/type",
+            SchemaNode::InterfacesInterfaceConfigMtu => "/interfaces/interface/config/mtu",
+            SchemaNode::InterfacesInterfaceConfigLoopbackMode => "/interfaces/interface/config/loopback-mode",
+            SchemaNode::InterfacesInterfaceConfigDescription => "/interfaces/interface/config/description",
+            SchemaNode::InterfacesInterfaceConfigEnabled => "/interfaces/interface/config/enabled",
+            SchemaNode::InterfacesInterfaceState => "/interfaces/interface/state",
+            SchemaNode::InterfacesInterfaceStateName => "/interfaces/interface/state/name",
+            SchemaNode::InterfacesInterfaceStateType => "/interfaces/interface/state/type",
+            SchemaNode::InterfacesInterfaceStateMtu => "/interfaces/interface/state/mtu",
+            SchemaNode::InterfacesInterfaceStateLoopbackMode => "/interfaces/interface/state/loopback-mode",
+            SchemaNode::InterfacesInterfaceStateDescription => "/interfaces/interface/state/description",
+            SchemaNode::InterfacesInterfaceStateEnabled => "/interfaces/interface/state/enabled",
+            SchemaNode::InterfacesInterfaceStateIfindex => "/interfaces/interface/state/ifindex",
+            SchemaNode::InterfacesInterfaceStateAdminStatus => "/interfaces/interface/state/admin-status",
+            SchemaNode::InterfacesInterfaceStateOperStatus => "/interfaces/interface/state/oper-status",
+            SchemaNode::InterfacesInterfaceStateLastChange => "/interfaces/interface/state/last-change",
+            SchemaNode::InterfacesInterfaceStateCounters => "/interfaces/interface/state/counters",
+            SchemaNode::InterfacesInterfaceStateCountersInOctets => "/interfaces/interface/state/counters/in-octets",
+            SchemaNode::InterfacesInterfaceStateCountersOutOctets => "/interfaces/interface/state/counters/out-octets",
+            SchemaNode::InterfacesInterfaceStateCountersLastClear => "/interfaces/interface/state/counters/last-clear",
+        }
+    }
+
+    /// Name of the Rust field holding the node in its parent struct.
+    #[must_use]
+    pub fn field_name(&self) -> &'static str {
+        match *self {
+            SchemaNode::Interfaces => "interfaces",
+            SchemaNode::InterfacesInterface => "interface",
+            SchemaNode::InterfacesInterfaceName => "name",
+            SchemaNode::InterfacesInterfaceConfig => "config",
+            SchemaNode::InterfacesInterfaceConfigName => "name",
+            SchemaNode::InterfacesInterfaceConfigType => "type_",
+            SchemaNode::InterfacesInterfaceConfigMtu => "mtu",
+            SchemaNode::InterfacesInterfaceConfigLoopbackMode => "loopback_mode",
+            SchemaNode::InterfacesInterfaceConfigDescription => "description",
+            SchemaNode::InterfacesInterfaceConfigEnabled => "enabled",
+            SchemaNode::InterfacesInterfaceState => "state",
+            SchemaNode::InterfacesInterfaceStateName => "name",
+            SchemaNode::InterfacesInterfaceStateType => "type_",
+            SchemaNode::InterfacesInterfaceStateMtu => "mtu",
+            SchemaNode::InterfacesInterfaceStateLoopbackMode => "loopback_mode",
+            SchemaNode::InterfacesInterfaceStateDescription => "description",
+            SchemaNode::InterfacesInterfaceStateEnabled => "enabled",
+            SchemaNode::InterfacesInterfaceStateIfindex => "ifindex",
+            SchemaNode::InterfacesInterfaceStateAdminStatus => "admin_status",
+            SchemaNode::InterfacesInterfaceStateOperStatus => "oper_status",
+            SchemaNode::InterfacesInterfaceStateLastChange => "last_change",
+            SchemaNode::InterfacesInterfaceStateCounters => "counters",
+            SchemaNode::InterfacesInterfaceStateCountersInOctets => "in_octets",
+            SchemaNode::InterfacesInterfaceStateCountersOutOctets => "out_octets",
+            SchemaNode::InterfacesInterfaceStateCountersLastClear => "last_clear",
+        }
+    }
+
+    /// Parent node, or `None` for top-level nodes.
+    #[must_use]
+    pub fn parent(&self) -> Option<SchemaNode> {
+        match *self {
+            SchemaNode::Interfaces => None,
+            SchemaNode::InterfacesInterface => Some(SchemaNode::Interfaces),
+            SchemaNode::InterfacesInterfaceName => Some(SchemaNode::InterfacesInterface),
+            SchemaNode::InterfacesInterfaceConfig => Some(SchemaNode::InterfacesInterface),
+            SchemaNode::InterfacesInterfaceConfigName => Some(SchemaNode::InterfacesInterfaceConfig),
+            SchemaNode::InterfacesInterfaceConfigType => Some(SchemaNode::InterfacesInterfaceConfig),
+            SchemaNode::InterfacesInterfaceConfigMtu => Some(SchemaNode::InterfacesInterfaceConfig),
+            SchemaNode::InterfacesInterfaceConfigLoopbackMode => Some(SchemaNode::InterfacesInterfaceConfig),
+            SchemaNode::InterfacesInterfaceConfigDescription => Some(SchemaNode::InterfacesInterfaceConfig),
+            SchemaNode::InterfacesInterfaceConfigEnabled => Some(SchemaNode::InterfacesInterfaceConfig),
+            SchemaNode::InterfacesInterfaceState => Some(SchemaNode::InterfacesInterface),
+            SchemaNode::InterfacesInterfaceStateName => Some(SchemaNode::InterfacesInterfaceState),
+            SchemaNode::InterfacesInterfaceStateType => Some(SchemaNode::InterfacesInterfaceState),
+            SchemaNode::InterfacesInterfaceStateMtu => Some(SchemaNode::InterfacesInterfaceState),
+            SchemaNode::InterfacesInterfaceStateLoopbackMode => Some(SchemaNode::InterfacesInterfaceState),
+            SchemaNode::InterfacesInterfaceStateDescription => Some(SchemaNode::InterfacesInterfaceState),
+            SchemaNode::InterfacesInterfaceStateEnabled => Some(SchemaNode::InterfacesInterfaceState),
+            SchemaNode::InterfacesInterfaceStateIfindex => Some(SchemaNode::InterfacesInterfaceState),
+            SchemaNode::InterfacesInterfaceStateAdminStatus => Some(SchemaNode::InterfacesInterfaceState),
+            SchemaNode::InterfacesInterfaceStateOperStatus => Some(SchemaNode::InterfacesInterfaceState),
+            SchemaNode::InterfacesInterfaceStateLastChange => Some(SchemaNode::InterfacesInterfaceState),
+            SchemaNode::InterfacesInterfaceStateCounters => Some(SchemaNode::InterfacesInterfaceState),
+            SchemaNode::InterfacesInterfaceStateCountersInOctets => Some(SchemaNode::InterfacesInterfaceStateCounters),
+            SchemaNode::InterfacesInterfaceStateCountersOutOctets => Some(SchemaNode::InterfacesInterfaceStateCounters),
+            SchemaNode::InterfacesInterfaceStateCountersLastClear => Some(SchemaNode::InterfacesInterfaceStateCounters),
+        }
+    }
+
+    /// Names of the key leaves of a list, empty for other nodes.
+    #[must_use]
+    pub fn list_keys(&self) -> &'static [&'static str] {
+        match *self {
+            SchemaNode::Interfaces => &[],
+            SchemaNode::InterfacesInterface => &["name"],
+            SchemaNode::InterfacesInterfaceName => &[],
+            SchemaNode::InterfacesInterfaceConfig => &[],
+            SchemaNode::InterfacesInterfaceConfigName => &[],
+            SchemaNode::InterfacesInterfaceConfigType => &[],
+            SchemaNode::InterfacesInterfaceConfigMtu => &[],
+            SchemaNode::InterfacesInterfaceConfigLoopbackMode => &[],
+            SchemaNode::InterfacesInterfaceConfigDescription => &[],
+            SchemaNode::InterfacesInterfaceConfigEnabled => &[],
+            SchemaNode::InterfacesInterfaceState => &[],
+            SchemaNode::InterfacesInterfaceStateName => &[],
+            SchemaNode::InterfacesInterfaceStateType => &[],
+            SchemaNode::InterfacesInterfaceStateMtu => &[],
+            SchemaNode::InterfacesInterfaceStateLoopbackMode => &[],
+            SchemaNode::InterfacesInterfaceStateDescription => &[],
+            SchemaNode::InterfacesInterfaceStateEnabled => &[],
+            SchemaNode::InterfacesInterfaceStateIfindex => &[],
+            SchemaNode::InterfacesInterfaceStateAdminStatus => &[],
+            SchemaNode::InterfacesInterfaceStateOperStatus => &[],
+            SchemaNode::InterfacesInterfaceStateLastChange => &[],
+            SchemaNode::InterfacesInterfaceStateCounters => &[],
+            SchemaNode::InterfacesInterfaceStateCountersInOctets => &[],
+            SchemaNode::InterfacesInterfaceStateCountersOutOctets => &[],
+            SchemaNode::InterfacesInterfaceStateCountersLastClear => &[],
+        }
+    }
+
+    /// XPath condition of the node's `when` statement, for tooling that
+    /// checks data against it. The node is only valid while it holds.
+    #[must_use]
+    pub fn when(&self) -> Option<&'static str> {
+        match *self {
+            SchemaNode::Interfaces => None,
+            SchemaNode::InterfacesInterface => None,
+            SchemaNode::InterfacesInterfaceName => None,
+            SchemaNode::InterfacesInterfaceConfig => None,
+            SchemaNode::InterfacesInterfaceConfigName => None,
+            SchemaNode::InterfacesInterfaceConfigType => None,
+            SchemaNode::InterfacesInterfaceConfigMtu => None,
+            SchemaNode::InterfacesInterfaceConfigLoopbackMode => None,
+            SchemaNode::InterfacesInterfaceConfigDescription => None,
+            SchemaNode::InterfacesInterfaceConfigEnabled => None,
+            SchemaNode::InterfacesInterfaceState => None,
+            SchemaNode::InterfacesInterfaceStateName => None,
+            SchemaNode::InterfacesInterfaceStateType => None,
+            SchemaNode::InterfacesInterfaceStateMtu => None,
+            SchemaNode::InterfacesInterfaceStateLoopbackMode => None,
+            SchemaNode::InterfacesInterfaceStateDescription => None,
+            SchemaNode::InterfacesInterfaceStateEnabled => None,
+            SchemaNode::InterfacesInterfaceStateIfindex => None,
+            SchemaNode::InterfacesInterfaceStateAdminStatus => None,
+            SchemaNode::InterfacesInterfaceStateOperStatus => None,
+            SchemaNode::InterfacesInterfaceStateLastChange => None,
+            SchemaNode::InterfacesInterfaceStateCounters => None,
+            SchemaNode::InterfacesInterfaceStateCountersInOctets => None,
+            SchemaNode::InterfacesInterfaceStateCountersOutOctets => None,
+            SchemaNode::InterfacesInterfaceStateCountersLastClear => None,
+        }
+    }
+
+    /// Map a RESTCONF `error-path` to the node it identifies.
+    ///
+    /// Module qualifiers and list key predicates are ignored, so
+    /// `/example:a/b[name='x']/c` identifies the node at `/a/b/c`.
+    #[must_use]
+    pub fn from_error_path(error_path: &str) -> Option<SchemaNode> {
+        let path = strip_error_path(error_path);
+        SchemaNode::ALL
+            .iter()
+            .copied()
+            .find(|node| node.schema_path() == path)
+    }
+}
+
+/// Remove module qualifiers and key predicates from an instance path.
+fn strip_error_path(error_path: &str) -> String {
+    let mut path = String::new();
+    let mut depth = 0;
+    let mut quote = None;
+    for c in error_path.chars() {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') if depth > 0 => quote = Some(c),
+            (None, '[') => depth += 1,
+            (None, ']') => depth -= 1,
+            (None, _) if depth == 0 => path.push(c),
+            _ => {}
+        }
+    }
+    path.split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| segment.rsplit(':').next().unwrap_or(segment))
+        .fold(String::new(), |path, segment| path + "/" + segment)
+}
@@ -0,0 +1,519 @@
+// This file is automatically generated by rustconf.
+// DO NOT EDIT MANUALLY.
+
+#![allow(unused_imports, unused_variables, dead_code, clippy::module_inception)]
+
+use serde::{Deserialize, Serialize};
+use super::validation::*;
+
+/// Reusable type for by-name reference to a base interface.
+/// This type may be used in cases where ability to reference
+/// a subinterface is not required.
+pub type BaseInterfaceRef = String;
+
+/// Top level container for interfaces, including configuration
+/// and state data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interfaces {
+    /// The list of named interfaces on the device.
+    #[serde(rename = "interface")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub interface: Vec<Interface>,
+}
+
+impl Interfaces {
+    /// Merge `other` into `self` following YANG merge semantics.
+    ///
+    /// Leaves set in `other` overwrite those in `self` and unset optional leaves
+    /// are left alone, so `other` acts as a patch. Containers are merged
+    /// recursively, list entries are merged by key with new entries appended,
+    /// and leaf-list values are added if not already present. Choices are
+    /// replaced as a whole.
+    pub fn merge(&mut self, other: &Self) {
+        {
+            let mut index: std::collections::HashMap<String, usize> = self.interface.iter().enumerate().map(|(i, entry)| (entry.merge_key(), i)).collect();
+            for theirs in &other.interface {
+                match index.get(&theirs.merge_key()) {
+                    Some(&i) => self.interface[i].merge(theirs),
+                    None => {
+                        index.insert(theirs.merge_key(), self.interface.len());
+                        self.interface.push(theirs.clone());
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The list of named interfaces on the device.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interface {
+    /// References the name of the interface
+    #[serde(rename = "name")]
+    pub name: String,
+    /// Configurable items at the global, physical interface
+    /// level
+    #[serde(rename = "config")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub config: Option<Config>,
+    /// Operational state data at the global interface level
+    #[serde(rename = "state")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<State>,
+}
+
+impl Interface {
+    /// Merge `other` into `self` following YANG merge semantics.
+    ///
+    /// Leaves set in `other` overwrite those in `self` and unset optional leaves
+    /// are left alone, so `other` acts as a patch. Containers are merged
+    /// recursively, list entries are merged by key with new entries appended,
+    /// and leaf-list values are added if not already present. Choices are
+    /// replaced as a whole.
+    pub fn merge(&mut self, other: &Self) {
+        if let Some(theirs) = &other.config {
+            match &mut self.config {
+                Some(mine) => mine.merge(theirs),
+                None => self.config = Some(theirs.clone()),
+            }
+        }
+        if let Some(theirs) = &other.state {
+            match &mut self.state {
+                Some(mine) => mine.merge(theirs),
+                None => self.state = Some(theirs.clone()),
+            }
+        }
+    }
+
+    /// Serialized key, identifying the entry when lists are merged.
+    #[allow(dead_code)]
+    fn merge_key(&self) -> String {
+        serde_json::to_string(&(&self.name,)).unwrap_or_default()
+    }
+}
+
+/// Key of a `interface` entry.
+///
+/// Displays as the entry's RESTCONF list instance fragment, `interface=<name>`, with
+/// each value percent-encoded, and parses from it.
+#[derive(Debug, Clone)]
+pub struct InterfaceKey {
+    /// The `name` key leaf.
+    pub name: String,
+}
+
+impl Interface {
+    /// The key of this entry.
+    #[must_use]
+    pub fn key(&self) -> InterfaceKey {
+        InterfaceKey {
+            name: self.name.clone(),
+        }
+    }
+}
+
+impl std::fmt::Display for InterfaceKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let values = [
+            rustconf_runtime::list_key::key_value(&self.name),
+        ];
+        rustconf_runtime::list_key::write_fragment(f, "interface", &values)
+    }
+}
+
+impl From<InterfaceKey> for String {
+    fn from(key: InterfaceKey) -> Self {
+        key.to_string()
+    }
+}
+
+impl std::str::FromStr for InterfaceKey {
+    type Err = rustconf_runtime::ListKeyError;
+
+    fn from_str(fragment: &str) -> Result<Self, Self::Err> {
+        let values = rustconf_runtime::list_key::parse_fragment(fragment, "interface", 1)?;
+        Ok(Self {
+            name: rustconf_runtime::list_key::parse_key_value(&values[0])?,
+        })
+    }
+}
+
+/// Configurable items at the global, physical interface
+/// level
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Config {
+    /// The name of the interface.
+    #[serde(rename = "name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The type of the interface.
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// Set the max transmission unit size in octets
+    /// for the physical interface.  If this is not set, the mtu is
+    /// set to the operational default -- e.g., 1514 bytes on an
+    /// Ethernet interface.
+    #[serde(rename = "mtu")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mtu: Option<u16>,
+    /// When set to true, the interface is logically looped back,
+    /// such that packets that are forwarded via the interface
+    /// are received on the same interface.
+    #[serde(rename = "loopback-mode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loopback_mode: Option<bool>,
+    /// A textual description of the interface.
+    #[serde(rename = "description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// This leaf contains the configured, desired state of the
+    /// interface.
+    #[serde(rename = "enabled")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+}
+
+impl Config {
+    /// Merge `other` into `self` following YANG merge semantics.
+    ///
+    /// Leaves set in `other` overwrite those in `self` and unset optional leaves
+    /// are left alone, so `other` acts as a patch. Containers are merged
+    /// recursively, list entries are merged by key with new entries appended,
+    /// and leaf-list values are added if not already present. Choices are
+    /// replaced as a whole.
+    pub fn merge(&mut self, other: &Self) {
+        if other.name.is_some() {
+            self.name.clone_from(&other.name);
+        }
+        self.type_.clone_from(&other.type_);
+        if other.mtu.is_some() {
+            self.mtu.clone_from(&other.mtu);
+        }
+        if other.loopback_mode.is_some() {
+            self.loopback_mode.clone_from(&other.loopback_mode);
+        }
+        if other.description.is_some() {
+            self.description.clone_from(&other.description);
+        }
+        if other.enabled.is_some() {
+            self.enabled.clone_from(&other.enabled);
+        }
+    }
+}
+
+/// Operational state data at the global interface level
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct State {
+    /// The name of the interface.
+    #[serde(rename = "name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The type of the interface.
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// Set the max transmission unit size in octets
+    /// for the physical interface.  If this is not set, the mtu is
+    /// set to the operational default -- e.g., 1514 bytes on an
+    /// Ethernet interface.
+    #[serde(rename = "mtu")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mtu: Option<u16>,
+    /// When set to true, the interface is logically looped back,
+    /// such that packets that are forwarded via the interface
+    /// are received on the same interface.
+    #[serde(rename = "loopback-mode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loopback_mode: Option<bool>,
+    /// A textual description of the interface.
+    #[serde(rename = "description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// This leaf contains the configured, desired state of the
+    /// interface.
+    #[serde(rename = "enabled")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// System assigned number for each interface.  Corresponds to
+    /// ifIndex object in SNMP Interface MIB
+    #[serde(rename = "ifindex")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ifindex: Option<u32>,
+    /// The desired state of the interface.  In RFC 7223 this leaf
+    /// has the same read semantics as ifAdminStatus.  Here, it
+    /// reflects the administrative state as set by enabling or
+    /// disabling the interface.
+    #[serde(rename = "admin-status")]
+    pub admin_status: String,
+    /// The current operational state of the interface.
+    #[serde(rename = "oper-status")]
+    pub oper_status: String,
+    /// This timestamp indicates the absolute time of the last
+    /// state change of the interface (e.g., up-to-down transition).
+    #[serde(rename = "last-change")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_change: Option<u64>,
+    /// A collection of interface-related statistics objects.
+    #[serde(rename = "counters")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub counters: Option<Counters>,
+}
+
+impl State {
+    /// Merge `other` into `self` following YANG merge semantics.
+    ///
+    /// Leaves set in `other` overwrite those in `self` and unset optional leaves
+    /// are left alone, so `other` acts as a patch. Containers are merged
+    /// recursively, list entries are merged by key with new entries appended,
+    /// and leaf-list values are added if not already present. Choices are
+    /// replaced as a whole.
+    pub fn merge(&mut self, other: &Self) {
+        if other.name.is_some() {
+            self.name.clone_from(&other.name);
+        }
+        self.type_.clone_from(&other.type_);
+        if other.mtu.is_some() {
+            self.mtu.clone_from(&other.mtu);
+        }
+        if other.loopback_mode.is_some() {
+            self.loopback_mode.clone_from(&other.loopback_mode);
+        }
+        if other.description.is_some() {
+            self.description.clone_from(&other.description);
+        }
+        if other.enabled.is_some() {
+            self.enabled.clone_from(&other.enabled);
+        }
+        if other.ifindex.is_some() {
+            self.ifindex.clone_from(&other.ifindex);
+        }
+        self.admin_status.clone_from(&other.admin_status);
+        self.oper_status.clone_from(&other.oper_status);
+        if other.last_change.is_some() {
+            self.last_change.clone_from(&other.last_change);
+        }
+        if let Some(theirs) = &other.counters {
+            match &mut self.counters {
+                Some(mine) => mine.merge(theirs),
+                None => self.counters = Some(theirs.clone()),
+            }
+        }
+    }
+}
+
+/// A collection of interface-related statistics objects.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Counters {
+    /// The total number of octets received on the interface,
+    /// including framing characters.
+    #[serde(rename = "in-octets")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_octets: Option<u64>,
+    /// The total number of octets transmitted out of the
+    /// interface, including framing characters.
+    #[serde(rename = "out-octets")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub out_octets: Option<u64>,
+    /// Timestamp of the last time the interface counters were
+    /// cleared.
+    #[serde(rename = "last-clear")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_clear: Option<u64>,
+}
+
+impl Counters {
+    /// Merge `other` into `self` following YANG merge semantics.
+    ///
+    /// Leaves set in `other` overwrite those in `self` and unset optional leaves
+    /// are left alone, so `other` acts as a patch. Containers are merged
+    /// recursively, list entries are merged by key with new entries appended,
+    /// and leaf-list values are added if not already present. Choices are
+    /// replaced as a whole.
+    pub fn merge(&mut self, other: &Self) {
+        if other.in_octets.is_some() {
+            self.in_octets.clone_from(&other.in_octets);
+        }
+        if other.out_octets.is_some() {
+            self.out_octets.clone_from(&other.out_octets);
+        }
+        if other.last_clear.is_some() {
+            self.last_clear.clone_from(&other.last_clear);
+        }
+    }
+}
+
+impl Interfaces {
+    /// The value at the relative `path` below this `interfaces`, if the path is in
+    /// the schema and a value is set there.
+    ///
+    /// List entries are selected by key as in RESTCONF URLs: `list=key1,key2`.
+    #[must_use]
+    pub fn get_by_path(&self, path: &str) -> Option<serde_json::Value> {
+        let data = serde_json::to_value(self).ok()?;
+        rustconf_runtime::data_path::get(&data, "/interfaces", path, |path| {
+            super::schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())
+        })
+    }
+
+    /// Set the value at `path` below this `interfaces`, creating missing
+    /// containers. A `null` value removes the node.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is not in the schema, selects a list entry
+    /// that does not exist, or the value does not fit the node.
+    pub fn set_by_path(
+        &mut self,
+        path: &str,
+        value: serde_json::Value,
+    ) -> Result<(), rustconf_runtime::DataPathError> {
+        let mut data = serde_json::to_value(&*self)?;
+        rustconf_runtime::data_path::set(&mut data, "/interfaces", path, value, |path| {
+            super::schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())
+        })?;
+        *self = serde_json::from_value(data)?;
+        Ok(())
+    }
+}
+
+impl Interface {
+    /// The value at the relative `path` below this `interface`, if the path is in
+    /// the schema and a value is set there.
+    ///
+    /// List entries are selected by key as in RESTCONF URLs: `list=key1,key2`.
+    #[must_use]
+    pub fn get_by_path(&self, path: &str) -> Option<serde_json::Value> {
+        let data = serde_json::to_value(self).ok()?;
+        rustconf_runtime::data_path::get(&data, "/interfaces/interface", path, |path| {
+            super::schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())
+        })
+    }
+
+    /// Set the value at `path` below this `interface`, creating missing
+    /// containers. A `null` value removes the node.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is not in the schema, selects a list entry
+    /// that does not exist, or the value does not fit the node.
+    pub fn set_by_path(
+        &mut self,
+        path: &str,
+        value: serde_json::Value,
+    ) -> Result<(), rustconf_runtime::DataPathError> {
+        let mut data = serde_json::to_value(&*self)?;
+        rustconf_runtime::data_path::set(&mut data, "/interfaces/interface", path, value, |path| {
+            super::schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())
+        })?;
+        *self = serde_json::from_value(data)?;
+        Ok(())
+    }
+}
+
+impl Config {
+    /// The value at the relative `path` below this `config`, if the path is in
+    /// the schema and a value is set there.
+    ///
+    /// List entries are selected by key as in RESTCONF URLs: `list=key1,key2`.
+    #[must_use]
+    pub fn get_by_path(&self, path: &str) -> Option<serde_json::Value> {
+        let data = serde_json::to_value(self).ok()?;
+        rustconf_runtime::data_path::get(&data, "/interfaces/interface/config", path, |path| {
+            super::schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())
+        })
+    }
+
+    /// Set the value at `path` below this `config`, creating missing
+    /// containers. A `null` value removes the node.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is not in the schema, selects a list entry
+    /// that does not exist, or the value does not fit the node.
+    pub fn set_by_path(
+        &mut self,
+        path: &str,
+        value: serde_json::Value,
+    ) -> Result<(), rustconf_runtime::DataPathError> {
+        let mut data = serde_json::to_value(&*self)?;
+        rustconf_runtime::data_path::set(&mut data, "/interfaces/interface/config", path, value, |path| {
+            super::schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())
+        })?;
+        *self = serde_json::from_value(data)?;
+        Ok(())
+    }
+}
+
+impl State {
+    /// The value at the relative `path` below this `state`, if the path is in
+    /// the schema and a value is set there.
+    ///
+    /// List entries are selected by key as in RESTCONF URLs: `list=key1,key2`.
+    #[must_use]
+    pub fn get_by_path(&self, path: &str) -> Option<serde_json::Value> {
+        let data = serde_json::to_value(self).ok()?;
+        rustconf_runtime::data_path::get(&data, "/interfaces/interface/state", path, |path| {
+            super::schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())
+        })
+    }
+
+    /// Set the value at `path` below this `state`, creating missing
+    /// containers. A `null` value removes the node.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is not in the schema, selects a list entry
+    /// that does not exist, or the value does not fit the node.
+    pub fn set_by_path(
+        &mut self,
+        path: &str,
+        value: serde_json::Value,
+    ) -> Result<(), rustconf_runtime::DataPathError> {
+        let mut data = serde_json::to_value(&*self)?;
+        rustconf_runtime::data_path::set(&mut data, "/interfaces/interface/state", path, value, |path| {
+            super::schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())
+        })?;
+        *self = serde_json::from_value(data)?;
+        Ok(())
+    }
+}
+
+impl Counters {
+    /// The value at the relative `path` below this `counters`, if the path is in
+    /// the schema and a value is set there.
+    ///
+    /// List entries are selected by key as in RESTCONF URLs: `list=key1,key2`.
+    #[must_use]
+    pub fn get_by_path(&self, path: &str) -> Option<serde_json::Value> {
+        let data = serde_json::to_value(self).ok()?;
+        rustconf_runtime::data_path::get(&data, "/interfaces/interface/state/counters", path, |path| {
+            super::schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())
+        })
+    }
+
+    /// Set the value at `path` below this `counters`, creating missing
+    /// containers. A `null` value removes the node.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is not in the schema, selects a list entry
+    /// that does not exist, or the value does not fit the node.
+    pub fn set_by_path(
+        &mut self,
+        path: &str,
+        value: serde_json::Value,
+    ) -> Result<(), rustconf_runtime::DataPathError> {
+        let mut data = serde_json::to_value(&*self)?;
+        rustconf_runtime::data_path::set(&mut data, "/interfaces/interface/state/counters", path, value, |path| {
+            super::schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())
+        })?;
+        *self = serde_json::from_value(data)?;
+        Ok(())
+    }
+}
+
+/// Register the types of the top-level nodes of the `openconfig-interfaces` module, for
+/// decoding `anydata` content that carries them.
+pub fn register_anydata(registry: &mut rustconf_runtime::AnyDataRegistry) {
+    registry.register::<Interfaces>("openconfig-interfaces:interfaces");
+}
@@ -0,0 +1,71 @@
+// This file is automatically generated by rustconf.
+// DO NOT EDIT MANUALLY.
+
+#![allow(unused_imports, unused_variables, dead_code, clippy::module_inception)]
+
+use serde::{Deserialize, Serialize};
+
+/// Validation error for constrained types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    /// Value is outside the allowed range.
+    OutOfRange {
+        /// The rejected value.
+        value: String,
+        /// The constraint it violates, as written in YANG.
+        constraint: String,
+    },
+    /// String length is outside the allowed range.
+    InvalidLength {
+        /// The rejected value.
+        value: String,
+        /// The constraint it violates, as written in YANG.
+        constraint: String,
+    },
+    /// String does not match the required pattern.
+    InvalidPattern {
+        /// The rejected value.
+        value: String,
+        /// The pattern it does not match.
+        pattern: String,
+    },
+    /// A `must` condition does not hold.
+    MustViolation {
+        /// Data path of the node, relative to the validated one.
+        path: String,
+        /// The condition, as written in YANG.
+        condition: String,
+        /// The `error-message` of the `must` statement, if any.
+        message: Option<String>,
+        /// The `error-app-tag` of the `must` statement, if any.
+        app_tag: Option<String>,
+    },
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationError::OutOfRange { value, constraint } => {
+                write!(f, "Value '{}' is outside allowed range: {}", value, constraint)
+            }
+            ValidationError::InvalidLength { value, constraint } => {
+                write!(f, "Value '{}' has invalid length, expected: {}", value, constraint)
+            }
+            ValidationError::InvalidPattern { value, pattern } => {
+                write!(f, "Value '{}' does not match pattern: {}", value, pattern)
+            }
+            ValidationError::MustViolation {
+                path,
+                condition,
+                message,
+                ..
+            } => match message {
+                Some(message) => write!(f, "{}: {}", path, message),
+                None => write!(f, "{}: must condition failed: {}", path, condition),
+            },
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
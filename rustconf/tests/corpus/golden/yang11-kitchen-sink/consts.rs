@@ -0,0 +1,55 @@
+// This file is automatically generated by rustconf.
+// DO NOT EDIT MANUALLY.
+
+#![allow(unused_imports, unused_variables, dead_code, clippy::module_inception)]
+
+/// Name of the YANG module.
+pub const MODULE_NAME: &str = "yang11-kitchen-sink";
+/// XML namespace of the YANG module.
+pub const MODULE_NAMESPACE: &str = "urn:rustconf:corpus:yang11-kitchen-sink";
+/// Prefix of the YANG module.
+pub const MODULE_PREFIX: &str = "ks";
+/// Organization responsible for the YANG module, if stated.
+pub const MODULE_ORGANIZATION: Option<&str> = Some("rustconf");
+/// Contact information for the YANG module's authors, if stated.
+pub const MODULE_CONTACT: Option<&str> = Some("https://github.com/philcali/rustconf");
+/// Description of the YANG module, if stated.
+pub const MODULE_DESCRIPTION: Option<&str> = Some("A module exercising the YANG 1.1 statements rustconf supports, for\nthe golden-file tests of the generator. Extend it as statements\ngain support, and bless the golden files with RUSTCONF_BLESS=1.");
+/// Revision of the YANG module the code was generated from, if known.
+pub const MODULE_REVISION: Option<&str> = Some("2024-06-01");
+/// Modules the server must implement, with their revisions.
+pub const IMPLEMENTED_MODULES: &[(&str, Option<&str>)] = &[("yang11-kitchen-sink", MODULE_REVISION)];
+/// Modules imported by the YANG module, with the revisions used.
+pub const IMPORTED_MODULES: &[(&str, Option<&str>)] = &[("ietf-yang-types", Some("2013-07-15"))];
+/// Media type for JSON data and operation bodies (RFC 8040).
+pub const YANG_DATA_JSON: &str = "application/yang-data+json";
+/// Media type for XML data and operation bodies (RFC 8040).
+pub const YANG_DATA_XML: &str = "application/yang-data+xml";
+/// Media type for YANG Patch documents (RFC 8072).
+pub const YANG_PATCH_JSON: &str = "application/yang-patch+json";
+/// Media type of notification event streams.
+pub const EVENT_STREAM: &str = "text/event-stream";
+/// Root of the RESTCONF API.
+pub const RESTCONF_ROOT: &str = "/restconf";
+/// Root of the datastore resource.
+pub const DATA_PATH: &str = "/restconf/data";
+/// Root of the operations resource.
+pub const OPERATIONS_PATH: &str = "/restconf/operations";
+/// Stream list advertised by the server (RFC 8040 section 9.3).
+pub const STREAMS_PATH: &str = "/restconf/data/ietf-restconf-monitoring:restconf-state/streams";
+/// Name of the default notification stream (RFC 8040 section 6.2).
+pub const NETCONF_STREAM: &str = "NETCONF";
+/// Notification streams carrying this module's notifications.
+pub const STREAMS: &[&str] = &[NETCONF_STREAM];
+/// Qualified name of the `reboot` RPC, as used in its URL.
+pub const OPERATION_REBOOT: &str = "yang11-kitchen-sink:reboot";
+/// Qualified name of the `ping` RPC, as used in its URL.
+pub const OPERATION_PING: &str = "yang11-kitchen-sink:ping";
+/// Qualified names of all RPCs defined by the module.
+pub const OPERATIONS: &[&str] = &["yang11-kitchen-sink:reboot", "yang11-kitchen-sink:ping"];
+/// Qualified name of the `config-change` notification, as it appears in events.
+pub const NOTIFICATION_CONFIG_CHANGE: &str = "yang11-kitchen-sink:config-change";
+/// Qualified name of the `link-flap` notification, as it appears in events.
+pub const NOTIFICATION_LINK_FLAP: &str = "yang11-kitchen-sink:link-flap";
+/// Qualified names of all notifications defined by the module.
+pub const NOTIFICATIONS: &[&str] = &["yang11-kitchen-sink:config-change", "yang11-kitchen-sink:link-flap"];
@@ -0,0 +1,55 @@
+// This file is automatically generated by rustconf.
+// DO NOT EDIT MANUALLY.
+//
+// Source YANG module: yang11-kitchen-sink
+// Namespace: urn:rustconf:corpus:yang11-kitchen-sink
+// Prefix: ks
+
+//! A module exercising the YANG 1.1 statements rustconf supports, for
+//! the golden-file tests of the generator. Extend it as statements
+//! gain support, and bless the golden files with RUSTCONF_BLESS=1.
+//!
+//! # Organization
+//!
+//! rustconf
+//!
+//! # Contact
+//!
+//! ```text
+//! https://github.com/philcali/rustconf
+//! ```
+
+#![allow(unused_imports, unused_variables, dead_code, clippy::module_inception)]
+
+/// Data types of the yang11-kitchen-sink YANG module.
+pub mod types;
+/// RESTCONF constants for the yang11-kitchen-sink YANG module.
+pub mod consts;
+/// Schema nodes of the yang11-kitchen-sink YANG module.
+pub mod schema;
+/// RESTCONF operations.
+pub mod operations;
+/// RESTCONF notification types.
+pub mod notifications;
+/// Types enforcing YANG range, length and pattern restrictions.
+pub mod validation;
+/// RESTCONF server for the yang11-kitchen-sink YANG module.
+pub mod server;
+
+pub use types::*;
+pub use operations::*;
+pub use notifications::*;
+pub use server::*;
+
+pub use rustconf_runtime::{
+    RestconfClient,
+    HttpTransport,
+    HttpRequest,
+    HttpResponse,
+    HttpMethod,
+    RpcError,
+    ResponseEnvelope,
+    RequestInterceptor,
+    Transaction,
+    with_rollback,
+};
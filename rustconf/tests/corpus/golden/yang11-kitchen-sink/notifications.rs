@@ -0,0 +1,39 @@
+// This file is automatically generated by rustconf.
+// DO NOT EDIT MANUALLY.
+
+#![allow(unused_imports, unused_variables, dead_code, clippy::module_inception)]
+
+use serde::{Deserialize, Serialize};
+use super::types::*;
+use super::validation::*;
+
+/// RESTCONF notification types.
+pub mod notifications {
+    use super::*;
+
+    /// Sent when the configuration changes.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ConfigChange {
+        /// The `changed-at` leaf.
+        #[serde(rename = "changed-at", skip_serializing_if = "Option::is_none")]
+        pub changed_at: Option<ValidatedStringIdbf9149867bc51280>,
+        /// The `changed-path` leaf-list.
+        #[serde(rename = "changed-path", default, skip_serializing_if = "Vec::is_empty")]
+        pub changed_path: Vec<String>,
+        /// The changed subtrees, as data of their modules.
+        #[serde(rename = "changes", skip_serializing_if = "Option::is_none")]
+        pub changes: Option<rustconf_runtime::AnyData>,
+    }
+
+    /// Notification payload for link-flap.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct LinkFlap {
+        /// The `interface` leaf.
+        #[serde(rename = "interface")]
+        pub interface: String,
+        /// The `severity` leaf.
+        #[serde(rename = "severity", skip_serializing_if = "Option::is_none")]
+        pub severity: Option<Severity>,
+    }
+
+}
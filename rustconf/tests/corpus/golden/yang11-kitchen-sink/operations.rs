@@ -0,0 +1,1139 @@
+// This file is automatically generated by rustconf.
+// DO NOT EDIT MANUALLY.
+
+#![allow(unused_imports, unused_variables, dead_code, clippy::module_inception)]
+
+use rustconf_runtime::{RestconfClient, HttpTransport, HttpRequest, HttpResponse, HttpMethod, RpcError, ResourceCapabilities, ResponseEnvelope};
+use serde::{Deserialize, Serialize};
+use super::types::*;
+use super::validation::*;
+use super::consts;
+
+/// RESTCONF operations.
+pub mod operations {
+    use super::*;
+
+    /// Percent-encode a string for use in URLs.
+    ///
+    /// This function encodes special characters according to RFC 3986.
+    #[allow(dead_code)]
+    fn percent_encode(s: &str) -> String {
+        s.chars()
+            .map(|c| match c {
+                'A'..='Z' | 'a'..='z' | '0'..='9' | '-' | '_' | '.' | '~' => c.to_string(),
+                _ => format!("%{:02X}", c as u8),
+            })
+            .collect()
+    }
+
+    /// Input parameters for reboot RPC.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct RebootInput {
+        /// The `delay` leaf.
+        #[serde(rename = "delay", skip_serializing_if = "Option::is_none")]
+        pub delay: Option<u32>,
+        /// The `reason` leaf.
+        #[serde(rename = "reason", skip_serializing_if = "Option::is_none")]
+        pub reason: Option<String>,
+    }
+
+    /// Reboot the system.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The RestconfClient to use for executing the RPC request
+    /// * `input` - The input parameters for the reboot operation
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Input serialization fails (`RpcError::SerializationError`)
+    /// - The HTTP request fails (`RpcError::TransportError`)
+    /// - The server returns an error status:
+    ///   - 400: `RpcError::InvalidInput`
+    ///   - 401/403: `RpcError::Unauthorized`
+    ///   - 404: `RpcError::NotFound`
+    ///   - 500-599: `RpcError::ServerError`
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use yang11_kitchen_sink::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), RpcError> {
+    ///     // Create a transport adapter
+    ///     let transport = reqwest_adapter::ReqwestTransport::new();
+    ///
+    ///     // Create a client for the RESTCONF server
+    ///     let client = RestconfClient::new(
+    ///         "https://device.example.com",
+    ///         transport
+    ///     )?;
+    ///
+    ///     // Prepare input parameters
+    ///     let input = RebootInput {
+    ///         // Set input fields here
+    ///         // ...
+    ///     };
+    ///
+    ///     // Execute the RPC operation
+    ///     let result = reboot(&client, &input).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn reboot<T: HttpTransport>(client: &RestconfClient<T>, input: &RebootInput) -> Result<(), RpcError> {
+        reboot_full(client, input).await.map(ResponseEnvelope::into_body)
+    }
+
+    /// Like [`reboot`], also returning the response status and headers.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`reboot`].
+    pub async fn reboot_full<T: HttpTransport>(client: &RestconfClient<T>, input: &RebootInput) -> Result<ResponseEnvelope<()>, RpcError> {
+        // Serialize input to JSON
+        let body = rustconf_runtime::wrap_envelope("yang11-kitchen-sink:input", input)
+            .map_err(|e| RpcError::SerializationError(format!("Failed to serialize input: {}", e)))?;
+
+        // Construct RESTCONF URL
+        let base = client.base_url().trim_end_matches('/');
+        let url = format!("{}{}/{}:{}", base, consts::OPERATIONS_PATH, percent_encode("yang11-kitchen-sink"), percent_encode("reboot"));
+
+        // Build HTTP request
+        let request = HttpRequest {
+            method: HttpMethod::POST,
+            url,
+            headers: vec![
+                ("Content-Type".to_string(), consts::YANG_DATA_JSON.to_string()),
+                ("Accept".to_string(), consts::YANG_DATA_JSON.to_string()),
+            ],
+            body: Some(body.into()),
+            context: Default::default(),
+        };
+
+        // Execute request through client
+        let response = client.execute(request).await?;
+
+        // Map HTTP status to error
+        match response.status_code {
+            200..=299 => {
+                // Success - no output expected
+                Ok(ResponseEnvelope::new(response, ()))
+            }
+            _ => Err(RpcError::HttpError {
+                status_code: response.status_code,
+                message: String::from_utf8_lossy(&response.body).to_string(),
+            }),
+        }
+    }
+
+    /// Input parameters for ping RPC.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct PingInput {
+        /// The `address` leaf.
+        #[serde(rename = "address")]
+        pub address: AddressOrName,
+        /// The `port` leaf.
+        #[serde(rename = "port", skip_serializing_if = "Option::is_none")]
+        pub port: Option<PortNumber>,
+        /// The `count` leaf.
+        #[serde(rename = "count", skip_serializing_if = "Option::is_none")]
+        pub count: Option<ValidatedUint8Id2764a18cad2b0d61>,
+    }
+
+    /// Output result for ping RPC.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct PingOutput {
+        /// The `received` leaf.
+        #[serde(rename = "received", skip_serializing_if = "Option::is_none")]
+        pub received: Option<u8>,
+        /// The `average-rtt` leaf.
+        #[serde(rename = "average-rtt", skip_serializing_if = "Option::is_none")]
+        pub average_rtt: Option<u32>,
+    }
+
+    impl TryFrom<&HttpResponse> for PingOutput {
+        type Error = RpcError;
+
+        /// Map a RESTCONF response to the RPC output.
+        ///
+        /// Success status codes deserialize the body; any other status becomes
+        /// `RpcError::HttpError`.
+        fn try_from(response: &HttpResponse) -> Result<Self, Self::Error> {
+            match response.status_code {
+                200..=299 => rustconf_runtime::unwrap_envelope("yang11-kitchen-sink:output", &response.body)
+                    .map_err(|e| RpcError::DeserializationError(
+                        format!("Failed to deserialize response: {}", e)
+                    )),
+                _ => Err(RpcError::HttpError {
+                    status_code: response.status_code,
+                    message: String::from_utf8_lossy(&response.body).to_string(),
+                }),
+            }
+        }
+    }
+
+    /// Execute the ping RPC operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The RestconfClient to use for executing the RPC request
+    /// * `input` - The input parameters for the ping operation
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(PingOutput)` on success, containing the operation result.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Input serialization fails (`RpcError::SerializationError`)
+    /// - The HTTP request fails (`RpcError::TransportError`)
+    /// - The server returns an error status:
+    ///   - 400: `RpcError::InvalidInput`
+    ///   - 401/403: `RpcError::Unauthorized`
+    ///   - 404: `RpcError::NotFound`
+    ///   - 500-599: `RpcError::ServerError`
+    /// - Response deserialization fails (`RpcError::DeserializationError`)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use yang11_kitchen_sink::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), RpcError> {
+    ///     // Create a transport adapter
+    ///     let transport = reqwest_adapter::ReqwestTransport::new();
+    ///
+    ///     // Create a client for the RESTCONF server
+    ///     let client = RestconfClient::new(
+    ///         "https://device.example.com",
+    ///         transport
+    ///     )?;
+    ///
+    ///     // Prepare input parameters
+    ///     let input = PingInput {
+    ///         // Set input fields here
+    ///         // ...
+    ///     };
+    ///
+    ///     // Execute the RPC operation
+    ///     let result = ping(&client, &input).await?;
+    ///
+    ///     // Process the result
+    ///     println!("Operation completed successfully");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn ping<T: HttpTransport>(client: &RestconfClient<T>, input: &PingInput) -> Result<PingOutput, RpcError> {
+        ping_full(client, input).await.map(ResponseEnvelope::into_body)
+    }
+
+    /// Like [`ping`], also returning the response status and headers.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`ping`].
+    pub async fn ping_full<T: HttpTransport>(client: &RestconfClient<T>, input: &PingInput) -> Result<ResponseEnvelope<PingOutput>, RpcError> {
+        // Serialize input to JSON
+        let body = rustconf_runtime::wrap_envelope("yang11-kitchen-sink:input", input)
+            .map_err(|e| RpcError::SerializationError(format!("Failed to serialize input: {}", e)))?;
+
+        // Construct RESTCONF URL
+        let base = client.base_url().trim_end_matches('/');
+        let url = format!("{}{}/{}:{}", base, consts::OPERATIONS_PATH, percent_encode("yang11-kitchen-sink"), percent_encode("ping"));
+
+        // Build HTTP request
+        let request = HttpRequest {
+            method: HttpMethod::POST,
+            url,
+            headers: vec![
+                ("Content-Type".to_string(), consts::YANG_DATA_JSON.to_string()),
+                ("Accept".to_string(), consts::YANG_DATA_JSON.to_string()),
+            ],
+            body: Some(body.into()),
+            context: Default::default(),
+        };
+
+        // Execute request through client
+        let response = client.execute(request).await?;
+
+        // Map HTTP status to error or deserialize response
+        let output = PingOutput::try_from(&response)?;
+        Ok(ResponseEnvelope::new(response, output))
+    }
+
+    /// RESTCONF CRUD operations for data resources.
+    pub mod crud {
+        use super::*;
+
+        /// Build the RESTCONF URL path for the system container.
+        #[allow(dead_code)]
+        fn system_path() -> String {
+            "/restconf/data/system".to_string()
+        }
+
+        /// Retrieve the system container.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the operation fails.
+        pub async fn get_system() -> Result<System, RpcError> {
+            let _path = system_path();
+            // TODO: Implement GET request to RESTCONF server
+            unimplemented!("GET operation not yet implemented")
+        }
+
+        /// Discover the methods permitted on the system container.
+        ///
+        /// Issues OPTIONS and parses the `Allow` and `Accept-Patch` headers.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the request fails or the server responds with a non-2xx status.
+        pub async fn options_system<T: HttpTransport>(client: &RestconfClient<T>) -> Result<ResourceCapabilities, RpcError> {
+            let url = format!("{}{}", client.base_url().trim_end_matches('/'), system_path());
+            let request = HttpRequest {
+                method: HttpMethod::OPTIONS,
+                url,
+                headers: vec![],
+                body: None,
+                context: Default::default(),
+            };
+            let response = client.execute(request).await?;
+            ResourceCapabilities::try_from(&response)
+        }
+
+        /// Check whether the system container exists.
+        ///
+        /// Issues HEAD, falling back to GET with `depth=1` if the server rejects HEAD.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the request fails or the server responds with a status other than 2xx or 404.
+        pub async fn exists_system<T: HttpTransport>(client: &RestconfClient<T>) -> Result<bool, RpcError> {
+            client.exists(&system_path()).await
+        }
+
+        /// Replace the system container.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the operation fails.
+        pub async fn put_system(_data: System) -> Result<(), RpcError> {
+            let _path = system_path();
+            // TODO: Implement PUT request to RESTCONF server
+            unimplemented!("PUT operation not yet implemented")
+        }
+
+        /// Partially update the system container.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the operation fails.
+        pub async fn patch_system(_data: System) -> Result<(), RpcError> {
+            let _path = system_path();
+            // TODO: Implement PATCH request to RESTCONF server
+            unimplemented!("PATCH operation not yet implemented")
+        }
+
+        /// Delete the system container.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the operation fails.
+        pub async fn delete_system() -> Result<(), RpcError> {
+            let _path = system_path();
+            // TODO: Implement DELETE request to RESTCONF server
+            unimplemented!("DELETE operation not yet implemented")
+        }
+
+        /// Build the RESTCONF URL path for the route collection.
+        #[allow(dead_code)]
+        fn route_path() -> String {
+            "/restconf/data/route".to_string()
+        }
+
+        /// Build the RESTCONF URL path for a specific route item.
+        ///
+        /// Keys are percent-encoded for URL safety.
+        #[allow(dead_code)]
+        fn route_item_path(prefix: String, next_hop: String) -> String {
+            let mut path = "/restconf/data/route".to_string();
+            path.push_str(&format!("={}=", percent_encode(&prefix.to_string())));
+            path.push_str(&format!("={}=", percent_encode(&next_hop.to_string())));
+            path
+        }
+
+        /// Retrieve all route items.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the operation fails.
+        pub async fn get_route() -> Result<Vec<Route>, RpcError> {
+            let _path = route_path();
+            // TODO: Implement GET request to RESTCONF server
+            unimplemented!("GET operation not yet implemented")
+        }
+
+        /// Retrieve a single route item by key.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the operation fails.
+        pub async fn get_route_by_key(prefix: String, next_hop: String) -> Result<Route, RpcError> {
+            let _path = route_item_path(prefix, next_hop);
+            // TODO: Implement GET request to RESTCONF server
+            unimplemented!("GET operation not yet implemented")
+        }
+
+        /// Discover the methods permitted on the route collection.
+        ///
+        /// Issues OPTIONS and parses the `Allow` and `Accept-Patch` headers.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the request fails or the server responds with a non-2xx status.
+        pub async fn options_route<T: HttpTransport>(client: &RestconfClient<T>) -> Result<ResourceCapabilities, RpcError> {
+            let url = format!("{}{}", client.base_url().trim_end_matches('/'), route_path());
+            let request = HttpRequest {
+                method: HttpMethod::OPTIONS,
+                url,
+                headers: vec![],
+                body: None,
+                context: Default::default(),
+            };
+            let response = client.execute(request).await?;
+            ResourceCapabilities::try_from(&response)
+        }
+
+        /// Discover the methods permitted on a route item by key.
+        ///
+        /// Issues OPTIONS and parses the `Allow` and `Accept-Patch` headers.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the request fails or the server responds with a non-2xx status.
+        pub async fn options_route_by_key<T: HttpTransport>(client: &RestconfClient<T>, prefix: String, next_hop: String) -> Result<ResourceCapabilities, RpcError> {
+            let url = format!("{}{}", client.base_url().trim_end_matches('/'), route_item_path(prefix, next_hop));
+            let request = HttpRequest {
+                method: HttpMethod::OPTIONS,
+                url,
+                headers: vec![],
+                body: None,
+                context: Default::default(),
+            };
+            let response = client.execute(request).await?;
+            ResourceCapabilities::try_from(&response)
+        }
+
+        /// Deserialize the entries of a route collection one at a time.
+        ///
+        /// The returned `DeserializeSeed` calls `on_entry` for every entry and produces
+        /// the number of entries, without collecting them into a `Vec`.
+        pub fn route_entries<F: FnMut(Route)>(on_entry: F) -> rustconf_runtime::ListEntries<'static, Route, F> {
+            rustconf_runtime::ListEntries::new("route", on_entry)
+        }
+
+        /// Read a route collection response from `body`, calling `on_entry` for each entry.
+        ///
+        /// Only one entry is held in memory at a time. Returns the number of entries.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the body is not valid JSON or an entry does not match.
+        pub fn for_each_route<R: std::io::Read>(body: R, on_entry: impl FnMut(Route)) -> Result<usize, RpcError> {
+            rustconf_runtime::for_each_list_entry(body, "route", on_entry)
+        }
+
+        /// Check whether a route item exists.
+        ///
+        /// Issues HEAD, falling back to GET with `depth=1` if the server rejects HEAD.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the request fails or the server responds with a status other than 2xx or 404.
+        pub async fn exists_route_by_key<T: HttpTransport>(client: &RestconfClient<T>, prefix: String, next_hop: String) -> Result<bool, RpcError> {
+            client.exists(&route_item_path(prefix, next_hop)).await
+        }
+
+        /// Create a new route item.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the operation fails.
+        pub async fn create_route(_data: Route) -> Result<(), RpcError> {
+            let _path = route_path();
+            // TODO: Implement POST request to RESTCONF server
+            unimplemented!("POST operation not yet implemented")
+        }
+
+        /// Replace a route item by key.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the operation fails.
+        pub async fn put_route(prefix: String, next_hop: String, _data: Route) -> Result<(), RpcError> {
+            let _path = route_item_path(prefix, next_hop);
+            // TODO: Implement PUT request to RESTCONF server
+            unimplemented!("PUT operation not yet implemented")
+        }
+
+        /// Partially update a route item by key.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the operation fails.
+        pub async fn patch_route(prefix: String, next_hop: String, _data: Route) -> Result<(), RpcError> {
+            let _path = route_item_path(prefix, next_hop);
+            // TODO: Implement PATCH request to RESTCONF server
+            unimplemented!("PATCH operation not yet implemented")
+        }
+
+        /// Delete a route item by key.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the operation fails.
+        pub async fn delete_route(prefix: String, next_hop: String) -> Result<(), RpcError> {
+            let _path = route_item_path(prefix, next_hop);
+            // TODO: Implement DELETE request to RESTCONF server
+            unimplemented!("DELETE operation not yet implemented")
+        }
+
+    }
+    /// Typed handles for the data resources of the module.
+    ///
+    /// Bring `resources::Yang11KitchenSinkResources` into scope to reach them from a `RestconfClient`.
+    pub mod resources {
+        use super::*;
+
+        /// Deserialize the contents of the `member` envelope of a response.
+        fn decode<V: serde::de::DeserializeOwned>(member: &str, response: &HttpResponse) -> Result<V, RpcError> {
+            rustconf_runtime::unwrap_envelope(member, &response.body)
+                .map_err(|e| RpcError::DeserializationError(format!("Failed to deserialize {}: {}", member, e)))
+        }
+
+        /// Serialize `value` inside the `member` envelope.
+        fn encode<V: serde::Serialize>(member: &str, value: &V) -> Result<Vec<u8>, RpcError> {
+            rustconf_runtime::wrap_envelope(member, value)
+                .map_err(|e| RpcError::SerializationError(format!("Failed to serialize {}: {}", member, e)))
+        }
+
+        /// Entry points to the data resources of the `yang11-kitchen-sink` module.
+        pub trait Yang11KitchenSinkResources<T: HttpTransport> {
+            /// System-wide settings.
+            fn system(&self) -> SystemResource<'_, T>;
+            /// Static routes, keyed by destination and next hop.
+            fn route(&self) -> RouteResource<'_, T>;
+        }
+
+        impl<T: HttpTransport> Yang11KitchenSinkResources<T> for RestconfClient<T> {
+            fn system(&self) -> SystemResource<'_, T> {
+                SystemResource { client: self, path: "/restconf/data/system".to_string() }
+            }
+            fn route(&self) -> RouteResource<'_, T> {
+                RouteResource { client: self, path: "/restconf/data/route".to_string() }
+            }
+        }
+
+        /// Handle for the `system` container.
+        #[must_use = "a resource handle does nothing until one of its methods is called"]
+        pub struct SystemResource<'a, T: HttpTransport> {
+            client: &'a RestconfClient<T>,
+            path: String,
+        }
+
+        impl<T: HttpTransport> SystemResource<'_, T> {
+            /// The RESTCONF path of the resource.
+            #[must_use]
+            pub fn path(&self) -> &str {
+                &self.path
+            }
+
+            /// Retrieve the container.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get(&self) -> Result<System, RpcError> {
+                self.get_full().await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`get`](Self::get), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get_full(&self) -> Result<ResponseEnvelope<System>, RpcError> {
+                let response = self.client.raw_request(HttpMethod::GET, &self.path, None).await?;
+                let value = decode("yang11-kitchen-sink:system", &response)?;
+                Ok(ResponseEnvelope::new(response, value))
+            }
+
+            /// Retrieve the operational state of the container with the origin of each node.
+            ///
+            /// Requires a server implementing NMDA (RFC 8527). Origins are keyed by
+            /// JSON Pointers into the response, starting with the module-qualified
+            /// member of the resource.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get_with_origin(&self) -> Result<rustconf_runtime::WithOrigin<System>, RpcError> {
+                let path = rustconf_runtime::origin::with_origin_path(&self.path);
+                let response = self.client.raw_request(HttpMethod::GET, &path, None).await?;
+                let value = decode("yang11-kitchen-sink:system", &response)?;
+                let origins = rustconf_runtime::OriginTable::from_json(&response.body)?;
+                Ok(rustconf_runtime::WithOrigin { data: value, origins })
+            }
+
+            /// Replace the container with `data`.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn put(&self, data: &System) -> Result<(), RpcError> {
+                self.put_full(data).await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`put`](Self::put), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn put_full(&self, data: &System) -> Result<ResponseEnvelope<()>, RpcError> {
+                let body = encode("yang11-kitchen-sink:system", data)?;
+                let response = self.client.raw_request(HttpMethod::PUT, &self.path, Some(body.into())).await?;
+                Ok(ResponseEnvelope::new(response, ()))
+            }
+
+            /// Merge `data` into the container.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn patch(&self, data: &System) -> Result<(), RpcError> {
+                self.patch_full(data).await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`patch`](Self::patch), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn patch_full(&self, data: &System) -> Result<ResponseEnvelope<()>, RpcError> {
+                let body = encode("yang11-kitchen-sink:system", data)?;
+                let response = self.client.raw_request(HttpMethod::PATCH, &self.path, Some(body.into())).await?;
+                Ok(ResponseEnvelope::new(response, ()))
+            }
+
+            /// Delete the container.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn delete(&self) -> Result<(), RpcError> {
+                self.delete_full().await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`delete`](Self::delete), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn delete_full(&self) -> Result<ResponseEnvelope<()>, RpcError> {
+                let response = self.client.raw_request(HttpMethod::DELETE, &self.path, None).await?;
+                Ok(ResponseEnvelope::new(response, ()))
+            }
+
+            /// Retrieve the value of the `hostname` leaf alone.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get_hostname(&self) -> Result<Hostname, RpcError> {
+                self.get_hostname_full().await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`get_hostname`](Self::get_hostname), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get_hostname_full(&self) -> Result<ResponseEnvelope<Hostname>, RpcError> {
+                let path = format!("{}/hostname", self.path);
+                let response = self.client.raw_request(HttpMethod::GET, &path, None).await?;
+                let value = decode("yang11-kitchen-sink:hostname", &response)?;
+                Ok(ResponseEnvelope::new(response, value))
+            }
+
+            /// Set the `hostname` leaf to `value`, leaving the rest of the container unchanged.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn set_hostname(&self, value: Hostname) -> Result<(), RpcError> {
+                self.set_hostname_full(value).await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`set_hostname`](Self::set_hostname), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn set_hostname_full(&self, value: Hostname) -> Result<ResponseEnvelope<()>, RpcError> {
+                let path = format!("{}/hostname", self.path);
+                let body = encode("yang11-kitchen-sink:hostname", &value)?;
+                let response = self.client.raw_request(HttpMethod::PUT, &path, Some(body.into())).await?;
+                Ok(ResponseEnvelope::new(response, ()))
+            }
+
+            /// Retrieve the value of the `enabled` leaf alone.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get_enabled(&self) -> Result<bool, RpcError> {
+                self.get_enabled_full().await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`get_enabled`](Self::get_enabled), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get_enabled_full(&self) -> Result<ResponseEnvelope<bool>, RpcError> {
+                let path = format!("{}/enabled", self.path);
+                let response = self.client.raw_request(HttpMethod::GET, &path, None).await?;
+                let value = decode("yang11-kitchen-sink:enabled", &response)?;
+                Ok(ResponseEnvelope::new(response, value))
+            }
+
+            /// Set the `enabled` leaf to `value`, leaving the rest of the container unchanged.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn set_enabled(&self, value: bool) -> Result<(), RpcError> {
+                self.set_enabled_full(value).await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`set_enabled`](Self::set_enabled), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn set_enabled_full(&self, value: bool) -> Result<ResponseEnvelope<()>, RpcError> {
+                let path = format!("{}/enabled", self.path);
+                let body = encode("yang11-kitchen-sink:enabled", &value)?;
+                let response = self.client.raw_request(HttpMethod::PUT, &path, Some(body.into())).await?;
+                Ok(ResponseEnvelope::new(response, ()))
+            }
+
+            /// Retrieve the value of the `motd` leaf alone.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get_motd(&self) -> Result<String, RpcError> {
+                self.get_motd_full().await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`get_motd`](Self::get_motd), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get_motd_full(&self) -> Result<ResponseEnvelope<String>, RpcError> {
+                let path = format!("{}/motd", self.path);
+                let response = self.client.raw_request(HttpMethod::GET, &path, None).await?;
+                let value = decode("yang11-kitchen-sink:motd", &response)?;
+                Ok(ResponseEnvelope::new(response, value))
+            }
+
+            /// Set the `motd` leaf to `value`, leaving the rest of the container unchanged.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn set_motd(&self, value: String) -> Result<(), RpcError> {
+                self.set_motd_full(value).await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`set_motd`](Self::set_motd), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn set_motd_full(&self, value: String) -> Result<ResponseEnvelope<()>, RpcError> {
+                let path = format!("{}/motd", self.path);
+                let body = encode("yang11-kitchen-sink:motd", &value)?;
+                let response = self.client.raw_request(HttpMethod::PUT, &path, Some(body.into())).await?;
+                Ok(ResponseEnvelope::new(response, ()))
+            }
+
+            /// Retrieve the value of the `host-key` leaf alone.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get_host_key(&self) -> Result<ValidatedBinaryId4525a1be33a1c9c0, RpcError> {
+                self.get_host_key_full().await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`get_host_key`](Self::get_host_key), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get_host_key_full(&self) -> Result<ResponseEnvelope<ValidatedBinaryId4525a1be33a1c9c0>, RpcError> {
+                let path = format!("{}/host-key", self.path);
+                let response = self.client.raw_request(HttpMethod::GET, &path, None).await?;
+                let value = decode("yang11-kitchen-sink:host-key", &response)?;
+                Ok(ResponseEnvelope::new(response, value))
+            }
+
+            /// Set the `host-key` leaf to `value`, leaving the rest of the container unchanged.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn set_host_key(&self, value: ValidatedBinaryId4525a1be33a1c9c0) -> Result<(), RpcError> {
+                self.set_host_key_full(value).await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`set_host_key`](Self::set_host_key), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn set_host_key_full(&self, value: ValidatedBinaryId4525a1be33a1c9c0) -> Result<ResponseEnvelope<()>, RpcError> {
+                let path = format!("{}/host-key", self.path);
+                let body = encode("yang11-kitchen-sink:host-key", &value)?;
+                let response = self.client.raw_request(HttpMethod::PUT, &path, Some(body.into())).await?;
+                Ok(ResponseEnvelope::new(response, ()))
+            }
+
+            /// Retrieve the value of the `temperature` leaf alone.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get_temperature(&self) -> Result<ValidatedInt16Idcffc60125879a407, RpcError> {
+                self.get_temperature_full().await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`get_temperature`](Self::get_temperature), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get_temperature_full(&self) -> Result<ResponseEnvelope<ValidatedInt16Idcffc60125879a407>, RpcError> {
+                let path = format!("{}/temperature", self.path);
+                let response = self.client.raw_request(HttpMethod::GET, &path, None).await?;
+                let value = decode("yang11-kitchen-sink:temperature", &response)?;
+                Ok(ResponseEnvelope::new(response, value))
+            }
+
+            /// Set the `temperature` leaf to `value`, leaving the rest of the container unchanged.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn set_temperature(&self, value: ValidatedInt16Idcffc60125879a407) -> Result<(), RpcError> {
+                self.set_temperature_full(value).await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`set_temperature`](Self::set_temperature), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn set_temperature_full(&self, value: ValidatedInt16Idcffc60125879a407) -> Result<ResponseEnvelope<()>, RpcError> {
+                let path = format!("{}/temperature", self.path);
+                let body = encode("yang11-kitchen-sink:temperature", &value)?;
+                let response = self.client.raw_request(HttpMethod::PUT, &path, Some(body.into())).await?;
+                Ok(ResponseEnvelope::new(response, ()))
+            }
+
+            /// Check whether the resource exists.
+            ///
+            /// Issues HEAD, falling back to GET with `depth=1` if the server rejects HEAD.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a status other than 2xx or 404.
+            pub async fn exists(&self) -> Result<bool, RpcError> {
+                self.client.exists(&self.path).await
+            }
+
+            /// Discover the methods permitted on the resource.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn options(&self) -> Result<ResourceCapabilities, RpcError> {
+                let response = self.client.raw_request(HttpMethod::OPTIONS, &self.path, None).await?;
+                ResourceCapabilities::try_from(&response)
+            }
+        }
+
+        /// Handle for the `route` list.
+        #[must_use = "a resource handle does nothing until one of its methods is called"]
+        pub struct RouteResource<'a, T: HttpTransport> {
+            client: &'a RestconfClient<T>,
+            path: String,
+        }
+
+        impl<'a, T: HttpTransport> RouteResource<'a, T> {
+            /// The RESTCONF path of the resource.
+            #[must_use]
+            pub fn path(&self) -> &str {
+                &self.path
+            }
+
+            /// Retrieve all entries of the list.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get(&self) -> Result<Vec<Route>, RpcError> {
+                self.get_full().await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`get`](Self::get), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get_full(&self) -> Result<ResponseEnvelope<Vec<Route>>, RpcError> {
+                let response = self.client.raw_request(HttpMethod::GET, &self.path, None).await?;
+                let value = decode("yang11-kitchen-sink:route", &response)?;
+                Ok(ResponseEnvelope::new(response, value))
+            }
+
+            /// Retrieve the operational state of the list with the origin of each node.
+            ///
+            /// Requires a server implementing NMDA (RFC 8527). Origins are keyed by
+            /// JSON Pointers into the response, starting with the module-qualified
+            /// member of the resource.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get_with_origin(&self) -> Result<rustconf_runtime::WithOrigin<Vec<Route>>, RpcError> {
+                let path = rustconf_runtime::origin::with_origin_path(&self.path);
+                let response = self.client.raw_request(HttpMethod::GET, &path, None).await?;
+                let value = decode("yang11-kitchen-sink:route", &response)?;
+                let origins = rustconf_runtime::OriginTable::from_json(&response.body)?;
+                Ok(rustconf_runtime::WithOrigin { data: value, origins })
+            }
+
+            /// Create a new entry, which must not exist yet.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn post(&self, entry: &Route) -> Result<(), RpcError> {
+                self.post_full(entry).await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`post`](Self::post), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn post_full(&self, entry: &Route) -> Result<ResponseEnvelope<()>, RpcError> {
+                let body = encode("yang11-kitchen-sink:route", &[entry])?;
+                let response = self.client.raw_request(HttpMethod::POST, consts::DATA_PATH, Some(body.into())).await?;
+                Ok(ResponseEnvelope::new(response, ()))
+            }
+
+            /// Handle for the entry with the given keys.
+            pub fn item(&self, prefix: impl Into<String>, next_hop: impl Into<String>) -> RouteItemResource<'a, T> {
+                let prefix: String = prefix.into();
+                let next_hop: String = next_hop.into();
+                RouteItemResource {
+                    client: self.client,
+                    path: format!("{}={},{}", self.path, percent_encode(&prefix), percent_encode(&next_hop)),
+                }
+            }
+
+            /// Replace all entries of the list with `entries`, a batch at a time.
+            ///
+            /// Set the batch size, progress callback or point to resume from on the
+            /// returned [`ChunkedWrite`](rustconf_runtime::ChunkedWrite) and `run` it.
+            pub fn replace_chunked(&self, entries: &'a [Route]) -> rustconf_runtime::ChunkedWrite<'a, Route> {
+                rustconf_runtime::ChunkedWrite::new(self.client, self.path.clone(), "yang11-kitchen-sink:route", &["prefix", "next-hop"], entries)
+            }
+
+            /// Check whether the resource exists.
+            ///
+            /// Issues HEAD, falling back to GET with `depth=1` if the server rejects HEAD.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a status other than 2xx or 404.
+            pub async fn exists(&self) -> Result<bool, RpcError> {
+                self.client.exists(&self.path).await
+            }
+
+            /// Discover the methods permitted on the resource.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn options(&self) -> Result<ResourceCapabilities, RpcError> {
+                let response = self.client.raw_request(HttpMethod::OPTIONS, &self.path, None).await?;
+                ResourceCapabilities::try_from(&response)
+            }
+        }
+
+        /// Handle for an entry of the `route` list.
+        #[must_use = "a resource handle does nothing until one of its methods is called"]
+        pub struct RouteItemResource<'a, T: HttpTransport> {
+            client: &'a RestconfClient<T>,
+            path: String,
+        }
+
+        impl<T: HttpTransport> RouteItemResource<'_, T> {
+            /// The RESTCONF path of the resource.
+            #[must_use]
+            pub fn path(&self) -> &str {
+                &self.path
+            }
+
+            /// Retrieve the entry.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get(&self) -> Result<Route, RpcError> {
+                self.get_full().await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`get`](Self::get), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get_full(&self) -> Result<ResponseEnvelope<Route>, RpcError> {
+                let response = self.client.raw_request(HttpMethod::GET, &self.path, None).await?;
+                let entries: Vec<Route> = decode("yang11-kitchen-sink:route", &response)?;
+                let value = entries.into_iter().next().ok_or_else(|| {
+                    RpcError::DeserializationError("Response has no `yang11-kitchen-sink:route` entry".to_string())
+                })?;
+                Ok(ResponseEnvelope::new(response, value))
+            }
+
+            /// Retrieve the operational state of the entry with the origin of each node.
+            ///
+            /// Requires a server implementing NMDA (RFC 8527). Origins are keyed by
+            /// JSON Pointers into the response, starting with the module-qualified
+            /// member of the resource.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get_with_origin(&self) -> Result<rustconf_runtime::WithOrigin<Route>, RpcError> {
+                let path = rustconf_runtime::origin::with_origin_path(&self.path);
+                let response = self.client.raw_request(HttpMethod::GET, &path, None).await?;
+                let entries: Vec<Route> = decode("yang11-kitchen-sink:route", &response)?;
+                let value = entries.into_iter().next().ok_or_else(|| {
+                    RpcError::DeserializationError("Response has no `yang11-kitchen-sink:route` entry".to_string())
+                })?;
+                let origins = rustconf_runtime::OriginTable::from_json(&response.body)?;
+                Ok(rustconf_runtime::WithOrigin { data: value, origins })
+            }
+
+            /// Create or replace the entry with `entry`.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn put(&self, entry: &Route) -> Result<(), RpcError> {
+                self.put_full(entry).await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`put`](Self::put), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn put_full(&self, entry: &Route) -> Result<ResponseEnvelope<()>, RpcError> {
+                let body = encode("yang11-kitchen-sink:route", &[entry])?;
+                let response = self.client.raw_request(HttpMethod::PUT, &self.path, Some(body.into())).await?;
+                Ok(ResponseEnvelope::new(response, ()))
+            }
+
+            /// Merge `entry` into the entry.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn patch(&self, entry: &Route) -> Result<(), RpcError> {
+                self.patch_full(entry).await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`patch`](Self::patch), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn patch_full(&self, entry: &Route) -> Result<ResponseEnvelope<()>, RpcError> {
+                let body = encode("yang11-kitchen-sink:route", &[entry])?;
+                let response = self.client.raw_request(HttpMethod::PATCH, &self.path, Some(body.into())).await?;
+                Ok(ResponseEnvelope::new(response, ()))
+            }
+
+            /// Delete the entry.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn delete(&self) -> Result<(), RpcError> {
+                self.delete_full().await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`delete`](Self::delete), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn delete_full(&self) -> Result<ResponseEnvelope<()>, RpcError> {
+                let response = self.client.raw_request(HttpMethod::DELETE, &self.path, None).await?;
+                Ok(ResponseEnvelope::new(response, ()))
+            }
+
+            /// Check whether the resource exists.
+            ///
+            /// Issues HEAD, falling back to GET with `depth=1` if the server rejects HEAD.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a status other than 2xx or 404.
+            pub async fn exists(&self) -> Result<bool, RpcError> {
+                self.client.exists(&self.path).await
+            }
+
+            /// Discover the methods permitted on the resource.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn options(&self) -> Result<ResourceCapabilities, RpcError> {
+                let response = self.client.raw_request(HttpMethod::OPTIONS, &self.path, None).await?;
+                ResourceCapabilities::try_from(&response)
+            }
+        }
+    }
+}
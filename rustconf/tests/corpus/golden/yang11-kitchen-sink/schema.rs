@@ -0,0 +1,289 @@
+// This file is automatically generated by rustconf.
+// DO NOT EDIT MANUALLY.
+
+#![allow(unused_imports, unused_variables, dead_code, clippy::module_inception)]
+
+/// Data nodes of the yang11-kitchen-sink YANG module, e.g. for mapping the `error-path` of
+/// a RESTCONF error to the field that caused it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SchemaNode {
+    /// `/system`
+    System,
+    /// `/system/hostname`
+    SystemHostname,
+    /// `/system/enabled`
+    SystemEnabled,
+    /// `/system/motd`
+    SystemMotd,
+    /// `/system/dns-server`
+    SystemDnsServer,
+    /// `/system/tag`
+    SystemTag,
+    /// `/system/host-key`
+    SystemHostKey,
+    /// `/system/debug`
+    SystemDebug,
+    /// `/system/temperature`
+    SystemTemperature,
+    /// `/system/certificate`
+    SystemCertificate,
+    /// `/system/verify-peer`
+    SystemVerifyPeer,
+    /// `/system/public-key`
+    SystemPublicKey,
+    /// `/system/logging`
+    SystemLogging,
+    /// `/system/logging/level`
+    SystemLoggingLevel,
+    /// `/system/logging/remote-server`
+    SystemLoggingRemoteServer,
+    /// `/system/logging/remote-server/name`
+    SystemLoggingRemoteServerName,
+    /// `/system/logging/remote-server/address`
+    SystemLoggingRemoteServerAddress,
+    /// `/system/logging/remote-server/port`
+    SystemLoggingRemoteServerPort,
+    /// `/system/logging/remote-server/min-severity`
+    SystemLoggingRemoteServerMinSeverity,
+    /// `/system/logging/buffer-size`
+    SystemLoggingBufferSize,
+    /// `/system/statistics`
+    SystemStatistics,
+    /// `/system/statistics/uptime`
+    SystemStatisticsUptime,
+    /// `/system/statistics/last-change`
+    SystemStatisticsLastChange,
+    /// `/system/statistics/cpu`
+    SystemStatisticsCpu,
+    /// `/route`
+    Route,
+    /// `/route/prefix`
+    RoutePrefix,
+    /// `/route/next-hop`
+    RouteNextHop,
+    /// `/route/metric`
+    RouteMetric,
+    /// `/route/interface`
+    RouteInterface,
+}
+
+impl SchemaNode {
+    /// All data nodes, in schema order.
+    pub const ALL: &[SchemaNode] = &[SchemaNode::System, SchemaNode::SystemHostname, SchemaNode::SystemEnabled, SchemaNode::SystemMotd, SchemaNode::SystemDnsServer, SchemaNode::SystemTag, SchemaNode::SystemHostKey, SchemaNode::SystemDebug, SchemaNode::SystemTemperature, SchemaNode::SystemCertificate, SchemaNode::SystemVerifyPeer, SchemaNode::SystemPublicKey, SchemaNode::SystemLogging, SchemaNode::SystemLoggingLevel, SchemaNode::SystemLoggingRemoteServer, SchemaNode::SystemLoggingRemoteServerName, SchemaNode::SystemLoggingRemoteServerAddress, SchemaNode::SystemLoggingRemoteServerPort, SchemaNode::SystemLoggingRemoteServerMinSeverity, SchemaNode::SystemLoggingBufferSize, SchemaNode::SystemStatistics, SchemaNode::SystemStatisticsUptime, SchemaNode::SystemStatisticsLastChange, SchemaNode::SystemStatisticsCpu, SchemaNode::Route, SchemaNode::RoutePrefix, SchemaNode::RouteNextHop, SchemaNode::RouteMetric, SchemaNode::RouteInterface];
+
+    /// Schema path without module qualifiers or list keys, e.g. `/a/b`.
+    #[must_use]
+    pub fn schema_path(&self) -> &'static str {
+        match *self {
+            SchemaNode::System => "/system",
+            SchemaNode::SystemHostname => "/system/hostname",
+            SchemaNode::SystemEnabled => "/system/enabled",
+            SchemaNode::SystemMotd => "/system/motd",
+            SchemaNode::SystemDnsServer => "/system/dns-server",
+            SchemaNode::SystemTag => "/system/tag",
+            SchemaNode::SystemHostKey => "/system/host-key",
+            SchemaNode::SystemDebug => "/system/debug",
+            SchemaNode::SystemTemperature => "/system/temperature",
+            SchemaNode::SystemCertificate => "/system/certificate",
+            SchemaNode::SystemVerifyPeer => "/system/verify-peer",
+            SchemaNode::SystemPublicKey => "/system/public-key",
+            SchemaNode::SystemLogging => "/system/logging",
+            SchemaNode::SystemLoggingLevel => "/system/logging/level",
+            SchemaNode::SystemLoggingRemoteServer => "/system/logging/remote-server",
+            SchemaNode::SystemLoggingRemoteServerName => "/system/logging/remote-server/name",
+            SchemaNode::SystemLoggingRemoteServerAddress => "/system/logging/remote-server/address",
+            SchemaNode::SystemLoggingRemoteServerPort => "/system/logging/remote-server/port",
+            SchemaNode::SystemLoggingRemoteServerMinSeverity => "/system/logging/remote-server/min-severity",
+            SchemaNode::SystemLoggingBufferSize => "/system/logging/buffer-size",
+            SchemaNode::SystemStatistics => "/system/statistics",
+            SchemaNode::SystemStatisticsUptime => "/system/statistics/uptime",
+            SchemaNode::SystemStatisticsLastChange => "/system/statistics/last-change",
+            SchemaNode::SystemStatisticsCpu => "/system/statistics/cpu",
+            SchemaNode::Route => "/route",
+            SchemaNode::RoutePrefix => "/route/prefix",
+            SchemaNode::RouteNextHop => "/route/next-hop",
+            SchemaNode::RouteMetric => "/route/metric",
+            SchemaNode::RouteInterface => "/route/interface",
+        }
+    }
+
+    /// Name of the Rust field holding the node in its parent struct.
+    #[must_use]
+    pub fn field_name(&self) -> &'static str {
+        match *self {
+            SchemaNode::System => "system",
+            SchemaNode::SystemHostname => "hostname",
+            SchemaNode::SystemEnabled => "enabled",
+            SchemaNode::SystemMotd => "motd",
+            SchemaNode::SystemDnsServer => "dns_server",
+            SchemaNode::SystemTag => "tag",
+            SchemaNode::SystemHostKey => "host_key",
+            SchemaNode::SystemDebug => "debug",
+            SchemaNode::SystemTemperature => "temperature",
+            SchemaNode::SystemCertificate => "certificate",
+            SchemaNode::SystemVerifyPeer => "verify_peer",
+            SchemaNode::SystemPublicKey => "public_key",
+            SchemaNode::SystemLogging => "logging",
+            SchemaNode::SystemLoggingLevel => "level",
+            SchemaNode::SystemLoggingRemoteServer => "remote_server",
+            SchemaNode::SystemLoggingRemoteServerName => "name",
+            SchemaNode::SystemLoggingRemoteServerAddress => "address",
+            SchemaNode::SystemLoggingRemoteServerPort => "port",
+            SchemaNode::SystemLoggingRemoteServerMinSeverity => "min_severity",
+            SchemaNode::SystemLoggingBufferSize => "buffer_size",
+            SchemaNode::SystemStatistics => "statistics",
+            SchemaNode::SystemStatisticsUptime => "uptime",
+            SchemaNode::SystemStatisticsLastChange => "last_change",
+            SchemaNode::SystemStatisticsCpu => "cpu",
+            SchemaNode::Route => "route",
+            SchemaNode::RoutePrefix => "prefix",
+            SchemaNode::RouteNextHop => "next_hop",
+            SchemaNode::RouteMetric => "metric",
+            SchemaNode::RouteInterface => "interface",
+        }
+    }
+
+    /// Parent node, or `None` for top-level nodes.
+    #[must_use]
+    pub fn parent(&self) -> Option<SchemaNode> {
+        match *self {
+            SchemaNode::System => None,
+            SchemaNode::SystemHostname => Some(SchemaNode::System),
+            SchemaNode::SystemEnabled => Some(SchemaNode::System),
+            SchemaNode::SystemMotd => Some(SchemaNode::System),
+            SchemaNode::SystemDnsServer => Some(SchemaNode::System),
+            SchemaNode::SystemTag => Some(SchemaNode::System),
+            SchemaNode::SystemHostKey => Some(SchemaNode::System),
+            SchemaNode::SystemDebug => Some(SchemaNode::System),
+            SchemaNode::SystemTemperature => Some(SchemaNode::System),
+            SchemaNode::SystemCertificate => Some(SchemaNode::System),
+            SchemaNode::SystemVerifyPeer => Some(SchemaNode::System),
+            SchemaNode::SystemPublicKey => Some(SchemaNode::System),
+            SchemaNode::SystemLogging => Some(SchemaNode::System),
+            SchemaNode::SystemLoggingLevel => Some(SchemaNode::SystemLogging),
+            SchemaNode::SystemLoggingRemoteServer => Some(SchemaNode::SystemLogging),
+            SchemaNode::SystemLoggingRemoteServerName => Some(SchemaNode::SystemLoggingRemoteServer),
+            SchemaNode::SystemLoggingRemoteServerAddress => Some(SchemaNode::SystemLoggingRemoteServer),
+            SchemaNode::SystemLoggingRemoteServerPort => Some(SchemaNode::SystemLoggingRemoteServer),
+            SchemaNode::SystemLoggingRemoteServerMinSeverity => Some(SchemaNode::SystemLoggingRemoteServer),
+            SchemaNode::SystemLoggingBufferSize => Some(SchemaNode::SystemLogging),
+            SchemaNode::SystemStatistics => Some(SchemaNode::System),
+            SchemaNode::SystemStatisticsUptime => Some(SchemaNode::SystemStatistics),
+            SchemaNode::SystemStatisticsLastChange => Some(SchemaNode::SystemStatistics),
+            SchemaNode::SystemStatisticsCpu => Some(SchemaNode::SystemStatistics),
+            SchemaNode::Route => None,
+            SchemaNode::RoutePrefix => Some(SchemaNode::Route),
+            SchemaNode::RouteNextHop => Some(SchemaNode::Route),
+            SchemaNode::RouteMetric => Some(SchemaNode::Route),
+            SchemaNode::RouteInterface => Some(SchemaNode::Route),
+        }
+    }
+
+    /// Names of the key leaves of a list, empty for other nodes.
+    #[must_use]
+    pub fn list_keys(&self) -> &'static [&'static str] {
+        match *self {
+            SchemaNode::System => &[],
+            SchemaNode::SystemHostname => &[],
+            SchemaNode::SystemEnabled => &[],
+            SchemaNode::SystemMotd => &[],
+            SchemaNode::SystemDnsServer => &[],
+            SchemaNode::SystemTag => &[],
+            SchemaNode::SystemHostKey => &[],
+            SchemaNode::SystemDebug => &[],
+            SchemaNode::SystemTemperature => &[],
+            SchemaNode::SystemCertificate => &[],
+            SchemaNode::SystemVerifyPeer => &[],
+            SchemaNode::SystemPublicKey => &[],
+            SchemaNode::SystemLogging => &[],
+            SchemaNode::SystemLoggingLevel => &[],
+            SchemaNode::SystemLoggingRemoteServer => &["name"],
+            SchemaNode::SystemLoggingRemoteServerName => &[],
+            SchemaNode::SystemLoggingRemoteServerAddress => &[],
+            SchemaNode::SystemLoggingRemoteServerPort => &[],
+            SchemaNode::SystemLoggingRemoteServerMinSeverity => &[],
+            SchemaNode::SystemLoggingBufferSize => &[],
+            SchemaNode::SystemStatistics => &[],
+            SchemaNode::SystemStatisticsUptime => &[],
+            SchemaNode::SystemStatisticsLastChange => &[],
+            SchemaNode::SystemStatisticsCpu => &[],
+            SchemaNode::Route => &["prefix", "next-hop"],
+            SchemaNode::RoutePrefix => &[],
+            SchemaNode::RouteNextHop => &[],
+            SchemaNode::RouteMetric => &[],
+            SchemaNode::RouteInterface => &[],
+        }
+    }
+
+    /// XPath condition of the node's `when` statement, for tooling that
+    /// checks data against it. The node is only valid while it holds.
+    #[must_use]
+    pub fn when(&self) -> Option<&'static str> {
+        match *self {
+            SchemaNode::System => None,
+            SchemaNode::SystemHostname => None,
+            SchemaNode::SystemEnabled => None,
+            SchemaNode::SystemMotd => None,
+            SchemaNode::SystemDnsServer => None,
+            SchemaNode::SystemTag => None,
+            SchemaNode::SystemHostKey => None,
+            SchemaNode::SystemDebug => None,
+            SchemaNode::SystemTemperature => None,
+            SchemaNode::SystemCertificate => None,
+            SchemaNode::SystemVerifyPeer => Some("../certificate"),
+            SchemaNode::SystemPublicKey => None,
+            SchemaNode::SystemLogging => None,
+            SchemaNode::SystemLoggingLevel => None,
+            SchemaNode::SystemLoggingRemoteServer => None,
+            SchemaNode::SystemLoggingRemoteServerName => None,
+            SchemaNode::SystemLoggingRemoteServerAddress => None,
+            SchemaNode::SystemLoggingRemoteServerPort => None,
+            SchemaNode::SystemLoggingRemoteServerMinSeverity => None,
+            SchemaNode::SystemLoggingBufferSize => None,
+            SchemaNode::SystemStatistics => None,
+            SchemaNode::SystemStatisticsUptime => None,
+            SchemaNode::SystemStatisticsLastChange => None,
+            SchemaNode::SystemStatisticsCpu => None,
+            SchemaNode::Route => None,
+            SchemaNode::RoutePrefix => None,
+            SchemaNode::RouteNextHop => None,
+            SchemaNode::RouteMetric => None,
+            SchemaNode::RouteInterface => None,
+        }
+    }
+
+    /// Map a RESTCONF `error-path` to the node it identifies.
+    ///
+    /// Module qualifiers and list key predicates are ignored, so
+    /// `/example:a/b[name='x']/c` identifies the node at `/a/b/c`.
+    #[must_use]
+    pub fn from_error_path(error_path: &str) -> Option<SchemaNode> {
+        let path = strip_error_path(error_path);
+        SchemaNode::ALL
+            .iter()
+            .copied()
+            .find(|node| node.schema_path() == path)
+    }
+}
+
+/// Remove module qualifiers and key predicates from an instance path.
+fn strip_error_path(error_path: &str) -> String {
+    let mut path = String::new();
+    let mut depth = 0;
+    let mut quote = None;
+    for c in error_path.chars() {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') if depth > 0 => quote = Some(c),
+            (None, '[') => depth += 1,
+            (None, ']') => depth -= 1,
+            (None, _) if depth == 0 => path.push(c),
+            _ => {}
+        }
+    }
+    path.split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| segment.rsplit(':').next().unwrap_or(segment))
+        .fold(String::new(), |path, segment| path + "/" + segment)
+}
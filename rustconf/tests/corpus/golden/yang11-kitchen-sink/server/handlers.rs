@@ -0,0 +1,56 @@
+// This file is automatically generated by rustconf.
+// DO NOT EDIT MANUALLY.
+
+#![allow(unused_imports, unused_variables, dead_code, clippy::module_inception)]
+
+use async_trait::async_trait;
+use rustconf_runtime::ServerError;
+use super::super::types::*;
+use super::super::operations::operations::*;
+
+/// Handler trait for yang11-kitchen-sink YANG module operations.
+///
+/// This trait defines the server-side API surface for RESTCONF operations.
+/// Implement this trait to provide custom handler logic, or use the generated
+/// stub implementation for testing.
+#[async_trait]
+pub trait Yang11KitchenSinkHandler: Send + Sync {
+    /// Reboot the system.
+    async fn reboot(&self, input: RebootInput) -> Result<(), ServerError>;
+    /// Execute ping RPC operation.
+    async fn ping(&self, input: PingInput) -> Result<PingOutput, ServerError>;
+    /// System-wide settings.
+    ///
+    /// GET operation for the system container.
+    async fn get_system(&self) -> Result<System, ServerError>;
+
+    /// PUT operation for the system container.
+    async fn put_system(&self, data: System) -> Result<(), ServerError>;
+
+    /// PATCH operation for the system container.
+    async fn patch_system(&self, data: System) -> Result<(), ServerError>;
+
+    /// DELETE operation for the system container.
+    async fn delete_system(&self) -> Result<(), ServerError>;
+
+    /// Static routes, keyed by destination and next hop.
+    ///
+    /// GET operation for all route items.
+    async fn get_route(&self) -> Result<Vec<Route>, ServerError>;
+
+    /// GET operation for a single route item by key.
+    async fn get_route_by_key(&self, prefix: String, next_hop: String) -> Result<Route, ServerError>;
+
+    /// POST operation for a new route item.
+    async fn create_route(&self, data: Route) -> Result<(), ServerError>;
+
+    /// PUT operation for a route item by key.
+    async fn put_route(&self, prefix: String, next_hop: String, data: Route) -> Result<(), ServerError>;
+
+    /// PATCH operation for a route item by key.
+    async fn patch_route(&self, prefix: String, next_hop: String, data: Route) -> Result<(), ServerError>;
+
+    /// DELETE operation for a route item by key.
+    async fn delete_route(&self, prefix: String, next_hop: String) -> Result<(), ServerError>;
+
+}
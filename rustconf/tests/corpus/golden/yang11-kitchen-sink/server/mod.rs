@@ -0,0 +1,23 @@
+// This file is automatically generated by rustconf.
+// DO NOT EDIT MANUALLY.
+//
+// Server module for YANG module: yang11-kitchen-sink
+
+#![allow(unused_imports, unused_variables, dead_code, clippy::module_inception)]
+
+/// Handler trait implemented by the server application.
+pub mod handlers;
+/// Stub handler returning default values, for testing.
+pub mod stubs;
+/// Routing of RESTCONF requests to handler methods.
+pub mod router;
+/// Registry of handler functions by path.
+pub mod registry;
+/// Publishing notifications to subscribers.
+pub mod notifications;
+
+pub use handlers::*;
+pub use stubs::*;
+pub use router::*;
+pub use registry::*;
+pub use notifications::*;
@@ -0,0 +1,198 @@
+// This file is automatically generated by rustconf.
+// DO NOT EDIT MANUALLY.
+
+#![allow(unused_imports, unused_variables, dead_code, clippy::module_inception)]
+
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use serde::{Deserialize, Serialize};
+use super::super::types::*;
+
+/// Notification data types.
+pub mod notifications {
+    use super::*;
+
+    /// Sent when the configuration changes.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ConfigChange {
+        /// The `changed-at` leaf.
+        #[serde(rename = "changed-at", skip_serializing_if = "Option::is_none")]
+        pub changed_at: Option<ValidatedStringIdbf9149867bc51280>,
+        /// The `changed-path` leaf-list.
+        #[serde(rename = "changed-path", default, skip_serializing_if = "Vec::is_empty")]
+        pub changed_path: Vec<String>,
+        /// The changed subtrees, as data of their modules.
+        #[serde(rename = "changes", skip_serializing_if = "Option::is_none")]
+        pub changes: Option<rustconf_runtime::AnyData>,
+    }
+
+    /// Notification payload for link-flap.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct LinkFlap {
+        /// The `interface` leaf.
+        #[serde(rename = "interface")]
+        pub interface: String,
+        /// The `severity` leaf.
+        #[serde(rename = "severity", skip_serializing_if = "Option::is_none")]
+        pub severity: Option<Severity>,
+    }
+
+}
+
+/// Trait for notification subscribers.
+///
+/// Implement this trait to receive notifications from the publisher.
+#[async_trait::async_trait]
+pub trait NotificationSubscriber: Send + Sync {
+    /// Receive a notification.
+    ///
+    /// # Arguments
+    ///
+    /// * `notification_name` - The name of the notification type
+    /// * `payload` - The serialized notification payload as JSON
+    async fn on_notification(
+        &self,
+        notification_name: &str,
+        payload: Vec<u8>,
+    ) -> Result<(), String>;
+}
+
+/// Notification publisher for sending notifications to subscribers.
+///
+/// This struct manages subscriber registration and notification delivery.
+pub struct NotificationPublisher {
+    subscribers: Arc<RwLock<Vec<Arc<dyn NotificationSubscriber>>>>,
+}
+
+impl NotificationPublisher {
+    /// Create a new notification publisher.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            subscribers: Arc::new(RwLock::new(Vec::new())),
+        }
+    }
+
+    /// Register a new subscriber.
+    ///
+    /// # Arguments
+    ///
+    /// * `subscriber` - The subscriber to register
+    pub async fn subscribe(&self, subscriber: Arc<dyn NotificationSubscriber>) {
+        let mut subs = self.subscribers.write().await;
+        subs.push(subscriber);
+    }
+
+    /// Get the number of active subscribers.
+    pub async fn subscriber_count(&self) -> usize {
+        self.subscribers.read().await.len()
+    }
+
+    /// Remove all subscribers.
+    pub async fn clear_subscribers(&self) {
+        let mut subs = self.subscribers.write().await;
+        subs.clear();
+    }
+
+    /// Publish config-change notification.
+    ///
+    /// Sent when the configuration changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `notification` - The notification data to publish
+    ///
+    /// # Returns
+    ///
+    /// Returns Ok(()) if all subscribers were notified successfully.
+    /// Individual subscriber failures are logged but do not cause the method to fail.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if serialization fails.
+    pub async fn publish_config_change(&self, notification: notifications::ConfigChange) -> Result<(), String> {
+        // Serialize notification to JSON according to YANG schema
+        let payload = serde_json::to_vec(&notification)
+            .map_err(|e| format!("Failed to serialize notification: {}", e))?;
+
+        // Notify all subscribers concurrently
+        let subscribers = self.subscribers.read().await;
+        let notification_name = "config-change";
+
+        // Deliver to all subscribers, handling failures gracefully
+        let mut delivery_errors = Vec::new();
+        for (idx, subscriber) in subscribers.iter().enumerate() {
+            // Deliver to subscriber
+            if let Err(e) = subscriber.on_notification(notification_name, payload.clone()).await {
+                let error_msg = format!("Subscriber {} delivery failed: {}", idx, e);
+                eprintln!("{}", error_msg);
+                delivery_errors.push(error_msg);
+            }
+        }
+
+        // Log summary if there were any failures
+        if !delivery_errors.is_empty() {
+            eprintln!("Notification delivery completed with {} failures out of {} subscribers",
+                delivery_errors.len(), subscribers.len());
+        }
+
+        Ok(())
+    }
+
+    /// Publish link-flap notification.
+    ///
+    /// # Arguments
+    ///
+    /// * `notification` - The notification data to publish
+    ///
+    /// # Returns
+    ///
+    /// Returns Ok(()) if all subscribers were notified successfully.
+    /// Individual subscriber failures are logged but do not cause the method to fail.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if serialization fails.
+    pub async fn publish_link_flap(&self, notification: notifications::LinkFlap) -> Result<(), String> {
+        // Serialize notification to JSON according to YANG schema
+        let payload = serde_json::to_vec(&notification)
+            .map_err(|e| format!("Failed to serialize notification: {}", e))?;
+
+        // Notify all subscribers concurrently
+        let subscribers = self.subscribers.read().await;
+        let notification_name = "link-flap";
+
+        // Deliver to all subscribers, handling failures gracefully
+        let mut delivery_errors = Vec::new();
+        for (idx, subscriber) in subscribers.iter().enumerate() {
+            // Deliver to subscriber
+            if let Err(e) = subscriber.on_notification(notification_name, payload.clone()).await {
+                let error_msg = format!("Subscriber {} delivery failed: {}", idx, e);
+                eprintln!("{}", error_msg);
+                delivery_errors.push(error_msg);
+            }
+        }
+
+        // Log summary if there were any failures
+        if !delivery_errors.is_empty() {
+            eprintln!("Notification delivery completed with {} failures out of {} subscribers",
+                delivery_errors.len(), subscribers.len());
+        }
+
+        Ok(())
+    }
+
+}
+
+impl Default for NotificationPublisher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for NotificationPublisher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NotificationPublisher").finish_non_exhaustive()
+    }
+}
+
@@ -0,0 +1,120 @@
+// This file is automatically generated by rustconf.
+// DO NOT EDIT MANUALLY.
+
+#![allow(unused_imports, unused_variables, dead_code, clippy::module_inception)]
+
+use async_trait::async_trait;
+use rustconf_runtime::{ServerRequest, ServerResponse, ServerError};
+use std::collections::HashMap;
+use std::sync::Arc;
+use super::handlers::*;
+
+/// Type alias for handler functions.
+///
+/// Handler functions take a request and return a response asynchronously.
+pub type HandlerFn<H> = Arc<dyn Fn(Arc<H>, ServerRequest) -> std::pin::Pin<Box<dyn std::future::Future<Output = ServerResponse> + Send>> + Send + Sync>;
+
+/// Registry for mapping operation paths to handler implementations.
+///
+/// The registry stores path patterns mapped to handler functions,
+/// allowing dynamic registration and lookup of handlers at runtime.
+/// When no handler is registered for a path, a default not-implemented
+/// handler is returned.
+pub struct HandlerRegistry<H: Yang11KitchenSinkHandler> {
+    /// Map of path patterns to handler functions
+    handlers: HashMap<String, HandlerFn<H>>,
+    /// The default handler implementation
+    default_handler: Arc<H>,
+}
+
+impl<H: Yang11KitchenSinkHandler + 'static> HandlerRegistry<H> {
+    /// Create a new handler registry with a default handler.
+    ///
+    /// # Arguments
+    ///
+    /// * `default_handler` - The default handler to use when no specific handler is registered
+    pub fn new(default_handler: H) -> Self {
+        Self {
+            handlers: HashMap::new(),
+            default_handler: Arc::new(default_handler),
+        }
+    }
+
+    /// Register a handler for a specific path pattern.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path pattern to register (e.g., "/data/interfaces")
+    /// * `handler` - The handler function to invoke for this path
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if registration succeeds, or an error if the path is invalid.
+    pub fn register(
+        &mut self,
+        path: impl Into<String>,
+        handler: HandlerFn<H>,
+    ) -> Result<(), ServerError> {
+        let path = path.into();
+        
+        // Validate path format
+        if path.is_empty() {
+            return Err(ServerError::ValidationError(
+                "Handler path cannot be empty".to_string()
+            ));
+        }
+        
+        // Store handler
+        self.handlers.insert(path, handler);
+        Ok(())
+    }
+
+    /// Look up a handler for a given path.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to look up (e.g., "/data/interfaces")
+    ///
+    /// # Returns
+    ///
+    /// Returns the registered handler function if found, or a default
+    /// not-implemented handler if no handler is registered for this path.
+    #[must_use]
+    pub fn lookup(&self, path: &str) -> HandlerFn<H> {
+        // Try exact match first
+        if let Some(handler) = self.handlers.get(path) {
+            return handler.clone();
+        }
+        
+        // Return default not-implemented handler
+        Arc::new(move |_handler: Arc<H>, _request: ServerRequest| {
+            Box::pin(async move {
+                ServerResponse::from_error(
+                    ServerError::NotFound(
+                        "Handler not implemented for this path".to_string()
+                    )
+                )
+            })
+        })
+    }
+
+    /// Get the default handler implementation.
+    ///
+    /// # Returns
+    ///
+    /// Returns a reference to the default handler.
+    #[must_use]
+    pub fn default_handler(&self) -> &Arc<H> {
+        &self.default_handler
+    }
+
+    /// List all registered handler paths.
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of all registered path patterns.
+    #[must_use]
+    pub fn registered_paths(&self) -> Vec<String> {
+        self.handlers.keys().cloned().collect()
+    }
+}
@@ -0,0 +1,416 @@
+// This file is automatically generated by rustconf.
+// DO NOT EDIT MANUALLY.
+
+#![allow(unused_imports, unused_variables, dead_code, clippy::module_inception)]
+
+use async_trait::async_trait;
+use rustconf_runtime::{ServerRequest, ServerResponse, ServerError, HttpMethod};
+use std::sync::Arc;
+use serde_json;
+use super::handlers::*;
+use super::super::types::*;
+#[allow(unused_imports)]
+use super::super::operations::operations::*;
+
+/// Request router for yang11-kitchen-sink YANG module.
+///
+/// Routes incoming RESTCONF requests to appropriate handler methods
+/// based on URL path and HTTP method.
+pub struct RestconfRouter<H: Yang11KitchenSinkHandler> {
+    /// The handler implementation
+    handler: Arc<H>,
+    /// Base path for RESTCONF operations (e.g., "/restconf")
+    base_path: String,
+}
+
+impl<H: Yang11KitchenSinkHandler> RestconfRouter<H> {
+    /// Create a new router with the given handler.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - The handler implementation
+    /// * `base_path` - Base path for RESTCONF operations (e.g., "/restconf")
+    pub fn new(handler: H, base_path: impl Into<String>) -> Self {
+        Self {
+            handler: Arc::new(handler),
+            base_path: base_path.into(),
+        }
+    }
+
+    /// Route an incoming request to the appropriate handler.
+    ///
+    /// This method:
+    /// 1. Parses the request path and matches it against YANG-defined paths
+    /// 2. Deserializes the request body if needed
+    /// 3. Invokes the appropriate handler method
+    /// 4. Serializes the response
+    /// 5. Handles errors appropriately
+    pub async fn route(&self, request: ServerRequest) -> ServerResponse {
+        // Strip base path from request path
+        let path = if let Some(stripped) = request.path.strip_prefix(&self.base_path) {
+            stripped
+        } else {
+            return ServerResponse::from_error(
+                ServerError::NotFound(format!(
+                    "Path does not start with base path: {}",
+                    self.base_path
+                ))
+            );
+        };
+
+        // Parse path segments
+        let segments: Vec<&str> = path
+            .trim_start_matches('/')
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .collect();
+
+        // Match path and route to handler
+        self.match_and_route(&segments, &request).await
+    }
+
+    /// Match path segments against YANG-defined paths and route to handler.
+    async fn match_and_route(
+        &self,
+        segments: &[&str],
+        request: &ServerRequest,
+    ) -> ServerResponse {
+        // Check for RPC operations first (path: /operations/<rpc-name>)
+        if segments.len() >= 2 && segments[0] == "operations" {
+            return self.route_rpc(segments[1], request).await;
+        }
+
+        // Check for data operations (path: /data/<path>)
+        if segments.len() >= 2 && segments[0] == "data" {
+            return self.route_data(&segments[1..], request).await;
+        }
+
+        // Path not found
+        ServerResponse::from_error(
+            ServerError::NotFound(format!("Unknown path: /{}", segments.join("/")))
+        )
+    }
+
+    /// Route RPC operation requests.
+    async fn route_rpc(&self, rpc_name: &str, request: &ServerRequest) -> ServerResponse {
+        // Decode percent-encoded RPC name
+        let rpc_name = match Self::percent_decode(rpc_name) {
+            Ok(name) => name,
+            Err(e) => return ServerResponse::from_error(e),
+        };
+
+        // Match RPC name and dispatch to handler
+        match rpc_name.as_str() {
+            "reboot" => {
+                let input: RebootInput = match Self::deserialize_envelope(request, "yang11-kitchen-sink:input") {
+                    Ok(data) => data,
+                    Err(e) => return ServerResponse::from_error(e),
+                };
+
+                match self.handler.reboot(input).await {
+                    Ok(()) => ServerResponse::new(204),
+                    Err(e) => ServerResponse::from_error(e),
+                }
+            }
+            "ping" => {
+                let input: PingInput = match Self::deserialize_envelope(request, "yang11-kitchen-sink:input") {
+                    Ok(data) => data,
+                    Err(e) => return ServerResponse::from_error(e),
+                };
+
+                match self.handler.ping(input).await {
+                    Ok(output) => {
+                        let output = std::collections::BTreeMap::from([("yang11-kitchen-sink:output", output)]);
+                        match Self::serialize_response(output, request) {
+                            Ok(response) => response,
+                            Err(e) => ServerResponse::from_error(e),
+                        }
+                    }
+                    Err(e) => ServerResponse::from_error(e),
+                }
+            }
+            _ => ServerResponse::from_error(
+                ServerError::NotFound(format!("RPC operation not found: {}", rpc_name))
+            ),
+        }
+    }
+
+    /// Route data operation requests.
+    async fn route_data(&self, segments: &[&str], request: &ServerRequest) -> ServerResponse {
+        // Decode percent-encoded path segments
+        let decoded_segments: Result<Vec<String>, ServerError> = segments
+            .iter()
+            .map(|s| Self::percent_decode(s))
+            .collect();
+
+        let decoded_segments = match decoded_segments {
+            Ok(segs) => segs,
+            Err(e) => return ServerResponse::from_error(e),
+        };
+
+        // Match data path and dispatch to handler
+        if decoded_segments.is_empty() {
+            return ServerResponse::from_error(
+                ServerError::NotFound("Empty data path".to_string())
+            );
+        }
+
+        // Match first segment against top-level data nodes
+        match decoded_segments[0].as_str() {
+            "system" => {
+                // Container operations
+                if decoded_segments.len() != 1 {
+                    return ServerResponse::from_error(
+                        ServerError::NotFound("Invalid path depth for container".to_string())
+                    );
+                }
+
+                match request.method {
+                    HttpMethod::GET => {
+                        match self.handler.get_system().await {
+                            Ok(data) => {
+                                match Self::serialize_response(data, request) {
+                                    Ok(response) => response,
+                                    Err(e) => ServerResponse::from_error(e),
+                                }
+                            }
+                            Err(e) => ServerResponse::from_error(e),
+                        }
+                    }
+                    HttpMethod::PUT => {
+                        let data: System = match Self::deserialize_body(request) {
+                            Ok(d) => d,
+                            Err(e) => return ServerResponse::from_error(e),
+                        };
+                        match self.handler.put_system(data).await {
+                            Ok(()) => ServerResponse::new(204),
+                            Err(e) => ServerResponse::from_error(e),
+                        }
+                    }
+                    HttpMethod::PATCH => {
+                        let data: System = match Self::deserialize_body(request) {
+                            Ok(d) => d,
+                            Err(e) => return ServerResponse::from_error(e),
+                        };
+                        match self.handler.patch_system(data).await {
+                            Ok(()) => ServerResponse::new(204),
+                            Err(e) => ServerResponse::from_error(e),
+                        }
+                    }
+                    HttpMethod::DELETE => {
+                        match self.handler.delete_system().await {
+                            Ok(()) => ServerResponse::new(204),
+                            Err(e) => ServerResponse::from_error(e),
+                        }
+                    }
+                    _ => ServerResponse::from_error(
+                        ServerError::NotFound("Method not allowed".to_string())
+                    ),
+                }
+            }
+            "route" => {
+                // List operations
+                // TODO: Implement list key extraction and routing
+                // For now, only support collection-level GET
+                if decoded_segments.len() == 1 && request.method == HttpMethod::GET {
+                    match self.handler.get_route().await {
+                        Ok(data) => {
+                            match Self::serialize_response(data, request) {
+                                Ok(response) => response,
+                                Err(e) => ServerResponse::from_error(e),
+                            }
+                        }
+                        Err(e) => ServerResponse::from_error(e),
+                    }
+                } else {
+                    ServerResponse::from_error(
+                        ServerError::NotFound("List item operations not yet implemented".to_string())
+                    )
+                }
+            }
+            _ => ServerResponse::from_error(
+                ServerError::NotFound(format!("Data path not found: /{}", decoded_segments.join("/")))
+            ),
+        }
+    }
+
+    /// Decode percent-encoded URL component.
+    fn percent_decode(s: &str) -> Result<String, ServerError> {
+        // Simple percent decoding implementation
+        let mut result = String::new();
+        let mut chars = s.chars().peekable();
+
+        while let Some(ch) = chars.next() {
+            if ch == '%' {
+                // Read next two characters as hex digits
+                let hex: String = chars.by_ref().take(2).collect();
+                if hex.len() != 2 {
+                    return Err(ServerError::DeserializationError(
+                        format!("Invalid percent encoding: %{}", hex)
+                    ));
+                }
+
+                match u8::from_str_radix(&hex, 16) {
+                    Ok(byte) => {
+                        // Convert byte to char
+                        if byte < 128 {
+                            result.push(byte as char);
+                        } else {
+                            return Err(ServerError::DeserializationError(
+                                format!("Invalid UTF-8 in percent encoding: %{}", hex)
+                            ));
+                        }
+                    }
+                    Err(_) => {
+                        return Err(ServerError::DeserializationError(
+                            format!("Invalid hex in percent encoding: %{}", hex)
+                        ));
+                    }
+                }
+            } else {
+                result.push(ch);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Deserialize and validate request body as JSON.
+    ///
+    /// This method deserializes the request body and automatically validates
+    /// all YANG constraints through the type's Deserialize implementation.
+    /// Validation errors are converted to 400 Bad Request responses.
+    fn deserialize_body<T: serde::de::DeserializeOwned>(
+        request: &ServerRequest,
+    ) -> Result<T, ServerError> {
+        // Check if body exists
+        let body = request.body.as_ref().ok_or_else(|| {
+            ServerError::DeserializationError("Request body is required".to_string())
+        })?;
+
+        // Deserialize JSON with automatic validation
+        // Validated types will check constraints during deserialization
+        serde_json::from_slice(body).map_err(|e| {
+            let error_msg = e.to_string();
+            // Check if this is a validation error
+            if error_msg.contains("outside allowed range")
+                || error_msg.contains("invalid length")
+                || error_msg.contains("does not match pattern")
+            {
+                ServerError::ValidationError(format!(
+                    "Request validation failed: {}",
+                    error_msg
+                ))
+            } else {
+                ServerError::DeserializationError(format!(
+                    "Failed to deserialize request body: {}",
+                    error_msg
+                ))
+            }
+        })
+    }
+
+    /// Deserialize and validate an RPC body wrapped in the `member` envelope.
+    fn deserialize_envelope<T: serde::de::DeserializeOwned>(
+        request: &ServerRequest,
+        member: &str,
+    ) -> Result<T, ServerError> {
+        let body = request.body.as_ref().ok_or_else(|| {
+            ServerError::DeserializationError("Request body is required".to_string())
+        })?;
+
+        rustconf_runtime::unwrap_envelope(member, body).map_err(|e| {
+            let error_msg = e.to_string();
+            // Check if this is a validation error
+            if error_msg.contains("outside allowed range")
+                || error_msg.contains("invalid length")
+                || error_msg.contains("does not match pattern")
+            {
+                ServerError::ValidationError(format!(
+                    "Request validation failed: {}",
+                    error_msg
+                ))
+            } else {
+                ServerError::DeserializationError(format!(
+                    "Failed to deserialize request body: {}",
+                    error_msg
+                ))
+            }
+        })
+    }
+
+    /// Determine the response content type based on Accept header.
+    ///
+    /// Parses the Accept header and returns the preferred content type.
+    /// Supports application/json and application/xml.
+    /// Defaults to JSON if no Accept header is present.
+    fn negotiate_content_type(request: &ServerRequest) -> &'static str {
+        if let Some(accept) = request.get_header("Accept") {
+            let accept_lower = accept.to_lowercase();
+            // Check for XML preference
+            if accept_lower.contains("application/xml") {
+                return "application/xml";
+            }
+            // Check for JSON preference (or wildcard)
+            if accept_lower.contains("application/json") || accept_lower.contains("*/*") {
+                return "application/json";
+            }
+        }
+        // Default to JSON
+        "application/json"
+    }
+
+    /// Validate and serialize response data with content negotiation.
+    ///
+    /// This method validates the response data by attempting to serialize it.
+    /// If serialization succeeds, the data is valid according to YANG constraints.
+    /// The response format is determined by the Accept header in the request.
+    /// Validation errors are converted to 500 Internal Server Error responses.
+    fn serialize_response<T: serde::Serialize>(
+        data: T,
+        request: &ServerRequest,
+    ) -> Result<ServerResponse, ServerError> {
+        // Determine content type from Accept header
+        let content_type = Self::negotiate_content_type(request);
+
+        // Serialize based on content type
+        let (body, actual_content_type) = match content_type {
+            "application/xml" => {
+                // XML serialization using quick-xml
+                // For now, we only support JSON and return an error for XML
+                return Err(ServerError::SerializationError(
+                    "XML serialization not yet implemented".to_string()
+                ));
+            }
+            _ => {
+                // JSON serialization (default)
+                let body = serde_json::to_vec(&data).map_err(|e| {
+                    let error_msg = e.to_string();
+                    // Check if this is a validation error
+                    if error_msg.contains("outside allowed range")
+                        || error_msg.contains("invalid length")
+                        || error_msg.contains("does not match pattern")
+                    {
+                        ServerError::ValidationError(format!(
+                            "Response validation failed: {}",
+                            error_msg
+                        ))
+                    } else {
+                        ServerError::SerializationError(format!(
+                            "Failed to serialize response: {}",
+                            error_msg
+                        ))
+                    }
+                })?;
+                (body, "application/json")
+            }
+        };
+
+        // Create response with appropriate content type
+        Ok(ServerResponse {
+            status_code: 200,
+            headers: vec![("Content-Type".to_string(), actual_content_type.to_string())],
+            body,
+        })
+    }
+}
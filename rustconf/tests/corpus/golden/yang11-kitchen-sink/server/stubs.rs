@@ -0,0 +1,153 @@
+// This file is automatically generated by rustconf.
+// DO NOT EDIT MANUALLY.
+
+#![allow(unused_imports, unused_variables, dead_code, clippy::module_inception)]
+
+use async_trait::async_trait;
+use rustconf_runtime::ServerError;
+use std::sync::{Arc, Mutex};
+use super::handlers::*;
+use super::super::types::*;
+use super::super::operations::operations::*;
+
+/// Stub implementation of Yang11KitchenSinkHandler.
+///
+/// This implementation returns sensible default values for all operations.
+/// Use as-is for testing, or override specific methods for production.
+///
+/// All method invocations are logged for debugging purposes.
+#[derive(Debug, Clone)]
+pub struct StubYang11KitchenSinkHandler {
+    /// Log of handler method calls for debugging.
+    call_log: Arc<Mutex<Vec<String>>>,
+}
+
+impl StubYang11KitchenSinkHandler {
+    /// Create a new stub handler.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            call_log: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Get the log of handler calls for debugging and testing.
+    #[must_use]
+    pub fn get_call_log(&self) -> Vec<String> {
+        self.call_log.lock().unwrap().clone()
+    }
+}
+
+impl Default for StubYang11KitchenSinkHandler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Yang11KitchenSinkHandler for StubYang11KitchenSinkHandler {
+    async fn reboot(&self, input: RebootInput) -> Result<(), ServerError> {
+        self.call_log.lock().unwrap().push(
+            format!("reboot({:?})", input)
+        );
+
+        Ok(())
+    }
+    async fn ping(&self, input: PingInput) -> Result<PingOutput, ServerError> {
+        self.call_log.lock().unwrap().push(
+            format!("ping({:?})", input)
+        );
+
+        Ok(PingOutput {
+            received: None,
+            average_rtt: None,
+        })
+    }
+    async fn get_system(&self) -> Result<System, ServerError> {
+        self.call_log.lock().unwrap().push(
+            "get_system()".to_string()
+        );
+
+        Err(ServerError::HandlerError(
+            "get_system has no default value in the stub".to_string(),
+        ))
+    }
+
+    async fn put_system(&self, data: System) -> Result<(), ServerError> {
+        self.call_log.lock().unwrap().push(
+            format!("put_system({:?})", data)
+        );
+
+        Ok(())
+    }
+
+    async fn patch_system(&self, data: System) -> Result<(), ServerError> {
+        self.call_log.lock().unwrap().push(
+            format!("patch_system({:?})", data)
+        );
+
+        Ok(())
+    }
+
+    async fn delete_system(&self) -> Result<(), ServerError> {
+        self.call_log.lock().unwrap().push(
+            "delete_system()".to_string()
+        );
+
+        Ok(())
+    }
+
+    async fn get_route(&self) -> Result<Vec<Route>, ServerError> {
+        self.call_log.lock().unwrap().push(
+            "get_route()".to_string()
+        );
+
+        Ok(Vec::new())
+    }
+
+    async fn get_route_by_key(&self, prefix: String, next_hop: String) -> Result<Route, ServerError> {
+        self.call_log.lock().unwrap().push(
+            format!("get_route_by_key({:?}, {:?})", prefix, next_hop)
+        );
+
+        Ok(Route {
+            prefix: String::new(),
+            next_hop: String::new(),
+            metric: None,
+            interface: None,
+        })
+    }
+
+    async fn create_route(&self, data: Route) -> Result<(), ServerError> {
+        self.call_log.lock().unwrap().push(
+            format!("create_route({:?})", data)
+        );
+
+        Ok(())
+    }
+
+    async fn put_route(&self, prefix: String, next_hop: String, data: Route) -> Result<(), ServerError> {
+        self.call_log.lock().unwrap().push(
+            format!("put_route({:?}, {:?}, {:?})", prefix, next_hop, data)
+        );
+
+        Ok(())
+    }
+
+    async fn patch_route(&self, prefix: String, next_hop: String, data: Route) -> Result<(), ServerError> {
+        self.call_log.lock().unwrap().push(
+            format!("patch_route({:?}, {:?}, {:?})", prefix, next_hop, data)
+        );
+
+        Ok(())
+    }
+
+    async fn delete_route(&self, prefix: String, next_hop: String) -> Result<(), ServerError> {
+        self.call_log.lock().unwrap().push(
+            format!("delete_route({:?}, {:?})", prefix, next_hop)
+        );
+
+        Ok(())
+    }
+
+}
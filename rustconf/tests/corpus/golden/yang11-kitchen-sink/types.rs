@@ -0,0 +1,613 @@
+// This file is automatically generated by rustconf.
+// DO NOT EDIT MANUALLY.
+
+#![allow(unused_imports, unused_variables, dead_code, clippy::module_inception)]
+
+use serde::{Deserialize, Serialize};
+use super::validation::*;
+
+/// A percentage.
+pub type Percent = ValidatedUint8Id14f2c1260fc9824b;
+
+/// A DNS host name.
+pub type Hostname = ValidatedStringId58764a9bea2c64d4;
+
+/// The `port-number` typedef.
+pub type PortNumber = ValidatedUint16Id909fe05d3ba56049;
+
+/// The `address-or-name` typedef.
+pub type AddressOrName = String;
+
+/// The `severity` typedef.
+pub type Severity = String;
+
+/// System-wide settings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct System {
+    /// The `hostname` leaf.
+    #[serde(rename = "hostname")]
+    pub hostname: Hostname,
+    /// The `enabled` leaf.
+    #[serde(rename = "enabled")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Message of the day, superseded by the login banner.
+    #[serde(rename = "motd")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub motd: Option<String>,
+    /// DNS servers, in order of preference.
+    #[serde(rename = "dns-server")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub dns_server: Vec<AddressOrName>,
+    /// The `tag` leaf-list.
+    #[serde(rename = "tag")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tag: Vec<ValidatedStringIdd8c1dd04d14b1db3>,
+    /// The `host-key` leaf.
+    #[serde(rename = "host-key")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host_key: Option<ValidatedBinaryId4525a1be33a1c9c0>,
+    /// The `debug` leaf.
+    #[serde(rename = "debug")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub debug: Option<()>,
+    /// The `temperature` leaf.
+    #[serde(rename = "temperature")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<ValidatedInt16Idcffc60125879a407>,
+    /// The `transport` choice.
+    #[serde(rename = "transport")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transport: Option<Transport>,
+    /// The `logging` container.
+    #[serde(rename = "logging")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logging: Option<Logging>,
+    /// The `statistics` container.
+    #[serde(rename = "statistics")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statistics: Option<Statistics>,
+}
+
+impl System {
+    /// Merge `other` into `self` following YANG merge semantics.
+    ///
+    /// Leaves set in `other` overwrite those in `self` and unset optional leaves
+    /// are left alone, so `other` acts as a patch. Containers are merged
+    /// recursively, list entries are merged by key with new entries appended,
+    /// and leaf-list values are added if not already present. Choices are
+    /// replaced as a whole.
+    pub fn merge(&mut self, other: &Self) {
+        self.hostname.clone_from(&other.hostname);
+        if other.enabled.is_some() {
+            self.enabled.clone_from(&other.enabled);
+        }
+        if other.motd.is_some() {
+            self.motd.clone_from(&other.motd);
+        }
+        {
+            let mut seen: std::collections::HashSet<String> = self.dns_server.iter().map(|value| serde_json::to_string(value).unwrap_or_default()).collect();
+            self.dns_server.extend(other.dns_server.iter().filter(|value| seen.insert(serde_json::to_string(value).unwrap_or_default())).cloned());
+        }
+        {
+            let mut seen: std::collections::HashSet<String> = self.tag.iter().map(|value| serde_json::to_string(value).unwrap_or_default()).collect();
+            self.tag.extend(other.tag.iter().filter(|value| seen.insert(serde_json::to_string(value).unwrap_or_default())).cloned());
+        }
+        if other.host_key.is_some() {
+            self.host_key.clone_from(&other.host_key);
+        }
+        if other.debug.is_some() {
+            self.debug.clone_from(&other.debug);
+        }
+        if other.temperature.is_some() {
+            self.temperature.clone_from(&other.temperature);
+        }
+        if other.transport.is_some() {
+            self.transport.clone_from(&other.transport);
+        }
+        if let Some(theirs) = &other.logging {
+            match &mut self.logging {
+                Some(mine) => mine.merge(theirs),
+                None => self.logging = Some(theirs.clone()),
+            }
+        }
+        if let Some(theirs) = &other.statistics {
+            match &mut self.statistics {
+                Some(mine) => mine.merge(theirs),
+                None => self.statistics = Some(theirs.clone()),
+            }
+        }
+    }
+}
+
+/// The `transport` choice.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Transport {
+    /// The `tls` case.
+    Tls(TlsData),
+    /// The `ssh` case.
+    Ssh(Vec<u8>),
+}
+
+/// The `tls` case.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TlsData {
+    /// The `certificate` leaf.
+    #[serde(rename = "certificate")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub certificate: Option<String>,
+    /// The `verify-peer` leaf.
+    ///
+    /// Only valid when `../certificate`.
+    #[serde(rename = "verify-peer")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verify_peer: Option<bool>,
+}
+
+impl TlsData {
+    /// Merge `other` into `self` following YANG merge semantics.
+    ///
+    /// Leaves set in `other` overwrite those in `self` and unset optional leaves
+    /// are left alone, so `other` acts as a patch. Containers are merged
+    /// recursively, list entries are merged by key with new entries appended,
+    /// and leaf-list values are added if not already present. Choices are
+    /// replaced as a whole.
+    pub fn merge(&mut self, other: &Self) {
+        if other.certificate.is_some() {
+            self.certificate.clone_from(&other.certificate);
+        }
+        if other.verify_peer.is_some() {
+            self.verify_peer.clone_from(&other.verify_peer);
+        }
+    }
+}
+
+/// The `logging` container.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Logging {
+    /// The `level` leaf.
+    #[serde(rename = "level")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<Severity>,
+    /// The `remote-server` list.
+    #[serde(rename = "remote-server")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub remote_server: Vec<RemoteServer>,
+    /// The `buffer-size` leaf.
+    #[serde(rename = "buffer-size")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub buffer_size: Option<u32>,
+}
+
+impl Logging {
+    /// Merge `other` into `self` following YANG merge semantics.
+    ///
+    /// Leaves set in `other` overwrite those in `self` and unset optional leaves
+    /// are left alone, so `other` acts as a patch. Containers are merged
+    /// recursively, list entries are merged by key with new entries appended,
+    /// and leaf-list values are added if not already present. Choices are
+    /// replaced as a whole.
+    pub fn merge(&mut self, other: &Self) {
+        if other.level.is_some() {
+            self.level.clone_from(&other.level);
+        }
+        {
+            let mut index: std::collections::HashMap<String, usize> = self.remote_server.iter().enumerate().map(|(i, entry)| (entry.merge_key(), i)).collect();
+            for theirs in &other.remote_server {
+                match index.get(&theirs.merge_key()) {
+                    Some(&i) => self.remote_server[i].merge(theirs),
+                    None => {
+                        index.insert(theirs.merge_key(), self.remote_server.len());
+                        self.remote_server.push(theirs.clone());
+                    }
+                }
+            }
+        }
+        if other.buffer_size.is_some() {
+            self.buffer_size.clone_from(&other.buffer_size);
+        }
+    }
+}
+
+/// The `remote-server` list.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteServer {
+    /// The `name` leaf.
+    #[serde(rename = "name")]
+    pub name: String,
+    /// The `address` leaf.
+    #[serde(rename = "address")]
+    pub address: AddressOrName,
+    /// The `port` leaf.
+    #[serde(rename = "port")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<PortNumber>,
+    /// The `min-severity` leaf.
+    #[serde(rename = "min-severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_severity: Option<Severity>,
+}
+
+impl RemoteServer {
+    /// Merge `other` into `self` following YANG merge semantics.
+    ///
+    /// Leaves set in `other` overwrite those in `self` and unset optional leaves
+    /// are left alone, so `other` acts as a patch. Containers are merged
+    /// recursively, list entries are merged by key with new entries appended,
+    /// and leaf-list values are added if not already present. Choices are
+    /// replaced as a whole.
+    pub fn merge(&mut self, other: &Self) {
+        self.address.clone_from(&other.address);
+        if other.port.is_some() {
+            self.port.clone_from(&other.port);
+        }
+        if other.min_severity.is_some() {
+            self.min_severity.clone_from(&other.min_severity);
+        }
+    }
+
+    /// Serialized key, identifying the entry when lists are merged.
+    #[allow(dead_code)]
+    fn merge_key(&self) -> String {
+        serde_json::to_string(&(&self.name,)).unwrap_or_default()
+    }
+}
+
+/// Key of a `remote-server` entry.
+///
+/// Displays as the entry's RESTCONF list instance fragment, `remote-server=<name>`, with
+/// each value percent-encoded, and parses from it.
+#[derive(Debug, Clone)]
+pub struct RemoteServerKey {
+    /// The `name` key leaf.
+    pub name: String,
+}
+
+impl RemoteServer {
+    /// The key of this entry.
+    #[must_use]
+    pub fn key(&self) -> RemoteServerKey {
+        RemoteServerKey {
+            name: self.name.clone(),
+        }
+    }
+}
+
+impl std::fmt::Display for RemoteServerKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let values = [
+            rustconf_runtime::list_key::key_value(&self.name),
+        ];
+        rustconf_runtime::list_key::write_fragment(f, "remote-server", &values)
+    }
+}
+
+impl From<RemoteServerKey> for String {
+    fn from(key: RemoteServerKey) -> Self {
+        key.to_string()
+    }
+}
+
+impl std::str::FromStr for RemoteServerKey {
+    type Err = rustconf_runtime::ListKeyError;
+
+    fn from_str(fragment: &str) -> Result<Self, Self::Err> {
+        let values = rustconf_runtime::list_key::parse_fragment(fragment, "remote-server", 1)?;
+        Ok(Self {
+            name: rustconf_runtime::list_key::parse_key_value(&values[0])?,
+        })
+    }
+}
+
+/// The `statistics` container.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Statistics {
+    /// The `uptime` leaf.
+    #[serde(rename = "uptime")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uptime: Option<u64>,
+    /// The `last-change` leaf.
+    #[serde(rename = "last-change")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_change: Option<ValidatedStringIdbf9149867bc51280>,
+    /// The `cpu` leaf.
+    #[serde(rename = "cpu")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu: Option<Percent>,
+}
+
+impl Statistics {
+    /// Merge `other` into `self` following YANG merge semantics.
+    ///
+    /// Leaves set in `other` overwrite those in `self` and unset optional leaves
+    /// are left alone, so `other` acts as a patch. Containers are merged
+    /// recursively, list entries are merged by key with new entries appended,
+    /// and leaf-list values are added if not already present. Choices are
+    /// replaced as a whole.
+    pub fn merge(&mut self, other: &Self) {
+        if other.uptime.is_some() {
+            self.uptime.clone_from(&other.uptime);
+        }
+        if other.last_change.is_some() {
+            self.last_change.clone_from(&other.last_change);
+        }
+        if other.cpu.is_some() {
+            self.cpu.clone_from(&other.cpu);
+        }
+    }
+}
+
+/// Static routes, keyed by destination and next hop.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Route {
+    /// The `prefix` leaf.
+    #[serde(rename = "prefix")]
+    pub prefix: String,
+    /// The `next-hop` leaf.
+    #[serde(rename = "next-hop")]
+    pub next_hop: String,
+    /// The `metric` leaf.
+    #[serde(rename = "metric")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metric: Option<ValidatedUint32Id47625c2db5dd700c>,
+    /// The `interface` leaf.
+    #[serde(rename = "interface")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interface: Option<String>,
+}
+
+impl Route {
+    /// Merge `other` into `self` following YANG merge semantics.
+    ///
+    /// Leaves set in `other` overwrite those in `self` and unset optional leaves
+    /// are left alone, so `other` acts as a patch. Containers are merged
+    /// recursively, list entries are merged by key with new entries appended,
+    /// and leaf-list values are added if not already present. Choices are
+    /// replaced as a whole.
+    pub fn merge(&mut self, other: &Self) {
+        if other.metric.is_some() {
+            self.metric.clone_from(&other.metric);
+        }
+        if other.interface.is_some() {
+            self.interface.clone_from(&other.interface);
+        }
+    }
+
+    /// Serialized key, identifying the entry when lists are merged.
+    #[allow(dead_code)]
+    fn merge_key(&self) -> String {
+        serde_json::to_string(&(&self.prefix, &self.next_hop,)).unwrap_or_default()
+    }
+}
+
+/// Key of a `route` entry.
+///
+/// Displays as the entry's RESTCONF list instance fragment, `route=<prefix>,<next-hop>`, with
+/// each value percent-encoded, and parses from it.
+#[derive(Debug, Clone)]
+pub struct RouteKey {
+    /// The `prefix` key leaf.
+    pub prefix: String,
+    /// The `next-hop` key leaf.
+    pub next_hop: String,
+}
+
+impl Route {
+    /// The key of this entry.
+    #[must_use]
+    pub fn key(&self) -> RouteKey {
+        RouteKey {
+            prefix: self.prefix.clone(),
+            next_hop: self.next_hop.clone(),
+        }
+    }
+}
+
+impl std::fmt::Display for RouteKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let values = [
+            rustconf_runtime::list_key::key_value(&self.prefix),
+            rustconf_runtime::list_key::key_value(&self.next_hop),
+        ];
+        rustconf_runtime::list_key::write_fragment(f, "route", &values)
+    }
+}
+
+impl From<RouteKey> for String {
+    fn from(key: RouteKey) -> Self {
+        key.to_string()
+    }
+}
+
+impl std::str::FromStr for RouteKey {
+    type Err = rustconf_runtime::ListKeyError;
+
+    fn from_str(fragment: &str) -> Result<Self, Self::Err> {
+        let values = rustconf_runtime::list_key::parse_fragment(fragment, "route", 2)?;
+        Ok(Self {
+            prefix: rustconf_runtime::list_key::parse_key_value(&values[0])?,
+            next_hop: rustconf_runtime::list_key::parse_key_value(&values[1])?,
+        })
+    }
+}
+
+impl System {
+    /// The value at the relative `path` below this `system`, if the path is in
+    /// the schema and a value is set there.
+    ///
+    /// List entries are selected by key as in RESTCONF URLs: `list=key1,key2`.
+    #[must_use]
+    pub fn get_by_path(&self, path: &str) -> Option<serde_json::Value> {
+        let data = serde_json::to_value(self).ok()?;
+        rustconf_runtime::data_path::get(&data, "/system", path, |path| {
+            super::schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())
+        })
+    }
+
+    /// Set the value at `path` below this `system`, creating missing
+    /// containers. A `null` value removes the node.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is not in the schema, selects a list entry
+    /// that does not exist, or the value does not fit the node.
+    pub fn set_by_path(
+        &mut self,
+        path: &str,
+        value: serde_json::Value,
+    ) -> Result<(), rustconf_runtime::DataPathError> {
+        let mut data = serde_json::to_value(&*self)?;
+        rustconf_runtime::data_path::set(&mut data, "/system", path, value, |path| {
+            super::schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())
+        })?;
+        *self = serde_json::from_value(data)?;
+        Ok(())
+    }
+}
+
+impl Logging {
+    /// The value at the relative `path` below this `logging`, if the path is in
+    /// the schema and a value is set there.
+    ///
+    /// List entries are selected by key as in RESTCONF URLs: `list=key1,key2`.
+    #[must_use]
+    pub fn get_by_path(&self, path: &str) -> Option<serde_json::Value> {
+        let data = serde_json::to_value(self).ok()?;
+        rustconf_runtime::data_path::get(&data, "/system/logging", path, |path| {
+            super::schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())
+        })
+    }
+
+    /// Set the value at `path` below this `logging`, creating missing
+    /// containers. A `null` value removes the node.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is not in the schema, selects a list entry
+    /// that does not exist, or the value does not fit the node.
+    pub fn set_by_path(
+        &mut self,
+        path: &str,
+        value: serde_json::Value,
+    ) -> Result<(), rustconf_runtime::DataPathError> {
+        let mut data = serde_json::to_value(&*self)?;
+        rustconf_runtime::data_path::set(&mut data, "/system/logging", path, value, |path| {
+            super::schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())
+        })?;
+        *self = serde_json::from_value(data)?;
+        Ok(())
+    }
+}
+
+impl RemoteServer {
+    /// The value at the relative `path` below this `remote-server`, if the path is in
+    /// the schema and a value is set there.
+    ///
+    /// List entries are selected by key as in RESTCONF URLs: `list=key1,key2`.
+    #[must_use]
+    pub fn get_by_path(&self, path: &str) -> Option<serde_json::Value> {
+        let data = serde_json::to_value(self).ok()?;
+        rustconf_runtime::data_path::get(&data, "/system/logging/remote-server", path, |path| {
+            super::schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())
+        })
+    }
+
+    /// Set the value at `path` below this `remote-server`, creating missing
+    /// containers. A `null` value removes the node.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is not in the schema, selects a list entry
+    /// that does not exist, or the value does not fit the node.
+    pub fn set_by_path(
+        &mut self,
+        path: &str,
+        value: serde_json::Value,
+    ) -> Result<(), rustconf_runtime::DataPathError> {
+        let mut data = serde_json::to_value(&*self)?;
+        rustconf_runtime::data_path::set(&mut data, "/system/logging/remote-server", path, value, |path| {
+            super::schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())
+        })?;
+        *self = serde_json::from_value(data)?;
+        Ok(())
+    }
+}
+
+impl Statistics {
+    /// The value at the relative `path` below this `statistics`, if the path is in
+    /// the schema and a value is set there.
+    ///
+    /// List entries are selected by key as in RESTCONF URLs: `list=key1,key2`.
+    #[must_use]
+    pub fn get_by_path(&self, path: &str) -> Option<serde_json::Value> {
+        let data = serde_json::to_value(self).ok()?;
+        rustconf_runtime::data_path::get(&data, "/system/statistics", path, |path| {
+            super::schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())
+        })
+    }
+
+    /// Set the value at `path` below this `statistics`, creating missing
+    /// containers. A `null` value removes the node.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is not in the schema, selects a list entry
+    /// that does not exist, or the value does not fit the node.
+    pub fn set_by_path(
+        &mut self,
+        path: &str,
+        value: serde_json::Value,
+    ) -> Result<(), rustconf_runtime::DataPathError> {
+        let mut data = serde_json::to_value(&*self)?;
+        rustconf_runtime::data_path::set(&mut data, "/system/statistics", path, value, |path| {
+            super::schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())
+        })?;
+        *self = serde_json::from_value(data)?;
+        Ok(())
+    }
+}
+
+impl Route {
+    /// The value at the relative `path` below this `route`, if the path is in
+    /// the schema and a value is set there.
+    ///
+    /// List entries are selected by key as in RESTCONF URLs: `list=key1,key2`.
+    #[must_use]
+    pub fn get_by_path(&self, path: &str) -> Option<serde_json::Value> {
+        let data = serde_json::to_value(self).ok()?;
+        rustconf_runtime::data_path::get(&data, "/route", path, |path| {
+            super::schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())
+        })
+    }
+
+    /// Set the value at `path` below this `route`, creating missing
+    /// containers. A `null` value removes the node.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is not in the schema, selects a list entry
+    /// that does not exist, or the value does not fit the node.
+    pub fn set_by_path(
+        &mut self,
+        path: &str,
+        value: serde_json::Value,
+    ) -> Result<(), rustconf_runtime::DataPathError> {
+        let mut data = serde_json::to_value(&*self)?;
+        rustconf_runtime::data_path::set(&mut data, "/route", path, value, |path| {
+            super::schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())
+        })?;
+        *self = serde_json::from_value(data)?;
+        Ok(())
+    }
+}
+
+/// Register the types of the top-level nodes of the `yang11-kitchen-sink` module, for
+/// decoding `anydata` content that carries them.
+pub fn register_anydata(registry: &mut rustconf_runtime::AnyDataRegistry) {
+    registry.register::<System>("yang11-kitchen-sink:system");
+    registry.register::<Vec<Route>>("yang11-kitchen-sink:route");
+}
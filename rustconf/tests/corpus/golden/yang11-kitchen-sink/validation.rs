@@ -0,0 +1,589 @@
+// This file is automatically generated by rustconf.
+// DO NOT EDIT MANUALLY.
+
+#![allow(unused_imports, unused_variables, dead_code, clippy::module_inception)]
+
+use serde::{Deserialize, Serialize};
+
+/// Validation error for constrained types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    /// Value is outside the allowed range.
+    OutOfRange {
+        /// The rejected value.
+        value: String,
+        /// The constraint it violates, as written in YANG.
+        constraint: String,
+    },
+    /// String length is outside the allowed range.
+    InvalidLength {
+        /// The rejected value.
+        value: String,
+        /// The constraint it violates, as written in YANG.
+        constraint: String,
+    },
+    /// String does not match the required pattern.
+    InvalidPattern {
+        /// The rejected value.
+        value: String,
+        /// The pattern it does not match.
+        pattern: String,
+    },
+    /// A `must` condition does not hold.
+    MustViolation {
+        /// Data path of the node, relative to the validated one.
+        path: String,
+        /// The condition, as written in YANG.
+        condition: String,
+        /// The `error-message` of the `must` statement, if any.
+        message: Option<String>,
+        /// The `error-app-tag` of the `must` statement, if any.
+        app_tag: Option<String>,
+    },
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationError::OutOfRange { value, constraint } => {
+                write!(f, "Value '{}' is outside allowed range: {}", value, constraint)
+            }
+            ValidationError::InvalidLength { value, constraint } => {
+                write!(f, "Value '{}' has invalid length, expected: {}", value, constraint)
+            }
+            ValidationError::InvalidPattern { value, pattern } => {
+                write!(f, "Value '{}' does not match pattern: {}", value, pattern)
+            }
+            ValidationError::MustViolation {
+                path,
+                condition,
+                message,
+                ..
+            } => match message {
+                Some(message) => write!(f, "{}: {}", path, message),
+                None => write!(f, "{}: must condition failed: {}", path, condition),
+            },
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Validated binary type with length constraints.
+///
+/// Length constraints:
+/// - 32 to 512 bytes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidatedBinaryId4525a1be33a1c9c0 {
+    value: Vec<u8>,
+}
+
+impl ValidatedBinaryId4525a1be33a1c9c0 {
+    /// Create a new validated binary value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError::InvalidLength` if the length is outside the allowed ranges.
+    pub fn new(value: Vec<u8>) -> Result<Self, ValidationError> {
+        let length = value.len() as u64;
+        let length_valid = (32..=512).contains(&length);
+
+        if !length_valid {
+            return Err(ValidationError::InvalidLength {
+                value: format!("{:?}", value),
+                constraint: "32..512".to_string(),
+            });
+        }
+
+        Ok(Self { value })
+    }
+
+    /// Get the inner value.
+    #[must_use]
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+}
+
+impl TryFrom<Vec<u8>> for ValidatedBinaryId4525a1be33a1c9c0 {
+    type Error = ValidationError;
+
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl serde::Serialize for ValidatedBinaryId4525a1be33a1c9c0 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ValidatedBinaryId4525a1be33a1c9c0 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = Vec::<u8>::deserialize(deserializer)?;
+        Self::new(value).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Validated i16 type with range constraints.
+///
+/// Allowed ranges:
+/// - -40 to 125
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidatedInt16Idcffc60125879a407 {
+    value: i16,
+}
+
+impl ValidatedInt16Idcffc60125879a407 {
+    /// Create a new validated value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError::OutOfRange` if the value is outside the allowed ranges.
+    pub fn new(value: i16) -> Result<Self, ValidationError> {
+        let valid = (-40..=125).contains(&value);
+
+        if valid {
+            Ok(Self { value })
+        } else {
+            Err(ValidationError::OutOfRange {
+                value: value.to_string(),
+                constraint: "-40..125".to_string(),
+            })
+        }
+    }
+
+    /// Get the inner value.
+    #[must_use]
+    pub fn value(&self) -> i16 {
+        self.value
+    }
+}
+
+impl TryFrom<i16> for ValidatedInt16Idcffc60125879a407 {
+    type Error = ValidationError;
+
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl serde::Serialize for ValidatedInt16Idcffc60125879a407 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ValidatedInt16Idcffc60125879a407 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = i16::deserialize(deserializer)?;
+        Self::new(value).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Validated String type with constraints.
+///
+/// Length constraints:
+/// - 1 to 253 characters
+///
+/// Pattern: [a-zA-Z0-9]([a-zA-Z0-9.-]*[a-zA-Z0-9])?
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidatedStringId58764a9bea2c64d4 {
+    value: String,
+}
+
+impl ValidatedStringId58764a9bea2c64d4 {
+    /// Create a new validated string.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError` if the string violates length or pattern constraints.
+    pub fn new(value: String) -> Result<Self, ValidationError> {
+        let length = value.len() as u64;
+        let length_valid = (1..=253).contains(&length);
+
+        if !length_valid {
+            return Err(ValidationError::InvalidLength {
+                value: value.clone(),
+                constraint: "1..253".to_string(),
+            });
+        }
+
+        // Pattern validation
+        let pattern = regex::Regex::new(r"[a-zA-Z0-9]([a-zA-Z0-9.-]*[a-zA-Z0-9])?").map_err(|_| ValidationError::InvalidPattern {
+            value: value.clone(),
+            pattern: r"[a-zA-Z0-9]([a-zA-Z0-9.-]*[a-zA-Z0-9])?".to_string(),
+        })?;
+
+        if !pattern.is_match(&value) {
+            return Err(ValidationError::InvalidPattern {
+                value: value.clone(),
+                pattern: r"[a-zA-Z0-9]([a-zA-Z0-9.-]*[a-zA-Z0-9])?".to_string(),
+            });
+        }
+
+        Ok(Self { value })
+    }
+
+    /// Get the inner value.
+    #[must_use]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl TryFrom<String> for ValidatedStringId58764a9bea2c64d4 {
+    type Error = ValidationError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl serde::Serialize for ValidatedStringId58764a9bea2c64d4 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ValidatedStringId58764a9bea2c64d4 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Self::new(value).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Validated String type with constraints.
+///
+/// Pattern: \d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(\.\d+)?(Z|[\+\-]\d{2}:\d{2})
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidatedStringIdbf9149867bc51280 {
+    value: String,
+}
+
+impl ValidatedStringIdbf9149867bc51280 {
+    /// Create a new validated string.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError` if the string violates length or pattern constraints.
+    pub fn new(value: String) -> Result<Self, ValidationError> {
+        // Pattern validation
+        let pattern = regex::Regex::new(r"\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(\.\d+)?(Z|[\+\-]\d{2}:\d{2})").map_err(|_| ValidationError::InvalidPattern {
+            value: value.clone(),
+            pattern: r"\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(\.\d+)?(Z|[\+\-]\d{2}:\d{2})".to_string(),
+        })?;
+
+        if !pattern.is_match(&value) {
+            return Err(ValidationError::InvalidPattern {
+                value: value.clone(),
+                pattern: r"\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(\.\d+)?(Z|[\+\-]\d{2}:\d{2})".to_string(),
+            });
+        }
+
+        Ok(Self { value })
+    }
+
+    /// Get the inner value.
+    #[must_use]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl TryFrom<String> for ValidatedStringIdbf9149867bc51280 {
+    type Error = ValidationError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl serde::Serialize for ValidatedStringIdbf9149867bc51280 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ValidatedStringIdbf9149867bc51280 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Self::new(value).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Validated String type with constraints.
+///
+/// Length constraints:
+/// - 1 to 32 characters
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidatedStringIdd8c1dd04d14b1db3 {
+    value: String,
+}
+
+impl ValidatedStringIdd8c1dd04d14b1db3 {
+    /// Create a new validated string.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError` if the string violates length or pattern constraints.
+    pub fn new(value: String) -> Result<Self, ValidationError> {
+        let length = value.len() as u64;
+        let length_valid = (1..=32).contains(&length);
+
+        if !length_valid {
+            return Err(ValidationError::InvalidLength {
+                value: value.clone(),
+                constraint: "1..32".to_string(),
+            });
+        }
+
+        Ok(Self { value })
+    }
+
+    /// Get the inner value.
+    #[must_use]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl TryFrom<String> for ValidatedStringIdd8c1dd04d14b1db3 {
+    type Error = ValidationError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl serde::Serialize for ValidatedStringIdd8c1dd04d14b1db3 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ValidatedStringIdd8c1dd04d14b1db3 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Self::new(value).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Validated u16 type with range constraints.
+///
+/// Allowed ranges:
+/// - 1 to 65535
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidatedUint16Id909fe05d3ba56049 {
+    value: u16,
+}
+
+impl ValidatedUint16Id909fe05d3ba56049 {
+    /// Create a new validated value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError::OutOfRange` if the value is outside the allowed ranges.
+    pub fn new(value: u16) -> Result<Self, ValidationError> {
+        let valid = (1..=65535).contains(&value);
+
+        if valid {
+            Ok(Self { value })
+        } else {
+            Err(ValidationError::OutOfRange {
+                value: value.to_string(),
+                constraint: "1..65535".to_string(),
+            })
+        }
+    }
+
+    /// Get the inner value.
+    #[must_use]
+    pub fn value(&self) -> u16 {
+        self.value
+    }
+}
+
+impl TryFrom<u16> for ValidatedUint16Id909fe05d3ba56049 {
+    type Error = ValidationError;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl serde::Serialize for ValidatedUint16Id909fe05d3ba56049 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ValidatedUint16Id909fe05d3ba56049 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = u16::deserialize(deserializer)?;
+        Self::new(value).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Validated u32 type with range constraints.
+///
+/// Allowed ranges:
+/// - 0 to 65535
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidatedUint32Id47625c2db5dd700c {
+    value: u32,
+}
+
+impl ValidatedUint32Id47625c2db5dd700c {
+    /// Create a new validated value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError::OutOfRange` if the value is outside the allowed ranges.
+    pub fn new(value: u32) -> Result<Self, ValidationError> {
+        let valid = (0..=65535).contains(&value);
+
+        if valid {
+            Ok(Self { value })
+        } else {
+            Err(ValidationError::OutOfRange {
+                value: value.to_string(),
+                constraint: "0..65535".to_string(),
+            })
+        }
+    }
+
+    /// Get the inner value.
+    #[must_use]
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+impl TryFrom<u32> for ValidatedUint32Id47625c2db5dd700c {
+    type Error = ValidationError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl serde::Serialize for ValidatedUint32Id47625c2db5dd700c {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ValidatedUint32Id47625c2db5dd700c {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = u32::deserialize(deserializer)?;
+        Self::new(value).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Validated u8 type with range constraints.
+///
+/// Allowed ranges:
+/// - 0 to 100
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidatedUint8Id14f2c1260fc9824b {
+    value: u8,
+}
+
+impl ValidatedUint8Id14f2c1260fc9824b {
+    /// Create a new validated value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError::OutOfRange` if the value is outside the allowed ranges.
+    pub fn new(value: u8) -> Result<Self, ValidationError> {
+        let valid = (0..=100).contains(&value);
+
+        if valid {
+            Ok(Self { value })
+        } else {
+            Err(ValidationError::OutOfRange {
+                value: value.to_string(),
+                constraint: "0..100".to_string(),
+            })
+        }
+    }
+
+    /// Get the inner value.
+    #[must_use]
+    pub fn value(&self) -> u8 {
+        self.value
+    }
+}
+
+impl TryFrom<u8> for ValidatedUint8Id14f2c1260fc9824b {
+    type Error = ValidationError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl serde::Serialize for ValidatedUint8Id14f2c1260fc9824b {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ValidatedUint8Id14f2c1260fc9824b {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = u8::deserialize(deserializer)?;
+        Self::new(value).map_err(serde::de::Error::custom)
+    }
+}
+
@@ -0,0 +1,231 @@
+//! Golden-file tests over a corpus of real-world YANG modules
+//!
+//! Each corpus entry runs the full load, expand and generate steps of
+//! `RustconfBuilder` over modules bundled in `tests/corpus/yang/` and
+//! compares every generated file with its golden copy in
+//! `tests/corpus/golden/<entry>/`. A generator change that alters the output
+//! fails these tests; rerun them with `RUSTCONF_BLESS=1` to rewrite the
+//! golden files, and review the change as a diff of those files.
+//!
+//! Every module of the corpus is also run through the formatter, which must
+//! not change what the module parses to.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rustconf::parser::format_yang;
+use rustconf::{RustconfBuilder, YangParser};
+use tempfile::TempDir;
+
+/// Environment variable that rewrites the golden files instead of checking
+/// them.
+const BLESS: &str = "RUSTCONF_BLESS";
+
+fn corpus_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("corpus")
+}
+
+/// Generate bindings for `modules` and check them against the golden files
+/// of `entry`.
+fn check_corpus_entry(
+    entry: &str,
+    modules: &[&str],
+    configure: impl FnOnce(RustconfBuilder) -> RustconfBuilder,
+) {
+    let yang_dir = corpus_dir().join("yang");
+    let output_dir = TempDir::new().unwrap();
+
+    let mut builder = RustconfBuilder::new()
+        .search_path(&yang_dir)
+        .output_dir(output_dir.path());
+    for module in modules {
+        builder = builder.yang_file(yang_dir.join(format!("{}.yang", module)));
+    }
+    let builder = configure(builder);
+
+    let loaded = builder
+        .load_modules()
+        .unwrap_or_else(|e| panic!("{}: {}", entry, e));
+    let expanded = builder
+        .expand(loaded)
+        .unwrap_or_else(|e| panic!("{}: {}", entry, e));
+    let generated = builder
+        .generate_code(&expanded)
+        .unwrap_or_else(|e| panic!("{}: {}", entry, e));
+
+    let actual: BTreeMap<PathBuf, String> = generated
+        .files
+        .iter()
+        .map(|file| {
+            let relative = file.path.strip_prefix(output_dir.path()).unwrap();
+            (relative.to_path_buf(), normalize(&file.content))
+        })
+        .collect();
+
+    let golden_dir = corpus_dir().join("golden").join(entry);
+    if std::env::var_os(BLESS).is_some() {
+        bless(&golden_dir, &actual);
+        return;
+    }
+
+    let expected = read_golden(&golden_dir);
+    let mut failures = Vec::new();
+    for (path, content) in &actual {
+        match expected.get(path) {
+            None => failures.push(format!("{}: not in the golden files", path.display())),
+            Some(golden) if golden != content => failures.push(format!(
+                "{}: {}",
+                path.display(),
+                first_difference(golden, content)
+            )),
+            Some(_) => {}
+        }
+    }
+    for path in expected.keys().filter(|path| !actual.contains_key(*path)) {
+        failures.push(format!("{}: no longer generated", path.display()));
+    }
+
+    assert!(
+        failures.is_empty(),
+        "Generated code for corpus entry '{}' differs from {}:\n  {}\n\nIf the change is intended, rerun with {}=1 and review the diff of the golden files.",
+        entry,
+        golden_dir.display(),
+        failures.join("\n  "),
+        BLESS
+    );
+}
+
+/// Drop the generation timestamp, which changes on every run.
+fn normalize(content: &str) -> String {
+    content
+        .lines()
+        .filter(|line| !line.starts_with("// Generated at: "))
+        .map(|line| format!("{}\n", line))
+        .collect()
+}
+
+/// Describe the first line where `actual` departs from `golden`.
+fn first_difference(golden: &str, actual: &str) -> String {
+    let mut golden_lines = golden.lines();
+    let mut actual_lines = actual.lines();
+    for line in 1.. {
+        match (golden_lines.next(), actual_lines.next()) {
+            (Some(expected), Some(found)) if expected == found => continue,
+            (Some(expected), Some(found)) => {
+                return format!(
+                    "line {} differs\n      golden:    {}\n      generated: {}",
+                    line, expected, found
+                )
+            }
+            (Some(expected), None) => {
+                return format!("ends at line {}, golden continues with: {}", line, expected)
+            }
+            (None, Some(found)) => {
+                return format!(
+                    "golden ends at line {}, generated continues with: {}",
+                    line, found
+                )
+            }
+            (None, None) => break,
+        }
+    }
+    "contents differ".to_string()
+}
+
+fn read_golden(golden_dir: &Path) -> BTreeMap<PathBuf, String> {
+    let mut files = BTreeMap::new();
+    let mut pending = vec![golden_dir.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                pending.push(path);
+            } else {
+                let relative = path.strip_prefix(golden_dir).unwrap().to_path_buf();
+                files.insert(relative, fs::read_to_string(&path).unwrap());
+            }
+        }
+    }
+    files
+}
+
+fn bless(golden_dir: &Path, files: &BTreeMap<PathBuf, String>) {
+    if golden_dir.exists() {
+        fs::remove_dir_all(golden_dir).unwrap();
+    }
+    for (path, content) in files {
+        let path = golden_dir.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+}
+
+#[test]
+fn test_corpus_ietf_interfaces() {
+    check_corpus_entry("ietf-interfaces", &["ietf-interfaces"], |builder| {
+        builder.enable_restful_rpcs(true)
+    });
+}
+
+#[test]
+fn test_corpus_openconfig_interfaces() {
+    check_corpus_entry(
+        "openconfig-interfaces",
+        &["openconfig-interfaces"],
+        |builder| builder.enable_restful_rpcs(true).modular_output(true),
+    );
+}
+
+#[test]
+fn test_corpus_yang11_kitchen_sink() {
+    check_corpus_entry("yang11-kitchen-sink", &["yang11-kitchen-sink"], |builder| {
+        builder
+            .enable_restful_rpcs(true)
+            .enable_validation(true)
+            .enable_server_generation(true)
+    });
+}
+
+#[test]
+fn test_corpus_formatting_preserves_meaning() {
+    let yang_dir = corpus_dir().join("yang");
+    let mut paths: Vec<PathBuf> = fs::read_dir(&yang_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    paths.sort();
+    assert!(paths
+        .iter()
+        .any(|path| path.ends_with("multiline-strings.yang")));
+
+    for path in paths {
+        let source = fs::read_to_string(&path).unwrap();
+        let filename = path.file_name().unwrap().to_string_lossy().to_string();
+        let formatted = format_yang(&source).unwrap_or_else(|e| panic!("{}: {}", filename, e));
+        assert_eq!(
+            format_yang(&formatted).unwrap(),
+            formatted,
+            "{}: formatting is not idempotent",
+            filename
+        );
+
+        let parse = |source: &str| {
+            let mut parser = YangParser::new();
+            parser.add_search_path(yang_dir.clone());
+            parser
+                .parse_string(source, &filename)
+                .unwrap_or_else(|e| panic!("{}: {}", filename, e))
+        };
+        assert!(
+            parse(&source) == parse(&formatted),
+            "{}: formatting changed the parsed module",
+            filename
+        );
+    }
+}
@@ -0,0 +1,120 @@
+//! Generates bindings for `yang/lint-model.yang` in several configurations so
+//! that the generated code can be linted with `cargo clippy -- -D warnings`.
+
+use rustconf::generator::{
+    DateAndTimeRepresentation, Decimal64Representation, Int64Encoding, ItemVisibility, Target,
+    Visibility,
+};
+use rustconf::RustconfBuilder;
+
+fn main() {
+    let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR not set");
+    let model = "yang/lint-model.yang";
+
+    // Single-file client with validation, RESTful RPCs and pretty printers
+    RustconfBuilder::new()
+        .yang_file(model)
+        .search_path("yang")
+        .output_dir(&out_dir)
+        .module_name("lint_model")
+        .enable_validation(true)
+        .enable_restful_rpcs(true)
+        .enable_pretty_printers(true)
+        .generate()
+        .expect("Failed to generate client bindings");
+
+    // Single-file client with RFC 7951 encodings and a NETCONF schema
+    RustconfBuilder::new()
+        .yang_file(model)
+        .search_path("yang")
+        .output_dir(&out_dir)
+        .module_name("lint_model_rfc7951")
+        .enable_validation(true)
+        .enable_restful_rpcs(true)
+        .enable_namespace_prefixes(true)
+        .serde_rename_all(true)
+        .int64_encoding(Int64Encoding::String)
+        .decimal64_representation(Decimal64Representation::RustDecimal)
+        .date_and_time_representation(DateAndTimeRepresentation::Chrono)
+        .enable_netconf(true)
+        .generate()
+        .expect("Failed to generate RFC 7951 bindings");
+
+    // Single-file client without validation, with XML and bare RPC bodies
+    RustconfBuilder::new()
+        .yang_file(model)
+        .search_path("yang")
+        .output_dir(&out_dir)
+        .module_name("lint_model_plain")
+        .enable_validation(false)
+        .enable_xml(true)
+        .bare_rpc_bodies(true)
+        .generate()
+        .expect("Failed to generate plain bindings");
+
+    // Single-file client taking type-erased clients
+    RustconfBuilder::new()
+        .yang_file(model)
+        .search_path("yang")
+        .output_dir(&out_dir)
+        .module_name("lint_model_dyn")
+        .enable_validation(true)
+        .enable_restful_rpcs(true)
+        .enable_dyn_client(true)
+        .generate()
+        .expect("Failed to generate dyn client bindings");
+
+    // Single-file client with crate-private operations and helpers
+    RustconfBuilder::new()
+        .yang_file(model)
+        .search_path("yang")
+        .output_dir(&out_dir)
+        .module_name("lint_model_embedded")
+        .enable_validation(true)
+        .enable_restful_rpcs(true)
+        .visibility(ItemVisibility {
+            operations: Visibility::Crate,
+            helpers: Visibility::Crate,
+            ..ItemVisibility::default()
+        })
+        .generate()
+        .expect("Failed to generate embedded bindings");
+
+    // Single-file stubs importing the helpers from rustconf-runtime
+    RustconfBuilder::new()
+        .yang_file(model)
+        .search_path("yang")
+        .output_dir(&out_dir)
+        .module_name("lint_model_shared")
+        .enable_validation(true)
+        .shared_helpers(true)
+        .generate()
+        .expect("Failed to generate shared helper bindings");
+
+    // Notification structs only
+    RustconfBuilder::new()
+        .yang_file(model)
+        .search_path("yang")
+        .output_dir(&out_dir)
+        .module_name("lint_model_events")
+        .enable_validation(true)
+        .enable_restful_rpcs(true)
+        .targets(&[Target::Notifications])
+        .generate()
+        .expect("Failed to generate notification bindings");
+
+    // Modular client and server
+    RustconfBuilder::new()
+        .yang_file(model)
+        .search_path("yang")
+        .output_dir("src/generated")
+        .module_name("lint_model")
+        .enable_validation(true)
+        .enable_restful_rpcs(true)
+        .enable_server_generation(true)
+        .generate()
+        .expect("Failed to generate server bindings");
+
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=yang/");
+}
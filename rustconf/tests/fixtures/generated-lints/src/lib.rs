@@ -0,0 +1,34 @@
+//! Generated code under lint.
+//!
+//! Built by `tests/generated_code_lints.rs`; every module here is generated
+//! from `yang/lint-model.yang`.
+
+pub mod client {
+    include!(concat!(env!("OUT_DIR"), "/lint_model.rs"));
+}
+
+pub mod rfc7951 {
+    include!(concat!(env!("OUT_DIR"), "/lint_model_rfc7951.rs"));
+}
+
+pub mod plain {
+    include!(concat!(env!("OUT_DIR"), "/lint_model_plain.rs"));
+}
+
+pub mod dyn_client {
+    include!(concat!(env!("OUT_DIR"), "/lint_model_dyn.rs"));
+}
+
+pub mod embedded {
+    include!(concat!(env!("OUT_DIR"), "/lint_model_embedded.rs"));
+}
+
+pub mod shared {
+    include!(concat!(env!("OUT_DIR"), "/lint_model_shared.rs"));
+}
+
+pub mod events {
+    include!(concat!(env!("OUT_DIR"), "/lint_model_events.rs"));
+}
+
+pub mod generated;
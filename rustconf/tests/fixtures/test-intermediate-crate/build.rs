@@ -2,7 +2,7 @@ fn main() {
     rustconf::RustconfBuilder::new()
         .yang_file("yang/test-device.yang")
         .search_path("yang/")
-        .output_dir("src/generated")
+        .generate_into_src("src/generated")
         .enable_validation(true)
         .enable_restful_rpcs(true)
         .modular_output(true)
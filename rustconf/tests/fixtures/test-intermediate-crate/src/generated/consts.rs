@@ -0,0 +1,53 @@
+// @generated by rustconf. Do not edit; rerun the build to update.
+// This file is automatically generated by rustconf.
+// DO NOT EDIT MANUALLY.
+
+#![allow(unused_imports, unused_variables, dead_code, clippy::module_inception)]
+
+/// Name of the YANG module.
+pub const MODULE_NAME: &str = "test-device";
+/// XML namespace of the YANG module.
+pub const MODULE_NAMESPACE: &str = "http://example.com/test-device";
+/// Prefix of the YANG module.
+pub const MODULE_PREFIX: &str = "td";
+/// Organization responsible for the YANG module, if stated.
+pub const MODULE_ORGANIZATION: Option<&str> = Some("Example Organization");
+/// Contact information for the YANG module's authors, if stated.
+pub const MODULE_CONTACT: Option<&str> = Some("test@example.com");
+/// Description of the YANG module, if stated.
+pub const MODULE_DESCRIPTION: Option<&str> =
+    Some("Test device management module for integration testing");
+/// Revision of the YANG module the code was generated from, if known.
+pub const MODULE_REVISION: Option<&str> = Some("2024-01-01");
+/// Modules the server must implement, with their revisions.
+pub const IMPLEMENTED_MODULES: &[(&str, Option<&str>)] = &[("test-device", MODULE_REVISION)];
+/// Modules imported by the YANG module, with the revisions used.
+pub const IMPORTED_MODULES: &[(&str, Option<&str>)] = &[];
+/// Media type for JSON data and operation bodies (RFC 8040).
+pub const YANG_DATA_JSON: &str = "application/yang-data+json";
+/// Media type for XML data and operation bodies (RFC 8040).
+pub const YANG_DATA_XML: &str = "application/yang-data+xml";
+/// Media type for YANG Patch documents (RFC 8072).
+pub const YANG_PATCH_JSON: &str = "application/yang-patch+json";
+/// Media type of notification event streams.
+pub const EVENT_STREAM: &str = "text/event-stream";
+/// Root of the RESTCONF API.
+pub const RESTCONF_ROOT: &str = "/restconf";
+/// Root of the datastore resource.
+pub const DATA_PATH: &str = "/restconf/data";
+/// Root of the operations resource.
+pub const OPERATIONS_PATH: &str = "/restconf/operations";
+/// Stream list advertised by the server (RFC 8040 section 9.3).
+pub const STREAMS_PATH: &str = "/restconf/data/ietf-restconf-monitoring:restconf-state/streams";
+/// Name of the default notification stream (RFC 8040 section 6.2).
+pub const NETCONF_STREAM: &str = "NETCONF";
+/// Notification streams carrying this module's notifications.
+pub const STREAMS: &[&str] = &[];
+/// Qualified name of the `restart-device` RPC, as used in its URL.
+pub const OPERATION_RESTART_DEVICE: &str = "test-device:restart-device";
+/// Qualified name of the `get-status` RPC, as used in its URL.
+pub const OPERATION_GET_STATUS: &str = "test-device:get-status";
+/// Qualified names of all RPCs defined by the module.
+pub const OPERATIONS: &[&str] = &["test-device:restart-device", "test-device:get-status"];
+/// Qualified names of all notifications defined by the module.
+pub const NOTIFICATIONS: &[&str] = &[];
@@ -1,26 +1,40 @@
+// @generated by rustconf. Do not edit; rerun the build to update.
 // This file is automatically generated by rustconf.
 // DO NOT EDIT MANUALLY.
 //
 // Source YANG module: test-device
 // Namespace: http://example.com/test-device
 // Prefix: td
-// Generated at: 2026-04-16 22:14:34 UTC
+
+//! Test device management module for integration testing
+//!
+//! # Organization
+//!
+//! Example Organization
+//!
+//! # Contact
+//!
+//! ```text
+//! test@example.com
+//! ```
 
 #![allow(unused_imports, unused_variables, dead_code, clippy::module_inception)]
 
-pub mod types;
+/// RESTCONF constants for the test-device YANG module.
+pub mod consts;
+/// RESTCONF operations.
 pub mod operations;
+/// Schema nodes of the test-device YANG module.
+pub mod schema;
+/// Data types of the test-device YANG module.
+pub mod types;
+/// Types enforcing YANG range, length and pattern restrictions.
 pub mod validation;
 
-pub use types::*;
 pub use operations::*;
+pub use types::*;
 
 pub use rustconf_runtime::{
-    RestconfClient,
-    HttpTransport,
-    HttpRequest,
-    HttpResponse,
-    HttpMethod,
-    RpcError,
-    RequestInterceptor,
+    with_rollback, HttpMethod, HttpRequest, HttpResponse, HttpTransport, RequestInterceptor,
+    ResponseEnvelope, RestconfClient, RpcError, Transaction,
 };
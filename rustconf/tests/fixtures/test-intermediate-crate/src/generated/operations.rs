@@ -1,11 +1,17 @@
+// @generated by rustconf. Do not edit; rerun the build to update.
 // This file is automatically generated by rustconf.
 // DO NOT EDIT MANUALLY.
 
 #![allow(unused_imports, unused_variables, dead_code, clippy::module_inception)]
 
-use rustconf_runtime::{RestconfClient, HttpTransport, HttpRequest, HttpMethod, RpcError};
-use serde::{Deserialize, Serialize};
+use super::consts;
 use super::types::*;
+use super::validation::*;
+use rustconf_runtime::{
+    HttpMethod, HttpRequest, HttpResponse, HttpTransport, ResourceCapabilities, ResponseEnvelope,
+    RestconfClient, RpcError,
+};
+use serde::{Deserialize, Serialize};
 
 /// RESTCONF operations.
 pub mod operations {
@@ -43,6 +49,30 @@ pub mod operations {
         pub message: Option<String>,
     }
 
+    impl TryFrom<&HttpResponse> for RestartDeviceOutput {
+        type Error = RpcError;
+
+        /// Map a RESTCONF response to the RPC output.
+        ///
+        /// Success status codes deserialize the body; any other status becomes
+        /// `RpcError::HttpError`.
+        fn try_from(response: &HttpResponse) -> Result<Self, Self::Error> {
+            match response.status_code {
+                200..=299 => rustconf_runtime::unwrap_envelope(
+                    "test-device:output",
+                    &response.body,
+                )
+                .map_err(|e| {
+                    RpcError::DeserializationError(format!("Failed to deserialize response: {}", e))
+                }),
+                _ => Err(RpcError::HttpError {
+                    status_code: response.status_code,
+                    message: String::from_utf8_lossy(&response.body).to_string(),
+                }),
+            }
+        }
+    }
+
     /// Restart the device
     ///
     /// # Arguments
@@ -89,7 +119,7 @@ pub mod operations {
     ///     };
     ///
     ///     // Execute the RPC operation
-    ///     let result = restart_device(&client, input).await?;
+    ///     let result = restart_device(&client, &input).await?;
     ///
     ///     // Process the result
     ///     println!("Operation completed successfully");
@@ -97,43 +127,60 @@ pub mod operations {
     ///     Ok(())
     /// }
     /// ```
-    pub async fn restart_device<T: HttpTransport>(client: &RestconfClient<T>, input: RestartDeviceInput) -> Result<RestartDeviceOutput, RpcError> {
+    pub async fn restart_device<T: HttpTransport>(
+        client: &RestconfClient<T>,
+        input: &RestartDeviceInput,
+    ) -> Result<RestartDeviceOutput, RpcError> {
+        restart_device_full(client, input)
+            .await
+            .map(ResponseEnvelope::into_body)
+    }
+
+    /// Like [`restart_device`], also returning the response status and headers.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`restart_device`].
+    pub async fn restart_device_full<T: HttpTransport>(
+        client: &RestconfClient<T>,
+        input: &RestartDeviceInput,
+    ) -> Result<ResponseEnvelope<RestartDeviceOutput>, RpcError> {
         // Serialize input to JSON
-        let body = serde_json::to_vec(&input)
-            .map_err(|e| RpcError::SerializationError(format!("Failed to serialize input: {}", e)))?;
+        let body = rustconf_runtime::wrap_envelope("test-device:input", input).map_err(|e| {
+            RpcError::SerializationError(format!("Failed to serialize input: {}", e))
+        })?;
 
         // Construct RESTCONF URL
         let base = client.base_url().trim_end_matches('/');
-        let url = format!("{}/ restconf/operations/{}:{}", base, percent_encode("test-device"), percent_encode("restart-device"));
+        let url = format!(
+            "{}{}/{}:{}",
+            base,
+            consts::OPERATIONS_PATH,
+            percent_encode("test-device"),
+            percent_encode("restart-device")
+        );
 
         // Build HTTP request
         let request = HttpRequest {
             method: HttpMethod::POST,
             url,
             headers: vec![
-                ("Content-Type".to_string(), "application/yang-data+json".to_string()),
-                ("Accept".to_string(), "application/yang-data+json".to_string()),
+                (
+                    "Content-Type".to_string(),
+                    consts::YANG_DATA_JSON.to_string(),
+                ),
+                ("Accept".to_string(), consts::YANG_DATA_JSON.to_string()),
             ],
-            body: Some(body),
+            body: Some(body.into()),
+            context: Default::default(),
         };
 
         // Execute request through client
         let response = client.execute(request).await?;
 
         // Map HTTP status to error or deserialize response
-        match response.status_code {
-            200..=299 => {
-                // Success - deserialize response body
-                serde_json::from_slice(&response.body)
-                    .map_err(|e| RpcError::DeserializationError(
-                        format!("Failed to deserialize response: {}", e)
-                    ))
-            }
-            _ => Err(RpcError::HttpError {
-                status_code: response.status_code,
-                message: String::from_utf8_lossy(&response.body).to_string(),
-            }),
-        }
+        let output = RestartDeviceOutput::try_from(&response)?;
+        Ok(ResponseEnvelope::new(response, output))
     }
 
     /// Output result for get-status RPC.
@@ -147,6 +194,30 @@ pub mod operations {
         pub status: Option<String>,
     }
 
+    impl TryFrom<&HttpResponse> for GetStatusOutput {
+        type Error = RpcError;
+
+        /// Map a RESTCONF response to the RPC output.
+        ///
+        /// Success status codes deserialize the body; any other status becomes
+        /// `RpcError::HttpError`.
+        fn try_from(response: &HttpResponse) -> Result<Self, Self::Error> {
+            match response.status_code {
+                200..=299 => rustconf_runtime::unwrap_envelope(
+                    "test-device:output",
+                    &response.body,
+                )
+                .map_err(|e| {
+                    RpcError::DeserializationError(format!("Failed to deserialize response: {}", e))
+                }),
+                _ => Err(RpcError::HttpError {
+                    status_code: response.status_code,
+                    message: String::from_utf8_lossy(&response.body).to_string(),
+                }),
+            }
+        }
+    }
+
     /// Get device status
     ///
     /// # Arguments
@@ -193,39 +264,53 @@ pub mod operations {
     ///     Ok(())
     /// }
     /// ```
-    pub async fn get_status<T: HttpTransport>(client: &RestconfClient<T>) -> Result<GetStatusOutput, RpcError> {
+    pub async fn get_status<T: HttpTransport>(
+        client: &RestconfClient<T>,
+    ) -> Result<GetStatusOutput, RpcError> {
+        get_status_full(client)
+            .await
+            .map(ResponseEnvelope::into_body)
+    }
+
+    /// Like [`get_status`], also returning the response status and headers.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`get_status`].
+    pub async fn get_status_full<T: HttpTransport>(
+        client: &RestconfClient<T>,
+    ) -> Result<ResponseEnvelope<GetStatusOutput>, RpcError> {
         // Construct RESTCONF URL
         let base = client.base_url().trim_end_matches('/');
-        let url = format!("{}/ restconf/operations/{}:{}", base, percent_encode("test-device"), percent_encode("get-status"));
+        let url = format!(
+            "{}{}/{}:{}",
+            base,
+            consts::OPERATIONS_PATH,
+            percent_encode("test-device"),
+            percent_encode("get-status")
+        );
 
         // Build HTTP request
         let request = HttpRequest {
             method: HttpMethod::POST,
             url,
             headers: vec![
-                ("Content-Type".to_string(), "application/yang-data+json".to_string()),
-                ("Accept".to_string(), "application/yang-data+json".to_string()),
+                (
+                    "Content-Type".to_string(),
+                    consts::YANG_DATA_JSON.to_string(),
+                ),
+                ("Accept".to_string(), consts::YANG_DATA_JSON.to_string()),
             ],
             body: None,
+            context: Default::default(),
         };
 
         // Execute request through client
         let response = client.execute(request).await?;
 
         // Map HTTP status to error or deserialize response
-        match response.status_code {
-            200..=299 => {
-                // Success - deserialize response body
-                serde_json::from_slice(&response.body)
-                    .map_err(|e| RpcError::DeserializationError(
-                        format!("Failed to deserialize response: {}", e)
-                    ))
-            }
-            _ => Err(RpcError::HttpError {
-                status_code: response.status_code,
-                message: String::from_utf8_lossy(&response.body).to_string(),
-            }),
-        }
+        let output = GetStatusOutput::try_from(&response)?;
+        Ok(ResponseEnvelope::new(response, output))
     }
 
     /// RESTCONF CRUD operations for data resources.
@@ -249,6 +334,45 @@ pub mod operations {
             unimplemented!("GET operation not yet implemented")
         }
 
+        /// Discover the methods permitted on the device container.
+        ///
+        /// Issues OPTIONS and parses the `Allow` and `Accept-Patch` headers.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the request fails or the server responds with a non-2xx status.
+        pub async fn options_device<T: HttpTransport>(
+            client: &RestconfClient<T>,
+        ) -> Result<ResourceCapabilities, RpcError> {
+            let url = format!(
+                "{}{}",
+                client.base_url().trim_end_matches('/'),
+                device_path()
+            );
+            let request = HttpRequest {
+                method: HttpMethod::OPTIONS,
+                url,
+                headers: vec![],
+                body: None,
+                context: Default::default(),
+            };
+            let response = client.execute(request).await?;
+            ResourceCapabilities::try_from(&response)
+        }
+
+        /// Check whether the device container exists.
+        ///
+        /// Issues HEAD, falling back to GET with `depth=1` if the server rejects HEAD.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the request fails or the server responds with a status other than 2xx or 404.
+        pub async fn exists_device<T: HttpTransport>(
+            client: &RestconfClient<T>,
+        ) -> Result<bool, RpcError> {
+            client.exists(&device_path()).await
+        }
+
         /// Replace the device container.
         ///
         /// # Errors
@@ -281,6 +405,362 @@ pub mod operations {
             // TODO: Implement DELETE request to RESTCONF server
             unimplemented!("DELETE operation not yet implemented")
         }
+    }
+    /// Typed handles for the data resources of the module.
+    ///
+    /// Bring `resources::TestDeviceResources` into scope to reach them from a `RestconfClient`.
+    pub mod resources {
+        use super::*;
+
+        /// Deserialize the contents of the `member` envelope of a response.
+        fn decode<V: serde::de::DeserializeOwned>(
+            member: &str,
+            response: &HttpResponse,
+        ) -> Result<V, RpcError> {
+            rustconf_runtime::unwrap_envelope(member, &response.body).map_err(|e| {
+                RpcError::DeserializationError(format!("Failed to deserialize {}: {}", member, e))
+            })
+        }
+
+        /// Serialize `value` inside the `member` envelope.
+        fn encode<V: serde::Serialize>(member: &str, value: &V) -> Result<Vec<u8>, RpcError> {
+            rustconf_runtime::wrap_envelope(member, value).map_err(|e| {
+                RpcError::SerializationError(format!("Failed to serialize {}: {}", member, e))
+            })
+        }
+
+        /// Entry points to the data resources of the `test-device` module.
+        pub trait TestDeviceResources<T: HttpTransport> {
+            /// Device configuration and state
+            fn device(&self) -> DeviceResource<'_, T>;
+        }
+
+        impl<T: HttpTransport> TestDeviceResources<T> for RestconfClient<T> {
+            fn device(&self) -> DeviceResource<'_, T> {
+                DeviceResource {
+                    client: self,
+                    path: "/restconf/data/device".to_string(),
+                }
+            }
+        }
+
+        /// Handle for the `device` container.
+        #[must_use = "a resource handle does nothing until one of its methods is called"]
+        pub struct DeviceResource<'a, T: HttpTransport> {
+            client: &'a RestconfClient<T>,
+            path: String,
+        }
+
+        impl<T: HttpTransport> DeviceResource<'_, T> {
+            /// The RESTCONF path of the resource.
+            #[must_use]
+            pub fn path(&self) -> &str {
+                &self.path
+            }
+
+            /// Retrieve the container.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get(&self) -> Result<Device, RpcError> {
+                self.get_full().await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`get`](Self::get), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get_full(&self) -> Result<ResponseEnvelope<Device>, RpcError> {
+                let response = self
+                    .client
+                    .raw_request(HttpMethod::GET, &self.path, None)
+                    .await?;
+                let value = decode("test-device:device", &response)?;
+                Ok(ResponseEnvelope::new(response, value))
+            }
+
+            /// Retrieve the operational state of the container with the origin of each node.
+            ///
+            /// Requires a server implementing NMDA (RFC 8527). Origins are keyed by
+            /// JSON Pointers into the response, starting with the module-qualified
+            /// member of the resource.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get_with_origin(
+                &self,
+            ) -> Result<rustconf_runtime::WithOrigin<Device>, RpcError> {
+                let path = rustconf_runtime::origin::with_origin_path(&self.path);
+                let response = self
+                    .client
+                    .raw_request(HttpMethod::GET, &path, None)
+                    .await?;
+                let value = decode("test-device:device", &response)?;
+                let origins = rustconf_runtime::OriginTable::from_json(&response.body)?;
+                Ok(rustconf_runtime::WithOrigin {
+                    data: value,
+                    origins,
+                })
+            }
+
+            /// Replace the container with `data`.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn put(&self, data: &Device) -> Result<(), RpcError> {
+                self.put_full(data).await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`put`](Self::put), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn put_full(&self, data: &Device) -> Result<ResponseEnvelope<()>, RpcError> {
+                let body = encode("test-device:device", data)?;
+                let response = self
+                    .client
+                    .raw_request(HttpMethod::PUT, &self.path, Some(body.into()))
+                    .await?;
+                Ok(ResponseEnvelope::new(response, ()))
+            }
+
+            /// Merge `data` into the container.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn patch(&self, data: &Device) -> Result<(), RpcError> {
+                self.patch_full(data).await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`patch`](Self::patch), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn patch_full(
+                &self,
+                data: &Device,
+            ) -> Result<ResponseEnvelope<()>, RpcError> {
+                let body = encode("test-device:device", data)?;
+                let response = self
+                    .client
+                    .raw_request(HttpMethod::PATCH, &self.path, Some(body.into()))
+                    .await?;
+                Ok(ResponseEnvelope::new(response, ()))
+            }
+
+            /// Delete the container.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn delete(&self) -> Result<(), RpcError> {
+                self.delete_full().await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`delete`](Self::delete), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn delete_full(&self) -> Result<ResponseEnvelope<()>, RpcError> {
+                let response = self
+                    .client
+                    .raw_request(HttpMethod::DELETE, &self.path, None)
+                    .await?;
+                Ok(ResponseEnvelope::new(response, ()))
+            }
+
+            /// Retrieve the value of the `name` leaf alone.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get_name(&self) -> Result<String, RpcError> {
+                self.get_name_full().await.map(ResponseEnvelope::into_body)
+            }
 
+            /// Like [`get_name`](Self::get_name), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get_name_full(&self) -> Result<ResponseEnvelope<String>, RpcError> {
+                let path = format!("{}/name", self.path);
+                let response = self
+                    .client
+                    .raw_request(HttpMethod::GET, &path, None)
+                    .await?;
+                let value = decode("test-device:name", &response)?;
+                Ok(ResponseEnvelope::new(response, value))
+            }
+
+            /// Set the `name` leaf to `value`, leaving the rest of the container unchanged.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn set_name(&self, value: String) -> Result<(), RpcError> {
+                self.set_name_full(value)
+                    .await
+                    .map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`set_name`](Self::set_name), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn set_name_full(
+                &self,
+                value: String,
+            ) -> Result<ResponseEnvelope<()>, RpcError> {
+                let path = format!("{}/name", self.path);
+                let body = encode("test-device:name", &value)?;
+                let response = self
+                    .client
+                    .raw_request(HttpMethod::PUT, &path, Some(body.into()))
+                    .await?;
+                Ok(ResponseEnvelope::new(response, ()))
+            }
+
+            /// Retrieve the value of the `enabled` leaf alone.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get_enabled(&self) -> Result<bool, RpcError> {
+                self.get_enabled_full()
+                    .await
+                    .map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`get_enabled`](Self::get_enabled), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get_enabled_full(&self) -> Result<ResponseEnvelope<bool>, RpcError> {
+                let path = format!("{}/enabled", self.path);
+                let response = self
+                    .client
+                    .raw_request(HttpMethod::GET, &path, None)
+                    .await?;
+                let value = decode("test-device:enabled", &response)?;
+                Ok(ResponseEnvelope::new(response, value))
+            }
+
+            /// Set the `enabled` leaf to `value`, leaving the rest of the container unchanged.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn set_enabled(&self, value: bool) -> Result<(), RpcError> {
+                self.set_enabled_full(value)
+                    .await
+                    .map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`set_enabled`](Self::set_enabled), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn set_enabled_full(
+                &self,
+                value: bool,
+            ) -> Result<ResponseEnvelope<()>, RpcError> {
+                let path = format!("{}/enabled", self.path);
+                let body = encode("test-device:enabled", &value)?;
+                let response = self
+                    .client
+                    .raw_request(HttpMethod::PUT, &path, Some(body.into()))
+                    .await?;
+                Ok(ResponseEnvelope::new(response, ()))
+            }
+
+            /// Retrieve the value of the `port` leaf alone.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get_port(&self) -> Result<u16, RpcError> {
+                self.get_port_full().await.map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`get_port`](Self::get_port), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn get_port_full(&self) -> Result<ResponseEnvelope<u16>, RpcError> {
+                let path = format!("{}/port", self.path);
+                let response = self
+                    .client
+                    .raw_request(HttpMethod::GET, &path, None)
+                    .await?;
+                let value = decode("test-device:port", &response)?;
+                Ok(ResponseEnvelope::new(response, value))
+            }
+
+            /// Set the `port` leaf to `value`, leaving the rest of the container unchanged.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn set_port(&self, value: u16) -> Result<(), RpcError> {
+                self.set_port_full(value)
+                    .await
+                    .map(ResponseEnvelope::into_body)
+            }
+
+            /// Like [`set_port`](Self::set_port), also returning the response status and headers.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn set_port_full(
+                &self,
+                value: u16,
+            ) -> Result<ResponseEnvelope<()>, RpcError> {
+                let path = format!("{}/port", self.path);
+                let body = encode("test-device:port", &value)?;
+                let response = self
+                    .client
+                    .raw_request(HttpMethod::PUT, &path, Some(body.into()))
+                    .await?;
+                Ok(ResponseEnvelope::new(response, ()))
+            }
+
+            /// Check whether the resource exists.
+            ///
+            /// Issues HEAD, falling back to GET with `depth=1` if the server rejects HEAD.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a status other than 2xx or 404.
+            pub async fn exists(&self) -> Result<bool, RpcError> {
+                self.client.exists(&self.path).await
+            }
+
+            /// Discover the methods permitted on the resource.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the request fails or the server responds with a non-2xx status.
+            pub async fn options(&self) -> Result<ResourceCapabilities, RpcError> {
+                let response = self
+                    .client
+                    .raw_request(HttpMethod::OPTIONS, &self.path, None)
+                    .await?;
+                ResourceCapabilities::try_from(&response)
+            }
+        }
     }
 }
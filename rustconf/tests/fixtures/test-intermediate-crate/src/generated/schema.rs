@@ -0,0 +1,120 @@
+// @generated by rustconf. Do not edit; rerun the build to update.
+// This file is automatically generated by rustconf.
+// DO NOT EDIT MANUALLY.
+
+#![allow(unused_imports, unused_variables, dead_code, clippy::module_inception)]
+
+/// Data nodes of the test-device YANG module, e.g. for mapping the `error-path` of
+/// a RESTCONF error to the field that caused it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SchemaNode {
+    /// `/device`
+    Device,
+    /// `/device/name`
+    DeviceName,
+    /// `/device/enabled`
+    DeviceEnabled,
+    /// `/device/port`
+    DevicePort,
+}
+
+impl SchemaNode {
+    /// All data nodes, in schema order.
+    pub const ALL: &[SchemaNode] = &[
+        SchemaNode::Device,
+        SchemaNode::DeviceName,
+        SchemaNode::DeviceEnabled,
+        SchemaNode::DevicePort,
+    ];
+
+    /// Schema path without module qualifiers or list keys, e.g. `/a/b`.
+    #[must_use]
+    pub fn schema_path(&self) -> &'static str {
+        match *self {
+            SchemaNode::Device => "/device",
+            SchemaNode::DeviceName => "/device/name",
+            SchemaNode::DeviceEnabled => "/device/enabled",
+            SchemaNode::DevicePort => "/device/port",
+        }
+    }
+
+    /// Name of the Rust field holding the node in its parent struct.
+    #[must_use]
+    pub fn field_name(&self) -> &'static str {
+        match *self {
+            SchemaNode::Device => "device",
+            SchemaNode::DeviceName => "name",
+            SchemaNode::DeviceEnabled => "enabled",
+            SchemaNode::DevicePort => "port",
+        }
+    }
+
+    /// Parent node, or `None` for top-level nodes.
+    #[must_use]
+    pub fn parent(&self) -> Option<SchemaNode> {
+        match *self {
+            SchemaNode::Device => None,
+            SchemaNode::DeviceName => Some(SchemaNode::Device),
+            SchemaNode::DeviceEnabled => Some(SchemaNode::Device),
+            SchemaNode::DevicePort => Some(SchemaNode::Device),
+        }
+    }
+
+    /// Names of the key leaves of a list, empty for other nodes.
+    #[must_use]
+    pub fn list_keys(&self) -> &'static [&'static str] {
+        match *self {
+            SchemaNode::Device => &[],
+            SchemaNode::DeviceName => &[],
+            SchemaNode::DeviceEnabled => &[],
+            SchemaNode::DevicePort => &[],
+        }
+    }
+
+    /// XPath condition of the node's `when` statement, for tooling that
+    /// checks data against it. The node is only valid while it holds.
+    #[must_use]
+    pub fn when(&self) -> Option<&'static str> {
+        match *self {
+            SchemaNode::Device => None,
+            SchemaNode::DeviceName => None,
+            SchemaNode::DeviceEnabled => None,
+            SchemaNode::DevicePort => None,
+        }
+    }
+
+    /// Map a RESTCONF `error-path` to the node it identifies.
+    ///
+    /// Module qualifiers and list key predicates are ignored, so
+    /// `/example:a/b[name='x']/c` identifies the node at `/a/b/c`.
+    #[must_use]
+    pub fn from_error_path(error_path: &str) -> Option<SchemaNode> {
+        let path = strip_error_path(error_path);
+        SchemaNode::ALL
+            .iter()
+            .copied()
+            .find(|node| node.schema_path() == path)
+    }
+}
+
+/// Remove module qualifiers and key predicates from an instance path.
+fn strip_error_path(error_path: &str) -> String {
+    let mut path = String::new();
+    let mut depth = 0;
+    let mut quote = None;
+    for c in error_path.chars() {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') if depth > 0 => quote = Some(c),
+            (None, '[') => depth += 1,
+            (None, ']') => depth -= 1,
+            (None, _) if depth == 0 => path.push(c),
+            _ => {}
+        }
+    }
+    path.split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| segment.rsplit(':').next().unwrap_or(segment))
+        .fold(String::new(), |path, segment| path + "/" + segment)
+}
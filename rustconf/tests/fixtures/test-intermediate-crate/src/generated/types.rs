@@ -1,25 +1,86 @@
+// @generated by rustconf. Do not edit; rerun the build to update.
 // This file is automatically generated by rustconf.
 // DO NOT EDIT MANUALLY.
 
 #![allow(unused_imports, unused_variables, dead_code, clippy::module_inception)]
 
-use serde::{Deserialize, Serialize};
 use super::validation::*;
+use serde::{Deserialize, Serialize};
 
-///Device configuration and state
+/// Device configuration and state
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Device {
-    ///Device name
+    /// Device name
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
-    ///Whether the device is enabled
+    /// Whether the device is enabled
     #[serde(rename = "enabled")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub enabled: Option<bool>,
-    ///Device port number
+    /// Device port number
     #[serde(rename = "port")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub port: Option<u16>,
 }
 
+impl Device {
+    /// Merge `other` into `self` following YANG merge semantics.
+    ///
+    /// Leaves set in `other` overwrite those in `self` and unset optional leaves
+    /// are left alone, so `other` acts as a patch. Containers are merged
+    /// recursively, list entries are merged by key with new entries appended,
+    /// and leaf-list values are added if not already present. Choices are
+    /// replaced as a whole.
+    pub fn merge(&mut self, other: &Self) {
+        if other.name.is_some() {
+            self.name.clone_from(&other.name);
+        }
+        if other.enabled.is_some() {
+            self.enabled.clone_from(&other.enabled);
+        }
+        if other.port.is_some() {
+            self.port.clone_from(&other.port);
+        }
+    }
+}
+
+impl Device {
+    /// The value at the relative `path` below this `device`, if the path is in
+    /// the schema and a value is set there.
+    ///
+    /// List entries are selected by key as in RESTCONF URLs: `list=key1,key2`.
+    #[must_use]
+    pub fn get_by_path(&self, path: &str) -> Option<serde_json::Value> {
+        let data = serde_json::to_value(self).ok()?;
+        rustconf_runtime::data_path::get(&data, "/device", path, |path| {
+            super::schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())
+        })
+    }
+
+    /// Set the value at `path` below this `device`, creating missing
+    /// containers. A `null` value removes the node.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is not in the schema, selects a list entry
+    /// that does not exist, or the value does not fit the node.
+    pub fn set_by_path(
+        &mut self,
+        path: &str,
+        value: serde_json::Value,
+    ) -> Result<(), rustconf_runtime::DataPathError> {
+        let mut data = serde_json::to_value(&*self)?;
+        rustconf_runtime::data_path::set(&mut data, "/device", path, value, |path| {
+            super::schema::SchemaNode::from_error_path(path).map(|node| node.list_keys())
+        })?;
+        *self = serde_json::from_value(data)?;
+        Ok(())
+    }
+}
+
+/// Register the types of the top-level nodes of the `test-device` module, for
+/// decoding `anydata` content that carries them.
+pub fn register_anydata(registry: &mut rustconf_runtime::AnyDataRegistry) {
+    registry.register::<Device>("test-device:device");
+}
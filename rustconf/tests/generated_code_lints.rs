@@ -0,0 +1,34 @@
+//! Self-check that generated code is Clippy-clean
+//!
+//! The `generated-lints` fixture generates bindings for a representative
+//! model in several configurations (single-file and modular, with and
+//! without validation, RFC 7951 encodings, server code) and compiles them
+//! with `cargo clippy --all-targets -- -D warnings`, as a downstream crate
+//! with strict lints would.
+
+use std::path::PathBuf;
+use std::process::Command;
+
+fn workspace_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+#[test]
+fn test_generated_code_passes_clippy() {
+    let fixture_path = workspace_root()
+        .join("tests")
+        .join("fixtures")
+        .join("generated-lints");
+
+    let output = Command::new("cargo")
+        .args(["clippy", "--all-targets", "--", "-D", "warnings"])
+        .current_dir(&fixture_path)
+        .output()
+        .expect("Failed to execute cargo clippy");
+
+    if !output.status.success() {
+        eprintln!("STDOUT:\n{}", String::from_utf8_lossy(&output.stdout));
+        eprintln!("STDERR:\n{}", String::from_utf8_lossy(&output.stderr));
+        panic!("Generated code has clippy warnings");
+    }
+}
@@ -0,0 +1,127 @@
+//! Integration tests for the testkit server, driven over real HTTP.
+
+use std::io::{Read, Write};
+use std::net::TcpStream;
+
+use rustconf::testkit::TestServer;
+use serde_json::{json, Value};
+
+const DEVICE_YANG: &str = r#"
+    module device {
+        namespace "urn:device";
+        prefix dev;
+
+        container system {
+            leaf hostname {
+                type string;
+            }
+        }
+
+        container interfaces {
+            list interface {
+                key "name";
+                leaf name {
+                    type string;
+                }
+                leaf mtu {
+                    type uint16;
+                }
+            }
+        }
+    }
+"#;
+
+/// Send a request and return the status code and JSON body.
+fn send(server: &TestServer, method: &str, target: &str, body: Option<Value>) -> (u16, Value) {
+    let body = body.map(|b| b.to_string()).unwrap_or_default();
+    let mut stream = TcpStream::connect(server.addr()).unwrap();
+    write!(
+        stream,
+        "{} {} HTTP/1.1\r\nHost: {}\r\nContent-Type: application/yang-data+json\r\nContent-Length: {}\r\n\r\n{}",
+        method,
+        target,
+        server.addr(),
+        body.len(),
+        body
+    )
+    .unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let status = response
+        .split_whitespace()
+        .nth(1)
+        .and_then(|code| code.parse().ok())
+        .unwrap();
+    let payload = response
+        .split_once("\r\n\r\n")
+        .map(|(_, payload)| payload)
+        .unwrap_or_default();
+    let json = serde_json::from_str(payload).unwrap_or(Value::Null);
+    (status, json)
+}
+
+#[test]
+fn test_server_serves_initial_data_and_records_requests() {
+    let server = TestServer::builder()
+        .yang_source(DEVICE_YANG)
+        .data(json!({ "device:system": { "hostname": "edge-1" } }))
+        .start()
+        .unwrap();
+    assert!(server.base_url().starts_with("http://127.0.0.1:"));
+
+    let (status, body) = send(&server, "GET", "/restconf/data/device:system?depth=1", None);
+    assert_eq!(status, 200);
+    assert_eq!(body, json!({ "device:system": { "hostname": "edge-1" } }));
+
+    let request = server.assert_requested("GET", "/restconf/data/device:system");
+    assert_eq!(request.query.as_deref(), Some("depth=1"));
+    assert_eq!(request.status, 200);
+    server.assert_not_requested("DELETE", "/restconf/data/device:system");
+}
+
+#[test]
+fn test_writes_are_visible_in_datastore() {
+    let server = TestServer::builder()
+        .yang_source(DEVICE_YANG)
+        .data(json!({ "device:interfaces": { "interface": [] } }))
+        .start()
+        .unwrap();
+
+    let entry = json!({ "device:interface": [{ "name": "eth0", "mtu": 1500 }] });
+    let (status, _) = send(
+        &server,
+        "PUT",
+        "/restconf/data/device:interfaces/interface=eth0",
+        Some(entry.clone()),
+    );
+    assert_eq!(status, 201);
+
+    let request = server.assert_requested("PUT", "/restconf/data/device:interfaces/interface=eth0");
+    assert_eq!(request.body, Some(entry));
+    assert_eq!(
+        server.data()["device:interfaces"]["interface"],
+        json!([{ "name": "eth0", "mtu": 1500 }])
+    );
+
+    server.clear_requests();
+    assert!(server.requests().is_empty());
+    send(&server, "GET", "/restconf/data/device:interfaces", None);
+    send(&server, "GET", "/restconf/data/device:interfaces", None);
+    assert_eq!(
+        server.request_count("GET", "/restconf/data/device:interfaces"),
+        2
+    );
+}
+
+#[test]
+#[should_panic(expected = "expected a PATCH /restconf/data/device:system request")]
+fn test_assert_requested_panics_when_missing() {
+    let server = TestServer::builder()
+        .yang_source(DEVICE_YANG)
+        .start()
+        .unwrap();
+    send(&server, "GET", "/restconf/data/device:system", None);
+
+    server.assert_requested("PATCH", "/restconf/data/device:system");
+}